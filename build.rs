@@ -38,6 +38,9 @@ fn main() -> anyhow::Result<()> {
             test_directory_module(out, "tests/misc_testsuite/memory64", strategy)?;
             test_directory_module(out, "tests/misc_testsuite/component-model", strategy)?;
             test_directory_module(out, "tests/misc_testsuite/function-references", strategy)?;
+            test_directory_module(out, "tests/misc_testsuite/gc", strategy)?;
+            test_directory_module(out, "tests/misc_testsuite/exceptions", strategy)?;
+            test_directory_module(out, "tests/misc_testsuite/extended-const", strategy)?;
             // The testsuite of Winch is a subset of the official
             // WebAssembly test suite, until parity is reached. This
             // check is in place to prevent Cranelift from duplicating
@@ -206,17 +209,62 @@ fn ignore(testsuite: &str, testname: &str, strategy: &str) -> bool {
     // Ignore everything except the winch misc test suite.
     if strategy == "Winch" {
         if testsuite == "misc_testsuite" {
-            // The misc/call_indirect is fully supported by Winch.
-            if testname != "call_indirect" {
-                return true;
-            }
+            // The following misc tests are fully supported by Winch.
+            return ![
+                "call_indirect",
+                "elem_ref_null",
+                "elem_drop",
+                "externref_id_function",
+                "externref_segment",
+                "many_table_gets_lead_to_gc",
+                "mutable_externref_globals",
+                "no_mixup_stack_maps",
+                "simple_ref_is_null",
+                "table_grow_with_funcref",
+            ]
+            .contains(&testname);
         }
         if testsuite == "spec_testsuite" {
             // The official following tests are supported.
-            return !["table_init", "table_copy"].contains(&testname);
+            return ![
+                "table_init",
+                "table_copy",
+                "address",
+                "endianness",
+                "float_memory",
+                "load",
+                "store",
+                "memory_trap",
+            ]
+            .contains(&testname);
+        }
+
+        if testsuite == "simd" {
+            // NaN canonicalization is a Cranelift-only setting.
+            return testname == "canonicalize_nan";
+        }
+
+        if testsuite == "threads" {
+            // The misc threads tests are supported.
+            return ![
+                "LB",
+                "LB_atomic",
+                "MP",
+                "MP_atomic",
+                "MP_wait",
+                "SB",
+                "SB_atomic",
+                "atomics_notify",
+                "atomics_wait_address",
+                "load_store_alignment",
+                "wait_notify",
+            ]
+            .contains(&testname);
         }
 
-        if testsuite != "winch" {
+        // Typed function references and tail calls are supported, modulo
+        // the tests ignored below for all strategies.
+        if testsuite != "winch" && testsuite != "function_references" && testsuite != "tail_call" {
             return true;
         }
     }
@@ -4,6 +4,7 @@ cargo test \
     --features wasi-threads \
     --features wasi-http \
     --features component-model \
+    --features wasi-nn-onnx \
     --features serve \
     --workspace \
     --exclude test-programs \
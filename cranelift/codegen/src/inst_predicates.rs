@@ -70,7 +70,27 @@ pub fn is_pure_for_egraph(func: &Function, inst: Inst) -> bool {
 
     let op = func.dfg.insts[inst].opcode();
 
-    has_one_result && (is_readonly_load || (!op.can_load() && !trivially_has_side_effects(op)))
+    has_one_result
+        && !is_reference_bitcast(func, inst)
+        && (is_readonly_load || (!op.can_load() && !trivially_has_side_effects(op)))
+}
+
+/// Is the given instruction a `bitcast` to or from a reference type?
+///
+/// These must stay where they are: moving one past a safepoint would leave
+/// the reference live there as an integer, which stack maps don't record.
+fn is_reference_bitcast(func: &Function, inst: Inst) -> bool {
+    match func.dfg.insts[inst] {
+        InstructionData::LoadNoOffset {
+            opcode: Opcode::Bitcast,
+            arg,
+            ..
+        } => {
+            func.dfg.value_type(arg).is_ref()
+                || func.dfg.value_type(func.dfg.first_result(inst)).is_ref()
+        }
+        _ => false,
+    }
 }
 
 /// Can the given instruction be merged into another copy of itself?
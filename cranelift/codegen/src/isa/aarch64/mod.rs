@@ -10,6 +10,8 @@ use crate::machinst::{
     compile, CompiledCode, CompiledCodeStencil, MachInst, MachTextSectionBuilder, Reg, SigSet,
     TextSectionBuilder, VCode,
 };
+#[cfg(feature = "unwind")]
+use crate::machinst::{Final, MachBufferFinalized};
 use crate::result::CodegenResult;
 use crate::settings as shared_settings;
 use alloc::{boxed::Box, vec::Vec};
@@ -26,6 +28,9 @@ pub mod settings;
 
 use self::inst::EmitInfo;
 
+#[cfg(feature = "unwind")]
+pub use inst::unwind::systemv::create_cie;
+
 /// An AArch64 backend.
 pub struct AArch64Backend {
     triple: Triple,
@@ -125,25 +130,7 @@ impl TargetIsa for AArch64Backend {
         result: &CompiledCode,
         kind: crate::isa::unwind::UnwindInfoKind,
     ) -> CodegenResult<Option<crate::isa::unwind::UnwindInfo>> {
-        use crate::isa::unwind::UnwindInfo;
-        use crate::isa::unwind::UnwindInfoKind;
-        Ok(match kind {
-            UnwindInfoKind::SystemV => {
-                let mapper = self::inst::unwind::systemv::RegisterMapper;
-                Some(UnwindInfo::SystemV(
-                    crate::isa::unwind::systemv::create_unwind_info_from_insts(
-                        &result.buffer.unwind_info[..],
-                        result.buffer.data().len(),
-                        &mapper,
-                    )?,
-                ))
-            }
-            UnwindInfoKind::Windows => {
-                // TODO: support Windows unwind info on AArch64
-                None
-            }
-            _ => None,
-        })
+        emit_unwind_info(&result.buffer, kind)
     }
 
     #[cfg(feature = "unwind")]
@@ -163,7 +150,7 @@ impl TargetIsa for AArch64Backend {
             unimplemented!("Specifying that the B key is used with pointer authentication instructions in the CIE is not implemented.");
         }
 
-        Some(inst::unwind::systemv::create_cie())
+        Some(create_cie())
     }
 
     fn text_section_builder(&self, num_funcs: usize) -> Box<dyn TextSectionBuilder> {
@@ -216,6 +203,32 @@ impl TargetIsa for AArch64Backend {
     }
 }
 
+/// Emit unwind info for an AArch64 target.
+#[cfg(feature = "unwind")]
+pub fn emit_unwind_info(
+    buffer: &MachBufferFinalized<Final>,
+    kind: crate::isa::unwind::UnwindInfoKind,
+) -> CodegenResult<Option<crate::isa::unwind::UnwindInfo>> {
+    use crate::isa::unwind::{UnwindInfo, UnwindInfoKind};
+    Ok(match kind {
+        UnwindInfoKind::SystemV => {
+            let mapper = self::inst::unwind::systemv::RegisterMapper;
+            Some(UnwindInfo::SystemV(
+                crate::isa::unwind::systemv::create_unwind_info_from_insts(
+                    &buffer.unwind_info[..],
+                    buffer.data().len(),
+                    &mapper,
+                )?,
+            ))
+        }
+        UnwindInfoKind::Windows => {
+            // TODO: support Windows unwind info on AArch64
+            None
+        }
+        _ => None,
+    })
+}
+
 impl fmt::Display for AArch64Backend {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         f.debug_struct("MachBackend")
@@ -80,7 +80,10 @@ use crate::translation_utils::{
     block_with_params, blocktype_params_results, f32_translation, f64_translation,
 };
 use crate::wasm_unsupported;
-use crate::{FuncIndex, GlobalIndex, MemoryIndex, TableIndex, TypeIndex, WasmResult};
+use crate::{
+    DataIndex, ElemIndex, FuncIndex, GlobalIndex, MemoryIndex, TableIndex, TagIndex, TypeIndex,
+    WasmResult,
+};
 use core::{i32, u32};
 use cranelift_codegen::ir::condcodes::{FloatCC, IntCC};
 use cranelift_codegen::ir::immediates::Offset32;
@@ -94,7 +97,7 @@ use itertools::Itertools;
 use smallvec::SmallVec;
 use std::convert::TryFrom;
 use std::vec::Vec;
-use wasmparser::{FuncValidator, MemArg, Operator, WasmModuleResources};
+use wasmparser::{Catch, FuncValidator, MemArg, Operator, WasmModuleResources};
 
 /// Given a `Reachability<T>`, unwrap the inner `T` or, when unreachable, set
 /// `state.reachable = false` and return.
@@ -450,6 +453,7 @@ pub fn translate_operator<FE: FuncEnvironment + ?Sized>(
             state
                 .stack
                 .extend_from_slice(builder.block_params(next_block));
+            seal_exception_handlers(builder, state);
         }
         /**************************** Branch instructions *********************************
          * The branch instructions all have as arguments a target nesting level, which
@@ -594,7 +598,6 @@ pub fn translate_operator<FE: FuncEnvironment + ?Sized>(
         /********************************** Exception handing **********************************/
         Operator::Try { .. }
         | Operator::Catch { .. }
-        | Operator::Throw { .. }
         | Operator::Rethrow { .. }
         | Operator::Delegate { .. }
         | Operator::CatchAll => {
@@ -636,6 +639,7 @@ pub fn translate_operator<FE: FuncEnvironment + ?Sized>(
             );
             state.popn(num_args);
             state.pushn(inst_results);
+            translate_exception_check(builder, state, environ)?;
         }
         Operator::CallIndirect {
             type_index,
@@ -670,6 +674,7 @@ pub fn translate_operator<FE: FuncEnvironment + ?Sized>(
             );
             state.popn(num_args);
             state.pushn(inst_results);
+            translate_exception_check(builder, state, environ)?;
         }
         /******************************* Tail Calls ******************************************
          * The tail call instructions pop their arguments from the stack and
@@ -2495,6 +2500,7 @@ pub fn translate_operator<FE: FuncEnvironment + ?Sized>(
             );
             state.popn(num_args);
             state.pushn(inst_results);
+            translate_exception_check(builder, state, environ)?;
         }
         Operator::RefAsNonNull => {
             let r = state.pop1();
@@ -2503,43 +2509,243 @@ pub fn translate_operator<FE: FuncEnvironment + ?Sized>(
             state.push1(r);
         }
 
-        Operator::TryTable { .. } | Operator::ThrowRef => {
-            unimplemented!("exception operators not yet implemented")
-        }
-
-        Operator::RefI31
-        | Operator::I31GetS
-        | Operator::I31GetU
-        | Operator::RefEq
-        | Operator::RefTestNonNull { .. }
-        | Operator::RefTestNullable { .. }
-        | Operator::RefCastNonNull { .. }
-        | Operator::RefCastNullable { .. }
-        | Operator::BrOnCast { .. }
-        | Operator::BrOnCastFail { .. }
-        | Operator::AnyConvertExtern
-        | Operator::ExternConvertAny
-        | Operator::ArrayNew { .. }
-        | Operator::ArrayNewDefault { .. }
-        | Operator::ArrayNewFixed { .. }
-        | Operator::ArrayNewData { .. }
-        | Operator::ArrayNewElem { .. }
-        | Operator::ArrayGet { .. }
-        | Operator::ArrayGetU { .. }
-        | Operator::ArrayGetS { .. }
-        | Operator::ArraySet { .. }
-        | Operator::ArrayLen { .. }
-        | Operator::ArrayFill { .. }
-        | Operator::ArrayCopy { .. }
-        | Operator::ArrayInitData { .. }
-        | Operator::ArrayInitElem { .. }
-        | Operator::StructNew { .. }
-        | Operator::StructNewDefault { .. }
-        | Operator::StructGetS { .. }
-        | Operator::StructGetU { .. }
-        | Operator::StructSet { .. }
-        | Operator::StructGet { .. } => {
-            unimplemented!("GC operators not yet implemented")
+        Operator::TryTable { try_table } => {
+            let (params, results) = blocktype_params_results(validator, try_table.ty)?;
+            let next = block_with_params(builder, results.clone(), environ)?;
+            let body = builder.create_block();
+            builder.ins().jump(body, &[]);
+            builder.seal_block(body); // Only predecessor is the current block.
+
+            // The labels of the catch clauses are relative to the context
+            // outside of the `try_table`, so the dispatch code is translated
+            // before its frame is pushed. Calls within the body are the
+            // predecessors of the dispatch block, which is sealed at the `end`.
+            let dispatch = builder.create_block();
+            builder.set_cold_block(dispatch);
+            builder.switch_to_block(dispatch);
+            translate_exception_dispatch(&try_table.catches, builder, state, environ)?;
+
+            builder.switch_to_block(body);
+            state.push_block(next, params.len(), results.len());
+            state
+                .exception_handlers
+                .push((dispatch, state.control_stack.len()));
+        }
+        Operator::Throw { tag_index } => {
+            let num_args = validator
+                .resources()
+                .tag_at(*tag_index)
+                .expect("should be valid")
+                .params()
+                .len();
+            let args = state.peekn(num_args);
+            environ.translate_throw(builder, TagIndex::from_u32(*tag_index), args)?;
+            state.popn(num_args);
+            translate_propagate_exception(builder, state);
+            state.reachable = false;
+        }
+        Operator::ThrowRef => {
+            let exnref = state.pop1();
+            environ.translate_throw_ref(builder, exnref)?;
+            translate_propagate_exception(builder, state);
+            state.reachable = false;
+        }
+
+        Operator::RefI31 => {
+            let value = state.pop1();
+            state.push1(environ.translate_ref_i31(builder.cursor(), value)?);
+        }
+        Operator::I31GetS | Operator::I31GetU => {
+            let i31ref = state.pop1();
+            let signed = matches!(op, Operator::I31GetS);
+            state.push1(environ.translate_i31_get(builder.cursor(), i31ref, signed)?);
+        }
+        Operator::RefEq => {
+            let (lhs, rhs) = state.pop2();
+            state.push1(environ.translate_ref_eq(builder.cursor(), lhs, rhs)?);
+        }
+        Operator::RefTestNonNull { hty } | Operator::RefTestNullable { hty } => {
+            let value = state.pop1();
+            let nullable = matches!(op, Operator::RefTestNullable { .. });
+            let hty = environ.convert_heap_type(*hty);
+            state.push1(environ.translate_ref_test(builder, nullable, hty, value)?);
+        }
+        Operator::RefCastNonNull { hty } | Operator::RefCastNullable { hty } => {
+            // The cast doesn't change the representation of the reference so
+            // it's left on the stack as-is.
+            let value = state.peek1();
+            let nullable = matches!(op, Operator::RefCastNullable { .. });
+            let hty = environ.convert_heap_type(*hty);
+            environ.translate_ref_cast(builder, nullable, hty, value)?;
+        }
+        Operator::BrOnCast {
+            relative_depth,
+            to_ref_type,
+            ..
+        }
+        | Operator::BrOnCastFail {
+            relative_depth,
+            to_ref_type,
+            ..
+        } => {
+            let hty = environ.convert_heap_type(to_ref_type.heap_type());
+            let is_cast = environ.translate_ref_test(
+                builder,
+                to_ref_type.is_nullable(),
+                hty,
+                state.peek1(),
+            )?;
+            let (br_destination, inputs) = translate_br_if_args(*relative_depth, state);
+            let else_block = builder.create_block();
+            if matches!(op, Operator::BrOnCast { .. }) {
+                canonicalise_brif(builder, is_cast, br_destination, inputs, else_block, &[]);
+            } else {
+                canonicalise_brif(builder, is_cast, else_block, &[], br_destination, inputs);
+            }
+
+            builder.seal_block(else_block); // The only predecessor is the current block.
+            builder.switch_to_block(else_block);
+        }
+
+        Operator::StructNew { struct_type_index } => {
+            let fields = match &validator
+                .resources()
+                .sub_type_at(*struct_type_index)
+                .unwrap()
+                .composite_type
+            {
+                wasmparser::CompositeType::Struct(s) => s.fields.len(),
+                _ => unreachable!("validation checks that the type is a struct"),
+            };
+            let type_index = TypeIndex::from_u32(*struct_type_index);
+            let value = environ.translate_struct_new(builder, type_index, state.peekn(fields))?;
+            state.popn(fields);
+            state.push1(value);
+        }
+        Operator::StructNewDefault { struct_type_index } => {
+            let type_index = TypeIndex::from_u32(*struct_type_index);
+            state.push1(environ.translate_struct_new_default(builder, type_index)?);
+        }
+        Operator::StructGet {
+            struct_type_index,
+            field_index,
+        }
+        | Operator::StructGetS {
+            struct_type_index,
+            field_index,
+        }
+        | Operator::StructGetU {
+            struct_type_index,
+            field_index,
+        } => {
+            let struct_ref = state.pop1();
+            let type_index = TypeIndex::from_u32(*struct_type_index);
+            let signed = matches!(op, Operator::StructGetS { .. });
+            state.push1(environ.translate_struct_get(
+                builder,
+                type_index,
+                *field_index,
+                signed,
+                struct_ref,
+            )?);
+        }
+        Operator::StructSet {
+            struct_type_index,
+            field_index,
+        } => {
+            let (struct_ref, value) = state.pop2();
+            let type_index = TypeIndex::from_u32(*struct_type_index);
+            environ.translate_struct_set(builder, type_index, *field_index, struct_ref, value)?;
+        }
+        Operator::ArrayNew { array_type_index } => {
+            let (elem, len) = state.pop2();
+            let type_index = TypeIndex::from_u32(*array_type_index);
+            state.push1(environ.translate_array_new(builder, type_index, elem, len)?);
+        }
+        Operator::ArrayNewDefault { array_type_index } => {
+            let len = state.pop1();
+            let type_index = TypeIndex::from_u32(*array_type_index);
+            state.push1(environ.translate_array_new_default(builder, type_index, len)?);
+        }
+        Operator::ArrayNewFixed {
+            array_type_index,
+            array_size,
+        } => {
+            let len = usize::try_from(*array_size).unwrap();
+            let type_index = TypeIndex::from_u32(*array_type_index);
+            let value = environ.translate_array_new_fixed(builder, type_index, state.peekn(len))?;
+            state.popn(len);
+            state.push1(value);
+        }
+        Operator::ArrayNewData {
+            array_type_index,
+            array_data_index,
+        } => {
+            let (offset, len) = state.pop2();
+            let type_index = TypeIndex::from_u32(*array_type_index);
+            let data_index = DataIndex::from_u32(*array_data_index);
+            state.push1(
+                environ.translate_array_new_data(builder, type_index, data_index, offset, len)?,
+            );
+        }
+        Operator::ArrayNewElem {
+            array_type_index,
+            array_elem_index,
+        } => {
+            let (offset, len) = state.pop2();
+            let type_index = TypeIndex::from_u32(*array_type_index);
+            let elem_index = ElemIndex::from_u32(*array_elem_index);
+            state.push1(
+                environ.translate_array_new_elem(builder, type_index, elem_index, offset, len)?,
+            );
+        }
+        Operator::ArrayGet { array_type_index }
+        | Operator::ArrayGetS { array_type_index }
+        | Operator::ArrayGetU { array_type_index } => {
+            let (array, index) = state.pop2();
+            let type_index = TypeIndex::from_u32(*array_type_index);
+            let signed = matches!(op, Operator::ArrayGetS { .. });
+            state.push1(environ.translate_array_get(builder, type_index, signed, array, index)?);
+        }
+        Operator::ArraySet { array_type_index } => {
+            let (array, index, value) = state.pop3();
+            let type_index = TypeIndex::from_u32(*array_type_index);
+            environ.translate_array_set(builder, type_index, array, index, value)?;
+        }
+        Operator::ArrayLen => {
+            let array = state.pop1();
+            state.push1(environ.translate_array_len(builder, array)?);
+        }
+        Operator::ArrayFill { array_type_index } => {
+            let len = state.pop1();
+            let (array, index, value) = state.pop3();
+            let type_index = TypeIndex::from_u32(*array_type_index);
+            environ.translate_array_fill(builder, type_index, array, index, value, len)?;
+        }
+        Operator::ArrayCopy { .. } => {
+            let (src_index, len) = state.pop2();
+            let (dst, dst_index, src) = state.pop3();
+            environ.translate_array_copy(builder, dst, dst_index, src, src_index, len)?;
+        }
+        Operator::ArrayInitData {
+            array_data_index, ..
+        } => {
+            let (offset, len) = state.pop2();
+            let (array, index) = state.pop2();
+            let data_index = DataIndex::from_u32(*array_data_index);
+            environ.translate_array_init_data(builder, data_index, array, index, offset, len)?;
+        }
+        Operator::ArrayInitElem {
+            array_elem_index, ..
+        } => {
+            let (offset, len) = state.pop2();
+            let (array, index) = state.pop2();
+            let elem_index = ElemIndex::from_u32(*array_elem_index);
+            environ.translate_array_init_elem(builder, elem_index, array, index, offset, len)?;
+        }
+
+        Operator::AnyConvertExtern | Operator::ExternConvertAny => {
+            return Err(wasm_unsupported!("proposed GC operator {:?}", op));
         }
     };
     Ok(())
@@ -2571,7 +2777,9 @@ fn translate_unreachable_operator<FE: FuncEnvironment + ?Sized>(
                 blockty,
             );
         }
-        Operator::Loop { blockty: _ } | Operator::Block { blockty: _ } => {
+        Operator::Loop { blockty: _ }
+        | Operator::Block { blockty: _ }
+        | Operator::TryTable { try_table: _ } => {
             state.push_block(ir::Block::reserved_value(), 0, 0);
         }
         Operator::Else => {
@@ -2675,6 +2883,7 @@ fn translate_unreachable_operator<FE: FuncEnvironment + ?Sized>(
                 stack.extend_from_slice(builder.block_params(frame.following_code()));
                 state.reachable = true;
             }
+            seal_exception_handlers(builder, state);
         }
         _ => {
             // We don't translate because this is unreachable code
@@ -3220,6 +3429,171 @@ fn translate_vector_fcmp(
     state.push1(builder.ins().fcmp(cc, bitcast_a, bitcast_b))
 }
 
+/// Returns the block that an exception thrown at the current position
+/// propagates to: the dispatch block of the innermost `try_table`, or
+/// otherwise a block returning to this function's caller.
+///
+/// The latter is created on first use, in which case the returned flag is set
+/// and the block must be filled with `fill_exception_unwind` once the current
+/// block has been terminated.
+fn exception_handler(
+    builder: &mut FunctionBuilder,
+    state: &mut FuncTranslationState,
+) -> (ir::Block, bool) {
+    if let Some(&(dispatch, _)) = state.exception_handlers.last() {
+        return (dispatch, false);
+    }
+    if let Some(unwind) = state.exception_unwind {
+        return (unwind, false);
+    }
+    let unwind = builder.create_block();
+    builder.set_cold_block(unwind);
+    state.exception_unwind = Some(unwind);
+    (unwind, true)
+}
+
+/// Fills the block which propagates exceptions to this function's caller.
+///
+/// The exception stays pending, so the results returned alongside it are
+/// just placeholders which are never observed.
+fn fill_exception_unwind(
+    builder: &mut FunctionBuilder,
+    state: &mut FuncTranslationState,
+    unwind: ir::Block,
+) {
+    let frame = &mut state.control_stack[0];
+    frame.set_branched_to_exit();
+    let exit = frame.br_destination();
+    builder.switch_to_block(unwind);
+    let results = builder
+        .block_params(exit)
+        .iter()
+        .map(|v| builder.func.dfg.value_type(*v))
+        .collect::<SmallVec<[ir::Type; 4]>>()
+        .into_iter()
+        .map(|ty| {
+            if ty.is_ref() {
+                builder.ins().null(ty)
+            } else if ty.is_vector() {
+                let zero = builder.func.dfg.constants.insert([0; 16].to_vec().into());
+                builder.ins().vconst(ty, zero)
+            } else if ty == F32 {
+                builder.ins().f32const(0.0)
+            } else if ty == F64 {
+                builder.ins().f64const(0.0)
+            } else {
+                builder.ins().iconst(ty, 0)
+            }
+        })
+        .collect::<SmallVec<[Value; 4]>>();
+    builder.ins().jump(exit, &results);
+}
+
+/// Transfers control to the innermost exception handler after an exception
+/// has been thrown, terminating the current block.
+fn translate_propagate_exception(builder: &mut FunctionBuilder, state: &mut FuncTranslationState) {
+    let (handler, new_unwind) = exception_handler(builder, state);
+    builder.ins().jump(handler, &[]);
+    if new_unwind {
+        fill_exception_unwind(builder, state, handler);
+    }
+}
+
+/// Checks whether the call that was just translated threw an exception and, if
+/// so, branches to the innermost exception handler.
+fn translate_exception_check<FE: FuncEnvironment + ?Sized>(
+    builder: &mut FunctionBuilder,
+    state: &mut FuncTranslationState,
+    environ: &mut FE,
+) -> WasmResult<()> {
+    if !environ.exceptions_enabled() {
+        return Ok(());
+    }
+    let pending = environ.translate_exception_pending(builder)?;
+    let next = builder.create_block();
+    let (handler, new_unwind) = exception_handler(builder, state);
+    builder.ins().brif(pending, handler, &[], next, &[]);
+    if new_unwind {
+        fill_exception_unwind(builder, state, handler);
+    }
+    builder.seal_block(next); // The only predecessor is the current block.
+    builder.switch_to_block(next);
+    Ok(())
+}
+
+/// Translates the dispatch code of a `try_table` into the current block,
+/// which tests each of `catches` in order and branches to the label of the
+/// first one that matches the pending exception.
+fn translate_exception_dispatch<FE: FuncEnvironment + ?Sized>(
+    catches: &[Catch],
+    builder: &mut FunctionBuilder,
+    state: &mut FuncTranslationState,
+    environ: &mut FE,
+) -> WasmResult<()> {
+    for catch in catches {
+        let (tag, with_ref, label) = match *catch {
+            Catch::One { tag, label } => (Some(TagIndex::from_u32(tag)), false, label),
+            Catch::OneRef { tag, label } => (Some(TagIndex::from_u32(tag)), true, label),
+            Catch::All { label } => (None, false, label),
+            Catch::AllRef { label } => (None, true, label),
+        };
+
+        let next = match tag {
+            Some(tag) => {
+                let matches = environ.translate_exception_matches(builder, tag)?;
+                let catch_block = builder.create_block();
+                let next = builder.create_block();
+                builder.ins().brif(matches, catch_block, &[], next, &[]);
+                builder.seal_block(catch_block);
+                builder.seal_block(next);
+                builder.switch_to_block(catch_block);
+                Some(next)
+            }
+            None => None,
+        };
+
+        let (mut args, exnref) = environ.translate_take_exception(builder, tag)?;
+        if with_ref {
+            args.push(exnref);
+        }
+        let i = state.control_stack.len() - 1 - (label as usize);
+        let frame = &mut state.control_stack[i];
+        frame.set_branched_to_exit();
+        canonicalise_then_jump(builder, frame.br_destination(), &args);
+
+        match next {
+            Some(next) => builder.switch_to_block(next),
+            // A `catch_all` catches everything, so any following clauses are
+            // never reached.
+            None => return Ok(()),
+        }
+    }
+
+    // No clause matched, so the exception continues on to the enclosing
+    // handler.
+    translate_propagate_exception(builder, state);
+    Ok(())
+}
+
+/// Seals the dispatch block of the `try_table` whose frame was just popped, if
+/// any, as calls within its body were its only predecessors, along with the
+/// block propagating exceptions to the caller once the whole function has been
+/// translated.
+fn seal_exception_handlers(builder: &mut FunctionBuilder, state: &mut FuncTranslationState) {
+    let height = state.control_stack.len() + 1;
+    if let Some(&(dispatch, h)) = state.exception_handlers.last() {
+        if h == height {
+            state.exception_handlers.pop();
+            builder.seal_block(dispatch);
+        }
+    }
+    if state.control_stack.is_empty() {
+        if let Some(unwind) = state.exception_unwind {
+            builder.seal_block(unwind);
+        }
+    }
+}
+
 fn translate_br_if(
     relative_depth: u32,
     builder: &mut FunctionBuilder,
@@ -7,10 +7,11 @@
 //! [Wasmtime]: https://github.com/bytecodealliance/wasmtime
 
 use crate::state::FuncTranslationState;
+use crate::wasm_unsupported;
 use crate::{
     DataIndex, ElemIndex, FuncIndex, Global, GlobalIndex, GlobalInit, Heap, HeapData, Memory,
-    MemoryIndex, SignatureIndex, Table, TableIndex, Tag, TagIndex, TypeConvert, TypeIndex,
-    WasmError, WasmFuncType, WasmHeapType, WasmResult,
+    MemoryIndex, SignatureIndex, Table, TableIndex, TagIndex, TypeConvert, TypeIndex, WasmError,
+    WasmFuncType, WasmHeapType, WasmResult,
 };
 use core::convert::From;
 use cranelift_codegen::cursor::FuncCursor;
@@ -21,6 +22,7 @@ use cranelift_entity::PrimaryMap;
 use cranelift_frontend::FunctionBuilder;
 use std::boxed::Box;
 use std::string::ToString;
+use std::vec::Vec;
 use wasmparser::{FuncValidator, FunctionBody, Operator, ValidatorResources, WasmFeatures};
 
 /// The value of a WebAssembly global variable.
@@ -481,6 +483,407 @@ pub trait FuncEnvironment: TargetEnvironment {
         func_index: FuncIndex,
     ) -> WasmResult<ir::Value>;
 
+    /// Translate a `ref.eq` WebAssembly instruction, returning an `i32` that
+    /// is non-zero if the two references are equal.
+    ///
+    /// By default, the GC proposal is not supported.
+    fn translate_ref_eq(
+        &mut self,
+        pos: FuncCursor,
+        lhs: ir::Value,
+        rhs: ir::Value,
+    ) -> WasmResult<ir::Value> {
+        let _ = (pos, lhs, rhs);
+        Err(wasm_unsupported!("the GC proposal is not supported"))
+    }
+
+    /// Translate a `ref.i31` WebAssembly instruction.
+    ///
+    /// By default, the GC proposal is not supported.
+    fn translate_ref_i31(&mut self, pos: FuncCursor, value: ir::Value) -> WasmResult<ir::Value> {
+        let _ = (pos, value);
+        Err(wasm_unsupported!("the GC proposal is not supported"))
+    }
+
+    /// Translate an `i31.get_s` or `i31.get_u` WebAssembly instruction,
+    /// including the trap if `i31ref` is null.
+    ///
+    /// By default, the GC proposal is not supported.
+    fn translate_i31_get(
+        &mut self,
+        pos: FuncCursor,
+        i31ref: ir::Value,
+        signed: bool,
+    ) -> WasmResult<ir::Value> {
+        let _ = (pos, i31ref, signed);
+        Err(wasm_unsupported!("the GC proposal is not supported"))
+    }
+
+    /// Translate a `ref.test` of `value` against the reference type with the
+    /// heap type `ty` and the given nullability, returning an `i32` that is
+    /// non-zero if the test succeeds.
+    ///
+    /// This is also used for the `br_on_cast` and `br_on_cast_fail`
+    /// instructions. By default, the GC proposal is not supported.
+    fn translate_ref_test(
+        &mut self,
+        builder: &mut FunctionBuilder,
+        nullable: bool,
+        ty: WasmHeapType,
+        value: ir::Value,
+    ) -> WasmResult<ir::Value> {
+        let _ = (builder, nullable, ty, value);
+        Err(wasm_unsupported!("the GC proposal is not supported"))
+    }
+
+    /// Translate a `ref.cast` of `value` to the reference type with the heap
+    /// type `ty` and the given nullability, trapping if the cast fails.
+    ///
+    /// By default, the GC proposal is not supported.
+    fn translate_ref_cast(
+        &mut self,
+        builder: &mut FunctionBuilder,
+        nullable: bool,
+        ty: WasmHeapType,
+        value: ir::Value,
+    ) -> WasmResult<()> {
+        let _ = (builder, nullable, ty, value);
+        Err(wasm_unsupported!("the GC proposal is not supported"))
+    }
+
+    /// Translate a `struct.new` WebAssembly instruction of the struct type
+    /// `struct_type_index`, whose fields are initialized with `fields`.
+    ///
+    /// By default, the GC proposal is not supported.
+    fn translate_struct_new(
+        &mut self,
+        builder: &mut FunctionBuilder,
+        struct_type_index: TypeIndex,
+        fields: &[ir::Value],
+    ) -> WasmResult<ir::Value> {
+        let _ = (builder, struct_type_index, fields);
+        Err(wasm_unsupported!("the GC proposal is not supported"))
+    }
+
+    /// Translate a `struct.new_default` WebAssembly instruction of the struct
+    /// type `struct_type_index`.
+    ///
+    /// By default, the GC proposal is not supported.
+    fn translate_struct_new_default(
+        &mut self,
+        builder: &mut FunctionBuilder,
+        struct_type_index: TypeIndex,
+    ) -> WasmResult<ir::Value> {
+        let _ = (builder, struct_type_index);
+        Err(wasm_unsupported!("the GC proposal is not supported"))
+    }
+
+    /// Translate a `struct.get`, `struct.get_s` or `struct.get_u` WebAssembly
+    /// instruction, including the trap if `struct_ref` is null.
+    ///
+    /// `signed` selects how packed fields are extended, and is ignored for
+    /// other fields. By default, the GC proposal is not supported.
+    fn translate_struct_get(
+        &mut self,
+        builder: &mut FunctionBuilder,
+        struct_type_index: TypeIndex,
+        field_index: u32,
+        signed: bool,
+        struct_ref: ir::Value,
+    ) -> WasmResult<ir::Value> {
+        let _ = (builder, struct_type_index, field_index, signed, struct_ref);
+        Err(wasm_unsupported!("the GC proposal is not supported"))
+    }
+
+    /// Translate a `struct.set` WebAssembly instruction, including the trap
+    /// if `struct_ref` is null.
+    ///
+    /// By default, the GC proposal is not supported.
+    fn translate_struct_set(
+        &mut self,
+        builder: &mut FunctionBuilder,
+        struct_type_index: TypeIndex,
+        field_index: u32,
+        struct_ref: ir::Value,
+        value: ir::Value,
+    ) -> WasmResult<()> {
+        let _ = (builder, struct_type_index, field_index, struct_ref, value);
+        Err(wasm_unsupported!("the GC proposal is not supported"))
+    }
+
+    /// Translate an `array.new` WebAssembly instruction of the array type
+    /// `array_type_index`, whose `len` elements are initialized with `elem`.
+    ///
+    /// By default, the GC proposal is not supported.
+    fn translate_array_new(
+        &mut self,
+        builder: &mut FunctionBuilder,
+        array_type_index: TypeIndex,
+        elem: ir::Value,
+        len: ir::Value,
+    ) -> WasmResult<ir::Value> {
+        let _ = (builder, array_type_index, elem, len);
+        Err(wasm_unsupported!("the GC proposal is not supported"))
+    }
+
+    /// Translate an `array.new_default` WebAssembly instruction of the array
+    /// type `array_type_index`.
+    ///
+    /// By default, the GC proposal is not supported.
+    fn translate_array_new_default(
+        &mut self,
+        builder: &mut FunctionBuilder,
+        array_type_index: TypeIndex,
+        len: ir::Value,
+    ) -> WasmResult<ir::Value> {
+        let _ = (builder, array_type_index, len);
+        Err(wasm_unsupported!("the GC proposal is not supported"))
+    }
+
+    /// Translate an `array.new_fixed` WebAssembly instruction of the array
+    /// type `array_type_index`, whose elements are `elems`.
+    ///
+    /// By default, the GC proposal is not supported.
+    fn translate_array_new_fixed(
+        &mut self,
+        builder: &mut FunctionBuilder,
+        array_type_index: TypeIndex,
+        elems: &[ir::Value],
+    ) -> WasmResult<ir::Value> {
+        let _ = (builder, array_type_index, elems);
+        Err(wasm_unsupported!("the GC proposal is not supported"))
+    }
+
+    /// Translate an `array.new_data` WebAssembly instruction, which copies
+    /// `len` elements from `offset` in the passive data segment `data_index`.
+    ///
+    /// By default, the GC proposal is not supported.
+    fn translate_array_new_data(
+        &mut self,
+        builder: &mut FunctionBuilder,
+        array_type_index: TypeIndex,
+        data_index: DataIndex,
+        offset: ir::Value,
+        len: ir::Value,
+    ) -> WasmResult<ir::Value> {
+        let _ = (builder, array_type_index, data_index, offset, len);
+        Err(wasm_unsupported!("the GC proposal is not supported"))
+    }
+
+    /// Translate an `array.new_elem` WebAssembly instruction, which copies
+    /// `len` elements from `offset` in the passive element segment
+    /// `elem_index`.
+    ///
+    /// By default, the GC proposal is not supported.
+    fn translate_array_new_elem(
+        &mut self,
+        builder: &mut FunctionBuilder,
+        array_type_index: TypeIndex,
+        elem_index: ElemIndex,
+        offset: ir::Value,
+        len: ir::Value,
+    ) -> WasmResult<ir::Value> {
+        let _ = (builder, array_type_index, elem_index, offset, len);
+        Err(wasm_unsupported!("the GC proposal is not supported"))
+    }
+
+    /// Translate an `array.get`, `array.get_s` or `array.get_u` WebAssembly
+    /// instruction, including the traps if `array` is null or `index` is out
+    /// of bounds.
+    ///
+    /// `signed` selects how packed elements are extended, and is ignored for
+    /// other elements. By default, the GC proposal is not supported.
+    fn translate_array_get(
+        &mut self,
+        builder: &mut FunctionBuilder,
+        array_type_index: TypeIndex,
+        signed: bool,
+        array: ir::Value,
+        index: ir::Value,
+    ) -> WasmResult<ir::Value> {
+        let _ = (builder, array_type_index, signed, array, index);
+        Err(wasm_unsupported!("the GC proposal is not supported"))
+    }
+
+    /// Translate an `array.set` WebAssembly instruction, including the traps
+    /// if `array` is null or `index` is out of bounds.
+    ///
+    /// By default, the GC proposal is not supported.
+    fn translate_array_set(
+        &mut self,
+        builder: &mut FunctionBuilder,
+        array_type_index: TypeIndex,
+        array: ir::Value,
+        index: ir::Value,
+        value: ir::Value,
+    ) -> WasmResult<()> {
+        let _ = (builder, array_type_index, array, index, value);
+        Err(wasm_unsupported!("the GC proposal is not supported"))
+    }
+
+    /// Translate an `array.len` WebAssembly instruction, including the trap if
+    /// `array` is null.
+    ///
+    /// By default, the GC proposal is not supported.
+    fn translate_array_len(
+        &mut self,
+        builder: &mut FunctionBuilder,
+        array: ir::Value,
+    ) -> WasmResult<ir::Value> {
+        let _ = (builder, array);
+        Err(wasm_unsupported!("the GC proposal is not supported"))
+    }
+
+    /// Translate an `array.fill` WebAssembly instruction, which sets the `len`
+    /// elements of `array` starting at `index` to `value`.
+    ///
+    /// By default, the GC proposal is not supported.
+    fn translate_array_fill(
+        &mut self,
+        builder: &mut FunctionBuilder,
+        array_type_index: TypeIndex,
+        array: ir::Value,
+        index: ir::Value,
+        value: ir::Value,
+        len: ir::Value,
+    ) -> WasmResult<()> {
+        let _ = (builder, array_type_index, array, index, value, len);
+        Err(wasm_unsupported!("the GC proposal is not supported"))
+    }
+
+    /// Translate an `array.copy` WebAssembly instruction, which copies `len`
+    /// elements from `src` starting at `src_index` to `dst` starting at
+    /// `dst_index`.
+    ///
+    /// By default, the GC proposal is not supported.
+    fn translate_array_copy(
+        &mut self,
+        builder: &mut FunctionBuilder,
+        dst: ir::Value,
+        dst_index: ir::Value,
+        src: ir::Value,
+        src_index: ir::Value,
+        len: ir::Value,
+    ) -> WasmResult<()> {
+        let _ = (builder, dst, dst_index, src, src_index, len);
+        Err(wasm_unsupported!("the GC proposal is not supported"))
+    }
+
+    /// Translate an `array.init_data` WebAssembly instruction, which copies
+    /// `len` elements from `offset` in the passive data segment `data_index`
+    /// to `array` starting at `index`.
+    ///
+    /// By default, the GC proposal is not supported.
+    fn translate_array_init_data(
+        &mut self,
+        builder: &mut FunctionBuilder,
+        data_index: DataIndex,
+        array: ir::Value,
+        index: ir::Value,
+        offset: ir::Value,
+        len: ir::Value,
+    ) -> WasmResult<()> {
+        let _ = (builder, data_index, array, index, offset, len);
+        Err(wasm_unsupported!("the GC proposal is not supported"))
+    }
+
+    /// Translate an `array.init_elem` WebAssembly instruction, which copies
+    /// `len` elements from `offset` in the passive element segment
+    /// `elem_index` to `array` starting at `index`.
+    ///
+    /// By default, the GC proposal is not supported.
+    fn translate_array_init_elem(
+        &mut self,
+        builder: &mut FunctionBuilder,
+        elem_index: ElemIndex,
+        array: ir::Value,
+        index: ir::Value,
+        offset: ir::Value,
+        len: ir::Value,
+    ) -> WasmResult<()> {
+        let _ = (builder, elem_index, array, index, offset, len);
+        Err(wasm_unsupported!("the GC proposal is not supported"))
+    }
+
+    /// Returns whether the exception handling proposal is enabled, in which
+    /// case a check for a pending exception follows every call.
+    ///
+    /// By default, the exception handling proposal is not supported.
+    fn exceptions_enabled(&self) -> bool {
+        false
+    }
+
+    /// Translate a `throw` WebAssembly instruction of the tag `tag_index` with
+    /// the payload `args`, which makes the new exception pending.
+    ///
+    /// The caller is responsible for transferring control to the innermost
+    /// exception handler afterwards.
+    fn translate_throw(
+        &mut self,
+        builder: &mut FunctionBuilder,
+        tag_index: TagIndex,
+        args: &[ir::Value],
+    ) -> WasmResult<()> {
+        let _ = (builder, tag_index, args);
+        Err(wasm_unsupported!(
+            "the exception handling proposal is not supported"
+        ))
+    }
+
+    /// Translate a `throw_ref` WebAssembly instruction, which makes `exnref`
+    /// pending again or traps if it is null.
+    ///
+    /// The caller is responsible for transferring control to the innermost
+    /// exception handler afterwards.
+    fn translate_throw_ref(
+        &mut self,
+        builder: &mut FunctionBuilder,
+        exnref: ir::Value,
+    ) -> WasmResult<()> {
+        let _ = (builder, exnref);
+        Err(wasm_unsupported!(
+            "the exception handling proposal is not supported"
+        ))
+    }
+
+    /// Returns a value which is non-zero if an exception is pending, i.e. if
+    /// the callee of the call just translated threw.
+    fn translate_exception_pending(
+        &mut self,
+        builder: &mut FunctionBuilder,
+    ) -> WasmResult<ir::Value> {
+        let _ = builder;
+        Err(wasm_unsupported!(
+            "the exception handling proposal is not supported"
+        ))
+    }
+
+    /// Returns a value which is non-zero if the pending exception was thrown
+    /// with the tag `tag_index`.
+    fn translate_exception_matches(
+        &mut self,
+        builder: &mut FunctionBuilder,
+        tag_index: TagIndex,
+    ) -> WasmResult<ir::Value> {
+        let _ = (builder, tag_index);
+        Err(wasm_unsupported!(
+            "the exception handling proposal is not supported"
+        ))
+    }
+
+    /// Catches the pending exception, returning its `exnref` along with its
+    /// payload when `tag_index`, the tag it was thrown with, is provided.
+    fn translate_take_exception(
+        &mut self,
+        builder: &mut FunctionBuilder,
+        tag_index: Option<TagIndex>,
+    ) -> WasmResult<(Vec<ir::Value>, ir::Value)> {
+        let _ = (builder, tag_index);
+        Err(wasm_unsupported!(
+            "the exception handling proposal is not supported"
+        ))
+    }
+
     /// Translate a `global.get` WebAssembly instruction at `pos` for a global
     /// that is custom.
     fn translate_custom_global_get(
@@ -547,6 +950,13 @@ pub trait FuncEnvironment: TargetEnvironment {
         Ok(())
     }
 
+    /// Returns whether [`FuncTranslationState::operand_types`] should be
+    /// collected for `before_translate_operator`, which slows down
+    /// translation and is disabled by default.
+    fn needs_operand_types(&self) -> bool {
+        false
+    }
+
     /// Optional callback for the `FunctionEnvironment` performing this translation to maintain
     /// internal state or prepare custom state for the operator to translate
     fn before_translate_operator(
@@ -739,10 +1149,11 @@ pub trait ModuleEnvironment<'data>: TypeConvert {
         field: &'data str,
     ) -> WasmResult<()>;
 
-    /// Declares an tag import to the environment.
+    /// Declares an tag import to the environment, given the index of the
+    /// tag's function type.
     fn declare_tag_import(
         &mut self,
-        tag: Tag,
+        tag: TypeIndex,
         module: &'data str,
         field: &'data str,
     ) -> WasmResult<()> {
@@ -796,8 +1207,9 @@ pub trait ModuleEnvironment<'data>: TypeConvert {
         Ok(())
     }
 
-    /// Declares an tag to the environment
-    fn declare_tag(&mut self, tag: Tag) -> WasmResult<()> {
+    /// Declares an tag to the environment, given the index of the tag's
+    /// function type.
+    fn declare_tag(&mut self, tag: TypeIndex) -> WasmResult<()> {
         let _ = tag;
         Err(WasmError::Unsupported("wasm tags".to_string()))
     }
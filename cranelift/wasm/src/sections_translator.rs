@@ -10,7 +10,7 @@
 use crate::environ::ModuleEnvironment;
 use crate::wasm_unsupported;
 use crate::{
-    DataIndex, ElemIndex, FuncIndex, GlobalIndex, GlobalInit, Memory, MemoryIndex, TableIndex, Tag,
+    DataIndex, ElemIndex, FuncIndex, GlobalIndex, GlobalInit, Memory, MemoryIndex, TableIndex,
     TagIndex, TypeIndex, WasmError, WasmResult,
 };
 use cranelift_entity::packed_option::ReservedValue;
@@ -33,11 +33,9 @@ fn memory(ty: MemoryType) -> Memory {
     }
 }
 
-fn tag(e: TagType) -> Tag {
+fn tag(e: TagType) -> TypeIndex {
     match e.kind {
-        wasmparser::TagKind::Exception => Tag {
-            ty: TypeIndex::from_u32(e.func_type_idx),
-        },
+        wasmparser::TagKind::Exception => TypeIndex::from_u32(e.func_type_idx),
     }
 }
 
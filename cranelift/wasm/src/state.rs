@@ -8,6 +8,7 @@ use crate::{FuncIndex, GlobalIndex, Heap, MemoryIndex, TableIndex, TypeIndex, Wa
 use crate::{HashMap, Occupied, Vacant};
 use cranelift_codegen::ir::{self, Block, Inst, Value};
 use std::vec::Vec;
+use wasmparser::{FuncValidator, ValType, WasmModuleResources};
 
 /// Information about the presence of an associated `else` for an `if`, or the
 /// lack thereof.
@@ -241,6 +242,20 @@ pub struct FuncTranslationState {
     // `FuncEnvironment::make_direct_func()`.
     // Stores both the function reference and the number of WebAssembly arguments
     functions: HashMap<FuncIndex, (ir::FuncRef, usize)>,
+
+    /// A stack of the exception dispatch blocks of the active `try_table`s,
+    /// along with the height of the control stack including the `try_table`'s
+    /// own frame.
+    pub(crate) exception_handlers: Vec<(Block, usize)>,
+
+    /// The block which propagates exceptions thrown outside of any
+    /// `try_table` to this function's caller, created on first use.
+    pub(crate) exception_unwind: Option<Block>,
+
+    /// The wasm types of the values on the operand stack before the operator
+    /// that's being translated, which are only collected if
+    /// `FuncEnvironment::needs_operand_types` returns `true`.
+    operand_types: Vec<Option<ValType>>,
 }
 
 // Public methods that are exposed to non-`cranelift_wasm` API consumers.
@@ -250,6 +265,22 @@ impl FuncTranslationState {
     pub fn reachable(&self) -> bool {
         self.reachable
     }
+
+    /// The values on the operand stack, bottom-most first.
+    #[inline]
+    pub fn stack(&self) -> &[Value] {
+        &self.stack
+    }
+
+    /// The wasm types of the values on the operand stack, bottom-most first,
+    /// or `None` for values of an unknown type in unreachable code.
+    ///
+    /// This is empty unless `FuncEnvironment::needs_operand_types` returns
+    /// `true`.
+    #[inline]
+    pub fn operand_types(&self) -> &[Option<ValType>] {
+        &self.operand_types
+    }
 }
 
 impl FuncTranslationState {
@@ -264,6 +295,9 @@ impl FuncTranslationState {
             tables: HashMap::new(),
             signatures: HashMap::new(),
             functions: HashMap::new(),
+            exception_handlers: Vec::new(),
+            exception_unwind: None,
+            operand_types: Vec::new(),
         }
     }
 
@@ -276,6 +310,24 @@ impl FuncTranslationState {
         self.tables.clear();
         self.signatures.clear();
         self.functions.clear();
+        debug_assert!(self.exception_handlers.is_empty());
+        self.exception_unwind = None;
+        self.operand_types.clear();
+    }
+
+    /// Records the types of the operand stack of `validator`, which must not
+    /// have validated the operator that's about to be translated yet.
+    pub(crate) fn set_operand_types(
+        &mut self,
+        validator: &FuncValidator<impl WasmModuleResources>,
+    ) {
+        let height = validator.operand_stack_height() as usize;
+        self.operand_types.clear();
+        self.operand_types.extend(
+            (0..height)
+                .rev()
+                .map(|depth| validator.get_operand_type(depth).flatten()),
+        );
     }
 
     /// Initialize the state for compiling a function with the given signature.
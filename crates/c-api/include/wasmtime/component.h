@@ -0,0 +1,22 @@
+/**
+ * \file wasmtime/component.h
+ *
+ * Wasmtime APIs for the WebAssembly component model.
+ *
+ * These APIs are only available if the `component-model` feature of the C API
+ * is enabled, which it is by default.
+ */
+
+#ifndef WASMTIME_COMPONENT_H
+#define WASMTIME_COMPONENT_H
+
+// IWYU pragma: begin_exports
+#include <wasmtime/component/component.h>
+#include <wasmtime/component/func.h>
+#include <wasmtime/component/instance.h>
+#include <wasmtime/component/linker.h>
+#include <wasmtime/component/val.h>
+#include <wasmtime/component/wasi.h>
+// IWYU pragma: end_exports
+
+#endif // WASMTIME_COMPONENT_H
@@ -0,0 +1,111 @@
+/**
+ * \file wasmtime/component/component.h
+ *
+ * Wasmtime APIs for compiling and (de)serializing components.
+ */
+
+#ifndef WASMTIME_COMPONENT_COMPONENT_H
+#define WASMTIME_COMPONENT_COMPONENT_H
+
+#include <wasm.h>
+#include <wasmtime/error.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+/**
+ * \typedef wasmtime_component_t
+ * \brief Convenience alias for #wasmtime_component
+ *
+ * \struct wasmtime_component
+ * \brief A compiled Wasmtime component.
+ *
+ * This type corresponds to the `wasmtime::component::Component` type in Rust.
+ * A component is compiled from the binary format of the WebAssembly component
+ * model and is ready to be instantiated with a #wasmtime_component_linker_t. It
+ * is safe to use a component across multiple threads simultaneously.
+ */
+typedef struct wasmtime_component wasmtime_component_t;
+
+/**
+ * \brief Compiles a WebAssembly component binary into a #wasmtime_component_t
+ *
+ * On success the returned #wasmtime_error_t is `NULL` and the `ret` pointer is
+ * filled in with a #wasmtime_component_t. On failure the #wasmtime_error_t is
+ * non-`NULL` and the `ret` pointer is unmodified.
+ *
+ * This function does not take ownership of any of its arguments, but the
+ * returned error and component are owned by the caller.
+ */
+WASM_API_EXTERN wasmtime_error_t *
+wasmtime_component_new(const wasm_engine_t *engine, const uint8_t *buf,
+                       size_t len, wasmtime_component_t **ret);
+
+/**
+ * \brief Deletes a component.
+ */
+WASM_API_EXTERN void wasmtime_component_delete(wasmtime_component_t *c);
+
+/**
+ * \brief Creates a shallow clone of the specified component, increasing the
+ * internal reference count.
+ */
+WASM_API_EXTERN wasmtime_component_t *
+wasmtime_component_clone(const wasmtime_component_t *c);
+
+/**
+ * \brief This function serializes compiled component artifacts as blob data.
+ *
+ * \param component the component
+ * \param ret if the conversion is successful, this byte vector is filled in
+ *   with the serialized compiled component.
+ *
+ * \return a non-null error if serialization fails, and `NULL` otherwise.
+ *
+ * The caller is responsible for deallocating the returned error or byte
+ * vector.
+ */
+WASM_API_EXTERN wasmtime_error_t *
+wasmtime_component_serialize(const wasmtime_component_t *component,
+                             wasm_byte_vec_t *ret);
+
+/**
+ * \brief Build a component from serialized data.
+ *
+ * This function does not take ownership of any of its arguments, but the
+ * returned error and component are owned by the caller.
+ *
+ * This function is not safe to receive arbitrary user input. See the Rust
+ * documentation for more information on what inputs are safe to pass in here
+ * (e.g. only that of #wasmtime_component_serialize)
+ */
+WASM_API_EXTERN wasmtime_error_t *
+wasmtime_component_deserialize(const wasm_engine_t *engine,
+                               const uint8_t *bytes, size_t len,
+                               wasmtime_component_t **ret);
+
+/**
+ * \brief Deserialize a component from an on-disk file.
+ *
+ * This function is the same as #wasmtime_component_deserialize except that it
+ * reads the data for the serialized component from the path on disk. This can
+ * be faster than the alternative which may require copying the data around.
+ *
+ * This function does not take ownership of any of its arguments, but the
+ * returned error and component are owned by the caller.
+ *
+ * This function is not safe to receive arbitrary user input. See the Rust
+ * documentation for more information on what inputs are safe to pass in here
+ * (e.g. only that of #wasmtime_component_serialize)
+ */
+WASM_API_EXTERN wasmtime_error_t *
+wasmtime_component_deserialize_file(const wasm_engine_t *engine,
+                                    const char *path,
+                                    wasmtime_component_t **ret);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif
+
+#endif // WASMTIME_COMPONENT_COMPONENT_H
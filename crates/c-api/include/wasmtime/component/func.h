@@ -0,0 +1,60 @@
+/**
+ * \file wasmtime/component/func.h
+ *
+ * Wasmtime APIs for calling functions exported by components.
+ */
+
+#ifndef WASMTIME_COMPONENT_FUNC_H
+#define WASMTIME_COMPONENT_FUNC_H
+
+#include <wasm.h>
+#include <wasmtime/component/val.h>
+#include <wasmtime/error.h>
+#include <wasmtime/store.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+/// \brief Representation of a function exported by a component instance.
+///
+/// Functions are owned by the store of the instance they were exported from
+/// and do not have any destructor associated with them.
+typedef struct wasmtime_component_func {
+  /// Internal identifier of what store this belongs to, never zero.
+  uint64_t store_id;
+  /// Internal index within the store.
+  size_t index;
+} wasmtime_component_func_t;
+
+/**
+ * \brief Calls a component function.
+ *
+ * \param func the function to call.
+ * \param context the store that owns `func`.
+ * \param args the arguments to the function, which are borrowed.
+ * \param nargs the number of values in `args`.
+ * \param results where to write the results of the function, which must have
+ *   space for `nresults` values.
+ * \param nresults the number of results `func` produces.
+ *
+ * The arguments are checked against, and converted to, the parameter types of
+ * `func`. On success `NULL` is returned and `results` is filled in with owned
+ * values which must be deleted with #wasmtime_component_val_delete. On failure
+ * an error is returned, for example if the arguments don't match the
+ * function's type or if the function traps, and `results` is left
+ * uninitialized.
+ *
+ * This also performs the "post return" cleanup of the function, so unlike the
+ * Rust API no additional steps are needed before `func` may be called again.
+ */
+WASM_API_EXTERN wasmtime_error_t *wasmtime_component_func_call(
+    const wasmtime_component_func_t *func, wasmtime_context_t *context,
+    const wasmtime_component_val_t *args, size_t nargs,
+    wasmtime_component_val_t *results, size_t nresults);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif
+
+#endif // WASMTIME_COMPONENT_FUNC_H
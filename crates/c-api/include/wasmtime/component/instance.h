@@ -0,0 +1,63 @@
+/**
+ * \file wasmtime/component/instance.h
+ *
+ * Wasmtime APIs for interacting with component instances.
+ */
+
+#ifndef WASMTIME_COMPONENT_INSTANCE_H
+#define WASMTIME_COMPONENT_INSTANCE_H
+
+#include <wasm.h>
+#include <wasmtime/component/func.h>
+#include <wasmtime/store.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+/// \brief Representation of a component instance in Wasmtime.
+///
+/// Like core wasm instances, component instances are owned by a store and
+/// do not have any destructor associated with them. Passing an instance to
+/// a store other than the one it was created in may trigger an assertion to
+/// abort the process.
+typedef struct wasmtime_component_instance {
+  /// Internal identifier of what store this belongs to, never zero.
+  uint64_t store_id;
+  /// Internal index within the store.
+  size_t index;
+} wasmtime_component_instance_t;
+
+/**
+ * \brief Looks up an exported function by name from the root of an instance.
+ *
+ * \param instance the instance to look up the function in.
+ * \param context the store that owns `instance`.
+ * \param name the name of the export.
+ * \param name_len the byte length of `name`.
+ * \param func where to store the function, if found.
+ *
+ * \return `true` if the function was found and `func` was filled in, or
+ * `false` otherwise.
+ */
+WASM_API_EXTERN bool wasmtime_component_instance_get_func(
+    const wasmtime_component_instance_t *instance, wasmtime_context_t *context,
+    const char *name, size_t name_len, wasmtime_component_func_t *func);
+
+/**
+ * \brief Looks up a function exported from an instance exported by
+ * `instance`, for example `name` within the interface `instance_name`.
+ *
+ * \return `true` if the function was found and `func` was filled in, or
+ * `false` otherwise.
+ */
+WASM_API_EXTERN bool wasmtime_component_instance_get_instance_func(
+    const wasmtime_component_instance_t *instance, wasmtime_context_t *context,
+    const char *instance_name, size_t instance_name_len, const char *name,
+    size_t name_len, wasmtime_component_func_t *func);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif
+
+#endif // WASMTIME_COMPONENT_INSTANCE_H
@@ -0,0 +1,220 @@
+/**
+ * \file wasmtime/component/linker.h
+ *
+ * Wasmtime API for a name-based linker used to instantiate components.
+ */
+
+#ifndef WASMTIME_COMPONENT_LINKER_H
+#define WASMTIME_COMPONENT_LINKER_H
+
+#include <wasm.h>
+#include <wasmtime/component/component.h>
+#include <wasmtime/component/instance.h>
+#include <wasmtime/component/val.h>
+#include <wasmtime/error.h>
+#include <wasmtime/module.h>
+#include <wasmtime/store.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+/**
+ * \typedef wasmtime_component_linker_t
+ * \brief Alias to #wasmtime_component_linker
+ *
+ * \struct #wasmtime_component_linker
+ * \brief Object used to define the imports of, and instantiate, components.
+ *
+ * This type corresponds to the `wasmtime::component::Linker` type in Rust.
+ * Items are defined in the linker through the #wasmtime_component_linker_instance_t
+ * returned by #wasmtime_component_linker_root.
+ */
+typedef struct wasmtime_component_linker wasmtime_component_linker_t;
+
+/**
+ * \typedef wasmtime_component_linker_instance_t
+ * \brief Alias to #wasmtime_component_linker_instance
+ *
+ * \struct #wasmtime_component_linker_instance
+ * \brief A namespace within a #wasmtime_component_linker_t which items can
+ * be defined in.
+ *
+ * This type corresponds to the `wasmtime::component::LinkerInstance` type in
+ * Rust. A linker instance mutably borrows the linker it was created from, so
+ * while it's alive its linker, and any parent linker instance, must not be
+ * used. It must be deleted with #wasmtime_component_linker_instance_delete
+ * before the linker is used again.
+ */
+typedef struct wasmtime_component_linker_instance
+    wasmtime_component_linker_instance_t;
+
+/**
+ * \brief Creates a new component linker for the specified engine.
+ *
+ * This function does not take ownership of the engine argument, and the caller
+ * is expected to delete the returned linker.
+ */
+WASM_API_EXTERN wasmtime_component_linker_t *
+wasmtime_component_linker_new(const wasm_engine_t *engine);
+
+/**
+ * \brief Deletes a component linker.
+ */
+WASM_API_EXTERN void
+wasmtime_component_linker_delete(wasmtime_component_linker_t *linker);
+
+/**
+ * \brief Configures whether this linker allows later definitions to shadow
+ * previous definitions.
+ *
+ * By default this setting is `false`.
+ */
+WASM_API_EXTERN void
+wasmtime_component_linker_allow_shadowing(wasmtime_component_linker_t *linker,
+                                          bool allow_shadowing);
+
+/**
+ * \brief Returns the root namespace of `linker`, which corresponds to the
+ * top-level imports of a component.
+ *
+ * The returned linker instance borrows `linker` and must be deleted with
+ * #wasmtime_component_linker_instance_delete before `linker` is used again.
+ */
+WASM_API_EXTERN wasmtime_component_linker_instance_t *
+wasmtime_component_linker_root(wasmtime_component_linker_t *linker);
+
+/**
+ * \brief Instantiates a component using the items defined in `linker`.
+ *
+ * \param linker the linker to resolve the imports of `component` with.
+ * \param context the store to instantiate the component in.
+ * \param component the component to instantiate.
+ * \param instance_out where to store the instance on success.
+ *
+ * \return On success `NULL` is returned, otherwise an error is returned which
+ * describes why instantiation failed, for example due to a missing import or a
+ * trap during instantiation.
+ */
+WASM_API_EXTERN wasmtime_error_t *wasmtime_component_linker_instantiate(
+    const wasmtime_component_linker_t *linker, wasmtime_context_t *context,
+    const wasmtime_component_t *component,
+    wasmtime_component_instance_t *instance_out);
+
+/**
+ * \brief Deletes a linker instance, ending its borrow of the linker it was
+ * created from.
+ */
+WASM_API_EXTERN void wasmtime_component_linker_instance_delete(
+    wasmtime_component_linker_instance_t *linker_instance);
+
+/**
+ * \brief Defines a nested instance, such as an interface, within
+ * `linker_instance`.
+ *
+ * \param linker_instance the namespace to define the instance in.
+ * \param name the name of the instance.
+ * \param name_len the byte length of `name`.
+ * \param linker_instance_out where to store the new linker instance on
+ *   success.
+ *
+ * The returned linker instance borrows `linker_instance` and must be deleted
+ * before `linker_instance` is used again.
+ */
+WASM_API_EXTERN wasmtime_error_t *
+wasmtime_component_linker_instance_add_instance(
+    wasmtime_component_linker_instance_t *linker_instance, const char *name,
+    size_t name_len,
+    wasmtime_component_linker_instance_t **linker_instance_out);
+
+/**
+ * \brief Defines a core wasm module, which a component can import, within
+ * `linker_instance`.
+ *
+ * This function does not take ownership of `module`.
+ */
+WASM_API_EXTERN wasmtime_error_t *wasmtime_component_linker_instance_add_module(
+    wasmtime_component_linker_instance_t *linker_instance, const char *name,
+    size_t name_len, const wasmtime_module_t *module);
+
+/**
+ * \brief Callback signature for #wasmtime_component_linker_instance_add_func.
+ *
+ * The callback receives the `env` pointer it was registered with, the store
+ * the call happens in, the arguments of the call and space for the results of
+ * the call.
+ *
+ * The arguments are owned by the caller. The results are initialized to
+ * `false` booleans and should be overwritten by the callback, ownership of them
+ * is transferred back to Wasmtime once the callback returns. The results are
+ * checked against the result types of the function.
+ *
+ * If the callback returns a non-`NULL` error, then the function call fails
+ * with that error and the results are discarded.
+ */
+typedef wasmtime_error_t *(*wasmtime_component_func_callback_t)(
+    void *env, wasmtime_context_t *context, const wasmtime_component_val_t *args,
+    size_t nargs, wasmtime_component_val_t *results, size_t nresults);
+
+/**
+ * \brief Defines a host function within `linker_instance`.
+ *
+ * \param linker_instance the namespace to define the function in.
+ * \param component the component whose import of this function determines its
+ *   type.
+ * \param name the name of the function.
+ * \param name_len the byte length of `name`.
+ * \param callback the host function to invoke when the function is called.
+ * \param env data passed to `callback`.
+ * \param finalizer optional finalizer for `env`, run when the linker and all
+ *   instances created from it are deallocated.
+ *
+ * Component functions are typed by the import they satisfy, so `component`
+ * must have an import of a function named `name` within the instance that
+ * `linker_instance` describes, and the function can only be used to
+ * instantiate components importing a function of that type.
+ */
+WASM_API_EXTERN wasmtime_error_t *wasmtime_component_linker_instance_add_func(
+    wasmtime_component_linker_instance_t *linker_instance,
+    const wasmtime_component_t *component, const char *name, size_t name_len,
+    wasmtime_component_func_callback_t callback, void *env,
+    void (*finalizer)(void *));
+
+/**
+ * \brief Callback signature for
+ * #wasmtime_component_linker_instance_add_resource.
+ *
+ * The callback receives the `env` pointer it was registered with, the store
+ * in which the resource is being destroyed and the representation of the
+ * resource.
+ */
+typedef wasmtime_error_t *(*wasmtime_component_resource_destructor_t)(
+    void *env, wasmtime_context_t *context, uint32_t rep);
+
+/**
+ * \brief Defines a host resource type within `linker_instance`.
+ *
+ * \param linker_instance the namespace to define the resource in.
+ * \param name the name of the resource.
+ * \param name_len the byte length of `name`.
+ * \param destructor invoked when a guest drops an owned handle to the
+ *   resource.
+ * \param env data passed to `destructor`.
+ * \param finalizer optional finalizer for `env`.
+ *
+ * Handles to resources defined with this function are created with
+ * #wasmtime_component_resource_host_new. Note that all resources defined
+ * through the C API share the same host type, so a component importing two of
+ * them can't rely on Wasmtime to tell them apart.
+ */
+WASM_API_EXTERN wasmtime_error_t *
+wasmtime_component_linker_instance_add_resource(
+    wasmtime_component_linker_instance_t *linker_instance, const char *name,
+    size_t name_len, wasmtime_component_resource_destructor_t destructor,
+    void *env, void (*finalizer)(void *));
+
+#ifdef __cplusplus
+} // extern "C"
+#endif
+
+#endif // WASMTIME_COMPONENT_LINKER_H
@@ -0,0 +1,408 @@
+/**
+ * \file wasmtime/component/val.h
+ *
+ * APIs for working with values of the component model.
+ */
+
+#ifndef WASMTIME_COMPONENT_VAL_H
+#define WASMTIME_COMPONENT_VAL_H
+
+#include <stdbool.h>
+#include <stdint.h>
+#include <wasm.h>
+#include <wasmtime/error.h>
+#include <wasmtime/store.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+/**
+ * \typedef wasmtime_component_resource_any_t
+ * \brief Convenience alias for #wasmtime_component_resource_any
+ *
+ * \struct wasmtime_component_resource_any
+ * \brief A handle to a resource, defined either by the host or by a guest.
+ *
+ * This type corresponds to `wasmtime::component::ResourceAny` in Rust. Like
+ * in Rust every resource handed to the host, owned or borrowed, must be
+ * released with #wasmtime_component_resource_any_drop once the embedder is
+ * done with it. #wasmtime_component_resource_any_delete only deallocates the
+ * handle itself.
+ */
+typedef struct wasmtime_component_resource_any
+    wasmtime_component_resource_any_t;
+
+/**
+ * \brief Deletes a resource handle.
+ *
+ * Note that this does not release the resource from the store, see
+ * #wasmtime_component_resource_any_drop.
+ */
+WASM_API_EXTERN void wasmtime_component_resource_any_delete(
+    wasmtime_component_resource_any_t *resource);
+
+/**
+ * \brief Creates a copy of the specified resource handle.
+ *
+ * Both handles refer to the same resource, which must be dropped only once.
+ */
+WASM_API_EXTERN wasmtime_component_resource_any_t *
+wasmtime_component_resource_any_clone(
+    const wasmtime_component_resource_any_t *resource);
+
+/**
+ * \brief Returns whether `resource` is an `own` handle, as opposed to a
+ * `borrow`.
+ */
+WASM_API_EXTERN bool wasmtime_component_resource_any_owned(
+    const wasmtime_component_resource_any_t *resource);
+
+/**
+ * \brief Releases the resource from the store.
+ *
+ * For owned resources this will run the resource's destructor, if it has
+ * one. For borrowed resources this ends the borrow.
+ */
+WASM_API_EXTERN wasmtime_error_t *
+wasmtime_component_resource_any_drop(wasmtime_context_t *context,
+                                     const wasmtime_component_resource_any_t *resource);
+
+/**
+ * \brief Creates an owned handle to a host-defined resource.
+ *
+ * \param context the store the resource will live in.
+ * \param rep the 32-bit representation of the resource, which is opaque to
+ *   Wasmtime and is what the embedder uses to identify it.
+ * \param ret where to store the returned handle on success.
+ *
+ * All resources defined through the C API, see
+ * #wasmtime_component_linker_instance_add_resource, share the same host type.
+ * This means that the resulting handle can be passed to guests for any of
+ * them, and it's up to the embedder to ensure that `rep` makes sense for the
+ * resource type the guest expects.
+ *
+ * The destructor registered with the linker is not run if the handle is
+ * dropped from the host with #wasmtime_component_resource_any_drop, it is only
+ * run when a guest drops the resource.
+ */
+WASM_API_EXTERN wasmtime_error_t *
+wasmtime_component_resource_host_new(wasmtime_context_t *context, uint32_t rep,
+                                     wasmtime_component_resource_any_t **ret);
+
+/**
+ * \brief Converts a handle to a host-defined resource back to its
+ * representation.
+ *
+ * On success `owned` and `rep` are filled in and `resource` has been consumed
+ * from the store, meaning it no longer needs to be dropped. An error is
+ * returned if `resource` is not a resource defined through the C API.
+ */
+WASM_API_EXTERN wasmtime_error_t *wasmtime_component_resource_any_to_host(
+    wasmtime_context_t *context,
+    const wasmtime_component_resource_any_t *resource, bool *owned,
+    uint32_t *rep);
+
+/// \brief Discriminant used in #wasmtime_component_val_t::kind
+typedef uint8_t wasmtime_component_valkind_t;
+
+/// \brief Value of #wasmtime_component_valkind_t meaning that
+/// #wasmtime_component_val_t is a `bool`
+#define WASMTIME_COMPONENT_BOOL 0
+/// \brief Value of #wasmtime_component_valkind_t meaning that
+/// #wasmtime_component_val_t is a `s8`
+#define WASMTIME_COMPONENT_S8 1
+/// \brief Value of #wasmtime_component_valkind_t meaning that
+/// #wasmtime_component_val_t is a `u8`
+#define WASMTIME_COMPONENT_U8 2
+/// \brief Value of #wasmtime_component_valkind_t meaning that
+/// #wasmtime_component_val_t is a `s16`
+#define WASMTIME_COMPONENT_S16 3
+/// \brief Value of #wasmtime_component_valkind_t meaning that
+/// #wasmtime_component_val_t is a `u16`
+#define WASMTIME_COMPONENT_U16 4
+/// \brief Value of #wasmtime_component_valkind_t meaning that
+/// #wasmtime_component_val_t is a `s32`
+#define WASMTIME_COMPONENT_S32 5
+/// \brief Value of #wasmtime_component_valkind_t meaning that
+/// #wasmtime_component_val_t is a `u32`
+#define WASMTIME_COMPONENT_U32 6
+/// \brief Value of #wasmtime_component_valkind_t meaning that
+/// #wasmtime_component_val_t is a `s64`
+#define WASMTIME_COMPONENT_S64 7
+/// \brief Value of #wasmtime_component_valkind_t meaning that
+/// #wasmtime_component_val_t is a `u64`
+#define WASMTIME_COMPONENT_U64 8
+/// \brief Value of #wasmtime_component_valkind_t meaning that
+/// #wasmtime_component_val_t is a `float32`
+#define WASMTIME_COMPONENT_F32 9
+/// \brief Value of #wasmtime_component_valkind_t meaning that
+/// #wasmtime_component_val_t is a `float64`
+#define WASMTIME_COMPONENT_F64 10
+/// \brief Value of #wasmtime_component_valkind_t meaning that
+/// #wasmtime_component_val_t is a `char`
+#define WASMTIME_COMPONENT_CHAR 11
+/// \brief Value of #wasmtime_component_valkind_t meaning that
+/// #wasmtime_component_val_t is a `string`
+#define WASMTIME_COMPONENT_STRING 12
+/// \brief Value of #wasmtime_component_valkind_t meaning that
+/// #wasmtime_component_val_t is a `list`
+#define WASMTIME_COMPONENT_LIST 13
+/// \brief Value of #wasmtime_component_valkind_t meaning that
+/// #wasmtime_component_val_t is a `record`
+#define WASMTIME_COMPONENT_RECORD 14
+/// \brief Value of #wasmtime_component_valkind_t meaning that
+/// #wasmtime_component_val_t is a `tuple`
+#define WASMTIME_COMPONENT_TUPLE 15
+/// \brief Value of #wasmtime_component_valkind_t meaning that
+/// #wasmtime_component_val_t is a `variant`
+#define WASMTIME_COMPONENT_VARIANT 16
+/// \brief Value of #wasmtime_component_valkind_t meaning that
+/// #wasmtime_component_val_t is an `enum`
+#define WASMTIME_COMPONENT_ENUM 17
+/// \brief Value of #wasmtime_component_valkind_t meaning that
+/// #wasmtime_component_val_t is an `option`
+#define WASMTIME_COMPONENT_OPTION 18
+/// \brief Value of #wasmtime_component_valkind_t meaning that
+/// #wasmtime_component_val_t is a `result`
+#define WASMTIME_COMPONENT_RESULT 19
+/// \brief Value of #wasmtime_component_valkind_t meaning that
+/// #wasmtime_component_val_t is a `flags`
+#define WASMTIME_COMPONENT_FLAGS 20
+/// \brief Value of #wasmtime_component_valkind_t meaning that
+/// #wasmtime_component_val_t is an `own` or `borrow` resource handle
+#define WASMTIME_COMPONENT_RESOURCE 21
+
+struct wasmtime_component_val;
+struct wasmtime_component_valrecord_entry;
+
+/// \brief A vector of component values, used for lists and tuples.
+///
+/// Like the vectors of `wasm.h` these are created with
+/// #wasmtime_component_vallist_new and friends and owned by whichever value
+/// contains them.
+typedef struct wasmtime_component_vallist {
+  /// Number of values in this list.
+  size_t size;
+  /// Pointer to the values.
+  struct wasmtime_component_val *data;
+} wasmtime_component_vallist_t;
+
+/// \brief A vector of named record fields.
+typedef struct wasmtime_component_valrecord {
+  /// Number of fields in this record.
+  size_t size;
+  /// Pointer to the fields.
+  struct wasmtime_component_valrecord_entry *data;
+} wasmtime_component_valrecord_t;
+
+/// \brief A vector of names of flags which are set.
+typedef struct wasmtime_component_valflags {
+  /// Number of flags which are set.
+  size_t size;
+  /// Pointer to the names of the flags.
+  wasm_name_t *data;
+} wasmtime_component_valflags_t;
+
+/// \brief Payload of a #WASMTIME_COMPONENT_VARIANT value.
+typedef struct wasmtime_component_valvariant {
+  /// Name of the case of the variant.
+  wasm_name_t discriminant;
+  /// Owned payload of the case, or `NULL` if the case has no payload.
+  struct wasmtime_component_val *val;
+} wasmtime_component_valvariant_t;
+
+/// \brief Payload of a #WASMTIME_COMPONENT_RESULT value.
+typedef struct wasmtime_component_valresult {
+  /// Whether this is an `ok` or an `err` value.
+  bool is_ok;
+  /// Owned payload of the `ok` or `err` case, or `NULL` if that case has no
+  /// payload.
+  struct wasmtime_component_val *val;
+} wasmtime_component_valresult_t;
+
+/**
+ * \typedef wasmtime_component_valunion_t
+ * \brief Convenience alias for #wasmtime_component_valunion
+ *
+ * \union wasmtime_component_valunion
+ * \brief Container for different kinds of component values.
+ *
+ * This type is contained in #wasmtime_component_val_t and contains the payload
+ * for the various kinds of items a value can be.
+ */
+typedef union wasmtime_component_valunion {
+  /// Field used if #wasmtime_component_val_t::kind is #WASMTIME_COMPONENT_BOOL
+  bool boolean;
+  /// Field used if #wasmtime_component_val_t::kind is #WASMTIME_COMPONENT_S8
+  int8_t s8;
+  /// Field used if #wasmtime_component_val_t::kind is #WASMTIME_COMPONENT_U8
+  uint8_t u8;
+  /// Field used if #wasmtime_component_val_t::kind is #WASMTIME_COMPONENT_S16
+  int16_t s16;
+  /// Field used if #wasmtime_component_val_t::kind is #WASMTIME_COMPONENT_U16
+  uint16_t u16;
+  /// Field used if #wasmtime_component_val_t::kind is #WASMTIME_COMPONENT_S32
+  int32_t s32;
+  /// Field used if #wasmtime_component_val_t::kind is #WASMTIME_COMPONENT_U32
+  uint32_t u32;
+  /// Field used if #wasmtime_component_val_t::kind is #WASMTIME_COMPONENT_S64
+  int64_t s64;
+  /// Field used if #wasmtime_component_val_t::kind is #WASMTIME_COMPONENT_U64
+  uint64_t u64;
+  /// Field used if #wasmtime_component_val_t::kind is #WASMTIME_COMPONENT_F32
+  float32_t f32;
+  /// Field used if #wasmtime_component_val_t::kind is #WASMTIME_COMPONENT_F64
+  float64_t f64;
+  /// Field used if #wasmtime_component_val_t::kind is #WASMTIME_COMPONENT_CHAR
+  ///
+  /// This is a Unicode scalar value.
+  uint32_t character;
+  /// Field used if #wasmtime_component_val_t::kind is
+  /// #WASMTIME_COMPONENT_STRING
+  ///
+  /// The string is encoded as UTF-8.
+  wasm_name_t string;
+  /// Field used if #wasmtime_component_val_t::kind is #WASMTIME_COMPONENT_LIST
+  wasmtime_component_vallist_t list;
+  /// Field used if #wasmtime_component_val_t::kind is
+  /// #WASMTIME_COMPONENT_RECORD
+  wasmtime_component_valrecord_t record;
+  /// Field used if #wasmtime_component_val_t::kind is #WASMTIME_COMPONENT_TUPLE
+  wasmtime_component_vallist_t tuple;
+  /// Field used if #wasmtime_component_val_t::kind is
+  /// #WASMTIME_COMPONENT_VARIANT
+  wasmtime_component_valvariant_t variant;
+  /// Field used if #wasmtime_component_val_t::kind is #WASMTIME_COMPONENT_ENUM
+  ///
+  /// This is the name of the enum case.
+  wasm_name_t enumeration;
+  /// Field used if #wasmtime_component_val_t::kind is
+  /// #WASMTIME_COMPONENT_OPTION
+  ///
+  /// This is an owned pointer to the value for `some`, or `NULL` for `none`.
+  struct wasmtime_component_val *option;
+  /// Field used if #wasmtime_component_val_t::kind is
+  /// #WASMTIME_COMPONENT_RESULT
+  wasmtime_component_valresult_t result;
+  /// Field used if #wasmtime_component_val_t::kind is #WASMTIME_COMPONENT_FLAGS
+  wasmtime_component_valflags_t flags;
+  /// Field used if #wasmtime_component_val_t::kind is
+  /// #WASMTIME_COMPONENT_RESOURCE
+  ///
+  /// This is an owned handle which is never `NULL`.
+  wasmtime_component_resource_any_t *resource;
+} wasmtime_component_valunion_t;
+
+/**
+ * \typedef wasmtime_component_val_t
+ * \brief Convenience alias for #wasmtime_component_val
+ *
+ * \struct wasmtime_component_val
+ * \brief Container for a value of the component model.
+ *
+ * This type corresponds to `wasmtime::component::Val` in Rust, except that it
+ * does not carry any type information. Values are checked against, and
+ * converted to, the types expected by a function when they're passed to it.
+ * For example record fields are matched by name and may be in any order.
+ *
+ * Values own everything they point to, such as strings, nested values and
+ * resource handles, and must be deallocated with
+ * #wasmtime_component_val_delete.
+ */
+typedef struct wasmtime_component_val {
+  /// Discriminant of which field of #of is valid.
+  wasmtime_component_valkind_t kind;
+  /// Container for the payload of this value.
+  wasmtime_component_valunion_t of;
+} wasmtime_component_val_t;
+
+/// \brief A field of a #WASMTIME_COMPONENT_RECORD value.
+typedef struct wasmtime_component_valrecord_entry {
+  /// Name of the field.
+  wasm_name_t name;
+  /// Value of the field.
+  wasmtime_component_val_t val;
+} wasmtime_component_valrecord_entry_t;
+
+/**
+ * \brief Deletes an owned #wasmtime_component_val_t.
+ *
+ * Note that this only deletes the contents, not the memory that `val` points
+ * to itself (which is owned by the caller).
+ */
+WASM_API_EXTERN void wasmtime_component_val_delete(wasmtime_component_val_t *val);
+
+/**
+ * \brief Performs a deep copy of the `src` provided into `dst`.
+ *
+ * Note that resource handles are copied, not the underlying resources, so
+ * both values refer to the same resource.
+ */
+WASM_API_EXTERN void wasmtime_component_val_copy(wasmtime_component_val_t *dst,
+                                                 const wasmtime_component_val_t *src);
+
+/// \brief Creates an empty list.
+WASM_API_EXTERN void
+wasmtime_component_vallist_new_empty(wasmtime_component_vallist_t *out);
+/// \brief Creates a list of `size` values which are all `false` booleans.
+WASM_API_EXTERN void
+wasmtime_component_vallist_new_uninitialized(wasmtime_component_vallist_t *out,
+                                             size_t size);
+/// \brief Creates a list taking ownership of the `size` values in `ptr`.
+WASM_API_EXTERN void
+wasmtime_component_vallist_new(wasmtime_component_vallist_t *out, size_t size,
+                               const wasmtime_component_val_t ptr[]);
+/// \brief Performs a deep copy of `src` into `out`.
+WASM_API_EXTERN void
+wasmtime_component_vallist_copy(wasmtime_component_vallist_t *out,
+                                const wasmtime_component_vallist_t *src);
+/// \brief Deletes a list and all the values in it.
+WASM_API_EXTERN void
+wasmtime_component_vallist_delete(wasmtime_component_vallist_t *list);
+
+/// \brief Creates an empty record.
+WASM_API_EXTERN void
+wasmtime_component_valrecord_new_empty(wasmtime_component_valrecord_t *out);
+/// \brief Creates a record of `size` fields with empty names and `false`
+/// boolean values.
+WASM_API_EXTERN void wasmtime_component_valrecord_new_uninitialized(
+    wasmtime_component_valrecord_t *out, size_t size);
+/// \brief Creates a record taking ownership of the `size` fields in `ptr`.
+WASM_API_EXTERN void
+wasmtime_component_valrecord_new(wasmtime_component_valrecord_t *out,
+                                 size_t size,
+                                 const wasmtime_component_valrecord_entry_t ptr[]);
+/// \brief Performs a deep copy of `src` into `out`.
+WASM_API_EXTERN void
+wasmtime_component_valrecord_copy(wasmtime_component_valrecord_t *out,
+                                  const wasmtime_component_valrecord_t *src);
+/// \brief Deletes a record and all of its fields.
+WASM_API_EXTERN void
+wasmtime_component_valrecord_delete(wasmtime_component_valrecord_t *record);
+
+/// \brief Creates an empty set of flags.
+WASM_API_EXTERN void
+wasmtime_component_valflags_new_empty(wasmtime_component_valflags_t *out);
+/// \brief Creates a set of `size` empty flag names.
+WASM_API_EXTERN void
+wasmtime_component_valflags_new_uninitialized(wasmtime_component_valflags_t *out,
+                                              size_t size);
+/// \brief Creates a set of flags taking ownership of the `size` names in
+/// `ptr`.
+WASM_API_EXTERN void
+wasmtime_component_valflags_new(wasmtime_component_valflags_t *out, size_t size,
+                                const wasm_name_t ptr[]);
+/// \brief Performs a deep copy of `src` into `out`.
+WASM_API_EXTERN void
+wasmtime_component_valflags_copy(wasmtime_component_valflags_t *out,
+                                 const wasmtime_component_valflags_t *src);
+/// \brief Deletes a set of flags and all of its names.
+WASM_API_EXTERN void
+wasmtime_component_valflags_delete(wasmtime_component_valflags_t *flags);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif
+
+#endif // WASMTIME_COMPONENT_VAL_H
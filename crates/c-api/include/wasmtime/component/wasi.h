@@ -0,0 +1,300 @@
+/**
+ * \file wasmtime/component/wasi.h
+ *
+ * Wasmtime APIs for configuring WASI preview2 for components, for example to
+ * run `wasi:cli/command` components.
+ *
+ * These APIs are only available if both the `component-model` and `wasi`
+ * features of the C API are enabled, which they are by default.
+ */
+
+#ifndef WASMTIME_COMPONENT_WASI_H
+#define WASMTIME_COMPONENT_WASI_H
+
+#include <wasm.h>
+#include <wasmtime/component/linker.h>
+#include <wasmtime/error.h>
+#include <wasmtime/store.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+/**
+ * \typedef wasi_preview2_config_t
+ * \brief Convenience alias for #wasi_preview2_config_t
+ *
+ * \struct wasi_preview2_config_t
+ * \brief Builder for the WASI preview2 state of a store.
+ *
+ * Unlike #wasi_config_t this configures the WASI implementation used by
+ * components, which is added to a component linker with
+ * #wasmtime_component_linker_add_wasi_preview2.
+ *
+ * \fn void wasi_preview2_config_delete(wasi_preview2_config_t *);
+ * \brief Deletes a configuration object.
+ */
+typedef struct wasi_preview2_config_t wasi_preview2_config_t;
+
+/// \brief Deletes a configuration object.
+WASM_API_EXTERN void wasi_preview2_config_delete(wasi_preview2_config_t *config);
+
+/**
+ * \brief Creates a new empty configuration object.
+ *
+ * The caller is expected to deallocate the returned configuration, either with
+ * #wasi_preview2_config_delete or by passing it to
+ * #wasmtime_context_set_wasi_preview2.
+ */
+WASM_API_EXTERN wasi_preview2_config_t *wasi_preview2_config_new(void);
+
+/**
+ * \brief Sets the argv list for this configuration object.
+ *
+ * The arguments are copied into the `config` object as part of this function
+ * call, so the `argv` pointer only needs to stay alive for this function call.
+ *
+ * Returns `false` if any argument is not valid UTF-8, in which case the
+ * configuration is left unchanged.
+ */
+WASM_API_EXTERN bool wasi_preview2_config_set_argv(wasi_preview2_config_t *config,
+                                                   int argc, const char *argv[]);
+
+/**
+ * \brief Indicates that the argv list should be inherited from this process's
+ * argv list.
+ */
+WASM_API_EXTERN void
+wasi_preview2_config_inherit_argv(wasi_preview2_config_t *config);
+
+/**
+ * \brief Adds the given environment variables to this configuration.
+ *
+ * It is required that the `names` and `values` lists both have `envc` entries.
+ * They are copied into `config` as part of this call.
+ *
+ * Returns `false` if any name or value is not valid UTF-8, in which case the
+ * configuration is left unchanged.
+ */
+WASM_API_EXTERN bool wasi_preview2_config_set_env(wasi_preview2_config_t *config,
+                                                  int envc, const char *names[],
+                                                  const char *values[]);
+
+/**
+ * \brief Indicates that the entire environment of the calling process should be
+ * inherited by this configuration.
+ */
+WASM_API_EXTERN void
+wasi_preview2_config_inherit_env(wasi_preview2_config_t *config);
+
+/**
+ * \brief Configures this process's own stdin stream to be used as stdin.
+ */
+WASM_API_EXTERN void
+wasi_preview2_config_inherit_stdin(wasi_preview2_config_t *config);
+
+/**
+ * \brief Configures this process's own stdout stream to be used as stdout.
+ */
+WASM_API_EXTERN void
+wasi_preview2_config_inherit_stdout(wasi_preview2_config_t *config);
+
+/**
+ * \brief Configures this process's own stderr stream to be used as stderr.
+ */
+WASM_API_EXTERN void
+wasi_preview2_config_inherit_stderr(wasi_preview2_config_t *config);
+
+/**
+ * \brief Configures standard input to be read from the specified bytes.
+ *
+ * This function takes ownership of the `binary` argument.
+ */
+WASM_API_EXTERN void
+wasi_preview2_config_set_stdin_bytes(wasi_preview2_config_t *config,
+                                     wasm_byte_vec_t *binary);
+
+/**
+ * \brief Callback used to read standard input.
+ *
+ * The callback should fill in up to `len` bytes of `buf` and return how many
+ * bytes were read. Returning 0 signals the end of the stream and returning a
+ * negative value signals an error.
+ */
+typedef intptr_t (*wasi_preview2_read_callback_t)(void *env, uint8_t *buf,
+                                                  size_t len);
+
+/**
+ * \brief Callback used to write standard output or standard error.
+ *
+ * The callback should consume up to `len` bytes of `buf` and return how many
+ * bytes were written. It is invoked again for any remaining bytes. Returning
+ * 0 or a negative value signals an error.
+ */
+typedef intptr_t (*wasi_preview2_write_callback_t)(void *env,
+                                                   const uint8_t *buf,
+                                                   size_t len);
+
+/**
+ * \brief Configures standard input to be read through `callback`.
+ *
+ * The `env` pointer is passed to each invocation of `callback` and `finalizer`
+ * is invoked with `env` once the stream is no longer in use.
+ */
+WASM_API_EXTERN void wasi_preview2_config_set_stdin_callback(
+    wasi_preview2_config_t *config, wasi_preview2_read_callback_t callback,
+    void *env, void (*finalizer)(void *));
+
+/**
+ * \brief Configures standard output to be written through `callback`.
+ *
+ * See #wasi_preview2_config_set_stdin_callback for the meaning of `env` and
+ * `finalizer`.
+ */
+WASM_API_EXTERN void wasi_preview2_config_set_stdout_callback(
+    wasi_preview2_config_t *config, wasi_preview2_write_callback_t callback,
+    void *env, void (*finalizer)(void *));
+
+/**
+ * \brief Configures standard error to be written through `callback`.
+ *
+ * See #wasi_preview2_config_set_stdin_callback for the meaning of `env` and
+ * `finalizer`.
+ */
+WASM_API_EXTERN void wasi_preview2_config_set_stderr_callback(
+    wasi_preview2_config_t *config, wasi_preview2_write_callback_t callback,
+    void *env, void (*finalizer)(void *));
+
+/// \brief Permissions on a preopened directory.
+typedef size_t wasi_preview2_dir_perms_t;
+
+/// \brief The directory may be read, for example listed or opened.
+#define WASI_PREVIEW2_DIR_PERMS_READ 1
+/// \brief Entries within the directory may be created, renamed or removed.
+#define WASI_PREVIEW2_DIR_PERMS_MUTATE 2
+
+/// \brief Permissions on files within a preopened directory.
+typedef size_t wasi_preview2_file_perms_t;
+
+/// \brief Files may be opened for reading.
+#define WASI_PREVIEW2_FILE_PERMS_READ 1
+/// \brief Files may be opened for writing.
+#define WASI_PREVIEW2_FILE_PERMS_WRITE 2
+
+/**
+ * \brief Configures a "preopened directory" to be available to WASI APIs.
+ *
+ * The `path` argument is a path name on the host filesystem, and `guest_path`
+ * is the name by which it will be known in wasm. Access to the directory and
+ * the files within it is restricted by `dir_perms` and `file_perms`, which are
+ * bitwise ORs of the `WASI_PREVIEW2_DIR_PERMS_*` and
+ * `WASI_PREVIEW2_FILE_PERMS_*` constants respectively.
+ *
+ * Returns `false` if the directory could not be opened, if either path is not
+ * valid UTF-8, or if unknown permission bits are set.
+ */
+WASM_API_EXTERN bool wasi_preview2_config_preopen_dir(
+    wasi_preview2_config_t *config, const char *path, const char *guest_path,
+    wasi_preview2_dir_perms_t dir_perms, wasi_preview2_file_perms_t file_perms);
+
+/**
+ * \brief Grants access to all socket addresses of the host's network.
+ *
+ * This replaces any callback previously configured with
+ * #wasi_preview2_config_socket_addr_check.
+ */
+WASM_API_EXTERN void
+wasi_preview2_config_inherit_network(wasi_preview2_config_t *config);
+
+/// \brief The reason a socket address is being checked.
+typedef uint8_t wasi_preview2_socket_addr_use_t;
+
+/// \brief Binding a TCP socket.
+#define WASI_PREVIEW2_SOCKET_ADDR_USE_TCP_BIND 0
+/// \brief Connecting a TCP socket.
+#define WASI_PREVIEW2_SOCKET_ADDR_USE_TCP_CONNECT 1
+/// \brief Binding a UDP socket.
+#define WASI_PREVIEW2_SOCKET_ADDR_USE_UDP_BIND 2
+/// \brief Connecting a UDP socket.
+#define WASI_PREVIEW2_SOCKET_ADDR_USE_UDP_CONNECT 3
+/// \brief Sending a UDP datagram to an address on an unconnected socket.
+#define WASI_PREVIEW2_SOCKET_ADDR_USE_UDP_OUTGOING_DATAGRAM 4
+
+/**
+ * \brief Callback used to decide whether a socket address may be used.
+ *
+ * The address is formatted as a string such as `127.0.0.1:80` or `[::1]:80`
+ * of `addr_len` bytes which is not nul-terminated. Returns whether the use of
+ * the address is allowed.
+ */
+typedef bool (*wasi_preview2_socket_addr_check_t)(
+    void *env, const char *addr, size_t addr_len,
+    wasi_preview2_socket_addr_use_t addr_use);
+
+/**
+ * \brief Configures a callback which allows or denies each use of a socket
+ * address, for example to implement an allow list.
+ *
+ * By default all addresses are denied. This replaces the effect of a previous
+ * call to #wasi_preview2_config_inherit_network. The `env` pointer is passed to each
+ * invocation of `callback` and `finalizer` is invoked with `env` when the
+ * callback is no longer in use.
+ */
+WASM_API_EXTERN void wasi_preview2_config_socket_addr_check(
+    wasi_preview2_config_t *config, wasi_preview2_socket_addr_check_t callback,
+    void *env, void (*finalizer)(void *));
+
+/**
+ * \brief Configures whether TCP sockets are allowed, which they are by
+ * default.
+ */
+WASM_API_EXTERN void wasi_preview2_config_allow_tcp(wasi_preview2_config_t *config,
+                                                    bool enable);
+
+/**
+ * \brief Configures whether UDP sockets are allowed, which they are by
+ * default.
+ */
+WASM_API_EXTERN void wasi_preview2_config_allow_udp(wasi_preview2_config_t *config,
+                                                    bool enable);
+
+/**
+ * \brief Configures whether IP name lookups are allowed, which they are not by
+ * default.
+ */
+WASM_API_EXTERN void
+wasi_preview2_config_allow_ip_name_lookup(wasi_preview2_config_t *config,
+                                          bool enable);
+
+/**
+ * \brief Configures the WASI preview2 state within the specified store.
+ *
+ * This function is required if #wasmtime_component_linker_add_wasi_preview2 is
+ * used, and must be called before any WASI function is invoked.
+ *
+ * This function does not take ownership of `context` but it does take ownership
+ * of `config`. The caller should no longer use `config` after calling this
+ * function.
+ */
+WASM_API_EXTERN void
+wasmtime_context_set_wasi_preview2(wasmtime_context_t *context,
+                                   wasi_preview2_config_t *config);
+
+/**
+ * \brief Adds the WASI preview2 interfaces, including those of
+ * `wasi:cli/command`, to the specified linker.
+ *
+ * The WASI state of a store is configured with
+ * #wasmtime_context_set_wasi_preview2.
+ *
+ * \return An error is returned if any of the definitions conflict with
+ * existing definitions in `linker`, otherwise `NULL`.
+ */
+WASM_API_EXTERN wasmtime_error_t *
+wasmtime_component_linker_add_wasi_preview2(wasmtime_component_linker_t *linker);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif
+
+#endif // WASMTIME_COMPONENT_WASI_H
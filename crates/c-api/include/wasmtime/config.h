@@ -188,6 +188,20 @@ WASMTIME_CONFIG_PROP(void, wasm_relaxed_simd, bool)
  */
 WASMTIME_CONFIG_PROP(void, wasm_relaxed_simd_deterministic, bool)
 
+/**
+ * \brief Configures whether execution is guaranteed to be deterministic.
+ *
+ * This enables NaN canonicalization and deterministic relaxed SIMD, disables
+ * threads, and makes memory growth which fails on the host trap. Settings
+ * which contradict this are rejected when the engine is created.
+ *
+ * This setting is `false` by default.
+ *
+ * For more information see the Rust documentation at
+ * https://docs.wasmtime.dev/api/wasmtime/struct.Config.html#method.deterministic
+ */
+WASMTIME_CONFIG_PROP(void, deterministic, bool)
+
 /**
  * \brief Configures whether the WebAssembly bulk memory proposal is
  * enabled.
@@ -220,6 +234,14 @@ WASMTIME_CONFIG_PROP(void, wasm_multi_memory, bool)
  */
 WASMTIME_CONFIG_PROP(void, wasm_memory64, bool)
 
+/**
+ * \brief Configures whether the WebAssembly extended-const proposal is
+ * enabled.
+ *
+ * This setting is `false` by default.
+ */
+WASMTIME_CONFIG_PROP(void, wasm_extended_const, bool)
+
 /**
  * \brief Configures how JIT code will be compiled.
  *
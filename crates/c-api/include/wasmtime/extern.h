@@ -70,6 +70,18 @@ typedef struct wasmtime_global {
   size_t index;
 } wasmtime_global_t;
 
+/// \brief Representation of a tag in Wasmtime.
+///
+/// Tags are part of the exception handling proposal. Like globals they are
+/// represented with a 64-bit identifying integer, have no destructor, and
+/// cannot interoperate between #wasmtime_store_t instances.
+typedef struct wasmtime_tag {
+  /// Internal identifier of what store this belongs to, never zero.
+  uint64_t store_id;
+  /// Internal index within the store.
+  size_t index;
+} wasmtime_tag_t;
+
 /// \brief Discriminant of #wasmtime_extern_t
 typedef uint8_t wasmtime_extern_kind_t;
 
@@ -85,6 +97,12 @@ typedef uint8_t wasmtime_extern_kind_t;
 /// \brief Value of #wasmtime_extern_kind_t meaning that #wasmtime_extern_t is a
 /// memory
 #define WASMTIME_EXTERN_MEMORY 3
+/// \brief Value of #wasmtime_extern_kind_t meaning that #wasmtime_extern_t is a
+/// tag
+///
+/// This value is also returned by `wasm_extern_kind` and
+/// `wasm_externtype_kind` for tags, which have no kind in `wasm.h`.
+#define WASMTIME_EXTERN_TAG 4
 
 /**
  * \typedef wasmtime_extern_union_t
@@ -105,6 +123,8 @@ typedef union wasmtime_extern_union {
   wasmtime_table_t table;
   /// Field used if #wasmtime_extern_t::kind is #WASMTIME_EXTERN_MEMORY
   wasmtime_memory_t memory;
+  /// Field used if #wasmtime_extern_t::kind is #WASMTIME_EXTERN_TAG
+  wasmtime_tag_t tag;
 } wasmtime_extern_union_t;
 
 /**
@@ -107,11 +107,48 @@ typedef uint8_t wasmtime_valkind_t;
 /// \brief Value of #wasmtime_valkind_t meaning that #wasmtime_val_t is an
 /// externref
 #define WASMTIME_EXTERNREF 6
+/// \brief Value of #wasmtime_valkind_t meaning that #wasmtime_val_t is an
+/// anyref
+#define WASMTIME_ANYREF 7
+/// \brief Value of #wasmtime_valkind_t meaning that #wasmtime_val_t is an
+/// exnref
+#define WASMTIME_EXNREF 8
 
 /// \brief A 128-bit value representing the WebAssembly `v128` type. Bytes are
 /// stored in little-endian order.
 typedef uint8_t wasmtime_v128[16];
 
+/**
+ * \typedef wasmtime_anyref_t
+ * \brief Convenience alias for #wasmtime_anyref
+ *
+ * \struct wasmtime_anyref
+ * \brief A reference in the `any` hierarchy of the GC proposal.
+ *
+ * The only such references which are currently supported are `i31ref`s.
+ * References to `struct` and `array` objects returned by WebAssembly are
+ * represented as null.
+ */
+typedef struct wasmtime_anyref {
+  /// Whether this is the null `anyref`.
+  bool is_null;
+  /// If this is not null, the 31-bit value of this `i31ref`, zero-extended.
+  uint32_t i31;
+} wasmtime_anyref_t;
+
+/**
+ * \typedef wasmtime_exnref_t
+ * \brief Convenience alias for #wasmtime_exnref
+ *
+ * \struct wasmtime_exnref
+ * \brief An opaque reference to an exception from the exception handling
+ * proposal.
+ *
+ * Exception references are owned by the #wasmtime_val_t containing them, and
+ * are copied and deleted with #wasmtime_val_copy and #wasmtime_val_delete.
+ */
+typedef struct wasmtime_exnref wasmtime_exnref_t;
+
 /**
  * \typedef wasmtime_valunion_t
  * \brief Convenience alias for #wasmtime_valunion
@@ -143,6 +180,13 @@ typedef union wasmtime_valunion {
   wasmtime_externref_t *externref;
   /// Field used if #wasmtime_val_t::kind is #WASMTIME_V128
   wasmtime_v128 v128;
+  /// Field used if #wasmtime_val_t::kind is #WASMTIME_ANYREF
+  wasmtime_anyref_t anyref;
+  /// Field used if #wasmtime_val_t::kind is #WASMTIME_EXNREF
+  ///
+  /// If this value represents a `ref.null exn` value then this pointer will be
+  /// `NULL`.
+  wasmtime_exnref_t *exnref;
 } wasmtime_valunion_t;
 
 /**
@@ -0,0 +1,84 @@
+use crate::{handle_result, wasm_byte_vec_t, wasm_engine_t, wasmtime_error_t};
+use anyhow::Context;
+use std::ffi::CStr;
+use std::os::raw::c_char;
+use wasmtime::component::Component;
+
+mod func;
+mod instance;
+mod linker;
+mod resource;
+mod val;
+#[cfg(feature = "wasi")]
+mod wasi;
+pub use self::func::*;
+pub use self::instance::*;
+pub use self::linker::*;
+pub use self::resource::*;
+pub use self::val::*;
+#[cfg(feature = "wasi")]
+pub use self::wasi::*;
+
+#[derive(Clone)]
+pub struct wasmtime_component_t {
+    pub(crate) component: Component,
+}
+
+wasmtime_c_api_macros::declare_own!(wasmtime_component_t);
+
+#[no_mangle]
+pub unsafe extern "C" fn wasmtime_component_new(
+    engine: &wasm_engine_t,
+    bytes: *const u8,
+    len: usize,
+    out: &mut *mut wasmtime_component_t,
+) -> Option<Box<wasmtime_error_t>> {
+    let bytes = crate::slice_from_raw_parts(bytes, len);
+    handle_result(Component::new(&engine.engine, bytes), |component| {
+        *out = Box::into_raw(Box::new(wasmtime_component_t { component }));
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn wasmtime_component_clone(
+    component: &wasmtime_component_t,
+) -> Box<wasmtime_component_t> {
+    Box::new(component.clone())
+}
+
+#[no_mangle]
+pub extern "C" fn wasmtime_component_serialize(
+    component: &wasmtime_component_t,
+    ret: &mut wasm_byte_vec_t,
+) -> Option<Box<wasmtime_error_t>> {
+    handle_result(component.component.serialize(), |buf| ret.set_buffer(buf))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn wasmtime_component_deserialize(
+    engine: &wasm_engine_t,
+    bytes: *const u8,
+    len: usize,
+    out: &mut *mut wasmtime_component_t,
+) -> Option<Box<wasmtime_error_t>> {
+    let bytes = crate::slice_from_raw_parts(bytes, len);
+    handle_result(Component::deserialize(&engine.engine, bytes), |component| {
+        *out = Box::into_raw(Box::new(wasmtime_component_t { component }));
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn wasmtime_component_deserialize_file(
+    engine: &wasm_engine_t,
+    path: *const c_char,
+    out: &mut *mut wasmtime_component_t,
+) -> Option<Box<wasmtime_error_t>> {
+    let path = CStr::from_ptr(path);
+    let result = path
+        .to_str()
+        .context("input path is not valid utf-8")
+        .and_then(|path| Component::deserialize_file(&engine.engine, path));
+    handle_result(result, |component| {
+        *out = Box::into_raw(Box::new(wasmtime_component_t { component }));
+    })
+}
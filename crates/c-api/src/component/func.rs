@@ -0,0 +1,65 @@
+use crate::func::error_from_panic;
+use crate::{wasmtime_component_val_t, wasmtime_error_t, CStoreContextMut};
+use anyhow::{bail, Result};
+use std::mem::MaybeUninit;
+use std::panic::{self, AssertUnwindSafe};
+use wasmtime::component::{Func, Val};
+
+#[no_mangle]
+pub unsafe extern "C" fn wasmtime_component_func_call(
+    func: &Func,
+    mut store: CStoreContextMut<'_>,
+    args: *const wasmtime_component_val_t,
+    nargs: usize,
+    results: *mut MaybeUninit<wasmtime_component_val_t>,
+    nresults: usize,
+) -> Option<Box<wasmtime_error_t>> {
+    let args = crate::slice_from_raw_parts(args, nargs);
+    let results = crate::slice_from_raw_parts_mut(results, nresults);
+
+    // We're calling arbitrary code here most of the time, and we in general
+    // want to try to insulate callers against bugs in wasmtime/wasi/etc if we
+    // can. As a result we catch panics here and transform them to errors to
+    // allow the caller to have any insulation possible against Rust panics.
+    let result = panic::catch_unwind(AssertUnwindSafe(|| {
+        call(func, &mut store, args, results.len())
+    }));
+    let vals = match result {
+        Ok(Ok(vals)) => vals,
+        Ok(Err(err)) => return Some(Box::new(wasmtime_error_t::from(err))),
+        Err(panic) => return Some(Box::new(wasmtime_error_t::from(error_from_panic(panic)))),
+    };
+    for (slot, val) in results.iter_mut().zip(vals.iter()) {
+        crate::initialize(slot, wasmtime_component_val_t::from_val(val));
+    }
+    None
+}
+
+unsafe fn call(
+    func: &Func,
+    store: &mut CStoreContextMut<'_>,
+    args: &[wasmtime_component_val_t],
+    nresults: usize,
+) -> Result<Vec<Val>> {
+    let param_types = func.params(&*store);
+    if param_types.len() != args.len() {
+        bail!(
+            "expected {} arguments, got {}",
+            param_types.len(),
+            args.len()
+        );
+    }
+    let nexpected = func.results(&*store).len();
+    if nexpected != nresults {
+        bail!("expected {nexpected} results, got {nresults}");
+    }
+    let params = args
+        .iter()
+        .zip(param_types.iter())
+        .map(|(arg, ty)| arg.to_val(ty))
+        .collect::<Result<Vec<_>>>()?;
+    let mut results = vec![Val::Bool(false); nresults];
+    func.call(&mut *store, &params, &mut results)?;
+    func.post_return(&mut *store)?;
+    Ok(results)
+}
@@ -0,0 +1,58 @@
+use crate::CStoreContextMut;
+use std::str;
+use wasmtime::component::{Func, Instance};
+
+#[no_mangle]
+pub unsafe extern "C" fn wasmtime_component_instance_get_func(
+    instance: &Instance,
+    store: CStoreContextMut<'_>,
+    name: *const u8,
+    name_len: usize,
+    func_out: &mut Func,
+) -> bool {
+    let name = match str::from_utf8(crate::slice_from_raw_parts(name, name_len)) {
+        Ok(name) => name,
+        Err(_) => return false,
+    };
+    match instance.get_func(store, name) {
+        Some(func) => {
+            *func_out = func;
+            true
+        }
+        None => false,
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn wasmtime_component_instance_get_instance_func(
+    instance: &Instance,
+    store: CStoreContextMut<'_>,
+    instance_name: *const u8,
+    instance_name_len: usize,
+    name: *const u8,
+    name_len: usize,
+    func_out: &mut Func,
+) -> bool {
+    let instance_name = match str::from_utf8(crate::slice_from_raw_parts(
+        instance_name,
+        instance_name_len,
+    )) {
+        Ok(name) => name,
+        Err(_) => return false,
+    };
+    let name = match str::from_utf8(crate::slice_from_raw_parts(name, name_len)) {
+        Ok(name) => name,
+        Err(_) => return false,
+    };
+    let mut exports = instance.exports(store);
+    let func = exports
+        .instance(instance_name)
+        .and_then(|mut instance| instance.func(name));
+    match func {
+        Some(func) => {
+            *func_out = func;
+            true
+        }
+        None => false,
+    }
+}
@@ -0,0 +1,173 @@
+use super::resource::CHostResource;
+use crate::linker::to_str;
+use crate::{
+    bad_utf8, handle_result, wasm_engine_t, wasmtime_component_t, wasmtime_component_val_t,
+    wasmtime_error_t, wasmtime_module_t, CStoreContextMut, StoreData,
+};
+use std::ffi::c_void;
+use std::str;
+use wasmtime::component::{Instance, Linker, LinkerInstance, ResourceType};
+
+#[repr(C)]
+pub struct wasmtime_component_linker_t {
+    pub(crate) linker: Linker<StoreData>,
+}
+
+wasmtime_c_api_macros::declare_own!(wasmtime_component_linker_t);
+
+#[repr(C)]
+pub struct wasmtime_component_linker_instance_t<'a> {
+    pub(crate) linker_instance: LinkerInstance<'a, StoreData>,
+}
+
+wasmtime_c_api_macros::declare_own!(wasmtime_component_linker_instance_t);
+
+pub type wasmtime_component_func_callback_t = extern "C" fn(
+    *mut c_void,
+    CStoreContextMut<'_>,
+    *const wasmtime_component_val_t,
+    usize,
+    *mut wasmtime_component_val_t,
+    usize,
+) -> Option<Box<wasmtime_error_t>>;
+
+pub type wasmtime_component_resource_destructor_t =
+    extern "C" fn(*mut c_void, CStoreContextMut<'_>, u32) -> Option<Box<wasmtime_error_t>>;
+
+#[no_mangle]
+pub extern "C" fn wasmtime_component_linker_new(
+    engine: &wasm_engine_t,
+) -> Box<wasmtime_component_linker_t> {
+    Box::new(wasmtime_component_linker_t {
+        linker: Linker::new(&engine.engine),
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn wasmtime_component_linker_allow_shadowing(
+    linker: &mut wasmtime_component_linker_t,
+    allow_shadowing: bool,
+) {
+    linker.linker.allow_shadowing(allow_shadowing);
+}
+
+#[no_mangle]
+pub extern "C" fn wasmtime_component_linker_root(
+    linker: &mut wasmtime_component_linker_t,
+) -> Box<wasmtime_component_linker_instance_t<'_>> {
+    Box::new(wasmtime_component_linker_instance_t {
+        linker_instance: linker.linker.root(),
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn wasmtime_component_linker_instantiate(
+    linker: &wasmtime_component_linker_t,
+    store: CStoreContextMut<'_>,
+    component: &wasmtime_component_t,
+    instance_out: &mut Instance,
+) -> Option<Box<wasmtime_error_t>> {
+    let result = linker.linker.instantiate(store, &component.component);
+    handle_result(result, |instance| *instance_out = instance)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn wasmtime_component_linker_instance_add_instance<'a>(
+    linker_instance: &'a mut wasmtime_component_linker_instance_t<'_>,
+    name: *const u8,
+    name_len: usize,
+    linker_instance_out: &mut *mut wasmtime_component_linker_instance_t<'a>,
+) -> Option<Box<wasmtime_error_t>> {
+    let name = to_str!(name, name_len);
+    handle_result(
+        linker_instance.linker_instance.instance(name),
+        |linker_instance| {
+            *linker_instance_out = Box::into_raw(Box::new(wasmtime_component_linker_instance_t {
+                linker_instance,
+            }));
+        },
+    )
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn wasmtime_component_linker_instance_add_module(
+    linker_instance: &mut wasmtime_component_linker_instance_t<'_>,
+    name: *const u8,
+    name_len: usize,
+    module: &wasmtime_module_t,
+) -> Option<Box<wasmtime_error_t>> {
+    let name = to_str!(name, name_len);
+    handle_result(
+        linker_instance.linker_instance.module(name, &module.module),
+        |()| (),
+    )
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn wasmtime_component_linker_instance_add_func(
+    linker_instance: &mut wasmtime_component_linker_instance_t<'_>,
+    component: &wasmtime_component_t,
+    name: *const u8,
+    name_len: usize,
+    callback: wasmtime_component_func_callback_t,
+    data: *mut c_void,
+    finalizer: Option<extern "C" fn(*mut c_void)>,
+) -> Option<Box<wasmtime_error_t>> {
+    let name = to_str!(name, name_len);
+    let foreign = crate::ForeignData { data, finalizer };
+    let result = linker_instance.linker_instance.func_new_with_result_types(
+        &component.component,
+        name,
+        move |store, params, result_types, results| {
+            let _ = &foreign; // move entire foreign into this closure
+            let params = params
+                .iter()
+                .map(wasmtime_component_val_t::from_val)
+                .collect::<Vec<_>>();
+            let mut c_results = (0..results.len())
+                .map(|_| wasmtime_component_val_t::default())
+                .collect::<Vec<_>>();
+            let out = callback(
+                foreign.data,
+                store,
+                params.as_ptr(),
+                params.len(),
+                c_results.as_mut_ptr(),
+                c_results.len(),
+            );
+            if let Some(err) = out {
+                return Err((*err).into());
+            }
+            for ((slot, val), ty) in results.iter_mut().zip(&c_results).zip(result_types) {
+                *slot = val.to_val(ty)?;
+            }
+            Ok(())
+        },
+    );
+    handle_result(result, |()| ())
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn wasmtime_component_linker_instance_add_resource(
+    linker_instance: &mut wasmtime_component_linker_instance_t<'_>,
+    name: *const u8,
+    name_len: usize,
+    destructor: wasmtime_component_resource_destructor_t,
+    data: *mut c_void,
+    finalizer: Option<extern "C" fn(*mut c_void)>,
+) -> Option<Box<wasmtime_error_t>> {
+    let name = to_str!(name, name_len);
+    let foreign = crate::ForeignData { data, finalizer };
+    let result = linker_instance.linker_instance.resource(
+        name,
+        ResourceType::host::<CHostResource>(),
+        move |store, rep| {
+            let _ = &foreign; // move entire foreign into this closure
+            match destructor(foreign.data, store, rep) {
+                None => Ok(()),
+                Some(err) => Err((*err).into()),
+            }
+        },
+    );
+    handle_result(result, |_idx| ())
+}
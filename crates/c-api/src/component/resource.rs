@@ -0,0 +1,73 @@
+use crate::{handle_result, wasmtime_error_t, CStoreContextMut};
+use anyhow::anyhow;
+use wasmtime::component::{Resource, ResourceAny, ResourceType};
+
+/// Marker type for all resources defined through the C API.
+///
+/// The C API doesn't have a way to name distinct Rust types, so all
+/// host-defined resources share this one type and are distinguished only by
+/// their representation.
+pub(crate) struct CHostResource;
+
+#[derive(Clone)]
+pub struct wasmtime_component_resource_any_t {
+    pub(crate) resource: ResourceAny,
+}
+
+wasmtime_c_api_macros::declare_own!(wasmtime_component_resource_any_t);
+
+#[no_mangle]
+pub extern "C" fn wasmtime_component_resource_any_clone(
+    resource: &wasmtime_component_resource_any_t,
+) -> Box<wasmtime_component_resource_any_t> {
+    Box::new(resource.clone())
+}
+
+#[no_mangle]
+pub extern "C" fn wasmtime_component_resource_any_owned(
+    resource: &wasmtime_component_resource_any_t,
+) -> bool {
+    resource.resource.owned()
+}
+
+#[no_mangle]
+pub extern "C" fn wasmtime_component_resource_any_drop(
+    store: CStoreContextMut<'_>,
+    resource: &wasmtime_component_resource_any_t,
+) -> Option<Box<wasmtime_error_t>> {
+    handle_result(resource.resource.resource_drop(store), |()| ())
+}
+
+#[no_mangle]
+pub extern "C" fn wasmtime_component_resource_host_new(
+    mut store: CStoreContextMut<'_>,
+    rep: u32,
+    out: &mut *mut wasmtime_component_resource_any_t,
+) -> Option<Box<wasmtime_error_t>> {
+    let resource = Resource::<CHostResource>::new_own(rep);
+    handle_result(
+        ResourceAny::try_from_host_resource(resource, &mut store),
+        |resource| {
+            *out = Box::into_raw(Box::new(wasmtime_component_resource_any_t { resource }));
+        },
+    )
+}
+
+#[no_mangle]
+pub extern "C" fn wasmtime_component_resource_any_to_host(
+    mut store: CStoreContextMut<'_>,
+    resource: &wasmtime_component_resource_any_t,
+    owned: &mut bool,
+    rep: &mut u32,
+) -> Option<Box<wasmtime_error_t>> {
+    let resource = resource.resource;
+    let result = if resource.ty() == ResourceType::host::<CHostResource>() {
+        resource.try_into_resource::<CHostResource>(&mut store)
+    } else {
+        Err(anyhow!("resource is not a host-defined resource"))
+    };
+    handle_result(result, |resource| {
+        *owned = resource.owned();
+        *rep = resource.rep();
+    })
+}
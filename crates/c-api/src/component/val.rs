@@ -0,0 +1,536 @@
+use crate::{
+    wasm_name_t, wasmtime_component_resource_any_t, wasmtime_component_valflags_t,
+    wasmtime_component_vallist_t, wasmtime_component_valrecord_t,
+};
+use anyhow::{bail, Context, Result};
+use std::mem::{ManuallyDrop, MaybeUninit};
+use std::str;
+use wasmtime::component::{Type, Val};
+
+pub type wasmtime_component_valkind_t = u8;
+pub const WASMTIME_COMPONENT_BOOL: wasmtime_component_valkind_t = 0;
+pub const WASMTIME_COMPONENT_S8: wasmtime_component_valkind_t = 1;
+pub const WASMTIME_COMPONENT_U8: wasmtime_component_valkind_t = 2;
+pub const WASMTIME_COMPONENT_S16: wasmtime_component_valkind_t = 3;
+pub const WASMTIME_COMPONENT_U16: wasmtime_component_valkind_t = 4;
+pub const WASMTIME_COMPONENT_S32: wasmtime_component_valkind_t = 5;
+pub const WASMTIME_COMPONENT_U32: wasmtime_component_valkind_t = 6;
+pub const WASMTIME_COMPONENT_S64: wasmtime_component_valkind_t = 7;
+pub const WASMTIME_COMPONENT_U64: wasmtime_component_valkind_t = 8;
+pub const WASMTIME_COMPONENT_F32: wasmtime_component_valkind_t = 9;
+pub const WASMTIME_COMPONENT_F64: wasmtime_component_valkind_t = 10;
+pub const WASMTIME_COMPONENT_CHAR: wasmtime_component_valkind_t = 11;
+pub const WASMTIME_COMPONENT_STRING: wasmtime_component_valkind_t = 12;
+pub const WASMTIME_COMPONENT_LIST: wasmtime_component_valkind_t = 13;
+pub const WASMTIME_COMPONENT_RECORD: wasmtime_component_valkind_t = 14;
+pub const WASMTIME_COMPONENT_TUPLE: wasmtime_component_valkind_t = 15;
+pub const WASMTIME_COMPONENT_VARIANT: wasmtime_component_valkind_t = 16;
+pub const WASMTIME_COMPONENT_ENUM: wasmtime_component_valkind_t = 17;
+pub const WASMTIME_COMPONENT_OPTION: wasmtime_component_valkind_t = 18;
+pub const WASMTIME_COMPONENT_RESULT: wasmtime_component_valkind_t = 19;
+pub const WASMTIME_COMPONENT_FLAGS: wasmtime_component_valkind_t = 20;
+pub const WASMTIME_COMPONENT_RESOURCE: wasmtime_component_valkind_t = 21;
+
+#[repr(C)]
+pub struct wasmtime_component_val_t {
+    pub kind: wasmtime_component_valkind_t,
+    pub of: wasmtime_component_valunion_t,
+}
+
+#[repr(C)]
+pub union wasmtime_component_valunion_t {
+    pub boolean: bool,
+    pub s8: i8,
+    pub u8: u8,
+    pub s16: i16,
+    pub u16: u16,
+    pub s32: i32,
+    pub u32: u32,
+    pub s64: i64,
+    pub u64: u64,
+    pub f32: f32,
+    pub f64: f64,
+    pub character: u32,
+    pub string: ManuallyDrop<wasm_name_t>,
+    pub list: ManuallyDrop<wasmtime_component_vallist_t>,
+    pub record: ManuallyDrop<wasmtime_component_valrecord_t>,
+    pub tuple: ManuallyDrop<wasmtime_component_vallist_t>,
+    pub variant: ManuallyDrop<wasmtime_component_valvariant_t>,
+    pub enumeration: ManuallyDrop<wasm_name_t>,
+    pub option: ManuallyDrop<Option<Box<wasmtime_component_val_t>>>,
+    pub result: ManuallyDrop<wasmtime_component_valresult_t>,
+    pub flags: ManuallyDrop<wasmtime_component_valflags_t>,
+    pub resource: ManuallyDrop<Option<Box<wasmtime_component_resource_any_t>>>,
+}
+
+#[repr(C)]
+#[derive(Clone, Default)]
+pub struct wasmtime_component_valrecord_entry_t {
+    pub name: wasm_name_t,
+    pub val: wasmtime_component_val_t,
+}
+
+#[repr(C)]
+#[derive(Clone)]
+pub struct wasmtime_component_valvariant_t {
+    pub discriminant: wasm_name_t,
+    pub val: Option<Box<wasmtime_component_val_t>>,
+}
+
+#[repr(C)]
+#[derive(Clone)]
+pub struct wasmtime_component_valresult_t {
+    pub is_ok: bool,
+    pub val: Option<Box<wasmtime_component_val_t>>,
+}
+
+impl wasmtime_component_val_t {
+    pub(crate) fn from_val(val: &Val) -> wasmtime_component_val_t {
+        let (kind, of) = match val {
+            Val::Bool(b) => (
+                WASMTIME_COMPONENT_BOOL,
+                wasmtime_component_valunion_t { boolean: *b },
+            ),
+            Val::S8(i) => (
+                WASMTIME_COMPONENT_S8,
+                wasmtime_component_valunion_t { s8: *i },
+            ),
+            Val::U8(i) => (
+                WASMTIME_COMPONENT_U8,
+                wasmtime_component_valunion_t { u8: *i },
+            ),
+            Val::S16(i) => (
+                WASMTIME_COMPONENT_S16,
+                wasmtime_component_valunion_t { s16: *i },
+            ),
+            Val::U16(i) => (
+                WASMTIME_COMPONENT_U16,
+                wasmtime_component_valunion_t { u16: *i },
+            ),
+            Val::S32(i) => (
+                WASMTIME_COMPONENT_S32,
+                wasmtime_component_valunion_t { s32: *i },
+            ),
+            Val::U32(i) => (
+                WASMTIME_COMPONENT_U32,
+                wasmtime_component_valunion_t { u32: *i },
+            ),
+            Val::S64(i) => (
+                WASMTIME_COMPONENT_S64,
+                wasmtime_component_valunion_t { s64: *i },
+            ),
+            Val::U64(i) => (
+                WASMTIME_COMPONENT_U64,
+                wasmtime_component_valunion_t { u64: *i },
+            ),
+            Val::Float32(f) => (
+                WASMTIME_COMPONENT_F32,
+                wasmtime_component_valunion_t { f32: *f },
+            ),
+            Val::Float64(f) => (
+                WASMTIME_COMPONENT_F64,
+                wasmtime_component_valunion_t { f64: *f },
+            ),
+            Val::Char(c) => (
+                WASMTIME_COMPONENT_CHAR,
+                wasmtime_component_valunion_t {
+                    character: u32::from(*c),
+                },
+            ),
+            Val::String(s) => (
+                WASMTIME_COMPONENT_STRING,
+                wasmtime_component_valunion_t {
+                    string: ManuallyDrop::new(s.as_bytes().to_vec().into()),
+                },
+            ),
+            Val::List(l) => (
+                WASMTIME_COMPONENT_LIST,
+                wasmtime_component_valunion_t {
+                    list: ManuallyDrop::new(Self::from_vals(l.iter())),
+                },
+            ),
+            Val::Record(r) => (
+                WASMTIME_COMPONENT_RECORD,
+                wasmtime_component_valunion_t {
+                    record: ManuallyDrop::new(
+                        r.fields()
+                            .map(|(name, val)| wasmtime_component_valrecord_entry_t {
+                                name: name.as_bytes().to_vec().into(),
+                                val: Self::from_val(val),
+                            })
+                            .collect::<Vec<_>>()
+                            .into(),
+                    ),
+                },
+            ),
+            Val::Tuple(t) => (
+                WASMTIME_COMPONENT_TUPLE,
+                wasmtime_component_valunion_t {
+                    tuple: ManuallyDrop::new(Self::from_vals(t.values().iter())),
+                },
+            ),
+            Val::Variant(v) => (
+                WASMTIME_COMPONENT_VARIANT,
+                wasmtime_component_valunion_t {
+                    variant: ManuallyDrop::new(wasmtime_component_valvariant_t {
+                        discriminant: v.discriminant().as_bytes().to_vec().into(),
+                        val: v.payload().map(|v| Box::new(Self::from_val(v))),
+                    }),
+                },
+            ),
+            Val::Enum(e) => (
+                WASMTIME_COMPONENT_ENUM,
+                wasmtime_component_valunion_t {
+                    enumeration: ManuallyDrop::new(e.discriminant().as_bytes().to_vec().into()),
+                },
+            ),
+            Val::Option(o) => (
+                WASMTIME_COMPONENT_OPTION,
+                wasmtime_component_valunion_t {
+                    option: ManuallyDrop::new(o.value().map(|v| Box::new(Self::from_val(v)))),
+                },
+            ),
+            Val::Result(r) => {
+                let (is_ok, val) = match r.value() {
+                    Ok(val) => (true, val),
+                    Err(val) => (false, val),
+                };
+                (
+                    WASMTIME_COMPONENT_RESULT,
+                    wasmtime_component_valunion_t {
+                        result: ManuallyDrop::new(wasmtime_component_valresult_t {
+                            is_ok,
+                            val: val.map(|v| Box::new(Self::from_val(v))),
+                        }),
+                    },
+                )
+            }
+            Val::Flags(f) => (
+                WASMTIME_COMPONENT_FLAGS,
+                wasmtime_component_valunion_t {
+                    flags: ManuallyDrop::new(
+                        f.flags()
+                            .map(|name| -> wasm_name_t { name.as_bytes().to_vec().into() })
+                            .collect::<Vec<_>>()
+                            .into(),
+                    ),
+                },
+            ),
+            Val::Resource(r) => (
+                WASMTIME_COMPONENT_RESOURCE,
+                wasmtime_component_valunion_t {
+                    resource: ManuallyDrop::new(Some(Box::new(
+                        wasmtime_component_resource_any_t { resource: *r },
+                    ))),
+                },
+            ),
+        };
+        wasmtime_component_val_t { kind, of }
+    }
+
+    fn from_vals<'a>(vals: impl Iterator<Item = &'a Val>) -> wasmtime_component_vallist_t {
+        vals.map(Self::from_val).collect::<Vec<_>>().into()
+    }
+
+    /// Converts this value into a [`Val`] of the type `ty`.
+    ///
+    /// Component values are constructed from their type, so unlike core wasm
+    /// values the expected type must be known to perform the conversion.
+    pub(crate) unsafe fn to_val(&self, ty: &Type) -> Result<Val> {
+        Ok(match (self.kind, ty) {
+            (WASMTIME_COMPONENT_BOOL, Type::Bool) => Val::Bool(self.of.boolean),
+            (WASMTIME_COMPONENT_S8, Type::S8) => Val::S8(self.of.s8),
+            (WASMTIME_COMPONENT_U8, Type::U8) => Val::U8(self.of.u8),
+            (WASMTIME_COMPONENT_S16, Type::S16) => Val::S16(self.of.s16),
+            (WASMTIME_COMPONENT_U16, Type::U16) => Val::U16(self.of.u16),
+            (WASMTIME_COMPONENT_S32, Type::S32) => Val::S32(self.of.s32),
+            (WASMTIME_COMPONENT_U32, Type::U32) => Val::U32(self.of.u32),
+            (WASMTIME_COMPONENT_S64, Type::S64) => Val::S64(self.of.s64),
+            (WASMTIME_COMPONENT_U64, Type::U64) => Val::U64(self.of.u64),
+            (WASMTIME_COMPONENT_F32, Type::Float32) => Val::Float32(self.of.f32),
+            (WASMTIME_COMPONENT_F64, Type::Float64) => Val::Float64(self.of.f64),
+            (WASMTIME_COMPONENT_CHAR, Type::Char) => Val::Char(
+                char::from_u32(self.of.character)
+                    .with_context(|| format!("invalid char value {:#x}", self.of.character))?,
+            ),
+            (WASMTIME_COMPONENT_STRING, Type::String) => {
+                Val::String(to_str(&self.of.string)?.into())
+            }
+            (WASMTIME_COMPONENT_LIST, Type::List(list)) => {
+                let element = list.ty();
+                list.new_val(
+                    self.of
+                        .list
+                        .as_slice()
+                        .iter()
+                        .map(|v| v.to_val(&element))
+                        .collect::<Result<_>>()?,
+                )?
+            }
+            (WASMTIME_COMPONENT_RECORD, Type::Record(record)) => {
+                let entries = self.of.record.as_slice();
+                let mut values = Vec::with_capacity(entries.len());
+                for field in record.fields() {
+                    let entry = entries
+                        .iter()
+                        .find(|e| e.name.as_slice() == field.name.as_bytes())
+                        .with_context(|| format!("missing record field `{}`", field.name))?;
+                    values.push((field.name, entry.val.to_val(&field.ty)?));
+                }
+                if entries.len() != values.len() {
+                    bail!(
+                        "expected {} record fields, found {}",
+                        values.len(),
+                        entries.len()
+                    );
+                }
+                record.new_val(values)?
+            }
+            (WASMTIME_COMPONENT_TUPLE, Type::Tuple(tuple)) => {
+                let values = self.of.tuple.as_slice();
+                if values.len() != tuple.types().len() {
+                    bail!(
+                        "expected {} tuple fields, found {}",
+                        tuple.types().len(),
+                        values.len()
+                    );
+                }
+                tuple.new_val(
+                    values
+                        .iter()
+                        .zip(tuple.types())
+                        .map(|(v, ty)| v.to_val(&ty))
+                        .collect::<Result<_>>()?,
+                )?
+            }
+            (WASMTIME_COMPONENT_VARIANT, Type::Variant(variant)) => {
+                let name = to_str(&self.of.variant.discriminant)?;
+                let case = variant
+                    .cases()
+                    .find(|c| c.name == name)
+                    .with_context(|| format!("unknown variant case `{name}`"))?;
+                let payload = optional_to_val(&self.of.variant.val, case.ty.as_ref())?;
+                variant.new_val(name, payload)?
+            }
+            (WASMTIME_COMPONENT_ENUM, Type::Enum(enumeration)) => {
+                enumeration.new_val(to_str(&self.of.enumeration)?)?
+            }
+            (WASMTIME_COMPONENT_OPTION, Type::Option(option)) => {
+                let value = match &*self.of.option {
+                    Some(v) => Some(v.to_val(&option.ty())?),
+                    None => None,
+                };
+                option.new_val(value)?
+            }
+            (WASMTIME_COMPONENT_RESULT, Type::Result(result)) => {
+                let val = &self.of.result.val;
+                let value = if self.of.result.is_ok {
+                    Ok(optional_to_val(val, result.ok().as_ref())?)
+                } else {
+                    Err(optional_to_val(val, result.err().as_ref())?)
+                };
+                result.new_val(value)?
+            }
+            (WASMTIME_COMPONENT_FLAGS, Type::Flags(flags)) => {
+                let names = self
+                    .of
+                    .flags
+                    .as_slice()
+                    .iter()
+                    .map(|name| to_str(name))
+                    .collect::<Result<Vec<_>>>()?;
+                flags.new_val(&names)?
+            }
+            (WASMTIME_COMPONENT_RESOURCE, Type::Own(_) | Type::Borrow(_)) => {
+                match &*self.of.resource {
+                    Some(r) => Val::Resource(r.resource),
+                    None => bail!("resource values cannot be null"),
+                }
+            }
+            _ => bail!(
+                "type mismatch: expected a `{}` value, found a `{}` value",
+                kind_desc(type_kind(ty)),
+                kind_desc(self.kind)
+            ),
+        })
+    }
+}
+
+fn type_kind(ty: &Type) -> wasmtime_component_valkind_t {
+    match ty {
+        Type::Bool => WASMTIME_COMPONENT_BOOL,
+        Type::S8 => WASMTIME_COMPONENT_S8,
+        Type::U8 => WASMTIME_COMPONENT_U8,
+        Type::S16 => WASMTIME_COMPONENT_S16,
+        Type::U16 => WASMTIME_COMPONENT_U16,
+        Type::S32 => WASMTIME_COMPONENT_S32,
+        Type::U32 => WASMTIME_COMPONENT_U32,
+        Type::S64 => WASMTIME_COMPONENT_S64,
+        Type::U64 => WASMTIME_COMPONENT_U64,
+        Type::Float32 => WASMTIME_COMPONENT_F32,
+        Type::Float64 => WASMTIME_COMPONENT_F64,
+        Type::Char => WASMTIME_COMPONENT_CHAR,
+        Type::String => WASMTIME_COMPONENT_STRING,
+        Type::List(_) => WASMTIME_COMPONENT_LIST,
+        Type::Record(_) => WASMTIME_COMPONENT_RECORD,
+        Type::Tuple(_) => WASMTIME_COMPONENT_TUPLE,
+        Type::Variant(_) => WASMTIME_COMPONENT_VARIANT,
+        Type::Enum(_) => WASMTIME_COMPONENT_ENUM,
+        Type::Option(_) => WASMTIME_COMPONENT_OPTION,
+        Type::Result(_) => WASMTIME_COMPONENT_RESULT,
+        Type::Flags(_) => WASMTIME_COMPONENT_FLAGS,
+        Type::Own(_) | Type::Borrow(_) => WASMTIME_COMPONENT_RESOURCE,
+    }
+}
+
+fn kind_desc(kind: wasmtime_component_valkind_t) -> &'static str {
+    match kind {
+        WASMTIME_COMPONENT_BOOL => "bool",
+        WASMTIME_COMPONENT_S8 => "s8",
+        WASMTIME_COMPONENT_U8 => "u8",
+        WASMTIME_COMPONENT_S16 => "s16",
+        WASMTIME_COMPONENT_U16 => "u16",
+        WASMTIME_COMPONENT_S32 => "s32",
+        WASMTIME_COMPONENT_U32 => "u32",
+        WASMTIME_COMPONENT_S64 => "s64",
+        WASMTIME_COMPONENT_U64 => "u64",
+        WASMTIME_COMPONENT_F32 => "float32",
+        WASMTIME_COMPONENT_F64 => "float64",
+        WASMTIME_COMPONENT_CHAR => "char",
+        WASMTIME_COMPONENT_STRING => "string",
+        WASMTIME_COMPONENT_LIST => "list",
+        WASMTIME_COMPONENT_RECORD => "record",
+        WASMTIME_COMPONENT_TUPLE => "tuple",
+        WASMTIME_COMPONENT_VARIANT => "variant",
+        WASMTIME_COMPONENT_ENUM => "enum",
+        WASMTIME_COMPONENT_OPTION => "option",
+        WASMTIME_COMPONENT_RESULT => "result",
+        WASMTIME_COMPONENT_FLAGS => "flags",
+        WASMTIME_COMPONENT_RESOURCE => "resource",
+        _ => "unknown",
+    }
+}
+
+unsafe fn to_str(name: &wasm_name_t) -> Result<&str> {
+    str::from_utf8(name.as_slice()).context("input was not valid utf-8")
+}
+
+/// Converts the optional payload of a variant or result into a [`Val`] of the
+/// optional type `ty`.
+unsafe fn optional_to_val(
+    val: &Option<Box<wasmtime_component_val_t>>,
+    ty: Option<&Type>,
+) -> Result<Option<Val>> {
+    match (val, ty) {
+        (Some(val), Some(ty)) => Ok(Some(val.to_val(ty)?)),
+        (None, None) => Ok(None),
+        (Some(_), None) => bail!("expected no payload, found a value"),
+        (None, Some(_)) => bail!("expected a payload, found none"),
+    }
+}
+
+impl Default for wasmtime_component_val_t {
+    fn default() -> Self {
+        wasmtime_component_val_t {
+            kind: WASMTIME_COMPONENT_BOOL,
+            of: wasmtime_component_valunion_t { boolean: false },
+        }
+    }
+}
+
+impl Clone for wasmtime_component_val_t {
+    fn clone(&self) -> Self {
+        unsafe {
+            let of = match self.kind {
+                WASMTIME_COMPONENT_BOOL => wasmtime_component_valunion_t {
+                    boolean: self.of.boolean,
+                },
+                WASMTIME_COMPONENT_S8 => wasmtime_component_valunion_t { s8: self.of.s8 },
+                WASMTIME_COMPONENT_U8 => wasmtime_component_valunion_t { u8: self.of.u8 },
+                WASMTIME_COMPONENT_S16 => wasmtime_component_valunion_t { s16: self.of.s16 },
+                WASMTIME_COMPONENT_U16 => wasmtime_component_valunion_t { u16: self.of.u16 },
+                WASMTIME_COMPONENT_S32 => wasmtime_component_valunion_t { s32: self.of.s32 },
+                WASMTIME_COMPONENT_U32 => wasmtime_component_valunion_t { u32: self.of.u32 },
+                WASMTIME_COMPONENT_S64 => wasmtime_component_valunion_t { s64: self.of.s64 },
+                WASMTIME_COMPONENT_U64 => wasmtime_component_valunion_t { u64: self.of.u64 },
+                WASMTIME_COMPONENT_F32 => wasmtime_component_valunion_t { f32: self.of.f32 },
+                WASMTIME_COMPONENT_F64 => wasmtime_component_valunion_t { f64: self.of.f64 },
+                WASMTIME_COMPONENT_CHAR => wasmtime_component_valunion_t {
+                    character: self.of.character,
+                },
+                WASMTIME_COMPONENT_STRING => wasmtime_component_valunion_t {
+                    string: self.of.string.clone(),
+                },
+                WASMTIME_COMPONENT_LIST => wasmtime_component_valunion_t {
+                    list: self.of.list.clone(),
+                },
+                WASMTIME_COMPONENT_RECORD => wasmtime_component_valunion_t {
+                    record: self.of.record.clone(),
+                },
+                WASMTIME_COMPONENT_TUPLE => wasmtime_component_valunion_t {
+                    tuple: self.of.tuple.clone(),
+                },
+                WASMTIME_COMPONENT_VARIANT => wasmtime_component_valunion_t {
+                    variant: self.of.variant.clone(),
+                },
+                WASMTIME_COMPONENT_ENUM => wasmtime_component_valunion_t {
+                    enumeration: self.of.enumeration.clone(),
+                },
+                WASMTIME_COMPONENT_OPTION => wasmtime_component_valunion_t {
+                    option: self.of.option.clone(),
+                },
+                WASMTIME_COMPONENT_RESULT => wasmtime_component_valunion_t {
+                    result: self.of.result.clone(),
+                },
+                WASMTIME_COMPONENT_FLAGS => wasmtime_component_valunion_t {
+                    flags: self.of.flags.clone(),
+                },
+                WASMTIME_COMPONENT_RESOURCE => wasmtime_component_valunion_t {
+                    resource: self.of.resource.clone(),
+                },
+                // Unknown kinds have no field which is known to be
+                // initialized, so don't read any of them.
+                _ => return wasmtime_component_val_t::default(),
+            };
+            wasmtime_component_val_t {
+                kind: self.kind,
+                of,
+            }
+        }
+    }
+}
+
+impl Drop for wasmtime_component_val_t {
+    fn drop(&mut self) {
+        unsafe {
+            match self.kind {
+                WASMTIME_COMPONENT_STRING => ManuallyDrop::drop(&mut self.of.string),
+                WASMTIME_COMPONENT_LIST => ManuallyDrop::drop(&mut self.of.list),
+                WASMTIME_COMPONENT_RECORD => ManuallyDrop::drop(&mut self.of.record),
+                WASMTIME_COMPONENT_TUPLE => ManuallyDrop::drop(&mut self.of.tuple),
+                WASMTIME_COMPONENT_VARIANT => ManuallyDrop::drop(&mut self.of.variant),
+                WASMTIME_COMPONENT_ENUM => ManuallyDrop::drop(&mut self.of.enumeration),
+                WASMTIME_COMPONENT_OPTION => ManuallyDrop::drop(&mut self.of.option),
+                WASMTIME_COMPONENT_RESULT => ManuallyDrop::drop(&mut self.of.result),
+                WASMTIME_COMPONENT_FLAGS => ManuallyDrop::drop(&mut self.of.flags),
+                WASMTIME_COMPONENT_RESOURCE => ManuallyDrop::drop(&mut self.of.resource),
+                _ => {}
+            }
+        }
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn wasmtime_component_val_delete(
+    val: &mut ManuallyDrop<wasmtime_component_val_t>,
+) {
+    ManuallyDrop::drop(val)
+}
+
+#[no_mangle]
+pub extern "C" fn wasmtime_component_val_copy(
+    dst: &mut MaybeUninit<wasmtime_component_val_t>,
+    src: &wasmtime_component_val_t,
+) {
+    crate::initialize(dst, src.clone())
+}
@@ -0,0 +1,374 @@
+//! The WASI preview2 embedding API definitions for Wasmtime, for use with
+//! components.
+
+use crate::{
+    handle_result, wasm_byte_vec_t, wasmtime_component_linker_t, wasmtime_error_t,
+    CStoreContextMut, ForeignData, StoreData,
+};
+use anyhow::anyhow;
+use bytes::Bytes;
+use cap_std::ambient_authority;
+use std::ffi::{c_void, CStr};
+use std::net::SocketAddr;
+use std::os::raw::{c_char, c_int};
+use std::slice;
+use std::sync::Arc;
+use wasmtime::component::ResourceTable;
+use wasmtime_wasi::preview2::{
+    pipe::MemoryInputPipe, DirPerms, FilePerms, HostInputStream, HostOutputStream, SocketAddrUse,
+    StdinStream, StdoutStream, StreamError, StreamResult, Subscribe, WasiCtx, WasiCtxBuilder,
+    WasiView,
+};
+
+/// Maximum number of bytes read from a stdin callback at once.
+const MAX_READ_SIZE: usize = 64 * 1024;
+
+pub struct wasi_preview2_config_t {
+    builder: WasiCtxBuilder,
+}
+
+wasmtime_c_api_macros::declare_own!(wasi_preview2_config_t);
+
+pub type wasi_preview2_read_callback_t = extern "C" fn(*mut c_void, *mut u8, usize) -> isize;
+pub type wasi_preview2_write_callback_t = extern "C" fn(*mut c_void, *const u8, usize) -> isize;
+
+pub type wasi_preview2_socket_addr_use_t = u8;
+pub const WASI_PREVIEW2_SOCKET_ADDR_USE_TCP_BIND: wasi_preview2_socket_addr_use_t = 0;
+pub const WASI_PREVIEW2_SOCKET_ADDR_USE_TCP_CONNECT: wasi_preview2_socket_addr_use_t = 1;
+pub const WASI_PREVIEW2_SOCKET_ADDR_USE_UDP_BIND: wasi_preview2_socket_addr_use_t = 2;
+pub const WASI_PREVIEW2_SOCKET_ADDR_USE_UDP_CONNECT: wasi_preview2_socket_addr_use_t = 3;
+pub const WASI_PREVIEW2_SOCKET_ADDR_USE_UDP_OUTGOING_DATAGRAM: wasi_preview2_socket_addr_use_t = 4;
+
+pub type wasi_preview2_socket_addr_check_t =
+    extern "C" fn(*mut c_void, *const c_char, usize, wasi_preview2_socket_addr_use_t) -> bool;
+
+impl WasiView for StoreData {
+    fn table(&mut self) -> &mut ResourceTable {
+        &mut self.resource_table
+    }
+
+    fn ctx(&mut self) -> &mut WasiCtx {
+        self.wasi_preview2.as_mut().expect(
+            "WASI preview2 is not configured; did you call `wasmtime_context_set_wasi_preview2`?",
+        )
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn wasi_preview2_config_new() -> Box<wasi_preview2_config_t> {
+    Box::new(wasi_preview2_config_t {
+        builder: WasiCtxBuilder::new(),
+    })
+}
+
+unsafe fn cstrs_to_strs<'a>(ptrs: *const *const c_char, len: c_int) -> Option<Vec<&'a str>> {
+    if len <= 0 {
+        return Some(Vec::new());
+    }
+    slice::from_raw_parts(ptrs, len as usize)
+        .iter()
+        .map(|p| CStr::from_ptr(*p).to_str().ok())
+        .collect()
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn wasi_preview2_config_set_argv(
+    config: &mut wasi_preview2_config_t,
+    argc: c_int,
+    argv: *const *const c_char,
+) -> bool {
+    match cstrs_to_strs(argv, argc) {
+        Some(args) => {
+            config.builder.args(&args);
+            true
+        }
+        None => false,
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn wasi_preview2_config_inherit_argv(config: &mut wasi_preview2_config_t) {
+    config.builder.args(&std::env::args().collect::<Vec<_>>());
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn wasi_preview2_config_set_env(
+    config: &mut wasi_preview2_config_t,
+    envc: c_int,
+    names: *const *const c_char,
+    values: *const *const c_char,
+) -> bool {
+    let (names, values) = match (cstrs_to_strs(names, envc), cstrs_to_strs(values, envc)) {
+        (Some(names), Some(values)) => (names, values),
+        _ => return false,
+    };
+    for (name, value) in names.iter().zip(values) {
+        config.builder.env(name, value);
+    }
+    true
+}
+
+#[no_mangle]
+pub extern "C" fn wasi_preview2_config_inherit_env(config: &mut wasi_preview2_config_t) {
+    config.builder.envs(&std::env::vars().collect::<Vec<_>>());
+}
+
+#[no_mangle]
+pub extern "C" fn wasi_preview2_config_inherit_stdin(config: &mut wasi_preview2_config_t) {
+    config.builder.inherit_stdin();
+}
+
+#[no_mangle]
+pub extern "C" fn wasi_preview2_config_inherit_stdout(config: &mut wasi_preview2_config_t) {
+    config.builder.inherit_stdout();
+}
+
+#[no_mangle]
+pub extern "C" fn wasi_preview2_config_inherit_stderr(config: &mut wasi_preview2_config_t) {
+    config.builder.inherit_stderr();
+}
+
+#[no_mangle]
+pub extern "C" fn wasi_preview2_config_set_stdin_bytes(
+    config: &mut wasi_preview2_config_t,
+    binary: &mut wasm_byte_vec_t,
+) {
+    let binary = binary.take();
+    config.builder.stdin(MemoryInputPipe::new(binary.into()));
+}
+
+#[no_mangle]
+pub extern "C" fn wasi_preview2_config_set_stdin_callback(
+    config: &mut wasi_preview2_config_t,
+    callback: wasi_preview2_read_callback_t,
+    data: *mut c_void,
+    finalizer: Option<extern "C" fn(*mut c_void)>,
+) {
+    config.builder.stdin(CallbackStdin(Arc::new((
+        callback,
+        ForeignData { data, finalizer },
+    ))));
+}
+
+#[no_mangle]
+pub extern "C" fn wasi_preview2_config_set_stdout_callback(
+    config: &mut wasi_preview2_config_t,
+    callback: wasi_preview2_write_callback_t,
+    data: *mut c_void,
+    finalizer: Option<extern "C" fn(*mut c_void)>,
+) {
+    config.builder.stdout(CallbackStdout(Arc::new((
+        callback,
+        ForeignData { data, finalizer },
+    ))));
+}
+
+#[no_mangle]
+pub extern "C" fn wasi_preview2_config_set_stderr_callback(
+    config: &mut wasi_preview2_config_t,
+    callback: wasi_preview2_write_callback_t,
+    data: *mut c_void,
+    finalizer: Option<extern "C" fn(*mut c_void)>,
+) {
+    config.builder.stderr(CallbackStdout(Arc::new((
+        callback,
+        ForeignData { data, finalizer },
+    ))));
+}
+
+pub type wasi_preview2_dir_perms_t = usize;
+pub type wasi_preview2_file_perms_t = usize;
+
+#[no_mangle]
+pub unsafe extern "C" fn wasi_preview2_config_preopen_dir(
+    config: &mut wasi_preview2_config_t,
+    path: *const c_char,
+    guest_path: *const c_char,
+    dir_perms: wasi_preview2_dir_perms_t,
+    file_perms: wasi_preview2_file_perms_t,
+) -> bool {
+    let (path, guest_path) = match (
+        CStr::from_ptr(path).to_str(),
+        CStr::from_ptr(guest_path).to_str(),
+    ) {
+        (Ok(path), Ok(guest_path)) => (path, guest_path),
+        _ => return false,
+    };
+    let (dir_perms, file_perms) = match (
+        DirPerms::from_bits(dir_perms),
+        FilePerms::from_bits(file_perms),
+    ) {
+        (Some(dir_perms), Some(file_perms)) => (dir_perms, file_perms),
+        _ => return false,
+    };
+    let dir = match cap_std::fs::Dir::open_ambient_dir(path, ambient_authority()) {
+        Ok(dir) => dir,
+        Err(_) => return false,
+    };
+    config
+        .builder
+        .preopened_dir(dir, dir_perms, file_perms, guest_path);
+    true
+}
+
+#[no_mangle]
+pub extern "C" fn wasi_preview2_config_inherit_network(config: &mut wasi_preview2_config_t) {
+    config.builder.inherit_network();
+}
+
+#[no_mangle]
+pub extern "C" fn wasi_preview2_config_socket_addr_check(
+    config: &mut wasi_preview2_config_t,
+    callback: wasi_preview2_socket_addr_check_t,
+    data: *mut c_void,
+    finalizer: Option<extern "C" fn(*mut c_void)>,
+) {
+    let foreign = ForeignData { data, finalizer };
+    config
+        .builder
+        .socket_addr_check(move |addr: &SocketAddr, addr_use: SocketAddrUse| {
+            let _ = &foreign; // move entire foreign into this closure
+            let addr = addr.to_string();
+            let addr_use = match addr_use {
+                SocketAddrUse::TcpBind => WASI_PREVIEW2_SOCKET_ADDR_USE_TCP_BIND,
+                SocketAddrUse::TcpConnect => WASI_PREVIEW2_SOCKET_ADDR_USE_TCP_CONNECT,
+                SocketAddrUse::UdpBind => WASI_PREVIEW2_SOCKET_ADDR_USE_UDP_BIND,
+                SocketAddrUse::UdpConnect => WASI_PREVIEW2_SOCKET_ADDR_USE_UDP_CONNECT,
+                SocketAddrUse::UdpOutgoingDatagram => {
+                    WASI_PREVIEW2_SOCKET_ADDR_USE_UDP_OUTGOING_DATAGRAM
+                }
+            };
+            callback(foreign.data, addr.as_ptr().cast(), addr.len(), addr_use)
+        });
+}
+
+#[no_mangle]
+pub extern "C" fn wasi_preview2_config_allow_tcp(
+    config: &mut wasi_preview2_config_t,
+    enable: bool,
+) {
+    config.builder.allow_tcp(enable);
+}
+
+#[no_mangle]
+pub extern "C" fn wasi_preview2_config_allow_udp(
+    config: &mut wasi_preview2_config_t,
+    enable: bool,
+) {
+    config.builder.allow_udp(enable);
+}
+
+#[no_mangle]
+pub extern "C" fn wasi_preview2_config_allow_ip_name_lookup(
+    config: &mut wasi_preview2_config_t,
+    enable: bool,
+) {
+    config.builder.allow_ip_name_lookup(enable);
+}
+
+#[no_mangle]
+pub extern "C" fn wasmtime_context_set_wasi_preview2(
+    mut context: CStoreContextMut<'_>,
+    mut config: Box<wasi_preview2_config_t>,
+) {
+    context.data_mut().wasi_preview2 = Some(config.builder.build());
+}
+
+#[no_mangle]
+pub extern "C" fn wasmtime_component_linker_add_wasi_preview2(
+    linker: &mut wasmtime_component_linker_t,
+) -> Option<Box<wasmtime_error_t>> {
+    handle_result(
+        wasmtime_wasi::preview2::command::sync::add_to_linker(&mut linker.linker),
+        |()| (),
+    )
+}
+
+/// A stdin stream which is read through a C callback.
+///
+/// All streams created for a context share the same callback, and the
+/// callback's data is finalized once the last of them is dropped.
+#[derive(Clone)]
+struct CallbackStdin(Arc<(wasi_preview2_read_callback_t, ForeignData)>);
+
+impl StdinStream for CallbackStdin {
+    fn stream(&self) -> Box<dyn HostInputStream> {
+        Box::new(self.clone())
+    }
+
+    fn isatty(&self) -> bool {
+        false
+    }
+}
+
+impl HostInputStream for CallbackStdin {
+    fn read(&mut self, size: usize) -> StreamResult<Bytes> {
+        if size == 0 {
+            return Ok(Bytes::new());
+        }
+        let (callback, foreign) = &*self.0;
+        let mut buf = vec![0; size.min(MAX_READ_SIZE)];
+        match callback(foreign.data, buf.as_mut_ptr(), buf.len()) {
+            0 => Err(StreamError::Closed),
+            n if n < 0 => Err(StreamError::LastOperationFailed(anyhow!(
+                "stdin callback failed"
+            ))),
+            n => {
+                buf.truncate((n as usize).min(buf.len()));
+                Ok(buf.into())
+            }
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl Subscribe for CallbackStdin {
+    async fn ready(&mut self) {}
+}
+
+/// An output stream which is written through a C callback.
+#[derive(Clone)]
+struct CallbackStdout(Arc<(wasi_preview2_write_callback_t, ForeignData)>);
+
+impl StdoutStream for CallbackStdout {
+    fn stream(&self) -> Box<dyn HostOutputStream> {
+        Box::new(self.clone())
+    }
+
+    fn isatty(&self) -> bool {
+        false
+    }
+}
+
+impl HostOutputStream for CallbackStdout {
+    fn write(&mut self, mut bytes: Bytes) -> StreamResult<()> {
+        let (callback, foreign) = &*self.0;
+        while !bytes.is_empty() {
+            match callback(foreign.data, bytes.as_ptr(), bytes.len()) {
+                n if n <= 0 => {
+                    return Err(StreamError::LastOperationFailed(anyhow!(
+                        "output callback failed"
+                    )))
+                }
+                n => {
+                    let _ = bytes.split_to((n as usize).min(bytes.len()));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn flush(&mut self) -> StreamResult<()> {
+        // Writes are passed straight through to the callback.
+        Ok(())
+    }
+
+    fn check_write(&mut self) -> StreamResult<usize> {
+        Ok(usize::MAX)
+    }
+}
+
+#[async_trait::async_trait]
+impl Subscribe for CallbackStdout {
+    async fn ready(&mut self) {}
+}
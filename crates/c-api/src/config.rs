@@ -103,6 +103,11 @@ pub extern "C" fn wasmtime_config_wasm_relaxed_simd_deterministic_set(
     c.config.relaxed_simd_deterministic(enable);
 }
 
+#[no_mangle]
+pub extern "C" fn wasmtime_config_deterministic_set(c: &mut wasm_config_t, enable: bool) {
+    c.config.deterministic(enable);
+}
+
 #[no_mangle]
 pub extern "C" fn wasmtime_config_wasm_bulk_memory_set(c: &mut wasm_config_t, enable: bool) {
     c.config.wasm_bulk_memory(enable);
@@ -123,6 +128,11 @@ pub extern "C" fn wasmtime_config_wasm_memory64_set(c: &mut wasm_config_t, enabl
     c.config.wasm_memory64(enable);
 }
 
+#[no_mangle]
+pub extern "C" fn wasmtime_config_wasm_extended_const_set(c: &mut wasm_config_t, enable: bool) {
+    c.config.wasm_extended_const(enable);
+}
+
 #[no_mangle]
 pub extern "C" fn wasmtime_config_strategy_set(
     c: &mut wasm_config_t,
@@ -163,7 +163,7 @@ pub unsafe extern "C" fn wasm_func_call(
     }
 }
 
-fn error_from_panic(panic: Box<dyn Any + Send>) -> Error {
+pub(crate) fn error_from_panic(panic: Box<dyn Any + Send>) -> Error {
     if let Some(msg) = panic.downcast_ref::<String>() {
         Error::msg(msg.clone())
     } else if let Some(msg) = panic.downcast_ref::<&'static str>() {
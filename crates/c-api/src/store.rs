@@ -72,6 +72,12 @@ pub struct StoreData {
     foreign: crate::ForeignData,
     #[cfg(feature = "wasi")]
     pub(crate) wasi: Option<wasmtime_wasi::WasiCtx>,
+    #[cfg(all(feature = "wasi", feature = "component-model"))]
+    pub(crate) wasi_preview2: Option<wasmtime_wasi::preview2::WasiCtx>,
+
+    /// Host-side resources of WASI preview2 components.
+    #[cfg(all(feature = "wasi", feature = "component-model"))]
+    pub(crate) resource_table: wasmtime::component::ResourceTable,
 
     /// Temporary storage for usage during a wasm->host call to store values
     /// in a slice we pass to the C API.
@@ -98,6 +104,10 @@ pub extern "C" fn wasmtime_store_new(
                 foreign: ForeignData { data, finalizer },
                 #[cfg(feature = "wasi")]
                 wasi: None,
+                #[cfg(all(feature = "wasi", feature = "component-model"))]
+                wasi_preview2: None,
+                #[cfg(all(feature = "wasi", feature = "component-model"))]
+                resource_table: wasmtime::component::ResourceTable::new(),
                 hostcall_val_storage: Vec::new(),
                 wasm_val_storage: Vec::new(),
                 store_limits: StoreLimits::default(),
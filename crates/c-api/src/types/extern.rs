@@ -1,6 +1,6 @@
 use crate::{wasm_functype_t, wasm_globaltype_t, wasm_memorytype_t, wasm_tabletype_t};
 use crate::{CFuncType, CGlobalType, CMemoryType, CTableType};
-use wasmtime::ExternType;
+use wasmtime::{ExternType, TagType};
 
 #[repr(C)]
 #[derive(Clone)]
@@ -16,6 +16,7 @@ pub(crate) enum CExternType {
     Global(CGlobalType),
     Memory(CMemoryType),
     Table(CTableType),
+    Tag(TagType),
 }
 
 pub type wasm_externkind_t = u8;
@@ -33,6 +34,7 @@ impl wasm_externtype_t {
                 ExternType::Global(f) => CExternType::Global(CGlobalType::new(f)),
                 ExternType::Memory(f) => CExternType::Memory(CMemoryType::new(f)),
                 ExternType::Table(f) => CExternType::Table(CTableType::new(f)),
+                ExternType::Tag(t) => CExternType::Tag(t),
             },
         }
     }
@@ -43,6 +45,7 @@ impl wasm_externtype_t {
             CExternType::Table(f) => ExternType::Table(f.ty.clone()),
             CExternType::Global(f) => ExternType::Global(f.ty.clone()),
             CExternType::Memory(f) => ExternType::Memory(f.ty.clone()),
+            CExternType::Tag(t) => ExternType::Tag(t.clone()),
         }
     }
 }
@@ -54,6 +57,7 @@ pub extern "C" fn wasm_externtype_kind(et: &wasm_externtype_t) -> wasm_externkin
         CExternType::Table(_) => WASM_EXTERN_TABLE,
         CExternType::Global(_) => WASM_EXTERN_GLOBAL,
         CExternType::Memory(_) => WASM_EXTERN_MEMORY,
+        CExternType::Tag(_) => crate::WASMTIME_EXTERN_TAG,
     }
 }
 
@@ -37,6 +37,8 @@ pub(crate) fn into_valtype(kind: wasm_valkind_t) -> ValType {
         WASM_EXTERNREF => ValType::ExternRef,
         WASM_FUNCREF => ValType::FuncRef,
         WASMTIME_V128 => ValType::V128,
+        WASMTIME_ANYREF => ValType::AnyRef,
+        WASMTIME_EXNREF => ValType::ExnRef,
         _ => panic!("unexpected kind: {}", kind),
     }
 }
@@ -50,6 +52,8 @@ pub(crate) fn from_valtype(ty: &ValType) -> wasm_valkind_t {
         ValType::ExternRef => WASM_EXTERNREF,
         ValType::FuncRef => WASM_FUNCREF,
         ValType::V128 => WASMTIME_V128,
+        ValType::AnyRef => WASMTIME_ANYREF,
+        ValType::ExnRef => WASMTIME_EXNREF,
     }
 }
 
@@ -61,3 +65,5 @@ pub const WASMTIME_F64: wasmtime_valkind_t = 3;
 pub const WASMTIME_V128: wasmtime_valkind_t = 4;
 pub const WASMTIME_FUNCREF: wasmtime_valkind_t = 5;
 pub const WASMTIME_EXTERNREF: wasmtime_valkind_t = 6;
+pub const WASMTIME_ANYREF: wasmtime_valkind_t = 7;
+pub const WASMTIME_EXNREF: wasmtime_valkind_t = 8;
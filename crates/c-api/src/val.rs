@@ -6,7 +6,7 @@ use crate::{
 use std::ffi::c_void;
 use std::mem::{self, ManuallyDrop, MaybeUninit};
 use std::ptr;
-use wasmtime::{ExternRef, Func, Val, ValType};
+use wasmtime::{AnyRef, ExnRef, ExternRef, Func, Val, ValType, I31};
 
 #[repr(C)]
 pub struct wasm_val_t {
@@ -167,6 +167,15 @@ pub union wasmtime_val_union {
     pub funcref: wasmtime_func_t,
     pub externref: ManuallyDrop<Option<ExternRef>>,
     pub v128: [u8; 16],
+    pub anyref: wasmtime_anyref_t,
+    pub exnref: ManuallyDrop<Option<Box<ExnRef>>>,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct wasmtime_anyref_t {
+    pub is_null: bool,
+    pub i31: u32,
 }
 
 #[repr(C)]
@@ -219,6 +228,28 @@ impl wasmtime_val_t {
                     v128: val.as_u128().to_le_bytes(),
                 },
             },
+            Val::AnyRef(a) => wasmtime_val_t {
+                kind: crate::WASMTIME_ANYREF,
+                of: wasmtime_val_union {
+                    anyref: match a.and_then(|a| a.as_i31()) {
+                        Some(i31) => wasmtime_anyref_t {
+                            is_null: false,
+                            i31: i31.get_u32(),
+                        },
+                        // Objects can't be represented in the C API yet.
+                        None => wasmtime_anyref_t {
+                            is_null: true,
+                            i31: 0,
+                        },
+                    },
+                },
+            },
+            Val::ExnRef(e) => wasmtime_val_t {
+                kind: crate::WASMTIME_EXNREF,
+                of: wasmtime_val_union {
+                    exnref: ManuallyDrop::new(e.map(Box::new)),
+                },
+            },
         }
     }
 
@@ -239,6 +270,12 @@ impl wasmtime_val_t {
                 })
             }
             crate::WASMTIME_EXTERNREF => Val::ExternRef((*self.of.externref).clone()),
+            crate::WASMTIME_ANYREF => Val::AnyRef(if self.of.anyref.is_null {
+                None
+            } else {
+                Some(AnyRef::from_i31(I31::wrapping_u32(self.of.anyref.i31)))
+            }),
+            crate::WASMTIME_EXNREF => Val::ExnRef(self.of.exnref.as_deref().cloned()),
             other => panic!("unknown wasmtime_valkind_t: {}", other),
         }
     }
@@ -246,10 +283,14 @@ impl wasmtime_val_t {
 
 impl Drop for wasmtime_val_t {
     fn drop(&mut self) {
-        if self.kind == crate::WASMTIME_EXTERNREF {
-            unsafe {
+        match self.kind {
+            crate::WASMTIME_EXTERNREF => unsafe {
                 ManuallyDrop::drop(&mut self.of.externref);
-            }
+            },
+            crate::WASMTIME_EXNREF => unsafe {
+                ManuallyDrop::drop(&mut self.of.exnref);
+            },
+            _ => {}
         }
     }
 }
@@ -85,6 +85,12 @@ macro_rules! declare_vecs {
             }
         }
 
+        impl$(<$lt>)? Default for $name $(<$lt>)? {
+            fn default() -> Self {
+                Vec::new().into()
+            }
+        }
+
         impl$(<$lt>)? From<Vec<$elem_ty>> for $name $(<$lt>)? {
             fn from(vec: Vec<$elem_ty>) -> Self {
                 let mut vec = vec.into_boxed_slice();
@@ -249,3 +255,34 @@ declare_vecs! {
         delete: wasm_extern_vec_delete,
     )
 }
+
+#[cfg(feature = "component-model")]
+declare_vecs! {
+    (
+        name: wasmtime_component_vallist_t,
+        ty: crate::wasmtime_component_val_t,
+        new: wasmtime_component_vallist_new,
+        empty: wasmtime_component_vallist_new_empty,
+        uninit: wasmtime_component_vallist_new_uninitialized,
+        copy: wasmtime_component_vallist_copy,
+        delete: wasmtime_component_vallist_delete,
+    )
+    (
+        name: wasmtime_component_valrecord_t,
+        ty: crate::wasmtime_component_valrecord_entry_t,
+        new: wasmtime_component_valrecord_new,
+        empty: wasmtime_component_valrecord_new_empty,
+        uninit: wasmtime_component_valrecord_new_uninitialized,
+        copy: wasmtime_component_valrecord_copy,
+        delete: wasmtime_component_valrecord_delete,
+    )
+    (
+        name: wasmtime_component_valflags_t,
+        ty: wasm_name_t,
+        new: wasmtime_component_valflags_new,
+        empty: wasmtime_component_valflags_new_empty,
+        uninit: wasmtime_component_valflags_new_uninitialized,
+        copy: wasmtime_component_valflags_copy,
+        delete: wasmtime_component_valflags_delete,
+    )
+}
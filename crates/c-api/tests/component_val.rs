@@ -0,0 +1,476 @@
+#![cfg(feature = "component-model")]
+
+use std::ffi::c_void;
+use std::mem::{ManuallyDrop, MaybeUninit};
+use std::ptr;
+use std::sync::atomic::{AtomicU32, Ordering::SeqCst};
+use wamstime_c_api::*;
+use wasmtime::component::{Func, Instance};
+
+fn copy(src: &wasmtime_component_val_t) -> wasmtime_component_val_t {
+    let mut dst = MaybeUninit::uninit();
+    wasmtime_component_val_copy(&mut dst, src);
+    unsafe { dst.assume_init() }
+}
+
+fn delete(val: wasmtime_component_val_t) {
+    unsafe { wasmtime_component_val_delete(&mut ManuallyDrop::new(val)) }
+}
+
+#[test]
+fn copy_scalars() {
+    macro_rules! scalars {
+        ($($kind:ident $field:ident $value:expr,)*) => {$(
+            let src = wasmtime_component_val_t {
+                kind: $kind,
+                of: wasmtime_component_valunion_t { $field: $value },
+            };
+            let dst = copy(&src);
+            assert_eq!(dst.kind, $kind);
+            assert_eq!(unsafe { dst.of.$field }, $value, stringify!($kind));
+            delete(dst);
+            delete(src);
+        )*};
+    }
+
+    scalars! {
+        WASMTIME_COMPONENT_BOOL boolean true,
+        WASMTIME_COMPONENT_S8 s8 -100,
+        WASMTIME_COMPONENT_U8 u8 200,
+        WASMTIME_COMPONENT_S16 s16 -30_000,
+        WASMTIME_COMPONENT_U16 u16 60_000,
+        WASMTIME_COMPONENT_S32 s32 -2_000_000_000,
+        WASMTIME_COMPONENT_U32 u32 4_000_000_000,
+        WASMTIME_COMPONENT_S64 s64 i64::MIN,
+        WASMTIME_COMPONENT_U64 u64 u64::MAX,
+        WASMTIME_COMPONENT_F32 f32 1.5,
+        WASMTIME_COMPONENT_F64 f64 -2.25,
+        WASMTIME_COMPONENT_CHAR character u32::from('☃'),
+    }
+}
+
+#[test]
+fn copy_unknown_kind() {
+    let src = wasmtime_component_val_t {
+        kind: u8::MAX,
+        of: wasmtime_component_valunion_t { u8: 1 },
+    };
+    let dst = copy(&src);
+    assert_eq!(dst.kind, WASMTIME_COMPONENT_BOOL);
+    assert!(!unsafe { dst.of.boolean });
+    delete(dst);
+    delete(src);
+}
+
+/// A component whose exports forward their argument to a host import of the
+/// same name and return whatever the host hands back.
+const ECHO: &str = r#"
+(component
+  (type $rec' (record (field "a" u32) (field "b" string)))
+  (import "rec" (type $rec (eq $rec')))
+  (type $var' (variant (case "none") (case "num" u32) (case "str" string)))
+  (import "var" (type $var (eq $var')))
+  (import "r" (type $r (sub resource)))
+
+  (import "echo-record" (func $echo-record (param "x" $rec) (result $rec)))
+  (import "echo-variant" (func $echo-variant (param "x" $var) (result $var)))
+  (import "echo-list" (func $echo-list (param "x" (list u32)) (result (list u32))))
+  (import "echo-resource" (func $echo-resource (param "x" (own $r)) (result (own $r))))
+
+  (core module $libc
+    (memory (export "memory") 1)
+    (global $next (mut i32) (i32.const 1024))
+    (func (export "realloc") (param i32 i32 i32 i32) (result i32)
+      (local $ret i32)
+      (local.set $ret
+        (i32.and
+          (i32.add (global.get $next) (i32.sub (local.get 2) (i32.const 1)))
+          (i32.sub (i32.const 0) (local.get 2))))
+      (global.set $next (i32.add (local.get $ret) (local.get 3)))
+      (local.get $ret))
+  )
+  (core instance $libc (instantiate $libc))
+
+  (core func $echo-record (canon lower (func $echo-record)
+    (memory $libc "memory") (realloc (func $libc "realloc"))))
+  (core func $echo-variant (canon lower (func $echo-variant)
+    (memory $libc "memory") (realloc (func $libc "realloc"))))
+  (core func $echo-list (canon lower (func $echo-list)
+    (memory $libc "memory") (realloc (func $libc "realloc"))))
+  (core func $echo-resource (canon lower (func $echo-resource)))
+
+  (core module $m
+    (import "" "echo-record" (func $echo-record (param i32 i32 i32 i32)))
+    (import "" "echo-variant" (func $echo-variant (param i32 i32 i32 i32)))
+    (import "" "echo-list" (func $echo-list (param i32 i32 i32)))
+    (import "" "echo-resource" (func $echo-resource (param i32) (result i32)))
+
+    (func (export "echo-record") (param i32 i32 i32) (result i32)
+      (call $echo-record (local.get 0) (local.get 1) (local.get 2) (i32.const 16))
+      (i32.const 16))
+    (func (export "echo-variant") (param i32 i32 i32) (result i32)
+      (call $echo-variant (local.get 0) (local.get 1) (local.get 2) (i32.const 32))
+      (i32.const 32))
+    (func (export "echo-list") (param i32 i32) (result i32)
+      (call $echo-list (local.get 0) (local.get 1) (i32.const 48))
+      (i32.const 48))
+    (func (export "echo-resource") (param i32) (result i32)
+      (call $echo-resource (local.get 0)))
+  )
+  (core instance $m (instantiate $m
+    (with "" (instance
+      (export "echo-record" (func $echo-record))
+      (export "echo-variant" (func $echo-variant))
+      (export "echo-list" (func $echo-list))
+      (export "echo-resource" (func $echo-resource))
+    ))
+  ))
+
+  (func (export "echo-record") (param "x" $rec) (result $rec)
+    (canon lift (core func $m "echo-record")
+      (memory $libc "memory") (realloc (func $libc "realloc"))))
+  (func (export "echo-variant") (param "x" $var) (result $var)
+    (canon lift (core func $m "echo-variant")
+      (memory $libc "memory") (realloc (func $libc "realloc"))))
+  (func (export "echo-list") (param "x" (list u32)) (result (list u32))
+    (canon lift (core func $m "echo-list")
+      (memory $libc "memory") (realloc (func $libc "realloc"))))
+  (func (export "echo-resource") (param "x" (own $r)) (result (own $r))
+    (canon lift (core func $m "echo-resource")))
+)
+"#;
+
+/// Host state shared with the callbacks through their `data` pointer.
+#[derive(Default)]
+struct Host {
+    calls: AtomicU32,
+    destroyed: AtomicU32,
+}
+
+extern "C" fn echo(
+    data: *mut c_void,
+    _store: CStoreContextMut<'_>,
+    params: *const wasmtime_component_val_t,
+    nparams: usize,
+    results: *mut wasmtime_component_val_t,
+    nresults: usize,
+) -> Option<Box<wasmtime_error_t>> {
+    let host = unsafe { &*(data as *const Host) };
+    host.calls.fetch_add(1, SeqCst);
+    let params = unsafe { std::slice::from_raw_parts(params, nparams) };
+    let results = unsafe { std::slice::from_raw_parts_mut(results, nresults) };
+    results.clone_from_slice(params);
+    None
+}
+
+extern "C" fn destroy(
+    data: *mut c_void,
+    _store: CStoreContextMut<'_>,
+    rep: u32,
+) -> Option<Box<wasmtime_error_t>> {
+    let host = unsafe { &*(data as *const Host) };
+    host.destroyed.store(rep, SeqCst);
+    None
+}
+
+fn check(err: Option<Box<wasmtime_error_t>>) {
+    if let Some(err) = err {
+        let mut message = wasm_name_t::default();
+        wasmtime_error_message(&err, &mut message);
+        panic!("{}", String::from_utf8_lossy(message.as_slice()));
+    }
+}
+
+struct Echo {
+    store: Box<wasmtime_store_t>,
+    instance: Instance,
+    host: Box<Host>,
+}
+
+impl Echo {
+    fn new() -> Echo {
+        let engine = wasm_engine_new();
+        let mut store = wasmtime_store_new(&engine, ptr::null_mut(), None);
+        let host = Box::<Host>::default();
+        let data = &*host as *const Host as *mut c_void;
+
+        let wasm = wat::parse_str(ECHO).unwrap();
+        let mut component = ptr::null_mut();
+        check(unsafe {
+            wasmtime_component_new(&engine, wasm.as_ptr(), wasm.len(), &mut component)
+        });
+        let component = unsafe { Box::from_raw(component) };
+
+        let mut linker = wasmtime_component_linker_new(&engine);
+        {
+            let mut root = wasmtime_component_linker_root(&mut linker);
+            check(unsafe {
+                wasmtime_component_linker_instance_add_resource(
+                    &mut root,
+                    "r".as_ptr(),
+                    1,
+                    destroy,
+                    data,
+                    None,
+                )
+            });
+            for name in ["echo-record", "echo-variant", "echo-list", "echo-resource"] {
+                check(unsafe {
+                    wasmtime_component_linker_instance_add_func(
+                        &mut root,
+                        &component,
+                        name.as_ptr(),
+                        name.len(),
+                        echo,
+                        data,
+                        None,
+                    )
+                });
+            }
+        }
+
+        let mut instance = MaybeUninit::<Instance>::uninit();
+        check(wasmtime_component_linker_instantiate(
+            &linker,
+            wasmtime_store_context(&mut store),
+            &component,
+            unsafe { &mut *instance.as_mut_ptr() },
+        ));
+        Echo {
+            store,
+            instance: unsafe { instance.assume_init() },
+            host,
+        }
+    }
+
+    fn func(&mut self, name: &str) -> Func {
+        let mut func = MaybeUninit::<Func>::uninit();
+        let found = unsafe {
+            wasmtime_component_instance_get_func(
+                &self.instance,
+                wasmtime_store_context(&mut self.store),
+                name.as_ptr(),
+                name.len(),
+                &mut *func.as_mut_ptr(),
+            )
+        };
+        assert!(found, "missing export {name}");
+        unsafe { func.assume_init() }
+    }
+
+    fn try_call(
+        &mut self,
+        name: &str,
+        arg: wasmtime_component_val_t,
+    ) -> Result<wasmtime_component_val_t, Box<wasmtime_error_t>> {
+        let func = self.func(name);
+        let mut result = MaybeUninit::uninit();
+        let err = unsafe {
+            wasmtime_component_func_call(
+                &func,
+                wasmtime_store_context(&mut self.store),
+                &arg,
+                1,
+                &mut result,
+                1,
+            )
+        };
+        match err {
+            None => Ok(unsafe { result.assume_init() }),
+            Some(err) => Err(err),
+        }
+    }
+
+    fn call(&mut self, name: &str, arg: wasmtime_component_val_t) -> wasmtime_component_val_t {
+        let calls = self.host.calls.load(SeqCst);
+        let result = self.try_call(name, arg).unwrap_or_else(|err| {
+            check(Some(err));
+            unreachable!()
+        });
+        assert_eq!(self.host.calls.load(SeqCst), calls + 1);
+        result
+    }
+}
+
+fn u32_val(u32: u32) -> wasmtime_component_val_t {
+    wasmtime_component_val_t {
+        kind: WASMTIME_COMPONENT_U32,
+        of: wasmtime_component_valunion_t { u32 },
+    }
+}
+
+fn string_val(s: &str) -> wasmtime_component_val_t {
+    wasmtime_component_val_t {
+        kind: WASMTIME_COMPONENT_STRING,
+        of: wasmtime_component_valunion_t {
+            string: ManuallyDrop::new(s.as_bytes().to_vec().into()),
+        },
+    }
+}
+
+fn variant_val(case: &str, val: Option<wasmtime_component_val_t>) -> wasmtime_component_val_t {
+    wasmtime_component_val_t {
+        kind: WASMTIME_COMPONENT_VARIANT,
+        of: wasmtime_component_valunion_t {
+            variant: ManuallyDrop::new(wasmtime_component_valvariant_t {
+                discriminant: case.as_bytes().to_vec().into(),
+                val: val.map(Box::new),
+            }),
+        },
+    }
+}
+
+fn as_u32(val: &wasmtime_component_val_t) -> u32 {
+    assert_eq!(val.kind, WASMTIME_COMPONENT_U32);
+    unsafe { val.of.u32 }
+}
+
+fn as_str(val: &wasmtime_component_val_t) -> &str {
+    assert_eq!(val.kind, WASMTIME_COMPONENT_STRING);
+    std::str::from_utf8(unsafe { val.of.string.as_slice() }).unwrap()
+}
+
+#[test]
+fn call_record() {
+    let mut echo = Echo::new();
+    let arg = wasmtime_component_val_t {
+        kind: WASMTIME_COMPONENT_RECORD,
+        of: wasmtime_component_valunion_t {
+            record: ManuallyDrop::new(
+                vec![
+                    wasmtime_component_valrecord_entry_t {
+                        name: b"a".to_vec().into(),
+                        val: u32_val(7),
+                    },
+                    wasmtime_component_valrecord_entry_t {
+                        name: b"b".to_vec().into(),
+                        val: string_val("hello"),
+                    },
+                ]
+                .into(),
+            ),
+        },
+    };
+    let result = echo.call("echo-record", arg);
+    assert_eq!(result.kind, WASMTIME_COMPONENT_RECORD);
+    let fields = unsafe { result.of.record.as_slice() };
+    assert_eq!(fields.len(), 2);
+    assert_eq!(fields[0].name.as_slice(), b"a");
+    assert_eq!(as_u32(&fields[0].val), 7);
+    assert_eq!(fields[1].name.as_slice(), b"b");
+    assert_eq!(as_str(&fields[1].val), "hello");
+}
+
+#[test]
+fn call_variant() {
+    let mut echo = Echo::new();
+
+    let result = echo.call("echo-variant", variant_val("none", None));
+    assert_eq!(result.kind, WASMTIME_COMPONENT_VARIANT);
+    let variant = unsafe { &result.of.variant };
+    assert_eq!(variant.discriminant.as_slice(), b"none");
+    assert!(variant.val.is_none());
+
+    let result = echo.call("echo-variant", variant_val("num", Some(u32_val(3))));
+    let variant = unsafe { &result.of.variant };
+    assert_eq!(variant.discriminant.as_slice(), b"num");
+    assert_eq!(as_u32(variant.val.as_ref().unwrap()), 3);
+
+    let result = echo.call("echo-variant", variant_val("str", Some(string_val("hi"))));
+    let variant = unsafe { &result.of.variant };
+    assert_eq!(variant.discriminant.as_slice(), b"str");
+    assert_eq!(as_str(variant.val.as_ref().unwrap()), "hi");
+
+    // An unknown case and a missing payload are both rejected before the
+    // host is ever called.
+    assert!(echo
+        .try_call("echo-variant", variant_val("nope", None))
+        .is_err());
+    assert!(echo
+        .try_call("echo-variant", variant_val("num", None))
+        .is_err());
+    assert_eq!(echo.host.calls.load(SeqCst), 3);
+}
+
+#[test]
+fn call_list() {
+    let mut echo = Echo::new();
+    let arg = wasmtime_component_val_t {
+        kind: WASMTIME_COMPONENT_LIST,
+        of: wasmtime_component_valunion_t {
+            list: ManuallyDrop::new(vec![u32_val(1), u32_val(2), u32_val(3)].into()),
+        },
+    };
+    let result = echo.call("echo-list", arg);
+    assert_eq!(result.kind, WASMTIME_COMPONENT_LIST);
+    let items = unsafe { result.of.list.as_slice() };
+    assert_eq!(items.iter().map(as_u32).collect::<Vec<_>>(), [1, 2, 3]);
+
+    let empty = wasmtime_component_val_t {
+        kind: WASMTIME_COMPONENT_LIST,
+        of: wasmtime_component_valunion_t {
+            list: ManuallyDrop::new(Vec::new().into()),
+        },
+    };
+    let result = echo.call("echo-list", empty);
+    assert!(unsafe { result.of.list.as_slice() }.is_empty());
+}
+
+fn resource_val(store: CStoreContextMut<'_>, rep: u32) -> wasmtime_component_val_t {
+    let mut resource = ptr::null_mut();
+    check(wasmtime_component_resource_host_new(
+        store,
+        rep,
+        &mut resource,
+    ));
+    let resource = unsafe { Box::from_raw(resource) };
+    assert!(wasmtime_component_resource_any_owned(&resource));
+    wasmtime_component_val_t {
+        kind: WASMTIME_COMPONENT_RESOURCE,
+        of: wasmtime_component_valunion_t {
+            resource: ManuallyDrop::new(Some(resource)),
+        },
+    }
+}
+
+#[test]
+fn call_resource() {
+    let mut echo = Echo::new();
+
+    // A resource which comes back from the guest can be turned back into its
+    // host representation, which takes it out of the store's table.
+    let arg = resource_val(wasmtime_store_context(&mut echo.store), 42);
+    let result = echo.call("echo-resource", arg);
+    assert_eq!(result.kind, WASMTIME_COMPONENT_RESOURCE);
+    let resource = unsafe { result.of.resource.as_ref().unwrap() };
+    assert!(wasmtime_component_resource_any_owned(resource));
+    let mut owned = false;
+    let mut rep = 0;
+    check(wasmtime_component_resource_any_to_host(
+        wasmtime_store_context(&mut echo.store),
+        resource,
+        &mut owned,
+        &mut rep,
+    ));
+    assert!(owned);
+    assert_eq!(rep, 42);
+    assert_eq!(echo.host.destroyed.load(SeqCst), 0);
+
+    // Dropping one instead runs the destructor registered with the linker.
+    let arg = resource_val(wasmtime_store_context(&mut echo.store), 7);
+    let result = echo.call("echo-resource", arg);
+    let resource = unsafe { result.of.resource.as_ref().unwrap() };
+    check(wasmtime_component_resource_any_drop(
+        wasmtime_store_context(&mut echo.store),
+        resource,
+    ));
+    assert_eq!(echo.host.destroyed.load(SeqCst), 7);
+}
+
+#[test]
+fn call_with_wrong_type() {
+    let mut echo = Echo::new();
+    assert!(echo.try_call("echo-record", u32_val(1)).is_err());
+    assert!(echo.try_call("echo-list", string_val("x")).is_err());
+    assert_eq!(echo.host.calls.load(SeqCst), 0);
+}
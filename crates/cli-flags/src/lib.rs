@@ -208,16 +208,29 @@ wasmtime_option_group! {
         /// the specification. Note that enabling this option may come at a
         /// performance cost.
         pub relaxed_simd_deterministic: Option<bool>,
+        /// Force deterministic execution, producing bit-identical results on
+        /// all hosts.
+        ///
+        /// This enables NaN canonicalization and deterministic relaxed-simd,
+        /// disables threads, and makes host memory allocation failures trap.
+        /// WASI clocks and random numbers are also made deterministic.
+        pub deterministic: Option<bool>,
         /// Configure support for the tail-call proposal.
         pub tail_call: Option<bool>,
         /// Configure support for the threads proposal.
         pub threads: Option<bool>,
         /// Configure support for the memory64 proposal.
         pub memory64: Option<bool>,
+        /// Configure support for the extended-const proposal.
+        pub extended_const: Option<bool>,
         /// Configure support for the component-model proposal.
         pub component_model: Option<bool>,
         /// Configure support for the function-references proposal.
         pub function_references: Option<bool>,
+        /// Configure support for the GC proposal.
+        pub gc: Option<bool>,
+        /// Configure support for the exception handling proposal.
+        pub exceptions: Option<bool>,
     }
 
     enum Wasm {
@@ -236,6 +249,12 @@ wasmtime_option_group! {
         pub threads: Option<bool>,
         /// Enable suport for WASI HTTP API (experimental)
         pub http: Option<bool>,
+        /// Enable support for WASI key-value API (experimental)
+        pub keyvalue: Option<bool>,
+        /// Persist `wasi:keyvalue` buckets as files in the given host
+        /// directory instead of keeping them in memory for the duration of
+        /// the run.
+        pub keyvalue_dir: Option<String>,
         /// Inherit environment variables and file descriptors following the
         /// systemd listen fd specification (UNIX only)
         pub listenfd: Option<bool>,
@@ -256,7 +275,8 @@ wasmtime_option_group! {
         /// directory name: e.g., `--wasi-nn-graph openvino:/foo/bar` will preload
         /// an OpenVINO model named `bar`. Note that which model encodings are
         /// available is dependent on the backends implemented in the
-        /// `wasmtime_wasi_nn` crate.
+        /// `wasmtime_wasi_nn` crate; e.g., `onnx::/foo/bar` requires the
+        /// `wasi-nn-onnx` feature and loads `/foo/bar/model.onnx`.
         pub nn_graph: Vec<WasiNnGraph>,
         /// Flag for WASI preview2 to inherit the host's network within the
         /// guest so it has full access to all addresses/ports/etc.
@@ -393,6 +413,11 @@ impl CommonOptions {
         self.configure();
         let mut config = Config::new();
 
+        // Applied first so that explicitly contradicting flags below are
+        // reported as errors rather than silently overridden.
+        if let Some(enable) = self.wasm.deterministic {
+            config.deterministic(enable);
+        }
         match_feature! {
             ["cranelift" : self.codegen.compiler]
             strategy => config.strategy(strategy),
@@ -572,6 +597,12 @@ impl CommonOptions {
         if let Some(enable) = self.wasm.function_references.or(all) {
             config.wasm_function_references(enable);
         }
+        if let Some(enable) = self.wasm.gc.or(all) {
+            config.wasm_gc(enable);
+        }
+        if let Some(enable) = self.wasm.exceptions.or(all) {
+            config.wasm_exceptions(enable);
+        }
         if let Some(enable) = self.wasm.multi_value.or(all) {
             config.wasm_multi_value(enable);
         }
@@ -587,6 +618,9 @@ impl CommonOptions {
         if let Some(enable) = self.wasm.memory64.or(all) {
             config.wasm_memory64(enable);
         }
+        if let Some(enable) = self.wasm.extended_const.or(all) {
+            config.wasm_extended_const(enable);
+        }
         if let Some(enable) = self.wasm.component_model.or(all) {
             #[cfg(feature = "component-model")]
             config.wasm_component_model(enable);
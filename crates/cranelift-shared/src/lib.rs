@@ -66,6 +66,14 @@ pub const ALWAYS_TRAP_CODE: u16 = 100;
 /// adapters to provide a more useful error message in such situations.
 pub const CANNOT_ENTER_CODE: u16 = 101;
 
+/// A custom code with `TrapCode::User` raised when a `ref.cast` instruction
+/// fails because its operand is not of the target type.
+pub const CAST_FAILURE_CODE: u16 = 102;
+
+/// A custom code with `TrapCode::User` raised by out-of-bounds accesses to GC
+/// arrays.
+pub const ARRAY_OUT_OF_BOUNDS_CODE: u16 = 103;
+
 /// Converts machine traps to trap information.
 pub fn mach_trap_to_trap(trap: &MachTrap) -> Option<TrapInformation> {
     let &MachTrap { offset, code } = trap;
@@ -85,6 +93,8 @@ pub fn mach_trap_to_trap(trap: &MachTrap) -> Option<TrapInformation> {
             ir::TrapCode::Interrupt => Trap::Interrupt,
             ir::TrapCode::User(ALWAYS_TRAP_CODE) => Trap::AlwaysTrapAdapter,
             ir::TrapCode::User(CANNOT_ENTER_CODE) => Trap::CannotEnterComponent,
+            ir::TrapCode::User(CAST_FAILURE_CODE) => Trap::CastFailure,
+            ir::TrapCode::User(ARRAY_OUT_OF_BOUNDS_CODE) => Trap::ArrayOutOfBounds,
             ir::TrapCode::NullReference => Trap::NullReference,
 
             // These do not get converted to wasmtime traps, since they
@@ -208,6 +208,9 @@ impl wasmtime_environ::Compiler for Compiler {
         });
         context.func.stack_limit = Some(stack_limit);
         let FunctionBodyData { validator, body } = input;
+        if self.tunables.guest_debug {
+            func_env.declare_debug_locals(wasm_func_ty, &body)?;
+        }
         let mut validator =
             validator.into_validator(mem::take(&mut compiler.cx.validator_allocations));
         compiler.cx.func_translator.translate_body(
@@ -228,7 +231,9 @@ impl wasmtime_environ::Compiler for Compiler {
             write!(output, "{}", context.func.display()).unwrap();
         }
 
-        let (info, func) = compiler.finish_with_info(Some((&body, &self.tunables)))?;
+        let coverage = func_env.take_coverage_blocks();
+        let (mut info, func) = compiler.finish_with_info(Some((&body, &self.tunables)))?;
+        info.coverage = coverage.into();
 
         let timing = cranelift_codegen::timing::take_current();
         log::debug!("{:?} translated in {:?}", func_index, timing.total());
@@ -1094,6 +1099,7 @@ impl FunctionCompiler<'_> {
             WasmFunctionInfo {
                 start_srcloc: compiled_function.metadata().address_map.start_srcloc,
                 stack_maps: stack_maps.into(),
+                coverage: Default::default(),
             },
             compiled_function,
         ))
@@ -13,18 +13,24 @@ use cranelift_entity::{EntityRef, PrimaryMap};
 use cranelift_frontend::FunctionBuilder;
 use cranelift_frontend::Variable;
 use cranelift_wasm::{
-    self, FuncIndex, FuncTranslationState, GlobalIndex, GlobalVariable, Heap, HeapData, HeapStyle,
-    MemoryIndex, TableIndex, TargetEnvironment, TypeIndex, WasmHeapType, WasmRefType, WasmResult,
-    WasmType,
+    self, DataIndex, ElemIndex, FuncIndex, FuncTranslationState, GlobalIndex, GlobalVariable, Heap,
+    HeapData, HeapStyle, MemoryIndex, SignatureIndex, TableIndex, TagIndex, TargetEnvironment,
+    TypeIndex, WasmFuncType, WasmHeapType, WasmRefType, WasmResult, WasmType,
 };
 use std::convert::TryFrom;
 use std::mem;
-use wasmparser::Operator;
+use wasmparser::{FunctionBody, Operator};
+use wasmtime_cranelift_shared::{ARRAY_OUT_OF_BOUNDS_CODE, CAST_FAILURE_CODE};
 use wasmtime_environ::{
-    BuiltinFunctionIndex, MemoryPlan, MemoryStyle, Module, ModuleTranslation, ModuleTypesBuilder,
-    PtrSize, TableStyle, Tunables, TypeConvert, VMOffsets, WASM_PAGE_SIZE,
+    BuiltinFunctionIndex, DebugValType, FilePos, GcLayout, GcStorage, GcTypeIndex, MemoryPlan,
+    MemoryStyle, Module, ModuleTranslation, ModuleType, ModuleTypesBuilder, PtrSize, TableStyle,
+    Tunables, TypeConvert, VMOffsets, WasmGcType, WASM_PAGE_SIZE,
+};
+use wasmtime_environ::{
+    ANYREF_STACK_TAG, DEBUG_SLOT_SIZE, DEBUG_SLOT_TYPE_OFFSET, FUNCREF_INIT_BIT, FUNCREF_MASK,
+    GC_ARRAY_ELEMS_OFFSET, GC_ARRAY_LENGTH_OFFSET, GC_HEADER_ARRAY_BIT, GC_HEADER_SIZE_OFFSET,
+    I31_TAG,
 };
-use wasmtime_environ::{FUNCREF_INIT_BIT, FUNCREF_MASK};
 
 macro_rules! declare_function_signatures {
     (
@@ -161,6 +167,25 @@ pub struct FuncEnvironment<'module_environment> {
 
     fuel_consumed: i64,
 
+    /// The types of the current function's locals, including its parameters,
+    /// when guest debugging is enabled.
+    debug_locals: Vec<DebugValType>,
+
+    /// The stack slot which the wasm-level state of the current function is
+    /// stored in when it stops at a breakpoint, created on first use.
+    debug_slot: Option<ir::StackSlot>,
+
+    /// The pointer to the coverage counters of the current function, loaded
+    /// on function entry when coverage is enabled.
+    coverage_counters: Option<ir::Value>,
+
+    /// The wasm offset of the first instruction of each basic block which
+    /// counts its entries so far, in the order of the counters.
+    coverage_blocks: Vec<FilePos>,
+
+    /// Whether the next reachable instruction starts a new basic block.
+    coverage_block_start: bool,
+
     #[cfg(feature = "wmemcheck")]
     wmemcheck: bool,
 }
@@ -206,11 +231,46 @@ impl<'module_environment> FuncEnvironment<'module_environment> {
             // Start with at least one fuel being consumed because even empty
             // functions should consume at least some fuel.
             fuel_consumed: 1,
+            debug_locals: Vec::new(),
+            debug_slot: None,
+            coverage_counters: None,
+            coverage_blocks: Vec::new(),
+            coverage_block_start: true,
             #[cfg(feature = "wmemcheck")]
             wmemcheck,
         }
     }
 
+    /// Declares the types of the locals of the function with type `ty` and
+    /// body `body` which is about to be translated, which is required when
+    /// guest debugging is enabled.
+    pub fn declare_debug_locals(
+        &mut self,
+        ty: &WasmFuncType,
+        body: &FunctionBody<'_>,
+    ) -> WasmResult<()> {
+        self.debug_locals = ty
+            .params()
+            .iter()
+            .map(DebugValType::from_wasm_type)
+            .collect();
+        let mut reader = body.get_locals_reader()?;
+        for _ in 0..reader.get_count() {
+            let (count, ty) = reader.read()?;
+            let ty = DebugValType::from_wasm_type(&self.convert_valtype(ty));
+            self.debug_locals
+                .extend(std::iter::repeat(ty).take(usize::try_from(count).unwrap()));
+        }
+        Ok(())
+    }
+
+    /// Returns the wasm offset of the first instruction of each basic block
+    /// of the translated function which counts its entries, in the order of
+    /// the counters, when coverage is enabled.
+    pub fn take_coverage_blocks(&mut self) -> Vec<FilePos> {
+        mem::take(&mut self.coverage_blocks)
+    }
+
     fn pointer_type(&self) -> ir::Type {
         self.isa.pointer_type()
     }
@@ -382,6 +442,67 @@ impl<'module_environment> FuncEnvironment<'module_environment> {
         }
     }
 
+    /// Returns the address of the definition of the tag `index`, which is the
+    /// identity of the tag.
+    fn tag_addr(&mut self, builder: &mut FunctionBuilder<'_>, index: TagIndex) -> ir::Value {
+        let pointer_type = self.pointer_type();
+        let vmctx = self.vmctx(builder.func);
+        let base = builder.ins().global_value(pointer_type, vmctx);
+        match self.module.defined_tag_index(index) {
+            Some(def_index) => {
+                let offset = self.offsets.vmctx_vmtag_definition(def_index);
+                builder.ins().iadd_imm(base, i64::from(offset))
+            }
+            None => {
+                let offset = i32::try_from(self.offsets.vmctx_vmtag_import_from(index)).unwrap();
+                builder.ins().load(
+                    pointer_type,
+                    MemFlags::trusted().with_readonly(),
+                    base,
+                    offset,
+                )
+            }
+        }
+    }
+
+    /// Returns a pointer to a new stack slot holding the payload of an
+    /// exception with the tag `index`, laid out like the arguments of an
+    /// array call, or null if the payload is empty.
+    fn exception_payload_slot(
+        &mut self,
+        builder: &mut FunctionBuilder<'_>,
+        index: TagIndex,
+    ) -> (ir::Value, &'module_environment WasmFuncType) {
+        let pointer_type = self.pointer_type();
+        let ty = &self.types[self.module.tags[index].signature];
+        if ty.params().is_empty() {
+            return (builder.ins().iconst(pointer_type, 0), ty);
+        }
+        let size = u32::try_from(ty.params().len() * mem::size_of::<u128>()).unwrap();
+        let slot = builder.func.create_sized_stack_slot(ir::StackSlotData::new(
+            ir::StackSlotKind::ExplicitSlot,
+            size,
+        ));
+        (builder.ins().stack_addr(pointer_type, slot, 0), ty)
+    }
+
+    /// Calls the builtin `index` with the signature `sig` and arguments
+    /// `args`, following the `vmctx` argument.
+    fn call_builtin(
+        &mut self,
+        builder: &mut FunctionBuilder<'_>,
+        index: BuiltinFunctionIndex,
+        sig: ir::SigRef,
+        args: &[ir::Value],
+    ) -> ir::Inst {
+        let (vmctx, addr) =
+            self.translate_load_builtin_function_address(&mut builder.cursor(), index);
+        let args = std::iter::once(vmctx)
+            .chain(args.iter().copied())
+            .collect::<Vec<_>>();
+        builder.ins().call_indirect(sig, addr, &args)
+    }
+
     fn declare_vmruntime_limits_ptr(&mut self, builder: &mut FunctionBuilder<'_>) {
         // We load the `*const VMRuntimeLimits` value stored within vmctx at the
         // head of the function and reuse the same value across the entire
@@ -398,6 +519,85 @@ impl<'module_environment> FuncEnvironment<'module_environment> {
         builder.def_var(self.vmruntime_limits_ptr, interrupt_ptr);
     }
 
+    fn coverage_function_entry(&mut self, builder: &mut FunctionBuilder<'_>) {
+        // The counters of each defined function are found through an array
+        // of pointers in the vmctx, and the current function's pointer is
+        // loaded up front since it never changes.
+        let func_index = match &builder.func.name {
+            UserFuncName::User(user) => FuncIndex::from_u32(user.index),
+            _ => panic!("function name not a UserFuncName::User as expected"),
+        };
+        let def_index = self.module.defined_func_index(func_index).unwrap();
+        let pointer_type = self.pointer_type();
+        let vmctx = self.vmctx(builder.func);
+        let base = builder.ins().global_value(pointer_type, vmctx);
+        let flags = ir::MemFlags::trusted().with_readonly();
+        let offset = i32::try_from(self.offsets.vmctx_coverage_counters()).unwrap();
+        let counters = builder.ins().load(pointer_type, flags, base, offset);
+        let offset =
+            i32::try_from(def_index.as_u32() * u32::from(self.offsets.ptr.size())).unwrap();
+        let counters = builder.ins().load(pointer_type, flags, counters, offset);
+        self.coverage_counters = Some(counters);
+    }
+
+    fn coverage_before_op(&mut self, builder: &mut FunctionBuilder<'_>, reachable: bool) {
+        // Count the entries of a basic block with its first reachable
+        // instruction. The counters are shared by all instances of the module,
+        // which may run on different threads, so they're incremented
+        // atomically.
+        if reachable && mem::replace(&mut self.coverage_block_start, false) {
+            let offset = i64::try_from(self.coverage_blocks.len() * 8).unwrap();
+            self.coverage_blocks
+                .push(FilePos::new(builder.srcloc().bits()));
+            let counters = self.coverage_counters.unwrap();
+            let counter = builder.ins().iadd_imm(counters, offset);
+            let one = builder.ins().iconst(ir::types::I64, 1);
+            builder.ins().atomic_rmw(
+                ir::types::I64,
+                ir::MemFlags::trusted(),
+                ir::AtomicRmwOp::Add,
+                counter,
+                one,
+            );
+        }
+    }
+
+    fn coverage_after_op(&mut self, op: &Operator<'_>) {
+        // These instructions are followed by a new basic block, either
+        // because they branch, because they don't fall through at all or
+        // because they are a branch target.
+        match op {
+            Operator::Loop { .. }
+            | Operator::If { .. }
+            | Operator::Else
+            | Operator::End
+            | Operator::TryTable { .. }
+            | Operator::Br { .. }
+            | Operator::BrIf { .. }
+            | Operator::BrTable { .. }
+            | Operator::BrOnNull { .. }
+            | Operator::BrOnNonNull { .. }
+            | Operator::BrOnCast { .. }
+            | Operator::BrOnCastFail { .. }
+            | Operator::Unreachable
+            | Operator::Return
+            | Operator::ReturnCall { .. }
+            | Operator::ReturnCallIndirect { .. }
+            | Operator::ReturnCallRef { .. }
+            | Operator::Throw { .. }
+            | Operator::ThrowRef => self.coverage_block_start = true,
+
+            // With exceptions the rest of a block isn't executed when a call
+            // throws, so calls end blocks too.
+            Operator::Call { .. } | Operator::CallIndirect { .. } | Operator::CallRef { .. }
+                if self.tunables.exceptions =>
+            {
+                self.coverage_block_start = true
+            }
+            _ => {}
+        }
+    }
+
     fn fuel_function_entry(&mut self, builder: &mut FunctionBuilder<'_>) {
         // On function entry we load the amount of fuel into a function-local
         // `self.fuel_var` to make fuel modifications fast locally. This cache
@@ -852,6 +1052,317 @@ impl<'module_environment> FuncEnvironment<'module_environment> {
         }
     }
 
+    /// Returns an `i8` that is non-zero if the non-null `func_ref` has the
+    /// signature `sig_index` of this module.
+    fn func_ref_has_signature(
+        &mut self,
+        builder: &mut FunctionBuilder,
+        func_ref: ir::Value,
+        sig_index: SignatureIndex,
+    ) -> ir::Value {
+        let pointer_type = self.pointer_type();
+        let sig_id_size = self.offsets.size_of_vmshared_signature_index();
+        let sig_id_type = Type::int(u16::from(sig_id_size) * 8).unwrap();
+        let vmctx = self.vmctx(builder.func);
+        let base = builder.ins().global_value(pointer_type, vmctx);
+
+        // Load the expected ID. This requires loading the
+        // `*mut VMSharedSignatureIndex` base pointer from `VMContext` and then
+        // loading, based on `SignatureIndex`, the corresponding entry.
+        let mem_flags = ir::MemFlags::trusted().with_readonly();
+        let signatures = builder.ins().load(
+            pointer_type,
+            mem_flags,
+            base,
+            i32::try_from(self.offsets.vmctx_signature_ids_array()).unwrap(),
+        );
+        let offset =
+            i32::try_from(sig_index.as_u32().checked_mul(sig_id_type.bytes()).unwrap()).unwrap();
+        let expected_sig_id = builder
+            .ins()
+            .load(sig_id_type, mem_flags, signatures, offset);
+
+        // Load the ID of the function.
+        let actual_sig_id = builder.ins().load(
+            sig_id_type,
+            mem_flags,
+            func_ref,
+            i32::from(self.offsets.ptr.vm_func_ref_type_index()),
+        );
+
+        builder
+            .ins()
+            .icmp(IntCC::Equal, actual_sig_id, expected_sig_id)
+    }
+
+    /// Converts the `any` reference `value`, held in its tagged form, to the
+    /// `i32` it represents.
+    fn anyref_to_raw(&mut self, pos: &mut FuncCursor, value: ir::Value) -> ir::Value {
+        let bits = pos.ins().bitcast(I64, MemFlags::new(), value);
+        let bits = pos.ins().ushr_imm(bits, 1);
+        pos.ins().ireduce(I32, bits)
+    }
+
+    /// Converts the `i32` `any` reference `raw` to its tagged form, see
+    /// `wasmtime_environ::anyref_to_stack`.
+    fn anyref_from_raw(&mut self, pos: &mut FuncCursor, raw: ir::Value) -> ir::Value {
+        let is_non_null = pos.ins().icmp_imm(IntCC::NotEqual, raw, 0);
+        let tag = pos.ins().uextend(I64, is_non_null);
+        self.anyref_from_raw_with_tag(pos, raw, tag)
+    }
+
+    /// Like `anyref_from_raw` for `raw` which is known not to be `null`.
+    fn anyref_from_non_null_raw(&mut self, pos: &mut FuncCursor, raw: ir::Value) -> ir::Value {
+        let tag = pos.ins().iconst(I64, ANYREF_STACK_TAG as i64);
+        self.anyref_from_raw_with_tag(pos, raw, tag)
+    }
+
+    fn anyref_from_raw_with_tag(
+        &mut self,
+        pos: &mut FuncCursor,
+        raw: ir::Value,
+        tag: ir::Value,
+    ) -> ir::Value {
+        let bits = pos.ins().uextend(I64, raw);
+        let bits = pos.ins().ishl_imm(bits, 1);
+        let bits = pos.ins().bor(bits, tag);
+        let ty = self.reference_type(WasmHeapType::Any);
+        pos.ins().bitcast(ty, MemFlags::new(), bits)
+    }
+
+    /// Returns the address of the GC object `raw` in the store's GC heap.
+    ///
+    /// The heap moves when it grows, so the address must not be used across
+    /// anything which may allocate.
+    fn gc_object_addr(&mut self, pos: &mut FuncCursor, raw: ir::Value) -> ir::Value {
+        let pointer_type = self.pointer_type();
+        let vmctx = self.vmctx(&mut pos.func);
+        let base = pos.ins().global_value(pointer_type, vmctx);
+        let offset = i32::try_from(self.offsets.vmctx_runtime_limits()).unwrap();
+        let limits = pos.ins().load(
+            pointer_type,
+            MemFlags::trusted().with_readonly(),
+            base,
+            offset,
+        );
+        let offset = i32::from(self.offsets.ptr.vmruntime_limits_gc_heap_base());
+        let heap_base = pos
+            .ins()
+            .load(pointer_type, MemFlags::trusted(), limits, offset);
+        let raw = pos.ins().uextend(pointer_type, raw);
+        pos.ins().iadd(heap_base, raw)
+    }
+
+    /// Returns the `i32` representation of the `struct` or `array` reference
+    /// `value`, trapping if it's null.
+    fn gc_object_non_null(&mut self, builder: &mut FunctionBuilder, value: ir::Value) -> ir::Value {
+        let raw = self.anyref_to_raw(&mut builder.cursor(), value);
+        builder.ins().trapz(raw, ir::TrapCode::NullReference);
+        raw
+    }
+
+    /// Returns the type and layout of the `struct` or `array` type `index`.
+    fn gc_type(
+        &self,
+        index: TypeIndex,
+    ) -> (GcTypeIndex, &'module_environment WasmGcType, GcLayout) {
+        let index = match self.module.types[index] {
+            ModuleType::Struct(index) | ModuleType::Array(index) => index,
+            ModuleType::Function(_) => unreachable!("validation checks for GC types"),
+        };
+        let ty = &self.types[index];
+        (index, ty, GcLayout::new(ty))
+    }
+
+    /// Returns the flags of accesses to GC objects, which are stored in
+    /// little-endian order like `ValRaw`s.
+    fn gc_mem_flags() -> MemFlags {
+        MemFlags::trusted().with_endianness(ir::Endianness::Little)
+    }
+
+    /// Loads the field or element stored as `storage` at `addr`, extending
+    /// packed values as `signed` says.
+    fn gc_load(
+        &mut self,
+        pos: &mut FuncCursor,
+        storage: GcStorage,
+        signed: bool,
+        addr: ir::Value,
+        offset: i32,
+    ) -> ir::Value {
+        let flags = Self::gc_mem_flags();
+        match storage {
+            GcStorage::I8 if signed => pos.ins().sload8(I32, flags, addr, offset),
+            GcStorage::I8 => pos.ins().uload8(I32, flags, addr, offset),
+            GcStorage::I16 if signed => pos.ins().sload16(I32, flags, addr, offset),
+            GcStorage::I16 => pos.ins().uload16(I32, flags, addr, offset),
+            GcStorage::I32 => pos.ins().load(I32, flags, addr, offset),
+            GcStorage::I64 => pos.ins().load(I64, flags, addr, offset),
+            GcStorage::F32 => pos.ins().load(F32, flags, addr, offset),
+            GcStorage::F64 => pos.ins().load(F64, flags, addr, offset),
+            GcStorage::V128 => pos.ins().load(I8X16, flags, addr, offset),
+            GcStorage::AnyRef => {
+                let raw = pos.ins().load(I32, flags, addr, offset);
+                self.anyref_from_raw(pos, raw)
+            }
+            GcStorage::FuncRef => pos.ins().load(self.pointer_type(), flags, addr, offset),
+        }
+    }
+
+    /// Stores `value` as `storage` at `addr`.
+    fn gc_store(
+        &mut self,
+        pos: &mut FuncCursor,
+        storage: GcStorage,
+        value: ir::Value,
+        addr: ir::Value,
+        offset: i32,
+    ) {
+        let flags = Self::gc_mem_flags();
+        match storage {
+            GcStorage::I8 => pos.ins().istore8(flags, value, addr, offset),
+            GcStorage::I16 => pos.ins().istore16(flags, value, addr, offset),
+            GcStorage::AnyRef => {
+                let raw = self.anyref_to_raw(pos, value);
+                pos.ins().store(flags, raw, addr, offset)
+            }
+            GcStorage::I32
+            | GcStorage::I64
+            | GcStorage::F32
+            | GcStorage::F64
+            | GcStorage::V128
+            | GcStorage::FuncRef => pos.ins().store(flags, value, addr, offset),
+        };
+    }
+
+    /// Allocates a zeroed object of the type `index`, which has `len`
+    /// elements if it's an array, returning its `i32` representation.
+    fn gc_alloc(
+        &mut self,
+        builder: &mut FunctionBuilder,
+        index: GcTypeIndex,
+        len: ir::Value,
+    ) -> ir::Value {
+        // The allocation may collect garbage, which finds the references held
+        // by this function, such as the values of the fields to initialize,
+        // through the stack map of this call.
+        let ty = builder.ins().iconst(I32, i64::from(index.as_u32()));
+        let sig = self.builtin_function_signatures.gc_alloc(builder.func);
+        let call = self.call_builtin(builder, BuiltinFunctionIndex::gc_alloc(), sig, &[ty, len]);
+        builder.func.dfg.first_result(call)
+    }
+
+    /// Stores `value` as `storage` in the `len` consecutive elements starting
+    /// at `addr`.
+    fn gc_fill(
+        &mut self,
+        builder: &mut FunctionBuilder,
+        storage: GcStorage,
+        addr: ir::Value,
+        len: ir::Value,
+        value: ir::Value,
+    ) {
+        let pointer_type = self.pointer_type();
+        let elem_size = i64::from(storage.size());
+        let len = builder.ins().uextend(pointer_type, len);
+        let size = builder.ins().imul_imm(len, elem_size);
+        let end = builder.ins().iadd(addr, size);
+
+        let header_block = builder.create_block();
+        let body_block = builder.create_block();
+        let continue_block = builder.create_block();
+        let elem = builder.append_block_param(header_block, pointer_type);
+        builder.ins().jump(header_block, &[addr]);
+
+        builder.switch_to_block(header_block);
+        let done = builder.ins().icmp(IntCC::Equal, elem, end);
+        builder
+            .ins()
+            .brif(done, continue_block, &[], body_block, &[]);
+
+        builder.seal_block(body_block);
+        builder.switch_to_block(body_block);
+        self.gc_store(&mut builder.cursor(), storage, value, elem, 0);
+        let next = builder.ins().iadd_imm(elem, elem_size);
+        builder.ins().jump(header_block, &[next]);
+
+        builder.seal_block(header_block);
+        builder.seal_block(continue_block);
+        builder.switch_to_block(continue_block);
+    }
+
+    /// Returns the address of the element `index` of the non-null array
+    /// `raw`, which is stored as `storage`, trapping if it's out of bounds.
+    fn gc_array_elem_addr(
+        &mut self,
+        builder: &mut FunctionBuilder,
+        storage: GcStorage,
+        raw: ir::Value,
+        index: ir::Value,
+    ) -> ir::Value {
+        let pointer_type = self.pointer_type();
+        let array = self.gc_object_addr(&mut builder.cursor(), raw);
+        let len = builder.ins().load(
+            I32,
+            Self::gc_mem_flags(),
+            array,
+            GC_ARRAY_LENGTH_OFFSET as i32,
+        );
+        let out_of_bounds = builder
+            .ins()
+            .icmp(IntCC::UnsignedGreaterThanOrEqual, index, len);
+        builder
+            .ins()
+            .trapnz(out_of_bounds, ir::TrapCode::User(ARRAY_OUT_OF_BOUNDS_CODE));
+        let index = builder.ins().uextend(pointer_type, index);
+        let offset = builder.ins().imul_imm(index, i64::from(storage.size()));
+        let elem = builder.ins().iadd(array, offset);
+        builder
+            .ins()
+            .iadd_imm(elem, i64::from(GC_ARRAY_ELEMS_OFFSET))
+    }
+
+    /// Returns an `i32` that is non-zero if the non-null and non-`i31`
+    /// reference `raw` is an object of the heap type `ty`.
+    fn gc_object_has_type(
+        &mut self,
+        builder: &mut FunctionBuilder,
+        raw: ir::Value,
+        ty: WasmHeapType,
+    ) -> ir::Value {
+        match ty {
+            WasmHeapType::Struct | WasmHeapType::Array => {
+                let object = self.gc_object_addr(&mut builder.cursor(), raw);
+                let size = builder.ins().load(
+                    I32,
+                    Self::gc_mem_flags(),
+                    object,
+                    GC_HEADER_SIZE_OFFSET as i32,
+                );
+                let is_array = builder.ins().band_imm(size, i64::from(GC_HEADER_ARRAY_BIT));
+                if ty == WasmHeapType::Array {
+                    is_array
+                } else {
+                    builder
+                        .ins()
+                        .bxor_imm(is_array, i64::from(GC_HEADER_ARRAY_BIT))
+                }
+            }
+            WasmHeapType::TypedStruct(index) | WasmHeapType::TypedArray(index) => {
+                let ty = builder.ins().iconst(I32, i64::from(index.as_u32()));
+                let sig = self.builtin_function_signatures.gc_ref_test(builder.func);
+                let call = self.call_builtin(
+                    builder,
+                    BuiltinFunctionIndex::gc_ref_test(),
+                    sig,
+                    &[raw, ty],
+                );
+                builder.func.dfg.first_result(call)
+            }
+            _ => unreachable!(),
+        }
+    }
+
     fn get_or_init_func_ref_table_elem(
         &mut self,
         builder: &mut FunctionBuilder,
@@ -927,6 +1438,91 @@ impl<'module_environment> FuncEnvironment<'module_environment> {
             .call_indirect(free_start_sig, free_start, &[vmctx]);
     }
 
+    /// Stops at a breakpoint before the instruction which is about to be
+    /// translated if the store has requested so, passing the wasm-level state
+    /// of the function to the `debug_break` builtin.
+    fn debug_break(&mut self, builder: &mut FunctionBuilder<'_>, state: &FuncTranslationState) {
+        let break_block = builder.create_block();
+        let continuation_block = builder.create_block();
+        builder.set_cold_block(break_block);
+
+        let limits = builder.use_var(self.vmruntime_limits_ptr);
+        let offset = i32::from(self.offsets.ptr.vmruntime_limits_debug_break());
+        let flag = builder
+            .ins()
+            .load(ir::types::I8, MemFlags::trusted(), limits, offset);
+        builder
+            .ins()
+            .brif(flag, break_block, &[], continuation_block, &[]);
+        builder.seal_block(break_block);
+
+        builder.switch_to_block(break_block);
+        let mut values = Vec::with_capacity(self.debug_locals.len() + state.stack().len());
+        for (i, ty) in self.debug_locals.iter().enumerate() {
+            values.push((builder.use_var(Variable::new(i)), *ty));
+        }
+        debug_assert_eq!(state.stack().len(), state.operand_types().len());
+        for (value, ty) in state.stack().iter().zip(state.operand_types()) {
+            let ty = ty.expect("operand of an unknown type in reachable code");
+            let ty = DebugValType::from_wasm_type(&self.convert_valtype(ty));
+            values.push((*value, ty));
+        }
+
+        let pointer_type = self.pointer_type();
+        let slot_ptr = if values.is_empty() {
+            builder.ins().iconst(pointer_type, 0)
+        } else {
+            let size = u32::try_from(values.len()).unwrap() * DEBUG_SLOT_SIZE;
+            let slot = match self.debug_slot {
+                Some(slot) => {
+                    let data = &mut builder.func.sized_stack_slots[slot];
+                    data.size = data.size.max(size);
+                    slot
+                }
+                None => builder.func.create_sized_stack_slot(ir::StackSlotData::new(
+                    ir::StackSlotKind::ExplicitSlot,
+                    size,
+                )),
+            };
+            self.debug_slot = Some(slot);
+            builder.ins().stack_addr(pointer_type, slot, 0)
+        };
+        let flags = MemFlags::new()
+            .with_notrap()
+            .with_endianness(ir::Endianness::Little);
+        for (i, (value, ty)) in values.iter().enumerate() {
+            let offset = i32::try_from(i).unwrap() * DEBUG_SLOT_SIZE as i32;
+            builder.ins().store(flags, *value, slot_ptr, offset);
+            let ty = builder.ins().iconst(ir::types::I8, *ty as i64);
+            builder
+                .ins()
+                .store(flags, ty, slot_ptr, offset + DEBUG_SLOT_TYPE_OFFSET as i32);
+        }
+
+        let func_index = match &builder.func.name {
+            UserFuncName::User(user) => user.index,
+            _ => panic!("function name not a UserFuncName::User as expected"),
+        };
+        let offset = builder.srcloc().bits();
+        let args = [
+            builder.ins().iconst(ir::types::I32, i64::from(func_index)),
+            builder.ins().iconst(ir::types::I32, i64::from(offset)),
+            slot_ptr,
+            builder
+                .ins()
+                .iconst(ir::types::I32, self.debug_locals.len() as i64),
+            builder
+                .ins()
+                .iconst(ir::types::I32, state.stack().len() as i64),
+        ];
+        let sig = self.builtin_function_signatures.debug_break(builder.func);
+        self.call_builtin(builder, BuiltinFunctionIndex::debug_break(), sig, &args);
+        builder.ins().jump(continuation_block, &[]);
+        builder.seal_block(continuation_block);
+
+        builder.switch_to_block(continuation_block);
+    }
+
     fn current_func_name(&self, builder: &mut FunctionBuilder) -> Option<&str> {
         let func_index = match &builder.func.name {
             UserFuncName::User(user) => FuncIndex::from_u32(user.index),
@@ -1052,8 +1648,6 @@ impl<'a, 'func, 'module_env> Call<'a, 'func, 'module_env> {
         callee: ir::Value,
         call_args: &[ir::Value],
     ) -> WasmResult<ir::Inst> {
-        let pointer_type = self.env.pointer_type();
-
         // Get the funcref pointer from the table.
         let funcref_ptr =
             self.env
@@ -1067,44 +1661,10 @@ impl<'a, 'func, 'module_env> Call<'a, 'func, 'module_env> {
         // If necessary, check the signature.
         match self.env.module.table_plans[table_index].style {
             TableStyle::CallerChecksSignature => {
-                let sig_id_size = self.env.offsets.size_of_vmshared_signature_index();
-                let sig_id_type = Type::int(u16::from(sig_id_size) * 8).unwrap();
-                let vmctx = self.env.vmctx(self.builder.func);
-                let base = self.builder.ins().global_value(pointer_type, vmctx);
-
-                // Load the caller ID. This requires loading the `*mut
-                // VMFuncRef` base pointer from `VMContext` and then loading,
-                // based on `SignatureIndex`, the corresponding entry.
-                let mem_flags = ir::MemFlags::trusted().with_readonly();
-                let signatures = self.builder.ins().load(
-                    pointer_type,
-                    mem_flags,
-                    base,
-                    i32::try_from(self.env.offsets.vmctx_signature_ids_array()).unwrap(),
-                );
                 let sig_index = self.env.module.types[ty_index].unwrap_function();
-                let offset =
-                    i32::try_from(sig_index.as_u32().checked_mul(sig_id_type.bytes()).unwrap())
-                        .unwrap();
-                let caller_sig_id =
-                    self.builder
-                        .ins()
-                        .load(sig_id_type, mem_flags, signatures, offset);
-
-                // Load the callee ID.
-                let mem_flags = ir::MemFlags::trusted().with_readonly();
-                let callee_sig_id = self.builder.ins().load(
-                    sig_id_type,
-                    mem_flags,
-                    funcref_ptr,
-                    i32::from(self.env.offsets.ptr.vm_func_ref_type_index()),
-                );
-
-                // Check that they match.
                 let cmp = self
-                    .builder
-                    .ins()
-                    .icmp(IntCC::Equal, callee_sig_id, caller_sig_id);
+                    .env
+                    .func_ref_has_signature(self.builder, funcref_ptr, sig_index);
                 self.builder.ins().trapz(cmp, ir::TrapCode::BadSignature);
             }
         }
@@ -1316,19 +1876,24 @@ impl<'module_environment> cranelift_wasm::FuncEnvironment for FuncEnvironment<'m
         delta: ir::Value,
         init_value: ir::Value,
     ) -> WasmResult<ir::Value> {
-        let (func_idx, func_sig) =
-            match self.module.table_plans[table_index].table.wasm_ty.heap_type {
-                WasmHeapType::Func | WasmHeapType::TypedFunc(_) => (
-                    BuiltinFunctionIndex::table_grow_func_ref(),
-                    self.builtin_function_signatures
-                        .table_grow_func_ref(&mut pos.func),
-                ),
-                WasmHeapType::Extern => (
-                    BuiltinFunctionIndex::table_grow_externref(),
-                    self.builtin_function_signatures
-                        .table_grow_externref(&mut pos.func),
-                ),
-            };
+        let (func_idx, func_sig) = match self.module.table_plans[table_index]
+            .table
+            .wasm_ty
+            .heap_type
+            .top()
+        {
+            WasmHeapType::Func => (
+                BuiltinFunctionIndex::table_grow_func_ref(),
+                self.builtin_function_signatures
+                    .table_grow_func_ref(&mut pos.func),
+            ),
+            WasmHeapType::Extern => (
+                BuiltinFunctionIndex::table_grow_externref(),
+                self.builtin_function_signatures
+                    .table_grow_externref(&mut pos.func),
+            ),
+            _ => unreachable!("GC tables are rejected during translation"),
+        };
 
         let (vmctx, func_addr) = self.translate_load_builtin_function_address(&mut pos, func_idx);
 
@@ -1352,8 +1917,8 @@ impl<'module_environment> cranelift_wasm::FuncEnvironment for FuncEnvironment<'m
         let pointer_type = self.pointer_type();
 
         let plan = &self.module.table_plans[table_index];
-        match plan.table.wasm_ty.heap_type {
-            WasmHeapType::Func | WasmHeapType::TypedFunc(_) => match plan.style {
+        match plan.table.wasm_ty.heap_type.top() {
+            WasmHeapType::Func => match plan.style {
                 TableStyle::CallerChecksSignature => {
                     Ok(self.get_or_init_func_ref_table_elem(builder, table_index, table, index))
                 }
@@ -1474,6 +2039,7 @@ impl<'module_environment> cranelift_wasm::FuncEnvironment for FuncEnvironment<'m
 
                 Ok(elem)
             }
+            _ => unreachable!("GC tables are rejected during translation"),
         }
     }
 
@@ -1487,8 +2053,8 @@ impl<'module_environment> cranelift_wasm::FuncEnvironment for FuncEnvironment<'m
     ) -> WasmResult<()> {
         let pointer_type = self.pointer_type();
         let plan = &self.module.table_plans[table_index];
-        match plan.table.wasm_ty.heap_type {
-            WasmHeapType::Func | WasmHeapType::TypedFunc(_) => match plan.style {
+        match plan.table.wasm_ty.heap_type.top() {
+            WasmHeapType::Func => match plan.style {
                 TableStyle::CallerChecksSignature => {
                     let table_entry_addr = builder.ins().table_addr(pointer_type, table, index, 0);
                     // Set the "initialized bit". See doc-comment on
@@ -1635,6 +2201,7 @@ impl<'module_environment> cranelift_wasm::FuncEnvironment for FuncEnvironment<'m
 
                 Ok(())
             }
+            _ => unreachable!("GC tables are rejected during translation"),
         }
     }
 
@@ -1646,19 +2213,24 @@ impl<'module_environment> cranelift_wasm::FuncEnvironment for FuncEnvironment<'m
         val: ir::Value,
         len: ir::Value,
     ) -> WasmResult<()> {
-        let (builtin_idx, builtin_sig) =
-            match self.module.table_plans[table_index].table.wasm_ty.heap_type {
-                WasmHeapType::Func | WasmHeapType::TypedFunc(_) => (
-                    BuiltinFunctionIndex::table_fill_func_ref(),
-                    self.builtin_function_signatures
-                        .table_fill_func_ref(&mut pos.func),
-                ),
-                WasmHeapType::Extern => (
-                    BuiltinFunctionIndex::table_fill_externref(),
-                    self.builtin_function_signatures
-                        .table_fill_externref(&mut pos.func),
-                ),
-            };
+        let (builtin_idx, builtin_sig) = match self.module.table_plans[table_index]
+            .table
+            .wasm_ty
+            .heap_type
+            .top()
+        {
+            WasmHeapType::Func => (
+                BuiltinFunctionIndex::table_fill_func_ref(),
+                self.builtin_function_signatures
+                    .table_fill_func_ref(&mut pos.func),
+            ),
+            WasmHeapType::Extern => (
+                BuiltinFunctionIndex::table_fill_externref(),
+                self.builtin_function_signatures
+                    .table_fill_externref(&mut pos.func),
+            ),
+            _ => unreachable!("GC tables are rejected during translation"),
+        };
 
         let (vmctx, builtin_addr) =
             self.translate_load_builtin_function_address(&mut pos, builtin_idx);
@@ -1678,11 +2250,9 @@ impl<'module_environment> cranelift_wasm::FuncEnvironment for FuncEnvironment<'m
         mut pos: cranelift_codegen::cursor::FuncCursor,
         ht: WasmHeapType,
     ) -> WasmResult<ir::Value> {
-        Ok(match ht {
-            WasmHeapType::Func | WasmHeapType::TypedFunc(_) => {
-                pos.ins().iconst(self.pointer_type(), 0)
-            }
-            WasmHeapType::Extern => pos.ins().null(self.reference_type(ht)),
+        Ok(match ht.top() {
+            WasmHeapType::Func => pos.ins().iconst(self.reference_type(ht), 0),
+            _ => pos.ins().null(self.reference_type(ht)),
         })
     }
 
@@ -1692,7 +2262,7 @@ impl<'module_environment> cranelift_wasm::FuncEnvironment for FuncEnvironment<'m
         value: ir::Value,
     ) -> WasmResult<ir::Value> {
         let bool_is_null = match pos.func.dfg.value_type(value) {
-            // `externref`
+            // `externref`, `exnref` and references in the `any` hierarchy
             ty if ty.is_ref() => pos.ins().is_null(value),
             // `funcref`
             ty if ty == self.pointer_type() => {
@@ -1722,17 +2292,619 @@ impl<'module_environment> cranelift_wasm::FuncEnvironment for FuncEnvironment<'m
         Ok(pos.func.dfg.first_result(call_inst))
     }
 
+    fn translate_ref_eq(
+        &mut self,
+        mut pos: FuncCursor,
+        lhs: ir::Value,
+        rhs: ir::Value,
+    ) -> WasmResult<ir::Value> {
+        // Every reference has a single tagged form, so comparing those is
+        // the same as comparing the references.
+        let lhs = pos.ins().bitcast(I64, MemFlags::new(), lhs);
+        let rhs = pos.ins().bitcast(I64, MemFlags::new(), rhs);
+        let eq = pos.ins().icmp(IntCC::Equal, lhs, rhs);
+        Ok(pos.ins().uextend(I32, eq))
+    }
+
+    fn translate_ref_i31(
+        &mut self,
+        mut pos: FuncCursor,
+        value: ir::Value,
+    ) -> WasmResult<ir::Value> {
+        let shifted = pos.ins().ishl_imm(value, 1);
+        let raw = pos.ins().bor_imm(shifted, i64::from(I31_TAG));
+        Ok(self.anyref_from_non_null_raw(&mut pos, raw))
+    }
+
+    fn translate_i31_get(
+        &mut self,
+        mut pos: FuncCursor,
+        i31ref: ir::Value,
+        signed: bool,
+    ) -> WasmResult<ir::Value> {
+        let raw = self.anyref_to_raw(&mut pos, i31ref);
+        pos.ins().trapz(raw, ir::TrapCode::NullReference);
+        Ok(if signed {
+            pos.ins().sshr_imm(raw, 1)
+        } else {
+            pos.ins().ushr_imm(raw, 1)
+        })
+    }
+
+    fn translate_ref_test(
+        &mut self,
+        builder: &mut FunctionBuilder,
+        nullable: bool,
+        ty: WasmHeapType,
+        value: ir::Value,
+    ) -> WasmResult<ir::Value> {
+        let is_null = self.translate_ref_is_null(builder.cursor(), value)?;
+        let if_null = builder.ins().iconst(I32, i64::from(nullable));
+
+        let if_non_null = match ty {
+            WasmHeapType::Any
+            | WasmHeapType::Eq
+            | WasmHeapType::Func
+            | WasmHeapType::Extern
+            | WasmHeapType::Exn => builder.ins().iconst(I32, 1),
+            WasmHeapType::None | WasmHeapType::NoFunc | WasmHeapType::NoExtern => {
+                builder.ins().iconst(I32, 0)
+            }
+            WasmHeapType::I31 => {
+                let raw = self.anyref_to_raw(&mut builder.cursor(), value);
+                builder.ins().band_imm(raw, i64::from(I31_TAG))
+            }
+            // The type of objects and the signature of functions can only be
+            // loaded from non-null references, so these cases require control
+            // flow rather than a `select`.
+            WasmHeapType::Struct
+            | WasmHeapType::Array
+            | WasmHeapType::TypedStruct(_)
+            | WasmHeapType::TypedArray(_) => {
+                let raw = self.anyref_to_raw(&mut builder.cursor(), value);
+                let is_i31 = builder.ins().band_imm(raw, i64::from(I31_TAG));
+                let is_not_object = builder.ins().bor(is_null, is_i31);
+                let if_not_object = builder.ins().select(is_null, if_null, is_null);
+
+                let check_block = builder.create_block();
+                let continue_block = builder.create_block();
+                let result = builder.append_block_param(continue_block, I32);
+                builder.ins().brif(
+                    is_not_object,
+                    continue_block,
+                    &[if_not_object],
+                    check_block,
+                    &[],
+                );
+
+                builder.seal_block(check_block);
+                builder.switch_to_block(check_block);
+                let has_type = self.gc_object_has_type(builder, raw, ty);
+                builder.ins().jump(continue_block, &[has_type]);
+
+                builder.seal_block(continue_block);
+                builder.switch_to_block(continue_block);
+                return Ok(result);
+            }
+            WasmHeapType::TypedFunc(sig_index) => {
+                let check_block = builder.create_block();
+                let continue_block = builder.create_block();
+                let result = builder.append_block_param(continue_block, I32);
+                builder
+                    .ins()
+                    .brif(is_null, continue_block, &[if_null], check_block, &[]);
+
+                builder.seal_block(check_block);
+                builder.switch_to_block(check_block);
+                let has_sig = self.func_ref_has_signature(builder, value, sig_index);
+                let has_sig = builder.ins().uextend(I32, has_sig);
+                builder.ins().jump(continue_block, &[has_sig]);
+
+                builder.seal_block(continue_block);
+                builder.switch_to_block(continue_block);
+                return Ok(result);
+            }
+        };
+        Ok(builder.ins().select(is_null, if_null, if_non_null))
+    }
+
+    fn translate_ref_cast(
+        &mut self,
+        builder: &mut FunctionBuilder,
+        nullable: bool,
+        ty: WasmHeapType,
+        value: ir::Value,
+    ) -> WasmResult<()> {
+        let is_cast = self.translate_ref_test(builder, nullable, ty, value)?;
+        builder
+            .ins()
+            .trapz(is_cast, ir::TrapCode::User(CAST_FAILURE_CODE));
+        Ok(())
+    }
+
+    fn translate_struct_new(
+        &mut self,
+        builder: &mut FunctionBuilder,
+        struct_type_index: TypeIndex,
+        fields: &[ir::Value],
+    ) -> WasmResult<ir::Value> {
+        let (index, _, layout) = self.gc_type(struct_type_index);
+        let zero = builder.ins().iconst(I32, 0);
+        let raw = self.gc_alloc(builder, index, zero);
+        let object = self.gc_object_addr(&mut builder.cursor(), raw);
+        let layout = match layout {
+            GcLayout::Struct { fields, .. } => fields,
+            GcLayout::Array(_) => unreachable!(),
+        };
+        for ((offset, storage), value) in layout.iter().zip(fields) {
+            let offset = i32::try_from(*offset).unwrap();
+            self.gc_store(&mut builder.cursor(), *storage, *value, object, offset);
+        }
+        Ok(self.anyref_from_non_null_raw(&mut builder.cursor(), raw))
+    }
+
+    fn translate_struct_new_default(
+        &mut self,
+        builder: &mut FunctionBuilder,
+        struct_type_index: TypeIndex,
+    ) -> WasmResult<ir::Value> {
+        // Objects are allocated zeroed, which is the default value of every
+        // field.
+        let (index, _, _) = self.gc_type(struct_type_index);
+        let zero = builder.ins().iconst(I32, 0);
+        let raw = self.gc_alloc(builder, index, zero);
+        Ok(self.anyref_from_non_null_raw(&mut builder.cursor(), raw))
+    }
+
+    fn translate_struct_get(
+        &mut self,
+        builder: &mut FunctionBuilder,
+        struct_type_index: TypeIndex,
+        field_index: u32,
+        signed: bool,
+        struct_ref: ir::Value,
+    ) -> WasmResult<ir::Value> {
+        let (_, _, layout) = self.gc_type(struct_type_index);
+        let (offset, storage) = match layout {
+            GcLayout::Struct { fields, .. } => fields[usize::try_from(field_index).unwrap()],
+            GcLayout::Array(_) => unreachable!(),
+        };
+        let raw = self.gc_object_non_null(builder, struct_ref);
+        let object = self.gc_object_addr(&mut builder.cursor(), raw);
+        let offset = i32::try_from(offset).unwrap();
+        Ok(self.gc_load(&mut builder.cursor(), storage, signed, object, offset))
+    }
+
+    fn translate_struct_set(
+        &mut self,
+        builder: &mut FunctionBuilder,
+        struct_type_index: TypeIndex,
+        field_index: u32,
+        struct_ref: ir::Value,
+        value: ir::Value,
+    ) -> WasmResult<()> {
+        let (_, _, layout) = self.gc_type(struct_type_index);
+        let (offset, storage) = match layout {
+            GcLayout::Struct { fields, .. } => fields[usize::try_from(field_index).unwrap()],
+            GcLayout::Array(_) => unreachable!(),
+        };
+        let raw = self.gc_object_non_null(builder, struct_ref);
+        let object = self.gc_object_addr(&mut builder.cursor(), raw);
+        let offset = i32::try_from(offset).unwrap();
+        self.gc_store(&mut builder.cursor(), storage, value, object, offset);
+        Ok(())
+    }
+
+    fn translate_array_new(
+        &mut self,
+        builder: &mut FunctionBuilder,
+        array_type_index: TypeIndex,
+        elem: ir::Value,
+        len: ir::Value,
+    ) -> WasmResult<ir::Value> {
+        let (index, _, layout) = self.gc_type(array_type_index);
+        let storage = match layout {
+            GcLayout::Array(storage) => storage,
+            GcLayout::Struct { .. } => unreachable!(),
+        };
+        let raw = self.gc_alloc(builder, index, len);
+        let array = self.gc_object_addr(&mut builder.cursor(), raw);
+        let elems = builder
+            .ins()
+            .iadd_imm(array, i64::from(GC_ARRAY_ELEMS_OFFSET));
+        self.gc_fill(builder, storage, elems, len, elem);
+        Ok(self.anyref_from_non_null_raw(&mut builder.cursor(), raw))
+    }
+
+    fn translate_array_new_default(
+        &mut self,
+        builder: &mut FunctionBuilder,
+        array_type_index: TypeIndex,
+        len: ir::Value,
+    ) -> WasmResult<ir::Value> {
+        let (index, _, _) = self.gc_type(array_type_index);
+        let raw = self.gc_alloc(builder, index, len);
+        Ok(self.anyref_from_non_null_raw(&mut builder.cursor(), raw))
+    }
+
+    fn translate_array_new_fixed(
+        &mut self,
+        builder: &mut FunctionBuilder,
+        array_type_index: TypeIndex,
+        elems: &[ir::Value],
+    ) -> WasmResult<ir::Value> {
+        let (index, _, layout) = self.gc_type(array_type_index);
+        let storage = match layout {
+            GcLayout::Array(storage) => storage,
+            GcLayout::Struct { .. } => unreachable!(),
+        };
+        let len = builder.ins().iconst(I32, elems.len() as i64);
+        let raw = self.gc_alloc(builder, index, len);
+        let array = self.gc_object_addr(&mut builder.cursor(), raw);
+        for (i, elem) in elems.iter().enumerate() {
+            let offset = u32::try_from(i).unwrap() * storage.size() + GC_ARRAY_ELEMS_OFFSET;
+            let offset = i32::try_from(offset).unwrap();
+            self.gc_store(&mut builder.cursor(), storage, *elem, array, offset);
+        }
+        Ok(self.anyref_from_non_null_raw(&mut builder.cursor(), raw))
+    }
+
+    fn translate_array_new_data(
+        &mut self,
+        builder: &mut FunctionBuilder,
+        array_type_index: TypeIndex,
+        data_index: DataIndex,
+        offset: ir::Value,
+        len: ir::Value,
+    ) -> WasmResult<ir::Value> {
+        let (index, _, _) = self.gc_type(array_type_index);
+        let ty = builder.ins().iconst(I32, i64::from(index.as_u32()));
+        let data = builder.ins().iconst(I32, i64::from(data_index.as_u32()));
+        let sig = self
+            .builtin_function_signatures
+            .gc_array_new_data(builder.func);
+        let call = self.call_builtin(
+            builder,
+            BuiltinFunctionIndex::gc_array_new_data(),
+            sig,
+            &[ty, data, offset, len],
+        );
+        let raw = builder.func.dfg.first_result(call);
+        Ok(self.anyref_from_non_null_raw(&mut builder.cursor(), raw))
+    }
+
+    fn translate_array_new_elem(
+        &mut self,
+        builder: &mut FunctionBuilder,
+        array_type_index: TypeIndex,
+        elem_index: ElemIndex,
+        offset: ir::Value,
+        len: ir::Value,
+    ) -> WasmResult<ir::Value> {
+        let (index, _, _) = self.gc_type(array_type_index);
+        let ty = builder.ins().iconst(I32, i64::from(index.as_u32()));
+        let elem = builder.ins().iconst(I32, i64::from(elem_index.as_u32()));
+        let sig = self
+            .builtin_function_signatures
+            .gc_array_new_elem(builder.func);
+        let call = self.call_builtin(
+            builder,
+            BuiltinFunctionIndex::gc_array_new_elem(),
+            sig,
+            &[ty, elem, offset, len],
+        );
+        let raw = builder.func.dfg.first_result(call);
+        Ok(self.anyref_from_non_null_raw(&mut builder.cursor(), raw))
+    }
+
+    fn translate_array_get(
+        &mut self,
+        builder: &mut FunctionBuilder,
+        array_type_index: TypeIndex,
+        signed: bool,
+        array: ir::Value,
+        index: ir::Value,
+    ) -> WasmResult<ir::Value> {
+        let (_, _, layout) = self.gc_type(array_type_index);
+        let storage = match layout {
+            GcLayout::Array(storage) => storage,
+            GcLayout::Struct { .. } => unreachable!(),
+        };
+        let raw = self.gc_object_non_null(builder, array);
+        let elem = self.gc_array_elem_addr(builder, storage, raw, index);
+        Ok(self.gc_load(&mut builder.cursor(), storage, signed, elem, 0))
+    }
+
+    fn translate_array_set(
+        &mut self,
+        builder: &mut FunctionBuilder,
+        array_type_index: TypeIndex,
+        array: ir::Value,
+        index: ir::Value,
+        value: ir::Value,
+    ) -> WasmResult<()> {
+        let (_, _, layout) = self.gc_type(array_type_index);
+        let storage = match layout {
+            GcLayout::Array(storage) => storage,
+            GcLayout::Struct { .. } => unreachable!(),
+        };
+        let raw = self.gc_object_non_null(builder, array);
+        let elem = self.gc_array_elem_addr(builder, storage, raw, index);
+        self.gc_store(&mut builder.cursor(), storage, value, elem, 0);
+        Ok(())
+    }
+
+    fn translate_array_len(
+        &mut self,
+        builder: &mut FunctionBuilder,
+        array: ir::Value,
+    ) -> WasmResult<ir::Value> {
+        let raw = self.gc_object_non_null(builder, array);
+        let array = self.gc_object_addr(&mut builder.cursor(), raw);
+        Ok(builder.ins().load(
+            I32,
+            Self::gc_mem_flags(),
+            array,
+            GC_ARRAY_LENGTH_OFFSET as i32,
+        ))
+    }
+
+    fn translate_array_fill(
+        &mut self,
+        builder: &mut FunctionBuilder,
+        array_type_index: TypeIndex,
+        array: ir::Value,
+        index: ir::Value,
+        value: ir::Value,
+        len: ir::Value,
+    ) -> WasmResult<()> {
+        let (_, _, layout) = self.gc_type(array_type_index);
+        let storage = match layout {
+            GcLayout::Array(storage) => storage,
+            GcLayout::Struct { .. } => unreachable!(),
+        };
+        let raw = self.gc_object_non_null(builder, array);
+        let array = self.gc_object_addr(&mut builder.cursor(), raw);
+
+        // Check `index + len <= array.len` without overflowing.
+        let array_len = builder.ins().load(
+            I32,
+            Self::gc_mem_flags(),
+            array,
+            GC_ARRAY_LENGTH_OFFSET as i32,
+        );
+        let array_len = builder.ins().uextend(I64, array_len);
+        let wide_index = builder.ins().uextend(I64, index);
+        let wide_len = builder.ins().uextend(I64, len);
+        let end = builder.ins().iadd(wide_index, wide_len);
+        let out_of_bounds = builder
+            .ins()
+            .icmp(IntCC::UnsignedGreaterThan, end, array_len);
+        builder
+            .ins()
+            .trapnz(out_of_bounds, ir::TrapCode::User(ARRAY_OUT_OF_BOUNDS_CODE));
+
+        let pointer_type = self.pointer_type();
+        let index = builder.ins().uextend(pointer_type, index);
+        let offset = builder.ins().imul_imm(index, i64::from(storage.size()));
+        let elems = builder.ins().iadd(array, offset);
+        let elems = builder
+            .ins()
+            .iadd_imm(elems, i64::from(GC_ARRAY_ELEMS_OFFSET));
+        self.gc_fill(builder, storage, elems, len, value);
+        Ok(())
+    }
+
+    fn translate_array_copy(
+        &mut self,
+        builder: &mut FunctionBuilder,
+        dst: ir::Value,
+        dst_index: ir::Value,
+        src: ir::Value,
+        src_index: ir::Value,
+        len: ir::Value,
+    ) -> WasmResult<()> {
+        let dst = self.anyref_to_raw(&mut builder.cursor(), dst);
+        let src = self.anyref_to_raw(&mut builder.cursor(), src);
+        let sig = self.builtin_function_signatures.gc_array_copy(builder.func);
+        self.call_builtin(
+            builder,
+            BuiltinFunctionIndex::gc_array_copy(),
+            sig,
+            &[dst, dst_index, src, src_index, len],
+        );
+        Ok(())
+    }
+
+    fn translate_array_init_data(
+        &mut self,
+        builder: &mut FunctionBuilder,
+        data_index: DataIndex,
+        array: ir::Value,
+        index: ir::Value,
+        offset: ir::Value,
+        len: ir::Value,
+    ) -> WasmResult<()> {
+        let array = self.anyref_to_raw(&mut builder.cursor(), array);
+        let data = builder.ins().iconst(I32, i64::from(data_index.as_u32()));
+        let sig = self
+            .builtin_function_signatures
+            .gc_array_init_data(builder.func);
+        self.call_builtin(
+            builder,
+            BuiltinFunctionIndex::gc_array_init_data(),
+            sig,
+            &[array, index, data, offset, len],
+        );
+        Ok(())
+    }
+
+    fn translate_array_init_elem(
+        &mut self,
+        builder: &mut FunctionBuilder,
+        elem_index: ElemIndex,
+        array: ir::Value,
+        index: ir::Value,
+        offset: ir::Value,
+        len: ir::Value,
+    ) -> WasmResult<()> {
+        let array = self.anyref_to_raw(&mut builder.cursor(), array);
+        let elem = builder.ins().iconst(I32, i64::from(elem_index.as_u32()));
+        let sig = self
+            .builtin_function_signatures
+            .gc_array_init_elem(builder.func);
+        self.call_builtin(
+            builder,
+            BuiltinFunctionIndex::gc_array_init_elem(),
+            sig,
+            &[array, index, elem, offset, len],
+        );
+        Ok(())
+    }
+
+    fn exceptions_enabled(&self) -> bool {
+        self.tunables.exceptions
+    }
+
+    fn translate_throw(
+        &mut self,
+        builder: &mut FunctionBuilder,
+        tag_index: TagIndex,
+        args: &[ir::Value],
+    ) -> WasmResult<()> {
+        // Note that the payload is stored little-endian, like `ValRaw`s.
+        let mut flags = MemFlags::trusted();
+        flags.set_endianness(ir::Endianness::Little);
+        let (values, _) = self.exception_payload_slot(builder, tag_index);
+        for (i, arg) in args.iter().enumerate() {
+            let offset = i32::try_from(i * mem::size_of::<u128>()).unwrap();
+            builder.ins().store(flags, *arg, values, offset);
+        }
+
+        let tag = builder.ins().iconst(I32, i64::from(tag_index.as_u32()));
+        let sig = self
+            .builtin_function_signatures
+            .throw_exception(builder.func);
+        self.call_builtin(
+            builder,
+            BuiltinFunctionIndex::throw_exception(),
+            sig,
+            &[tag, values],
+        );
+        Ok(())
+    }
+
+    fn translate_throw_ref(
+        &mut self,
+        builder: &mut FunctionBuilder,
+        exnref: ir::Value,
+    ) -> WasmResult<()> {
+        let sig = self.builtin_function_signatures.throw_exnref(builder.func);
+        self.call_builtin(
+            builder,
+            BuiltinFunctionIndex::throw_exnref(),
+            sig,
+            &[exnref],
+        );
+        Ok(())
+    }
+
+    fn translate_exception_pending(
+        &mut self,
+        builder: &mut FunctionBuilder,
+    ) -> WasmResult<ir::Value> {
+        // The fuel consumed by the callee must be accounted for along the
+        // exceptional edge too, which skips the reload in
+        // `after_translate_operator`.
+        if self.tunables.consume_fuel {
+            self.fuel_load_into_var(builder);
+        }
+
+        let pointer_type = self.pointer_type();
+        let vmctx = self.vmctx(builder.func);
+        let base = builder.ins().global_value(pointer_type, vmctx);
+        let offset = i32::try_from(self.offsets.vmctx_runtime_limits()).unwrap();
+        let limits = builder.ins().load(
+            pointer_type,
+            MemFlags::trusted().with_readonly(),
+            base,
+            offset,
+        );
+        let offset = i32::from(self.offsets.ptr.vmruntime_limits_pending_exception());
+        let pending = builder
+            .ins()
+            .load(pointer_type, MemFlags::trusted(), limits, offset);
+        Ok(builder.ins().icmp_imm(IntCC::NotEqual, pending, 0))
+    }
+
+    fn translate_exception_matches(
+        &mut self,
+        builder: &mut FunctionBuilder,
+        tag_index: TagIndex,
+    ) -> WasmResult<ir::Value> {
+        let sig = self.builtin_function_signatures.exception_tag(builder.func);
+        let call = self.call_builtin(builder, BuiltinFunctionIndex::exception_tag(), sig, &[]);
+        let thrown = builder.func.dfg.first_result(call);
+        let tag = self.tag_addr(builder, tag_index);
+        Ok(builder.ins().icmp(IntCC::Equal, thrown, tag))
+    }
+
+    fn translate_take_exception(
+        &mut self,
+        builder: &mut FunctionBuilder,
+        tag_index: Option<TagIndex>,
+    ) -> WasmResult<(Vec<ir::Value>, ir::Value)> {
+        let (values, params) = match tag_index {
+            Some(index) => {
+                let (values, ty) = self.exception_payload_slot(builder, index);
+                (values, ty.params())
+            }
+            None => (builder.ins().iconst(self.pointer_type(), 0), &[][..]),
+        };
+
+        let sig = self
+            .builtin_function_signatures
+            .take_exception(builder.func);
+        let call = self.call_builtin(
+            builder,
+            BuiltinFunctionIndex::take_exception(),
+            sig,
+            &[values],
+        );
+        let exnref = builder.func.dfg.first_result(call);
+
+        let mut flags = MemFlags::trusted();
+        flags.set_endianness(ir::Endianness::Little);
+        let payload = params
+            .iter()
+            .enumerate()
+            .map(|(i, ty)| {
+                let offset = i32::try_from(i * mem::size_of::<u128>()).unwrap();
+                let ty = super::value_type(self.isa, *ty);
+                builder.ins().load(ty, flags, values, offset)
+            })
+            .collect();
+        Ok((payload, exnref))
+    }
+
     fn translate_custom_global_get(
         &mut self,
         mut pos: cranelift_codegen::cursor::FuncCursor<'_>,
         index: cranelift_wasm::GlobalIndex,
     ) -> WasmResult<ir::Value> {
-        debug_assert_eq!(
-            self.module.globals[index].wasm_ty,
-            WasmType::Ref(WasmRefType::EXTERNREF),
-            "We only use GlobalVariable::Custom for externref"
+        debug_assert!(
+            matches!(self.module.globals[index].wasm_ty, WasmType::Ref(_)),
+            "We only use GlobalVariable::Custom for references"
         );
 
+        if matches!(self.module.globals[index].wasm_ty, WasmType::Ref(r) if r.heap_type.is_anyref())
+        {
+            let (gv, offset) = self.get_global_location(&mut pos.func, index);
+            let addr = pos.ins().global_value(self.pointer_type(), gv);
+            let raw = pos.ins().load(I32, MemFlags::trusted(), addr, offset);
+            return Ok(self.anyref_from_raw(&mut pos, raw));
+        }
+
         let builtin_index = BuiltinFunctionIndex::externref_global_get();
         let builtin_sig = self
             .builtin_function_signatures
@@ -1755,12 +2927,20 @@ impl<'module_environment> cranelift_wasm::FuncEnvironment for FuncEnvironment<'m
         index: cranelift_wasm::GlobalIndex,
         value: ir::Value,
     ) -> WasmResult<()> {
-        debug_assert_eq!(
-            self.module.globals[index].wasm_ty,
-            WasmType::Ref(WasmRefType::EXTERNREF),
-            "We only use GlobalVariable::Custom for externref"
+        debug_assert!(
+            matches!(self.module.globals[index].wasm_ty, WasmType::Ref(_)),
+            "We only use GlobalVariable::Custom for references"
         );
 
+        if matches!(self.module.globals[index].wasm_ty, WasmType::Ref(r) if r.heap_type.is_anyref())
+        {
+            let (gv, offset) = self.get_global_location(&mut pos.func, index);
+            let addr = pos.ins().global_value(self.pointer_type(), gv);
+            let raw = self.anyref_to_raw(&mut pos, value);
+            pos.ins().store(MemFlags::trusted(), raw, addr, offset);
+            return Ok(());
+        }
+
         let builtin_index = BuiltinFunctionIndex::externref_global_set();
         let builtin_sig = self
             .builtin_function_signatures
@@ -2021,9 +3201,9 @@ impl<'module_environment> cranelift_wasm::FuncEnvironment for FuncEnvironment<'m
             // them requires ref counting barriers. Therefore, we need to use
             // `GlobalVariable::Custom`, as that is the only kind of
             // `GlobalVariable` for which `cranelift-wasm` supports custom
-            // access translation.
+            // access translation. Exception references are `VMExternRef`s too.
             WasmType::Ref(WasmRefType {
-                heap_type: WasmHeapType::Extern,
+                heap_type: WasmHeapType::Extern | WasmHeapType::NoExtern | WasmHeapType::Exn,
                 ..
             }) => return Ok(GlobalVariable::Custom),
 
@@ -2031,10 +3211,26 @@ impl<'module_environment> cranelift_wasm::FuncEnvironment for FuncEnvironment<'m
             // entire lifetime of the `Store` so there's no need for barriers.
             // This means that they can fall through to memory as well.
             WasmType::Ref(WasmRefType {
-                heap_type: WasmHeapType::Func | WasmHeapType::TypedFunc(_),
+                heap_type: WasmHeapType::Func | WasmHeapType::TypedFunc(_) | WasmHeapType::NoFunc,
                 ..
             }) => {}
 
+            // References in the `any` hierarchy are stored as `i32`s, like
+            // in the GC heap, but held in their tagged form by compiled code,
+            // so they're converted when getting or setting them.
+            WasmType::Ref(WasmRefType {
+                heap_type:
+                    WasmHeapType::Any
+                    | WasmHeapType::Eq
+                    | WasmHeapType::I31
+                    | WasmHeapType::Struct
+                    | WasmHeapType::Array
+                    | WasmHeapType::TypedStruct(_)
+                    | WasmHeapType::TypedArray(_)
+                    | WasmHeapType::None,
+                ..
+            }) => return Ok(GlobalVariable::Custom),
+
             // Value types all live in memory so let them fall through to a
             // memory-based global.
             WasmType::I32 | WasmType::I64 | WasmType::F32 | WasmType::F64 | WasmType::V128 => {}
@@ -2536,6 +3732,11 @@ impl<'module_environment> cranelift_wasm::FuncEnvironment for FuncEnvironment<'m
         Ok(())
     }
 
+    fn needs_operand_types(&self) -> bool {
+        // Breakpoints report the wasm types of the operand stack.
+        self.tunables.guest_debug
+    }
+
     fn before_translate_operator(
         &mut self,
         op: &Operator,
@@ -2545,6 +3746,12 @@ impl<'module_environment> cranelift_wasm::FuncEnvironment for FuncEnvironment<'m
         if self.tunables.consume_fuel {
             self.fuel_before_op(op, builder, state.reachable());
         }
+        if self.tunables.coverage {
+            self.coverage_before_op(builder, state.reachable());
+        }
+        if self.tunables.guest_debug && state.reachable() {
+            self.debug_break(builder, state);
+        }
         Ok(())
     }
 
@@ -2557,6 +3764,9 @@ impl<'module_environment> cranelift_wasm::FuncEnvironment for FuncEnvironment<'m
         if self.tunables.consume_fuel && state.reachable() {
             self.fuel_after_op(op, builder);
         }
+        if self.tunables.coverage {
+            self.coverage_after_op(op);
+        }
         Ok(())
     }
 
@@ -2578,7 +3788,10 @@ impl<'module_environment> cranelift_wasm::FuncEnvironment for FuncEnvironment<'m
     ) -> WasmResult<()> {
         // If the `vmruntime_limits_ptr` variable will get used then we initialize
         // it here.
-        if self.tunables.consume_fuel || self.tunables.epoch_interruption {
+        if self.tunables.consume_fuel
+            || self.tunables.epoch_interruption
+            || self.tunables.guest_debug
+        {
             self.declare_vmruntime_limits_ptr(builder);
         }
         // Additionally we initialize `fuel_var` if it will get used.
@@ -2589,6 +3802,9 @@ impl<'module_environment> cranelift_wasm::FuncEnvironment for FuncEnvironment<'m
         if self.tunables.epoch_interruption {
             self.epoch_function_entry(builder);
         }
+        if self.tunables.coverage {
+            self.coverage_function_entry(builder);
+        }
 
         let func_name = self.current_func_name(builder);
         if func_name == Some("malloc") {
@@ -177,10 +177,32 @@ fn reference_type(wasm_ht: cranelift_wasm::WasmHeapType, pointer_type: ir::Type)
         cranelift_wasm::WasmHeapType::Func | cranelift_wasm::WasmHeapType::TypedFunc(_) => {
             pointer_type
         }
-        cranelift_wasm::WasmHeapType::Extern => match pointer_type {
-            ir::types::I32 => ir::types::R32,
-            ir::types::I64 => ir::types::R64,
-            _ => panic!("unsupported pointer type"),
-        },
+        // Exception references are `VMExternRef`s, just like `externref`s.
+        cranelift_wasm::WasmHeapType::Extern | cranelift_wasm::WasmHeapType::Exn => {
+            match pointer_type {
+                ir::types::I32 => ir::types::R32,
+                ir::types::I64 => ir::types::R64,
+                _ => panic!("unsupported pointer type"),
+            }
+        }
+        cranelift_wasm::WasmHeapType::NoFunc => {
+            reference_type(cranelift_wasm::WasmHeapType::Func, pointer_type)
+        }
+        cranelift_wasm::WasmHeapType::NoExtern => {
+            reference_type(cranelift_wasm::WasmHeapType::Extern, pointer_type)
+        }
+        // References in the `any` hierarchy are 32-bit values, but they're
+        // held as tagged reference types so that stack maps record them for
+        // the tracing collector, see `wasmtime_environ::ANYREF_STACK_TAG`.
+        cranelift_wasm::WasmHeapType::Any
+        | cranelift_wasm::WasmHeapType::Eq
+        | cranelift_wasm::WasmHeapType::I31
+        | cranelift_wasm::WasmHeapType::Struct
+        | cranelift_wasm::WasmHeapType::Array
+        | cranelift_wasm::WasmHeapType::TypedStruct(_)
+        | cranelift_wasm::WasmHeapType::TypedArray(_)
+        | cranelift_wasm::WasmHeapType::None => {
+            reference_type(cranelift_wasm::WasmHeapType::Extern, pointer_type)
+        }
     }
 }
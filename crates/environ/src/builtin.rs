@@ -67,6 +67,39 @@ macro_rules! foreach_builtin_function {
             update_stack_pointer(vmctx: vmctx, value: i32);
             /// Invoked before memory.grow is called.
             update_mem_size(vmctx: vmctx, num_bytes: i32);
+            /// Returns an index for wasm's `throw` instruction.
+            throw_exception(vmctx: vmctx, tag: i32, values: pointer);
+            /// Returns an index for wasm's `throw_ref` instruction.
+            throw_exnref(vmctx: vmctx, exn: reference);
+            /// Returns the tag definition of the exception being thrown.
+            exception_tag(vmctx: vmctx) -> pointer;
+            /// Takes the exception being thrown, writing its payload to
+            /// `values` (if non-null) and returning it as an `exnref`.
+            take_exception(vmctx: vmctx, values: pointer) -> reference;
+            /// Invoked before an instruction at `offset` in function `func` is
+            /// executed while a breakpoint is set or single-stepping, with the
+            /// wasm-level state of the function stored in `values`.
+            debug_break(vmctx: vmctx, func: i32, offset: i32, values: pointer, locals: i32, stack: i32);
+            /// Returns a new zeroed `struct` or `array` of type `ty` for wasm's
+            /// `struct.new` and `array.new` instructions, where `len` is the
+            /// length of arrays.
+            gc_alloc(vmctx: vmctx, ty: i32, len: i32) -> i32;
+            /// Returns whether the `struct` or `array` `obj` is of the type `ty`
+            /// or one of its subtypes, for wasm's `ref.test` and `ref.cast`
+            /// instructions.
+            gc_ref_test(vmctx: vmctx, obj: i32, ty: i32) -> i32;
+            /// Returns an index for wasm's `array.copy` instruction.
+            gc_array_copy(vmctx: vmctx, dst: i32, dst_index: i32, src: i32, src_index: i32, len: i32);
+            /// Returns a new array of type `ty` for wasm's `array.new_data`
+            /// instruction.
+            gc_array_new_data(vmctx: vmctx, ty: i32, data: i32, offset: i32, len: i32) -> i32;
+            /// Returns an index for wasm's `array.init_data` instruction.
+            gc_array_init_data(vmctx: vmctx, array: i32, index: i32, data: i32, offset: i32, len: i32);
+            /// Returns a new array of type `ty` for wasm's `array.new_elem`
+            /// instruction.
+            gc_array_new_elem(vmctx: vmctx, ty: i32, elem: i32, offset: i32, len: i32) -> i32;
+            /// Returns an index for wasm's `array.init_elem` instruction.
+            gc_array_init_elem(vmctx: vmctx, array: i32, index: i32, elem: i32, offset: i32, len: i32);
         }
     };
 }
@@ -24,6 +24,10 @@ use thiserror::Error;
 pub struct WasmFunctionInfo {
     pub start_srcloc: FilePos,
     pub stack_maps: Box<[StackMapInformation]>,
+    /// The wasm offset of the first instruction of each basic block which
+    /// counts its entries, in the order of its counter, when coverage is
+    /// enabled.
+    pub coverage: Box<[FilePos]>,
 }
 
 /// Description of where a function is located in the text section of a
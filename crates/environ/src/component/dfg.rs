@@ -37,6 +37,10 @@ use std::ops::Index;
 #[derive(Default)]
 #[allow(missing_docs)]
 pub struct ComponentDfg {
+    /// Same as `Component::ty`, filled in once the root component has been
+    /// inlined.
+    pub ty: Option<TypeComponentIndex>,
+
     /// Same as `Component::import_types`
     pub import_types: PrimaryMap<ImportIndex, (String, TypeDef)>,
 
@@ -373,6 +377,7 @@ impl ComponentDfg {
         ComponentTranslation {
             trampolines: linearize.trampoline_defs,
             component: Component {
+                ty: self.ty.unwrap(),
                 exports,
                 initializers: linearize.initializers,
                 trampolines: linearize.trampolines,
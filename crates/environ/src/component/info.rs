@@ -69,8 +69,12 @@ pub struct ComponentTranslation {
 ///
 /// NB: Lots of the component model is not yet implemented in the runtime so
 /// this is going to undergo a lot of churn.
-#[derive(Default, Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct Component {
+    /// The type of this component, or the names and types of all of its
+    /// imports and exports.
+    pub ty: TypeComponentIndex,
+
     /// A list of typed values that this component imports.
     ///
     /// Note that each name is given an `ImportIndex` here for the next map to
@@ -77,6 +77,7 @@ pub(super) fn run(
     // item since we don't know the precise structure of the host import.
     let mut args = HashMap::with_capacity(result.exports.len());
     let mut path = Vec::new();
+    let mut component_ty = TypeComponent::default();
     types.resources_mut().set_current_instance(index);
     let types_ref = result.types_ref();
     for init in result.initializers.iter() {
@@ -108,6 +109,7 @@ pub(super) fn run(
         // With resources all taken care of it's now possible to convert this
         // into Wasmtime's type system.
         let ty = types.convert_component_entity_type(types_ref, ty)?;
+        component_ty.imports.insert(name.0.to_string(), ty);
 
         // Imports of types that aren't resources are not required to be
         // specified by the host since it's just for type information within
@@ -131,6 +133,16 @@ pub(super) fn run(
     let exports = inliner.run(types, &mut frames)?;
     assert!(frames.is_empty());
 
+    // The resources defined by the root component are still registered at
+    // this point, so the types of its exports can be converted as well to
+    // complete its type.
+    for name in result.exports.keys() {
+        let ty = types_ref.component_entity_type_of_export(name).unwrap();
+        let ty = types.convert_component_entity_type(types_ref, ty)?;
+        component_ty.exports.insert(name.to_string(), ty);
+    }
+    inliner.result.ty = Some(types.add_component_type(component_ty));
+
     let mut export_map = Default::default();
     for (name, def) in exports {
         inliner.record_export(name, def, types, &mut export_map)?;
@@ -383,7 +395,9 @@ impl<'a> Inliner<'a> {
                 // exports of the component are collected and then the entire
                 // frame is discarded. The exports are then either pushed in the
                 // parent frame, if any, as a new component instance or they're
-                // returned from this function for the root set of exports.
+                // returned from this function for the root set of exports. The
+                // resources registered by the root frame are kept so that the
+                // types of its exports can still be converted afterwards.
                 None => {
                     let exports = frame
                         .translation
@@ -393,9 +407,9 @@ impl<'a> Inliner<'a> {
                         .collect::<Result<_>>()?;
                     let instance_ty = frame.instance_ty;
                     let (_, snapshot) = frames.pop().unwrap();
-                    *types.resources_mut() = snapshot;
                     match frames.last_mut() {
                         Some((parent, _)) => {
+                            *types.resources_mut() = snapshot;
                             parent.finish_instantiate(
                                 ComponentInstanceDef::Items(exports),
                                 instance_ty.unwrap(),
@@ -945,6 +959,8 @@ impl<'a> Inliner<'a> {
                 EntityIndex::Table(i) => frame.tables[i].clone().into(),
                 EntityIndex::Global(i) => frame.globals[i].clone().into(),
                 EntityIndex::Memory(i) => frame.memories[i].clone().into(),
+                // Tags are rejected when synthetic instances are translated.
+                EntityIndex::Tag(_) => unreachable!(),
             },
         }
     }
@@ -555,7 +555,7 @@ impl ComponentTypesBuilder {
                 self.convert_component_entity_type(types, *ty)?,
             );
         }
-        Ok(self.component_types.components.push(result))
+        Ok(self.add_component_type(result))
     }
 
     fn convert_instance(
@@ -803,6 +803,11 @@ impl ComponentTypesBuilder {
         intern(&mut self.functions, &mut self.component_types.functions, ty)
     }
 
+    /// Adds a new component type within this type information.
+    pub fn add_component_type(&mut self, ty: TypeComponent) -> TypeComponentIndex {
+        self.component_types.components.push(ty)
+    }
+
     /// Interns a new record type within this type information.
     pub fn add_record_type(&mut self, ty: TypeRecord) -> TypeRecordIndex {
         intern_and_fill_flat_types!(self, records, ty)
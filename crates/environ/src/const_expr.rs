@@ -0,0 +1,206 @@
+//! Constant expressions as used in global initializers and the offsets of
+//! active data and element segments.
+
+use crate::{FuncIndex, GlobalIndex, Unsigned, WasmError, WasmResult, I31_TAG};
+use serde_derive::{Deserialize, Serialize};
+use wasmparser::Operator;
+
+/// A constant expression, the sequence of operators that computes an
+/// initial value at instantiation time.
+///
+/// Without the extended-const proposal this is always a single operator, but
+/// with it `i32` and `i64` expressions may also use `add`, `sub` and `mul`.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct ConstExpr {
+    ops: Box<[ConstOp]>,
+}
+
+/// A single operator within a [`ConstExpr`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[allow(missing_docs)]
+pub enum ConstOp {
+    I32Const(i32),
+    I64Const(i64),
+    F32Const(u32),
+    F64Const(u64),
+    V128Const(u128),
+    GlobalGet(GlobalIndex),
+    RefI31,
+    RefNull,
+    RefFunc(FuncIndex),
+    I32Add,
+    I32Sub,
+    I32Mul,
+    I64Add,
+    I64Sub,
+    I64Mul,
+}
+
+impl ConstExpr {
+    /// Creates a new constant expression from its operators, in order.
+    pub fn new(ops: impl IntoIterator<Item = ConstOp>) -> ConstExpr {
+        ConstExpr {
+            ops: ops.into_iter().collect(),
+        }
+    }
+
+    /// Converts a validated `wasmparser` constant expression.
+    pub fn from_wasmparser(expr: wasmparser::ConstExpr<'_>) -> WasmResult<ConstExpr> {
+        let mut reader = expr.get_operators_reader();
+        let mut ops = Vec::new();
+        loop {
+            let op = match reader.read()? {
+                Operator::End => break,
+                Operator::I32Const { value } => ConstOp::I32Const(value),
+                Operator::I64Const { value } => ConstOp::I64Const(value),
+                Operator::F32Const { value } => ConstOp::F32Const(value.bits()),
+                Operator::F64Const { value } => ConstOp::F64Const(value.bits()),
+                Operator::V128Const { value } => {
+                    ConstOp::V128Const(u128::from_le_bytes(*value.bytes()))
+                }
+                Operator::GlobalGet { global_index } => {
+                    ConstOp::GlobalGet(GlobalIndex::from_u32(global_index))
+                }
+                Operator::RefI31 => ConstOp::RefI31,
+                Operator::RefNull { hty: _ } => ConstOp::RefNull,
+                Operator::RefFunc { function_index } => {
+                    ConstOp::RefFunc(FuncIndex::from_u32(function_index))
+                }
+                Operator::I32Add => ConstOp::I32Add,
+                Operator::I32Sub => ConstOp::I32Sub,
+                Operator::I32Mul => ConstOp::I32Mul,
+                Operator::I64Add => ConstOp::I64Add,
+                Operator::I64Sub => ConstOp::I64Sub,
+                Operator::I64Mul => ConstOp::I64Mul,
+                s => {
+                    return Err(WasmError::Unsupported(format!(
+                        "unsupported operator in constant expression: {:?}",
+                        s
+                    )));
+                }
+            };
+            ops.push(op);
+        }
+        Ok(ConstExpr { ops: ops.into() })
+    }
+
+    /// Returns the operators of this expression, in order.
+    pub fn ops(&self) -> &[ConstOp] {
+        &self.ops
+    }
+
+    /// Evaluates this expression, which must produce an `i32`, `i64` or
+    /// `i31ref`, to its value zero-extended to a `u64`.
+    ///
+    /// An `i31ref` evaluates to its raw bit representation. The values of
+    /// globals are provided by `get_global`, zero-extended in the same way.
+    ///
+    /// Returns `None` if `get_global` returns `None`, for example because
+    /// globals aren't known at compile time, or if this isn't an integer
+    /// expression.
+    pub fn eval_int(&self, mut get_global: impl FnMut(GlobalIndex) -> Option<u64>) -> Option<u64> {
+        let mut stack: Vec<u64> = Vec::with_capacity(self.ops.len());
+        for op in self.ops.iter() {
+            let val = match *op {
+                ConstOp::I32Const(x) => u64::from(x.unsigned()),
+                ConstOp::I64Const(x) => x.unsigned(),
+                ConstOp::GlobalGet(index) => get_global(index)?,
+                ConstOp::RefI31 => {
+                    let x = stack.pop()? as u32;
+                    u64::from((x << 1) | I31_TAG)
+                }
+                ConstOp::I32Add | ConstOp::I32Sub | ConstOp::I32Mul => {
+                    let b = stack.pop()? as u32;
+                    let a = stack.pop()? as u32;
+                    u64::from(match op {
+                        ConstOp::I32Add => a.wrapping_add(b),
+                        ConstOp::I32Sub => a.wrapping_sub(b),
+                        _ => a.wrapping_mul(b),
+                    })
+                }
+                ConstOp::I64Add | ConstOp::I64Sub | ConstOp::I64Mul => {
+                    let b = stack.pop()?;
+                    let a = stack.pop()?;
+                    match op {
+                        ConstOp::I64Add => a.wrapping_add(b),
+                        ConstOp::I64Sub => a.wrapping_sub(b),
+                        _ => a.wrapping_mul(b),
+                    }
+                }
+                ConstOp::F32Const(_)
+                | ConstOp::F64Const(_)
+                | ConstOp::V128Const(_)
+                | ConstOp::RefNull
+                | ConstOp::RefFunc(_) => return None,
+            };
+            stack.push(val);
+        }
+        match stack[..] {
+            [val] => Some(val),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn eval(wat: &str, globals: &[u64]) -> Option<u64> {
+        let wasm = wat::parse_str(wat).unwrap();
+        let global = wasmparser::Parser::new(0)
+            .parse_all(&wasm)
+            .find_map(|payload| match payload.unwrap() {
+                wasmparser::Payload::GlobalSection(s) => Some(s.into_iter().last()?.unwrap()),
+                _ => None,
+            })
+            .unwrap();
+        let expr = ConstExpr::from_wasmparser(global.init_expr).unwrap();
+        expr.eval_int(|g| globals.get(g.as_u32() as usize).copied())
+    }
+
+    #[test]
+    fn eval_int() {
+        assert_eq!(
+            eval("(module (global i32 (i32.const -1)))", &[]),
+            Some(0xffff_ffff)
+        );
+        assert_eq!(
+            eval("(module (global i64 (i64.const -1)))", &[]),
+            Some(u64::MAX)
+        );
+        assert_eq!(
+            eval(
+                "(module (global i32 (i32.add (i32.const 0x7fffffff) (i32.const 2))))",
+                &[]
+            ),
+            Some(0x8000_0001)
+        );
+        assert_eq!(
+            eval(
+                "(module (global i32 (i32.sub (i32.const 0) (i32.const 1))))",
+                &[]
+            ),
+            Some(0xffff_ffff)
+        );
+        assert_eq!(
+            eval(
+                "(module
+                    (import \"\" \"g\" (global i64))
+                    (global i64 (i64.mul (global.get 0) (i64.const 3))))",
+                &[5]
+            ),
+            Some(15)
+        );
+        assert_eq!(
+            eval(
+                "(module
+                    (import \"\" \"g\" (global i32))
+                    (global i32 (i32.add (global.get 0) (i32.const 1))))",
+                &[]
+            ),
+            None
+        );
+        assert_eq!(eval("(module (global f32 (f32.const 1)))", &[]), None);
+    }
+}
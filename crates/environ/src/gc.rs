@@ -0,0 +1,150 @@
+//! Layout of the objects in a store's GC heap.
+//!
+//! References in the `any` hierarchy which aren't `i31ref`s are byte offsets
+//! of objects within the GC heap of the store they belong to. Every object
+//! starts with a header of two 32-bit words:
+//!
+//! * the engine-wide index of the object's `struct` or `array` type, and
+//! * the size of the object in bytes, including the header, whose low bits
+//!   are used as flags (see [`GC_HEADER_ARRAY_BIT`]).
+//!
+//! A struct's fields follow its header, each aligned to its size. An array
+//! stores its length after its header and its elements after that. Objects
+//! are always [`GC_ALIGN`]-aligned, so their offsets never have the
+//! [`I31_TAG`](crate::I31_TAG) bit set.
+
+use crate::{WasmFieldType, WasmGcType, WasmGcTypeKind, WasmStorageType, WasmType};
+use serde_derive::{Deserialize, Serialize};
+
+/// The alignment, in bytes, of every object in a GC heap.
+pub const GC_ALIGN: u32 = 8;
+
+/// The offset of the type index in an object's header.
+pub const GC_HEADER_TYPE_OFFSET: u32 = 0;
+
+/// The offset of the size and flags word in an object's header.
+pub const GC_HEADER_SIZE_OFFSET: u32 = 4;
+
+/// The flag in an object's size word which is set for arrays.
+pub const GC_HEADER_ARRAY_BIT: u32 = 1;
+
+/// The size of an object's header in bytes.
+pub const GC_HEADER_SIZE: u32 = 8;
+
+/// The offset of an array's length.
+pub const GC_ARRAY_LENGTH_OFFSET: u32 = GC_HEADER_SIZE;
+
+/// The offset of an array's first element.
+pub const GC_ARRAY_ELEMS_OFFSET: u32 = GC_HEADER_SIZE + 8;
+
+/// How a struct field or array element is stored in the GC heap.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[allow(missing_docs)]
+pub enum GcStorage {
+    I8,
+    I16,
+    I32,
+    I64,
+    F32,
+    F64,
+    V128,
+    /// A 32-bit reference in the `any` hierarchy, which is traced by the
+    /// collector.
+    AnyRef,
+    /// A `*mut VMFuncRef`, which is owned by an instance in the store and so
+    /// doesn't need to be traced.
+    FuncRef,
+}
+
+impl GcStorage {
+    /// Returns how values of the storage type `ty` are stored.
+    ///
+    /// # Panics
+    ///
+    /// Panics for `externref`s and `exnref`s, which can't be stored in the GC
+    /// heap.
+    pub fn new(ty: &WasmStorageType) -> GcStorage {
+        match ty {
+            WasmStorageType::I8 => GcStorage::I8,
+            WasmStorageType::I16 => GcStorage::I16,
+            WasmStorageType::Val(WasmType::I32) => GcStorage::I32,
+            WasmStorageType::Val(WasmType::I64) => GcStorage::I64,
+            WasmStorageType::Val(WasmType::F32) => GcStorage::F32,
+            WasmStorageType::Val(WasmType::F64) => GcStorage::F64,
+            WasmStorageType::Val(WasmType::V128) => GcStorage::V128,
+            WasmStorageType::Val(WasmType::Ref(r)) if r.heap_type.is_anyref() => GcStorage::AnyRef,
+            WasmStorageType::Val(WasmType::Ref(r)) => {
+                assert!(!r.heap_type.is_vmexternref());
+                GcStorage::FuncRef
+            }
+        }
+    }
+
+    /// Returns the size, in bytes, of values stored this way.
+    pub fn size(&self) -> u32 {
+        match self {
+            GcStorage::I8 => 1,
+            GcStorage::I16 => 2,
+            GcStorage::I32 | GcStorage::F32 | GcStorage::AnyRef => 4,
+            GcStorage::I64 | GcStorage::F64 | GcStorage::FuncRef => 8,
+            GcStorage::V128 => 16,
+        }
+    }
+}
+
+/// The layout of the objects of a `struct` or `array` type.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum GcLayout {
+    /// A struct of the given size, in bytes, whose fields are stored at the
+    /// given offsets.
+    Struct {
+        /// The size of the struct, including its header.
+        size: u32,
+        /// The offset of and the way each field is stored.
+        fields: Box<[(u32, GcStorage)]>,
+    },
+    /// An array whose elements are stored this way.
+    Array(GcStorage),
+}
+
+impl GcLayout {
+    /// Computes the layout of objects of type `ty`.
+    pub fn new(ty: &WasmGcType) -> GcLayout {
+        match &ty.kind {
+            WasmGcTypeKind::Struct(fields) => {
+                let mut size = GC_HEADER_SIZE;
+                let fields = fields
+                    .iter()
+                    .map(|field: &WasmFieldType| {
+                        let storage = GcStorage::new(&field.element_type);
+                        let align = storage.size().min(GC_ALIGN);
+                        let offset = (size + align - 1) & !(align - 1);
+                        size = offset + storage.size();
+                        (offset, storage)
+                    })
+                    .collect();
+                GcLayout::Struct {
+                    size: (size + GC_ALIGN - 1) & !(GC_ALIGN - 1),
+                    fields,
+                }
+            }
+            WasmGcTypeKind::Array(elem) => GcLayout::Array(GcStorage::new(&elem.element_type)),
+        }
+    }
+
+    /// Returns the size, in bytes, of an array with `len` elements of this
+    /// layout, or `None` if it's too large to be allocated.
+    ///
+    /// # Panics
+    ///
+    /// Panics if this is the layout of a struct.
+    pub fn array_size(&self, len: u32) -> Option<u32> {
+        let elem = match self {
+            GcLayout::Array(elem) => elem,
+            GcLayout::Struct { .. } => panic!("not an array layout"),
+        };
+        let size = u64::from(len) * u64::from(elem.size()) + u64::from(GC_ARRAY_ELEMS_OFFSET);
+        let size = (size + u64::from(GC_ALIGN) - 1) & !u64::from(GC_ALIGN - 1);
+        u32::try_from(size).ok()
+    }
+}
@@ -0,0 +1,78 @@
+//! Layout of the wasm-level state which compiled code passes to the runtime
+//! when it stops at a breakpoint, see [`Tunables::guest_debug`].
+//!
+//! The state is an array of slots, each [`DEBUG_SLOT_SIZE`] bytes large, with
+//! the function's locals first followed by the values on the operand stack,
+//! bottom-most first. Each slot contains the value itself in the same format
+//! as a `ValRaw`, and a [`DebugValType`] at [`DEBUG_SLOT_TYPE_OFFSET`].
+//!
+//! [`Tunables::guest_debug`]: crate::Tunables::guest_debug
+
+use crate::{WasmHeapType, WasmType};
+
+/// The size, in bytes, of each slot of the wasm-level state.
+pub const DEBUG_SLOT_SIZE: u32 = 32;
+
+/// The offset within a slot at which the type of its value is stored.
+pub const DEBUG_SLOT_TYPE_OFFSET: u32 = 16;
+
+/// The type of a value stored in a slot of the wasm-level state.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[repr(u8)]
+#[allow(missing_docs)]
+pub enum DebugValType {
+    I32,
+    I64,
+    F32,
+    F64,
+    V128,
+    FuncRef,
+    ExternRef,
+    AnyRef,
+    ExnRef,
+}
+
+impl DebugValType {
+    /// Returns the type of slots which hold values of the wasm type `ty`.
+    pub fn from_wasm_type(ty: &WasmType) -> DebugValType {
+        match ty {
+            WasmType::I32 => DebugValType::I32,
+            WasmType::I64 => DebugValType::I64,
+            WasmType::F32 => DebugValType::F32,
+            WasmType::F64 => DebugValType::F64,
+            WasmType::V128 => DebugValType::V128,
+            WasmType::Ref(r) => match r.heap_type {
+                WasmHeapType::Func | WasmHeapType::TypedFunc(_) | WasmHeapType::NoFunc => {
+                    DebugValType::FuncRef
+                }
+                WasmHeapType::Extern | WasmHeapType::NoExtern => DebugValType::ExternRef,
+                WasmHeapType::Exn => DebugValType::ExnRef,
+                WasmHeapType::Any
+                | WasmHeapType::Eq
+                | WasmHeapType::I31
+                | WasmHeapType::Struct
+                | WasmHeapType::Array
+                | WasmHeapType::TypedStruct(_)
+                | WasmHeapType::TypedArray(_)
+                | WasmHeapType::None => DebugValType::AnyRef,
+            },
+        }
+    }
+
+    /// Decodes the type stored in a slot, returning `None` if `byte` isn't a
+    /// valid type.
+    pub fn from_u8(byte: u8) -> Option<DebugValType> {
+        Some(match byte {
+            0 => DebugValType::I32,
+            1 => DebugValType::I64,
+            2 => DebugValType::F32,
+            3 => DebugValType::F64,
+            4 => DebugValType::V128,
+            5 => DebugValType::FuncRef,
+            6 => DebugValType::ExternRef,
+            7 => DebugValType::AnyRef,
+            8 => DebugValType::ExnRef,
+            _ => return None,
+        })
+    }
+}
@@ -9,7 +9,10 @@
 mod address_map;
 mod builtin;
 mod compilation;
+mod const_expr;
 mod demangling;
+mod gc;
+mod guest_debug;
 mod instantiate;
 mod module;
 mod module_environ;
@@ -25,7 +28,10 @@ mod vmoffsets;
 pub use crate::address_map::*;
 pub use crate::builtin::*;
 pub use crate::compilation::*;
+pub use crate::const_expr::*;
 pub use crate::demangling::*;
+pub use crate::gc::*;
+pub use crate::guest_debug::*;
 pub use crate::module::*;
 pub use crate::module_environ::*;
 pub use crate::module_types::*;
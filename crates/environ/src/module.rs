@@ -1,6 +1,6 @@
 //! Data structures for representing decoded wasm modules.
 
-use crate::{ModuleTranslation, PrimaryMap, Tunables, WasmHeapType, WASM_PAGE_SIZE};
+use crate::{ConstExpr, ModuleTranslation, PrimaryMap, Tunables, WasmHeapType, WASM_PAGE_SIZE};
 use cranelift_entity::{packed_option::ReservedValue, EntityRef};
 use indexmap::IndexMap;
 use serde_derive::{Deserialize, Serialize};
@@ -104,10 +104,8 @@ impl MemoryPlan {
 pub struct MemoryInitializer {
     /// The index of a linear memory to initialize.
     pub memory_index: MemoryIndex,
-    /// Optionally, a global variable giving a base index.
-    pub base: Option<GlobalIndex>,
-    /// The offset to add to the base.
-    pub offset: u64,
+    /// The constant expression computing the offset to write the data at.
+    pub offset: ConstExpr,
     /// The range of the data to write within the linear memory.
     ///
     /// This range indexes into a separately stored data section which will be
@@ -128,7 +126,7 @@ pub struct StaticMemoryInitializer {
 }
 
 /// The type of WebAssembly linear memory initialization to use for a module.
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub enum MemoryInitialization {
     /// Memory initialization is segmented.
     ///
@@ -136,7 +134,7 @@ pub enum MemoryInitialization {
     /// if:
     ///
     /// * A data segment referenced an imported memory.
-    /// * A data segment uses a global base.
+    /// * A data segment offset uses a global.
     ///
     /// Segmented initialization is performed by processing the complete set of
     /// data segments when the module is instantiated.
@@ -148,7 +146,7 @@ pub enum MemoryInitialization {
     /// or otherwise simply making the defined data visible.
     ///
     /// To be statically initialized everything must reference a defined memory
-    /// and all data segments have a statically known in-bounds base (no
+    /// and all data segments have a statically known in-bounds offset (no
     /// globals).
     ///
     /// This form of memory initialization is a more optimized version of
@@ -450,17 +448,18 @@ impl ModuleTranslation<'_> {
                 None => break,
             };
 
-            // If the base of this segment is dynamic, then we can't
+            // If the offset of this segment is dynamic, then we can't
             // include it in the statically-built array of initial
             // contents.
-            if segment.base.is_some() {
-                break;
-            }
+            let offset = match segment.offset.eval_int(|_| None) {
+                Some(offset) => u32::try_from(offset).unwrap(),
+                None => break,
+            };
 
             // Get the end of this segment. If out-of-bounds, or too
             // large for our dense table representation, then skip the
             // segment.
-            let top = match segment.offset.checked_add(segment.elements.len() as u32) {
+            let top = match offset.checked_add(segment.elements.len() as u32) {
                 Some(top) => top,
                 None => break,
             };
@@ -473,14 +472,15 @@ impl ModuleTranslation<'_> {
                 .table
                 .wasm_ty
                 .heap_type
+                .top()
             {
-                WasmHeapType::Func | WasmHeapType::TypedFunc(_) => {}
+                WasmHeapType::Func => {}
                 // If this is not a funcref table, then we can't support a
                 // pre-computed table of function indices. Technically this
                 // initializer won't trap so we could continue processing
                 // segments, but that's left as a future optimization if
                 // necessary.
-                WasmHeapType::Extern => break,
+                _ => break,
             }
 
             let precomputed =
@@ -504,7 +504,7 @@ impl ModuleTranslation<'_> {
             if precomputed.len() < top as usize {
                 precomputed.resize(top as usize, FuncIndex::reserved_value());
             }
-            let dst = &mut precomputed[(segment.offset as usize)..(top as usize)];
+            let dst = &mut precomputed[(offset as usize)..(top as usize)];
             dst.copy_from_slice(&segment.elements[..]);
 
             // advance the iterator to see the next segment
@@ -594,8 +594,7 @@ impl MemoryInitialization {
         for initializer in initializers {
             let MemoryInitializer {
                 memory_index,
-                base,
-                offset,
+                ref offset,
                 ref data,
             } = *initializer;
 
@@ -604,16 +603,13 @@ impl MemoryInitialization {
             // Note that this can bail if we don't have access to globals yet
             // (e.g. this is a task happening before instantiation at
             // compile-time).
-            let base = match base {
-                Some(index) => match &init {
-                    InitMemory::Runtime {
-                        get_global_as_u64, ..
-                    } => get_global_as_u64(state, index),
-                    InitMemory::CompileTime(_) => return false,
-                },
-                None => 0,
+            let start = match &init {
+                InitMemory::Runtime {
+                    get_global_as_u64, ..
+                } => offset.eval_int(|index| Some(get_global_as_u64(state, index))),
+                InitMemory::CompileTime(_) => offset.eval_int(|_| None),
             };
-            let start = match base.checked_add(offset) {
+            let start = match start {
                 Some(start) => start,
                 None => return false,
             };
@@ -718,7 +714,7 @@ impl TablePlan {
 }
 
 /// Table initialization data for all tables in the module.
-#[derive(Debug, Default, Serialize, Deserialize)]
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
 pub struct TableInitialization {
     /// Initial values for tables defined within the module itself.
     ///
@@ -765,10 +761,9 @@ pub enum TableInitialValue {
 pub struct TableSegment {
     /// The index of a table to initialize.
     pub table_index: TableIndex,
-    /// Optionally, a global variable giving a base index.
-    pub base: Option<GlobalIndex>,
-    /// The offset to add to the base.
-    pub offset: u32,
+    /// The constant expression computing the offset of the first element
+    /// written.
+    pub offset: ConstExpr,
     /// The values to write into the table elements.
     pub elements: Box<[FuncIndex]>,
 }
@@ -781,6 +776,8 @@ pub struct TableSegment {
 #[allow(missing_docs)]
 pub enum ModuleType {
     Function(SignatureIndex),
+    Struct(GcTypeIndex),
+    Array(GcTypeIndex),
 }
 
 impl ModuleType {
@@ -789,13 +786,23 @@ impl ModuleType {
     pub fn unwrap_function(&self) -> SignatureIndex {
         match self {
             ModuleType::Function(f) => *f,
+            _ => panic!("not a function type"),
+        }
+    }
+
+    /// Returns the heap type of references to this type.
+    pub fn heap_type(&self) -> WasmHeapType {
+        match *self {
+            ModuleType::Function(f) => WasmHeapType::TypedFunc(f),
+            ModuleType::Struct(t) => WasmHeapType::TypedStruct(t),
+            ModuleType::Array(t) => WasmHeapType::TypedArray(t),
         }
     }
 }
 
 /// A translated WebAssembly module, excluding the function bodies and
 /// memory initializers.
-#[derive(Default, Debug, Serialize, Deserialize)]
+#[derive(Clone, Default, Debug, Serialize, Deserialize)]
 pub struct Module {
     /// The name of this wasm module, often found in the wasm file.
     pub name: Option<String>,
@@ -839,6 +846,9 @@ pub struct Module {
     /// Number of imported or aliased globals in the module.
     pub num_imported_globals: usize,
 
+    /// Number of imported or aliased tags in the module.
+    pub num_imported_tags: usize,
+
     /// Number of functions that "escape" from this module may need to have a
     /// `VMFuncRef` constructed for them.
     ///
@@ -859,12 +869,15 @@ pub struct Module {
     pub globals: PrimaryMap<GlobalIndex, Global>,
 
     /// WebAssembly global initializers for locally-defined globals.
-    pub global_initializers: PrimaryMap<DefinedGlobalIndex, GlobalInit>,
+    pub global_initializers: PrimaryMap<DefinedGlobalIndex, ConstExpr>,
+
+    /// WebAssembly exception tags.
+    pub tags: PrimaryMap<TagIndex, Tag>,
 }
 
 /// Initialization routines for creating an instance, encompassing imports,
 /// modules, instances, aliases, etc.
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub enum Initializer {
     /// An imported item is required to be provided.
     Import {
@@ -1006,6 +1019,29 @@ impl Module {
         index.index() < self.num_imported_globals
     }
 
+    /// Convert a `DefinedTagIndex` into a `TagIndex`.
+    #[inline]
+    pub fn tag_index(&self, defined_tag: DefinedTagIndex) -> TagIndex {
+        TagIndex::new(self.num_imported_tags + defined_tag.index())
+    }
+
+    /// Convert a `TagIndex` into a `DefinedTagIndex`. Returns None if the
+    /// index is an imported tag.
+    #[inline]
+    pub fn defined_tag_index(&self, tag: TagIndex) -> Option<DefinedTagIndex> {
+        if tag.index() < self.num_imported_tags {
+            None
+        } else {
+            Some(DefinedTagIndex::new(tag.index() - self.num_imported_tags))
+        }
+    }
+
+    /// Test whether the given tag index is for an imported tag.
+    #[inline]
+    pub fn is_imported_tag(&self, index: TagIndex) -> bool {
+        index.index() < self.num_imported_tags
+    }
+
     /// Returns an iterator of all the imports in this module, along with their
     /// module name, field name, and type that's being imported.
     pub fn imports(&self) -> impl ExactSizeIterator<Item = (&str, &str, EntityType)> {
@@ -1023,6 +1059,7 @@ impl Module {
             EntityIndex::Table(i) => EntityType::Table(self.table_plans[i].table),
             EntityIndex::Memory(i) => EntityType::Memory(self.memory_plans[i].memory),
             EntityIndex::Function(i) => EntityType::Function(self.functions[i].signature),
+            EntityIndex::Tag(i) => EntityType::Tag(self.tags[i]),
         }
     }
 
@@ -1050,7 +1087,7 @@ impl Module {
 }
 
 /// Type information about functions in a wasm module.
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct FunctionType {
     /// The type of this function, indexed into the module-wide type tables for
     /// a module compilation.
@@ -3,10 +3,10 @@ use crate::module::{
     ModuleType, TablePlan, TableSegment,
 };
 use crate::{
-    DataIndex, DefinedFuncIndex, ElemIndex, EntityIndex, EntityType, FuncIndex, GlobalIndex,
-    GlobalInit, MemoryIndex, ModuleTypesBuilder, PrimaryMap, SignatureIndex, TableIndex,
-    TableInitialValue, Tunables, TypeConvert, TypeIndex, Unsigned, WasmError, WasmHeapType,
-    WasmResult, WasmType, WasmparserTypeConverter,
+    ConstExpr, ConstOp, DataIndex, DefinedFuncIndex, ElemIndex, EntityIndex, EntityType, FuncIndex,
+    GlobalIndex, MemoryIndex, ModuleTypesBuilder, PrimaryMap, SignatureIndex, TableIndex,
+    TableInitialValue, Tag, TagIndex, Tunables, TypeConvert, TypeIndex, WasmError, WasmGcType,
+    WasmGcTypeKind, WasmHeapType, WasmResult, WasmStorageType, WasmType, WasmparserTypeConverter,
 };
 use cranelift_entity::packed_option::ReservedValue;
 use std::borrow::Cow;
@@ -20,6 +20,16 @@ use wasmparser::{
     ExternalKind, FuncToValidate, FunctionBody, NameSectionReader, Naming, Operator, Parser,
     Payload, TypeRef, Validator, ValidatorResources,
 };
+use wasmtime_types::wasm_unsupported;
+
+/// Tables of references to GC objects (i.e. the `any` hierarchy) and of
+/// exception references don't have a runtime representation yet.
+fn check_table_type(table: &crate::Table) -> WasmResult<()> {
+    match table.wasm_ty.heap_type.top() {
+        WasmHeapType::Func | WasmHeapType::Extern => Ok(()),
+        _ => Err(wasm_unsupported!("tables of type `{}`", table.wasm_ty)),
+    }
+}
 
 /// Object containing the standalone environment information.
 pub struct ModuleEnvironment<'a, 'data> {
@@ -238,10 +248,21 @@ impl<'a, 'data> ModuleEnvironment<'a, 'data> {
                 self.result.module.types.reserve(num);
                 self.types.reserve_wasm_signatures(num);
 
-                for i in 0..types.count() {
-                    let types = self.validator.types(0).unwrap();
-                    let ty = types.core_type_at(i);
-                    self.declare_type(ty.unwrap_sub())?;
+                let mut type_index = self.result.module.types.len() as u32;
+                for rec_group in types {
+                    let rec_group = rec_group?;
+                    // Type canonicalization of recursion groups isn't
+                    // implemented yet, so only the implicit single-type
+                    // groups are supported.
+                    if rec_group.types().len() != 1 {
+                        return Err(wasm_unsupported!("recursion groups with multiple types"));
+                    }
+                    for _ in rec_group.types() {
+                        let types = self.validator.types(0).unwrap();
+                        let ty = types.core_type_at(type_index);
+                        self.declare_type(ty.unwrap_sub())?;
+                        type_index += 1;
+                    }
                 }
             }
 
@@ -271,11 +292,14 @@ impl<'a, 'data> ModuleEnvironment<'a, 'data> {
                         }
                         TypeRef::Table(ty) => {
                             self.result.module.num_imported_tables += 1;
-                            EntityType::Table(self.convert_table_type(&ty))
+                            let table = self.convert_table_type(&ty);
+                            check_table_type(&table)?;
+                            EntityType::Table(table)
+                        }
+                        TypeRef::Tag(ty) => {
+                            self.result.module.num_imported_tags += 1;
+                            EntityType::Tag(self.convert_tag_type(ty)?)
                         }
-
-                        // doesn't get past validation
-                        TypeRef::Tag(_) => unreachable!(),
                     };
                     self.declare_import(import.module, import.name, ty);
                 }
@@ -303,6 +327,7 @@ impl<'a, 'data> ModuleEnvironment<'a, 'data> {
                 for entry in tables {
                     let wasmparser::Table { ty, init } = entry?;
                     let table = self.convert_table_type(&ty);
+                    check_table_type(&table)?;
                     let plan = TablePlan::for_table(table, &self.tunables);
                     self.result.module.table_plans.push(plan);
                     let init = match init {
@@ -353,9 +378,10 @@ impl<'a, 'data> ModuleEnvironment<'a, 'data> {
             Payload::TagSection(tags) => {
                 self.validator.tag_section(&tags)?;
 
-                // This feature isn't enabled at this time, so we should
-                // never get here.
-                unreachable!();
+                for entry in tags {
+                    let tag = self.convert_tag_type(entry?)?;
+                    self.result.module.tags.push(tag);
+                }
             }
 
             Payload::GlobalSection(globals) => {
@@ -366,31 +392,7 @@ impl<'a, 'data> ModuleEnvironment<'a, 'data> {
 
                 for entry in globals {
                     let wasmparser::Global { ty, init_expr } = entry?;
-                    let mut init_expr_reader = init_expr.get_binary_reader();
-                    let initializer = match init_expr_reader.read_operator()? {
-                        Operator::I32Const { value } => GlobalInit::I32Const(value),
-                        Operator::I64Const { value } => GlobalInit::I64Const(value),
-                        Operator::F32Const { value } => GlobalInit::F32Const(value.bits()),
-                        Operator::F64Const { value } => GlobalInit::F64Const(value.bits()),
-                        Operator::V128Const { value } => {
-                            GlobalInit::V128Const(u128::from_le_bytes(*value.bytes()))
-                        }
-                        Operator::RefNull { hty: _ } => GlobalInit::RefNullConst,
-                        Operator::RefFunc { function_index } => {
-                            let index = FuncIndex::from_u32(function_index);
-                            self.flag_func_escaped(index);
-                            GlobalInit::RefFunc(index)
-                        }
-                        Operator::GlobalGet { global_index } => {
-                            GlobalInit::GetGlobal(GlobalIndex::from_u32(global_index))
-                        }
-                        s => {
-                            return Err(WasmError::Unsupported(format!(
-                                "unsupported init expr in global section: {:?}",
-                                s
-                            )));
-                        }
-                    };
+                    let initializer = self.const_expr(init_expr)?;
                     let ty = self.convert_global_type(&ty);
                     self.result.module.globals.push(ty);
                     self.result.module.global_initializers.push(initializer);
@@ -414,9 +416,7 @@ impl<'a, 'data> ModuleEnvironment<'a, 'data> {
                         ExternalKind::Table => EntityIndex::Table(TableIndex::from_u32(index)),
                         ExternalKind::Memory => EntityIndex::Memory(MemoryIndex::from_u32(index)),
                         ExternalKind::Global => EntityIndex::Global(GlobalIndex::from_u32(index)),
-
-                        // this never gets past validation
-                        ExternalKind::Tag => unreachable!(),
+                        ExternalKind::Tag => EntityIndex::Tag(TagIndex::from_u32(index)),
                     };
                     self.result
                         .module
@@ -487,19 +487,7 @@ impl<'a, 'data> ModuleEnvironment<'a, 'data> {
                             offset_expr,
                         } => {
                             let table_index = TableIndex::from_u32(table_index.unwrap_or(0));
-                            let mut offset_expr_reader = offset_expr.get_binary_reader();
-                            let (base, offset) = match offset_expr_reader.read_operator()? {
-                                Operator::I32Const { value } => (None, value.unsigned()),
-                                Operator::GlobalGet { global_index } => {
-                                    (Some(GlobalIndex::from_u32(global_index)), 0)
-                                }
-                                ref s => {
-                                    return Err(WasmError::Unsupported(format!(
-                                        "unsupported init expr in element section: {:?}",
-                                        s
-                                    )));
-                                }
-                            };
+                            let offset = self.const_expr(offset_expr)?;
 
                             self.result
                                 .module
@@ -507,7 +495,6 @@ impl<'a, 'data> ModuleEnvironment<'a, 'data> {
                                 .segments
                                 .push(TableSegment {
                                     table_index,
-                                    base,
                                     offset,
                                     elements: elements.into(),
                                 });
@@ -607,24 +594,10 @@ impl<'a, 'data> ModuleEnvironment<'a, 'data> {
                         } => {
                             let range = mk_range(&mut self.result.total_data)?;
                             let memory_index = MemoryIndex::from_u32(memory_index);
-                            let mut offset_expr_reader = offset_expr.get_binary_reader();
-                            let (base, offset) = match offset_expr_reader.read_operator()? {
-                                Operator::I32Const { value } => (None, value.unsigned().into()),
-                                Operator::I64Const { value } => (None, value.unsigned()),
-                                Operator::GlobalGet { global_index } => {
-                                    (Some(GlobalIndex::from_u32(global_index)), 0)
-                                }
-                                s => {
-                                    return Err(WasmError::Unsupported(format!(
-                                        "unsupported init expr in data section: {:?}",
-                                        s
-                                    )));
-                                }
-                            };
+                            let offset = ConstExpr::from_wasmparser(offset_expr)?;
 
                             initializers.push(MemoryInitializer {
                                 memory_index,
-                                base,
                                 offset,
                                 data: range,
                             });
@@ -778,10 +751,43 @@ and for re-adding support for interface types you can see this issue:
                 EntityIndex::Memory(self.result.module.memory_plans.push(plan))
             }
             EntityType::Global(ty) => EntityIndex::Global(self.result.module.globals.push(ty)),
-            EntityType::Tag(_) => unimplemented!(),
+            EntityType::Tag(ty) => EntityIndex::Tag(self.result.module.tags.push(ty)),
+        }
+    }
+
+    fn convert_tag_type(&self, ty: wasmparser::TagType) -> WasmResult<Tag> {
+        match ty.kind {
+            wasmparser::TagKind::Exception => {
+                let index = TypeIndex::from_u32(ty.func_type_idx);
+                let signature = self.result.module.types[index].unwrap_function();
+                // The payloads of pending exceptions aren't traced by the GC
+                // heap's collector, so they can't hold references to objects.
+                for param in self.types[signature].params() {
+                    if let WasmType::Ref(r) = param {
+                        if r.heap_type.is_anyref()
+                            && !matches!(r.heap_type, WasmHeapType::I31 | WasmHeapType::None)
+                        {
+                            return Err(wasm_unsupported!("exception payloads of type `{}`", r));
+                        }
+                    }
+                }
+                Ok(Tag { signature })
+            }
         }
     }
 
+    /// Converts a constant expression, flagging any functions it references
+    /// as escaping.
+    fn const_expr(&mut self, expr: wasmparser::ConstExpr<'_>) -> WasmResult<ConstExpr> {
+        let expr = ConstExpr::from_wasmparser(expr)?;
+        for op in expr.ops() {
+            if let ConstOp::RefFunc(index) = *op {
+                self.flag_func_escaped(index);
+            }
+        }
+        Ok(expr)
+    }
+
     fn flag_func_escaped(&mut self, func: FuncIndex) {
         let ty = &mut self.result.module.functions[func];
         // If this was already assigned a funcref index no need to re-assign it.
@@ -794,21 +800,74 @@ and for re-adding support for interface types you can see this issue:
     }
 
     fn declare_type(&mut self, id: CoreTypeId) -> WasmResult<()> {
+        // Validation canonicalizes identical types to the same `id`, so share
+        // the definition of any type seen before.
+        if let Some(ty) = self.types.lookup(id) {
+            self.result.module.types.push(ty);
+            return Ok(());
+        }
         let types = self.validator.types(0).unwrap();
         let ty = &types[id];
-        assert!(ty.is_final);
-        assert!(ty.supertype_idx.is_none());
-        match &ty.composite_type {
-            CompositeType::Func(ty) => {
-                let wasm = self.convert_func_type(ty);
+        let (kind, is_array) = match &ty.composite_type {
+            CompositeType::Func(func) => {
+                if ty.supertype_idx.is_some() || !ty.is_final {
+                    return Err(wasm_unsupported!("subtyping of function types"));
+                }
+                let wasm = self.convert_func_type(func);
                 let sig_index = self.types.wasm_func_type(id, wasm);
                 self.result
                     .module
                     .types
                     .push(ModuleType::Function(sig_index));
+                return Ok(());
+            }
+            CompositeType::Struct(_) => (ty.composite_type.clone(), false),
+            CompositeType::Array(_) => (ty.composite_type.clone(), true),
+        };
+        let supertype = ty.supertype_idx.map(|idx| {
+            let supertype = idx.as_core_type_id().unwrap();
+            match self.types.lookup(supertype).unwrap() {
+                ModuleType::Struct(t) | ModuleType::Array(t) => t,
+                ModuleType::Function(_) => unreachable!(),
+            }
+        });
+        let is_final = ty.is_final;
+
+        let index = self.types.start_gc_type(id, is_array);
+        let kind = match &kind {
+            CompositeType::Struct(s) => WasmGcTypeKind::Struct(
+                s.fields
+                    .iter()
+                    .map(|f| self.convert_field_type(f))
+                    .collect(),
+            ),
+            CompositeType::Array(a) => WasmGcTypeKind::Array(self.convert_field_type(&a.0)),
+            CompositeType::Func(_) => unreachable!(),
+        };
+        let fields = match &kind {
+            WasmGcTypeKind::Struct(fields) => &fields[..],
+            WasmGcTypeKind::Array(elem) => std::slice::from_ref(elem),
+        };
+        for field in fields {
+            if let WasmStorageType::Val(WasmType::Ref(r)) = field.element_type {
+                if r.heap_type.is_vmexternref() {
+                    return Err(wasm_unsupported!("`{}` fields in GC types", r));
+                }
             }
-            CompositeType::Array(_) | CompositeType::Struct(_) => unimplemented!(),
         }
+        self.types.finish_gc_type(
+            index,
+            WasmGcType {
+                supertype,
+                is_final,
+                kind,
+            },
+        );
+        self.result.module.types.push(if is_array {
+            ModuleType::Array(index)
+        } else {
+            ModuleType::Struct(index)
+        });
         Ok(())
     }
 
@@ -1,6 +1,6 @@
 use crate::{
-    Module, ModuleType, PrimaryMap, SignatureIndex, TypeConvert, TypeIndex, WasmFuncType,
-    WasmHeapType,
+    GcTypeIndex, Module, ModuleType, PrimaryMap, SignatureIndex, TypeConvert, TypeIndex,
+    WasmFuncType, WasmGcType, WasmHeapType,
 };
 use serde_derive::{Deserialize, Serialize};
 use std::collections::HashMap;
@@ -10,8 +10,8 @@ use wasmparser::UnpackedIndex;
 
 /// All types used in a core wasm module.
 ///
-/// At this time this only contains function types. Note, though, that function
-/// types are deduplicated within this [`ModuleTypes`].
+/// This contains function types, which are deduplicated within this
+/// [`ModuleTypes`], and the `struct` and `array` types of the GC proposal.
 ///
 /// Note that accesing this type is primarily done through the `Index`
 /// implementations for this type.
@@ -19,6 +19,7 @@ use wasmparser::UnpackedIndex;
 #[allow(missing_docs)]
 pub struct ModuleTypes {
     wasm_signatures: PrimaryMap<SignatureIndex, WasmFuncType>,
+    gc_types: PrimaryMap<GcTypeIndex, WasmGcType>,
 }
 
 impl ModuleTypes {
@@ -27,6 +28,12 @@ impl ModuleTypes {
     pub fn wasm_signatures(&self) -> impl Iterator<Item = (SignatureIndex, &WasmFuncType)> {
         self.wasm_signatures.iter()
     }
+
+    /// Returns an iterator over all the `struct` and `array` types found
+    /// within this module.
+    pub fn gc_types(&self) -> impl ExactSizeIterator<Item = (GcTypeIndex, &WasmGcType)> {
+        self.gc_types.iter()
+    }
 }
 
 impl Index<SignatureIndex> for ModuleTypes {
@@ -37,13 +44,22 @@ impl Index<SignatureIndex> for ModuleTypes {
     }
 }
 
+impl Index<GcTypeIndex> for ModuleTypes {
+    type Output = WasmGcType;
+
+    fn index(&self, ty: GcTypeIndex) -> &WasmGcType {
+        &self.gc_types[ty]
+    }
+}
+
 /// A builder for [`ModuleTypes`].
 #[derive(Default)]
 #[allow(missing_docs)]
 pub struct ModuleTypesBuilder {
     types: ModuleTypes,
     interned_func_types: HashMap<WasmFuncType, SignatureIndex>,
-    wasmparser_to_wasmtime: HashMap<CoreTypeId, SignatureIndex>,
+    wasmparser_to_wasmtime: HashMap<CoreTypeId, ModuleType>,
+    defining: Option<ModuleType>,
 }
 
 impl ModuleTypesBuilder {
@@ -57,10 +73,45 @@ impl ModuleTypesBuilder {
     /// at runtime.
     pub fn wasm_func_type(&mut self, id: CoreTypeId, sig: WasmFuncType) -> SignatureIndex {
         let sig = self.intern_func_type(sig);
-        self.wasmparser_to_wasmtime.insert(id, sig);
+        self.wasmparser_to_wasmtime
+            .insert(id, ModuleType::Function(sig));
         sig
     }
 
+    /// Returns the type previously defined for the canonicalized type `id`.
+    ///
+    /// Identical types in different recursion groups are canonicalized to the
+    /// same `id` by validation, so this is used to share one definition of
+    /// them.
+    pub fn lookup(&self, id: CoreTypeId) -> Option<ModuleType> {
+        self.wasmparser_to_wasmtime.get(&id).copied()
+    }
+
+    /// Reserves the `GcTypeIndex` for the `struct` (or `array`, per `is_array`)
+    /// type `id` whose definition is about to be converted.
+    ///
+    /// While it's being converted, references from the type to itself resolve
+    /// to the returned index.
+    pub fn start_gc_type(&mut self, id: CoreTypeId, is_array: bool) -> GcTypeIndex {
+        let index = GcTypeIndex::from_u32(self.types.gc_types.len() as u32);
+        let ty = if is_array {
+            ModuleType::Array(index)
+        } else {
+            ModuleType::Struct(index)
+        };
+        self.wasmparser_to_wasmtime.insert(id, ty);
+        self.defining = Some(ty);
+        index
+    }
+
+    /// Finishes the definition of the type started with
+    /// [`ModuleTypesBuilder::start_gc_type`].
+    pub fn finish_gc_type(&mut self, index: GcTypeIndex, ty: WasmGcType) {
+        self.defining = None;
+        let i = self.types.gc_types.push(ty);
+        assert_eq!(i, index);
+    }
+
     fn intern_func_type(&mut self, sig: WasmFuncType) -> SignatureIndex {
         if let Some(idx) = self.interned_func_types.get(&sig) {
             return *idx;
@@ -104,16 +155,13 @@ pub struct WasmparserTypeConverter<'a> {
 impl TypeConvert for WasmparserTypeConverter<'_> {
     fn lookup_heap_type(&self, index: UnpackedIndex) -> WasmHeapType {
         match index {
-            UnpackedIndex::Id(id) => {
-                let signature = self.types.wasmparser_to_wasmtime[&id];
-                WasmHeapType::TypedFunc(signature)
-            }
-            UnpackedIndex::RecGroup(_) => unreachable!(),
+            UnpackedIndex::Id(id) => self.types.wasmparser_to_wasmtime[&id].heap_type(),
+            // Recursion groups have a single type, so this must be a
+            // reference from the type being defined to itself.
+            UnpackedIndex::RecGroup(_) => self.types.defining.unwrap().heap_type(),
             UnpackedIndex::Module(i) => {
                 let i = TypeIndex::from_u32(i);
-                match self.module.types[i] {
-                    ModuleType::Function(sig) => WasmHeapType::TypedFunc(sig),
-                }
+                self.module.types[i].heap_type()
             }
         }
     }
@@ -34,3 +34,36 @@ pub const FUNCREF_INIT_BIT: usize = 1;
 /// to distinguish from an uninitialized element in a
 /// lazily-initialized funcref table.
 pub const FUNCREF_MASK: usize = !FUNCREF_INIT_BIT;
+
+/// The tag bit set in the representation of an `i31ref`.
+///
+/// References in the `any` hierarchy are 32-bit values where zero is `null`
+/// and an `i31` with value `x` is represented as `(x << 1) | I31_TAG`,
+/// leaving the untagged values free for boxed, heap-allocated objects.
+pub const I31_TAG: u32 = 1;
+
+/// The tag bit set in the representation of `any` references held in
+/// compiled code.
+///
+/// Compiled code holds `any` references as `r64` values so that they are
+/// recorded in stack maps and can be found by the tracing collector. The
+/// 32-bit reference `r` is held as `((r as u64) << 1) | ANYREF_STACK_TAG`,
+/// which keeps it distinct from the pointers of `externref`s that share those
+/// stack maps: pointers are always aligned, while this is always odd.
+pub const ANYREF_STACK_TAG: u64 = 1;
+
+/// Encodes the 32-bit `any` reference `r` the way compiled code holds it.
+///
+/// `null` stays zero so that it's the same as a `null` `externref`.
+pub fn anyref_to_stack(r: u32) -> u64 {
+    if r == 0 {
+        0
+    } else {
+        (u64::from(r) << 1) | ANYREF_STACK_TAG
+    }
+}
+
+/// Decodes an `any` reference encoded with [`anyref_to_stack`].
+pub fn anyref_from_stack(bits: u64) -> u32 {
+    (bits >> 1) as u32
+}
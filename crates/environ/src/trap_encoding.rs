@@ -96,6 +96,17 @@ pub enum Trap {
     /// would have violated the reentrance rules of the component model,
     /// triggering a trap instead.
     CannotEnterComponent,
+
+    /// A `ref.cast` instruction was executed on a reference that is not of
+    /// the target type.
+    CastFailure,
+
+    /// An out-of-bounds access to a GC array.
+    ArrayOutOfBounds,
+
+    /// A GC object couldn't be allocated because it's too large or the GC
+    /// heap is exhausted.
+    AllocationTooLarge,
     // if adding a variant here be sure to update the `check!` macro below
 }
 
@@ -120,6 +131,9 @@ impl fmt::Display for Trap {
             AtomicWaitNonSharedMemory => "atomic wait on non-shared memory",
             NullReference => "null reference",
             CannotEnterComponent => "cannot enter component instance",
+            CastFailure => "cast failure",
+            ArrayOutOfBounds => "out of bounds array access",
+            AllocationTooLarge => "allocation size too large",
         };
         write!(f, "wasm trap: {desc}")
     }
@@ -236,6 +250,9 @@ pub fn lookup_trap_code(section: &[u8], offset: usize) -> Option<Trap> {
         AtomicWaitNonSharedMemory
         NullReference
         CannotEnterComponent
+        CastFailure
+        ArrayOutOfBounds
+        AllocationTooLarge
     }
 
     if cfg!(debug_assertions) {
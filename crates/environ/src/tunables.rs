@@ -54,6 +54,17 @@ pub struct Tunables {
 
     /// Whether or not Wasm functions can be tail-called or not.
     pub tail_callable: bool,
+
+    /// Whether or not calls check for exceptions thrown by their callees.
+    pub exceptions: bool,
+
+    /// Whether or not compiled code can be stopped at breakpoints and
+    /// single-stepped, passing its wasm-level state to the runtime.
+    pub guest_debug: bool,
+
+    /// Whether or not compiled code counts how many times each of its basic
+    /// blocks is entered.
+    pub coverage: bool,
 }
 
 impl Default for Tunables {
@@ -112,6 +123,9 @@ impl Default for Tunables {
             debug_adapter_modules: false,
             relaxed_simd_deterministic: false,
             tail_callable: false,
+            exceptions: false,
+            guest_debug: false,
+            coverage: false,
         }
     }
 }
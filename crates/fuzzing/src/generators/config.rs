@@ -86,6 +86,23 @@ impl Config {
         }
     }
 
+    /// Indicates that this configuration is being used to compare executions
+    /// with [`wasmtime::Config::deterministic`] enabled.
+    ///
+    /// This is the differential configuration, except that NaNs are left for
+    /// Wasmtime to canonicalize and settings which deterministic mode rejects
+    /// are turned off.
+    pub fn set_deterministic_config(&mut self) {
+        self.set_differential_config();
+
+        let config = &mut self.module_config.config;
+        config.canonicalize_nans = false;
+        config.threads_enabled = false;
+
+        self.wasmtime.epoch_interruption = false;
+        self.wasmtime.compiler_strategy = CompilerStrategy::Cranelift;
+    }
+
     /// Uses this configuration and the supplied source of data to generate
     /// a wasm module.
     ///
@@ -292,6 +292,8 @@ impl TryFrom<wasmtime::ValType> for DiffValueType {
             V128 => Ok(Self::V128),
             FuncRef => Ok(Self::FuncRef),
             ExternRef => Ok(Self::ExternRef),
+            AnyRef => Err("anyref is not supported"),
+            ExnRef => Err("exnref is not supported"),
         }
     }
 }
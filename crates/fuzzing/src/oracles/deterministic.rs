@@ -0,0 +1,180 @@
+//! Compare executions of Wasmtime in deterministic mode across configurations.
+
+use crate::generators::{self, DiffValue, DiffValueType, WasmtimeConfig};
+use crate::oracles::diff_wasmtime::WasmtimeInstance;
+use crate::oracles::engine::DiffInstance;
+use crate::oracles::{compile_module, log_wasm, StoreLimits};
+use anyhow::Result;
+use arbitrary::Unstructured;
+use wasmtime::{Engine, Store, Trap};
+
+/// Upper limit on the number of invocations of each exported function.
+const NUM_INVOCATIONS: usize = 5;
+
+/// Execute a generated module in two differently-configured Wasmtime engines,
+/// both with [`wasmtime::Config::deterministic`] enabled, and assert that all
+/// results, globals and memories are bit-for-bit identical.
+///
+/// The two configurations differ in everything that's allowed to vary from
+/// host to host, such as the available CPU features, optimization level and
+/// memory configuration. Unlike the other differential oracles, NaNs are
+/// compared by their bit patterns here and NaN inputs aren't canonicalized.
+pub fn check_deterministic(u: &mut Unstructured<'_>) -> arbitrary::Result<()> {
+    let mut lhs_config: generators::Config = u.arbitrary()?;
+    lhs_config.set_deterministic_config();
+    let mut rhs_config = lhs_config.clone();
+    rhs_config.wasmtime = u.arbitrary::<WasmtimeConfig>()?;
+    rhs_config
+        .wasmtime
+        .make_compatible_with(&lhs_config.wasmtime);
+    rhs_config.set_deterministic_config();
+
+    let wasm = lhs_config.generate(u, Some(1000))?.to_bytes();
+    log_wasm(&wasm);
+
+    let (Some(lhs), Some(rhs)) = (
+        instantiate(&lhs_config, &wasm),
+        instantiate(&rhs_config, &wasm),
+    ) else {
+        return Ok(());
+    };
+    // Stack overflow depends on native frame sizes, which may legitimately
+    // differ between the two configurations, see
+    // `wasmtime::Config::deterministic`.
+    let (mut lhs, mut rhs) = match (lhs, rhs) {
+        (Ok(lhs), Ok(rhs)) => (lhs, rhs),
+        (Err(lhs), Err(rhs)) => {
+            if !is_stack_overflow(&lhs) && !is_stack_overflow(&rhs) {
+                assert_same_failure(&lhs, &rhs);
+            }
+            return Ok(());
+        }
+        (Ok(_), Err(e)) | (Err(e), Ok(_)) if is_stack_overflow(&e) => return Ok(()),
+        (Ok(_), Err(e)) => panic!("only the `rhs` failed to instantiate: {e:?}"),
+        (Err(e), Ok(_)) => panic!("only the `lhs` failed to instantiate: {e:?}"),
+    };
+
+    'outer: for (name, signature) in lhs.exported_functions() {
+        for _ in 0..NUM_INVOCATIONS {
+            let arguments = signature
+                .params()
+                .map(|t| arbitrary_value(u, t.try_into().unwrap()))
+                .collect::<arbitrary::Result<Vec<_>>>()?;
+            let result_tys = signature
+                .results()
+                .map(|t| DiffValueType::try_from(t).unwrap())
+                .collect::<Vec<_>>();
+            log::debug!("Evaluating: `{name}` with {arguments:?}");
+            let lhs_results = lhs.evaluate(&name, &arguments, &result_tys);
+            let rhs_results = rhs.evaluate(&name, &arguments, &result_tys);
+            log::debug!(" -> lhs: {lhs_results:?}, rhs: {rhs_results:?}");
+
+            // Stack overflow and OOM depend on frame sizes and allocation
+            // patterns, which may legitimately differ between the two
+            // configurations, so the instances can't be compared afterwards.
+            if lhs.is_oom() || rhs.is_oom() {
+                break 'outer;
+            }
+            match (lhs_results, rhs_results) {
+                (Ok(lhs), Ok(rhs)) => assert_bits_eq(&lhs.unwrap(), &rhs.unwrap()),
+                (Err(lhs), Err(rhs)) => {
+                    if is_stack_overflow(&lhs) || is_stack_overflow(&rhs) {
+                        break 'outer;
+                    }
+                    assert_same_failure(&lhs, &rhs);
+                }
+                (Ok(_), Err(e)) | (Err(e), Ok(_)) => {
+                    if is_stack_overflow(&e) {
+                        break 'outer;
+                    }
+                    panic!("only one side failed: {e:?}");
+                }
+            }
+
+            for (global, ty) in lhs.exported_globals() {
+                log::debug!("Comparing global `{global}`");
+                let l = lhs.get_global(&global, ty).unwrap();
+                let r = rhs.get_global(&global, ty).unwrap();
+                assert_bits_eq(&[l], &[r]);
+            }
+            for (memory, shared) in lhs.exported_memories() {
+                log::debug!("Comparing memory `{memory}`");
+                let l = lhs.get_memory(&memory, shared).unwrap();
+                let r = rhs.get_memory(&memory, shared).unwrap();
+                assert!(l == r, "memory `{memory}` differs");
+            }
+
+            if u.is_empty() {
+                break 'outer;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Compiles and instantiates `wasm` with `config` in deterministic mode.
+///
+/// Returns `None` if the module couldn't be compiled or instantiated for a
+/// reason which isn't a bug, such as exceeding pooling allocator limits.
+fn instantiate(config: &generators::Config, wasm: &[u8]) -> Option<Result<WasmtimeInstance>> {
+    let mut wasmtime_config = config.to_wasmtime();
+    wasmtime_config.deterministic(true);
+    let engine = Engine::new(&wasmtime_config).unwrap();
+    let mut store = Store::new(&engine, StoreLimits::new());
+    config.configure_store(&mut store);
+    let module = compile_module(&engine, wasm, true, config)?;
+    let instance = WasmtimeInstance::new(store, module);
+    if let Err(e) = &instance {
+        if e.downcast_ref::<Trap>().is_none() {
+            log::debug!("failed to instantiate: {e:?}");
+            return None;
+        }
+    }
+    Some(instance)
+}
+
+/// Generates an argument of type `ty`, using arbitrary bit patterns for
+/// floats so that NaN payloads make their way into wasm.
+fn arbitrary_value(u: &mut Unstructured<'_>, ty: DiffValueType) -> arbitrary::Result<DiffValue> {
+    Ok(match ty {
+        DiffValueType::F32 if u.arbitrary()? => DiffValue::F32(u.arbitrary()?),
+        DiffValueType::F64 if u.arbitrary()? => DiffValue::F64(u.arbitrary()?),
+        _ => DiffValue::arbitrary_of_type(u, ty)?,
+    })
+}
+
+fn is_stack_overflow(err: &anyhow::Error) -> bool {
+    err.downcast_ref::<Trap>() == Some(&Trap::StackOverflow)
+}
+
+fn assert_same_failure(lhs: &anyhow::Error, rhs: &anyhow::Error) {
+    let lhs_trap = lhs.downcast_ref::<Trap>();
+    let rhs_trap = rhs.downcast_ref::<Trap>();
+    assert_eq!(lhs_trap, rhs_trap, "{lhs:?}\nis not equal to\n{rhs:?}");
+}
+
+/// Asserts that `lhs` and `rhs` are identical, including the bit patterns of
+/// any NaNs.
+fn assert_bits_eq(lhs: &[DiffValue], rhs: &[DiffValue]) {
+    assert_eq!(lhs.len(), rhs.len());
+    for (l, r) in lhs.iter().zip(rhs) {
+        let same = match (l, r) {
+            (DiffValue::F32(l), DiffValue::F32(r)) => l == r,
+            (DiffValue::F64(l), DiffValue::F64(r)) => l == r,
+            _ => l == r,
+        };
+        assert!(same, "{l:?} is not bitwise equal to {r:?}");
+    }
+}
+
+#[test]
+fn smoke() {
+    use rand::prelude::*;
+
+    let mut rng = SmallRng::seed_from_u64(0);
+    let mut buf = vec![0; 2048];
+    for _ in 0..100 {
+        rng.fill_bytes(&mut buf);
+        let _ = check_deterministic(&mut Unstructured::new(&buf));
+    }
+}
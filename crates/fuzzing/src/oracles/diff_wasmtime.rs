@@ -219,6 +219,8 @@ impl Into<DiffValue> for Val {
             Val::V128(n) => DiffValue::V128(n.into()),
             Val::FuncRef(f) => DiffValue::FuncRef { null: f.is_none() },
             Val::ExternRef(e) => DiffValue::ExternRef { null: e.is_none() },
+            Val::AnyRef(_) => unreachable!("anyref is not a `DiffValueType`"),
+            Val::ExnRef(_) => unreachable!("exnref is not a `DiffValueType`"),
         }
     }
 }
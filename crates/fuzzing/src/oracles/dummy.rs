@@ -23,6 +23,7 @@ pub fn dummy_extern<T>(store: &mut Store<T>, ty: ExternType) -> Result<Extern> {
         ExternType::Global(global_ty) => Extern::Global(dummy_global(store, global_ty)),
         ExternType::Table(table_ty) => Extern::Table(dummy_table(store, table_ty)?),
         ExternType::Memory(mem_ty) => Extern::Memory(dummy_memory(store, mem_ty)?),
+        ExternType::Tag(tag_ty) => Extern::Tag(Tag::new(store, &tag_ty)?),
     })
 }
 
@@ -46,6 +47,8 @@ pub fn dummy_value(val_ty: ValType) -> Val {
         ValType::V128 => Val::V128(0.into()),
         ValType::ExternRef => Val::ExternRef(None),
         ValType::FuncRef => Val::FuncRef(None),
+        ValType::AnyRef => Val::AnyRef(None),
+        ValType::ExnRef => Val::ExnRef(None),
     }
 }
 
@@ -0,0 +1,125 @@
+//! Runtime support for the WebAssembly exception handling proposal.
+//!
+//! An `exnref` is represented as a `VMExternRef` whose data is an
+//! [`Exception`], which means that exception references are reference counted
+//! and managed by the same stack maps and activations table as `externref`s.
+//!
+//! Exceptions are propagated through Wasm frames without any unwinding
+//! support from the underlying code generator: a thrown exception is stored in
+//! the store's `VMRuntimeLimits::pending_exception` slot and compiled code
+//! checks that slot after each call, branching to the innermost handler (or
+//! returning to its own caller) when it's set.
+
+use crate::{SendSyncPtr, VMExternRef, VMRuntimeLimits, VMTagDefinition, ValRaw};
+use std::ptr::NonNull;
+use wasmtime_environ::{WasmFuncType, WasmType};
+
+/// The data of an `exnref`: the tag an exception was thrown with along with
+/// its payload values.
+pub struct Exception {
+    tag: SendSyncPtr<VMTagDefinition>,
+    payload: Box<[ValRaw]>,
+    /// Strong references to all non-null `externref` and `exnref` values in
+    /// `payload`, which keep them alive as long as this exception is.
+    refs: Vec<VMExternRef>,
+}
+
+impl Exception {
+    /// Creates a new exception for `tag` carrying `payload`, whose values
+    /// have the types of the parameters of `ty`.
+    ///
+    /// # Safety
+    ///
+    /// All reference values within `payload` must be valid raw `VMExternRef`
+    /// pointers (or null); a new strong reference is taken to each of them.
+    pub unsafe fn new(
+        tag: NonNull<VMTagDefinition>,
+        ty: &WasmFuncType,
+        payload: Box<[ValRaw]>,
+    ) -> VMExternRef {
+        assert_eq!(ty.params().len(), payload.len());
+        let refs = ty
+            .params()
+            .iter()
+            .zip(payload.iter())
+            .filter_map(|(ty, val)| match ty {
+                WasmType::Ref(r) if r.heap_type.is_vmexternref() => {
+                    let raw = val.get_externref().cast::<u8>();
+                    if raw.is_null() {
+                        None
+                    } else {
+                        Some(VMExternRef::clone_from_raw(raw))
+                    }
+                }
+                _ => None,
+            })
+            .collect();
+        VMExternRef::new(Exception {
+            tag: SendSyncPtr::new(tag),
+            payload,
+            refs,
+        })
+    }
+
+    /// Returns the definition of the tag that this exception was thrown with.
+    ///
+    /// The address of the definition is the identity of the tag.
+    pub fn tag(&self) -> *mut VMTagDefinition {
+        self.tag.as_ptr()
+    }
+
+    /// Returns the payload values of this exception.
+    ///
+    /// Reference values within the payload are kept alive by this exception,
+    /// so they are only valid as long as it is.
+    pub fn payload(&self) -> &[ValRaw] {
+        &self.payload
+    }
+
+    /// Returns the strong references held within the payload of this
+    /// exception.
+    pub fn refs(&self) -> &[VMExternRef] {
+        &self.refs
+    }
+}
+
+/// Returns the `Exception` within the `exnref` provided.
+///
+/// # Panics
+///
+/// Panics if `exnref` was not created by [`Exception::new`].
+pub fn exception(exnref: &VMExternRef) -> &Exception {
+    exnref
+        .downcast_ref::<Exception>()
+        .expect("exnref should contain an `Exception`")
+}
+
+/// Marks `exnref` as the exception currently being thrown in the store owning
+/// `limits`, replacing any previously pending exception.
+///
+/// # Safety
+///
+/// `limits` must be a valid pointer to the store's `VMRuntimeLimits`.
+pub unsafe fn set_pending_exception(limits: *const VMRuntimeLimits, exnref: VMExternRef) {
+    let slot = (*limits).pending_exception.get();
+    let prev = std::mem::replace(&mut *slot, exnref.into_raw());
+    if !prev.is_null() {
+        drop(VMExternRef::from_raw(prev));
+    }
+}
+
+/// Takes the exception currently being thrown in the store owning `limits`,
+/// if any, clearing it.
+///
+/// # Safety
+///
+/// `limits` must be a valid pointer to the store's `VMRuntimeLimits`.
+pub unsafe fn take_pending_exception(limits: *const VMRuntimeLimits) -> Option<VMExternRef> {
+    let slot = (*limits).pending_exception.get();
+    let raw = std::mem::replace(&mut *slot, std::ptr::null_mut());
+    if raw.is_null() {
+        None
+    } else {
+        Some(VMExternRef::from_raw(raw))
+    }
+}
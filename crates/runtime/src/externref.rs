@@ -110,7 +110,7 @@ use std::mem;
 use std::ops::Deref;
 use std::ptr::{self, NonNull};
 use std::sync::atomic::{self, AtomicUsize, Ordering};
-use wasmtime_environ::StackMap;
+use wasmtime_environ::{StackMap, ANYREF_STACK_TAG};
 
 /// An external reference to some opaque data.
 ///
@@ -891,6 +891,50 @@ pub unsafe fn gc(
     }
 
     log::trace!("begin GC trace");
+    for_each_stack_map_slot(limits, module_info_lookup, |stack_slot| {
+        let r = std::ptr::read(stack_slot.cast::<*mut VMExternData>());
+        log::trace!("Stack slot @ {:p} = {:p}", stack_slot, r);
+
+        // Slots holding `any` references are tagged so that they're never
+        // aligned like the pointers of `externref`s, see
+        // `wasmtime_environ::ANYREF_STACK_TAG`.
+        if (r as usize as u64) & ANYREF_STACK_TAG != 0 {
+            return;
+        }
+
+        debug_assert!(
+            r.is_null() || activations_table_set.contains(&r),
+            "every on-stack externref inside a Wasm frame should \
+             have an entry in the VMExternRefActivationsTable; \
+             {:?} is not in the table",
+            r
+        );
+
+        if let Some(r) = NonNull::new(r) {
+            VMExternRefActivationsTable::insert_precise_stack_root(
+                &mut externref_activations_table.precise_stack_roots,
+                r,
+            );
+        }
+    });
+    log::trace!("end GC trace");
+
+    externref_activations_table.sweep();
+
+    log::debug!("end GC");
+}
+
+/// Calls `f` with every stack slot of the Wasm frames on the stack which
+/// their stack maps say holds a reference.
+///
+/// # Safety
+///
+/// Has the same requirements as [`gc`].
+pub(crate) unsafe fn for_each_stack_map_slot(
+    limits: *const VMRuntimeLimits,
+    module_info_lookup: &dyn ModuleInfoLookup,
+    mut f: impl FnMut(*const usize),
+) {
     Backtrace::trace(limits, |frame| {
         let pc = frame.pc();
         debug_assert!(pc != 0, "we should always get a valid PC for Wasm frames");
@@ -928,39 +972,17 @@ pub unsafe fn gc(
 
             if !stack_map.get_bit(i) {
                 log::trace!(
-                    "Stack slot @ {:p} does not contain externrefs",
+                    "Stack slot @ {:p} does not contain references",
                     stack_slot as *const (),
                 );
                 continue;
             }
 
-            let stack_slot = stack_slot as *const *mut VMExternData;
-            let r = std::ptr::read(stack_slot);
-            log::trace!("Stack slot @ {:p} = {:p}", stack_slot, r);
-
-            debug_assert!(
-                r.is_null() || activations_table_set.contains(&r),
-                "every on-stack externref inside a Wasm frame should \
-                 have an entry in the VMExternRefActivationsTable; \
-                 {:?} is not in the table",
-                r
-            );
-
-            if let Some(r) = NonNull::new(r) {
-                VMExternRefActivationsTable::insert_precise_stack_root(
-                    &mut externref_activations_table.precise_stack_roots,
-                    r,
-                );
-            }
+            f(stack_slot as *const usize);
         }
 
         std::ops::ControlFlow::Continue(())
     });
-    log::trace!("end GC trace");
-
-    externref_activations_table.sweep();
-
-    log::debug!("end GC");
 }
 
 #[cfg(test)]
@@ -1005,10 +1027,12 @@ mod tests {
             num_imported_tables: 0,
             num_imported_memories: 0,
             num_imported_globals: 0,
+            num_imported_tags: 0,
             num_defined_tables: 0,
             num_defined_memories: 0,
             num_owned_memories: 0,
             num_defined_globals: 0,
+            num_defined_tags: 0,
             num_escaped_funcs: 0,
         });
         assert_eq!(
@@ -1032,10 +1056,12 @@ mod tests {
             num_imported_tables: 0,
             num_imported_memories: 0,
             num_imported_globals: 0,
+            num_imported_tags: 0,
             num_defined_tables: 0,
             num_defined_memories: 0,
             num_owned_memories: 0,
             num_defined_globals: 0,
+            num_defined_tags: 0,
             num_escaped_funcs: 0,
         });
         assert_eq!(
@@ -1059,10 +1085,12 @@ mod tests {
             num_imported_tables: 0,
             num_imported_memories: 0,
             num_imported_globals: 0,
+            num_imported_tags: 0,
             num_defined_tables: 0,
             num_defined_memories: 0,
             num_owned_memories: 0,
             num_defined_globals: 0,
+            num_defined_tags: 0,
             num_escaped_funcs: 0,
         });
         assert_eq!(
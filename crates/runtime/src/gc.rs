@@ -0,0 +1,406 @@
+//! The GC heap of a store, which holds the `struct`s and `array`s of the GC
+//! proposal.
+//!
+//! References to objects are their 32-bit offsets in the heap, laid out as
+//! described in `wasmtime_environ`'s `gc` module. The heap never moves objects
+//! within itself, but the heap as a whole may move when it grows, so compiled
+//! code always accesses it through `VMRuntimeLimits::gc_heap_base`.
+//!
+//! Objects are reclaimed by a mark-sweep collector. The roots are found by
+//! the embedder, which walks the stack maps of Wasm frames (see
+//! [`anyref_stack_roots`]) along with its own handles and globals. Sweeping
+//! turns runs of dead objects into free chunks, which allocation then bumps
+//! through in address order before moving on to the untouched tail of the
+//! heap.
+
+use crate::externref::for_each_stack_map_slot;
+use crate::{ModuleInfoLookup, Store, VMRuntimeLimits};
+use std::collections::HashMap;
+use std::ops::Range;
+use std::sync::Arc;
+use wasmtime_environ::{
+    anyref_from_stack, GcLayout, GcStorage, Trap, WasmGcType, ANYREF_STACK_TAG, GC_ALIGN,
+    GC_ARRAY_ELEMS_OFFSET, GC_ARRAY_LENGTH_OFFSET, GC_HEADER_ARRAY_BIT, GC_HEADER_SIZE,
+    GC_HEADER_SIZE_OFFSET, GC_HEADER_TYPE_OFFSET, I31_TAG,
+};
+
+/// An index into the engine-wide registry of `struct` and `array` types.
+///
+/// This is stored in the header of every object in a GC heap.
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+#[repr(transparent)]
+pub struct VMSharedGcTypeIndex(u32);
+
+impl VMSharedGcTypeIndex {
+    /// Create a new `VMSharedGcTypeIndex`.
+    #[inline]
+    pub fn new(value: u32) -> Self {
+        VMSharedGcTypeIndex(value)
+    }
+
+    /// Returns the underlying bits of the index.
+    #[inline]
+    pub fn bits(&self) -> u32 {
+        self.0
+    }
+}
+
+/// A `struct` or `array` type registered with an engine.
+#[derive(Debug)]
+pub struct GcTypeInfo {
+    /// The index the type is registered at.
+    pub index: VMSharedGcTypeIndex,
+    /// The definition of the type, where references to other `struct` and
+    /// `array` types use their [`VMSharedGcTypeIndex`]es and references to
+    /// function types use their `VMSharedSignatureIndex`es.
+    pub ty: WasmGcType,
+    /// The layout of objects of this type.
+    pub layout: GcLayout,
+    /// This type followed by its supertypes, ending with the root of its
+    /// hierarchy.
+    pub supertypes: Box<[VMSharedGcTypeIndex]>,
+}
+
+impl GcTypeInfo {
+    /// Returns whether this type is a subtype of the type `ty`.
+    pub fn is_subtype_of(&self, ty: VMSharedGcTypeIndex) -> bool {
+        self.supertypes.contains(&ty)
+    }
+}
+
+/// The type index of the headers of free chunks.
+const FREE: u32 = u32::MAX;
+
+/// The flag in an object's size word that is set once it's marked.
+const MARK_BIT: u32 = 2;
+
+/// The mask of the flags in an object's size word.
+const FLAGS_MASK: u32 = GC_ALIGN - 1;
+
+/// The size of a heap when it's first used.
+const INITIAL_SIZE: u64 = 64 << 10;
+
+/// The largest possible size of a heap, such that every offset in it fits in
+/// a `u32`.
+const MAX_SIZE: u64 = (1 << 32) - GC_ALIGN as u64;
+
+/// The GC heap of a store.
+pub struct GcHeap {
+    /// The memory of the heap, as `u64`s so that it's aligned for objects.
+    memory: Vec<u64>,
+    /// The end of the region which holds objects and free chunks. Everything
+    /// beyond it is unused.
+    end: u32,
+    /// The chunk that objects are currently bump-allocated from.
+    chunk: Range<u32>,
+    /// The free chunks found by the last sweep, in descending order.
+    free: Vec<Range<u32>>,
+    /// The types of the objects which have been allocated in this heap.
+    types: HashMap<VMSharedGcTypeIndex, Arc<GcTypeInfo>>,
+}
+
+impl Default for GcHeap {
+    fn default() -> GcHeap {
+        GcHeap {
+            memory: Vec::new(),
+            // The first word is reserved so that no object is at offset zero,
+            // which is `null`.
+            end: GC_ALIGN,
+            chunk: 0..0,
+            free: Vec::new(),
+            types: HashMap::new(),
+        }
+    }
+}
+
+impl GcHeap {
+    /// Returns the base address of this heap, which changes whenever it
+    /// grows.
+    pub fn base(&mut self) -> *mut u8 {
+        self.memory.as_mut_ptr().cast()
+    }
+
+    /// Returns the size of this heap in bytes.
+    pub fn capacity(&self) -> u32 {
+        u32::try_from(self.memory.len() * 8).unwrap()
+    }
+
+    /// Returns the number of bytes available for allocation without growing
+    /// this heap.
+    pub fn free_bytes(&self) -> u32 {
+        let tail = self.capacity().saturating_sub(self.end);
+        let chunk = self.chunk.end - self.chunk.start;
+        self.free
+            .iter()
+            .fold(tail + chunk, |sum, c| sum + (c.end - c.start))
+    }
+
+    /// Returns the bytes of this heap.
+    pub fn bytes(&self) -> &[u8] {
+        unsafe { std::slice::from_raw_parts(self.memory.as_ptr().cast(), self.memory.len() * 8) }
+    }
+
+    /// Returns the bytes of this heap, mutably.
+    pub fn bytes_mut(&mut self) -> &mut [u8] {
+        unsafe {
+            std::slice::from_raw_parts_mut(self.memory.as_mut_ptr().cast(), self.memory.len() * 8)
+        }
+    }
+
+    fn read_u32(&self, offset: u32) -> u32 {
+        let offset = usize::try_from(offset).unwrap();
+        u32::from_le_bytes(self.bytes()[offset..][..4].try_into().unwrap())
+    }
+
+    fn write_u32(&mut self, offset: u32, value: u32) {
+        let offset = usize::try_from(offset).unwrap();
+        self.bytes_mut()[offset..][..4].copy_from_slice(&value.to_le_bytes());
+    }
+
+    /// Returns the type of the object `r`.
+    pub fn object_type(&self, r: u32) -> &Arc<GcTypeInfo> {
+        let ty = VMSharedGcTypeIndex::new(self.read_u32(r + GC_HEADER_TYPE_OFFSET));
+        &self.types[&ty]
+    }
+
+    /// Returns the length of the array `r`.
+    pub fn array_len(&self, r: u32) -> u32 {
+        self.read_u32(r + GC_ARRAY_LENGTH_OFFSET)
+    }
+
+    /// Returns the size of the elements of the array `r`.
+    pub fn array_elem_size(&self, r: u32) -> u32 {
+        match self.object_type(r).layout {
+            GcLayout::Array(elem) => elem.size(),
+            GcLayout::Struct { .. } => panic!("not an array"),
+        }
+    }
+
+    /// Returns the range of bytes holding the `len` elements of the array `r`
+    /// starting at `index`, or `None` if they're out of the array's bounds.
+    pub fn array_elems(&self, r: u32, index: u32, len: u32) -> Option<Range<usize>> {
+        let elem_size = self.array_elem_size(r);
+        if u64::from(index) + u64::from(len) > u64::from(self.array_len(r)) {
+            return None;
+        }
+        let start = usize::try_from(r + GC_ARRAY_ELEMS_OFFSET + index * elem_size).unwrap();
+        Some(start..start + usize::try_from(len * elem_size).unwrap())
+    }
+
+    /// Returns the size of an object of type `ty`, which has `len` elements
+    /// if it's an array, or `None` if it's too large to be allocated.
+    pub fn object_size(ty: &GcTypeInfo, len: u32) -> Option<u32> {
+        match &ty.layout {
+            GcLayout::Struct { size, .. } => Some(*size),
+            layout @ GcLayout::Array(_) => layout.array_size(len),
+        }
+    }
+
+    /// Allocates a zeroed object of type `ty` and `size` bytes, which has
+    /// `len` elements if it's an array.
+    ///
+    /// Returns `None` if there's no room for the object without collecting
+    /// garbage or growing this heap.
+    pub fn alloc(&mut self, ty: &Arc<GcTypeInfo>, size: u32, len: u32) -> Option<u32> {
+        debug_assert_eq!(Some(size), GcHeap::object_size(ty, len));
+        while self.chunk.end - self.chunk.start < size {
+            // The tail of the heap is the only chunk which starts at the end
+            // of the swept region.
+            let was_tail = self.chunk.start >= self.end;
+            self.retire_chunk();
+            self.chunk = match self.free.pop() {
+                Some(chunk) => chunk,
+                // Once the free chunks are used up the tail of the heap is
+                // allocated from, unless it already has been.
+                None if !was_tail && self.end < self.capacity() => self.end..self.capacity(),
+                None => return None,
+            };
+        }
+        let r = self.chunk.start;
+        self.chunk.start += size;
+        self.end = self.end.max(self.chunk.start);
+
+        self.types.entry(ty.index).or_insert_with(|| ty.clone());
+        let object = usize::try_from(r).unwrap()..usize::try_from(r + size).unwrap();
+        self.bytes_mut()[object].fill(0);
+        self.write_u32(r + GC_HEADER_TYPE_OFFSET, ty.index.bits());
+        if let GcLayout::Array(_) = ty.layout {
+            self.write_u32(r + GC_HEADER_SIZE_OFFSET, size | GC_HEADER_ARRAY_BIT);
+            self.write_u32(r + GC_ARRAY_LENGTH_OFFSET, len);
+        } else {
+            self.write_u32(r + GC_HEADER_SIZE_OFFSET, size);
+        }
+        Some(r)
+    }
+
+    /// Marks the unused remainder of the current chunk as free, if it lies
+    /// within the region that's swept.
+    fn retire_chunk(&mut self) {
+        let chunk = std::mem::replace(&mut self.chunk, 0..0);
+        if chunk.start < chunk.end && chunk.start < self.end {
+            self.write_free_header(chunk);
+        }
+    }
+
+    fn write_free_header(&mut self, chunk: Range<u32>) {
+        self.write_u32(chunk.start + GC_HEADER_TYPE_OFFSET, FREE);
+        self.write_u32(chunk.start + GC_HEADER_SIZE_OFFSET, chunk.end - chunk.start);
+    }
+
+    /// Grows this heap so that an object of `size` bytes can be allocated in
+    /// it, at least doubling its size.
+    ///
+    /// Returns `false` if the heap can't grow that large.
+    pub fn grow(&mut self, size: u32) -> bool {
+        let needed = u64::from(self.end) + u64::from(size);
+        let capacity = (u64::from(self.capacity()) * 2)
+            .max(needed)
+            .max(INITIAL_SIZE)
+            .min(MAX_SIZE);
+        if capacity < needed {
+            return false;
+        }
+        // Allocation moves on to the new tail of the heap once the free
+        // chunks are used up.
+        self.retire_chunk();
+        self.memory
+            .resize(usize::try_from(capacity / 8).unwrap(), 0);
+        true
+    }
+
+    /// Frees every object which isn't reachable from the `roots`.
+    ///
+    /// The roots may include `i31ref`s and `null`, which are ignored.
+    pub fn collect(&mut self, roots: impl IntoIterator<Item = u32>) {
+        log::debug!("start GC heap collection");
+        self.retire_chunk();
+
+        let mut worklist = Vec::new();
+        for r in roots {
+            self.mark(r, &mut worklist);
+        }
+        while let Some(r) = worklist.pop() {
+            let ty = self.object_type(r).clone();
+            match &ty.layout {
+                GcLayout::Struct { fields, .. } => {
+                    for (offset, storage) in fields.iter() {
+                        if *storage == GcStorage::AnyRef {
+                            let field = self.read_u32(r + offset);
+                            self.mark(field, &mut worklist);
+                        }
+                    }
+                }
+                GcLayout::Array(GcStorage::AnyRef) => {
+                    for i in 0..self.array_len(r) {
+                        let elem = self.read_u32(r + GC_ARRAY_ELEMS_OFFSET + i * 4);
+                        self.mark(elem, &mut worklist);
+                    }
+                }
+                GcLayout::Array(_) => {}
+            }
+        }
+
+        self.sweep();
+        log::debug!(
+            "end GC heap collection: {} of {} bytes free",
+            self.free_bytes(),
+            self.capacity()
+        );
+    }
+
+    fn mark(&mut self, r: u32, worklist: &mut Vec<u32>) {
+        if r == 0 || r & I31_TAG != 0 {
+            return;
+        }
+        debug_assert!(r < self.end && r % GC_ALIGN == 0);
+        let size = self.read_u32(r + GC_HEADER_SIZE_OFFSET);
+        if size & MARK_BIT == 0 {
+            self.write_u32(r + GC_HEADER_SIZE_OFFSET, size | MARK_BIT);
+            worklist.push(r);
+        }
+    }
+
+    /// Turns the runs of unmarked objects into free chunks and unmarks the
+    /// marked ones.
+    fn sweep(&mut self) {
+        let mut free = Vec::new();
+        let mut dead_run = None;
+        let mut offset = GC_ALIGN;
+        while offset < self.end {
+            let ty = self.read_u32(offset + GC_HEADER_TYPE_OFFSET);
+            let size = self.read_u32(offset + GC_HEADER_SIZE_OFFSET);
+            debug_assert!(size & !FLAGS_MASK >= GC_HEADER_SIZE);
+            if ty != FREE && size & MARK_BIT != 0 {
+                self.write_u32(offset + GC_HEADER_SIZE_OFFSET, size & !MARK_BIT);
+                if let Some(start) = dead_run.take() {
+                    self.write_free_header(start..offset);
+                    free.push(start..offset);
+                }
+            } else if dead_run.is_none() {
+                dead_run = Some(offset);
+            }
+            offset += size & !FLAGS_MASK;
+        }
+        debug_assert_eq!(offset, self.end);
+
+        // Dead objects at the end of the swept region are returned to the
+        // tail of the heap.
+        if let Some(start) = dead_run {
+            self.end = start;
+        }
+        free.reverse();
+        self.free = free;
+    }
+}
+
+/// Allocates a zeroed object of type `ty` in the GC heap of `store`, which has
+/// `len` elements if it's an array.
+///
+/// This collects garbage, and then grows the heap, if there isn't room for
+/// the object.
+///
+/// # Safety
+///
+/// Must be called from a host function called by Wasm, or with no Wasm on
+/// the stack, since it may collect garbage.
+pub unsafe fn gc_alloc(store: *mut dyn Store, ty: &Arc<GcTypeInfo>, len: u32) -> Result<u32, Trap> {
+    let size = GcHeap::object_size(ty, len).ok_or(Trap::AllocationTooLarge)?;
+    if let Some(r) = (*store).gc_heap().alloc(ty, size, len) {
+        return Ok(r);
+    }
+
+    (*store).gc();
+    let heap = (*store).gc_heap();
+    // Grow the heap if collecting garbage didn't free at least half of it,
+    // so that collections don't become more frequent as it fills up.
+    if heap.free_bytes() < heap.capacity() / 2 {
+        heap.grow(size);
+    }
+    let r = match heap.alloc(ty, size, len) {
+        Some(r) => r,
+        None if heap.grow(size) => heap.alloc(ty, size, len).unwrap(),
+        None => return Err(Trap::AllocationTooLarge),
+    };
+    *(*(*store).vmruntime_limits()).gc_heap_base.get() = heap.base();
+    Ok(r)
+}
+
+/// Walks the stack maps of the Wasm frames on the stack, pushing the `any`
+/// references they hold onto `roots`.
+///
+/// # Safety
+///
+/// Must be called from a host function called by Wasm, or with no Wasm on
+/// the stack, like [`crate::gc`].
+pub unsafe fn anyref_stack_roots(
+    limits: *const VMRuntimeLimits,
+    module_info_lookup: &dyn ModuleInfoLookup,
+    roots: &mut Vec<u32>,
+) {
+    for_each_stack_map_slot(limits, module_info_lookup, |slot| {
+        let bits = std::ptr::read(slot.cast::<u64>());
+        if bits & ANYREF_STACK_TAG != 0 {
+            roots.push(anyref_from_stack(bits));
+        }
+    });
+}
@@ -9,11 +9,11 @@ use crate::table::{Table, TableElement, TableElementType};
 use crate::vmcontext::{
     VMBuiltinFunctionsArray, VMContext, VMFuncRef, VMFunctionImport, VMGlobalDefinition,
     VMGlobalImport, VMMemoryDefinition, VMMemoryImport, VMOpaqueContext, VMRuntimeLimits,
-    VMTableDefinition, VMTableImport,
+    VMTableDefinition, VMTableImport, VMTagDefinition, VMTagImport,
 };
 use crate::{
-    ExportFunction, ExportGlobal, ExportMemory, ExportTable, Imports, ModuleRuntimeInfo,
-    SendSyncPtr, Store, VMFunctionBody, VMSharedSignatureIndex, WasmFault,
+    ExportFunction, ExportGlobal, ExportMemory, ExportTable, ExportTag, GcTypeInfo, Imports,
+    ModuleRuntimeInfo, SendSyncPtr, Store, VMFunctionBody, VMSharedSignatureIndex, WasmFault,
 };
 use anyhow::Error;
 use anyhow::Result;
@@ -27,10 +27,11 @@ use std::sync::atomic::AtomicU64;
 use std::sync::Arc;
 use std::{mem, ptr};
 use wasmtime_environ::{
-    packed_option::ReservedValue, DataIndex, DefinedGlobalIndex, DefinedMemoryIndex,
-    DefinedTableIndex, ElemIndex, EntityIndex, EntityRef, EntitySet, FuncIndex, GlobalIndex,
-    GlobalInit, HostPtr, MemoryIndex, MemoryPlan, Module, PrimaryMap, SignatureIndex, TableIndex,
-    TableInitialValue, Trap, VMOffsets, WasmHeapType, WasmRefType, WasmType, VMCONTEXT_MAGIC,
+    packed_option::ReservedValue, ConstOp, DataIndex, DefinedGlobalIndex, DefinedMemoryIndex,
+    DefinedTableIndex, DefinedTagIndex, ElemIndex, EntityIndex, EntityRef, EntitySet, FuncIndex,
+    GcTypeIndex, Global, GlobalIndex, HostPtr, MemoryIndex, MemoryPlan, Module, PrimaryMap,
+    SignatureIndex, TableIndex, TableInitialValue, TagIndex, Trap, VMOffsets, WasmFuncType,
+    WasmHeapType, WasmRefType, WasmType, VMCONTEXT_MAGIC,
 };
 #[cfg(feature = "wmemcheck")]
 use wasmtime_wmemcheck::Wmemcheck;
@@ -266,6 +267,17 @@ impl Instance {
         self.runtime_info.module()
     }
 
+    /// Returns the type of the payload of the tag `index`.
+    pub(crate) fn tag_type(&self, index: TagIndex) -> &WasmFuncType {
+        self.runtime_info
+            .function_type(self.module().tags[index].signature)
+    }
+
+    /// Returns the registered type of the `struct` or `array` type `index`.
+    pub(crate) fn gc_type(&self, index: GcTypeIndex) -> &Arc<GcTypeInfo> {
+        self.runtime_info.gc_type(index)
+    }
+
     #[inline]
     fn offsets(&self) -> &VMOffsets<HostPtr> {
         self.runtime_info.offsets()
@@ -291,6 +303,11 @@ impl Instance {
         unsafe { &*self.vmctx_plus_offset(self.offsets().vmctx_vmglobal_import(index)) }
     }
 
+    /// Return the indexed `VMTagImport`.
+    fn imported_tag(&self, index: TagIndex) -> &VMTagImport {
+        unsafe { &*self.vmctx_plus_offset(self.offsets().vmctx_vmtag_import(index)) }
+    }
+
     /// Return the indexed `VMTableDefinition`.
     #[allow(dead_code)]
     fn table(&mut self, index: DefinedTableIndex) -> VMTableDefinition {
@@ -384,6 +401,38 @@ impl Instance {
         }
     }
 
+    /// Returns the value of the integer global at the given index, as used by
+    /// constant expressions, zero-extended to a `u64`.
+    ///
+    /// # Unsafety
+    ///
+    /// The global must be initialized.
+    pub(crate) unsafe fn global_as_u64(&mut self, index: GlobalIndex) -> u64 {
+        let def = self.defined_or_imported_global_ptr(index);
+        if self.module().globals[index].wasm_ty == WasmType::I64 {
+            *(*def).as_u64()
+        } else {
+            u64::from(*(*def).as_u32())
+        }
+    }
+
+    /// Return the indexed `VMTagDefinition`.
+    fn tag_ptr(&mut self, index: DefinedTagIndex) -> *mut VMTagDefinition {
+        unsafe { self.vmctx_plus_offset_mut(self.offsets().vmctx_vmtag_definition(index)) }
+    }
+
+    /// Get a raw pointer to the tag at the given index regardless whether it
+    /// is defined locally or imported from another module.
+    ///
+    /// The address of a tag's definition is its identity.
+    pub(crate) fn defined_or_imported_tag_ptr(&mut self, index: TagIndex) -> *mut VMTagDefinition {
+        if let Some(index) = self.module().defined_tag_index(index) {
+            self.tag_ptr(index)
+        } else {
+            self.imported_tag(index).from
+        }
+    }
+
     /// Get all globals within this instance.
     ///
     /// Returns both import and defined globals.
@@ -555,7 +604,29 @@ impl Instance {
             } else {
                 self.imported_global(index).from
             },
-            global: self.module().globals[index],
+            global: self.shared_global_type(index),
+        }
+    }
+
+    /// Returns the type of the global `index` where references to `struct`
+    /// and `array` types use their engine-wide [`crate::VMSharedGcTypeIndex`]es, so
+    /// that it can be compared with values and types from other modules.
+    fn shared_global_type(&self, index: GlobalIndex) -> Global {
+        let mut global = self.module().globals[index];
+        if let WasmType::Ref(r) = &mut global.wasm_ty {
+            let shared = |i| GcTypeIndex::from_u32(self.gc_type(i).index.bits());
+            r.heap_type = match r.heap_type {
+                WasmHeapType::TypedStruct(i) => WasmHeapType::TypedStruct(shared(i)),
+                WasmHeapType::TypedArray(i) => WasmHeapType::TypedArray(shared(i)),
+                other => other,
+            };
+        }
+        global
+    }
+
+    fn get_exported_tag(&mut self, index: TagIndex) -> ExportTag {
+        ExportTag {
+            definition: self.defined_or_imported_tag_ptr(index),
         }
     }
 
@@ -956,6 +1027,26 @@ impl Instance {
         self.memory_init_segment(memory_index, range, dst, src, len)
     }
 
+    /// Returns the contents of the passive data segment `data_index`, which
+    /// are empty once it has been dropped.
+    pub(crate) fn passive_data(&self, data_index: DataIndex) -> &[u8] {
+        match self.module().passive_data_map.get(&data_index).cloned() {
+            Some(range) if !self.dropped_data.contains(data_index) => self.wasm_data(range),
+            _ => &[],
+        }
+    }
+
+    /// Returns the functions of the passive element segment `elem_index`,
+    /// which are empty once it has been dropped.
+    pub(crate) fn passive_elements(&self, elem_index: ElemIndex) -> &[FuncIndex] {
+        match self.module().passive_elements_map.get(&elem_index) {
+            Some(index) if !self.dropped_elements.contains(elem_index) => {
+                &self.module().passive_elements[*index]
+            }
+            _ => &[],
+        }
+    }
+
     pub(crate) fn wasm_data(&self, range: Range<u32>) -> &[u8] {
         &self.runtime_info.wasm_data()[range.start as usize..range.end as usize]
     }
@@ -1127,6 +1218,9 @@ impl Instance {
         *self.vmctx_plus_offset_mut(offsets.vmctx_builtin_functions()) =
             &VMBuiltinFunctionsArray::INIT;
 
+        *self.vmctx_plus_offset_mut(offsets.vmctx_coverage_counters()) =
+            self.runtime_info.coverage_counters();
+
         // Initialize the imports
         debug_assert_eq!(imports.functions.len(), module.num_imported_funcs);
         ptr::copy_nonoverlapping(
@@ -1152,6 +1246,12 @@ impl Instance {
             self.vmctx_plus_offset_mut(offsets.vmctx_imported_globals_begin()),
             imports.globals.len(),
         );
+        debug_assert_eq!(imports.tags.len(), module.num_imported_tags);
+        ptr::copy_nonoverlapping(
+            imports.tags.as_ptr(),
+            self.vmctx_plus_offset_mut(offsets.vmctx_imported_tags_begin()),
+            imports.tags.len(),
+        );
 
         // N.B.: there is no need to initialize the funcrefs array because we
         // eagerly construct each element in it whenever asked for a reference
@@ -1192,6 +1292,14 @@ impl Instance {
 
         // Initialize the defined globals
         self.initialize_vmctx_globals(module);
+
+        // Initialize the defined tags with their (engine-wide) signatures.
+        let mut ptr = self.vmctx_plus_offset_mut(offsets.vmctx_tags_begin());
+        for tag in module.tags.values().skip(module.num_imported_tags) {
+            let type_index = self.runtime_info.signature_ids()[tag.signature.index()];
+            ptr::write(ptr, VMTagDefinition::new(type_index));
+            ptr = ptr.add(1);
+        }
     }
 
     unsafe fn initialize_vmctx_globals(&mut self, module: &Module) {
@@ -1202,24 +1310,11 @@ impl Instance {
             // Initialize the global before writing to it
             ptr::write(to, VMGlobalDefinition::new());
 
-            match *init {
-                GlobalInit::I32Const(x) => {
-                    let index = module.global_index(index);
-                    if index.index() == 0 {
-                        #[cfg(feature = "wmemcheck")]
-                        {
-                            if let Some(wmemcheck) = &mut self.wmemcheck_state {
-                                wmemcheck.set_stack_size(x as usize);
-                            }
-                        }
-                    }
-                    *(*to).as_i32_mut() = x;
-                }
-                GlobalInit::I64Const(x) => *(*to).as_i64_mut() = x,
-                GlobalInit::F32Const(x) => *(*to).as_f32_bits_mut() = x,
-                GlobalInit::F64Const(x) => *(*to).as_f64_bits_mut() = x,
-                GlobalInit::V128Const(x) => *(*to).as_u128_mut() = x,
-                GlobalInit::GetGlobal(x) => {
+            match *init.ops() {
+                [ConstOp::F32Const(x)] => *(*to).as_f32_bits_mut() = x,
+                [ConstOp::F64Const(x)] => *(*to).as_f64_bits_mut() = x,
+                [ConstOp::V128Const(x)] => *(*to).as_u128_mut() = x,
+                [ConstOp::GlobalGet(x)] => {
                     let from = if let Some(def_x) = module.defined_global_index(x) {
                         self.global(def_x)
                     } else {
@@ -1229,21 +1324,42 @@ impl Instance {
                     // count as values move between globals, everything else is just
                     // copy-able bits.
                     match wasm_ty {
-                        WasmType::Ref(WasmRefType {
-                            heap_type: WasmHeapType::Extern,
-                            ..
-                        }) => *(*to).as_externref_mut() = from.as_externref().clone(),
+                        WasmType::Ref(r) if r.heap_type.is_vmexternref() => {
+                            *(*to).as_externref_mut() = from.as_externref().clone()
+                        }
                         _ => ptr::copy_nonoverlapping(from, to, 1),
                     }
                 }
-                GlobalInit::RefFunc(f) => {
+                [ConstOp::RefFunc(f)] => {
                     *(*to).as_func_ref_mut() = self.get_func_ref(f).unwrap();
                 }
-                GlobalInit::RefNullConst => match wasm_ty {
+                [ConstOp::RefNull] => match wasm_ty {
                     // `VMGlobalDefinition::new()` already zeroed out the bits
                     WasmType::Ref(WasmRefType { nullable: true, .. }) => {}
                     ty => panic!("unsupported reference type for global: {:?}", ty),
                 },
+                // Everything else is an integer expression, including
+                // `i31ref`s, possibly using the extended-const proposal.
+                _ => {
+                    let x = init
+                        .eval_int(|global| Some(self.global_as_u64(global)))
+                        .expect("global initializer should be an integer expression");
+                    if wasm_ty == WasmType::I64 {
+                        *(*to).as_u64_mut() = x;
+                    } else {
+                        let x = x as u32;
+                        let index = module.global_index(index);
+                        if index.index() == 0 && wasm_ty == WasmType::I32 {
+                            #[cfg(feature = "wmemcheck")]
+                            {
+                                if let Some(wmemcheck) = &mut self.wmemcheck_state {
+                                    wmemcheck.set_stack_size(x as usize);
+                                }
+                            }
+                        }
+                        *(*to).as_u32_mut() = x;
+                    }
+                }
             }
         }
     }
@@ -1276,11 +1392,9 @@ impl Drop for Instance {
                 None => continue,
             };
             match global.wasm_ty {
-                // For now only externref globals need to get destroyed
-                WasmType::Ref(WasmRefType {
-                    heap_type: WasmHeapType::Extern,
-                    ..
-                }) => {}
+                // For now only externref and exnref globals need to get
+                // destroyed
+                WasmType::Ref(r) if r.heap_type.is_vmexternref() => {}
                 _ => continue,
             }
             unsafe {
@@ -1333,6 +1447,11 @@ impl InstanceHandle {
         self.instance_mut().get_exported_table(export)
     }
 
+    /// Lookup a tag by index.
+    pub fn get_exported_tag(&mut self, export: TagIndex) -> ExportTag {
+        self.instance_mut().get_exported_tag(export)
+    }
+
     /// Lookup an item with the given index.
     pub fn get_export_by_index(&mut self, export: EntityIndex) -> Export {
         match export {
@@ -1340,6 +1459,7 @@ impl InstanceHandle {
             EntityIndex::Global(i) => Export::Global(self.get_exported_global(i)),
             EntityIndex::Table(i) => Export::Table(self.get_exported_table(i)),
             EntityIndex::Memory(i) => Export::Memory(self.get_exported_memory(i)),
+            EntityIndex::Tag(i) => Export::Tag(self.get_exported_tag(i)),
         }
     }
 
@@ -1357,6 +1477,18 @@ impl InstanceHandle {
         self.instance().host_state()
     }
 
+    /// Returns whether the passive data segment `index` has been dropped with
+    /// `data.drop`.
+    pub fn is_data_dropped(&self, index: DataIndex) -> bool {
+        self.instance().dropped_data.contains(index)
+    }
+
+    /// Returns whether the passive element segment `index` has been dropped
+    /// with `elem.drop`.
+    pub fn is_elem_dropped(&self, index: ElemIndex) -> bool {
+        self.instance().dropped_elements.contains(index)
+    }
+
     /// Get a table defined locally within this module.
     pub fn get_defined_table(&mut self, index: DefinedTableIndex) -> *mut Table {
         self.instance_mut().get_defined_table(index)
@@ -4,12 +4,12 @@ use crate::memory::Memory;
 use crate::mpk::ProtectionKey;
 use crate::table::Table;
 use crate::{CompiledModuleId, ModuleRuntimeInfo, Store};
-use anyhow::{anyhow, bail, Result};
+use anyhow::{bail, Result};
 use std::{alloc, any::Any, mem, ptr, sync::Arc};
 use wasmtime_environ::{
     DefinedMemoryIndex, DefinedTableIndex, HostPtr, InitMemory, MemoryInitialization,
     MemoryInitializer, MemoryPlan, Module, PrimaryMap, TableInitialValue, TablePlan, TableSegment,
-    Trap, VMOffsets, WasmType, WASM_PAGE_SIZE,
+    Trap, VMOffsets, WASM_PAGE_SIZE,
 };
 
 #[cfg(feature = "component-model")]
@@ -489,23 +489,18 @@ pub trait InstanceAllocator: InstanceAllocatorImpl {
 // must use the defaults. This blanket impl provides both of those things.
 impl<T: InstanceAllocatorImpl> InstanceAllocator for T {}
 
-fn get_table_init_start(init: &TableSegment, instance: &mut Instance) -> Result<u32> {
-    match init.base {
-        Some(base) => {
-            let val = unsafe { *(*instance.defined_or_imported_global_ptr(base)).as_u32() };
-
-            init.offset
-                .checked_add(val)
-                .ok_or_else(|| anyhow!("element segment global base overflows"))
-        }
-        None => Ok(init.offset),
-    }
+fn get_table_init_start(init: &TableSegment, instance: &mut Instance) -> u32 {
+    let start = init
+        .offset
+        .eval_int(|global| Some(unsafe { instance.global_as_u64(global) }))
+        .expect("element segment offsets are `i32` expressions");
+    u32::try_from(start).unwrap()
 }
 
 fn check_table_init_bounds(instance: &mut Instance, module: &Module) -> Result<()> {
     for segment in module.table_initialization.segments.iter() {
         let table = unsafe { &*instance.get_table(segment.table_index) };
-        let start = get_table_init_start(segment, instance)?;
+        let start = get_table_init_start(segment, instance);
         let start = usize::try_from(start).unwrap();
         let end = start.checked_add(segment.elements.len());
 
@@ -544,7 +539,7 @@ fn initialize_tables(instance: &mut Instance, module: &Module) -> Result<()> {
     // iterates over all segments (Segments mode) or leftover
     // segments (FuncTable mode) to initialize.
     for segment in module.table_initialization.segments.iter() {
-        let start = get_table_init_start(segment, instance)?;
+        let start = get_table_init_start(segment, instance);
         instance.table_init_segment(
             segment.table_index,
             &segment.elements,
@@ -557,27 +552,10 @@ fn initialize_tables(instance: &mut Instance, module: &Module) -> Result<()> {
     Ok(())
 }
 
-fn get_memory_init_start(init: &MemoryInitializer, instance: &mut Instance) -> Result<u64> {
-    match init.base {
-        Some(base) => {
-            let mem64 = instance.module().memory_plans[init.memory_index]
-                .memory
-                .memory64;
-            let val = unsafe {
-                let global = instance.defined_or_imported_global_ptr(base);
-                if mem64 {
-                    *(*global).as_u64()
-                } else {
-                    u64::from(*(*global).as_u32())
-                }
-            };
-
-            init.offset
-                .checked_add(val)
-                .ok_or_else(|| anyhow!("data segment global base overflows"))
-        }
-        None => Ok(init.offset),
-    }
+fn get_memory_init_start(init: &MemoryInitializer, instance: &mut Instance) -> u64 {
+    init.offset
+        .eval_int(|global| Some(unsafe { instance.global_as_u64(global) }))
+        .expect("data segment offsets are `i32` or `i64` expressions")
 }
 
 fn check_memory_init_bounds(
@@ -586,7 +564,7 @@ fn check_memory_init_bounds(
 ) -> Result<()> {
     for init in initializers {
         let memory = instance.get_memory(init.memory_index);
-        let start = get_memory_init_start(init, instance)?;
+        let start = get_memory_init_start(init, instance);
         let end = usize::try_from(start)
             .ok()
             .and_then(|start| start.checked_add(init.data.len()));
@@ -609,16 +587,8 @@ fn initialize_memories(instance: &mut Instance, module: &Module) -> Result<()> {
         (instance.get_memory(memory).current_length() as u64) / u64::from(WASM_PAGE_SIZE)
     };
 
-    // Loads the `global` value and returns it as a `u64`, but sign-extends
-    // 32-bit globals which can be used as the base for 32-bit memories.
-    let get_global_as_u64 = &mut |instance: &mut Instance, global| unsafe {
-        let def = instance.defined_or_imported_global_ptr(global);
-        if module.globals[global].wasm_ty == WasmType::I64 {
-            *(*def).as_u64()
-        } else {
-            u64::from(*(*def).as_u32())
-        }
-    };
+    let get_global_as_u64 =
+        &|instance: &mut Instance, global| unsafe { instance.global_as_u64(global) };
 
     // Delegates to the `init_memory` method which is sort of a duplicate of
     // `instance.memory_init_segment` but is used at compile-time in other
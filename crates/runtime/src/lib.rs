@@ -8,13 +8,18 @@ use std::fmt;
 use std::ptr::NonNull;
 use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
 use std::sync::Arc;
-use wasmtime_environ::{DefinedFuncIndex, DefinedMemoryIndex, HostPtr, VMOffsets};
+use wasmtime_environ::{
+    DefinedFuncIndex, DefinedMemoryIndex, FuncIndex, GcTypeIndex, HostPtr, SignatureIndex,
+    VMOffsets, WasmFuncType,
+};
 
 mod arch;
 #[cfg(feature = "component-model")]
 pub mod component;
+mod exception;
 mod export;
 mod externref;
+mod gc;
 mod imports;
 mod instance;
 mod memory;
@@ -37,8 +42,10 @@ pub mod mpk;
 pub use wasmtime_jit_debug::gdb_jit_int::GdbJitImageRegistration;
 
 pub use crate::arch::{get_stack_pointer, V128Abi};
+pub use crate::exception::*;
 pub use crate::export::*;
 pub use crate::externref::*;
+pub use crate::gc::*;
 pub use crate::imports::Imports;
 pub use crate::instance::{
     Instance, InstanceAllocationRequest, InstanceAllocator, InstanceAllocatorImpl, InstanceHandle,
@@ -62,8 +69,8 @@ pub use crate::vmcontext::{
     VMArrayCallFunction, VMArrayCallHostFuncContext, VMContext, VMFuncRef, VMFunctionBody,
     VMFunctionImport, VMGlobalDefinition, VMGlobalImport, VMInvokeArgument, VMMemoryDefinition,
     VMMemoryImport, VMNativeCallFunction, VMNativeCallHostFuncContext, VMOpaqueContext,
-    VMRuntimeLimits, VMSharedSignatureIndex, VMTableDefinition, VMTableImport, VMWasmCallFunction,
-    ValRaw,
+    VMRuntimeLimits, VMSharedSignatureIndex, VMTableDefinition, VMTableImport, VMTagDefinition,
+    VMTagImport, VMWasmCallFunction, ValRaw,
 };
 pub use send_sync_ptr::SendSyncPtr;
 
@@ -110,6 +117,12 @@ pub unsafe trait Store {
         &mut self,
     ) -> (&mut VMExternRefActivationsTable, &dyn ModuleInfoLookup);
 
+    /// Returns this store's GC heap.
+    fn gc_heap(&mut self) -> &mut GcHeap;
+
+    /// Collects the garbage in this store's GC heap and its `externref`s.
+    fn gc(&mut self);
+
     /// Callback invoked to allow the store's resource limiter to reject a
     /// memory grow operation.
     fn memory_growing(
@@ -123,6 +136,11 @@ pub unsafe trait Store {
     ///
     /// Note that this is not invoked if `memory_growing` returns an error.
     fn memory_grow_failed(&mut self, error: Error) -> Result<()>;
+    /// Returns whether execution in this store must be deterministic.
+    ///
+    /// When it must, a memory grow operation which fails for a host-specific
+    /// reason traps instead of returning -1.
+    fn deterministic(&self) -> bool;
     /// Callback invoked to allow the store's resource limiter to reject a
     /// table grow operation.
     fn table_growing(
@@ -144,12 +162,36 @@ pub unsafe trait Store {
     /// number. Cannot fail; cooperative epoch-based yielding is
     /// completely semantically transparent. Returns the new deadline.
     fn new_epoch(&mut self) -> Result<u64, Error>;
+    /// Callback invoked when compiled code with guest debugging enabled is
+    /// about to execute an instruction while the `debug_break` field of this
+    /// store's `VMRuntimeLimits` is set. If an error is returned it's raised
+    /// as a trap.
+    fn debug_break(&mut self, frame: DebugBreak) -> Result<(), Error>;
 
     /// Metadata required for resources for the component model.
     #[cfg(feature = "component-model")]
     fn component_calls(&mut self) -> &mut component::CallContexts;
 }
 
+/// The state of a function which is about to execute an instruction at a
+/// breakpoint, see [`Store::debug_break`].
+pub struct DebugBreak {
+    /// The instance which the function belongs to.
+    pub vmctx: *mut VMContext,
+    /// The index of the function within its module.
+    pub func: FuncIndex,
+    /// The offset of the instruction within the original wasm module.
+    pub offset: u32,
+    /// The values of the function's locals followed by the values on its
+    /// operand stack, in the format described in `wasmtime_environ`'s
+    /// `guest_debug` module.
+    pub values: *const u8,
+    /// The number of locals, including parameters, stored in `values`.
+    pub locals: u32,
+    /// The number of operand stack values stored in `values`.
+    pub stack: u32,
+}
+
 /// Functionality required by this crate for a particular module. This
 /// is chiefly needed for lazy initialization of various bits of
 /// instance state.
@@ -211,8 +253,20 @@ pub trait ModuleRuntimeInfo: Send + Sync + 'static {
     /// `VMSharedSignatureIndex` entries corresponding to the `SignatureIndex`.
     fn signature_ids(&self) -> &[VMSharedSignatureIndex];
 
+    /// Returns the type of the function signature `index` within this module.
+    fn function_type(&self, index: SignatureIndex) -> &WasmFuncType;
+
+    /// Returns the registered type of the `struct` or `array` type `index`
+    /// within this module.
+    fn gc_type(&self, index: GcTypeIndex) -> &Arc<GcTypeInfo>;
+
     /// Offset information for the current host.
     fn offsets(&self) -> &VMOffsets<HostPtr>;
+
+    /// Returns an array, indexed by `DefinedFuncIndex`, of pointers to the
+    /// coverage counters of each defined function, or null if the module
+    /// wasn't compiled with coverage enabled.
+    fn coverage_counters(&self) -> *const *mut u64;
 }
 
 /// Returns the host OS page size, in bytes.
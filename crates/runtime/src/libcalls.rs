@@ -54,10 +54,11 @@
 //! }
 //! ```
 
+use crate::exception::{exception, set_pending_exception, take_pending_exception, Exception};
 use crate::externref::VMExternRef;
 use crate::table::{Table, TableElementType};
 use crate::vmcontext::VMFuncRef;
-use crate::{Instance, TrapReason};
+use crate::{DebugBreak, Instance, TrapReason, ValRaw};
 #[cfg(feature = "wmemcheck")]
 use anyhow::bail;
 use anyhow::Result;
@@ -66,7 +67,8 @@ use std::mem;
 use std::ptr::{self, NonNull};
 use std::time::{Duration, Instant};
 use wasmtime_environ::{
-    DataIndex, ElemIndex, FuncIndex, GlobalIndex, MemoryIndex, TableIndex, Trap, Unsigned,
+    DataIndex, ElemIndex, FuncIndex, GcLayout, GcStorage, GcTypeIndex, GlobalIndex, MemoryIndex,
+    TableIndex, TagIndex, Trap, Unsigned,
 };
 #[cfg(feature = "wmemcheck")]
 use wasmtime_wmemcheck::AccessError::{
@@ -190,6 +192,13 @@ pub mod trampolines {
             self
         }
     }
+
+    impl LibcallResult for u32 {
+        type Abi = u32;
+        unsafe fn convert(self) -> u32 {
+            self
+        }
+    }
 }
 
 fn memory32_grow(
@@ -496,6 +505,277 @@ unsafe fn new_epoch(instance: &mut Instance) -> Result<u64> {
     (*instance.store()).new_epoch()
 }
 
+// Implementation of wasm's `throw` instruction, where the payload of the
+// exception is stored in `values` with the same layout as the arguments of an
+// array call.
+unsafe fn throw_exception(instance: &mut Instance, tag: u32, values: *mut u8) {
+    let tag = TagIndex::from_u32(tag);
+    let len = instance.tag_type(tag).params().len();
+    // Tags without parameters don't have any space reserved for their payload.
+    let payload = if len == 0 {
+        &[]
+    } else {
+        std::slice::from_raw_parts(values.cast::<ValRaw>(), len)
+    };
+    let definition = NonNull::new(instance.defined_or_imported_tag_ptr(tag)).unwrap();
+    let exnref = Exception::new(definition, instance.tag_type(tag), payload.into());
+    set_pending_exception(*instance.runtime_limits(), exnref);
+}
+
+// Implementation of wasm's `throw_ref` instruction.
+unsafe fn throw_exnref(instance: &mut Instance, exnref: *mut u8) -> Result<(), Trap> {
+    if exnref.is_null() {
+        return Err(Trap::NullReference);
+    }
+    let exnref = VMExternRef::clone_from_raw(exnref);
+    set_pending_exception(*instance.runtime_limits(), exnref);
+    Ok(())
+}
+
+// Returns the tag definition of the pending exception, which is compared by
+// address against the tags of `catch` clauses.
+unsafe fn exception_tag(instance: &mut Instance) -> *mut u8 {
+    let limits = *instance.runtime_limits();
+    let exnref = *(*limits).pending_exception.get();
+    debug_assert!(!exnref.is_null());
+    let exnref = mem::ManuallyDrop::new(VMExternRef::from_raw(exnref));
+    exception(&exnref).tag().cast()
+}
+
+// Catches the pending exception, writing its payload into `values` if it's
+// non-null, and returns it as an `exnref`.
+//
+// All references which are handed to wasm are inserted into the activations
+// table without triggering a GC as the payload in `values` is not yet visible
+// to stack maps.
+unsafe fn take_exception(instance: &mut Instance, values: *mut u8) -> *mut u8 {
+    let limits = *instance.runtime_limits();
+    let exnref = take_pending_exception(limits).expect("an exception should be pending");
+    let (activations_table, _) = (*instance.store()).externref_activations_table();
+    if !values.is_null() {
+        let exn = exception(&exnref);
+        let payload = exn.payload();
+        ptr::copy_nonoverlapping(payload.as_ptr(), values.cast::<ValRaw>(), payload.len());
+        for r in exn.refs() {
+            activations_table.insert_without_gc(r.clone());
+        }
+    }
+    let raw = exnref.as_raw();
+    activations_table.insert_without_gc(exnref);
+    raw
+}
+
+// Hook for when compiled code with guest debugging enabled is about to execute
+// an instruction while a breakpoint is set or single-stepping.
+unsafe fn debug_break(
+    instance: &mut Instance,
+    func: u32,
+    offset: u32,
+    values: *mut u8,
+    locals: u32,
+    stack: u32,
+) -> Result<()> {
+    let frame = DebugBreak {
+        vmctx: instance.vmctx(),
+        func: FuncIndex::from_u32(func),
+        offset,
+        values,
+        locals,
+        stack,
+    };
+    (*instance.store()).debug_break(frame)
+}
+
+// Implementation of `struct.new`, `array.new` and their variants, which
+// initialize the fields of the new object themselves.
+unsafe fn gc_alloc(instance: &mut Instance, ty: u32, len: u32) -> Result<u32, Trap> {
+    let ty = instance.gc_type(GcTypeIndex::from_u32(ty)).clone();
+    crate::gc::gc_alloc(instance.store(), &ty, len)
+}
+
+// Tests whether an object is of a `struct` or `array` type for `ref.test`,
+// `ref.cast` and `br_on_cast`, where compiled code handles `null`s and
+// `i31ref`s itself.
+unsafe fn gc_ref_test(instance: &mut Instance, obj: u32, ty: u32) -> u32 {
+    let ty = instance.gc_type(GcTypeIndex::from_u32(ty)).index;
+    let heap = (*instance.store()).gc_heap();
+    u32::from(heap.object_type(obj).is_subtype_of(ty))
+}
+
+// Implementation of `array.copy`.
+unsafe fn gc_array_copy(
+    instance: &mut Instance,
+    dst: u32,
+    dst_index: u32,
+    src: u32,
+    src_index: u32,
+    len: u32,
+) -> Result<(), Trap> {
+    if dst == 0 || src == 0 {
+        return Err(Trap::NullReference);
+    }
+    let heap = (*instance.store()).gc_heap();
+    let dst = heap
+        .array_elems(dst, dst_index, len)
+        .ok_or(Trap::ArrayOutOfBounds)?;
+    let src = heap
+        .array_elems(src, src_index, len)
+        .ok_or(Trap::ArrayOutOfBounds)?;
+    heap.bytes_mut().copy_within(src, dst.start);
+    Ok(())
+}
+
+// Implementation of `array.new_data`.
+unsafe fn gc_array_new_data(
+    instance: &mut Instance,
+    ty: u32,
+    data: u32,
+    offset: u32,
+    len: u32,
+) -> Result<u32, Trap> {
+    let ty = instance.gc_type(GcTypeIndex::from_u32(ty)).clone();
+    let elem_size = match ty.layout {
+        GcLayout::Array(elem) => elem.size(),
+        GcLayout::Struct { .. } => unreachable!(),
+    };
+    let (data, range) = passive_data_range(instance, data, offset, len, elem_size)?;
+    let array = crate::gc::gc_alloc(instance.store(), &ty, len)?;
+    let data = &instance.passive_data(DataIndex::from_u32(data))[range];
+    let heap = (*instance.store()).gc_heap();
+    let elems = heap.array_elems(array, 0, len).unwrap();
+    heap.bytes_mut()[elems].copy_from_slice(data);
+    Ok(array)
+}
+
+// Implementation of `array.init_data`.
+unsafe fn gc_array_init_data(
+    instance: &mut Instance,
+    array: u32,
+    index: u32,
+    data: u32,
+    offset: u32,
+    len: u32,
+) -> Result<(), Trap> {
+    if array == 0 {
+        return Err(Trap::NullReference);
+    }
+    let heap = (*instance.store()).gc_heap();
+    let elems = heap
+        .array_elems(array, index, len)
+        .ok_or(Trap::ArrayOutOfBounds)?;
+    let elem_size = heap.array_elem_size(array);
+    let (data, range) = passive_data_range(instance, data, offset, len, elem_size)?;
+    let data = &instance.passive_data(DataIndex::from_u32(data))[range];
+    heap.bytes_mut()[elems].copy_from_slice(data);
+    Ok(())
+}
+
+// Implementation of `array.new_elem`.
+unsafe fn gc_array_new_elem(
+    instance: &mut Instance,
+    ty: u32,
+    elem: u32,
+    offset: u32,
+    len: u32,
+) -> Result<u32, Trap> {
+    let ty = instance.gc_type(GcTypeIndex::from_u32(ty)).clone();
+    let elements = passive_elements(instance, elem, offset, len)?;
+    let array = crate::gc::gc_alloc(instance.store(), &ty, len)?;
+    write_array_elements(instance, array, 0, &elements);
+    Ok(array)
+}
+
+// Implementation of `array.init_elem`.
+unsafe fn gc_array_init_elem(
+    instance: &mut Instance,
+    array: u32,
+    index: u32,
+    elem: u32,
+    offset: u32,
+    len: u32,
+) -> Result<(), Trap> {
+    if array == 0 {
+        return Err(Trap::NullReference);
+    }
+    let heap = (*instance.store()).gc_heap();
+    if heap.array_elems(array, index, len).is_none() {
+        return Err(Trap::ArrayOutOfBounds);
+    }
+    let elements = passive_elements(instance, elem, offset, len)?;
+    write_array_elements(instance, array, index, &elements);
+    Ok(())
+}
+
+// Returns the `len` elements starting at `offset` of the passive element
+// segment `elem`.
+fn passive_elements(
+    instance: &Instance,
+    elem: u32,
+    offset: u32,
+    len: u32,
+) -> Result<Vec<FuncIndex>, Trap> {
+    instance
+        .passive_elements(ElemIndex::from_u32(elem))
+        .get(usize::try_from(offset).unwrap()..)
+        .and_then(|s| s.get(..usize::try_from(len).unwrap()))
+        .map(|s| s.to_vec())
+        .ok_or(Trap::TableOutOfBounds)
+}
+
+// Stores the function references `elements` in the array `array` starting at
+// `index`, which must be in bounds.
+//
+// Element segments only hold functions, so arrays of `any` references can
+// only be initialized from segments of `null`s, which arrays are full of
+// already.
+unsafe fn write_array_elements(
+    instance: &mut Instance,
+    array: u32,
+    index: u32,
+    elements: &[FuncIndex],
+) {
+    let func_refs = elements
+        .iter()
+        .map(|f| instance.get_func_ref(*f).unwrap_or(ptr::null_mut()))
+        .collect::<Vec<_>>();
+    let heap = (*instance.store()).gc_heap();
+    let len = u32::try_from(elements.len()).unwrap();
+    let elems = heap.array_elems(array, index, len).unwrap();
+    match heap.object_type(array).layout {
+        GcLayout::Array(GcStorage::FuncRef) => {
+            let bytes = &mut heap.bytes_mut()[elems];
+            for (dst, func_ref) in bytes.chunks_exact_mut(8).zip(func_refs) {
+                dst.copy_from_slice(&(func_ref as u64).to_le_bytes());
+            }
+        }
+        _ => {
+            debug_assert!(func_refs.iter().all(|f| f.is_null()));
+            heap.bytes_mut()[elems].fill(0);
+        }
+    }
+}
+
+// Returns the range of the bytes of `len` array elements of `elem_size` bytes
+// starting at `offset` in the passive data segment `data`.
+fn passive_data_range(
+    instance: &Instance,
+    data: u32,
+    offset: u32,
+    len: u32,
+    elem_size: u32,
+) -> Result<(u32, std::ops::Range<usize>), Trap> {
+    let bytes = instance.passive_data(DataIndex::from_u32(data)).len();
+    let start = u64::from(offset);
+    let end = start + u64::from(len) * u64::from(elem_size);
+    if end > u64::try_from(bytes).unwrap() {
+        return Err(Trap::MemoryOutOfBounds);
+    }
+    Ok((
+        data,
+        usize::try_from(start).unwrap()..usize::try_from(end).unwrap(),
+    ))
+}
+
 cfg_if! {
     if #[cfg(feature = "wmemcheck")] {
         // Hook for validating malloc using wmemcheck_state.
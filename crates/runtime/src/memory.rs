@@ -131,6 +131,12 @@ pub trait RuntimeLinearMemory: Send + Sync {
                 // dropped
                 // (https://github.com/bytecodealliance/wasmtime/issues/4240).
                 if let Some(store) = store {
+                    // Whether the host can provide more memory varies from
+                    // host to host, so in deterministic mode this traps
+                    // rather than letting wasm observe the failure.
+                    if store.deterministic() {
+                        return Err(e.context("failed to grow memory in deterministic mode"));
+                    }
                     store.memory_grow_failed(e)?;
                 }
                 Ok(None)
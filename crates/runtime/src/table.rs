@@ -175,10 +175,10 @@ pub enum Table {
 pub type TableValue = Option<SendSyncPtr<u8>>;
 
 fn wasm_to_table_type(ty: WasmRefType) -> Result<TableElementType> {
-    match ty.heap_type {
+    match ty.heap_type.top() {
         WasmHeapType::Func => Ok(TableElementType::Func),
         WasmHeapType::Extern => Ok(TableElementType::Extern),
-        WasmHeapType::TypedFunc(_) => Ok(TableElementType::Func),
+        _ => bail!("tables of type `{ty}` are not supported yet"),
     }
 }
 
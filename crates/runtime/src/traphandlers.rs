@@ -198,6 +198,12 @@ impl From<wasmtime_environ::Trap> for TrapReason {
     }
 }
 
+/// Returns the runtime limits of the store of the innermost Wasm activation on
+/// the current thread, if any.
+pub fn innermost_limits() -> Option<*const VMRuntimeLimits> {
+    tls::with(|state| state.map(|state| state.limits))
+}
+
 /// Catches any wasm traps that happen within the execution of `closure`,
 /// returning them as a `Result`.
 ///
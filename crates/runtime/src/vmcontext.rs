@@ -266,6 +266,45 @@ mod test_vmglobal_import {
     }
 }
 
+/// The fields compiled code needs to access to utilize a WebAssembly exception
+/// tag imported from another instance.
+///
+/// The address of the imported `VMTagDefinition` is the identity of the tag,
+/// which is what compiled code compares when matching exceptions.
+#[derive(Debug, Copy, Clone)]
+#[repr(C)]
+pub struct VMTagImport {
+    /// A pointer to the imported tag description.
+    pub from: *mut VMTagDefinition,
+}
+
+// Declare that this type is send/sync, it's the responsibility of users of
+// `VMTagImport` to uphold this guarantee.
+unsafe impl Send for VMTagImport {}
+unsafe impl Sync for VMTagImport {}
+
+#[cfg(test)]
+mod test_vmtag_import {
+    use super::VMTagImport;
+    use memoffset::offset_of;
+    use std::mem::size_of;
+    use wasmtime_environ::{Module, VMOffsets};
+
+    #[test]
+    fn check_vmtag_import_offsets() {
+        let module = Module::new();
+        let offsets = VMOffsets::new(size_of::<*mut u8>() as u8, &module);
+        assert_eq!(
+            size_of::<VMTagImport>(),
+            usize::from(offsets.size_of_vmtag_import())
+        );
+        assert_eq!(
+            offset_of!(VMTagImport, from),
+            usize::from(offsets.vmtag_import_from())
+        );
+    }
+}
+
 /// The fields compiled code needs to access to utilize a WebAssembly linear
 /// memory defined within the instance, namely the start address and the
 /// size in bytes.
@@ -557,6 +596,47 @@ impl VMGlobalDefinition {
     }
 }
 
+/// The storage for a WebAssembly exception tag defined within the instance.
+///
+/// Tags have no state beyond their type; the address of this definition is
+/// what distinguishes one tag from another.
+#[derive(Debug)]
+#[repr(C)]
+pub struct VMTagDefinition {
+    /// The type of the tag, whose parameters are the payload of exceptions
+    /// thrown with this tag.
+    pub type_index: VMSharedSignatureIndex,
+}
+
+impl VMTagDefinition {
+    /// Creates a new tag definition of the given type.
+    pub fn new(type_index: VMSharedSignatureIndex) -> Self {
+        VMTagDefinition { type_index }
+    }
+}
+
+#[cfg(test)]
+mod test_vmtag_definition {
+    use super::VMTagDefinition;
+    use memoffset::offset_of;
+    use std::mem::size_of;
+    use wasmtime_environ::{Module, VMOffsets};
+
+    #[test]
+    fn check_vmtag_definition_offsets() {
+        let module = Module::new();
+        let offsets = VMOffsets::new(size_of::<*mut u8>() as u8, &module);
+        assert_eq!(
+            size_of::<VMTagDefinition>(),
+            usize::from(offsets.size_of_vmtag_definition())
+        );
+        assert_eq!(
+            offset_of!(VMTagDefinition, type_index),
+            usize::from(offsets.vmtag_definition_type_index())
+        );
+    }
+}
+
 /// An index into the shared signature registry, usable for checking signatures
 /// at indirect calls.
 #[repr(C)]
@@ -829,6 +909,28 @@ pub struct VMRuntimeLimits {
     /// Used to find the end of a contiguous sequence of Wasm frames when
     /// walking the stack.
     pub last_wasm_entry_sp: UnsafeCell<usize>,
+
+    /// The exception which is currently being thrown, as an owned raw
+    /// `VMExternRef` pointer, or null if there is none.
+    ///
+    /// Compiled code checks this after every call when the exception
+    /// handling proposal is enabled: a non-null value means the callee threw
+    /// and control is transferred to the innermost handler, or the caller if
+    /// there is none in the current function. See the `exception` module for
+    /// the functions which manage this.
+    pub pending_exception: UnsafeCell<*mut u8>,
+
+    /// The base address of the store's GC heap, which compiled code adds the
+    /// 32-bit references of `struct`s and `array`s to when accessing them.
+    ///
+    /// The heap may move when it grows, so this is updated whenever it does
+    /// and compiled code reloads it after any call.
+    pub gc_heap_base: UnsafeCell<*mut u8>,
+
+    /// Whether compiled code with guest debugging enabled calls the
+    /// `debug_break` libcall before each instruction, which is the case while
+    /// the store has a breakpoint set or is single-stepping.
+    pub debug_break: UnsafeCell<u8>,
 }
 
 // The `VMRuntimeLimits` type is a pod-type with no destructor, and we don't
@@ -847,6 +949,9 @@ impl Default for VMRuntimeLimits {
             last_wasm_exit_fp: UnsafeCell::new(0),
             last_wasm_exit_pc: UnsafeCell::new(0),
             last_wasm_entry_sp: UnsafeCell::new(0),
+            pending_exception: UnsafeCell::new(std::ptr::null_mut()),
+            gc_heap_base: UnsafeCell::new(std::ptr::null_mut()),
+            debug_break: UnsafeCell::new(0),
         }
     }
 }
@@ -896,6 +1001,18 @@ mod test_vmruntime_limits {
             offset_of!(VMRuntimeLimits, last_wasm_entry_sp),
             usize::from(offsets.ptr.vmruntime_limits_last_wasm_entry_sp())
         );
+        assert_eq!(
+            offset_of!(VMRuntimeLimits, pending_exception),
+            usize::from(offsets.ptr.vmruntime_limits_pending_exception())
+        );
+        assert_eq!(
+            offset_of!(VMRuntimeLimits, gc_heap_base),
+            usize::from(offsets.ptr.vmruntime_limits_gc_heap_base())
+        );
+        assert_eq!(
+            offset_of!(VMRuntimeLimits, debug_break),
+            usize::from(offsets.ptr.vmruntime_limits_debug_break())
+        );
     }
 }
 
@@ -1026,6 +1143,16 @@ pub union ValRaw {
     ///
     /// This value is always stored in a little-endian format.
     externref: *mut c_void,
+
+    /// A WebAssembly `anyref` value.
+    ///
+    /// The payload here is the runtime-defined 32-bit representation of the
+    /// reference where zero is `null` (see `wasmtime_environ::I31_TAG`),
+    /// encoded the way compiled code holds it (see
+    /// `wasmtime_environ::ANYREF_STACK_TAG`).
+    ///
+    /// This value is always stored in a little-endian format.
+    anyref: u64,
 }
 
 // This type is just a bag-of-bits so it's up to the caller to figure out how
@@ -1102,6 +1229,14 @@ impl ValRaw {
         }
     }
 
+    /// Creates a WebAssembly `anyref` value
+    #[inline]
+    pub fn anyref(i: u32) -> ValRaw {
+        ValRaw {
+            anyref: wasmtime_environ::anyref_to_stack(i).to_le(),
+        }
+    }
+
     /// Gets the WebAssembly `i32` value
     #[inline]
     pub fn get_i32(&self) -> i32 {
@@ -1155,6 +1290,12 @@ impl ValRaw {
     pub fn get_externref(&self) -> *mut c_void {
         unsafe { Strict::map_addr(self.externref, |i| usize::from_le(i)) }
     }
+
+    /// Gets the WebAssembly `anyref` value
+    #[inline]
+    pub fn get_anyref(&self) -> u32 {
+        unsafe { wasmtime_environ::anyref_from_stack(u64::from_le(self.anyref)) }
+    }
 }
 
 /// An "opaque" version of `VMContext` which must be explicitly casted to a
@@ -56,6 +56,14 @@ impl WasmRefType {
         nullable: true,
         heap_type: WasmHeapType::Func,
     };
+    pub const ANYREF: WasmRefType = WasmRefType {
+        nullable: true,
+        heap_type: WasmHeapType::Any,
+    };
+    pub const EXNREF: WasmRefType = WasmRefType {
+        nullable: true,
+        heap_type: WasmHeapType::Exn,
+    };
 }
 
 impl fmt::Display for WasmRefType {
@@ -90,6 +98,24 @@ pub enum WasmHeapType {
     // propagated to quite a few locations though so it's left for a future
     // refactoring at this time.
     TypedFunc(SignatureIndex),
+
+    // The concrete `struct` and `array` types defined by a module.
+    TypedStruct(GcTypeIndex),
+    TypedArray(GcTypeIndex),
+
+    // The abstract heap types introduced by the GC proposal.
+    Any,
+    Eq,
+    I31,
+    Struct,
+    Array,
+    None,
+    NoFunc,
+    NoExtern,
+
+    // The exception reference type introduced by the exception handling
+    // proposal.
+    Exn,
 }
 
 impl fmt::Display for WasmHeapType {
@@ -98,8 +124,66 @@ impl fmt::Display for WasmHeapType {
             Self::Func => write!(f, "func"),
             Self::Extern => write!(f, "extern"),
             Self::TypedFunc(i) => write!(f, "func_sig{}", i.as_u32()),
+            Self::TypedStruct(i) => write!(f, "struct_ty{}", i.as_u32()),
+            Self::TypedArray(i) => write!(f, "array_ty{}", i.as_u32()),
+            Self::Any => write!(f, "any"),
+            Self::Eq => write!(f, "eq"),
+            Self::I31 => write!(f, "i31"),
+            Self::Struct => write!(f, "struct"),
+            Self::Array => write!(f, "array"),
+            Self::None => write!(f, "none"),
+            Self::NoFunc => write!(f, "nofunc"),
+            Self::NoExtern => write!(f, "noextern"),
+            Self::Exn => write!(f, "exn"),
+        }
+    }
+}
+
+impl WasmHeapType {
+    /// Returns the top type of the hierarchy this heap type belongs to: one
+    /// of `func`, `extern`, `any` or `exn`.
+    pub fn top(&self) -> WasmHeapType {
+        match self {
+            Self::Func | Self::TypedFunc(_) | Self::NoFunc => Self::Func,
+            Self::Extern | Self::NoExtern => Self::Extern,
+            Self::Any
+            | Self::Eq
+            | Self::I31
+            | Self::Struct
+            | Self::Array
+            | Self::TypedStruct(_)
+            | Self::TypedArray(_)
+            | Self::None => Self::Any,
+            Self::Exn => Self::Exn,
         }
     }
+
+    /// Returns the bottom type of the hierarchy this heap type belongs to:
+    /// one of `nofunc`, `noextern` or `none`.
+    ///
+    /// The `exn` hierarchy has no bottom type of its own, so `exn` is
+    /// returned for it.
+    pub fn bottom(&self) -> WasmHeapType {
+        match self.top() {
+            Self::Func => Self::NoFunc,
+            Self::Extern => Self::NoExtern,
+            Self::Exn => Self::Exn,
+            _ => Self::None,
+        }
+    }
+
+    /// Returns whether references of this type are host-managed, reference
+    /// counted pointers which must be tracked in stack maps: `externref`s and
+    /// `exnref`s.
+    pub fn is_vmexternref(&self) -> bool {
+        matches!(self, Self::Extern | Self::Exn)
+    }
+
+    /// Returns whether references of this type are `anyref`s: either
+    /// `i31ref`s or references to objects in a store's GC heap.
+    pub fn is_anyref(&self) -> bool {
+        self.top() == Self::Any
+    }
 }
 
 /// WebAssembly function type -- equivalent of `wasmparser`'s FuncType.
@@ -117,14 +201,14 @@ impl WasmFuncType {
         let externref_params_count = params
             .iter()
             .filter(|p| match **p {
-                WasmType::Ref(rt) => rt.heap_type == WasmHeapType::Extern,
+                WasmType::Ref(rt) => rt.heap_type.is_vmexternref(),
                 _ => false,
             })
             .count();
         let externref_returns_count = returns
             .iter()
             .filter(|r| match **r {
-                WasmType::Ref(rt) => rt.heap_type == WasmHeapType::Extern,
+                WasmType::Ref(rt) => rt.heap_type.is_vmexternref(),
                 _ => false,
             })
             .count();
@@ -161,6 +245,70 @@ impl WasmFuncType {
     }
 }
 
+/// The type of a struct field or array element -- equivalent of `wasmparser`'s
+/// StorageType.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum WasmStorageType {
+    /// A packed 8-bit integer.
+    I8,
+    /// A packed 16-bit integer.
+    I16,
+    /// A value type.
+    Val(WasmType),
+}
+
+impl WasmStorageType {
+    /// Returns the value type that values of this storage type are unpacked
+    /// to.
+    pub fn unpacked(&self) -> WasmType {
+        match self {
+            Self::I8 | Self::I16 => WasmType::I32,
+            Self::Val(ty) => *ty,
+        }
+    }
+}
+
+impl fmt::Display for WasmStorageType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::I8 => write!(f, "i8"),
+            Self::I16 => write!(f, "i16"),
+            Self::Val(ty) => write!(f, "{ty}"),
+        }
+    }
+}
+
+/// A struct field or array element type -- equivalent of `wasmparser`'s
+/// FieldType.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct WasmFieldType {
+    /// The type of the values stored in this field.
+    pub element_type: WasmStorageType,
+    /// Whether this field may be mutated after it is initialized.
+    pub mutable: bool,
+}
+
+/// The definition of a `struct` or `array` type in the GC proposal --
+/// equivalent of `wasmparser`'s SubType with a non-function CompositeType.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct WasmGcType {
+    /// The declared supertype of this type, if any.
+    pub supertype: Option<GcTypeIndex>,
+    /// Whether this type may not be used as a supertype.
+    pub is_final: bool,
+    /// The fields of this type.
+    pub kind: WasmGcTypeKind,
+}
+
+/// The fields of a [`WasmGcType`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum WasmGcTypeKind {
+    /// A `struct` with the given fields.
+    Struct(Box<[WasmFieldType]>),
+    /// An `array` with the given element type.
+    Array(WasmFieldType),
+}
+
 /// Index type of a function (imported or defined) inside the WebAssembly module.
 #[derive(Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Debug, Serialize, Deserialize)]
 pub struct FuncIndex(u32);
@@ -191,6 +339,11 @@ entity_impl!(OwnedMemoryIndex);
 pub struct DefinedGlobalIndex(u32);
 entity_impl!(DefinedGlobalIndex);
 
+/// Index type of a defined tag inside the WebAssembly module.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Debug, Serialize, Deserialize)]
+pub struct DefinedTagIndex(u32);
+entity_impl!(DefinedTagIndex);
+
 /// Index type of a table (imported or defined) inside the WebAssembly module.
 #[derive(Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Debug, Serialize, Deserialize)]
 pub struct TableIndex(u32);
@@ -211,6 +364,11 @@ entity_impl!(MemoryIndex);
 pub struct SignatureIndex(u32);
 entity_impl!(SignatureIndex);
 
+/// Index type of a `struct` or `array` type inside the WebAssembly module.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Debug, Serialize, Deserialize)]
+pub struct GcTypeIndex(u32);
+entity_impl!(GcTypeIndex);
+
 /// Index type of a passive data segment inside the WebAssembly module.
 #[derive(Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Debug, Serialize, Deserialize)]
 pub struct DataIndex(u32);
@@ -250,6 +408,8 @@ pub enum EntityIndex {
     Memory(MemoryIndex),
     /// Global index.
     Global(GlobalIndex),
+    /// Tag index.
+    Tag(TagIndex),
 }
 
 impl From<FuncIndex> for EntityIndex {
@@ -276,6 +436,12 @@ impl From<GlobalIndex> for EntityIndex {
     }
 }
 
+impl From<TagIndex> for EntityIndex {
+    fn from(idx: TagIndex) -> EntityIndex {
+        EntityIndex::Tag(idx)
+    }
+}
+
 /// A type of an item in a wasm module where an item is typically something that
 /// can be exported.
 #[allow(missing_docs)]
@@ -370,6 +536,8 @@ pub enum GlobalInit {
     RefNullConst,
     /// A `ref.func <index>`.
     RefFunc(FuncIndex),
+    /// An `i32.const` followed by `ref.i31`.
+    RefI31Const(i32),
 }
 
 /// WebAssembly table.
@@ -407,21 +575,12 @@ impl From<wasmparser::MemoryType> for Memory {
     }
 }
 
-/// WebAssembly event.
+/// WebAssembly exception tag.
 #[derive(Debug, Clone, Copy, Hash, Eq, PartialEq, Serialize, Deserialize)]
 pub struct Tag {
-    /// The event signature type.
-    pub ty: TypeIndex,
-}
-
-impl From<wasmparser::TagType> for Tag {
-    fn from(ty: wasmparser::TagType) -> Tag {
-        match ty.kind {
-            wasmparser::TagKind::Exception => Tag {
-                ty: TypeIndex::from_u32(ty.func_type_idx),
-            },
-        }
-    }
+    /// The signature of the tag, whose parameters are the types of the
+    /// payload of exceptions thrown with this tag.
+    pub signature: SignatureIndex,
 }
 
 /// Helpers used to convert a `wasmparser` type to a type in this crate.
@@ -458,6 +617,18 @@ pub trait TypeConvert {
         WasmFuncType::new(params, results)
     }
 
+    /// Converts a wasmparser struct or array field type to a wasmtime type
+    fn convert_field_type(&self, ty: &wasmparser::FieldType) -> WasmFieldType {
+        WasmFieldType {
+            element_type: match ty.element_type {
+                wasmparser::StorageType::I8 => WasmStorageType::I8,
+                wasmparser::StorageType::I16 => WasmStorageType::I16,
+                wasmparser::StorageType::Val(ty) => WasmStorageType::Val(self.convert_valtype(ty)),
+            },
+            mutable: ty.mutable,
+        }
+    }
+
     /// Converts a wasmparser value type to a wasmtime type
     fn convert_valtype(&self, ty: wasmparser::ValType) -> WasmType {
         match ty {
@@ -485,17 +656,16 @@ pub trait TypeConvert {
             wasmparser::HeapType::Extern => WasmHeapType::Extern,
             wasmparser::HeapType::Concrete(i) => self.lookup_heap_type(i),
 
-            wasmparser::HeapType::Any
-            | wasmparser::HeapType::Exn
-            | wasmparser::HeapType::None
-            | wasmparser::HeapType::NoExtern
-            | wasmparser::HeapType::NoFunc
-            | wasmparser::HeapType::Eq
-            | wasmparser::HeapType::Struct
-            | wasmparser::HeapType::Array
-            | wasmparser::HeapType::I31 => {
-                unimplemented!("unsupported heap type {ty:?}");
-            }
+            wasmparser::HeapType::Any => WasmHeapType::Any,
+            wasmparser::HeapType::Eq => WasmHeapType::Eq,
+            wasmparser::HeapType::I31 => WasmHeapType::I31,
+            wasmparser::HeapType::Struct => WasmHeapType::Struct,
+            wasmparser::HeapType::Array => WasmHeapType::Array,
+            wasmparser::HeapType::None => WasmHeapType::None,
+            wasmparser::HeapType::NoFunc => WasmHeapType::NoFunc,
+            wasmparser::HeapType::NoExtern => WasmHeapType::NoExtern,
+
+            wasmparser::HeapType::Exn => WasmHeapType::Exn,
         }
     }
 
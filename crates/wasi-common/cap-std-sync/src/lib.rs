@@ -147,6 +147,12 @@ impl WasiCtxBuilder {
             .insert_file(fd, file, FileAccessMode::READ | FileAccessMode::WRITE);
         Ok(self)
     }
+    /// Use deterministic clocks, scheduling and randomness; see
+    /// [`WasiCtx::set_deterministic`].
+    pub fn deterministic(&mut self) -> &mut Self {
+        self.ctx.set_deterministic();
+        self
+    }
     pub fn build(&mut self) -> WasiCtx {
         assert!(!self.built);
         let WasiCtxBuilder { ctx, .. } = mem::replace(self, Self::new());
@@ -1,5 +1,7 @@
 use crate::{Error, ErrorExt};
 use cap_std::time::{Duration, Instant, SystemTime};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
 
 pub enum SystemTimeSpec {
     SymbolicNow,
@@ -65,3 +67,76 @@ impl WasiClocks {
             .ok_or_else(|| Error::badf().context("monotonic clock is not supported"))
     }
 }
+
+/// A clock which doesn't read the host's time, for deterministic execution.
+///
+/// Time starts at zero, which the system clock reports as the Unix epoch, and
+/// advances by [`DeterministicClock::TICK`] every time the clock is read.
+/// Clones of a clock share the same time, so one clock may be used as both the
+/// system and monotonic clock. [`DeterministicSched`](crate::sched::DeterministicSched)
+/// moves the clock forward to deadlines instead of waiting for them.
+#[derive(Clone)]
+pub struct DeterministicClock {
+    nanos: Arc<AtomicU64>,
+    base: Instant,
+}
+
+impl DeterministicClock {
+    /// The amount of time which passes each time the clock is read.
+    pub const TICK: Duration = Duration::from_micros(1);
+
+    pub fn new() -> Self {
+        Self {
+            nanos: Arc::new(AtomicU64::new(0)),
+            // Only differences between instants are visible to the guest, so
+            // any base instant will do.
+            base: Instant::from_std(std::time::Instant::now()),
+        }
+    }
+
+    /// Moves the clock forward to `deadline` if it's in the future.
+    pub fn advance_to(&self, deadline: Instant) {
+        let nanos = deadline
+            .checked_duration_since(self.base)
+            .unwrap_or_default()
+            .as_nanos();
+        self.nanos
+            .fetch_max(nanos.try_into().unwrap_or(u64::MAX), Ordering::SeqCst);
+    }
+
+    /// Moves the clock forward by `duration`.
+    pub fn advance(&self, duration: Duration) {
+        let nanos = duration.as_nanos().try_into().unwrap_or(u64::MAX);
+        let _ = self
+            .nanos
+            .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |n| {
+                Some(n.saturating_add(nanos))
+            });
+    }
+
+    /// Returns the time since the clock's creation and advances the clock.
+    fn tick(&self) -> Duration {
+        Duration::from_nanos(
+            self.nanos
+                .fetch_add(Self::TICK.as_nanos() as u64, Ordering::SeqCst),
+        )
+    }
+}
+
+impl WasiSystemClock for DeterministicClock {
+    fn resolution(&self) -> Duration {
+        Self::TICK
+    }
+    fn now(&self, _precision: Duration) -> SystemTime {
+        SystemTime::from_std(std::time::SystemTime::UNIX_EPOCH + self.tick())
+    }
+}
+
+impl WasiMonotonicClock for DeterministicClock {
+    fn resolution(&self) -> Duration {
+        Self::TICK
+    }
+    fn now(&self, _precision: Duration) -> Instant {
+        self.base + self.tick()
+    }
+}
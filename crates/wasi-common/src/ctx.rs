@@ -1,11 +1,11 @@
-use crate::clocks::WasiClocks;
+use crate::clocks::{DeterministicClock, WasiClocks};
 use crate::dir::{DirEntry, WasiDir};
 use crate::file::{FileAccessMode, FileEntry, WasiFile};
-use crate::sched::WasiSched;
+use crate::sched::{DeterministicSched, WasiSched};
 use crate::string_array::StringArray;
 use crate::table::Table;
 use crate::{Error, StringArrayError};
-use cap_rand::RngCore;
+use cap_rand::{RngCore, SeedableRng};
 use std::ops::Deref;
 use std::path::{Path, PathBuf};
 use std::sync::{Arc, Mutex};
@@ -97,6 +97,24 @@ impl WasiCtx {
         Ok(())
     }
 
+    /// Replaces the clocks, scheduler and random number generator with
+    /// deterministic ones, so that the guest observes the same time and
+    /// randomness on every run and on every host.
+    ///
+    /// Both clocks share a single [`DeterministicClock`] and the random number
+    /// generator is seeded with a fixed value, so it is entirely predictable.
+    pub fn set_deterministic(&mut self) {
+        let s = Arc::get_mut(&mut self.0).expect(
+            "`set_deterministic` should only be used during initialization before the context is cloned",
+        );
+        let clock = DeterministicClock::new();
+        s.clocks = WasiClocks::new()
+            .with_system(clock.clone())
+            .with_monotonic(clock.clone());
+        s.sched = Box::new(DeterministicSched::new(clock));
+        s.random = Mutex::new(Box::new(cap_rand::rngs::StdRng::seed_from_u64(0)));
+    }
+
     pub fn set_stdin(&self, f: Box<dyn WasiFile>) {
         self.insert_file(0, f, FileAccessMode::READ);
     }
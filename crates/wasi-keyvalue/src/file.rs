@@ -0,0 +1,551 @@
+//! A [`Store`] which persists buckets in files on the host.
+//!
+//! Each bucket is a log of the changes made to it, stored in a `.kv` file
+//! named after the bucket. The log is a sequence of frames:
+//!
+//! ```text
+//! frame := payload-len:u32 checksum:u64 payload
+//! payload := op*
+//! op := 0:u8 key-len:u32 key value-len:u32 value   ; set
+//!     | 1:u8 key-len:u32 key                       ; delete
+//! ```
+//!
+//! All integers are little-endian and the checksum is the 64-bit FNV-1a hash
+//! of the payload. Every write to a bucket appends a single frame, so a batch
+//! of changes is either entirely present in the log or not at all. When a
+//! bucket is opened its log is replayed into memory, and anything after the
+//! last intact frame, such as a frame which was only partially written when
+//! the host crashed, is discarded. Once most of a log is made up of stale
+//! entries it is compacted by writing the live entries to a new file and
+//! renaming it over the old one.
+
+use crate::{Bucket, Error, KeyResponse, Store};
+use anyhow::Context;
+use std::collections::{BTreeMap, HashMap};
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, BufWriter, Read, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+/// The size of a frame's header.
+const HEADER_SIZE: usize = 12;
+
+/// Logs smaller than this are never compacted.
+const MIN_COMPACT_SIZE: u64 = 64 * 1024;
+
+/// The payload size at which compaction starts a new frame.
+const COMPACT_FRAME_SIZE: usize = 1024 * 1024;
+
+const OP_SET: u8 = 0;
+const OP_DELETE: u8 = 1;
+
+/// A [`Store`] whose buckets are persisted in a directory on the host.
+///
+/// Opening a bucket which doesn't exist yet creates an empty one. Bucket
+/// identifiers may only contain ASCII alphanumerics, `-`, `_` and `.`, and
+/// must not start with a `.`; any other identifier fails to open with
+/// [`Error::NoSuchStore`]. Identifiers are case-insensitive, so that they name
+/// the same bucket regardless of whether the host's filesystem is.
+///
+/// Each opened bucket is kept in memory in its entirety, with every change
+/// written through to its file and synced to disk before it becomes visible,
+/// see [`FileStore::sync`]. The directory must not be used by more than one
+/// `FileStore` at a time, including from other processes.
+pub struct FileStore {
+    dir: PathBuf,
+    sync: bool,
+    buckets: Mutex<HashMap<String, Arc<FileBucket>>>,
+}
+
+impl FileStore {
+    /// Creates a store which keeps its buckets in `dir`, creating the
+    /// directory if it doesn't exist yet.
+    pub fn new(dir: impl AsRef<Path>) -> anyhow::Result<FileStore> {
+        let dir = dir.as_ref();
+        fs::create_dir_all(dir)
+            .with_context(|| format!("failed to create directory {}", dir.display()))?;
+        Ok(FileStore {
+            dir: dir.to_path_buf(),
+            sync: true,
+            buckets: Mutex::new(HashMap::new()),
+        })
+    }
+
+    /// Configures whether changes are synced to disk before they become
+    /// visible.
+    ///
+    /// Without syncing, changes are still handed to the operating system
+    /// immediately and survive the host process crashing, but the most recent
+    /// ones may be lost if the machine itself crashes or loses power. Buckets
+    /// which are already open keep the setting they were opened with.
+    ///
+    /// This is enabled by default.
+    pub fn sync(&mut self, enable: bool) -> &mut Self {
+        self.sync = enable;
+        self
+    }
+}
+
+fn is_valid_identifier(identifier: &str) -> bool {
+    !identifier.is_empty()
+        && identifier.len() <= 255
+        && !identifier.starts_with('.')
+        && identifier
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'))
+}
+
+impl Store for FileStore {
+    fn open(&self, identifier: &str) -> Result<Arc<dyn Bucket>, Error> {
+        if !is_valid_identifier(identifier) {
+            return Err(Error::NoSuchStore);
+        }
+        // Case-insensitive filesystems would otherwise back identifiers which
+        // only differ in case with the same file, opening it twice.
+        let identifier = identifier.to_ascii_lowercase();
+        let mut buckets = self.buckets.lock().unwrap();
+        if let Some(bucket) = buckets.get(&identifier) {
+            return Ok(bucket.clone());
+        }
+        let path = self.dir.join(format!("{identifier}.kv"));
+        let bucket = Arc::new(FileBucket {
+            log: Mutex::new(Log::open(path, self.sync).map_err(io_error)?),
+        });
+        buckets.insert(identifier, bucket.clone());
+        Ok(bucket)
+    }
+}
+
+fn io_error(e: io::Error) -> Error {
+    Error::Other(e.to_string())
+}
+
+/// A single change to a bucket.
+#[derive(Clone, Copy)]
+enum Op<'a> {
+    Set(&'a str, &'a [u8]),
+    Delete(&'a str),
+}
+
+impl Op<'_> {
+    fn key(&self) -> &str {
+        match self {
+            Op::Set(key, _) | Op::Delete(key) => key,
+        }
+    }
+}
+
+/// The number of bytes an entry takes up in a compacted log.
+fn entry_size(key: &str, value: &[u8]) -> u64 {
+    (9 + key.len() + value.len()) as u64
+}
+
+fn checksum(payload: &[u8]) -> u64 {
+    payload.iter().fold(0xcbf29ce484222325, |hash, byte| {
+        (hash ^ u64::from(*byte)).wrapping_mul(0x100000001b3)
+    })
+}
+
+/// Encodes `ops` as a frame, appending it to `out`.
+fn encode_frame(ops: &[Op<'_>], out: &mut Vec<u8>) -> io::Result<()> {
+    let start = out.len();
+    out.extend_from_slice(&[0; HEADER_SIZE]);
+    for op in ops {
+        let (tag, key, value) = match op {
+            Op::Set(key, value) => (OP_SET, key, Some(value)),
+            Op::Delete(key) => (OP_DELETE, key, None),
+        };
+        out.push(tag);
+        for bytes in [Some(key.as_bytes()), value.copied()].into_iter().flatten() {
+            let len = u32::try_from(bytes.len())
+                .map_err(|_| io::Error::new(io::ErrorKind::InvalidInput, "entry too large"))?;
+            out.extend_from_slice(&len.to_le_bytes());
+            out.extend_from_slice(bytes);
+        }
+    }
+    let payload = &out[start + HEADER_SIZE..];
+    let len = u32::try_from(payload.len())
+        .map_err(|_| io::Error::new(io::ErrorKind::InvalidInput, "batch too large"))?;
+    let checksum = checksum(payload);
+    out[start..start + 4].copy_from_slice(&len.to_le_bytes());
+    out[start + 4..start + HEADER_SIZE].copy_from_slice(&checksum.to_le_bytes());
+    Ok(())
+}
+
+/// Decodes the frame at the start of `data`, returning its operations and its
+/// size, or `None` if `data` doesn't start with an intact frame.
+fn decode_frame(data: &[u8]) -> Option<(Vec<Op<'_>>, usize)> {
+    let mut reader = Reader(data);
+    let len = usize::try_from(reader.u32()?).ok()?;
+    let checksum = reader.u64()?;
+    let payload = reader.bytes(len)?;
+    if self::checksum(payload) != checksum {
+        return None;
+    }
+    let mut reader = Reader(payload);
+    let mut ops = Vec::new();
+    while !reader.0.is_empty() {
+        let tag = reader.bytes(1)?[0];
+        let key = reader.sized()?;
+        let key = std::str::from_utf8(key).ok()?;
+        ops.push(match tag {
+            OP_SET => Op::Set(key, reader.sized()?),
+            OP_DELETE => Op::Delete(key),
+            _ => return None,
+        });
+    }
+    Some((ops, HEADER_SIZE + len))
+}
+
+struct Reader<'a>(&'a [u8]);
+
+impl<'a> Reader<'a> {
+    fn bytes(&mut self, len: usize) -> Option<&'a [u8]> {
+        if self.0.len() < len {
+            return None;
+        }
+        let (bytes, rest) = self.0.split_at(len);
+        self.0 = rest;
+        Some(bytes)
+    }
+
+    fn u32(&mut self) -> Option<u32> {
+        Some(u32::from_le_bytes(self.bytes(4)?.try_into().unwrap()))
+    }
+
+    fn u64(&mut self) -> Option<u64> {
+        Some(u64::from_le_bytes(self.bytes(8)?.try_into().unwrap()))
+    }
+
+    /// Reads a length-prefixed byte string.
+    fn sized(&mut self) -> Option<&'a [u8]> {
+        let len = usize::try_from(self.u32()?).ok()?;
+        self.bytes(len)
+    }
+}
+
+/// The contents of a bucket along with the file they're persisted in.
+struct Log {
+    path: PathBuf,
+    file: File,
+    entries: BTreeMap<String, Vec<u8>>,
+    /// The size of the log file.
+    len: u64,
+    /// The size `entries` would take up in a compacted log.
+    live: u64,
+    /// Whether commits are synced to disk.
+    sync: bool,
+}
+
+impl Log {
+    fn open(path: PathBuf, sync: bool) -> io::Result<Log> {
+        let existed = path.exists();
+        let mut file = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .open(&path)?;
+        if sync && !existed {
+            sync_parent(&path)?;
+        }
+        let mut data = Vec::new();
+        file.read_to_end(&mut data)?;
+
+        let mut log = Log {
+            path,
+            file,
+            entries: BTreeMap::new(),
+            len: 0,
+            live: 0,
+            sync,
+        };
+        let mut rest = &data[..];
+        while let Some((ops, size)) = decode_frame(rest) {
+            for op in ops {
+                log.apply(op);
+            }
+            rest = &rest[size..];
+            log.len += size as u64;
+        }
+        if !rest.is_empty() {
+            tracing::warn!(
+                "discarding {} bytes of corrupt data at the end of {}",
+                rest.len(),
+                log.path.display()
+            );
+            log.file.set_len(log.len)?;
+        }
+        log.file.seek(SeekFrom::Start(log.len))?;
+        log.maybe_compact();
+        Ok(log)
+    }
+
+    fn apply(&mut self, op: Op<'_>) {
+        let old = match op {
+            Op::Set(key, value) => {
+                self.live += entry_size(key, value);
+                self.entries.insert(key.to_string(), value.to_vec())
+            }
+            Op::Delete(key) => self.entries.remove(key),
+        };
+        if let Some(old) = old {
+            self.live -= entry_size(op.key(), &old);
+        }
+    }
+
+    /// Appends `ops` to the log as a single frame and applies them.
+    fn commit(&mut self, ops: &[Op<'_>]) -> Result<(), Error> {
+        let mut frame = Vec::new();
+        encode_frame(ops, &mut frame).map_err(io_error)?;
+        let result = self.file.write_all(&frame).and_then(|()| {
+            if self.sync {
+                self.file.sync_data()?;
+            }
+            Ok(())
+        });
+        if let Err(e) = result {
+            // Don't leave a partial frame behind for the next commit to
+            // append to.
+            let _ = self.file.set_len(self.len);
+            let _ = self.file.seek(SeekFrom::Start(self.len));
+            return Err(io_error(e));
+        }
+        self.len += frame.len() as u64;
+        for op in ops {
+            self.apply(*op);
+        }
+        self.maybe_compact();
+        Ok(())
+    }
+
+    /// Compacts the log if more than half of it is stale.
+    fn maybe_compact(&mut self) {
+        if self.len < MIN_COMPACT_SIZE || self.len / 2 < self.live {
+            return;
+        }
+        // The log is still intact if compaction fails, so this only costs
+        // some disk space.
+        if let Err(e) = self.compact() {
+            tracing::warn!("failed to compact {}: {e}", self.path.display());
+        }
+    }
+
+    fn compact(&mut self) -> io::Result<()> {
+        let tmp = self.path.with_extension("kv.tmp");
+        let mut writer = BufWriter::new(File::create(&tmp)?);
+        let mut len = 0;
+        let mut frame = Vec::new();
+        let mut ops = Vec::new();
+        let mut size = 0;
+        let mut entries = self.entries.iter().peekable();
+        while let Some((key, value)) = entries.next() {
+            ops.push(Op::Set(key, value));
+            size += entry_size(key, value) as usize;
+            if size >= COMPACT_FRAME_SIZE || entries.peek().is_none() {
+                encode_frame(&ops, &mut frame)?;
+                writer.write_all(&frame)?;
+                len += frame.len() as u64;
+                frame.clear();
+                ops.clear();
+                size = 0;
+            }
+        }
+        let file = writer.into_inner().map_err(|e| e.into_error())?;
+        file.sync_all()?;
+        drop(file);
+        fs::rename(&tmp, &self.path)?;
+        // Make sure the rename itself is durable, not only the new contents.
+        sync_parent(&self.path)?;
+
+        let mut file = OpenOptions::new().read(true).write(true).open(&self.path)?;
+        file.seek(SeekFrom::Start(len))?;
+        self.file = file;
+        self.len = len;
+        Ok(())
+    }
+}
+
+/// Syncs the directory containing `path`, making the creation or renaming of
+/// `path` durable.
+fn sync_parent(path: &Path) -> io::Result<()> {
+    #[cfg(unix)]
+    if let Some(dir) = path.parent() {
+        File::open(dir)?.sync_all()?;
+    }
+    #[cfg(not(unix))]
+    let _ = path;
+    Ok(())
+}
+
+struct FileBucket {
+    log: Mutex<Log>,
+}
+
+impl Bucket for FileBucket {
+    fn get(&self, key: &str) -> Result<Option<Vec<u8>>, Error> {
+        Ok(self.log.lock().unwrap().entries.get(key).cloned())
+    }
+
+    fn set(&self, key: &str, value: Vec<u8>) -> Result<(), Error> {
+        self.log.lock().unwrap().commit(&[Op::Set(key, &value)])
+    }
+
+    fn delete(&self, key: &str) -> Result<(), Error> {
+        let mut log = self.log.lock().unwrap();
+        if !log.entries.contains_key(key) {
+            return Ok(());
+        }
+        log.commit(&[Op::Delete(key)])
+    }
+
+    fn exists(&self, key: &str) -> Result<bool, Error> {
+        Ok(self.log.lock().unwrap().entries.contains_key(key))
+    }
+
+    fn list_keys(&self, cursor: Option<&str>) -> Result<KeyResponse, Error> {
+        Ok(crate::list_keys(&self.log.lock().unwrap().entries, cursor))
+    }
+
+    fn increment(&self, key: &str, delta: i64) -> Result<i64, Error> {
+        let mut log = self.log.lock().unwrap();
+        let value = crate::increment(log.entries.get(key).map(|v| &v[..]), delta)?;
+        log.commit(&[Op::Set(key, value.to_string().as_bytes())])?;
+        Ok(value)
+    }
+
+    fn compare_and_swap(
+        &self,
+        key: &str,
+        current: Option<&[u8]>,
+        new: Vec<u8>,
+    ) -> Result<bool, Error> {
+        let mut log = self.log.lock().unwrap();
+        if log.entries.get(key).map(|v| &v[..]) != current {
+            return Ok(false);
+        }
+        log.commit(&[Op::Set(key, &new)])?;
+        Ok(true)
+    }
+
+    fn get_many(&self, keys: Vec<String>) -> Result<Vec<(String, Option<Vec<u8>>)>, Error> {
+        let log = self.log.lock().unwrap();
+        Ok(keys
+            .into_iter()
+            .map(|key| {
+                let value = log.entries.get(&key).cloned();
+                (key, value)
+            })
+            .collect())
+    }
+
+    fn set_many(&self, key_values: Vec<(String, Vec<u8>)>) -> Result<(), Error> {
+        let ops = key_values
+            .iter()
+            .map(|(key, value)| Op::Set(key, value))
+            .collect::<Vec<_>>();
+        self.log.lock().unwrap().commit(&ops)
+    }
+
+    fn delete_many(&self, keys: Vec<String>) -> Result<(), Error> {
+        let mut log = self.log.lock().unwrap();
+        let ops = keys
+            .iter()
+            .filter(|key| log.entries.contains_key(key.as_str()))
+            .map(|key| Op::Delete(key))
+            .collect::<Vec<_>>();
+        if ops.is_empty() {
+            return Ok(());
+        }
+        log.commit(&ops)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn persists() -> anyhow::Result<()> {
+        let dir = tempfile::tempdir()?;
+        {
+            let store = FileStore::new(dir.path())?;
+            let bucket = store.open("test")?;
+            bucket.set("a", b"1".to_vec())?;
+            bucket.set("b", b"2".to_vec())?;
+            bucket.increment("a", 1)?;
+            bucket.delete("b")?;
+            store.open("other")?.set("c", b"3".to_vec())?;
+        }
+        let store = FileStore::new(dir.path())?;
+        let bucket = store.open("test")?;
+        assert_eq!(bucket.list_keys(None)?.keys, ["a"]);
+        assert_eq!(bucket.get("a")?.as_deref(), Some(&b"2"[..]));
+        assert_eq!(store.open("other")?.get("c")?.as_deref(), Some(&b"3"[..]));
+
+        for identifier in ["", ".kv", "../test", "a/b", "a b"] {
+            assert!(matches!(store.open(identifier), Err(Error::NoSuchStore)));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn identifiers_ignore_case() -> anyhow::Result<()> {
+        let dir = tempfile::tempdir()?;
+        {
+            let store = FileStore::new(dir.path())?;
+            store.open("Test")?.set("a", b"1".to_vec())?;
+            assert_eq!(store.open("TEST")?.get("a")?.as_deref(), Some(&b"1"[..]));
+        }
+        assert!(dir.path().join("test.kv").exists());
+        let store = FileStore::new(dir.path())?;
+        assert_eq!(store.open("test")?.get("a")?.as_deref(), Some(&b"1"[..]));
+        Ok(())
+    }
+
+    #[test]
+    fn discards_torn_frames() -> anyhow::Result<()> {
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("test.kv");
+        let mut data = Vec::new();
+        encode_frame(&[Op::Set("a", b"1")], &mut data)?;
+        let intact = data.len();
+        encode_frame(&[Op::Set("b", b"2"), Op::Delete("a")], &mut data)?;
+        data.truncate(data.len() - 1);
+        fs::write(&path, &data)?;
+
+        let bucket = FileStore::new(dir.path())?.open("test")?;
+        assert_eq!(bucket.list_keys(None)?.keys, ["a"]);
+        assert_eq!(fs::metadata(&path)?.len(), intact as u64);
+
+        // New frames are appended right after the last intact one.
+        bucket.set("c", b"3".to_vec())?;
+        drop(bucket);
+        let bucket = FileStore::new(dir.path())?.open("test")?;
+        assert_eq!(bucket.list_keys(None)?.keys, ["a", "c"]);
+        Ok(())
+    }
+
+    #[test]
+    fn compacts() -> anyhow::Result<()> {
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("test.kv");
+        let mut store = FileStore::new(dir.path())?;
+        let bucket = store.sync(false).open("test")?;
+        bucket.set("keep", b"yes".to_vec())?;
+        for i in 0..MIN_COMPACT_SIZE {
+            bucket.set("counter", i.to_string().into_bytes())?;
+        }
+        assert!(fs::metadata(&path)?.len() < MIN_COMPACT_SIZE);
+        assert!(!dir.path().join("test.kv.tmp").exists());
+
+        drop(bucket);
+        drop(store);
+        let bucket = FileStore::new(dir.path())?.open("test")?;
+        assert_eq!(bucket.get("keep")?.as_deref(), Some(&b"yes"[..]));
+        assert_eq!(
+            bucket.get("counter")?,
+            Some((MIN_COMPACT_SIZE - 1).to_string().into_bytes())
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,174 @@
+//! Implementations of the generated `wasi:keyvalue` host traits in terms of
+//! the [`Bucket`] of each resource.
+
+use crate::bindings::keyvalue::{atomics, batch, store};
+use crate::{Bucket, Error, HostBucket, HostCas, KeyResponse, WasiKeyValueView};
+use std::sync::Arc;
+use wasmtime::component::Resource;
+
+/// Returns the bucket behind the `bucket` resource.
+fn bucket(
+    view: &mut dyn WasiKeyValueView,
+    bucket: &Resource<HostBucket>,
+) -> wasmtime::Result<Arc<dyn Bucket>> {
+    Ok(view.table().get(bucket)?.bucket.clone())
+}
+
+impl<T: WasiKeyValueView> store::Host for T {
+    fn open(
+        &mut self,
+        identifier: String,
+    ) -> wasmtime::Result<Result<Resource<HostBucket>, Error>> {
+        let bucket = match self.ctx().store.open(&identifier) {
+            Ok(bucket) => bucket,
+            Err(e) => return Ok(Err(e)),
+        };
+        Ok(Ok(self.table().push(HostBucket { bucket })?))
+    }
+}
+
+impl<T: WasiKeyValueView> store::HostBucket for T {
+    fn get(
+        &mut self,
+        self_: Resource<HostBucket>,
+        key: String,
+    ) -> wasmtime::Result<Result<Option<Vec<u8>>, Error>> {
+        Ok(bucket(self, &self_)?.get(&key))
+    }
+
+    fn set(
+        &mut self,
+        self_: Resource<HostBucket>,
+        key: String,
+        value: Vec<u8>,
+    ) -> wasmtime::Result<Result<(), Error>> {
+        Ok(bucket(self, &self_)?.set(&key, value))
+    }
+
+    fn delete(
+        &mut self,
+        self_: Resource<HostBucket>,
+        key: String,
+    ) -> wasmtime::Result<Result<(), Error>> {
+        Ok(bucket(self, &self_)?.delete(&key))
+    }
+
+    fn exists(
+        &mut self,
+        self_: Resource<HostBucket>,
+        key: String,
+    ) -> wasmtime::Result<Result<bool, Error>> {
+        Ok(bucket(self, &self_)?.exists(&key))
+    }
+
+    fn list_keys(
+        &mut self,
+        self_: Resource<HostBucket>,
+        cursor: Option<String>,
+    ) -> wasmtime::Result<Result<KeyResponse, Error>> {
+        Ok(bucket(self, &self_)?.list_keys(cursor.as_deref()))
+    }
+
+    fn drop(&mut self, rep: Resource<HostBucket>) -> wasmtime::Result<()> {
+        self.table().delete(rep)?;
+        Ok(())
+    }
+}
+
+impl<T: WasiKeyValueView> atomics::Host for T {
+    fn increment(
+        &mut self,
+        bucket: Resource<HostBucket>,
+        key: String,
+        delta: i64,
+    ) -> wasmtime::Result<Result<i64, Error>> {
+        Ok(self::bucket(self, &bucket)?.increment(&key, delta))
+    }
+
+    fn swap(
+        &mut self,
+        cas: Resource<HostCas>,
+        value: Vec<u8>,
+    ) -> wasmtime::Result<Result<(), atomics::CasError>> {
+        let HostCas {
+            bucket,
+            key,
+            current,
+        } = self.table().delete(cas)?;
+        match bucket.compare_and_swap(&key, current.as_deref(), value) {
+            Ok(true) => Ok(Ok(())),
+            Ok(false) => {
+                // Hand back a handle with the latest value so the guest can
+                // retry.
+                let current = match bucket.get(&key) {
+                    Ok(current) => current,
+                    Err(e) => return Ok(Err(atomics::CasError::StoreError(e))),
+                };
+                let cas = self.table().push(HostCas {
+                    bucket,
+                    key,
+                    current,
+                })?;
+                Ok(Err(atomics::CasError::CasFailed(cas)))
+            }
+            Err(e) => Ok(Err(atomics::CasError::StoreError(e))),
+        }
+    }
+}
+
+impl<T: WasiKeyValueView> atomics::HostCas for T {
+    fn new(
+        &mut self,
+        bucket: Resource<HostBucket>,
+        key: String,
+    ) -> wasmtime::Result<Result<Resource<HostCas>, Error>> {
+        let bucket = self::bucket(self, &bucket)?;
+        let current = match bucket.get(&key) {
+            Ok(current) => current,
+            Err(e) => return Ok(Err(e)),
+        };
+        Ok(Ok(self.table().push(HostCas {
+            bucket,
+            key,
+            current,
+        })?))
+    }
+
+    fn current(
+        &mut self,
+        self_: Resource<HostCas>,
+    ) -> wasmtime::Result<Result<Option<Vec<u8>>, Error>> {
+        Ok(Ok(self.table().get(&self_)?.current.clone()))
+    }
+
+    fn drop(&mut self, rep: Resource<HostCas>) -> wasmtime::Result<()> {
+        self.table().delete(rep)?;
+        Ok(())
+    }
+}
+
+impl<T: WasiKeyValueView> batch::Host for T {
+    fn get_many(
+        &mut self,
+        bucket: Resource<HostBucket>,
+        keys: Vec<String>,
+    ) -> wasmtime::Result<Result<Vec<(String, Option<Vec<u8>>)>, Error>> {
+        Ok(self::bucket(self, &bucket)?.get_many(keys))
+    }
+
+    fn set_many(
+        &mut self,
+        bucket: Resource<HostBucket>,
+        key_values: Vec<(String, Vec<u8>)>,
+    ) -> wasmtime::Result<Result<(), Error>> {
+        Ok(self::bucket(self, &bucket)?.set_many(key_values))
+    }
+
+    fn delete_many(
+        &mut self,
+        bucket: Resource<HostBucket>,
+        keys: Vec<String>,
+    ) -> wasmtime::Result<Result<(), Error>> {
+        Ok(self::bucket(self, &bucket)?.delete_many(keys))
+    }
+}
@@ -0,0 +1,407 @@
+//! Wasmtime's implementation of the [`wasi:keyvalue`] proposal.
+//!
+//! This crate provides the host side of the `wasi:keyvalue/imports` world:
+//! the `store`, `atomics` and `batch` interfaces. Guests open buckets by
+//! name, and the host decides what backs them through the [`Store`] trait.
+//! Two stores are provided out of the box:
+//!
+//! * [`MemoryStore`] keeps buckets in memory for as long as the store is
+//!   alive, which is handy for tests or for ephemeral state shared between
+//!   instances.
+//! * [`FileStore`] persists each bucket in a log-structured file within a
+//!   directory on the host.
+//!
+//! Embedders give a [`WasiKeyValueCtx`] to their store data, implement
+//! [`WasiKeyValueView`] for it, and then call [`add_to_linker`]:
+//!
+//! ```
+//! use wasmtime::component::{Linker, ResourceTable};
+//! use wasmtime::{Config, Engine};
+//! use wasmtime_wasi_keyvalue::{MemoryStore, WasiKeyValueCtx, WasiKeyValueView};
+//!
+//! struct MyState {
+//!     keyvalue: WasiKeyValueCtx,
+//!     table: ResourceTable,
+//! }
+//!
+//! impl WasiKeyValueView for MyState {
+//!     fn ctx(&mut self) -> &mut WasiKeyValueCtx {
+//!         &mut self.keyvalue
+//!     }
+//!
+//!     fn table(&mut self) -> &mut ResourceTable {
+//!         &mut self.table
+//!     }
+//! }
+//!
+//! # fn main() -> wasmtime::Result<()> {
+//! let mut config = Config::new();
+//! config.wasm_component_model(true);
+//! let engine = Engine::new(&config)?;
+//! let mut linker = Linker::<MyState>::new(&engine);
+//! wasmtime_wasi_keyvalue::add_to_linker(&mut linker)?;
+//!
+//! let state = MyState {
+//!     keyvalue: WasiKeyValueCtx::new(MemoryStore::new()),
+//!     table: ResourceTable::new(),
+//! };
+//! # let _ = state;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! [`wasi:keyvalue`]: https://github.com/WebAssembly/wasi-keyvalue
+
+use std::ops::Bound;
+use std::sync::Arc;
+use wasmtime::component::{Linker, ResourceTable};
+
+mod file;
+mod host;
+mod memory;
+
+pub use file::FileStore;
+pub use memory::MemoryStore;
+
+pub mod bindings {
+    wasmtime::component::bindgen!({
+        path: "wit",
+        world: "wasi:keyvalue/imports",
+        tracing: true,
+        async: false,
+        with: {
+            "wasi:keyvalue/store/bucket": super::HostBucket,
+            "wasi:keyvalue/atomics/cas": super::HostCas,
+        }
+    });
+
+    pub use wasi::keyvalue;
+}
+
+pub use bindings::keyvalue::store::{Error, KeyResponse};
+
+/// The maximum number of keys returned by a single `list-keys` call of the
+/// built-in stores.
+const LIST_KEYS_PAGE_SIZE: usize = 1000;
+
+/// A provider of the buckets which guests can open.
+pub trait Store: Send + Sync + 'static {
+    /// Opens the bucket named `identifier`.
+    ///
+    /// Returns [`Error::NoSuchStore`] if the store doesn't have such a bucket.
+    fn open(&self, identifier: &str) -> Result<Arc<dyn Bucket>, Error>;
+}
+
+/// A collection of key-value pairs, as opened through a [`Store`].
+///
+/// Every method takes `&self` since several guest handles, possibly in
+/// different stores, may refer to the same bucket at once. The batch
+/// operations default to performing one operation per key, so implementations
+/// which can apply them more efficiently (or atomically) should override them.
+pub trait Bucket: Send + Sync {
+    /// Returns the value of `key`, or `None` if it isn't present.
+    fn get(&self, key: &str) -> Result<Option<Vec<u8>>, Error>;
+
+    /// Sets the value of `key`, replacing any previous value.
+    fn set(&self, key: &str, value: Vec<u8>) -> Result<(), Error>;
+
+    /// Removes `key`, doing nothing if it isn't present.
+    fn delete(&self, key: &str) -> Result<(), Error>;
+
+    /// Returns whether `key` is present.
+    fn exists(&self, key: &str) -> Result<bool, Error> {
+        Ok(self.get(key)?.is_some())
+    }
+
+    /// Returns a page of the keys in this bucket.
+    ///
+    /// `cursor` is `None` for the first page, and otherwise the `cursor` of
+    /// the previous page's [`KeyResponse`].
+    fn list_keys(&self, cursor: Option<&str>) -> Result<KeyResponse, Error>;
+
+    /// Atomically adds `delta` to the integer value of `key` and returns the
+    /// result.
+    ///
+    /// Integers are stored as their decimal representation, and a missing key
+    /// counts as zero.
+    fn increment(&self, key: &str, delta: i64) -> Result<i64, Error>;
+
+    /// Atomically sets the value of `key` to `new`, but only if its value is
+    /// still `current` (where `None` means the key isn't present).
+    ///
+    /// Returns whether the value was replaced.
+    fn compare_and_swap(
+        &self,
+        key: &str,
+        current: Option<&[u8]>,
+        new: Vec<u8>,
+    ) -> Result<bool, Error>;
+
+    /// Returns the values of all of `keys`.
+    fn get_many(&self, keys: Vec<String>) -> Result<Vec<(String, Option<Vec<u8>>)>, Error> {
+        keys.into_iter()
+            .map(|key| {
+                let value = self.get(&key)?;
+                Ok((key, value))
+            })
+            .collect()
+    }
+
+    /// Sets the values of all of `key_values`.
+    fn set_many(&self, key_values: Vec<(String, Vec<u8>)>) -> Result<(), Error> {
+        for (key, value) in key_values {
+            self.set(&key, value)?;
+        }
+        Ok(())
+    }
+
+    /// Removes all of `keys`.
+    fn delete_many(&self, keys: Vec<String>) -> Result<(), Error> {
+        for key in keys {
+            self.delete(&key)?;
+        }
+        Ok(())
+    }
+}
+
+/// Capture the state necessary for use in the wasi-keyvalue API
+/// implementation.
+///
+/// The context can be cloned cheaply, and all clones share the same
+/// [`Store`].
+#[derive(Clone)]
+pub struct WasiKeyValueCtx {
+    store: Arc<dyn Store>,
+}
+
+impl WasiKeyValueCtx {
+    /// Creates a context whose guests open buckets from `store`.
+    pub fn new(store: impl Store) -> WasiKeyValueCtx {
+        WasiKeyValueCtx {
+            store: Arc::new(store),
+        }
+    }
+}
+
+pub trait WasiKeyValueView: Send {
+    fn ctx(&mut self) -> &mut WasiKeyValueCtx;
+    fn table(&mut self) -> &mut ResourceTable;
+}
+
+/// The host representation of a `wasi:keyvalue/store.bucket` resource.
+pub struct HostBucket {
+    bucket: Arc<dyn Bucket>,
+}
+
+/// The host representation of a `wasi:keyvalue/atomics.cas` resource.
+///
+/// This records the value of its key when it was created, and swapping it
+/// only succeeds if the key still has that value.
+pub struct HostCas {
+    bucket: Arc<dyn Bucket>,
+    key: String,
+    current: Option<Vec<u8>>,
+}
+
+/// Adds all of the `wasi:keyvalue` interfaces to `linker`.
+pub fn add_to_linker<T: WasiKeyValueView>(linker: &mut Linker<T>) -> anyhow::Result<()> {
+    bindings::keyvalue::store::add_to_linker(linker, |t| t)?;
+    bindings::keyvalue::atomics::add_to_linker(linker, |t| t)?;
+    bindings::keyvalue::batch::add_to_linker(linker, |t| t)?;
+    Ok(())
+}
+
+/// Returns the page of keys following `cursor` from a sorted map.
+fn list_keys<V>(
+    entries: &std::collections::BTreeMap<String, V>,
+    cursor: Option<&str>,
+) -> KeyResponse {
+    let start = match cursor {
+        Some(cursor) => Bound::Excluded(cursor),
+        None => Bound::Unbounded,
+    };
+    let mut keys = entries
+        .range::<str, _>((start, Bound::Unbounded))
+        .map(|(key, _)| key.clone());
+    let page = keys.by_ref().take(LIST_KEYS_PAGE_SIZE).collect::<Vec<_>>();
+    let cursor = match keys.next() {
+        Some(_) => page.last().cloned(),
+        None => None,
+    };
+    KeyResponse { keys: page, cursor }
+}
+
+/// Adds `delta` to an integer value as stored by [`Bucket::increment`].
+fn increment(value: Option<&[u8]>, delta: i64) -> Result<i64, Error> {
+    let value = match value {
+        Some(value) => std::str::from_utf8(value)
+            .ok()
+            .and_then(|value| value.parse::<i64>().ok())
+            .ok_or_else(|| Error::Other("value is not an integer".to_string()))?,
+        None => 0,
+    };
+    value
+        .checked_add(delta)
+        .ok_or_else(|| Error::Other("integer overflow".to_string()))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use bindings::keyvalue::store::{Host as _, HostBucket as _};
+    use bindings::keyvalue::{atomics, batch, store};
+    use wasmtime::component::Resource;
+
+    fn exercise(store: &dyn Store) -> Result<(), Error> {
+        let bucket = store.open("test")?;
+        assert_eq!(bucket.get("a")?, None);
+        bucket.set("a", b"1".to_vec())?;
+        assert!(bucket.exists("a")?);
+        assert_eq!(bucket.get("a")?.as_deref(), Some(&b"1"[..]));
+        assert_eq!(store.open("test")?.get("a")?.as_deref(), Some(&b"1"[..]));
+        assert_eq!(store.open("other")?.get("a")?, None);
+
+        assert_eq!(bucket.increment("a", 41)?, 42);
+        assert_eq!(bucket.increment("b", -1)?, -1);
+        assert_eq!(bucket.get("a")?.as_deref(), Some(&b"42"[..]));
+        bucket.set("c", b"x".to_vec())?;
+        assert!(matches!(bucket.increment("c", 1), Err(Error::Other(_))));
+        assert!(matches!(
+            bucket.increment("a", i64::MAX),
+            Err(Error::Other(_))
+        ));
+
+        assert!(!bucket.compare_and_swap("c", Some(b"y"), b"z".to_vec())?);
+        assert!(bucket.compare_and_swap("c", Some(b"x"), b"z".to_vec())?);
+        assert!(!bucket.compare_and_swap("d", Some(b"z"), b"z".to_vec())?);
+        assert!(bucket.compare_and_swap("d", None, b"w".to_vec())?);
+
+        bucket.set_many(vec![
+            ("e".to_string(), b"5".to_vec()),
+            ("f".to_string(), b"6".to_vec()),
+        ])?;
+        bucket.delete_many(vec!["a".to_string(), "missing".to_string()])?;
+        bucket.delete("b")?;
+        bucket.delete("b")?;
+        assert_eq!(
+            bucket.get_many(vec!["a".to_string(), "f".to_string()])?,
+            [
+                ("a".to_string(), None),
+                ("f".to_string(), Some(b"6".to_vec()))
+            ]
+        );
+
+        let response = bucket.list_keys(None)?;
+        assert_eq!(response.keys, ["c", "d", "e", "f"]);
+        assert_eq!(response.cursor, None);
+        Ok(())
+    }
+
+    #[test]
+    fn memory_store() -> Result<(), Error> {
+        exercise(&MemoryStore::new())
+    }
+
+    #[test]
+    fn file_store() -> Result<(), Error> {
+        let dir = tempfile::tempdir().unwrap();
+        exercise(&FileStore::new(dir.path()).unwrap())
+    }
+
+    #[test]
+    fn list_keys_pages() -> Result<(), Error> {
+        let bucket = MemoryStore::new().open("test")?;
+        let count = LIST_KEYS_PAGE_SIZE * 2 + 1;
+        bucket.set_many(
+            (0..count)
+                .map(|i| (format!("{i:05}"), Vec::new()))
+                .collect(),
+        )?;
+        let mut keys = Vec::new();
+        let mut cursor = None;
+        loop {
+            let response = bucket.list_keys(cursor.as_deref())?;
+            assert!(response.keys.len() <= LIST_KEYS_PAGE_SIZE);
+            keys.extend(response.keys);
+            cursor = response.cursor;
+            if cursor.is_none() {
+                break;
+            }
+        }
+        assert_eq!(keys.len(), count);
+        assert!(keys.windows(2).all(|w| w[0] < w[1]));
+        Ok(())
+    }
+
+    struct Ctx {
+        keyvalue: WasiKeyValueCtx,
+        table: ResourceTable,
+    }
+
+    impl WasiKeyValueView for Ctx {
+        fn ctx(&mut self) -> &mut WasiKeyValueCtx {
+            &mut self.keyvalue
+        }
+
+        fn table(&mut self) -> &mut ResourceTable {
+            &mut self.table
+        }
+    }
+
+    #[test]
+    fn host() -> wasmtime::Result<()> {
+        let mut ctx = Ctx {
+            keyvalue: WasiKeyValueCtx::new(MemoryStore::new()),
+            table: ResourceTable::new(),
+        };
+        let bucket = ctx.open("test".to_string())?.unwrap();
+        ctx.set(
+            Resource::new_borrow(bucket.rep()),
+            "a".to_string(),
+            b"1".to_vec(),
+        )?
+        .unwrap();
+
+        // A CAS fails if the value changes after it was created, and the
+        // returned handle sees the new value.
+        let cas = atomics::HostCas::new(
+            &mut ctx,
+            Resource::new_borrow(bucket.rep()),
+            "a".to_string(),
+        )?
+        .unwrap();
+        let n = atomics::Host::increment(
+            &mut ctx,
+            Resource::new_borrow(bucket.rep()),
+            "a".to_string(),
+            1,
+        )?
+        .unwrap();
+        assert_eq!(n, 2);
+        let cas = match atomics::Host::swap(&mut ctx, cas, b"10".to_vec())? {
+            Err(atomics::CasError::CasFailed(cas)) => cas,
+            _ => panic!("expected the swap to fail"),
+        };
+        assert_eq!(
+            atomics::HostCas::current(&mut ctx, Resource::new_borrow(cas.rep()))?.unwrap(),
+            Some(b"2".to_vec())
+        );
+        atomics::Host::swap(&mut ctx, cas, b"10".to_vec())?.unwrap();
+
+        let values = batch::Host::get_many(
+            &mut ctx,
+            Resource::new_borrow(bucket.rep()),
+            vec!["a".to_string()],
+        )?
+        .unwrap();
+        assert_eq!(values, [("a".to_string(), Some(b"10".to_vec()))]);
+
+        let rep = bucket.rep();
+        store::HostBucket::drop(&mut ctx, bucket)?;
+        assert!(ctx
+            .table
+            .get(&Resource::<HostBucket>::new_borrow(rep))
+            .is_err());
+        Ok(())
+    }
+}
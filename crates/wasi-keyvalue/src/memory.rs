@@ -0,0 +1,103 @@
+//! A [`Store`] which keeps buckets in memory.
+
+use crate::{Bucket, Error, KeyResponse, Store};
+use std::collections::{BTreeMap, HashMap};
+use std::sync::{Arc, Mutex};
+
+/// A [`Store`] whose buckets live in memory.
+///
+/// Opening a bucket which doesn't exist yet creates an empty one, and buckets
+/// are kept until the store itself is dropped. Clones of a
+/// [`WasiKeyValueCtx`](crate::WasiKeyValueCtx) share the same buckets, so this
+/// can also be used to share state between instances.
+#[derive(Default)]
+pub struct MemoryStore {
+    buckets: Mutex<HashMap<String, Arc<MemoryBucket>>>,
+}
+
+impl MemoryStore {
+    pub fn new() -> MemoryStore {
+        MemoryStore::default()
+    }
+}
+
+impl Store for MemoryStore {
+    fn open(&self, identifier: &str) -> Result<Arc<dyn Bucket>, Error> {
+        let mut buckets = self.buckets.lock().unwrap();
+        Ok(buckets.entry(identifier.to_string()).or_default().clone())
+    }
+}
+
+#[derive(Default)]
+struct MemoryBucket {
+    entries: Mutex<BTreeMap<String, Vec<u8>>>,
+}
+
+impl Bucket for MemoryBucket {
+    fn get(&self, key: &str) -> Result<Option<Vec<u8>>, Error> {
+        Ok(self.entries.lock().unwrap().get(key).cloned())
+    }
+
+    fn set(&self, key: &str, value: Vec<u8>) -> Result<(), Error> {
+        self.entries.lock().unwrap().insert(key.to_string(), value);
+        Ok(())
+    }
+
+    fn delete(&self, key: &str) -> Result<(), Error> {
+        self.entries.lock().unwrap().remove(key);
+        Ok(())
+    }
+
+    fn exists(&self, key: &str) -> Result<bool, Error> {
+        Ok(self.entries.lock().unwrap().contains_key(key))
+    }
+
+    fn list_keys(&self, cursor: Option<&str>) -> Result<KeyResponse, Error> {
+        Ok(crate::list_keys(&self.entries.lock().unwrap(), cursor))
+    }
+
+    fn increment(&self, key: &str, delta: i64) -> Result<i64, Error> {
+        let mut entries = self.entries.lock().unwrap();
+        let value = crate::increment(entries.get(key).map(|v| &v[..]), delta)?;
+        entries.insert(key.to_string(), value.to_string().into_bytes());
+        Ok(value)
+    }
+
+    fn compare_and_swap(
+        &self,
+        key: &str,
+        current: Option<&[u8]>,
+        new: Vec<u8>,
+    ) -> Result<bool, Error> {
+        let mut entries = self.entries.lock().unwrap();
+        if entries.get(key).map(|v| &v[..]) != current {
+            return Ok(false);
+        }
+        entries.insert(key.to_string(), new);
+        Ok(true)
+    }
+
+    fn get_many(&self, keys: Vec<String>) -> Result<Vec<(String, Option<Vec<u8>>)>, Error> {
+        let entries = self.entries.lock().unwrap();
+        Ok(keys
+            .into_iter()
+            .map(|key| {
+                let value = entries.get(&key).cloned();
+                (key, value)
+            })
+            .collect())
+    }
+
+    fn set_many(&self, key_values: Vec<(String, Vec<u8>)>) -> Result<(), Error> {
+        self.entries.lock().unwrap().extend(key_values);
+        Ok(())
+    }
+
+    fn delete_many(&self, keys: Vec<String>) -> Result<(), Error> {
+        let mut entries = self.entries.lock().unwrap();
+        for key in keys {
+            entries.remove(&key);
+        }
+        Ok(())
+    }
+}
@@ -2,6 +2,8 @@
 //! this crate. The `Box<dyn ...>` types returned by these interfaces allow
 //! implementations to maintain backend-specific state between calls.
 
+#[cfg(feature = "onnx")]
+pub mod onnx;
 pub mod openvino;
 
 use self::openvino::OpenvinoBackend;
@@ -13,7 +15,11 @@ use wiggle::GuestError;
 
 /// Return a list of all available backend frameworks.
 pub fn list() -> Vec<crate::Backend> {
-    vec![Backend::from(OpenvinoBackend::default())]
+    vec![
+        Backend::from(OpenvinoBackend::default()),
+        #[cfg(feature = "onnx")]
+        Backend::from(onnx::OnnxBackend::default()),
+    ]
 }
 
 /// A [Backend] contains the necessary state to load [Graph]s.
@@ -56,6 +62,8 @@ pub enum BackendError {
     BackendAccess(#[from] anyhow::Error),
     #[error("Failed while accessing guest module")]
     GuestAccess(#[from] GuestError),
+    #[error("Invalid argument: {0}")]
+    InvalidArgument(String),
     #[error("The backend expects {0} buffers, passed {1}")]
     InvalidNumberOfBuilders(usize, usize),
     #[error("Not enough memory to copy tensor data of size: {0}")]
@@ -0,0 +1,266 @@
+//! Implements a `wasi-nn` [`BackendInner`] for ONNX models using `tract`, a
+//! pure-Rust inference engine; no system libraries are needed.
+
+use super::{BackendError, BackendExecutionContext, BackendFromDir, BackendGraph, BackendInner};
+use crate::wit::types::{ExecutionTarget, GraphEncoding, Tensor, TensorType};
+use crate::{ExecutionContext, Graph};
+use anyhow::anyhow;
+use std::path::Path;
+use std::sync::Arc;
+use tract_onnx::prelude::{
+    DatumType, Framework, InferenceFact, InferenceModel, InferenceModelExt, IntoTValue, IntoTensor,
+    TypedModel, TypedRunnableModel,
+};
+
+#[derive(Default)]
+pub struct OnnxBackend;
+
+impl BackendInner for OnnxBackend {
+    fn encoding(&self) -> GraphEncoding {
+        GraphEncoding::Onnx
+    }
+
+    fn load(&mut self, builders: &[&[u8]], target: ExecutionTarget) -> Result<Graph, BackendError> {
+        if builders.len() != 1 {
+            return Err(BackendError::InvalidNumberOfBuilders(1, builders.len()));
+        }
+        if target != ExecutionTarget::Cpu {
+            return Err(
+                anyhow!("the ONNX backend only supports CPU execution, not {target:?}").into(),
+            );
+        }
+
+        // Only parse the model here: ONNX models commonly leave some input
+        // dimensions symbolic (e.g., the batch size) so the model is optimized
+        // once the actual input shapes are known (see `compute`).
+        let model = tract_onnx::onnx().model_for_read(&mut &builders[0][..])?;
+        let box_: Box<dyn BackendGraph> = Box::new(OnnxGraph(Arc::new(model)));
+        Ok(box_.into())
+    }
+
+    fn as_dir_loadable(&mut self) -> Option<&mut dyn BackendFromDir> {
+        Some(self)
+    }
+}
+
+impl BackendFromDir for OnnxBackend {
+    fn load_from_dir(
+        &mut self,
+        path: &Path,
+        target: ExecutionTarget,
+    ) -> Result<Graph, BackendError> {
+        let model = std::fs::read(path.join("model.onnx")).map_err(anyhow::Error::from)?;
+        self.load(&[&model], target)
+    }
+}
+
+struct OnnxGraph(Arc<InferenceModel>);
+
+impl BackendGraph for OnnxGraph {
+    fn init_execution_context(&self) -> Result<ExecutionContext, BackendError> {
+        let num_inputs = self.0.input_outlets()?.len();
+        let box_: Box<dyn BackendExecutionContext> = Box::new(OnnxExecutionContext {
+            model: self.0.clone(),
+            plan: None,
+            inputs: vec![None; num_inputs],
+            outputs: Vec::new(),
+        });
+        Ok(box_.into())
+    }
+}
+
+struct OnnxExecutionContext {
+    model: Arc<InferenceModel>,
+    /// The optimized model, along with the input shapes it was specialized
+    /// for; it is only rebuilt when the shapes of the inputs change.
+    plan: Option<(Vec<InferenceFact>, TypedRunnableModel<TypedModel>)>,
+    inputs: Vec<Option<tract_onnx::prelude::Tensor>>,
+    outputs: Vec<tract_onnx::prelude::Tensor>,
+}
+
+impl BackendExecutionContext for OnnxExecutionContext {
+    fn set_input(&mut self, index: u32, tensor: &Tensor) -> Result<(), BackendError> {
+        let num_inputs = self.inputs.len();
+        let slot = self
+            .inputs
+            .get_mut(index as usize)
+            .ok_or_else(|| anyhow!("invalid input index {index}; the model has {num_inputs}"))?;
+        *slot = Some(to_tract_tensor(tensor)?);
+        Ok(())
+    }
+
+    fn compute(&mut self) -> Result<(), BackendError> {
+        let inputs = self
+            .inputs
+            .iter()
+            .enumerate()
+            .map(|(i, t)| {
+                t.clone()
+                    .ok_or_else(|| anyhow!("input {i} has not been set"))
+            })
+            .collect::<anyhow::Result<Vec<_>>>()?;
+        let facts = inputs
+            .iter()
+            .map(InferenceFact::dt_shape_from_tensor)
+            .collect::<Vec<_>>();
+
+        let plan = match &self.plan {
+            Some((plan_facts, plan)) if *plan_facts == facts => plan,
+            _ => {
+                let mut model = (*self.model).clone();
+                for (i, fact) in facts.iter().enumerate() {
+                    model = model.with_input_fact(i, fact.clone())?;
+                }
+                let plan = model.into_optimized()?.into_runnable()?;
+                &self.plan.insert((facts, plan)).1
+            }
+        };
+        self.outputs = plan
+            .run(inputs.into_iter().map(|t| t.into_tvalue()).collect())?
+            .into_iter()
+            .map(|t| t.into_tensor())
+            .collect();
+        Ok(())
+    }
+
+    fn get_output(&mut self, index: u32, destination: &mut [u8]) -> Result<u32, BackendError> {
+        let output = self
+            .outputs
+            .get(index as usize)
+            .ok_or_else(|| anyhow!("no output at index {index}; has the graph been computed?"))?;
+        // Only tensors of plain numeric types can be handed out as bytes;
+        // others (e.g., strings) hold pointers to their actual data.
+        match output.datum_type() {
+            DatumType::U8
+            | DatumType::U16
+            | DatumType::U32
+            | DatumType::U64
+            | DatumType::I8
+            | DatumType::I16
+            | DatumType::I32
+            | DatumType::I64
+            | DatumType::F16
+            | DatumType::F32
+            | DatumType::F64 => {}
+            ty => return Err(anyhow!("output {index} has a non-numeric type: {ty:?}").into()),
+        }
+        // SAFETY: the tensor holds plain numeric data (checked above), so its
+        // bytes are fully initialized.
+        let bytes = unsafe { output.as_bytes() };
+        if bytes.len() > destination.len() {
+            return Err(BackendError::NotEnoughMemory(bytes.len()));
+        }
+
+        // Copy the tensor data into the destination buffer.
+        destination[..bytes.len()].copy_from_slice(bytes);
+        Ok(bytes.len() as u32)
+    }
+}
+
+/// Convert a wasi-nn tensor into a `tract` tensor, checking that its data
+/// matches its type and dimensions.
+fn to_tract_tensor(tensor: &Tensor) -> Result<tract_onnx::prelude::Tensor, BackendError> {
+    let datum_type = match tensor.tensor_type {
+        TensorType::Fp16 => DatumType::F16,
+        TensorType::Fp32 => DatumType::F32,
+        TensorType::Fp64 => DatumType::F64,
+        TensorType::U8 => DatumType::U8,
+        TensorType::I32 => DatumType::I32,
+        TensorType::I64 => DatumType::I64,
+        TensorType::Bf16 => {
+            return Err(anyhow!("bf16 tensors are not supported by the ONNX backend").into())
+        }
+    };
+    let shape = tensor
+        .dimensions
+        .iter()
+        .map(|&d| d as usize)
+        .collect::<Vec<_>>();
+    let expected = shape
+        .iter()
+        .try_fold(datum_type.size_of(), |size, &d| size.checked_mul(d))
+        .ok_or_else(|| {
+            BackendError::InvalidArgument(format!(
+                "tensor dimensions {:?} are too large",
+                tensor.dimensions
+            ))
+        })?;
+    if tensor.data.len() != expected {
+        return Err(BackendError::InvalidArgument(format!(
+            "tensor data is {} bytes but its type and dimensions require {expected}",
+            tensor.data.len()
+        )));
+    }
+    // SAFETY: the length of the data was checked above and all of the types
+    // above are valid for any bit pattern.
+    let tensor =
+        unsafe { tract_onnx::prelude::Tensor::from_raw_dt(datum_type, &shape, &tensor.data) }?;
+    Ok(tensor)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn linear_model() -> anyhow::Result<()> {
+        let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/linear");
+        let graph = OnnxBackend.load_from_dir(&dir, ExecutionTarget::Cpu)?;
+        let mut context = graph.init_execution_context()?;
+
+        let input = [1.0f32, 2.0, 3.0, 4.0];
+        let tensor = Tensor {
+            dimensions: vec![1, 4],
+            tensor_type: TensorType::Fp32,
+            data: input.iter().flat_map(|f| f.to_le_bytes()).collect(),
+        };
+        context.set_input(0, &tensor)?;
+        context.compute()?;
+
+        let mut output = [0u8; 8];
+        assert_eq!(context.get_output(0, &mut output)?, 8);
+        let output = output
+            .chunks(4)
+            .map(|c| f32::from_le_bytes(c.try_into().unwrap()))
+            .collect::<Vec<_>>();
+        assert_eq!(output, [4.5, 5.5]);
+
+        // Too small of an output buffer is an error.
+        assert!(matches!(
+            context.get_output(0, &mut [0u8; 4]),
+            Err(BackendError::NotEnoughMemory(8))
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_input() -> anyhow::Result<()> {
+        let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/linear");
+        let graph = OnnxBackend.load_from_dir(&dir, ExecutionTarget::Cpu)?;
+        let mut context = graph.init_execution_context()?;
+
+        let tensor = Tensor {
+            dimensions: vec![1, 4],
+            tensor_type: TensorType::Fp32,
+            data: vec![0; 4],
+        };
+        assert!(matches!(
+            context.set_input(0, &tensor),
+            Err(BackendError::InvalidArgument(_))
+        ));
+        let tensor = Tensor {
+            dimensions: vec![u32::MAX; 4],
+            tensor_type: TensorType::Fp32,
+            data: vec![0; 4],
+        };
+        assert!(matches!(
+            context.set_input(0, &tensor),
+            Err(BackendError::InvalidArgument(_))
+        ));
+        assert!(context.compute().is_err());
+        assert!(OnnxBackend
+            .load(&[b"not a model"], ExecutionTarget::Cpu)
+            .is_err());
+        Ok(())
+    }
+}
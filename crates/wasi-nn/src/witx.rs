@@ -13,6 +13,7 @@
 //!
 //! [`types`]: crate::wit::types
 
+use crate::backend::BackendError;
 use crate::ctx::{UsageError, WasiNnCtx, WasiNnError, WasiNnResult as Result};
 use wiggle::GuestPtr;
 
@@ -42,7 +43,15 @@ mod gen {
         ) -> anyhow::Result<types::NnErrno> {
             tracing::debug!("host error: {:?}", e);
             match e {
-                WasiNnError::BackendError(_) => unimplemented!(),
+                WasiNnError::BackendError(e) => match e {
+                    BackendError::InvalidArgument(_)
+                    | BackendError::InvalidNumberOfBuilders(..) => {
+                        Ok(types::NnErrno::InvalidArgument)
+                    }
+                    BackendError::NotEnoughMemory(_) => Ok(types::NnErrno::TooLarge),
+                    BackendError::BackendAccess(_) => Ok(types::NnErrno::RuntimeError),
+                    BackendError::GuestAccess(e) => Err(e.into()),
+                },
                 WasiNnError::GuestError(_) => unimplemented!(),
                 WasiNnError::UsageError(_) => unimplemented!(),
             }
@@ -184,3 +193,28 @@ impl From<gen::types::TensorType> for crate::wit::types::TensorType {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::gen::types::{NnErrno, UserErrorConversion};
+    use super::*;
+    use crate::InMemoryRegistry;
+
+    #[test]
+    fn backend_errors() {
+        let mut ctx = WasiNnCtx::new([], InMemoryRegistry::new().into());
+        let mut errno = |e: BackendError| {
+            ctx.nn_errno_from_wasi_nn_error(e.into())
+                .expect("backend errors are reported to the guest")
+        };
+        assert_eq!(
+            errno(BackendError::InvalidArgument("bad tensor".to_string())),
+            NnErrno::InvalidArgument
+        );
+        assert_eq!(errno(BackendError::NotEnoughMemory(8)), NnErrno::TooLarge);
+        assert_eq!(
+            errno(anyhow::anyhow!("failed to run").into()),
+            NnErrno::RuntimeError
+        );
+    }
+}
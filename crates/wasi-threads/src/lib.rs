@@ -16,16 +16,31 @@ const WASI_ENTRY_POINT: &str = "wasi_thread_start";
 
 pub struct WasiThreadsCtx<T> {
     instance_pre: Arc<InstancePre<T>>,
+    configure_store: Option<Arc<dyn Fn(&mut Store<T>) -> Result<()> + Send + Sync>>,
 }
 
 impl<T: Clone + Send + 'static> WasiThreadsCtx<T> {
     pub fn new(module: Module, linker: Arc<Linker<T>>) -> Result<Self> {
         let instance_pre = Arc::new(linker.instantiate_pre(&module)?);
-        Ok(Self { instance_pre })
+        Ok(Self {
+            instance_pre,
+            configure_store: None,
+        })
+    }
+
+    /// Configures a callback which is invoked with the store of each spawned
+    /// thread before its instance is created, for example to install epoch
+    /// deadline callbacks or call hooks.
+    pub fn configure_store(
+        &mut self,
+        f: impl Fn(&mut Store<T>) -> Result<()> + Send + Sync + 'static,
+    ) {
+        self.configure_store = Some(Arc::new(f));
     }
 
     pub fn spawn(&self, host: T, thread_start_arg: i32) -> Result<i32> {
         let instance_pre = self.instance_pre.clone();
+        let configure_store = self.configure_store.clone();
 
         // Check that the thread entry point is present. Why here? If we check
         // for this too early, then we cannot accept modules that do not have an
@@ -54,6 +69,9 @@ impl<T: Clone + Send + 'static> WasiThreadsCtx<T> {
             let result = catch_unwind(AssertUnwindSafe(|| {
                 // Each new instance is created in its own store.
                 let mut store = Store::new(&instance_pre.module().engine(), host);
+                if let Some(configure_store) = &configure_store {
+                    configure_store(&mut store).unwrap();
+                }
                 let instance = instance_pre.instantiate(&mut store).unwrap();
                 let thread_entry_point = instance
                     .get_typed_func::<(i32, i32), ()>(&mut store, WASI_ENTRY_POINT)
@@ -88,6 +88,18 @@ pub mod snapshots {
 }
 }
 
+/// Returns the exit status that `e` requests if it's an [`I32Exit`] of either
+/// WASI preview1 or preview2.
+///
+/// This can be passed to `wasmtime::Store::host_call_exit_code` so that the
+/// exit status of a guest is kept in recorded host calls.
+#[cfg(feature = "exit")]
+pub fn exit_code(e: &anyhow::Error) -> Option<i32> {
+    e.downcast_ref::<I32Exit>()
+        .map(|e| e.0)
+        .or_else(|| e.downcast_ref::<preview2::I32Exit>().map(|e| e.0))
+}
+
 /// Exit the process with a conventional OS error code as long as Wasmtime
 /// understands the error. If the error is not an `I32Exit` or `Trap`, return
 /// the error back to the caller for it to decide what to do.
@@ -103,11 +115,7 @@ pub fn maybe_exit_on_error(e: anyhow::Error) -> anyhow::Error {
     // If a specific WASI error code was requested then that's
     // forwarded through to the process here without printing any
     // extra error information.
-    let code = e
-        .downcast_ref::<I32Exit>()
-        .map(|e| e.0)
-        .or_else(|| e.downcast_ref::<preview2::I32Exit>().map(|e| e.0));
-    if let Some(exit) = code {
+    if let Some(exit) = exit_code(&e) {
         // Print the error message in the usual way.
         // On Windows, exit status 3 indicates an abort (see below),
         // so return 1 indicating a non-zero status to avoid ambiguity.
@@ -1,6 +1,9 @@
+pub mod deterministic;
 pub mod host;
 use cap_std::time::Duration;
 
+pub use self::deterministic::DeterministicClock;
+
 pub trait HostWallClock: Send {
     fn resolution(&self) -> Duration;
     fn now(&self) -> Duration;
@@ -9,4 +12,15 @@ pub trait HostWallClock: Send {
 pub trait HostMonotonicClock: Send {
     fn resolution(&self) -> u64;
     fn now(&self) -> u64;
+
+    /// Moves this clock forward to `when`, returning whether it did so.
+    ///
+    /// Subscribing to a deadline of a clock which can be moved forward, such
+    /// as [`DeterministicClock`], moves the clock to that deadline and is
+    /// ready immediately rather than waiting for it in real time. Clocks which
+    /// follow the host's time return `false`, which is the default.
+    fn advance_to(&self, when: u64) -> bool {
+        let _ = when;
+        false
+    }
 }
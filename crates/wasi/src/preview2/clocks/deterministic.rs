@@ -0,0 +1,56 @@
+use super::{HostMonotonicClock, HostWallClock};
+use cap_std::time::Duration;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+
+/// A clock which doesn't read the host's time, for deterministic execution.
+///
+/// Time starts at zero, which the wall clock reports as the Unix epoch, and
+/// advances by [`DeterministicClock::TICK`] every time the clock is read.
+/// Subscribing to a deadline moves the clock forward to it immediately. Clones
+/// of a clock share the same time, so one clock may be used as both the wall
+/// and monotonic clock of a [`WasiCtx`](crate::preview2::WasiCtx).
+#[derive(Clone, Default)]
+pub struct DeterministicClock {
+    nanos: Arc<AtomicU64>,
+}
+
+impl DeterministicClock {
+    /// The amount of time which passes each time the clock is read.
+    pub const TICK: Duration = Duration::from_micros(1);
+
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the current time, in nanoseconds, and advances the clock.
+    fn tick(&self) -> u64 {
+        self.nanos
+            .fetch_add(Self::TICK.as_nanos() as u64, Ordering::SeqCst)
+    }
+}
+
+impl HostWallClock for DeterministicClock {
+    fn resolution(&self) -> Duration {
+        Self::TICK
+    }
+
+    fn now(&self) -> Duration {
+        Duration::from_nanos(self.tick())
+    }
+}
+
+impl HostMonotonicClock for DeterministicClock {
+    fn resolution(&self) -> u64 {
+        Self::TICK.as_nanos() as u64
+    }
+
+    fn now(&self) -> u64 {
+        self.tick()
+    }
+
+    fn advance_to(&self, when: u64) -> bool {
+        self.nanos.fetch_max(when, Ordering::SeqCst);
+        true
+    }
+}
@@ -26,6 +26,11 @@ wasmtime::component::bindgen!({
     },
 });
 
+/// Adds all interfaces of the `wasi:cli/command` world to `l`.
+///
+/// Every interface is always added, including `wasi:clocks` and
+/// `wasi:random`. For reproducible results across hosts, build the context
+/// with [`WasiCtxBuilder::deterministic`](crate::preview2::WasiCtxBuilder::deterministic).
 pub fn add_to_linker<T: WasiView>(l: &mut wasmtime::component::Linker<T>) -> anyhow::Result<()> {
     crate::preview2::bindings::clocks::wall_clock::add_to_linker(l, |t| t)?;
     crate::preview2::bindings::clocks::monotonic_clock::add_to_linker(l, |t| t)?;
@@ -87,6 +92,8 @@ pub mod sync {
         },
     });
 
+    /// Synchronous version of [`add_to_linker`](super::add_to_linker), which
+    /// likewise adds every interface of the `wasi:cli/command` world.
     pub fn add_to_linker<T: WasiView>(
         l: &mut wasmtime::component::Linker<T>,
     ) -> anyhow::Result<()> {
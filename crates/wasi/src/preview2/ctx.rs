@@ -1,13 +1,13 @@
 use crate::preview2::{
     clocks::{
         host::{monotonic_clock, wall_clock},
-        HostMonotonicClock, HostWallClock,
+        DeterministicClock, HostMonotonicClock, HostWallClock,
     },
     filesystem::Dir,
     network::{SocketAddrCheck, SocketAddrUse},
     pipe, random, stdio,
     stdio::{StdinStream, StdoutStream},
-    DirPerms, FilePerms,
+    DirPerms, FilePerms, WasiDir,
 };
 use cap_rand::{Rng, RngCore, SeedableRng};
 use std::sync::Arc;
@@ -144,8 +144,24 @@ impl WasiCtxBuilder {
         file_perms: FilePerms,
         path: impl AsRef<str>,
     ) -> &mut Self {
-        self.preopens
-            .push((Dir::new(dir, perms, file_perms), path.as_ref().to_owned()));
+        self.preopened_virtual_dir(dir, perms, file_perms, path)
+    }
+
+    /// Gives the guest access to `dir` under the name `path`.
+    ///
+    /// Unlike [`WasiCtxBuilder::preopened_dir`] this accepts any [`WasiDir`],
+    /// such as a [`MemoryDir`](crate::preview2::MemoryDir).
+    pub fn preopened_virtual_dir(
+        &mut self,
+        dir: impl Into<Box<dyn WasiDir>>,
+        perms: DirPerms,
+        file_perms: FilePerms,
+        path: impl AsRef<str>,
+    ) -> &mut Self {
+        self.preopens.push((
+            Dir::new_virtual(dir, perms, file_perms),
+            path.as_ref().to_owned(),
+        ));
         self
     }
 
@@ -184,6 +200,22 @@ impl WasiCtxBuilder {
         self
     }
 
+    /// Configures the clocks and random number generators for deterministic
+    /// execution, so that the guest observes the same time and randomness on
+    /// every run and on every host.
+    ///
+    /// Both clocks share a single [`DeterministicClock`] and all random number
+    /// generators are seeded with fixed values. This means that the secure
+    /// random API is entirely predictable in this mode.
+    pub fn deterministic(&mut self) -> &mut Self {
+        let clock = DeterministicClock::new();
+        self.wall_clock(clock.clone())
+            .monotonic_clock(clock)
+            .secure_random(cap_rand::rngs::StdRng::seed_from_u64(0))
+            .insecure_random(cap_rand::rngs::StdRng::seed_from_u64(1))
+            .insecure_random_seed(0)
+    }
+
     /// Allow all network addresses accessible to the host
     pub fn inherit_network(&mut self) -> &mut Self {
         self.socket_addr_check(|_, _| true)
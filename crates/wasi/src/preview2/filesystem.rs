@@ -1,13 +1,23 @@
 use crate::preview2::bindings::filesystem::types;
 use crate::preview2::{
-    spawn_blocking, AbortOnDropJoinHandle, HostOutputStream, StreamError, Subscribe, TrappableError,
+    spawn, AbortOnDropJoinHandle, HostOutputStream, StreamError, Subscribe, TrappableError,
 };
 use anyhow::anyhow;
-use bytes::{Bytes, BytesMut};
+use bytes::{Buf, Bytes};
+use std::any::Any;
 use std::io;
 use std::mem;
 use std::sync::Arc;
 
+mod memory;
+mod os;
+mod quota;
+mod read_only;
+
+pub use self::memory::MemoryDir;
+pub use self::quota::QuotaDir;
+pub use self::read_only::ReadOnlyDir;
+
 pub type FsResult<T> = Result<T, FsError>;
 
 pub type FsError = TrappableError<types::ErrorCode>;
@@ -24,6 +34,185 @@ impl From<io::Error> for FsError {
     }
 }
 
+/// A directory which can be handed to a guest through
+/// [`WasiCtxBuilder::preopened_virtual_dir`](crate::preview2::WasiCtxBuilder::preopened_virtual_dir).
+///
+/// Host directories opened with `cap_std` implement this trait, as do
+/// [`MemoryDir`], [`ReadOnlyDir`] and [`QuotaDir`]. Implementations don't
+/// need to check [`DirPerms`] or [`FilePerms`], which are enforced before any
+/// of these methods are called.
+///
+/// Paths are always relative to this directory, and implementations must not
+/// allow them to resolve to anything outside of it.
+#[async_trait::async_trait]
+pub trait WasiDir: Send + Sync + 'static {
+    fn as_any(&self) -> &dyn Any;
+
+    /// Opens the file or directory at `path`.
+    ///
+    /// Returns `error-code::not-directory` if `oflags` contains
+    /// `DIRECTORY` and `path` isn't a directory.
+    async fn open_at(
+        &self,
+        symlink_follow: bool,
+        path: &str,
+        oflags: types::OpenFlags,
+        flags: types::DescriptorFlags,
+    ) -> FsResult<OpenResult>;
+
+    async fn create_directory_at(&self, path: &str) -> FsResult<()>;
+
+    async fn read_directory(
+        &self,
+    ) -> FsResult<Box<dyn Iterator<Item = FsResult<types::DirectoryEntry>> + Send>>;
+
+    async fn stat(&self) -> FsResult<types::DescriptorStat>;
+
+    async fn stat_at(&self, symlink_follow: bool, path: &str) -> FsResult<types::DescriptorStat>;
+
+    /// Returns a hash of the identity of this directory. Two descriptors refer
+    /// to the same object if, and only if, their hashes are equal.
+    async fn metadata_hash(&self) -> FsResult<types::MetadataHashValue>;
+
+    async fn metadata_hash_at(
+        &self,
+        symlink_follow: bool,
+        path: &str,
+    ) -> FsResult<types::MetadataHashValue>;
+
+    async fn remove_directory_at(&self, path: &str) -> FsResult<()>;
+
+    async fn unlink_file_at(&self, path: &str) -> FsResult<()>;
+
+    /// Renames `old_path` to `new_path` within `new_dir`, which is typically
+    /// required to be of the same type as `self`.
+    async fn rename_at(
+        &self,
+        old_path: &str,
+        new_dir: &dyn WasiDir,
+        new_path: &str,
+    ) -> FsResult<()>;
+
+    async fn link_at(
+        &self,
+        _old_path: &str,
+        _new_dir: &dyn WasiDir,
+        _new_path: &str,
+    ) -> FsResult<()> {
+        Err(types::ErrorCode::Unsupported.into())
+    }
+
+    async fn symlink_at(&self, _src_path: &str, _dest_path: &str) -> FsResult<()> {
+        Err(types::ErrorCode::Unsupported.into())
+    }
+
+    async fn readlink_at(&self, _path: &str) -> FsResult<String> {
+        Err(types::ErrorCode::Unsupported.into())
+    }
+
+    async fn set_times(
+        &self,
+        _atim: types::NewTimestamp,
+        _mtim: types::NewTimestamp,
+    ) -> FsResult<()> {
+        Err(types::ErrorCode::Unsupported.into())
+    }
+
+    async fn set_times_at(
+        &self,
+        _symlink_follow: bool,
+        _path: &str,
+        _atim: types::NewTimestamp,
+        _mtim: types::NewTimestamp,
+    ) -> FsResult<()> {
+        Err(types::ErrorCode::Unsupported.into())
+    }
+
+    /// Returns the synchronization flags of this directory. The `READ` and
+    /// `MUTATE_DIRECTORY` flags are filled in from its [`DirPerms`].
+    async fn get_flags(&self) -> FsResult<types::DescriptorFlags> {
+        Ok(types::DescriptorFlags::empty())
+    }
+
+    async fn sync(&self) -> FsResult<()> {
+        Ok(())
+    }
+
+    async fn sync_data(&self) -> FsResult<()> {
+        Ok(())
+    }
+}
+
+/// A file opened through [`WasiDir::open_at`].
+#[async_trait::async_trait]
+pub trait WasiFile: Send + Sync + 'static {
+    fn as_any(&self) -> &dyn Any;
+
+    async fn stat(&self) -> FsResult<types::DescriptorStat>;
+
+    /// Returns a hash of the identity of this file. Two descriptors refer to
+    /// the same object if, and only if, their hashes are equal.
+    async fn metadata_hash(&self) -> FsResult<types::MetadataHashValue>;
+
+    /// Reads up to `len` bytes at `offset`. An empty buffer is returned at the
+    /// end of the file.
+    async fn read_at(&self, len: usize, offset: u64) -> FsResult<Vec<u8>>;
+
+    /// Writes some prefix of `buf` at `offset`, returning how many bytes were
+    /// written.
+    async fn write_at(&self, buf: Bytes, offset: u64) -> FsResult<usize>;
+
+    /// Writes some prefix of `buf` at the end of the file, returning how many
+    /// bytes were written.
+    async fn append(&self, buf: Bytes) -> FsResult<usize>;
+
+    async fn set_size(&self, size: u64) -> FsResult<()>;
+
+    async fn set_times(
+        &self,
+        _atim: types::NewTimestamp,
+        _mtim: types::NewTimestamp,
+    ) -> FsResult<()> {
+        Err(types::ErrorCode::Unsupported.into())
+    }
+
+    async fn advise(&self, _offset: u64, _len: u64, _advice: types::Advice) -> FsResult<()> {
+        Ok(())
+    }
+
+    /// Returns the synchronization flags of this file. The `READ` and `WRITE`
+    /// flags are filled in from its [`FilePerms`].
+    async fn get_flags(&self) -> FsResult<types::DescriptorFlags> {
+        Ok(types::DescriptorFlags::empty())
+    }
+
+    async fn sync(&self) -> FsResult<()> {
+        Ok(())
+    }
+
+    async fn sync_data(&self) -> FsResult<()> {
+        Ok(())
+    }
+}
+
+impl<D: WasiDir> From<D> for Box<dyn WasiDir> {
+    fn from(dir: D) -> Self {
+        Box::new(dir)
+    }
+}
+
+impl<F: WasiFile> From<F> for Box<dyn WasiFile> {
+    fn from(file: F) -> Self {
+        Box::new(file)
+    }
+}
+
+/// The result of [`WasiDir::open_at`].
+pub enum OpenResult {
+    File(Box<dyn WasiFile>),
+    Dir(Box<dyn WasiDir>),
+}
+
 pub enum Descriptor {
     File(File),
     Dir(Dir),
@@ -69,30 +258,32 @@ bitflags::bitflags! {
 
 pub struct File {
     /// Wrapped in an Arc because the same underlying file is used for
-    /// implementing the stream types. Also needed for [`spawn_blocking`].
+    /// implementing the stream types.
     ///
-    /// [`spawn_blocking`]: Self::spawn_blocking
-    pub file: Arc<cap_std::fs::File>,
+    /// Use [`File::cap_std_file`] to get at the host file behind it, if any.
+    pub file: Arc<dyn WasiFile>,
     pub perms: FilePerms,
 }
 
 impl File {
     pub fn new(file: cap_std::fs::File, perms: FilePerms) -> Self {
+        Self::new_virtual(file, perms)
+    }
+
+    /// Creates a descriptor for any [`WasiFile`], such as one opened from a
+    /// [`MemoryDir`].
+    pub fn new_virtual(file: impl Into<Box<dyn WasiFile>>, perms: FilePerms) -> Self {
         Self {
-            file: Arc::new(file),
+            file: Arc::from(file.into()),
             perms,
         }
     }
 
-    /// Spawn a task on tokio's blocking thread for performing blocking
-    /// syscalls on the underlying [`cap_std::fs::File`].
-    pub(crate) async fn spawn_blocking<F, R>(&self, body: F) -> R
-    where
-        F: FnOnce(&cap_std::fs::File) -> R + Send + 'static,
-        R: Send + 'static,
-    {
-        let f = self.file.clone();
-        spawn_blocking(move || body(&f)).await
+    /// Returns the host file behind this descriptor, or `None` if it was
+    /// opened from a virtual [`WasiDir`].
+    pub fn cap_std_file(&self) -> Option<&Arc<cap_std::fs::File>> {
+        let file = self.file.as_any().downcast_ref::<os::OsFile>()?;
+        Some(&file.0)
     }
 }
 
@@ -106,55 +297,59 @@ bitflags::bitflags! {
 
 #[derive(Clone)]
 pub struct Dir {
-    pub dir: Arc<cap_std::fs::Dir>,
+    /// Use [`Dir::cap_std_dir`] to get at the host directory behind it, if
+    /// any.
+    pub dir: Arc<dyn WasiDir>,
     pub perms: DirPerms,
     pub file_perms: FilePerms,
 }
 
 impl Dir {
     pub fn new(dir: cap_std::fs::Dir, perms: DirPerms, file_perms: FilePerms) -> Self {
+        Self::new_virtual(dir, perms, file_perms)
+    }
+
+    /// Creates a descriptor for any [`WasiDir`], such as a [`MemoryDir`].
+    pub fn new_virtual(
+        dir: impl Into<Box<dyn WasiDir>>,
+        perms: DirPerms,
+        file_perms: FilePerms,
+    ) -> Self {
         Dir {
-            dir: Arc::new(dir),
+            dir: Arc::from(dir.into()),
             perms,
             file_perms,
         }
     }
 
-    /// Spawn a task on tokio's blocking thread for performing blocking
-    /// syscalls on the underlying [`cap_std::fs::Dir`].
-    pub(crate) async fn spawn_blocking<F, R>(&self, body: F) -> R
-    where
-        F: FnOnce(&cap_std::fs::Dir) -> R + Send + 'static,
-        R: Send + 'static,
-    {
-        let d = self.dir.clone();
-        spawn_blocking(move || body(&d)).await
+    /// Returns the host directory behind this descriptor, or `None` if it's
+    /// a virtual [`WasiDir`].
+    pub fn cap_std_dir(&self) -> Option<&Arc<cap_std::fs::Dir>> {
+        let dir = self.dir.as_any().downcast_ref::<os::OsDir>()?;
+        Some(&dir.0)
     }
 }
 
 pub struct FileInputStream {
-    file: Arc<cap_std::fs::File>,
+    file: Arc<dyn WasiFile>,
     position: u64,
 }
 impl FileInputStream {
-    pub fn new(file: Arc<cap_std::fs::File>, position: u64) -> Self {
+    pub fn new(file: Arc<dyn WasiFile>, position: u64) -> Self {
         Self { file, position }
     }
 
     pub async fn read(&mut self, size: usize) -> Result<Bytes, StreamError> {
-        use system_interface::fs::FileIoExt;
-        let f = Arc::clone(&self.file);
-        let p = self.position;
-        let (r, mut buf) = spawn_blocking(move || {
-            let mut buf = BytesMut::zeroed(size);
-            let r = f.read_at(&mut buf, p);
-            (r, buf)
-        })
-        .await;
-        let n = read_result(r)?;
-        buf.truncate(n);
-        self.position += n as u64;
-        Ok(buf.freeze())
+        let buf = self
+            .file
+            .read_at(size, self.position)
+            .await
+            .map_err(stream_error)?;
+        if buf.is_empty() && size > 0 {
+            return Err(StreamError::Closed);
+        }
+        self.position += buf.len() as u64;
+        Ok(buf.into())
     }
 
     pub async fn skip(&mut self, nelem: usize) -> Result<usize, StreamError> {
@@ -163,12 +358,12 @@ impl FileInputStream {
     }
 }
 
-fn read_result(r: io::Result<usize>) -> Result<usize, StreamError> {
-    match r {
-        Ok(0) => Err(StreamError::Closed),
-        Ok(n) => Ok(n),
-        Err(e) if e.kind() == std::io::ErrorKind::Interrupted => Ok(0),
-        Err(e) => Err(StreamError::LastOperationFailed(e.into())),
+/// Converts an error from a [`WasiFile`] into a stream error, keeping traps
+/// as traps.
+fn stream_error(error: FsError) -> StreamError {
+    match error.downcast() {
+        Ok(code) => StreamError::LastOperationFailed(code.into()),
+        Err(trap) => StreamError::Trap(trap),
     }
 }
 
@@ -179,7 +374,7 @@ pub(crate) enum FileOutputMode {
 }
 
 pub(crate) struct FileOutputStream {
-    file: Arc<cap_std::fs::File>,
+    file: Arc<dyn WasiFile>,
     mode: FileOutputMode,
     state: OutputState,
 }
@@ -188,21 +383,21 @@ enum OutputState {
     Ready,
     /// Allows join future to be awaited in a cancellable manner. Gone variant indicates
     /// no task is currently outstanding.
-    Waiting(AbortOnDropJoinHandle<io::Result<usize>>),
+    Waiting(AbortOnDropJoinHandle<FsResult<usize>>),
     /// The last I/O operation failed with this error.
-    Error(io::Error),
+    Error(FsError),
     Closed,
 }
 
 impl FileOutputStream {
-    pub fn write_at(file: Arc<cap_std::fs::File>, position: u64) -> Self {
+    pub fn write_at(file: Arc<dyn WasiFile>, position: u64) -> Self {
         Self {
             file,
             mode: FileOutputMode::Position(position),
             state: OutputState::Ready,
         }
     }
-    pub fn append(file: Arc<cap_std::fs::File>) -> Self {
+    pub fn append(file: Arc<dyn WasiFile>) -> Self {
         Self {
             file,
             mode: FileOutputMode::Append,
@@ -216,7 +411,6 @@ const FILE_WRITE_CAPACITY: usize = 1024 * 1024;
 
 impl HostOutputStream for FileOutputStream {
     fn write(&mut self, buf: Bytes) -> Result<(), StreamError> {
-        use system_interface::fs::FileIoExt;
         match self.state {
             OutputState::Ready => {}
             OutputState::Closed => return Err(StreamError::Closed),
@@ -230,42 +424,34 @@ impl HostOutputStream for FileOutputStream {
 
         let f = Arc::clone(&self.file);
         let m = self.mode;
-        let task = spawn_blocking(move || match m {
-            FileOutputMode::Position(mut p) => {
-                let mut total = 0;
-                let mut buf = buf;
-                while !buf.is_empty() {
-                    let nwritten = f.write_at(buf.as_ref(), p)?;
-                    // afterwards buf contains [nwritten, len):
-                    let _ = buf.split_to(nwritten);
-                    p += nwritten as u64;
-                    total += nwritten;
-                }
-                Ok(total)
-            }
-            FileOutputMode::Append => {
-                let mut total = 0;
-                let mut buf = buf;
-                while !buf.is_empty() {
-                    let nwritten = f.append(buf.as_ref())?;
-                    let _ = buf.split_to(nwritten);
-                    total += nwritten;
-                }
-                Ok(total)
+        let task = spawn(async move {
+            let mut total = 0;
+            let mut buf = buf;
+            while !buf.is_empty() {
+                let nwritten = match m {
+                    FileOutputMode::Position(p) => {
+                        f.write_at(buf.clone(), p + total as u64).await?
+                    }
+                    FileOutputMode::Append => f.append(buf.clone()).await?,
+                };
+                // afterwards buf contains [nwritten, len):
+                buf.advance(nwritten);
+                total += nwritten;
             }
+            Ok(total)
         });
         self.state = OutputState::Waiting(task);
         Ok(())
     }
     fn flush(&mut self) -> Result<(), StreamError> {
         match self.state {
-            // Only userland buffering of file writes is in the blocking task,
-            // so there's nothing extra that needs to be done to request a
-            // flush.
+            // Only userland buffering of file writes is in the background
+            // task, so there's nothing extra that needs to be done to request
+            // a flush.
             OutputState::Ready | OutputState::Waiting(_) => Ok(()),
             OutputState::Closed => Err(StreamError::Closed),
             OutputState::Error(_) => match mem::replace(&mut self.state, OutputState::Closed) {
-                OutputState::Error(e) => Err(StreamError::LastOperationFailed(e.into())),
+                OutputState::Error(e) => Err(stream_error(e)),
                 _ => unreachable!(),
             },
         }
@@ -275,7 +461,7 @@ impl HostOutputStream for FileOutputStream {
             OutputState::Ready => Ok(FILE_WRITE_CAPACITY),
             OutputState::Closed => Err(StreamError::Closed),
             OutputState::Error(_) => match mem::replace(&mut self.state, OutputState::Closed) {
-                OutputState::Error(e) => Err(StreamError::LastOperationFailed(e.into())),
+                OutputState::Error(e) => Err(stream_error(e)),
                 _ => unreachable!(),
             },
             OutputState::Waiting(_) => Ok(0),
@@ -323,3 +509,66 @@ impl IntoIterator for ReaddirIterator {
         self.0.into_inner().unwrap()
     }
 }
+
+#[cfg(test)]
+mod test_util {
+    //! Helpers for the tests of the `WasiDir` implementations.
+
+    use super::*;
+    use types::{DescriptorFlags, ErrorCode, OpenFlags};
+
+    pub(super) fn code<T>(result: FsResult<T>) -> ErrorCode {
+        match result {
+            Ok(_) => panic!("expected an error"),
+            Err(e) => e.downcast().unwrap(),
+        }
+    }
+
+    pub(super) async fn create(dir: &dyn WasiDir, path: &str) -> FsResult<Box<dyn WasiFile>> {
+        let flags = DescriptorFlags::READ | DescriptorFlags::WRITE;
+        match dir.open_at(false, path, OpenFlags::CREATE, flags).await? {
+            OpenResult::File(f) => Ok(f),
+            OpenResult::Dir(_) => panic!("expected a file"),
+        }
+    }
+
+    pub(super) async fn open_dir(dir: &dyn WasiDir, path: &str) -> FsResult<Box<dyn WasiDir>> {
+        let flags = DescriptorFlags::READ;
+        match dir
+            .open_at(false, path, OpenFlags::DIRECTORY, flags)
+            .await?
+        {
+            OpenResult::Dir(d) => Ok(d),
+            OpenResult::File(_) => panic!("expected a directory"),
+        }
+    }
+
+    pub(super) async fn names(dir: &dyn WasiDir) -> Vec<String> {
+        dir.read_directory()
+            .await
+            .unwrap()
+            .map(|e| e.unwrap().name)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn cap_std_accessors() -> anyhow::Result<()> {
+        let tmp = tempfile::tempdir()?;
+        let host = cap_std::fs::Dir::open_ambient_dir(tmp.path(), cap_std::ambient_authority())?;
+        let file = host.create("a.txt")?;
+
+        let dir = Dir::new(host, DirPerms::all(), FilePerms::all());
+        assert!(dir.cap_std_dir().is_some());
+        let file = File::new(file, FilePerms::all());
+        assert!(file.cap_std_file().unwrap().metadata()?.is_file());
+
+        let dir = Dir::new_virtual(MemoryDir::new(), DirPerms::all(), FilePerms::all());
+        assert!(dir.cap_std_dir().is_none());
+        Ok(())
+    }
+}
@@ -0,0 +1,874 @@
+//! A filesystem which lives entirely in host memory.
+
+use super::{FsResult, OpenResult, WasiDir, WasiFile};
+use crate::preview2::bindings::clocks::wall_clock::Datetime;
+use crate::preview2::bindings::filesystem::types::{self, ErrorCode};
+use bytes::Bytes;
+use std::any::Any;
+use std::collections::{BTreeMap, HashMap};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::SystemTime;
+
+/// A directory of a filesystem which lives entirely in host memory.
+///
+/// This can be used to give guests ephemeral scratch space, or to serve files
+/// bundled with the host without touching disk. Cloning a `MemoryDir` gives
+/// another handle to the same directory, so the host can keep one to inspect
+/// what the guest wrote:
+///
+/// ```
+/// use wasmtime_wasi::preview2::{DirPerms, FilePerms, MemoryDir, WasiCtxBuilder};
+///
+/// # fn main() -> wasmtime_wasi::preview2::FsResult<()> {
+/// let assets = MemoryDir::new();
+/// assets.write_file("img/logo.svg", "<svg/>")?;
+///
+/// let mut builder = WasiCtxBuilder::new();
+/// builder.preopened_virtual_dir(assets.clone(), DirPerms::all(), FilePerms::all(), "/assets");
+/// # Ok(())
+/// # }
+/// ```
+///
+/// The size of files and of the whole filesystem is limited, see
+/// [`MemoryDir::with_limits`]. Wrapping the directory in a
+/// [`QuotaDir`](crate::preview2::QuotaDir) gives a guest a smaller share of
+/// it. Symbolic links are not supported.
+pub struct MemoryDir {
+    fs: Arc<Mutex<Fs>>,
+    ino: u64,
+}
+
+impl MemoryDir {
+    /// The default limit on the size of a single file.
+    pub const DEFAULT_MAX_FILE_SIZE: u64 = 256 << 20;
+
+    /// The default limit on the total size of the files of a filesystem.
+    pub const DEFAULT_MAX_SIZE: u64 = 1 << 30;
+
+    /// Creates a new, empty filesystem and returns its root directory.
+    ///
+    /// Its files may be at most [`MemoryDir::DEFAULT_MAX_FILE_SIZE`] bytes
+    /// large and may hold at most [`MemoryDir::DEFAULT_MAX_SIZE`] bytes in
+    /// total.
+    pub fn new() -> MemoryDir {
+        MemoryDir::with_limits(Self::DEFAULT_MAX_FILE_SIZE, Self::DEFAULT_MAX_SIZE)
+    }
+
+    /// Creates a new, empty filesystem whose files may be at most
+    /// `max_file_size` bytes large and may hold at most `max_size` bytes in
+    /// total, and returns its root directory.
+    ///
+    /// Writes which would exceed these limits fail with
+    /// `error-code::file-too-large` and `error-code::insufficient-memory`
+    /// respectively.
+    pub fn with_limits(max_file_size: u64, max_size: u64) -> MemoryDir {
+        static NEXT_ID: AtomicU64 = AtomicU64::new(0);
+
+        const ROOT: u64 = 0;
+        let mut root = Inode::new(Kind::Dir {
+            entries: BTreeMap::new(),
+            parent: ROOT,
+        });
+        root.handles = 1;
+        let fs = Fs {
+            id: NEXT_ID.fetch_add(1, Ordering::Relaxed),
+            inodes: HashMap::from([(ROOT, root)]),
+            next_ino: ROOT + 1,
+            size: 0,
+            max_file_size,
+            max_size,
+        };
+        MemoryDir {
+            fs: Arc::new(Mutex::new(fs)),
+            ino: ROOT,
+        }
+    }
+
+    /// Writes `contents` to the file at `path`, creating it and any missing
+    /// parent directories if needed.
+    pub fn write_file(&self, path: &str, contents: impl Into<Vec<u8>>) -> FsResult<()> {
+        let mut components = components(path)?;
+        let name = components.pop().unwrap();
+        if name == "." || name == ".." {
+            return Err(ErrorCode::Invalid.into());
+        }
+        let mut fs = self.lock();
+        let dir = fs.create_dirs(self.ino, &components)?;
+        let ino = fs.create_file(dir, name, false)?;
+        let contents = contents.into();
+        fs.resize(ino, contents.len())?;
+        fs.file_mut(ino)?.copy_from_slice(&contents);
+        fs.touch(ino);
+        Ok(())
+    }
+
+    /// Returns the contents of the file at `path`.
+    pub fn read_file(&self, path: &str) -> FsResult<Vec<u8>> {
+        let fs = self.lock();
+        let ino = fs.lookup(self.ino, path)?;
+        match &fs.inode(ino).kind {
+            Kind::File(data) => Ok(data.clone()),
+            Kind::Dir { .. } => Err(ErrorCode::IsDirectory.into()),
+        }
+    }
+
+    /// Creates the directory at `path` along with any missing parents.
+    pub fn create_dir_all(&self, path: &str) -> FsResult<()> {
+        let components = components(path)?;
+        self.lock().create_dirs(self.ino, &components)?;
+        Ok(())
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Fs> {
+        self.fs.lock().unwrap()
+    }
+
+    fn handle(fs: &Arc<Mutex<Fs>>, ino: u64) -> MemoryDir {
+        fs.lock().unwrap().inode_mut(ino).handles += 1;
+        MemoryDir {
+            fs: fs.clone(),
+            ino,
+        }
+    }
+
+    /// Returns the handle `dir`, which must be a directory of the same
+    /// filesystem as `self`.
+    fn same_fs<'a>(&self, dir: &'a dyn WasiDir) -> FsResult<&'a MemoryDir> {
+        match dir.as_any().downcast_ref::<MemoryDir>() {
+            Some(dir) if Arc::ptr_eq(&self.fs, &dir.fs) => Ok(dir),
+            _ => Err(ErrorCode::CrossDevice.into()),
+        }
+    }
+}
+
+impl Default for MemoryDir {
+    fn default() -> MemoryDir {
+        MemoryDir::new()
+    }
+}
+
+impl Clone for MemoryDir {
+    fn clone(&self) -> MemoryDir {
+        MemoryDir::handle(&self.fs, self.ino)
+    }
+}
+
+impl Drop for MemoryDir {
+    fn drop(&mut self) {
+        if let Ok(mut fs) = self.fs.lock() {
+            fs.inode_mut(self.ino).handles -= 1;
+            fs.collect(self.ino);
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl WasiDir for MemoryDir {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    async fn open_at(
+        &self,
+        _symlink_follow: bool,
+        path: &str,
+        oflags: types::OpenFlags,
+        flags: types::DescriptorFlags,
+    ) -> FsResult<OpenResult> {
+        use types::{DescriptorFlags, OpenFlags};
+
+        let mut fs = self.lock();
+        let ino = if oflags.contains(OpenFlags::CREATE) {
+            let (dir, name) = fs.parent(self.ino, path)?;
+            fs.create_file(dir, &name, oflags.contains(OpenFlags::EXCLUSIVE))?
+        } else {
+            fs.lookup(self.ino, path)?
+        };
+
+        match fs.inode(ino).kind {
+            Kind::Dir { .. } => {
+                if flags.contains(DescriptorFlags::WRITE) || oflags.contains(OpenFlags::TRUNCATE) {
+                    return Err(ErrorCode::IsDirectory.into());
+                }
+                drop(fs);
+                Ok(OpenResult::Dir(Box::new(MemoryDir::handle(&self.fs, ino))))
+            }
+            Kind::File(_) => {
+                if oflags.contains(OpenFlags::DIRECTORY) {
+                    return Err(ErrorCode::NotDirectory.into());
+                }
+                if oflags.contains(OpenFlags::TRUNCATE) {
+                    fs.resize(ino, 0)?;
+                    fs.touch(ino);
+                }
+                drop(fs);
+                Ok(OpenResult::File(Box::new(MemoryFile::handle(
+                    &self.fs, ino,
+                ))))
+            }
+        }
+    }
+
+    async fn create_directory_at(&self, path: &str) -> FsResult<()> {
+        let mut fs = self.lock();
+        let (dir, name) = fs.parent(self.ino, path)?;
+        if fs.entries(dir)?.contains_key(&name) {
+            return Err(ErrorCode::Exist.into());
+        }
+        fs.link(
+            dir,
+            name,
+            Kind::Dir {
+                entries: BTreeMap::new(),
+                parent: dir,
+            },
+        );
+        Ok(())
+    }
+
+    async fn read_directory(
+        &self,
+    ) -> FsResult<Box<dyn Iterator<Item = FsResult<types::DirectoryEntry>> + Send>> {
+        let fs = self.lock();
+        let entries = fs
+            .entries(self.ino)?
+            .iter()
+            .map(|(name, ino)| {
+                Ok(types::DirectoryEntry {
+                    type_: fs.inode(*ino).type_(),
+                    name: name.clone(),
+                })
+            })
+            .collect::<Vec<_>>();
+        Ok(Box::new(entries.into_iter()))
+    }
+
+    async fn stat(&self) -> FsResult<types::DescriptorStat> {
+        Ok(self.lock().inode(self.ino).stat())
+    }
+
+    async fn stat_at(&self, _symlink_follow: bool, path: &str) -> FsResult<types::DescriptorStat> {
+        let fs = self.lock();
+        let ino = fs.lookup(self.ino, path)?;
+        Ok(fs.inode(ino).stat())
+    }
+
+    async fn metadata_hash(&self) -> FsResult<types::MetadataHashValue> {
+        Ok(self.lock().metadata_hash(self.ino))
+    }
+
+    async fn metadata_hash_at(
+        &self,
+        _symlink_follow: bool,
+        path: &str,
+    ) -> FsResult<types::MetadataHashValue> {
+        let fs = self.lock();
+        let ino = fs.lookup(self.ino, path)?;
+        Ok(fs.metadata_hash(ino))
+    }
+
+    async fn remove_directory_at(&self, path: &str) -> FsResult<()> {
+        let mut fs = self.lock();
+        let (dir, name) = fs.parent(self.ino, path)?;
+        let ino = fs.entry(dir, &name)?;
+        if !fs.entries(ino)?.is_empty() {
+            return Err(ErrorCode::NotEmpty.into());
+        }
+        fs.unlink(dir, &name);
+        Ok(())
+    }
+
+    async fn unlink_file_at(&self, path: &str) -> FsResult<()> {
+        let mut fs = self.lock();
+        let (dir, name) = fs.parent(self.ino, path)?;
+        let ino = fs.entry(dir, &name)?;
+        if let Kind::Dir { .. } = fs.inode(ino).kind {
+            return Err(ErrorCode::IsDirectory.into());
+        }
+        fs.unlink(dir, &name);
+        Ok(())
+    }
+
+    async fn rename_at(
+        &self,
+        old_path: &str,
+        new_dir: &dyn WasiDir,
+        new_path: &str,
+    ) -> FsResult<()> {
+        let new_dir = self.same_fs(new_dir)?;
+        let mut fs = self.lock();
+        let (old_dir, old_name) = fs.parent(self.ino, old_path)?;
+        let (new_dir, new_name) = fs.parent(new_dir.ino, new_path)?;
+        let ino = fs.entry(old_dir, &old_name)?;
+
+        let is_dir = matches!(fs.inode(ino).kind, Kind::Dir { .. });
+        if is_dir && fs.is_ancestor(ino, new_dir) {
+            return Err(ErrorCode::Invalid.into());
+        }
+        if let Some(&target) = fs.entries(new_dir)?.get(&new_name) {
+            if target == ino {
+                return Ok(());
+            }
+            match (&fs.inode(target).kind, is_dir) {
+                (Kind::Dir { entries, .. }, true) if !entries.is_empty() => {
+                    return Err(ErrorCode::NotEmpty.into());
+                }
+                (Kind::Dir { .. }, false) => return Err(ErrorCode::IsDirectory.into()),
+                (Kind::File(_), true) => return Err(ErrorCode::NotDirectory.into()),
+                _ => {}
+            }
+            fs.unlink(new_dir, &new_name);
+        }
+
+        fs.entries_mut(old_dir).remove(&old_name);
+        fs.entries_mut(new_dir).insert(new_name, ino);
+        if let Kind::Dir { parent, .. } = &mut fs.inode_mut(ino).kind {
+            *parent = new_dir;
+        }
+        fs.touch(old_dir);
+        fs.touch(new_dir);
+        Ok(())
+    }
+
+    async fn link_at(&self, old_path: &str, new_dir: &dyn WasiDir, new_path: &str) -> FsResult<()> {
+        let new_dir = self.same_fs(new_dir)?;
+        let mut fs = self.lock();
+        let ino = fs.lookup(self.ino, old_path)?;
+        if let Kind::Dir { .. } = fs.inode(ino).kind {
+            return Err(ErrorCode::NotPermitted.into());
+        }
+        let (new_dir, new_name) = fs.parent(new_dir.ino, new_path)?;
+        if fs.entries(new_dir)?.contains_key(&new_name) {
+            return Err(ErrorCode::Exist.into());
+        }
+        fs.entries_mut(new_dir).insert(new_name, ino);
+        fs.inode_mut(ino).nlink += 1;
+        fs.touch(new_dir);
+        Ok(())
+    }
+
+    async fn readlink_at(&self, path: &str) -> FsResult<String> {
+        // There are no symbolic links, so this can only fail.
+        self.lock().lookup(self.ino, path)?;
+        Err(ErrorCode::Invalid.into())
+    }
+
+    async fn set_times(
+        &self,
+        atim: types::NewTimestamp,
+        mtim: types::NewTimestamp,
+    ) -> FsResult<()> {
+        self.lock().inode_mut(self.ino).set_times(atim, mtim);
+        Ok(())
+    }
+
+    async fn set_times_at(
+        &self,
+        _symlink_follow: bool,
+        path: &str,
+        atim: types::NewTimestamp,
+        mtim: types::NewTimestamp,
+    ) -> FsResult<()> {
+        let mut fs = self.lock();
+        let ino = fs.lookup(self.ino, path)?;
+        fs.inode_mut(ino).set_times(atim, mtim);
+        Ok(())
+    }
+}
+
+/// A file of a [`MemoryDir`].
+struct MemoryFile {
+    fs: Arc<Mutex<Fs>>,
+    ino: u64,
+}
+
+impl MemoryFile {
+    fn handle(fs: &Arc<Mutex<Fs>>, ino: u64) -> MemoryFile {
+        fs.lock().unwrap().inode_mut(ino).handles += 1;
+        MemoryFile {
+            fs: fs.clone(),
+            ino,
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Fs> {
+        self.fs.lock().unwrap()
+    }
+}
+
+impl Drop for MemoryFile {
+    fn drop(&mut self) {
+        if let Ok(mut fs) = self.fs.lock() {
+            fs.inode_mut(self.ino).handles -= 1;
+            fs.collect(self.ino);
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl WasiFile for MemoryFile {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    async fn stat(&self) -> FsResult<types::DescriptorStat> {
+        Ok(self.lock().inode(self.ino).stat())
+    }
+
+    async fn metadata_hash(&self) -> FsResult<types::MetadataHashValue> {
+        Ok(self.lock().metadata_hash(self.ino))
+    }
+
+    async fn read_at(&self, len: usize, offset: u64) -> FsResult<Vec<u8>> {
+        let mut fs = self.lock();
+        let data = fs.file_mut(self.ino)?;
+        let start = usize::try_from(offset)
+            .unwrap_or(usize::MAX)
+            .min(data.len());
+        let end = start.saturating_add(len).min(data.len());
+        Ok(data[start..end].to_vec())
+    }
+
+    async fn write_at(&self, buf: Bytes, offset: u64) -> FsResult<usize> {
+        let mut fs = self.lock();
+        let start = usize::try_from(offset).map_err(ErrorCode::from)?;
+        let end = start.checked_add(buf.len()).ok_or(ErrorCode::Overflow)?;
+        let len = fs.file_mut(self.ino)?.len();
+        fs.resize(self.ino, end.max(len))?;
+        fs.file_mut(self.ino)?[start..end].copy_from_slice(&buf);
+        fs.touch(self.ino);
+        Ok(buf.len())
+    }
+
+    async fn append(&self, buf: Bytes) -> FsResult<usize> {
+        let mut fs = self.lock();
+        let start = fs.file_mut(self.ino)?.len();
+        let end = start.checked_add(buf.len()).ok_or(ErrorCode::Overflow)?;
+        fs.resize(self.ino, end)?;
+        fs.file_mut(self.ino)?[start..].copy_from_slice(&buf);
+        fs.touch(self.ino);
+        Ok(buf.len())
+    }
+
+    async fn set_size(&self, size: u64) -> FsResult<()> {
+        let mut fs = self.lock();
+        let size = usize::try_from(size).map_err(ErrorCode::from)?;
+        fs.resize(self.ino, size)?;
+        fs.touch(self.ino);
+        Ok(())
+    }
+
+    async fn set_times(
+        &self,
+        atim: types::NewTimestamp,
+        mtim: types::NewTimestamp,
+    ) -> FsResult<()> {
+        self.lock().inode_mut(self.ino).set_times(atim, mtim);
+        Ok(())
+    }
+}
+
+/// The state of a filesystem, shared by all handles to its files and
+/// directories.
+struct Fs {
+    /// Distinguishes the metadata hashes of different filesystems.
+    id: u64,
+    inodes: HashMap<u64, Inode>,
+    next_ino: u64,
+    /// The total size of the files in `inodes`.
+    size: u64,
+    max_file_size: u64,
+    max_size: u64,
+}
+
+impl Fs {
+    fn inode(&self, ino: u64) -> &Inode {
+        &self.inodes[&ino]
+    }
+
+    fn inode_mut(&mut self, ino: u64) -> &mut Inode {
+        self.inodes.get_mut(&ino).unwrap()
+    }
+
+    fn entries(&self, ino: u64) -> Result<&BTreeMap<String, u64>, ErrorCode> {
+        match &self.inode(ino).kind {
+            Kind::Dir { entries, .. } => Ok(entries),
+            Kind::File(_) => Err(ErrorCode::NotDirectory),
+        }
+    }
+
+    fn entries_mut(&mut self, ino: u64) -> &mut BTreeMap<String, u64> {
+        match &mut self.inode_mut(ino).kind {
+            Kind::Dir { entries, .. } => entries,
+            Kind::File(_) => unreachable!(),
+        }
+    }
+
+    fn entry(&self, dir: u64, name: &str) -> Result<u64, ErrorCode> {
+        self.entries(dir)?
+            .get(name)
+            .copied()
+            .ok_or(ErrorCode::NoEntry)
+    }
+
+    fn file_mut(&mut self, ino: u64) -> Result<&mut Vec<u8>, ErrorCode> {
+        match &mut self.inode_mut(ino).kind {
+            Kind::File(data) => Ok(data),
+            Kind::Dir { .. } => Err(ErrorCode::IsDirectory),
+        }
+    }
+
+    /// Resizes the file `ino` to `len` bytes, failing rather than aborting if
+    /// it would exceed the limits of the filesystem or the memory can't be
+    /// allocated.
+    fn resize(&mut self, ino: u64, len: usize) -> Result<(), ErrorCode> {
+        let old = self.file_mut(ino)?.len() as u64;
+        let new = len as u64;
+        if new > old {
+            if new > self.max_file_size {
+                return Err(ErrorCode::FileTooLarge);
+            }
+            if self.size - old + new > self.max_size {
+                return Err(ErrorCode::InsufficientMemory);
+            }
+        }
+        let data = self.file_mut(ino)?;
+        data.try_reserve(len.saturating_sub(data.len()))
+            .map_err(|_| ErrorCode::InsufficientMemory)?;
+        data.resize(len, 0);
+        self.size = self.size - old + new;
+        Ok(())
+    }
+
+    /// Resolves `path` relative to the directory `root`, which it may not
+    /// escape.
+    fn lookup(&self, root: u64, path: &str) -> Result<u64, ErrorCode> {
+        self.resolve(root, &components(path)?)
+    }
+
+    fn resolve(&self, root: u64, components: &[&str]) -> Result<u64, ErrorCode> {
+        let mut stack = vec![root];
+        for component in components {
+            let ino = *stack.last().unwrap();
+            match *component {
+                "." => {}
+                ".." => {
+                    self.entries(ino)?;
+                    if stack.len() == 1 {
+                        return Err(ErrorCode::NotPermitted);
+                    }
+                    stack.pop();
+                }
+                name => stack.push(self.entry(ino, name)?),
+            }
+        }
+        Ok(*stack.last().unwrap())
+    }
+
+    /// Resolves all but the last component of `path`, returning the directory
+    /// they name along with the last component.
+    fn parent(&self, root: u64, path: &str) -> Result<(u64, String), ErrorCode> {
+        let mut components = components(path)?;
+        let name = components.pop().unwrap();
+        if name == "." || name == ".." {
+            return Err(ErrorCode::Invalid);
+        }
+        let dir = self.resolve(root, &components)?;
+        self.entries(dir)?;
+        if self.inode(dir).nlink == 0 {
+            return Err(ErrorCode::NoEntry);
+        }
+        Ok((dir, name.to_owned()))
+    }
+
+    /// Returns the file `name` in `dir`, creating it if it doesn't exist.
+    fn create_file(&mut self, dir: u64, name: &str, exclusive: bool) -> Result<u64, ErrorCode> {
+        match self.entries(dir)?.get(name) {
+            Some(_) if exclusive => Err(ErrorCode::Exist),
+            Some(ino) => Ok(*ino),
+            None => Ok(self.link(dir, name.to_owned(), Kind::File(Vec::new()))),
+        }
+    }
+
+    /// Creates the directories named by `components` in `root` where they
+    /// don't exist yet, returning the innermost one.
+    fn create_dirs(&mut self, root: u64, components: &[&str]) -> Result<u64, ErrorCode> {
+        let mut dir = root;
+        for component in components {
+            dir = match *component {
+                "." | ".." => self.resolve(dir, &[component])?,
+                name => match self.entries(dir)?.get(name) {
+                    Some(ino) => *ino,
+                    None => self.link(
+                        dir,
+                        name.to_owned(),
+                        Kind::Dir {
+                            entries: BTreeMap::new(),
+                            parent: dir,
+                        },
+                    ),
+                },
+            };
+        }
+        self.entries(dir)?;
+        Ok(dir)
+    }
+
+    /// Adds a new inode to the filesystem as `name` in `dir`.
+    fn link(&mut self, dir: u64, name: String, kind: Kind) -> u64 {
+        let ino = self.next_ino;
+        self.next_ino += 1;
+        self.inodes.insert(ino, Inode::new(kind));
+        self.entries_mut(dir).insert(name, ino);
+        self.touch(dir);
+        ino
+    }
+
+    /// Removes `name` from `dir`, deleting the inode it refers to once it's
+    /// no longer used.
+    fn unlink(&mut self, dir: u64, name: &str) {
+        let ino = self.entries_mut(dir).remove(name).unwrap();
+        self.touch(dir);
+        let inode = self.inode_mut(ino);
+        inode.nlink = match inode.kind {
+            Kind::Dir { .. } => 0,
+            Kind::File(_) => inode.nlink - 1,
+        };
+        self.collect(ino);
+    }
+
+    fn collect(&mut self, ino: u64) {
+        let inode = self.inode(ino);
+        if inode.nlink == 0 && inode.handles == 0 {
+            if let Kind::File(data) = &inode.kind {
+                self.size -= data.len() as u64;
+            }
+            self.inodes.remove(&ino);
+        }
+    }
+
+    /// Returns whether the directory `dir` is `ancestor` or one of its
+    /// descendants.
+    fn is_ancestor(&self, ancestor: u64, mut dir: u64) -> bool {
+        loop {
+            if dir == ancestor {
+                return true;
+            }
+            match self.inode(dir).kind {
+                Kind::Dir { parent, .. } if parent != dir => dir = parent,
+                _ => return false,
+            }
+        }
+    }
+
+    /// Updates the modification time of `ino`.
+    fn touch(&mut self, ino: u64) {
+        let inode = self.inode_mut(ino);
+        inode.mtime = now();
+        inode.ctime = inode.mtime;
+    }
+
+    fn metadata_hash(&self, ino: u64) -> types::MetadataHashValue {
+        types::MetadataHashValue {
+            lower: ino,
+            upper: self.id,
+        }
+    }
+}
+
+fn components(path: &str) -> Result<Vec<&str>, ErrorCode> {
+    if path.is_empty() {
+        return Err(ErrorCode::NoEntry);
+    }
+    if path.starts_with('/') {
+        return Err(ErrorCode::NotPermitted);
+    }
+    Ok(path.split('/').filter(|c| !c.is_empty()).collect())
+}
+
+struct Inode {
+    kind: Kind,
+    /// The number of directory entries referring to this inode.
+    nlink: u64,
+    /// The number of open [`MemoryDir`]s and [`MemoryFile`]s referring to
+    /// this inode.
+    handles: usize,
+    atime: Datetime,
+    mtime: Datetime,
+    ctime: Datetime,
+}
+
+enum Kind {
+    File(Vec<u8>),
+    Dir {
+        entries: BTreeMap<String, u64>,
+        /// The parent of this directory, or itself for the root.
+        parent: u64,
+    },
+}
+
+impl Inode {
+    fn new(kind: Kind) -> Inode {
+        let now = now();
+        Inode {
+            kind,
+            nlink: 1,
+            handles: 0,
+            atime: now,
+            mtime: now,
+            ctime: now,
+        }
+    }
+
+    fn type_(&self) -> types::DescriptorType {
+        match self.kind {
+            Kind::File(_) => types::DescriptorType::RegularFile,
+            Kind::Dir { .. } => types::DescriptorType::Directory,
+        }
+    }
+
+    fn stat(&self) -> types::DescriptorStat {
+        types::DescriptorStat {
+            type_: self.type_(),
+            link_count: self.nlink,
+            size: match &self.kind {
+                Kind::File(data) => data.len() as u64,
+                Kind::Dir { .. } => 0,
+            },
+            data_access_timestamp: Some(self.atime),
+            data_modification_timestamp: Some(self.mtime),
+            status_change_timestamp: Some(self.ctime),
+        }
+    }
+
+    fn set_times(&mut self, atim: types::NewTimestamp, mtim: types::NewTimestamp) {
+        let now = now();
+        let new = |t| match t {
+            types::NewTimestamp::NoChange => None,
+            types::NewTimestamp::Now => Some(now),
+            types::NewTimestamp::Timestamp(t) => Some(t),
+        };
+        if let Some(t) = new(atim) {
+            self.atime = t;
+        }
+        if let Some(t) = new(mtim) {
+            self.mtime = t;
+        }
+        self.ctime = now;
+    }
+}
+
+fn now() -> Datetime {
+    let now = SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .unwrap_or_default();
+    Datetime {
+        seconds: now.as_secs(),
+        nanoseconds: now.subsec_nanos(),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::preview2::filesystem::test_util::*;
+    use types::{DescriptorFlags, OpenFlags};
+
+    #[tokio::test]
+    async fn memory_dir() -> FsResult<()> {
+        let root = MemoryDir::new();
+        root.write_file("a/b/hello.txt", "hello")?;
+        assert_eq!(names(&root).await, ["a"]);
+
+        let a = open_dir(&root, "a").await?;
+        let f = create(&*a, "b/../b/./hello.txt").await?;
+        assert_eq!(f.read_at(100, 1).await?, b"ello");
+        assert_eq!(f.read_at(100, 5).await?, b"");
+        assert_eq!(f.write_at(Bytes::from_static(b"!"), 7).await?, 1);
+        assert_eq!(f.append(Bytes::from_static(b"?")).await?, 1);
+        assert_eq!(root.read_file("a/b/hello.txt")?, b"hello\0\0!?");
+        f.set_size(2).await?;
+        assert_eq!(f.stat().await?.size, 2);
+
+        // Paths can't escape the directory they're resolved in.
+        assert_eq!(code(a.stat_at(false, "..").await), ErrorCode::NotPermitted);
+        assert_eq!(code(a.stat_at(false, "/a").await), ErrorCode::NotPermitted);
+        assert_eq!(code(a.stat_at(false, "c").await), ErrorCode::NoEntry);
+        assert_eq!(
+            code(
+                a.open_at(
+                    false,
+                    "b/hello.txt/x",
+                    OpenFlags::empty(),
+                    DescriptorFlags::READ
+                )
+                .await
+            ),
+            ErrorCode::NotDirectory
+        );
+
+        // Renaming and linking keep the same file.
+        let hash = f.metadata_hash().await?;
+        root.rename_at("a/b/hello.txt", &root, "hi.txt").await?;
+        root.link_at("hi.txt", &*a, "link.txt").await?;
+        assert_eq!(
+            root.metadata_hash_at(false, "a/link.txt").await?.lower,
+            hash.lower
+        );
+        assert_eq!(root.stat_at(false, "hi.txt").await?.link_count, 2);
+        root.unlink_file_at("hi.txt").await?;
+        assert_eq!(names(&*a).await, ["b", "link.txt"]);
+        assert_eq!(
+            code(root.rename_at("a", &*a, "b/c").await),
+            ErrorCode::Invalid
+        );
+        assert_eq!(
+            code(root.rename_at("a", &MemoryDir::new(), "a").await),
+            ErrorCode::CrossDevice
+        );
+
+        // Directories can only be removed once empty, and open files outlive
+        // their last link.
+        assert_eq!(
+            code(root.remove_directory_at("a").await),
+            ErrorCode::NotEmpty
+        );
+        a.unlink_file_at("link.txt").await?;
+        a.remove_directory_at("b").await?;
+        root.remove_directory_at("a").await?;
+        assert_eq!(names(&root).await, Vec::<String>::new());
+        assert_eq!(f.read_at(100, 0).await?, b"he");
+        assert_eq!(code(a.create_directory_at("c").await), ErrorCode::NoEntry);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn limits() -> FsResult<()> {
+        let root = MemoryDir::with_limits(4, 6);
+        let a = create(&root, "a").await?;
+        assert_eq!(
+            code(a.write_at(Bytes::from_static(b"!"), 4).await),
+            ErrorCode::FileTooLarge
+        );
+        a.set_size(4).await?;
+        assert_eq!(
+            code(root.write_file("b", "abc")),
+            ErrorCode::InsufficientMemory
+        );
+        root.write_file("b", "ab")?;
+        assert_eq!(
+            code(a.append(Bytes::from_static(b"!")).await),
+            ErrorCode::FileTooLarge
+        );
+
+        // Space is released once a file's contents are gone.
+        a.set_size(1).await?;
+        root.write_file("b", "abc")?;
+        root.unlink_file_at("b").await?;
+        drop(a);
+        root.unlink_file_at("a").await?;
+        root.write_file("c", "abcd")?;
+        Ok(())
+    }
+}
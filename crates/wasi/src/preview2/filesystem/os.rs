@@ -0,0 +1,588 @@
+//! The default [`WasiDir`] and [`WasiFile`] implementations, backed by the
+//! host's filesystem through `cap_std`.
+
+use super::{FsResult, OpenResult, WasiDir, WasiFile};
+use crate::preview2::bindings::clocks::wall_clock;
+use crate::preview2::bindings::filesystem::types::{self, ErrorCode};
+use crate::preview2::spawn_blocking;
+use bytes::Bytes;
+use std::any::Any;
+use std::sync::Arc;
+
+impl From<cap_std::fs::Dir> for Box<dyn WasiDir> {
+    fn from(dir: cap_std::fs::Dir) -> Self {
+        Box::new(OsDir(Arc::new(dir)))
+    }
+}
+
+impl From<cap_std::fs::File> for Box<dyn WasiFile> {
+    fn from(file: cap_std::fs::File) -> Self {
+        Box::new(OsFile(Arc::new(file)))
+    }
+}
+
+pub(super) struct OsDir(pub(super) Arc<cap_std::fs::Dir>);
+
+impl OsDir {
+    /// Spawn a task on tokio's blocking thread for performing blocking
+    /// syscalls on the underlying [`cap_std::fs::Dir`].
+    async fn spawn_blocking<F, R>(&self, body: F) -> R
+    where
+        F: FnOnce(&cap_std::fs::Dir) -> R + Send + 'static,
+        R: Send + 'static,
+    {
+        let d = self.0.clone();
+        spawn_blocking(move || body(&d)).await
+    }
+
+    /// Returns the host directory of `dir`, which must also be an [`OsDir`].
+    fn same_fs(dir: &dyn WasiDir) -> FsResult<Arc<cap_std::fs::Dir>> {
+        match dir.as_any().downcast_ref::<OsDir>() {
+            Some(dir) => Ok(dir.0.clone()),
+            None => Err(ErrorCode::CrossDevice.into()),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl WasiDir for OsDir {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    async fn open_at(
+        &self,
+        symlink_follow: bool,
+        path: &str,
+        oflags: types::OpenFlags,
+        flags: types::DescriptorFlags,
+    ) -> FsResult<OpenResult> {
+        use cap_fs_ext::{FollowSymlinks, OpenOptionsFollowExt, OpenOptionsMaybeDirExt};
+        use system_interface::fs::{FdFlags, GetSetFdFlags};
+        use types::{DescriptorFlags, OpenFlags};
+
+        let mut opts = cap_std::fs::OpenOptions::new();
+        opts.maybe_dir(true);
+
+        if oflags.contains(OpenFlags::CREATE | OpenFlags::EXCLUSIVE) {
+            opts.create_new(true);
+            opts.write(true);
+        } else if oflags.contains(OpenFlags::CREATE) {
+            opts.create(true);
+            opts.write(true);
+        }
+        if oflags.contains(OpenFlags::TRUNCATE) {
+            opts.truncate(true);
+        }
+        if flags.contains(DescriptorFlags::READ) {
+            opts.read(true);
+        }
+        if flags.contains(DescriptorFlags::WRITE) {
+            opts.write(true);
+        } else {
+            // If not opened write, open read. This way the OS lets us open
+            // the file, but we can use perms to reject use of the file later.
+            opts.read(true);
+        }
+        if symlink_follow {
+            opts.follow(FollowSymlinks::Yes);
+        } else {
+            opts.follow(FollowSymlinks::No);
+        }
+
+        // Represents each possible outcome from the spawn_blocking operation.
+        enum Opened {
+            Dir(cap_std::fs::Dir),
+            File(cap_std::fs::File),
+            NotDir,
+        }
+
+        let path = path.to_owned();
+        let opened = self
+            .spawn_blocking::<_, std::io::Result<Opened>>(move |d| {
+                let mut opened = d.open_with(&path, &opts)?;
+                if opened.metadata()?.is_dir() {
+                    Ok(Opened::Dir(cap_std::fs::Dir::from_std_file(
+                        opened.into_std(),
+                    )))
+                } else if oflags.contains(OpenFlags::DIRECTORY) {
+                    Ok(Opened::NotDir)
+                } else {
+                    // FIXME cap-std needs a nonblocking open option so that files reads and writes
+                    // are nonblocking. Instead we set it after opening here:
+                    let set_fd_flags = opened.new_set_fd_flags(FdFlags::NONBLOCK)?;
+                    opened.set_fd_flags(set_fd_flags)?;
+                    Ok(Opened::File(opened))
+                }
+            })
+            .await?;
+
+        match opened {
+            Opened::Dir(dir) => Ok(OpenResult::Dir(dir.into())),
+            Opened::File(file) => Ok(OpenResult::File(file.into())),
+            Opened::NotDir => Err(ErrorCode::NotDirectory.into()),
+        }
+    }
+
+    async fn create_directory_at(&self, path: &str) -> FsResult<()> {
+        let path = path.to_owned();
+        self.spawn_blocking(move |d| d.create_dir(&path)).await?;
+        Ok(())
+    }
+
+    async fn read_directory(
+        &self,
+    ) -> FsResult<Box<dyn Iterator<Item = FsResult<types::DirectoryEntry>> + Send>> {
+        enum ReaddirError {
+            Io(std::io::Error),
+            IllegalSequence,
+        }
+        impl From<std::io::Error> for ReaddirError {
+            fn from(e: std::io::Error) -> ReaddirError {
+                ReaddirError::Io(e)
+            }
+        }
+
+        let entries = self
+            .spawn_blocking(|d| {
+                // Both `entries` and `metadata` perform syscalls, which is why they are done
+                // within this `block` call, rather than delay calculating the metadata
+                // for entries when they're demanded later in the iterator chain.
+                Ok::<_, std::io::Error>(
+                    d.entries()?
+                        .map(|entry| {
+                            let entry = entry?;
+                            let meta = entry.metadata()?;
+                            let type_ = descriptortype_from(meta.file_type());
+                            let name = entry
+                                .file_name()
+                                .into_string()
+                                .map_err(|_| ReaddirError::IllegalSequence)?;
+                            Ok(types::DirectoryEntry { type_, name })
+                        })
+                        .collect::<Vec<Result<types::DirectoryEntry, ReaddirError>>>(),
+                )
+            })
+            .await?
+            .into_iter();
+
+        // On windows, filter out files like `C:\DumpStack.log.tmp` which we
+        // can't get full metadata for.
+        #[cfg(windows)]
+        let entries = entries.filter(|entry| {
+            use windows_sys::Win32::Foundation::{ERROR_ACCESS_DENIED, ERROR_SHARING_VIOLATION};
+            if let Err(ReaddirError::Io(err)) = entry {
+                if err.raw_os_error() == Some(ERROR_SHARING_VIOLATION as i32)
+                    || err.raw_os_error() == Some(ERROR_ACCESS_DENIED as i32)
+                {
+                    return false;
+                }
+            }
+            true
+        });
+        let entries = entries.map(|r| match r {
+            Ok(r) => Ok(r),
+            Err(ReaddirError::Io(e)) => Err(e.into()),
+            Err(ReaddirError::IllegalSequence) => Err(ErrorCode::IllegalByteSequence.into()),
+        });
+        Ok(Box::new(entries))
+    }
+
+    async fn stat(&self) -> FsResult<types::DescriptorStat> {
+        let meta = self.spawn_blocking(|d| d.dir_metadata()).await?;
+        Ok(descriptorstat_from(meta))
+    }
+
+    async fn stat_at(&self, symlink_follow: bool, path: &str) -> FsResult<types::DescriptorStat> {
+        let path = path.to_owned();
+        let meta = if symlink_follow {
+            self.spawn_blocking(move |d| d.metadata(&path)).await?
+        } else {
+            self.spawn_blocking(move |d| d.symlink_metadata(&path))
+                .await?
+        };
+        Ok(descriptorstat_from(meta))
+    }
+
+    async fn metadata_hash(&self) -> FsResult<types::MetadataHashValue> {
+        let meta = self.spawn_blocking(|d| d.dir_metadata()).await?;
+        Ok(calculate_metadata_hash(&meta))
+    }
+
+    async fn metadata_hash_at(
+        &self,
+        symlink_follow: bool,
+        path: &str,
+    ) -> FsResult<types::MetadataHashValue> {
+        let path = path.to_owned();
+        let meta = self
+            .spawn_blocking(move |d| {
+                if symlink_follow {
+                    d.metadata(path)
+                } else {
+                    d.symlink_metadata(path)
+                }
+            })
+            .await?;
+        Ok(calculate_metadata_hash(&meta))
+    }
+
+    async fn remove_directory_at(&self, path: &str) -> FsResult<()> {
+        let path = path.to_owned();
+        Ok(self.spawn_blocking(move |d| d.remove_dir(&path)).await?)
+    }
+
+    async fn unlink_file_at(&self, path: &str) -> FsResult<()> {
+        use cap_fs_ext::DirExt;
+
+        let path = path.to_owned();
+        Ok(self
+            .spawn_blocking(move |d| d.remove_file_or_symlink(&path))
+            .await?)
+    }
+
+    async fn rename_at(
+        &self,
+        old_path: &str,
+        new_dir: &dyn WasiDir,
+        new_path: &str,
+    ) -> FsResult<()> {
+        let new_dir = OsDir::same_fs(new_dir)?;
+        let old_path = old_path.to_owned();
+        let new_path = new_path.to_owned();
+        Ok(self
+            .spawn_blocking(move |d| d.rename(&old_path, &new_dir, &new_path))
+            .await?)
+    }
+
+    async fn link_at(&self, old_path: &str, new_dir: &dyn WasiDir, new_path: &str) -> FsResult<()> {
+        let new_dir = OsDir::same_fs(new_dir)?;
+        let old_path = old_path.to_owned();
+        let new_path = new_path.to_owned();
+        self.spawn_blocking(move |d| d.hard_link(&old_path, &new_dir, &new_path))
+            .await?;
+        Ok(())
+    }
+
+    async fn symlink_at(&self, src_path: &str, dest_path: &str) -> FsResult<()> {
+        // On windows, Dir.symlink is provided by DirExt
+        #[cfg(windows)]
+        use cap_fs_ext::DirExt;
+
+        let src_path = src_path.to_owned();
+        let dest_path = dest_path.to_owned();
+        Ok(self
+            .spawn_blocking(move |d| d.symlink(&src_path, &dest_path))
+            .await?)
+    }
+
+    async fn readlink_at(&self, path: &str) -> FsResult<String> {
+        let path = path.to_owned();
+        let link = self.spawn_blocking(move |d| d.read_link(&path)).await?;
+        Ok(link
+            .into_os_string()
+            .into_string()
+            .map_err(|_| ErrorCode::IllegalByteSequence)?)
+    }
+
+    async fn set_times(
+        &self,
+        atim: types::NewTimestamp,
+        mtim: types::NewTimestamp,
+    ) -> FsResult<()> {
+        use fs_set_times::SetTimes;
+
+        let atim = systemtimespec_from(atim)?;
+        let mtim = systemtimespec_from(mtim)?;
+        self.spawn_blocking(|d| d.set_times(atim, mtim)).await?;
+        Ok(())
+    }
+
+    async fn set_times_at(
+        &self,
+        symlink_follow: bool,
+        path: &str,
+        atim: types::NewTimestamp,
+        mtim: types::NewTimestamp,
+    ) -> FsResult<()> {
+        use cap_fs_ext::DirExt;
+
+        let path = path.to_owned();
+        let atim = systemtimespec_from(atim)?;
+        let mtim = systemtimespec_from(mtim)?;
+        if symlink_follow {
+            self.spawn_blocking(move |d| {
+                d.set_times(
+                    &path,
+                    atim.map(cap_fs_ext::SystemTimeSpec::from_std),
+                    mtim.map(cap_fs_ext::SystemTimeSpec::from_std),
+                )
+            })
+            .await?;
+        } else {
+            self.spawn_blocking(move |d| {
+                d.set_symlink_times(
+                    &path,
+                    atim.map(cap_fs_ext::SystemTimeSpec::from_std),
+                    mtim.map(cap_fs_ext::SystemTimeSpec::from_std),
+                )
+            })
+            .await?;
+        }
+        Ok(())
+    }
+
+    async fn get_flags(&self) -> FsResult<types::DescriptorFlags> {
+        use system_interface::fs::GetSetFdFlags;
+
+        let flags = self.spawn_blocking(|d| d.get_fd_flags()).await?;
+        Ok(get_from_fdflags(flags))
+    }
+
+    async fn sync(&self) -> FsResult<()> {
+        self.spawn_blocking(|d| Ok(d.open(std::path::Component::CurDir)?.sync_all()?))
+            .await
+    }
+
+    async fn sync_data(&self) -> FsResult<()> {
+        self.spawn_blocking(|d| Ok(d.open(std::path::Component::CurDir)?.sync_data()?))
+            .await
+    }
+}
+
+pub(super) struct OsFile(pub(super) Arc<cap_std::fs::File>);
+
+impl OsFile {
+    /// Spawn a task on tokio's blocking thread for performing blocking
+    /// syscalls on the underlying [`cap_std::fs::File`].
+    async fn spawn_blocking<F, R>(&self, body: F) -> R
+    where
+        F: FnOnce(&cap_std::fs::File) -> R + Send + 'static,
+        R: Send + 'static,
+    {
+        let f = self.0.clone();
+        spawn_blocking(move || body(&f)).await
+    }
+}
+
+#[async_trait::async_trait]
+impl WasiFile for OsFile {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    async fn stat(&self) -> FsResult<types::DescriptorStat> {
+        let meta = self.spawn_blocking(|f| f.metadata()).await?;
+        Ok(descriptorstat_from(meta))
+    }
+
+    async fn metadata_hash(&self) -> FsResult<types::MetadataHashValue> {
+        let meta = self.spawn_blocking(|f| f.metadata()).await?;
+        Ok(calculate_metadata_hash(&meta))
+    }
+
+    async fn read_at(&self, len: usize, offset: u64) -> FsResult<Vec<u8>> {
+        use std::io::IoSliceMut;
+        use system_interface::fs::FileIoExt;
+
+        let (mut buffer, r) = self
+            .spawn_blocking(move |f| {
+                let mut buffer = vec![0; len];
+                let r = loop {
+                    match f.read_vectored_at(&mut [IoSliceMut::new(&mut buffer)], offset) {
+                        Err(e) if e.kind() == std::io::ErrorKind::Interrupted => continue,
+                        r => break r,
+                    }
+                };
+                (buffer, r)
+            })
+            .await;
+        buffer.truncate(r?);
+        Ok(buffer)
+    }
+
+    async fn write_at(&self, buf: Bytes, offset: u64) -> FsResult<usize> {
+        use std::io::IoSlice;
+        use system_interface::fs::FileIoExt;
+
+        Ok(self
+            .spawn_blocking(move |f| f.write_vectored_at(&[IoSlice::new(&buf)], offset))
+            .await?)
+    }
+
+    async fn append(&self, buf: Bytes) -> FsResult<usize> {
+        use system_interface::fs::FileIoExt;
+
+        Ok(self.spawn_blocking(move |f| f.append(&buf)).await?)
+    }
+
+    async fn set_size(&self, size: u64) -> FsResult<()> {
+        self.spawn_blocking(move |f| f.set_len(size)).await?;
+        Ok(())
+    }
+
+    async fn set_times(
+        &self,
+        atim: types::NewTimestamp,
+        mtim: types::NewTimestamp,
+    ) -> FsResult<()> {
+        use fs_set_times::SetTimes;
+
+        let atim = systemtimespec_from(atim)?;
+        let mtim = systemtimespec_from(mtim)?;
+        self.spawn_blocking(|f| f.set_times(atim, mtim)).await?;
+        Ok(())
+    }
+
+    async fn advise(&self, offset: u64, len: u64, advice: types::Advice) -> FsResult<()> {
+        use system_interface::fs::{Advice as A, FileIoExt};
+        use types::Advice;
+
+        let advice = match advice {
+            Advice::Normal => A::Normal,
+            Advice::Sequential => A::Sequential,
+            Advice::Random => A::Random,
+            Advice::WillNeed => A::WillNeed,
+            Advice::DontNeed => A::DontNeed,
+            Advice::NoReuse => A::NoReuse,
+        };
+
+        self.spawn_blocking(move |f| f.advise(offset, len, advice))
+            .await?;
+        Ok(())
+    }
+
+    async fn get_flags(&self) -> FsResult<types::DescriptorFlags> {
+        use system_interface::fs::GetSetFdFlags;
+
+        let flags = self.spawn_blocking(|f| f.get_fd_flags()).await?;
+        Ok(get_from_fdflags(flags))
+    }
+
+    async fn sync(&self) -> FsResult<()> {
+        match self.spawn_blocking(|f| f.sync_all()).await {
+            Ok(()) => Ok(()),
+            // On windows, `sync_data` uses `FileFlushBuffers` which fails with
+            // `ERROR_ACCESS_DENIED` if the file is not upen for writing. Ignore
+            // this error, for POSIX compatibility.
+            #[cfg(windows)]
+            Err(e)
+                if e.raw_os_error()
+                    == Some(windows_sys::Win32::Foundation::ERROR_ACCESS_DENIED as _) =>
+            {
+                Ok(())
+            }
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    async fn sync_data(&self) -> FsResult<()> {
+        match self.spawn_blocking(|f| f.sync_data()).await {
+            Ok(()) => Ok(()),
+            // On windows, `sync_data` uses `FileFlushBuffers` which fails with
+            // `ERROR_ACCESS_DENIED` if the file is not upen for writing. Ignore
+            // this error, for POSIX compatibility.
+            #[cfg(windows)]
+            Err(e)
+                if e.raw_os_error()
+                    == Some(windows_sys::Win32::Foundation::ERROR_ACCESS_DENIED as _) =>
+            {
+                Ok(())
+            }
+            Err(e) => Err(e.into()),
+        }
+    }
+}
+
+fn get_from_fdflags(flags: system_interface::fs::FdFlags) -> types::DescriptorFlags {
+    use system_interface::fs::FdFlags;
+    use types::DescriptorFlags;
+
+    let mut out = DescriptorFlags::empty();
+    if flags.contains(FdFlags::DSYNC) {
+        out |= DescriptorFlags::REQUESTED_WRITE_SYNC;
+    }
+    if flags.contains(FdFlags::RSYNC) {
+        out |= DescriptorFlags::DATA_INTEGRITY_SYNC;
+    }
+    if flags.contains(FdFlags::SYNC) {
+        out |= DescriptorFlags::FILE_INTEGRITY_SYNC;
+    }
+    out
+}
+
+fn calculate_metadata_hash(meta: &cap_std::fs::Metadata) -> types::MetadataHashValue {
+    use cap_fs_ext::MetadataExt;
+    // Without incurring any deps, std provides us with a 64 bit hash
+    // function:
+    use std::hash::Hasher;
+    // Note that this means that the metadata hash (which becomes a preview1 ino) may
+    // change when a different rustc release is used to build this host implementation:
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    hasher.write_u64(meta.dev());
+    hasher.write_u64(meta.ino());
+    let lower = hasher.finish();
+    // MetadataHashValue has a pair of 64-bit members for representing a
+    // single 128-bit number. However, we only have 64 bits of entropy. To
+    // synthesize the upper 64 bits, lets xor the lower half with an arbitrary
+    // constant, in this case the 64 bit integer corresponding to the IEEE
+    // double representation of (a number as close as possible to) pi.
+    // This seems better than just repeating the same bits in the upper and
+    // lower parts outright, which could make folks wonder if the struct was
+    // mangled in the ABI, or worse yet, lead to consumers of this interface
+    // expecting them to be equal.
+    let upper = lower ^ 4614256656552045848u64;
+    types::MetadataHashValue { lower, upper }
+}
+
+fn descriptortype_from(ft: cap_std::fs::FileType) -> types::DescriptorType {
+    use cap_fs_ext::FileTypeExt;
+    use types::DescriptorType;
+    if ft.is_dir() {
+        DescriptorType::Directory
+    } else if ft.is_symlink() {
+        DescriptorType::SymbolicLink
+    } else if ft.is_block_device() {
+        DescriptorType::BlockDevice
+    } else if ft.is_char_device() {
+        DescriptorType::CharacterDevice
+    } else if ft.is_file() {
+        DescriptorType::RegularFile
+    } else {
+        DescriptorType::Unknown
+    }
+}
+
+fn systemtimespec_from(t: types::NewTimestamp) -> FsResult<Option<fs_set_times::SystemTimeSpec>> {
+    use fs_set_times::SystemTimeSpec;
+    use types::NewTimestamp;
+    match t {
+        NewTimestamp::NoChange => Ok(None),
+        NewTimestamp::Now => Ok(Some(SystemTimeSpec::SymbolicNow)),
+        NewTimestamp::Timestamp(st) => Ok(Some(SystemTimeSpec::Absolute(systemtime_from(st)?))),
+    }
+}
+
+fn systemtime_from(t: wall_clock::Datetime) -> FsResult<std::time::SystemTime> {
+    use std::time::{Duration, SystemTime};
+    SystemTime::UNIX_EPOCH
+        .checked_add(Duration::new(t.seconds, t.nanoseconds))
+        .ok_or_else(|| ErrorCode::Overflow.into())
+}
+
+fn datetime_from(t: std::time::SystemTime) -> wall_clock::Datetime {
+    // FIXME make this infallible or handle errors properly
+    wall_clock::Datetime::try_from(cap_std::time::SystemTime::from_std(t)).unwrap()
+}
+
+fn descriptorstat_from(meta: cap_std::fs::Metadata) -> types::DescriptorStat {
+    use cap_fs_ext::MetadataExt;
+    types::DescriptorStat {
+        type_: descriptortype_from(meta.file_type()),
+        link_count: meta.nlink(),
+        size: meta.len(),
+        data_access_timestamp: meta.accessed().map(|t| datetime_from(t.into_std())).ok(),
+        data_modification_timestamp: meta.modified().map(|t| datetime_from(t.into_std())).ok(),
+        status_change_timestamp: meta.created().map(|t| datetime_from(t.into_std())).ok(),
+    }
+}
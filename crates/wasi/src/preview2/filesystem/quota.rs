@@ -0,0 +1,590 @@
+//! A wrapper which limits how much data can be written to a directory tree.
+
+use super::{FsResult, OpenResult, WasiDir, WasiFile};
+use crate::preview2::bindings::filesystem::types::{self, ErrorCode};
+use bytes::Bytes;
+use std::any::Any;
+use std::collections::{HashMap, HashSet};
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+
+/// Enforces a storage quota on another [`WasiDir`].
+///
+/// The quota limits the total size of the regular files within the directory
+/// tree, including the files which already exist when the `QuotaDir` is
+/// created. Writes which would exceed it fail with `error-code::quota`, while
+/// truncating or removing files releases the space they used again. A file
+/// which is removed while it's still open keeps using its space until the last
+/// descriptor for it is dropped, since it can still be written to until then.
+///
+/// Combined with a [`MemoryDir`](crate::preview2::MemoryDir) this gives guests
+/// a bounded amount of ephemeral scratch space.
+pub struct QuotaDir {
+    dir: Box<dyn WasiDir>,
+    quota: Arc<Quota>,
+}
+
+impl QuotaDir {
+    /// Limits the size of the files in `dir` to at most `limit` bytes in
+    /// total.
+    ///
+    /// This walks `dir` to find how much of the quota is already used by its
+    /// existing files. Directories which already use more than `limit` bytes
+    /// are accepted, in which case files can only shrink until enough space
+    /// has been released.
+    pub async fn new(dir: impl Into<Box<dyn WasiDir>>, limit: u64) -> FsResult<QuotaDir> {
+        let dir = dir.into();
+        let used = tree_size(&*dir, &mut HashSet::new()).await?;
+        Ok(QuotaDir {
+            dir,
+            quota: Arc::new(Quota {
+                limit,
+                used: AtomicU64::new(used),
+                open: Mutex::new(HashMap::new()),
+            }),
+        })
+    }
+
+    /// Returns how many bytes of the quota are currently used.
+    pub fn used(&self) -> u64 {
+        self.quota.used.load(Ordering::Relaxed)
+    }
+
+    /// Returns the directory wrapped by `dir`, which must share the quota of
+    /// `self`.
+    fn same_quota<'a>(&self, dir: &'a dyn WasiDir) -> FsResult<&'a dyn WasiDir> {
+        match dir.as_any().downcast_ref::<QuotaDir>() {
+            Some(dir) if Arc::ptr_eq(&self.quota, &dir.quota) => Ok(&*dir.dir),
+            _ => Err(ErrorCode::CrossDevice.into()),
+        }
+    }
+}
+
+/// Returns the total size of the regular files in `dir` and its
+/// subdirectories, counting files with several links only once.
+fn tree_size<'a>(
+    dir: &'a dyn WasiDir,
+    linked: &'a mut HashSet<FileId>,
+) -> Pin<Box<dyn Future<Output = FsResult<u64>> + Send + 'a>> {
+    Box::pin(async move {
+        let mut size = 0;
+        let entries = dir.read_directory().await?.collect::<FsResult<Vec<_>>>()?;
+        for entry in entries {
+            match entry.type_ {
+                types::DescriptorType::RegularFile => {
+                    let stat = dir.stat_at(false, &entry.name).await?;
+                    if stat.link_count > 1 {
+                        let id = FileId::from(dir.metadata_hash_at(false, &entry.name).await?);
+                        if !linked.insert(id) {
+                            continue;
+                        }
+                    }
+                    size += stat.size;
+                }
+                types::DescriptorType::Directory => {
+                    let flags = types::DescriptorFlags::READ;
+                    let opened = dir
+                        .open_at(false, &entry.name, types::OpenFlags::DIRECTORY, flags)
+                        .await?;
+                    if let OpenResult::Dir(subdir) = opened {
+                        size += tree_size(&*subdir, linked).await?;
+                    }
+                }
+                _ => {}
+            }
+        }
+        Ok(size)
+    })
+}
+
+/// Returns the identity and size of the regular file at `path` in `dir`, if
+/// removing or replacing that path would release it.
+async fn removed_file(dir: &dyn WasiDir, path: &str) -> Option<(FileId, u64)> {
+    match dir.stat_at(false, path).await {
+        Ok(stat) if stat.type_ == types::DescriptorType::RegularFile && stat.link_count <= 1 => {
+            let id = dir.metadata_hash_at(false, path).await.ok()?;
+            Some((id.into(), stat.size))
+        }
+        _ => None,
+    }
+}
+
+/// Identifies a file by its metadata hash.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+struct FileId(u64, u64);
+
+impl From<types::MetadataHashValue> for FileId {
+    fn from(hash: types::MetadataHashValue) -> Self {
+        FileId(hash.lower, hash.upper)
+    }
+}
+
+struct Quota {
+    limit: u64,
+    used: AtomicU64,
+    /// The files which are currently open through a [`QuotaFile`].
+    open: Mutex<HashMap<FileId, OpenFile>>,
+}
+
+/// A file with at least one open [`QuotaFile`].
+struct OpenFile {
+    handles: usize,
+    /// The size the file was last seen to have.
+    size: u64,
+    /// Whether the file's last link was removed, in which case its space is
+    /// released once the last handle is dropped.
+    removed: bool,
+}
+
+impl Quota {
+    /// Claims `bytes` of the quota, failing if there isn't enough left.
+    fn reserve(&self, bytes: u64) -> FsResult<()> {
+        self.used
+            .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |used| {
+                used.checked_add(bytes).filter(|used| *used <= self.limit)
+            })
+            .map_err(|_| ErrorCode::Quota)?;
+        Ok(())
+    }
+
+    fn release(&self, bytes: u64) {
+        let _ = self
+            .used
+            .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |used| {
+                Some(used.saturating_sub(bytes))
+            });
+    }
+
+    /// Releases the space of a file whose last link was removed, unless it's
+    /// still open.
+    fn remove(&self, (id, size): (FileId, u64)) {
+        match self.open.lock().unwrap().get_mut(&id) {
+            Some(file) => file.removed = true,
+            None => self.release(size),
+        }
+    }
+
+    /// Records that the file `id` now has `size` bytes.
+    fn resize(&self, id: FileId, size: u64) {
+        if let Some(file) = self.open.lock().unwrap().get_mut(&id) {
+            file.size = size;
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl WasiDir for QuotaDir {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    async fn open_at(
+        &self,
+        symlink_follow: bool,
+        path: &str,
+        oflags: types::OpenFlags,
+        flags: types::DescriptorFlags,
+    ) -> FsResult<OpenResult> {
+        let truncated = if oflags.contains(types::OpenFlags::TRUNCATE) {
+            match self.dir.stat_at(symlink_follow, path).await {
+                Ok(stat) if stat.type_ == types::DescriptorType::RegularFile => stat.size,
+                _ => 0,
+            }
+        } else {
+            0
+        };
+        let opened = self
+            .dir
+            .open_at(symlink_follow, path, oflags, flags)
+            .await?;
+        self.quota.release(truncated);
+        Ok(match opened {
+            OpenResult::File(file) => {
+                OpenResult::File(Box::new(QuotaFile::new(file, self.quota.clone()).await?))
+            }
+            OpenResult::Dir(dir) => OpenResult::Dir(Box::new(QuotaDir {
+                dir,
+                quota: self.quota.clone(),
+            })),
+        })
+    }
+
+    async fn create_directory_at(&self, path: &str) -> FsResult<()> {
+        self.dir.create_directory_at(path).await
+    }
+
+    async fn read_directory(
+        &self,
+    ) -> FsResult<Box<dyn Iterator<Item = FsResult<types::DirectoryEntry>> + Send>> {
+        self.dir.read_directory().await
+    }
+
+    async fn stat(&self) -> FsResult<types::DescriptorStat> {
+        self.dir.stat().await
+    }
+
+    async fn stat_at(&self, symlink_follow: bool, path: &str) -> FsResult<types::DescriptorStat> {
+        self.dir.stat_at(symlink_follow, path).await
+    }
+
+    async fn metadata_hash(&self) -> FsResult<types::MetadataHashValue> {
+        self.dir.metadata_hash().await
+    }
+
+    async fn metadata_hash_at(
+        &self,
+        symlink_follow: bool,
+        path: &str,
+    ) -> FsResult<types::MetadataHashValue> {
+        self.dir.metadata_hash_at(symlink_follow, path).await
+    }
+
+    async fn remove_directory_at(&self, path: &str) -> FsResult<()> {
+        self.dir.remove_directory_at(path).await
+    }
+
+    async fn unlink_file_at(&self, path: &str) -> FsResult<()> {
+        let removed = removed_file(&*self.dir, path).await;
+        self.dir.unlink_file_at(path).await?;
+        if let Some(removed) = removed {
+            self.quota.remove(removed);
+        }
+        Ok(())
+    }
+
+    async fn rename_at(
+        &self,
+        old_path: &str,
+        new_dir: &dyn WasiDir,
+        new_path: &str,
+    ) -> FsResult<()> {
+        let new_dir = self.same_quota(new_dir)?;
+        let replaced = removed_file(new_dir, new_path).await;
+        // Renaming a file over itself doesn't remove anything.
+        let renamed = self.dir.metadata_hash_at(false, old_path).await.ok();
+        let replaced = replaced.filter(|(id, _)| renamed.map(FileId::from) != Some(*id));
+        self.dir.rename_at(old_path, new_dir, new_path).await?;
+        if let Some(replaced) = replaced {
+            self.quota.remove(replaced);
+        }
+        Ok(())
+    }
+
+    async fn link_at(&self, old_path: &str, new_dir: &dyn WasiDir, new_path: &str) -> FsResult<()> {
+        let new_dir = self.same_quota(new_dir)?;
+        self.dir.link_at(old_path, new_dir, new_path).await
+    }
+
+    async fn symlink_at(&self, src_path: &str, dest_path: &str) -> FsResult<()> {
+        self.dir.symlink_at(src_path, dest_path).await
+    }
+
+    async fn readlink_at(&self, path: &str) -> FsResult<String> {
+        self.dir.readlink_at(path).await
+    }
+
+    async fn set_times(
+        &self,
+        atim: types::NewTimestamp,
+        mtim: types::NewTimestamp,
+    ) -> FsResult<()> {
+        self.dir.set_times(atim, mtim).await
+    }
+
+    async fn set_times_at(
+        &self,
+        symlink_follow: bool,
+        path: &str,
+        atim: types::NewTimestamp,
+        mtim: types::NewTimestamp,
+    ) -> FsResult<()> {
+        self.dir
+            .set_times_at(symlink_follow, path, atim, mtim)
+            .await
+    }
+
+    async fn get_flags(&self) -> FsResult<types::DescriptorFlags> {
+        self.dir.get_flags().await
+    }
+
+    async fn sync(&self) -> FsResult<()> {
+        self.dir.sync().await
+    }
+
+    async fn sync_data(&self) -> FsResult<()> {
+        self.dir.sync_data().await
+    }
+}
+
+/// A file opened through a [`QuotaDir`].
+struct QuotaFile {
+    file: Box<dyn WasiFile>,
+    id: FileId,
+    quota: Arc<Quota>,
+}
+
+impl QuotaFile {
+    async fn new(file: Box<dyn WasiFile>, quota: Arc<Quota>) -> FsResult<QuotaFile> {
+        let id = FileId::from(file.metadata_hash().await?);
+        let size = file.stat().await?.size;
+        quota
+            .open
+            .lock()
+            .unwrap()
+            .entry(id)
+            .and_modify(|file| {
+                file.handles += 1;
+                file.size = size;
+            })
+            .or_insert(OpenFile {
+                handles: 1,
+                size,
+                removed: false,
+            });
+        Ok(QuotaFile { file, id, quota })
+    }
+
+    /// Runs `write`, which extends the file to at most `end` bytes, while
+    /// holding enough of the quota for it.
+    async fn grow(
+        &self,
+        end: impl FnOnce(u64) -> FsResult<u64>,
+        write: impl std::future::Future<Output = FsResult<usize>>,
+    ) -> FsResult<usize> {
+        let size = self.file.stat().await?.size;
+        let growth = end(size)?.saturating_sub(size);
+        self.quota.reserve(growth)?;
+        let result = write.await;
+        // Give back whatever wasn't written.
+        let new_size = match self.file.stat().await {
+            Ok(stat) => stat.size,
+            Err(_) => size,
+        };
+        self.quota
+            .release(growth.saturating_sub(new_size.saturating_sub(size)));
+        self.quota.resize(self.id, new_size);
+        result
+    }
+}
+
+impl Drop for QuotaFile {
+    fn drop(&mut self) {
+        let mut open = self.quota.open.lock().unwrap();
+        let file = open.get_mut(&self.id).unwrap();
+        file.handles -= 1;
+        if file.handles == 0 {
+            let file = open.remove(&self.id).unwrap();
+            drop(open);
+            if file.removed {
+                self.quota.release(file.size);
+            }
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl WasiFile for QuotaFile {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    async fn stat(&self) -> FsResult<types::DescriptorStat> {
+        self.file.stat().await
+    }
+
+    async fn metadata_hash(&self) -> FsResult<types::MetadataHashValue> {
+        self.file.metadata_hash().await
+    }
+
+    async fn read_at(&self, len: usize, offset: u64) -> FsResult<Vec<u8>> {
+        self.file.read_at(len, offset).await
+    }
+
+    async fn write_at(&self, buf: Bytes, offset: u64) -> FsResult<usize> {
+        let len = buf.len() as u64;
+        self.grow(
+            |_| Ok(offset.checked_add(len).ok_or(ErrorCode::Overflow)?),
+            self.file.write_at(buf, offset),
+        )
+        .await
+    }
+
+    async fn append(&self, buf: Bytes) -> FsResult<usize> {
+        let len = buf.len() as u64;
+        self.grow(
+            |size| Ok(size.checked_add(len).ok_or(ErrorCode::Overflow)?),
+            self.file.append(buf),
+        )
+        .await
+    }
+
+    async fn set_size(&self, size: u64) -> FsResult<()> {
+        let old_size = self.file.stat().await?.size;
+        if size > old_size {
+            self.grow(|_| Ok(size), async {
+                self.file.set_size(size).await?;
+                Ok(0)
+            })
+            .await?;
+        } else {
+            self.file.set_size(size).await?;
+            self.quota.release(old_size - size);
+            self.quota.resize(self.id, size);
+        }
+        Ok(())
+    }
+
+    async fn set_times(
+        &self,
+        atim: types::NewTimestamp,
+        mtim: types::NewTimestamp,
+    ) -> FsResult<()> {
+        self.file.set_times(atim, mtim).await
+    }
+
+    async fn advise(&self, offset: u64, len: u64, advice: types::Advice) -> FsResult<()> {
+        self.file.advise(offset, len, advice).await
+    }
+
+    async fn get_flags(&self) -> FsResult<types::DescriptorFlags> {
+        self.file.get_flags().await
+    }
+
+    async fn sync(&self) -> FsResult<()> {
+        self.file.sync().await
+    }
+
+    async fn sync_data(&self) -> FsResult<()> {
+        self.file.sync_data().await
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::preview2::filesystem::test_util::*;
+    use crate::preview2::MemoryDir;
+    use types::{DescriptorFlags, OpenFlags};
+
+    #[tokio::test]
+    async fn quota_dir() -> FsResult<()> {
+        let scratch = MemoryDir::new();
+        let dir = QuotaDir::new(scratch.clone(), 10).await?;
+
+        let f = create(&dir, "a.txt").await?;
+        assert_eq!(f.write_at(Bytes::from_static(b"12345678"), 0).await?, 8);
+        // Overwriting existing data doesn't use any more of the quota.
+        assert_eq!(f.write_at(Bytes::from_static(b"abcd"), 4).await?, 4);
+        assert_eq!(dir.used(), 8);
+        assert_eq!(
+            code(f.append(Bytes::from_static(b"xyz")).await),
+            ErrorCode::Quota
+        );
+        assert_eq!(code(f.set_size(11).await), ErrorCode::Quota);
+        assert_eq!(dir.used(), 8);
+
+        let g = create(&dir, "b.txt").await?;
+        assert_eq!(
+            code(g.write_at(Bytes::from_static(b"xyz"), 0).await),
+            ErrorCode::Quota
+        );
+        f.set_size(4).await?;
+        assert_eq!(g.write_at(Bytes::from_static(b"xyz"), 0).await?, 3);
+        assert_eq!(dir.used(), 7);
+        drop((f, g));
+
+        // Removing files, or replacing them through renames and truncation,
+        // releases the space they used.
+        dir.rename_at("b.txt", &dir, "a.txt").await?;
+        assert_eq!(dir.used(), 3);
+        dir.rename_at("a.txt", &dir, "a.txt").await?;
+        assert_eq!(dir.used(), 3);
+        dir.unlink_file_at("a.txt").await?;
+        assert_eq!(dir.used(), 0);
+        assert_eq!(names(&scratch).await, Vec::<String>::new());
+
+        scratch.write_file("c.txt", "0123")?;
+        let flags = DescriptorFlags::WRITE;
+        dir.open_at(false, "c.txt", OpenFlags::TRUNCATE, flags)
+            .await?;
+        assert_eq!(dir.used(), 0);
+        assert_eq!(
+            code(dir.rename_at("c.txt", &scratch, "x.txt").await),
+            ErrorCode::CrossDevice
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn counts_existing_files() -> FsResult<()> {
+        let scratch = MemoryDir::new();
+        scratch.write_file("a.txt", "0123")?;
+        scratch.write_file("d/b.txt", "456")?;
+        scratch.link_at("a.txt", &scratch, "d/link.txt").await?;
+        let dir = QuotaDir::new(scratch.clone(), 10).await?;
+        assert_eq!(dir.used(), 7);
+
+        let f = create(&dir, "c.txt").await?;
+        assert_eq!(
+            code(f.write_at(Bytes::from_static(b"abcd"), 0).await),
+            ErrorCode::Quota
+        );
+        assert_eq!(f.write_at(Bytes::from_static(b"abc"), 0).await?, 3);
+
+        // Removing one of several links doesn't release anything.
+        dir.unlink_file_at("a.txt").await?;
+        assert_eq!(dir.used(), 10);
+        dir.unlink_file_at("d/link.txt").await?;
+        assert_eq!(dir.used(), 6);
+
+        // Directories which are already over their quota only allow shrinking.
+        let dir = QuotaDir::new(scratch.clone(), 2).await?;
+        let f = create(&dir, "c.txt").await?;
+        assert_eq!(
+            code(f.append(Bytes::from_static(b"d")).await),
+            ErrorCode::Quota
+        );
+        f.set_size(1).await?;
+        assert_eq!(dir.used(), 4);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn open_files_keep_their_space() -> FsResult<()> {
+        let scratch = MemoryDir::new();
+        let dir = QuotaDir::new(scratch.clone(), 10).await?;
+
+        let f = create(&dir, "a.txt").await?;
+        assert_eq!(f.write_at(Bytes::from_static(b"0123"), 0).await?, 4);
+        let g = create(&dir, "a.txt").await?;
+        dir.unlink_file_at("a.txt").await?;
+        assert_eq!(dir.used(), 4);
+
+        // The removed file can still grow, and shrinking it doesn't release
+        // its space twice.
+        assert_eq!(f.append(Bytes::from_static(b"45")).await?, 2);
+        assert_eq!(dir.used(), 6);
+        let h = create(&dir, "b.txt").await?;
+        assert_eq!(
+            code(h.write_at(Bytes::from_static(b"6789x"), 0).await),
+            ErrorCode::Quota
+        );
+        g.set_size(1).await?;
+        assert_eq!(dir.used(), 1);
+        drop(f);
+        assert_eq!(dir.used(), 1);
+        drop(g);
+        assert_eq!(dir.used(), 0);
+
+        // The same goes for files which are replaced by a rename.
+        assert_eq!(h.write_at(Bytes::from_static(b"01234"), 0).await?, 5);
+        create(&dir, "c.txt").await?;
+        dir.rename_at("c.txt", &dir, "b.txt").await?;
+        assert_eq!(dir.used(), 5);
+        drop(h);
+        assert_eq!(dir.used(), 0);
+        Ok(())
+    }
+}
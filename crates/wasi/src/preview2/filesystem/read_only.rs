@@ -0,0 +1,249 @@
+//! A wrapper which prevents any changes to a directory tree.
+
+use super::{FsResult, OpenResult, WasiDir, WasiFile};
+use crate::preview2::bindings::filesystem::types::{self, ErrorCode};
+use bytes::Bytes;
+use std::any::Any;
+
+/// A read-only overlay on another [`WasiDir`].
+///
+/// Everything within the directory can be read, but any attempt to change it
+/// fails with `error-code::read-only`, regardless of the [`DirPerms`] and
+/// [`FilePerms`] it was opened with. This is useful to share a directory of
+/// assets, perhaps a [`MemoryDir`], between guests.
+///
+/// [`DirPerms`]: crate::preview2::DirPerms
+/// [`FilePerms`]: crate::preview2::FilePerms
+/// [`MemoryDir`]: crate::preview2::MemoryDir
+pub struct ReadOnlyDir(Box<dyn WasiDir>);
+
+impl ReadOnlyDir {
+    pub fn new(dir: impl Into<Box<dyn WasiDir>>) -> ReadOnlyDir {
+        ReadOnlyDir(dir.into())
+    }
+}
+
+fn read_only<T>() -> FsResult<T> {
+    Err(ErrorCode::ReadOnly.into())
+}
+
+#[async_trait::async_trait]
+impl WasiDir for ReadOnlyDir {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    async fn open_at(
+        &self,
+        symlink_follow: bool,
+        path: &str,
+        oflags: types::OpenFlags,
+        flags: types::DescriptorFlags,
+    ) -> FsResult<OpenResult> {
+        use types::{DescriptorFlags, OpenFlags};
+
+        if oflags.intersects(OpenFlags::CREATE | OpenFlags::TRUNCATE)
+            || flags.intersects(DescriptorFlags::WRITE | DescriptorFlags::MUTATE_DIRECTORY)
+        {
+            return read_only();
+        }
+        Ok(
+            match self.0.open_at(symlink_follow, path, oflags, flags).await? {
+                OpenResult::File(file) => OpenResult::File(Box::new(ReadOnlyFile(file))),
+                OpenResult::Dir(dir) => OpenResult::Dir(Box::new(ReadOnlyDir(dir))),
+            },
+        )
+    }
+
+    async fn create_directory_at(&self, _path: &str) -> FsResult<()> {
+        read_only()
+    }
+
+    async fn read_directory(
+        &self,
+    ) -> FsResult<Box<dyn Iterator<Item = FsResult<types::DirectoryEntry>> + Send>> {
+        self.0.read_directory().await
+    }
+
+    async fn stat(&self) -> FsResult<types::DescriptorStat> {
+        self.0.stat().await
+    }
+
+    async fn stat_at(&self, symlink_follow: bool, path: &str) -> FsResult<types::DescriptorStat> {
+        self.0.stat_at(symlink_follow, path).await
+    }
+
+    async fn metadata_hash(&self) -> FsResult<types::MetadataHashValue> {
+        self.0.metadata_hash().await
+    }
+
+    async fn metadata_hash_at(
+        &self,
+        symlink_follow: bool,
+        path: &str,
+    ) -> FsResult<types::MetadataHashValue> {
+        self.0.metadata_hash_at(symlink_follow, path).await
+    }
+
+    async fn remove_directory_at(&self, _path: &str) -> FsResult<()> {
+        read_only()
+    }
+
+    async fn unlink_file_at(&self, _path: &str) -> FsResult<()> {
+        read_only()
+    }
+
+    async fn rename_at(
+        &self,
+        _old_path: &str,
+        _new_dir: &dyn WasiDir,
+        _new_path: &str,
+    ) -> FsResult<()> {
+        read_only()
+    }
+
+    async fn link_at(
+        &self,
+        _old_path: &str,
+        _new_dir: &dyn WasiDir,
+        _new_path: &str,
+    ) -> FsResult<()> {
+        read_only()
+    }
+
+    async fn symlink_at(&self, _src_path: &str, _dest_path: &str) -> FsResult<()> {
+        read_only()
+    }
+
+    async fn readlink_at(&self, path: &str) -> FsResult<String> {
+        self.0.readlink_at(path).await
+    }
+
+    async fn set_times(
+        &self,
+        _atim: types::NewTimestamp,
+        _mtim: types::NewTimestamp,
+    ) -> FsResult<()> {
+        read_only()
+    }
+
+    async fn set_times_at(
+        &self,
+        _symlink_follow: bool,
+        _path: &str,
+        _atim: types::NewTimestamp,
+        _mtim: types::NewTimestamp,
+    ) -> FsResult<()> {
+        read_only()
+    }
+
+    async fn get_flags(&self) -> FsResult<types::DescriptorFlags> {
+        self.0.get_flags().await
+    }
+
+    async fn sync(&self) -> FsResult<()> {
+        self.0.sync().await
+    }
+
+    async fn sync_data(&self) -> FsResult<()> {
+        self.0.sync_data().await
+    }
+}
+
+/// A file opened through a [`ReadOnlyDir`].
+struct ReadOnlyFile(Box<dyn WasiFile>);
+
+#[async_trait::async_trait]
+impl WasiFile for ReadOnlyFile {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    async fn stat(&self) -> FsResult<types::DescriptorStat> {
+        self.0.stat().await
+    }
+
+    async fn metadata_hash(&self) -> FsResult<types::MetadataHashValue> {
+        self.0.metadata_hash().await
+    }
+
+    async fn read_at(&self, len: usize, offset: u64) -> FsResult<Vec<u8>> {
+        self.0.read_at(len, offset).await
+    }
+
+    async fn write_at(&self, _buf: Bytes, _offset: u64) -> FsResult<usize> {
+        read_only()
+    }
+
+    async fn append(&self, _buf: Bytes) -> FsResult<usize> {
+        read_only()
+    }
+
+    async fn set_size(&self, _size: u64) -> FsResult<()> {
+        read_only()
+    }
+
+    async fn set_times(
+        &self,
+        _atim: types::NewTimestamp,
+        _mtim: types::NewTimestamp,
+    ) -> FsResult<()> {
+        read_only()
+    }
+
+    async fn advise(&self, offset: u64, len: u64, advice: types::Advice) -> FsResult<()> {
+        self.0.advise(offset, len, advice).await
+    }
+
+    async fn get_flags(&self) -> FsResult<types::DescriptorFlags> {
+        self.0.get_flags().await
+    }
+
+    async fn sync(&self) -> FsResult<()> {
+        self.0.sync().await
+    }
+
+    async fn sync_data(&self) -> FsResult<()> {
+        self.0.sync_data().await
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::preview2::filesystem::test_util::*;
+    use crate::preview2::MemoryDir;
+    use types::{DescriptorFlags, OpenFlags};
+
+    #[tokio::test]
+    async fn read_only_dir() -> FsResult<()> {
+        let assets = MemoryDir::new();
+        assets.write_file("index.html", "<html/>")?;
+        let dir = ReadOnlyDir::new(assets.clone());
+
+        let flags = DescriptorFlags::READ;
+        let f = match dir
+            .open_at(false, "index.html", OpenFlags::empty(), flags)
+            .await?
+        {
+            OpenResult::File(f) => f,
+            OpenResult::Dir(_) => unreachable!(),
+        };
+        assert_eq!(f.read_at(100, 0).await?, b"<html/>");
+        assert_eq!(
+            code(f.write_at(Bytes::from_static(b"x"), 0).await),
+            ErrorCode::ReadOnly
+        );
+        assert_eq!(code(create(&dir, "new.txt").await), ErrorCode::ReadOnly);
+        assert_eq!(
+            code(dir.unlink_file_at("index.html").await),
+            ErrorCode::ReadOnly
+        );
+        assert_eq!(
+            code(dir.create_directory_at("d").await),
+            ErrorCode::ReadOnly
+        );
+        assert_eq!(names(&dir).await, ["index.html"]);
+        Ok(())
+    }
+}
@@ -71,6 +71,9 @@ impl<T: WasiView> monotonic_clock::Host for T {
     }
 
     fn subscribe_instant(&mut self, when: Instant) -> anyhow::Result<Resource<Pollable>> {
+        if self.ctx().monotonic_clock.advance_to(when) {
+            return subscribe_to_duration(&mut self.table(), Duration::ZERO);
+        }
         let clock_now = self.ctx().monotonic_clock.now();
         let duration = if when > clock_now {
             Duration::from_nanos(when - clock_now)
@@ -81,6 +84,12 @@ impl<T: WasiView> monotonic_clock::Host for T {
     }
 
     fn subscribe_duration(&mut self, duration: WasiDuration) -> anyhow::Result<Resource<Pollable>> {
+        let clock = &self.ctx().monotonic_clock;
+        if let Some(when) = clock.now().checked_add(duration) {
+            if clock.advance_to(when) {
+                return subscribe_to_duration(&mut self.table(), Duration::ZERO);
+            }
+        }
         subscribe_to_duration(&mut self.table(), Duration::from_nanos(duration))
     }
 }
@@ -40,11 +40,14 @@ mod tcp;
 mod udp;
 mod write_stream;
 
-pub use self::clocks::{HostMonotonicClock, HostWallClock};
+pub use self::clocks::{DeterministicClock, HostMonotonicClock, HostWallClock};
 pub use self::ctx::{WasiCtx, WasiCtxBuilder, WasiView};
 pub use self::error::{I32Exit, TrappableError};
-pub use self::filesystem::{DirPerms, FilePerms, FsError, FsResult};
-pub use self::network::{Network, SocketError, SocketResult};
+pub use self::filesystem::{
+    DirPerms, FilePerms, FsError, FsResult, MemoryDir, OpenResult, QuotaDir, ReadOnlyDir, WasiDir,
+    WasiFile,
+};
+pub use self::network::{Network, SocketAddrUse, SocketError, SocketResult};
 pub use self::poll::{subscribe, ClosureFuture, MakeFuture, Pollable, PollableFuture, Subscribe};
 pub use self::random::{thread_rng, Deterministic};
 pub use self::stdio::{
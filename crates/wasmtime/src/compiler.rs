@@ -312,9 +312,16 @@ impl<'a> CompileInputs<'a> {
                 }
             }
 
-            sigs.extend(translation.module.types.iter().map(|(_, ty)| match ty {
-                ModuleType::Function(ty) => *ty,
-            }));
+            sigs.extend(
+                translation
+                    .module
+                    .types
+                    .iter()
+                    .filter_map(|(_, ty)| match ty {
+                        ModuleType::Function(ty) => Some(*ty),
+                        ModuleType::Struct(_) | ModuleType::Array(_) => None,
+                    }),
+            );
         }
 
         for signature in sigs {
@@ -610,8 +617,9 @@ impl FunctionIndices {
                     .module
                     .types
                     .iter()
-                    .map(|(_, ty)| match ty {
-                        ModuleType::Function(ty) => *ty,
+                    .filter_map(|(_, ty)| match ty {
+                        ModuleType::Function(ty) => Some(*ty),
+                        ModuleType::Struct(_) | ModuleType::Array(_) => None,
                     })
                     .collect::<BTreeSet<_>>();
                 let wasm_to_native_trampolines = unique_and_sorted_sigs
@@ -1,3 +1,5 @@
+use crate::component::matching::InstanceType;
+use crate::component::types;
 use crate::{
     code::CodeObject, code_memory::CodeMemory, instantiate::finish_object,
     signatures::SignatureCollection, Engine, Module, ResourcesRequired,
@@ -332,11 +334,45 @@ impl Component {
         &self.inner.static_modules[idx]
     }
 
+    pub(crate) fn static_modules(&self) -> impl ExactSizeIterator<Item = &Module> + '_ {
+        self.inner.static_modules.values()
+    }
+
+    pub(crate) fn trampoline_locs(
+        &self,
+    ) -> impl Iterator<Item = (TrampolineIndex, &AllCallFunc<FunctionLoc>)> + '_ {
+        self.inner.info.trampolines.iter()
+    }
+
+    pub(crate) fn resource_drop_trampoline_loc(&self) -> Option<&FunctionLoc> {
+        self.inner
+            .info
+            .resource_drop_wasm_to_native_trampoline
+            .as_ref()
+    }
+
     #[inline]
     pub(crate) fn types(&self) -> &Arc<ComponentTypes> {
         self.inner.component_types()
     }
 
+    /// Returns the type of this component, or the names and types of all of
+    /// its imports and exports.
+    ///
+    /// This can be used to inspect a component before instantiating it, for
+    /// example to decide which host interfaces to provide to it. Resources in
+    /// the returned type haven't been instantiated yet, so their
+    /// [`ResourceType`](crate::component::ResourceType)s are placeholders
+    /// specific to this component.
+    pub fn component_type(&self) -> types::Component {
+        let resources = Arc::new(PrimaryMap::new());
+        let instance = InstanceType {
+            types: self.types(),
+            resources: &resources,
+        };
+        types::Component::from(self.inner.info.component.ty, &instance)
+    }
+
     pub(crate) fn signatures(&self) -> &SignatureCollection {
         self.inner.code.signatures()
     }
@@ -74,6 +74,7 @@ impl<T> MaybeUninitExt<T> for MaybeUninit<T> {
 
 mod host;
 mod options;
+mod record;
 mod typed;
 pub use self::host::*;
 pub use self::options::*;
@@ -89,6 +90,7 @@ union ParamsAndResults<Params: Copy, Return: Copy> {
 //
 // FIXME: write more docs here
 #[derive(Copy, Clone, Debug)]
+#[repr(transparent)] // here for the C API
 pub struct Func(Stored<FuncData>);
 
 #[doc(hidden)]
@@ -1,7 +1,9 @@
+use crate::component::func::record::ComponentCall;
 use crate::component::func::{LiftContext, LowerContext, Options};
 use crate::component::matching::InstanceType;
 use crate::component::storage::slice_to_storage_mut;
-use crate::component::{ComponentNamedList, ComponentType, Lift, Lower, Type, Val};
+use crate::component::{ComponentNamedList, ComponentType, Func, Lift, Lower, Type, Val};
+use crate::store::{StoreId, StoreOpaque};
 use crate::{AsContextMut, StoreContextMut, ValRaw};
 use anyhow::{anyhow, bail, Context, Result};
 use std::any::Any;
@@ -10,7 +12,7 @@ use std::panic::{self, AssertUnwindSafe};
 use std::ptr::NonNull;
 use std::sync::Arc;
 use wasmtime_environ::component::{
-    CanonicalAbiInfo, ComponentTypes, InterfaceType, StringEncoding, TypeFuncIndex,
+    CanonicalAbiInfo, ComponentTypes, InterfaceType, StringEncoding, TypeFuncIndex, TypeTupleIndex,
     MAX_FLAT_PARAMS, MAX_FLAT_RESULTS,
 };
 use wasmtime_runtime::component::{
@@ -77,25 +79,86 @@ impl HostFunc {
         types: &Arc<ComponentTypes>,
     ) -> Arc<HostFunc>
     where
-        F: Fn(StoreContextMut<'_, T>, &[Val], &mut [Val]) -> Result<()> + Send + Sync + 'static,
+        F: Fn(StoreContextMut<'_, T>, &[Val], &[Type], &mut [Val]) -> Result<()>
+            + Send
+            + Sync
+            + 'static,
+    {
+        let types = types.clone();
+        Self::dynamic(func, move |expected_index, expected_types| {
+            if index == expected_index && std::ptr::eq(&*types, &**expected_types.types) {
+                Ok(())
+            } else {
+                Err(anyhow!("function type mismatch"))
+            }
+        })
+    }
+
+    fn dynamic<T, F>(
+        func: F,
+        typecheck: impl Fn(TypeFuncIndex, &InstanceType<'_>) -> Result<()> + Send + Sync + 'static,
+    ) -> Arc<HostFunc>
+    where
+        F: Fn(StoreContextMut<'_, T>, &[Val], &[Type], &mut [Val]) -> Result<()>
+            + Send
+            + Sync
+            + 'static,
     {
         Arc::new(HostFunc {
             entrypoint: dynamic_entrypoint::<T, F>,
-            typecheck: Box::new({
-                let types = types.clone();
-
-                move |expected_index, expected_types| {
-                    if index == expected_index && std::ptr::eq(&*types, &**expected_types.types) {
-                        Ok(())
-                    } else {
-                        Err(anyhow!("function type mismatch"))
-                    }
-                }
-            }),
+            typecheck: Box::new(typecheck),
             func: Box::new(func),
         })
     }
 
+    /// Creates a host function which forwards calls to `func`, a function
+    /// exported from another component instance whose parameters and results
+    /// have the types `params` and `results`.
+    ///
+    /// The returned function type-checks against any imported function with
+    /// the same parameter and result types, where resources are compared as
+    /// seen by the importing instance. Calling it within any store other than
+    /// `store_id`, which owns `func`, fails.
+    pub(crate) fn from_guest_func<T>(
+        func: Func,
+        store_id: StoreId,
+        params: Box<[Type]>,
+        results: Box<[Type]>,
+    ) -> Arc<HostFunc> {
+        let call = move |mut store: StoreContextMut<'_, T>,
+                         args: &[Val],
+                         _: &[Type],
+                         results: &mut [Val]| {
+            check_linked_store(store.0, store_id)?;
+            func.call(&mut store, args, results)?;
+            func.post_return(&mut store)?;
+
+            // Borrowed resources were lowered into the host's table for the
+            // duration of this call, so they need to be released again
+            // before returning to the caller.
+            for arg in args {
+                release_borrows(&mut store, arg)?;
+            }
+            Ok(())
+        };
+        Self::dynamic(call, move |expected_index, expected_types| {
+            let ty = &expected_types.types[expected_index];
+            let matches = |expected: TypeTupleIndex, actual: &[Type]| {
+                let expected = &expected_types.types[expected].types;
+                expected.len() == actual.len()
+                    && expected
+                        .iter()
+                        .zip(actual)
+                        .all(|(ty, actual)| Type::from(ty, expected_types) == *actual)
+            };
+            if matches(ty.params, &params) && matches(ty.results, &results) {
+                Ok(())
+            } else {
+                Err(anyhow!("function type mismatch"))
+            }
+        })
+    }
+
     pub fn typecheck(&self, ty: TypeFuncIndex, types: &InstanceType<'_>) -> Result<()> {
         (self.typecheck)(ty, types)
     }
@@ -194,6 +257,9 @@ where
     }
 
     let types = (*instance).component_types();
+    let call = ComponentCall::begin(cx.0, types, ty, storage)?;
+    let raw_storage: *mut [MaybeUninit<ValRaw>] = storage;
+    let ty_index = ty;
     let ty = &types[ty];
     let param_tys = InterfaceType::Tuple(ty.params);
     let result_tys = InterfaceType::Tuple(ty.results);
@@ -209,30 +275,45 @@ where
     // branch, but today is not that day.
     let mut storage: Storage<'_, Params, Return> = if Params::flatten_count() <= MAX_FLAT_PARAMS {
         if Return::flatten_count() <= MAX_FLAT_RESULTS {
-            Storage::Direct(slice_to_storage_mut(storage))
+            Storage::Direct(slice_to_storage_mut(&mut *raw_storage))
         } else {
-            Storage::ResultsIndirect(slice_to_storage_mut(storage).assume_init_ref())
+            Storage::ResultsIndirect(slice_to_storage_mut(&mut *raw_storage).assume_init_ref())
         }
     } else {
         if Return::flatten_count() <= MAX_FLAT_RESULTS {
-            Storage::ParamsIndirect(slice_to_storage_mut(storage))
+            Storage::ParamsIndirect(slice_to_storage_mut(&mut *raw_storage))
         } else {
-            Storage::Indirect(slice_to_storage_mut(storage).assume_init_ref())
+            Storage::Indirect(slice_to_storage_mut(&mut *raw_storage).assume_init_ref())
         }
     };
     let mut lift = LiftContext::new(cx.0, &options, types, instance);
     lift.enter_call();
     let params = storage.lift_params(&mut lift, param_tys)?;
 
-    let ret = closure(cx.as_context_mut(), params)?;
-    flags.set_may_leave(false);
-    let mut lower = LowerContext::new(cx, &options, types, instance);
-    storage.lower_results(&mut lower, result_tys, ret)?;
-    flags.set_may_leave(true);
+    // A replayed call still lifts its parameters, which may transfer resources
+    // out of the guest, but then lowers the recorded results instead of
+    // calling the host.
+    if call.is_replay() {
+        flags.set_may_leave(false);
+        let mut lower = LowerContext::new(cx, &options, types, instance);
+        call.replay(&mut lower, ty_index, &mut *raw_storage)?;
+        flags.set_may_leave(true);
+        return lower.exit_call();
+    }
+
+    let result = (|| {
+        let ret = closure(cx.as_context_mut(), params)?;
+        flags.set_may_leave(false);
+        call.begin_lowering(cx.0);
+        let mut lower = LowerContext::new(cx.as_context_mut(), &options, types, instance);
+        storage.lower_results(&mut lower, result_tys, ret)?;
+        flags.set_may_leave(true);
 
-    lower.exit_call()?;
+        lower.exit_call()
+    })();
+    call.finish(cx.0, &options, types, ty_index, &*raw_storage, &result);
 
-    return Ok(());
+    return result;
 
     enum Storage<'a, P: ComponentType, R: ComponentType> {
         Direct(&'a mut MaybeUninit<ReturnStack<P::Lower, R::Lower>>),
@@ -320,7 +401,7 @@ unsafe fn call_host_dynamic<T, F>(
     closure: F,
 ) -> Result<()>
 where
-    F: FnOnce(StoreContextMut<'_, T>, &[Val], &mut [Val]) -> Result<()>,
+    F: FnOnce(StoreContextMut<'_, T>, &[Val], &[Type], &mut [Val]) -> Result<()>,
 {
     let cx = VMComponentContext::from_opaque(cx);
     let instance = (*cx).instance();
@@ -340,9 +421,11 @@ where
         bail!("cannot leave component instance");
     }
 
+    let call = ComponentCall::begin(store.0, types, ty, storage)?;
     let args;
     let ret_index;
 
+    let ty_index = ty;
     let func_ty = &types[ty];
     let param_tys = &types[func_ty.params];
     let result_tys = &types[func_ty.results];
@@ -375,38 +458,115 @@ where
         ret_index = 1;
     };
 
-    let mut result_vals = Vec::with_capacity(result_tys.types.len());
-    for _ in result_tys.types.iter() {
-        result_vals.push(Val::Bool(false));
+    // See `call_host` for how replayed calls are handled.
+    if call.is_replay() {
+        flags.set_may_leave(false);
+        let mut cx = LowerContext::new(store, &options, types, instance);
+        call.replay(&mut cx, ty_index, storage)?;
+        flags.set_may_leave(true);
+        return cx.exit_call();
     }
-    closure(store.as_context_mut(), &args, &mut result_vals)?;
-    flags.set_may_leave(false);
 
-    let mut cx = LowerContext::new(store, &options, types, instance);
-    let instance = cx.instance_type();
-    for (val, ty) in result_vals.iter().zip(result_tys.types.iter()) {
-        Type::from(ty, &instance).is_supertype_of(val)?;
-    }
-    if let Some(cnt) = result_tys.abi.flat_count(MAX_FLAT_RESULTS) {
-        let mut dst = storage[..cnt].iter_mut();
-        for (val, ty) in result_vals.iter().zip(result_tys.types.iter()) {
-            val.lower(&mut cx, *ty, &mut dst)?;
+    let result_types = {
+        let instance = cx.instance_type();
+        result_tys
+            .types
+            .iter()
+            .map(|ty| Type::from(ty, &instance))
+            .collect::<Box<[_]>>()
+    };
+    let result = (|| {
+        let mut result_vals = Vec::with_capacity(result_tys.types.len());
+        for _ in result_tys.types.iter() {
+            result_vals.push(Val::Bool(false));
         }
-        assert!(dst.next().is_none());
-    } else {
-        let ret_ptr = storage[ret_index].assume_init_ref();
-        let mut ptr = validate_inbounds_dynamic(&result_tys.abi, cx.as_slice_mut(), ret_ptr)?;
-        for (val, ty) in result_vals.iter().zip(result_tys.types.iter()) {
-            let offset = types.canonical_abi(ty).next_field32_size(&mut ptr);
-            val.store(&mut cx, *ty, offset)?;
+        closure(
+            store.as_context_mut(),
+            &args,
+            &result_types,
+            &mut result_vals,
+        )?;
+        flags.set_may_leave(false);
+
+        for (val, ty) in result_vals.iter().zip(result_types.iter()) {
+            ty.is_supertype_of(val)?;
+        }
+        call.begin_lowering(store.0);
+        let mut cx = LowerContext::new(store.as_context_mut(), &options, types, instance);
+        if let Some(cnt) = result_tys.abi.flat_count(MAX_FLAT_RESULTS) {
+            let mut dst = storage[..cnt].iter_mut();
+            for (val, ty) in result_vals.iter().zip(result_tys.types.iter()) {
+                val.lower(&mut cx, *ty, &mut dst)?;
+            }
+            assert!(dst.next().is_none());
+        } else {
+            let ret_ptr = storage[ret_index].assume_init_ref();
+            let mut ptr = validate_inbounds_dynamic(&result_tys.abi, cx.as_slice_mut(), ret_ptr)?;
+            for (val, ty) in result_vals.iter().zip(result_tys.types.iter()) {
+                let offset = types.canonical_abi(ty).next_field32_size(&mut ptr);
+                val.store(&mut cx, *ty, offset)?;
+            }
         }
-    }
 
-    flags.set_may_leave(true);
+        flags.set_may_leave(true);
+
+        cx.exit_call()
+    })();
+    call.finish(store.0, &options, types, ty_index, storage, &result);
+
+    return result;
+}
 
-    cx.exit_call()?;
+/// Checks that a definition from
+/// [`LinkerInstance::instance_exports`](crate::component::LinkerInstance::instance_exports),
+/// whose instance lives in the store `id`, is used within that same store.
+pub(crate) fn check_linked_store(store: &StoreOpaque, id: StoreId) -> Result<()> {
+    if store.id() != id {
+        bail!(
+            "cannot use the exports of a component instance within a store \
+             other than the one which owns the instance"
+        );
+    }
+    Ok(())
+}
 
-    return Ok(());
+/// Drops all of the borrowed resources within `val`.
+fn release_borrows<T>(store: &mut StoreContextMut<'_, T>, val: &Val) -> Result<()> {
+    match val {
+        Val::Resource(resource) if !resource.owned() => resource.resource_drop(store)?,
+        Val::List(list) => {
+            for val in list.iter() {
+                release_borrows(store, val)?;
+            }
+        }
+        Val::Record(record) => {
+            for (_, val) in record.fields() {
+                release_borrows(store, val)?;
+            }
+        }
+        Val::Tuple(tuple) => {
+            for val in tuple.values() {
+                release_borrows(store, val)?;
+            }
+        }
+        Val::Variant(variant) => {
+            if let Some(val) = variant.payload() {
+                release_borrows(store, val)?;
+            }
+        }
+        Val::Option(option) => {
+            if let Some(val) = option.value() {
+                release_borrows(store, val)?;
+            }
+        }
+        Val::Result(result) => {
+            if let Ok(Some(val)) | Err(Some(val)) = result.value() {
+                release_borrows(store, val)?;
+            }
+        }
+        _ => {}
+    }
+    Ok(())
 }
 
 fn validate_inbounds_dynamic(abi: &CanonicalAbiInfo, memory: &[u8], ptr: &ValRaw) -> Result<usize> {
@@ -436,7 +596,10 @@ extern "C" fn dynamic_entrypoint<T, F>(
     storage: *mut MaybeUninit<ValRaw>,
     storage_len: usize,
 ) where
-    F: Fn(StoreContextMut<'_, T>, &[Val], &mut [Val]) -> Result<()> + Send + Sync + 'static,
+    F: Fn(StoreContextMut<'_, T>, &[Val], &[Type], &mut [Val]) -> Result<()>
+        + Send
+        + Sync
+        + 'static,
 {
     let data = data as *const F;
     unsafe {
@@ -449,7 +612,9 @@ extern "C" fn dynamic_entrypoint<T, F>(
                 realloc,
                 string_encoding,
                 std::slice::from_raw_parts_mut(storage, storage_len),
-                |store, params, results| (*data)(store, params, results),
+                |store, params, result_types, results| {
+                    (*data)(store, params, result_types, results)
+                },
             )
         })
     }
@@ -1,5 +1,6 @@
 use crate::component::matching::InstanceType;
 use crate::component::ResourceType;
+use crate::record::LowerOp;
 use crate::store::{StoreId, StoreOpaque};
 use crate::StoreContextMut;
 use anyhow::{bail, Result};
@@ -116,6 +117,11 @@ impl Options {
         Ok((result_slice, result))
     }
 
+    /// Returns whether a memory is configured in these options.
+    pub fn has_memory(&self) -> bool {
+        self.memory.is_some()
+    }
+
     /// Asserts that this function has an associated memory attached to it and
     /// then returns the slice of memory tied to the lifetime of the provided
     /// store.
@@ -242,9 +248,20 @@ impl<'a, T> LowerContext<'a, T> {
         old_align: u32,
         new_size: usize,
     ) -> Result<usize> {
-        self.options
-            .realloc(&mut self.store, old, old_size, old_align, new_size)
-            .map(|(_, ptr)| ptr)
+        let (_, ptr) = self
+            .options
+            .realloc(&mut self.store, old, old_size, old_align, new_size)?;
+        self.store
+            .0
+            .host_call_tracer()
+            .record_lowering(LowerOp::Realloc {
+                old: old as u64,
+                old_size: old_size as u64,
+                old_align,
+                new_size: new_size as u64,
+                result: ptr as u64,
+            });
+        Ok(ptr)
     }
 
     /// Returns a fixed mutable slice of memory `N` bytes large starting at
@@ -278,7 +295,15 @@ impl<'a, T> LowerContext<'a, T> {
     ///
     /// The `ty` provided is which table to put this into.
     pub fn guest_resource_lower_own(&mut self, ty: TypeResourceTableIndex, rep: u32) -> u32 {
-        self.resource_tables().resource_lower_own(Some(ty), rep)
+        let idx = self.resource_tables().resource_lower_own(Some(ty), rep);
+        self.store
+            .0
+            .host_call_tracer()
+            .record_lowering(LowerOp::LowerOwn {
+                table: ty.as_u32(),
+                rep,
+            });
+        idx
     }
 
     /// Lowers a `borrow` resource into the guest, converting the `rep` to a
@@ -0,0 +1,277 @@
+//! Recording and replaying of calls to component model imports, see
+//! `crate::record` for more information.
+
+use crate::component::func::{LowerContext, Options};
+use crate::record::{HostCallError, HostCallKind, LowerOp, MemoryChange, RecordedVal, TracedCall};
+use crate::store::StoreOpaque;
+use crate::ValRaw;
+use anyhow::{ensure, Result};
+use std::mem::MaybeUninit;
+use wasmtime_environ::component::{
+    ComponentTypes, InterfaceType, TypeFuncIndex, TypeResourceTableIndex, MAX_FLAT_PARAMS,
+    MAX_FLAT_RESULTS,
+};
+
+/// A call to a component model import, which is recorded or replayed at the
+/// level of the canonical ABI.
+///
+/// Imports can only write to the guest's memory while lowering their results,
+/// either into the return area passed by the guest or into memory allocated
+/// with the guest's `realloc`, so only those ranges of memory are recorded.
+pub(crate) struct ComponentCall {
+    call: TracedCall,
+}
+
+impl ComponentCall {
+    /// Starts a call to an import of type `ty`, whose flattened parameters
+    /// are stored in `storage`.
+    ///
+    /// # Unsafety
+    ///
+    /// The parameters in `storage` must be initialized.
+    pub(crate) unsafe fn begin(
+        store: &mut StoreOpaque,
+        types: &ComponentTypes,
+        ty: TypeFuncIndex,
+        storage: &[MaybeUninit<ValRaw>],
+    ) -> Result<ComponentCall> {
+        let mut call = ComponentCall {
+            call: TracedCall::Untraced,
+        };
+        if !store.host_call_tracer().is_enabled() {
+            return Ok(call);
+        }
+        let (params, _) = flat_widths(types, ty);
+        let params = record_flat(&params, storage);
+        call.call = store
+            .host_call_tracer()
+            .begin(HostCallKind::Component, params)?;
+        Ok(call)
+    }
+
+    /// Returns whether this call is replayed, in which case the host must not
+    /// be called and [`ComponentCall::replay`] lowers the results instead.
+    pub(crate) fn is_replay(&self) -> bool {
+        matches!(self.call, TracedCall::Replay(_))
+    }
+
+    /// Must be called right before the results of the import are lowered.
+    pub(crate) fn begin_lowering(&self, store: &mut StoreOpaque) {
+        if let TracedCall::Record(_) = self.call {
+            store.host_call_tracer().begin_lowering();
+        }
+    }
+
+    /// Replays the lowering of the recorded results into `storage`, or
+    /// returns the recorded error.
+    pub(crate) fn replay<T>(
+        self,
+        cx: &mut LowerContext<'_, T>,
+        ty: TypeFuncIndex,
+        storage: &mut [MaybeUninit<ValRaw>],
+    ) -> Result<()> {
+        let TracedCall::Replay(call) = self.call else {
+            unreachable!()
+        };
+        for op in call.lowering.iter() {
+            match *op {
+                LowerOp::Realloc {
+                    old,
+                    old_size,
+                    old_align,
+                    new_size,
+                    result,
+                } => {
+                    let ptr = cx.realloc(
+                        usize::try_from(old)?,
+                        usize::try_from(old_size)?,
+                        old_align,
+                        usize::try_from(new_size)?,
+                    )?;
+                    ensure!(
+                        ptr as u64 == result,
+                        "replay diverged: `realloc` returned {ptr:#x} instead of {result:#x}"
+                    );
+                }
+                LowerOp::LowerOwn { table, rep } => {
+                    cx.guest_resource_lower_own(TypeResourceTableIndex::from_u32(table), rep);
+                }
+            }
+        }
+        if let Some(change) = call.memories.first() {
+            ensure!(
+                cx.options.has_memory(),
+                "replay diverged: host call wrote to memory which doesn't exist"
+            );
+            change.apply(cx.options.memory_mut(cx.store.0))?;
+        }
+        call.result()?;
+        let (_, results) = flat_widths(cx.types, ty);
+        ensure!(
+            call.results.len() == results.len(),
+            "replay diverged: host call returned a different number of results"
+        );
+        for (slot, val) in storage.iter_mut().zip(call.results.iter()) {
+            *slot = MaybeUninit::new(val.to_raw()?);
+        }
+        Ok(())
+    }
+
+    /// Finishes recording this call, whose flattened results are stored in
+    /// `storage` unless it failed with an error.
+    ///
+    /// # Unsafety
+    ///
+    /// The results in `storage` must be initialized if the call succeeded.
+    pub(crate) unsafe fn finish(
+        self,
+        store: &mut StoreOpaque,
+        options: &Options,
+        types: &ComponentTypes,
+        ty: TypeFuncIndex,
+        storage: &[MaybeUninit<ValRaw>],
+        result: &Result<()>,
+    ) {
+        let TracedCall::Record(params) = self.call else {
+            return;
+        };
+        let (_, widths) = flat_widths(types, ty);
+        let results = match result {
+            Ok(()) => record_flat(&widths, storage),
+            Err(_) => Vec::new(),
+        };
+
+        // The written ranges are the memory returned by `realloc` and, if the
+        // results don't fit in flat values, the return area whose pointer is
+        // the last parameter.
+        let mut written = store
+            .host_call_tracer()
+            .lowering()
+            .iter()
+            .filter_map(|op| match *op {
+                LowerOp::Realloc {
+                    new_size, result, ..
+                } => Some((result as usize, new_size as usize)),
+                LowerOp::LowerOwn { .. } => None,
+            })
+            .collect::<Vec<_>>();
+        let abi = &types[types[ty].results].abi;
+        if abi.flat_count(MAX_FLAT_RESULTS).is_none() {
+            if let Some(RecordedVal::Flat(retptr)) = params.last() {
+                written.push((*retptr as usize, abi.size32 as usize));
+            }
+        }
+        let memories = if options.has_memory() {
+            MemoryChange::ranges(0, options.memory(store), written)
+                .into_iter()
+                .collect()
+        } else {
+            Vec::new()
+        };
+        let error = result
+            .as_ref()
+            .err()
+            .map(|e| HostCallError::new(e, store.host_call_exit_code()));
+        store
+            .host_call_tracer()
+            .finish(HostCallKind::Component, params, results, memories, error);
+    }
+}
+
+/// Records the flattened values in `storage` whose widths are `wide`.
+///
+/// Only the low 32 bits of 32-bit values are recorded since the upper bits
+/// of their storage are unspecified.
+///
+/// # Unsafety
+///
+/// The first `wide.len()` values in `storage` must be initialized.
+unsafe fn record_flat(wide: &[bool], storage: &[MaybeUninit<ValRaw>]) -> Vec<RecordedVal> {
+    wide.iter()
+        .zip(storage)
+        .map(|(wide, val)| {
+            let val = val.assume_init_ref().get_u64();
+            RecordedVal::Flat(if *wide { val } else { val & 0xffff_ffff })
+        })
+        .collect()
+}
+
+/// Returns which of the flattened parameters, including a return pointer,
+/// and flattened results that are passed in the storage of a call to an
+/// import of type `ty` are 64 bits wide.
+fn flat_widths(types: &ComponentTypes, ty: TypeFuncIndex) -> (Vec<bool>, Vec<bool>) {
+    let ty = &types[ty];
+    let mut params = Vec::new();
+    let mut results = Vec::new();
+    for ty in types[ty.params].types.iter() {
+        push_flat_widths(types, ty, &mut params);
+    }
+    for ty in types[ty.results].types.iter() {
+        push_flat_widths(types, ty, &mut results);
+    }
+    if params.len() > MAX_FLAT_PARAMS {
+        params = vec![false];
+    }
+    if results.len() > MAX_FLAT_RESULTS {
+        results.clear();
+        params.push(false);
+    }
+    (params, results)
+}
+
+/// Appends whether each of the core wasm values that `ty` is flattened to
+/// in the canonical ABI is 64 bits wide.
+fn push_flat_widths(types: &ComponentTypes, ty: &InterfaceType, dst: &mut Vec<bool>) {
+    let mut push_variant = |cases: &mut dyn Iterator<Item = Option<&InterfaceType>>| {
+        dst.push(false);
+        let start = dst.len();
+        for ty in cases.flatten() {
+            let mut case = Vec::new();
+            push_flat_widths(types, ty, &mut case);
+            for (i, wide) in case.into_iter().enumerate() {
+                match dst.get_mut(start + i) {
+                    Some(slot) => *slot |= wide,
+                    None => dst.push(wide),
+                }
+            }
+        }
+    };
+    match ty {
+        InterfaceType::Bool
+        | InterfaceType::S8
+        | InterfaceType::U8
+        | InterfaceType::S16
+        | InterfaceType::U16
+        | InterfaceType::S32
+        | InterfaceType::U32
+        | InterfaceType::Float32
+        | InterfaceType::Char
+        | InterfaceType::Enum(_)
+        | InterfaceType::Own(_)
+        | InterfaceType::Borrow(_) => dst.push(false),
+        InterfaceType::S64 | InterfaceType::U64 | InterfaceType::Float64 => dst.push(true),
+        InterfaceType::String | InterfaceType::List(_) => dst.extend([false, false]),
+        InterfaceType::Record(i) => {
+            for field in types[*i].fields.iter() {
+                push_flat_widths(types, &field.ty, dst);
+            }
+        }
+        InterfaceType::Tuple(i) => {
+            for ty in types[*i].types.iter() {
+                push_flat_widths(types, ty, dst);
+            }
+        }
+        InterfaceType::Flags(i) => {
+            let count = (types[*i].names.len() + 31) / 32;
+            dst.extend(std::iter::repeat(false).take(count));
+        }
+        InterfaceType::Variant(i) => {
+            push_variant(&mut types[*i].cases.iter().map(|case| case.ty.as_ref()))
+        }
+        InterfaceType::Option(i) => push_variant(&mut [None, Some(&types[*i].ty)].into_iter()),
+        InterfaceType::Result(i) => {
+            let ty = &types[*i];
+            push_variant(&mut [ty.ok.as_ref(), ty.err.as_ref()].into_iter())
+        }
+    }
+}
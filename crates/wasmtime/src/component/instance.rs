@@ -7,7 +7,7 @@ use crate::instance::OwnedImports;
 use crate::linker::DefinitionType;
 use crate::store::{StoreOpaque, Stored};
 use crate::{AsContextMut, Module, StoreContextMut};
-use anyhow::{anyhow, Context, Result};
+use anyhow::{anyhow, bail, Context, Result};
 use indexmap::IndexMap;
 use std::marker;
 use std::ptr::NonNull;
@@ -25,6 +25,7 @@ use wasmtime_runtime::VMFuncRef;
 //
 // FIXME: need to write more docs here.
 #[derive(Copy, Clone)]
+#[repr(transparent)] // here for the C API
 pub struct Instance(pub(crate) Stored<Option<Box<InstanceData>>>);
 
 pub(crate) struct InstanceData {
@@ -147,6 +148,46 @@ impl Instance {
     }
 }
 
+impl Instance {
+    /// Runs the destructor of the resource of type `ty`, as seen by this
+    /// instance, for the resource `rep`.
+    ///
+    /// This is used for resources which are exported from this instance and
+    /// imported into another, where the other instance drops its own handle.
+    pub(crate) fn resource_dtor<T>(
+        &self,
+        store: &mut StoreContextMut<'_, T>,
+        ty: TypeResourceTableIndex,
+        rep: u32,
+    ) -> Result<()> {
+        let (dtor, flags) = store.0[self.0].as_ref().unwrap().state.dtor_and_flags(ty);
+
+        // Implement the reentrance check required by the canonical ABI, as
+        // with `ResourceAny::resource_drop`.
+        //
+        // Note that this should be safe because the flags are owned by the
+        // store, which is still alive.
+        if let Some(flags) = flags {
+            unsafe {
+                if !flags.may_enter() {
+                    bail!(crate::Trap::CannotEnterComponent);
+                }
+            }
+        }
+
+        let dtor = match dtor {
+            Some(dtor) => dtor,
+            None => return Ok(()),
+        };
+        let mut args = [crate::ValRaw::u32(rep)];
+
+        // This should be safe because `dtor` belongs to this instance, which
+        // lives in `store`, and destructors have been previously type-checked
+        // to take one i32 argument and return no results.
+        unsafe { crate::Func::call_unchecked_raw(store, dtor, args.as_mut_ptr(), args.len()) }
+    }
+}
+
 impl InstanceData {
     pub fn lookup_def(&self, store: &mut StoreOpaque, def: &CoreDef) -> wasmtime_runtime::Export {
         match def {
@@ -628,6 +669,15 @@ impl<T> InstancePre<T> {
     }
 }
 
+/// An item exported from an [`Instance`], as returned by
+/// [`ExportInstance::linkable_exports`].
+pub(crate) enum LinkableExport {
+    Func(Func),
+    Module(Module),
+    Resource(ResourceType, TypeResourceTableIndex),
+    Instance(Vec<(String, LinkableExport)>),
+}
+
 /// Description of the exports of an [`Instance`].
 ///
 /// This structure is created through the [`Instance::exports`] method and is
@@ -784,6 +834,32 @@ impl<'a, 'store> ExportInstance<'a, 'store> {
         })
     }
 
+    /// Returns all of the items exported from this instance, recursively,
+    /// which can be defined in a [`Linker`](crate::component::Linker).
+    ///
+    /// Types other than resources are left out as they don't have a
+    /// definition in a `Linker`.
+    pub(crate) fn linkable_exports(&mut self) -> Vec<(String, LinkableExport)> {
+        let mut items = Vec::new();
+        for (name, export) in self.exports.iter() {
+            let item = match export {
+                Export::LiftedFunction { .. } => LinkableExport::Func(self.func(name).unwrap()),
+                Export::ModuleStatic(_) | Export::ModuleImport(_) => {
+                    LinkableExport::Module(self.module(name).unwrap().clone())
+                }
+                Export::Instance(_) => {
+                    LinkableExport::Instance(self.instance(name).unwrap().linkable_exports())
+                }
+                Export::Type(TypeDef::Resource(id)) => {
+                    LinkableExport::Resource(self.data.ty().resource_type(*id), *id)
+                }
+                Export::Type(_) => continue,
+            };
+            items.push((name.clone(), item));
+        }
+        items
+    }
+
     fn as_mut(&mut self) -> ExportInstance<'a, '_> {
         ExportInstance {
             exports: self.exports,
@@ -0,0 +1,308 @@
+//! Conversion of component values to and from JSON.
+//!
+//! Values are mapped to JSON according to their types as follows:
+//!
+//! * `bool` is a JSON boolean and integers are JSON numbers.
+//! * Floats are JSON numbers, except for NaN and infinities which are the
+//!   strings `"nan"`, `"inf"` and `"-inf"`.
+//! * `char` is a string with a single character and `string` is a string.
+//! * `list` and `tuple` are arrays.
+//! * `record` is an object with a member for each field, in order, where
+//!   fields of an `option` type may be left out if they're `none`.
+//! * `variant` is the name of the case as a string if the case has no
+//!   payload, or an object with the name of the case as its only member
+//!   otherwise, for example `{"circle": 1.5}`. `result` is a variant with the
+//!   cases `ok` and `err`.
+//! * `enum` is the name of the case as a string.
+//! * `option` is `null` for `none` and the payload for `some`, except that
+//!   `some` of a nested `option` is `{"some": payload}` to remain unambiguous.
+//! * `flags` is an array of the names of the flags which are set.
+//!
+//! Resources have no JSON representation.
+
+use crate::component::types::{self, Type};
+use crate::component::Val;
+use anyhow::{anyhow, bail, Context, Result};
+use serde::ser::{Error as _, Serialize, Serializer};
+use serde_json::Value;
+
+impl Val {
+    /// Parses a value of type `ty` from its JSON representation.
+    ///
+    /// See the documentation of [`Val::to_json`] for how values are
+    /// represented.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `json` isn't valid JSON, if it doesn't represent a
+    /// value of type `ty`, or if `ty` contains resources, which can't be
+    /// represented as JSON.
+    pub fn from_json(ty: &Type, json: &str) -> Result<Val> {
+        let json = serde_json::from_str(json).context("invalid JSON")?;
+        from_json(ty, &json)
+    }
+
+    /// Returns the JSON representation of this value.
+    ///
+    /// Values are represented in the most natural way for their type: records
+    /// are objects, lists and tuples are arrays, enums are strings, `none` is
+    /// `null` and so on. Variants are the name of their case, or an object
+    /// such as `{"circle": 1.5}` for cases with a payload, and results are
+    /// variants with `ok` and `err` cases. The returned text can be parsed
+    /// back into the same value with [`Val::from_json`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if this value contains resources, which can't be
+    /// represented as JSON.
+    pub fn to_json(&self) -> Result<String> {
+        Ok(serde_json::to_string(&Json(self))?)
+    }
+}
+
+/// Serializes a [`Val`] as JSON.
+///
+/// This goes through `serde` rather than building a [`Value`] so that record
+/// fields are written in the order they're declared in, where a JSON object
+/// would sort them by name.
+struct Json<'a>(&'a Val);
+
+impl Serialize for Json<'_> {
+    fn serialize<S: Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+        match self.0 {
+            Val::Bool(b) => s.serialize_bool(*b),
+            Val::S8(n) => s.serialize_i8(*n),
+            Val::U8(n) => s.serialize_u8(*n),
+            Val::S16(n) => s.serialize_i16(*n),
+            Val::U16(n) => s.serialize_u16(*n),
+            Val::S32(n) => s.serialize_i32(*n),
+            Val::U32(n) => s.serialize_u32(*n),
+            Val::S64(n) => s.serialize_i64(*n),
+            Val::U64(n) => s.serialize_u64(*n),
+            // Finite floats are written in their own shortest round-trip
+            // representation, which for a `float32` isn't that of the
+            // equivalent `f64`.
+            Val::Float32(f) if f.is_finite() => s.serialize_f32(*f),
+            Val::Float64(f) if f.is_finite() => s.serialize_f64(*f),
+            Val::Float32(f) => serialize_non_finite(f64::from(*f), s),
+            Val::Float64(f) => serialize_non_finite(*f, s),
+            Val::Char(c) => s.serialize_char(*c),
+            Val::String(string) => s.serialize_str(string),
+            Val::List(list) => s.collect_seq(list.iter().map(Json)),
+            Val::Tuple(tuple) => s.collect_seq(tuple.values().iter().map(Json)),
+            Val::Record(record) => {
+                s.collect_map(record.fields().map(|(name, val)| (name, Json(val))))
+            }
+            Val::Variant(variant) => serialize_case(variant.discriminant(), variant.payload(), s),
+            Val::Enum(e) => s.serialize_str(e.discriminant()),
+            Val::Option(option) => match option.value() {
+                Some(val) if matches!(val, Val::Option(_)) => serialize_case("some", Some(val), s),
+                Some(val) => Json(val).serialize(s),
+                None => s.serialize_none(),
+            },
+            Val::Result(result) => match result.value() {
+                Ok(val) => serialize_case("ok", val, s),
+                Err(val) => serialize_case("err", val, s),
+            },
+            Val::Flags(flags) => s.collect_seq(flags.flags()),
+            Val::Resource(_) => Err(S::Error::custom("resources cannot be represented as JSON")),
+        }
+    }
+}
+
+fn serialize_non_finite<S: Serializer>(f: f64, s: S) -> Result<S::Ok, S::Error> {
+    if f.is_nan() {
+        s.serialize_str("nan")
+    } else if f > 0.0 {
+        s.serialize_str("inf")
+    } else {
+        s.serialize_str("-inf")
+    }
+}
+
+fn serialize_case<S: Serializer>(
+    name: &str,
+    payload: Option<&Val>,
+    s: S,
+) -> Result<S::Ok, S::Error> {
+    match payload {
+        Some(val) => s.collect_map([(name, Json(val))]),
+        None => s.serialize_str(name),
+    }
+}
+
+fn from_json(ty: &Type, json: &Value) -> Result<Val> {
+    Ok(match ty {
+        Type::Bool => Val::Bool(json.as_bool().ok_or_else(|| mismatch("a boolean", json))?),
+        Type::S8 => Val::S8(integer_from_json(json)?),
+        Type::U8 => Val::U8(integer_from_json(json)?),
+        Type::S16 => Val::S16(integer_from_json(json)?),
+        Type::U16 => Val::U16(integer_from_json(json)?),
+        Type::S32 => Val::S32(integer_from_json(json)?),
+        Type::U32 => Val::U32(integer_from_json(json)?),
+        Type::S64 => Val::S64(integer_from_json(json)?),
+        Type::U64 => Val::U64(integer_from_json(json)?),
+        Type::Float32 => Val::Float32(float_from_json(json)? as f32),
+        Type::Float64 => Val::Float64(float_from_json(json)?),
+        Type::Char => {
+            let s = json.as_str().ok_or_else(|| mismatch("a char", json))?;
+            let mut chars = s.chars();
+            match (chars.next(), chars.next()) {
+                (Some(c), None) => Val::Char(c),
+                _ => bail!("expected a single character, found {json}"),
+            }
+        }
+        Type::String => Val::String(
+            json.as_str()
+                .ok_or_else(|| mismatch("a string", json))?
+                .into(),
+        ),
+        Type::List(ty) => {
+            let elem = ty.ty();
+            let vals = array(json, "a list")?
+                .iter()
+                .map(|json| from_json(&elem, json))
+                .collect::<Result<Vec<_>>>()?;
+            ty.new_val(vals.into())?
+        }
+        Type::Tuple(ty) => {
+            let array = array(json, "a tuple")?;
+            if array.len() != ty.types().len() {
+                bail!(
+                    "expected a tuple of {} elements, found {json}",
+                    ty.types().len()
+                );
+            }
+            let vals = ty
+                .types()
+                .zip(array)
+                .map(|(ty, json)| from_json(&ty, json))
+                .collect::<Result<Vec<_>>>()?;
+            ty.new_val(vals.into())?
+        }
+        Type::Record(ty) => record_from_json(ty, json)?,
+        Type::Variant(ty) => {
+            let (name, payload) = case_from_json(json)?;
+            let case = ty
+                .cases()
+                .find(|case| case.name == name)
+                .ok_or_else(|| anyhow!("unknown variant case `{name}`"))?;
+            ty.new_val(
+                case.name,
+                payload_from_json(name, case.ty.as_ref(), payload)?,
+            )?
+        }
+        Type::Enum(ty) => {
+            let name = json
+                .as_str()
+                .ok_or_else(|| mismatch("an enum case", json))?;
+            ty.new_val(name)?
+        }
+        Type::Option(ty) => {
+            let payload = ty.ty();
+            match json {
+                Value::Null => ty.new_val(None)?,
+                json if matches!(payload, Type::Option(_)) => match json.get("some") {
+                    Some(inner) if json.as_object().unwrap().len() == 1 => {
+                        ty.new_val(Some(from_json(&payload, inner)?))?
+                    }
+                    _ => bail!("expected `{{\"some\": ...}}` for a nested option, found {json}"),
+                },
+                json => ty.new_val(Some(from_json(&payload, json)?))?,
+            }
+        }
+        Type::Result(ty) => match case_from_json(json)? {
+            ("ok", payload) => {
+                ty.new_val(Ok(payload_from_json("ok", ty.ok().as_ref(), payload)?))?
+            }
+            ("err", payload) => {
+                ty.new_val(Err(payload_from_json("err", ty.err().as_ref(), payload)?))?
+            }
+            _ => bail!("expected `ok` or `err`, found {json}"),
+        },
+        Type::Flags(ty) => {
+            let names = array(json, "flags")?
+                .iter()
+                .map(|json| json.as_str().ok_or_else(|| mismatch("a flag name", json)))
+                .collect::<Result<Vec<_>>>()?;
+            ty.new_val(&names)?
+        }
+        Type::Own(_) | Type::Borrow(_) => bail!("resources cannot be represented as JSON"),
+    })
+}
+
+fn record_from_json(ty: &types::Record, json: &Value) -> Result<Val> {
+    let object = json.as_object().ok_or_else(|| mismatch("a record", json))?;
+    if let Some(name) = object
+        .keys()
+        .find(|name| !ty.fields().any(|field| field.name == *name))
+    {
+        bail!("unknown record field `{name}`");
+    }
+    let fields = ty
+        .fields()
+        .map(|field| {
+            let val = match (object.get(field.name), &field.ty) {
+                (Some(json), ty) => from_json(ty, json)
+                    .with_context(|| format!("invalid record field `{}`", field.name))?,
+                (None, Type::Option(ty)) => ty.new_val(None)?,
+                (None, _) => bail!("missing record field `{}`", field.name),
+            };
+            Ok((field.name, val))
+        })
+        .collect::<Result<Vec<_>>>()?;
+    ty.new_val(fields)
+}
+
+/// Splits the JSON representation of a variant case into its name and
+/// payload, accepting `{"name": null}` for cases without a payload as well.
+fn case_from_json(json: &Value) -> Result<(&str, Option<&Value>)> {
+    match json {
+        Value::String(name) => Ok((name, None)),
+        Value::Object(object) if object.len() == 1 => {
+            let (name, payload) = object.iter().next().unwrap();
+            Ok((name, Some(payload).filter(|p| !p.is_null())))
+        }
+        _ => Err(mismatch("a variant case", json)),
+    }
+}
+
+fn payload_from_json(name: &str, ty: Option<&Type>, json: Option<&Value>) -> Result<Option<Val>> {
+    match (ty, json) {
+        (Some(ty), Some(json)) => Ok(Some(from_json(ty, json)?)),
+        (None, None) => Ok(None),
+        (Some(_), None) => bail!("missing payload for case `{name}`"),
+        (None, Some(_)) => bail!("case `{name}` has no payload"),
+    }
+}
+
+fn integer_from_json<T: TryFrom<i64> + TryFrom<u64>>(json: &Value) -> Result<T> {
+    let n = match json {
+        Value::Number(n) => n,
+        _ => return Err(mismatch("an integer", json)),
+    };
+    let n = match (n.as_i64(), n.as_u64()) {
+        (_, Some(n)) => T::try_from(n).ok(),
+        (Some(n), None) => T::try_from(n).ok(),
+        (None, None) => None,
+    };
+    n.ok_or_else(|| anyhow!("expected an integer in range, found {json}"))
+}
+
+fn float_from_json(json: &Value) -> Result<f64> {
+    match json {
+        Value::Number(n) => Ok(n.as_f64().unwrap()),
+        Value::String(s) if s == "nan" => Ok(f64::NAN),
+        Value::String(s) if s == "inf" => Ok(f64::INFINITY),
+        Value::String(s) if s == "-inf" => Ok(f64::NEG_INFINITY),
+        _ => Err(mismatch("a number", json)),
+    }
+}
+
+fn array<'a>(json: &'a Value, what: &str) -> Result<&'a Vec<Value>> {
+    json.as_array().ok_or_else(|| mismatch(what, json))
+}
+
+fn mismatch(expected: &str, json: &Value) -> anyhow::Error {
+    anyhow!("expected {expected}, found {json}")
+}
@@ -1,10 +1,10 @@
-use crate::component::func::HostFunc;
-use crate::component::instance::RuntimeImport;
+use crate::component::func::{check_linked_store, HostFunc};
+use crate::component::instance::{LinkableExport, RuntimeImport};
 use crate::component::matching::TypeChecker;
 use crate::component::{
-    Component, ComponentNamedList, Instance, InstancePre, Lift, Lower, ResourceType, Val,
+    Component, ComponentNamedList, Instance, InstancePre, Lift, Lower, ResourceType, Type, Val,
 };
-use crate::{AsContextMut, Engine, Module, StoreContextMut};
+use crate::{AsContext, AsContextMut, Engine, Module, StoreContextMut};
 use anyhow::{anyhow, bail, Context, Result};
 use indexmap::IndexMap;
 use std::collections::hash_map::{Entry, HashMap};
@@ -395,6 +395,28 @@ impl<T> LinkerInstance<'_, T> {
         component: &Component,
         name: &str,
         func: F,
+    ) -> Result<()> {
+        self.func_new_with_result_types(component, name, move |store, params, _, results| {
+            func(store, params, results)
+        })
+    }
+
+    /// Same as [`Self::func_new`], except that `func` is additionally provided
+    /// with the [`Type`] of each of the function's results.
+    ///
+    /// Compound values such as records and lists are constructed from their
+    /// type, so this is useful for hosts which don't statically know the
+    /// signature of the imports they define, such as language bindings.
+    pub fn func_new_with_result_types<
+        F: Fn(StoreContextMut<'_, T>, &[Val], &[Type], &mut [Val]) -> Result<()>
+            + Send
+            + Sync
+            + 'static,
+    >(
+        &mut self,
+        component: &Component,
+        name: &str,
+        func: F,
     ) -> Result<()> {
         let mut map = &component
             .env_component()
@@ -498,11 +520,20 @@ impl<T> LinkerInstance<'_, T> {
         ty: ResourceType,
         dtor: impl Fn(StoreContextMut<'_, T>, u32) -> Result<()> + Send + Sync + 'static,
     ) -> Result<ResourceImportIndex> {
-        let name = self.strings.intern(name);
         let dtor = Arc::new(crate::func::HostFunc::wrap(
             &self.engine,
             move |mut cx: crate::Caller<'_, T>, param: u32| dtor(cx.as_context_mut(), param),
         ));
+        self.insert_resource(name, ty, dtor)
+    }
+
+    fn insert_resource(
+        &mut self,
+        name: &str,
+        ty: ResourceType,
+        dtor: Arc<crate::func::HostFunc>,
+    ) -> Result<ResourceImportIndex> {
+        let name = self.strings.intern(name);
         let idx = ResourceImportIndex::new(*self.resource_imports);
         *self.resource_imports = self
             .resource_imports
@@ -512,6 +543,92 @@ impl<T> LinkerInstance<'_, T> {
         Ok(idx)
     }
 
+    /// Defines all of the exports of the component `instance` within this
+    /// instance of the linker.
+    ///
+    /// This can be used to link components together at runtime: the exports
+    /// of an instance of one component, such as its exported interfaces, are
+    /// defined here with the same names so that they can satisfy the imports
+    /// of other components instantiated with this linker. For example
+    /// `linker.root().instance_exports(&mut store, &instance)` defines an
+    /// exported `wasi:keyvalue/store` instance of `instance` as an import of
+    /// the same name.
+    ///
+    /// Exported functions are defined as host functions which call the
+    /// exported function, and exported resource types are defined as
+    /// resources which are the same type as in `instance`. Resources can thus
+    /// be passed between the two instances, and dropping an owned resource in
+    /// an importing instance runs the destructor of `instance`.
+    ///
+    /// The definitions are only valid within the `store` which owns
+    /// `instance`, and components which import them must be instantiated
+    /// within that same store. Calling an imported function or dropping an
+    /// imported resource within any other store fails with an error.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a name exported by `instance` is already defined
+    /// within the linker and shadowing isn't allowed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `store` does not own `instance` or if async support is
+    /// enabled, which isn't supported yet.
+    pub fn instance_exports(
+        &mut self,
+        mut store: impl AsContextMut<Data = T>,
+        instance: &Instance,
+    ) -> Result<()> {
+        let mut store = store.as_context_mut();
+        assert!(
+            !store.0.async_support(),
+            "linking component instances is not supported with async support"
+        );
+        let items = instance.exports(&mut store).root().linkable_exports();
+        self.define_exports(&mut store, instance, items)
+    }
+
+    fn define_exports(
+        &mut self,
+        store: &mut StoreContextMut<'_, T>,
+        instance: &Instance,
+        items: Vec<(String, LinkableExport)>,
+    ) -> Result<()> {
+        let store_id = store.0.id();
+        for (name, item) in items {
+            match item {
+                LinkableExport::Func(func) => {
+                    let params = func.params(&*store);
+                    let results = func.results(&*store);
+                    let key = self.strings.intern(&name);
+                    self.insert(
+                        key,
+                        Definition::Func(HostFunc::from_guest_func::<T>(
+                            func, store_id, params, results,
+                        )),
+                    )?;
+                }
+                LinkableExport::Module(module) => self.module(&name, &module)?,
+                LinkableExport::Resource(ty, index) => {
+                    let instance = *instance;
+                    let dtor = Arc::new(crate::func::HostFunc::wrap(
+                        &self.engine,
+                        move |mut cx: crate::Caller<'_, T>, rep: u32| {
+                            check_linked_store(cx.as_context().0, store_id)?;
+                            instance.resource_dtor(&mut cx.as_context_mut(), index, rep)
+                        },
+                    ));
+                    self.insert_resource(&name, ty, dtor)?;
+                }
+                LinkableExport::Instance(items) => {
+                    self.instance(&name)?
+                        .define_exports(store, instance, items)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
     /// Defines a nested instance within this instance.
     ///
     /// This can be used to describe arbitrarily nested levels of instances
@@ -8,6 +8,7 @@
 mod component;
 mod func;
 mod instance;
+mod json;
 mod linker;
 mod matching;
 mod resource_table;
@@ -16,6 +17,7 @@ mod storage;
 mod store;
 pub mod types;
 mod values;
+mod wave;
 pub use self::component::Component;
 pub use self::func::{
     ComponentNamedList, ComponentType, Func, Lift, Lower, TypedFunc, WasmList, WasmStr,
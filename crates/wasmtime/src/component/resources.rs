@@ -11,7 +11,9 @@ use std::fmt;
 use std::marker;
 use std::mem::MaybeUninit;
 use std::sync::atomic::{AtomicU32, Ordering::Relaxed};
-use wasmtime_environ::component::{CanonicalAbiInfo, DefinedResourceIndex, InterfaceType};
+use wasmtime_environ::component::{
+    CanonicalAbiInfo, ComponentTypes, DefinedResourceIndex, InterfaceType, ResourceIndex,
+};
 use wasmtime_runtime::component::{ComponentInstance, InstanceFlags, ResourceTables};
 use wasmtime_runtime::{SendSyncPtr, VMFuncRef, ValRaw};
 
@@ -26,6 +28,11 @@ use wasmtime_runtime::{SendSyncPtr, VMFuncRef, ValRaw};
 /// Resource types can also be defined on the host in addition to guests. On the
 /// host resource types are tied to a `T`, an arbitrary Rust type. Two host
 /// resource types are the same if they point to the same `T`.
+///
+/// The resource types of a component which hasn't been instantiated, as seen
+/// through [`Component::component_type`](crate::component::Component::component_type),
+/// are placeholders which are distinct from all other resource types but are
+/// the same for all uses of the same resource within the component.
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub struct ResourceType {
     kind: ResourceTypeKind,
@@ -67,6 +74,15 @@ impl ResourceType {
             },
         }
     }
+
+    pub(crate) fn uninstantiated(types: &ComponentTypes, index: ResourceIndex) -> ResourceType {
+        ResourceType {
+            kind: ResourceTypeKind::Uninstantiated {
+                component: types as *const _ as usize,
+                index,
+            },
+        }
+    }
 }
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
@@ -80,6 +96,13 @@ enum ResourceTypeKind {
         instance: usize,
         id: DefinedResourceIndex,
     },
+    Uninstantiated {
+        // Like `instance` above this is a pointer, to the type information of
+        // the component, which distinguishes resources of different
+        // components.
+        component: usize,
+        index: ResourceIndex,
+    },
 }
 
 /// A host-defined resource in the component model.
@@ -406,9 +429,8 @@ where
         let store_id = store.0.id();
         let mut tables = host_resource_tables(store.0);
         assert_eq!(ty, ResourceType::host::<T>(), "resource type mismatch");
-        let (state, rep) = if let Some(OwnState { store, dtor, flags }) = own_state {
+        let (state, rep) = if let Some(OwnState { store, flags, .. }) = own_state {
             assert_eq!(store_id, store, "wrong store used to convert resource");
-            assert!(dtor.is_some(), "destructor must be set");
             assert!(flags.is_none(), "flags must not be set");
             let rep = tables.resource_lift_own(None, idx)?;
             (AtomicU32::new(NOT_IN_TABLE), rep)
@@ -594,6 +616,43 @@ impl ResourceAny {
         })
     }
 
+    /// Converts a host-defined [`Resource`] into a [`ResourceAny`] without
+    /// needing the [`InstancePre`] it will be used with.
+    ///
+    /// Only owned resources can be converted, borrows created with
+    /// [`Resource::new_borrow`] are rejected. This is useful for embedders which only deal with resources
+    /// dynamically, such as language bindings. Unlike
+    /// [`ResourceAny::try_from_resource`] no destructor is associated with the
+    /// returned value, so destroying an owned resource from the host with
+    /// [`ResourceAny::resource_drop`] does not invoke the destructor passed to
+    /// [`Linker::resource`]. Once the resource has been passed to a guest the
+    /// guest's own destructor is used as usual.
+    ///
+    /// [`Linker::resource`]: crate::component::LinkerInstance::resource
+    pub fn try_from_host_resource<T: 'static>(
+        Resource { rep, state, .. }: Resource<T>,
+        mut store: impl AsContextMut,
+    ) -> Result<Self> {
+        let store = store.as_context_mut();
+        let store_id = store.0.id();
+        let mut tables = host_resource_tables(store.0);
+        let idx = match state.load(Relaxed) {
+            BORROW => bail!("cannot convert a borrowed host resource to `ResourceAny`"),
+            NOT_IN_TABLE => tables.resource_lower_own(None, rep),
+            TAKEN => bail!("host resource already consumed"),
+            idx => idx,
+        };
+        Ok(Self {
+            idx,
+            ty: ResourceType::host::<T>(),
+            own_state: Some(OwnState {
+                dtor: None,
+                flags: None,
+                store: store_id,
+            }),
+        })
+    }
+
     /// See [`Resource::try_from_resource_any`]
     pub fn try_into_resource<T: 'static>(self, store: impl AsContextMut) -> Result<Resource<T>> {
         Resource::try_from_resource_any(self, store)
@@ -1,16 +1,19 @@
-//! This module defines the `Type` type, representing the dynamic form of a component interface type.
+//! This module defines the `Type` type, representing the dynamic form of a component interface type,
+//! along with the types of the items that a component imports and exports.
 
 use crate::component::matching::InstanceType;
 use crate::component::values::{self, Val};
+use crate::{ExternType, FuncType};
 use anyhow::{anyhow, Result};
 use std::fmt;
 use std::mem;
 use std::ops::Deref;
 use std::sync::Arc;
 use wasmtime_environ::component::{
-    CanonicalAbiInfo, ComponentTypes, InterfaceType, ResourceIndex, TypeEnumIndex, TypeFlagsIndex,
-    TypeListIndex, TypeOptionIndex, TypeRecordIndex, TypeResourceTableIndex, TypeResultIndex,
-    TypeTupleIndex, TypeVariantIndex,
+    CanonicalAbiInfo, ComponentTypes, InterfaceType, ResourceIndex, TypeComponentIndex,
+    TypeComponentInstanceIndex, TypeDef, TypeEnumIndex, TypeFlagsIndex, TypeFuncIndex,
+    TypeListIndex, TypeModuleIndex, TypeOptionIndex, TypeRecordIndex, TypeResourceTableIndex,
+    TypeResultIndex, TypeTupleIndex, TypeVariantIndex,
 };
 use wasmtime_environ::PrimaryMap;
 
@@ -757,3 +760,183 @@ impl Type {
         }
     }
 }
+
+/// The type of a component, as returned by
+/// [`Component::component_type`](crate::component::Component::component_type).
+#[derive(Clone, Debug)]
+pub struct Component(Handle<TypeComponentIndex>);
+
+impl Component {
+    pub(crate) fn from(index: TypeComponentIndex, ty: &InstanceType<'_>) -> Self {
+        Component(Handle::new(index, ty))
+    }
+
+    /// Returns the item that this component imports with the name `name`, if
+    /// any.
+    pub fn get_import(&self, name: &str) -> Option<ComponentItem> {
+        self.0.types[self.0.index]
+            .imports
+            .get(name)
+            .map(|ty| ComponentItem::from(ty, &self.0.instance()))
+    }
+
+    /// Retrieve the names and types of the imports of this component, in
+    /// declaration order.
+    pub fn imports(&self) -> impl ExactSizeIterator<Item = (&str, ComponentItem)> {
+        self.0.types[self.0.index]
+            .imports
+            .iter()
+            .map(|(name, ty)| (name.as_str(), ComponentItem::from(ty, &self.0.instance())))
+    }
+
+    /// Returns the item that this component exports with the name `name`, if
+    /// any.
+    pub fn get_export(&self, name: &str) -> Option<ComponentItem> {
+        self.0.types[self.0.index]
+            .exports
+            .get(name)
+            .map(|ty| ComponentItem::from(ty, &self.0.instance()))
+    }
+
+    /// Retrieve the names and types of the exports of this component, in
+    /// declaration order.
+    pub fn exports(&self) -> impl ExactSizeIterator<Item = (&str, ComponentItem)> {
+        self.0.types[self.0.index]
+            .exports
+            .iter()
+            .map(|(name, ty)| (name.as_str(), ComponentItem::from(ty, &self.0.instance())))
+    }
+}
+
+/// The type of a component instance.
+#[derive(Clone, Debug)]
+pub struct ComponentInstance(Handle<TypeComponentInstanceIndex>);
+
+impl ComponentInstance {
+    pub(crate) fn from(index: TypeComponentInstanceIndex, ty: &InstanceType<'_>) -> Self {
+        ComponentInstance(Handle::new(index, ty))
+    }
+
+    /// Returns the item that this instance exports with the name `name`, if
+    /// any.
+    pub fn get_export(&self, name: &str) -> Option<ComponentItem> {
+        self.0.types[self.0.index]
+            .exports
+            .get(name)
+            .map(|ty| ComponentItem::from(ty, &self.0.instance()))
+    }
+
+    /// Retrieve the names and types of the exports of this instance, in
+    /// declaration order.
+    pub fn exports(&self) -> impl ExactSizeIterator<Item = (&str, ComponentItem)> {
+        self.0.types[self.0.index]
+            .exports
+            .iter()
+            .map(|(name, ty)| (name.as_str(), ComponentItem::from(ty, &self.0.instance())))
+    }
+}
+
+/// The type of a component function.
+#[derive(Clone, Debug)]
+pub struct ComponentFunc(Handle<TypeFuncIndex>);
+
+impl ComponentFunc {
+    pub(crate) fn from(index: TypeFuncIndex, ty: &InstanceType<'_>) -> Self {
+        ComponentFunc(Handle::new(index, ty))
+    }
+
+    /// Retrieve the types of the parameters of this function, in declaration
+    /// order.
+    pub fn params(&self) -> impl ExactSizeIterator<Item = Type> + '_ {
+        let params = self.0.types[self.0.index].params;
+        self.0.types[params]
+            .types
+            .iter()
+            .map(|ty| Type::from(ty, &self.0.instance()))
+    }
+
+    /// Retrieve the types of the results of this function, in declaration
+    /// order.
+    pub fn results(&self) -> impl ExactSizeIterator<Item = Type> + '_ {
+        let results = self.0.types[self.0.index].results;
+        self.0.types[results]
+            .types
+            .iter()
+            .map(|ty| Type::from(ty, &self.0.instance()))
+    }
+}
+
+/// The type of a core WebAssembly module imported or exported by a component.
+#[derive(Clone, Debug)]
+pub struct Module(Handle<TypeModuleIndex>);
+
+impl Module {
+    pub(crate) fn from(index: TypeModuleIndex, ty: &InstanceType<'_>) -> Self {
+        Module(Handle::new(index, ty))
+    }
+
+    /// Retrieve the module and field names and types of the imports of this
+    /// module, in declaration order.
+    pub fn imports(&self) -> impl ExactSizeIterator<Item = ((&str, &str), ExternType)> {
+        let types = self.0.types.module_types();
+        self.0.types[self.0.index]
+            .imports
+            .iter()
+            .map(move |((module, name), ty)| {
+                (
+                    (module.as_str(), name.as_str()),
+                    ExternType::from_wasmtime(types, ty),
+                )
+            })
+    }
+
+    /// Retrieve the names and types of the exports of this module, in
+    /// declaration order.
+    pub fn exports(&self) -> impl ExactSizeIterator<Item = (&str, ExternType)> {
+        let types = self.0.types.module_types();
+        self.0.types[self.0.index]
+            .exports
+            .iter()
+            .map(move |(name, ty)| (name.as_str(), ExternType::from_wasmtime(types, ty)))
+    }
+}
+
+/// The type of an item imported or exported by a component or exported by a
+/// component instance.
+#[derive(Clone, Debug)]
+pub enum ComponentItem {
+    /// A component function.
+    ComponentFunc(ComponentFunc),
+    /// A core WebAssembly function.
+    CoreFunc(FuncType),
+    /// A core WebAssembly module.
+    Module(Module),
+    /// A component.
+    Component(Component),
+    /// A component instance.
+    ComponentInstance(ComponentInstance),
+    /// An interface type, which is only informational.
+    Type(Type),
+    /// A resource type.
+    Resource(ResourceType),
+}
+
+impl ComponentItem {
+    pub(crate) fn from(def: &TypeDef, ty: &InstanceType<'_>) -> Self {
+        match def {
+            TypeDef::Component(index) => ComponentItem::Component(Component::from(*index, ty)),
+            TypeDef::ComponentInstance(index) => {
+                ComponentItem::ComponentInstance(ComponentInstance::from(*index, ty))
+            }
+            TypeDef::ComponentFunc(index) => {
+                ComponentItem::ComponentFunc(ComponentFunc::from(*index, ty))
+            }
+            TypeDef::Interface(iface) => ComponentItem::Type(Type::from(iface, ty)),
+            TypeDef::Module(index) => ComponentItem::Module(Module::from(*index, ty)),
+            TypeDef::CoreFunc(index) => ComponentItem::CoreFunc(FuncType::from_wasm_func_type(
+                ty.types.module_types()[*index].clone(),
+            )),
+            TypeDef::Resource(index) => ComponentItem::Resource(ty.resource_type(*index)),
+        }
+    }
+}
@@ -0,0 +1,529 @@
+//! Conversion of component values to and from the WebAssembly Value Encoding
+//! (WAVE), a human-oriented text format which resembles WIT.
+//!
+//! Values are written as in the following examples, given their types:
+//!
+//! * `bool`: `true`, `false`
+//! * integers: `42`, `-7`
+//! * floats: `1.5`, `-2e10`, `nan`, `inf`, `-inf`
+//! * `char`: `'x'`, `'\n'`, `'\u{1f600}'`
+//! * `string`: `"hello\tworld"`
+//! * `list<T>`: `[1, 2, 3]`
+//! * `tuple<A, B>`: `(1, "two")`
+//! * `record`: `{name: "x", size: 3}`, where fields of an `option` type may be
+//!   left out if they're `none`
+//! * `variant`: `case`, `case(payload)`
+//! * `enum`: `case`
+//! * `option<T>`: `none`, `some(value)`
+//! * `result<T, E>`: `ok`, `ok(value)`, `err`, `err(value)`
+//! * `flags`: `{read, write}`
+//!
+//! Labels which are also keywords, such as a variant case named `none`, may be
+//! prefixed with `%` to avoid ambiguity, and `//` starts a comment which runs
+//! until the end of the line. Resources have no text representation.
+
+use crate::component::types::{self, Type};
+use crate::component::Val;
+use anyhow::{anyhow, bail, Result};
+use std::fmt::Write as _;
+
+/// Labels which need a `%` prefix when written as labels.
+const KEYWORDS: &[&str] = &["true", "false", "some", "none", "ok", "err", "inf", "nan"];
+
+impl Val {
+    /// Parses a value of type `ty` from its WAVE text representation.
+    ///
+    /// See the documentation of [`Val::to_wave`] for the syntax of values.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `text` is malformed, if it doesn't represent a
+    /// value of type `ty`, or if `ty` contains resources, which can't be
+    /// represented as text.
+    pub fn from_wave(ty: &Type, text: &str) -> Result<Val> {
+        let mut parser = Parser { text, pos: 0 };
+        let val = parser.value(ty)?;
+        parser.skip_whitespace();
+        if parser.pos < text.len() {
+            return Err(parser.error("unexpected trailing characters"));
+        }
+        Ok(val)
+    }
+
+    /// Returns the WAVE text representation of this value.
+    ///
+    /// WAVE, the WebAssembly Value Encoding, writes values in a syntax which
+    /// resembles WIT, for example `{name: "x", tags: [a, b], size: some(3)}`
+    /// for a record with a string, a list of enum cases and an option. The
+    /// returned text can be parsed back into the same value with
+    /// [`Val::from_wave`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if this value contains resources, which can't be
+    /// represented as text.
+    pub fn to_wave(&self) -> Result<String> {
+        let mut text = String::new();
+        write_value(&mut text, self)?;
+        Ok(text)
+    }
+}
+
+fn write_value(dst: &mut String, val: &Val) -> Result<()> {
+    match val {
+        Val::Bool(b) => write!(dst, "{b}")?,
+        Val::S8(n) => write!(dst, "{n}")?,
+        Val::U8(n) => write!(dst, "{n}")?,
+        Val::S16(n) => write!(dst, "{n}")?,
+        Val::U16(n) => write!(dst, "{n}")?,
+        Val::S32(n) => write!(dst, "{n}")?,
+        Val::U32(n) => write!(dst, "{n}")?,
+        Val::S64(n) => write!(dst, "{n}")?,
+        Val::U64(n) => write!(dst, "{n}")?,
+        Val::Float32(f) => write_float(dst, f64::from(*f), *f),
+        Val::Float64(f) => write_float(dst, *f, *f),
+        Val::Char(c) => {
+            dst.push('\'');
+            write_char(dst, *c, '\'');
+            dst.push('\'');
+        }
+        Val::String(s) => {
+            dst.push('"');
+            for c in s.chars() {
+                write_char(dst, c, '"');
+            }
+            dst.push('"');
+        }
+        Val::List(list) => write_seq(dst, '[', list.iter(), ']')?,
+        Val::Tuple(tuple) => write_seq(dst, '(', tuple.values().iter(), ')')?,
+        Val::Record(record) => {
+            dst.push('{');
+            for (i, (name, val)) in record.fields().enumerate() {
+                if i > 0 {
+                    dst.push_str(", ");
+                }
+                write_label(dst, name);
+                dst.push_str(": ");
+                write_value(dst, val)?;
+            }
+            dst.push('}');
+        }
+        Val::Variant(variant) => {
+            write_label(dst, variant.discriminant());
+            write_payload(dst, variant.payload())?;
+        }
+        Val::Enum(e) => write_label(dst, e.discriminant()),
+        Val::Option(option) => match option.value() {
+            Some(val) => {
+                dst.push_str("some");
+                write_payload(dst, Some(val))?;
+            }
+            None => dst.push_str("none"),
+        },
+        Val::Result(result) => match result.value() {
+            Ok(val) => {
+                dst.push_str("ok");
+                write_payload(dst, val)?;
+            }
+            Err(val) => {
+                dst.push_str("err");
+                write_payload(dst, val)?;
+            }
+        },
+        Val::Flags(flags) => {
+            dst.push('{');
+            for (i, name) in flags.flags().enumerate() {
+                if i > 0 {
+                    dst.push_str(", ");
+                }
+                write_label(dst, name);
+            }
+            dst.push('}');
+        }
+        Val::Resource(_) => bail!("resources cannot be represented as text"),
+    }
+    Ok(())
+}
+
+fn write_float<T: std::fmt::Display>(dst: &mut String, f: f64, display: T) {
+    if f.is_nan() {
+        dst.push_str("nan");
+    } else if f.is_infinite() {
+        dst.push_str(if f > 0.0 { "inf" } else { "-inf" });
+    } else {
+        write!(dst, "{display}").unwrap();
+    }
+}
+
+fn write_char(dst: &mut String, c: char, quote: char) {
+    match c {
+        '\\' => dst.push_str("\\\\"),
+        '\n' => dst.push_str("\\n"),
+        '\r' => dst.push_str("\\r"),
+        '\t' => dst.push_str("\\t"),
+        c if c == quote => {
+            dst.push('\\');
+            dst.push(c);
+        }
+        c if c.is_control() => write!(dst, "\\u{{{:x}}}", u32::from(c)).unwrap(),
+        c => dst.push(c),
+    }
+}
+
+fn write_seq<'a>(
+    dst: &mut String,
+    open: char,
+    vals: impl Iterator<Item = &'a Val>,
+    close: char,
+) -> Result<()> {
+    dst.push(open);
+    for (i, val) in vals.enumerate() {
+        if i > 0 {
+            dst.push_str(", ");
+        }
+        write_value(dst, val)?;
+    }
+    dst.push(close);
+    Ok(())
+}
+
+fn write_payload(dst: &mut String, payload: Option<&Val>) -> Result<()> {
+    if let Some(val) = payload {
+        dst.push('(');
+        write_value(dst, val)?;
+        dst.push(')');
+    }
+    Ok(())
+}
+
+fn write_label(dst: &mut String, name: &str) {
+    if KEYWORDS.contains(&name) {
+        dst.push('%');
+    }
+    dst.push_str(name);
+}
+
+/// A parser of WAVE text which is driven by the type of the value being
+/// parsed.
+struct Parser<'a> {
+    text: &'a str,
+    pos: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn value(&mut self, ty: &Type) -> Result<Val> {
+        self.skip_whitespace();
+        Ok(match ty {
+            Type::Bool => match self.keyword()? {
+                "true" => Val::Bool(true),
+                "false" => Val::Bool(false),
+                _ => return Err(self.error("expected `true` or `false`")),
+            },
+            Type::S8 => Val::S8(self.integer()?),
+            Type::U8 => Val::U8(self.integer()?),
+            Type::S16 => Val::S16(self.integer()?),
+            Type::U16 => Val::U16(self.integer()?),
+            Type::S32 => Val::S32(self.integer()?),
+            Type::U32 => Val::U32(self.integer()?),
+            Type::S64 => Val::S64(self.integer()?),
+            Type::U64 => Val::U64(self.integer()?),
+            Type::Float32 => Val::Float32(self.float()?),
+            Type::Float64 => Val::Float64(self.float()?),
+            Type::Char => {
+                self.expect('\'')?;
+                let c = self.char_in_literal('\'')?;
+                self.expect('\'')?;
+                Val::Char(c)
+            }
+            Type::String => {
+                self.expect('"')?;
+                let mut s = String::new();
+                while !self.eat('"') {
+                    s.push(self.char_in_literal('"')?);
+                }
+                Val::String(s.into())
+            }
+            Type::List(ty) => {
+                let elem = ty.ty();
+                let mut vals = Vec::new();
+                self.seq('[', ']', |p| {
+                    vals.push(p.value(&elem)?);
+                    Ok(())
+                })?;
+                ty.new_val(vals.into())?
+            }
+            Type::Tuple(ty) => {
+                let mut types = ty.types();
+                let mut vals = Vec::new();
+                self.seq('(', ')', |p| match types.next() {
+                    Some(ty) => {
+                        vals.push(p.value(&ty)?);
+                        Ok(())
+                    }
+                    None => Err(p.error("too many tuple elements")),
+                })?;
+                if types.len() > 0 {
+                    return Err(self.error("too few tuple elements"));
+                }
+                ty.new_val(vals.into())?
+            }
+            Type::Record(ty) => self.record(ty)?,
+            Type::Variant(ty) => {
+                let name = self.label()?;
+                let case = ty
+                    .cases()
+                    .find(|case| case.name == name)
+                    .ok_or_else(|| self.error(&format!("unknown variant case `{name}`")))?;
+                let payload = self.payload(case.ty.as_ref())?;
+                ty.new_val(case.name, payload)?
+            }
+            Type::Enum(ty) => {
+                let name = self.label()?;
+                if !ty.names().any(|n| n == name) {
+                    return Err(self.error(&format!("unknown enum case `{name}`")));
+                }
+                ty.new_val(&name)?
+            }
+            Type::Option(ty) => match self.keyword()? {
+                "none" => ty.new_val(None)?,
+                "some" => ty.new_val(self.payload(Some(&ty.ty()))?)?,
+                _ => return Err(self.error("expected `some` or `none`")),
+            },
+            Type::Result(ty) => match self.keyword()? {
+                "ok" => ty.new_val(Ok(self.payload(ty.ok().as_ref())?))?,
+                "err" => ty.new_val(Err(self.payload(ty.err().as_ref())?))?,
+                _ => return Err(self.error("expected `ok` or `err`")),
+            },
+            Type::Flags(ty) => {
+                let mut names = Vec::new();
+                self.seq('{', '}', |p| {
+                    let name = p.label()?;
+                    if !ty.names().any(|n| n == name) {
+                        return Err(p.error(&format!("unknown flag `{name}`")));
+                    }
+                    names.push(name);
+                    Ok(())
+                })?;
+                let names = names.iter().map(|s| s.as_str()).collect::<Vec<_>>();
+                ty.new_val(&names)?
+            }
+            Type::Own(_) | Type::Borrow(_) => bail!("resources cannot be represented as text"),
+        })
+    }
+
+    fn record(&mut self, ty: &types::Record) -> Result<Val> {
+        let mut vals = ty.fields().map(|_| None).collect::<Vec<_>>();
+        self.seq('{', '}', |p| {
+            let name = p.label()?;
+            let (index, field) = ty
+                .fields()
+                .enumerate()
+                .find(|(_, field)| field.name == name)
+                .ok_or_else(|| p.error(&format!("unknown record field `{name}`")))?;
+            p.skip_whitespace();
+            p.expect(':')?;
+            let val = p.value(&field.ty)?;
+            if vals[index].replace(val).is_some() {
+                return Err(p.error(&format!("duplicate record field `{name}`")));
+            }
+            Ok(())
+        })?;
+        let fields = ty
+            .fields()
+            .zip(vals)
+            .map(|(field, val)| {
+                let val = match (val, &field.ty) {
+                    (Some(val), _) => val,
+                    (None, Type::Option(ty)) => ty.new_val(None)?,
+                    (None, _) => bail!("missing record field `{}`", field.name),
+                };
+                Ok((field.name, val))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        ty.new_val(fields)
+    }
+
+    /// Parses the parenthesized payload of a case, which is present if and
+    /// only if `ty` is.
+    fn payload(&mut self, ty: Option<&Type>) -> Result<Option<Val>> {
+        let Some(ty) = ty else {
+            return Ok(None);
+        };
+        self.skip_whitespace();
+        self.expect('(')?;
+        let val = self.value(ty)?;
+        self.skip_whitespace();
+        self.expect(')')?;
+        Ok(Some(val))
+    }
+
+    /// Parses a comma-separated sequence, with an optional trailing comma,
+    /// between `open` and `close`, parsing each element with `elem`.
+    fn seq(
+        &mut self,
+        open: char,
+        close: char,
+        mut elem: impl FnMut(&mut Self) -> Result<()>,
+    ) -> Result<()> {
+        self.expect(open)?;
+        loop {
+            self.skip_whitespace();
+            if self.eat(close) {
+                return Ok(());
+            }
+            elem(self)?;
+            self.skip_whitespace();
+            if !self.eat(',') {
+                self.skip_whitespace();
+                return self.expect(close);
+            }
+        }
+    }
+
+    /// Parses a keyword, which is a label without a `%` prefix.
+    fn keyword(&mut self) -> Result<&'a str> {
+        if self.rest().starts_with('%') {
+            return Err(self.error("expected a keyword"));
+        }
+        let start = self.pos;
+        self.word();
+        Ok(&self.text[start..self.pos])
+    }
+
+    /// Parses a label, stripping its `%` prefix if present.
+    fn label(&mut self) -> Result<String> {
+        self.eat('%');
+        let start = self.pos;
+        self.word();
+        if start == self.pos {
+            return Err(self.error("expected a label"));
+        }
+        Ok(self.text[start..self.pos].to_string())
+    }
+
+    fn word(&mut self) {
+        let len = self
+            .rest()
+            .find(|c: char| !(c.is_ascii_alphanumeric() || c == '-'))
+            .unwrap_or(self.rest().len());
+        self.pos += len;
+    }
+
+    fn number(&mut self) -> &'a str {
+        let start = self.pos;
+        self.eat('-');
+        let len = self
+            .rest()
+            .find(|c: char| !(c.is_ascii_alphanumeric() || matches!(c, '.' | '+' | '-')))
+            .unwrap_or(self.rest().len());
+        self.pos += len;
+        &self.text[start..self.pos]
+    }
+
+    fn integer<T: std::str::FromStr>(&mut self) -> Result<T> {
+        let start = self.pos;
+        let s = self.number();
+        s.parse().map_err(|_| {
+            self.pos = start;
+            self.error(&format!("invalid integer `{s}`"))
+        })
+    }
+
+    fn float<T: std::str::FromStr>(&mut self) -> Result<T> {
+        let start = self.pos;
+        let s = self.number();
+        // Rust also accepts spellings like `NaN` and `infinity`, so only the
+        // WAVE spellings of special values are passed through.
+        let valid = match s.trim_start_matches('-') {
+            "nan" | "inf" => true,
+            s => s.starts_with(|c: char| c.is_ascii_digit()),
+        };
+        match s.parse() {
+            Ok(f) if valid && s != "-nan" => Ok(f),
+            _ => {
+                self.pos = start;
+                Err(self.error(&format!("invalid number `{s}`")))
+            }
+        }
+    }
+
+    /// Parses a character within a char or string literal delimited by
+    /// `quote`, unescaping it if needed.
+    fn char_in_literal(&mut self, quote: char) -> Result<char> {
+        let c = self
+            .next_char()
+            .ok_or_else(|| self.error("unterminated literal"))?;
+        if c == quote {
+            return Err(self.error("empty char literal"));
+        }
+        if c != '\\' {
+            return Ok(c);
+        }
+        Ok(match self.next_char() {
+            Some('\\') => '\\',
+            Some('\'') => '\'',
+            Some('"') => '"',
+            Some('n') => '\n',
+            Some('r') => '\r',
+            Some('t') => '\t',
+            Some('u') => {
+                self.expect('{')?;
+                let end = self
+                    .rest()
+                    .find('}')
+                    .ok_or_else(|| self.error("unterminated unicode escape"))?;
+                let hex = &self.rest()[..end];
+                let c = u32::from_str_radix(hex, 16)
+                    .ok()
+                    .and_then(char::from_u32)
+                    .ok_or_else(|| self.error(&format!("invalid unicode escape `{hex}`")))?;
+                self.pos += end + 1;
+                c
+            }
+            _ => return Err(self.error("invalid escape")),
+        })
+    }
+
+    fn skip_whitespace(&mut self) {
+        loop {
+            let rest = self.rest();
+            let trimmed = rest.trim_start();
+            self.pos += rest.len() - trimmed.len();
+            if !trimmed.starts_with("//") {
+                break;
+            }
+            self.pos += trimmed.find('\n').unwrap_or(trimmed.len());
+        }
+    }
+
+    fn rest(&self) -> &'a str {
+        &self.text[self.pos..]
+    }
+
+    fn next_char(&mut self) -> Option<char> {
+        let c = self.rest().chars().next()?;
+        self.pos += c.len_utf8();
+        Some(c)
+    }
+
+    fn eat(&mut self, c: char) -> bool {
+        if self.rest().starts_with(c) {
+            self.pos += c.len_utf8();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, c: char) -> Result<()> {
+        if self.eat(c) {
+            Ok(())
+        } else {
+            Err(self.error(&format!("expected `{c}`")))
+        }
+    }
+
+    fn error(&self, msg: &str) -> anyhow::Error {
+        anyhow!("{msg} at offset {} of WAVE value", self.pos)
+    }
+}
@@ -121,6 +121,7 @@ pub struct Config {
     pub(crate) wmemcheck: bool,
     pub(crate) coredump_on_trap: bool,
     pub(crate) macos_use_mach_ports: bool,
+    pub(crate) deterministic: bool,
 }
 
 /// User-provided configuration for the compiler.
@@ -217,6 +218,7 @@ impl Config {
             wmemcheck: false,
             coredump_on_trap: false,
             macos_use_mach_ports: true,
+            deterministic: false,
         };
         #[cfg(any(feature = "cranelift", feature = "winch"))]
         {
@@ -382,6 +384,58 @@ impl Config {
         self
     }
 
+    /// Configures whether compiled WebAssembly can be stopped at breakpoints
+    /// and single-stepped at the level of WebAssembly instructions.
+    ///
+    /// When enabled, compiled code checks before each instruction whether
+    /// the store it runs in has a breakpoint set with
+    /// [`Store::add_breakpoint`](crate::Store::add_breakpoint) or is
+    /// single-stepping with [`Store::single_step`](crate::Store::single_step).
+    /// If so, and the instruction is at a breakpoint, the callback configured
+    /// with [`Store::debug_hook`](crate::Store::debug_hook) is invoked with a
+    /// [`DebugFrame`](crate::DebugFrame) describing the state of the stopped
+    /// function: its locals and operand stack. The rest of the state, such as
+    /// linear memory and the call stack, is available through the store, for
+    /// example with [`WasmBacktrace::capture`](crate::WasmBacktrace::capture).
+    ///
+    /// Unlike [`Config::debug_info`], this doesn't rely on DWARF or a native
+    /// debugger and isn't affected by optimizations. It's what the `wasmtime
+    /// run --debug-server` command uses to host a debugger. Only the
+    /// innermost function's locals and operand stack are available though,
+    /// and the values can't be modified.
+    ///
+    /// This option makes compiled code considerably larger and slower, and
+    /// execution slows down even more while any breakpoint is set. It isn't
+    /// supported by Winch.
+    ///
+    /// By default this option is `false`.
+    pub fn guest_debug(&mut self, enable: bool) -> &mut Self {
+        self.tunables.guest_debug = enable;
+        self
+    }
+
+    /// Configures whether compiled code counts how many times each of its
+    /// basic blocks is entered.
+    ///
+    /// When enabled, each function is instrumented in the same way as
+    /// [`Config::consume_fuel`] to increment a counter when entering any of
+    /// its basic blocks. The counters are shared by all instances of a
+    /// module and can be read with [`Module::coverage`](crate::Module::coverage),
+    /// which maps each block back to its offset in the original wasm module
+    /// and, if [`Config::wasm_backtrace_details`] is enabled and the module
+    /// has DWARF debug information, to its source location. It's what the
+    /// `wasmtime run --coverage` command uses to write a coverage report.
+    ///
+    /// Counters are incremented atomically, so counts are exact even when
+    /// instances run on multiple threads at once. This option makes compiled
+    /// code larger and slower. It isn't supported by Winch.
+    ///
+    /// By default this option is `false`.
+    pub fn coverage(&mut self, enable: bool) -> &mut Self {
+        self.tunables.coverage = enable;
+        self
+    }
+
     /// Configures whether [`WasmBacktrace`] will be present in the context of
     /// errors returned from Wasmtime.
     ///
@@ -722,6 +776,61 @@ impl Config {
         self
     }
 
+    /// Configures whether the [WebAssembly GC proposal][proposal] will be
+    /// enabled for compilation.
+    ///
+    /// This feature gates the `any`, `eq`, `i31`, `struct`, `array` and
+    /// bottom heap types, `struct` and `array` type definitions, `i31ref`
+    /// values, and the instructions which create and access these.
+    ///
+    /// Objects are allocated in a per-store GC heap which is collected by a
+    /// tracing collector, see [`Store::gc`](crate::Store::gc). References in
+    /// the `any` hierarchy are exposed in the embedder API as
+    /// [`Val::AnyRef`], whose objects can be inspected as
+    /// [`StructRef`](crate::StructRef)s and [`ArrayRef`](crate::ArrayRef)s.
+    ///
+    /// Support for this proposal is incomplete: subtyping of function types,
+    /// recursion groups of more than one type, tables of GC references,
+    /// `externref` fields, exception payloads holding objects, and the
+    /// `any.convert_extern` and `extern.convert_any` instructions are all
+    /// rejected when a module is compiled. Winch doesn't support the proposal's
+    /// instructions.
+    ///
+    /// Note that the GC proposal depends on the function references proposal.
+    ///
+    /// This feature is `false` by default.
+    ///
+    /// [proposal]: https://github.com/WebAssembly/gc
+    /// [`Val::AnyRef`]: crate::Val::AnyRef
+    pub fn wasm_gc(&mut self, enable: bool) -> &mut Self {
+        self.features.gc = enable;
+        self
+    }
+
+    /// Configures whether the [WebAssembly exception handling
+    /// proposal][proposal] will be enabled for compilation.
+    ///
+    /// This feature gates tags, the `exnref` type, and the `try_table`,
+    /// `throw` and `throw_ref` instructions. Tags are exposed in the embedder
+    /// API as [`Tag`], and host functions can throw exceptions by returning a
+    /// [`ThrownException`] error, which is also how exceptions that escape
+    /// from wasm are reported. The legacy `try`, `catch`, `rethrow` and
+    /// `delegate` instructions are not supported.
+    ///
+    /// Note that enabling this feature adds a check for a thrown exception
+    /// after every call in compiled code.
+    ///
+    /// This feature is `false` by default.
+    ///
+    /// [proposal]: https://github.com/WebAssembly/exception-handling
+    /// [`Tag`]: crate::Tag
+    /// [`ThrownException`]: crate::ThrownException
+    pub fn wasm_exceptions(&mut self, enable: bool) -> &mut Self {
+        self.features.exceptions = enable;
+        self.tunables.exceptions = enable;
+        self
+    }
+
     /// Configures whether the WebAssembly SIMD proposal will be
     /// enabled for compilation.
     ///
@@ -790,6 +899,55 @@ impl Config {
         self
     }
 
+    /// Configures whether WebAssembly execution is guaranteed to be
+    /// deterministic, producing bit-identical results on all hosts.
+    ///
+    /// WebAssembly is deterministic with a few exceptions, and this option
+    /// configures Wasmtime to close off each of them. Enabling this option:
+    ///
+    /// * Enables [`Config::cranelift_nan_canonicalization`] so that the bit
+    ///   patterns of NaN values produced by floating-point instructions are
+    ///   the same on every platform.
+    /// * Enables [`Config::relaxed_simd_deterministic`] so that relaxed SIMD
+    ///   instructions have the same behavior on every platform.
+    /// * Disables [`Config::wasm_threads`], since shared memories may be
+    ///   observed in a nondeterministic order.
+    /// * Makes a `memory.grow` which fails for a host-specific reason, such as
+    ///   the host failing to allocate memory, trap instead of returning -1.
+    ///   Growth beyond a memory's maximum or rejected by a
+    ///   [`ResourceLimiter`](crate::ResourceLimiter) still returns -1.
+    ///
+    /// Stack exhaustion is the one exception which this option doesn't close
+    /// off. Whether a call traps with a stack overflow depends on the sizes of
+    /// native stack frames, which differ between hosts, architectures and
+    /// versions of Wasmtime, as well as on [`Config::max_wasm_stack`]. Modules
+    /// which recurse deeply may therefore trap on some hosts but not on others.
+    ///
+    /// This option only affects compiled code and the runtime. WASI clocks and
+    /// random number generators read the host's state unless their context is
+    /// also made deterministic, for example with
+    /// `wasmtime_wasi::preview2::WasiCtxBuilder::deterministic`.
+    ///
+    /// Settings which contradict this option are rejected when the
+    /// [`Engine`](crate::Engine) is created: re-enabling any of the above after
+    /// calling this method, enabling [`Config::epoch_interruption`], whose
+    /// interruptions depend on timing, or using the Winch compiler, which
+    /// doesn't support NaN canonicalization. Fuel, configured with
+    /// [`Config::consume_fuel`], is deterministic and may be used to bound
+    /// execution instead of epochs.
+    ///
+    /// This is `false` by default.
+    pub fn deterministic(&mut self, enable: bool) -> &mut Self {
+        self.deterministic = enable;
+        if enable {
+            self.features.threads = false;
+            self.tunables.relaxed_simd_deterministic = true;
+            #[cfg(any(feature = "cranelift", feature = "winch"))]
+            self.cranelift_nan_canonicalization(true);
+        }
+        self
+    }
+
     /// Configures whether the [WebAssembly bulk memory operations
     /// proposal][proposal] will be enabled for compilation.
     ///
@@ -856,6 +1014,21 @@ impl Config {
         self
     }
 
+    /// Configures whether the WebAssembly extended-const [proposal] will be
+    /// enabled for compilation.
+    ///
+    /// This proposal allows the `add`, `sub` and `mul` instructions for `i32`
+    /// and `i64` in constant expressions, such as global initializers and the
+    /// offsets of active data and element segments.
+    ///
+    /// This is `false` by default.
+    ///
+    /// [proposal]: https://github.com/webassembly/extended-const
+    pub fn wasm_extended_const(&mut self, enable: bool) -> &mut Self {
+        self.features.extended_const = enable;
+        self
+    }
+
     /// Configures whether the WebAssembly component-model [proposal] will
     /// be enabled for compilation.
     ///
@@ -1584,6 +1757,9 @@ impl Config {
         if self.features.reference_types && !self.features.bulk_memory {
             bail!("feature 'reference_types' requires 'bulk_memory' to be enabled");
         }
+        if self.features.gc && !self.features.function_references {
+            bail!("feature 'gc' requires 'function_references' to be enabled");
+        }
         if self.features.threads && !self.features.bulk_memory {
             bail!("feature 'threads' requires 'bulk_memory' to be enabled");
         }
@@ -1603,6 +1779,19 @@ impl Config {
         if self.wmemcheck {
             bail!("wmemcheck (memory checker) was requested but is not enabled in this build");
         }
+        if self.deterministic {
+            if self.features.threads {
+                bail!("deterministic execution requires the threads proposal to be disabled");
+            }
+            if !self.tunables.relaxed_simd_deterministic {
+                bail!(
+                    "deterministic execution requires `relaxed_simd_deterministic` to be enabled"
+                );
+            }
+            if self.tunables.epoch_interruption {
+                bail!("deterministic execution is incompatible with epoch interruption");
+            }
+        }
 
         Ok(())
     }
@@ -1707,6 +1896,47 @@ impl Config {
             );
         }
 
+        if self.features.gc {
+            ensure!(
+                self.compiler_config.strategy != Strategy::Winch,
+                "the GC proposal is not supported by Winch yet"
+            );
+        }
+
+        if self.features.exceptions {
+            ensure!(
+                self.compiler_config.strategy != Strategy::Winch,
+                "the exception handling proposal is not supported by Winch yet"
+            );
+        }
+
+        if self.tunables.guest_debug {
+            ensure!(
+                self.compiler_config.strategy != Strategy::Winch,
+                "guest debugging is not supported by Winch yet"
+            );
+        }
+
+        if self.tunables.coverage {
+            ensure!(
+                self.compiler_config.strategy != Strategy::Winch,
+                "coverage is not supported by Winch yet"
+            );
+        }
+
+        if self.deterministic {
+            ensure!(
+                self.compiler_config.strategy != Strategy::Winch,
+                "deterministic execution is not supported by Winch yet"
+            );
+            if !self
+                .compiler_config
+                .ensure_setting_unset_or_given("enable_nan_canonicalization", "true")
+            {
+                bail!("deterministic execution requires NaN canonicalization to be enabled");
+            }
+        }
+
         if let Some(unwind_requested) = self.native_unwind_info {
             if !self
                 .compiler_config
@@ -1831,6 +2061,8 @@ impl fmt::Debug for Config {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         let mut f = f.debug_struct("Config");
         f.field("debug_info", &self.tunables.generate_native_debuginfo)
+            .field("guest_debug", &self.tunables.guest_debug)
+            .field("coverage", &self.tunables.coverage)
             .field("parse_wasm_debuginfo", &self.tunables.parse_wasm_debuginfo)
             .field("wasm_threads", &self.features.threads)
             .field("wasm_reference_types", &self.features.reference_types)
@@ -1838,10 +2070,14 @@ impl fmt::Debug for Config {
                 "wasm_function_references",
                 &self.features.function_references,
             )
+            .field("wasm_gc", &self.features.gc)
+            .field("wasm_exceptions", &self.features.exceptions)
             .field("wasm_bulk_memory", &self.features.bulk_memory)
             .field("wasm_simd", &self.features.simd)
             .field("wasm_relaxed_simd", &self.features.relaxed_simd)
             .field("wasm_multi_value", &self.features.multi_value)
+            .field("wasm_extended_const", &self.features.extended_const)
+            .field("deterministic", &self.deterministic)
             .field(
                 "static_memory_maximum_size",
                 &(u64::from(self.tunables.static_memory_bound)
@@ -169,6 +169,14 @@ impl WasmCoreDump {
                     ValType::V128 => wasm_encoder::ValType::V128,
                     ValType::FuncRef => wasm_encoder::ValType::FUNCREF,
                     ValType::ExternRef => wasm_encoder::ValType::EXTERNREF,
+                    ValType::AnyRef => wasm_encoder::ValType::Ref(wasm_encoder::RefType {
+                        nullable: true,
+                        heap_type: wasm_encoder::HeapType::Any,
+                    }),
+                    ValType::ExnRef => wasm_encoder::ValType::Ref(wasm_encoder::RefType {
+                        nullable: true,
+                        heap_type: wasm_encoder::HeapType::Exn,
+                    }),
                 };
                 let init = match g.get(&mut store) {
                     Val::I32(x) => wasm_encoder::ConstExpr::i32_const(x),
@@ -186,6 +194,12 @@ impl WasmCoreDump {
                     Val::ExternRef(_) => {
                         wasm_encoder::ConstExpr::ref_null(wasm_encoder::HeapType::Extern)
                     }
+                    Val::AnyRef(_) => {
+                        wasm_encoder::ConstExpr::ref_null(wasm_encoder::HeapType::Any)
+                    }
+                    Val::ExnRef(_) => {
+                        wasm_encoder::ConstExpr::ref_null(wasm_encoder::HeapType::Exn)
+                    }
                 };
                 globals.global(wasm_encoder::GlobalType { val_type, mutable }, &init);
             }
@@ -0,0 +1,157 @@
+//! Counting how many times each basic block of WebAssembly code is entered,
+//! see [`Config::coverage`].
+//!
+//! [`Config::coverage`]: crate::Config::coverage
+
+use crate::{CompiledModule, FrameSymbol};
+use std::ops::Range;
+use std::sync::atomic::{AtomicU64, Ordering};
+use wasmtime_environ::{DefinedFuncIndex, PrimaryMap};
+
+/// The coverage counters of a module, shared by all of its instances.
+pub(crate) struct CoverageCounters {
+    counters: Box<[AtomicU64]>,
+    /// The range of `counters` which belongs to each defined function.
+    ranges: PrimaryMap<DefinedFuncIndex, Range<usize>>,
+    /// The pointer to the first counter of each defined function, which is
+    /// where compiled code finds its counters.
+    ptrs: Box<[*mut u64]>,
+}
+
+// The raw pointers in `ptrs` point into `counters`, which is only ever
+// accessed atomically from Rust.
+unsafe impl Send for CoverageCounters {}
+unsafe impl Sync for CoverageCounters {}
+
+impl CoverageCounters {
+    pub(crate) fn new(module: &CompiledModule) -> CoverageCounters {
+        let mut ranges = PrimaryMap::new();
+        let mut len = 0;
+        for (index, _) in module.module().functions.iter() {
+            if let Some(index) = module.module().defined_func_index(index) {
+                let blocks = module.wasm_func_info(index).coverage.len();
+                ranges.push(len..len + blocks);
+                len += blocks;
+            }
+        }
+        let counters: Box<[AtomicU64]> = (0..len).map(|_| AtomicU64::new(0)).collect();
+        let ptrs = ranges
+            .values()
+            .map(|range| counters[range.start..].as_ptr().cast::<u64>().cast_mut())
+            .collect();
+        CoverageCounters {
+            counters,
+            ranges,
+            ptrs,
+        }
+    }
+
+    /// Returns the array of pointers to the counters of each defined
+    /// function, which is stored in the vmctx of each instance.
+    pub(crate) fn ptrs(&self) -> *const *mut u64 {
+        self.ptrs.as_ptr()
+    }
+
+    pub(crate) fn reset(&self) {
+        for counter in self.counters.iter() {
+            counter.store(0, Ordering::Relaxed);
+        }
+    }
+
+    pub(crate) fn report(&self, module: &CompiledModule) -> Vec<FunctionCoverage> {
+        #[cfg(feature = "addr2line")]
+        let cx = module.symbolize_context().ok().flatten();
+        self.ranges
+            .iter()
+            .map(|(index, range)| {
+                let info = module.wasm_func_info(index);
+                let blocks = info
+                    .coverage
+                    .iter()
+                    .zip(&self.counters[range.clone()])
+                    .map(|(pos, count)| {
+                        let offset = pos.file_offset().unwrap();
+                        #[cfg(feature = "addr2line")]
+                        let symbols = match &cx {
+                            Some(cx) => FrameSymbol::lookup(cx, offset),
+                            None => Vec::new(),
+                        };
+                        #[cfg(not(feature = "addr2line"))]
+                        let symbols = Vec::new();
+                        BlockCoverage {
+                            module_offset: usize::try_from(offset).unwrap(),
+                            count: count.load(Ordering::Relaxed),
+                            symbols,
+                        }
+                    })
+                    .collect();
+                let index = module.module().func_index(index);
+                FunctionCoverage {
+                    func_index: index.as_u32(),
+                    func_name: module.func_name(index).map(|s| s.to_string()),
+                    blocks,
+                }
+            })
+            .collect()
+    }
+}
+
+/// How many times each basic block of a WebAssembly function was entered,
+/// see [`Module::coverage`](crate::Module::coverage).
+#[derive(Debug)]
+pub struct FunctionCoverage {
+    func_index: u32,
+    func_name: Option<String>,
+    blocks: Vec<BlockCoverage>,
+}
+
+impl FunctionCoverage {
+    /// Returns the index of this function within its module.
+    pub fn func_index(&self) -> u32 {
+        self.func_index
+    }
+
+    /// Returns the name of this function, if one is available, in the same
+    /// way as [`FrameInfo::func_name`](crate::FrameInfo::func_name).
+    pub fn func_name(&self) -> Option<&str> {
+        self.func_name.as_deref()
+    }
+
+    /// Returns the basic blocks of this function, in the order of the
+    /// offsets of their first instruction.
+    ///
+    /// The first block is the entry block, so its count is the number of
+    /// times this function was called.
+    pub fn blocks(&self) -> &[BlockCoverage] {
+        &self.blocks
+    }
+}
+
+/// How many times a basic block of a WebAssembly function was entered, see
+/// [`FunctionCoverage`].
+#[derive(Debug)]
+pub struct BlockCoverage {
+    module_offset: usize,
+    count: u64,
+    symbols: Vec<FrameSymbol>,
+}
+
+impl BlockCoverage {
+    /// Returns the offset, within the original wasm module, of the first
+    /// instruction of this block.
+    pub fn module_offset(&self) -> usize {
+        self.module_offset
+    }
+
+    /// Returns the number of times this block was entered.
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+
+    /// Returns the source locations of the first instruction of this block,
+    /// if DWARF debug information is present, in the same way as
+    /// [`FrameInfo::symbols`](crate::FrameInfo::symbols).
+    pub fn symbols(&self) -> &[FrameSymbol] {
+        &self.symbols
+    }
+}
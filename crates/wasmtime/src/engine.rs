@@ -131,6 +131,13 @@ impl Engine {
         &self.inner.config
     }
 
+    /// Returns whether this engine was configured with
+    /// [`Config::deterministic`].
+    #[inline]
+    pub fn is_deterministic(&self) -> bool {
+        self.inner.config.deterministic
+    }
+
     #[cfg(any(feature = "cranelift", feature = "winch"))]
     pub(crate) fn compiler(&self) -> &dyn wasmtime_environ::Compiler {
         &*self.inner.compiler
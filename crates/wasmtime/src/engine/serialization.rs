@@ -199,6 +199,7 @@ struct WasmFeatures {
     relaxed_simd: bool,
     extended_const: bool,
     function_references: bool,
+    gc: bool,
 }
 
 impl Metadata<'_> {
@@ -231,7 +232,6 @@ impl Metadata<'_> {
         } = engine.config().features;
 
         assert!(!memory_control);
-        assert!(!gc);
         assert!(!component_model_values);
         assert!(!component_model_nested_names);
 
@@ -254,6 +254,7 @@ impl Metadata<'_> {
                 relaxed_simd,
                 extended_const,
                 function_references,
+                gc,
             },
         }
     }
@@ -348,6 +349,9 @@ impl Metadata<'_> {
             guard_before_linear_memory,
             relaxed_simd_deterministic,
             tail_callable,
+            exceptions,
+            guest_debug,
+            coverage,
 
             // This doesn't affect compilation, it's just a runtime setting.
             dynamic_memory_growth_reserve: _,
@@ -409,6 +413,13 @@ impl Metadata<'_> {
             "relaxed simd deterministic semantics",
         )?;
         Self::check_bool(tail_callable, other.tail_callable, "WebAssembly tail calls")?;
+        Self::check_bool(
+            exceptions,
+            other.exceptions,
+            "WebAssembly exception handling",
+        )?;
+        Self::check_bool(guest_debug, other.guest_debug, "guest debugging support")?;
+        Self::check_bool(coverage, other.coverage, "coverage instrumentation")?;
 
         Ok(())
     }
@@ -428,6 +439,7 @@ impl Metadata<'_> {
             relaxed_simd,
             extended_const,
             function_references,
+            gc,
         } = self.features;
 
         Self::check_bool(
@@ -483,6 +495,7 @@ impl Metadata<'_> {
             other.function_references,
             "WebAssembly function-references support",
         )?;
+        Self::check_bool(gc, other.gc, "WebAssembly GC support")?;
 
         Ok(())
     }
@@ -0,0 +1,107 @@
+use crate::store::StoreOpaque;
+use crate::ExnRef;
+use anyhow::{bail, Result};
+use std::fmt;
+
+/// An error representing a WebAssembly exception, thrown either by
+/// WebAssembly or by a host function.
+///
+/// When WebAssembly throws an exception that isn't caught before it reaches
+/// the host, the call into WebAssembly returns an error which can be
+/// downcast to a `ThrownException` to inspect the exception.
+///
+/// Conversely, a host function called from WebAssembly can throw an exception
+/// by returning an error containing a `ThrownException`, which unwinds the
+/// WebAssembly frames up to the innermost handler for its tag. Exceptions that
+/// pass back through a host function without being caught, for example by
+/// propagating its error with `?`, are rethrown into its caller in this way.
+///
+/// This requires [`Config::wasm_exceptions`](crate::Config::wasm_exceptions)
+/// to be enabled; otherwise an exception returned from a host function is
+/// treated like any other error and traps.
+///
+/// # Example
+///
+/// ```
+/// # use wasmtime::*;
+/// # fn main() -> anyhow::Result<()> {
+/// let mut config = Config::new();
+/// config.wasm_exceptions(true);
+/// let engine = Engine::new(&config)?;
+/// let mut store = Store::new(&engine, ());
+/// let module = Module::new(
+///     &engine,
+///     r#"
+///         (module
+///             (tag $t (export "t") (param i32))
+///             (func (export "run") (param i32)
+///                 (throw $t (local.get 0))))
+///     "#,
+/// )?;
+/// let instance = Instance::new(&mut store, &module, &[])?;
+/// let run = instance.get_typed_func::<i32, ()>(&mut store, "run")?;
+///
+/// let error = run.call(&mut store, 42).unwrap_err();
+/// let exn = error.downcast::<ThrownException>()?.into_exnref();
+/// let tag = instance.get_tag(&mut store, "t").unwrap();
+/// assert!(Tag::eq(&exn.tag(&mut store), &tag, &store));
+/// assert_eq!(exn.fields(&mut store)[0].unwrap_i32(), 42);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct ThrownException(ExnRef);
+
+impl ThrownException {
+    /// Creates an error which throws `exn` when returned from a host function.
+    pub fn new(exn: ExnRef) -> ThrownException {
+        ThrownException(exn)
+    }
+
+    /// Returns the exception that was thrown.
+    pub fn exnref(&self) -> &ExnRef {
+        &self.0
+    }
+
+    /// Returns the exception that was thrown.
+    pub fn into_exnref(self) -> ExnRef {
+        self.0
+    }
+}
+
+impl fmt::Display for ThrownException {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "uncaught wasm exception")
+    }
+}
+
+impl std::error::Error for ThrownException {}
+
+/// Handles the `error` returned by a host function called from wasm.
+///
+/// If it's a [`ThrownException`] the exception becomes the store's pending
+/// exception, which compiled code checks for after the call returns, and
+/// `Ok` is returned. Otherwise the error is returned to be raised as a trap.
+pub(crate) fn throw_from_host(store: &mut StoreOpaque, error: anyhow::Error) -> Result<()> {
+    if !store.engine().config().tunables.exceptions {
+        return Err(error);
+    }
+    let exn = error.downcast::<ThrownException>()?.into_exnref();
+    if exn.store_id() != store.id() {
+        bail!("cross-`Store` exceptions are not supported");
+    }
+    unsafe {
+        wasmtime_runtime::set_pending_exception(store.runtime_limits(), exn.inner);
+    }
+    Ok(())
+}
+
+/// Takes the store's pending exception, if any, after a call into wasm has
+/// returned, as a [`ThrownException`] error.
+pub(crate) fn take_pending_exception(store: &mut StoreOpaque) -> Result<()> {
+    let exn = unsafe { wasmtime_runtime::take_pending_exception(store.runtime_limits()) };
+    match exn {
+        Some(exn) => Err(ThrownException(ExnRef::from_vm(exn, store.id())).into()),
+        None => Ok(()),
+    }
+}
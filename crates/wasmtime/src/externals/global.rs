@@ -1,9 +1,12 @@
 use crate::store::{StoreData, StoreOpaque, Stored};
 use crate::trampoline::generate_global_export;
-use crate::{AsContext, AsContextMut, ExternRef, Func, GlobalType, Mutability, Val, ValType};
+use crate::{
+    AnyRef, AsContext, AsContextMut, ExnRef, ExternRef, Func, GlobalType, Mutability, Val, ValType,
+};
 use anyhow::{bail, Result};
 use std::mem;
 use std::ptr;
+use wasmtime_environ::WasmType;
 
 /// A WebAssembly `global` value which can be read and written to.
 ///
@@ -118,6 +121,13 @@ impl Global {
                     Val::FuncRef(Func::from_raw(store, definition.as_func_ref().cast()))
                 }
                 ValType::V128 => Val::V128((*definition.as_u128()).into()),
+                ValType::AnyRef => Val::AnyRef(AnyRef::from_raw(store, *definition.as_u32())),
+                ValType::ExnRef => Val::ExnRef(
+                    definition
+                        .as_externref()
+                        .clone()
+                        .map(|inner| ExnRef::from_vm(inner, store.0.id())),
+                ),
             }
         }
     }
@@ -146,6 +156,11 @@ impl Global {
         if !val.comes_from_same_store(store) {
             bail!("cross-`Store` values are not supported");
         }
+        if let (Val::AnyRef(a), WasmType::Ref(r)) = (&val, store[self.0].global.wasm_ty) {
+            if !AnyRef::matches_ty(a.as_ref(), store, r) {
+                bail!("global of type `{r}` cannot be set to this `anyref`");
+            }
+        }
         unsafe {
             let definition = &mut *store[self.0].definition;
             match val {
@@ -162,6 +177,11 @@ impl Global {
                     drop(old);
                 }
                 Val::V128(i) => *definition.as_u128_mut() = i.into(),
+                Val::AnyRef(a) => *definition.as_u32_mut() = a.map_or(0, |a| a._to_raw(store)),
+                Val::ExnRef(x) => {
+                    let old = mem::replace(definition.as_externref_mut(), x.map(|x| x.inner));
+                    drop(old);
+                }
             }
         }
         Ok(())
@@ -0,0 +1,149 @@
+use crate::store::{StoreData, StoreOpaque, Stored};
+use crate::trampoline::generate_tag_export;
+use crate::{AsContext, AsContextMut, TagType};
+use anyhow::{bail, Result};
+use std::ptr::NonNull;
+use wasmtime_runtime::{VMSharedSignatureIndex, VMTagDefinition};
+
+/// A WebAssembly exception `tag`.
+///
+/// Tags are defined or imported by wasm modules using the [exception handling
+/// proposal] and identify the kind of an exception: a `throw` instruction
+/// creates an exception with a tag and payload values whose types are given by
+/// the tag's [`TagType`], and `catch` clauses of `try_table` instructions
+/// select the exceptions they handle by tag. Two tags are only the same tag if
+/// they come from the same definition, even if their types are equal.
+///
+/// Exceptions thrown by WebAssembly that aren't caught are returned to the
+/// host as a [`ThrownException`](crate::ThrownException) error, and host
+/// functions can throw exceptions into WebAssembly in the same way.
+///
+/// A [`Tag`] "belongs" to the store that it was originally created within
+/// (either via [`Tag::new`] or via instantiating a
+/// [`Module`](crate::Module)). Operations on a [`Tag`] only work with the
+/// store it belongs to, and if another store is passed in by accident then
+/// methods will panic.
+///
+/// [exception handling proposal]: https://github.com/WebAssembly/exception-handling
+#[derive(Copy, Clone, Debug)]
+#[repr(transparent)] // here for the C API
+pub struct Tag(pub(super) Stored<wasmtime_runtime::ExportTag>);
+
+impl Tag {
+    /// Creates a new tag of the type `ty`, distinct from all other tags.
+    ///
+    /// The `store` argument will be the owner of the [`Tag`] returned. The tag
+    /// can be provided as an import to wasm modules, for example with
+    /// [`Linker::define`](crate::Linker::define).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the exception handling proposal is not enabled
+    /// within the store's [`Config`](crate::Config).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use wasmtime::*;
+    /// # fn main() -> anyhow::Result<()> {
+    /// let mut config = Config::new();
+    /// config.wasm_exceptions(true);
+    /// let engine = Engine::new(&config)?;
+    /// let mut store = Store::new(&engine, ());
+    ///
+    /// let tag = Tag::new(&mut store, &TagType::new([ValType::I32]))?;
+    ///
+    /// let module = Module::new(
+    ///     &engine,
+    ///     r#"
+    ///         (module
+    ///             (tag $t (import "" "tag") (param i32))
+    ///             (func (export "run") (result i32)
+    ///                 (block $handler (result i32)
+    ///                     (try_table (catch $t $handler)
+    ///                         (throw $t (i32.const 42)))
+    ///                     unreachable)))
+    ///     "#,
+    /// )?;
+    ///
+    /// let mut linker = Linker::new(&engine);
+    /// linker.define(&store, "", "tag", tag)?;
+    /// let instance = linker.instantiate(&mut store, &module)?;
+    /// let run = instance.get_typed_func::<(), i32>(&mut store, "run")?;
+    /// assert_eq!(run.call(&mut store, ())?, 42);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn new(mut store: impl AsContextMut, ty: &TagType) -> Result<Tag> {
+        Tag::_new(store.as_context_mut().0, ty)
+    }
+
+    fn _new(store: &mut StoreOpaque, ty: &TagType) -> Result<Tag> {
+        if !store.engine().config().features.exceptions {
+            bail!("exception handling support is not enabled");
+        }
+        unsafe {
+            let export = generate_tag_export(store, ty);
+            Ok(Tag::from_wasmtime_tag(export, store))
+        }
+    }
+
+    /// Returns the type of this tag.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `store` does not own this tag.
+    pub fn ty(&self, store: impl AsContext) -> TagType {
+        let store = store.as_context();
+        let ty = store
+            .engine()
+            .signatures()
+            .lookup_type(self.sig_index(store.0.store_data()))
+            .expect("signature should be registered");
+        TagType::from_wasm_func_type(ty)
+    }
+
+    /// Returns whether `a` and `b` are the same tag.
+    ///
+    /// Getting an export of an instance multiple times creates distinct
+    /// [`Tag`] values which all refer to the same tag, and exceptions thrown
+    /// with any of them are caught by handlers for the others.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `store` does not own both of `a` and `b`.
+    pub fn eq(a: &Tag, b: &Tag, store: impl AsContext) -> bool {
+        let store = store.as_context().0;
+        a.definition(store.store_data()) == b.definition(store.store_data())
+    }
+
+    pub(crate) unsafe fn from_wasmtime_tag(
+        wasmtime_export: wasmtime_runtime::ExportTag,
+        store: &mut StoreOpaque,
+    ) -> Tag {
+        Tag(store.store_data_mut().insert(wasmtime_export))
+    }
+
+    /// Creates a [`Tag`] for the definition `definition`, which must be owned
+    /// by `store`.
+    pub(crate) unsafe fn from_definition(
+        definition: *mut VMTagDefinition,
+        store: &mut StoreOpaque,
+    ) -> Tag {
+        Tag::from_wasmtime_tag(wasmtime_runtime::ExportTag { definition }, store)
+    }
+
+    pub(crate) fn definition(&self, data: &StoreData) -> NonNull<VMTagDefinition> {
+        NonNull::new(data[self.0].definition).unwrap()
+    }
+
+    pub(crate) fn sig_index(&self, data: &StoreData) -> VMSharedSignatureIndex {
+        unsafe { (*data[self.0].definition).type_index }
+    }
+
+    pub(crate) fn vmimport(&self, store: &StoreOpaque) -> wasmtime_runtime::VMTagImport {
+        wasmtime_runtime::VMTagImport {
+            from: store[self.0].definition,
+        }
+    }
+}
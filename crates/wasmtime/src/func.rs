@@ -1,7 +1,8 @@
+use crate::record::{CoreCaller, TracedCall};
 use crate::store::{StoreData, StoreOpaque, Stored};
 use crate::{
-    AsContext, AsContextMut, CallHook, Engine, Extern, FuncType, Instance, Module, StoreContext,
-    StoreContextMut, Val, ValRaw, ValType,
+    AnyRef, AsContext, AsContextMut, CallHook, Engine, Extern, FuncType, Instance, Module,
+    StoreContext, StoreContextMut, Val, ValRaw, ValType,
 };
 use anyhow::{bail, Context as _, Error, Result};
 use std::ffi::c_void;
@@ -11,6 +12,7 @@ use std::panic::{self, AssertUnwindSafe};
 use std::pin::Pin;
 use std::ptr::{self, NonNull};
 use std::sync::Arc;
+use wasmtime_environ::{WasmRefType, WasmType};
 use wasmtime_runtime::{
     ExportFunction, SendSyncPtr, StoreBox, VMArrayCallHostFuncContext, VMContext, VMFuncRef,
     VMFunctionImport, VMNativeCallHostFuncContext, VMOpaqueContext, VMSharedSignatureIndex,
@@ -1023,7 +1025,7 @@ impl Func {
                 results.len()
             );
         }
-        for (ty, arg) in ty.params().zip(params) {
+        for ((ty, wasm_ty), arg) in ty.params().zip(ty.as_wasm_func_type().params()).zip(params) {
             if arg.ty() != ty {
                 bail!(
                     "argument type mismatch: found {} but expected {}",
@@ -1034,6 +1036,11 @@ impl Func {
             if !arg.comes_from_same_store(opaque) {
                 bail!("cross-`Store` values are not currently supported");
             }
+            if let (Val::AnyRef(a), WasmType::Ref(r)) = (arg, wasm_ty) {
+                if !AnyRef::matches_ty(a.as_ref(), opaque, *r) {
+                    bail!("argument type mismatch: `anyref` is not of type `{r}`");
+                }
+            }
         }
 
         let values_vec_size = params.len().max(ty.results().len());
@@ -1194,13 +1201,23 @@ impl Func {
         // values produced are correct. There could be a bug in `func` that
         // produces the wrong number, wrong types, or wrong stores of
         // values, and we need to catch that here.
-        for (i, (ret, ty)) in results.iter().zip(ty.results()).enumerate() {
-            if ret.ty() != ty {
+        for (i, ((ret, ret_ty), wasm_ty)) in results
+            .iter()
+            .zip(ty.results())
+            .zip(ty.as_wasm_func_type().returns())
+            .enumerate()
+        {
+            if ret.ty() != ret_ty {
                 bail!("function attempted to return an incompatible value");
             }
             if !ret.comes_from_same_store(caller.store.0) {
                 bail!("cross-`Store` values are not currently supported");
             }
+            if let (Val::AnyRef(a), WasmType::Ref(r)) = (ret, wasm_ty) {
+                if !AnyRef::matches_ty(a.as_ref(), caller.store.0, *r) {
+                    bail!("function attempted to return an incompatible value");
+                }
+            }
             unsafe {
                 values_vec[i] = ret.to_raw(&mut caller.store);
             }
@@ -1333,6 +1350,17 @@ impl Func {
         Params::typecheck(ty.params()).context("type mismatch with parameters")?;
         Results::typecheck(ty.results()).context("type mismatch with results")?;
 
+        // Parameters of more specific types than `anyref` would require
+        // dynamic checks of the values passed to them, which typed calls
+        // don't perform.
+        for param in ty.as_wasm_func_type().params() {
+            if let WasmType::Ref(r) = param {
+                if r.heap_type.is_anyref() && *r != WasmRefType::ANYREF {
+                    bail!("typed parameters of type `{r}` are not supported, use `Func::call`");
+                }
+            }
+        }
+
         // and then we can construct the typed version of this function
         // (unsafely), which should be safe since we just did the type check above.
         unsafe { Ok(TypedFunc::new_unchecked(*self)) }
@@ -1375,9 +1403,13 @@ pub(crate) fn invoke_wasm_and_catch_traps<T>(
             store.0.default_caller(),
             closure,
         );
+        // Take any exception that wasm didn't catch before anything else, so
+        // that it's always cleared from the store.
+        let exn = crate::exception::take_pending_exception(store.0);
         exit_wasm(store, exit);
         store.0.call_hook(CallHook::ReturningFromWasm)?;
-        result.map_err(|t| crate::trap::from_runtime_box(store.0, t))
+        result.map_err(|t| crate::trap::from_runtime_box(store.0, t))?;
+        exn
     }
 }
 
@@ -1494,6 +1526,14 @@ pub unsafe trait WasmRet {
     #[doc(hidden)]
     unsafe fn wrap_trampoline(ptr: *mut ValRaw, f: impl FnOnce(Self::Retptr) -> Self::Abi);
 
+    // Converts raw values stored at `ptr` into the ABI representation of this
+    // type, storing all but the first value into `retptr`, and the reverse.
+    // These are used to record and replay host calls.
+    #[doc(hidden)]
+    unsafe fn abi_from_raw(ptr: *mut ValRaw, retptr: Self::Retptr) -> Self::Abi;
+    #[doc(hidden)]
+    unsafe fn abi_into_raw(abi: Self::Abi, retptr: Self::Retptr, ptr: *mut ValRaw);
+
     // Utilities used to convert an instance of this type to a `Result`
     // explicitly, used when wrapping async functions which always bottom-out
     // in a function that returns a trap because futures can be cancelled.
@@ -1529,6 +1569,14 @@ where
         T::abi_into_raw(f(()), ptr);
     }
 
+    unsafe fn abi_from_raw(ptr: *mut ValRaw, _retptr: ()) -> Self::Abi {
+        <T as WasmTy>::abi_from_raw(ptr)
+    }
+
+    unsafe fn abi_into_raw(abi: Self::Abi, _retptr: (), ptr: *mut ValRaw) {
+        <T as WasmTy>::abi_into_raw(abi, ptr)
+    }
+
     fn into_fallible(self) -> Result<T> {
         Ok(self)
     }
@@ -1569,6 +1617,14 @@ where
         T::wrap_trampoline(ptr, f)
     }
 
+    unsafe fn abi_from_raw(ptr: *mut ValRaw, retptr: Self::Retptr) -> Self::Abi {
+        T::abi_from_raw(ptr, retptr)
+    }
+
+    unsafe fn abi_into_raw(abi: Self::Abi, retptr: Self::Retptr, ptr: *mut ValRaw) {
+        T::abi_into_raw(abi, retptr, ptr)
+    }
+
     fn into_fallible(self) -> Result<T> {
         self
     }
@@ -1619,6 +1675,24 @@ macro_rules! impl_wasm_host_results {
                 )*
             }
 
+            #[allow(unused_assignments)]
+            unsafe fn abi_from_raw(mut _ptr: *mut ValRaw, retptr: Self::Retptr) -> Self::Abi {
+                $(
+                    let $t = $t::abi_from_raw(_ptr);
+                    _ptr = _ptr.add(1);
+                )*
+                <($($t::Abi,)*) as HostAbi>::into_abi(($($t,)*), retptr)
+            }
+
+            #[allow(unused_assignments)]
+            unsafe fn abi_into_raw(abi: Self::Abi, retptr: Self::Retptr, mut _ptr: *mut ValRaw) {
+                let ($($t,)*) = <($($t::Abi,)*) as HostAbi>::from_abi(abi, retptr);
+                $(
+                    $t::abi_into_raw($t, _ptr);
+                    _ptr = _ptr.add(1);
+                )*
+            }
+
             #[inline]
             fn into_fallible(self) -> Result<Self> {
                 Ok(self)
@@ -1655,6 +1729,10 @@ pub trait HostAbi {
     // function.
     unsafe fn into_abi(self, ptr: Self::Retptr) -> Self::Abi;
 
+    // The reverse of `into_abi`, reassembling an instance of `Self` from its
+    // components.
+    unsafe fn from_abi(abi: Self::Abi, ptr: Self::Retptr) -> Self;
+
     // Calls `f` with a suitably sized return area and requires `f` to return
     // the raw abi value of the first element of our tuple. This will then
     // unpack the `Retptr` and assemble it with `Self::Abi` to return an
@@ -1672,6 +1750,9 @@ macro_rules! impl_host_abi {
             #[inline]
             unsafe fn into_abi(self, _ptr: Self::Retptr) -> Self::Abi {}
 
+            #[inline]
+            unsafe fn from_abi(_abi: Self::Abi, _ptr: Self::Retptr) -> Self {}
+
             #[inline]
             unsafe fn call(f: impl FnOnce(Self::Retptr) -> Self::Abi) -> Self {
                 f(())
@@ -1689,6 +1770,10 @@ macro_rules! impl_host_abi {
                 self.0
             }
 
+            unsafe fn from_abi(abi: Self::Abi, _ptr: Self::Retptr) -> Self {
+                (abi,)
+            }
+
             unsafe fn call(f: impl FnOnce(Self::Retptr) -> Self::Abi) -> Self {
                 (f(()),)
             }
@@ -1724,6 +1809,10 @@ macro_rules! impl_host_abi {
                 $t
             }
 
+            unsafe fn from_abi(abi: Self::Abi, ptr: Self::Retptr) -> Self {
+                (abi, $((*ptr).$u,)*)
+            }
+
             unsafe fn call(f: impl FnOnce(Self::Retptr) -> Self::Abi) -> Self {
                 // Create space to store all the return values and then invoke
                 // the function.
@@ -1797,6 +1886,27 @@ impl<T> Caller<'_, T> {
         }
     }
 
+    /// Calls `func`, a host function of type `ty` whose parameters and
+    /// results are stored in `values`, while recording the call or replaying
+    /// it instead of calling `func`.
+    fn trace_host_call(
+        &mut self,
+        ty: &FuncType,
+        values: &mut [ValRaw],
+        func: impl FnOnce(Caller<'_, T>, &mut [ValRaw]) -> Result<()>,
+    ) -> Result<()> {
+        let mut caller = CoreCaller::new(self.caller);
+        match caller.begin(self.store.0, ty, values)? {
+            TracedCall::Untraced => func(self.sub_caller(), values),
+            TracedCall::Record(params) => {
+                let result = func(self.sub_caller(), values);
+                caller.finish(self.store.0, params, ty, values, result.as_ref().err());
+                result
+            }
+            TracedCall::Replay(_) => Ok(()),
+        }
+    }
+
     /// Looks up an export from the caller's module by the `name` given.
     ///
     /// This is a low-level function that's typically used to implement passing
@@ -1944,6 +2054,7 @@ macro_rules! impl_into_func {
                 {
                     enum CallResult<U> {
                         Ok(U),
+                        Thrown,
                         Trap(anyhow::Error),
                         Panic(Box<dyn std::any::Any + Send>),
                     }
@@ -1965,6 +2076,47 @@ macro_rules! impl_into_func {
                         debug_assert!(state.is::<F>());
                         let func = &*(state as *const _ as *const F);
 
+                        // Recording and replaying host calls works with raw
+                        // values, which is slower than the path below.
+                        if caller.store.0.host_call_tracer().is_enabled() {
+                            let ty = R::func_type(
+                                None::<ValType>.into_iter()
+                                    $(.chain(Some($args::valtype())))*
+                            );
+                            let mut values = vec![ValRaw::i32(0); ty.params().len().max(ty.results().len())];
+                            let mut _n = 0;
+                            $(
+                                $args::abi_into_raw($args, values.as_mut_ptr().add(_n));
+                                _n += 1;
+                            )*
+                            let ret = panic::catch_unwind(AssertUnwindSafe(|| {
+                                caller.store.0.call_hook(CallHook::CallingHost)?;
+                                caller.trace_host_call(&ty, &mut values, |mut caller, values| {
+                                    let mut _n = 0;
+                                    $(
+                                        let $args = $args::from_abi($args::abi_from_raw(values.as_mut_ptr().add(_n)), caller.store.0);
+                                        _n += 1;
+                                    )*
+                                    let r = func(caller.sub_caller(), $( $args, )*);
+                                    if !r.compatible_with_store(caller.store.0) {
+                                        bail!("host function attempted to return cross-`Store` value to Wasm");
+                                    }
+                                    let abi = r.into_abi_for_ret(caller.store.0, retptr)?;
+                                    R::abi_into_raw(abi, retptr, values.as_mut_ptr());
+                                    Ok(())
+                                })?;
+                                caller.store.0.call_hook(CallHook::ReturningFromHost)
+                            }));
+                            return match ret {
+                                Err(panic) => CallResult::Panic(panic),
+                                Ok(Ok(())) => CallResult::Ok(R::abi_from_raw(values.as_mut_ptr(), retptr)),
+                                Ok(Err(trap)) => match crate::exception::throw_from_host(caller.store.0, trap) {
+                                    Ok(()) => CallResult::Thrown,
+                                    Err(trap) => CallResult::Trap(trap),
+                                },
+                            };
+                        }
+
                         let ret = {
                             panic::catch_unwind(AssertUnwindSafe(|| {
                                 if let Err(trap) = caller.store.0.call_hook(CallHook::CallingHost) {
@@ -2000,7 +2152,10 @@ macro_rules! impl_into_func {
                                 } else {
                                     match ret.into_abi_for_ret(caller.store.0, retptr) {
                                         Ok(val) => CallResult::Ok(val),
-                                        Err(trap) => CallResult::Trap(trap.into()),
+                                        Err(trap) => match crate::exception::throw_from_host(caller.store.0, trap.into()) {
+                                            Ok(()) => CallResult::Thrown,
+                                            Err(trap) => CallResult::Trap(trap),
+                                        },
                                     }
                                 }
 
@@ -2010,6 +2165,8 @@ macro_rules! impl_into_func {
 
                     match result {
                         CallResult::Ok(val) => val,
+                        // Wasm ignores the results of a call that throws.
+                        CallResult::Thrown => std::mem::zeroed(),
                         CallResult::Trap(err) => crate::trap::raise(err),
                         CallResult::Panic(panic) => wasmtime_runtime::resume_panic(panic),
                     }
@@ -2135,12 +2292,23 @@ impl HostFunc {
         ty: FuncType,
         func: impl Fn(Caller<'_, T>, &mut [ValRaw]) -> Result<()> + Send + Sync + 'static,
     ) -> Self {
+        let func_ty = ty.clone();
         let func = move |caller_vmctx, values: &mut [ValRaw]| {
             Caller::<T>::with(caller_vmctx, |mut caller| {
                 caller.store.0.call_hook(CallHook::CallingHost)?;
-                let result = func(caller.sub_caller(), values)?;
+                let result = if caller.store.0.host_call_tracer().is_enabled() {
+                    caller.trace_host_call(&func_ty, values, &func)
+                } else {
+                    func(caller.sub_caller(), values)
+                };
+                if let Err(error) = result {
+                    // Wasm ignores the results of a call that throws, but
+                    // don't leave any stale values in them.
+                    crate::exception::throw_from_host(caller.store.0, error)?;
+                    values.fill(ValRaw::v128(0));
+                }
                 caller.store.0.call_hook(CallHook::ReturningFromHost)?;
-                Ok(result)
+                Ok(())
             })
         };
         let ctx = crate::trampoline::create_array_call_function(&ty, func, engine)
@@ -1,10 +1,11 @@
 use super::{invoke_wasm_and_catch_traps, HostAbi};
 use crate::store::{AutoAssertNoGc, StoreOpaque};
-use crate::{AsContextMut, ExternRef, Func, FuncType, StoreContextMut, ValRaw, ValType};
+use crate::{AnyRef, AsContextMut, ExternRef, Func, FuncType, StoreContextMut, ValRaw, ValType};
 use anyhow::{bail, Result};
 use std::marker;
 use std::mem::{self, MaybeUninit};
 use std::ptr::{self, NonNull};
+use wasmtime_environ::{anyref_from_stack, anyref_to_stack};
 use wasmtime_runtime::{
     VMContext, VMFuncRef, VMNativeCallFunction, VMOpaqueContext, VMSharedSignatureIndex,
 };
@@ -183,7 +184,9 @@ where
             *returned = true
         });
         let (_, ret, _, returned) = captures;
-        debug_assert_eq!(result.is_ok(), returned);
+        // Wasm which unwinds due to an uncaught exception still returns
+        // normally, with zeroed results, so only a trap skips the return.
+        debug_assert!(result.is_err() || returned);
         result?;
         Ok(Results::from_abi(store.0, ret.assume_init()))
     }
@@ -451,6 +454,50 @@ unsafe impl WasmTy for Option<Func> {
     }
 }
 
+unsafe impl WasmTy for Option<AnyRef> {
+    // `anyref`s are passed to and from Wasm in their tagged form, see
+    // `wasmtime_environ::anyref_to_stack`.
+    type Abi = u64;
+
+    #[inline]
+    fn valtype() -> ValType {
+        ValType::AnyRef
+    }
+
+    #[inline]
+    fn compatible_with_store(&self, store: &StoreOpaque) -> bool {
+        match self {
+            Some(a) => a.comes_from_same_store(store),
+            None => true,
+        }
+    }
+
+    #[inline]
+    fn is_externref(&self) -> bool {
+        false
+    }
+
+    #[inline]
+    unsafe fn abi_from_raw(raw: *mut ValRaw) -> u64 {
+        anyref_to_stack((*raw).get_anyref())
+    }
+
+    #[inline]
+    unsafe fn abi_into_raw(abi: u64, raw: *mut ValRaw) {
+        *raw = ValRaw::anyref(anyref_from_stack(abi));
+    }
+
+    #[inline]
+    fn into_abi(self, store: &mut StoreOpaque) -> Self::Abi {
+        anyref_to_stack(self.map_or(0, |a| a._to_raw(store)))
+    }
+
+    #[inline]
+    unsafe fn from_abi(abi: Self::Abi, store: &mut StoreOpaque) -> Self {
+        AnyRef::_from_raw(store, anyref_from_stack(abi))
+    }
+}
+
 /// A trait used for [`Func::typed`] and with [`TypedFunc`] to represent the set of
 /// parameters for wasm functions.
 ///
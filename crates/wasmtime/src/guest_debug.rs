@@ -0,0 +1,182 @@
+//! Stopping WebAssembly at breakpoints, see [`Config::guest_debug`].
+//!
+//! [`Config::guest_debug`]: crate::Config::guest_debug
+
+use crate::store::StoreOpaque;
+use crate::{AsContextMut, Global, Instance, Memory, Module, Val, ValType, V128};
+use std::collections::HashSet;
+use wasmtime_environ::{DebugValType, DEBUG_SLOT_SIZE, DEBUG_SLOT_TYPE_OFFSET};
+use wasmtime_runtime::{CompiledModuleId, DebugBreak, Instance as RuntimeInstance, ValRaw};
+
+/// The state of a WebAssembly function which stopped before executing an
+/// instruction, either at a breakpoint or because single-stepping is enabled.
+///
+/// This is passed to the callback configured with [`Store::debug_hook`],
+/// see [`Config::guest_debug`] for more information.
+///
+/// [`Store::debug_hook`]: crate::Store::debug_hook
+/// [`Config::guest_debug`]: crate::Config::guest_debug
+pub struct DebugFrame {
+    instance: Instance,
+    module: Module,
+    func_index: u32,
+    module_offset: usize,
+    locals: Vec<Val>,
+    stack: Vec<Val>,
+}
+
+impl DebugFrame {
+    /// Returns the instance which the stopped function belongs to.
+    pub fn instance(&self) -> Instance {
+        self.instance
+    }
+
+    /// Returns the module which the stopped function is defined in.
+    pub fn module(&self) -> &Module {
+        &self.module
+    }
+
+    /// Returns the index of the stopped function within its module.
+    pub fn func_index(&self) -> u32 {
+        self.func_index
+    }
+
+    /// Returns the offset, within the original wasm module, of the
+    /// instruction which is executed next.
+    ///
+    /// This is in the same format as [`FrameInfo::module_offset`] and the
+    /// offsets passed to [`Store::add_breakpoint`].
+    ///
+    /// [`FrameInfo::module_offset`]: crate::FrameInfo::module_offset
+    /// [`Store::add_breakpoint`]: crate::Store::add_breakpoint
+    pub fn module_offset(&self) -> usize {
+        self.module_offset
+    }
+
+    /// Returns the values of the function's locals, starting with its
+    /// parameters.
+    pub fn locals(&self) -> &[Val] {
+        &self.locals
+    }
+
+    /// Returns the values on the function's operand stack, bottom-most first.
+    pub fn stack(&self) -> &[Val] {
+        &self.stack
+    }
+
+    /// Returns the memory at `index` in the index space of the stopped
+    /// function's instance, including imported and non-exported memories.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `store` does not own the stopped function's instance.
+    pub fn memory(&self, mut store: impl AsContextMut, index: u32) -> Option<Memory> {
+        let index = usize::try_from(index).ok()?;
+        self.instance
+            .all_memories(store.as_context_mut().0)
+            .nth(index)
+            .map(|(_, memory)| memory)
+    }
+
+    /// Returns the global at `index` in the index space of the stopped
+    /// function's instance, including imported and non-exported globals.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `store` does not own the stopped function's instance.
+    pub fn global(&self, mut store: impl AsContextMut, index: u32) -> Option<Global> {
+        let index = usize::try_from(index).ok()?;
+        self.instance
+            .all_globals(store.as_context_mut().0)
+            .nth(index)
+            .map(|(_, global)| global)
+    }
+}
+
+/// The breakpoints of a store and whether it's single-stepping.
+#[derive(Default)]
+pub(crate) struct Breakpoints {
+    set: HashSet<(CompiledModuleId, u32)>,
+    single_step: bool,
+}
+
+impl Breakpoints {
+    pub(crate) fn add(&mut self, module: &Module, offset: u32) {
+        self.set.insert((module.id(), offset));
+    }
+
+    pub(crate) fn remove(&mut self, module: &Module, offset: u32) -> bool {
+        self.set.remove(&(module.id(), offset))
+    }
+
+    pub(crate) fn set_single_step(&mut self, enable: bool) {
+        self.single_step = enable;
+    }
+
+    /// Returns whether compiled code must check for breakpoints before each
+    /// instruction.
+    pub(crate) fn is_active(&self) -> bool {
+        self.single_step || !self.set.is_empty()
+    }
+
+    fn stops_at(&self, module: &Module, offset: u32) -> bool {
+        self.single_step || self.set.contains(&(module.id(), offset))
+    }
+}
+
+impl DebugFrame {
+    /// Creates the frame which stopped at `frame`, returning `None` if it
+    /// doesn't stop at a breakpoint.
+    ///
+    /// # Unsafety
+    ///
+    /// `frame` must have been passed by compiled code belonging to `store`
+    /// to the `debug_break` libcall.
+    pub(crate) unsafe fn new(
+        mut store: impl AsContextMut,
+        frame: &DebugBreak,
+    ) -> Option<DebugFrame> {
+        let instance = RuntimeInstance::from_vmctx(frame.vmctx, |i| {
+            i.host_state().downcast_ref::<Instance>().copied()
+        })?;
+        let store_opaque: &StoreOpaque = store.as_context_mut().0;
+        let module = instance._module(store_opaque).clone();
+        if !store_opaque.breakpoints().stops_at(&module, frame.offset) {
+            return None;
+        }
+
+        let mut locals = Vec::with_capacity(frame.locals as usize);
+        let mut stack = Vec::with_capacity(frame.stack as usize);
+        for i in 0..frame.locals + frame.stack {
+            let slot = frame.values.add((i * DEBUG_SLOT_SIZE) as usize);
+            let raw = slot.cast::<ValRaw>().read_unaligned();
+            let ty = DebugValType::from_u8(*slot.add(DEBUG_SLOT_TYPE_OFFSET as usize))
+                .expect("invalid debug value type");
+            let val = match ty {
+                DebugValType::I32 => Val::I32(raw.get_i32()),
+                DebugValType::I64 => Val::I64(raw.get_i64()),
+                DebugValType::F32 => Val::F32(raw.get_f32()),
+                DebugValType::F64 => Val::F64(raw.get_f64()),
+                DebugValType::V128 => Val::V128(V128::from(raw.get_v128())),
+                DebugValType::FuncRef => Val::from_raw(&mut store, raw, ValType::FuncRef),
+                DebugValType::ExternRef => Val::from_raw(&mut store, raw, ValType::ExternRef),
+                DebugValType::AnyRef => Val::from_raw(&mut store, raw, ValType::AnyRef),
+                DebugValType::ExnRef => Val::from_raw(&mut store, raw, ValType::ExnRef),
+            };
+            if i < frame.locals {
+                locals.push(val);
+            } else {
+                stack.push(val);
+            }
+        }
+
+        Some(DebugFrame {
+            instance,
+            module,
+            func_index: frame.func.as_u32(),
+            module_offset: frame.offset as usize,
+            locals,
+            stack,
+        })
+    }
+}
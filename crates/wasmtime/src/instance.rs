@@ -3,16 +3,19 @@ use crate::store::{InstanceId, StoreOpaque, Stored};
 use crate::types::matching;
 use crate::{
     AsContextMut, Engine, Export, Extern, Func, Global, Memory, Module, SharedMemory, StoreContext,
-    StoreContextMut, Table, TypedFunc,
+    StoreContextMut, Table, Tag, TypedFunc,
 };
 use anyhow::{anyhow, bail, Context, Result};
 use std::mem;
 use std::ptr::NonNull;
 use std::sync::Arc;
-use wasmtime_environ::{EntityType, FuncIndex, GlobalIndex, MemoryIndex, PrimaryMap, TableIndex};
+use wasmtime_environ::{
+    EntityType, FuncIndex, GlobalIndex, MemoryIndex, PrimaryMap, TableIndex, TagIndex,
+};
 use wasmtime_runtime::{
-    Imports, InstanceAllocationRequest, StorePtr, VMContext, VMFuncRef, VMFunctionImport,
-    VMGlobalImport, VMMemoryImport, VMNativeCallFunction, VMOpaqueContext, VMTableImport,
+    Imports, InstanceAllocationRequest, ModuleRuntimeInfo, StorePtr, VMContext, VMFuncRef,
+    VMFunctionImport, VMGlobalImport, VMMemoryImport, VMNativeCallFunction, VMOpaqueContext,
+    VMTableImport, VMTagImport,
 };
 
 /// An instantiated WebAssembly module.
@@ -157,7 +160,7 @@ impl Instance {
         unsafe { Instance::new_started_async(&mut store, module, imports.as_ref()).await }
     }
 
-    fn typecheck_externs(
+    pub(crate) fn typecheck_externs(
         store: &mut StoreOpaque,
         module: &Module,
         imports: &[Extern],
@@ -202,7 +205,8 @@ impl Instance {
         module: &Module,
         imports: Imports<'_>,
     ) -> Result<Instance> {
-        let (instance, start) = Instance::new_raw(store.0, module, imports)?;
+        let (instance, start) =
+            Instance::new_raw(store.0, module, &module.runtime_info(), imports)?;
         if let Some(start) = start {
             instance.start_raw(store, start)?;
         }
@@ -246,9 +250,15 @@ impl Instance {
     /// This method is unsafe because it does not type-check the `imports`
     /// provided. The `imports` provided must be suitable for the module
     /// provided as well.
-    unsafe fn new_raw(
+    ///
+    /// The instance is created from `runtime_info`, which is usually
+    /// `module.runtime_info()` but may also describe different initial state
+    /// for the same compiled code, as with an
+    /// [`InstanceSnapshot`](crate::InstanceSnapshot).
+    pub(crate) unsafe fn new_raw(
         store: &mut StoreOpaque,
         module: &Module,
+        runtime_info: &Arc<dyn ModuleRuntimeInfo>,
         imports: Imports<'_>,
     ) -> Result<(Instance, Option<FuncIndex>)> {
         if !Engine::same(store.engine(), module.engine()) {
@@ -279,7 +289,7 @@ impl Instance {
                 .engine()
                 .allocator()
                 .allocate_module(InstanceAllocationRequest {
-                    runtime_info: &module.runtime_info(),
+                    runtime_info,
                     imports,
                     host_state: Box::new(Instance(instance_to_be)),
                     store: StorePtr::new(store.traitobj()),
@@ -333,12 +343,10 @@ impl Instance {
         // items from this instance into other instances should be ok when
         // those items are loaded and run we'll have all the metadata to
         // look at them.
-        instance_handle.initialize(
-            compiled_module.module(),
-            store.engine().config().features.bulk_memory,
-        )?;
+        let env_module = runtime_info.module();
+        instance_handle.initialize(env_module, store.engine().config().features.bulk_memory)?;
 
-        Ok((instance, compiled_module.module().start_func))
+        Ok((instance, env_module.start_func))
     }
 
     pub(crate) fn from_wasmtime(handle: InstanceData, store: &mut StoreOpaque) -> Instance {
@@ -369,7 +377,7 @@ impl Instance {
         self._module(store.into().0)
     }
 
-    fn _module<'a>(&self, store: &'a StoreOpaque) -> &'a Module {
+    pub(crate) fn _module<'a>(&self, store: &'a StoreOpaque) -> &'a Module {
         let InstanceData { id, .. } = store[self.0];
         store.module_for_instance(id).unwrap()
     }
@@ -543,7 +551,18 @@ impl Instance {
         self.get_export(store, name)?.into_global()
     }
 
-    #[cfg(feature = "component-model")]
+    /// Looks up an exported [`Tag`] value by name.
+    ///
+    /// Returns `None` if there was no export named `name`, or if there was but
+    /// it wasn't a tag.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `store` does not own this instance.
+    pub fn get_tag(&self, store: impl AsContextMut, name: &str) -> Option<Tag> {
+        self.get_export(store, name)?.into_tag()
+    }
+
     pub(crate) fn id(&self, store: &StoreOpaque) -> InstanceId {
         store[self.0].id
     }
@@ -594,6 +613,7 @@ pub(crate) struct OwnedImports {
     tables: PrimaryMap<TableIndex, VMTableImport>,
     memories: PrimaryMap<MemoryIndex, VMMemoryImport>,
     globals: PrimaryMap<GlobalIndex, VMGlobalImport>,
+    tags: PrimaryMap<TagIndex, VMTagImport>,
 }
 
 impl OwnedImports {
@@ -609,6 +629,7 @@ impl OwnedImports {
             tables: PrimaryMap::new(),
             memories: PrimaryMap::new(),
             globals: PrimaryMap::new(),
+            tags: PrimaryMap::new(),
         }
     }
 
@@ -618,6 +639,7 @@ impl OwnedImports {
         self.tables.reserve(raw.num_imported_tables);
         self.memories.reserve(raw.num_imported_memories);
         self.globals.reserve(raw.num_imported_globals);
+        self.tags.reserve(raw.num_imported_tags);
     }
 
     #[cfg(feature = "component-model")]
@@ -626,6 +648,7 @@ impl OwnedImports {
         self.tables.clear();
         self.memories.clear();
         self.globals.clear();
+        self.tags.clear();
     }
 
     fn push(&mut self, item: &Extern, store: &mut StoreOpaque, module: &Module) {
@@ -645,6 +668,9 @@ impl OwnedImports {
             Extern::SharedMemory(i) => {
                 self.memories.push(i.vmimport(store));
             }
+            Extern::Tag(i) => {
+                self.tags.push(i.vmimport(store));
+            }
         }
     }
 
@@ -678,6 +704,9 @@ impl OwnedImports {
                     index: m.index,
                 });
             }
+            wasmtime_runtime::Export::Tag(t) => {
+                self.tags.push(VMTagImport { from: t.definition });
+            }
         }
     }
 
@@ -687,6 +716,7 @@ impl OwnedImports {
             globals: self.globals.values().as_slice(),
             memories: self.memories.values().as_slice(),
             functions: self.functions.values().as_slice(),
+            tags: self.tags.values().as_slice(),
         }
     }
 }
@@ -419,9 +419,12 @@ mod compiler;
 mod code;
 mod code_memory;
 mod config;
+mod coverage;
 mod debug;
 mod engine;
+mod exception;
 mod externals;
+mod guest_debug;
 mod instance;
 mod instantiate;
 mod limits;
@@ -431,9 +434,11 @@ mod module;
 #[cfg(feature = "profiling")]
 mod profiling;
 mod profiling_agent;
+mod record;
 mod r#ref;
 mod resources;
 mod signatures;
+mod snapshot;
 mod store;
 mod trampoline;
 mod trap;
@@ -446,9 +451,12 @@ mod stack;
 
 pub use crate::code_memory::CodeMemory;
 pub use crate::config::*;
+pub use crate::coverage::{BlockCoverage, FunctionCoverage};
 pub use crate::engine::*;
+pub use crate::exception::ThrownException;
 pub use crate::externals::*;
 pub use crate::func::*;
+pub use crate::guest_debug::DebugFrame;
 pub use crate::instance::{Instance, InstancePre};
 pub use crate::instantiate::CompiledModule;
 pub use crate::limits::*;
@@ -457,8 +465,10 @@ pub use crate::memory::*;
 pub use crate::module::Module;
 #[cfg(feature = "profiling")]
 pub use crate::profiling::GuestProfiler;
-pub use crate::r#ref::ExternRef;
+pub use crate::r#ref::{AnyRef, ArrayRef, ExnRef, ExternRef, StructRef, I31};
+pub use crate::record::{HostCallError, HostCallTrace};
 pub use crate::resources::*;
+pub use crate::snapshot::InstanceSnapshot;
 #[cfg(feature = "async")]
 pub use crate::store::CallHookHandler;
 pub use crate::store::{
@@ -130,6 +130,7 @@ pub(crate) enum DefinitionType {
     // no longer be the current size of the table/memory.
     Table(wasmtime_environ::Table, u32),
     Memory(wasmtime_environ::Memory, u64),
+    Tag(wasmtime_runtime::VMSharedSignatureIndex),
 }
 
 macro_rules! generate_wrap_async_func {
@@ -334,6 +335,8 @@ impl<T> Linker<T> {
                                     ValType::V128 => Val::V128(0_u128.into()),
                                     ValType::FuncRef => Val::FuncRef(None),
                                     ValType::ExternRef => Val::ExternRef(None),
+                                    ValType::AnyRef => Val::AnyRef(None),
+                                    ValType::ExnRef => Val::ExnRef(None),
                                 };
                             }
                             Ok(())
@@ -1373,6 +1376,7 @@ impl DefinitionType {
                 DefinitionType::Memory(*t.wasmtime_ty(data), t.internal_size(store))
             }
             Extern::SharedMemory(t) => DefinitionType::Memory(*t.ty().wasmtime_memory(), t.size()),
+            Extern::Tag(t) => DefinitionType::Tag(t.sig_index(data)),
         }
     }
 
@@ -1382,6 +1386,7 @@ impl DefinitionType {
             DefinitionType::Table(..) => "table",
             DefinitionType::Memory(..) => "memory",
             DefinitionType::Global(_) => "global",
+            DefinitionType::Tag(_) => "tag",
         }
     }
 }
@@ -1,6 +1,7 @@
 use crate::{
     code::CodeObject,
     code_memory::CodeMemory,
+    coverage::{CoverageCounters, FunctionCoverage},
     instantiate::CompiledModule,
     resources::ResourcesRequired,
     signatures::SignatureCollection,
@@ -17,11 +18,11 @@ use std::ptr::NonNull;
 use std::sync::Arc;
 use wasmparser::{Parser, ValidPayload, Validator};
 use wasmtime_environ::{
-    CompiledModuleInfo, DefinedFuncIndex, DefinedMemoryIndex, HostPtr, ModuleEnvironment,
-    ModuleTypes, ObjectKind, VMOffsets,
+    CompiledModuleInfo, DefinedFuncIndex, DefinedMemoryIndex, GcTypeIndex, HostPtr,
+    ModuleEnvironment, ModuleTypes, ObjectKind, SignatureIndex, VMOffsets, WasmFuncType,
 };
 use wasmtime_runtime::{
-    CompiledModuleId, MemoryImage, MmapVec, ModuleMemoryImages, VMArrayCallFunction,
+    CompiledModuleId, GcTypeInfo, MemoryImage, MmapVec, ModuleMemoryImages, VMArrayCallFunction,
     VMNativeCallFunction, VMSharedSignatureIndex, VMWasmCallFunction,
 };
 
@@ -131,6 +132,9 @@ struct ModuleInner {
 
     /// Runtime offset information for `VMContext`.
     offsets: VMOffsets<HostPtr>,
+
+    /// The counters of each basic block, if compiled with coverage enabled.
+    coverage: Option<CoverageCounters>,
 }
 
 impl std::fmt::Debug for Module {
@@ -582,6 +586,12 @@ impl Module {
             .allocator()
             .validate_module(module.module(), &offsets)?;
 
+        let coverage = if engine.config().tunables.coverage {
+            Some(CoverageCounters::new(&module))
+        } else {
+            None
+        };
+
         Ok(Self {
             inner: Arc::new(ModuleInner {
                 engine: engine.clone(),
@@ -590,6 +600,7 @@ impl Module {
                 module,
                 serializable,
                 offsets,
+                coverage,
             }),
         })
     }
@@ -1093,6 +1104,32 @@ impl Module {
     pub(crate) fn id(&self) -> CompiledModuleId {
         self.inner.module.unique_id()
     }
+
+    /// Returns whether the modules `a` and `b` refer to the same compiled
+    /// module, for example when one is a clone of the other.
+    pub fn same(a: &Module, b: &Module) -> bool {
+        a.id() == b.id()
+    }
+
+    /// Returns how many times each basic block of each function defined in
+    /// this module was entered, across all of its instances, when compiled
+    /// with [`Config::coverage`] enabled.
+    ///
+    /// Returns an empty list if this module wasn't compiled with coverage
+    /// enabled.
+    pub fn coverage(&self) -> Vec<FunctionCoverage> {
+        match &self.inner.coverage {
+            Some(coverage) => coverage.report(self.compiled_module()),
+            None => Vec::new(),
+        }
+    }
+
+    /// Resets the counts reported by [`Module::coverage`] to zero.
+    pub fn reset_coverage(&self) {
+        if let Some(coverage) = &self.inner.coverage {
+            coverage.reset();
+        }
+    }
 }
 
 impl ModuleInner {
@@ -1215,9 +1252,24 @@ impl wasmtime_runtime::ModuleRuntimeInfo for ModuleInner {
         self.code.signatures().as_module_map().values().as_slice()
     }
 
+    fn function_type(&self, index: SignatureIndex) -> &WasmFuncType {
+        &self.code.module_types()[index]
+    }
+
+    fn gc_type(&self, index: GcTypeIndex) -> &Arc<GcTypeInfo> {
+        self.code.signatures().gc_type(index)
+    }
+
     fn offsets(&self) -> &VMOffsets<HostPtr> {
         &self.offsets
     }
+
+    fn coverage_counters(&self) -> *const *mut u64 {
+        match &self.coverage {
+            Some(coverage) => coverage.ptrs(),
+            None => std::ptr::null(),
+        }
+    }
 }
 
 impl wasmtime_runtime::ModuleInfo for ModuleInner {
@@ -1323,9 +1375,21 @@ impl wasmtime_runtime::ModuleRuntimeInfo for BareModuleInfo {
         }
     }
 
+    fn function_type(&self, _index: SignatureIndex) -> &WasmFuncType {
+        unreachable!()
+    }
+
+    fn gc_type(&self, _index: GcTypeIndex) -> &Arc<GcTypeInfo> {
+        unreachable!()
+    }
+
     fn offsets(&self) -> &VMOffsets<HostPtr> {
         &self.offsets
     }
+
+    fn coverage_counters(&self) -> *const *mut u64 {
+        std::ptr::null()
+    }
 }
 
 /// Helper method to construct a `ModuleMemoryImages` for an associated
@@ -1,4 +1,4 @@
-use crate::{instantiate::CompiledModule, AsContext, Module};
+use crate::{instantiate::CompiledModule, AsContext, CallHook, Module};
 #[allow(unused_imports)]
 use anyhow::bail;
 use anyhow::Result;
@@ -7,18 +7,20 @@ use fxprof_processed_profile::{
     CategoryHandle, CpuDelta, Frame, FrameFlags, FrameInfo, LibraryInfo, Profile,
     ReferenceTimestamp, Symbol, SymbolTable, Timestamp,
 };
+use std::collections::HashMap;
 use std::ops::Range;
-use std::sync::Arc;
-use std::time::{Duration, Instant};
+use std::sync::{Arc, Mutex};
+use std::thread::{self, ThreadId};
+use std::time::{Duration, Instant, SystemTime};
 use wasmtime_environ::demangle_function_name;
 use wasmtime_runtime::Backtrace;
 
+mod pprof;
+
 // TODO: collect more data
-// - Provide additional hooks for recording host-guest transitions, to be
-//   invoked from a Store::call_hook
 // - On non-Windows, measure thread-local CPU usage between events with
 //   rustix::time::clock_gettime(ClockId::ThreadCPUTime)
-// - Report which wasm module, and maybe instance, each frame came from
+// - Report which instance each frame came from
 
 /// Collects basic profiling data for a single WebAssembly guest.
 ///
@@ -32,6 +34,14 @@ use wasmtime_runtime::Backtrace;
 /// regular intervals while the guest is on the stack. The most straightforward
 /// way to do that is to call it from a callback registered with
 /// [`Store::epoch_deadline_callback()`](crate::Store::epoch_deadline_callback).
+/// To also measure the time spent in calls from the guest to the host, call
+/// [`GuestProfiler::call_hook`] from a callback registered with
+/// [`Store::call_hook()`](crate::Store::call_hook).
+///
+/// A guest may run on several threads, each with its own store, such as with
+/// `wasi-threads`. Samples are recorded separately for each thread that calls
+/// into the profiler, so a single profiler can be shared between the stores of
+/// all threads of a guest.
 ///
 /// # Accuracy
 ///
@@ -41,7 +51,8 @@ use wasmtime_runtime::Backtrace;
 /// If you use epoch interruption, then samples will only be collected at
 /// function entry points and loop headers. This introduces some bias to the
 /// results. In addition, samples will only be taken at times when WebAssembly
-/// functions are running, not during host-calls.
+/// functions are running, not during host-calls, unless
+/// [`GuestProfiler::call_hook`] is used to measure those.
 ///
 /// It is technically possible to use fuel interruption instead. That
 /// introduces worse bias since samples occur after a certain number of
@@ -74,11 +85,63 @@ use wasmtime_runtime::Backtrace;
 /// should only include user-provided modules in profiles.
 #[derive(Debug)]
 pub struct GuestProfiler {
-    profile: Profile,
-    modules: Vec<(Range<usize>, fxprof_processed_profile::LibraryHandle)>,
-    process: fxprof_processed_profile::ProcessHandle,
-    thread: fxprof_processed_profile::ThreadHandle,
+    name: String,
+    interval: Duration,
+    libs: Vec<ProfiledLib>,
+    /// The address range of each library's code, sorted by start address.
+    ranges: Vec<(Range<usize>, usize)>,
     start: Instant,
+    start_time: SystemTime,
+    threads: Mutex<Vec<ThreadSamples>>,
+    /// The start time and stack of each host call in progress, innermost
+    /// last, keyed by the runtime limits of the store which made the call.
+    /// Calls are tracked per store rather than per thread since async host
+    /// calls may resume on a different thread than they started on.
+    host_calls: Mutex<HashMap<usize, Vec<(Duration, Vec<ProfiledFrame>)>>>,
+}
+
+/// The code of a module or component which appears in the profile.
+#[derive(Debug)]
+struct ProfiledLib {
+    name: String,
+    size: usize,
+    /// The functions of the library, sorted by address.
+    symbols: Vec<Symbol>,
+}
+
+/// A frame within the code of a [`ProfiledLib`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+struct ProfiledFrame {
+    lib: usize,
+    offset: u32,
+}
+
+#[derive(Debug)]
+struct ThreadSamples {
+    id: ThreadId,
+    name: Option<String>,
+    samples: Vec<Sample>,
+    /// The time spent in host calls which wasn't attributed to a whole sample
+    /// yet.
+    host_remainder: Duration,
+}
+
+#[derive(Debug)]
+struct Sample {
+    time: Duration,
+    /// The frames of the sample, oldest first.
+    frames: Vec<ProfiledFrame>,
+    /// Whether the sample was taken in a call to the host.
+    host: bool,
+    /// The number of sampling intervals which this sample represents.
+    weight: i32,
+}
+
+impl Sample {
+    /// The time which this sample represents.
+    fn duration(&self, interval: Duration) -> Duration {
+        interval.saturating_mul(self.weight as u32)
+    }
 }
 
 impl GuestProfiler {
@@ -98,31 +161,110 @@ impl GuestProfiler {
     /// "Security" section of the [`GuestProfiler`] documentation for guidance
     /// on what modules should not be included in this list.
     pub fn new(module_name: &str, interval: Duration, modules: Vec<(String, Module)>) -> Self {
-        let zero = ReferenceTimestamp::from_millis_since_unix_epoch(0.0);
-        let mut profile = Profile::new(module_name, zero, interval.into());
-
-        let mut modules: Vec<_> = modules
+        let libs = modules
             .into_iter()
-            .filter_map(|(name, module)| {
+            .map(|(name, module)| {
                 let compiled = module.compiled_module();
-                let text = compiled.text().as_ptr_range();
-                let address_range = text.start as usize..text.end as usize;
-                module_symbols(name, compiled).map(|lib| (address_range, profile.add_lib(lib)))
+                let symbols = module_symbols(None, compiled);
+                (name, text_range(compiled.text()), symbols)
             })
             .collect();
+        Self::from_libs(module_name, interval, libs)
+    }
 
-        modules.sort_unstable_by_key(|(range, _)| range.start);
+    /// Begin profiling a new component guest, in the same way as
+    /// [`GuestProfiler::new`].
+    ///
+    /// All of the code of `component` appears in stack traces in this
+    /// profile, which includes the core modules defined within it, the
+    /// adapter modules generated to connect its instances, and the
+    /// trampolines used to call host functions. Stack frames of the modules
+    /// in `extra_modules` appear as well.
+    #[cfg(feature = "component-model")]
+    pub fn new_component(
+        component_name: &str,
+        interval: Duration,
+        component: crate::component::Component,
+        extra_modules: Vec<(String, Module)>,
+    ) -> Self {
+        let mut symbols = Vec::new();
+        for (i, module) in component.static_modules().enumerate() {
+            let name = match module.name() {
+                Some(name) => name.to_string(),
+                None => format!("module{i}"),
+            };
+            symbols.extend(module_symbols(Some(&name), module.compiled_module()));
+        }
+        for (index, locs) in component.trampoline_locs() {
+            let index = index.as_u32();
+            for (loc, kind) in [
+                (&locs.wasm_call, "wasm call"),
+                (&locs.array_call, "array call"),
+                (&locs.native_call, "native call"),
+            ] {
+                symbols.push(Symbol {
+                    address: loc.start,
+                    size: Some(loc.length),
+                    name: format!("component trampoline {index} ({kind})"),
+                });
+            }
+        }
+        if let Some(loc) = component.resource_drop_trampoline_loc() {
+            symbols.push(Symbol {
+                address: loc.start,
+                size: Some(loc.length),
+                name: "resource.drop trampoline".to_string(),
+            });
+        }
+
+        let mut libs = vec![(
+            component_name.to_string(),
+            text_range(component.text()),
+            symbols,
+        )];
+        for (name, module) in extra_modules.iter() {
+            let compiled = module.compiled_module();
+            libs.push((
+                name.clone(),
+                text_range(compiled.text()),
+                module_symbols(None, compiled),
+            ));
+        }
+        Self::from_libs(component_name, interval, libs)
+    }
+
+    fn from_libs(
+        name: &str,
+        interval: Duration,
+        libs: Vec<(String, Range<usize>, Vec<Symbol>)>,
+    ) -> Self {
+        let mut ranges = Vec::new();
+        let libs = libs
+            .into_iter()
+            .filter(|(_, _, symbols)| !symbols.is_empty())
+            .enumerate()
+            .map(|(i, (name, text, mut symbols))| {
+                symbols.sort_unstable_by_key(|s| s.address);
+                let size = text.len();
+                ranges.push((text, i));
+                ProfiledLib {
+                    name,
+                    size,
+                    symbols,
+                }
+            })
+            .collect();
+        ranges.sort_unstable_by_key(|(range, _)| range.start);
 
-        profile.set_reference_timestamp(std::time::SystemTime::now().into());
-        let process = profile.add_process(module_name, 0, Timestamp::from_nanos_since_reference(0));
-        let thread = profile.add_thread(process, 0, Timestamp::from_nanos_since_reference(0), true);
-        let start = Instant::now();
         Self {
-            profile,
-            modules,
-            process,
-            thread,
-            start,
+            name: name.to_string(),
+            interval,
+            libs,
+            ranges,
+            start: Instant::now(),
+            start_time: SystemTime::now(),
+            threads: Mutex::new(vec![ThreadSamples::current()]),
+            host_calls: Mutex::default(),
         }
     }
 
@@ -130,39 +272,118 @@ impl GuestProfiler {
     /// any stack frames for allowed modules on the current stack. It should
     /// typically be called from a callback registered using
     /// [`Store::epoch_deadline_callback()`](crate::Store::epoch_deadline_callback).
-    pub fn sample(&mut self, store: impl AsContext) {
-        let now = Timestamp::from_nanos_since_reference(
-            self.start.elapsed().as_nanos().try_into().unwrap(),
-        );
+    ///
+    /// The sample is recorded for the current thread.
+    pub fn sample(&self, store: impl AsContext) {
+        let time = self.start.elapsed();
+        let frames = self.frames(Backtrace::new(store.as_context().0.vmruntime_limits()));
+        self.with_current_thread(|thread| {
+            thread.samples.push(Sample {
+                time,
+                frames,
+                host: false,
+                weight: 1,
+            })
+        });
+    }
 
-        let backtrace = Backtrace::new(store.as_context().0.vmruntime_limits());
-        let frames = backtrace
+    /// Record the time spent in calls from the guest to the host. This should
+    /// be called with every transition reported to a callback registered with
+    /// [`Store::call_hook()`](crate::Store::call_hook), on the thread which
+    /// invoked the callback.
+    ///
+    /// When the host returns to the guest, a sample is added to the profile
+    /// for the stack of the guest which called the host, ending in a frame
+    /// representing the host, with a weight of the number of sampling
+    /// intervals spent in the host. Time which doesn't add up to a whole
+    /// interval is carried over to the next call on the same thread.
+    pub fn call_hook(&self, kind: CallHook) {
+        let now = self.start.elapsed();
+        let Some(limits) = wasmtime_runtime::innermost_limits() else {
+            return;
+        };
+        let key = limits as usize;
+        match kind {
+            CallHook::CallingWasm | CallHook::ReturningFromWasm => {}
+            CallHook::CallingHost => {
+                let frames = self.frames(Backtrace::new(limits));
+                let mut host_calls = self.host_calls.lock().unwrap();
+                host_calls.entry(key).or_default().push((now, frames));
+            }
+            CallHook::ReturningFromHost => {
+                let (start, frames) = {
+                    let mut host_calls = self.host_calls.lock().unwrap();
+                    let Some(calls) = host_calls.get_mut(&key) else {
+                        return;
+                    };
+                    let call = calls.pop();
+                    if calls.is_empty() {
+                        host_calls.remove(&key);
+                    }
+                    match call {
+                        Some(call) => call,
+                        None => return,
+                    }
+                };
+                let duration = now.saturating_sub(start);
+                self.with_current_thread(|thread| {
+                    // Carry over the time which doesn't add up to a whole
+                    // interval so that many short calls are still accounted
+                    // for, without recording a sample for each of them.
+                    let total = thread.host_remainder + duration;
+                    let interval = self.interval.as_nanos().max(1);
+                    let weight = u32::try_from(total.as_nanos() / interval).unwrap_or(u32::MAX);
+                    thread.host_remainder =
+                        Duration::from_nanos(u64::try_from(total.as_nanos() % interval).unwrap());
+                    if weight == 0 {
+                        return;
+                    }
+                    thread.samples.push(Sample {
+                        time: start,
+                        frames,
+                        host: true,
+                        weight: i32::try_from(weight).unwrap_or(i32::MAX),
+                    });
+                });
+            }
+        }
+    }
+
+    /// Returns the frames of the allowed modules in `backtrace`, oldest first.
+    fn frames(&self, backtrace: Backtrace) -> Vec<ProfiledFrame> {
+        backtrace
             .frames()
             // Samply needs to see the oldest frame first, but we list the newest
             // first, so iterate in reverse.
             .rev()
             .filter_map(|frame| {
-                // Find the first module whose start address includes this PC.
-                let module_idx = self
-                    .modules
-                    .partition_point(|(range, _)| range.start > frame.pc());
-                if let Some((range, lib)) = self.modules.get(module_idx) {
-                    if range.contains(&frame.pc()) {
-                        return Some(FrameInfo {
-                            frame: Frame::RelativeAddressFromReturnAddress(
-                                *lib,
-                                u32::try_from(frame.pc() - range.start).unwrap(),
-                            ),
-                            category_pair: CategoryHandle::OTHER.into(),
-                            flags: FrameFlags::empty(),
-                        });
-                    }
+                // Find the last module whose start address is at most this PC.
+                let idx = self
+                    .ranges
+                    .partition_point(|(range, _)| range.start <= frame.pc());
+                let (range, lib) = self.ranges.get(idx.checked_sub(1)?)?;
+                if !range.contains(&frame.pc()) {
+                    return None;
                 }
-                None
-            });
+                Some(ProfiledFrame {
+                    lib: *lib,
+                    offset: u32::try_from(frame.pc() - range.start).unwrap(),
+                })
+            })
+            .collect()
+    }
 
-        self.profile
-            .add_sample(self.thread, now, frames, CpuDelta::ZERO, 1);
+    fn with_current_thread<R>(&self, f: impl FnOnce(&mut ThreadSamples) -> R) -> R {
+        let mut threads = self.threads.lock().unwrap();
+        let id = thread::current().id();
+        let idx = match threads.iter().position(|t| t.id == id) {
+            Some(idx) => idx,
+            None => {
+                threads.push(ThreadSamples::current());
+                threads.len() - 1
+            }
+        };
+        f(&mut threads[idx])
     }
 
     /// When the guest finishes running, call this function to write the
@@ -170,26 +391,130 @@ impl GuestProfiler {
     /// the [Firefox "processed profile format"][fmt]. Files in this format may
     /// be visualized at <https://profiler.firefox.com/>.
     ///
+    /// The profile contains the samples recorded so far on all threads, so
+    /// samples which other threads record later on are not included.
+    ///
     /// [fmt]: https://github.com/firefox-devtools/profiler/blob/main/docs-developer/processed-profile-format.md
-    pub fn finish(mut self, output: impl std::io::Write) -> Result<()> {
-        let now = Timestamp::from_nanos_since_reference(
-            self.start.elapsed().as_nanos().try_into().unwrap(),
-        );
-        self.profile.set_thread_end_time(self.thread, now);
-        self.profile.set_process_end_time(self.process, now);
-
-        serde_json::to_writer(output, &self.profile)?;
+    pub fn finish(&self, output: impl std::io::Write) -> Result<()> {
+        let end = timestamp(self.start.elapsed());
+        let zero = ReferenceTimestamp::from_millis_since_unix_epoch(0.0);
+        let mut profile = Profile::new(&self.name, zero, self.interval.into());
+        profile.set_reference_timestamp(self.start_time.into());
+
+        let libs = self
+            .libs
+            .iter()
+            .map(|lib| {
+                profile.add_lib(LibraryInfo {
+                    name: lib.name.clone(),
+                    debug_name: String::new(),
+                    path: String::new(),
+                    debug_path: String::new(),
+                    debug_id: DebugId::nil(),
+                    code_id: None,
+                    arch: None,
+                    symbol_table: Some(Arc::new(SymbolTable::new(lib.symbols.clone()))),
+                })
+            })
+            .collect::<Vec<_>>();
+        let host = profile.intern_string(HOST_FRAME);
+        let frame_info = |frame| FrameInfo {
+            frame,
+            category_pair: CategoryHandle::OTHER.into(),
+            flags: FrameFlags::empty(),
+        };
+
+        let process = profile.add_process(&self.name, 0, timestamp(Duration::ZERO));
+        for (i, thread) in self.threads.lock().unwrap().iter().enumerate() {
+            let handle = profile.add_thread(
+                process,
+                u32::try_from(i).unwrap(),
+                timestamp(Duration::ZERO),
+                i == 0,
+            );
+            if let Some(name) = &thread.name {
+                profile.set_thread_name(handle, name);
+            }
+            for sample in thread.samples.iter() {
+                let frames = sample
+                    .frames
+                    .iter()
+                    .map(|f| {
+                        frame_info(Frame::RelativeAddressFromReturnAddress(
+                            libs[f.lib],
+                            f.offset,
+                        ))
+                    })
+                    .chain(sample.host.then(|| frame_info(Frame::Label(host))));
+                profile.add_sample(
+                    handle,
+                    timestamp(sample.time),
+                    frames,
+                    CpuDelta::from(sample.duration(self.interval)),
+                    sample.weight,
+                );
+            }
+            profile.set_thread_end_time(handle, end);
+        }
+        profile.set_process_end_time(process, end);
+
+        serde_json::to_writer(output, &profile)?;
         Ok(())
     }
+
+    /// Same as [`GuestProfiler::finish`], except that the profile is written
+    /// in the [pprof format][fmt], which can be analyzed with `go tool pprof`
+    /// and other tools that support it.
+    ///
+    /// Each sample has two values: the number of sampling intervals it
+    /// represents and the wall-clock time it represents, in nanoseconds. The
+    /// name of the thread that recorded a sample, if any, is attached to it as
+    /// the `thread` label.
+    ///
+    /// [fmt]: https://github.com/google/pprof/blob/main/proto/profile.proto
+    pub fn finish_pprof(&self, mut output: impl std::io::Write) -> Result<()> {
+        let threads = self.threads.lock().unwrap();
+        output.write_all(&pprof::encode(self, &threads))?;
+        Ok(())
+    }
+}
+
+impl ThreadSamples {
+    fn current() -> ThreadSamples {
+        let thread = thread::current();
+        ThreadSamples {
+            id: thread.id(),
+            name: thread.name().map(|n| n.to_string()),
+            samples: Vec::new(),
+            host_remainder: Duration::ZERO,
+        }
+    }
 }
 
-fn module_symbols(name: String, compiled: &CompiledModule) -> Option<LibraryInfo> {
-    let symbols = Vec::from_iter(compiled.finished_functions().map(|(defined_idx, _)| {
+/// The name of the frame which represents the host in samples taken while the
+/// guest called the host.
+const HOST_FRAME: &str = "<host>";
+
+fn text_range(text: &[u8]) -> Range<usize> {
+    let text = text.as_ptr_range();
+    text.start as usize..text.end as usize
+}
+
+fn timestamp(since_start: Duration) -> Timestamp {
+    Timestamp::from_nanos_since_reference(since_start.as_nanos().try_into().unwrap())
+}
+
+fn module_symbols(prefix: Option<&str>, compiled: &CompiledModule) -> Vec<Symbol> {
+    Vec::from_iter(compiled.finished_functions().map(|(defined_idx, _)| {
         let loc = compiled.func_loc(defined_idx);
         let func_idx = compiled.module().func_index(defined_idx);
         let mut name = String::new();
+        if let Some(prefix) = prefix {
+            name.push_str(prefix);
+            name.push_str("::");
+        }
         match compiled.func_name(func_idx) {
-            None => name = format!("wasm_function_{}", defined_idx.as_u32()),
+            None => name.push_str(&format!("wasm_function_{}", defined_idx.as_u32())),
             Some(func_name) => demangle_function_name(&mut name, func_name).unwrap(),
         };
         Symbol {
@@ -197,19 +522,5 @@ fn module_symbols(name: String, compiled: &CompiledModule) -> Option<LibraryInfo
             size: Some(loc.length),
             name,
         }
-    }));
-    if symbols.is_empty() {
-        return None;
-    }
-
-    Some(LibraryInfo {
-        name,
-        debug_name: String::new(),
-        path: String::new(),
-        debug_path: String::new(),
-        debug_id: DebugId::nil(),
-        code_id: None,
-        arch: None,
-        symbol_table: Some(Arc::new(SymbolTable::new(symbols))),
-    })
+    }))
 }
@@ -0,0 +1,300 @@
+//! Encoding of guest profiles in the pprof format, which is a protobuf
+//! message described by
+//! <https://github.com/google/pprof/blob/main/proto/profile.proto>.
+//!
+//! The message is small enough that it's encoded by hand here rather than
+//! through a protobuf library.
+
+use super::{GuestProfiler, ProfiledFrame, ThreadSamples, HOST_FRAME};
+use std::collections::HashMap;
+use std::time::UNIX_EPOCH;
+
+/// Returns the encoded pprof `Profile` message for the samples of `threads`.
+pub(super) fn encode(profiler: &GuestProfiler, threads: &[ThreadSamples]) -> Vec<u8> {
+    let mut builder = Builder::default();
+    // The string table must start with the empty string.
+    builder.string("");
+
+    let mut profile = Message::default();
+    let samples = builder.string("samples");
+    let count = builder.string("count");
+    let wall = builder.string("wall");
+    let nanoseconds = builder.string("nanoseconds");
+    for (ty, unit) in [(samples, count), (wall, nanoseconds)] {
+        profile.message(1, |m| {
+            m.int(1, ty);
+            m.int(2, unit);
+        });
+    }
+
+    let thread_label = builder.string("thread");
+    for thread in threads {
+        let name = thread.name.as_deref().map(|n| builder.string(n));
+        for sample in thread.samples.iter() {
+            // The leaf frame comes first in pprof.
+            let mut locations = Vec::with_capacity(sample.frames.len() + 1);
+            if sample.host {
+                locations.push(builder.host_location());
+            }
+            for frame in sample.frames.iter().rev() {
+                locations.push(builder.location(profiler, *frame));
+            }
+            profile.message(2, |m| {
+                m.packed(1, locations.iter().copied());
+                m.packed(
+                    2,
+                    [
+                        sample.weight as u64,
+                        u64::try_from(sample.duration(profiler.interval).as_nanos())
+                            .unwrap_or(u64::MAX),
+                    ],
+                );
+                if let Some(name) = name {
+                    m.message(3, |m| {
+                        m.int(1, thread_label);
+                        m.int(2, name);
+                    });
+                }
+            });
+        }
+    }
+
+    for (i, lib) in profiler.libs.iter().enumerate() {
+        let filename = builder.string(&lib.name);
+        profile.message(3, |m| {
+            m.int(1, i as u64 + 1);
+            m.int(3, lib.size as u64);
+            m.int(5, filename);
+            m.int(7, 1);
+        });
+    }
+    for (id, mapping, address, function) in builder.locations.iter().copied() {
+        profile.message(4, |m| {
+            m.int(1, id);
+            m.int(2, mapping);
+            m.int(3, address);
+            m.message(4, |m| m.int(1, function));
+        });
+    }
+    for (id, name, filename) in builder.functions.iter().copied() {
+        profile.message(5, |m| {
+            m.int(1, id);
+            m.int(2, name);
+            m.int(3, name);
+            m.int(4, filename);
+        });
+    }
+    for s in builder.strings.iter() {
+        profile.bytes(6, s.as_bytes());
+    }
+
+    let start = profiler
+        .start_time
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default();
+    profile.int(9, u64::try_from(start.as_nanos()).unwrap_or(u64::MAX));
+    let duration = profiler.start.elapsed();
+    profile.int(10, u64::try_from(duration.as_nanos()).unwrap_or(u64::MAX));
+    profile.message(11, |m| {
+        m.int(1, wall);
+        m.int(2, nanoseconds);
+    });
+    profile.int(
+        12,
+        u64::try_from(profiler.interval.as_nanos()).unwrap_or(u64::MAX),
+    );
+    profile.0
+}
+
+/// The tables of a profile which samples refer to by index or id.
+#[derive(Default)]
+struct Builder {
+    strings: Vec<String>,
+    string_ids: HashMap<String, u64>,
+    /// The id, mapping id, address and function id of each location.
+    locations: Vec<(u64, u64, u64, u64)>,
+    location_ids: HashMap<Option<ProfiledFrame>, u64>,
+    /// The id, name and file name of each function.
+    functions: Vec<(u64, u64, u64)>,
+    function_ids: HashMap<FunctionKey, u64>,
+}
+
+/// Identifies a function of the profile.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+enum FunctionKey {
+    /// The symbol with the given index in a library.
+    Symbol { lib: usize, index: usize },
+    /// Code of a library which isn't covered by any of its symbols, named
+    /// after the offset of the frame.
+    Unresolved { lib: usize, offset: u32 },
+    /// The pseudo-function for time spent in the host.
+    Host,
+}
+
+impl Builder {
+    fn string(&mut self, s: &str) -> u64 {
+        if let Some(id) = self.string_ids.get(s) {
+            return *id;
+        }
+        let id = self.strings.len() as u64;
+        self.strings.push(s.to_string());
+        self.string_ids.insert(s.to_string(), id);
+        id
+    }
+
+    fn location(&mut self, profiler: &GuestProfiler, frame: ProfiledFrame) -> u64 {
+        if let Some(id) = self.location_ids.get(&Some(frame)) {
+            return *id;
+        }
+        let lib = &profiler.libs[frame.lib];
+        // Return addresses are just past the call instruction, which might be
+        // the end of the calling function.
+        let address = frame.offset.saturating_sub(1);
+        let symbol = lib
+            .symbols
+            .partition_point(|s| s.address <= address)
+            .checked_sub(1)
+            .filter(|i| {
+                let s = &lib.symbols[*i];
+                s.size.map_or(true, |size| address < s.address + size)
+            });
+        let key = match symbol {
+            Some(index) => FunctionKey::Symbol {
+                lib: frame.lib,
+                index,
+            },
+            None => FunctionKey::Unresolved {
+                lib: frame.lib,
+                offset: frame.offset,
+            },
+        };
+        let function = match self.function_ids.get(&key) {
+            Some(id) => *id,
+            None => {
+                let name = match symbol {
+                    Some(i) => self.string(&lib.symbols[i].name),
+                    None => self.string(&format!("{}+{:#x}", lib.name, frame.offset)),
+                };
+                let filename = self.string(&lib.name);
+                self.function(key, name, filename)
+            }
+        };
+        let id = self.locations.len() as u64 + 1;
+        self.locations
+            .push((id, frame.lib as u64 + 1, u64::from(frame.offset), function));
+        self.location_ids.insert(Some(frame), id);
+        id
+    }
+
+    fn host_location(&mut self) -> u64 {
+        if let Some(id) = self.location_ids.get(&None) {
+            return *id;
+        }
+        let name = self.string(HOST_FRAME);
+        let function = self.function(FunctionKey::Host, name, 0);
+        let id = self.locations.len() as u64 + 1;
+        self.locations.push((id, 0, 0, function));
+        self.location_ids.insert(None, id);
+        id
+    }
+
+    fn function(&mut self, key: FunctionKey, name: u64, filename: u64) -> u64 {
+        let id = self.functions.len() as u64 + 1;
+        self.functions.push((id, name, filename));
+        self.function_ids.insert(key, id);
+        id
+    }
+}
+
+/// An encoded protobuf message.
+#[derive(Default)]
+struct Message(Vec<u8>);
+
+impl Message {
+    fn varint(&mut self, mut value: u64) {
+        while value >= 0x80 {
+            self.0.push(value as u8 | 0x80);
+            value >>= 7;
+        }
+        self.0.push(value as u8);
+    }
+
+    /// Encodes an integer field, omitting it if it has the default value.
+    fn int(&mut self, field: u32, value: u64) {
+        if value != 0 {
+            self.varint(u64::from(field) << 3);
+            self.varint(value);
+        }
+    }
+
+    fn bytes(&mut self, field: u32, bytes: &[u8]) {
+        self.varint(u64::from(field) << 3 | 2);
+        self.varint(bytes.len() as u64);
+        self.0.extend_from_slice(bytes);
+    }
+
+    fn packed(&mut self, field: u32, values: impl IntoIterator<Item = u64>) {
+        let mut packed = Message::default();
+        for value in values {
+            packed.varint(value);
+        }
+        self.bytes(field, &packed.0);
+    }
+
+    fn message(&mut self, field: u32, f: impl FnOnce(&mut Message)) {
+        let mut message = Message::default();
+        f(&mut message);
+        self.bytes(field, &message.0);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use fxprof_processed_profile::Symbol;
+    use std::time::Duration;
+
+    #[test]
+    fn unresolved_frames_and_host_have_their_own_functions() {
+        let symbol = |address| Symbol {
+            address,
+            size: Some(0x10),
+            name: format!("f{address:#x}"),
+        };
+        let profiler = GuestProfiler::from_libs(
+            "test",
+            Duration::from_millis(1),
+            vec![
+                ("a".to_string(), 0..0x100, vec![symbol(0)]),
+                ("b".to_string(), 0x100..0x200, vec![symbol(0)]),
+            ],
+        );
+
+        let mut builder = Builder::default();
+        builder.string("");
+        let frame = |lib, offset| ProfiledFrame { lib, offset };
+        let locations = [
+            builder.location(&profiler, frame(0, 0x5)),
+            builder.location(&profiler, frame(0, 0x51)),
+            builder.location(&profiler, frame(0, 0x61)),
+            builder.location(&profiler, frame(1, 0x51)),
+            builder.host_location(),
+            builder.location(&profiler, frame(1, 0x61)),
+        ];
+        assert_eq!(builder.location(&profiler, frame(0, 0x51)), locations[1]);
+        assert_eq!(builder.host_location(), locations[4]);
+
+        let names = builder
+            .locations
+            .iter()
+            .map(|(_, _, _, function)| {
+                let (_, name, _) = builder.functions[*function as usize - 1];
+                builder.strings[name as usize].as_str()
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            names,
+            ["f0x0", "a+0x51", "a+0x61", "b+0x51", HOST_FRAME, "b+0x61"]
+        );
+    }
+}
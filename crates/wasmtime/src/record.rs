@@ -0,0 +1,699 @@
+//! Recording and replaying of the calls that WebAssembly makes to the host.
+
+use crate::store::StoreOpaque;
+use crate::{FuncType, Instance, ValRaw, ValType};
+use anyhow::{anyhow, bail, ensure, Context, Error, Result};
+use serde_derive::{Deserialize, Serialize};
+use std::collections::hash_map::DefaultHasher;
+use std::fmt;
+use std::hash::Hasher;
+use wasmtime_environ::{EntityRef, MemoryIndex, WASM_PAGE_SIZE};
+use wasmtime_runtime::{ExportMemory, Instance as RuntimeInstance};
+
+/// A log of the calls that WebAssembly made to host functions, which can be
+/// replayed later on to reproduce an execution without the host.
+///
+/// A trace is recorded by calling [`Store::record_host_calls`] before running
+/// WebAssembly in a [`Store`], after which every call from WebAssembly to a
+/// host function, both core functions and component model imports, is logged
+/// along with its arguments, its results and all the bytes that it wrote
+/// into the caller's linear memory. The trace is retrieved afterwards with
+/// [`Store::take_host_call_trace`], and can be saved with
+/// [`HostCallTrace::serialize`].
+///
+/// Handing a trace to [`Store::replay_host_calls`] makes subsequent host
+/// calls in that store not invoke the host at all. Instead each call is
+/// checked against the next recorded call, its recorded writes to linear
+/// memory are applied and its recorded results are returned to WebAssembly.
+/// Provided that the same module is instantiated the same way, this re-executes
+/// the guest exactly as it originally ran, which makes it possible to debug a
+/// misbehaving guest whose inputs came from the outside world, for example
+/// from WASI. When the guest makes a call that differs from the recorded one
+/// the call fails with an error that describes the divergence.
+///
+/// # Limitations
+///
+/// Only the outermost call to the host is recorded, so if a host function
+/// itself calls back into WebAssembly then any host calls made by that
+/// WebAssembly are not recorded and replaying doesn't call back into
+/// WebAssembly. The effects of such callbacks on linear memory are part of the
+/// trace but other effects, for example on globals and tables, are not.
+///
+/// Host functions which take or return references, such as `externref`, can
+/// be recorded but calls which return a reference can't be replayed. Errors
+/// returned by host functions are replayed as a [`HostCallError`] with their
+/// original message, but not their original type.
+///
+/// Component model imports can only write to linear memory while their
+/// results are lowered, so only those writes are recorded. Core host
+/// functions may write anywhere in the memories of the calling instance, so
+/// recording such a call fingerprints every page of those memories before the
+/// call and records the pages whose fingerprint changed. This still reads all
+/// of the memory on each call, so recording is intended as a debugging aid
+/// and not for use in production on every execution.
+///
+/// [`Store`]: crate::Store
+/// [`Store::record_host_calls`]: crate::Store::record_host_calls
+/// [`Store::take_host_call_trace`]: crate::Store::take_host_call_trace
+/// [`Store::replay_host_calls`]: crate::Store::replay_host_calls
+#[derive(Clone, Default, Serialize, Deserialize)]
+pub struct HostCallTrace {
+    calls: Vec<HostCall>,
+}
+
+impl HostCallTrace {
+    /// Returns the number of host calls in this trace.
+    pub fn len(&self) -> usize {
+        self.calls.len()
+    }
+
+    /// Returns whether this trace has no host calls in it.
+    pub fn is_empty(&self) -> bool {
+        self.calls.is_empty()
+    }
+
+    /// Serializes this trace into a list of bytes, which can be turned back
+    /// into a trace with [`HostCallTrace::deserialize`].
+    pub fn serialize(&self) -> Result<Vec<u8>> {
+        Ok(bincode::serialize(self)?)
+    }
+
+    /// Deserializes a trace that was previously produced with
+    /// [`HostCallTrace::serialize`].
+    pub fn deserialize(bytes: &[u8]) -> Result<HostCallTrace> {
+        bincode::deserialize(bytes).context("failed to deserialize host call trace")
+    }
+}
+
+impl fmt::Debug for HostCallTrace {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("HostCallTrace")
+            .field("calls", &self.calls.len())
+            .finish()
+    }
+}
+
+/// A single recorded call from WebAssembly to the host.
+#[derive(Clone, Serialize, Deserialize)]
+pub(crate) struct HostCall {
+    kind: HostCallKind,
+    params: Vec<RecordedVal>,
+    /// The results of the call, which are empty if it failed.
+    pub(crate) results: Vec<RecordedVal>,
+    /// Operations performed by the host while lowering the results of a
+    /// component model import into the guest.
+    pub(crate) lowering: Vec<LowerOp>,
+    pub(crate) memories: Vec<MemoryChange>,
+    error: Option<HostCallError>,
+}
+
+impl HostCall {
+    /// Returns the recorded error of this call, if it failed.
+    pub(crate) fn result(&self) -> Result<()> {
+        match &self.error {
+            Some(e) => Err(e.clone().into()),
+            None => Ok(()),
+        }
+    }
+}
+
+/// An error returned by a host function whose call was replayed from a
+/// [`HostCallTrace`].
+///
+/// This carries the message of the error that the host function originally
+/// returned and, if one was found with
+/// [`Store::host_call_exit_code`](crate::Store::host_call_exit_code) while
+/// recording, the exit status that it requested.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct HostCallError {
+    message: String,
+    exit_code: Option<i32>,
+}
+
+impl HostCallError {
+    pub(crate) fn new(error: &Error, exit_code: fn(&Error) -> Option<i32>) -> HostCallError {
+        HostCallError {
+            message: format!("{error:#}"),
+            exit_code: exit_code(error),
+        }
+    }
+
+    /// Returns the exit status that the original error requested, if any.
+    pub fn exit_code(&self) -> Option<i32> {
+        self.exit_code
+    }
+}
+
+impl fmt::Display for HostCallError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl std::error::Error for HostCallError {}
+
+#[derive(Clone, Copy, PartialEq, Debug, Serialize, Deserialize)]
+pub(crate) enum HostCallKind {
+    Core,
+    #[cfg_attr(not(feature = "component-model"), allow(dead_code))]
+    Component,
+}
+
+/// A WebAssembly value passed to or returned from a host call.
+#[derive(Clone, Copy, PartialEq, Debug, Serialize, Deserialize)]
+pub(crate) enum RecordedVal {
+    I32(u32),
+    I64(u64),
+    F32(u32),
+    F64(u64),
+    V128(u128),
+    /// A reference, whose value isn't recorded.
+    Ref,
+    /// A flattened component model value.
+    #[cfg_attr(not(feature = "component-model"), allow(dead_code))]
+    Flat(u64),
+}
+
+impl RecordedVal {
+    fn new(ty: &ValType, val: &ValRaw) -> RecordedVal {
+        match ty {
+            ValType::I32 => RecordedVal::I32(val.get_u32()),
+            ValType::I64 => RecordedVal::I64(val.get_u64()),
+            ValType::F32 => RecordedVal::F32(val.get_f32()),
+            ValType::F64 => RecordedVal::F64(val.get_f64()),
+            ValType::V128 => RecordedVal::V128(val.get_v128()),
+            ValType::FuncRef | ValType::ExternRef | ValType::AnyRef | ValType::ExnRef => {
+                RecordedVal::Ref
+            }
+        }
+    }
+
+    pub(crate) fn to_raw(&self) -> Result<ValRaw> {
+        Ok(match *self {
+            RecordedVal::I32(i) => ValRaw::u32(i),
+            RecordedVal::I64(i) => ValRaw::u64(i),
+            RecordedVal::F32(i) => ValRaw::f32(i),
+            RecordedVal::F64(i) => ValRaw::f64(i),
+            RecordedVal::V128(i) => ValRaw::v128(i),
+            RecordedVal::Flat(i) => ValRaw::u64(i),
+            RecordedVal::Ref => bail!("cannot replay a host call which returned a reference"),
+        })
+    }
+}
+
+/// An operation performed while lowering the results of a component model
+/// import, which has to be performed again when the call is replayed.
+#[derive(Clone, Copy, Debug, Serialize, Deserialize)]
+#[cfg_attr(not(feature = "component-model"), allow(dead_code))]
+pub(crate) enum LowerOp {
+    /// A call to the guest's `realloc` function and the pointer it returned.
+    Realloc {
+        old: u64,
+        old_size: u64,
+        old_align: u32,
+        new_size: u64,
+        result: u64,
+    },
+    /// An `own` handle that was inserted into one of the guest's resource
+    /// tables.
+    LowerOwn { table: u32, rep: u32 },
+}
+
+/// The bytes that a host call changed in one linear memory.
+#[derive(Clone, Serialize, Deserialize)]
+pub(crate) struct MemoryChange {
+    index: u32,
+    /// The size of the memory after the call, in bytes.
+    size: u64,
+    writes: Vec<MemoryWrite>,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+struct MemoryWrite {
+    offset: u64,
+    bytes: Vec<u8>,
+}
+
+/// The granularity, in bytes, at which changes made by core host functions to
+/// linear memory are detected and recorded.
+const PAGE_SIZE: usize = 4096;
+
+/// Fingerprints of the pages of a linear memory, which are used to find the
+/// pages that a host call changed without copying the whole memory.
+pub(crate) struct MemoryFingerprint {
+    pages: Vec<u64>,
+}
+
+impl MemoryFingerprint {
+    pub(crate) fn new(memory: &[u8]) -> MemoryFingerprint {
+        MemoryFingerprint {
+            pages: memory.chunks(PAGE_SIZE).map(fingerprint).collect(),
+        }
+    }
+}
+
+fn fingerprint(page: &[u8]) -> u64 {
+    // The default hasher always uses the same keys, and a 64-bit hash makes
+    // missing a changed page vanishingly unlikely.
+    let mut hasher = DefaultHasher::new();
+    hasher.write(page);
+    hasher.finish()
+}
+
+impl MemoryChange {
+    /// Records the pages of `after` whose fingerprint differs from `before`,
+    /// returning `None` if the call didn't change the memory.
+    pub(crate) fn dirty_pages(
+        index: u32,
+        before: &MemoryFingerprint,
+        after: &[u8],
+    ) -> Option<MemoryChange> {
+        let mut change = MemoryChange {
+            index,
+            size: after.len() as u64,
+            writes: Vec::new(),
+        };
+        for (i, page) in after.chunks(PAGE_SIZE).enumerate() {
+            let dirty = match before.pages.get(i) {
+                Some(old) => *old != fingerprint(page),
+                // Memory that didn't exist before the call was zero.
+                None => page.iter().any(|b| *b != 0),
+            };
+            if dirty {
+                change.push(i * PAGE_SIZE, page);
+            }
+        }
+        if change.writes.is_empty() && before.pages.len() == after.len().div_ceil(PAGE_SIZE) {
+            return None;
+        }
+        Some(change)
+    }
+
+    /// Records the contents of `memory` in the byte ranges `ranges`, given
+    /// as offset and length, which are all the bytes that a call wrote.
+    ///
+    /// Ranges outside of `memory` are ignored since nothing could have been
+    /// written to them.
+    #[cfg(feature = "component-model")]
+    pub(crate) fn ranges(
+        index: u32,
+        memory: &[u8],
+        ranges: impl IntoIterator<Item = (usize, usize)>,
+    ) -> Option<MemoryChange> {
+        let mut change = MemoryChange {
+            index,
+            size: memory.len() as u64,
+            writes: Vec::new(),
+        };
+        for (offset, len) in ranges {
+            if let Some(bytes) = offset
+                .checked_add(len)
+                .and_then(|end| memory.get(offset..end))
+            {
+                change.push(offset, bytes);
+            }
+        }
+        if change.writes.is_empty() {
+            return None;
+        }
+        Some(change)
+    }
+
+    fn push(&mut self, offset: usize, bytes: &[u8]) {
+        if bytes.is_empty() {
+            return;
+        }
+        match self.writes.last_mut() {
+            Some(w) if w.offset as usize + w.bytes.len() == offset => {
+                w.bytes.extend_from_slice(bytes);
+            }
+            _ => self.writes.push(MemoryWrite {
+                offset: offset as u64,
+                bytes: bytes.to_vec(),
+            }),
+        }
+    }
+
+    pub(crate) fn index(&self) -> u32 {
+        self.index
+    }
+
+    /// Returns the number of bytes that the memory has to grow by for this
+    /// change to be applied to a memory of `current` bytes.
+    pub(crate) fn growth(&self, current: usize) -> Result<u64> {
+        ensure!(
+            self.size >= current as u64,
+            "replay diverged: memory {} is larger than it was when recorded",
+            self.index
+        );
+        Ok(self.size - current as u64)
+    }
+
+    /// Writes the recorded bytes into `memory`, which must have already been
+    /// grown to its recorded size.
+    pub(crate) fn apply(&self, memory: &mut [u8]) -> Result<()> {
+        ensure!(
+            memory.len() as u64 == self.size,
+            "replay diverged: memory {} is {} bytes large but was {} bytes large when recorded",
+            self.index,
+            memory.len(),
+            self.size
+        );
+        for write in self.writes.iter() {
+            let start = write.offset as usize;
+            memory[start..][..write.bytes.len()].copy_from_slice(&write.bytes);
+        }
+        Ok(())
+    }
+}
+
+/// The state of recording or replaying host calls within a store.
+#[derive(Default)]
+pub(crate) enum HostCallTracer {
+    #[default]
+    Off,
+    Recording {
+        trace: HostCallTrace,
+        /// Whether a host call that's being recorded is on the stack, in
+        /// which case nested host calls aren't recorded.
+        in_call: bool,
+        /// The operations of the component model import whose results are
+        /// currently being lowered.
+        lowering: Option<Vec<LowerOp>>,
+    },
+    Replaying {
+        /// The calls which remain to be replayed, in reverse order.
+        remaining: Vec<HostCall>,
+        replayed: usize,
+    },
+}
+
+/// What to do about a call to a host function, as returned by
+/// [`HostCallTracer::begin`].
+pub(crate) enum TracedCall {
+    /// The host function should be called as usual.
+    Untraced,
+    /// The host function should be called and then recorded with
+    /// [`HostCallTracer::finish`].
+    Record(Vec<RecordedVal>),
+    /// The host function must not be called and the recorded call should be
+    /// replayed instead.
+    Replay(HostCall),
+}
+
+impl HostCallTracer {
+    pub(crate) fn record() -> HostCallTracer {
+        HostCallTracer::Recording {
+            trace: HostCallTrace::default(),
+            in_call: false,
+            lowering: None,
+        }
+    }
+
+    pub(crate) fn replay(mut trace: HostCallTrace) -> HostCallTracer {
+        trace.calls.reverse();
+        HostCallTracer::Replaying {
+            remaining: trace.calls,
+            replayed: 0,
+        }
+    }
+
+    /// Returns the trace that's been recorded so far, if recording, and stops
+    /// recording or replaying.
+    pub(crate) fn take_trace(&mut self) -> Option<HostCallTrace> {
+        match std::mem::take(self) {
+            HostCallTracer::Recording { trace, .. } => Some(trace),
+            HostCallTracer::Off | HostCallTracer::Replaying { .. } => None,
+        }
+    }
+
+    #[inline]
+    pub(crate) fn is_enabled(&self) -> bool {
+        !matches!(self, HostCallTracer::Off)
+    }
+
+    /// Starts a call to a host function with the given parameters.
+    ///
+    /// Returns an error if the call doesn't match the next call of the trace
+    /// that's being replayed.
+    pub(crate) fn begin(
+        &mut self,
+        kind: HostCallKind,
+        params: Vec<RecordedVal>,
+    ) -> Result<TracedCall> {
+        match self {
+            HostCallTracer::Off | HostCallTracer::Recording { in_call: true, .. } => {
+                Ok(TracedCall::Untraced)
+            }
+            HostCallTracer::Recording { in_call, .. } => {
+                *in_call = true;
+                Ok(TracedCall::Record(params))
+            }
+            HostCallTracer::Replaying {
+                remaining,
+                replayed,
+            } => {
+                let n = *replayed;
+                let call = remaining.pop().with_context(|| {
+                    format!(
+                        "replay diverged: host call {n} was not recorded, the trace is exhausted"
+                    )
+                })?;
+                ensure!(
+                    call.kind == kind && call.params == params,
+                    "replay diverged: host call {n} was recorded as a {:?} call with {:?} \
+                     but was made as a {kind:?} call with {params:?}",
+                    call.kind,
+                    call.params,
+                );
+                *replayed += 1;
+                Ok(TracedCall::Replay(call))
+            }
+        }
+    }
+
+    /// Starts recording the operations performed while lowering the results
+    /// of the component model import that's being recorded.
+    #[cfg(feature = "component-model")]
+    pub(crate) fn begin_lowering(&mut self) {
+        if let HostCallTracer::Recording {
+            in_call: true,
+            lowering,
+            ..
+        } = self
+        {
+            *lowering = Some(Vec::new());
+        }
+    }
+
+    /// Records an operation performed while lowering the results of a
+    /// component model import, if one is being recorded.
+    #[cfg(feature = "component-model")]
+    pub(crate) fn record_lowering(&mut self, op: LowerOp) {
+        if let HostCallTracer::Recording {
+            lowering: Some(ops),
+            ..
+        } = self
+        {
+            ops.push(op);
+        }
+    }
+
+    /// Returns the operations recorded so far while lowering the results of
+    /// the component model import that's being recorded.
+    #[cfg(feature = "component-model")]
+    pub(crate) fn lowering(&self) -> &[LowerOp] {
+        match self {
+            HostCallTracer::Recording {
+                lowering: Some(ops),
+                ..
+            } => ops,
+            _ => &[],
+        }
+    }
+
+    /// Finishes recording a host call which was started by
+    /// [`HostCallTracer::begin`] returning [`TracedCall::Record`].
+    pub(crate) fn finish(
+        &mut self,
+        kind: HostCallKind,
+        params: Vec<RecordedVal>,
+        results: Vec<RecordedVal>,
+        memories: Vec<MemoryChange>,
+        error: Option<HostCallError>,
+    ) {
+        // Note that the host function itself may have stopped recording.
+        if let HostCallTracer::Recording {
+            trace,
+            in_call,
+            lowering,
+        } = self
+        {
+            *in_call = false;
+            trace.calls.push(HostCall {
+                kind,
+                params,
+                results: if error.is_some() { Vec::new() } else { results },
+                lowering: lowering.take().unwrap_or_default(),
+                memories,
+                error,
+            });
+        }
+    }
+}
+
+/// The core instance that called a host function, whose linear memories are
+/// recorded and replayed.
+pub(crate) struct CoreCaller {
+    /// The calling instance, or `None` if the host function was called
+    /// directly by the host.
+    instance: Option<Instance>,
+    /// Fingerprints of the instance's memories before a recorded call.
+    memories: Vec<MemoryFingerprint>,
+}
+
+impl CoreCaller {
+    pub(crate) fn new(caller: &RuntimeInstance) -> CoreCaller {
+        CoreCaller {
+            instance: caller.host_state().downcast_ref::<Instance>().copied(),
+            memories: Vec::new(),
+        }
+    }
+
+    fn memories(&self, store: &mut StoreOpaque) -> Vec<ExportMemory> {
+        let Some(instance) = self.instance else {
+            return Vec::new();
+        };
+        let id = instance.id(store);
+        let handle = store.instance_mut(id);
+        (0..handle.module().memory_plans.len())
+            .map(|i| handle.get_exported_memory(MemoryIndex::new(i)))
+            .collect()
+    }
+
+    /// Starts a call from this instance to a host function with type `ty` and
+    /// the parameters in `values`.
+    ///
+    /// If the call is replayed then its results are stored into `values`,
+    /// and if the recorded call failed its error is returned.
+    pub(crate) fn begin(
+        &mut self,
+        store: &mut StoreOpaque,
+        ty: &FuncType,
+        values: &mut [ValRaw],
+    ) -> Result<TracedCall> {
+        let params = ty
+            .params()
+            .zip(values.iter())
+            .map(|(ty, val)| RecordedVal::new(&ty, val))
+            .collect();
+        let call = store.host_call_tracer().begin(HostCallKind::Core, params)?;
+        match &call {
+            TracedCall::Untraced => {}
+            TracedCall::Record(_) => {
+                self.memories = self
+                    .memories(store)
+                    .iter()
+                    .map(|m| MemoryFingerprint::new(unsafe { memory_slice(m) }))
+                    .collect();
+            }
+            TracedCall::Replay(call) => {
+                let memories = self.memories(store);
+                for change in call.memories.iter() {
+                    let memory = memories.get(change.index() as usize).with_context(|| {
+                        format!(
+                            "replay diverged: host call wrote to memory {} which doesn't exist",
+                            change.index()
+                        )
+                    })?;
+                    unsafe {
+                        replay_memory(store, memory, change)?;
+                    }
+                }
+                call.result()?;
+                ensure!(
+                    call.results.len() == ty.results().len(),
+                    "replay diverged: host call returned a different number of results"
+                );
+                for (slot, val) in values.iter_mut().zip(call.results.iter()) {
+                    *slot = val.to_raw()?;
+                }
+            }
+        }
+        Ok(call)
+    }
+
+    /// Finishes recording a call from this instance that was started with
+    /// [`CoreCaller::begin`], whose results are in `values` unless it failed
+    /// with `error`.
+    pub(crate) fn finish(
+        self,
+        store: &mut StoreOpaque,
+        params: Vec<RecordedVal>,
+        ty: &FuncType,
+        values: &[ValRaw],
+        error: Option<&Error>,
+    ) {
+        let results = ty
+            .results()
+            .zip(values.iter())
+            .map(|(ty, val)| RecordedVal::new(&ty, val))
+            .collect();
+        let memories = self
+            .memories(store)
+            .iter()
+            .zip(self.memories.iter())
+            .enumerate()
+            .filter_map(|(i, (memory, before))| {
+                MemoryChange::dirty_pages(i as u32, before, unsafe { memory_slice(memory) })
+            })
+            .collect();
+        let error = error.map(|e| HostCallError::new(e, store.host_call_exit_code()));
+        store
+            .host_call_tracer()
+            .finish(HostCallKind::Core, params, results, memories, error);
+    }
+}
+
+/// Returns the contents of `memory`.
+///
+/// # Unsafety
+///
+/// The memory must be owned by a store that's borrowed for the lifetime of
+/// the returned slice.
+unsafe fn memory_slice<'a>(memory: &ExportMemory) -> &'a [u8] {
+    let definition = &*memory.definition;
+    std::slice::from_raw_parts(definition.base, definition.current_length())
+}
+
+/// Grows `memory` to its recorded size and writes the recorded bytes into it.
+///
+/// # Unsafety
+///
+/// The memory must be owned by `store`.
+unsafe fn replay_memory(
+    store: &mut StoreOpaque,
+    memory: &ExportMemory,
+    change: &MemoryChange,
+) -> Result<()> {
+    let current = (*memory.definition).current_length();
+    let delta = change.growth(current)? / u64::from(WASM_PAGE_SIZE);
+    if delta > 0 {
+        RuntimeInstance::from_vmctx(memory.vmctx, |handle| {
+            let mem = &mut *handle.get_defined_memory(memory.index);
+            match mem.grow(delta, Some(&mut *store.traitobj()))? {
+                Some(_) => {
+                    *memory.definition = mem.vmmemory();
+                    Ok(())
+                }
+                None => Err(anyhow!("failed to grow memory by `{delta}`")),
+            }
+        })
+        .context("failed to replay memory growth")?;
+    }
+    let definition = &*memory.definition;
+    change.apply(std::slice::from_raw_parts_mut(
+        definition.base,
+        definition.current_length(),
+    ))
+}
@@ -1,9 +1,18 @@
 #![allow(missing_docs)]
 
-use crate::AsContextMut;
+use crate::store::{StoreId, StoreOpaque};
+use crate::{AsContext, AsContextMut, Func, Tag, Val};
+use anyhow::{bail, Result};
 use std::any::Any;
 use std::ffi::c_void;
-use wasmtime_runtime::VMExternRef;
+use std::hash::{Hash, Hasher};
+use std::sync::Arc;
+use std::{mem, ptr};
+use wasmtime_environ::{
+    GcLayout, GcStorage, WasmGcTypeKind, WasmHeapType, WasmRefType, WasmStorageType, WasmType,
+    I31_TAG,
+};
+use wasmtime_runtime::{Exception, VMExternRef, VMSharedGcTypeIndex};
 
 /// Represents an opaque reference to any data within WebAssembly.
 #[derive(Clone, Debug)]
@@ -107,3 +116,686 @@ impl std::fmt::Pointer for ExternRef {
         std::fmt::Pointer::fmt(&self.inner, f)
     }
 }
+
+/// A reference in the `any` hierarchy of the [GC proposal].
+///
+/// This is either an unboxed 31-bit integer, an [`I31`], or a reference to a
+/// `struct` or `array` object in the GC heap of a store, a [`StructRef`] or an
+/// [`ArrayRef`].
+///
+/// [GC proposal]: https://github.com/WebAssembly/gc
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct AnyRef(AnyRefInner);
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+enum AnyRefInner {
+    I31(I31),
+    Struct(StructRef),
+    Array(ArrayRef),
+}
+
+impl AnyRef {
+    /// Creates a new `i31ref` from the given [`I31`].
+    pub fn from_i31(i31: I31) -> AnyRef {
+        AnyRef(AnyRefInner::I31(i31))
+    }
+
+    /// Returns whether this reference is an `i31ref`.
+    pub fn is_i31(&self) -> bool {
+        matches!(self.0, AnyRefInner::I31(_))
+    }
+
+    /// Returns this reference's [`I31`] if it is an `i31ref`.
+    pub fn as_i31(&self) -> Option<I31> {
+        match self.0 {
+            AnyRefInner::I31(i31) => Some(i31),
+            _ => None,
+        }
+    }
+
+    /// Returns this reference's [`StructRef`] if it refers to a `struct`.
+    pub fn as_struct(&self) -> Option<&StructRef> {
+        match &self.0 {
+            AnyRefInner::Struct(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    /// Returns this reference's [`ArrayRef`] if it refers to an `array`.
+    pub fn as_array(&self) -> Option<&ArrayRef> {
+        match &self.0 {
+            AnyRefInner::Array(a) => Some(a),
+            _ => None,
+        }
+    }
+
+    /// Creates an [`AnyRef`] from the raw value provided, returning `None` if
+    /// it is the null reference.
+    ///
+    /// This is intended to be used in conjunction with [`Func::new_unchecked`],
+    /// [`Func::call_unchecked`], and [`ValRaw`] with its `anyref` field.
+    ///
+    /// # Unsafety
+    ///
+    /// This function is `unsafe` because `raw` must be a valid `anyref` value
+    /// produced prior by `to_raw` or by WebAssembly within `store`. Like with
+    /// [`ExternRef::from_raw`], `raw` must still be rooted, meaning that no GC
+    /// may have happened in `store` since it was produced.
+    ///
+    /// [`Func::call_unchecked`]: crate::Func::call_unchecked
+    /// [`Func::new_unchecked`]: crate::Func::new_unchecked
+    /// [`ValRaw`]: crate::ValRaw
+    pub unsafe fn from_raw(mut store: impl AsContextMut, raw: u32) -> Option<AnyRef> {
+        AnyRef::_from_raw(store.as_context_mut().0, raw)
+    }
+
+    pub(crate) unsafe fn _from_raw(store: &mut StoreOpaque, raw: u32) -> Option<AnyRef> {
+        if raw == 0 {
+            return None;
+        }
+        if raw & I31_TAG != 0 {
+            return Some(AnyRef::from_i31(I31(raw >> 1)));
+        }
+        let is_array = match store.gc_heap().object_type(raw).layout {
+            GcLayout::Struct { .. } => false,
+            GcLayout::Array(_) => true,
+        };
+        let object = GcObject {
+            root: store.root_gc_object(raw),
+            store: store.id(),
+        };
+        Some(if is_array {
+            AnyRef(AnyRefInner::Array(ArrayRef(object)))
+        } else {
+            AnyRef(AnyRefInner::Struct(StructRef(object)))
+        })
+    }
+
+    /// Converts this [`AnyRef`] to a raw value suitable to store within a
+    /// [`ValRaw`].
+    ///
+    /// # Unsafety
+    ///
+    /// Produces a raw value which is only safe to pass into a store if a GC
+    /// doesn't happen between when the value is produced and when it's passed
+    /// into the store.
+    ///
+    /// # Panics
+    ///
+    /// Panics if this reference refers to an object in a store other than
+    /// `store`.
+    ///
+    /// [`ValRaw`]: crate::ValRaw
+    pub unsafe fn to_raw(&self, store: impl AsContext) -> u32 {
+        self._to_raw(store.as_context().0)
+    }
+
+    pub(crate) fn _to_raw(&self, store: &StoreOpaque) -> u32 {
+        match &self.0 {
+            AnyRefInner::I31(i31) => (i31.0 << 1) | I31_TAG,
+            AnyRefInner::Struct(StructRef(object)) | AnyRefInner::Array(ArrayRef(object)) => {
+                object.get(store)
+            }
+        }
+    }
+
+    pub(crate) fn comes_from_same_store(&self, store: &StoreOpaque) -> bool {
+        match &self.0 {
+            AnyRefInner::I31(_) => true,
+            AnyRefInner::Struct(StructRef(object)) | AnyRefInner::Array(ArrayRef(object)) => {
+                object.store == store.id()
+            }
+        }
+    }
+
+    /// Returns whether the possibly-null reference `anyref`, which must come
+    /// from `store`, is a value of the type `ty`.
+    pub(crate) fn matches_ty(
+        anyref: Option<&AnyRef>,
+        store: &StoreOpaque,
+        ty: WasmRefType,
+    ) -> bool {
+        let anyref = match anyref {
+            Some(anyref) => anyref,
+            None => return ty.nullable,
+        };
+        match (&anyref.0, ty.heap_type) {
+            (_, WasmHeapType::Any | WasmHeapType::Eq)
+            | (AnyRefInner::I31(_), WasmHeapType::I31)
+            | (AnyRefInner::Struct(_), WasmHeapType::Struct)
+            | (AnyRefInner::Array(_), WasmHeapType::Array) => true,
+            (AnyRefInner::Struct(StructRef(object)), WasmHeapType::TypedStruct(index))
+            | (AnyRefInner::Array(ArrayRef(object)), WasmHeapType::TypedArray(index)) => store
+                .gc_heap()
+                .object_type(object.get(store))
+                .is_subtype_of(VMSharedGcTypeIndex::new(index.as_u32())),
+            _ => false,
+        }
+    }
+}
+
+impl From<I31> for AnyRef {
+    fn from(i31: I31) -> AnyRef {
+        AnyRef::from_i31(i31)
+    }
+}
+
+impl From<StructRef> for AnyRef {
+    fn from(s: StructRef) -> AnyRef {
+        AnyRef(AnyRefInner::Struct(s))
+    }
+}
+
+impl From<ArrayRef> for AnyRef {
+    fn from(a: ArrayRef) -> AnyRef {
+        AnyRef(AnyRefInner::Array(a))
+    }
+}
+
+/// A root which keeps an object in a store's GC heap alive while the host
+/// holds references to it.
+///
+/// The store keeps every root it creates, and drops those which are no longer
+/// referenced by anything else when it collects garbage.
+#[derive(Debug)]
+pub(crate) struct GcRoot(pub(crate) u32);
+
+#[derive(Clone, Debug)]
+struct GcObject {
+    root: Arc<GcRoot>,
+    store: StoreId,
+}
+
+impl GcObject {
+    fn get(&self, store: &StoreOpaque) -> u32 {
+        assert_eq!(store.id(), self.store, "object used with the wrong store");
+        self.root.0
+    }
+}
+
+impl PartialEq for GcObject {
+    fn eq(&self, other: &GcObject) -> bool {
+        self.store == other.store && self.root.0 == other.root.0
+    }
+}
+
+impl Eq for GcObject {}
+
+impl Hash for GcObject {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.store.hash(state);
+        self.root.0.hash(state);
+    }
+}
+
+/// A reference to a `struct` object in the GC heap of a store.
+///
+/// The object is kept alive for as long as any `StructRef` referring to it
+/// exists, or until its store is dropped. Equality of `StructRef`s is the
+/// identity of the objects they refer to.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct StructRef(GcObject);
+
+impl StructRef {
+    /// Returns the number of fields of this struct.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `store` does not own this object.
+    pub fn fields_len(&self, store: impl AsContext) -> usize {
+        let store = store.as_context().0;
+        match &store.gc_heap().object_type(self.0.get(store)).layout {
+            GcLayout::Struct { fields, .. } => fields.len(),
+            GcLayout::Array(_) => unreachable!(),
+        }
+    }
+
+    /// Returns the value of the field `index` of this struct.
+    ///
+    /// Packed `i8` and `i16` fields are zero-extended to [`Val::I32`]s.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `index` is out of bounds.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `store` does not own this object.
+    pub fn field(&self, mut store: impl AsContextMut, index: usize) -> Result<Val> {
+        let store = store.as_context_mut().0;
+        let r = self.0.get(store);
+        let (offset, storage) = self.field_storage(store, r, index)?;
+        Ok(unsafe { read_storage(store, r + offset, storage) })
+    }
+
+    /// Sets the field `index` of this struct to `value`.
+    ///
+    /// Packed `i8` and `i16` fields are set to the low bits of [`Val::I32`]s.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `index` is out of bounds, if the field is
+    /// immutable, or if `value` doesn't match the type of the field or comes
+    /// from a different store.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `store` does not own this object.
+    pub fn set_field(&self, mut store: impl AsContextMut, index: usize, value: Val) -> Result<()> {
+        let store = store.as_context_mut().0;
+        let r = self.0.get(store);
+        let (offset, storage) = self.field_storage(store, r, index)?;
+        let ty = store.gc_heap().object_type(r).clone();
+        let field = match &ty.ty.kind {
+            WasmGcTypeKind::Struct(fields) => fields[index],
+            WasmGcTypeKind::Array(_) => unreachable!(),
+        };
+        if !field.mutable {
+            bail!("field {index} of the struct is immutable");
+        }
+        write_storage(store, r + offset, storage, field.element_type, value)
+    }
+
+    fn field_storage(&self, store: &StoreOpaque, r: u32, index: usize) -> Result<(u32, GcStorage)> {
+        match &store.gc_heap().object_type(r).layout {
+            GcLayout::Struct { fields, .. } => match fields.get(index) {
+                Some(field) => Ok(*field),
+                None => bail!(
+                    "field index {index} is out of bounds for a struct with {} fields",
+                    fields.len()
+                ),
+            },
+            GcLayout::Array(_) => unreachable!(),
+        }
+    }
+}
+
+/// A reference to an `array` object in the GC heap of a store.
+///
+/// Like [`StructRef`], the object is kept alive for as long as any `ArrayRef`
+/// referring to it exists, and equality is the identity of objects.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ArrayRef(GcObject);
+
+impl ArrayRef {
+    /// Returns the length of this array.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `store` does not own this object.
+    pub fn len(&self, store: impl AsContext) -> u32 {
+        let store = store.as_context().0;
+        store.gc_heap().array_len(self.0.get(store))
+    }
+
+    /// Returns the element `index` of this array.
+    ///
+    /// Packed `i8` and `i16` elements are zero-extended to [`Val::I32`]s.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `index` is out of bounds.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `store` does not own this object.
+    pub fn get(&self, mut store: impl AsContextMut, index: u32) -> Result<Val> {
+        let store = store.as_context_mut().0;
+        let (offset, storage) = self.elem_storage(store, index)?;
+        Ok(unsafe { read_storage(store, offset, storage) })
+    }
+
+    /// Sets the element `index` of this array to `value`.
+    ///
+    /// Packed `i8` and `i16` elements are set to the low bits of
+    /// [`Val::I32`]s.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `index` is out of bounds, if the array's elements
+    /// are immutable, or if `value` doesn't match the type of the elements or
+    /// comes from a different store.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `store` does not own this object.
+    pub fn set(&self, mut store: impl AsContextMut, index: u32, value: Val) -> Result<()> {
+        let store = store.as_context_mut().0;
+        let (offset, storage) = self.elem_storage(store, index)?;
+        let ty = store.gc_heap().object_type(self.0.get(store)).clone();
+        let elem = match &ty.ty.kind {
+            WasmGcTypeKind::Array(elem) => *elem,
+            WasmGcTypeKind::Struct(_) => unreachable!(),
+        };
+        if !elem.mutable {
+            bail!("the elements of the array are immutable");
+        }
+        write_storage(store, offset, storage, elem.element_type, value)
+    }
+
+    fn elem_storage(&self, store: &StoreOpaque, index: u32) -> Result<(u32, GcStorage)> {
+        let heap = store.gc_heap();
+        let r = self.0.get(store);
+        let storage = match heap.object_type(r).layout {
+            GcLayout::Array(storage) => storage,
+            GcLayout::Struct { .. } => unreachable!(),
+        };
+        match heap.array_elems(r, index, 1) {
+            Some(elem) => Ok((u32::try_from(elem.start).unwrap(), storage)),
+            None => bail!(
+                "index {index} is out of bounds for an array of length {}",
+                heap.array_len(r)
+            ),
+        }
+    }
+}
+
+/// Reads the value stored at `offset` in the GC heap of `store`.
+///
+/// # Unsafety
+///
+/// There must be a value stored that way at `offset`.
+unsafe fn read_storage(store: &mut StoreOpaque, offset: u32, storage: GcStorage) -> Val {
+    let offset = usize::try_from(offset).unwrap();
+    let bytes = &store.gc_heap().bytes()[offset..][..usize::try_from(storage.size()).unwrap()];
+    match storage {
+        GcStorage::I8 => Val::I32(bytes[0].into()),
+        GcStorage::I16 => Val::I32(u16::from_le_bytes(bytes.try_into().unwrap()).into()),
+        GcStorage::I32 => Val::I32(i32::from_le_bytes(bytes.try_into().unwrap())),
+        GcStorage::I64 => Val::I64(i64::from_le_bytes(bytes.try_into().unwrap())),
+        GcStorage::F32 => Val::F32(u32::from_le_bytes(bytes.try_into().unwrap())),
+        GcStorage::F64 => Val::F64(u64::from_le_bytes(bytes.try_into().unwrap())),
+        GcStorage::V128 => Val::V128(u128::from_le_bytes(bytes.try_into().unwrap()).into()),
+        GcStorage::AnyRef => {
+            let raw = u32::from_le_bytes(bytes.try_into().unwrap());
+            Val::AnyRef(AnyRef::_from_raw(store, raw))
+        }
+        GcStorage::FuncRef => {
+            let raw = usize::from_le_bytes(bytes[..mem::size_of::<usize>()].try_into().unwrap());
+            Val::FuncRef(Func::from_caller_checked_func_ref(store, raw as *mut _))
+        }
+    }
+}
+
+/// Stores `value`, which must be of the type `ty`, at `offset` in the GC heap
+/// of `store`.
+fn write_storage(
+    store: &mut StoreOpaque,
+    offset: u32,
+    storage: GcStorage,
+    ty: WasmStorageType,
+    value: Val,
+) -> Result<()> {
+    if !value.comes_from_same_store(store) {
+        bail!("cross-`Store` values are not supported in GC objects");
+    }
+    let matches = match (&value, ty) {
+        (Val::I32(_), WasmStorageType::I8 | WasmStorageType::I16) => true,
+        (Val::AnyRef(a), WasmStorageType::Val(WasmType::Ref(r))) if r.heap_type.is_anyref() => {
+            AnyRef::matches_ty(a.as_ref(), store, r)
+        }
+        (Val::FuncRef(f), WasmStorageType::Val(WasmType::Ref(r)))
+            if r.heap_type.top() == WasmHeapType::Func =>
+        {
+            match f {
+                None => r.nullable,
+                Some(f) => match r.heap_type {
+                    WasmHeapType::Func => true,
+                    WasmHeapType::TypedFunc(index) => {
+                        f.sig_index(store.store_data()).bits() == index.as_u32()
+                    }
+                    _ => false,
+                },
+            }
+        }
+        (value, WasmStorageType::Val(ty)) => value.ty().to_wasm_type() == ty,
+        _ => false,
+    };
+    if !matches {
+        bail!(
+            "value of type {} does not match the storage type {ty}",
+            value.ty()
+        );
+    }
+
+    let mut buf = [0; 16];
+    let bytes: &[u8] = match value {
+        Val::I32(i) => {
+            buf[..4].copy_from_slice(&i.to_le_bytes());
+            &buf[..storage.size() as usize]
+        }
+        Val::I64(i) => {
+            buf[..8].copy_from_slice(&i.to_le_bytes());
+            &buf[..8]
+        }
+        Val::F32(f) => {
+            buf[..4].copy_from_slice(&f.to_le_bytes());
+            &buf[..4]
+        }
+        Val::F64(f) => {
+            buf[..8].copy_from_slice(&f.to_le_bytes());
+            &buf[..8]
+        }
+        Val::V128(v) => {
+            buf.copy_from_slice(&v.as_u128().to_le_bytes());
+            &buf[..]
+        }
+        Val::AnyRef(a) => {
+            let raw = a.map_or(0, |a| a._to_raw(store));
+            buf[..4].copy_from_slice(&raw.to_le_bytes());
+            &buf[..4]
+        }
+        Val::FuncRef(f) => {
+            let raw = f.map_or(ptr::null_mut(), |f| f.vm_func_ref(store).as_ptr());
+            buf[..8].copy_from_slice(&(raw as usize as u64).to_le_bytes());
+            &buf[..8]
+        }
+        Val::ExternRef(_) | Val::ExnRef(_) => unreachable!(),
+    };
+    let offset = usize::try_from(offset).unwrap();
+    store.gc_heap_mut().bytes_mut()[offset..][..bytes.len()].copy_from_slice(bytes);
+    Ok(())
+}
+
+/// A 31-bit integer, the payload of an `i31ref`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default)]
+pub struct I31(u32);
+
+impl I31 {
+    const MAX: u32 = (1 << 31) - 1;
+
+    /// Creates an `I31` from an unsigned integer, returning `None` if it
+    /// doesn't fit in 31 bits.
+    pub fn new_u32(value: u32) -> Option<I31> {
+        if value <= Self::MAX {
+            Some(I31(value))
+        } else {
+            None
+        }
+    }
+
+    /// Creates an `I31` from a signed integer, returning `None` if it doesn't
+    /// fit in 31 bits.
+    pub fn new_i32(value: i32) -> Option<I31> {
+        if (-(1 << 30)..1 << 30).contains(&value) {
+            Some(I31::wrapping_i32(value))
+        } else {
+            None
+        }
+    }
+
+    /// Creates an `I31` from the low 31 bits of an unsigned integer,
+    /// discarding its most significant bit.
+    pub fn wrapping_u32(value: u32) -> I31 {
+        I31(value & Self::MAX)
+    }
+
+    /// Creates an `I31` from the low 31 bits of a signed integer, discarding
+    /// its most significant bit. This is how `ref.i31` behaves.
+    pub fn wrapping_i32(value: i32) -> I31 {
+        I31::wrapping_u32(value as u32)
+    }
+
+    /// Returns this value zero-extended to 32 bits, like `i31.get_u`.
+    pub fn get_u32(&self) -> u32 {
+        self.0
+    }
+
+    /// Returns this value sign-extended to 32 bits, like `i31.get_s`.
+    pub fn get_i32(&self) -> i32 {
+        ((self.0 << 1) as i32) >> 1
+    }
+}
+
+/// A reference to a WebAssembly exception, an `exnref` from the [exception
+/// handling proposal].
+///
+/// An exception is made of the [`Tag`] it was thrown with and its fields,
+/// whose types are the parameters of the tag's type. Exceptions are immutable
+/// and reference counted, and like [`Tag`]s they belong to the store they were
+/// created within.
+///
+/// [exception handling proposal]: https://github.com/WebAssembly/exception-handling
+#[derive(Clone, Debug)]
+pub struct ExnRef {
+    pub(crate) inner: VMExternRef,
+    store: StoreId,
+}
+
+impl ExnRef {
+    /// Creates a new exception with the tag `tag` and the field values
+    /// `fields`.
+    ///
+    /// The exception can be thrown into WebAssembly by returning it from a
+    /// host function as a [`ThrownException`](crate::ThrownException) error.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `fields` doesn't match the type of `tag`, or if any
+    /// of the values come from a different store than `store`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `store` does not own `tag`.
+    pub fn new(mut store: impl AsContextMut, tag: &Tag, fields: &[Val]) -> Result<ExnRef> {
+        let mut store = store.as_context_mut();
+        let ty = tag.ty(&store);
+        if ty.params().len() != fields.len() {
+            bail!(
+                "expected {} exception fields, got {}",
+                ty.params().len(),
+                fields.len()
+            );
+        }
+        for (field, ty) in fields.iter().zip(ty.params()) {
+            if field.ty() != ty {
+                bail!(
+                    "exception field of type {} does not match the tag's {ty}",
+                    field.ty()
+                );
+            }
+            if !field.comes_from_same_store(store.0) {
+                bail!("cross-`Store` values are not supported");
+            }
+        }
+        let payload = fields
+            .iter()
+            .map(|field| unsafe { field.to_raw(&mut store) })
+            .collect();
+        let definition = tag.definition(store.0.store_data());
+        let inner = unsafe { Exception::new(definition, ty.as_wasm_func_type(), payload) };
+        Ok(ExnRef {
+            inner,
+            store: store.0.id(),
+        })
+    }
+
+    pub(crate) fn from_vm(inner: VMExternRef, store: StoreId) -> ExnRef {
+        ExnRef { inner, store }
+    }
+
+    pub(crate) fn store_id(&self) -> StoreId {
+        self.store
+    }
+
+    /// Returns the tag that this exception was thrown with.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `store` does not own this exception.
+    pub fn tag(&self, mut store: impl AsContextMut) -> Tag {
+        let store = store.as_context_mut().0;
+        assert_eq!(
+            store.id(),
+            self.store,
+            "exception used with the wrong store"
+        );
+        let tag = wasmtime_runtime::exception(&self.inner).tag();
+        unsafe { Tag::from_definition(tag, store) }
+    }
+
+    /// Returns the field values of this exception.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `store` does not own this exception.
+    pub fn fields(&self, mut store: impl AsContextMut) -> Vec<Val> {
+        let mut store = store.as_context_mut();
+        let ty = self.tag(&mut store).ty(&store);
+        let exception = wasmtime_runtime::exception(&self.inner);
+        exception
+            .payload()
+            .iter()
+            .zip(ty.params())
+            .map(|(raw, ty)| unsafe { Val::from_raw(&mut store, *raw, ty) })
+            .collect()
+    }
+
+    /// Does this `ExnRef` point to the same exception as `other`?
+    pub fn ptr_eq(&self, other: &ExnRef) -> bool {
+        VMExternRef::eq(&self.inner, &other.inner)
+    }
+
+    /// Creates a new strongly-owned [`ExnRef`] from the raw value provided.
+    ///
+    /// This is intended to be used in conjunction with [`Func::new_unchecked`],
+    /// [`Func::call_unchecked`], and [`ValRaw`] with its `externref` field,
+    /// which is also used for `exnref` values.
+    ///
+    /// # Unsafety
+    ///
+    /// This function has the same requirements as [`ExternRef::from_raw`],
+    /// and additionally `raw` must be an `exnref` created within `store`.
+    ///
+    /// [`Func::call_unchecked`]: crate::Func::call_unchecked
+    /// [`Func::new_unchecked`]: crate::Func::new_unchecked
+    /// [`ValRaw`]: crate::ValRaw
+    pub unsafe fn from_raw(store: impl AsContext, raw: *mut c_void) -> Option<ExnRef> {
+        let raw = raw.cast::<u8>();
+        if raw.is_null() {
+            None
+        } else {
+            Some(ExnRef {
+                inner: VMExternRef::clone_from_raw(raw),
+                store: store.as_context().0.id(),
+            })
+        }
+    }
+
+    /// Converts this [`ExnRef`] to a raw value suitable to store within a
+    /// [`ValRaw`].
+    ///
+    /// # Unsafety
+    ///
+    /// This function has the same requirements as [`ExternRef::to_raw`].
+    ///
+    /// [`ValRaw`]: crate::ValRaw
+    pub unsafe fn to_raw(&self, mut store: impl AsContextMut) -> *mut c_void {
+        let exnref_ptr = self.inner.as_raw();
+        store
+            .as_context_mut()
+            .0
+            .insert_vmexternref_without_gc(self.inner.clone());
+        exnref_ptr.cast()
+    }
+}
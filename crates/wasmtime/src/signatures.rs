@@ -6,8 +6,12 @@ use std::{
     sync::RwLock,
 };
 use std::{convert::TryFrom, sync::Arc};
-use wasmtime_environ::{ModuleTypes, PrimaryMap, SignatureIndex, WasmFuncType};
-use wasmtime_runtime::VMSharedSignatureIndex;
+use wasmtime_environ::packed_option::ReservedValue;
+use wasmtime_environ::{
+    EntityRef, GcLayout, GcTypeIndex, ModuleTypes, PrimaryMap, SignatureIndex, WasmFieldType,
+    WasmFuncType, WasmGcType, WasmGcTypeKind, WasmHeapType, WasmRefType, WasmStorageType, WasmType,
+};
+use wasmtime_runtime::{GcTypeInfo, VMSharedGcTypeIndex, VMSharedSignatureIndex};
 
 /// Represents a collection of shared signatures.
 ///
@@ -20,18 +24,20 @@ pub struct SignatureCollection {
     registry: Arc<RwLock<SignatureRegistryInner>>,
     signatures: PrimaryMap<SignatureIndex, VMSharedSignatureIndex>,
     reverse_signatures: HashMap<VMSharedSignatureIndex, SignatureIndex>,
+    gc_types: PrimaryMap<GcTypeIndex, Arc<GcTypeInfo>>,
 }
 
 impl SignatureCollection {
     /// Creates a signature collection for a module given the module's signatures.
     pub fn new_for_module(registry: &SignatureRegistry, types: &ModuleTypes) -> Self {
-        let signatures = registry.0.write().unwrap().register_for_module(types);
+        let (signatures, gc_types) = registry.0.write().unwrap().register_for_module(types);
         let reverse_signatures = signatures.iter().map(|(k, v)| (*v, k)).collect();
 
         Self {
             registry: registry.0.clone(),
             signatures,
             reverse_signatures,
+            gc_types,
         }
     }
 
@@ -54,11 +60,18 @@ impl SignatureCollection {
     pub fn local_signature(&self, index: VMSharedSignatureIndex) -> Option<SignatureIndex> {
         self.reverse_signatures.get(&index).copied()
     }
+
+    /// Gets the registered type of the module's `struct` or `array` type
+    /// `index`.
+    #[inline]
+    pub fn gc_type(&self, index: GcTypeIndex) -> &Arc<GcTypeInfo> {
+        &self.gc_types[index]
+    }
 }
 
 impl Drop for SignatureCollection {
     fn drop(&mut self) {
-        if !self.signatures.is_empty() {
+        if !self.signatures.is_empty() || !self.gc_types.is_empty() {
             self.registry.write().unwrap().unregister_signatures(self);
         }
     }
@@ -70,6 +83,12 @@ struct RegistryEntry {
     ty: WasmFuncType,
 }
 
+#[derive(Debug)]
+struct GcRegistryEntry {
+    references: usize,
+    info: Arc<GcTypeInfo>,
+}
+
 #[derive(Debug, Default)]
 struct SignatureRegistryInner {
     // A map from the Wasm function type to a `VMSharedSignatureIndex`, for all
@@ -87,19 +106,43 @@ struct SignatureRegistryInner {
     // we reuse entries rather than leak them and have logical holes in our
     // `self.entries` list.
     free: Vec<VMSharedSignatureIndex>,
+
+    // A map from the canonical form of every registered `struct` and `array`
+    // type to its registration. Canonical types refer to other types by their
+    // shared indices, so that structurally equivalent types defined by
+    // different modules are registered only once.
+    //
+    // Shared indices of these types are never reused, since they're also
+    // stored in the headers of objects in GC heaps.
+    gc_types: HashMap<WasmGcType, GcRegistryEntry>,
+    next_gc_type: u32,
 }
 
 impl SignatureRegistryInner {
     fn register_for_module(
         &mut self,
         types: &ModuleTypes,
-    ) -> PrimaryMap<SignatureIndex, VMSharedSignatureIndex> {
+    ) -> (
+        PrimaryMap<SignatureIndex, VMSharedSignatureIndex>,
+        PrimaryMap<GcTypeIndex, Arc<GcTypeInfo>>,
+    ) {
+        let mut module = ModuleRegistration {
+            registry: self,
+            types,
+            signatures: vec![None; types.wasm_signatures().count()],
+            gc_types: vec![None; types.gc_types().len()],
+        };
         let mut sigs = PrimaryMap::default();
-        for (idx, ty) in types.wasm_signatures() {
-            let b = sigs.push(self.register(ty));
+        for (idx, _) in types.wasm_signatures() {
+            let b = sigs.push(module.signature(idx));
+            assert_eq!(idx, b);
+        }
+        let mut gc_types = PrimaryMap::default();
+        for (idx, _) in types.gc_types() {
+            let b = gc_types.push(module.gc_type(idx));
             assert_eq!(idx, b);
         }
-        sigs
+        (sigs, gc_types)
     }
 
     fn register(&mut self, ty: &WasmFuncType) -> VMSharedSignatureIndex {
@@ -148,10 +191,46 @@ impl SignatureRegistryInner {
         index
     }
 
+    fn register_gc_type(
+        &mut self,
+        ty: WasmGcType,
+        supertype: Option<&GcTypeInfo>,
+    ) -> Arc<GcTypeInfo> {
+        let entry = self.gc_types.entry(ty).or_insert_with_key(|ty| {
+            let index = VMSharedGcTypeIndex::new(self.next_gc_type);
+            self.next_gc_type = self.next_gc_type.checked_add(1).unwrap();
+            let supertypes = std::iter::once(index)
+                .chain(
+                    supertype
+                        .into_iter()
+                        .flat_map(|s| s.supertypes.iter().copied()),
+                )
+                .collect();
+            GcRegistryEntry {
+                references: 0,
+                info: Arc::new(GcTypeInfo {
+                    index,
+                    layout: GcLayout::new(ty),
+                    ty: ty.clone(),
+                    supertypes,
+                }),
+            }
+        });
+        entry.references += 1;
+        entry.info.clone()
+    }
+
     fn unregister_signatures(&mut self, collection: &SignatureCollection) {
         for (_, index) in collection.signatures.iter() {
             self.unregister_entry(*index, 1);
         }
+        for (_, info) in collection.gc_types.iter() {
+            let entry = self.gc_types.get_mut(&info.ty).unwrap();
+            entry.references -= 1;
+            if entry.references == 0 {
+                self.gc_types.remove(&info.ty);
+            }
+        }
     }
 
     fn unregister_entry(&mut self, index: VMSharedSignatureIndex, count: usize) {
@@ -190,9 +269,121 @@ impl Drop for SignatureRegistryInner {
             self.entries.len(),
             "signature registery not empty: not all entries in free list"
         );
+        assert!(
+            self.gc_types.is_empty(),
+            "signature registry not empty: still have registered types in self.gc_types"
+        );
     }
 }
 
+/// The registration of the types of a single module, which registers each
+/// type after the types it refers to.
+///
+/// Types only refer to types defined before them, or to themselves, so this
+/// always terminates.
+struct ModuleRegistration<'a> {
+    registry: &'a mut SignatureRegistryInner,
+    types: &'a ModuleTypes,
+    signatures: Vec<Option<VMSharedSignatureIndex>>,
+    gc_types: Vec<Option<Arc<GcTypeInfo>>>,
+}
+
+impl ModuleRegistration<'_> {
+    fn signature(&mut self, index: SignatureIndex) -> VMSharedSignatureIndex {
+        if let Some(shared) = self.signatures[index.index()] {
+            return shared;
+        }
+        let types = self.types;
+        let ty = &types[index];
+        let params = ty
+            .params()
+            .iter()
+            .map(|t| self.canonical(*t, None))
+            .collect();
+        let returns = ty
+            .returns()
+            .iter()
+            .map(|t| self.canonical(*t, None))
+            .collect();
+        let shared = self.registry.register(&WasmFuncType::new(params, returns));
+        self.signatures[index.index()] = Some(shared);
+        shared
+    }
+
+    fn gc_type(&mut self, index: GcTypeIndex) -> Arc<GcTypeInfo> {
+        if let Some(info) = &self.gc_types[index.index()] {
+            return info.clone();
+        }
+        let types = self.types;
+        let ty = &types[index];
+        let supertype = ty.supertype.map(|s| self.gc_type(s));
+        let field = |this: &mut Self, field: &WasmFieldType| WasmFieldType {
+            element_type: match field.element_type {
+                WasmStorageType::Val(t) => WasmStorageType::Val(this.canonical(t, Some(index))),
+                packed => packed,
+            },
+            mutable: field.mutable,
+        };
+        let kind = match &ty.kind {
+            WasmGcTypeKind::Struct(fields) => {
+                WasmGcTypeKind::Struct(fields.iter().map(|f| field(self, f)).collect())
+            }
+            WasmGcTypeKind::Array(elem) => WasmGcTypeKind::Array(field(self, elem)),
+        };
+        let canonical = WasmGcType {
+            supertype: supertype.as_ref().map(|s| shared_gc_index(s.index)),
+            is_final: ty.is_final,
+            kind,
+        };
+        let info = self
+            .registry
+            .register_gc_type(canonical, supertype.as_deref());
+        self.gc_types[index.index()] = Some(info.clone());
+        info
+    }
+
+    /// Rewrites the references to `struct` and `array` types in `ty`, which
+    /// belongs to the definition of `this` if it's a `struct` or `array`
+    /// type, to use shared indices.
+    ///
+    /// References to function types within `struct` and `array` types are
+    /// rewritten as well, but not those within function types, which are keyed
+    /// by their module-local indices like before.
+    fn canonical(&mut self, ty: WasmType, this: Option<GcTypeIndex>) -> WasmType {
+        let r = match ty {
+            WasmType::Ref(r) => r,
+            _ => return ty,
+        };
+        let heap_type = match r.heap_type {
+            WasmHeapType::TypedStruct(i) if Some(i) == this => {
+                WasmHeapType::TypedStruct(GcTypeIndex::reserved_value())
+            }
+            WasmHeapType::TypedArray(i) if Some(i) == this => {
+                WasmHeapType::TypedArray(GcTypeIndex::reserved_value())
+            }
+            WasmHeapType::TypedStruct(i) => {
+                WasmHeapType::TypedStruct(shared_gc_index(self.gc_type(i).index))
+            }
+            WasmHeapType::TypedArray(i) => {
+                WasmHeapType::TypedArray(shared_gc_index(self.gc_type(i).index))
+            }
+            WasmHeapType::TypedFunc(i) if this.is_some() => {
+                WasmHeapType::TypedFunc(SignatureIndex::from_u32(self.signature(i).bits()))
+            }
+            other => other,
+        };
+        WasmType::Ref(WasmRefType {
+            heap_type,
+            nullable: r.nullable,
+        })
+    }
+}
+
+/// Returns the shared index `index` as it's stored in canonical types.
+fn shared_gc_index(index: VMSharedGcTypeIndex) -> GcTypeIndex {
+    GcTypeIndex::from_u32(index.bits())
+}
+
 /// Implements a shared signature registry.
 ///
 /// WebAssembly requires that the caller and callee signatures in an indirect
@@ -0,0 +1,679 @@
+//! Snapshots of the state of an instance, which new instances can be created
+//! from.
+
+use crate::instance::OwnedImports;
+use crate::store::StoreOpaque;
+use crate::{AsContextMut, Extern, Instance, Module};
+use anyhow::{bail, ensure, Context, Result};
+use serde_derive::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::ptr::NonNull;
+use std::sync::Arc;
+use wasmtime_environ::{
+    packed_option::ReservedValue, ConstExpr, ConstOp, DataIndex, DefinedFuncIndex,
+    DefinedGlobalIndex, DefinedMemoryIndex, DefinedTableIndex, ElemIndex, FuncIndex, GcTypeIndex,
+    HostPtr, MemoryInitialization, PrimaryMap, SignatureIndex, StaticMemoryInitializer,
+    TableInitialValue, VMOffsets, WasmFuncType, WasmHeapType, WasmRefType, WasmType, I31_TAG,
+    WASM_PAGE_SIZE,
+};
+use wasmtime_runtime::{
+    CompiledModuleId, GcTypeInfo, MemoryImage, ModuleMemoryImages, ModuleRuntimeInfo, TableElement,
+    VMArrayCallFunction, VMFuncRef, VMNativeCallFunction, VMSharedSignatureIndex,
+    VMWasmCallFunction,
+};
+
+/// A snapshot of the state of an [`Instance`], from which any number of new
+/// instances can be created.
+///
+/// A snapshot is taken with [`Instance::snapshot`], typically after running
+/// some initialization code in the instance, and captures the contents of the
+/// instance's own linear memories, globals and tables. Instantiating the
+/// snapshot with [`InstanceSnapshot::instantiate`] then creates an instance of
+/// the same [`Module`] whose state starts out exactly as it was when the
+/// snapshot was taken, without running the module's `start` function or data
+/// and element segments again. This makes it possible to pay for expensive
+/// initialization once and then create many pre-initialized instances cheaply.
+///
+/// When [`Config::memory_init_cow`](crate::Config::memory_init_cow) is
+/// enabled, which it is by default, the captured contents of linear memories
+/// are mapped into new instances copy-on-write in the same way as a module's
+/// data segments, so instantiating a snapshot doesn't copy its memory.
+///
+/// Snapshots can also be saved with [`InstanceSnapshot::serialize`] and loaded
+/// again with [`InstanceSnapshot::deserialize`], for example to distribute a
+/// pre-initialized instance along with its precompiled module.
+///
+/// # Limitations
+///
+/// Only state owned by the instance is captured. Imported memories, tables
+/// and globals are left untouched both when taking a snapshot and when
+/// instantiating it, so a snapshot should only be instantiated with imports
+/// that are equivalent to the ones the original instance was given.
+///
+/// Taking a snapshot fails if the instance's state can't be recreated from
+/// the module alone. This is the case if the instance defines a shared
+/// memory, or if its globals or tables hold a non-null `externref` or
+/// `exnref`, or a `funcref` to a function that doesn't belong to the
+/// instance.
+///
+/// # Example
+///
+/// ```
+/// # use wasmtime::*;
+/// # fn main() -> anyhow::Result<()> {
+/// let engine = Engine::default();
+/// let module = Module::new(
+///     &engine,
+///     r#"
+///         (module
+///             (memory (export "memory") 1)
+///             (global $counter (mut i32) (i32.const 0))
+///             (func (export "init")
+///                 (i32.store (i32.const 100) (i32.const 42))
+///                 (global.set $counter (i32.const 10)))
+///             (func (export "next") (result i32)
+///                 (global.set $counter (i32.add (global.get $counter) (i32.const 1)))
+///                 (global.get $counter)))
+///     "#,
+/// )?;
+///
+/// // Initialize an instance once and take a snapshot of it...
+/// let mut store = Store::new(&engine, ());
+/// let instance = Instance::new(&mut store, &module, &[])?;
+/// let init = instance.get_typed_func::<(), ()>(&mut store, "init")?;
+/// init.call(&mut store, ())?;
+/// let snapshot = instance.snapshot(&mut store)?;
+///
+/// // ... and then create pre-initialized instances from it.
+/// for _ in 0..3 {
+///     let mut store = Store::new(&engine, ());
+///     let instance = snapshot.instantiate(&mut store, &[])?;
+///     let next = instance.get_typed_func::<(), i32>(&mut store, "next")?;
+///     assert_eq!(next.call(&mut store, ())?, 11);
+///     let memory = instance.get_memory(&mut store, "memory").unwrap();
+///     assert_eq!(memory.data(&store)[100], 42);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct InstanceSnapshot {
+    module: Module,
+    state: Arc<SnapshotState>,
+    runtime_info: Arc<dyn ModuleRuntimeInfo>,
+}
+
+/// The captured state of an instance, which is what gets serialized.
+#[derive(Serialize, Deserialize)]
+struct SnapshotState {
+    memories: PrimaryMap<DefinedMemoryIndex, MemorySnapshot>,
+    globals: PrimaryMap<DefinedGlobalIndex, ConstExpr>,
+    tables: PrimaryMap<DefinedTableIndex, TableSnapshot>,
+    dropped_data: Vec<DataIndex>,
+    dropped_elements: Vec<ElemIndex>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct MemorySnapshot {
+    /// The size of the memory, in wasm pages.
+    pages: u64,
+    /// The byte offset of `data` within the memory. Everything outside of
+    /// `data` is zero.
+    offset: u64,
+    data: Vec<u8>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct TableSnapshot {
+    size: u32,
+    /// The elements of a `funcref` table, with the reserved index for null.
+    /// This is empty for other tables, whose elements are all null.
+    elements: Vec<FuncIndex>,
+}
+
+impl Instance {
+    /// Takes a snapshot of the current state of this instance, from which new
+    /// instances can be created.
+    ///
+    /// For more information see [`InstanceSnapshot`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the instance holds state that can't be captured, as
+    /// described in the limitations of [`InstanceSnapshot`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `store` does not own this instance.
+    pub fn snapshot(&self, mut store: impl AsContextMut) -> Result<InstanceSnapshot> {
+        let store = store.as_context_mut().0;
+        let module = self._module(store).clone();
+        let state = SnapshotState::capture(store, self)?;
+        InstanceSnapshot::new(module, state)
+    }
+}
+
+impl InstanceSnapshot {
+    fn new(module: Module, state: SnapshotState) -> Result<InstanceSnapshot> {
+        let inner = module.runtime_info();
+        let env_module = inner.module();
+        state.validate(env_module)?;
+
+        let mut env_module = (**env_module).clone();
+        env_module.start_func = None;
+        env_module.global_initializers = state.globals.clone();
+
+        for (index, table) in state.tables.iter() {
+            let index = env_module.table_index(index);
+            env_module.table_plans[index].table.minimum = table.size;
+        }
+        for (index, init) in env_module.table_initialization.initial_values.iter_mut() {
+            *init = TableInitialValue::Null {
+                precomputed: state.tables[index].elements.clone(),
+            };
+        }
+        env_module.table_initialization.segments.clear();
+        for index in state.dropped_elements.iter() {
+            env_module.passive_elements_map.remove(index);
+        }
+        for index in state.dropped_data.iter() {
+            env_module.passive_data_map.remove(index);
+        }
+
+        // The captured contents of memories are appended to the module's own
+        // data, which passive data segments still refer to, and then
+        // described as a page-aligned static initializer for each memory.
+        let page_size = wasmtime_runtime::page_size();
+        let mut wasm_data = inner.wasm_data().to_vec();
+        let mut map = PrimaryMap::with_capacity(env_module.memory_plans.len());
+        for index in env_module.memory_plans.keys() {
+            let Some(defined_index) = env_module.defined_memory_index(index) else {
+                map.push(None);
+                continue;
+            };
+            let memory = &state.memories[defined_index];
+            env_module.memory_plans[index].memory.minimum = memory.pages;
+            if memory.data.is_empty() {
+                map.push(None);
+                continue;
+            }
+            let len = memory.pages * u64::from(WASM_PAGE_SIZE);
+            let start = memory.offset - memory.offset % page_size as u64;
+            let end = (memory.offset + memory.data.len() as u64)
+                .next_multiple_of(page_size as u64)
+                .min(len);
+            let data_start = wasm_data.len();
+            wasm_data.resize(data_start + (memory.offset - start) as usize, 0);
+            wasm_data.extend_from_slice(&memory.data);
+            wasm_data.resize(data_start + (end - start) as usize, 0);
+            let data = u32::try_from(data_start)
+                .ok()
+                .zip(u32::try_from(wasm_data.len()).ok())
+                .context("snapshot memories are too large")?;
+            map.push(Some(StaticMemoryInitializer {
+                offset: start,
+                data: data.0..data.1,
+            }));
+        }
+        env_module.memory_initialization = MemoryInitialization::Static { map };
+
+        // Images can only be created if everything is aligned to the host's
+        // page size, which is the case unless pages are larger than wasm
+        // pages.
+        let engine = module.engine();
+        let memory_images =
+            if engine.config().memory_init_cow && WASM_PAGE_SIZE as usize % page_size == 0 {
+                ModuleMemoryImages::new(&env_module, &wasm_data, None)?
+            } else {
+                None
+            };
+
+        let runtime_info = Arc::new(SnapshotRuntimeInfo {
+            inner,
+            module: Arc::new(env_module),
+            wasm_data,
+            memory_images,
+        });
+        Ok(InstanceSnapshot {
+            module,
+            state: Arc::new(state),
+            runtime_info,
+        })
+    }
+
+    /// Returns the module that this is a snapshot of an instance of.
+    pub fn module(&self) -> &Module {
+        &self.module
+    }
+
+    /// Creates a new instance from this snapshot in `store`.
+    ///
+    /// The `imports` are provided in the same way as for [`Instance::new`],
+    /// and the module's `start` function is not run. The new instance's
+    /// memories, globals and tables start out with the contents they had when
+    /// the snapshot was taken.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error for the same reasons as [`Instance::new`], other than
+    /// those related to running the `start` function.
+    ///
+    /// # Panics
+    ///
+    /// Panics if called with a store associated with an [asynchronous
+    /// config](crate::Config::async_support), or if any [`Extern`] supplied is
+    /// not owned by `store`.
+    pub fn instantiate(
+        &self,
+        mut store: impl AsContextMut,
+        imports: &[Extern],
+    ) -> Result<Instance> {
+        let store = store.as_context_mut().0;
+        assert!(
+            !store.async_support(),
+            "must use async instantiation when async support is enabled",
+        );
+        let imports = self.typecheck(store, imports)?;
+        // Note that the unsafety here is satisfied by `typecheck` above, which
+        // checks that the imports are valid for this module.
+        let (instance, _start) = unsafe {
+            Instance::new_raw(store, &self.module, &self.runtime_info, imports.as_ref())?
+        };
+        Ok(instance)
+    }
+
+    /// Same as [`InstanceSnapshot::instantiate`], except for usage in
+    /// [asynchronous stores](crate::Config::async_support).
+    ///
+    /// # Panics
+    ///
+    /// Panics if called with a store associated with a synchronous config, or
+    /// if any [`Extern`] supplied is not owned by `store`.
+    #[cfg(feature = "async")]
+    #[cfg_attr(nightlydoc, doc(cfg(feature = "async")))]
+    pub async fn instantiate_async<T>(
+        &self,
+        mut store: impl AsContextMut<Data = T>,
+        imports: &[Extern],
+    ) -> Result<Instance>
+    where
+        T: Send,
+    {
+        let mut store = store.as_context_mut();
+        assert!(
+            store.0.async_support(),
+            "must use sync instantiation when async support is disabled",
+        );
+        let imports = self.typecheck(store.0, imports)?;
+        // An async resource limiter may need to yield during allocation, so
+        // this happens on a fiber. See `instantiate` for notes on unsafety.
+        let (instance, _start) = store
+            .on_fiber(|store| unsafe {
+                Instance::new_raw(store.0, &self.module, &self.runtime_info, imports.as_ref())
+            })
+            .await??;
+        Ok(instance)
+    }
+
+    fn typecheck(&self, store: &mut StoreOpaque, imports: &[Extern]) -> Result<OwnedImports> {
+        Instance::typecheck_externs(store, &self.module, imports)
+    }
+
+    /// Serializes this snapshot into a list of bytes.
+    ///
+    /// The bytes can be turned back into a snapshot with
+    /// [`InstanceSnapshot::deserialize`], given the same module. The module
+    /// itself isn't included, so the snapshot's bytes should be stored
+    /// alongside the module, for example in its [serialized
+    /// form](Module::serialize).
+    pub fn serialize(&self) -> Result<Vec<u8>> {
+        Ok(bincode::serialize(&*self.state)?)
+    }
+
+    /// Deserializes a snapshot of an instance of `module` that was previously
+    /// produced with [`InstanceSnapshot::serialize`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `bytes` isn't a valid snapshot of an instance of
+    /// `module`. Note that a snapshot of an instance of a different module
+    /// that happens to define the same memories, globals and tables may not
+    /// be detected as invalid, in which case the instance it produces will
+    /// start out in an unexpected, but safe, state.
+    pub fn deserialize(module: &Module, bytes: &[u8]) -> Result<InstanceSnapshot> {
+        let state =
+            bincode::deserialize(bytes).context("failed to deserialize instance snapshot")?;
+        InstanceSnapshot::new(module.clone(), state)
+    }
+}
+
+impl SnapshotState {
+    fn capture(store: &mut StoreOpaque, instance: &Instance) -> Result<SnapshotState> {
+        let id = instance.id(store);
+        let handle = store.instance_mut(id);
+        let env_module = handle.module().clone();
+
+        // References to this instance's functions are captured as function
+        // indices, and any other reference to a function can't be.
+        let funcs = env_module
+            .functions
+            .iter()
+            .filter(|(_, func)| func.is_escaping())
+            .map(|(index, _)| (handle.get_exported_func(index).func_ref.as_ptr(), index))
+            .collect::<HashMap<_, _>>();
+        let func_index = |func_ref: *mut VMFuncRef| -> Result<FuncIndex> {
+            if func_ref.is_null() {
+                return Ok(FuncIndex::reserved_value());
+            }
+            funcs.get(&func_ref).copied().context(
+                "cannot snapshot a reference to a function that doesn't belong to the instance",
+            )
+        };
+
+        let mut memories = PrimaryMap::new();
+        for export in handle.defined_memories() {
+            ensure!(
+                !export.memory.memory.shared,
+                "cannot snapshot an instance that defines a shared memory"
+            );
+            let data = unsafe {
+                let definition = &*export.definition;
+                std::slice::from_raw_parts(definition.base, definition.current_length())
+            };
+            let pages = (data.len() / WASM_PAGE_SIZE as usize) as u64;
+            let start = data.iter().position(|b| *b != 0).unwrap_or(data.len());
+            let end = data.iter().rposition(|b| *b != 0).map_or(start, |i| i + 1);
+            memories.push(MemorySnapshot {
+                pages,
+                offset: start as u64,
+                data: data[start..end].to_vec(),
+            });
+        }
+
+        let mut globals = PrimaryMap::new();
+        for (_, export) in handle.defined_globals() {
+            let definition = unsafe { &*export.definition };
+            let op = unsafe {
+                match export.global.wasm_ty {
+                    WasmType::I32 => ConstOp::I32Const(*definition.as_i32()),
+                    WasmType::I64 => ConstOp::I64Const(*definition.as_i64()),
+                    WasmType::F32 => ConstOp::F32Const(*definition.as_u32()),
+                    WasmType::F64 => ConstOp::F64Const(*definition.as_u64()),
+                    WasmType::V128 => ConstOp::V128Const(*definition.as_u128()),
+                    WasmType::Ref(r) => match r.heap_type.top() {
+                        WasmHeapType::Func => match func_index(definition.as_func_ref())? {
+                            f if f.is_reserved_value() => ConstOp::RefNull,
+                            f => ConstOp::RefFunc(f),
+                        },
+                        WasmHeapType::Any => match *definition.as_u32() {
+                            0 => ConstOp::RefNull,
+                            raw => {
+                                ensure!(
+                                    raw & I31_TAG != 0,
+                                    "cannot snapshot a reference to a garbage-collected object"
+                                );
+                                globals.push(ConstExpr::new([
+                                    ConstOp::I32Const((raw as i32) >> 1),
+                                    ConstOp::RefI31,
+                                ]));
+                                continue;
+                            }
+                        },
+                        _ => {
+                            ensure!(
+                                definition.as_externref().is_none(),
+                                "cannot snapshot a global holding a non-null `{}`",
+                                r
+                            );
+                            ConstOp::RefNull
+                        }
+                    },
+                }
+            };
+            globals.push(ConstExpr::new([op]));
+        }
+
+        let mut tables = PrimaryMap::new();
+        for (index, plan) in env_module.table_plans.iter() {
+            let Some(index) = env_module.defined_table_index(index) else {
+                continue;
+            };
+            let table = handle.get_defined_table(index);
+            let size = unsafe { (*table).size() };
+            let table = handle.get_defined_table_with_lazy_init(index, 0..size);
+            let mut elements = Vec::new();
+            for i in 0..size {
+                match unsafe { (*table).get(i).unwrap() } {
+                    TableElement::FuncRef(f) => elements.push(func_index(f)?),
+                    TableElement::ExternRef(None) => {}
+                    TableElement::ExternRef(Some(_)) => bail!(
+                        "cannot snapshot a table holding a non-null `{}`",
+                        plan.table.wasm_ty
+                    ),
+                    TableElement::UninitFunc => unreachable!(),
+                }
+            }
+            // Trailing nulls don't need to be recorded.
+            while elements.last().is_some_and(|f| f.is_reserved_value()) {
+                elements.pop();
+            }
+            tables.push(TableSnapshot { size, elements });
+        }
+
+        let dropped_data = env_module
+            .passive_data_map
+            .keys()
+            .copied()
+            .filter(|i| handle.is_data_dropped(*i))
+            .collect();
+        let dropped_elements = env_module
+            .passive_elements_map
+            .keys()
+            .copied()
+            .filter(|i| handle.is_elem_dropped(*i))
+            .collect();
+
+        Ok(SnapshotState {
+            memories,
+            globals,
+            tables,
+            dropped_data,
+            dropped_elements,
+        })
+    }
+
+    /// Checks that this state is valid for `module`, so that instantiating it
+    /// results in a valid instance.
+    fn validate(&self, module: &wasmtime_environ::Module) -> Result<()> {
+        let num_defined = |total: usize, imported: usize| total - imported;
+        ensure!(
+            self.memories.len()
+                == num_defined(module.memory_plans.len(), module.num_imported_memories)
+                && self.globals.len()
+                    == num_defined(module.globals.len(), module.num_imported_globals)
+                && self.tables.len()
+                    == num_defined(module.table_plans.len(), module.num_imported_tables),
+            "snapshot does not match the module's memories, globals and tables"
+        );
+
+        for (index, memory) in self.memories.iter() {
+            let plan = &module.memory_plans[module.memory_index(index)];
+            let ty = &plan.memory;
+            let max = ty
+                .maximum
+                .unwrap_or(if ty.memory64 { 1 << 48 } else { 1 << 16 });
+            ensure!(
+                ty.minimum <= memory.pages && memory.pages <= max,
+                "snapshot memory size is out of bounds for memory {}",
+                index.as_u32()
+            );
+            ensure!(
+                memory.offset.checked_add(memory.data.len() as u64)
+                    <= memory.pages.checked_mul(u64::from(WASM_PAGE_SIZE)),
+                "snapshot memory contents are out of bounds for memory {}",
+                index.as_u32()
+            );
+        }
+
+        let check_func = |f: FuncIndex, ty: &WasmRefType| -> Result<()> {
+            if f.is_reserved_value() {
+                ensure!(ty.nullable, "snapshot has a null value of type `{}`", ty);
+                return Ok(());
+            }
+            let func = module
+                .functions
+                .get(f)
+                .filter(|func| func.is_escaping())
+                .context("snapshot refers to an invalid function")?;
+            let ok = match ty.heap_type {
+                WasmHeapType::Func => true,
+                WasmHeapType::TypedFunc(signature) => func.signature == signature,
+                _ => false,
+            };
+            ensure!(ok, "snapshot has a function of the wrong type for `{}`", ty);
+            Ok(())
+        };
+
+        for (index, expr) in self.globals.iter() {
+            let ty = module.globals[module.global_index(index)].wasm_ty;
+            let ok = match (ty, expr.ops()) {
+                (WasmType::I32, [ConstOp::I32Const(_)])
+                | (WasmType::I64, [ConstOp::I64Const(_)])
+                | (WasmType::F32, [ConstOp::F32Const(_)])
+                | (WasmType::F64, [ConstOp::F64Const(_)])
+                | (WasmType::V128, [ConstOp::V128Const(_)]) => true,
+                (WasmType::Ref(r), [ConstOp::RefNull]) => r.nullable,
+                (WasmType::Ref(r), [ConstOp::RefFunc(f)]) => {
+                    check_func(*f, &r)?;
+                    true
+                }
+                (WasmType::Ref(r), [ConstOp::I32Const(_), ConstOp::RefI31]) => matches!(
+                    r.heap_type,
+                    WasmHeapType::Any | WasmHeapType::Eq | WasmHeapType::I31
+                ),
+                _ => false,
+            };
+            ensure!(
+                ok,
+                "snapshot has an invalid value for global {}",
+                index.as_u32()
+            );
+        }
+
+        for (index, table) in self.tables.iter() {
+            let ty = &module.table_plans[module.table_index(index)].table;
+            ensure!(
+                ty.minimum <= table.size && ty.maximum.map_or(true, |max| table.size <= max),
+                "snapshot table size is out of bounds for table {}",
+                index.as_u32()
+            );
+            if ty.wasm_ty.heap_type.top() == WasmHeapType::Func {
+                ensure!(
+                    table.elements.len() <= table.size as usize,
+                    "snapshot table contents are out of bounds for table {}",
+                    index.as_u32()
+                );
+                if table.elements.len() < table.size as usize {
+                    check_func(FuncIndex::reserved_value(), &ty.wasm_ty)?;
+                }
+                for f in table.elements.iter() {
+                    check_func(*f, &ty.wasm_ty)?;
+                }
+            } else {
+                ensure!(
+                    table.elements.is_empty() && (table.size == 0 || ty.wasm_ty.nullable),
+                    "snapshot has invalid contents for table {}",
+                    index.as_u32()
+                );
+            }
+        }
+
+        ensure!(
+            self.dropped_data
+                .iter()
+                .all(|i| module.passive_data_map.contains_key(i))
+                && self
+                    .dropped_elements
+                    .iter()
+                    .all(|i| module.passive_elements_map.contains_key(i)),
+            "snapshot refers to an invalid passive segment"
+        );
+        Ok(())
+    }
+}
+
+/// The runtime information for instances created from a snapshot.
+///
+/// This is the same as for the snapshot's module, except for the module's
+/// initializers and data, which describe the snapshot's state instead.
+struct SnapshotRuntimeInfo {
+    inner: Arc<dyn ModuleRuntimeInfo>,
+    module: Arc<wasmtime_environ::Module>,
+    wasm_data: Vec<u8>,
+    memory_images: Option<ModuleMemoryImages>,
+}
+
+impl ModuleRuntimeInfo for SnapshotRuntimeInfo {
+    fn module(&self) -> &Arc<wasmtime_environ::Module> {
+        &self.module
+    }
+
+    fn function(&self, index: DefinedFuncIndex) -> NonNull<VMWasmCallFunction> {
+        self.inner.function(index)
+    }
+
+    fn native_to_wasm_trampoline(
+        &self,
+        index: DefinedFuncIndex,
+    ) -> Option<NonNull<VMNativeCallFunction>> {
+        self.inner.native_to_wasm_trampoline(index)
+    }
+
+    fn array_to_wasm_trampoline(&self, index: DefinedFuncIndex) -> Option<VMArrayCallFunction> {
+        self.inner.array_to_wasm_trampoline(index)
+    }
+
+    fn wasm_to_native_trampoline(
+        &self,
+        signature: VMSharedSignatureIndex,
+    ) -> Option<NonNull<VMWasmCallFunction>> {
+        self.inner.wasm_to_native_trampoline(signature)
+    }
+
+    fn memory_image(&self, memory: DefinedMemoryIndex) -> Result<Option<&Arc<MemoryImage>>> {
+        Ok(self
+            .memory_images
+            .as_ref()
+            .and_then(|images| images.get_memory_image(memory)))
+    }
+
+    fn unique_id(&self) -> Option<CompiledModuleId> {
+        // The module's id identifies its own memory images, which differ from
+        // the snapshot's.
+        None
+    }
+
+    fn wasm_data(&self) -> &[u8] {
+        &self.wasm_data
+    }
+
+    fn signature_ids(&self) -> &[VMSharedSignatureIndex] {
+        self.inner.signature_ids()
+    }
+
+    fn function_type(&self, index: SignatureIndex) -> &WasmFuncType {
+        self.inner.function_type(index)
+    }
+
+    fn gc_type(&self, index: GcTypeIndex) -> &Arc<GcTypeInfo> {
+        self.inner.gc_type(index)
+    }
+
+    fn offsets(&self) -> &VMOffsets<HostPtr> {
+        self.inner.offsets()
+    }
+
+    fn coverage_counters(&self) -> *const *mut u64 {
+        self.inner.coverage_counters()
+    }
+}
@@ -76,13 +76,16 @@
 //! contents of `StoreOpaque`. This is an invariant that we, as the authors of
 //! `wasmtime`, must uphold for the public interface to be safe.
 
+use crate::guest_debug::Breakpoints;
 use crate::instance::InstanceData;
 use crate::linker::Definition;
 use crate::module::{BareModuleInfo, RegisteredModuleId};
-use crate::trampoline::VMHostGlobalContext;
-use crate::{module::ModuleRegistry, Engine, Module, Trap, Val, ValRaw};
+use crate::r#ref::GcRoot;
+use crate::record::{HostCallTrace, HostCallTracer};
+use crate::trampoline::{VMHostGlobalContext, VMHostTagContext};
+use crate::{module::ModuleRegistry, DebugFrame, Engine, Module, Trap, Val, ValRaw, ValType};
 use crate::{Global, Instance, Memory};
-use anyhow::{anyhow, bail, Result};
+use anyhow::{anyhow, bail, Error, Result};
 use std::cell::UnsafeCell;
 use std::fmt;
 use std::future::Future;
@@ -95,10 +98,11 @@ use std::ptr;
 use std::sync::atomic::AtomicU64;
 use std::sync::Arc;
 use std::task::{Context, Poll};
+use wasmtime_environ::WasmType;
 use wasmtime_runtime::{
-    mpk::ProtectionKey, ExportGlobal, InstanceAllocationRequest, InstanceAllocator, InstanceHandle,
-    ModuleInfo, OnDemandInstanceAllocator, SignalHandler, StoreBox, StorePtr, VMContext,
-    VMExternRef, VMExternRefActivationsTable, VMFuncRef, VMRuntimeLimits, WasmFault,
+    mpk::ProtectionKey, ExportGlobal, GcHeap, InstanceAllocationRequest, InstanceAllocator,
+    InstanceHandle, ModuleInfo, OnDemandInstanceAllocator, SignalHandler, StoreBox, StorePtr,
+    VMContext, VMExternRef, VMExternRefActivationsTable, VMFuncRef, VMRuntimeLimits, WasmFault,
 };
 
 mod context;
@@ -207,6 +211,7 @@ pub struct StoreInner<T> {
     call_hook: Option<CallHookInner<T>>,
     epoch_deadline_behavior:
         Option<Box<dyn FnMut(StoreContextMut<T>) -> Result<UpdateDeadline> + Send + Sync>>,
+    debug_hook: Option<Box<dyn FnMut(StoreContextMut<T>, &DebugFrame) -> Result<()> + Send + Sync>>,
     // for comments about `ManuallyDrop`, see `Store::into_data`
     data: ManuallyDrop<T>,
 }
@@ -295,9 +300,14 @@ pub struct StoreOpaque {
     num_component_instances: usize,
     signal_handler: Option<Box<SignalHandler<'static>>>,
     externref_activations_table: VMExternRefActivationsTable,
+    gc_heap: GcHeap,
+    /// The roots of the objects in `gc_heap` which are referenced by
+    /// [`StructRef`](crate::StructRef)s and [`ArrayRef`](crate::ArrayRef)s.
+    gc_roots: Vec<Arc<GcRoot>>,
     modules: ModuleRegistry,
     func_refs: FuncRefs,
     host_globals: Vec<StoreBox<VMHostGlobalContext>>,
+    host_tags: Vec<StoreBox<VMHostTagContext>>,
 
     // Numbers of resources instantiated in this store, and their limits
     instance_count: usize,
@@ -357,6 +367,18 @@ pub struct StoreOpaque {
     component_host_table: wasmtime_runtime::component::ResourceTable,
     #[cfg(feature = "component-model")]
     component_calls: wasmtime_runtime::component::CallContexts,
+
+    /// Recording or replaying of calls to host functions, see
+    /// `Store::record_host_calls` and `Store::replay_host_calls`.
+    host_call_tracer: HostCallTracer,
+
+    /// Finds the exit status in errors of recorded host calls, see
+    /// `Store::host_call_exit_code`.
+    host_call_exit_code: fn(&Error) -> Option<i32>,
+
+    /// The breakpoints which WebAssembly stops at, see
+    /// `Store::add_breakpoint`.
+    breakpoints: Breakpoints,
 }
 
 #[cfg(feature = "async")]
@@ -482,9 +504,12 @@ impl<T> Store<T> {
                 num_component_instances: 0,
                 signal_handler: None,
                 externref_activations_table: VMExternRefActivationsTable::new(),
+                gc_heap: GcHeap::default(),
+                gc_roots: Vec::new(),
                 modules: ModuleRegistry::default(),
                 func_refs: FuncRefs::default(),
                 host_globals: Vec::new(),
+                host_tags: Vec::new(),
                 instance_count: 0,
                 instance_limit: crate::DEFAULT_INSTANCE_LIMIT,
                 memory_count: 0,
@@ -508,10 +533,14 @@ impl<T> Store<T> {
                 component_host_table: Default::default(),
                 #[cfg(feature = "component-model")]
                 component_calls: Default::default(),
+                host_call_tracer: HostCallTracer::Off,
+                host_call_exit_code: |_| None,
+                breakpoints: Breakpoints::default(),
             },
             limiter: None,
             call_hook: None,
             epoch_deadline_behavior: None,
+            debug_hook: None,
             data: ManuallyDrop::new(data),
         });
 
@@ -771,11 +800,12 @@ impl<T> Store<T> {
         self.inner.engine()
     }
 
-    /// Perform garbage collection of `ExternRef`s.
+    /// Perform garbage collection of `ExternRef`s and of the `struct` and
+    /// `array` objects of the GC proposal.
     ///
     /// Note that it is not required to actively call this function. GC will
-    /// automatically happen when internal buffers fill up. This is provided if
-    /// fine-grained control over the GC is desired.
+    /// automatically happen when internal buffers or the GC heap fill up. This
+    /// is provided if fine-grained control over the GC is desired.
     pub fn gc(&mut self) {
         self.inner.gc()
     }
@@ -968,6 +998,111 @@ impl<T> Store<T> {
     pub fn epoch_deadline_async_yield_and_update(&mut self, delta: u64) {
         self.inner.epoch_deadline_async_yield_and_update(delta);
     }
+
+    /// Starts recording all calls from WebAssembly to host functions in this
+    /// store.
+    ///
+    /// Every subsequent call that WebAssembly makes to a host function is
+    /// logged along with its arguments, results and writes to linear memory
+    /// until [`Store::take_host_call_trace`] is called, which returns the
+    /// recorded [`HostCallTrace`]. Any trace that was previously being
+    /// recorded or replayed is discarded.
+    ///
+    /// See [`HostCallTrace`] for more information.
+    pub fn record_host_calls(&mut self) {
+        self.inner.record_host_calls();
+    }
+
+    /// Replays the calls to host functions recorded in `trace` instead of
+    /// calling the host.
+    ///
+    /// Every subsequent call that WebAssembly makes to a host function in this
+    /// store is checked against the next call in `trace` and, instead of
+    /// calling the host function, the recorded results and writes to linear
+    /// memory of that call are replayed. A call which doesn't match the next
+    /// recorded call, or which is made after all of `trace` has been
+    /// replayed, fails with an error. Any trace that was previously being
+    /// recorded or replayed is discarded.
+    ///
+    /// See [`HostCallTrace`] for more information.
+    pub fn replay_host_calls(&mut self, trace: HostCallTrace) {
+        self.inner.replay_host_calls(trace);
+    }
+
+    /// Stops recording or replaying calls to host functions, returning the
+    /// trace that was recorded since [`Store::record_host_calls`].
+    ///
+    /// Returns `None` if host calls weren't being recorded.
+    pub fn take_host_call_trace(&mut self) -> Option<HostCallTrace> {
+        self.inner.take_host_call_trace()
+    }
+
+    /// Configures how the exit status that an error returned by a host
+    /// function requests, for example through WASI's `proc_exit`, is found
+    /// while recording host calls.
+    ///
+    /// The exit status is saved in the trace along with the error's message,
+    /// and when the call is replayed it's available through
+    /// [`HostCallError::exit_code`](crate::HostCallError::exit_code). By default no exit status is recorded.
+    pub fn host_call_exit_code(&mut self, exit_code: fn(&Error) -> Option<i32>) {
+        self.inner.host_call_exit_code = exit_code;
+    }
+
+    /// Configures a callback which is invoked whenever WebAssembly in this
+    /// store stops before executing an instruction, either at a breakpoint or
+    /// because single-stepping is enabled.
+    ///
+    /// The callback receives the state of the stopped function, and may
+    /// inspect the store, for example its memories, and add or remove
+    /// breakpoints before execution resumes. If it returns an error then
+    /// that's raised as a trap in the stopped function.
+    ///
+    /// See [`Config::guest_debug`](crate::Config::guest_debug) for more
+    /// information.
+    pub fn debug_hook(
+        &mut self,
+        hook: impl FnMut(StoreContextMut<'_, T>, &DebugFrame) -> Result<()> + Send + Sync + 'static,
+    ) {
+        self.inner.debug_hook = Some(Box::new(hook));
+    }
+
+    /// Sets a breakpoint on the instruction at `offset` within the original
+    /// wasm binary of `module`, in the same format as
+    /// [`FrameInfo::module_offset`](crate::FrameInfo::module_offset).
+    ///
+    /// Whenever an instance of `module` in this store is about to execute
+    /// that instruction the callback configured with [`Store::debug_hook`] is
+    /// invoked. Offsets which aren't the start of an instruction in a
+    /// function body are never reached.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if guest debugging isn't enabled
+    /// with [`Config::guest_debug`](crate::Config::guest_debug) or if
+    /// `module` belongs to a different engine than this store.
+    pub fn add_breakpoint(&mut self, module: &Module, offset: usize) -> Result<()> {
+        self.inner.add_breakpoint(module, offset)
+    }
+
+    /// Removes the breakpoint which was set with [`Store::add_breakpoint`] on
+    /// the instruction at `offset` within `module`.
+    ///
+    /// Returns whether such a breakpoint was set.
+    pub fn remove_breakpoint(&mut self, module: &Module, offset: usize) -> bool {
+        self.inner.remove_breakpoint(module, offset)
+    }
+
+    /// Configures whether WebAssembly in this store stops before each
+    /// instruction it executes, invoking the callback configured with
+    /// [`Store::debug_hook`].
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if guest debugging isn't enabled
+    /// with [`Config::guest_debug`](crate::Config::guest_debug).
+    pub fn single_step(&mut self, enable: bool) -> Result<()> {
+        self.inner.single_step(enable)
+    }
 }
 
 impl<'a, T> StoreContext<'a, T> {
@@ -1015,7 +1150,7 @@ impl<'a, T> StoreContextMut<'a, T> {
         self.0.engine()
     }
 
-    /// Perform garbage collection of `ExternRef`s.
+    /// Perform garbage collection of `ExternRef`s and GC objects.
     ///
     /// Same as [`Store::gc`].
     pub fn gc(&mut self) {
@@ -1067,6 +1202,56 @@ impl<'a, T> StoreContextMut<'a, T> {
     pub fn epoch_deadline_async_yield_and_update(&mut self, delta: u64) {
         self.0.epoch_deadline_async_yield_and_update(delta);
     }
+
+    /// Starts recording all calls from WebAssembly to host functions.
+    ///
+    /// For more information see [`Store::record_host_calls`].
+    pub fn record_host_calls(&mut self) {
+        self.0.record_host_calls();
+    }
+
+    /// Replays the calls to host functions recorded in `trace`.
+    ///
+    /// For more information see [`Store::replay_host_calls`].
+    pub fn replay_host_calls(&mut self, trace: HostCallTrace) {
+        self.0.replay_host_calls(trace);
+    }
+
+    /// Stops recording or replaying calls to host functions.
+    ///
+    /// For more information see [`Store::take_host_call_trace`].
+    pub fn take_host_call_trace(&mut self) -> Option<HostCallTrace> {
+        self.0.take_host_call_trace()
+    }
+
+    /// Configures how the exit status is found in errors of recorded host
+    /// calls.
+    ///
+    /// For more information see [`Store::host_call_exit_code`].
+    pub fn host_call_exit_code(&mut self, exit_code: fn(&Error) -> Option<i32>) {
+        self.0.host_call_exit_code = exit_code;
+    }
+
+    /// Sets a breakpoint on the instruction at `offset` within `module`.
+    ///
+    /// For more information see [`Store::add_breakpoint`].
+    pub fn add_breakpoint(&mut self, module: &Module, offset: usize) -> Result<()> {
+        self.0.add_breakpoint(module, offset)
+    }
+
+    /// Removes the breakpoint on the instruction at `offset` within `module`.
+    ///
+    /// For more information see [`Store::remove_breakpoint`].
+    pub fn remove_breakpoint(&mut self, module: &Module, offset: usize) -> bool {
+        self.0.remove_breakpoint(module, offset)
+    }
+
+    /// Configures whether WebAssembly stops before each instruction.
+    ///
+    /// For more information see [`Store::single_step`].
+    pub fn single_step(&mut self, enable: bool) -> Result<()> {
+        self.0.single_step(enable)
+    }
 }
 
 impl<T> StoreInner<T> {
@@ -1229,6 +1414,10 @@ impl StoreOpaque {
         &mut self.host_globals
     }
 
+    pub(crate) fn host_tags(&mut self) -> &mut Vec<StoreBox<VMHostTagContext>> {
+        &mut self.host_tags
+    }
+
     pub fn module_for_instance(&self, instance: InstanceId) -> Option<&'_ Module> {
         match self.instances[instance.0].kind {
             StoreInstanceKind::Dummy => None,
@@ -1361,6 +1550,59 @@ impl StoreOpaque {
                 &mut self.externref_activations_table,
             )
         }
+        self.collect_gc_heap();
+    }
+
+    /// Frees the objects in the GC heap which are no longer referenced by Wasm
+    /// frames, globals, or the host.
+    fn collect_gc_heap(&mut self) {
+        if self.gc_heap.capacity() == 0 {
+            return;
+        }
+
+        let mut roots = Vec::new();
+        unsafe {
+            wasmtime_runtime::anyref_stack_roots(self.runtime_limits(), &self.modules, &mut roots);
+        }
+        for instance in self.instances.iter_mut() {
+            for (_, global) in instance.handle.defined_globals() {
+                if let WasmType::Ref(r) = global.global.wasm_ty {
+                    if r.heap_type.is_anyref() {
+                        roots.push(unsafe { *(*global.definition).as_u32() });
+                    }
+                }
+            }
+        }
+        for global in self.host_globals.iter() {
+            unsafe {
+                if (*global.get()).ty.content() == &ValType::AnyRef {
+                    roots.push(*(*global.get()).global.as_u32());
+                }
+            }
+        }
+        // Roots which aren't referenced by anything else anymore are dropped.
+        self.gc_roots.retain(|root| Arc::strong_count(root) > 1);
+        roots.extend(self.gc_roots.iter().map(|root| root.0));
+
+        self.gc_heap.collect(roots);
+    }
+
+    #[inline]
+    pub(crate) fn gc_heap(&self) -> &GcHeap {
+        &self.gc_heap
+    }
+
+    #[inline]
+    pub(crate) fn gc_heap_mut(&mut self) -> &mut GcHeap {
+        &mut self.gc_heap
+    }
+
+    /// Roots the object `r` in the GC heap for as long as the returned root is
+    /// referenced.
+    pub(crate) fn root_gc_object(&mut self, r: u32) -> Arc<GcRoot> {
+        let root = Arc::new(GcRoot(r));
+        self.gc_roots.push(root.clone());
+        root
     }
 
     /// Yields the async context, assuming that we are executing on a fiber and
@@ -1624,6 +1866,68 @@ at https://bytecodealliance.org/security.
         (&mut self.component_calls, &mut self.component_host_table)
     }
 
+    pub(crate) fn record_host_calls(&mut self) {
+        self.host_call_tracer = HostCallTracer::record();
+    }
+
+    pub(crate) fn replay_host_calls(&mut self, trace: HostCallTrace) {
+        self.host_call_tracer = HostCallTracer::replay(trace);
+    }
+
+    pub(crate) fn take_host_call_trace(&mut self) -> Option<HostCallTrace> {
+        self.host_call_tracer.take_trace()
+    }
+
+    #[inline]
+    pub(crate) fn host_call_tracer(&mut self) -> &mut HostCallTracer {
+        &mut self.host_call_tracer
+    }
+
+    pub(crate) fn host_call_exit_code(&self) -> fn(&Error) -> Option<i32> {
+        self.host_call_exit_code
+    }
+
+    pub(crate) fn breakpoints(&self) -> &Breakpoints {
+        &self.breakpoints
+    }
+
+    fn update_breakpoints<R>(&mut self, f: impl FnOnce(&mut Breakpoints) -> R) -> R {
+        let ret = f(&mut self.breakpoints);
+        *self.runtime_limits.debug_break.get_mut() = u8::from(self.breakpoints.is_active());
+        ret
+    }
+
+    fn breakpoint_offset(&self, module: &Module, offset: usize) -> Result<u32> {
+        if !self.engine().config().tunables.guest_debug {
+            bail!("guest debugging is not enabled in this engine's configuration");
+        }
+        if !Engine::same(self.engine(), module.engine()) {
+            bail!("cross-`Engine` breakpoints are not supported");
+        }
+        Ok(u32::try_from(offset)?)
+    }
+
+    pub(crate) fn add_breakpoint(&mut self, module: &Module, offset: usize) -> Result<()> {
+        let offset = self.breakpoint_offset(module, offset)?;
+        self.update_breakpoints(|b| b.add(module, offset));
+        Ok(())
+    }
+
+    pub(crate) fn remove_breakpoint(&mut self, module: &Module, offset: usize) -> bool {
+        match u32::try_from(offset) {
+            Ok(offset) => self.update_breakpoints(|b| b.remove(module, offset)),
+            Err(_) => false,
+        }
+    }
+
+    pub(crate) fn single_step(&mut self, enable: bool) -> Result<()> {
+        if !self.engine().config().tunables.guest_debug {
+            bail!("guest debugging is not enabled in this engine's configuration");
+        }
+        self.update_breakpoints(|b| b.set_single_step(enable));
+        Ok(())
+    }
+
     #[cfg(feature = "component-model")]
     pub(crate) fn push_component_instance(&mut self, instance: crate::component::Instance) {
         // We don't actually need the instance itself right now, but it seems
@@ -2001,6 +2305,14 @@ unsafe impl<T> wasmtime_runtime::Store for StoreInner<T> {
         (&mut inner.externref_activations_table, &inner.modules)
     }
 
+    fn gc_heap(&mut self) -> &mut GcHeap {
+        &mut self.inner.gc_heap
+    }
+
+    fn gc(&mut self) {
+        self.inner.gc();
+    }
+
     fn memory_growing(
         &mut self,
         current: usize,
@@ -2042,6 +2354,10 @@ unsafe impl<T> wasmtime_runtime::Store for StoreInner<T> {
         }
     }
 
+    fn deterministic(&self) -> bool {
+        self.engine().is_deterministic()
+    }
+
     fn table_growing(
         &mut self,
         current: u32,
@@ -2138,6 +2454,24 @@ unsafe impl<T> wasmtime_runtime::Store for StoreInner<T> {
         delta_result
     }
 
+    fn debug_break(&mut self, frame: wasmtime_runtime::DebugBreak) -> Result<()> {
+        let frame = match unsafe { DebugFrame::new(&mut *self, &frame) } {
+            Some(frame) => frame,
+            None => return Ok(()),
+        };
+        // Temporarily take the hook to avoid mutably borrowing the store
+        // twice, unless the hook replaced itself in the meantime.
+        let mut hook = self.debug_hook.take();
+        let result = match &mut hook {
+            Some(hook) => hook((&mut *self).as_context_mut(), &frame),
+            None => Ok(()),
+        };
+        if self.debug_hook.is_none() {
+            self.debug_hook = hook;
+        }
+        result
+    }
+
     #[cfg(feature = "component-model")]
     fn component_calls(&mut self) -> &mut wasmtime_runtime::component::CallContexts {
         &mut self.component_calls
@@ -2235,6 +2569,13 @@ impl Drop for StoreOpaque {
             }
             ondemand.deallocate_module(&mut self.default_caller);
 
+            // An exception can only be pending while wasm is running, but make
+            // sure it isn't leaked if the store is dropped in the middle of a
+            // call.
+            drop(wasmtime_runtime::take_pending_exception(
+                &self.runtime_limits,
+            ));
+
             #[cfg(feature = "component-model")]
             {
                 for _ in 0..self.num_component_instances {
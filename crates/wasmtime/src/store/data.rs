@@ -26,6 +26,7 @@ pub struct StoreData {
     globals: Vec<wasmtime_runtime::ExportGlobal>,
     instances: Vec<crate::instance::InstanceData>,
     memories: Vec<wasmtime_runtime::ExportMemory>,
+    tags: Vec<wasmtime_runtime::ExportTag>,
     #[cfg(feature = "component-model")]
     pub(crate) components: crate::component::ComponentStoreData,
 }
@@ -52,6 +53,7 @@ impl_store_data! {
     globals => wasmtime_runtime::ExportGlobal,
     instances => crate::instance::InstanceData,
     memories => wasmtime_runtime::ExportMemory,
+    tags => wasmtime_runtime::ExportTag,
 }
 
 impl StoreData {
@@ -63,6 +65,7 @@ impl StoreData {
             globals: Vec::new(),
             instances: Vec::new(),
             memories: Vec::new(),
+            tags: Vec::new(),
             #[cfg(feature = "component-model")]
             components: Default::default(),
         }
@@ -201,7 +204,7 @@ where
 /// owned by a `Store` and will embed a `StoreId` internally to say which store
 /// it came from. Comparisons with this value are how panics are generated for
 /// mismatching the item that a store belongs to.
-#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
 pub struct StoreId(NonZeroU64);
 
 impl StoreId {
@@ -17,10 +17,11 @@ impl Drop for VMHostGlobalContext {
             | crate::ValType::F32
             | crate::ValType::F64
             | crate::ValType::V128
-            | crate::ValType::FuncRef => {
+            | crate::ValType::FuncRef
+            | crate::ValType::AnyRef => {
                 // Nothing to drop.
             }
-            crate::ValType::ExternRef => unsafe {
+            crate::ValType::ExternRef | crate::ValType::ExnRef => unsafe {
                 ptr::drop_in_place(self.global.as_externref_mut())
             },
         }
@@ -57,6 +58,8 @@ pub fn generate_global_export(
                     f.map_or(ptr::null_mut(), |f| f.vm_func_ref(store).as_ptr())
             }
             Val::ExternRef(x) => *global.as_externref_mut() = x.map(|x| x.inner),
+            Val::AnyRef(x) => *global.as_u32_mut() = x.map_or(0, |x| x._to_raw(store)),
+            Val::ExnRef(x) => *global.as_externref_mut() = x.map(|x| x.inner),
         }
         global
     };
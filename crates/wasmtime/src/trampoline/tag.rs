@@ -0,0 +1,33 @@
+use crate::store::StoreOpaque;
+use crate::{Engine, TagType};
+use wasmtime_runtime::{StoreBox, VMTagDefinition};
+
+pub struct VMHostTagContext {
+    pub(crate) definition: VMTagDefinition,
+
+    // Stored to unregister this tag's signature with the engine when this is
+    // dropped.
+    engine: Engine,
+}
+
+impl Drop for VMHostTagContext {
+    fn drop(&mut self) {
+        unsafe {
+            self.engine
+                .signatures()
+                .unregister(self.definition.type_index);
+        }
+    }
+}
+
+pub fn generate_tag_export(store: &mut StoreOpaque, ty: &TagType) -> wasmtime_runtime::ExportTag {
+    let engine = store.engine().clone();
+    let type_index = engine.signatures().register(ty.as_wasm_func_type());
+    let ctx = StoreBox::new(VMHostTagContext {
+        definition: VMTagDefinition::new(type_index),
+        engine,
+    });
+    let definition = unsafe { &mut (*ctx.get()).definition as *mut _ };
+    store.host_tags().push(ctx);
+    wasmtime_runtime::ExportTag { definition }
+}
@@ -483,25 +483,7 @@ impl FrameInfo {
         #[cfg(feature = "addr2line")]
         if let Some(s) = &compiled_module.symbolize_context().ok().and_then(|c| c) {
             if let Some(offset) = instr.and_then(|i| i.file_offset()) {
-                let to_lookup = u64::from(offset) - s.code_section_offset();
-                if let Ok(mut frames) = s.addr2line().find_frames(to_lookup).skip_all_loads() {
-                    while let Ok(Some(frame)) = frames.next() {
-                        symbols.push(FrameSymbol {
-                            name: frame
-                                .function
-                                .as_ref()
-                                .and_then(|l| l.raw_name().ok())
-                                .map(|s| s.to_string()),
-                            file: frame
-                                .location
-                                .as_ref()
-                                .and_then(|l| l.file)
-                                .map(|s| s.to_string()),
-                            line: frame.location.as_ref().and_then(|l| l.line),
-                            column: frame.location.as_ref().and_then(|l| l.column),
-                        });
-                    }
-                }
+                symbols = FrameSymbol::lookup(s, offset);
             }
         }
 
@@ -606,6 +588,36 @@ pub struct FrameSymbol {
 }
 
 impl FrameSymbol {
+    /// Returns the symbols of the instruction at `offset` in the original wasm
+    /// module, innermost inlined function first.
+    #[cfg(feature = "addr2line")]
+    pub(crate) fn lookup(
+        cx: &crate::instantiate::SymbolizeContext<'_>,
+        offset: u32,
+    ) -> Vec<FrameSymbol> {
+        let mut symbols = Vec::new();
+        let to_lookup = u64::from(offset) - cx.code_section_offset();
+        if let Ok(mut frames) = cx.addr2line().find_frames(to_lookup).skip_all_loads() {
+            while let Ok(Some(frame)) = frames.next() {
+                symbols.push(FrameSymbol {
+                    name: frame
+                        .function
+                        .as_ref()
+                        .and_then(|l| l.raw_name().ok())
+                        .map(|s| s.to_string()),
+                    file: frame
+                        .location
+                        .as_ref()
+                        .and_then(|l| l.file)
+                        .map(|s| s.to_string()),
+                    line: frame.location.as_ref().and_then(|l| l.line),
+                    column: frame.location.as_ref().and_then(|l| l.column),
+                });
+            }
+        }
+        symbols
+    }
+
     /// Returns the function name associated with this symbol.
     ///
     /// Note that this may not be present with malformed debug information, or
@@ -39,6 +39,12 @@ pub enum ValType {
     FuncRef,
     /// A reference to opaque data in the Wasm instance.
     ExternRef,
+    /// A reference in the `any` hierarchy of the GC proposal; see
+    /// [`AnyRef`](crate::AnyRef).
+    AnyRef,
+    /// A reference to an exception from the exception handling proposal; see
+    /// [`ExnRef`](crate::ExnRef).
+    ExnRef,
 }
 
 impl fmt::Display for ValType {
@@ -51,6 +57,8 @@ impl fmt::Display for ValType {
             ValType::V128 => write!(f, "v128"),
             ValType::ExternRef => write!(f, "externref"),
             ValType::FuncRef => write!(f, "funcref"),
+            ValType::AnyRef => write!(f, "anyref"),
+            ValType::ExnRef => write!(f, "exnref"),
         }
     }
 }
@@ -68,7 +76,7 @@ impl ValType {
     /// Returns true if `ValType` matches either of the reference types.
     pub fn is_ref(&self) -> bool {
         match self {
-            ValType::ExternRef | ValType::FuncRef => true,
+            ValType::ExternRef | ValType::FuncRef | ValType::AnyRef | ValType::ExnRef => true,
             _ => false,
         }
     }
@@ -82,6 +90,8 @@ impl ValType {
             Self::V128 => WasmType::V128,
             Self::FuncRef => WasmType::Ref(WasmRefType::FUNCREF),
             Self::ExternRef => WasmType::Ref(WasmRefType::EXTERNREF),
+            Self::AnyRef => WasmType::Ref(WasmRefType::ANYREF),
+            Self::ExnRef => WasmType::Ref(WasmRefType::EXNREF),
         }
     }
 
@@ -94,6 +104,12 @@ impl ValType {
             WasmType::V128 => Self::V128,
             WasmType::Ref(WasmRefType::FUNCREF) => Self::FuncRef,
             WasmType::Ref(WasmRefType::EXTERNREF) => Self::ExternRef,
+            WasmType::Ref(WasmRefType::ANYREF) => Self::AnyRef,
+            WasmType::Ref(WasmRefType::EXNREF) => Self::ExnRef,
+            // All of the types in the `any` hierarchy are exposed as `anyref`s,
+            // whose values are checked dynamically where they're passed to
+            // Wasm.
+            WasmType::Ref(r) if r.heap_type.is_anyref() => Self::AnyRef,
             // FIXME: exposing the full function-references (and beyond)
             // proposals will require redesigning the embedder API for `ValType`
             // and types in Wasmtime. That is a large undertaking which is
@@ -124,6 +140,8 @@ pub enum ExternType {
     Table(TableType),
     /// This external type is the type of a WebAssembly memory.
     Memory(MemoryType),
+    /// This external type is the type of a WebAssembly exception tag.
+    Tag(TagType),
 }
 
 macro_rules! accessors {
@@ -156,6 +174,7 @@ impl ExternType {
         (Global(GlobalType) global unwrap_global)
         (Table(TableType) table unwrap_table)
         (Memory(MemoryType) memory unwrap_memory)
+        (Tag(TagType) tag unwrap_tag)
     }
 
     pub(crate) fn from_wasmtime(types: &ModuleTypes, ty: &EntityType) -> ExternType {
@@ -164,7 +183,7 @@ impl ExternType {
             EntityType::Global(ty) => GlobalType::from_wasmtime_global(ty).into(),
             EntityType::Memory(ty) => MemoryType::from_wasmtime_memory(ty).into(),
             EntityType::Table(ty) => TableType::from_wasmtime_table(ty).into(),
-            EntityType::Tag(_) => unimplemented!("wasm tag support"),
+            EntityType::Tag(ty) => TagType::from_wasm_func_type(types[ty.signature].clone()).into(),
         }
     }
 }
@@ -193,6 +212,12 @@ impl From<TableType> for ExternType {
     }
 }
 
+impl From<TagType> for ExternType {
+    fn from(ty: TagType) -> ExternType {
+        ExternType::Tag(ty)
+    }
+}
+
 /// A descriptor for a function in a WebAssembly module.
 ///
 /// WebAssembly functions can have 0 or more parameters and results.
@@ -239,6 +264,44 @@ impl FuncType {
     }
 }
 
+// Tag Types
+
+/// A descriptor for an exception tag in a WebAssembly module.
+///
+/// A tag's type is a function type without results, whose parameters are the
+/// types of the payload values of exceptions thrown with the tag.
+#[derive(Debug, Clone, Hash, Eq, PartialEq)]
+pub struct TagType {
+    ty: FuncType,
+}
+
+impl TagType {
+    /// Creates a new tag descriptor whose exceptions carry values of the types
+    /// `params`.
+    pub fn new(params: impl IntoIterator<Item = ValType>) -> TagType {
+        TagType {
+            ty: FuncType::new(params, []),
+        }
+    }
+
+    /// Returns the types of the payload values of this tag's exceptions.
+    #[inline]
+    pub fn params(&self) -> impl ExactSizeIterator<Item = ValType> + '_ {
+        self.ty.params()
+    }
+
+    pub(crate) fn as_wasm_func_type(&self) -> &WasmFuncType {
+        self.ty.as_wasm_func_type()
+    }
+
+    pub(crate) fn from_wasm_func_type(sig: WasmFuncType) -> TagType {
+        debug_assert!(sig.returns().is_empty());
+        TagType {
+            ty: FuncType::from_wasm_func_type(sig),
+        }
+    }
+}
+
 // Global Types
 
 /// A WebAssembly global descriptor.
@@ -321,6 +384,7 @@ impl TableType {
                 wasm_ty: match element {
                     ValType::FuncRef => WasmRefType::FUNCREF,
                     ValType::ExternRef => WasmRefType::EXTERNREF,
+                    ValType::AnyRef => WasmRefType::ANYREF,
                     _ => panic!("Attempt to convert non-reference type to a reference type"),
                 },
                 minimum: min,
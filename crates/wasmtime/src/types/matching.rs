@@ -1,9 +1,9 @@
 use crate::linker::DefinitionType;
 use crate::{signatures::SignatureCollection, Engine};
-use anyhow::{anyhow, bail, Result};
+use anyhow::{anyhow, bail, Context, Result};
 use wasmtime_environ::{
-    EntityType, Global, Memory, ModuleTypes, SignatureIndex, Table, WasmFuncType, WasmHeapType,
-    WasmRefType, WasmType,
+    EntityType, GcTypeIndex, Global, Memory, ModuleTypes, SignatureIndex, Table, WasmFuncType,
+    WasmHeapType, WasmRefType, WasmType,
 };
 use wasmtime_runtime::VMSharedSignatureIndex;
 
@@ -41,11 +41,27 @@ impl MatchCx<'_> {
         Err(func_ty_mismatch(msg, expected, &actual))
     }
 
+    /// Returns the global type `ty` of this module with references to
+    /// `struct` and `array` types rewritten to use their shared indices, like
+    /// the types of the globals of instances.
+    fn shared_global(&self, ty: &Global) -> Global {
+        let mut ty = *ty;
+        if let WasmType::Ref(r) = &mut ty.wasm_ty {
+            let shared = |i| GcTypeIndex::from_u32(self.signatures.gc_type(i).index.bits());
+            r.heap_type = match r.heap_type {
+                WasmHeapType::TypedStruct(i) => WasmHeapType::TypedStruct(shared(i)),
+                WasmHeapType::TypedArray(i) => WasmHeapType::TypedArray(shared(i)),
+                other => other,
+            };
+        }
+        ty
+    }
+
     /// Validates that the `expected` type matches the type of `actual`
     pub(crate) fn definition(&self, expected: &EntityType, actual: &DefinitionType) -> Result<()> {
         match expected {
             EntityType::Global(expected) => match actual {
-                DefinitionType::Global(actual) => global_ty(expected, actual),
+                DefinitionType::Global(actual) => global_ty(&self.shared_global(expected), actual),
                 _ => bail!("expected global, but found {}", actual.desc()),
             },
             EntityType::Table(expected) => match actual {
@@ -64,7 +80,12 @@ impl MatchCx<'_> {
                 DefinitionType::Func(actual) => self.vmshared_signature_index(*expected, *actual),
                 _ => bail!("expected func, but found {}", actual.desc()),
             },
-            EntityType::Tag(_) => unimplemented!(),
+            EntityType::Tag(expected) => match actual {
+                DefinitionType::Tag(actual) => self
+                    .vmshared_signature_index(expected.signature, *actual)
+                    .context("tag types incompatible"),
+                _ => bail!("expected tag, but found {}", actual.desc()),
+            },
         }
     }
 }
@@ -105,7 +126,18 @@ pub fn entity_ty(
             }
             _ => bail!("expected func found {}", entity_desc(actual)),
         },
-        EntityType::Tag(_) => unimplemented!(),
+        EntityType::Tag(expected) => match actual {
+            EntityType::Tag(actual) => {
+                let expected = &expected_types[expected.signature];
+                let actual = &actual_types[actual.signature];
+                if expected == actual {
+                    Ok(())
+                } else {
+                    Err(func_ty_mismatch("tag types incompatible", expected, actual))
+                }
+            }
+            _ => bail!("expected tag found {}", entity_desc(actual)),
+        },
     }
 }
 
@@ -199,11 +231,21 @@ fn match_heap(expected: WasmHeapType, actual: WasmHeapType, desc: &str) -> Resul
             actual == expected
         }
         (WasmHeapType::TypedFunc(_), WasmHeapType::Func)
-        | (WasmHeapType::Func, WasmHeapType::Func)
-        | (WasmHeapType::Extern, WasmHeapType::Extern) => true,
-        (WasmHeapType::Func, _) | (WasmHeapType::Extern, _) | (WasmHeapType::TypedFunc(_), _) => {
-            false
-        }
+        | (WasmHeapType::TypedStruct(_), WasmHeapType::Struct)
+        | (WasmHeapType::TypedArray(_), WasmHeapType::Array)
+        | (
+            WasmHeapType::I31
+            | WasmHeapType::Struct
+            | WasmHeapType::Array
+            | WasmHeapType::TypedStruct(_)
+            | WasmHeapType::TypedArray(_),
+            WasmHeapType::Eq | WasmHeapType::Any,
+        )
+        | (WasmHeapType::Eq, WasmHeapType::Any) => true,
+        // The bottom type of each hierarchy is a subtype of everything else
+        // in that hierarchy.
+        (actual, expected) if actual == expected.bottom() => true,
+        (actual, expected) => actual == expected,
     };
     if result {
         Ok(())
@@ -1,4 +1,4 @@
-use crate::r#ref::ExternRef;
+use crate::r#ref::{AnyRef, ExnRef, ExternRef};
 use crate::store::StoreOpaque;
 use crate::{AsContextMut, Func, ValType, V128};
 use anyhow::{bail, Result};
@@ -46,6 +46,18 @@ pub enum Val {
     /// `ExternRef(None)` is the null external reference, created by `ref.null
     /// extern` in Wasm.
     ExternRef(Option<ExternRef>),
+
+    /// An `anyref` value from the GC proposal.
+    ///
+    /// `AnyRef(None)` is the null `anyref`, created by `ref.null any` in
+    /// Wasm.
+    AnyRef(Option<AnyRef>),
+
+    /// An `exnref` value from the exception handling proposal.
+    ///
+    /// `ExnRef(None)` is the null exception reference, created by `ref.null
+    /// exn` in Wasm.
+    ExnRef(Option<ExnRef>),
 }
 
 macro_rules! accessors {
@@ -92,6 +104,8 @@ impl Val {
             Val::ExternRef(_) => ValType::ExternRef,
             Val::FuncRef(_) => ValType::FuncRef,
             Val::V128(_) => ValType::V128,
+            Val::AnyRef(_) => ValType::AnyRef,
+            Val::ExnRef(_) => ValType::ExnRef,
         }
     }
 
@@ -122,6 +136,17 @@ impl Val {
                 };
                 ValRaw::funcref(funcref)
             }
+            Val::AnyRef(a) => ValRaw::anyref(match a {
+                Some(a) => a.to_raw(store),
+                None => 0,
+            }),
+            Val::ExnRef(e) => {
+                let exnref = match e {
+                    Some(e) => e.to_raw(store),
+                    None => ptr::null_mut(),
+                };
+                ValRaw::externref(exnref)
+            }
         }
     }
 
@@ -141,6 +166,8 @@ impl Val {
             ValType::V128 => Val::V128(raw.get_v128().into()),
             ValType::ExternRef => Val::ExternRef(ExternRef::from_raw(raw.get_externref())),
             ValType::FuncRef => Val::FuncRef(Func::from_raw(store, raw.get_funcref())),
+            ValType::AnyRef => Val::AnyRef(AnyRef::from_raw(store, raw.get_anyref())),
+            ValType::ExnRef => Val::ExnRef(ExnRef::from_raw(store, raw.get_externref())),
         }
     }
 
@@ -151,6 +178,7 @@ impl Val {
         (F32(f32) f32 unwrap_f32 f32::from_bits(*e))
         (F64(f64) f64 unwrap_f64 f64::from_bits(*e))
         (FuncRef(Option<&Func>) funcref unwrap_funcref e.as_ref())
+        (AnyRef(Option<AnyRef>) anyref unwrap_anyref e.clone())
         (V128(V128) v128 unwrap_v128 *e)
     }
 
@@ -184,6 +212,36 @@ impl Val {
         self.externref().expect("expected externref")
     }
 
+    /// Attempt to access the underlying `exnref` value of this `Val`.
+    ///
+    /// If this is not an `exnref`, then `None` is returned.
+    ///
+    /// If this is a null `exnref`, then `Some(None)` is returned.
+    ///
+    /// If this is a non-null `exnref`, then `Some(Some(..))` is returned.
+    #[inline]
+    pub fn exnref(&self) -> Option<Option<ExnRef>> {
+        match self {
+            Val::ExnRef(e) => Some(e.clone()),
+            _ => None,
+        }
+    }
+
+    /// Returns the underlying `exnref` value of this `Val`, panicking if it's
+    /// the wrong type.
+    ///
+    /// If this is a null `exnref`, then `None` is returned.
+    ///
+    /// If this is a non-null `exnref`, then `Some(..)` is returned.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is not a (nullable) `exnref`.
+    #[inline]
+    pub fn unwrap_exnref(&self) -> Option<ExnRef> {
+        self.exnref().expect("expected exnref")
+    }
+
     pub(crate) fn into_table_element(
         self,
         store: &mut StoreOpaque,
@@ -210,6 +268,8 @@ impl Val {
         match self {
             Val::FuncRef(Some(f)) => f.comes_from_same_store(store),
             Val::FuncRef(None) => true,
+            Val::ExnRef(Some(e)) => e.store_id() == store.id(),
+            Val::AnyRef(Some(a)) => a.comes_from_same_store(store),
 
             // Integers, floats, vectors, and `externref`s have no association
             // with any particular store, so they're always considered as "yes I
@@ -219,7 +279,9 @@ impl Val {
             | Val::F32(_)
             | Val::F64(_)
             | Val::V128(_)
-            | Val::ExternRef(_) => true,
+            | Val::ExternRef(_)
+            | Val::AnyRef(None)
+            | Val::ExnRef(None) => true,
         }
     }
 }
@@ -280,6 +342,20 @@ impl From<Func> for Val {
     }
 }
 
+impl From<AnyRef> for Val {
+    #[inline]
+    fn from(val: AnyRef) -> Val {
+        Val::AnyRef(Some(val))
+    }
+}
+
+impl From<Option<AnyRef>> for Val {
+    #[inline]
+    fn from(val: Option<AnyRef>) -> Val {
+        Val::AnyRef(val)
+    }
+}
+
 impl From<u128> for Val {
     #[inline]
     fn from(val: u128) -> Val {
@@ -16,6 +16,15 @@ pub fn val(v: &WastArgCore<'_>) -> Result<Val> {
         V128(x) => Val::V128(u128::from_le_bytes(x.to_le_bytes()).into()),
         RefNull(HeapType::Extern) => Val::ExternRef(None),
         RefNull(HeapType::Func) => Val::FuncRef(None),
+        RefNull(
+            HeapType::Any
+            | HeapType::Eq
+            | HeapType::I31
+            | HeapType::Struct
+            | HeapType::Array
+            | HeapType::None,
+        ) => Val::AnyRef(None),
+        RefNull(HeapType::Exn) => Val::ExnRef(None),
         RefExtern(x) => Val::ExternRef(Some(ExternRef::new(*x))),
         other => bail!("couldn't convert {:?} to a runtime value", other),
     })
@@ -93,6 +102,39 @@ pub fn match_val(actual: &Val, expected: &WastRetCore) -> Result<()> {
                 Ok(())
             }
         }
+        (Val::AnyRef(actual), WastRetCore::RefNull(expected)) => match (actual, expected) {
+            (None, None) => Ok(()),
+            (
+                None,
+                Some(
+                    HeapType::Any
+                    | HeapType::Eq
+                    | HeapType::I31
+                    | HeapType::Struct
+                    | HeapType::Array
+                    | HeapType::None,
+                ),
+            ) => Ok(()),
+            (None, Some(_)) => bail!("expected null non-anyref, found null anyref"),
+            (Some(_), _) => bail!("expected null anyref, found non-null"),
+        },
+        (Val::AnyRef(None), WastRetCore::RefAny | WastRetCore::RefEq | WastRetCore::RefI31) => {
+            bail!("expected non-null anyref, found null")
+        }
+        (Val::AnyRef(Some(_)), WastRetCore::RefAny | WastRetCore::RefEq) => Ok(()),
+        (Val::ExnRef(actual), WastRetCore::RefNull(expected)) => match (actual, expected) {
+            (None, None) => Ok(()),
+            (None, Some(HeapType::Exn)) => Ok(()),
+            (None, Some(_)) => bail!("expected null non-exnref, found null exnref"),
+            (Some(_), _) => bail!("expected null exnref, found non-null"),
+        },
+        (Val::AnyRef(Some(x)), WastRetCore::RefI31) => {
+            if x.is_i31() {
+                Ok(())
+            } else {
+                bail!("expected i31ref, found {:?}", x)
+            }
+        }
         _ => bail!(
             "don't know how to compare {:?} and {:?} yet",
             actual,
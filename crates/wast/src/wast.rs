@@ -351,6 +351,14 @@ where
         bail!("expected '{}', got '{}'", expected, actual)
     }
 
+    fn assert_exception(&self, result: Outcome) -> Result<()> {
+        match result {
+            Outcome::Ok(values) => bail!("expected exception, got {:?}", values),
+            Outcome::Trap(e) if e.is::<ThrownException>() => Ok(()),
+            Outcome::Trap(e) => bail!("expected exception, got '{e:?}'"),
+        }
+    }
+
     /// Run a wast script from a byte buffer.
     pub fn run_buffer(&mut self, filename: &str, wast: &[u8]) -> Result<()> {
         let wast = str::from_utf8(wast)?;
@@ -497,7 +505,10 @@ where
                     )
                 }
             }
-            AssertException { .. } => bail!("unimplemented assert_exception"),
+            AssertException { span: _, exec } => {
+                let result = self.perform_execute(exec)?;
+                self.assert_exception(result)?;
+            }
 
             Thread(thread) => {
                 let mut core_linker = Linker::new(self.store.engine());
@@ -1,5 +1,5 @@
 use anyhow::Result;
-use cranelift_codegen::isa::unwind::UnwindInfoKind;
+use cranelift_codegen::{isa::unwind::UnwindInfoKind, MachStackMap};
 use object::write::{Object, SymbolId};
 use std::any::Any;
 use std::mem;
@@ -8,8 +8,8 @@ use wasmparser::FuncValidatorAllocations;
 use wasmtime_cranelift_shared::{CompiledFunction, ModuleTextBuilder};
 use wasmtime_environ::{
     CompileError, DefinedFuncIndex, FilePos, FuncIndex, FunctionBodyData, FunctionLoc,
-    ModuleTranslation, ModuleTypesBuilder, PrimaryMap, TrapEncodingBuilder, VMOffsets,
-    WasmFunctionInfo,
+    ModuleTranslation, ModuleTypesBuilder, PrimaryMap, StackMapInformation, TrapEncodingBuilder,
+    Tunables, VMOffsets, WasmFunctionInfo,
 };
 use winch_codegen::{BuiltinFunctions, TargetIsa, TrampolineKind};
 
@@ -25,6 +25,7 @@ struct CompilationContext {
 
 pub(crate) struct Compiler {
     isa: Box<dyn TargetIsa>,
+    tunables: Tunables,
     contexts: Mutex<Vec<CompilationContext>>,
 }
 
@@ -40,9 +41,10 @@ impl wasmtime_cranelift_shared::CompiledFuncEnv for CompiledFuncEnv {
 }
 
 impl Compiler {
-    pub fn new(isa: Box<dyn TargetIsa>) -> Self {
+    pub fn new(isa: Box<dyn TargetIsa>, tunables: Tunables) -> Self {
         Self {
             isa,
+            tunables,
             contexts: Mutex::new(Vec::new()),
         }
     }
@@ -114,12 +116,14 @@ impl wasmtime_environ::Compiler for Compiler {
                 &body,
                 translation,
                 types,
+                &self.tunables,
                 &mut context.builtins,
                 &mut validator,
             )
             .map_err(|e| CompileError::Codegen(format!("{e:?}")));
         self.save_context(context, validator.into_allocations());
         let buffer = buffer?;
+        let stack_maps = mach_stack_maps_to_stack_maps(buffer.stack_maps());
 
         let mut compiled_function =
             CompiledFunction::new(buffer, CompiledFuncEnv {}, self.isa.function_alignment());
@@ -131,7 +135,8 @@ impl wasmtime_environ::Compiler for Compiler {
         Ok((
             WasmFunctionInfo {
                 start_srcloc,
-                stack_maps: Box::new([]),
+                stack_maps: stack_maps.into(),
+                coverage: Default::default(),
             },
             Box::new(compiled_function),
         ))
@@ -281,3 +286,26 @@ impl wasmtime_environ::Compiler for Compiler {
         self.isa.create_systemv_cie()
     }
 }
+
+fn mach_stack_maps_to_stack_maps(mach_stack_maps: &[MachStackMap]) -> Vec<StackMapInformation> {
+    // This is converting from Cranelift's representation of a stack map to
+    // Wasmtime's representation, which Winch reuses.
+    let mut stack_maps = Vec::new();
+    for &MachStackMap {
+        offset_end,
+        ref stack_map,
+        ..
+    } in mach_stack_maps
+    {
+        let stack_map = wasmtime_environ::StackMap::new(
+            stack_map.mapped_words(),
+            stack_map.as_slice().iter().map(|a| a.0),
+        );
+        stack_maps.push(StackMapInformation {
+            code_offset: offset_end,
+            stack_map,
+        });
+    }
+    stack_maps.sort_unstable_by_key(|info| info.code_offset);
+    stack_maps
+}
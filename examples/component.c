@@ -0,0 +1,154 @@
+/*
+Example of instantiating a WebAssembly component, defining a host function it
+imports, and invoking its exported function.
+
+You can compile and run this example on Linux with:
+
+   cargo build --release -p wasmtime-c-api
+   cc examples/component.c \
+       -I crates/c-api/include \
+       -I crates/c-api/wasm-c-api/include \
+       target/release/libwasmtime.a \
+       -lpthread -ldl -lm \
+       -o component
+   ./component
+
+Note that on Windows and macOS the command will be similar, but you'll need
+to tweak the `-lpthread` and such annotations as well as the name of the
+`libwasmtime.a` file on Windows.
+
+You can also build using cmake:
+
+mkdir build && cd build && cmake .. && cmake --build . --target wasmtime-component
+*/
+
+#include <assert.h>
+#include <stdio.h>
+#include <stdlib.h>
+#include <string.h>
+#include <wasm.h>
+#include <wasmtime.h>
+
+static void exit_with_error(const char *message, wasmtime_error_t *error);
+
+static wasmtime_error_t *greet_callback(void *env, wasmtime_context_t *context,
+                                        const wasmtime_component_val_t *args,
+                                        size_t nargs,
+                                        wasmtime_component_val_t *results,
+                                        size_t nresults) {
+  assert(nargs == 1 && nresults == 1);
+  assert(args[0].kind == WASMTIME_COMPONENT_STRING);
+  const wasm_name_t *name = &args[0].of.string;
+  printf("Calling back...\n");
+
+  // Results are owned by Wasmtime after the callback returns, so build the
+  // string in a freshly allocated vector.
+  const char prefix[] = "Hello, ";
+  size_t prefix_len = strlen(prefix);
+  wasm_name_t greeting;
+  wasm_byte_vec_new_uninitialized(&greeting, prefix_len + name->size + 1);
+  memcpy(greeting.data, prefix, prefix_len);
+  memcpy(greeting.data + prefix_len, name->data, name->size);
+  greeting.data[greeting.size - 1] = '!';
+
+  results[0].kind = WASMTIME_COMPONENT_STRING;
+  results[0].of.string = greeting;
+  return NULL;
+}
+
+int main() {
+  // Components are compiled and run with the same engines and stores as core
+  // wasm modules.
+  printf("Initializing...\n");
+  wasm_engine_t *engine = wasm_engine_new();
+  assert(engine != NULL);
+  wasmtime_store_t *store = wasmtime_store_new(engine, NULL, NULL);
+  assert(store != NULL);
+  wasmtime_context_t *context = wasmtime_store_context(store);
+
+  // Read our input file, which in this case is a wasm text file.
+  FILE *file = fopen("examples/component.wat", "r");
+  assert(file != NULL);
+  fseek(file, 0L, SEEK_END);
+  size_t file_size = ftell(file);
+  fseek(file, 0L, SEEK_SET);
+  wasm_byte_vec_t wat;
+  wasm_byte_vec_new_uninitialized(&wat, file_size);
+  if (fread(wat.data, file_size, 1, file) != 1) {
+    printf("> Error loading component!\n");
+    return 1;
+  }
+  fclose(file);
+
+  // Parse the wat into the binary format
+  wasm_byte_vec_t wasm;
+  wasmtime_error_t *error = wasmtime_wat2wasm(wat.data, wat.size, &wasm);
+  if (error != NULL)
+    exit_with_error("failed to parse wat", error);
+  wasm_byte_vec_delete(&wat);
+
+  printf("Compiling component...\n");
+  wasmtime_component_t *component = NULL;
+  error = wasmtime_component_new(engine, (uint8_t *)wasm.data, wasm.size,
+                                 &component);
+  wasm_byte_vec_delete(&wasm);
+  if (error != NULL)
+    exit_with_error("failed to compile component", error);
+
+  // Define the `greet` import in the root of a linker. Component functions are
+  // typed by the import they satisfy, so the component is passed in here too.
+  printf("Defining imports...\n");
+  wasmtime_component_linker_t *linker = wasmtime_component_linker_new(engine);
+  wasmtime_component_linker_instance_t *root =
+      wasmtime_component_linker_root(linker);
+  error = wasmtime_component_linker_instance_add_func(
+      root, component, "greet", 5, greet_callback, NULL, NULL);
+  if (error != NULL)
+    exit_with_error("failed to define `greet`", error);
+  wasmtime_component_linker_instance_delete(root);
+
+  printf("Instantiating component...\n");
+  wasmtime_component_instance_t instance;
+  error = wasmtime_component_linker_instantiate(linker, context, component,
+                                                &instance);
+  if (error != NULL)
+    exit_with_error("failed to instantiate", error);
+
+  printf("Extracting export...\n");
+  wasmtime_component_func_t run;
+  bool ok =
+      wasmtime_component_instance_get_func(&instance, context, "run", 3, &run);
+  assert(ok);
+
+  printf("Calling export...\n");
+  wasmtime_component_val_t arg;
+  arg.kind = WASMTIME_COMPONENT_STRING;
+  wasm_name_new(&arg.of.string, 5, "world");
+  wasmtime_component_val_t result;
+  error = wasmtime_component_func_call(&run, context, &arg, 1, &result, 1);
+  wasmtime_component_val_delete(&arg);
+  if (error != NULL)
+    exit_with_error("failed to call function", error);
+
+  assert(result.kind == WASMTIME_COMPONENT_STRING);
+  printf("> %.*s\n", (int)result.of.string.size, result.of.string.data);
+  wasmtime_component_val_delete(&result);
+
+  // Clean up after ourselves at this point
+  printf("All finished!\n");
+  wasmtime_component_linker_delete(linker);
+  wasmtime_component_delete(component);
+  wasmtime_store_delete(store);
+  wasm_engine_delete(engine);
+  return 0;
+}
+
+static void exit_with_error(const char *message, wasmtime_error_t *error) {
+  fprintf(stderr, "error: %s\n", message);
+  wasm_byte_vec_t error_message;
+  wasmtime_error_message(error, &error_message);
+  wasmtime_error_delete(error);
+  fprintf(stderr, "%.*s\n", (int)error_message.size, error_message.data);
+  wasm_byte_vec_delete(&error_message);
+  exit(1);
+}
@@ -0,0 +1,45 @@
+(component
+  ;; The host provides a function to build a greeting for a name.
+  (import "greet" (func $greet (param "name" string) (result string)))
+
+  ;; A small module providing the linear memory and allocator which are used
+  ;; to pass strings between the host and the component.
+  (core module $libc
+    (memory (export "memory") 1)
+    (global $next (mut i32) (i32.const 1024))
+    (func (export "realloc") (param i32 i32 i32 i32) (result i32)
+      (local $ret i32)
+      ;; Round up to the requested alignment, then bump-allocate.
+      (local.set $ret
+        (i32.and
+          (i32.add (global.get $next) (i32.sub (local.get 2) (i32.const 1)))
+          (i32.sub (i32.const 0) (local.get 2))))
+      (global.set $next (i32.add (local.get $ret) (local.get 3)))
+      (local.get $ret))
+  )
+  (core instance $libc (instantiate $libc))
+
+  (core func $greet_lowered
+    (canon lower (func $greet)
+      (memory $libc "memory")
+      (realloc (func $libc "realloc"))))
+
+  (core module $main
+    (import "libc" "memory" (memory 1))
+    (import "host" "greet" (func $greet (param i32 i32 i32)))
+    ;; Forwards the name to the host, which writes the pointer and length of
+    ;; its result at address 16, which is then returned as our own result.
+    (func (export "run") (param i32 i32) (result i32)
+      (call $greet (local.get 0) (local.get 1) (i32.const 16))
+      (i32.const 16))
+  )
+  (core instance $main
+    (instantiate $main
+      (with "libc" (instance $libc))
+      (with "host" (instance (export "greet" (func $greet_lowered))))))
+
+  (func (export "run") (param "name" string) (result string)
+    (canon lift (core func $main "run")
+      (memory $libc "memory")
+      (realloc (func $libc "realloc"))))
+)
@@ -0,0 +1,13 @@
+//! Check that execution in deterministic mode is identical across differently
+//! configured engines.
+
+#![no_main]
+
+use libfuzzer_sys::arbitrary::Unstructured;
+use libfuzzer_sys::fuzz_target;
+use wasmtime_fuzzing::oracles::check_deterministic;
+
+fuzz_target!(|data: &[u8]| {
+    // Errors here only mean there wasn't enough input, which is ignored.
+    let _ = check_deterministic(&mut Unstructured::new(data));
+});
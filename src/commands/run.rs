@@ -5,6 +5,7 @@
     allow(irrefutable_let_patterns, unreachable_patterns)
 )]
 
+use self::debug_server::DebugServer;
 use crate::common::{Profile, RunCommon, RunTarget};
 
 use anyhow::{anyhow, bail, Context as _, Error, Result};
@@ -13,7 +14,10 @@ use std::ffi::OsString;
 use std::path::{Path, PathBuf};
 use std::sync::{Arc, Mutex};
 use std::thread;
-use wasmtime::{Engine, Func, Module, Store, StoreLimits, Val, ValType};
+use wasmtime::{
+    Engine, Func, HostCallError, HostCallTrace, Module, Store, StoreLimits, Val, ValType,
+    WasmBacktraceDetails,
+};
 use wasmtime_wasi::maybe_exit_on_error;
 use wasmtime_wasi::preview2;
 use wasmtime_wasi::sync::{ambient_authority, Dir, TcpListener, WasiCtxBuilder};
@@ -21,12 +25,18 @@ use wasmtime_wasi::sync::{ambient_authority, Dir, TcpListener, WasiCtxBuilder};
 #[cfg(feature = "wasi-nn")]
 use wasmtime_wasi_nn::WasiNnCtx;
 
+mod coverage;
+mod debug_server;
+
 #[cfg(feature = "wasi-threads")]
 use wasmtime_wasi_threads::WasiThreadsCtx;
 
 #[cfg(feature = "wasi-http")]
 use wasmtime_wasi_http::WasiHttpCtx;
 
+#[cfg(feature = "wasi-keyvalue")]
+use wasmtime_wasi_keyvalue::WasiKeyValueCtx;
+
 fn parse_env_var(s: &str) -> Result<(String, Option<String>)> {
     let mut parts = s.splitn(2, '=');
     Ok((
@@ -53,6 +63,24 @@ fn parse_preloads(s: &str) -> Result<(String, PathBuf)> {
     Ok((parts[0].into(), parts[1].into()))
 }
 
+/// The text format of the arguments and results of component functions
+/// called with `--invoke`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum InvokeFormat {
+    /// The WebAssembly Value Encoding, the syntax of values in WIT.
+    Wave,
+    /// JSON, with values mapped as described by `wasmtime::component::Val::to_json`.
+    Json,
+}
+
+fn parse_invoke_format(s: &str) -> Result<InvokeFormat> {
+    match s {
+        "wave" => Ok(InvokeFormat::Wave),
+        "json" => Ok(InvokeFormat::Json),
+        _ => bail!("unknown format `{s}`, expected `wave` or `json`"),
+    }
+}
+
 /// Runs a WebAssembly module
 #[derive(Parser, PartialEq)]
 pub struct RunCommand {
@@ -80,9 +108,28 @@ pub struct RunCommand {
     pub vars: Vec<(String, Option<String>)>,
 
     /// The name of the function to run
+    ///
+    /// For components this is the name of an exported function, where
+    /// functions exported from an exported instance are named with the
+    /// instance and the function separated by `#`, for example
+    /// `wasi:cli/run@0.2.0#run`.
     #[arg(long, value_name = "FUNCTION")]
     pub invoke: Option<String>,
 
+    /// The format of the arguments and results of component functions called
+    /// with `--invoke`.
+    ///
+    /// This is either `wave`, the syntax used for values in WIT, or `json`.
+    /// Each argument to the function is a separate command line argument, and
+    /// each result is printed on its own line in the same format.
+    #[arg(
+        long,
+        value_name = "wave|json",
+        default_value = "wave",
+        value_parser = parse_invoke_format,
+    )]
+    pub invoke_format: InvokeFormat,
+
     /// Load the given WebAssembly module before the main module
     #[arg(
         long = "preload",
@@ -92,6 +139,51 @@ pub struct RunCommand {
     )]
     pub preloads: Vec<(String, PathBuf)>,
 
+    /// Record all calls from the guest to the host into a trace file.
+    ///
+    /// The trace contains the arguments and results of every call that the
+    /// guest makes to WASI and other host functions, along with the bytes that
+    /// those calls wrote into the guest's linear memory. It can be replayed
+    /// later on with `--replay`.
+    #[arg(long, value_name = "FILE", conflicts_with = "replay")]
+    pub record: Option<PathBuf>,
+
+    /// Replay a trace recorded with `--record` instead of calling the host.
+    ///
+    /// The guest is run as usual, but every call that it makes to WASI or
+    /// other host functions returns the results that were recorded in the
+    /// trace instead of calling the host, which reproduces the recorded
+    /// execution. The same module and arguments must be used as when the
+    /// trace was recorded. A guest which exited with a status when recorded
+    /// exits with the same status when replayed; other errors from the host
+    /// are replayed with their original message.
+    #[arg(long, value_name = "FILE")]
+    pub replay: Option<PathBuf>,
+
+    /// Wait for a debugger to connect on the given local port before running.
+    ///
+    /// This starts a GDB remote protocol server, in the dialect used by LLDB
+    /// to debug WebAssembly, which exposes wasm-level state to the debugger:
+    /// the wasm call stack, locals, operand stack, globals and linear memory,
+    /// along with breakpoints and single-stepping on wasm instructions. The
+    /// guest stops at its first instruction once the debugger connects, for
+    /// example with `lldb -o "process connect connect://localhost:PORT"`.
+    /// Port 0 picks any free port, which is printed to stderr. Components
+    /// can't be debugged yet.
+    #[arg(long, value_name = "PORT")]
+    pub debug_server: Option<u16>,
+
+    /// Write a report of which code the guest executed to the given file.
+    ///
+    /// Every basic block of the main module and of preloaded modules counts
+    /// how many times it's entered, and the counts are written in the lcov
+    /// format once the guest exits, even if it traps. Blocks are attributed
+    /// to source lines using the DWARF debug information of modules, when
+    /// present, and to the wasm file otherwise, with the offset of a block
+    /// used as its line number. Components aren't supported yet.
+    #[arg(long, value_name = "FILE")]
+    pub coverage: Option<PathBuf>,
+
     /// The WebAssembly module to run and arguments to pass to it.
     ///
     /// Arguments passed to the wasm module will be configured as WASI CLI
@@ -127,6 +219,13 @@ impl RunCommand {
             }
             None => {}
         }
+        if self.debug_server.is_some() {
+            config.guest_debug(true);
+        }
+        if self.coverage.is_some() {
+            config.coverage(true);
+            config.wasm_backtrace_details(WasmBacktraceDetails::Enable);
+        }
 
         let engine = Engine::new(&config)?;
 
@@ -174,10 +273,22 @@ impl RunCommand {
             store.set_fuel(fuel)?;
         }
 
+        if self.record.is_some() {
+            store.record_host_calls();
+            store.host_call_exit_code(wasmtime_wasi::exit_code);
+        }
+        if let Some(path) = &self.replay {
+            let trace = std::fs::read(path)
+                .with_context(|| format!("failed to read trace `{}`", path.display()))?;
+            store.replay_host_calls(HostCallTrace::deserialize(&trace)?);
+        }
+
         // Load the preload wasm modules.
         let mut modules = Vec::new();
+        let mut paths = Vec::new();
         if let RunTarget::Core(m) = &main {
             modules.push((String::new(), m.clone()));
+            paths.push(PathBuf::from(&self.module_and_args[0]));
         }
         for (name, path) in self.preloads.iter() {
             // Read the wasm module binary either as `*.wat` or a raw binary
@@ -187,6 +298,7 @@ impl RunCommand {
                 RunTarget::Component(_) => bail!("components cannot be loaded with `--preload`"),
             };
             modules.push((name.clone(), module.clone()));
+            paths.push(path.clone());
 
             // Add the module's functions to the linker.
             match &mut linker {
@@ -209,15 +321,76 @@ impl RunCommand {
             }
         }
 
+        let debug_server = match self.debug_server {
+            Some(port) => {
+                #[cfg(feature = "component-model")]
+                if let RunTarget::Component(_) = &main {
+                    bail!("`--debug-server` cannot be used with components yet");
+                }
+                let mut server = DebugServer::listen(port)?;
+                for ((name, module), path) in modules.iter().zip(&paths) {
+                    server.add_module(&engine, name, module, path)?;
+                }
+                let server = Arc::new(Mutex::new(server));
+                let hook = server.clone();
+                store.debug_hook(move |store, frame| hook.lock().unwrap().stop(store, frame));
+                store.single_step(true)?;
+                Some(server)
+            }
+            None => None,
+        };
+
+        let covered_modules = match &self.coverage {
+            Some(_) => {
+                #[cfg(feature = "component-model")]
+                if let RunTarget::Component(_) = &main {
+                    bail!("`--coverage` cannot be used with components yet");
+                }
+                modules
+                    .iter()
+                    .map(|(_, module)| module.clone())
+                    .zip(paths.iter().cloned())
+                    .collect()
+            }
+            None => Vec::new(),
+        };
+
         // Load the main wasm module.
-        match self
+        let result = self
             .load_main_module(&mut store, &mut linker, &main, modules)
             .with_context(|| {
                 format!(
                     "failed to run main module `{}`",
                     self.module_and_args[0].to_string_lossy()
                 )
-            }) {
+            });
+
+        // Save the recorded trace before the process might exit below.
+        if let Some(path) = &self.record {
+            let trace = store.take_host_call_trace().unwrap_or_default();
+            std::fs::write(path, trace.serialize()?)
+                .with_context(|| format!("failed to write trace `{}`", path.display()))?;
+        }
+
+        // A replayed exit is a plain error from the host, so turn it back into
+        // the exit that was recorded.
+        let result = match result {
+            Err(e) => match e.downcast_ref().and_then(HostCallError::exit_code) {
+                Some(code) => Err(wasmtime_wasi::I32Exit(code).into()),
+                None => Err(e),
+            },
+            Ok(()) => Ok(()),
+        };
+
+        if let Some(server) = debug_server {
+            server.lock().unwrap().finish(&result)?;
+        }
+
+        if let Some(path) = &self.coverage {
+            coverage::write_lcov(path, &covered_modules)?;
+        }
+
+        match result {
             Ok(()) => (),
             Err(e) => {
                 // Exit the process if Wasmtime understands the error;
@@ -282,14 +455,15 @@ impl RunCommand {
     fn setup_epoch_handler(
         &self,
         store: &mut Store<Host>,
+        main: &RunTarget,
         modules: Vec<(String, Module)>,
     ) -> Result<Box<dyn FnOnce(&mut Store<Host>)>> {
         if let Some(Profile::Guest { path, interval }) = &self.run.profile {
             #[cfg(feature = "profiling")]
-            return Ok(self.setup_guest_profiler(store, modules, path, *interval));
+            return Ok(self.setup_guest_profiler(store, main, modules, path, *interval));
             #[cfg(not(feature = "profiling"))]
             {
-                let _ = (modules, path, interval);
+                let _ = (main, modules, path, interval);
                 bail!("support for profiling disabled at compile time");
             }
         }
@@ -310,48 +484,28 @@ impl RunCommand {
     fn setup_guest_profiler(
         &self,
         store: &mut Store<Host>,
+        main: &RunTarget,
         modules: Vec<(String, Module)>,
         path: &str,
         interval: std::time::Duration,
     ) -> Box<dyn FnOnce(&mut Store<Host>)> {
-        use wasmtime::{AsContextMut, GuestProfiler, UpdateDeadline};
+        use wasmtime::GuestProfiler;
 
         let module_name = self.module_and_args[0].to_str().unwrap_or("<main module>");
-        store.data_mut().guest_profiler =
-            Some(Arc::new(GuestProfiler::new(module_name, interval, modules)));
-
-        fn sample(mut store: impl AsContextMut<Data = Host>) {
-            let mut profiler = store
-                .as_context_mut()
-                .data_mut()
-                .guest_profiler
-                .take()
-                .unwrap();
-            Arc::get_mut(&mut profiler)
-                .expect("profiling doesn't support threads yet")
-                .sample(&store);
-            store.as_context_mut().data_mut().guest_profiler = Some(profiler);
-        }
-
-        if let Some(timeout) = self.run.common.wasm.timeout {
-            let mut timeout = (timeout.as_secs_f64() / interval.as_secs_f64()).ceil() as u64;
-            assert!(timeout > 0);
-            store.epoch_deadline_callback(move |mut store| {
-                sample(&mut store);
-                timeout -= 1;
-                if timeout == 0 {
-                    bail!("timeout exceeded");
-                }
-                Ok(UpdateDeadline::Continue(1))
-            });
-        } else {
-            store.epoch_deadline_callback(move |mut store| {
-                sample(&mut store);
-                Ok(UpdateDeadline::Continue(1))
-            });
-        }
+        let profiler = match main {
+            RunTarget::Core(_) => GuestProfiler::new(module_name, interval, modules),
+            #[cfg(feature = "component-model")]
+            RunTarget::Component(component) => {
+                GuestProfiler::new_component(module_name, interval, component.clone(), modules)
+            }
+        };
+        store.data_mut().guest_profiler = Some(Arc::new(profiler));
+        crate::common::setup_guest_sampling(
+            store,
+            guest_profiler,
+            self.run.guest_profiler_timeout(interval),
+        );
 
-        store.set_epoch_deadline(1);
         let engine = store.engine().clone();
         thread::spawn(move || loop {
             thread::sleep(interval);
@@ -360,18 +514,8 @@ impl RunCommand {
 
         let path = path.to_string();
         return Box::new(move |store| {
-            let profiler = Arc::try_unwrap(store.data_mut().guest_profiler.take().unwrap())
-                .expect("profiling doesn't support threads yet");
-            if let Err(e) = std::fs::File::create(&path)
-                .map_err(anyhow::Error::new)
-                .and_then(|output| profiler.finish(std::io::BufWriter::new(output)))
-            {
-                eprintln!("failed writing profile at {path}: {e:#}");
-            } else {
-                eprintln!();
-                eprintln!("Profile written to: {path}");
-                eprintln!("View this profile at https://profiler.firefox.com/.");
-            }
+            let profiler = store.data_mut().guest_profiler.take().unwrap();
+            crate::common::write_guest_profile(&profiler, &path);
         });
     }
 
@@ -409,7 +553,7 @@ impl RunCommand {
             bail!("support for `unknown-imports-trap` disabled at compile time");
         }
 
-        let finish_epoch_handler = self.setup_epoch_handler(store, modules)?;
+        let finish_epoch_handler = self.setup_epoch_handler(store, module, modules)?;
 
         let result = match linker {
             CliLinker::Core(linker) => {
@@ -446,26 +590,40 @@ impl RunCommand {
             }
             #[cfg(feature = "component-model")]
             CliLinker::Component(linker) => {
-                if self.invoke.is_some() {
-                    bail!("using `--invoke` with components is not supported");
-                }
-
                 let component = module.unwrap_component();
 
-                let (command, _instance) =
-                    preview2::command::sync::Command::instantiate(&mut *store, component, linker)?;
-                let result = command
-                    .wasi_cli_run()
-                    .call_run(&mut *store)
-                    .context("failed to invoke `run` function")
-                    .map_err(|e| self.handle_core_dump(&mut *store, e));
-
-                // Translate the `Result<(),()>` produced by wasm into a feigned
-                // explicit exit here with status 1 if `Err(())` is returned.
-                result.and_then(|wasm_result| match wasm_result {
-                    Ok(()) => Ok(()),
-                    Err(()) => Err(wasmtime_wasi::I32Exit(1).into()),
-                })
+                if let Some(name) = &self.invoke {
+                    let instance = linker.instantiate(&mut *store, component).context(format!(
+                        "failed to instantiate {:?}",
+                        self.module_and_args[0]
+                    ))?;
+                    let func = {
+                        let mut exports = instance.exports(&mut *store);
+                        let path = name.split('#').collect::<Vec<_>>();
+                        find_component_func(exports.root(), &path)
+                    };
+                    let func =
+                        func.ok_or_else(|| anyhow!("no func export named `{}` found", name))?;
+                    self.invoke_component_func(store, func, name)
+                } else {
+                    let (command, _instance) = preview2::command::sync::Command::instantiate(
+                        &mut *store,
+                        component,
+                        linker,
+                    )?;
+                    let result = command
+                        .wasi_cli_run()
+                        .call_run(&mut *store)
+                        .context("failed to invoke `run` function")
+                        .map_err(|e| self.handle_core_dump(&mut *store, e));
+
+                    // Translate the `Result<(),()>` produced by wasm into a feigned
+                    // explicit exit here with status 1 if `Err(())` is returned.
+                    result.and_then(|wasm_result| match wasm_result {
+                        Ok(()) => Ok(()),
+                        Err(()) => Err(wasmtime_wasi::I32Exit(1).into()),
+                    })
+                }
             }
         };
         finish_epoch_handler(store);
@@ -542,12 +700,69 @@ impl RunCommand {
                 Val::ExternRef(_) => println!("<externref>"),
                 Val::FuncRef(_) => println!("<funcref>"),
                 Val::V128(i) => println!("{}", i.as_u128()),
+                Val::AnyRef(None) => println!("<anyref null>"),
+                Val::AnyRef(Some(a)) => match a.as_i31() {
+                    Some(i31) => println!("<i31ref {}>", i31.get_i32()),
+                    None => println!("<anyref>"),
+                },
+                Val::ExnRef(_) => println!("<exnref>"),
             }
         }
 
         Ok(())
     }
 
+    #[cfg(feature = "component-model")]
+    fn invoke_component_func(
+        &self,
+        store: &mut Store<Host>,
+        func: wasmtime::component::Func,
+        name: &str,
+    ) -> Result<()> {
+        use wasmtime::component::Val;
+
+        let params = func.params(&store);
+        let args = self.module_and_args.iter().skip(1).collect::<Vec<_>>();
+        if args.len() != params.len() {
+            bail!(
+                "`{}` takes {} arguments but {} were given",
+                name,
+                params.len(),
+                args.len()
+            );
+        }
+        let mut values = Vec::new();
+        for (i, (ty, arg)) in params.iter().zip(args).enumerate() {
+            let arg = arg
+                .to_str()
+                .ok_or_else(|| anyhow!("argument is not valid utf-8: {arg:?}"))?;
+            let val = match self.invoke_format {
+                InvokeFormat::Wave => Val::from_wave(ty, arg),
+                InvokeFormat::Json => Val::from_json(ty, arg),
+            };
+            values.push(val.with_context(|| format!("invalid argument {i} for `{name}`"))?);
+        }
+
+        let mut results = vec![Val::Bool(false); func.results(&store).len()];
+        let invoke_res = func
+            .call(&mut *store, &values, &mut results)
+            .and_then(|()| func.post_return(&mut *store))
+            .with_context(|| format!("failed to invoke `{}`", name));
+        if let Err(err) = invoke_res {
+            return Err(self.handle_core_dump(&mut *store, err));
+        }
+
+        for result in results {
+            let text = match self.invoke_format {
+                InvokeFormat::Wave => result.to_wave()?,
+                InvokeFormat::Json => result.to_json()?,
+            };
+            println!("{text}");
+        }
+
+        Ok(())
+    }
+
     #[cfg(feature = "coredump")]
     fn handle_core_dump(&self, store: &mut Store<Host>, err: Error) -> Error {
         let coredump_path = match &self.run.common.debug.coredump {
@@ -680,10 +895,19 @@ impl RunCommand {
                 wasmtime_wasi_threads::add_to_linker(linker, store, &module, |host| {
                     host.wasi_threads.as_ref().unwrap()
                 })?;
-                store.data_mut().wasi_threads = Some(Arc::new(WasiThreadsCtx::new(
-                    module.clone(),
-                    Arc::new(linker.clone()),
-                )?));
+                #[allow(unused_mut)]
+                let mut ctx = WasiThreadsCtx::new(module.clone(), Arc::new(linker.clone()))?;
+                // Each thread's store samples into the same guest profiler
+                // as the main thread.
+                #[cfg(feature = "profiling")]
+                if let Some(Profile::Guest { interval, .. }) = &self.run.profile {
+                    let timeout = self.run.guest_profiler_timeout(*interval);
+                    ctx.configure_store(move |store| {
+                        crate::common::setup_guest_sampling(store, guest_profiler, timeout);
+                        Ok(())
+                    });
+                }
+                store.data_mut().wasi_threads = Some(Arc::new(ctx));
             }
         }
 
@@ -707,6 +931,30 @@ impl RunCommand {
             }
         }
 
+        if self.run.common.wasi.keyvalue == Some(true) {
+            #[cfg(not(feature = "wasi-keyvalue"))]
+            {
+                bail!(
+                    "Cannot enable wasi-keyvalue when the binary is not compiled with this feature."
+                );
+            }
+            #[cfg(feature = "wasi-keyvalue")]
+            {
+                match linker {
+                    CliLinker::Core(_) => {
+                        bail!("Cannot enable wasi-keyvalue for core wasm modules");
+                    }
+                    CliLinker::Component(linker) => {
+                        wasmtime_wasi_keyvalue::add_to_linker(linker)?;
+                    }
+                }
+
+                store.data_mut().wasi_keyvalue = Some(self.run.wasi_keyvalue_ctx()?);
+            }
+        } else if self.run.common.wasi.keyvalue_dir.is_some() {
+            bail!("`-S keyvalue-dir` requires `-S keyvalue`");
+        }
+
         Ok(())
     }
 
@@ -738,6 +986,10 @@ impl RunCommand {
             builder.preopened_dir(dir, name)?;
         }
 
+        if store.engine().is_deterministic() {
+            builder.deterministic();
+        }
+
         store.data_mut().preview1_ctx = Some(builder.build());
         Ok(())
     }
@@ -783,6 +1035,9 @@ impl RunCommand {
         if let Some(enable) = self.run.common.wasi.udp {
             builder.allow_udp(enable);
         }
+        if store.engine().is_deterministic() {
+            builder.deterministic();
+        }
 
         let ctx = builder.build();
         store.data_mut().preview2_ctx = Some(Arc::new(Mutex::new(ctx)));
@@ -814,6 +1069,8 @@ struct Host {
     wasi_threads: Option<Arc<WasiThreadsCtx<Host>>>,
     #[cfg(feature = "wasi-http")]
     wasi_http: Option<Arc<WasiHttpCtx>>,
+    #[cfg(feature = "wasi-keyvalue")]
+    wasi_keyvalue: Option<WasiKeyValueCtx>,
     limits: StoreLimits,
     #[cfg(feature = "profiling")]
     guest_profiler: Option<Arc<wasmtime::GuestProfiler>>,
@@ -861,6 +1118,25 @@ impl wasmtime_wasi_http::types::WasiHttpView for Host {
     }
 }
 
+#[cfg(feature = "wasi-keyvalue")]
+impl wasmtime_wasi_keyvalue::WasiKeyValueView for Host {
+    fn ctx(&mut self) -> &mut WasiKeyValueCtx {
+        self.wasi_keyvalue.as_mut().unwrap()
+    }
+
+    fn table(&mut self) -> &mut wasmtime::component::ResourceTable {
+        Arc::get_mut(&mut self.preview2_table)
+            .expect("preview2 is not compatible with threads")
+            .get_mut()
+            .unwrap()
+    }
+}
+
+#[cfg(feature = "profiling")]
+fn guest_profiler(host: &Host) -> Option<&wasmtime::GuestProfiler> {
+    host.guest_profiler.as_deref()
+}
+
 #[cfg(not(unix))]
 fn ctx_set_listenfd(num_fd: usize, _builder: &mut WasiCtxBuilder) -> Result<usize> {
     Ok(num_fd)
@@ -890,6 +1166,20 @@ fn ctx_set_listenfd(mut num_fd: usize, builder: &mut WasiCtxBuilder) -> Result<u
     Ok(num_fd)
 }
 
+/// Looks up the function at `path` within `instance`, where all but the last
+/// element of `path` name nested exported instances.
+#[cfg(feature = "component-model")]
+fn find_component_func(
+    mut instance: wasmtime::component::ExportInstance<'_, '_>,
+    path: &[&str],
+) -> Option<wasmtime::component::Func> {
+    match path {
+        [name] => instance.func(name),
+        [name, rest @ ..] => find_component_func(instance.into_instance(name)?, rest),
+        [] => None,
+    }
+}
+
 #[cfg(feature = "coredump")]
 fn write_core_dump(
     store: &mut Store<Host>,
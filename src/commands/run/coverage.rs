@@ -0,0 +1,96 @@
+//! Writing the report of `wasmtime run --coverage` in the lcov tracefile
+//! format.
+//!
+//! Each basic block is attributed to the source line of its first instruction
+//! which is found through the DWARF debug information of its module. Blocks
+//! of modules without debug information, or of functions which it doesn't
+//! describe, are attributed to the wasm file itself instead, with the offset
+//! of the block's first instruction in that file used as its "line".
+
+use anyhow::{Context as _, Result};
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+use std::path::{Path, PathBuf};
+use wasmtime::{BlockCoverage, Module};
+
+/// The coverage of a single source file.
+#[derive(Default)]
+struct FileCoverage {
+    /// The name, first line and call count of each function which starts in
+    /// this file.
+    functions: Vec<(String, u32, u64)>,
+    /// The execution count of each line, which is the highest count of the
+    /// blocks which start on that line.
+    lines: BTreeMap<u32, u64>,
+}
+
+/// Writes the coverage of `modules`, which were loaded from the paired
+/// paths, to the lcov tracefile at `path`.
+pub fn write_lcov(path: &Path, modules: &[(Module, PathBuf)]) -> Result<()> {
+    let mut files = BTreeMap::<String, FileCoverage>::new();
+    for (module, module_path) in modules {
+        let module_path = module_path.display().to_string();
+        for func in module.coverage() {
+            let mut blocks = func.blocks().iter().map(|block| {
+                let (file, line) = source_line(block).unwrap_or_else(|| {
+                    (
+                        module_path.clone(),
+                        u32::try_from(block.module_offset()).unwrap(),
+                    )
+                });
+                (file, line, block.count())
+            });
+            let Some((file, line, count)) = blocks.next() else {
+                continue;
+            };
+            let name = match func.func_name() {
+                Some(name) => name.to_string(),
+                None => format!("wasm-function[{}]", func.func_index()),
+            };
+            files
+                .entry(file.clone())
+                .or_default()
+                .functions
+                .push((name, line, count));
+            for (file, line, count) in std::iter::once((file, line, count)).chain(blocks) {
+                let hits = files
+                    .entry(file)
+                    .or_default()
+                    .lines
+                    .entry(line)
+                    .or_default();
+                *hits = (*hits).max(count);
+            }
+        }
+    }
+
+    let mut report = String::from("TN:\n");
+    for (file, coverage) in files {
+        writeln!(report, "SF:{file}")?;
+        for (name, line, _) in &coverage.functions {
+            writeln!(report, "FN:{line},{name}")?;
+        }
+        for (name, _, count) in &coverage.functions {
+            writeln!(report, "FNDA:{count},{name}")?;
+        }
+        let hit = coverage.functions.iter().filter(|f| f.2 > 0).count();
+        writeln!(report, "FNF:{}", coverage.functions.len())?;
+        writeln!(report, "FNH:{hit}")?;
+        for (line, count) in &coverage.lines {
+            writeln!(report, "DA:{line},{count}")?;
+        }
+        let hit = coverage.lines.values().filter(|count| **count > 0).count();
+        writeln!(report, "LF:{}", coverage.lines.len())?;
+        writeln!(report, "LH:{hit}")?;
+        report.push_str("end_of_record\n");
+    }
+    std::fs::write(path, report)
+        .with_context(|| format!("failed to write coverage report `{}`", path.display()))
+}
+
+/// Returns the source file and line of the first instruction of `block`, if
+/// its module has debug information for it.
+fn source_line(block: &BlockCoverage) -> Option<(String, u32)> {
+    let symbol = block.symbols().first()?;
+    Some((symbol.file()?.to_string(), symbol.line()?))
+}
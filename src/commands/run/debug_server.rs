@@ -0,0 +1,552 @@
+//! A GDB remote serial protocol server which implements `wasmtime run
+//! --debug-server`.
+//!
+//! The server speaks the dialect of the protocol which LLDB uses to debug
+//! WebAssembly, which extends the protocol with `qWasm*` packets to read
+//! locals, globals, operand stack values and linear memory of a frame, and
+//! with a `qXfer:libraries:read` list of the modules being debugged so that
+//! the debugger can read their DWARF itself.
+//!
+//! Addresses are 64-bit with the type of the address space in the top two
+//! bits, the index of a module in the next 30 bits and an offset in the low 32
+//! bits. Code addresses, such as the PC, have type 1 and are offsets into the
+//! original wasm file of a module while memory addresses have type 0 and are
+//! offsets into the default linear memory of the stopped instance.
+//!
+//! The server runs on the thread executing wasm: it's called from the
+//! [`Store::debug_hook`](wasmtime::Store::debug_hook) and blocks there until
+//! the debugger resumes execution.
+
+use anyhow::{anyhow, bail, Context as _, Result};
+use std::fmt::Write as _;
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::path::Path;
+use wasmtime::{DebugFrame, Engine, Memory, Module, StoreContextMut, Val, WasmBacktrace};
+
+/// The only thread of the debugged process.
+const THREAD_ID: u32 = 1;
+
+/// The type of addresses which refer to the code of a module.
+const CODE_ADDRESS: u64 = 1 << 62;
+
+/// The maximum size of a packet sent to the server, which is reported to the
+/// debugger in hex by `qSupported`.
+const PACKET_SIZE: usize = 0x1000;
+
+/// The triple reported by `qHostInfo` and `qProcessInfo`.
+const TRIPLE: &str = "wasm32-unknown-unknown-wasm";
+
+/// The reply to packets which are malformed or refer to state which isn't
+/// available.
+const ERROR: &str = "E03";
+
+/// A module which can be debugged.
+struct DebugModule {
+    name: String,
+    module: Module,
+    wasm: Vec<u8>,
+}
+
+/// The state of the connection to a debugger.
+pub struct DebugServer {
+    reader: BufReader<TcpStream>,
+    writer: TcpStream,
+    ack: bool,
+    modules: Vec<DebugModule>,
+    breakpoints: Vec<(usize, u32)>,
+    /// Whether the debugger resumed execution and is waiting for it to stop.
+    running: bool,
+    /// Whether the debugger detached or killed the guest, after which the
+    /// server doesn't stop anymore.
+    detached: bool,
+}
+
+/// What to do after handling a packet while stopped.
+enum Action {
+    Reply(String),
+    Resume { step: bool },
+    Detach,
+    Kill,
+}
+
+impl DebugServer {
+    /// Listens on `port` of the loopback interface and waits for a debugger to
+    /// connect.
+    pub fn listen(port: u16) -> Result<DebugServer> {
+        let listener = TcpListener::bind(("127.0.0.1", port))
+            .with_context(|| format!("failed to bind debug server to port {port}"))?;
+        eprintln!(
+            "Debug server listening on {}, waiting for a debugger to connect",
+            listener.local_addr()?
+        );
+        let (stream, _) = listener
+            .accept()
+            .context("failed to accept debugger connection")?;
+        stream.set_nodelay(true)?;
+        Ok(DebugServer {
+            reader: BufReader::new(stream.try_clone()?),
+            writer: stream,
+            ack: true,
+            modules: Vec::new(),
+            breakpoints: Vec::new(),
+            running: false,
+            detached: false,
+        })
+    }
+
+    /// Makes `module`, compiled from the file at `path`, available to the
+    /// debugger.
+    pub fn add_module(
+        &mut self,
+        engine: &Engine,
+        name: &str,
+        module: &Module,
+        path: &Path,
+    ) -> Result<()> {
+        let wasm = std::fs::read(path)
+            .with_context(|| format!("failed to read file: {}", path.display()))?;
+        if engine.detect_precompiled(&wasm).is_some() {
+            bail!(
+                "cannot debug precompiled module `{}`, the original wasm module is required",
+                path.display()
+            );
+        }
+        #[cfg(feature = "wat")]
+        let wasm = wat::parse_bytes(&wasm)?.into_owned();
+        self.modules.push(DebugModule {
+            name: name.to_string(),
+            module: module.clone(),
+            wasm,
+        });
+        Ok(())
+    }
+
+    /// Reports that the guest stopped at `frame` to the debugger and handles
+    /// its requests until it resumes execution.
+    pub fn stop<T>(&mut self, mut store: StoreContextMut<'_, T>, frame: &DebugFrame) -> Result<()> {
+        if self.detached {
+            return Ok(());
+        }
+        if self.running {
+            self.running = false;
+            self.send(&stop_reply())?;
+        }
+        let pcs = self.call_stack(&store, frame);
+        loop {
+            let Some(packet) = self.recv()? else {
+                return self.detach(&mut store);
+            };
+            let action = match self.handle_common(&packet) {
+                Some(reply) => Action::Reply(reply),
+                None => self.handle_stopped(&mut store, frame, &pcs, &packet),
+            };
+            match action {
+                Action::Reply(reply) => self.send(&reply)?,
+                Action::Resume { step } => {
+                    store.single_step(step)?;
+                    self.running = true;
+                    return Ok(());
+                }
+                Action::Detach => {
+                    self.send("OK")?;
+                    return self.detach(&mut store);
+                }
+                Action::Kill => {
+                    self.detached = true;
+                    bail!("killed by the debugger");
+                }
+            }
+        }
+    }
+
+    /// Reports that the guest exited after `result` to the debugger.
+    pub fn finish(&mut self, result: &Result<()>) -> Result<()> {
+        if self.detached {
+            return Ok(());
+        }
+        let reply = match result {
+            Ok(()) => "W00".to_string(),
+            Err(e) if e.is::<wasmtime::Trap>() => "X06".to_string(),
+            Err(e) => {
+                let code = e
+                    .downcast_ref::<wasmtime_wasi::I32Exit>()
+                    .map(|e| e.0)
+                    .or_else(|| {
+                        e.downcast_ref::<wasmtime_wasi::preview2::I32Exit>()
+                            .map(|e| e.0)
+                    })
+                    .unwrap_or(1);
+                format!("W{:02x}", code as u8)
+            }
+        };
+        self.detached = true;
+        if self.running {
+            return self.send(&reply);
+        }
+
+        // The guest exited before it ever stopped, so answer the debugger's
+        // queries until it asks about or for the guest to run.
+        while let Some(packet) = self.recv()? {
+            match packet.as_bytes()[0] {
+                b'?' | b'c' | b's' => return self.send(&reply),
+                b'k' => return Ok(()),
+                b'D' => return self.send("OK"),
+                _ => {
+                    let reply = self.handle_common(&packet);
+                    self.send(reply.as_deref().unwrap_or(ERROR))?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn detach<T>(&mut self, store: &mut StoreContextMut<'_, T>) -> Result<()> {
+        self.detached = true;
+        for (module, offset) in self.breakpoints.drain(..) {
+            store.remove_breakpoint(&self.modules[module].module, offset as usize);
+        }
+        store.single_step(false)
+    }
+
+    /// Handles the packets which don't depend on the state of the guest,
+    /// returning `None` for other packets.
+    fn handle_common(&mut self, packet: &str) -> Option<String> {
+        let reply = if packet.starts_with("qSupported") {
+            format!("PacketSize={PACKET_SIZE:x};QStartNoAckMode+;qXfer:libraries:read+")
+        } else if packet == "qHostInfo" {
+            format!("triple:{};endian:little;ptrsize:4;", hex(TRIPLE.as_bytes()))
+        } else if packet == "qProcessInfo" {
+            format!(
+                "pid:1;parent-pid:1;triple:{};endian:little;ptrsize:4;",
+                hex(TRIPLE.as_bytes())
+            )
+        } else if let Some(index) = packet.strip_prefix("qRegisterInfo") {
+            if index == "0" {
+                "name:pc;alt-name:pc;bitsize:64;offset:0;encoding:uint;format:hex;\
+                 set:General Purpose Registers;gcc:16;dwarf:16;generic:pc;"
+                    .to_string()
+            } else {
+                "E45".to_string()
+            }
+        } else if packet == "qfThreadInfo" {
+            format!("m{THREAD_ID:x}")
+        } else if packet == "qsThreadInfo" {
+            "l".to_string()
+        } else if packet == "qC" {
+            format!("QC{THREAD_ID:x}")
+        } else if packet.starts_with('H') {
+            "OK".to_string()
+        } else if let Some(range) = packet.strip_prefix("qXfer:libraries:read::") {
+            match parse_pair(range, ',') {
+                Some((offset, len)) => self.libraries(offset as usize, len as usize),
+                None => ERROR.to_string(),
+            }
+        } else {
+            return None;
+        };
+        Some(reply)
+    }
+
+    /// Handles the packets which depend on the state of the guest while it's
+    /// stopped at `frame`.
+    fn handle_stopped<T>(
+        &mut self,
+        store: &mut StoreContextMut<'_, T>,
+        frame: &DebugFrame,
+        pcs: &[u64],
+        packet: &str,
+    ) -> Action {
+        let reply = match packet.as_bytes()[0] {
+            b'?' => stop_reply(),
+            b'c' => return Action::Resume { step: false },
+            b's' => return Action::Resume { step: true },
+            b'k' => return Action::Kill,
+            b'D' => return Action::Detach,
+            b'g' => hex(&pcs[0].to_le_bytes()),
+            b'p' if packet == "p0" => hex(&pcs[0].to_le_bytes()),
+            b'm' => match parse_pair(&packet[1..], ',') {
+                Some((addr, len)) => self.read(store, frame, addr, len),
+                None => ERROR.to_string(),
+            },
+            b'Z' | b'z' => self.breakpoint(store, packet),
+            _ => self
+                .handle_wasm_query(store, frame, pcs, packet)
+                .unwrap_or_default(),
+        };
+        Action::Reply(reply)
+    }
+
+    /// Handles LLDB's `qWasm*` packets, returning `None` for unknown packets.
+    ///
+    /// Only the innermost frame has its locals and operand stack available,
+    /// and globals and memory are always those of the innermost frame's
+    /// instance.
+    fn handle_wasm_query<T>(
+        &mut self,
+        store: &mut StoreContextMut<'_, T>,
+        frame: &DebugFrame,
+        pcs: &[u64],
+        packet: &str,
+    ) -> Option<String> {
+        let (query, args) = packet.split_once(':')?;
+        let args = args.split(';').collect::<Vec<_>>();
+        let arg = |i: usize| args.get(i).and_then(|a| u64::from_str_radix(a, 16).ok());
+        let reply = match query {
+            "qWasmCallStack" => pcs.iter().map(|pc| hex(&pc.to_le_bytes())).collect(),
+            "qWasmLocal" | "qWasmStackValue" | "qWasmGlobal" => {
+                let val = match (query, arg(0), arg(1)) {
+                    ("qWasmGlobal", Some(f), Some(i)) if (f as usize) < pcs.len() => {
+                        let global = u32::try_from(i)
+                            .ok()
+                            .and_then(|i| frame.global(&mut *store, i));
+                        global.map(|g| g.get(&mut *store))
+                    }
+                    ("qWasmLocal", Some(0), Some(i)) => frame.locals().get(i as usize).cloned(),
+                    ("qWasmStackValue", Some(0), Some(i)) => frame.stack().get(i as usize).cloned(),
+                    _ => None,
+                };
+                val.as_ref()
+                    .and_then(val_bytes)
+                    .map(|b| hex(&b))
+                    .unwrap_or_else(|| ERROR.to_string())
+            }
+            "qWasmMem" => match (arg(0), arg(1), arg(2)) {
+                (Some(f), Some(addr), Some(len)) if (f as usize) < pcs.len() => {
+                    match frame.memory(&mut *store, 0) {
+                        Some(memory) => read_memory(store, memory, addr, len),
+                        None => ERROR.to_string(),
+                    }
+                }
+                _ => ERROR.to_string(),
+            },
+            _ => return None,
+        };
+        Some(reply)
+    }
+
+    /// Returns the PCs of the frames on the stack, innermost first, where the
+    /// innermost frame is `frame`.
+    fn call_stack<T>(&self, store: &StoreContextMut<'_, T>, frame: &DebugFrame) -> Vec<u64> {
+        let mut pcs = vec![self.pc(frame.module(), frame.module_offset()).unwrap_or(0)];
+        let backtrace = WasmBacktrace::capture(store);
+        pcs.extend(
+            backtrace
+                .frames()
+                .iter()
+                .skip(1)
+                .filter_map(|f| self.pc(f.module(), f.module_offset()?)),
+        );
+        pcs
+    }
+
+    fn pc(&self, module: &Module, offset: usize) -> Option<u64> {
+        let index = self
+            .modules
+            .iter()
+            .position(|m| Module::same(&m.module, module))?;
+        Some(CODE_ADDRESS | (index as u64) << 32 | offset as u64)
+    }
+
+    /// Returns the module and offset within it of a code address.
+    fn code_address(&self, addr: u64) -> Option<(usize, u32)> {
+        if addr >> 62 != CODE_ADDRESS >> 62 {
+            return None;
+        }
+        let index = ((addr >> 32) & 0x3fff_ffff) as usize;
+        (index < self.modules.len()).then_some((index, addr as u32))
+    }
+
+    fn read<T>(
+        &self,
+        store: &mut StoreContextMut<'_, T>,
+        frame: &DebugFrame,
+        addr: u64,
+        len: u64,
+    ) -> String {
+        if let Some((index, offset)) = self.code_address(addr) {
+            let wasm = &self.modules[index].wasm;
+            let start = (offset as usize).min(wasm.len());
+            let end = start.saturating_add(len as usize).min(wasm.len());
+            return hex(&wasm[start..end]);
+        }
+        if addr >> 62 != 0 {
+            return ERROR.to_string();
+        }
+        match frame.memory(&mut *store, 0) {
+            Some(memory) => read_memory(store, memory, addr & 0xffff_ffff, len),
+            None => ERROR.to_string(),
+        }
+    }
+
+    fn breakpoint<T>(&mut self, store: &mut StoreContextMut<'_, T>, packet: &str) -> String {
+        // Only software breakpoints are supported.
+        let Some(args) = packet[1..].strip_prefix("0,") else {
+            return String::new();
+        };
+        let Some((index, offset)) = args
+            .split(',')
+            .next()
+            .and_then(|a| u64::from_str_radix(a, 16).ok())
+            .and_then(|addr| self.code_address(addr))
+        else {
+            return ERROR.to_string();
+        };
+        let module = &self.modules[index].module;
+        if packet.starts_with('Z') {
+            if store.add_breakpoint(module, offset as usize).is_err() {
+                return ERROR.to_string();
+            }
+            self.breakpoints.push((index, offset));
+        } else {
+            store.remove_breakpoint(module, offset as usize);
+            self.breakpoints.retain(|b| *b != (index, offset));
+        }
+        "OK".to_string()
+    }
+
+    fn libraries(&self, offset: usize, len: usize) -> String {
+        let mut xml = "<library-list>".to_string();
+        for (index, module) in self.modules.iter().enumerate() {
+            let name = if module.name.is_empty() {
+                module.module.name().unwrap_or("main")
+            } else {
+                &module.name
+            };
+            let name = name
+                .replace('&', "&amp;")
+                .replace('<', "&lt;")
+                .replace('"', "&quot;");
+            write!(
+                xml,
+                "<library name=\"{name}\"><section address=\"{:#x}\"/></library>",
+                CODE_ADDRESS | (index as u64) << 32
+            )
+            .unwrap();
+        }
+        xml.push_str("</library-list>");
+        let start = offset.min(xml.len());
+        let end = start.saturating_add(len).min(xml.len());
+        let prefix = if end == xml.len() { 'l' } else { 'm' };
+        format!("{prefix}{}", &xml[start..end])
+    }
+
+    /// Receives the next packet, returning `None` if the connection was
+    /// closed.
+    fn recv(&mut self) -> Result<Option<String>> {
+        loop {
+            // Skip acknowledgments and interrupt requests until the start of
+            // the next packet.
+            let mut skipped = Vec::new();
+            if self.reader.read_until(b'$', &mut skipped)? == 0 || skipped.last() != Some(&b'$') {
+                return Ok(None);
+            }
+            let mut packet = Vec::new();
+            self.reader.read_until(b'#', &mut packet)?;
+            if packet.pop() != Some(b'#') {
+                return Ok(None);
+            }
+            let mut checksum = [0; 2];
+            self.reader.read_exact(&mut checksum)?;
+            if self.ack {
+                let expected = std::str::from_utf8(&checksum)
+                    .ok()
+                    .and_then(|c| u8::from_str_radix(c, 16).ok());
+                if expected != Some(checksum_of(&packet)) {
+                    self.writer.write_all(b"-")?;
+                    continue;
+                }
+                self.writer.write_all(b"+")?;
+            }
+            if packet.is_empty() {
+                continue;
+            }
+            if packet == b"QStartNoAckMode" {
+                // The reply to this packet is still acknowledged.
+                self.send("OK")?;
+                self.ack = false;
+                continue;
+            }
+            return String::from_utf8(packet)
+                .map(Some)
+                .map_err(|_| anyhow!("invalid packet from debugger"));
+        }
+    }
+
+    fn send(&mut self, data: &str) -> Result<()> {
+        let mut packet = Vec::with_capacity(data.len() + 4);
+        packet.push(b'$');
+        for byte in data.bytes() {
+            if matches!(byte, b'#' | b'$' | b'}' | b'*') {
+                packet.extend_from_slice(&[b'}', byte ^ 0x20]);
+            } else {
+                packet.push(byte);
+            }
+        }
+        let checksum = checksum_of(&packet[1..]);
+        write!(packet, "#{checksum:02x}").unwrap();
+        self.writer.write_all(&packet)?;
+        if self.ack {
+            // Wait for the debugger to acknowledge the packet, resending it
+            // if it asks for that.
+            loop {
+                let mut ack = [0];
+                self.reader.read_exact(&mut ack)?;
+                match ack[0] {
+                    b'+' => break,
+                    b'-' => self.writer.write_all(&packet)?,
+                    _ => {}
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+fn stop_reply() -> String {
+    format!("T05thread:{THREAD_ID:x};")
+}
+
+fn read_memory<T>(store: &StoreContextMut<'_, T>, memory: Memory, addr: u64, len: u64) -> String {
+    let data = memory.data(store);
+    let start = usize::try_from(addr).unwrap_or(usize::MAX).min(data.len());
+    let end = start
+        .saturating_add(usize::try_from(len).unwrap_or(usize::MAX))
+        .min(data.len());
+    if start == end && len > 0 {
+        return ERROR.to_string();
+    }
+    hex(&data[start..end])
+}
+
+/// Returns the little-endian bytes of `val`, or `None` for references.
+fn val_bytes(val: &Val) -> Option<Vec<u8>> {
+    Some(match val {
+        Val::I32(i) => i.to_le_bytes().to_vec(),
+        Val::I64(i) => i.to_le_bytes().to_vec(),
+        Val::F32(f) => f.to_le_bytes().to_vec(),
+        Val::F64(f) => f.to_le_bytes().to_vec(),
+        Val::V128(v) => v.as_u128().to_le_bytes().to_vec(),
+        _ => return None,
+    })
+}
+
+fn parse_pair(s: &str, sep: char) -> Option<(u64, u64)> {
+    let (a, b) = s.split_once(sep)?;
+    Some((
+        u64::from_str_radix(a, 16).ok()?,
+        u64::from_str_radix(b, 16).ok()?,
+    ))
+}
+
+fn checksum_of(data: &[u8]) -> u8 {
+    data.iter().fold(0u8, |sum, b| sum.wrapping_add(*b))
+}
+
+fn hex(bytes: &[u8]) -> String {
+    let mut s = String::with_capacity(bytes.len() * 2);
+    for b in bytes {
+        write!(s, "{b:02x}").unwrap();
+    }
+    s
+}
@@ -21,6 +21,9 @@ use wasmtime_wasi_http::{
 #[cfg(feature = "wasi-nn")]
 use wasmtime_wasi_nn::WasiNnCtx;
 
+#[cfg(feature = "wasi-keyvalue")]
+use wasmtime_wasi_keyvalue::{WasiKeyValueCtx, WasiKeyValueView};
+
 struct Host {
     table: wasmtime::component::ResourceTable,
     ctx: WasiCtx,
@@ -30,6 +33,12 @@ struct Host {
 
     #[cfg(feature = "wasi-nn")]
     nn: Option<WasiNnCtx>,
+
+    #[cfg(feature = "wasi-keyvalue")]
+    keyvalue: Option<WasiKeyValueCtx>,
+
+    #[cfg(feature = "profiling")]
+    guest_profiler: Option<Arc<wasmtime::GuestProfiler>>,
 }
 
 impl WasiView for Host {
@@ -52,6 +61,17 @@ impl WasiHttpView for Host {
     }
 }
 
+#[cfg(feature = "wasi-keyvalue")]
+impl WasiKeyValueView for Host {
+    fn ctx(&mut self) -> &mut WasiKeyValueCtx {
+        self.keyvalue.as_mut().unwrap()
+    }
+
+    fn table(&mut self) -> &mut wasmtime::component::ResourceTable {
+        &mut self.table
+    }
+}
+
 const DEFAULT_ADDR: std::net::SocketAddr = std::net::SocketAddr::new(
     std::net::IpAddr::V4(std::net::Ipv4Addr::new(0, 0, 0, 0)),
     8080,
@@ -87,7 +107,10 @@ impl ServeCommand {
         }
 
         if let Some(Profile::Guest { .. }) = &self.run.profile {
-            bail!("Cannot use the guest profiler with components");
+            #[cfg(not(feature = "profiling"))]
+            {
+                bail!("support for profiling disabled at compile time");
+            }
         }
 
         if self.run.common.wasi.nn == Some(true) {
@@ -97,6 +120,17 @@ impl ServeCommand {
             }
         }
 
+        if self.run.common.wasi.keyvalue == Some(true) {
+            #[cfg(not(feature = "wasi-keyvalue"))]
+            {
+                bail!(
+                    "Cannot enable wasi-keyvalue when the binary is not compiled with this feature."
+                );
+            }
+        } else if self.run.common.wasi.keyvalue_dir.is_some() {
+            bail!("`-S keyvalue-dir` requires `-S keyvalue`");
+        }
+
         if self.run.common.wasi.threads == Some(true) {
             bail!("wasi-threads does not support components yet")
         }
@@ -115,22 +149,18 @@ impl ServeCommand {
             .enable_io()
             .build()?;
 
-        runtime.block_on(async move {
-            tokio::select! {
-                _ = tokio::signal::ctrl_c() => {
-                    Ok::<_, anyhow::Error>(())
-                }
-
-                res = self.serve() => {
-                    res
-                }
-            }
-        })?;
+        runtime.block_on(self.serve())?;
 
         Ok(())
     }
 
-    fn new_store(&self, engine: &Engine, req_id: u64) -> Result<Store<Host>> {
+    fn new_store(
+        &self,
+        engine: &Engine,
+        req_id: u64,
+        #[cfg(feature = "wasi-keyvalue")] keyvalue: Option<WasiKeyValueCtx>,
+        #[cfg(feature = "profiling")] guest_profiler: Option<Arc<wasmtime::GuestProfiler>>,
+    ) -> Result<Store<Host>> {
         let mut builder = WasiCtxBuilder::new();
 
         builder.envs(&[("REQUEST_ID", req_id.to_string())]);
@@ -154,6 +184,12 @@ impl ServeCommand {
 
             #[cfg(feature = "wasi-nn")]
             nn: None,
+
+            #[cfg(feature = "wasi-keyvalue")]
+            keyvalue,
+
+            #[cfg(feature = "profiling")]
+            guest_profiler,
         };
 
         if self.run.common.wasi.nn == Some(true) {
@@ -178,6 +214,15 @@ impl ServeCommand {
             store.set_epoch_deadline(1);
         }
 
+        #[cfg(feature = "profiling")]
+        if let Some(Profile::Guest { interval, .. }) = &self.run.profile {
+            crate::common::setup_guest_sampling(
+                &mut store,
+                |host| host.guest_profiler.as_deref(),
+                self.run.guest_profiler_timeout(*interval),
+            );
+        }
+
         store.data_mut().limits = self.run.store_limits();
         store.limiter(|t| &mut t.limits);
 
@@ -218,6 +263,17 @@ impl ServeCommand {
             }
         }
 
+        if self.run.common.wasi.keyvalue == Some(true) {
+            #[cfg(not(feature = "wasi-keyvalue"))]
+            {
+                bail!("support for wasi-keyvalue was disabled at compile time");
+            }
+            #[cfg(feature = "wasi-keyvalue")]
+            {
+                wasmtime_wasi_keyvalue::add_to_linker(linker)?;
+            }
+        }
+
         if self.run.common.wasi.threads == Some(true) {
             bail!("support for wasi-threads is not available with components");
         }
@@ -244,10 +300,9 @@ impl ServeCommand {
             Some(Profile::Native(s)) => {
                 config.profiler(s);
             }
-
-            // We bail early in `execute` if the guest profiler is configured.
-            Some(Profile::Guest { .. }) => unreachable!(),
-
+            Some(Profile::Guest { .. }) => {
+                config.epoch_interruption(true);
+            }
             None => {}
         }
 
@@ -267,18 +322,56 @@ impl ServeCommand {
 
         eprintln!("Serving HTTP on http://{}/", listener.local_addr()?);
 
-        let _epoch_thread = if let Some(timeout) = self.run.common.wasm.timeout {
-            Some(EpochThread::spawn(timeout, engine.clone()))
-        } else {
-            None
+        // Every store samples into the same guest profiler, which is written
+        // out once the server shuts down.
+        #[cfg(feature = "profiling")]
+        let guest_profiler = match &self.run.profile {
+            Some(Profile::Guest { path, interval }) => {
+                let name = self.component.to_str().unwrap_or("<main component>");
+                let profiler =
+                    wasmtime::GuestProfiler::new_component(name, *interval, component, Vec::new());
+                Some((Arc::new(profiler), path.clone()))
+            }
+            _ => None,
+        };
+
+        let _epoch_thread = match (&self.run.profile, self.run.common.wasm.timeout) {
+            (Some(Profile::Guest { interval, .. }), _) => {
+                Some(EpochThread::spawn(*interval, engine.clone()))
+            }
+            (_, Some(timeout)) => Some(EpochThread::spawn(timeout, engine.clone())),
+            _ => None,
         };
 
         log::info!("Listening on {}", self.addr);
 
-        let handler = ProxyHandler::new(self, engine, instance);
+        // All requests share the same buckets.
+        #[cfg(feature = "wasi-keyvalue")]
+        let keyvalue = match self.run.common.wasi.keyvalue {
+            Some(true) => Some(self.run.wasi_keyvalue_ctx()?),
+            _ => None,
+        };
+
+        let handler = ProxyHandler::new(
+            self,
+            engine,
+            instance,
+            #[cfg(feature = "wasi-keyvalue")]
+            keyvalue,
+            #[cfg(feature = "profiling")]
+            guest_profiler
+                .as_ref()
+                .map(|(profiler, _)| profiler.clone()),
+        );
+
+        let shutdown = tokio::signal::ctrl_c();
+        tokio::pin!(shutdown);
 
         loop {
-            let (stream, _) = listener.accept().await?;
+            let (stream, _) = tokio::select! {
+                _ = &mut shutdown => break,
+                res = listener.accept() => res?,
+            };
             let stream = TokioIo::new(stream);
             let h = handler.clone();
             tokio::task::spawn(async move {
@@ -291,6 +384,13 @@ impl ServeCommand {
                 }
             });
         }
+
+        #[cfg(feature = "profiling")]
+        if let Some((profiler, path)) = guest_profiler {
+            crate::common::write_guest_profile(&profiler, &path);
+        }
+
+        Ok(())
     }
 }
 
@@ -331,6 +431,10 @@ struct ProxyHandlerInner {
     engine: Engine,
     instance_pre: InstancePre<Host>,
     next_id: AtomicU64,
+    #[cfg(feature = "wasi-keyvalue")]
+    keyvalue: Option<WasiKeyValueCtx>,
+    #[cfg(feature = "profiling")]
+    guest_profiler: Option<Arc<wasmtime::GuestProfiler>>,
 }
 
 impl ProxyHandlerInner {
@@ -343,12 +447,22 @@ impl ProxyHandlerInner {
 struct ProxyHandler(Arc<ProxyHandlerInner>);
 
 impl ProxyHandler {
-    fn new(cmd: ServeCommand, engine: Engine, instance_pre: InstancePre<Host>) -> Self {
+    fn new(
+        cmd: ServeCommand,
+        engine: Engine,
+        instance_pre: InstancePre<Host>,
+        #[cfg(feature = "wasi-keyvalue")] keyvalue: Option<WasiKeyValueCtx>,
+        #[cfg(feature = "profiling")] guest_profiler: Option<Arc<wasmtime::GuestProfiler>>,
+    ) -> Self {
         Self(Arc::new(ProxyHandlerInner {
             cmd,
             engine,
             instance_pre,
             next_id: AtomicU64::from(0),
+            #[cfg(feature = "wasi-keyvalue")]
+            keyvalue,
+            #[cfg(feature = "profiling")]
+            guest_profiler,
         }))
     }
 }
@@ -408,7 +522,14 @@ impl hyper::service::Service<Request> for ProxyHandler {
                 req.uri()
             );
 
-            let mut store = inner.cmd.new_store(&inner.engine, req_id)?;
+            let mut store = inner.cmd.new_store(
+                &inner.engine,
+                req_id,
+                #[cfg(feature = "wasi-keyvalue")]
+                inner.keyvalue.clone(),
+                #[cfg(feature = "profiling")]
+                inner.guest_profiler.clone(),
+            )?;
 
             let req = store.data_mut().new_incoming_request(req)?;
             let out = store.data_mut().new_response_outparam(sender)?;
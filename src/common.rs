@@ -8,6 +8,8 @@ use wasmtime_cli_flags::{opt::WasmtimeOptionValue, CommonOptions};
 
 #[cfg(feature = "component-model")]
 use wasmtime::component::Component;
+#[cfg(feature = "profiling")]
+use wasmtime::{GuestProfiler, Store, UpdateDeadline};
 
 pub enum RunTarget {
     Core(Module),
@@ -63,7 +65,9 @@ pub struct RunCommon {
     ///
     /// where `path` is where to write the profile and `interval` is the
     /// duration between samples. When used with `--wasm-timeout` the timeout
-    /// will be rounded up to the nearest multiple of this interval.
+    /// will be rounded up to the nearest multiple of this interval. A `path`
+    /// ending in `.pb` or `.pprof` writes the profile in the pprof format
+    /// instead, which can be viewed with `go tool pprof`.
     #[arg(
         long,
         value_name = "STRATEGY",
@@ -105,6 +109,28 @@ impl RunCommon {
         }
     }
 
+    /// Returns the number of guest profiler samples, taken every `interval`,
+    /// after which the guest times out, if `--wasm-timeout` was given.
+    #[cfg(feature = "profiling")]
+    pub fn guest_profiler_timeout(&self, interval: Duration) -> Option<u64> {
+        let timeout = self.common.wasm.timeout?;
+        let timeout = (timeout.as_secs_f64() / interval.as_secs_f64()).ceil() as u64;
+        assert!(timeout > 0);
+        Some(timeout)
+    }
+
+    /// Creates the context for `-S keyvalue`, backed by the directory given
+    /// with `-S keyvalue-dir` or otherwise by memory.
+    #[cfg(feature = "wasi-keyvalue")]
+    pub fn wasi_keyvalue_ctx(&self) -> Result<wasmtime_wasi_keyvalue::WasiKeyValueCtx> {
+        use wasmtime_wasi_keyvalue::{FileStore, MemoryStore, WasiKeyValueCtx};
+
+        Ok(match &self.common.wasi.keyvalue_dir {
+            Some(dir) => WasiKeyValueCtx::new(FileStore::new(dir)?),
+            None => WasiKeyValueCtx::new(MemoryStore::new()),
+        })
+    }
+
     #[cfg(feature = "component-model")]
     fn ensure_allow_components(&self) -> Result<()> {
         if self.common.wasm.component_model == Some(false) {
@@ -246,3 +272,62 @@ impl Profile {
         }
     }
 }
+
+/// Samples the guest profiler returned by `profiler` on every epoch tick of
+/// `store` and records the time spent in calls to the host, failing with a
+/// timeout after `timeout` samples if given.
+#[cfg(feature = "profiling")]
+pub fn setup_guest_sampling<T: 'static>(
+    store: &mut Store<T>,
+    profiler: fn(&T) -> Option<&GuestProfiler>,
+    mut timeout: Option<u64>,
+) {
+    store.epoch_deadline_callback(move |store| {
+        if let Some(profiler) = profiler(store.data()) {
+            profiler.sample(&store);
+        }
+        if let Some(timeout) = &mut timeout {
+            *timeout -= 1;
+            if *timeout == 0 {
+                bail!("timeout exceeded");
+            }
+        }
+        Ok(UpdateDeadline::Continue(1))
+    });
+    store.call_hook(move |data, kind| {
+        if let Some(profiler) = profiler(data) {
+            profiler.call_hook(kind);
+        }
+        Ok(())
+    });
+    store.set_epoch_deadline(1);
+}
+
+/// Writes the profile collected by `profiler` to `path`, in the pprof format if
+/// `path` ends in `.pb` or `.pprof` and in the Firefox profiler's format
+/// otherwise.
+#[cfg(feature = "profiling")]
+pub fn write_guest_profile(profiler: &GuestProfiler, path: &str) {
+    let pprof = path.ends_with(".pb") || path.ends_with(".pprof");
+    if let Err(e) = std::fs::File::create(path)
+        .map_err(anyhow::Error::new)
+        .and_then(|output| {
+            let output = std::io::BufWriter::new(output);
+            if pprof {
+                profiler.finish_pprof(output)
+            } else {
+                profiler.finish(output)
+            }
+        })
+    {
+        eprintln!("failed writing profile at {path}: {e:#}");
+    } else {
+        eprintln!();
+        eprintln!("Profile written to: {path}");
+        if pprof {
+            eprintln!("View this profile with `go tool pprof {path}`.");
+        } else {
+            eprintln!("View this profile at https://profiler.firefox.com/.");
+        }
+    }
+}
@@ -825,7 +825,12 @@ impl RunCommand {
             dirs,
             vars,
             invoke,
+            invoke_format: crate::commands::InvokeFormat::Wave,
             preloads,
+            record: None,
+            replay: None,
+            debug_server: None,
+            coverage: None,
             module_and_args,
         }
     }
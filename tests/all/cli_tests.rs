@@ -865,6 +865,114 @@ fn run_precompiled_component() -> Result<()> {
     Ok(())
 }
 
+#[test]
+#[cfg_attr(not(feature = "component-model"), ignore)]
+fn invoke_component_func() -> Result<()> {
+    let path = "tests/all/cli_tests/component-invoke.wat";
+    let stdout = run_wasmtime(&[
+        "run",
+        "-Wcomponent-model",
+        "--invoke",
+        "add",
+        path,
+        "1",
+        "2",
+    ])?;
+    assert_eq!(stdout, "3\n");
+
+    // Functions of exported instances, with WAVE or JSON values.
+    let stdout = run_wasmtime(&[
+        "run",
+        "-Wcomponent-model",
+        "--invoke",
+        "geo#mirror",
+        path,
+        "{x: 1, y: -2}",
+    ])?;
+    assert_eq!(stdout, "{x: -2, y: 1}\n");
+    let stdout = run_wasmtime(&[
+        "run",
+        "-Wcomponent-model",
+        "--invoke-format",
+        "json",
+        "--invoke",
+        "geo#mirror",
+        path,
+        r#"{"x":1,"y":-2}"#,
+    ])?;
+    assert_eq!(stdout, "{\"x\":-2,\"y\":1}\n");
+
+    let output = get_wasmtime_command()?
+        .args(&[
+            "run",
+            "-Wcomponent-model",
+            "--invoke",
+            "add",
+            path,
+            "1",
+            "x",
+        ])
+        .output()?;
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("invalid argument 1 for `add`"),
+        "bad stderr: {stderr}"
+    );
+    Ok(())
+}
+
+#[test]
+#[cfg_attr(
+    not(all(feature = "component-model", feature = "wasi-keyvalue")),
+    ignore
+)]
+fn run_wasi_keyvalue() -> Result<()> {
+    let path = "tests/all/cli_tests/component-keyvalue.wat";
+
+    // Buckets only live as long as the run by default...
+    for _ in 0..2 {
+        let stdout = run_wasmtime(&["run", "-Skeyvalue", "--invoke", "run", path])?;
+        assert_eq!(stdout, "1\n");
+    }
+
+    // ... unless they're persisted in a directory.
+    let dir = tempfile::tempdir()?;
+    let keyvalue = format!("-Skeyvalue,keyvalue-dir={}", dir.path().display());
+    for expected in ["1\n", "2\n"] {
+        let stdout = run_wasmtime(&["run", &keyvalue, "--invoke", "run", path])?;
+        assert_eq!(stdout, expected);
+    }
+
+    let output = get_wasmtime_command()?
+        .args(&["run", "--invoke", "run", path])
+        .output()?;
+    assert!(!output.status.success());
+    Ok(())
+}
+
+#[test]
+#[cfg_attr(not(feature = "wasi-nn-onnx"), ignore)]
+fn run_wasi_nn_onnx() -> Result<()> {
+    let stdout = run_wasmtime(&[
+        "run",
+        "-Snn,nn-graph=onnx::crates/wasi-nn/tests/fixtures/linear",
+        "tests/all/cli_tests/nn-onnx-linear.wat",
+    ])?;
+    assert_eq!(stdout, "ok\n");
+
+    // Graphs aren't loadable without a backend for their encoding.
+    let output = get_wasmtime_command()?
+        .args(&[
+            "run",
+            "-Snn,nn-graph=tensorflow::crates/wasi-nn/tests/fixtures/linear",
+            "tests/all/cli_tests/nn-onnx-linear.wat",
+        ])
+        .output()?;
+    assert!(!output.status.success());
+    Ok(())
+}
+
 #[test]
 fn memory_growth_failure() -> Result<()> {
     let output = get_wasmtime_command()?
@@ -1588,3 +1696,159 @@ mod test_programs {
         Ok(())
     }
 }
+
+#[test]
+fn debug_server() -> Result<()> {
+    use std::io::{BufRead, BufReader, Read};
+    use std::net::TcpStream;
+
+    fn packet(data: &str) -> String {
+        let checksum = data.bytes().fold(0u8, |sum, b| sum.wrapping_add(b));
+        format!("${data}#{checksum:02x}")
+    }
+
+    // A minimal client of the GDB remote protocol after acknowledgments are
+    // disabled.
+    fn request(stream: &mut TcpStream, data: &str) -> Result<String> {
+        stream.write_all(packet(data).as_bytes())?;
+        let mut reply = Vec::new();
+        let mut byte = [0];
+        loop {
+            stream.read_exact(&mut byte)?;
+            match byte[0] {
+                b'$' => reply.clear(),
+                b'#' => break,
+                b => reply.push(b),
+            }
+        }
+        stream.read_exact(&mut [0; 2])?;
+        Ok(String::from_utf8(reply)?)
+    }
+
+    let wat = "tests/all/cli_tests/debug-server.wat";
+    let wasm = wat::parse_file(wat)?;
+    let mut offsets = Vec::new();
+    for payload in wasmparser::Parser::new(0).parse_all(&wasm) {
+        if let wasmparser::Payload::CodeSectionEntry(body) = payload? {
+            let mut ops = body.get_operators_reader()?;
+            offsets.push(ops.original_position() as u64);
+            while !ops.eof() {
+                if let wasmparser::Operator::I64Add = ops.read()? {
+                    offsets.push(ops.original_position() as u64 - 1);
+                }
+            }
+        }
+    }
+    let pc = |offset: u64| format!("{:016x}", (0x4000_0000_0000_0000 | offset).swap_bytes());
+    let (add, start) = (offsets[1], offsets[2]);
+
+    let mut child = get_wasmtime_command()?
+        .args(&["run", "-Ccache=n", "--debug-server", "0", wat])
+        .stderr(Stdio::piped())
+        .spawn()?;
+    let mut stderr = BufReader::new(child.stderr.take().unwrap());
+    let mut line = String::new();
+    stderr.read_line(&mut line)?;
+    let addr = line
+        .strip_prefix("Debug server listening on ")
+        .and_then(|l| l.split(',').next())
+        .unwrap_or_else(|| panic!("unexpected output: {line}"));
+    let mut stream = TcpStream::connect(addr)?;
+
+    stream.write_all(packet("QStartNoAckMode").as_bytes())?;
+    let mut reply = [0; 7];
+    stream.read_exact(&mut reply)?;
+    assert_eq!(&reply, format!("+{}", packet("OK")).as_bytes());
+    stream.write_all(b"+")?;
+
+    let supported = request(&mut stream, "qSupported:xmlRegisters=i386")?;
+    assert!(supported.contains("qXfer:libraries:read+"), "{supported}");
+    assert_eq!(request(&mut stream, "?")?, "T05thread:1;");
+    assert_eq!(
+        request(&mut stream, "qXfer:libraries:read::0,1000")?,
+        "l<library-list><library name=\"main\">\
+         <section address=\"0x4000000000000000\"/></library></library-list>"
+    );
+    assert_eq!(request(&mut stream, "m4000000000000000,4")?, "0061736d");
+
+    // The guest stops before its first instruction.
+    assert_eq!(request(&mut stream, "qWasmCallStack:1")?, pc(start));
+
+    // Continue to a breakpoint in `$add`.
+    assert_eq!(
+        request(
+            &mut stream,
+            &format!("Z0,{:x},1", 0x4000_0000_0000_0000 | add)
+        )?,
+        "OK"
+    );
+    assert_eq!(request(&mut stream, "c")?, "T05thread:1;");
+    let stack = request(&mut stream, "qWasmCallStack:1")?;
+    assert_eq!(stack.len(), 32);
+    assert_eq!(&stack[..16], pc(add));
+    assert_eq!(request(&mut stream, "p0")?, pc(add));
+    assert_eq!(request(&mut stream, "qWasmLocal:0;0")?, "02000000");
+    assert_eq!(request(&mut stream, "qWasmLocal:0;2")?, "E03");
+    assert_eq!(
+        request(&mut stream, "qWasmStackValue:0;0")?,
+        "0200000000000000"
+    );
+    assert_eq!(
+        request(&mut stream, "qWasmStackValue:0;1")?,
+        "6400000000000000"
+    );
+    assert_eq!(request(&mut stream, "qWasmGlobal:0;0")?, "07000000");
+    assert_eq!(request(&mut stream, "qWasmMem:0;10;4")?, "2a000000");
+    assert_eq!(request(&mut stream, "m10,4")?, "2a000000");
+
+    // Step over the addition and then run to completion.
+    assert_eq!(request(&mut stream, "s")?, "T05thread:1;");
+    assert_eq!(
+        request(&mut stream, "qWasmStackValue:0;0")?,
+        "6600000000000000"
+    );
+    assert_eq!(
+        request(
+            &mut stream,
+            &format!("z0,{:x},1", 0x4000_0000_0000_0000 | add)
+        )?,
+        "OK"
+    );
+    assert_eq!(request(&mut stream, "c")?, "W00");
+    assert!(child.wait()?.success());
+    Ok(())
+}
+
+#[test]
+fn coverage() -> Result<()> {
+    let report = NamedTempFile::new()?;
+    let report_path = report.path().to_str().unwrap();
+    run_wasmtime(&[
+        "run",
+        "-Ccache=n",
+        "--coverage",
+        report_path,
+        "--invoke",
+        "fib",
+        "tests/all/debug/testsuite/fib-wasm.wasm",
+        "5",
+    ])?;
+    let report = std::fs::read_to_string(report_path)?;
+    // The loop condition on line 10 is checked once more than the loop body
+    // on line 11 runs.
+    for line in [
+        "SF:./fib-wasm.c",
+        "FN:8,fib",
+        "FNDA:1,fib",
+        "DA:10,6",
+        "DA:11,5",
+        "DA:15,1",
+        "end_of_record",
+    ] {
+        assert!(
+            report.lines().any(|l| l == line),
+            "missing {line:?}:\n{report}"
+        );
+    }
+    Ok(())
+}
@@ -0,0 +1,27 @@
+(component
+  (core module $m
+    (memory (export "mem") 1)
+    (func (export "add") (param i32 i32) (result i32)
+      local.get 0
+      local.get 1
+      i32.add)
+    ;; Returns the record with its fields swapped.
+    (func (export "mirror") (param i32 i32) (result i32)
+      (i32.store (i32.const 8) (local.get 1))
+      (i32.store (i32.const 12) (local.get 0))
+      i32.const 8)
+  )
+  (core instance $i (instantiate $m))
+
+  (type $point' (record (field "x" s32) (field "y" s32)))
+  (export $point "point" (type $point'))
+
+  (func $add (param "a" u32) (param "b" u32) (result u32)
+    (canon lift (core func $i "add")))
+  (func $mirror (param "p" $point) (result $point)
+    (canon lift (core func $i "mirror") (memory $i "mem")))
+
+  (export "add" (func $add))
+  (instance (export "geo")
+    (export "mirror" (func $mirror)))
+)
@@ -0,0 +1,57 @@
+;; Increments the `counter` key of the `kv` bucket and returns its new value.
+(component $C
+  (import "wasi:keyvalue/store@0.2.0-draft2" (instance $store
+    (export $bucket "bucket" (type (sub resource)))
+    (type $error' (variant (case "no-such-store") (case "access-denied") (case "other" string)))
+    (export $error "error" (type (eq $error')))
+    (export "open" (func (param "identifier" string) (result (result (own $bucket) (error $error)))))
+  ))
+  (alias export $store "bucket" (type $bucket))
+  (alias export $store "error" (type $error))
+  (import "wasi:keyvalue/atomics@0.2.0-draft2" (instance $atomics
+    (alias outer $C $bucket (type $bucket))
+    (alias outer $C $error (type $error))
+    (export "increment" (func (param "bucket" (borrow $bucket)) (param "key" string) (param "delta" s64) (result (result s64 (error $error)))))
+  ))
+
+  (core module $libc
+    (memory (export "mem") 1)
+    (data (i32.const 0) "kvcounter")
+    (global $next (mut i32) (i32.const 1024))
+    (func (export "realloc") (param i32 i32 i32 i32) (result i32)
+      (local $ret i32)
+      (local.set $ret
+        (i32.and
+          (i32.add (global.get $next) (i32.sub (local.get 2) (i32.const 1)))
+          (i32.sub (i32.const 0) (local.get 2))))
+      (global.set $next (i32.add (local.get $ret) (local.get 3)))
+      local.get $ret)
+  )
+  (core instance $libc (instantiate $libc))
+
+  (core func $open (canon lower (func $store "open")
+    (memory $libc "mem") (realloc (func $libc "realloc"))))
+  (core func $increment (canon lower (func $atomics "increment")
+    (memory $libc "mem") (realloc (func $libc "realloc"))))
+
+  (core module $m
+    (import "libc" "mem" (memory 1))
+    (import "store" "open" (func $open (param i32 i32 i32)))
+    (import "atomics" "increment" (func $increment (param i32 i32 i32 i64 i32)))
+    (func (export "run") (result i64)
+      (local $bucket i32)
+      (call $open (i32.const 0) (i32.const 2) (i32.const 16))
+      (if (i32.load8_u (i32.const 16)) (then unreachable))
+      (local.set $bucket (i32.load (i32.const 20)))
+      (call $increment (local.get $bucket) (i32.const 2) (i32.const 7) (i64.const 1) (i32.const 32))
+      (if (i32.load8_u (i32.const 32)) (then unreachable))
+      (i64.load (i32.const 40)))
+  )
+  (core instance $i (instantiate $m
+    (with "libc" (instance $libc))
+    (with "store" (instance (export "open" (func $open))))
+    (with "atomics" (instance (export "increment" (func $increment))))
+  ))
+
+  (func (export "run") (result s64) (canon lift (core func $i "run")))
+)
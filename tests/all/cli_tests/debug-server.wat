@@ -0,0 +1,11 @@
+(module
+  (memory (export "memory") 1)
+  (global $g (mut i32) (i32.const 7))
+  (func $add (param i32 i64) (result i64)
+    (i64.add
+      (i64.extend_i32_u (local.get 0))
+      (local.get 1)))
+  (func (export "_start")
+    (i32.store (i32.const 16) (i32.const 42))
+    (drop (call $add (i32.const 2) (i64.const 100))))
+)
@@ -0,0 +1,53 @@
+;; Runs the `linear` ONNX fixture from `crates/wasi-nn/tests/fixtures`, which
+;; must be preloaded under that name, and prints "ok" if its output is
+;; `[4.5, 5.5]` for the input `[1, 2, 3, 4]`.
+(module
+  (import "wasi_ephemeral_nn" "load_by_name"
+    (func $load_by_name (param i32 i32 i32) (result i32)))
+  (import "wasi_ephemeral_nn" "init_execution_context"
+    (func $init_execution_context (param i32 i32) (result i32)))
+  (import "wasi_ephemeral_nn" "set_input"
+    (func $set_input (param i32 i32 i32) (result i32)))
+  (import "wasi_ephemeral_nn" "compute"
+    (func $compute (param i32) (result i32)))
+  (import "wasi_ephemeral_nn" "get_output"
+    (func $get_output (param i32 i32 i32 i32 i32) (result i32)))
+  (import "wasi_snapshot_preview1" "fd_write"
+    (func $fd_write (param i32 i32 i32 i32) (result i32)))
+
+  (memory (export "memory") 1)
+
+  ;; The graph name.
+  (data (i32.const 0) "linear")
+  ;; Tensor dimensions: `[1, 4]`.
+  (data (i32.const 32) "\01\00\00\00\04\00\00\00")
+  ;; Tensor data: `[1.0, 2.0, 3.0, 4.0]` as little-endian `f32`s.
+  (data (i32.const 48) "\00\00\80\3f\00\00\00\40\00\00\40\40\00\00\80\40")
+  ;; The tensor: dimensions pointer and length, type (`f32`), and data
+  ;; pointer and length.
+  (data (i32.const 64) "\20\00\00\00\02\00\00\00\01\00\00\00\30\00\00\00\10\00\00\00")
+  ;; An iovec for "ok\n".
+  (data (i32.const 112) "\80\00\00\00\03\00\00\00")
+  (data (i32.const 128) "ok\n")
+
+  (func $check (param i32)
+    local.get 0
+    if unreachable end)
+
+  (func (export "_start")
+    ;; graph = load_by_name("linear") -> 16
+    (call $check (call $load_by_name (i32.const 0) (i32.const 6) (i32.const 16)))
+    ;; context = init_execution_context(graph) -> 20
+    (call $check (call $init_execution_context (i32.load (i32.const 16)) (i32.const 20)))
+    (call $check (call $set_input (i32.load (i32.const 20)) (i32.const 0) (i32.const 64)))
+    (call $check (call $compute (i32.load (i32.const 20))))
+    ;; Read 8 bytes of output into 96, with the written size at 24.
+    (call $check
+      (call $get_output (i32.load (i32.const 20)) (i32.const 0) (i32.const 96) (i32.const 8) (i32.const 24)))
+
+    (if (i32.ne (i32.load (i32.const 24)) (i32.const 8)) (then unreachable))
+    (if (f32.ne (f32.load (i32.const 96)) (f32.const 4.5)) (then unreachable))
+    (if (f32.ne (f32.load (i32.const 100)) (f32.const 5.5)) (then unreachable))
+
+    (call $check (call $fd_write (i32.const 1) (i32.const 112) (i32.const 1) (i32.const 120))))
+)
@@ -0,0 +1,227 @@
+#![cfg(not(miri))]
+
+use anyhow::Result;
+use wasmtime::component::*;
+use wasmtime::Store;
+
+/// A library component which exports a resource along with functions that
+/// use it from the `test:lib/counters` instance.
+const LIBRARY: &str = r#"
+    (component
+        (core module $m
+            (global $drops (mut i32) i32.const 0)
+            (global $last-drop (mut i32) i32.const 0)
+
+            (func (export "dtor") (param i32)
+                (global.set $drops (i32.add (global.get $drops) (i32.const 1)))
+                (global.set $last-drop (local.get 0)))
+            (func (export "drops") (result i32) global.get $drops)
+            (func (export "last-drop") (result i32) global.get $last-drop)
+            ;; Borrows are passed as their representation to the instance
+            ;; which defines the resource.
+            (func (export "get") (param i32) (result i32) local.get 0)
+            (func (export "add") (param i32 i32) (result i32)
+                (i32.add (local.get 0) (local.get 1)))
+        )
+        (core instance $i (instantiate $m))
+
+        (type $counter (resource (rep i32) (dtor (func $i "dtor"))))
+        (core func $new (canon resource.new $counter))
+
+        (func $ctor (param "value" u32) (result (own $counter))
+            (canon lift (core func $new)))
+        (func $get (param "self" (borrow $counter)) (result u32)
+            (canon lift (core func $i "get")))
+        (func $add (param "a" u32) (param "b" u32) (result u32)
+            (canon lift (core func $i "add")))
+
+        (component $shim
+            (import "counter" (type $c (sub resource)))
+            (import "ctor" (func $ctor (param "value" u32) (result (own $c))))
+            (import "get" (func $get (param "self" (borrow $c)) (result u32)))
+            (import "add" (func $add (param "a" u32) (param "b" u32) (result u32)))
+            (export $c' "counter" (type $c))
+            (export "[constructor]counter" (func $ctor)
+                (func (param "value" u32) (result (own $c'))))
+            (export "[method]counter.get" (func $get)
+                (func (param "self" (borrow $c')) (result u32)))
+            (export "add" (func $add))
+        )
+        (instance $counters (instantiate $shim
+            (with "counter" (type $counter))
+            (with "ctor" (func $ctor))
+            (with "get" (func $get))
+            (with "add" (func $add))
+        ))
+        (export "test:lib/counters" (instance $counters))
+
+        (func (export "drops") (result u32)
+            (canon lift (core func $i "drops")))
+        (func (export "last-drop") (result u32)
+            (canon lift (core func $i "last-drop")))
+    )
+"#;
+
+/// A component which imports `test:lib/counters` from `LIBRARY`.
+const USER: &str = r#"
+    (component
+        (import "test:lib/counters" (instance $lib
+            (export $c "counter" (type (sub resource)))
+            (export "[constructor]counter" (func (param "value" u32) (result (own $c))))
+            (export "[method]counter.get" (func (param "self" (borrow $c)) (result u32)))
+            (export "add" (func (param "a" u32) (param "b" u32) (result u32)))
+        ))
+        (alias export $lib "counter" (type $counter))
+
+        (core func $ctor (canon lower (func $lib "[constructor]counter")))
+        (core func $get (canon lower (func $lib "[method]counter.get")))
+        (core func $add (canon lower (func $lib "add")))
+        (core func $drop (canon resource.drop $counter))
+
+        (core module $m
+            (import "" "ctor" (func $ctor (param i32) (result i32)))
+            (import "" "get" (func $get (param i32) (result i32)))
+            (import "" "add" (func $add (param i32 i32) (result i32)))
+            (import "" "drop" (func $drop (param i32)))
+
+            ;; Creates a counter, reads it back through a borrow and then
+            ;; drops it.
+            (func (export "run") (param i32) (result i32)
+                (local $h i32)
+                (local.set $h (call $ctor (local.get 0)))
+                (call $add (call $get (local.get $h)) (i32.const 1))
+                (call $drop (local.get $h)))
+            (func (export "make") (param i32) (result i32)
+                (call $ctor (local.get 0)))
+        )
+        (core instance $i (instantiate $m
+            (with "" (instance
+                (export "ctor" (func $ctor))
+                (export "get" (func $get))
+                (export "add" (func $add))
+                (export "drop" (func $drop))
+            ))
+        ))
+
+        (func (export "run") (param "value" u32) (result u32)
+            (canon lift (core func $i "run")))
+        (func (export "make") (param "value" u32) (result (own $counter))
+            (canon lift (core func $i "make")))
+    )
+"#;
+
+#[test]
+fn link_instances() -> Result<()> {
+    let engine = super::engine();
+    let library = Component::new(&engine, LIBRARY)?;
+    let user = Component::new(&engine, USER)?;
+
+    let mut store = Store::new(&engine, ());
+    let mut linker = Linker::new(&engine);
+    let lib = linker.instantiate(&mut store, &library)?;
+    linker.root().instance_exports(&mut store, &lib)?;
+
+    let drops = lib.get_typed_func::<(), (u32,)>(&mut store, "drops")?;
+    let last_drop = lib.get_typed_func::<(), (u32,)>(&mut store, "last-drop")?;
+    let mut drops = |store: &mut Store<()>| -> Result<(u32, u32)> {
+        let (n,) = drops.call(&mut *store, ())?;
+        drops.post_return(&mut *store)?;
+        let (last,) = last_drop.call(&mut *store, ())?;
+        last_drop.post_return(&mut *store)?;
+        Ok((n, last))
+    };
+
+    // Resources of the library can be created, borrowed and dropped by the
+    // user, which runs the library's destructor.
+    let i = linker.instantiate(&mut store, &user)?;
+    let run = i.get_typed_func::<(u32,), (u32,)>(&mut store, "run")?;
+    assert_eq!(run.call(&mut store, (41,))?, (42,));
+    run.post_return(&mut store)?;
+    assert_eq!(drops(&mut store)?, (1, 41));
+
+    // The resource type seen by the user is the library's.
+    let make = i.get_typed_func::<(u32,), (ResourceAny,)>(&mut store, "make")?;
+    let (counter,) = make.call(&mut store, (7,))?;
+    make.post_return(&mut store)?;
+    let ty = lib
+        .exports(&mut store)
+        .instance("test:lib/counters")
+        .unwrap()
+        .resource("counter")
+        .unwrap();
+    assert_eq!(counter.ty(), ty);
+    assert_eq!(drops(&mut store)?, (1, 41));
+    counter.resource_drop(&mut store)?;
+    assert_eq!(drops(&mut store)?, (2, 7));
+
+    // Several components can share the same library instance.
+    let i = linker.instantiate(&mut store, &user)?;
+    let run = i.get_typed_func::<(u32,), (u32,)>(&mut store, "run")?;
+    assert_eq!(run.call(&mut store, (1,))?, (2,));
+    run.post_return(&mut store)?;
+    assert_eq!(drops(&mut store)?, (3, 1));
+
+    Ok(())
+}
+
+#[test]
+fn link_instances_type_mismatch() -> Result<()> {
+    let engine = super::engine();
+    let library = Component::new(&engine, LIBRARY)?;
+    let user = Component::new(
+        &engine,
+        r#"
+            (component
+                (import "test:lib/counters" (instance
+                    (export "add" (func (param "a" u64) (param "b" u32) (result u32)))
+                ))
+            )
+        "#,
+    )?;
+
+    let mut store = Store::new(&engine, ());
+    let mut linker = Linker::new(&engine);
+    let lib = linker.instantiate(&mut store, &library)?;
+    linker.root().instance_exports(&mut store, &lib)?;
+    let err = linker.instantiate(&mut store, &user).err().unwrap();
+    assert!(
+        format!("{err:?}").contains("function type mismatch"),
+        "{err:?}"
+    );
+
+    // Names can't be defined twice unless shadowing is allowed.
+    assert!(linker.root().instance_exports(&mut store, &lib).is_err());
+    linker.allow_shadowing(true);
+    linker.root().instance_exports(&mut store, &lib)?;
+    Ok(())
+}
+
+#[test]
+fn link_instances_other_store() -> Result<()> {
+    let engine = super::engine();
+    let library = Component::new(&engine, LIBRARY)?;
+    let user = Component::new(&engine, USER)?;
+
+    let mut store = Store::new(&engine, ());
+    let mut linker = Linker::new(&engine);
+    let lib = linker.instantiate(&mut store, &library)?;
+    linker.root().instance_exports(&mut store, &lib)?;
+
+    // Using the library's exports from another store is an error rather
+    // than a panic.
+    let mut other = Store::new(&engine, ());
+    let i = linker.instantiate(&mut other, &user)?;
+    let run = i.get_typed_func::<(u32,), (u32,)>(&mut other, "run")?;
+    let err = run.call(&mut other, (1,)).unwrap_err();
+    assert!(
+        format!("{err:?}").contains("other than the one which owns the instance"),
+        "{err:?}"
+    );
+
+    // The store which owns the library can still use it.
+    let i = linker.instantiate(&mut store, &user)?;
+    let run = i.get_typed_func::<(u32,), (u32,)>(&mut store, "run")?;
+    assert_eq!(run.call(&mut store, (1,))?, (2,));
+    run.post_return(&mut store)?;
+    Ok(())
+}
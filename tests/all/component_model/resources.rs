@@ -657,6 +657,67 @@ fn dynamic_val() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn host_resource_any_without_instance_pre() -> Result<()> {
+    let engine = super::engine();
+    let c = Component::new(
+        &engine,
+        r#"
+            (component
+                (import "t" (type $t (sub resource)))
+
+                (core module $m
+                    (func (export "pass") (param i32) (result i32)
+                        (local.get 0)))
+                (core instance $i (instantiate $m))
+
+                (func (export "a") (param "x" (own $t)) (result (own $t))
+                    (canon lift (core func $i "pass")))
+            )
+        "#,
+    )?;
+
+    struct MyType;
+
+    let mut store = Store::new(&engine, ());
+    let mut linker = Linker::new(&engine);
+    linker
+        .root()
+        .resource("t", ResourceType::host::<MyType>(), |_, _| {
+            panic!("host-owned resources should not be destroyed by the guest dtor")
+        })?;
+    let i = linker.instantiate(&mut store, &c)?;
+    let a = i.get_func(&mut store, "a").unwrap();
+
+    // Owned resources round-trip through the guest.
+    let t = ResourceAny::try_from_host_resource(Resource::<MyType>::new_own(100), &mut store)?;
+    assert_eq!(t.ty(), ResourceType::host::<MyType>());
+    assert!(t.owned());
+    let mut results = [Val::Bool(false)];
+    a.call(&mut store, &[Val::Resource(t)], &mut results)?;
+    a.post_return(&mut store)?;
+    match &results[0] {
+        Val::Resource(resource) => {
+            let resource = resource.try_into_resource::<MyType>(&mut store)?;
+            assert_eq!(resource.rep(), 100);
+            assert!(resource.owned());
+        }
+        _ => unreachable!(),
+    }
+
+    // Host borrows only exist for the duration of a call.
+    assert!(
+        ResourceAny::try_from_host_resource(Resource::<MyType>::new_borrow(200), &mut store)
+            .is_err()
+    );
+
+    // Dropping an owned resource from the host doesn't run the destructor.
+    let t = ResourceAny::try_from_host_resource(Resource::<MyType>::new_own(300), &mut store)?;
+    t.resource_drop(&mut store)?;
+
+    Ok(())
+}
+
 #[test]
 fn cannot_reenter_during_import() -> Result<()> {
     let engine = super::engine();
@@ -0,0 +1,145 @@
+#![cfg(not(miri))]
+
+use anyhow::Result;
+use wasmtime::component::types::ComponentItem;
+use wasmtime::component::{Component, Type, Val};
+
+/// Returns the types of the parameters of the function `f` imported by a
+/// component with the given type definitions and parameters.
+fn param_types(types: &str, params: &str) -> Result<Vec<Type>> {
+    let engine = super::engine();
+    let component = Component::new(
+        &engine,
+        format!(
+            r#"
+                (component
+                    {types}
+                    (import "f" (func {params}))
+                )
+            "#
+        ),
+    )?;
+    match component.component_type().get_import("f") {
+        Some(ComponentItem::ComponentFunc(f)) => Ok(f.params().collect()),
+        other => panic!("expected a function, found {other:?}"),
+    }
+}
+
+fn param_type(types: &str, ty: &str) -> Result<Type> {
+    Ok(param_types(types, &format!("(param \"x\" {ty})"))?.remove(0))
+}
+
+/// Asserts that `wave` and `json` parse to the same value of type `ty`, and
+/// that the value is written back as the same text.
+fn assert_round_trip(ty: &Type, wave: &str, json: &str) -> Result<()> {
+    let val = Val::from_wave(ty, wave)?;
+    assert_eq!(val, Val::from_json(ty, json)?);
+    assert_eq!(val.to_wave()?, wave);
+    assert_eq!(val.to_json()?, json);
+    Ok(())
+}
+
+#[test]
+fn primitives() -> Result<()> {
+    let tys = param_types(
+        "",
+        r#"
+            (param "a" bool) (param "b" s8) (param "c" u64) (param "d" float32)
+            (param "e" float64) (param "f" char) (param "g" string)
+        "#,
+    )?;
+    assert_round_trip(&tys[0], "true", "true")?;
+    assert_round_trip(&tys[1], "-128", "-128")?;
+    assert_round_trip(&tys[2], "18446744073709551615", "18446744073709551615")?;
+    assert_round_trip(&tys[3], "1.5", "1.5")?;
+    assert_round_trip(&tys[3], "0.1", "0.1")?;
+    assert_round_trip(&tys[4], "-inf", "\"-inf\"")?;
+    assert_round_trip(&tys[4], "nan", "\"nan\"")?;
+    assert_round_trip(&tys[5], "'\\n'", "\"\\n\"")?;
+    assert_round_trip(&tys[5], "'😀'", "\"😀\"")?;
+    assert_round_trip(
+        &tys[6],
+        "\"a \\\"quoted\\\" word\"",
+        "\"a \\\"quoted\\\" word\"",
+    )?;
+
+    assert_eq!(Val::from_wave(&tys[5], "'\\u{1f600}'")?, Val::Char('😀'));
+    assert_eq!(Val::from_wave(&tys[2], " 7 // comment")?, Val::U64(7));
+    Ok(())
+}
+
+#[test]
+fn compound() -> Result<()> {
+    let types = r#"
+        (type $point' (record (field "x" s32) (field "y" s32) (field "label" (option string))))
+        (import "point" (type $point (eq $point')))
+        (type $shape' (variant (case "circle" float64) (case "none") (case "empty")))
+        (import "shape" (type $shape (eq $shape')))
+        (type $color' (enum "red" "green"))
+        (import "color" (type $color (eq $color')))
+        (type $perms' (flags "read" "write" "exec"))
+        (import "perms" (type $perms (eq $perms')))
+    "#;
+    let tys = param_types(
+        types,
+        r#"
+            (param "a" (list u8)) (param "b" (tuple u32 string)) (param "c" $point)
+            (param "d" $shape) (param "e" $color) (param "f" $perms)
+            (param "g" (option (option u32))) (param "h" (result u32 (error string)))
+            (param "i" (result))
+        "#,
+    )?;
+    assert_round_trip(&tys[0], "[1, 2, 3]", "[1,2,3]")?;
+    assert_round_trip(&tys[1], "(1, \"two\")", "[1,\"two\"]")?;
+    assert_round_trip(
+        &tys[2],
+        "{x: 1, y: -2, label: some(\"p\")}",
+        "{\"x\":1,\"y\":-2,\"label\":\"p\"}",
+    )?;
+    assert_round_trip(&tys[3], "circle(1.5)", "{\"circle\":1.5}")?;
+    assert_round_trip(&tys[3], "%none", "\"none\"")?;
+    assert_round_trip(&tys[3], "empty", "\"empty\"")?;
+    assert_round_trip(&tys[4], "green", "\"green\"")?;
+    assert_round_trip(&tys[5], "{read, exec}", "[\"read\",\"exec\"]")?;
+    assert_round_trip(&tys[5], "{}", "[]")?;
+    assert_round_trip(&tys[6], "some(some(3))", "{\"some\":3}")?;
+    assert_round_trip(&tys[6], "some(none)", "{\"some\":null}")?;
+    assert_round_trip(&tys[6], "none", "null")?;
+    assert_round_trip(&tys[7], "ok(3)", "{\"ok\":3}")?;
+    assert_round_trip(&tys[7], "err(\"bad\")", "{\"err\":\"bad\"}")?;
+    assert_round_trip(&tys[8], "ok", "\"ok\"")?;
+
+    // `none` fields of records may be left out.
+    let point = Val::from_wave(&tys[2], "{y: 2, x: 1}")?;
+    assert_eq!(point.to_wave()?, "{x: 1, y: 2, label: none}");
+    assert_eq!(Val::from_json(&tys[2], "{\"x\":1,\"y\":2}")?, point);
+    Ok(())
+}
+
+#[test]
+fn errors() -> Result<()> {
+    let u8 = param_type("", "u8")?;
+    let err = Val::from_wave(&u8, "256").unwrap_err();
+    assert!(err.to_string().contains("at offset 0"), "{err}");
+    assert!(Val::from_wave(&u8, "1 2").is_err());
+    assert!(Val::from_json(&u8, "256").is_err());
+    assert!(Val::from_json(&u8, "\"1\"").is_err());
+
+    let list = param_type("", "(list string)")?;
+    let err = Val::from_wave(&list, "[\"a\", 1]").unwrap_err();
+    assert!(err.to_string().contains("at offset 6"), "{err}");
+    assert!(Val::from_wave(&list, "[\"a\"").is_err());
+    assert!(Val::from_json(&list, "[\"a\",").is_err());
+
+    let record = param_type(
+        r#"
+            (type $r' (record (field "a" u32)))
+            (import "r" (type $r (eq $r')))
+        "#,
+        "$r",
+    )?;
+    assert!(Val::from_wave(&record, "{}").is_err());
+    assert!(Val::from_wave(&record, "{a: 1, b: 2}").is_err());
+    assert!(Val::from_json(&record, "{\"a\":1,\"b\":2}").is_err());
+    Ok(())
+}
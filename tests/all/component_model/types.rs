@@ -0,0 +1,166 @@
+#![cfg(not(miri))]
+
+use anyhow::Result;
+use wasmtime::component::types::{self, ComponentItem};
+use wasmtime::component::{Component, ResourceType, Type};
+use wasmtime::{ExternType, ValType};
+
+fn unwrap_func(item: Option<ComponentItem>) -> types::ComponentFunc {
+    match item {
+        Some(ComponentItem::ComponentFunc(f)) => f,
+        other => panic!("expected a function, found {other:?}"),
+    }
+}
+
+fn unwrap_instance(item: Option<ComponentItem>) -> types::ComponentInstance {
+    match item {
+        Some(ComponentItem::ComponentInstance(i)) => i,
+        other => panic!("expected an instance, found {other:?}"),
+    }
+}
+
+fn unwrap_resource(item: Option<ComponentItem>) -> ResourceType {
+    match item {
+        Some(ComponentItem::Resource(r)) => r,
+        other => panic!("expected a resource, found {other:?}"),
+    }
+}
+
+#[test]
+fn imports_and_exports() -> Result<()> {
+    let engine = super::engine();
+    let c = Component::new(
+        &engine,
+        r#"
+            (component
+                (import "host" (instance $host
+                    (export $r "r" (type (sub resource)))
+                    (export "log" (func (param "msg" string) (param "r" (borrow $r))))
+                    (export "nested" (component
+                        (export "g" (func))
+                    ))
+                ))
+                (import "m" (core module
+                    (import "a" "b" (func (param i32)))
+                    (export "mem" (memory 1))
+                ))
+                (import "f" (func (param "x" u32) (result (list u8))))
+
+                (core module $m
+                    (func (export "run") (param i32) (result i32) local.get 0)
+                )
+                (core instance $i (instantiate $m))
+                (func $run (param "x" s32) (result s32) (canon lift (core func $i "run")))
+                (export "run" (func $run))
+                (instance $exports
+                    (export "run" (func $run))
+                )
+                (export "exports" (instance $exports))
+            )
+        "#,
+    )?;
+    let ty = c.component_type();
+
+    let names = ty.imports().map(|(name, _)| name).collect::<Vec<_>>();
+    assert_eq!(names, ["host", "m", "f"]);
+    let names = ty.exports().map(|(name, _)| name).collect::<Vec<_>>();
+    assert_eq!(names, ["run", "exports"]);
+    assert!(ty.get_import("missing").is_none());
+
+    // Instances and their resources and functions.
+    let host = unwrap_instance(ty.get_import("host"));
+    let r = unwrap_resource(host.get_export("r"));
+    let log = unwrap_func(host.get_export("log"));
+    let params = log.params().collect::<Vec<_>>();
+    assert_eq!(params, [Type::String, Type::Borrow(r)]);
+    assert_eq!(log.results().len(), 0);
+    assert!(r != ResourceType::host::<()>());
+
+    // Core modules.
+    let m = match ty.get_import("m") {
+        Some(ComponentItem::Module(m)) => m,
+        other => panic!("expected a module, found {other:?}"),
+    };
+    let imports = m.imports().collect::<Vec<_>>();
+    assert_eq!(imports.len(), 1);
+    assert_eq!(imports[0].0, ("a", "b"));
+    match &imports[0].1 {
+        ExternType::Func(f) => assert_eq!(f.params().collect::<Vec<_>>(), [ValType::I32]),
+        other => panic!("expected a function, found {other:?}"),
+    }
+    let exports = m.exports().collect::<Vec<_>>();
+    assert_eq!(exports.len(), 1);
+    assert_eq!(exports[0].0, "mem");
+    assert!(matches!(exports[0].1, ExternType::Memory(_)));
+
+    // Functions.
+    let f = unwrap_func(ty.get_import("f"));
+    assert_eq!(f.params().collect::<Vec<_>>(), [Type::U32]);
+    let results = f.results().collect::<Vec<_>>();
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].unwrap_list().ty(), Type::U8);
+
+    // Nested components.
+    let nested = match host.get_export("nested") {
+        Some(ComponentItem::Component(c)) => c,
+        other => panic!("expected a component, found {other:?}"),
+    };
+    assert_eq!(nested.imports().len(), 0);
+    let g = unwrap_func(nested.get_export("g"));
+    assert_eq!(g.params().len(), 0);
+
+    // Exports.
+    let run = unwrap_func(ty.get_export("run"));
+    assert_eq!(run.params().collect::<Vec<_>>(), [Type::S32]);
+    assert_eq!(run.results().collect::<Vec<_>>(), [Type::S32]);
+    let exports = unwrap_instance(ty.get_export("exports"));
+    let run = unwrap_func(exports.get_export("run"));
+    assert_eq!(run.params().collect::<Vec<_>>(), [Type::S32]);
+    Ok(())
+}
+
+#[test]
+fn resources() -> Result<()> {
+    let engine = super::engine();
+    let c = Component::new(
+        &engine,
+        r#"
+            (component
+                (import "t" (type $t (sub resource)))
+                (import "u" (type $u (sub resource)))
+                (import "take-t" (func (param "t" (own $t))))
+
+                (type $r (resource (rep i32)))
+                (core func $drop (canon resource.drop $r))
+                (core func $new (canon resource.new $r))
+                (core module $m
+                    (import "" "new" (func $new (param i32) (result i32)))
+                    (func (export "make") (result i32) i32.const 0 call $new)
+                )
+                (core instance $i (instantiate $m
+                    (with "" (instance (export "new" (func $new))))
+                ))
+                (export $r-export "r" (type $r))
+                (func (export "make") (result (own $r-export))
+                    (canon lift (core func $i "make")))
+            )
+        "#,
+    )?;
+    let ty = c.component_type();
+
+    let t = unwrap_resource(ty.get_import("t"));
+    let u = unwrap_resource(ty.get_import("u"));
+    assert!(t != u);
+    let take_t = unwrap_func(ty.get_import("take-t"));
+    assert_eq!(take_t.params().collect::<Vec<_>>(), [Type::Own(t)]);
+
+    let r = unwrap_resource(ty.get_export("r"));
+    assert!(r != t && r != u);
+    let make = unwrap_func(ty.get_export("make"));
+    assert_eq!(make.results().collect::<Vec<_>>(), [Type::Own(r)]);
+
+    // Placeholder resources are specific to each component.
+    let other = Component::new(&engine, r#"(component (import "t" (type (sub resource))))"#)?;
+    assert!(unwrap_resource(other.component_type().get_import("t")) != t);
+    Ok(())
+}
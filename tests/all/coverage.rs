@@ -0,0 +1,175 @@
+use anyhow::Result;
+use wasmtime::*;
+
+const WAT: &str = r#"
+    (module
+        (func $count (export "count") (param i32) (result i32) (local i32)
+            block
+                loop
+                    local.get 0
+                    i32.eqz
+                    br_if 1
+                    local.get 0
+                    i32.const 1
+                    i32.sub
+                    local.set 0
+                    local.get 1
+                    i32.const 1
+                    i32.add
+                    local.set 1
+                    br 0
+                end
+            end
+            local.get 1)
+        (func $unused (export "unused")
+            nop)
+    )
+"#;
+
+fn counts(module: &Module) -> Vec<Vec<u64>> {
+    module
+        .coverage()
+        .iter()
+        .map(|func| func.blocks().iter().map(|b| b.count()).collect())
+        .collect()
+}
+
+fn coverage_engine() -> Result<Engine> {
+    let mut config = Config::new();
+    config.coverage(true);
+    Engine::new(&config)
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn counts_blocks() -> Result<()> {
+    let engine = coverage_engine()?;
+    let module = Module::new(&engine, WAT)?;
+    assert_eq!(counts(&module), [vec![0, 0, 0, 0], vec![0]]);
+
+    let mut store = Store::new(&engine, ());
+    let instance = Instance::new(&mut store, &module, &[])?;
+    let count = instance.get_typed_func::<i32, i32>(&mut store, "count")?;
+    assert_eq!(count.call(&mut store, 3)?, 3);
+    // The entry block, the loop header, the rest of the loop body and the
+    // code after the loop.
+    assert_eq!(counts(&module), [vec![1, 4, 3, 1], vec![0]]);
+
+    let coverage = module.coverage();
+    assert_eq!(coverage[0].func_index(), 0);
+    assert_eq!(coverage[0].func_name(), Some("count"));
+    assert_eq!(coverage[1].func_name(), Some("unused"));
+    let offsets = coverage[0]
+        .blocks()
+        .iter()
+        .map(|b| b.module_offset())
+        .collect::<Vec<_>>();
+    assert!(offsets.windows(2).all(|w| w[0] < w[1]), "{offsets:?}");
+    assert!(coverage[0].blocks()[0].symbols().is_empty());
+    Ok(())
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn instances_share_counters() -> Result<()> {
+    let engine = coverage_engine()?;
+    let module = Module::new(&engine, WAT)?;
+    for _ in 0..2 {
+        let mut store = Store::new(&engine, ());
+        let instance = Instance::new(&mut store, &module, &[])?;
+        let count = instance.get_typed_func::<i32, i32>(&mut store, "count")?;
+        count.call(&mut store, 0)?;
+    }
+    assert_eq!(counts(&module), [vec![2, 2, 0, 2], vec![0]]);
+
+    module.reset_coverage();
+    assert_eq!(counts(&module), [vec![0, 0, 0, 0], vec![0]]);
+    Ok(())
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn counts_across_threads() -> Result<()> {
+    let engine = coverage_engine()?;
+    let module = Module::new(&engine, WAT)?;
+    std::thread::scope(|s| {
+        let threads = (0..4)
+            .map(|_| {
+                s.spawn(|| -> Result<()> {
+                    let mut store = Store::new(&engine, ());
+                    let instance = Instance::new(&mut store, &module, &[])?;
+                    let count = instance.get_typed_func::<i32, i32>(&mut store, "count")?;
+                    for _ in 0..1000 {
+                        count.call(&mut store, 1)?;
+                    }
+                    Ok(())
+                })
+            })
+            .collect::<Vec<_>>();
+        threads
+            .into_iter()
+            .try_for_each(|thread| thread.join().unwrap())
+    })?;
+    assert_eq!(counts(&module), [vec![4000, 8000, 4000, 4000], vec![0]]);
+    Ok(())
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn blocks_end_at_throws_and_traps() -> Result<()> {
+    let mut config = Config::new();
+    config.coverage(true).wasm_exceptions(true);
+    let engine = Engine::new(&config)?;
+    let module = Module::new(
+        &engine,
+        r#"
+            (module
+                (tag $e)
+                (func $throw (param i32)
+                    local.get 0
+                    if
+                        throw $e
+                    end)
+                (func (export "run") (param i32) (result i32)
+                    block $caught
+                        try_table (catch $e $caught)
+                            local.get 0
+                            call $throw
+                            i32.const 1
+                            return
+                        end
+                        unreachable
+                    end
+                    i32.const 2)
+            )
+        "#,
+    )?;
+    let mut store = Store::new(&engine, ());
+    let instance = Instance::new(&mut store, &module, &[])?;
+    let run = instance.get_typed_func::<i32, i32>(&mut store, "run")?;
+    assert_eq!(run.call(&mut store, 0)?, 1);
+    assert_eq!(run.call(&mut store, 1)?, 2);
+
+    // `$throw`: its entry, the `throw` and the code after the `if`.
+    // `run`: its entry, the `try_table` body, the code after the call, and
+    // the code after the `block` which is only reached by the `catch`.
+    assert_eq!(counts(&module), [vec![2, 1, 1], vec![2, 2, 1, 1]]);
+    Ok(())
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn disabled_by_default() -> Result<()> {
+    let engine = Engine::default();
+    let module = Module::new(&engine, WAT)?;
+    let mut store = Store::new(&engine, ());
+    let instance = Instance::new(&mut store, &module, &[])?;
+    let count = instance.get_typed_func::<i32, i32>(&mut store, "count")?;
+    count.call(&mut store, 3)?;
+    assert!(module.coverage().is_empty());
+
+    let serialized = module.serialize()?;
+    let engine = coverage_engine()?;
+    assert!(unsafe { Module::deserialize(&engine, &serialized) }.is_err());
+    Ok(())
+}
@@ -0,0 +1,294 @@
+use std::ops::Range;
+use std::sync::Arc;
+use wasmtime::*;
+use wasmtime_environ::WASM_PAGE_SIZE;
+
+fn deterministic_config() -> Config {
+    let mut config = Config::new();
+    config.deterministic(true);
+    config
+}
+
+#[test]
+fn contradicting_settings_are_rejected() {
+    let mut config = deterministic_config();
+    config.wasm_threads(true);
+    assert!(Engine::new(&config).is_err());
+
+    let mut config = deterministic_config();
+    config.relaxed_simd_deterministic(false);
+    assert!(Engine::new(&config).is_err());
+
+    let mut config = deterministic_config();
+    config.cranelift_nan_canonicalization(false);
+    assert!(Engine::new(&config).is_err());
+
+    let mut config = deterministic_config();
+    config.epoch_interruption(true);
+    assert!(Engine::new(&config).is_err());
+
+    let mut config = deterministic_config();
+    config.consume_fuel(true);
+    assert!(Engine::new(&config).unwrap().is_deterministic());
+    assert!(!Engine::default().is_deterministic());
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn shared_memories_are_rejected() {
+    let mut config = Config::new();
+    config.wasm_threads(true).deterministic(true);
+    let engine = Engine::new(&config).unwrap();
+    assert!(Module::new(&engine, "(module (memory 1 1 shared))").is_err());
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn nans_are_canonical() -> Result<()> {
+    let engine = Engine::new(&deterministic_config())?;
+    let module = Module::new(
+        &engine,
+        r#"
+            (module
+                (func (export "f32") (param f32) (result i32)
+                    (i32.reinterpret_f32 (f32.add (local.get 0) (local.get 0))))
+                (func (export "f64") (param f64) (result i64)
+                    (i64.reinterpret_f64 (f64.div (local.get 0) (local.get 0))))
+                (func (export "relaxed") (param f32) (result i32)
+                    (i32x4.extract_lane 0
+                        (i32x4.relaxed_trunc_f32x4_s (f32x4.splat (local.get 0)))))
+            )
+        "#,
+    )?;
+    let mut store = Store::new(&engine, ());
+    let instance = Instance::new(&mut store, &module, &[])?;
+
+    let f32 = instance.get_typed_func::<f32, i32>(&mut store, "f32")?;
+    let nan = f32::from_bits(0xffc0_1234);
+    assert_eq!(f32.call(&mut store, nan)? as u32, 0x7fc0_0000);
+
+    let f64 = instance.get_typed_func::<f64, i64>(&mut store, "f64")?;
+    assert_eq!(f64.call(&mut store, 0.0)? as u64, 0x7ff8_0000_0000_0000);
+
+    let relaxed = instance.get_typed_func::<f32, i32>(&mut store, "relaxed")?;
+    assert_eq!(relaxed.call(&mut store, f32::NAN)?, 0);
+    assert_eq!(relaxed.call(&mut store, 1e10)?, i32::MAX);
+    Ok(())
+}
+
+/// A memory which can't grow past a fixed capacity, like a host that has run
+/// out of memory.
+struct FixedMemory {
+    data: Vec<u8>,
+    size: usize,
+    maximum: Option<usize>,
+}
+
+unsafe impl LinearMemory for FixedMemory {
+    fn byte_size(&self) -> usize {
+        self.size
+    }
+
+    fn maximum_byte_size(&self) -> Option<usize> {
+        self.maximum
+    }
+
+    fn grow_to(&mut self, new_size: usize) -> Result<()> {
+        if new_size > self.data.len() {
+            anyhow::bail!("host is out of memory");
+        }
+        self.size = new_size;
+        Ok(())
+    }
+
+    fn as_ptr(&self) -> *mut u8 {
+        self.data.as_ptr() as *mut u8
+    }
+
+    fn wasm_accessible(&self) -> Range<usize> {
+        let base = self.data.as_ptr() as usize;
+        base..base + self.data.len()
+    }
+}
+
+struct FixedMemoryCreator;
+
+unsafe impl MemoryCreator for FixedMemoryCreator {
+    fn new_memory(
+        &self,
+        _ty: MemoryType,
+        minimum: usize,
+        maximum: Option<usize>,
+        _reserved_size: Option<usize>,
+        _guard_size: usize,
+    ) -> Result<Box<dyn LinearMemory>, String> {
+        Ok(Box::new(FixedMemory {
+            data: vec![0; 2 * WASM_PAGE_SIZE as usize],
+            size: minimum,
+            maximum,
+        }))
+    }
+}
+
+fn grow_past_host_capacity(config: &mut Config) -> Result<Result<i32>> {
+    config
+        .with_host_memory(Arc::new(FixedMemoryCreator))
+        .static_memory_maximum_size(0)
+        .dynamic_memory_guard_size(0);
+    let engine = Engine::new(config)?;
+    let module = Module::new(
+        &engine,
+        r#"
+            (module
+                (memory 1 10)
+                (func (export "grow") (param i32) (result i32)
+                    (memory.grow (local.get 0)))
+            )
+        "#,
+    )?;
+    let mut store = Store::new(&engine, ());
+    let instance = Instance::new(&mut store, &module, &[])?;
+    let grow = instance.get_typed_func::<i32, i32>(&mut store, "grow")?;
+    assert_eq!(grow.call(&mut store, 1)?, 1);
+    // Growing past the declared maximum fails deterministically either way.
+    assert_eq!(grow.call(&mut store, 10)?, -1);
+    Ok(grow.call(&mut store, 1))
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn host_memory_growth_failure_traps() -> Result<()> {
+    assert_eq!(grow_past_host_capacity(&mut Config::new())??, -1);
+
+    let err = grow_past_host_capacity(&mut deterministic_config())?.unwrap_err();
+    assert!(
+        format!("{err:?}").contains("host is out of memory"),
+        "{err:?}"
+    );
+    Ok(())
+}
+
+// Reads the monotonic clock around a one second sleep, then the wall clock
+// and 16 random bytes, storing the results at offsets 0, 8, 16 and 32.
+const WASI_CLOCKS_AND_RANDOM: &str = r#"
+    (module
+        (import "wasi_snapshot_preview1" "clock_time_get"
+            (func $clock_time_get (param i32 i64 i32) (result i32)))
+        (import "wasi_snapshot_preview1" "poll_oneoff"
+            (func $poll_oneoff (param i32 i32 i32 i32) (result i32)))
+        (import "wasi_snapshot_preview1" "random_get"
+            (func $random_get (param i32 i32) (result i32)))
+        (memory (export "memory") 1)
+
+        ;; A relative monotonic clock subscription for one second.
+        (data (i32.const 64) "\00\00\00\00\00\00\00\00\00\00\00\00\00\00\00\00")
+        (data (i32.const 80) "\01\00\00\00\00\00\00\00\00\ca\9a\3b\00\00\00\00")
+
+        (func $check (param i32)
+            local.get 0
+            if unreachable end)
+
+        (func (export "_start")
+            (call $check (call $clock_time_get (i32.const 1) (i64.const 0) (i32.const 0)))
+            (call $check (call $poll_oneoff (i32.const 64) (i32.const 128) (i32.const 1) (i32.const 160)))
+            (call $check (call $clock_time_get (i32.const 1) (i64.const 0) (i32.const 8)))
+            (call $check (call $clock_time_get (i32.const 0) (i64.const 0) (i32.const 16)))
+            (call $check (call $random_get (i32.const 32) (i32.const 16))))
+    )
+"#;
+
+fn run_wasi_clocks_and_random<T>(
+    linker: &Linker<T>,
+    mut store: Store<T>,
+) -> Result<(u64, u64, u64, [u8; 16])> {
+    let module = Module::new(store.engine(), WASI_CLOCKS_AND_RANDOM)?;
+    let instance = linker.instantiate(&mut store, &module)?;
+    instance
+        .get_typed_func::<(), ()>(&mut store, "_start")?
+        .call(&mut store, ())?;
+    let memory = instance.get_memory(&mut store, "memory").unwrap();
+    let data = memory.data(&store);
+    let u64_at = |offset: usize| u64::from_le_bytes(data[offset..][..8].try_into().unwrap());
+    Ok((
+        u64_at(0),
+        u64_at(8),
+        u64_at(16),
+        data[32..48].try_into().unwrap(),
+    ))
+}
+
+fn assert_deterministic_results(results: [(u64, u64, u64, [u8; 16]); 2]) {
+    assert_eq!(results[0], results[1]);
+    let (before, after, wall, _) = results[0];
+    // The sleep doesn't block, but still moves the clock forward...
+    assert!(after - before >= 1_000_000_000, "{before} {after}");
+    // ... and the wall clock starts at the Unix epoch.
+    assert!(wall < 2_000_000_000, "{wall}");
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn wasi_common_is_deterministic() -> Result<()> {
+    let engine = Engine::new(&deterministic_config())?;
+    let mut linker = Linker::new(&engine);
+    wasmtime_wasi::add_to_linker(&mut linker, |s| s)?;
+
+    let start = std::time::Instant::now();
+    let results = [(); 2].map(|()| {
+        let ctx = wasmtime_wasi::sync::WasiCtxBuilder::new()
+            .deterministic()
+            .build();
+        run_wasi_clocks_and_random(&linker, Store::new(&engine, ctx)).unwrap()
+    });
+    assert!(start.elapsed() < std::time::Duration::from_secs(2));
+    assert_deterministic_results(results);
+    Ok(())
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn wasi_preview2_is_deterministic() -> Result<()> {
+    use wasmtime_wasi::preview2::preview1::{self, WasiPreview1Adapter, WasiPreview1View};
+    use wasmtime_wasi::preview2::{ResourceTable, WasiCtx, WasiCtxBuilder, WasiView};
+
+    struct Host {
+        table: ResourceTable,
+        wasi: WasiCtx,
+        adapter: WasiPreview1Adapter,
+    }
+
+    impl WasiView for Host {
+        fn table(&mut self) -> &mut ResourceTable {
+            &mut self.table
+        }
+        fn ctx(&mut self) -> &mut WasiCtx {
+            &mut self.wasi
+        }
+    }
+
+    impl WasiPreview1View for Host {
+        fn adapter(&self) -> &WasiPreview1Adapter {
+            &self.adapter
+        }
+        fn adapter_mut(&mut self) -> &mut WasiPreview1Adapter {
+            &mut self.adapter
+        }
+    }
+
+    let engine = Engine::new(&deterministic_config())?;
+    let mut linker = Linker::new(&engine);
+    preview1::add_to_linker_sync(&mut linker)?;
+
+    let start = std::time::Instant::now();
+    let results = [(); 2].map(|()| {
+        let host = Host {
+            table: ResourceTable::new(),
+            wasi: WasiCtxBuilder::new().deterministic().build(),
+            adapter: WasiPreview1Adapter::new(),
+        };
+        run_wasi_clocks_and_random(&linker, Store::new(&engine, host)).unwrap()
+    });
+    assert!(start.elapsed() < std::time::Duration::from_secs(2));
+    assert_deterministic_results(results);
+    Ok(())
+}
@@ -0,0 +1,224 @@
+use wasmtime::*;
+
+fn exceptions_store() -> anyhow::Result<Store<()>> {
+    let mut config = Config::new();
+    config.wasm_exceptions(true);
+    let engine = Engine::new(&config)?;
+    Ok(Store::new(&engine, ()))
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn host_throws_wasm_catches() -> anyhow::Result<()> {
+    let mut store = exceptions_store()?;
+    let module = Module::new(
+        store.engine(),
+        r#"
+            (module
+                (tag $t (import "" "tag") (param i32 i64))
+                (import "" "wrapped" (func $wrapped (param i32) (result f64)))
+                (import "" "dynamic" (func $dynamic (param i32) (result f64)))
+                (func (export "run") (param i32) (result i32 i64)
+                    (block $h (result i32 i64)
+                        (try_table (catch $t $h)
+                            (drop (call $wrapped (local.get 0)))
+                            (drop (call $dynamic (local.get 0))))
+                        (return (i32.const -1) (i64.const -1))))
+            )
+        "#,
+    )?;
+
+    let tag = Tag::new(&mut store, &TagType::new([ValType::I32, ValType::I64]))?;
+    let wrapped = Func::wrap(
+        &mut store,
+        move |mut caller: Caller<'_, ()>, x: i32| -> Result<f64> {
+            if x == 1 {
+                let exn = ExnRef::new(&mut caller, &tag, &[Val::I32(x), Val::I64(10)])?;
+                return Err(ThrownException::new(exn).into());
+            }
+            Ok(1.0)
+        },
+    );
+    let dynamic = Func::new(
+        &mut store,
+        FuncType::new([ValType::I32], [ValType::F64]),
+        move |mut caller, params, results| {
+            let x = params[0].unwrap_i32();
+            if x == 2 {
+                let exn = ExnRef::new(&mut caller, &tag, &[Val::I32(x), Val::I64(20)])?;
+                return Err(ThrownException::new(exn).into());
+            }
+            results[0] = Val::F64(1.0f64.to_bits());
+            Ok(())
+        },
+    );
+
+    let instance = Instance::new(
+        &mut store,
+        &module,
+        &[tag.into(), wrapped.into(), dynamic.into()],
+    )?;
+    let run = instance.get_typed_func::<i32, (i32, i64)>(&mut store, "run")?;
+    assert_eq!(run.call(&mut store, 0)?, (-1, -1));
+    assert_eq!(run.call(&mut store, 1)?, (1, 10));
+    assert_eq!(run.call(&mut store, 2)?, (2, 20));
+
+    // Other errors returned from host functions still trap.
+    let fail = Func::wrap(&mut store, || -> Result<()> { anyhow::bail!("boom") });
+    let module = Module::new(
+        store.engine(),
+        r#"
+            (module
+                (import "" "fail" (func $fail))
+                (func (export "run")
+                    (block $h
+                        (try_table (catch_all $h)
+                            (call $fail)))))
+        "#,
+    )?;
+    let instance = Instance::new(&mut store, &module, &[fail.into()])?;
+    let run = instance.get_typed_func::<(), ()>(&mut store, "run")?;
+    let err = run.call(&mut store, ()).unwrap_err();
+    assert!(format!("{err:?}").contains("boom"));
+    Ok(())
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn wasm_throws_host_catches() -> anyhow::Result<()> {
+    let mut store = exceptions_store()?;
+    let module = Module::new(
+        store.engine(),
+        r#"
+            (module
+                (tag $t (export "t") (param i32 externref))
+                (func (export "throw") (param i32 externref)
+                    (throw $t (local.get 0) (local.get 1)))
+            )
+        "#,
+    )?;
+    let instance = Instance::new(&mut store, &module, &[])?;
+    let throw = instance.get_typed_func::<(i32, Option<ExternRef>), ()>(&mut store, "throw")?;
+    let tag = instance.get_tag(&mut store, "t").unwrap();
+    assert_eq!(
+        tag.ty(&store).params().collect::<Vec<_>>(),
+        [ValType::I32, ValType::ExternRef]
+    );
+
+    let err = throw
+        .call(&mut store, (7, Some(ExternRef::new("hello"))))
+        .unwrap_err();
+    let exn = err.downcast::<ThrownException>()?.into_exnref();
+
+    // The exception keeps its payload alive across GCs.
+    store.gc();
+    assert!(Tag::eq(&exn.tag(&mut store), &tag, &store));
+    let fields = exn.fields(&mut store);
+    assert_eq!(fields[0].unwrap_i32(), 7);
+    let externref = fields[1].unwrap_externref().unwrap();
+    assert_eq!(externref.data().downcast_ref::<&str>(), Some(&"hello"));
+
+    // Nothing is left pending after an uncaught exception.
+    let module = Module::new(store.engine(), r#"(module (func (export "f")))"#)?;
+    let instance = Instance::new(&mut store, &module, &[])?;
+    let f = instance.get_typed_func::<(), ()>(&mut store, "f")?;
+    f.call(&mut store, ())?;
+    Ok(())
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn exceptions_pass_through_host_frames() -> anyhow::Result<()> {
+    let mut store = exceptions_store()?;
+    let mut linker = Linker::new(store.engine());
+    linker.func_wrap(
+        "",
+        "call",
+        |mut caller: Caller<'_, ()>, f: Option<Func>| -> Result<()> {
+            let f = f.unwrap().typed::<(), ()>(&caller)?;
+            f.call(&mut caller, ())?;
+            Ok(())
+        },
+    )?;
+    let module = Module::new(
+        store.engine(),
+        r#"
+            (module
+                (import "" "call" (func $call (param funcref)))
+                (tag $t (param i32))
+                (func $throw (throw $t (i32.const 42)))
+                (elem declare func $throw)
+                (func (export "run") (result i32)
+                    (block $h (result i32)
+                        (try_table (catch $t $h)
+                            (call $call (ref.func $throw)))
+                        (i32.const 0)))
+            )
+        "#,
+    )?;
+    let instance = linker.instantiate(&mut store, &module)?;
+    let run = instance.get_typed_func::<(), i32>(&mut store, "run")?;
+    assert_eq!(run.call(&mut store, ())?, 42);
+    Ok(())
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn exnref_values() -> anyhow::Result<()> {
+    let mut store = exceptions_store()?;
+    let module = Module::new(
+        store.engine(),
+        r#"
+            (module
+                (tag $t (export "t") (param i32))
+                (global $g (export "g") (mut exnref) (ref.null exn))
+                (func (export "catch") (param i32)
+                    (block $h (result i32 exnref)
+                        (try_table (catch_ref $t $h)
+                            (throw $t (local.get 0)))
+                        (unreachable))
+                    (global.set $g)
+                    (drop))
+                (func (export "rethrow") (param exnref)
+                    (throw_ref (local.get 0)))
+            )
+        "#,
+    )?;
+    let instance = Instance::new(&mut store, &module, &[])?;
+    let catch = instance.get_typed_func::<i32, ()>(&mut store, "catch")?;
+    let rethrow = instance.get_func(&mut store, "rethrow").unwrap();
+    let g = instance.get_global(&mut store, "g").unwrap();
+    let tag = instance.get_tag(&mut store, "t").unwrap();
+    assert_eq!(*g.ty(&store).content(), ValType::ExnRef);
+    assert!(g.get(&mut store).unwrap_exnref().is_none());
+
+    catch.call(&mut store, 5)?;
+    let exn = g.get(&mut store).unwrap_exnref().unwrap();
+    assert_eq!(exn.fields(&mut store)[0].unwrap_i32(), 5);
+
+    // Rethrowing an exception preserves its identity.
+    let err = rethrow
+        .call(&mut store, &[Val::ExnRef(Some(exn.clone()))], &mut [])
+        .unwrap_err();
+    let thrown = err.downcast::<ThrownException>()?.into_exnref();
+    assert!(thrown.ptr_eq(&exn));
+
+    // Exceptions created by the host can be thrown by wasm too.
+    let exn = ExnRef::new(&mut store, &tag, &[Val::I32(6)])?;
+    g.set(&mut store, Val::ExnRef(Some(exn.clone())))?;
+    let arg = g.get(&mut store);
+    let err = rethrow.call(&mut store, &[arg], &mut []).unwrap_err();
+    assert!(err.downcast::<ThrownException>()?.exnref().ptr_eq(&exn));
+
+    assert!(ExnRef::new(&mut store, &tag, &[]).is_err());
+    assert!(ExnRef::new(&mut store, &tag, &[Val::I64(0)]).is_err());
+    Ok(())
+}
+
+#[test]
+fn exceptions_disabled() -> anyhow::Result<()> {
+    let mut store = Store::<()>::default();
+    assert!(Tag::new(&mut store, &TagType::new([])).is_err());
+    assert!(Module::new(store.engine(), "(module (tag))").is_err());
+    Ok(())
+}
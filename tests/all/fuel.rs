@@ -7,6 +7,17 @@ mod kw {
     wast::custom_keyword!(assert_fuel);
 }
 
+/// The compilation strategies which implement fuel consumption; Winch is
+/// currently only supported on x86_64.
+fn strategies() -> impl Iterator<Item = Strategy> {
+    [
+        Some(Strategy::Cranelift),
+        cfg!(target_arch = "x86_64").then_some(Strategy::Winch),
+    ]
+    .into_iter()
+    .flatten()
+}
+
 struct FuelWast<'a> {
     assertions: Vec<(Span, u64, wast::core::Module<'a>)>,
 }
@@ -31,24 +42,29 @@ fn run() -> Result<()> {
     let buf = ParseBuffer::new(&test)?;
     let mut wast = parser::parse::<FuelWast<'_>>(&buf)?;
     for (span, fuel, module) in wast.assertions.iter_mut() {
-        let consumed = fuel_consumed(&module.encode()?);
-        if consumed == *fuel {
-            continue;
+        let wasm = module.encode()?;
+        for strategy in strategies() {
+            let consumed = fuel_consumed(&wasm, strategy);
+            if consumed == *fuel {
+                continue;
+            }
+            let (line, col) = span.linecol_in(&test);
+            panic!(
+                "tests/all/fuel.wast:{}:{} - expected {} fuel, found {} ({:?})",
+                line + 1,
+                col + 1,
+                fuel,
+                consumed,
+                strategy
+            );
         }
-        let (line, col) = span.linecol_in(&test);
-        panic!(
-            "tests/all/fuel.wast:{}:{} - expected {} fuel, found {}",
-            line + 1,
-            col + 1,
-            fuel,
-            consumed
-        );
     }
     Ok(())
 }
 
-fn fuel_consumed(wasm: &[u8]) -> u64 {
+fn fuel_consumed(wasm: &[u8], strategy: Strategy) -> u64 {
     let mut config = Config::new();
+    config.strategy(strategy);
     config.consume_fuel(true);
     let engine = Engine::new(&config).unwrap();
     let module = Module::new(&engine, wasm).unwrap();
@@ -111,14 +127,17 @@ fn iloop() {
     );
 
     fn iloop_aborts(wat: &str) {
-        let mut config = Config::new();
-        config.consume_fuel(true);
-        let engine = Engine::new(&config).unwrap();
-        let module = Module::new(&engine, wat).unwrap();
-        let mut store = Store::new(&engine, ());
-        store.set_fuel(10_000).unwrap();
-        let error = Instance::new(&mut store, &module, &[]).err().unwrap();
-        assert_eq!(error.downcast::<Trap>().unwrap(), Trap::OutOfFuel);
+        for strategy in strategies() {
+            let mut config = Config::new();
+            config.strategy(strategy);
+            config.consume_fuel(true);
+            let engine = Engine::new(&config).unwrap();
+            let module = Module::new(&engine, wat).unwrap();
+            let mut store = Store::new(&engine, ());
+            store.set_fuel(10_000).unwrap();
+            let error = Instance::new(&mut store, &module, &[]).err().unwrap();
+            assert_eq!(error.downcast::<Trap>().unwrap(), Trap::OutOfFuel);
+        }
     }
 }
 
@@ -138,33 +157,36 @@ fn manual_fuel() {
 #[cfg_attr(miri, ignore)]
 fn host_function_consumes_all() {
     const FUEL: u64 = 10_000;
-    let mut config = Config::new();
-    config.consume_fuel(true);
-    let engine = Engine::new(&config).unwrap();
-    let module = Module::new(
-        &engine,
-        r#"
-            (module
-                (import "" "" (func))
-                (func (export "")
-                    call 0
-                    call $other)
-                (func $other))
-        "#,
-    )
-    .unwrap();
-    let mut store = Store::new(&engine, ());
-    store.set_fuel(FUEL).unwrap();
-    let func = Func::wrap(&mut store, |mut caller: Caller<'_, ()>| {
-        let remaining = caller.get_fuel().unwrap();
-        assert_eq!(remaining, FUEL - 2);
-        assert!(caller.set_fuel(1).is_ok());
-    });
+    for strategy in strategies() {
+        let mut config = Config::new();
+        config.strategy(strategy);
+        config.consume_fuel(true);
+        let engine = Engine::new(&config).unwrap();
+        let module = Module::new(
+            &engine,
+            r#"
+                (module
+                    (import "" "" (func))
+                    (func (export "")
+                        call 0
+                        call $other)
+                    (func $other))
+            "#,
+        )
+        .unwrap();
+        let mut store = Store::new(&engine, ());
+        store.set_fuel(FUEL).unwrap();
+        let func = Func::wrap(&mut store, |mut caller: Caller<'_, ()>| {
+            let remaining = caller.get_fuel().unwrap();
+            assert_eq!(remaining, FUEL - 2);
+            assert!(caller.set_fuel(1).is_ok());
+        });
 
-    let instance = Instance::new(&mut store, &module, &[func.into()]).unwrap();
-    let export = instance.get_typed_func::<(), ()>(&mut store, "").unwrap();
-    let trap = export.call(&mut store, ()).unwrap_err();
-    assert_eq!(trap.downcast::<Trap>().unwrap(), Trap::OutOfFuel);
+        let instance = Instance::new(&mut store, &module, &[func.into()]).unwrap();
+        let export = instance.get_typed_func::<(), ()>(&mut store, "").unwrap();
+        let trap = export.call(&mut store, ()).unwrap_err();
+        assert_eq!(trap.downcast::<Trap>().unwrap(), Trap::OutOfFuel);
+    }
 }
 
 #[test]
@@ -0,0 +1,150 @@
+use wasmtime::*;
+
+fn gc_store_and_instance(wat: &str) -> anyhow::Result<(Store<()>, Instance)> {
+    let mut config = Config::new();
+    config.wasm_function_references(true).wasm_gc(true);
+    let engine = Engine::new(&config)?;
+    let mut store = Store::new(&engine, ());
+    let module = Module::new(&engine, wat)?;
+    let instance = Instance::new(&mut store, &module, &[])?;
+    Ok((store, instance))
+}
+
+const MODULE: &str = r#"
+    (module
+        (type $s (struct (field (mut i32)) (field i64) (field (mut i8)) (field (mut anyref))))
+        (type $a (array (mut i32)))
+
+        (func (export "new-struct") (result anyref)
+            (struct.new $s (i32.const 1) (i64.const 2) (i32.const 3) (ref.i31 (i32.const 4))))
+        (func (export "new-array") (param i32) (result anyref)
+            (array.new $a (i32.const 5) (local.get 0)))
+
+        (func (export "struct-field") (param anyref) (result i32)
+            (struct.get $s 0 (ref.cast (ref $s) (local.get 0))))
+        (func (export "array-sum") (param anyref) (result i32)
+            (local $a (ref $a))
+            (local $i i32)
+            (local $sum i32)
+            (local.set $a (ref.cast (ref $a) (local.get 0)))
+            (block $done
+                (loop $l
+                    (br_if $done (i32.eq (local.get $i) (array.len (local.get $a))))
+                    (local.set $sum
+                        (i32.add (local.get $sum)
+                            (array.get $a (local.get $a) (local.get $i))))
+                    (local.set $i (i32.add (local.get $i) (i32.const 1)))
+                    (br $l)))
+            (local.get $sum))
+
+        (func (export "churn") (param $n i32)
+            (block $done
+                (loop $l
+                    (br_if $done (i32.eqz (local.get $n)))
+                    (drop (array.new_default $a (i32.const 1024)))
+                    (local.set $n (i32.sub (local.get $n) (i32.const 1)))
+                    (br $l))))
+    )
+"#;
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn struct_fields_from_host() -> anyhow::Result<()> {
+    let (mut store, instance) = gc_store_and_instance(MODULE)?;
+    let new = instance.get_typed_func::<(), Option<AnyRef>>(&mut store, "new-struct")?;
+    let field = instance.get_typed_func::<Option<AnyRef>, i32>(&mut store, "struct-field")?;
+
+    let anyref = new.call(&mut store, ())?.unwrap();
+    assert!(!anyref.is_i31());
+    assert!(anyref.as_array().is_none());
+    let s = anyref.as_struct().unwrap().clone();
+    assert_eq!(s.fields_len(&store), 4);
+    assert_eq!(s.field(&mut store, 0)?.unwrap_i32(), 1);
+    assert_eq!(s.field(&mut store, 1)?.unwrap_i64(), 2);
+    assert_eq!(s.field(&mut store, 2)?.unwrap_i32(), 3);
+    let i31 = s.field(&mut store, 3)?.unwrap_anyref().unwrap().as_i31();
+    assert_eq!(i31, Some(I31::wrapping_u32(4)));
+
+    s.set_field(&mut store, 0, Val::I32(10))?;
+    assert_eq!(field.call(&mut store, Some(anyref.clone()))?, 10);
+
+    // Packed fields keep the low bits of the value.
+    s.set_field(&mut store, 2, Val::I32(0x1ff))?;
+    assert_eq!(s.field(&mut store, 2)?.unwrap_i32(), 0xff);
+
+    // Objects can be stored in other objects.
+    s.set_field(&mut store, 3, Val::AnyRef(Some(anyref.clone())))?;
+    let inner = s.field(&mut store, 3)?.unwrap_anyref().unwrap();
+    assert_eq!(inner, anyref);
+
+    assert!(s.field(&mut store, 4).is_err());
+    assert!(s.set_field(&mut store, 1, Val::I64(0)).is_err());
+    assert!(s.set_field(&mut store, 0, Val::I64(0)).is_err());
+    assert!(s.set_field(&mut store, 0, Val::AnyRef(None)).is_err());
+    Ok(())
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn array_elements_from_host() -> anyhow::Result<()> {
+    let (mut store, instance) = gc_store_and_instance(MODULE)?;
+    let new = instance.get_typed_func::<i32, Option<AnyRef>>(&mut store, "new-array")?;
+    let sum = instance.get_typed_func::<Option<AnyRef>, i32>(&mut store, "array-sum")?;
+
+    let anyref = new.call(&mut store, 3)?.unwrap();
+    assert!(anyref.as_struct().is_none());
+    let a = anyref.as_array().unwrap().clone();
+    assert_eq!(a.len(&store), 3);
+    assert_eq!(a.get(&mut store, 2)?.unwrap_i32(), 5);
+    a.set(&mut store, 1, Val::I32(100))?;
+    assert_eq!(sum.call(&mut store, Some(anyref))?, 110);
+
+    assert!(a.get(&mut store, 3).is_err());
+    assert!(a.set(&mut store, 3, Val::I32(0)).is_err());
+    assert!(a.set(&mut store, 0, Val::F32(0)).is_err());
+    Ok(())
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn host_references_are_roots() -> anyhow::Result<()> {
+    let (mut store, instance) = gc_store_and_instance(MODULE)?;
+    let new = instance.get_typed_func::<i32, Option<AnyRef>>(&mut store, "new-array")?;
+    let sum = instance.get_typed_func::<Option<AnyRef>, i32>(&mut store, "array-sum")?;
+    let churn = instance.get_typed_func::<i32, ()>(&mut store, "churn")?;
+
+    let kept = new.call(&mut store, 16)?.unwrap();
+    for _ in 0..10 {
+        drop(new.call(&mut store, 16)?);
+    }
+
+    // Collections triggered both by allocations in Wasm and by the host must
+    // keep the objects the host holds on to.
+    churn.call(&mut store, 1000)?;
+    store.gc();
+    assert_eq!(sum.call(&mut store, Some(kept.clone()))?, 80);
+
+    let a = kept.as_array().unwrap();
+    a.set(&mut store, 0, Val::I32(1))?;
+    churn.call(&mut store, 1000)?;
+    assert_eq!(a.get(&mut store, 0)?.unwrap_i32(), 1);
+    Ok(())
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn objects_are_store_specific() -> anyhow::Result<()> {
+    let (mut store1, instance1) = gc_store_and_instance(MODULE)?;
+    let (mut store2, instance2) = gc_store_and_instance(MODULE)?;
+    let new1 = instance1.get_typed_func::<(), Option<AnyRef>>(&mut store1, "new-struct")?;
+    let new2 = instance2.get_typed_func::<(), Option<AnyRef>>(&mut store2, "new-struct")?;
+
+    let s1 = new1.call(&mut store1, ())?;
+    let s2 = new2.call(&mut store2, ())?.unwrap();
+    let result = s2
+        .as_struct()
+        .unwrap()
+        .set_field(&mut store2, 3, Val::AnyRef(s1));
+    assert!(result.is_err());
+    Ok(())
+}
@@ -0,0 +1,224 @@
+use std::sync::{Arc, Mutex};
+use wasmtime::*;
+
+const WAT: &str = r#"
+    (module
+        (memory (export "memory") 1)
+        (global $g (mut i32) (i32.const 7))
+        (func $add (export "add") (param i32 i64) (result i64)
+            (local f32)
+            (local.set 2 (f32.const 1.5))
+            (i64.add
+                (i64.extend_i32_u (local.get 0))
+                (local.get 1)))
+        (func (export "run") (param i32) (result i64)
+            (i32.store (i32.const 16) (local.get 0))
+            (call $add (local.get 0) (i64.const 100)))
+    )
+"#;
+
+/// Returns the offsets within `WAT` of the `i64.add` and `local.set`
+/// instructions of `$add`.
+fn offsets() -> (usize, usize) {
+    let wasm = wat::parse_str(WAT).unwrap();
+    let mut add = None;
+    let mut set = None;
+    for payload in wasmparser::Parser::new(0).parse_all(&wasm) {
+        if let wasmparser::Payload::CodeSectionEntry(body) = payload.unwrap() {
+            let mut ops = body.get_operators_reader().unwrap();
+            while !ops.eof() {
+                let offset = ops.original_position();
+                match ops.read().unwrap() {
+                    wasmparser::Operator::I64Add if add.is_none() => add = Some(offset),
+                    wasmparser::Operator::LocalSet { .. } if set.is_none() => set = Some(offset),
+                    _ => {}
+                }
+            }
+        }
+    }
+    (add.unwrap(), set.unwrap())
+}
+
+fn engine() -> Engine {
+    let mut config = Config::new();
+    config.guest_debug(true);
+    Engine::new(&config).unwrap()
+}
+
+#[test]
+fn requires_config() -> Result<()> {
+    let engine = Engine::default();
+    let module = Module::new(&engine, WAT)?;
+    let mut store = Store::new(&engine, ());
+    assert!(store.add_breakpoint(&module, 0).is_err());
+    assert!(store.single_step(true).is_err());
+
+    let mut config = Config::new();
+    config.guest_debug(true).strategy(Strategy::Winch);
+    assert!(Engine::new(&config).is_err());
+    Ok(())
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn breakpoint_state() -> Result<()> {
+    let engine = engine();
+    let module = Module::new(&engine, WAT)?;
+    let (add, _) = offsets();
+    let mut store = Store::new(&engine, Vec::new());
+    store.debug_hook(|mut store, frame| {
+        let memory = frame.memory(&mut store, 0).unwrap();
+        let stored = memory.data(&store)[16];
+        let global = frame.global(&mut store, 0).unwrap().get(&mut store);
+        let backtrace = WasmBacktrace::capture(&store);
+        store.data_mut().push((
+            frame.func_index(),
+            frame.module_offset(),
+            frame
+                .locals()
+                .iter()
+                .map(|v| format!("{v:?}"))
+                .collect::<Vec<_>>(),
+            frame
+                .stack()
+                .iter()
+                .map(|v| format!("{v:?}"))
+                .collect::<Vec<_>>(),
+            stored,
+            global.unwrap_i32(),
+            backtrace.frames().len(),
+        ));
+        Ok(())
+    });
+    let instance = Instance::new(&mut store, &module, &[])?;
+    let run = instance.get_typed_func::<i32, i64>(&mut store, "run")?;
+
+    // Without breakpoints the hook isn't invoked.
+    assert_eq!(run.call(&mut store, 1)?, 101);
+    assert!(store.data().is_empty());
+
+    store.add_breakpoint(&module, add)?;
+    assert_eq!(run.call(&mut store, 2)?, 102);
+    assert_eq!(
+        store.data().as_slice(),
+        [(
+            0,
+            add,
+            vec![
+                "I32(2)".to_string(),
+                "I64(100)".to_string(),
+                "F32(1069547520)".to_string()
+            ],
+            vec!["I64(2)".to_string(), "I64(100)".to_string()],
+            2,
+            7,
+            2,
+        )]
+    );
+
+    assert!(store.remove_breakpoint(&module, add));
+    assert!(!store.remove_breakpoint(&module, add));
+    store.data_mut().clear();
+    assert_eq!(run.call(&mut store, 3)?, 103);
+    assert!(store.data().is_empty());
+    Ok(())
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn operand_stack_types() -> Result<()> {
+    let mut config = Config::new();
+    config.guest_debug(true).wasm_exceptions(true);
+    let engine = Engine::new(&config)?;
+    let module = Module::new(
+        &engine,
+        r#"
+            (module
+                (func $f)
+                (elem declare func $f)
+                (func (export "run") (param funcref) (result funcref)
+                    ref.func $f
+                    ref.null exn
+                    drop
+                    drop
+                    local.get 0)
+            )
+        "#,
+    )?;
+    let mut store = Store::new(&engine, Vec::new());
+    store.debug_hook(|mut store, frame| {
+        let local = frame.locals()[0].clone();
+        let stack = frame.stack().to_vec();
+        store.data_mut().push((local, stack));
+        Ok(())
+    });
+    let instance = Instance::new(&mut store, &module, &[])?;
+    let run = instance.get_typed_func::<Option<Func>, Option<Func>>(&mut store, "run")?;
+    store.single_step(true)?;
+    run.call(&mut store, None)?;
+    store.single_step(false)?;
+
+    // Both locals and operand stack values are reported with their wasm type.
+    let (local, stack) = &store.data()[1];
+    assert!(matches!(local, Val::FuncRef(None)));
+    assert!(matches!(stack[..], [Val::FuncRef(Some(_))]));
+    let (_, stack) = &store.data()[2];
+    assert!(matches!(
+        stack[..],
+        [Val::FuncRef(Some(_)), Val::ExnRef(None)]
+    ));
+    Ok(())
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn single_step() -> Result<()> {
+    let engine = engine();
+    let module = Module::new(&engine, WAT)?;
+    let (add, set) = offsets();
+    let offsets = Arc::new(Mutex::new(Vec::new()));
+    let mut store = Store::new(&engine, ());
+    let hook_offsets = offsets.clone();
+    store.debug_hook(move |mut store, frame| {
+        hook_offsets.lock().unwrap().push(frame.module_offset());
+        // Stop stepping once `$add` is entered, continuing to its end.
+        if frame.func_index() == 0 {
+            store.single_step(false)?;
+            store.add_breakpoint(frame.module(), add)?;
+        }
+        Ok(())
+    });
+    let instance = Instance::new(&mut store, &module, &[])?;
+    let run = instance.get_typed_func::<i32, i64>(&mut store, "run")?;
+    store.single_step(true)?;
+    assert_eq!(run.call(&mut store, 4)?, 104);
+
+    let offsets = offsets.lock().unwrap();
+    // Each of the 6 instructions of `run` before the call, then the first
+    // instruction of `$add` followed by the breakpoint.
+    assert_eq!(offsets.len(), 8, "{offsets:?}");
+    assert!(offsets[..6].windows(2).all(|w| w[0] < w[1]));
+    assert!(offsets[6] < set);
+    assert_eq!(offsets[7], add);
+    Ok(())
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn hook_errors_trap() -> Result<()> {
+    let engine = engine();
+    let module = Module::new(&engine, WAT)?;
+    let (add, _) = offsets();
+    let mut store = Store::new(&engine, ());
+    store.debug_hook(|_, _| anyhow::bail!("stopped by the debugger"));
+    let instance = Instance::new(&mut store, &module, &[])?;
+    let run = instance.get_typed_func::<i32, i64>(&mut store, "run")?;
+    store.add_breakpoint(&module, add)?;
+    let err = run.call(&mut store, 1).unwrap_err();
+    assert!(
+        format!("{err:?}").contains("stopped by the debugger"),
+        "{err:?}"
+    );
+    assert!(err.downcast_ref::<WasmBacktrace>().is_some());
+    Ok(())
+}
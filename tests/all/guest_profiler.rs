@@ -0,0 +1,171 @@
+#![cfg(not(miri))]
+
+use anyhow::Result;
+use std::sync::Arc;
+use std::time::Duration;
+use wasmtime::*;
+
+const INTERVAL: Duration = Duration::from_millis(1);
+
+const WAT: &str = r#"
+    (module $guest
+        (import "" "host" (func $host))
+        (func $inner
+            call $host)
+        (func $run (export "run")
+            call $inner)
+    )
+"#;
+
+/// Instantiates `WAT` with a host import which samples the profiler and then
+/// sleeps for a few sampling intervals, and calls its `run` export.
+fn profile_core_module() -> Result<Arc<GuestProfiler>> {
+    let engine = Engine::default();
+    let module = Module::new(&engine, WAT)?;
+    let profiler = Arc::new(GuestProfiler::new(
+        "guest",
+        INTERVAL,
+        vec![("guest".to_string(), module.clone())],
+    ));
+
+    let mut store = Store::new(&engine, profiler.clone());
+    store.call_hook(|profiler, kind| {
+        profiler.call_hook(kind);
+        Ok(())
+    });
+    let host = Func::wrap(&mut store, |caller: Caller<'_, Arc<GuestProfiler>>| {
+        caller.data().sample(&caller);
+        std::thread::sleep(INTERVAL * 3);
+    });
+    let instance = Instance::new(&mut store, &module, &[host.into()])?;
+    let run = instance.get_typed_func::<(), ()>(&mut store, "run")?;
+    run.call(&mut store, ())?;
+    Ok(profiler)
+}
+
+#[test]
+fn firefox_profile() -> Result<()> {
+    let profiler = profile_core_module()?;
+    let mut output = Vec::new();
+    profiler.finish(&mut output)?;
+    let profile: serde_json::Value = serde_json::from_slice(&output)?;
+
+    let thread = &profile["threads"][0];
+    let strings = thread["stringArray"].as_array().unwrap();
+    assert!(strings.iter().any(|s| s == "<host>"), "{strings:?}");
+    // One sample is taken by the host function and one is recorded for the
+    // time spent in it.
+    let weights = thread["samples"]["weight"].as_array().unwrap();
+    assert_eq!(weights.len(), 2);
+    assert!(weights[1].as_i64().unwrap() >= 3, "{weights:?}");
+    Ok(())
+}
+
+#[test]
+fn pprof_profile() -> Result<()> {
+    let profiler = profile_core_module()?;
+    let mut output = Vec::new();
+    profiler.finish_pprof(&mut output)?;
+
+    let contains = |s: &str| output.windows(s.len()).any(|w| w == s.as_bytes());
+    for s in [
+        "samples",
+        "wall",
+        "nanoseconds",
+        "thread",
+        "<host>",
+        "run",
+        "inner",
+    ] {
+        assert!(contains(s), "missing string {s:?}");
+    }
+    Ok(())
+}
+
+#[test]
+fn samples_per_thread() -> Result<()> {
+    let engine = Engine::default();
+    let module = Module::new(&engine, WAT)?;
+    let profiler = Arc::new(GuestProfiler::new(
+        "guest",
+        INTERVAL,
+        vec![("guest".to_string(), module.clone())],
+    ));
+
+    let run = |profiler: Arc<GuestProfiler>| -> Result<()> {
+        let mut store = Store::new(&engine, profiler);
+        let host = Func::wrap(&mut store, |caller: Caller<'_, Arc<GuestProfiler>>| {
+            caller.data().sample(&caller);
+        });
+        let instance = Instance::new(&mut store, &module, &[host.into()])?;
+        let run = instance.get_typed_func::<(), ()>(&mut store, "run")?;
+        run.call(&mut store, ())
+    };
+    run(profiler.clone())?;
+    std::thread::scope(|s| s.spawn(|| run(profiler.clone())).join().unwrap())?;
+
+    let mut output = Vec::new();
+    profiler.finish(&mut output)?;
+    let profile: serde_json::Value = serde_json::from_slice(&output)?;
+    let threads = profile["threads"].as_array().unwrap();
+    assert_eq!(threads.len(), 2);
+    for thread in threads {
+        assert_eq!(thread["samples"]["length"], 1);
+    }
+    Ok(())
+}
+
+#[test]
+fn component_profile() -> Result<()> {
+    use wasmtime::component::{Component, Linker};
+
+    let mut config = Config::new();
+    config.wasm_component_model(true);
+    let engine = Engine::new(&config)?;
+    let component = Component::new(
+        &engine,
+        r#"
+            (component
+                (import "host" (func $host))
+                (core func $host (canon lower (func $host)))
+                (core module $m
+                    (import "" "host" (func $host))
+                    (func $inner
+                        call $host)
+                    (func $run (export "run")
+                        call $inner)
+                )
+                (core instance $i (instantiate $m
+                    (with "" (instance (export "host" (func $host))))
+                ))
+                (func (export "run") (canon lift (core func $i "run")))
+            )
+        "#,
+    )?;
+    let profiler = Arc::new(GuestProfiler::new_component(
+        "component",
+        INTERVAL,
+        component.clone(),
+        Vec::new(),
+    ));
+
+    let mut store = Store::new(&engine, profiler.clone());
+    let mut linker = Linker::new(&engine);
+    linker.root().func_wrap(
+        "host",
+        |store: wasmtime::StoreContextMut<'_, Arc<GuestProfiler>>, (): ()| {
+            store.data().sample(&store);
+            Ok(())
+        },
+    )?;
+    let instance = linker.instantiate(&mut store, &component)?;
+    let run = instance.get_typed_func::<(), ()>(&mut store, "run")?;
+    run.call(&mut store, ())?;
+
+    let mut output = Vec::new();
+    profiler.finish_pprof(&mut output)?;
+    let contains = |s: &str| output.windows(s.len()).any(|w| w == s.as_bytes());
+    assert!(contains("m::inner"));
+    assert!(contains("m::run"));
+    Ok(())
+}
@@ -0,0 +1,142 @@
+use wasmtime::*;
+
+fn gc_store_and_module(wat: &str) -> anyhow::Result<(Store<()>, Module)> {
+    let mut config = Config::new();
+    config.wasm_function_references(true).wasm_gc(true);
+    let engine = Engine::new(&config)?;
+    let store = Store::new(&engine, ());
+    let module = Module::new(&engine, wat)?;
+    Ok((store, module))
+}
+
+#[test]
+fn i31_constructors() {
+    assert_eq!(I31::new_u32(0).unwrap().get_u32(), 0);
+    assert_eq!(I31::new_u32(0x7fff_ffff).unwrap().get_u32(), 0x7fff_ffff);
+    assert!(I31::new_u32(0x8000_0000).is_none());
+
+    assert_eq!(I31::new_i32(-1).unwrap().get_i32(), -1);
+    assert_eq!(I31::new_i32(-0x4000_0000).unwrap().get_i32(), -0x4000_0000);
+    assert!(I31::new_i32(0x4000_0000).is_none());
+    assert!(I31::new_i32(-0x4000_0001).is_none());
+
+    assert_eq!(I31::wrapping_u32(0x8000_0001).get_u32(), 1);
+    assert_eq!(I31::wrapping_i32(-1).get_u32(), 0x7fff_ffff);
+    assert_eq!(I31::wrapping_i32(0x4000_0000).get_i32(), -0x4000_0000);
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn pass_anyref_in_and_out_of_wasm() -> anyhow::Result<()> {
+    let (mut store, module) = gc_store_and_module(
+        r#"
+            (module
+                (func (export "id") (param anyref) (result anyref)
+                    local.get 0
+                )
+                (func (export "get") (param anyref) (result i32)
+                    (i31.get_s (ref.cast i31ref (local.get 0)))
+                )
+                (func (export "new") (param i32) (result anyref)
+                    (ref.i31 (local.get 0))
+                )
+            )
+        "#,
+    )?;
+    let instance = Instance::new(&mut store, &module, &[])?;
+
+    let id = instance.get_func(&mut store, "id").unwrap();
+    let anyref = AnyRef::from_i31(I31::wrapping_i32(-42));
+    let mut results = [Val::I32(0)];
+    id.call(
+        &mut store,
+        &[Val::AnyRef(Some(anyref.clone()))],
+        &mut results,
+    )?;
+    assert_eq!(results[0].unwrap_anyref(), Some(anyref.clone()));
+    id.call(&mut store, &[Val::AnyRef(None)], &mut results)?;
+    assert!(results[0].unwrap_anyref().is_none());
+
+    let get = instance.get_typed_func::<Option<AnyRef>, i32>(&mut store, "get")?;
+    assert_eq!(get.call(&mut store, Some(anyref))?, -42);
+    let trap = get.call(&mut store, None).unwrap_err().downcast::<Trap>()?;
+    assert_eq!(trap, Trap::NullReference);
+
+    let new = instance.get_func(&mut store, "new").unwrap();
+    new.call(&mut store, &[Val::I32(0x7fff_ffff)], &mut results)?;
+    let i31 = results[0].unwrap_anyref().unwrap().as_i31().unwrap();
+    assert_eq!(i31.get_u32(), 0x7fff_ffff);
+    assert_eq!(i31.get_i32(), -1);
+    Ok(())
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn cast_failure_trap() -> anyhow::Result<()> {
+    let (mut store, module) = gc_store_and_module(
+        r#"
+            (module
+                (func (export "cast") (param anyref)
+                    (drop (ref.cast structref (local.get 0)))
+                )
+            )
+        "#,
+    )?;
+    let instance = Instance::new(&mut store, &module, &[])?;
+    let cast = instance.get_typed_func::<Option<AnyRef>, ()>(&mut store, "cast")?;
+    cast.call(&mut store, None)?;
+    let trap = cast
+        .call(&mut store, Some(I31::wrapping_u32(1).into()))
+        .unwrap_err()
+        .downcast::<Trap>()?;
+    assert_eq!(trap, Trap::CastFailure);
+    Ok(())
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn anyref_globals() -> anyhow::Result<()> {
+    let (mut store, module) = gc_store_and_module(
+        r#"
+            (module
+                (global (export "g") (mut anyref) (ref.i31 (i32.const 7)))
+            )
+        "#,
+    )?;
+    let instance = Instance::new(&mut store, &module, &[])?;
+    let g = instance.get_global(&mut store, "g").unwrap();
+    assert_eq!(*g.ty(&store).content(), ValType::AnyRef);
+
+    let value = g.get(&mut store);
+    let i31 = value.unwrap_anyref().unwrap().as_i31().unwrap();
+    assert_eq!(i31.get_u32(), 7);
+
+    g.set(&mut store, Val::AnyRef(None))?;
+    assert!(g.get(&mut store).unwrap_anyref().is_none());
+
+    let host = Global::new(
+        &mut store,
+        GlobalType::new(ValType::AnyRef, Mutability::Const),
+        AnyRef::from(I31::wrapping_u32(3)).into(),
+    )?;
+    let i31 = host.get(&mut store).unwrap_anyref().unwrap().as_i31();
+    assert_eq!(i31, Some(I31::wrapping_u32(3)));
+    Ok(())
+}
+
+#[test]
+fn unsupported_gc_features() -> anyhow::Result<()> {
+    let mut config = Config::new();
+    config.wasm_gc(true);
+    assert!(Engine::new(&config).is_err());
+
+    config.wasm_function_references(true);
+    let engine = Engine::new(&config)?;
+    assert!(Module::new(&engine, "(module (type (struct (field externref))))").is_err());
+    assert!(Module::new(&engine, "(module (table 1 anyref))").is_err());
+
+    // Without GC support enabled the GC instructions don't validate.
+    let engine = Engine::default();
+    assert!(Module::new(&engine, "(module (func (drop (ref.i31 (i32.const 0)))))").is_err());
+    Ok(())
+}
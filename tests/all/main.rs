@@ -6,15 +6,22 @@ mod cli_tests;
 mod code_too_large;
 mod component_model;
 mod coredump;
+mod coverage;
 mod debug;
+mod deterministic;
 mod epoch_interruption;
+mod exceptions;
 mod externals;
 mod fuel;
 mod func;
 mod funcref;
 mod gc;
+mod gc_objects;
 mod globals;
+mod guest_debug;
+mod guest_profiler;
 mod host_funcs;
+mod i31ref;
 mod iloop;
 mod import_calling_export;
 mod import_indexes;
@@ -29,7 +36,9 @@ mod module_serialize;
 mod name;
 mod piped_tests;
 mod pooling_allocator;
+mod record_replay;
 mod relocs;
+mod snapshot;
 mod stack_creator;
 mod stack_overflow;
 mod store;
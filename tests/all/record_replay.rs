@@ -0,0 +1,299 @@
+use anyhow::bail;
+use wasmtime::*;
+
+const CORE: &str = r#"
+    (module
+        (import "host" "fill" (func $fill (param i32 i32) (result i32)))
+        (import "host" "next" (func $next (result i32 i64)))
+        (memory (export "memory") 1)
+        (func (export "run") (param $len i32) (result i32)
+            (local $sum i32)
+            (local $i i32)
+            (local.set $sum (call $fill (i32.const 100) (local.get $len)))
+            (loop $l
+                (if (i32.lt_u (local.get $i) (local.get $len))
+                    (then
+                        (local.set $sum
+                            (i32.add (local.get $sum)
+                                (i32.load8_u (i32.add (i32.const 100) (local.get $i)))))
+                        (local.set $i (i32.add (local.get $i) (i32.const 1)))
+                        (br $l))))
+            (call $next)
+            (i32.wrap_i64)
+            (i32.add)
+            (i32.add (local.get $sum)))
+    )
+"#;
+
+/// Instantiates `CORE` with host functions which, unless `panic` is set,
+/// return values that differ from call to call.
+fn core_instance(engine: &Engine, panic: bool) -> Result<(Store<u32>, TypedFunc<i32, i32>)> {
+    let module = Module::new(engine, CORE)?;
+    let mut linker = Linker::<u32>::new(engine);
+    linker.func_new(
+        "host",
+        "fill",
+        FuncType::new([ValType::I32, ValType::I32], [ValType::I32]),
+        move |mut caller, params, results| {
+            assert!(!panic, "host function called during replay");
+            *caller.data_mut() += 1;
+            let seed = *caller.data() as u8;
+            let memory = caller.get_export("memory").unwrap().into_memory().unwrap();
+            let start = params[0].unwrap_i32() as usize;
+            let len = params[1].unwrap_i32() as usize;
+            for (i, byte) in memory.data_mut(&mut caller)[start..][..len]
+                .iter_mut()
+                .enumerate()
+            {
+                *byte = seed.wrapping_mul(31).wrapping_add(i as u8);
+            }
+            if len == 0 {
+                bail!("nothing to fill");
+            }
+            results[0] = Val::I32(len as i32);
+            Ok(())
+        },
+    )?;
+    linker.func_wrap("host", "next", move |mut caller: Caller<'_, u32>| {
+        assert!(!panic, "host function called during replay");
+        *caller.data_mut() += 1;
+        let n = *caller.data();
+        (n as i32, i64::from(n) * 1000)
+    })?;
+    let mut store = Store::new(engine, 0);
+    let instance = linker.instantiate(&mut store, &module)?;
+    let run = instance.get_typed_func::<i32, i32>(&mut store, "run")?;
+    Ok((store, run))
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn core_host_calls() -> Result<()> {
+    let engine = Engine::default();
+
+    let (mut store, run) = core_instance(&engine, false)?;
+    store.record_host_calls();
+    let mut expected = Vec::new();
+    for len in [4, 100, 0, 7] {
+        expected.push(run.call(&mut store, len).map_err(|e| format!("{e:?}")));
+    }
+    assert_eq!(*store.data(), 7);
+    let trace = store.take_host_call_trace().unwrap();
+    assert_eq!(trace.len(), 7);
+    assert!(store.take_host_call_trace().is_none());
+    let trace = HostCallTrace::deserialize(&trace.serialize()?)?;
+
+    let (mut store, run) = core_instance(&engine, true)?;
+    store.replay_host_calls(trace.clone());
+    for (len, expected) in [4, 100, 0, 7].into_iter().zip(expected.iter()) {
+        match (run.call(&mut store, len), expected) {
+            (Ok(actual), Ok(expected)) => assert_eq!(actual, *expected),
+            (Err(actual), Err(expected)) => {
+                assert!(format!("{actual:?}").contains("nothing to fill"));
+                assert!(expected.contains("nothing to fill"));
+            }
+            (actual, expected) => panic!("{actual:?} != {expected:?}"),
+        }
+    }
+
+    // The trace is exhausted after replaying all of it.
+    let err = run.call(&mut store, 4).unwrap_err();
+    assert!(format!("{err:?}").contains("replay diverged"), "{err:?}");
+
+    // Different arguments than the recorded ones are detected.
+    let (mut store, run) = core_instance(&engine, true)?;
+    store.replay_host_calls(trace);
+    let err = run.call(&mut store, 5).unwrap_err();
+    assert!(format!("{err:?}").contains("replay diverged"), "{err:?}");
+    Ok(())
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn replay_memory_growth() -> Result<()> {
+    let engine = Engine::default();
+    let module = Module::new(
+        &engine,
+        r#"
+            (module
+                (import "host" "grow" (func $grow))
+                (memory (export "memory") 1)
+                (func (export "run") (result i32)
+                    (call $grow)
+                    (i32.add (memory.size) (i32.load (i32.const 65536))))
+            )
+        "#,
+    )?;
+    let instantiate = |replay: bool| -> Result<(Store<()>, TypedFunc<(), i32>)> {
+        let mut linker = Linker::new(&engine);
+        linker.func_wrap("host", "grow", move |mut caller: Caller<'_, ()>| {
+            assert!(!replay, "host function called during replay");
+            let memory = caller.get_export("memory").unwrap().into_memory().unwrap();
+            memory.grow(&mut caller, 2)?;
+            memory.data_mut(&mut caller)[65536..][..4].copy_from_slice(&40u32.to_le_bytes());
+            Ok(())
+        })?;
+        let mut store = Store::new(&engine, ());
+        let instance = linker.instantiate(&mut store, &module)?;
+        let run = instance.get_typed_func(&mut store, "run")?;
+        Ok((store, run))
+    };
+
+    let (mut store, run) = instantiate(false)?;
+    store.record_host_calls();
+    assert_eq!(run.call(&mut store, ())?, 43);
+    let trace = store.take_host_call_trace().unwrap();
+
+    let (mut store, run) = instantiate(true)?;
+    store.replay_host_calls(trace);
+    assert_eq!(run.call(&mut store, ())?, 43);
+    Ok(())
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn component_host_calls() -> Result<()> {
+    use wasmtime::component::{Component, Linker};
+
+    let engine = Engine::default();
+    let component = Component::new(
+        &engine,
+        r#"
+            (component
+                (import "get" (func $get (param "n" u32) (result string)))
+                (core module $libc
+                    (memory (export "memory") 1)
+                    (global $next (mut i32) (i32.const 1024))
+                    (func (export "realloc") (param i32 i32 i32 i32) (result i32)
+                        (global.get $next)
+                        (global.set $next (i32.add (global.get $next) (local.get 3))))
+                    (func (export "next") (result i32)
+                        (global.get $next))
+                )
+                (core instance $libc (instantiate $libc))
+                (core func $get
+                    (canon lower (func $get)
+                        (memory $libc "memory") (realloc (func $libc "realloc"))))
+                (core module $m
+                    (import "libc" "memory" (memory 1))
+                    (import "libc" "next" (func $next (result i32)))
+                    (import "" "get" (func $get (param i32 i32)))
+                    (func (export "run") (param i32) (result i32)
+                        (local $ptr i32)
+                        (local $len i32)
+                        (local $sum i32)
+                        (call $get (local.get 0) (i32.const 0))
+                        (local.set $ptr (i32.load (i32.const 0)))
+                        (local.set $len (i32.load (i32.const 4)))
+                        (loop $l
+                            (if (local.get $len)
+                                (then
+                                    (local.set $sum
+                                        (i32.add (local.get $sum) (i32.load8_u (local.get $ptr))))
+                                    (local.set $ptr (i32.add (local.get $ptr) (i32.const 1)))
+                                    (local.set $len (i32.sub (local.get $len) (i32.const 1)))
+                                    (br $l))))
+                        (i32.add (local.get $sum) (call $next)))
+                )
+                (core instance $m (instantiate $m
+                    (with "libc" (instance $libc))
+                    (with "" (instance (export "get" (func $get))))
+                ))
+                (func (export "run") (param "n" u32) (result u32)
+                    (canon lift (core func $m "run")))
+            )
+        "#,
+    )?;
+    let instantiate = |replay: bool| -> Result<_> {
+        let mut linker = Linker::<u32>::new(&engine);
+        linker
+            .root()
+            .func_wrap("get", move |mut store, (n,): (u32,)| {
+                assert!(!replay, "host function called during replay");
+                *store.data_mut() += 1;
+                let c = char::from(b'a' + *store.data() as u8);
+                Ok((c.to_string().repeat(n as usize),))
+            })?;
+        let mut store = Store::new(&engine, 0);
+        let instance = linker.instantiate(&mut store, &component)?;
+        let run = instance.get_typed_func::<(u32,), (u32,)>(&mut store, "run")?;
+        Ok((store, run))
+    };
+
+    let (mut store, run) = instantiate(false)?;
+    store.record_host_calls();
+    let mut expected = Vec::new();
+    for n in [3, 10, 0] {
+        expected.push(run.call(&mut store, (n,))?.0);
+        run.post_return(&mut store)?;
+    }
+    let trace = store.take_host_call_trace().unwrap();
+    assert_eq!(trace.len(), 3);
+
+    let (mut store, run) = instantiate(true)?;
+    store.replay_host_calls(HostCallTrace::deserialize(&trace.serialize()?)?);
+    for (n, expected) in [3, 10, 0].into_iter().zip(expected) {
+        assert_eq!(run.call(&mut store, (n,))?.0, expected);
+        run.post_return(&mut store)?;
+    }
+
+    let (mut store, run) = instantiate(true)?;
+    store.replay_host_calls(trace);
+    let err = run.call(&mut store, (4,)).unwrap_err();
+    assert!(format!("{err:?}").contains("replay diverged"), "{err:?}");
+    Ok(())
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn replay_exit_code() -> Result<()> {
+    #[derive(Debug)]
+    struct Exit(i32);
+
+    impl std::fmt::Display for Exit {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "exit with status {}", self.0)
+        }
+    }
+
+    impl std::error::Error for Exit {}
+
+    let engine = Engine::default();
+    let module = Module::new(
+        &engine,
+        r#"
+            (module
+                (import "host" "exit" (func $exit (param i32)))
+                (func (export "run")
+                    (call $exit (i32.const 3)))
+            )
+        "#,
+    )?;
+    let instantiate = |replay: bool| -> Result<(Store<()>, TypedFunc<(), ()>)> {
+        let mut linker = Linker::new(&engine);
+        linker.func_wrap("host", "exit", move |code: i32| -> Result<()> {
+            assert!(!replay, "host function called during replay");
+            Err(Exit(code).into())
+        })?;
+        let mut store = Store::new(&engine, ());
+        let instance = linker.instantiate(&mut store, &module)?;
+        let run = instance.get_typed_func(&mut store, "run")?;
+        Ok((store, run))
+    };
+
+    let (mut store, run) = instantiate(false)?;
+    store.record_host_calls();
+    store.host_call_exit_code(|e| e.downcast_ref::<Exit>().map(|e| e.0));
+    let err = run.call(&mut store, ()).unwrap_err();
+    assert_eq!(err.downcast_ref::<Exit>().unwrap().0, 3);
+    let trace = store.take_host_call_trace().unwrap();
+    let trace = HostCallTrace::deserialize(&trace.serialize()?)?;
+
+    let (mut store, run) = instantiate(true)?;
+    store.replay_host_calls(trace);
+    let err = run.call(&mut store, ()).unwrap_err();
+    let err = err.downcast_ref::<HostCallError>().unwrap();
+    assert_eq!(err.exit_code(), Some(3));
+    assert_eq!(err.to_string(), "exit with status 3");
+    Ok(())
+}
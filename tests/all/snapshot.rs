@@ -0,0 +1,225 @@
+use wasmtime::*;
+
+const COUNTER: &str = r#"
+    (module
+        (import "" "base" (global $base i32))
+        (memory (export "memory") 1 10)
+        (global $counter (export "counter") (mut i32) (i32.const 0))
+        (global $starts (export "starts") (mut i32) (i32.const 0))
+        (table $t (export "table") 1 10 funcref)
+        (data $passive "passive")
+        (func $start
+            (global.set $starts (i32.add (global.get $starts) (i32.const 1))))
+        (start $start)
+        (func $seven (result i32) (i32.const 7))
+        (elem declare func $seven)
+        (func (export "init")
+            (drop (memory.grow (i32.const 2)))
+            (i32.store (i32.const 0x20000) (global.get $base))
+            (i32.store (i32.const 100) (i32.const 42))
+            (global.set $counter (i32.const 10))
+            (drop (table.grow $t (ref.func $seven) (i32.const 3)))
+            (data.drop $passive))
+        (func (export "next") (result i32)
+            (global.set $counter (i32.add (global.get $counter) (i32.const 1)))
+            (global.get $counter))
+        (func (export "call") (param i32) (result i32)
+            (call_indirect (result i32) (local.get 0)))
+        (func (export "init-passive")
+            (memory.init $passive (i32.const 0) (i32.const 0) (i32.const 1)))
+    )
+"#;
+
+fn check_counter(store: &mut Store<()>, instance: Instance) -> Result<()> {
+    let memory = instance.get_memory(&mut *store, "memory").unwrap();
+    assert_eq!(memory.size(&*store), 3);
+    assert_eq!(memory.data(&*store)[100], 42);
+    assert_eq!(memory.data(&*store)[0x20000], 5);
+    assert_eq!(memory.data(&*store)[..100], [0; 100]);
+
+    // The start function was only run by the original instance.
+    let starts = instance.get_global(&mut *store, "starts").unwrap();
+    assert_eq!(starts.get(&mut *store).unwrap_i32(), 1);
+
+    let next = instance.get_typed_func::<(), i32>(&mut *store, "next")?;
+    assert_eq!(next.call(&mut *store, ())?, 11);
+    assert_eq!(next.call(&mut *store, ())?, 12);
+
+    let table = instance.get_table(&mut *store, "table").unwrap();
+    assert_eq!(table.size(&*store), 4);
+    let call = instance.get_typed_func::<i32, i32>(&mut *store, "call")?;
+    assert_eq!(call.call(&mut *store, 3)?, 7);
+    assert!(call.call(&mut *store, 0).is_err());
+
+    let init_passive = instance.get_typed_func::<(), ()>(&mut *store, "init-passive")?;
+    assert!(init_passive.call(&mut *store, ()).is_err());
+    Ok(())
+}
+
+fn snapshot_and_restore(config: &Config) -> Result<()> {
+    let engine = Engine::new(config)?;
+    let module = Module::new(&engine, COUNTER)?;
+
+    let mut store = Store::new(&engine, ());
+    let base = Global::new(
+        &mut store,
+        GlobalType::new(ValType::I32, Mutability::Const),
+        5.into(),
+    )?;
+    let instance = Instance::new(&mut store, &module, &[base.into()])?;
+    let init = instance.get_typed_func::<(), ()>(&mut store, "init")?;
+    init.call(&mut store, ())?;
+    let snapshot = instance.snapshot(&mut store)?;
+    let bytes = snapshot.serialize()?;
+
+    for _ in 0..3 {
+        let mut store = Store::new(&engine, ());
+        let base = Global::new(
+            &mut store,
+            GlobalType::new(ValType::I32, Mutability::Const),
+            5.into(),
+        )?;
+        let instance = snapshot.instantiate(&mut store, &[base.into()])?;
+        check_counter(&mut store, instance)?;
+    }
+
+    let snapshot = InstanceSnapshot::deserialize(&module, &bytes)?;
+    let mut store = Store::new(&engine, ());
+    let base = Global::new(
+        &mut store,
+        GlobalType::new(ValType::I32, Mutability::Const),
+        5.into(),
+    )?;
+    let instance = snapshot.instantiate(&mut store, &[base.into()])?;
+    check_counter(&mut store, instance)?;
+
+    // Snapshots of a snapshot work too.
+    let snapshot = instance.snapshot(&mut store)?;
+    let instance = snapshot.instantiate(&mut store, &[base.into()])?;
+    let next = instance.get_typed_func::<(), i32>(&mut store, "next")?;
+    assert_eq!(next.call(&mut store, ())?, 13);
+    Ok(())
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn snapshot_restore() -> Result<()> {
+    snapshot_and_restore(&Config::new())
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn snapshot_restore_without_cow() -> Result<()> {
+    let mut config = Config::new();
+    config.memory_init_cow(false);
+    snapshot_and_restore(&config)
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn snapshot_restore_pooling() -> Result<()> {
+    let mut pool = PoolingAllocationConfig::default();
+    pool.memory_pages(10);
+    let mut config = Config::new();
+    config.allocation_strategy(InstanceAllocationStrategy::Pooling(pool));
+    snapshot_and_restore(&config)
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn snapshot_reference_globals() -> Result<()> {
+    let mut config = Config::new();
+    config.wasm_function_references(true).wasm_gc(true);
+    let engine = Engine::new(&config)?;
+    let module = Module::new(
+        &engine,
+        r#"
+            (module
+                (global $f (export "f") (mut funcref) (ref.null func))
+                (global $i (export "i") (mut anyref) (ref.null any))
+                (global $v (mut v128) (v128.const i64x2 0 0))
+                (func $one (result i32) (i32.const 1))
+                (elem declare func $one)
+                (func (export "init")
+                    (global.set $f (ref.func $one))
+                    (global.set $i (ref.i31 (i32.const -3)))
+                    (global.set $v (v128.const i64x2 1 2)))
+                (func (export "v") (result i64)
+                    (i64x2.extract_lane 1 (global.get $v)))
+            )
+        "#,
+    )?;
+    let mut store = Store::new(&engine, ());
+    let instance = Instance::new(&mut store, &module, &[])?;
+    let init = instance.get_typed_func::<(), ()>(&mut store, "init")?;
+    init.call(&mut store, ())?;
+    let snapshot = instance.snapshot(&mut store)?;
+    let snapshot = InstanceSnapshot::deserialize(&module, &snapshot.serialize()?)?;
+
+    let instance = snapshot.instantiate(&mut store, &[])?;
+    let f = instance.get_global(&mut store, "f").unwrap();
+    let f = f.get(&mut store).unwrap_funcref().unwrap().clone();
+    assert_eq!(f.typed::<(), i32>(&store)?.call(&mut store, ())?, 1);
+    let i = instance.get_global(&mut store, "i").unwrap();
+    let i = i.get(&mut store).unwrap_anyref().unwrap();
+    assert_eq!(i.as_i31().unwrap().get_i32(), -3);
+    let v = instance.get_typed_func::<(), i64>(&mut store, "v")?;
+    assert_eq!(v.call(&mut store, ())?, 2);
+    Ok(())
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn snapshot_unsupported_state() -> Result<()> {
+    let mut store = Store::<()>::default();
+
+    let module = Module::new(
+        store.engine(),
+        r#"(module (global (export "g") (mut externref) (ref.null extern)))"#,
+    )?;
+    let instance = Instance::new(&mut store, &module, &[])?;
+    instance.snapshot(&mut store)?;
+    let g = instance.get_global(&mut store, "g").unwrap();
+    g.set(&mut store, Val::ExternRef(Some(ExternRef::new(1))))?;
+    assert!(instance.snapshot(&mut store).is_err());
+
+    let module = Module::new(store.engine(), r#"(module (table (export "t") 1 funcref))"#)?;
+    let instance = Instance::new(&mut store, &module, &[])?;
+    let host = Func::wrap(&mut store, || {});
+    let t = instance.get_table(&mut store, "t").unwrap();
+    t.set(&mut store, 0, host.into())?;
+    let err = instance.snapshot(&mut store).err().unwrap();
+    assert!(
+        err.to_string().contains("doesn't belong to the instance"),
+        "{err}"
+    );
+    Ok(())
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn snapshot_wrong_module() -> Result<()> {
+    let mut store = Store::<()>::default();
+    let module = Module::new(
+        store.engine(),
+        r#"(module (memory 1) (global i32 (i32.const 0)))"#,
+    )?;
+    let instance = Instance::new(&mut store, &module, &[])?;
+    let bytes = instance.snapshot(&mut store)?.serialize()?;
+
+    let other = Module::new(store.engine(), r#"(module (memory 1))"#)?;
+    assert!(InstanceSnapshot::deserialize(&other, &bytes).is_err());
+    let other = Module::new(
+        store.engine(),
+        r#"(module (memory 2) (global i32 (i32.const 0)))"#,
+    )?;
+    assert!(InstanceSnapshot::deserialize(&other, &bytes).is_err());
+    let other = Module::new(
+        store.engine(),
+        r#"(module (memory 1) (global f32 (f32.const 0)))"#,
+    )?;
+    assert!(InstanceSnapshot::deserialize(&other, &bytes).is_err());
+    assert!(InstanceSnapshot::deserialize(&module, &bytes[..bytes.len() - 1]).is_err());
+    InstanceSnapshot::deserialize(&module, &bytes)?;
+    Ok(())
+}
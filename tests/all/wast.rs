@@ -25,9 +25,14 @@ fn run_wast(wast: &str, strategy: Strategy, pooling: bool) -> anyhow::Result<()>
     let memory64 = feature_found(wast, "memory64");
     let multi_memory = feature_found(wast, "multi-memory");
     let threads = feature_found(wast, "threads");
-    let function_references = feature_found(wast, "function-references");
+    // Match the `gc` directory exactly since test names like
+    // `many_table_gets_lead_to_gc` otherwise also match.
+    let gc = wast.iter().any(|part| part == "gc");
+    let function_references = gc || feature_found(wast, "function-references");
     let reference_types = !(threads && feature_found(wast, "proposals"));
     let relaxed_simd = feature_found(wast, "relaxed-simd");
+    let exceptions = feature_found(wast, "exceptions");
+    let extended_const = feature_found(wast, "extended-const");
     let tail_call = feature_found(wast, "tail-call") || feature_found(wast, "function-references");
     let use_shared_memory = feature_found_src(&wast_bytes, "shared_memory")
         || feature_found_src(&wast_bytes, "shared)");
@@ -47,9 +52,12 @@ fn run_wast(wast: &str, strategy: Strategy, pooling: bool) -> anyhow::Result<()>
         .wasm_threads(threads)
         .wasm_memory64(memory64)
         .wasm_function_references(function_references)
+        .wasm_gc(gc)
         .wasm_reference_types(reference_types)
         .wasm_relaxed_simd(relaxed_simd)
         .wasm_tail_call(tail_call)
+        .wasm_exceptions(exceptions)
+        .wasm_extended_const(extended_const)
         .strategy(strategy);
 
     if is_cranelift {
@@ -60,6 +60,41 @@ const MIXED: &'static str = r#"
       (export "call_id_float" (func $call_id_float)))
 "#;
 
+const MIXED_TAIL: &'static str = r#"
+    (module
+      (import "" "" (func $id_float (param f64 f64 f64 f64 f32 f32 f32 f32 f64 i32 i64) (result f64)))
+      (func $tail_call_id_float (param f64 f64 f64 f64 f32 f32 f32 f32 f64 i32 i64) (result f64)
+         (return_call $id_float
+           (local.get 0)
+           (local.get 1)
+           (local.get 2)
+           (local.get 3)
+           (local.get 4)
+           (local.get 5)
+           (local.get 6)
+           (local.get 7)
+           (local.get 8)
+           (local.get 9)
+           (local.get 10))
+      )
+      (func $tail_call_from_fewer_args (param f64) (result f64)
+         (return_call $tail_call_id_float
+           (f64.const 0)
+           (f64.const 0)
+           (f64.const 0)
+           (f64.const 0)
+           (f32.const 0)
+           (f32.const 0)
+           (f32.const 0)
+           (f32.const 0)
+           (local.get 0)
+           (i32.const 0)
+           (i64.const 0))
+      )
+      (export "tail_call_id_float" (func $tail_call_id_float))
+      (export "tail_call_from_fewer_args" (func $tail_call_from_fewer_args)))
+"#;
+
 fn add_fn(store: impl AsContextMut) -> Func {
     Func::wrap(store, |a: i32, b: i32| a + b)
 }
@@ -173,6 +208,38 @@ fn mixed_roundtrip() -> Result<()> {
     Ok(())
 }
 
+#[test]
+#[cfg_attr(miri, ignore)]
+fn mixed_tail_call_roundtrip() -> Result<()> {
+    let mut c = Config::new();
+    c.strategy(Strategy::Winch);
+    c.wasm_tail_call(true);
+    let engine = Engine::new(&c)?;
+    let mut store = Store::new(&engine, ());
+
+    let module = Module::new(&engine, MIXED_TAIL)?;
+    let import = id_float(store.as_context_mut());
+
+    let instance = Instance::new(&mut store, &module, &[import.into()])?;
+    let tail_call_id_float = instance
+        .get_typed_func::<(f64, f64, f64, f64, f32, f32, f32, f32, f64, i32, i64), f64>(
+            &mut store,
+            "tail_call_id_float",
+        )?;
+
+    let result = tail_call_id_float.call(
+        &mut store,
+        (1.1, 1.2, 1.3, 1.4, 1.5, 1.6, 1.7, 1.8, 1.9, 0, 5),
+    )?;
+    assert_eq!(result, 1.9);
+
+    let tail_call_from_fewer_args =
+        instance.get_typed_func::<f64, f64>(&mut store, "tail_call_from_fewer_args")?;
+    let result = tail_call_from_fewer_args.call(&mut store, 2.5)?;
+    assert_eq!(result, 2.5);
+    Ok(())
+}
+
 #[test]
 #[cfg_attr(miri, ignore)]
 #[cfg_attr(windows, ignore)]
@@ -239,3 +306,78 @@ fn wasm_to_native_trap() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn epoch_interruption() -> Result<()> {
+    let mut c = Config::new();
+    c.strategy(Strategy::Winch);
+    c.epoch_interruption(true);
+    let engine = Engine::new(&c)?;
+    let wat = r#"
+        (module
+          (import "" "" (func $bump_epoch))
+          (func $run (param i32)
+            (loop $l
+              call $bump_epoch
+              (br_if $l (local.tee 0 (i32.sub (local.get 0) (i32.const 1))))))
+
+          (export "run" (func $run)))
+    "#;
+    let module = Module::new(&engine, wat)?;
+    let bump_epoch = |store: &mut Store<usize>| {
+        let engine = engine.clone();
+        Func::wrap(store, move || engine.increment_epoch())
+    };
+
+    // The deadline is checked at every loop header, so with a deadline of 5
+    // ticks and a delta of 1 tick, the callback is invoked at ticks 5..=9.
+    let mut store = Store::new(&engine, 0);
+    store.set_epoch_deadline(5);
+    store.epoch_deadline_callback(|mut cx| {
+        *cx.data_mut() += 1;
+        Ok(UpdateDeadline::Continue(1))
+    });
+    let func = bump_epoch(&mut store);
+    let instance = Instance::new(&mut store, &module, &[func.into()])?;
+    let run = instance.get_typed_func::<i32, ()>(&mut store, "run")?;
+    run.call(&mut store, 10)?;
+    assert_eq!(*store.data(), 5);
+
+    // Without a callback, reaching the deadline traps.
+    let mut store = Store::new(&engine, 0);
+    store.set_epoch_deadline(1);
+    let func = bump_epoch(&mut store);
+    let instance = Instance::new(&mut store, &module, &[func.into()])?;
+    let run = instance.get_typed_func::<i32, ()>(&mut store, "run")?;
+    let trap = run.call(&mut store, 10).unwrap_err();
+    assert_eq!(trap.downcast::<Trap>()?, Trap::Interrupt);
+
+    Ok(())
+}
+
+#[test]
+#[cfg_attr(any(miri, not(feature = "all-arch")), ignore)]
+fn aarch64_rejects_simd() -> Result<()> {
+    let mut c = Config::new();
+    c.strategy(Strategy::Winch);
+    c.target("aarch64-unknown-linux-gnu")?;
+    let engine = Engine::new(&c)?;
+
+    // Modules for another target can only be precompiled.
+    engine.precompile_module(b"(module (func (result i32) i32.const 1))")?;
+    for wat in [
+        "(module (func (result i32) (i32x4.extract_lane 0 (v128.const i64x2 0 0))))",
+        "(module (func (local v128)))",
+        "(module (func (param v128)))",
+        "(module (global v128 (v128.const i64x2 0 0)) (func))",
+        "(module (func (block (result v128) unreachable) drop))",
+    ] {
+        let err = engine.precompile_module(wat.as_bytes()).unwrap_err();
+        assert!(
+            format!("{err:?}").contains("SIMD is not supported by Winch on aarch64"),
+            "{wat}: {err:?}"
+        );
+    }
+    Ok(())
+}
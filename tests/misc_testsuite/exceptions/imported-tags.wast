@@ -0,0 +1,43 @@
+(module $a
+  (tag $t (export "t") (param i32))
+  (tag $u (export "u") (param i32))
+  (func (export "throw-t") (param i32)
+    (throw $t (local.get 0)))
+  (func (export "throw-u") (param i32)
+    (throw $u (local.get 0)))
+)
+(register "a" $a)
+
+(module $b
+  (import "a" "t" (tag $t (param i32)))
+  (import "a" "throw-t" (func $throw-t (param i32)))
+  (import "a" "throw-u" (func $throw-u (param i32)))
+  ;; A tag with the same type as the imported tags is still a different tag.
+  (tag $local (param i32))
+
+  (func (export "catch-imported") (result i32)
+    (block $h (result i32)
+      (try_table (catch $local $h) (catch $t $h)
+        (call $throw-t (i32.const 1)))
+      (unreachable)))
+
+  (func (export "catch-other") (result i32)
+    (block $h (result i32)
+      (try_table (catch $t $h) (catch $local $h)
+        (call $throw-u (i32.const 2)))
+      (unreachable)))
+
+  (func (export "throw-local")
+    (throw $local (i32.const 3)))
+)
+
+(assert_return (invoke "catch-imported") (i32.const 1))
+(assert_exception (invoke "catch-other"))
+(assert_exception (invoke "throw-local"))
+
+(assert_unlinkable
+  (module (import "a" "t" (tag (param i64))))
+  "tag types incompatible")
+(assert_unlinkable
+  (module (import "a" "throw-t" (tag (param i32))))
+  "expected tag, but found func")
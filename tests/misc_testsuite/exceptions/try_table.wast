@@ -0,0 +1,141 @@
+(module
+  (tag $e0)
+  (tag $e1 (param i32))
+  (tag $e2 (param i32 i64))
+  (tag $e3 (param f32 f64 v128))
+
+  (func $throw-e1 (param i32)
+    (throw $e1 (local.get 0)))
+
+  (func $throw-if (param i32) (result i32)
+    (if (local.get 0) (then (throw $e1 (local.get 0))))
+    (i32.const 100))
+
+  (func (export "catch-e0") (result i32)
+    (block $h
+      (try_table (catch $e0 $h)
+        (throw $e0))
+      (return (i32.const 0)))
+    (i32.const 1))
+
+  (func (export "catch-e1") (param i32) (result i32)
+    (block $h (result i32)
+      (try_table (result i32) (catch $e1 $h)
+        (call $throw-if (local.get 0)))))
+
+  (func (export "catch-e2") (result i32 i64)
+    (block $h (result i32 i64)
+      (try_table (catch $e2 $h)
+        (throw $e2 (i32.const 3) (i64.const 4)))
+      (unreachable)))
+
+  (func (export "catch-e3") (result f32 f64 i32)
+    (local v128)
+    (block $h (result f32 f64 v128)
+      (try_table (catch $e3 $h)
+        (throw $e3 (f32.const 1.5) (f64.const -2.5) (v128.const i32x4 1 2 3 4)))
+      (unreachable))
+    (local.set 0)
+    (i32x4.extract_lane 2 (local.get 0)))
+
+  (func (export "catch-all") (param i32) (result i32)
+    (block $h
+      (try_table (result i32) (catch_all $h)
+        (call $throw-if (local.get 0)))
+      (return))
+    (i32.const -1))
+
+  (func (export "no-match") (result i32)
+    (block $outer (result i32)
+      (block $inner
+        (try_table (catch $e1 $outer)
+          (try_table (catch $e0 $inner)
+            (call $throw-e1 (i32.const 7))))
+        (return (i32.const 0)))
+      (return (i32.const 1))))
+
+  (func (export "first-match") (result i32)
+    (block $a (result i32)
+      (block $b (result i32)
+        (try_table (catch $e1 $a) (catch $e1 $b)
+          (call $throw-e1 (i32.const 5)))
+        (unreachable))
+      (i32.add (i32.const 100))))
+
+  (func (export "rethrow") (result i32)
+    (block $h (result i32)
+      (try_table (catch $e1 $h)
+        (block $h2 (result i32 exnref)
+          (try_table (catch_ref $e1 $h2)
+            (call $throw-e1 (i32.const 9)))
+          (unreachable))
+        (throw_ref))
+      (unreachable)))
+
+  (func (export "rethrow-all") (result i32)
+    (block $h (result i32)
+      (try_table (catch $e1 $h)
+        (block $h2 (result exnref)
+          (try_table (catch_all_ref $h2)
+            (call $throw-e1 (i32.const 11)))
+          (unreachable))
+        (throw_ref))
+      (unreachable)))
+
+  (func (export "throw-ref-null")
+    (throw_ref (ref.null exn)))
+
+  (func (export "uncaught") (param i32)
+    (call $throw-e1 (local.get 0)))
+
+  (func (export "uncaught-with-results") (result i32 i64 f32)
+    (call $throw-e1 (i32.const 0))
+    (i32.const 1) (i64.const 2) (f32.const 3))
+
+  (table funcref (elem $throw-e1))
+  (func (export "call-indirect") (result i32)
+    (block $h (result i32)
+      (try_table (catch $e1 $h)
+        (call_indirect (param i32) (i32.const 13) (i32.const 0)))
+      (unreachable)))
+
+  ;; Locals keep their values when an exception is caught.
+  (func (export "loop") (param i32) (result i32)
+    (local $sum i32)
+    (loop $l
+      (block $h (result i32)
+        (try_table (catch $e1 $h)
+          (call $throw-e1 (local.get 0)))
+        (unreachable))
+      (local.set $sum (i32.add (local.get $sum)))
+      (local.tee 0 (i32.sub (local.get 0) (i32.const 1)))
+      (br_if $l))
+    (local.get $sum))
+
+  ;; A caught exception doesn't leak into later calls.
+  (func (export "after-catch") (result i32)
+    (block $h (result i32)
+      (try_table (catch $e1 $h)
+        (call $throw-e1 (i32.const 1)))
+      (unreachable))
+    (drop)
+    (call $throw-if (i32.const 0)))
+)
+
+(assert_return (invoke "catch-e0") (i32.const 1))
+(assert_return (invoke "catch-e1" (i32.const 0)) (i32.const 100))
+(assert_return (invoke "catch-e1" (i32.const 42)) (i32.const 42))
+(assert_return (invoke "catch-e2") (i32.const 3) (i64.const 4))
+(assert_return (invoke "catch-e3") (f32.const 1.5) (f64.const -2.5) (i32.const 3))
+(assert_return (invoke "catch-all" (i32.const 0)) (i32.const 100))
+(assert_return (invoke "catch-all" (i32.const 1)) (i32.const -1))
+(assert_return (invoke "no-match") (i32.const 7))
+(assert_return (invoke "first-match") (i32.const 5))
+(assert_return (invoke "rethrow") (i32.const 9))
+(assert_return (invoke "rethrow-all") (i32.const 11))
+(assert_trap (invoke "throw-ref-null") "null reference")
+(assert_exception (invoke "uncaught" (i32.const 3)))
+(assert_exception (invoke "uncaught-with-results"))
+(assert_return (invoke "call-indirect") (i32.const 13))
+(assert_return (invoke "loop" (i32.const 4)) (i32.const 10))
+(assert_return (invoke "after-catch") (i32.const 100))
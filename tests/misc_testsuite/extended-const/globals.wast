@@ -0,0 +1,44 @@
+(module $env
+  (global (export "g32") i32 (i32.const 10))
+  (global (export "g64") i64 (i64.const 100))
+)
+(register "env" $env)
+
+(module
+  (import "env" "g32" (global $g32 i32))
+  (import "env" "g64" (global $g64 i64))
+
+  (global $a (export "a") i32 (i32.add (global.get $g32) (i32.const 5)))
+  (global $b (export "b") i32 (i32.sub (global.get $g32) (i32.const 11)))
+  (global $c (export "c") i32 (i32.mul (global.get $g32) (global.get $g32)))
+  (global $d (export "d") i64 (i64.add (global.get $g64) (i64.const 1)))
+  (global $e (export "e") i64 (i64.sub (i64.const 0) (global.get $g64)))
+  (global $f (export "f") i64 (i64.mul (global.get $g64) (i64.const 0x100000000)))
+  (global $nested (export "nested") i32
+    (i32.add
+      (i32.mul (global.get $g32) (i32.const 3))
+      (i32.sub (i32.const 7) (i32.const 2))))
+  (global $wrap32 (export "wrap32") i32 (i32.add (i32.const 0x7fffffff) (i32.const 1)))
+  (global $wrap64 (export "wrap64") i64 (i64.mul (i64.const 0x4000000000000000) (i64.const 4)))
+  (global $mut (export "mut") (mut i32) (i32.mul (i32.const 6) (i32.const 7)))
+)
+
+(assert_return (get "a") (i32.const 15))
+(assert_return (get "b") (i32.const -1))
+(assert_return (get "c") (i32.const 100))
+(assert_return (get "d") (i64.const 101))
+(assert_return (get "e") (i64.const -100))
+(assert_return (get "f") (i64.const 0x6400000000))
+(assert_return (get "nested") (i32.const 35))
+(assert_return (get "wrap32") (i32.const 0x80000000))
+(assert_return (get "wrap64") (i64.const 0))
+(assert_return (get "mut") (i32.const 42))
+
+;; Expressions made only of constants.
+(module
+  (global (export "a") i32 (i32.mul (i32.const 3) (i32.const 4)))
+  (global (export "b") i64 (i64.add (i64.const 1) (i64.const 2)))
+)
+
+(assert_return (get "a") (i32.const 12))
+(assert_return (get "b") (i64.const 3))
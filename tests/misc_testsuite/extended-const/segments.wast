@@ -0,0 +1,64 @@
+(module $env
+  (global (export "base") i32 (i32.const 8))
+)
+(register "env" $env)
+
+;; Data segment offsets computed from imported globals.
+(module
+  (import "env" "base" (global $base i32))
+  (memory 1)
+  (data (i32.add (global.get $base) (i32.const 2)) "\01\02")
+  (data (i32.mul (global.get $base) (i32.const 4)) "\03")
+  (func (export "load") (param i32) (result i32)
+    (i32.load8_u (local.get 0)))
+)
+
+(assert_return (invoke "load" (i32.const 9)) (i32.const 0))
+(assert_return (invoke "load" (i32.const 10)) (i32.const 1))
+(assert_return (invoke "load" (i32.const 11)) (i32.const 2))
+(assert_return (invoke "load" (i32.const 32)) (i32.const 3))
+
+;; Constant data segment offsets, which can be used for static memory
+;; initialization.
+(module
+  (memory 1)
+  (data (i32.add (i32.const 0x1000) (i32.const 0x10)) "\04")
+  (data (i32.sub (i32.mul (i32.const 2) (i32.const 0x1000)) (i32.const 1)) "\05\06")
+  (func (export "load") (param i32) (result i32)
+    (i32.load8_u (local.get 0)))
+)
+
+(assert_return (invoke "load" (i32.const 0x1010)) (i32.const 4))
+(assert_return (invoke "load" (i32.const 0x1fff)) (i32.const 5))
+(assert_return (invoke "load" (i32.const 0x2000)) (i32.const 6))
+
+;; Element segment offsets.
+(module
+  (import "env" "base" (global $base i32))
+  (table 32 funcref)
+  (elem (i32.add (global.get $base) (i32.const 1)) $f1)
+  (elem (i32.mul (i32.const 4) (i32.const 5)) $f2)
+  (func $f1 (result i32) (i32.const 1))
+  (func $f2 (result i32) (i32.const 2))
+  (func (export "call") (param i32) (result i32)
+    (call_indirect (result i32) (local.get 0)))
+)
+
+(assert_return (invoke "call" (i32.const 9)) (i32.const 1))
+(assert_return (invoke "call" (i32.const 20)) (i32.const 2))
+(assert_trap (invoke "call" (i32.const 8)) "uninitialized element")
+
+;; Offsets wrap around according to `i32` arithmetic, so these are out of
+;; bounds.
+(assert_trap
+  (module
+    (memory 1)
+    (data (i32.sub (i32.const 0) (i32.const 1)) "\00"))
+  "out of bounds memory access")
+(assert_trap
+  (module
+    (import "env" "base" (global $base i32))
+    (table 1 funcref)
+    (elem (i32.sub (global.get $base) (i32.const 9)) $f)
+    (func $f))
+  "out of bounds table access")
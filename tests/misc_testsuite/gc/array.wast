@@ -0,0 +1,194 @@
+(module
+  (type $ints (array (mut i32)))
+  (type $bytes (array (mut i8)))
+  (type $i64s (array i64))
+  (type $refs (array (mut anyref)))
+  (type $funcs (array funcref))
+
+  (memory 1)
+  (data $d "\01\02\03\04\05\06\07\08")
+
+  (func $f1 (result i32) (i32.const 1))
+  (func $f2 (result i32) (i32.const 2))
+  (elem $e func $f1 $f2)
+  (type $ret-i32 (func (result i32)))
+
+  (func (export "new") (param i32 i32 i32) (result i32 i32)
+    (local $a (ref null $ints))
+    (local.set $a (array.new $ints (local.get 0) (local.get 1)))
+    (array.len (local.get $a))
+    (array.get $ints (local.get $a) (local.get 2)))
+
+  (func (export "new-default") (param i32) (result i32)
+    (array.get $ints (array.new_default $ints (i32.const 4)) (local.get 0)))
+
+  (func (export "new-fixed") (param i32) (result i64)
+    (array.get $i64s
+      (array.new_fixed $i64s 3 (i64.const 10) (i64.const 20) (i64.const 30))
+      (local.get 0)))
+
+  (func (export "set-get") (param i32 i32) (result i32)
+    (local $a (ref null $ints))
+    (local.set $a (array.new_default $ints (i32.const 4)))
+    (array.set $ints (local.get $a) (local.get 0) (local.get 1))
+    (array.get $ints (local.get $a) (local.get 0)))
+
+  (func (export "packed") (param i32) (result i32 i32)
+    (local $a (ref null $bytes))
+    (local.set $a (array.new $bytes (local.get 0) (i32.const 2)))
+    (array.get_s $bytes (local.get $a) (i32.const 1))
+    (array.get_u $bytes (local.get $a) (i32.const 1)))
+
+  (func (export "fill") (param i32 i32 i32) (result i32)
+    (local $a (ref null $ints))
+    (local $sum i32)
+    (local $i i32)
+    (local.set $a (array.new_default $ints (i32.const 8)))
+    (array.fill $ints (local.get $a) (local.get 0) (local.get 1) (local.get 2))
+    (block $done
+      (loop $l
+        (br_if $done (i32.eq (local.get $i) (i32.const 8)))
+        (local.set $sum
+          (i32.add (local.get $sum)
+            (array.get $ints (local.get $a) (local.get $i))))
+        (local.set $i (i32.add (local.get $i) (i32.const 1)))
+        (br $l)))
+    (local.get $sum))
+
+  (func $iota (param $n i32) (result (ref $ints))
+    (local $a (ref null $ints))
+    (local $i i32)
+    (local.set $a (array.new_default $ints (local.get $n)))
+    (block $done
+      (loop $l
+        (br_if $done (i32.eq (local.get $i) (local.get $n)))
+        (array.set $ints (local.get $a) (local.get $i) (local.get $i))
+        (local.set $i (i32.add (local.get $i) (i32.const 1)))
+        (br $l)))
+    (ref.as_non_null (local.get $a)))
+
+  ;; Copies within one array, with overlapping ranges, and returns the
+  ;; element at `index`.
+  (func (export "copy-self") (param $dst i32) (param $src i32) (param $len i32) (param $index i32) (result i32)
+    (local $a (ref null $ints))
+    (local.set $a (call $iota (i32.const 8)))
+    (array.copy $ints $ints
+      (local.get $a) (local.get $dst) (local.get $a) (local.get $src) (local.get $len))
+    (array.get $ints (local.get $a) (local.get $index)))
+
+  (func (export "copy") (param $dst i32) (param $src i32) (param $len i32) (param $index i32) (result i32)
+    (local $a (ref null $ints))
+    (local.set $a (array.new_default $ints (i32.const 8)))
+    (array.copy $ints $ints
+      (local.get $a) (local.get $dst) (call $iota (i32.const 8)) (local.get $src) (local.get $len))
+    (array.get $ints (local.get $a) (local.get $index)))
+
+  (func (export "new-data") (param i32 i32 i32) (result i32)
+    (array.get_u $bytes
+      (array.new_data $bytes $d (local.get 0) (local.get 1))
+      (local.get 2)))
+
+  (func (export "new-data-ints") (param i32) (result i32)
+    (array.get $ints
+      (array.new_data $ints $d (i32.const 0) (i32.const 2))
+      (local.get 0)))
+
+  (func (export "init-data") (param i32 i32 i32) (result i32)
+    (local $a (ref null $bytes))
+    (local.set $a (array.new_default $bytes (i32.const 4)))
+    (array.init_data $bytes $d (local.get $a) (local.get 0) (local.get 1) (local.get 2))
+    (array.get_u $bytes (local.get $a) (local.get 0)))
+
+  (func (export "new-elem") (param i32) (result i32)
+    (call_ref $ret-i32
+      (ref.cast (ref $ret-i32)
+        (array.get $funcs
+          (array.new_elem $funcs $e (i32.const 0) (i32.const 2))
+          (local.get 0)))))
+
+  (func (export "new-elem-oob") (param i32 i32)
+    (drop (array.new_elem $funcs $e (local.get 0) (local.get 1))))
+
+  (func (export "refs") (result i32)
+    (local $a (ref null $refs))
+    (local.set $a (array.new $refs (ref.i31 (i32.const 3)) (i32.const 2)))
+    (array.set $refs (local.get $a) (i32.const 1) (array.new_default $ints (i32.const 5)))
+    (i32.add
+      (i31.get_u (ref.cast i31ref (array.get $refs (local.get $a) (i32.const 0))))
+      (array.len (ref.cast arrayref (array.get $refs (local.get $a) (i32.const 1))))))
+
+  (func (export "null-len") (result i32)
+    (array.len (ref.null array)))
+  (func (export "null-get") (result i32)
+    (array.get $ints (ref.null $ints) (i32.const 0)))
+
+  (func (export "test") (result i32 i32 i32 i32)
+    (local $a anyref)
+    (local.set $a (array.new_default $ints (i32.const 1)))
+    (ref.test arrayref (local.get $a))
+    (ref.test (ref $ints) (local.get $a))
+    (ref.test (ref $bytes) (local.get $a))
+    (ref.test structref (local.get $a)))
+
+  ;; Allocates lots of short-lived arrays so that the GC heap must be
+  ;; collected, keeping one of them alive throughout.
+  (func (export "churn") (param $n i32) (result i32)
+    (local $keep (ref null $ints))
+    (local.set $keep (array.new $ints (i32.const 7) (i32.const 16)))
+    (block $done
+      (loop $l
+        (br_if $done (i32.eqz (local.get $n)))
+        (drop (array.new_default $ints (i32.const 1024)))
+        (local.set $n (i32.sub (local.get $n) (i32.const 1)))
+        (br $l)))
+    (array.get $ints (local.get $keep) (i32.const 15)))
+
+  (func (export "too-large")
+    (drop (array.new_default $ints (i32.const -1))))
+)
+
+(assert_return (invoke "new" (i32.const 9) (i32.const 3) (i32.const 2)) (i32.const 3) (i32.const 9))
+(assert_trap (invoke "new" (i32.const 9) (i32.const 3) (i32.const 3)) "out of bounds array access")
+(assert_trap (invoke "new" (i32.const 9) (i32.const 0) (i32.const 0)) "out of bounds array access")
+(assert_trap (invoke "new" (i32.const 9) (i32.const 3) (i32.const -1)) "out of bounds array access")
+(assert_return (invoke "new-default" (i32.const 3)) (i32.const 0))
+(assert_return (invoke "new-fixed" (i32.const 0)) (i64.const 10))
+(assert_return (invoke "new-fixed" (i32.const 2)) (i64.const 30))
+(assert_trap (invoke "new-fixed" (i32.const 3)) "out of bounds array access")
+(assert_return (invoke "set-get" (i32.const 3) (i32.const 42)) (i32.const 42))
+(assert_trap (invoke "set-get" (i32.const 4) (i32.const 42)) "out of bounds array access")
+(assert_return (invoke "packed" (i32.const 0x80)) (i32.const -128) (i32.const 0x80))
+(assert_return (invoke "packed" (i32.const 0x17f)) (i32.const 0x7f) (i32.const 0x7f))
+
+(assert_return (invoke "fill" (i32.const 2) (i32.const 3) (i32.const 4)) (i32.const 12))
+(assert_return (invoke "fill" (i32.const 8) (i32.const 3) (i32.const 0)) (i32.const 0))
+(assert_return (invoke "fill" (i32.const 0) (i32.const 1) (i32.const 8)) (i32.const 8))
+(assert_trap (invoke "fill" (i32.const 7) (i32.const 1) (i32.const 2)) "out of bounds array access")
+(assert_trap (invoke "fill" (i32.const 9) (i32.const 1) (i32.const 0)) "out of bounds array access")
+(assert_trap (invoke "fill" (i32.const 1) (i32.const 1) (i32.const -1)) "out of bounds array access")
+
+(assert_return (invoke "copy-self" (i32.const 2) (i32.const 0) (i32.const 4) (i32.const 5)) (i32.const 3))
+(assert_return (invoke "copy-self" (i32.const 0) (i32.const 2) (i32.const 4) (i32.const 1)) (i32.const 3))
+(assert_return (invoke "copy-self" (i32.const 0) (i32.const 2) (i32.const 4) (i32.const 6)) (i32.const 6))
+(assert_return (invoke "copy" (i32.const 4) (i32.const 0) (i32.const 4) (i32.const 7)) (i32.const 3))
+(assert_return (invoke "copy" (i32.const 8) (i32.const 8) (i32.const 0) (i32.const 0)) (i32.const 0))
+(assert_trap (invoke "copy" (i32.const 5) (i32.const 0) (i32.const 4) (i32.const 0)) "out of bounds array access")
+(assert_trap (invoke "copy" (i32.const 0) (i32.const 5) (i32.const 4) (i32.const 0)) "out of bounds array access")
+
+(assert_return (invoke "new-data" (i32.const 2) (i32.const 4) (i32.const 3)) (i32.const 6))
+(assert_trap (invoke "new-data" (i32.const 2) (i32.const 7) (i32.const 0)) "out of bounds memory access")
+(assert_return (invoke "new-data-ints" (i32.const 1)) (i32.const 0x08070605))
+(assert_return (invoke "init-data" (i32.const 1) (i32.const 4) (i32.const 3)) (i32.const 5))
+(assert_trap (invoke "init-data" (i32.const 2) (i32.const 0) (i32.const 3)) "out of bounds array access")
+(assert_trap (invoke "init-data" (i32.const 0) (i32.const 6) (i32.const 3)) "out of bounds memory access")
+
+(assert_return (invoke "new-elem" (i32.const 0)) (i32.const 1))
+(assert_return (invoke "new-elem" (i32.const 1)) (i32.const 2))
+(assert_trap (invoke "new-elem-oob" (i32.const 1) (i32.const 2)) "out of bounds table access")
+
+(assert_return (invoke "refs") (i32.const 8))
+(assert_trap (invoke "null-len") "null reference")
+(assert_trap (invoke "null-get") "null reference")
+(assert_return (invoke "test") (i32.const 1) (i32.const 1) (i32.const 0) (i32.const 0))
+(assert_return (invoke "churn" (i32.const 10000)) (i32.const 7))
+(assert_trap (invoke "too-large") "allocation size too large")
@@ -0,0 +1,89 @@
+(module
+  (func (export "new") (param i32) (result anyref)
+    (ref.i31 (local.get 0)))
+
+  (func (export "get_u") (param i32) (result i32)
+    (i31.get_u (ref.i31 (local.get 0))))
+
+  (func (export "get_s") (param i32) (result i32)
+    (i31.get_s (ref.i31 (local.get 0))))
+
+  (func (export "get_u-null") (result i32)
+    (i31.get_u (ref.null i31)))
+
+  (func (export "get_s-null") (result i32)
+    (i31.get_s (ref.null i31)))
+
+  (func (export "is_null") (param anyref) (result i32)
+    (ref.is_null (local.get 0)))
+
+  (func (export "eq") (param i32 i32) (result i32)
+    (ref.eq (ref.i31 (local.get 0)) (ref.i31 (local.get 1))))
+
+  (func (export "eq-null") (param i32) (result i32)
+    (ref.eq (ref.i31 (local.get 0)) (ref.null eq)))
+)
+
+(assert_return (invoke "new" (i32.const 1)) (ref.i31))
+(assert_return (invoke "is_null" (ref.null any)) (i32.const 1))
+
+(assert_return (invoke "get_u" (i32.const 0)) (i32.const 0))
+(assert_return (invoke "get_u" (i32.const 100)) (i32.const 100))
+(assert_return (invoke "get_u" (i32.const -1)) (i32.const 0x7fff_ffff))
+(assert_return (invoke "get_u" (i32.const 0x3fff_ffff)) (i32.const 0x3fff_ffff))
+(assert_return (invoke "get_u" (i32.const 0x4000_0000)) (i32.const 0x4000_0000))
+(assert_return (invoke "get_u" (i32.const 0x7fff_ffff)) (i32.const 0x7fff_ffff))
+(assert_return (invoke "get_u" (i32.const 0xaaaa_aaaa)) (i32.const 0x2aaa_aaaa))
+(assert_return (invoke "get_u" (i32.const 0xcaaa_aaaa)) (i32.const 0x4aaa_aaaa))
+
+(assert_return (invoke "get_s" (i32.const 0)) (i32.const 0))
+(assert_return (invoke "get_s" (i32.const 100)) (i32.const 100))
+(assert_return (invoke "get_s" (i32.const -1)) (i32.const -1))
+(assert_return (invoke "get_s" (i32.const 0x3fff_ffff)) (i32.const 0x3fff_ffff))
+(assert_return (invoke "get_s" (i32.const 0x4000_0000)) (i32.const -0x4000_0000))
+(assert_return (invoke "get_s" (i32.const 0x7fff_ffff)) (i32.const -1))
+(assert_return (invoke "get_s" (i32.const 0xaaaa_aaaa)) (i32.const 0x2aaa_aaaa))
+(assert_return (invoke "get_s" (i32.const 0xcaaa_aaaa)) (i32.const 0xcaaa_aaaa))
+
+(assert_trap (invoke "get_u-null") "null reference")
+(assert_trap (invoke "get_s-null") "null reference")
+
+(assert_return (invoke "eq" (i32.const 1) (i32.const 1)) (i32.const 1))
+(assert_return (invoke "eq" (i32.const 1) (i32.const 2)) (i32.const 0))
+;; Only the low 31 bits are preserved.
+(assert_return (invoke "eq" (i32.const 0x8000_0001) (i32.const 1)) (i32.const 1))
+(assert_return (invoke "eq-null" (i32.const 0)) (i32.const 0))
+
+;; Globals initialized with constant `i31ref`s.
+(module
+  (global $a (ref i31) (ref.i31 (i32.const 42)))
+  (global $b (mut anyref) (ref.i31 (i32.const -5)))
+  (global $c (mut i31ref) (ref.null i31))
+
+  (func (export "get-a") (result i32)
+    (i31.get_u (global.get $a)))
+  (func (export "get-b") (result anyref)
+    (global.get $b))
+  (func (export "get-b-s") (result i32)
+    (i31.get_s (ref.cast i31ref (global.get $b))))
+  (func (export "set-b") (param anyref)
+    (global.set $b (local.get 0)))
+  (func (export "c-is-null") (result i32)
+    (ref.is_null (global.get $c)))
+  (func (export "set-c") (param i32)
+    (global.set $c (ref.i31 (local.get 0))))
+  (func (export "get-c") (result i32)
+    (i31.get_u (global.get $c)))
+)
+
+(assert_return (invoke "get-a") (i32.const 42))
+(assert_return (invoke "get-b") (ref.i31))
+(assert_return (invoke "get-b-s") (i32.const -5))
+(assert_return (invoke "c-is-null") (i32.const 1))
+(assert_trap (invoke "get-c") "null reference")
+(invoke "set-c" (i32.const 7))
+(assert_return (invoke "c-is-null") (i32.const 0))
+(assert_return (invoke "get-c") (i32.const 7))
+(invoke "set-b" (ref.null any))
+(assert_return (invoke "get-b") (ref.null any))
+(assert_trap (invoke "get-b-s") "null reference")
@@ -0,0 +1,108 @@
+(module
+  (type $f (func))
+  (type $g (func (param i32)))
+
+  (func $f (type $f))
+  (func $g (type $g))
+  (elem declare func $f $g)
+
+  (func $any (param i32) (result anyref)
+    (if (result anyref) (local.get 0)
+      (then (ref.i31 (i32.const 3)))
+      (else (ref.null any))))
+
+  (func (export "test-any") (param i32) (result i32 i32 i32 i32 i32 i32 i32 i32 i32 i32)
+    (ref.test anyref (call $any (local.get 0)))
+    (ref.test (ref any) (call $any (local.get 0)))
+    (ref.test eqref (call $any (local.get 0)))
+    (ref.test (ref eq) (call $any (local.get 0)))
+    (ref.test i31ref (call $any (local.get 0)))
+    (ref.test (ref i31) (call $any (local.get 0)))
+    (ref.test structref (call $any (local.get 0)))
+    (ref.test (ref struct) (call $any (local.get 0)))
+    (ref.test arrayref (call $any (local.get 0)))
+    (ref.test nullref (call $any (local.get 0)))
+  )
+
+  (func (export "cast-i31") (param i32) (result i32)
+    (i31.get_u (ref.cast (ref i31) (call $any (local.get 0)))))
+  (func (export "cast-null-i31") (param i32) (result i32)
+    (ref.is_null (ref.cast i31ref (call $any (local.get 0)))))
+  (func (export "cast-struct") (param i32)
+    (drop (ref.cast structref (call $any (local.get 0)))))
+
+  (func $func (param i32) (result funcref)
+    (if (i32.eqz (local.get 0))
+      (then (return (ref.null func))))
+    (if (i32.eq (local.get 0) (i32.const 1))
+      (then (return (ref.func $g))))
+    (ref.func $f))
+
+  (func (export "test-func") (param i32) (result i32 i32 i32 i32 i32)
+    (ref.test funcref (call $func (local.get 0)))
+    (ref.test (ref func) (call $func (local.get 0)))
+    (ref.test (ref $f) (call $func (local.get 0)))
+    (ref.test (ref null $g) (call $func (local.get 0)))
+    (ref.test nullfuncref (call $func (local.get 0)))
+  )
+
+  (func (export "cast-f") (param i32)
+    (call_ref $f (ref.cast (ref $f) (call $func (local.get 0)))))
+
+  (func (export "test-extern") (param externref) (result i32 i32 i32)
+    (ref.test externref (local.get 0))
+    (ref.test (ref extern) (local.get 0))
+    (ref.test nullexternref (local.get 0))
+  )
+
+  (func (export "br_on_cast") (param i32) (result i32)
+    (block $l (result i31ref)
+      (br_on_cast $l anyref i31ref (call $any (local.get 0)))
+      (drop)
+      (return (i32.const -1)))
+    (ref.is_null))
+
+  (func (export "br_on_cast_fail") (param i32) (result i32)
+    (block $l (result anyref)
+      (br_on_cast_fail $l anyref (ref i31) (call $any (local.get 0)))
+      (return (i31.get_u)))
+    (drop)
+    (i32.const -1))
+)
+
+;; `$any` returns an `i31ref` for 1 and null for 0.
+(assert_return (invoke "test-any" (i32.const 1))
+  (i32.const 1) (i32.const 1) (i32.const 1) (i32.const 1) (i32.const 1)
+  (i32.const 1) (i32.const 0) (i32.const 0) (i32.const 0) (i32.const 0))
+(assert_return (invoke "test-any" (i32.const 0))
+  (i32.const 1) (i32.const 0) (i32.const 1) (i32.const 0) (i32.const 1)
+  (i32.const 0) (i32.const 1) (i32.const 0) (i32.const 1) (i32.const 1))
+
+(assert_return (invoke "cast-i31" (i32.const 1)) (i32.const 3))
+(assert_trap (invoke "cast-i31" (i32.const 0)) "cast failure")
+(assert_return (invoke "cast-null-i31" (i32.const 0)) (i32.const 1))
+(assert_return (invoke "cast-null-i31" (i32.const 1)) (i32.const 0))
+(assert_trap (invoke "cast-struct" (i32.const 1)) "cast failure")
+(invoke "cast-struct" (i32.const 0))
+
+;; `$func` returns null for 0, `$g` for 1 and `$f` otherwise.
+(assert_return (invoke "test-func" (i32.const 0))
+  (i32.const 1) (i32.const 0) (i32.const 0) (i32.const 1) (i32.const 1))
+(assert_return (invoke "test-func" (i32.const 1))
+  (i32.const 1) (i32.const 1) (i32.const 0) (i32.const 1) (i32.const 0))
+(assert_return (invoke "test-func" (i32.const 2))
+  (i32.const 1) (i32.const 1) (i32.const 1) (i32.const 0) (i32.const 0))
+
+(invoke "cast-f" (i32.const 2))
+(assert_trap (invoke "cast-f" (i32.const 1)) "cast failure")
+(assert_trap (invoke "cast-f" (i32.const 0)) "cast failure")
+
+(assert_return (invoke "test-extern" (ref.null extern))
+  (i32.const 1) (i32.const 0) (i32.const 1))
+(assert_return (invoke "test-extern" (ref.extern 1))
+  (i32.const 1) (i32.const 1) (i32.const 0))
+
+(assert_return (invoke "br_on_cast" (i32.const 1)) (i32.const 0))
+(assert_return (invoke "br_on_cast" (i32.const 0)) (i32.const 1))
+(assert_return (invoke "br_on_cast_fail" (i32.const 1)) (i32.const 3))
+(assert_return (invoke "br_on_cast_fail" (i32.const 0)) (i32.const -1))
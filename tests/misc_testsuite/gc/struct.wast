@@ -0,0 +1,160 @@
+(module
+  (type $point (struct (field $x (mut i32)) (field $y (mut i32))))
+  (type $packed (struct (field (mut i8)) (field (mut i16)) (field i64)))
+  (type $node (struct (field $val i32) (field $next (mut (ref null $node)))))
+  (type $floats (struct (field f32) (field f64)))
+
+  (func $point (param i32 i32) (result (ref $point))
+    (struct.new $point (local.get 0) (local.get 1)))
+
+  (func (export "point-x") (param i32 i32) (result i32)
+    (struct.get $point $x (call $point (local.get 0) (local.get 1))))
+  (func (export "point-y") (param i32 i32) (result i32)
+    (struct.get $point $y (call $point (local.get 0) (local.get 1))))
+
+  (func (export "set-x") (param i32) (result i32)
+    (local $p (ref null $point))
+    (local.set $p (struct.new_default $point))
+    (struct.set $point $x (local.get $p) (local.get 0))
+    (i32.add
+      (struct.get $point $x (local.get $p))
+      (struct.get $point $y (local.get $p))))
+
+  (func (export "packed") (param i32) (result i32 i32 i32 i32 i64)
+    (local $s (ref null $packed))
+    (local.set $s (struct.new $packed (local.get 0) (local.get 0) (i64.const -1)))
+    (struct.get_s $packed 0 (local.get $s))
+    (struct.get_u $packed 0 (local.get $s))
+    (struct.get_s $packed 1 (local.get $s))
+    (struct.get_u $packed 1 (local.get $s))
+    (struct.get $packed 2 (local.get $s)))
+
+  (func (export "floats") (result f32 f64)
+    (local $s (ref null $floats))
+    (local.set $s (struct.new $floats (f32.const 1.5) (f64.const -2.25)))
+    (struct.get $floats 0 (local.get $s))
+    (struct.get $floats 1 (local.get $s)))
+
+  ;; Builds a linked list of `n` nodes and sums their values.
+  (func (export "list-sum") (param $n i32) (result i32)
+    (local $head (ref null $node))
+    (local $sum i32)
+    (block $done
+      (loop $build
+        (br_if $done (i32.eqz (local.get $n)))
+        (local.set $head (struct.new $node (local.get $n) (local.get $head)))
+        (local.set $n (i32.sub (local.get $n) (i32.const 1)))
+        (br $build)))
+    (block $done
+      (loop $walk
+        (br_if $done (ref.is_null (local.get $head)))
+        (local.set $sum
+          (i32.add (local.get $sum) (struct.get $node $val (local.get $head))))
+        (local.set $head (struct.get $node $next (local.get $head)))
+        (br $walk)))
+    (local.get $sum))
+
+  ;; Like `list-sum`, but the list is only reachable through a global while
+  ;; lots of garbage is allocated, so collections must trace it from there.
+  (global $list (mut (ref null $node)) (ref.null $node))
+  (func (export "list-sum-after-gc") (param $n i32) (result i32)
+    (local $i i32)
+    (local $head (ref null $node))
+    (local $sum i32)
+    (local.set $i (local.get $n))
+    (block $done
+      (loop $build
+        (br_if $done (i32.eqz (local.get $i)))
+        (global.set $list (struct.new $node (local.get $i) (global.get $list)))
+        (local.set $i (i32.sub (local.get $i) (i32.const 1)))
+        (br $build)))
+    (local.set $i (i32.const 100000))
+    (block $done
+      (loop $churn
+        (br_if $done (i32.eqz (local.get $i)))
+        (drop (struct.new_default $point))
+        (local.set $i (i32.sub (local.get $i) (i32.const 1)))
+        (br $churn)))
+    (local.set $head (global.get $list))
+    (global.set $list (ref.null $node))
+    (block $done
+      (loop $walk
+        (br_if $done (ref.is_null (local.get $head)))
+        (local.set $sum
+          (i32.add (local.get $sum) (struct.get $node $val (local.get $head))))
+        (local.set $head (struct.get $node $next (local.get $head)))
+        (br $walk)))
+    (local.get $sum))
+
+  (func (export "null-get") (result i32)
+    (struct.get $point $x (ref.null $point)))
+  (func (export "null-set")
+    (struct.set $point $x (ref.null $point) (i32.const 0)))
+
+  (func (export "eq") (result i32 i32)
+    (local $a (ref null $point))
+    (local.set $a (struct.new_default $point))
+    (ref.eq (local.get $a) (local.get $a))
+    (ref.eq (local.get $a) (struct.new_default $point)))
+
+  (func (export "test") (result i32 i32 i32 i32 i32 i32)
+    (local $a anyref)
+    (local.set $a (struct.new_default $point))
+    (ref.test structref (local.get $a))
+    (ref.test eqref (local.get $a))
+    (ref.test (ref $point) (local.get $a))
+    (ref.test (ref $packed) (local.get $a))
+    (ref.test arrayref (local.get $a))
+    (ref.test i31ref (local.get $a)))
+
+  (func (export "cast") (result i32)
+    (struct.get $point $y
+      (ref.cast (ref $point)
+        (struct.new $point (i32.const 1) (i32.const 2)))))
+  (func (export "cast-fail")
+    (drop (ref.cast (ref $packed) (struct.new_default $point))))
+
+  (global $g (mut (ref null $point)) (ref.null $point))
+  (func (export "global") (result i32)
+    (global.set $g (struct.new $point (i32.const 5) (i32.const 6)))
+    (struct.get $point $y (global.get $g)))
+)
+
+(assert_return (invoke "point-x" (i32.const 1) (i32.const 2)) (i32.const 1))
+(assert_return (invoke "point-y" (i32.const 1) (i32.const 2)) (i32.const 2))
+(assert_return (invoke "set-x" (i32.const 42)) (i32.const 42))
+(assert_return (invoke "packed" (i32.const 0xffff))
+  (i32.const -1) (i32.const 0xff) (i32.const -1) (i32.const 0xffff) (i64.const -1))
+(assert_return (invoke "packed" (i32.const 0x1234))
+  (i32.const 0x34) (i32.const 0x34) (i32.const 0x1234) (i32.const 0x1234) (i64.const -1))
+(assert_return (invoke "floats") (f32.const 1.5) (f64.const -2.25))
+(assert_return (invoke "list-sum" (i32.const 0)) (i32.const 0))
+(assert_return (invoke "list-sum" (i32.const 100)) (i32.const 5050))
+(assert_return (invoke "list-sum" (i32.const 100000)) (i32.const 705082704))
+(assert_return (invoke "list-sum-after-gc" (i32.const 1000)) (i32.const 500500))
+(assert_trap (invoke "null-get") "null reference")
+(assert_trap (invoke "null-set") "null reference")
+(assert_return (invoke "eq") (i32.const 1) (i32.const 0))
+(assert_return (invoke "test")
+  (i32.const 1) (i32.const 1) (i32.const 1) (i32.const 0) (i32.const 0) (i32.const 0))
+(assert_return (invoke "cast") (i32.const 2))
+(assert_trap (invoke "cast-fail") "cast failure")
+(assert_return (invoke "global") (i32.const 6))
+
+;; Subtyping of struct types.
+(module
+  (type $a (sub (struct (field i32))))
+  (type $b (sub $a (struct (field i32) (field i64))))
+
+  (func (export "test") (result i32 i32 i32 i32)
+    (ref.test (ref $a) (struct.new $b (i32.const 1) (i64.const 2)))
+    (ref.test (ref $b) (struct.new $b (i32.const 1) (i64.const 2)))
+    (ref.test (ref $a) (struct.new $a (i32.const 1)))
+    (ref.test (ref $b) (struct.new $a (i32.const 1))))
+
+  (func (export "upcast") (result i32)
+    (struct.get $a 0 (struct.new $b (i32.const 7) (i64.const 0))))
+)
+
+(assert_return (invoke "test") (i32.const 1) (i32.const 1) (i32.const 1) (i32.const 0))
+(assert_return (invoke "upcast") (i32.const 7))
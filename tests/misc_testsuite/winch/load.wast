@@ -0,0 +1,109 @@
+(module
+  (memory 1)
+  (data (i32.const 0) "\01\02\03\04\05\06\07\08\f0\f1\f2\f3\f4\f5\f6\f7")
+
+  (func (export "i32.load") (param i32) (result i32)
+    (i32.load (local.get 0)))
+  (func (export "i32.load8_s") (param i32) (result i32)
+    (i32.load8_s (local.get 0)))
+  (func (export "i32.load8_u") (param i32) (result i32)
+    (i32.load8_u (local.get 0)))
+  (func (export "i32.load16_s") (param i32) (result i32)
+    (i32.load16_s (local.get 0)))
+  (func (export "i32.load16_u") (param i32) (result i32)
+    (i32.load16_u (local.get 0)))
+  (func (export "i64.load") (param i32) (result i64)
+    (i64.load (local.get 0)))
+  (func (export "i64.load8_s") (param i32) (result i64)
+    (i64.load8_s (local.get 0)))
+  (func (export "i64.load8_u") (param i32) (result i64)
+    (i64.load8_u (local.get 0)))
+  (func (export "i64.load16_s") (param i32) (result i64)
+    (i64.load16_s (local.get 0)))
+  (func (export "i64.load16_u") (param i32) (result i64)
+    (i64.load16_u (local.get 0)))
+  (func (export "i64.load32_s") (param i32) (result i64)
+    (i64.load32_s (local.get 0)))
+  (func (export "i64.load32_u") (param i32) (result i64)
+    (i64.load32_u (local.get 0)))
+  (func (export "f32.load") (param i32) (result f32)
+    (f32.load (local.get 0)))
+  (func (export "f64.load") (param i32) (result f64)
+    (f64.load (local.get 0)))
+
+  (func (export "i32.load offset") (param i32) (result i32)
+    (i32.load offset=65532 (local.get 0)))
+  (func (export "i64.load offset") (param i32) (result i64)
+    (i64.load offset=4294967295 (local.get 0)))
+
+  (func (export "as-br-value") (param i32) (result i32)
+    (block (result i32) (br 0 (i32.load (local.get 0)))))
+  (func (export "as-call-value") (param i32) (result i32)
+    (call $id (i32.load8_u offset=1 (local.get 0))))
+  (func $id (param i32) (result i32) (local.get 0))
+)
+
+(assert_return (invoke "i32.load" (i32.const 0)) (i32.const 0x04030201))
+(assert_return (invoke "i32.load" (i32.const 1)) (i32.const 0x05040302))
+(assert_return (invoke "i32.load" (i32.const 8)) (i32.const 0xf3f2f1f0))
+(assert_return (invoke "i32.load8_s" (i32.const 8)) (i32.const -16))
+(assert_return (invoke "i32.load8_u" (i32.const 8)) (i32.const 0xf0))
+(assert_return (invoke "i32.load16_s" (i32.const 8)) (i32.const -3600))
+(assert_return (invoke "i32.load16_u" (i32.const 8)) (i32.const 0xf1f0))
+(assert_return (invoke "i64.load" (i32.const 0)) (i64.const 0x0807060504030201))
+(assert_return (invoke "i64.load" (i32.const 8)) (i64.const 0xf7f6f5f4f3f2f1f0))
+(assert_return (invoke "i64.load8_s" (i32.const 9)) (i64.const -15))
+(assert_return (invoke "i64.load8_u" (i32.const 9)) (i64.const 0xf1))
+(assert_return (invoke "i64.load16_s" (i32.const 9)) (i64.const -3343))
+(assert_return (invoke "i64.load16_u" (i32.const 9)) (i64.const 0xf2f1))
+(assert_return (invoke "i64.load32_s" (i32.const 8)) (i64.const 0xfffffffff3f2f1f0))
+(assert_return (invoke "i64.load32_u" (i32.const 8)) (i64.const 0xf3f2f1f0))
+(assert_return (invoke "i64.load32_s" (i32.const 0)) (i64.const 0x04030201))
+(assert_return (invoke "f32.load" (i32.const 16)) (f32.const 0))
+(assert_return (invoke "f64.load" (i32.const 16)) (f64.const 0))
+(assert_return (invoke "as-br-value" (i32.const 4)) (i32.const 0x08070605))
+(assert_return (invoke "as-call-value" (i32.const 0)) (i32.const 2))
+
+(assert_return (invoke "i32.load" (i32.const 65532)) (i32.const 0))
+(assert_trap (invoke "i32.load" (i32.const 65533)) "out of bounds memory access")
+(assert_trap (invoke "i32.load" (i32.const -1)) "out of bounds memory access")
+(assert_return (invoke "i32.load8_s" (i32.const 65535)) (i32.const 0))
+(assert_trap (invoke "i32.load8_u" (i32.const 65536)) "out of bounds memory access")
+(assert_trap (invoke "i32.load16_s" (i32.const 65535)) "out of bounds memory access")
+(assert_trap (invoke "i64.load" (i32.const 65529)) "out of bounds memory access")
+(assert_trap (invoke "i64.load32_u" (i32.const 65533)) "out of bounds memory access")
+(assert_trap (invoke "f32.load" (i32.const 65533)) "out of bounds memory access")
+(assert_trap (invoke "f64.load" (i32.const 65529)) "out of bounds memory access")
+(assert_return (invoke "i32.load offset" (i32.const 0)) (i32.const 0))
+(assert_trap (invoke "i32.load offset" (i32.const 1)) "out of bounds memory access")
+(assert_trap (invoke "i64.load offset" (i32.const 0)) "out of bounds memory access")
+
+;; Accesses are checked against the current size of the memory.
+(module
+  (memory 0 2)
+  (func (export "grow") (param i32) (result i32) (memory.grow (local.get 0)))
+  (func (export "load") (param i32) (result i32) (i32.load (local.get 0)))
+)
+
+(assert_trap (invoke "load" (i32.const 0)) "out of bounds memory access")
+(assert_return (invoke "grow" (i32.const 1)) (i32.const 0))
+(assert_return (invoke "load" (i32.const 65532)) (i32.const 0))
+(assert_trap (invoke "load" (i32.const 65536)) "out of bounds memory access")
+(assert_return (invoke "grow" (i32.const 1)) (i32.const 1))
+(assert_return (invoke "load" (i32.const 65536)) (i32.const 0))
+(assert_trap (invoke "load" (i32.const 131069)) "out of bounds memory access")
+
+;; Imported memories.
+(module $M
+  (memory (export "mem") 1)
+  (data (i32.const 16) "\2a")
+)
+(register "M" $M)
+
+(module
+  (import "M" "mem" (memory 1))
+  (func (export "load") (param i32) (result i32) (i32.load8_u (local.get 0)))
+)
+
+(assert_return (invoke "load" (i32.const 16)) (i32.const 42))
+(assert_trap (invoke "load" (i32.const 65536)) "out of bounds memory access")
@@ -0,0 +1,64 @@
+(module
+  (memory 1)
+
+  (func (export "i32.store") (param i32 i32) (result i64)
+    (i32.store (local.get 0) (local.get 1))
+    (i64.load (local.get 0)))
+  (func (export "i32.store8") (param i32 i32) (result i64)
+    (i32.store8 (local.get 0) (local.get 1))
+    (i64.load (local.get 0)))
+  (func (export "i32.store16") (param i32 i32) (result i64)
+    (i32.store16 (local.get 0) (local.get 1))
+    (i64.load (local.get 0)))
+  (func (export "i64.store") (param i32 i64) (result i64)
+    (i64.store (local.get 0) (local.get 1))
+    (i64.load (local.get 0)))
+  (func (export "i64.store8") (param i32 i64) (result i64)
+    (i64.store8 (local.get 0) (local.get 1))
+    (i64.load (local.get 0)))
+  (func (export "i64.store16") (param i32 i64) (result i64)
+    (i64.store16 (local.get 0) (local.get 1))
+    (i64.load (local.get 0)))
+  (func (export "i64.store32") (param i32 i64) (result i64)
+    (i64.store32 (local.get 0) (local.get 1))
+    (i64.load (local.get 0)))
+  (func (export "f32.store") (param i32 f32) (result f32)
+    (f32.store (local.get 0) (local.get 1))
+    (f32.load (local.get 0)))
+  (func (export "f64.store") (param i32 f64) (result f64)
+    (f64.store (local.get 0) (local.get 1))
+    (f64.load (local.get 0)))
+  (func (export "store const") (param i32) (result i64)
+    (i64.store offset=8 (local.get 0) (i64.const 0x0102030405060708))
+    (i64.load offset=8 (local.get 0)))
+  (func (export "store offset") (param i32 i32)
+    (i32.store offset=65532 (local.get 0) (local.get 1)))
+)
+
+(assert_return (invoke "i32.store" (i32.const 0) (i32.const 0x01020304)) (i64.const 0x01020304))
+(assert_return (invoke "i32.store8" (i32.const 0) (i32.const 0xffff)) (i64.const 0x010203ff))
+(assert_return (invoke "i32.store16" (i32.const 1) (i32.const 0xabcdef)) (i64.const 0x01cdef))
+(assert_return (invoke "i64.store" (i32.const 16) (i64.const -1)) (i64.const -1))
+(assert_return (invoke "i64.store8" (i32.const 16) (i64.const 0)) (i64.const 0xffffffffffffff00))
+(assert_return (invoke "i64.store16" (i32.const 17) (i64.const 0)) (i64.const 0x00ffffffffff0000))
+(assert_return (invoke "i64.store32" (i32.const 20) (i64.const 0x1122334455667788)) (i64.const 0x55667788))
+(assert_return (invoke "f32.store" (i32.const 32) (f32.const 1.5)) (f32.const 1.5))
+(assert_return (invoke "f64.store" (i32.const 40) (f64.const -0x1p-1074)) (f64.const -0x1p-1074))
+(assert_return (invoke "store const" (i32.const 48)) (i64.const 0x0102030405060708))
+
+(assert_trap (invoke "i32.store" (i32.const 65533) (i32.const 0)) "out of bounds memory access")
+(assert_trap (invoke "i32.store8" (i32.const 65536) (i32.const 0)) "out of bounds memory access")
+(assert_trap (invoke "i64.store32" (i32.const -1) (i64.const 0)) "out of bounds memory access")
+(assert_trap (invoke "f64.store" (i32.const 65529) (f64.const 0)) "out of bounds memory access")
+(assert_return (invoke "store offset" (i32.const 0) (i32.const 1)))
+(assert_trap (invoke "store offset" (i32.const 1) (i32.const 1)) "out of bounds memory access")
+
+;; Memory is unchanged after an out of bounds store.
+(module
+  (memory 1)
+  (func (export "store") (param i32 i64) (i64.store (local.get 0) (local.get 1)))
+  (func (export "load") (param i32) (result i64) (i64.load (local.get 0)))
+)
+
+(assert_trap (invoke "store" (i32.const 65529) (i64.const -1)) "out of bounds memory access")
+(assert_return (invoke "load" (i32.const 65528)) (i64.const 0))
@@ -44,6 +44,7 @@
 use crate::codegen::ptr_type_from_ptr_size;
 use crate::isa::{reg::Reg, CallingConvention};
 use crate::masm::{OperandSize, SPOffset};
+use cranelift_codegen::binemit::StackMap;
 use smallvec::SmallVec;
 use std::collections::HashSet;
 use std::ops::{Add, BitAnd, Not, Sub};
@@ -76,10 +77,6 @@ pub(crate) trait ABI {
     /// The offset to the return address, relative to the frame pointer.
     fn ret_addr_offset() -> u8;
 
-    /// Construct the ABI-specific signature from a WebAssembly
-    /// function type.
-    fn sig(wasm_sig: &WasmFuncType, call_conv: &CallingConvention) -> ABISig;
-
     /// Construct an ABI signature from WasmType params and returns.
     fn sig_from(params: &[WasmType], returns: &[WasmType], call_conv: &CallingConvention)
         -> ABISig;
@@ -104,14 +101,8 @@ pub(crate) trait ABI {
     /// Returns the designated scratch register for the given [WasmType].
     fn scratch_for(ty: &WasmType) -> Reg {
         match ty {
-            WasmType::I32
-            | WasmType::I64
-            | WasmType::Ref(WasmRefType {
-                heap_type: WasmHeapType::Func,
-                ..
-            }) => Self::scratch_reg(),
-            WasmType::F32 | WasmType::F64 => Self::float_scratch_reg(),
-            _ => unimplemented!(),
+            WasmType::I32 | WasmType::I64 | WasmType::Ref(_) => Self::scratch_reg(),
+            WasmType::F32 | WasmType::F64 | WasmType::V128 => Self::float_scratch_reg(),
         }
     }
 
@@ -455,6 +446,7 @@ impl ABIParams {
         params: &[WasmType],
         initial_bytes: u32,
         needs_stack_results: bool,
+        call_conv: &CallingConvention,
         mut map: F,
     ) -> Self
     where
@@ -496,6 +488,13 @@ impl ABIParams {
             stack_bytes = bytes;
         }
 
+        // In Winch's default calling convention the callee pops its stack
+        // arguments, so the argument area is sized to preserve the stack
+        // alignment required at callsites.
+        if call_conv.is_default() {
+            stack_bytes = align_to(stack_bytes, <A as ABI>::call_stack_align().into());
+        }
+
         Self {
             operands: ABIOperands {
                 inner: operands,
@@ -557,7 +556,7 @@ impl ABIParams {
 }
 
 /// An ABI-specific representation of a function signature.
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone)]
 pub(crate) struct ABISig {
     /// Function parameters.
     pub params: ABIParams,
@@ -565,11 +564,13 @@ pub(crate) struct ABISig {
     pub results: ABIResults,
     /// A unique set of registers used in the entire [`ABISig`].
     pub regs: HashSet<Reg>,
+    /// The calling convention used by the signature.
+    pub call_conv: CallingConvention,
 }
 
 impl ABISig {
     /// Create a new ABI signature.
-    pub fn new(params: ABIParams, results: ABIResults) -> Self {
+    pub fn new(call_conv: CallingConvention, params: ABIParams, results: ABIResults) -> Self {
         let regs = params
             .operands
             .regs
@@ -580,6 +581,7 @@ impl ABISig {
             params,
             results,
             regs,
+            call_conv,
         }
     }
 
@@ -608,6 +610,21 @@ impl ABISig {
         self.params.operands.bytes
     }
 
+    /// Returns the stack size, in bytes, of the arguments that the callee
+    /// pops before returning to its caller.
+    ///
+    /// In Winch's default calling convention the callee is responsible for
+    /// popping its stack arguments, which is what allows tail calls to
+    /// functions taking a different number of stack arguments. In all the
+    /// other calling conventions, stack arguments are popped by the caller.
+    pub fn callee_popped_stack_size(&self) -> u32 {
+        if self.call_conv.is_default() {
+            self.params_stack_size()
+        } else {
+            0
+        }
+    }
+
     /// Returns the stack size, in bytes, needed for results on the stack.
     pub fn results_stack_size(&self) -> u32 {
         self.results.operands.bytes
@@ -619,6 +636,29 @@ impl ABISig {
     }
 }
 
+/// Constructs an [ABISig] for a WebAssembly function using Winch's default
+/// calling convention.
+///
+/// WebAssembly functions receive the callee and caller `VMContext` pointers
+/// as their first two parameters, matching the signature expected by
+/// Wasmtime for function imports and function references.
+pub(crate) fn wasm_sig<A: ABI>(ty: &WasmFuncType) -> ABISig {
+    let mut params: SmallVec<[WasmType; 6]> = SmallVec::with_capacity(ty.params().len() + 2);
+    params.extend_from_slice(&vmctx_types::<A>());
+    params.extend_from_slice(ty.params());
+    A::sig_from(&params, ty.returns(), &CallingConvention::Default)
+}
+
+/// The number of `VMContext` parameters, callee and caller, at the start of
+/// the signature of every WebAssembly function.
+pub(crate) const VMCTX_PARAMS: usize = 2;
+
+/// Returns the types of the callee and caller `VMContext` pointers.
+pub(crate) fn vmctx_types<A: ABI>() -> [WasmType; 2] {
+    let ptr_type = ptr_type_from_ptr_size(<A as ABI>::word_bytes() as u8);
+    [ptr_type, ptr_type]
+}
+
 /// Align a value up to the given power-of-two-alignment.
 // See https://sites.google.com/site/theoryofoperatingsystems/labs/malloc/align8
 pub(crate) fn align_to<N>(value: N, alignment: N) -> N
@@ -640,3 +680,42 @@ pub(crate) fn calculate_frame_adjustment(frame_size: u32, addend: u32, alignment
     let total = frame_size + addend;
     (alignment - (total % alignment)) % alignment
 }
+
+/// Returns true if values of the given type are `externref`s, which are
+/// managed by the runtime's garbage collector and must be tracked in stack
+/// maps while in the stack.
+pub(crate) fn is_externref(ty: &WasmType) -> bool {
+    matches!(
+        ty,
+        WasmType::Ref(WasmRefType {
+            heap_type: WasmHeapType::Extern,
+            ..
+        })
+    )
+}
+
+/// Builds the stack map for a call site given the stack pointer offset at
+/// the call and the offsets of the stack slots holding `externref`s.
+///
+/// A stack map covers the words between the stack pointer and the frame
+/// pointer; its zeroth bit represents the word at the stack pointer.
+/// `frame_bytes` is the size of any callee-saved area between the frame
+/// pointer and the start of the stack space tracked by `sp_offset`.
+pub(crate) fn stack_map(
+    sp_offset: SPOffset,
+    ref_slots: &[SPOffset],
+    word_bytes: u32,
+    frame_bytes: u32,
+) -> Option<StackMap> {
+    if ref_slots.is_empty() {
+        return None;
+    }
+
+    let sp_offset = sp_offset.as_u32();
+    let mut bits = vec![false; ((sp_offset + frame_bytes) / word_bytes) as usize];
+    for slot in ref_slots {
+        debug_assert!(slot.as_u32() <= sp_offset);
+        bits[((sp_offset - slot.as_u32()) / word_bytes) as usize] = true;
+    }
+    Some(StackMap::from_slice(&bits))
+}
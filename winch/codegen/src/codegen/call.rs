@@ -1,8 +1,9 @@
 //! Function call emission.  For more details around the ABI and
 //! calling convention, see [ABI].
 //!
-//! This module exposes a single function [`FnCall::emit`], which is responsible
-//! of orchestrating the emission of calls. In general such orchestration
+//! This module exposes [`FnCall::emit`], which is responsible of
+//! orchestrating the emission of calls, and [`FnCall::emit_return_call`], its
+//! counterpart for tail calls. In general such orchestration
 //! takes place in 6 steps:
 //!
 //! 1. [`Callee`] resolution.
@@ -57,15 +58,13 @@
 //! └──────────────────────────────────────────────────┘ ------> Stack pointer when emitting the call
 
 use crate::{
-    abi::{ABIOperand, ABISig, RetArea, ABI},
+    abi::{is_externref, wasm_sig, ABIOperand, ABISig, RetArea, ABI, VMCTX_PARAMS},
     codegen::{
         ptr_type_from_ptr_size, BuiltinFunction, BuiltinType, Callee, CalleeInfo, CodeGenContext,
-        TypedReg,
     },
     masm::{CalleeKind, MacroAssembler, MemMoveDirection, OperandSize, SPOffset},
     reg::Reg,
     stack::Val,
-    CallingConvention,
 };
 use smallvec::SmallVec;
 use std::borrow::Cow;
@@ -91,15 +90,16 @@ impl FnCall {
         R: FnMut(&mut CodeGenContext) -> Callee,
     {
         let callee = resolve(context);
-        let ptr_type = ptr_type_from_ptr_size(context.vmoffsets.ptr.size());
-        let mut sig = Self::get_sig::<M>(&callee, ptr_type);
-        let kind = Self::map(&context.vmoffsets, &callee, sig.as_ref(), context, masm);
+        let mut sig = Self::get_sig::<M>(&callee);
+        let (kind, callee_vmctx) =
+            Self::map(&context.vmoffsets, &callee, sig.as_ref(), context, masm);
 
         context.spill(masm);
         let ret_area = Self::make_ret_area(&sig, masm);
         let arg_stack_space = sig.params_stack_size();
-        let reserved_stack = masm.call(arg_stack_space, |masm| {
-            Self::assign(sig.as_ref(), ret_area.as_ref(), context, masm);
+        let ref_slots = Self::ref_slots(context);
+        let reserved_stack = masm.call(arg_stack_space, &ref_slots, |masm| {
+            Self::assign(sig.as_ref(), callee_vmctx, ret_area.as_ref(), context, masm);
             kind
         });
 
@@ -108,9 +108,88 @@ impl FnCall {
             _ => {}
         }
 
+        // Calls to imported functions or to function references may switch
+        // to a different `VMContext`, which the callee sets as its pinned
+        // `VMContext`; reload the caller's `VMContext` after the call.
+        if let Some(vmctx) = callee_vmctx {
+            let vmctx_reg = <M::ABI as ABI>::vmctx_reg();
+            if vmctx != vmctx_reg {
+                context.free_reg(vmctx);
+                let addr = masm.local_address(&context.frame.vmctx_slot);
+                masm.load_ptr(addr, vmctx_reg);
+            }
+        }
+
         Self::cleanup(&mut sig, reserved_stack, ret_area, masm, context);
     }
 
+    /// Orchestrates the emission of a tail call.
+    ///
+    /// Similar to [`Self::emit`], except that the current frame is replaced
+    /// by the callee's: the callee's stack arguments are placed where the
+    /// current function's stack arguments, of `caller_stack_args_size` bytes,
+    /// are located and the callee returns directly to the current function's
+    /// caller. Since the signature results of the caller and the callee must
+    /// match, the callee's return area, if any, is the current function's
+    /// return area.
+    ///
+    /// The code following a tail call is unreachable.
+    pub fn emit_return_call<M: MacroAssembler, P: PtrSize, R>(
+        masm: &mut M,
+        context: &mut CodeGenContext,
+        caller_stack_args_size: u32,
+        mut resolve: R,
+    ) where
+        R: FnMut(&mut CodeGenContext) -> Callee,
+    {
+        let callee = resolve(context);
+        let sig = Self::get_sig::<M>(&callee);
+        let (kind, callee_vmctx) =
+            Self::map(&context.vmoffsets, &callee, sig.as_ref(), context, masm);
+
+        context.spill(masm);
+        let ret_area = context.frame.results_base_slot.map(RetArea::slot);
+        let sp_offset = masm.sp_offset();
+        masm.return_call(sig.params_stack_size(), caller_stack_args_size, |masm| {
+            Self::assign(sig.as_ref(), callee_vmctx, ret_area.as_ref(), context, masm);
+            kind
+        });
+        masm.reset_stack_pointer(sp_offset);
+
+        match kind {
+            CalleeKind::Indirect(r) => context.free_reg(r),
+            _ => {}
+        }
+        if let Some(vmctx) = callee_vmctx {
+            if vmctx != <M::ABI as ABI>::vmctx_reg() {
+                context.free_reg(vmctx);
+            }
+        }
+    }
+
+    /// Collects the offsets of the stack slots holding `externref`s, which
+    /// must be reported in the stack map of the call.
+    ///
+    /// After spilling, all the values in the value stack are either in
+    /// memory, constants or locals, so the `externref`s live across the call
+    /// are either in a local slot or in a memory entry in the value stack.
+    fn ref_slots(context: &CodeGenContext) -> SmallVec<[SPOffset; 8]> {
+        let locals = context
+            .frame
+            .locals
+            .iter()
+            .filter(|slot| is_externref(&slot.ty))
+            .map(|slot| {
+                debug_assert!(slot.addressed_from_sp());
+                SPOffset::from_u32(slot.offset)
+            });
+        let values = context.stack.inner().iter().filter_map(|v| match v {
+            Val::Memory(mem) if is_externref(&mem.ty) => Some(mem.slot.offset),
+            _ => None,
+        });
+        locals.chain(values).collect()
+    }
+
     /// Calculates the return area for the callee, if any.
     fn make_ret_area<M: MacroAssembler>(callee_sig: &ABISig, masm: &mut M) -> Option<RetArea> {
         callee_sig.has_stack_results().then(|| {
@@ -124,42 +203,36 @@ impl FnCall {
     }
 
     /// Derive the [`ABISig`] for a particular [`Callee`].
-    fn get_sig<M: MacroAssembler>(callee: &Callee, ptr_type: WasmType) -> Cow<'_, ABISig> {
+    pub(crate) fn get_sig<M: MacroAssembler>(callee: &Callee) -> Cow<'_, ABISig> {
         match callee {
             Callee::Builtin(info) => Cow::Borrowed(info.sig()),
-            Callee::Import(info) => {
-                let mut params: SmallVec<[WasmType; 6]> =
-                    SmallVec::with_capacity(info.ty.params().len() + 2);
-                params.extend_from_slice(&[ptr_type, ptr_type]);
-                params.extend_from_slice(info.ty.params());
-                Cow::Owned(<M::ABI as ABI>::sig_from(
-                    &params,
-                    info.ty.returns(),
-                    &CallingConvention::Default,
-                ))
-            }
-            Callee::Local(info) => {
-                Cow::Owned(<M::ABI as ABI>::sig(&info.ty, &CallingConvention::Default))
-            }
-            Callee::FuncRef(ty) => {
-                Cow::Owned(<M::ABI as ABI>::sig(&ty, &CallingConvention::Default))
-            }
+            Callee::Import(info) | Callee::Local(info) => Cow::Owned(wasm_sig::<M::ABI>(&info.ty)),
+            Callee::FuncRef(ty) => Cow::Owned(wasm_sig::<M::ABI>(ty)),
         }
     }
 
     /// Maps the given [`Callee`] to a [`CalleeKind`].
-    fn map<P: PtrSize, M: MacroAssembler>(
+    ///
+    /// For calls to WebAssembly functions, this function also returns the
+    /// register holding the callee's `VMContext`.
+    pub(crate) fn map<P: PtrSize, M: MacroAssembler>(
         vmoffsets: &VMOffsets<P>,
         callee: &Callee,
         sig: &ABISig,
         context: &mut CodeGenContext,
         masm: &mut M,
-    ) -> CalleeKind {
+    ) -> (CalleeKind, Option<Reg>) {
         match callee {
-            Callee::Builtin(b) => Self::load_builtin(b, context, masm),
-            Callee::FuncRef(_) => Self::load_funcref(sig, vmoffsets.ptr.size(), context, masm),
-            Callee::Local(i) => Self::map_local(i),
-            Callee::Import(i) => Self::load_import(i, sig, context, masm, vmoffsets),
+            Callee::Builtin(b) => (Self::load_builtin(b, context, masm), None),
+            Callee::FuncRef(_) => {
+                let (kind, vmctx) = Self::load_funcref(sig, vmoffsets.ptr.size(), context, masm);
+                (kind, Some(vmctx))
+            }
+            Callee::Local(i) => (Self::map_local(i), Some(<M::ABI as ABI>::vmctx_reg())),
+            Callee::Import(i) => {
+                let (kind, vmctx) = Self::load_import(i, sig, context, masm, vmoffsets);
+                (kind, Some(vmctx))
+            }
         }
     }
 
@@ -192,16 +265,15 @@ impl FnCall {
         CalleeKind::direct(info.index.as_u32())
     }
 
-    /// Loads a function import to the next available register.
+    /// Loads a function import and its `VMContext` to the next available
+    /// registers.
     fn load_import<M: MacroAssembler, P: PtrSize>(
         info: &CalleeInfo,
         sig: &ABISig,
         context: &mut CodeGenContext,
         masm: &mut M,
         vmoffsets: &VMOffsets<P>,
-    ) -> CalleeKind {
-        let ptr_type = ptr_type_from_ptr_size(vmoffsets.ptr.size());
-        let caller_vmctx = <M::ABI as ABI>::vmctx_reg();
+    ) -> (CalleeKind, Reg) {
         let (callee, callee_vmctx) =
             context.without::<(Reg, Reg), M, _>(&sig.regs, masm, |context, masm| {
                 (context.any_gpr(masm), context.any_gpr(masm))
@@ -214,57 +286,70 @@ impl FnCall {
         let callee_addr = masm.address_at_vmctx(callee_body_offset);
         masm.load_ptr(callee_addr, callee);
 
-        // Put the callee / caller vmctx at the start of the
-        // range of the stack so that they are used as first
-        // and second arguments.
-        let stack = &mut context.stack;
-        let location = stack.len().checked_sub(sig.params.len() - 2).unwrap_or(0);
-        context.stack.insert_many(
-            location,
-            [
-                TypedReg::new(ptr_type, callee_vmctx).into(),
-                TypedReg::new(ptr_type, caller_vmctx).into(),
-            ],
-        );
-
-        CalleeKind::indirect(callee)
+        (CalleeKind::indirect(callee), callee_vmctx)
     }
 
-    /// Loads a function reference to the next available register.
+    /// Loads a function reference and its `VMContext` to the next available
+    /// registers.
     fn load_funcref<M: MacroAssembler>(
         sig: &ABISig,
         ptr: impl PtrSize,
         context: &mut CodeGenContext,
         masm: &mut M,
-    ) -> CalleeKind {
+    ) -> (CalleeKind, Reg) {
         // Pop the funcref pointer to a register and allocate a register to hold the
         // address of the funcref. Since the callee is not addressed from a global non
         // allocatable register (like the vmctx in the case of an import), we load the
         // funcref to a register ensuring that it doesn't get assigned to a register
         // used in the callee's signature.
-        let (funcref_ptr, funcref) = context.without::<_, M, _>(&sig.regs, masm, |cx, masm| {
-            (cx.pop_to_reg(masm, None).into(), cx.any_gpr(masm))
-        });
+        let (funcref_ptr, funcref, callee_vmctx) =
+            context.without::<_, M, _>(&sig.regs, masm, |cx, masm| {
+                (
+                    cx.pop_to_reg(masm, None).into(),
+                    cx.any_gpr(masm),
+                    cx.any_gpr(masm),
+                )
+            });
 
+        masm.load_ptr(
+            masm.address_at_reg(funcref_ptr, ptr.vm_func_ref_vmctx().into()),
+            callee_vmctx,
+        );
         masm.load_ptr(
             masm.address_at_reg(funcref_ptr, ptr.vm_func_ref_wasm_call().into()),
             funcref,
         );
         context.free_reg(funcref_ptr);
-        CalleeKind::indirect(funcref)
+        (CalleeKind::indirect(funcref), callee_vmctx)
     }
 
     /// Assign arguments for the function call.
-    fn assign<M: MacroAssembler>(
+    ///
+    /// When calling WebAssembly functions, the callee and caller `VMContext`
+    /// pointers are passed as the first two arguments.
+    pub(crate) fn assign<M: MacroAssembler>(
         sig: &ABISig,
+        callee_vmctx: Option<Reg>,
         ret_area: Option<&RetArea>,
         context: &mut CodeGenContext,
         masm: &mut M,
     ) {
-        let arg_count = sig.params.len_without_retptr();
+        let params = match callee_vmctx {
+            Some(vmctx) => {
+                let ptr_size = <M::ABI as ABI>::word_bytes();
+                let ptr_type = ptr_type_from_ptr_size(ptr_size as u8);
+                let vmctx_args = [vmctx, <M::ABI as ABI>::vmctx_reg()];
+                for (arg, src) in sig.params()[..VMCTX_PARAMS].iter().zip(vmctx_args) {
+                    masm.mov(src.into(), arg.unwrap_reg(), ptr_type.into());
+                }
+                &sig.params_without_retptr()[VMCTX_PARAMS..]
+            }
+            None => sig.params_without_retptr(),
+        };
+
         let stack = &context.stack;
-        let stack_values = stack.peekn(arg_count);
-        for (arg, val) in sig.params_without_retptr().iter().zip(stack_values) {
+        let stack_values = stack.peekn(params.len());
+        for (arg, val) in params.iter().zip(stack_values) {
             match arg {
                 &ABIOperand::Reg { reg, .. } => {
                     context.move_val_to_reg(&val, reg, masm);
@@ -281,19 +366,30 @@ impl FnCall {
 
         if sig.has_stack_results() {
             let operand = sig.params.unwrap_results_area_operand();
-            let base = ret_area.unwrap().unwrap_sp();
-            let addr = masm.address_from_sp(base);
+            // The return area is either addressed from the stack pointer or,
+            // for tail calls, its address is stored in a local slot.
+            let load_ret_area = |masm: &mut M, dst: Reg, ty: WasmType| match ret_area.unwrap() {
+                RetArea::SP(base) => {
+                    let addr = masm.address_from_sp(*base);
+                    masm.load_addr(addr, dst, ty.into());
+                }
+                RetArea::Slot(slot) => {
+                    let addr = masm.local_address(slot);
+                    masm.load_ptr(addr, dst);
+                }
+                RetArea::Uninit => unreachable!(),
+            };
 
             match operand {
                 &ABIOperand::Reg { ty, reg, .. } => {
-                    masm.load_addr(addr, reg, ty.into());
+                    load_ret_area(masm, reg, ty);
                 }
                 &ABIOperand::Stack { ty, offset, .. } => {
                     let slot = masm.address_at_sp(SPOffset::from_u32(offset));
                     // Don't rely on `ABI::scratch_for` as we always use
                     // an int register as the return pointer.
                     let scratch = <M::ABI as ABI>::scratch_reg();
-                    masm.load_addr(addr, scratch, ty.into());
+                    load_ret_area(masm, scratch, ty);
                     masm.store(scratch.into(), slot, ty.into());
                 }
             }
@@ -310,13 +406,23 @@ impl FnCall {
         context: &mut CodeGenContext,
     ) {
         // Deallocate the reserved space for stack arguments and for alignment,
-        // which was allocated last.
-        masm.free_stack(reserved_space);
+        // which was allocated last; the stack arguments may have already been
+        // popped by the callee.
+        let callee_popped = sig.callee_popped_stack_size();
+        masm.reset_stack_pointer(SPOffset::from_u32(
+            masm.sp_offset().as_u32() - callee_popped,
+        ));
+        masm.free_stack(reserved_space - callee_popped);
 
         // Drop params from value stack and calculate amount of machine stack
         // space they consumed.
         let mut stack_consumed = 0;
-        context.drop_last(sig.params.len_without_retptr(), |_regalloc, v| {
+        let params_len = if sig.call_conv.is_default() {
+            sig.params.len_without_retptr() - VMCTX_PARAMS
+        } else {
+            sig.params.len_without_retptr()
+        };
+        context.drop_last(params_len, |_regalloc, v| {
             debug_assert!(v.is_mem() || v.is_const());
             if let Val::Memory(mem) = v {
                 stack_consumed += mem.slot.size;
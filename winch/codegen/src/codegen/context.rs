@@ -1,4 +1,4 @@
-use wasmtime_environ::{VMOffsets, WasmHeapType, WasmType};
+use wasmtime_environ::{VMOffsets, WasmType};
 
 use super::ControlStackFrame;
 use crate::{
@@ -73,13 +73,8 @@ impl<'a, 'builtins> CodeGenContext<'a, 'builtins> {
     pub fn reg_for_type<M: MacroAssembler>(&mut self, ty: WasmType, masm: &mut M) -> Reg {
         use WasmType::*;
         match ty {
-            I32 | I64 => self.reg_for_class(RegClass::Int, masm),
-            F32 | F64 => self.reg_for_class(RegClass::Float, masm),
-            Ref(rt) => match rt.heap_type {
-                WasmHeapType::Func => self.reg_for_class(RegClass::Int, masm),
-                ht => unimplemented!("Support for WasmHeapType: {ht}"),
-            },
-            t => unimplemented!("Support for WasmType: {t}"),
+            I32 | I64 | Ref(_) => self.reg_for_class(RegClass::Int, masm),
+            F32 | F64 | V128 => self.reg_for_class(RegClass::Float, masm),
         }
     }
 
@@ -182,18 +177,19 @@ impl<'a, 'builtins> CodeGenContext<'a, 'builtins> {
             Val::I64(v) => masm.store(RegImm::i64(v), addr, size),
             Val::F32(v) => masm.store(RegImm::f32(v.bits()), addr, size),
             Val::F64(v) => masm.store(RegImm::f64(v.bits()), addr, size),
+            Val::V128(v) => masm.store(RegImm::v128(v), addr, size),
             Val::Local(local) => {
                 let slot = self
                     .frame
                     .get_local(local.index)
                     .unwrap_or_else(|| panic!("invalid local at index = {}", local.index));
-                let scratch = <M::ABI as ABI>::scratch_reg();
+                let scratch = <M::ABI as ABI>::scratch_for(&slot.ty);
                 let local_addr = masm.local_address(&slot);
                 masm.load(local_addr, scratch, size);
                 masm.store(scratch.into(), addr, size);
             }
             Val::Memory(_) => {
-                let scratch = <M::ABI as ABI>::scratch_reg();
+                let scratch = <M::ABI as ABI>::scratch_for(&val.ty());
                 masm.pop(scratch, size);
                 masm.store(scratch.into(), addr, size);
             }
@@ -209,6 +205,7 @@ impl<'a, 'builtins> CodeGenContext<'a, 'builtins> {
             Val::I64(imm) => masm.mov(RegImm::i64(*imm), dst, size),
             Val::F32(imm) => masm.mov(RegImm::f32(imm.bits()), dst, size),
             Val::F64(imm) => masm.mov(RegImm::f64(imm.bits()), dst, size),
+            Val::V128(imm) => masm.mov(RegImm::v128(*imm), dst, size),
             Val::Local(local) => {
                 let slot = self
                     .frame
@@ -268,7 +265,7 @@ impl<'a, 'builtins> CodeGenContext<'a, 'builtins> {
         let dst = match size {
             OperandSize::S32 => TypedReg::i32(dst),
             OperandSize::S64 => TypedReg::i64(dst),
-            OperandSize::S128 => unreachable!(),
+            OperandSize::S8 | OperandSize::S16 | OperandSize::S128 => unreachable!(),
         };
         self.stack.push(dst.into());
     }
@@ -335,7 +332,7 @@ impl<'a, 'builtins> CodeGenContext<'a, 'builtins> {
             WasmType::I64 => OperandSize::S64,
             WasmType::F32 => OperandSize::S32,
             WasmType::F64 => OperandSize::S64,
-            WasmType::V128 => unreachable!(),
+            WasmType::V128 => OperandSize::S128,
             WasmType::Ref(_) => unreachable!(),
         };
 
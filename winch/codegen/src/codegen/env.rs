@@ -1,12 +1,16 @@
-use crate::codegen::{control, BlockSig, BuiltinFunction, OperandSize};
+use crate::{
+    codegen::{control, BlockSig, BuiltinFunction, OperandSize},
+    isa::TargetIsa,
+};
 use std::collections::{
     hash_map::Entry::{Occupied, Vacant},
     HashMap,
 };
 use wasmparser::BlockType;
 use wasmtime_environ::{
-    FuncIndex, GlobalIndex, MemoryIndex, ModuleTranslation, ModuleTypesBuilder, PtrSize,
-    TableIndex, TablePlan, TypeConvert, TypeIndex, VMOffsets, WasmFuncType, WasmHeapType, WasmType,
+    FuncIndex, GlobalIndex, MemoryIndex, MemoryPlan, MemoryStyle, ModuleTranslation,
+    ModuleTypesBuilder, PtrSize, TableIndex, TablePlan, Tunables, TypeConvert, TypeIndex,
+    VMOffsets, WasmFuncType, WasmHeapType, WasmType, WASM_PAGE_SIZE,
 };
 
 /// Table metadata.
@@ -32,11 +36,36 @@ pub struct HeapData {
     pub offset: u32,
     /// The offset to the current length field.
     pub current_length_offset: u32,
-    /// If the heap is imported, this field contains the offset to locate the
-    /// base of the heap.
+    /// If the heap is imported or shared, this field contains the offset to
+    /// locate the base of the heap.
     pub import_from: Option<u32>,
     /// The memory type (32 or 64).
     pub ty: WasmType,
+    /// The minimum size of the heap, in bytes.
+    pub min_size: u64,
+    /// The maximum size of the heap, in bytes, if any.
+    pub max_size: Option<u64>,
+    /// The style of the heap, which determines how bounds checks are
+    /// emitted.
+    pub style: HeapStyle,
+    /// The size, in bytes, of the guard region right after the heap's
+    /// accessible memory.
+    pub offset_guard_size: u64,
+}
+
+/// The style of a heap.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum HeapStyle {
+    /// Static heaps have a fixed, reserved size (`bound`) that is never
+    /// exceeded by growing the heap. Accesses below the bound plus the
+    /// guard region don't need to be explicitly checked.
+    Static {
+        /// The heap bound, in bytes, not including the guard region.
+        bound: u64,
+    },
+    /// Dynamic heaps can be moved around when grown, so all accesses must be
+    /// checked against the current length of the heap.
+    Dynamic,
 }
 
 /// A function callee.
@@ -75,9 +104,14 @@ pub struct FuncEnv<'a, 'translation: 'a, 'data: 'translation, P: PtrSize> {
     pub translation: &'translation ModuleTranslation<'data>,
     /// The module's function types.
     pub types: &'translation ModuleTypesBuilder,
+    /// Compilation settings which affect the generated code, e.g. whether
+    /// fuel or epoch instrumentation is emitted.
+    pub tunables: &'translation Tunables,
     /// Track resolved table information.
     resolved_tables: HashMap<TableIndex, TableData>,
     resolved_heaps: HashMap<MemoryIndex, HeapData>,
+    /// The target ISA.
+    isa: &'a dyn TargetIsa,
 }
 
 pub fn ptr_type_from_ptr_size(size: u8) -> WasmType {
@@ -92,13 +126,17 @@ impl<'a, 'translation, 'data, P: PtrSize> FuncEnv<'a, 'translation, 'data, P> {
         vmoffsets: &'a VMOffsets<P>,
         translation: &'translation ModuleTranslation<'data>,
         types: &'translation ModuleTypesBuilder,
+        tunables: &'translation Tunables,
+        isa: &'a dyn TargetIsa,
     ) -> Self {
         Self {
             vmoffsets,
             translation,
             types,
+            tunables,
             resolved_tables: HashMap::new(),
             resolved_heaps: HashMap::new(),
+            isa,
         }
     }
 
@@ -198,14 +236,24 @@ impl<'a, 'translation, 'data, P: PtrSize> FuncEnv<'a, 'translation, 'data, P> {
     }
 
     /// Resolved a [HeapData] from a [MemoryIndex].
-    // TODO: (@saulecabrera)
-    // Handle shared memories when implementing support for Wasm Threads.
     pub fn resolve_heap(&mut self, index: MemoryIndex) -> HeapData {
         match self.resolved_heaps.entry(index) {
             Occupied(entry) => *entry.get(),
             Vacant(entry) => {
+                let is_shared = self.translation.module.memory_plans[index].memory.shared;
                 let (import_from, base_offset, current_length_offset) =
                     match self.translation.module.defined_memory_index(index) {
+                        // As with imported memories, the `VMMemoryDefinition`
+                        // of a shared memory is stored elsewhere and the
+                        // VMContext only holds a pointer to it.
+                        Some(defined) if is_shared => (
+                            Some(self.vmoffsets.vmctx_vmmemory_pointer(defined)),
+                            self.vmoffsets.ptr.vmmemory_definition_base().into(),
+                            self.vmoffsets
+                                .ptr
+                                .vmmemory_definition_current_length()
+                                .into(),
+                        ),
                         Some(defined) => {
                             let owned = self.translation.module.owned_memory_index(defined);
                             (
@@ -225,15 +273,23 @@ impl<'a, 'translation, 'data, P: PtrSize> FuncEnv<'a, 'translation, 'data, P> {
                         ),
                     };
 
+                let plan = &self.translation.module.memory_plans[index];
+                let (min_size, max_size) = heap_limits(plan);
+                let (style, offset_guard_size) = heap_style_and_offset_guard_size(plan);
+
                 *entry.insert(HeapData {
                     offset: base_offset,
                     import_from,
                     current_length_offset,
-                    ty: if self.translation.module.memory_plans[index].memory.memory64 {
+                    ty: if plan.memory.memory64 {
                         WasmType::I64
                     } else {
                         WasmType::I32
                     },
+                    min_size,
+                    max_size,
+                    style,
+                    offset_guard_size,
                 })
             }
         }
@@ -243,6 +299,52 @@ impl<'a, 'translation, 'data, P: PtrSize> FuncEnv<'a, 'translation, 'data, P> {
     pub fn table_plan(&mut self, index: TableIndex) -> &TablePlan {
         &self.translation.module.table_plans[index]
     }
+
+    /// Returns true if Spectre mitigations are enabled for heap accesses.
+    pub fn heap_access_spectre_mitigation(&self) -> bool {
+        self.isa.flags().enable_heap_access_spectre_mitigation()
+    }
+}
+
+/// Derive the heap style and the size of the offset guard region from a
+/// [`MemoryPlan`].
+fn heap_style_and_offset_guard_size(plan: &MemoryPlan) -> (HeapStyle, u64) {
+    match plan {
+        MemoryPlan {
+            style: MemoryStyle::Static { bound },
+            offset_guard_size,
+            ..
+        } => (
+            HeapStyle::Static {
+                bound: bound * u64::from(WASM_PAGE_SIZE),
+            },
+            *offset_guard_size,
+        ),
+
+        MemoryPlan {
+            style: MemoryStyle::Dynamic { .. },
+            offset_guard_size,
+            ..
+        } => (HeapStyle::Dynamic, *offset_guard_size),
+    }
+}
+
+/// Returns the minimum and maximum size, in bytes, of the heap described by
+/// the given [`MemoryPlan`].
+fn heap_limits(plan: &MemoryPlan) -> (u64, Option<u64>) {
+    (
+        plan.memory
+            .minimum
+            .checked_mul(u64::from(WASM_PAGE_SIZE))
+            .unwrap_or_else(|| {
+                // 2^64 as a minimum doesn't fit in a 64 bit integer.
+                // So in this case, the minimum is clamped to u64::MAX.
+                u64::MAX
+            }),
+        plan.memory
+            .maximum
+            .and_then(|max| max.checked_mul(u64::from(WASM_PAGE_SIZE))),
+    )
 }
 
 impl<P: PtrSize> TypeConvert for FuncEnv<'_, '_, '_, P> {
@@ -1,14 +1,21 @@
 use crate::{
-    abi::{ABIOperand, ABISig, RetArea, ABI},
+    abi::{is_externref, ABIOperand, ABISig, LocalSlot, RetArea, ABI, VMCTX_PARAMS},
     codegen::BlockSig,
     isa::reg::Reg,
-    masm::{IntCmpKind, MacroAssembler, OperandSize, RegImm, SPOffset, TrapCode},
-    stack::TypedReg,
+    masm::{
+        ExtendKind, IntCmpKind, MacroAssembler, OperandSize, RegImm, RmwOp, SPOffset, TrapCode,
+        V128ExtendKind, VectorShape,
+    },
+    stack::{TypedReg, Val},
 };
 use anyhow::Result;
 use smallvec::SmallVec;
-use wasmparser::{BinaryReader, FuncValidator, Operator, ValidatorResources, VisitOperator};
-use wasmtime_environ::{PtrSize, TableIndex, TypeIndex, WasmHeapType, WasmType, FUNCREF_MASK};
+use wasmparser::{
+    BinaryReader, FuncValidator, MemArg, Operator, ValidatorResources, VisitOperator,
+};
+use wasmtime_environ::{
+    MemoryIndex, PtrSize, TableIndex, TypeIndex, WasmRefType, WasmType, FUNCREF_MASK,
+};
 
 mod context;
 pub(crate) use context::*;
@@ -20,6 +27,9 @@ mod control;
 pub(crate) use control::*;
 mod builtin;
 pub use builtin::*;
+pub(crate) mod bounds;
+
+use bounds::{Bounds, ImmOffset, Index};
 
 /// The code generation abstraction.
 pub(crate) struct CodeGen<'a, 'translation: 'a, 'data: 'translation, M>
@@ -42,6 +52,10 @@ where
     // NB The 64 is set arbitrarily, we can adjust it as
     // we see fit.
     pub control_frames: SmallVec<[ControlStackFrame; 64]>,
+
+    /// Local counter to track fuel consumption, which is flushed to the
+    /// `VMRuntimeLimits` at basic block boundaries and before calls.
+    pub fuel_consumed: i64,
 }
 
 impl<'a, 'translation, 'data, M> CodeGen<'a, 'translation, 'data, M>
@@ -60,6 +74,8 @@ where
             masm,
             env,
             control_frames: Default::default(),
+            // Empty functions should consume at least 1 fuel unit.
+            fuel_consumed: 1,
         }
     }
 
@@ -78,6 +94,16 @@ where
 
     fn emit_start(&mut self) -> Result<()> {
         self.masm.prologue();
+
+        // Move the callee `VMContext` pointer, passed as the first parameter,
+        // to the designated pinned register.
+        let vmctx_param = self.sig.params()[0].unwrap_reg();
+        self.masm.mov(
+            vmctx_param.into(),
+            <M::ABI as ABI>::vmctx_reg(),
+            self.env.ptr_type().into(),
+        );
+
         self.masm.reserve_stack(self.context.frame.locals_size);
 
         // Check for stack overflow after reserving space, so that we get the most up-to-date view
@@ -173,6 +199,14 @@ where
             }
         });
 
+        if self.env.tunables.consume_fuel {
+            self.emit_fuel_check();
+        }
+
+        if self.env.tunables.epoch_interruption {
+            self.emit_epoch_check();
+        }
+
         while !body.eof() {
             let offset = body.original_position();
             body.visit_operator(&mut ValidateThenVisit(validator.visitor(offset), self))??;
@@ -192,7 +226,11 @@ where
                         // ignored except for If, Block, Loop, Else and End. These operators need
                         // to be observed in order to keep the control stack frames balanced and to
                         // determine if reachability should be restored.
-                        let visit_when_unreachable = visit_op_when_unreachable(Operator::$op $({ $($arg: $arg.clone()),* })?);
+                        let op = Operator::$op $({ $($arg: $arg.clone()),* })?;
+                        let visit_when_unreachable = visit_op_when_unreachable(&op);
+                        if self.1.is_reachable() {
+                            self.1.before_visit_op(&op);
+                        }
                         if self.1.is_reachable() || visit_when_unreachable  {
                             Ok(self.1.$visit($($($arg),*)?))
                         } else {
@@ -203,7 +241,7 @@ where
             };
         }
 
-        fn visit_op_when_unreachable(op: Operator) -> bool {
+        fn visit_op_when_unreachable(op: &Operator) -> bool {
             use Operator::*;
             match op {
                 If { .. } | Block { .. } | Loop { .. } | Else | End => true,
@@ -215,6 +253,10 @@ where
         trait ReachableState {
             /// Returns true if the current state of the program is reachable.
             fn is_reachable(&self) -> bool;
+
+            /// Hook invoked before visiting an operator in a reachable code
+            /// state.
+            fn before_visit_op(&mut self, op: &Operator);
         }
 
         impl<'a, 'translation, 'data, M: MacroAssembler> ReachableState
@@ -223,6 +265,12 @@ where
             fn is_reachable(&self) -> bool {
                 self.context.reachable
             }
+
+            fn before_visit_op(&mut self, op: &Operator) {
+                if self.env.tunables.consume_fuel {
+                    self.fuel_before_visit_op(op);
+                }
+            }
         }
 
         impl<'a, T, U> VisitOperator<'a> for ValidateThenVisit<'_, T, U>
@@ -281,6 +329,150 @@ where
         self.context.free_reg(caller_id);
     }
 
+    /// Accounts for the fuel consumed by the given operator and flushes the
+    /// accumulated fuel to the `VMRuntimeLimits` at the end of each basic
+    /// block and before any call, mirroring the accounting performed by
+    /// Cranelift.
+    fn fuel_before_visit_op(&mut self, op: &Operator) {
+        use Operator::*;
+
+        self.fuel_consumed += match op {
+            Nop | Drop => 0,
+            Block { .. } | Loop { .. } | Unreachable | Return | Else | End => 0,
+            _ => 1,
+        };
+
+        match op {
+            // Calls must observe an up-to-date fuel counter, and so must
+            // any instruction that ends the current basic block.
+            Unreachable
+            | Return
+            | Call { .. }
+            | CallIndirect { .. }
+            | CallRef { .. }
+            | ReturnCall { .. }
+            | ReturnCallIndirect { .. }
+            | ReturnCallRef { .. }
+            | Loop { .. }
+            | If { .. }
+            | Br { .. }
+            | BrIf { .. }
+            | BrTable { .. }
+            | BrOnNull { .. }
+            | BrOnNonNull { .. }
+            | End
+            | Else => self.emit_fuel_increment(),
+            _ => {}
+        }
+    }
+
+    /// Adds the locally accumulated fuel to the fuel counter in the
+    /// `VMRuntimeLimits`.
+    fn emit_fuel_increment(&mut self) {
+        let fuel_consumed = std::mem::replace(&mut self.fuel_consumed, 0);
+        if fuel_consumed == 0 {
+            return;
+        }
+
+        let limits = self.context.any_gpr(self.masm);
+        let fuel = self.context.any_gpr(self.masm);
+        self.emit_load_runtime_limits(limits);
+        let fuel_offset = self.env.vmoffsets.ptr.vmruntime_limits_fuel_consumed();
+        let fuel_addr = self.masm.address_at_reg(limits, fuel_offset.into());
+        self.masm.load(fuel_addr, fuel, OperandSize::S64);
+        self.masm
+            .add(fuel, fuel, RegImm::i64(fuel_consumed), OperandSize::S64);
+        let fuel_addr = self.masm.address_at_reg(limits, fuel_offset.into());
+        self.masm.store(fuel.into(), fuel_addr, OperandSize::S64);
+        self.context.free_reg(fuel);
+        self.context.free_reg(limits);
+    }
+
+    /// Flushes the accumulated fuel and emits a call to the `out_of_gas`
+    /// builtin if the fuel counter became non-negative.
+    pub fn emit_fuel_check(&mut self) {
+        // Spill before emitting any control flow, so that the value stack is
+        // in the same state in both the fallthrough and the call paths.
+        self.context.spill(self.masm);
+        self.emit_fuel_increment();
+
+        let continuation = self.masm.get_label();
+        let fuel = self.context.any_gpr(self.masm);
+        self.emit_load_runtime_limits(fuel);
+        let fuel_offset = self.env.vmoffsets.ptr.vmruntime_limits_fuel_consumed();
+        self.masm.load(
+            self.masm.address_at_reg(fuel, fuel_offset.into()),
+            fuel,
+            OperandSize::S64,
+        );
+        // Continue if `fuel < 0`.
+        self.masm.branch(
+            IntCmpKind::LtS,
+            RegImm::i64(0),
+            fuel,
+            continuation,
+            OperandSize::S64,
+        );
+        self.context.free_reg(fuel);
+
+        let vmctx = TypedReg::new(self.env.ptr_type(), <M::ABI as ABI>::vmctx_reg());
+        self.context.stack.extend([vmctx.into()]);
+        FnCall::emit::<M, M::Ptr, _>(self.masm, &mut self.context, |cx| {
+            Callee::Builtin(cx.builtins.out_of_gas::<M::ABI, M::Ptr>())
+        });
+        self.masm.bind(continuation);
+    }
+
+    /// Emits a call to the `new_epoch` builtin if the current epoch has
+    /// reached the epoch deadline.
+    pub fn emit_epoch_check(&mut self) {
+        self.context.spill(self.masm);
+
+        let continuation = self.masm.get_label();
+        let epoch = self.context.any_gpr(self.masm);
+        let deadline = self.context.any_gpr(self.masm);
+        let epoch_ptr_offset = self.env.vmoffsets.vmctx_epoch_ptr();
+        self.masm
+            .load_ptr(self.masm.address_at_vmctx(epoch_ptr_offset), epoch);
+        self.masm
+            .load(self.masm.address_at_reg(epoch, 0), epoch, OperandSize::S64);
+        self.emit_load_runtime_limits(deadline);
+        let deadline_offset = self.env.vmoffsets.ptr.vmruntime_limits_epoch_deadline();
+        self.masm.load(
+            self.masm.address_at_reg(deadline, deadline_offset.into()),
+            deadline,
+            OperandSize::S64,
+        );
+        // Continue if `epoch < deadline`.
+        self.masm.branch(
+            IntCmpKind::LtU,
+            deadline.into(),
+            epoch,
+            continuation,
+            OperandSize::S64,
+        );
+        self.context.free_reg(deadline);
+        self.context.free_reg(epoch);
+
+        let vmctx = TypedReg::new(self.env.ptr_type(), <M::ABI as ABI>::vmctx_reg());
+        self.context.stack.extend([vmctx.into()]);
+        FnCall::emit::<M, M::Ptr, _>(self.masm, &mut self.context, |cx| {
+            Callee::Builtin(cx.builtins.new_epoch::<M::ABI, M::Ptr>())
+        });
+        // The new deadline is reloaded from the `VMRuntimeLimits` on every
+        // check, so the returned value is not needed.
+        let deadline = self.context.pop_to_reg(self.masm, None);
+        self.context.free_reg(deadline);
+        self.masm.bind(continuation);
+    }
+
+    /// Loads the `VMRuntimeLimits` pointer into the given register.
+    fn emit_load_runtime_limits(&mut self, dst: Reg) {
+        let offset = self.env.vmoffsets.ptr.vmcontext_runtime_limits();
+        self.masm
+            .load_ptr(self.masm.address_at_vmctx(offset.into()), dst);
+    }
+
     /// Emit the usual function end instruction sequence.
     fn emit_end(&mut self) -> Result<()> {
         // The implicit body block is treated a normal block (it pushes results
@@ -302,19 +494,28 @@ where
             self.masm.reset_stack_pointer(base);
         }
         debug_assert_eq!(self.context.stack.len(), 0);
-        self.masm.epilogue(self.context.frame.locals_size);
+        self.masm.epilogue(
+            self.context.frame.locals_size,
+            self.sig.callee_popped_stack_size(),
+        );
         Ok(())
     }
 
     fn spill_register_arguments(&mut self) {
         use WasmType::*;
+        let arg_base_offset: u32 = <M::ABI as ABI>::arg_base_offset().into();
         self.sig
             // Skip the results base param if any; [Self::emit_body],
             // will handle spilling the results base param if it's in a register.
             .params_without_retptr()
             .iter()
+            // Skip the callee and caller `VMContext` params, which
+            // don't have an associated local.
+            .skip(VMCTX_PARAMS)
             .enumerate()
-            .filter(|(_, a)| a.is_reg())
+            // `externref` stack arguments are copied to a local slot,
+            // so that they are covered by the stack maps of the function.
+            .filter(|(_, a)| a.is_reg() || is_externref(&a.ty()))
             .for_each(|(index, arg)| {
                 let ty = arg.ty();
                 let local = self
@@ -323,17 +524,21 @@ where
                     .get_local(index as u32)
                     .expect("valid local slot at location");
                 let addr = self.masm.local_address(local);
-                let src = arg
-                    .get_reg()
-                    .expect("arg should be associated to a register");
-
-                match &ty {
-                    I32 | I64 | F32 | F64 => self.masm.store(src.into(), addr, ty.into()),
-                    Ref(rt) => match rt.heap_type {
-                        WasmHeapType::Func => self.masm.store_ptr(src.into(), addr),
-                        ht => unimplemented!("Support for WasmHeapType: {ht}"),
+
+                match arg {
+                    ABIOperand::Reg { reg, .. } => match &ty {
+                        I32 | I64 | F32 | F64 | V128 => {
+                            self.masm.store((*reg).into(), addr, ty.into())
+                        }
+                        Ref(_) => self.masm.store_ptr((*reg).into(), addr),
                     },
-                    _ => unimplemented!("Support for WasmType {ty}"),
+                    ABIOperand::Stack { offset, .. } => {
+                        let arg_slot = LocalSlot::stack_arg(ty, offset + arg_base_offset);
+                        let arg_addr = self.masm.local_address(&arg_slot);
+                        let scratch = <M::ABI as ABI>::scratch_reg();
+                        self.masm.load_ptr(arg_addr, scratch);
+                        self.masm.store_ptr(scratch.into(), addr);
+                    }
                 }
             });
     }
@@ -430,6 +635,396 @@ where
 
         self.masm.bind(cont);
     }
+
+    /// Emits a series of instructions to get an element of an `externref`
+    /// table.
+    ///
+    /// All the `externref`s in the stack must be held alive by the
+    /// `VMExternRefActivationsTable`, so non-null elements are inserted
+    /// in it through a call to a built-in function.
+    pub fn emit_externref_table_get(&mut self, table_index: TableIndex) {
+        let table_data = self.env.resolve_table_data(table_index);
+        let ptr_type = self.env.ptr_type();
+        let builtin = self
+            .context
+            .builtins
+            .activations_table_insert_with_gc::<M::ABI, M::Ptr>();
+
+        // Spill now, since the built-in function is called conditionally
+        // and spilling in only one of the paths would leave the machine stack
+        // unbalanced.
+        self.context.spill(self.masm);
+
+        let index = self.context.pop_to_reg(self.masm, None);
+        let base = self.context.any_gpr(self.masm);
+        let elem_addr =
+            self.masm
+                .table_elem_address(index.into(), base, &table_data, &mut self.context);
+        let elem = self.context.any_gpr(self.masm);
+        self.masm.load_ptr(elem_addr, elem);
+        self.context.free_reg(base);
+        self.context.free_reg(index);
+
+        // Spill the element, so that it's preserved across the call
+        // below and tracked in its stack map.
+        let ty = WasmType::Ref(WasmRefType::EXTERNREF);
+        self.context.stack.push(Val::reg(elem, ty));
+        self.context.spill(self.masm);
+        let slot = self.context.stack.peek().unwrap().unwrap_mem().slot;
+        let elem = self.context.any_gpr(self.masm);
+        self.masm
+            .load_ptr(self.masm.address_from_sp(slot.offset), elem);
+
+        let cont = self.masm.get_label();
+        self.masm
+            .branch(IntCmpKind::Eq, elem.into(), elem, cont, ptr_type.into());
+        self.context.stack.extend([
+            TypedReg::new(ptr_type, <M::ABI as ABI>::vmctx_reg()).into(),
+            TypedReg::new(ptr_type, elem).into(),
+        ]);
+        FnCall::emit::<M, M::Ptr, _>(self.masm, &mut self.context, |_| {
+            Callee::Builtin(builtin.clone())
+        });
+        self.masm.bind(cont);
+    }
+
+    /// Emits a series of instructions that compute the effective address of
+    /// a WebAssembly heap access, including the necessary bounds checks.
+    ///
+    /// The index of the access is expected to be at the top of the value
+    /// stack. Returns `None` if the access is statically known to be out of
+    /// bounds, in which case an unconditional trap is emitted and the current
+    /// code is marked as unreachable.
+    pub fn emit_compute_heap_address(
+        &mut self,
+        memarg: &MemArg,
+        access_size: OperandSize,
+    ) -> Option<Reg> {
+        let ptr_size: OperandSize = self.env.ptr_type().into();
+        let enable_spectre_mitigation = self.env.heap_access_spectre_mitigation();
+        let add_offset_and_access_size = |offset: ImmOffset, access_size: OperandSize| {
+            u64::from(access_size.bytes()) + u64::from(offset.as_u32())
+        };
+
+        let memory_index = MemoryIndex::from_u32(memarg.memory);
+        let heap = self.env.resolve_heap(memory_index);
+        let index = Index::from_typed_reg(self.context.pop_to_reg(self.masm, None));
+        let index_reg = index.as_typed_reg().reg;
+
+        // All the bounds checks are performed using pointer-sized operations,
+        // so ensure that 32-bit indices are zero extended.
+        if heap.ty == WasmType::I32 {
+            self.masm
+                .extend(index_reg, index_reg, ExtendKind::I64ExtendI32U);
+        }
+
+        let offset = bounds::ensure_index_and_offset(self.masm, index, memarg.offset, ptr_size);
+        let offset_with_access_size = add_offset_and_access_size(offset, access_size);
+
+        let addr = match heap.style {
+            // == Dynamic Heaps ==
+
+            // Account for the general case for dynamic memories. The access is
+            // out of bounds if:
+            // * index + offset + access_size overflows
+            //   OR
+            // * index + offset + access_size > bound
+            HeapStyle::Dynamic => {
+                let bounds =
+                    bounds::load_dynamic_heap_bounds(&mut self.context, self.masm, &heap, ptr_size);
+
+                let index_offset_and_access_size = self.context.any_gpr(self.masm);
+
+                // Perform
+                // index_offset_and_access_size = index + offset + access_size.
+                self.masm
+                    .mov(index_reg.into(), index_offset_and_access_size, ptr_size);
+                self.masm.checked_uadd(
+                    index_offset_and_access_size,
+                    index_offset_and_access_size,
+                    RegImm::i64(offset_with_access_size as i64),
+                    ptr_size,
+                    TrapCode::HeapOutOfBounds,
+                );
+
+                let addr = bounds::load_heap_addr_checked(
+                    self.masm,
+                    &mut self.context,
+                    ptr_size,
+                    &heap,
+                    enable_spectre_mitigation,
+                    bounds,
+                    index,
+                    offset,
+                    |masm, bounds, _| {
+                        let bounds_reg = bounds.as_typed_reg().reg;
+                        masm.cmp(bounds_reg.into(), index_offset_and_access_size, ptr_size);
+                        IntCmpKind::GtU
+                    },
+                );
+                self.context.free_reg(bounds.as_typed_reg().reg);
+                self.context.free_reg(index_offset_and_access_size);
+                Some(addr)
+            }
+
+            // == Static Heaps ==
+
+            // Detect at compile time if the access is out of bounds.
+            // Doing so will put the rest of the code in an unreachable state;
+            // there's no need to emit the rest of the access, since it will
+            // always trap.
+            HeapStyle::Static { bound } if offset_with_access_size > bound => {
+                self.masm.trap(TrapCode::HeapOutOfBounds);
+                self.context.reachable = false;
+                None
+            }
+
+            // Account for the case in which we can completely elide the bounds
+            // checks.
+            //
+            // This case, makes use of the fact that if a memory access uses
+            // a 32-bit index, then we can be certain that
+            //
+            //      index <= u32::MAX
+            //
+            // Therefore if any 32-bit index access occurs in the region
+            // represented by
+            //
+            //      bound + guard_size - (offset + access_size)
+            //
+            // We are certain that it's in bounds or that the underlying virtual
+            // memory subsystem will report an illegal access at runtime.
+            //
+            // Note:
+            //
+            // * bound - (offset + access_size) cannot wrap, because it's checked
+            // in the condition above.
+            // * bound + heap.offset_guard_size is guaranteed to not overflow if
+            // the heap configuration is correct, given that its address must
+            // fit in 64-bits.
+            // * If the heap type is 32-bits, the offset is at most u32::MAX, so
+            // no adjustment is needed as part of
+            // [bounds::ensure_index_and_offset].
+            HeapStyle::Static { bound }
+                if heap.ty == WasmType::I32
+                    && u64::from(u32::MAX)
+                        <= bound + heap.offset_guard_size - offset_with_access_size =>
+            {
+                let addr = self.context.any_gpr(self.masm);
+                bounds::load_heap_addr_unchecked(self.masm, &heap, index, offset, addr, ptr_size);
+                Some(addr)
+            }
+
+            // Account for the general case of static memories. The access is out
+            // of bounds if:
+            //
+            // index > bound - (offset + access_size)
+            //
+            // bound - (offset + access_size) cannot wrap, because we already
+            // checked that (offset + access_size) <= bound, above.
+            HeapStyle::Static { bound } => {
+                let bounds = Bounds::from_u64(bound);
+                let addr = bounds::load_heap_addr_checked(
+                    self.masm,
+                    &mut self.context,
+                    ptr_size,
+                    &heap,
+                    enable_spectre_mitigation,
+                    bounds,
+                    index,
+                    offset,
+                    |masm, bounds, index| {
+                        let adjusted_bounds = bounds.as_u64() - offset_with_access_size;
+                        let index_reg = index.as_typed_reg().reg;
+                        masm.cmp(RegImm::i64(adjusted_bounds as i64), index_reg, ptr_size);
+                        IntCmpKind::GtU
+                    },
+                );
+                Some(addr)
+            }
+        };
+
+        self.context.free_reg(index.as_typed_reg().reg);
+        addr
+    }
+
+    /// Emit a WebAssembly load.
+    pub fn emit_wasm_load(
+        &mut self,
+        arg: &MemArg,
+        ty: WasmType,
+        size: OperandSize,
+        sextend: Option<ExtendKind>,
+    ) {
+        if let Some(addr) = self.emit_compute_heap_address(arg, size) {
+            let dst = self.context.reg_for_type(ty, self.masm);
+            let src = self.masm.address_at_reg(addr, 0);
+            self.masm.wasm_load(src, dst, size, sextend);
+            self.context.stack.push(TypedReg::new(ty, dst).into());
+            self.context.free_reg(addr);
+        }
+    }
+
+    /// Emit a WebAssembly store.
+    pub fn emit_wasm_store(&mut self, arg: &MemArg, size: OperandSize) {
+        let src = self.context.pop_to_reg(self.masm, None);
+        if let Some(addr) = self.emit_compute_heap_address(arg, size) {
+            self.masm
+                .wasm_store(src.reg, self.masm.address_at_reg(addr, 0), size);
+            self.context.free_reg(addr);
+        }
+        self.context.free_reg(src);
+    }
+
+    /// Emit a WebAssembly atomic load.
+    pub fn emit_wasm_atomic_load(&mut self, arg: &MemArg, ty: WasmType, size: OperandSize) {
+        self.emit_check_align(arg, size);
+        if let Some(addr) = self.emit_compute_heap_address(arg, size) {
+            let dst = self.context.reg_for_type(ty, self.masm);
+            let src = self.masm.address_at_reg(addr, 0);
+            self.masm.wasm_atomic_load(src, dst, size);
+            self.context.stack.push(TypedReg::new(ty, dst).into());
+            self.context.free_reg(addr);
+        }
+    }
+
+    /// Emit a WebAssembly atomic store.
+    pub fn emit_wasm_atomic_store(&mut self, arg: &MemArg, size: OperandSize) {
+        let src = self.context.pop_to_reg(self.masm, None);
+        self.emit_check_align(arg, size);
+        if let Some(addr) = self.emit_compute_heap_address(arg, size) {
+            self.masm
+                .wasm_atomic_store(src.reg, self.masm.address_at_reg(addr, 0), size);
+            self.context.free_reg(addr);
+        }
+        self.context.free_reg(src);
+    }
+
+    /// Emit a WebAssembly atomic read-modify-write operation.
+    pub fn emit_atomic_rmw(&mut self, arg: &MemArg, size: OperandSize, op: RmwOp) {
+        let operand = self.context.pop_to_reg(self.masm, None);
+        self.emit_check_align(arg, size);
+        if let Some(addr) = self.emit_compute_heap_address(arg, size) {
+            // Hand the address and the operand over to the MacroAssembler
+            // through the value stack, given that some ISAs require specific
+            // registers for these operations.
+            let addr = TypedReg::new(self.env.ptr_type(), addr);
+            self.context.stack.extend([addr.into(), operand.into()]);
+            self.masm.atomic_rmw(&mut self.context, size, op);
+        } else {
+            self.context.free_reg(operand);
+        }
+    }
+
+    /// Emit a WebAssembly atomic compare-and-exchange.
+    pub fn emit_atomic_cmpxchg(&mut self, arg: &MemArg, size: OperandSize) {
+        let replacement = self.context.pop_to_reg(self.masm, None);
+        let expected = self.context.pop_to_reg(self.masm, None);
+        self.emit_check_align(arg, size);
+        if let Some(addr) = self.emit_compute_heap_address(arg, size) {
+            // See the note in [`Self::emit_atomic_rmw`].
+            let addr = TypedReg::new(self.env.ptr_type(), addr);
+            self.context
+                .stack
+                .extend([addr.into(), expected.into(), replacement.into()]);
+            self.masm.atomic_cas(&mut self.context, size);
+        } else {
+            self.context.free_reg(expected);
+            self.context.free_reg(replacement);
+        }
+    }
+
+    /// Emit a check ensuring that the effective address of an atomic access,
+    /// derived from the index at the top of the value stack, is aligned to
+    /// the access size; trapping otherwise.
+    fn emit_check_align(&mut self, memarg: &MemArg, size: OperandSize) {
+        if size.bytes() == 1 {
+            return;
+        }
+
+        let index = self.context.pop_to_reg(self.masm, None);
+        let tmp = self.context.any_gpr(self.masm);
+        // Only the low bits of the effective address are relevant to the
+        // check, so it's enough to account for the low bits of the offset.
+        let mask = size.bytes() - 1;
+        let offset = (memarg.offset & u64::from(mask)) as i32;
+        self.masm.mov(index.reg.into(), tmp, OperandSize::S32);
+        if offset != 0 {
+            self.masm
+                .add(tmp, tmp, RegImm::i32(offset), OperandSize::S32);
+        }
+        self.masm
+            .and(tmp, tmp, RegImm::i32(mask as i32), OperandSize::S32);
+        self.masm.cmp(RegImm::i32(0), tmp, OperandSize::S32);
+        self.masm.trapif(IntCmpKind::Ne, TrapCode::HeapMisaligned);
+        self.context.free_reg(tmp);
+        self.context.stack.push(index.into());
+    }
+
+    /// Emit a WebAssembly load of eight bytes, extending each of its lanes
+    /// into a v128.
+    pub fn emit_wasm_load_extend(&mut self, arg: &MemArg, kind: V128ExtendKind) {
+        let size = OperandSize::S64;
+        if let Some(addr) = self.emit_compute_heap_address(arg, size) {
+            let dst = self.context.reg_for_type(WasmType::V128, self.masm);
+            let src = self.masm.address_at_reg(addr, 0);
+            self.masm.wasm_load(src, dst, size, None);
+            self.masm.v128_extend(dst, dst, kind);
+            self.context.stack.push(TypedReg::v128(dst).into());
+            self.context.free_reg(addr);
+        }
+    }
+
+    /// Emit a WebAssembly load of a single lane, replicating it to all the
+    /// lanes of a v128.
+    pub fn emit_wasm_load_splat(&mut self, arg: &MemArg, shape: VectorShape) {
+        let size = shape.lane_size();
+        if let Some(addr) = self.emit_compute_heap_address(arg, size) {
+            let tmp = self.context.reg_for_type(shape.lane_type(), self.masm);
+            let src = self.masm.address_at_reg(addr, 0);
+            self.masm.wasm_load(src, tmp, size, None);
+            self.context.free_reg(addr);
+            let dst = self.context.reg_for_type(WasmType::V128, self.masm);
+            self.masm.splat(tmp, dst, shape);
+            self.context.free_reg(tmp);
+            self.context.stack.push(TypedReg::v128(dst).into());
+        }
+    }
+
+    /// Emit a WebAssembly load of a single lane into the v128 at the top of
+    /// the value stack.
+    pub fn emit_wasm_load_lane(&mut self, arg: &MemArg, lane: u8, shape: VectorShape) {
+        let size = shape.lane_size();
+        let vector = self.context.pop_to_reg(self.masm, None);
+        if let Some(addr) = self.emit_compute_heap_address(arg, size) {
+            let tmp = self.context.reg_for_type(shape.lane_type(), self.masm);
+            let src = self.masm.address_at_reg(addr, 0);
+            self.masm.wasm_load(src, tmp, size, None);
+            self.masm.replace_lane(tmp, vector.into(), lane, shape);
+            self.context.free_reg(tmp);
+            self.context.free_reg(addr);
+            self.context.stack.push(vector.into());
+        } else {
+            self.context.free_reg(vector);
+        }
+    }
+
+    /// Emit a WebAssembly store of a single lane of the v128 at the top of
+    /// the value stack.
+    pub fn emit_wasm_store_lane(&mut self, arg: &MemArg, lane: u8, shape: VectorShape) {
+        let size = shape.lane_size();
+        let vector = self.context.pop_to_reg(self.masm, None);
+        let src = self.context.reg_for_type(shape.lane_type(), self.masm);
+        self.masm
+            .extract_lane(vector.into(), src, lane, shape, None);
+        self.context.free_reg(vector);
+        if let Some(addr) = self.emit_compute_heap_address(arg, size) {
+            self.masm
+                .wasm_store(src, self.masm.address_at_reg(addr, 0), size);
+            self.context.free_reg(addr);
+        }
+        self.context.free_reg(src);
+    }
 }
 
 /// Returns the index of the [`ControlStackFrame`] for the given
@@ -1,5 +1,5 @@
 use crate::{
-    abi::{align_to, ABIOperand, ABISig, LocalSlot, ABI},
+    abi::{align_to, is_externref, ABIOperand, ABISig, LocalSlot, ABI, VMCTX_PARAMS},
     masm::MacroAssembler,
 };
 use anyhow::Result;
@@ -192,11 +192,15 @@ impl Frame {
         let arg_base_offset = <A as ABI>::arg_base_offset().into();
         let mut next_stack = 0u32;
 
+        // Skip the callee and caller `VMContext` params; the callee
+        // `VMContext` is moved to its pinned register and saved in the
+        // dedicated `VMContext` slot.
         // Skip the results base param; if present, the [Frame] will create
         // a dedicated slot for it.
         let slots: Locals = sig
             .params_without_retptr()
             .into_iter()
+            .skip(VMCTX_PARAMS)
             .map(|arg| Self::abi_arg_slot(&arg, &mut next_stack, arg_base_offset))
             .collect();
 
@@ -211,6 +215,13 @@ impl Frame {
                 *next_stack = align_to(*next_stack, *size) + *size;
                 LocalSlot::new(*ty, *next_stack)
             }
+            // Create a local slot for `externref` stack arguments, which
+            // are copied to the frame so that they are covered by the
+            // function's stack maps.
+            ABIOperand::Stack { ty, size, .. } if is_externref(ty) => {
+                *next_stack = align_to(*next_stack, *size) + *size;
+                LocalSlot::new(*ty, *next_stack)
+            }
             // Create a local slot, with an offset from the arguments base in
             // the stack; which is the frame pointer + return address.
             ABIOperand::Stack { ty, offset, .. } => {
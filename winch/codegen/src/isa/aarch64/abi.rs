@@ -3,7 +3,7 @@ use crate::abi::{align_to, ABIOperand, ABIParams, ABIResults, ABISig, ParamsOrRe
 use crate::isa::{reg::Reg, CallingConvention};
 use crate::masm::OperandSize;
 use smallvec::SmallVec;
-use wasmtime_environ::{WasmFuncType, WasmHeapType, WasmType};
+use wasmtime_environ::WasmType;
 
 #[derive(Default)]
 pub(crate) struct Aarch64ABI;
@@ -83,34 +83,36 @@ impl ABI for Aarch64ABI {
         64
     }
 
-    fn sig(wasm_sig: &WasmFuncType, call_conv: &CallingConvention) -> ABISig {
-        Self::sig_from(wasm_sig.params(), wasm_sig.returns(), call_conv)
-    }
-
     fn sig_from(
         params: &[WasmType],
         returns: &[WasmType],
         call_conv: &CallingConvention,
     ) -> ABISig {
-        assert!(call_conv.is_apple_aarch64() || call_conv.is_default());
+        assert!(call_conv.is_apple_aarch64() || call_conv.is_systemv() || call_conv.is_default());
 
         let mut params_index_env = RegIndexEnv::default();
         let results = Self::abi_results(returns, call_conv);
-        let params =
-            ABIParams::from::<_, Self>(params, 0, results.on_stack(), |ty, stack_offset| {
+        let params = ABIParams::from::<_, Self>(
+            params,
+            0,
+            results.on_stack(),
+            call_conv,
+            |ty, stack_offset| {
                 Self::to_abi_operand(
                     ty,
                     stack_offset,
                     &mut params_index_env,
+                    call_conv,
                     ParamsOrReturns::Params,
                 )
-            });
+            },
+        );
 
-        ABISig::new(params, results)
+        ABISig::new(*call_conv, params, results)
     }
 
     fn abi_results(returns: &[WasmType], call_conv: &CallingConvention) -> ABIResults {
-        assert!(call_conv.is_apple_aarch64() || call_conv.is_default());
+        assert!(call_conv.is_apple_aarch64() || call_conv.is_systemv() || call_conv.is_default());
 
         let mut returns_index_env = RegIndexEnv::with_limit(1);
         ABIResults::from(returns, call_conv, |ty, stack_offset| {
@@ -118,6 +120,7 @@ impl ABI for Aarch64ABI {
                 ty,
                 stack_offset,
                 &mut returns_index_env,
+                call_conv,
                 ParamsOrReturns::Returns,
             )
         })
@@ -128,11 +131,11 @@ impl ABI for Aarch64ABI {
     }
 
     fn float_scratch_reg() -> Reg {
-        todo!()
+        regs::float_scratch()
     }
 
     fn sp_reg() -> Reg {
-        todo!()
+        regs::sp()
     }
 
     fn fp_reg() -> Reg {
@@ -153,11 +156,7 @@ impl ABI for Aarch64ABI {
 
     fn sizeof(ty: &WasmType) -> u32 {
         match ty {
-            WasmType::Ref(rt) => match rt.heap_type {
-                WasmHeapType::Func => Self::word_bytes(),
-                ht => unimplemented!("Support for WasmHeapType: {ht}"),
-            },
-            WasmType::F64 | WasmType::I64 => Self::word_bytes(),
+            WasmType::Ref(_) | WasmType::F64 | WasmType::I64 => Self::word_bytes(),
             WasmType::F32 | WasmType::I32 => Self::word_bytes() / 2,
             ty => unimplemented!("Support for WasmType: {ty}"),
         }
@@ -169,10 +168,13 @@ impl Aarch64ABI {
         wasm_arg: &WasmType,
         stack_offset: u32,
         index_env: &mut RegIndexEnv,
+        call_conv: &CallingConvention,
         params_or_returns: ParamsOrReturns,
     ) -> (ABIOperand, u32) {
         let (reg, ty) = match wasm_arg {
-            ty @ (WasmType::I32 | WasmType::I64) => (index_env.next_xreg().map(regs::xreg), ty),
+            ty @ (WasmType::I32 | WasmType::I64 | WasmType::Ref(_)) => {
+                (index_env.next_xreg().map(regs::xreg), ty)
+            }
 
             ty @ (WasmType::F32 | WasmType::F64) => (index_env.next_vreg().map(regs::vreg), ty),
 
@@ -181,17 +183,23 @@ impl Aarch64ABI {
 
         let ty_size = <Self as ABI>::sizeof(wasm_arg);
         let default = || {
-            let arg = ABIOperand::stack_offset(stack_offset, *ty, ty_size);
             let slot_size = Self::stack_slot_size();
             // Stack slots for parameters are aligned to a fixed slot size,
             // in the case of Aarch64, 8 bytes.
-            // Stack slots for returns are type-size aligned.
-            let next_stack = if params_or_returns == ParamsOrReturns::Params {
-                align_to(stack_offset, slot_size) + slot_size
+            // Stack slots for returns are type-size aligned, except for the
+            // default calling convention, in which results on the stack must
+            // match spills generated from within the compiler, which use
+            // word-sized slots.
+            if params_or_returns == ParamsOrReturns::Params {
+                let arg = ABIOperand::stack_offset(stack_offset, *ty, ty_size);
+                (arg, align_to(stack_offset, slot_size) + slot_size)
+            } else if call_conv.is_default() {
+                let arg = ABIOperand::stack_offset(stack_offset, *ty, slot_size);
+                (arg, stack_offset + slot_size)
             } else {
-                align_to(stack_offset, ty_size) + ty_size
-            };
-            (arg, next_stack)
+                let arg = ABIOperand::stack_offset(stack_offset, *ty, ty_size);
+                (arg, align_to(stack_offset, ty_size) + ty_size)
+            }
         };
         reg.map_or_else(default, |reg| {
             (ABIOperand::reg(reg, *ty, ty_size), stack_offset)
@@ -231,7 +239,11 @@ mod tests {
             [].into(),
         );
 
-        let sig = Aarch64ABI::sig(&wasm_sig, &CallingConvention::Default);
+        let sig = Aarch64ABI::sig_from(
+            wasm_sig.params(),
+            wasm_sig.returns(),
+            &CallingConvention::Default,
+        );
         let params = sig.params;
 
         match_reg_arg(params.get(0).unwrap(), I32, regs::xreg(0));
@@ -252,7 +264,11 @@ mod tests {
             [].into(),
         );
 
-        let sig = Aarch64ABI::sig(&wasm_sig, &CallingConvention::Default);
+        let sig = Aarch64ABI::sig_from(
+            wasm_sig.params(),
+            wasm_sig.returns(),
+            &CallingConvention::Default,
+        );
         let params = sig.params;
 
         match_reg_arg(params.get(0).unwrap(), F32, regs::vreg(0));
@@ -273,7 +289,11 @@ mod tests {
             [].into(),
         );
 
-        let sig = Aarch64ABI::sig(&wasm_sig, &CallingConvention::Default);
+        let sig = Aarch64ABI::sig_from(
+            wasm_sig.params(),
+            wasm_sig.returns(),
+            &CallingConvention::Default,
+        );
         let params = sig.params;
 
         match_reg_arg(params.get(0).unwrap(), F32, regs::vreg(0));
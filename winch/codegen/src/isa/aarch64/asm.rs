@@ -1,16 +1,31 @@
 //! Assembler library implementation for Aarch64.
 
 use super::{address::Address, regs};
+use crate::masm::{
+    DivKind, ExtendKind, FloatCmpKind, IntCmpKind, RemKind, RoundingMode, ShiftKind,
+};
 use crate::{masm::OperandSize, reg::Reg};
 use cranelift_codegen::{
-    ir::MemFlags,
-    isa::aarch64::inst::{
-        self,
-        emit::{EmitInfo, EmitState},
-        ALUOp, ALUOp3, AMode, ExtendOp, Imm12, Inst, PairAMode,
+    binemit::{Reloc, StackMap},
+    entity::EntityRef,
+    ir::{
+        types, ExternalName, LibCall, MemFlags, Opcode, RelSourceLoc, TrapCode, Type,
+        UserExternalNameRef,
+    },
+    isa::{
+        aarch64::inst::{
+            self,
+            emit::{EmitInfo, EmitState},
+            ALUOp, ALUOp3, AMode, BitOp, BranchTarget, CallIndInfo, CallInfo, Cond, CondBrKind,
+            ExtendOp, FPULeftShiftImm, FPUOp1, FPUOp2, FPUOpRI, FPUOpRIMod, FPURightShiftImm,
+            FpuRoundMode, FpuToIntOp, Imm12, ImmLogic, ImmShift, Inst, IntToFpuOp, PairAMode,
+            ScalarSize, UImm5, VecLanesOp, VecMisc2, VectorSize, NZCV,
+        },
+        unwind::UnwindInst,
+        CallConv,
     },
-    settings, Final, MachBuffer, MachBufferFinalized, MachInstEmit, MachInstEmitState, MachLabel,
-    Writable,
+    settings, Final, MachBuffer, MachBufferFinalized, MachInst, MachInstEmit, MachInstEmitState,
+    MachLabel, Writable,
 };
 
 impl From<OperandSize> for inst::OperandSize {
@@ -23,6 +38,63 @@ impl From<OperandSize> for inst::OperandSize {
     }
 }
 
+impl From<OperandSize> for ScalarSize {
+    fn from(size: OperandSize) -> ScalarSize {
+        match size {
+            OperandSize::S8 => ScalarSize::Size8,
+            OperandSize::S16 => ScalarSize::Size16,
+            OperandSize::S32 => ScalarSize::Size32,
+            OperandSize::S64 => ScalarSize::Size64,
+            OperandSize::S128 => ScalarSize::Size128,
+        }
+    }
+}
+
+impl From<IntCmpKind> for Cond {
+    fn from(value: IntCmpKind) -> Self {
+        match value {
+            IntCmpKind::Eq => Cond::Eq,
+            IntCmpKind::Ne => Cond::Ne,
+            IntCmpKind::LtS => Cond::Lt,
+            IntCmpKind::LtU => Cond::Lo,
+            IntCmpKind::GtS => Cond::Gt,
+            IntCmpKind::GtU => Cond::Hi,
+            IntCmpKind::LeS => Cond::Le,
+            IntCmpKind::LeU => Cond::Ls,
+            IntCmpKind::GeS => Cond::Ge,
+            IntCmpKind::GeU => Cond::Hs,
+        }
+    }
+}
+
+impl From<FloatCmpKind> for Cond {
+    fn from(value: FloatCmpKind) -> Self {
+        // `fcmp` sets the flags to `C` and `V` when comparing unordered values,
+        // so the conditions below are chosen to be false for every kind except
+        // `Ne` when either operand is NaN.
+        match value {
+            FloatCmpKind::Eq => Cond::Eq,
+            FloatCmpKind::Ne => Cond::Ne,
+            FloatCmpKind::Lt => Cond::Mi,
+            FloatCmpKind::Gt => Cond::Gt,
+            FloatCmpKind::Le => Cond::Ls,
+            FloatCmpKind::Ge => Cond::Ge,
+        }
+    }
+}
+
+impl From<OperandSize> for Type {
+    fn from(size: OperandSize) -> Self {
+        match size {
+            OperandSize::S8 => types::I8,
+            OperandSize::S16 => types::I16,
+            OperandSize::S32 => types::I32,
+            OperandSize::S64 => types::I64,
+            OperandSize::S128 => types::I128,
+        }
+    }
+}
+
 /// Low level assembler implementation for Aarch64.
 pub(crate) struct Assembler {
     /// The machine instruction buffer.
@@ -36,9 +108,16 @@ pub(crate) struct Assembler {
 impl Assembler {
     /// Create a new Aarch64 assembler.
     pub fn new(shared_flags: settings::Flags) -> Self {
+        let mut emit_state = EmitState::default();
+        // Cranelift's Aarch64 backend only registers trap sites for memory
+        // accesses which are associated with a source location, so set a
+        // non-default one in order to get heap out-of-bounds traps for
+        // untrusted memory accesses.
+        emit_state.pre_sourceloc(RelSourceLoc::new(0));
+
         Self {
             buffer: MachBuffer::<Inst>::new(),
-            emit_state: Default::default(),
+            emit_state,
             emit_info: EmitInfo::new(shared_flags),
         }
     }
@@ -55,9 +134,54 @@ impl Assembler {
     }
 
     fn emit(&mut self, inst: Inst) {
+        self.emit_with_island(inst, Inst::worst_case_size());
+    }
+
+    /// Emit the given instruction, emitting an island beforehand if the
+    /// pending fixups (e.g. conditional branches or deferred traps) would
+    /// otherwise be out of range after emitting `needed_space` bytes.
+    fn emit_with_island(&mut self, inst: Inst, needed_space: u32) {
+        self.emit_island_if_needed(needed_space);
+        inst.emit(&[], &mut self.buffer, &self.emit_info, &mut self.emit_state);
+    }
+
+    /// Emit the given call instruction, associating the given stack map, if
+    /// any, to the call's return address.
+    fn emit_call(&mut self, inst: Inst, stack_map: Option<StackMap>) {
+        self.emit_island_if_needed(Inst::worst_case_size());
+        if let Some(stack_map) = stack_map {
+            self.emit_state.pre_safepoint(stack_map);
+        }
         inst.emit(&[], &mut self.buffer, &self.emit_info, &mut self.emit_state);
     }
 
+    fn emit_island_if_needed(&mut self, needed_space: u32) {
+        if self.buffer.island_needed(needed_space) {
+            let label = self.buffer.get_label();
+            let jmp = Inst::Jump {
+                dest: BranchTarget::Label(label),
+            };
+            jmp.emit(&[], &mut self.buffer, &self.emit_info, &mut self.emit_state);
+            self.buffer
+                .emit_island(needed_space, self.emit_state.ctrl_plane_mut());
+            self.buffer
+                .bind_label(label, self.emit_state.ctrl_plane_mut());
+        }
+    }
+
+    /// Converts the given [Address] into an [AMode] for a memory access of
+    /// the given size.
+    fn to_amode(addr: Address, size: OperandSize) -> AMode {
+        match addr.try_into().unwrap() {
+            AMode::RegOffset { rn, off, .. } => AMode::RegOffset {
+                rn,
+                off,
+                ty: size.into(),
+            },
+            mem => mem,
+        }
+    }
+
     /// Load a constant into a register.
     pub fn load_constant(&mut self, imm: u64, rd: Reg) {
         let writable = Writable::from_reg(rd.into());
@@ -78,23 +202,19 @@ impl Assembler {
     }
 
     /// Store a register.
-    pub fn str(&mut self, reg: Reg, addr: Address, size: OperandSize) {
-        let mem: AMode = addr.try_into().unwrap();
-        let flags = MemFlags::trusted();
+    pub fn str(&mut self, reg: Reg, addr: Address, size: OperandSize, flags: MemFlags) {
+        let mem = Self::to_amode(addr, size);
+        let rd = reg.into();
 
         use OperandSize::*;
-        let inst = match size {
-            S64 => Inst::Store64 {
-                rd: reg.into(),
-                mem,
-                flags,
-            },
-            S32 => Inst::Store32 {
-                rd: reg.into(),
-                mem,
-                flags,
-            },
-
+        let inst = match (reg.is_int(), size) {
+            (true, S8) => Inst::Store8 { rd, mem, flags },
+            (true, S16) => Inst::Store16 { rd, mem, flags },
+            (true, S32) => Inst::Store32 { rd, mem, flags },
+            (true, S64) => Inst::Store64 { rd, mem, flags },
+            (false, S32) => Inst::FpuStore32 { rd, mem, flags },
+            (false, S64) => Inst::FpuStore64 { rd, mem, flags },
+            (false, S128) => Inst::FpuStore128 { rd, mem, flags },
             _ => unreachable!(),
         };
 
@@ -102,24 +222,48 @@ impl Assembler {
     }
 
     /// Load a register.
-    pub fn ldr(&mut self, addr: Address, rd: Reg, size: OperandSize) {
+    pub fn ldr(&mut self, addr: Address, rd: Reg, size: OperandSize, flags: MemFlags) {
         use OperandSize::*;
-        let writable_reg = Writable::from_reg(rd.into());
-        let mem: AMode = addr.try_into().unwrap();
-        let flags = MemFlags::trusted();
+        let mem = Self::to_amode(addr, size);
+        let is_int = rd.is_int();
+        let rd = Writable::from_reg(rd.into());
 
-        let inst = match size {
-            S64 => Inst::ULoad64 {
-                rd: writable_reg,
-                mem,
+        let inst = match (is_int, size) {
+            (true, S8) => Inst::ULoad8 { rd, mem, flags },
+            (true, S16) => Inst::ULoad16 { rd, mem, flags },
+            (true, S32) => Inst::ULoad32 { rd, mem, flags },
+            (true, S64) => Inst::ULoad64 { rd, mem, flags },
+            (false, S32) => Inst::FpuLoad32 { rd, mem, flags },
+            (false, S64) => Inst::FpuLoad64 { rd, mem, flags },
+            (false, S128) => Inst::FpuLoad128 { rd, mem, flags },
+            _ => unreachable!(),
+        };
+
+        self.emit(inst);
+    }
+
+    /// Load a register, sign extending the loaded value according to the
+    /// given [ExtendKind].
+    pub fn sldr(&mut self, addr: Address, rd: Reg, kind: ExtendKind, flags: MemFlags) {
+        use ExtendKind::*;
+        let rd = Writable::from_reg(rd.into());
+        let inst = match kind {
+            I32Extend8S | I64Extend8S => Inst::SLoad8 {
+                rd,
+                mem: Self::to_amode(addr, OperandSize::S8),
                 flags,
             },
-            S32 => Inst::ULoad32 {
-                rd: writable_reg,
-                mem,
+            I32Extend16S | I64Extend16S => Inst::SLoad16 {
+                rd,
+                mem: Self::to_amode(addr, OperandSize::S16),
                 flags,
             },
-            _ => unreachable!(),
+            I64Extend32S | I64ExtendI32S => Inst::SLoad32 {
+                rd,
+                mem: Self::to_amode(addr, OperandSize::S32),
+                flags,
+            },
+            I64ExtendI32U => unreachable!(),
         };
 
         self.emit(inst);
@@ -139,6 +283,78 @@ impl Assembler {
         });
     }
 
+    /// Load-acquire a register from the address in `rn`, zero extending
+    /// values narrower than the register.
+    pub fn ldar(&mut self, rn: Reg, rt: Reg, size: OperandSize, flags: MemFlags) {
+        self.emit(Inst::LoadAcquire {
+            access_ty: size.into(),
+            rt: Writable::from_reg(rt.into()),
+            rn: rn.into(),
+            flags,
+        });
+    }
+
+    /// Store-release a register to the address in `rn`.
+    pub fn stlr(&mut self, rt: Reg, rn: Reg, size: OperandSize, flags: MemFlags) {
+        self.emit(Inst::StoreRelease {
+            access_ty: size.into(),
+            rt: rt.into(),
+            rn: rn.into(),
+            flags,
+        });
+    }
+
+    /// Load-acquire exclusive a register from the address in `rn`, zero
+    /// extending values narrower than the register.
+    pub fn ldaxr(&mut self, rn: Reg, rt: Reg, size: OperandSize, flags: MemFlags) {
+        self.emit_exclusive(
+            0b00_001000_0_1_0_11111_1_11111_00000_00000
+                | ((rn.hw_enc() as u32) << 5)
+                | rt.hw_enc() as u32,
+            size,
+            flags,
+        );
+    }
+
+    /// Store-release exclusive a register to the address in `rn`, setting
+    /// `rs` to 0 if the store succeeded or to 1 otherwise.
+    pub fn stlxr(&mut self, rt: Reg, rn: Reg, rs: Reg, size: OperandSize, flags: MemFlags) {
+        assert!(rs != rt && rs != rn);
+        self.emit_exclusive(
+            0b00_001000_000_00000_1_11111_00000_00000
+                | ((rs.hw_enc() as u32) << 16)
+                | ((rn.hw_enc() as u32) << 5)
+                | rt.hw_enc() as u32,
+            size,
+            flags,
+        );
+    }
+
+    /// Emit an exclusive memory access given its encoding without the size
+    /// field.
+    fn emit_exclusive(&mut self, bits: u32, size: OperandSize, flags: MemFlags) {
+        let sz = match size {
+            OperandSize::S8 => 0b00,
+            OperandSize::S16 => 0b01,
+            OperandSize::S32 => 0b10,
+            OperandSize::S64 => 0b11,
+            OperandSize::S128 => unreachable!(),
+        };
+        // Cranelift only emits exclusive accesses as part of its atomic
+        // loops, which hardcode their registers (including x28, the shadow
+        // stack pointer), so encode them directly.
+        self.emit_island_if_needed(4);
+        if !flags.notrap() {
+            self.buffer.add_trap(TrapCode::HeapOutOfBounds);
+        }
+        self.buffer.put4(bits | (sz << 30));
+    }
+
+    /// Data memory barrier over the inner shareable domain.
+    pub fn dmb_ish(&mut self) {
+        self.emit(Inst::Fence {});
+    }
+
     /// Register to register move.
     pub fn mov_rr(&mut self, rm: Reg, rd: Reg, size: OperandSize) {
         let writable_rd = Writable::from_reg(rd.into());
@@ -149,6 +365,64 @@ impl Assembler {
         });
     }
 
+    /// Floating point register to floating point register move.
+    pub fn fmov_rr(&mut self, rn: Reg, rd: Reg, size: OperandSize) {
+        let writable = Writable::from_reg(rd.into());
+        let inst = match size {
+            OperandSize::S32 | OperandSize::S64 => Inst::FpuMove64 {
+                rd: writable,
+                rn: rn.into(),
+            },
+            OperandSize::S128 => Inst::FpuMove128 {
+                rd: writable,
+                rn: rn.into(),
+            },
+            _ => unreachable!(),
+        };
+
+        self.emit(inst);
+    }
+
+    /// Move the bits of a general purpose register into a floating point
+    /// register.
+    pub fn mov_to_fpu(&mut self, rn: Reg, rd: Reg, size: OperandSize) {
+        self.emit(Inst::MovToFpu {
+            rd: Writable::from_reg(rd.into()),
+            rn: rn.into(),
+            size: size.into(),
+        });
+    }
+
+    /// Move the bits of a general purpose register into the lane at the given
+    /// index of a vector register, leaving its other lanes untouched.
+    pub fn mov_to_vec(&mut self, rn: Reg, rd: Reg, idx: u8, size: OperandSize) {
+        let size = match size {
+            OperandSize::S8 => VectorSize::Size8x16,
+            OperandSize::S16 => VectorSize::Size16x8,
+            OperandSize::S32 => VectorSize::Size32x4,
+            OperandSize::S64 => VectorSize::Size64x2,
+            OperandSize::S128 => unreachable!(),
+        };
+        self.emit(Inst::MovToVec {
+            rd: Writable::from_reg(rd.into()),
+            ri: rd.into(),
+            rn: rn.into(),
+            idx,
+            size,
+        });
+    }
+
+    /// Move the lane at the given index of a vector register into a general
+    /// purpose register.
+    pub fn mov_from_vec(&mut self, rn: Reg, rd: Reg, idx: u8, size: OperandSize) {
+        self.emit(Inst::MovFromVec {
+            rd: Writable::from_reg(rd.into()),
+            rn: rn.into(),
+            idx,
+            size: size.into(),
+        });
+    }
+
     /// Add with three registers.
     pub fn add_rrr(&mut self, rm: Reg, rn: Reg, rd: Reg, size: OperandSize) {
         self.emit_alu_rrr_extend(ALUOp::Add, rm, rn, rd, size);
@@ -166,6 +440,23 @@ impl Assembler {
         }
     }
 
+    /// Add with three registers, setting overflow flags.
+    pub fn adds_rrr(&mut self, rm: Reg, rn: Reg, rd: Reg, size: OperandSize) {
+        self.emit_alu_rrr_extend(ALUOp::AddS, rm, rn, rd, size);
+    }
+
+    /// Add immediate and register, setting overflow flags.
+    pub fn adds_ir(&mut self, imm: u64, rn: Reg, rd: Reg, size: OperandSize) {
+        let alu_op = ALUOp::AddS;
+        if let Some(imm) = Imm12::maybe_from_u64(imm) {
+            self.emit_alu_rri(alu_op, imm, rn, rd, size);
+        } else {
+            let scratch = regs::scratch();
+            self.load_constant(imm, scratch);
+            self.emit_alu_rrr_extend(alu_op, scratch, rn, rd, size);
+        }
+    }
+
     /// Substract with three registers.
     pub fn sub_rrr(&mut self, rm: Reg, rn: Reg, rd: Reg, size: OperandSize) {
         self.emit_alu_rrr_extend(ALUOp::Sub, rm, rn, rd, size);
@@ -183,6 +474,43 @@ impl Assembler {
         }
     }
 
+    /// Compare two registers, setting the flags to the result of `rn - rm`.
+    pub fn cmp_rr(&mut self, rm: Reg, rn: Reg, size: OperandSize) {
+        self.emit_alu_rrr_extend(ALUOp::SubS, rm, rn, regs::zero(), size);
+    }
+
+    /// Compare a register and an immediate, setting the flags to the result
+    /// of `rn - imm`.
+    pub fn cmp_ir(&mut self, imm: u64, rn: Reg, size: OperandSize) {
+        let alu_op = ALUOp::SubS;
+        if let Some(imm) = Imm12::maybe_from_u64(imm) {
+            self.emit_alu_rri(alu_op, imm, rn, regs::zero(), size);
+        } else {
+            let scratch = regs::scratch();
+            self.load_constant(imm, scratch);
+            self.emit_alu_rrr_extend(alu_op, scratch, rn, regs::zero(), size);
+        }
+    }
+
+    /// Test the bits of two registers, setting the flags to the result of
+    /// `rn & rm`.
+    pub fn tst_rr(&mut self, rm: Reg, rn: Reg, size: OperandSize) {
+        self.emit_alu_rrr(ALUOp::AndS, rm, rn, regs::zero(), size);
+    }
+
+    /// Conditional compare of a register and an immediate. If the given
+    /// condition holds, the flags are set to the result of `rn - imm`, else
+    /// the flags are set to the given `nzcv` value.
+    pub fn ccmp_ir(&mut self, imm: u8, rn: Reg, nzcv: NZCV, cond: Cond, size: OperandSize) {
+        self.emit(Inst::CCmpImm {
+            size: size.into(),
+            rn: rn.into(),
+            imm: UImm5::maybe_from_u8(imm).expect("valid 5-bit immediate"),
+            nzcv,
+            cond,
+        });
+    }
+
     /// Multiply with three registers.
     pub fn mul_rrr(&mut self, rm: Reg, rn: Reg, rd: Reg, size: OperandSize) {
         self.emit_alu_rrrr(ALUOp3::MAdd, rm, rn, rd, regs::zero(), size);
@@ -195,11 +523,655 @@ impl Assembler {
         self.emit_alu_rrrr(ALUOp3::MAdd, scratch, rn, rd, regs::zero(), size);
     }
 
+    /// Multiply and subtract: `rd = ra - (rn * rm)`.
+    pub fn msub_rrrr(&mut self, rm: Reg, rn: Reg, ra: Reg, rd: Reg, size: OperandSize) {
+        self.emit_alu_rrrr(ALUOp3::MSub, rm, rn, rd, ra, size);
+    }
+
+    /// Signed division with three registers.
+    pub fn sdiv_rrr(&mut self, rm: Reg, rn: Reg, rd: Reg, size: OperandSize) {
+        self.emit_alu_rrr(ALUOp::SDiv, rm, rn, rd, size);
+    }
+
+    /// Unsigned division with three registers.
+    pub fn udiv_rrr(&mut self, rm: Reg, rn: Reg, rd: Reg, size: OperandSize) {
+        self.emit_alu_rrr(ALUOp::UDiv, rm, rn, rd, size);
+    }
+
+    /// Integer division with three registers. Traps if the divisor is zero
+    /// or, for signed division, if the result overflows.
+    pub fn div_rrr(
+        &mut self,
+        divisor: Reg,
+        dividend: Reg,
+        dest: Reg,
+        kind: DivKind,
+        size: OperandSize,
+    ) {
+        self.extend_div_operands(divisor, dividend, &kind, size);
+        self.trapz(divisor, TrapCode::IntegerDivisionByZero);
+
+        if kind == DivKind::Signed {
+            // Check for the only overflowing case: `dividend == MIN` and
+            // `divisor == -1`.
+            // The first comparison sets the zero flag if the divisor is `-1`;
+            // if so, the conditional comparison sets the overflow flag if the
+            // dividend is the minimum value, else all flags are cleared.
+            self.adds_ir(1, divisor, regs::zero(), size);
+            self.ccmp_ir(
+                1,
+                dividend,
+                NZCV::new(false, false, false, false),
+                Cond::Eq,
+                size,
+            );
+            self.trapif(Cond::Vs, TrapCode::IntegerOverflow);
+        }
+
+        // The operands have been extended to 64 bits, if needed.
+        match kind {
+            DivKind::Signed => self.sdiv_rrr(divisor, dividend, dest, OperandSize::S64),
+            DivKind::Unsigned => self.udiv_rrr(divisor, dividend, dest, OperandSize::S64),
+        }
+    }
+
+    /// Integer remainder with three registers. Traps if the divisor is
+    /// zero.
+    pub fn rem_rrr(
+        &mut self,
+        divisor: Reg,
+        dividend: Reg,
+        dest: Reg,
+        kind: RemKind,
+        size: OperandSize,
+    ) {
+        let div_kind = match kind {
+            RemKind::Signed => DivKind::Signed,
+            RemKind::Unsigned => DivKind::Unsigned,
+        };
+        self.extend_div_operands(divisor, dividend, &div_kind, size);
+        self.trapz(divisor, TrapCode::IntegerDivisionByZero);
+
+        // Calculate the quotient in the scratch register and derive the
+        // remainder through `dividend - (quotient * divisor)`. Note that,
+        // unlike division, there's no overflow check needed: the hardware
+        // doesn't trap when dividing the minimum value by `-1` and the
+        // resulting remainder is `0`, as expected.
+        let scratch = regs::scratch();
+        match div_kind {
+            DivKind::Signed => self.sdiv_rrr(divisor, dividend, scratch, OperandSize::S64),
+            DivKind::Unsigned => self.udiv_rrr(divisor, dividend, scratch, OperandSize::S64),
+        }
+        self.msub_rrrr(divisor, scratch, dividend, dest, OperandSize::S64);
+    }
+
+    /// Extends the operands of 32-bit divisions to 64 bits, according to the
+    /// signedness of the operation.
+    fn extend_div_operands(
+        &mut self,
+        divisor: Reg,
+        dividend: Reg,
+        kind: &DivKind,
+        size: OperandSize,
+    ) {
+        if size == OperandSize::S32 {
+            let ext = || match kind {
+                DivKind::Signed => ExtendKind::I64ExtendI32S,
+                DivKind::Unsigned => ExtendKind::I64ExtendI32U,
+            };
+            self.extend(divisor, divisor, ext());
+            self.extend(dividend, dividend, ext());
+        }
+    }
+
+    /// And with three registers.
+    pub fn and_rrr(&mut self, rm: Reg, rn: Reg, rd: Reg, size: OperandSize) {
+        self.emit_alu_rrr(ALUOp::And, rm, rn, rd, size);
+    }
+
+    /// And immediate and register.
+    pub fn and_ir(&mut self, imm: u64, rn: Reg, rd: Reg, size: OperandSize) {
+        self.emit_alu_rri_logic(ALUOp::And, imm, rn, rd, size);
+    }
+
+    /// Or with three registers.
+    pub fn or_rrr(&mut self, rm: Reg, rn: Reg, rd: Reg, size: OperandSize) {
+        self.emit_alu_rrr(ALUOp::Orr, rm, rn, rd, size);
+    }
+
+    /// Or immediate and register.
+    pub fn or_ir(&mut self, imm: u64, rn: Reg, rd: Reg, size: OperandSize) {
+        self.emit_alu_rri_logic(ALUOp::Orr, imm, rn, rd, size);
+    }
+
+    /// Xor with three registers.
+    pub fn xor_rrr(&mut self, rm: Reg, rn: Reg, rd: Reg, size: OperandSize) {
+        self.emit_alu_rrr(ALUOp::Eor, rm, rn, rd, size);
+    }
+
+    /// Xor immediate and register.
+    pub fn xor_ir(&mut self, imm: u64, rn: Reg, rd: Reg, size: OperandSize) {
+        self.emit_alu_rri_logic(ALUOp::Eor, imm, rn, rd, size);
+    }
+
+    /// Shift with three registers.
+    pub fn shift_rrr(&mut self, rm: Reg, rn: Reg, rd: Reg, kind: ShiftKind, size: OperandSize) {
+        let shift_op = match kind {
+            ShiftKind::Rotl => {
+                // Aarch64 only supports right rotations; a left rotation by
+                // `n` is equivalent to a right rotation by `-n`, given that the
+                // rotation amount is taken modulo the operand size.
+                let scratch = regs::scratch();
+                self.emit_alu_rrr(ALUOp::Sub, rm, regs::zero(), scratch, size);
+                self.emit_alu_rrr(ALUOp::RotR, scratch, rn, rd, size);
+                return;
+            }
+            ShiftKind::Rotr => ALUOp::RotR,
+            ShiftKind::Shl => ALUOp::Lsl,
+            ShiftKind::ShrS => ALUOp::Asr,
+            ShiftKind::ShrU => ALUOp::Lsr,
+        };
+        self.emit_alu_rrr(shift_op, rm, rn, rd, size);
+    }
+
+    /// Shift immediate and register.
+    pub fn shift_ir(&mut self, imm: u64, rn: Reg, rd: Reg, kind: ShiftKind, size: OperandSize) {
+        let bits = size.num_bits() as u64;
+        let amount = imm % bits;
+        let (shift_op, amount) = match kind {
+            ShiftKind::Rotl => (ALUOp::RotR, (bits - amount) % bits),
+            ShiftKind::Rotr => (ALUOp::RotR, amount),
+            ShiftKind::Shl => (ALUOp::Lsl, amount),
+            ShiftKind::ShrS => (ALUOp::Asr, amount),
+            ShiftKind::ShrU => (ALUOp::Lsr, amount),
+        };
+        self.emit(Inst::AluRRImmShift {
+            alu_op: shift_op,
+            size: size.into(),
+            rd: Writable::from_reg(rd.into()),
+            rn: rn.into(),
+            immshift: ImmShift::maybe_from_u64(amount).unwrap(),
+        });
+    }
+
+    /// Count leading zeros.
+    pub fn clz(&mut self, rn: Reg, rd: Reg, size: OperandSize) {
+        self.emit_bit_rr(BitOp::Clz, rn, rd, size);
+    }
+
+    /// Reverse the bits of a register.
+    pub fn rbit(&mut self, rn: Reg, rd: Reg, size: OperandSize) {
+        self.emit_bit_rr(BitOp::RBit, rn, rd, size);
+    }
+
+    /// Count the number of set bits in each byte of the lower 64 bits of a
+    /// vector register.
+    pub fn cnt(&mut self, rn: Reg, rd: Reg) {
+        self.emit(Inst::VecMisc {
+            op: VecMisc2::Cnt,
+            rd: Writable::from_reg(rd.into()),
+            rn: rn.into(),
+            size: VectorSize::Size8x8,
+        });
+    }
+
+    /// Add the bytes of the lower 64 bits of a vector register.
+    pub fn addv(&mut self, rn: Reg, rd: Reg) {
+        self.emit(Inst::VecLanes {
+            op: VecLanesOp::Addv,
+            rd: Writable::from_reg(rd.into()),
+            rn: rn.into(),
+            size: VectorSize::Size8x8,
+        });
+    }
+
+    /// Sign or zero extend a register.
+    pub fn extend(&mut self, rn: Reg, rd: Reg, kind: ExtendKind) {
+        use ExtendKind::*;
+        let (signed, from_bits, to_bits) = match kind {
+            I64ExtendI32S | I64Extend32S => (true, 32, 64),
+            I64ExtendI32U => (false, 32, 64),
+            I32Extend8S => (true, 8, 32),
+            I32Extend16S => (true, 16, 32),
+            I64Extend8S => (true, 8, 64),
+            I64Extend16S => (true, 16, 64),
+        };
+        self.emit(Inst::Extend {
+            rd: Writable::from_reg(rd.into()),
+            rn: rn.into(),
+            signed,
+            from_bits,
+            to_bits,
+        });
+    }
+
+    /// Set a register to 1 if the condition holds, or to 0 otherwise.
+    pub fn cset(&mut self, rd: Reg, cond: impl Into<Cond>) {
+        self.emit(Inst::CSet {
+            rd: Writable::from_reg(rd.into()),
+            cond: cond.into(),
+        });
+    }
+
+    /// Conditional select: `rd = cond ? rn : rm`.
+    pub fn csel(&mut self, rn: Reg, rm: Reg, rd: Reg, cond: impl Into<Cond>) {
+        self.emit(Inst::CSel {
+            rd: Writable::from_reg(rd.into()),
+            cond: cond.into(),
+            rn: rn.into(),
+            rm: rm.into(),
+        });
+    }
+
+    /// Floating point conditional select: `rd = cond ? rn : rm`.
+    pub fn fpu_csel(
+        &mut self,
+        rn: Reg,
+        rm: Reg,
+        rd: Reg,
+        cond: impl Into<Cond>,
+        size: OperandSize,
+    ) {
+        let rd = Writable::from_reg(rd.into());
+        let (rn, rm, cond) = (rn.into(), rm.into(), cond.into());
+        let inst = match size {
+            OperandSize::S32 => Inst::FpuCSel32 { rd, rn, rm, cond },
+            OperandSize::S64 => Inst::FpuCSel64 { rd, rn, rm, cond },
+            _ => unreachable!(),
+        };
+        self.emit(inst);
+    }
+
+    /// Floating point add with three registers.
+    pub fn fadd_rrr(&mut self, rm: Reg, rn: Reg, rd: Reg, size: OperandSize) {
+        self.emit_fpu_rrr(FPUOp2::Add, rm, rn, rd, size);
+    }
+
+    /// Floating point subtract with three registers.
+    pub fn fsub_rrr(&mut self, rm: Reg, rn: Reg, rd: Reg, size: OperandSize) {
+        self.emit_fpu_rrr(FPUOp2::Sub, rm, rn, rd, size);
+    }
+
+    /// Floating point multiply with three registers.
+    pub fn fmul_rrr(&mut self, rm: Reg, rn: Reg, rd: Reg, size: OperandSize) {
+        self.emit_fpu_rrr(FPUOp2::Mul, rm, rn, rd, size);
+    }
+
+    /// Floating point divide with three registers.
+    pub fn fdiv_rrr(&mut self, rm: Reg, rn: Reg, rd: Reg, size: OperandSize) {
+        self.emit_fpu_rrr(FPUOp2::Div, rm, rn, rd, size);
+    }
+
+    /// Floating point minimum with three registers.
+    pub fn fmin_rrr(&mut self, rm: Reg, rn: Reg, rd: Reg, size: OperandSize) {
+        self.emit_fpu_rrr(FPUOp2::Min, rm, rn, rd, size);
+    }
+
+    /// Floating point maximum with three registers.
+    pub fn fmax_rrr(&mut self, rm: Reg, rn: Reg, rd: Reg, size: OperandSize) {
+        self.emit_fpu_rrr(FPUOp2::Max, rm, rn, rd, size);
+    }
+
+    /// Floating point negation.
+    pub fn fneg_rr(&mut self, rn: Reg, rd: Reg, size: OperandSize) {
+        self.emit_fpu_rr(FPUOp1::Neg, rn, rd, size);
+    }
+
+    /// Floating point absolute value.
+    pub fn fabs_rr(&mut self, rn: Reg, rd: Reg, size: OperandSize) {
+        self.emit_fpu_rr(FPUOp1::Abs, rn, rd, size);
+    }
+
+    /// Floating point square root.
+    pub fn fsqrt_rr(&mut self, rn: Reg, rd: Reg, size: OperandSize) {
+        self.emit_fpu_rr(FPUOp1::Sqrt, rn, rd, size);
+    }
+
+    /// Convert a floating point value to a different floating point size.
+    pub fn cvt_float_to_float(
+        &mut self,
+        rn: Reg,
+        rd: Reg,
+        src_size: OperandSize,
+        dst_size: OperandSize,
+    ) {
+        let op = match (src_size, dst_size) {
+            (OperandSize::S32, OperandSize::S64) => FPUOp1::Cvt32To64,
+            (OperandSize::S64, OperandSize::S32) => FPUOp1::Cvt64To32,
+            _ => unreachable!(),
+        };
+        self.emit_fpu_rr(op, rn, rd, src_size);
+    }
+
+    /// Floating point rounding.
+    pub fn fround_rr(&mut self, rn: Reg, rd: Reg, mode: RoundingMode, size: OperandSize) {
+        let op = match (mode, size) {
+            (RoundingMode::Up, OperandSize::S32) => FpuRoundMode::Plus32,
+            (RoundingMode::Up, OperandSize::S64) => FpuRoundMode::Plus64,
+            (RoundingMode::Down, OperandSize::S32) => FpuRoundMode::Minus32,
+            (RoundingMode::Down, OperandSize::S64) => FpuRoundMode::Minus64,
+            (RoundingMode::Zero, OperandSize::S32) => FpuRoundMode::Zero32,
+            (RoundingMode::Zero, OperandSize::S64) => FpuRoundMode::Zero64,
+            (RoundingMode::Nearest, OperandSize::S32) => FpuRoundMode::Nearest32,
+            (RoundingMode::Nearest, OperandSize::S64) => FpuRoundMode::Nearest64,
+            _ => unreachable!(),
+        };
+        self.emit(Inst::FpuRound {
+            op,
+            rd: Writable::from_reg(rd.into()),
+            rn: rn.into(),
+        });
+    }
+
+    /// Floating point unsigned shift right by an immediate.
+    pub fn fushr_ri(&mut self, amount: u8, rn: Reg, rd: Reg, size: OperandSize) {
+        let bits = size.num_bits() as u8;
+        let imm = FPURightShiftImm::maybe_from_u8(amount, bits).unwrap();
+        let fpu_op = match size {
+            OperandSize::S32 => FPUOpRI::UShr32(imm),
+            OperandSize::S64 => FPUOpRI::UShr64(imm),
+            _ => unreachable!(),
+        };
+        self.emit(Inst::FpuRRI {
+            fpu_op,
+            rd: Writable::from_reg(rd.into()),
+            rn: rn.into(),
+        });
+    }
+
+    /// Floating point shift left by an immediate and insert, preserving the
+    /// bits of `rd` that are not overwritten by the shifted value.
+    pub fn fsli_ri(&mut self, amount: u8, rn: Reg, rd: Reg, size: OperandSize) {
+        let bits = size.num_bits() as u8;
+        let imm = FPULeftShiftImm::maybe_from_u8(amount, bits).unwrap();
+        let fpu_op = match size {
+            OperandSize::S32 => FPUOpRIMod::Sli32(imm),
+            OperandSize::S64 => FPUOpRIMod::Sli64(imm),
+            _ => unreachable!(),
+        };
+        self.emit(Inst::FpuRRIMod {
+            fpu_op,
+            rd: Writable::from_reg(rd.into()),
+            ri: rd.into(),
+            rn: rn.into(),
+        });
+    }
+
+    /// Floating point compare, setting the flags to the result of comparing
+    /// `rn` and `rm`.
+    pub fn fcmp(&mut self, rn: Reg, rm: Reg, size: OperandSize) {
+        self.emit(Inst::FpuCmp {
+            size: size.into(),
+            rn: rn.into(),
+            rm: rm.into(),
+        });
+    }
+
+    /// Convert a floating point value to an integer, rounding towards zero.
+    pub fn cvt_float_to_int(
+        &mut self,
+        rn: Reg,
+        rd: Reg,
+        signed: bool,
+        src_size: OperandSize,
+        dst_size: OperandSize,
+    ) {
+        use OperandSize::*;
+        let op = match (signed, src_size, dst_size) {
+            (true, S32, S32) => FpuToIntOp::F32ToI32,
+            (true, S32, S64) => FpuToIntOp::F32ToI64,
+            (true, S64, S32) => FpuToIntOp::F64ToI32,
+            (true, S64, S64) => FpuToIntOp::F64ToI64,
+            (false, S32, S32) => FpuToIntOp::F32ToU32,
+            (false, S32, S64) => FpuToIntOp::F32ToU64,
+            (false, S64, S32) => FpuToIntOp::F64ToU32,
+            (false, S64, S64) => FpuToIntOp::F64ToU64,
+            _ => unreachable!(),
+        };
+        self.emit(Inst::FpuToInt {
+            op,
+            rd: Writable::from_reg(rd.into()),
+            rn: rn.into(),
+        });
+    }
+
+    /// Emits the sequence to convert a floating point value to an integer,
+    /// trapping if the value is NaN or if it's out of the range of the
+    /// destination integer type.
+    pub fn cvt_float_to_int_seq(
+        &mut self,
+        src: Reg,
+        dst: Reg,
+        signed: bool,
+        src_size: OperandSize,
+        dst_size: OperandSize,
+    ) {
+        use OperandSize::*;
+        let tmp = regs::float_scratch();
+
+        // Check for NaN.
+        self.fcmp(src, src, src_size);
+        self.trapif(Cond::Vs, TrapCode::BadConversionToInteger);
+
+        // Check the lower bound. The bound is exclusive when it can be
+        // exactly represented in the source type, in which case the bound
+        // itself is a valid input.
+        let (min, min_cond) = match (signed, src_size, dst_size) {
+            (true, S32, S32) => ((i32::MIN as f32).to_bits() as u64, Cond::Mi),
+            (true, S32, S64) => ((i64::MIN as f32).to_bits() as u64, Cond::Mi),
+            (true, S64, S32) => ((i32::MIN as f64 - 1.).to_bits(), Cond::Ls),
+            (true, S64, S64) => ((i64::MIN as f64).to_bits(), Cond::Mi),
+            (false, S32, _) => ((-1f32).to_bits() as u64, Cond::Ls),
+            (false, S64, _) => ((-1f64).to_bits(), Cond::Ls),
+            _ => unreachable!(),
+        };
+        self.load_constant(min, regs::scratch());
+        self.mov_to_fpu(regs::scratch(), tmp, src_size);
+        self.fcmp(src, tmp, src_size);
+        self.trapif(min_cond, TrapCode::IntegerOverflow);
+
+        // Check the upper bound.
+        let max = match (signed, src_size, dst_size) {
+            (true, S32, S32) => ((i32::MAX as u64 + 1) as f32).to_bits() as u64,
+            (true, S32, S64) => ((i64::MAX as u64 + 1) as f32).to_bits() as u64,
+            (true, S64, S32) => ((i32::MAX as u64 + 1) as f64).to_bits(),
+            (true, S64, S64) => ((i64::MAX as u64 + 1) as f64).to_bits(),
+            (false, S32, S32) => ((u32::MAX as u64 + 1) as f32).to_bits() as u64,
+            (false, S32, S64) => ((u64::MAX as u128 + 1) as f32).to_bits() as u64,
+            (false, S64, S32) => ((u32::MAX as u64 + 1) as f64).to_bits(),
+            (false, S64, S64) => ((u64::MAX as u128 + 1) as f64).to_bits(),
+            _ => unreachable!(),
+        };
+        self.load_constant(max, regs::scratch());
+        self.mov_to_fpu(regs::scratch(), tmp, src_size);
+        self.fcmp(src, tmp, src_size);
+        self.trapif(Cond::Ge, TrapCode::IntegerOverflow);
+
+        self.cvt_float_to_int(src, dst, signed, src_size, dst_size);
+    }
+
+    /// Convert an integer to a floating point value.
+    pub fn cvt_int_to_float(
+        &mut self,
+        rn: Reg,
+        rd: Reg,
+        signed: bool,
+        src_size: OperandSize,
+        dst_size: OperandSize,
+    ) {
+        use OperandSize::*;
+        let op = match (signed, src_size, dst_size) {
+            (true, S32, S32) => IntToFpuOp::I32ToF32,
+            (true, S32, S64) => IntToFpuOp::I32ToF64,
+            (true, S64, S32) => IntToFpuOp::I64ToF32,
+            (true, S64, S64) => IntToFpuOp::I64ToF64,
+            (false, S32, S32) => IntToFpuOp::U32ToF32,
+            (false, S32, S64) => IntToFpuOp::U32ToF64,
+            (false, S64, S32) => IntToFpuOp::U64ToF32,
+            (false, S64, S64) => IntToFpuOp::U64ToF64,
+            _ => unreachable!(),
+        };
+        self.emit(Inst::IntToFpu {
+            op,
+            rd: Writable::from_reg(rd.into()),
+            rn: rn.into(),
+        });
+    }
+
     /// Return instruction.
     pub fn ret(&mut self) {
         self.emit(Inst::Ret {});
     }
 
+    /// Unconditional jump to a label.
+    pub fn jmp(&mut self, target: MachLabel) {
+        self.emit(Inst::Jump {
+            dest: BranchTarget::Label(target),
+        });
+    }
+
+    /// Emits a jump to a locally defined function through an index, used
+    /// for tail calls.
+    pub fn jmp_with_index(&mut self, index: u32) {
+        let dest = ExternalName::user(UserExternalNameRef::new(index as usize));
+        self.emit_island_if_needed(4);
+        // There's no Cranelift instruction for a jump to an external name,
+        // so encode `b` directly.
+        self.buffer.add_reloc(Reloc::Arm64Call, &dest, 0);
+        self.buffer.put4(0b000101 << 26);
+    }
+
+    /// Emits an indirect jump through the given register, used for tail
+    /// calls.
+    pub fn jmp_reg(&mut self, target: Reg) {
+        self.emit(Inst::IndirectBr {
+            rn: target.into(),
+            targets: vec![],
+        });
+    }
+
+    /// Conditional jump to a label; falls through to the next instruction
+    /// when the condition doesn't hold.
+    pub fn jmp_if(&mut self, cond: impl Into<Cond>, taken: MachLabel) {
+        self.emit_cond_br(CondBrKind::Cond(cond.into()), taken);
+    }
+
+    /// Jump to a label if the given register is not zero; falls through to
+    /// the next instruction otherwise.
+    pub fn jmp_if_not_zero(&mut self, rn: Reg, taken: MachLabel) {
+        self.emit_cond_br(CondBrKind::NotZero(rn.into()), taken);
+    }
+
+    fn emit_cond_br(&mut self, kind: CondBrKind, taken: MachLabel) {
+        let not_taken = self.buffer.get_label();
+        self.emit(Inst::CondBr {
+            taken: BranchTarget::Label(taken),
+            not_taken: BranchTarget::Label(not_taken),
+            kind,
+        });
+        // Binding the fallthrough label right after the branch allows the
+        // machine buffer to elide the unconditional part of the branch.
+        self.buffer
+            .bind_label(not_taken, self.emit_state.ctrl_plane_mut());
+    }
+
+    /// Emits a jump table sequence. The default target is expected to be
+    /// taken if the flags, as set by a prior comparison of the index against
+    /// the number of targets, indicate an unsigned greater than or equal
+    /// result.
+    pub fn jmp_table(
+        &mut self,
+        targets: &[MachLabel],
+        default: MachLabel,
+        index: Reg,
+        tmp1: Reg,
+        tmp2: Reg,
+    ) {
+        // The sequence is made of 8 instructions plus the table itself.
+        let needed_space = 4 * (8 + targets.len() as u32);
+        self.emit_with_island(
+            Inst::JTSequence {
+                default,
+                targets: Box::new(targets.to_vec()),
+                ridx: index.into(),
+                rtmp1: Writable::from_reg(tmp1.into()),
+                rtmp2: Writable::from_reg(tmp2.into()),
+            },
+            needed_space,
+        );
+    }
+
+    /// Emit an unconditional trap.
+    pub fn udf(&mut self, code: TrapCode) {
+        self.emit(Inst::Udf { trap_code: code });
+    }
+
+    /// Trap if the condition holds.
+    pub fn trapif(&mut self, cond: impl Into<Cond>, code: TrapCode) {
+        self.emit(Inst::TrapIf {
+            kind: CondBrKind::Cond(cond.into()),
+            trap_code: code,
+        });
+    }
+
+    /// Trap if the given register is zero.
+    pub fn trapz(&mut self, rn: Reg, code: TrapCode) {
+        self.emit(Inst::TrapIf {
+            kind: CondBrKind::Zero(rn.into()),
+            trap_code: code,
+        });
+    }
+
+    /// Emit a call to an unknown location through a register.
+    pub fn call_with_reg(&mut self, callee: Reg, stack_map: Option<StackMap>) {
+        let inst = Inst::CallInd {
+            info: Box::new(CallIndInfo {
+                rn: callee.into(),
+                uses: Default::default(),
+                defs: Default::default(),
+                clobbers: Default::default(),
+                opcode: Opcode::CallIndirect,
+                caller_callconv: CallConv::SystemV,
+                callee_callconv: CallConv::SystemV,
+                callee_pop_size: 0,
+            }),
+        };
+        self.emit_call(inst, stack_map);
+    }
+
+    /// Emit a call to a locally defined function through an index.
+    pub fn call_with_index(&mut self, index: u32, stack_map: Option<StackMap>) {
+        let dest = ExternalName::user(UserExternalNameRef::new(index as usize));
+        self.call_with_name(dest, stack_map);
+    }
+
+    /// Emit a call to a well-known libcall.
+    pub fn call_with_lib(&mut self, lib: LibCall, stack_map: Option<StackMap>) {
+        let dest = ExternalName::LibCall(lib);
+        self.call_with_name(dest, stack_map);
+    }
+
+    fn call_with_name(&mut self, dest: ExternalName, stack_map: Option<StackMap>) {
+        let inst = Inst::Call {
+            info: Box::new(CallInfo {
+                dest,
+                uses: Default::default(),
+                defs: Default::default(),
+                clobbers: Default::default(),
+                opcode: Opcode::Call,
+                caller_callconv: CallConv::SystemV,
+                callee_callconv: CallConv::SystemV,
+                callee_pop_size: 0,
+            }),
+        };
+        self.emit_call(inst, stack_map);
+    }
+
+    /// Emit an unwind instruction.
+    pub fn emit_unwind_inst(&mut self, inst: UnwindInst) {
+        self.emit(Inst::Unwind { inst })
+    }
+
     // Helpers for ALU operations.
 
     fn emit_alu_rri(&mut self, op: ALUOp, imm: Imm12, rn: Reg, rd: Reg, size: OperandSize) {
@@ -212,6 +1184,32 @@ impl Assembler {
         });
     }
 
+    fn emit_alu_rri_logic(&mut self, op: ALUOp, imm: u64, rn: Reg, rd: Reg, size: OperandSize) {
+        if let Some(imml) = ImmLogic::maybe_from_u64(imm, size.into()) {
+            self.emit(Inst::AluRRImmLogic {
+                alu_op: op,
+                size: size.into(),
+                rd: Writable::from_reg(rd.into()),
+                rn: rn.into(),
+                imml,
+            });
+        } else {
+            let scratch = regs::scratch();
+            self.load_constant(imm, scratch);
+            self.emit_alu_rrr(op, scratch, rn, rd, size);
+        }
+    }
+
+    fn emit_alu_rrr(&mut self, op: ALUOp, rm: Reg, rn: Reg, rd: Reg, size: OperandSize) {
+        self.emit(Inst::AluRRR {
+            alu_op: op,
+            size: size.into(),
+            rd: Writable::from_reg(rd.into()),
+            rn: rn.into(),
+            rm: rm.into(),
+        });
+    }
+
     fn emit_alu_rrr_extend(&mut self, op: ALUOp, rm: Reg, rn: Reg, rd: Reg, size: OperandSize) {
         self.emit(Inst::AluRRRExtend {
             alu_op: op,
@@ -234,6 +1232,36 @@ impl Assembler {
         });
     }
 
+    fn emit_bit_rr(&mut self, op: BitOp, rn: Reg, rd: Reg, size: OperandSize) {
+        self.emit(Inst::BitRR {
+            op,
+            size: size.into(),
+            rd: Writable::from_reg(rd.into()),
+            rn: rn.into(),
+        });
+    }
+
+    // Helpers for floating point operations.
+
+    fn emit_fpu_rrr(&mut self, op: FPUOp2, rm: Reg, rn: Reg, rd: Reg, size: OperandSize) {
+        self.emit(Inst::FpuRRR {
+            fpu_op: op,
+            size: size.into(),
+            rd: Writable::from_reg(rd.into()),
+            rn: rn.into(),
+            rm: rm.into(),
+        });
+    }
+
+    fn emit_fpu_rr(&mut self, op: FPUOp1, rn: Reg, rd: Reg, size: OperandSize) {
+        self.emit(Inst::FpuRR {
+            fpu_op: op,
+            size: size.into(),
+            rd: Writable::from_reg(rd.into()),
+            rn: rn.into(),
+        });
+    }
+
     /// Get a label from the underlying machine code buffer.
     pub fn get_label(&mut self) -> MachLabel {
         self.buffer.get_label()
@@ -1,16 +1,21 @@
 use super::{abi::Aarch64ABI, address::Address, asm::Assembler, regs};
 use crate::{
-    abi::{self, local::LocalSlot},
+    abi::{self, align_to, calculate_frame_adjustment, local::LocalSlot, ABI},
     codegen::{ptr_type_from_ptr_size, CodeGenContext, HeapData, TableData},
-    isa::reg::Reg,
+    isa::reg::{Reg, RegClass},
     masm::{
         CalleeKind, DivKind, ExtendKind, FloatCmpKind, Imm as I, IntCmpKind,
-        MacroAssembler as Masm, OperandSize, RegImm, RemKind, RoundingMode, SPOffset, ShiftKind,
-        StackSlot, TrapCode,
+        MacroAssembler as Masm, OperandSize, RegImm, RemKind, RmwOp, RoundingMode, SPOffset,
+        ShiftKind, StackSlot, TrapCode, V128AddKind, V128ConvertKind, V128ExtAddKind,
+        V128ExtendKind, V128MinMaxKind, V128MulKind, V128NarrowKind, V128SubKind, V128TruncSatKind,
+        VectorShape,
     },
+    stack::{TypedReg, Val},
 };
-use cranelift_codegen::{settings, Final, MachBufferFinalized, MachLabel};
-use wasmtime_environ::PtrSize;
+use cranelift_codegen::{
+    ir::MemFlags, isa::unwind::UnwindInst, settings, Final, MachBufferFinalized, MachLabel,
+};
+use wasmtime_environ::{PtrSize, WasmType, WASM_PAGE_SIZE};
 
 /// Aarch64 MacroAssembler.
 pub(crate) struct MacroAssembler {
@@ -20,15 +25,18 @@ pub(crate) struct MacroAssembler {
     sp_offset: u32,
     /// The target pointer size.
     ptr_size: OperandSize,
+    /// Shared flags.
+    shared_flags: settings::Flags,
 }
 
 impl MacroAssembler {
     /// Create an Aarch64 MacroAssembler.
     pub fn new(ptr_size: impl PtrSize, shared_flags: settings::Flags) -> Self {
         Self {
-            asm: Assembler::new(shared_flags),
+            asm: Assembler::new(shared_flags.clone()),
             sp_offset: 0u32,
             ptr_size: ptr_type_from_ptr_size(ptr_size.size()).into(),
+            shared_flags,
         }
     }
 }
@@ -45,16 +53,56 @@ impl Masm for MacroAssembler {
         let addr = Address::pre_indexed_from_sp(-16);
 
         self.asm.stp(fp, lr, addr);
+
+        if self.shared_flags.unwind_info() {
+            self.asm.emit_unwind_inst(UnwindInst::PushFrameRegs {
+                offset_upward_to_caller_sp: Self::ABI::arg_base_offset().into(),
+            })
+        }
+
         self.asm.mov_rr(sp, fp, OperandSize::S64);
+
+        if self.shared_flags.unwind_info() {
+            self.asm.emit_unwind_inst(UnwindInst::DefineNewFrame {
+                offset_upward_to_caller_sp: Self::ABI::arg_base_offset().into(),
+                // Clobbers appear directly after the FP and LR if they're
+                // present.
+                offset_downward_to_clobbers: 0,
+            })
+        }
+
+        // The shadow stack pointer is callee-saved, save it right below the
+        // frame pointer, keeping the real stack pointer 16-byte aligned.
+        let addr = Address::pre_indexed_from_sp(-16);
+        self.asm.str(
+            regs::shadow_sp(),
+            addr,
+            OperandSize::S64,
+            MemFlags::trusted(),
+        );
         self.move_sp_to_shadow_sp();
     }
 
     fn check_stack(&mut self) {
-        // TODO: implement when we have more complete assembler support
+        let ptr_size: u8 = self.ptr_size.bytes().try_into().unwrap();
+        let scratch = regs::scratch();
+
+        self.load_ptr(
+            self.address_at_vmctx(ptr_size.vmcontext_runtime_limits().into()),
+            scratch,
+        );
+
+        self.load_ptr(
+            Address::offset(scratch, ptr_size.vmruntime_limits_stack_limit().into()),
+            scratch,
+        );
+
+        self.asm.cmp_rr(scratch, regs::sp(), self.ptr_size);
+        self.asm.trapif(IntCmpKind::LtU, TrapCode::StackOverflow);
     }
 
-    fn epilogue(&mut self, locals_size: u32) {
-        assert!(self.sp_offset == locals_size);
+    fn epilogue(&mut self, locals_size: u32, stack_args_size: u32) {
+        assert_eq!(self.sp_offset, locals_size);
 
         let sp = regs::sp();
         if locals_size > 0 {
@@ -63,11 +111,35 @@ impl Masm for MacroAssembler {
             self.move_sp_to_shadow_sp();
         }
 
+        // Restore the shadow stack pointer.
+        let addr = Address::post_indexed_from_sp(16);
+        self.asm.ldr(
+            addr,
+            regs::shadow_sp(),
+            OperandSize::S64,
+            MemFlags::trusted(),
+        );
+
         let lr = regs::lr();
         let fp = regs::fp();
         let addr = Address::post_indexed_from_sp(16);
 
         self.asm.ldp(fp, lr, addr);
+
+        // Pop the stack arguments, keeping the shadow stack pointer in sync
+        // with the caller's view of the stack.
+        if stack_args_size > 0 {
+            let sp = regs::sp();
+            let shadow_sp = regs::shadow_sp();
+            self.asm
+                .add_ir(stack_args_size as u64, sp, sp, OperandSize::S64);
+            self.asm.add_ir(
+                stack_args_size as u64,
+                shadow_sp,
+                shadow_sp,
+                OperandSize::S64,
+            );
+        }
         self.asm.ret();
     }
 
@@ -83,8 +155,16 @@ impl Masm for MacroAssembler {
         self.increment_sp(bytes);
     }
 
-    fn free_stack(&mut self, _bytes: u32) {
-        todo!()
+    fn free_stack(&mut self, bytes: u32) {
+        if bytes == 0 {
+            return;
+        }
+
+        let sp = regs::sp();
+        self.asm.add_ir(bytes as u64, sp, sp, OperandSize::S64);
+        self.move_sp_to_shadow_sp();
+
+        self.decrement_sp(bytes);
     }
 
     fn reset_stack_pointer(&mut self, offset: SPOffset) {
@@ -108,32 +188,130 @@ impl Masm for MacroAssembler {
 
     fn table_elem_address(
         &mut self,
-        _index: Reg,
-        _base: Reg,
-        _table_data: &TableData,
-        _context: &mut CodeGenContext,
+        index: Reg,
+        ptr_base: Reg,
+        table_data: &TableData,
+        context: &mut CodeGenContext,
     ) -> Self::Address {
-        todo!()
+        let vmctx = <Self::ABI as ABI>::vmctx_reg();
+        let scratch = regs::scratch();
+        let bound = context.any_gpr(self);
+        let tmp = context.any_gpr(self);
+
+        if let Some(offset) = table_data.import_from {
+            // If the table data declares a particular offset base,
+            // load the address into a register to further use it as
+            // the table address.
+            self.load_ptr(self.address_at_vmctx(offset), ptr_base);
+        } else {
+            // Else, simply move the vmctx register into the addr register as
+            // the base to calculate the table address.
+            self.asm.mov_rr(vmctx, ptr_base, self.ptr_size);
+        };
+
+        // OOB check.
+        let bound_addr = self.address_at_reg(ptr_base, table_data.current_elems_offset);
+        let bound_size = table_data.current_elements_size;
+        self.asm
+            .ldr(bound_addr, bound, bound_size, MemFlags::trusted());
+        self.asm.cmp_rr(bound, index, bound_size);
+        self.asm.trapif(IntCmpKind::GeU, TrapCode::TableOutOfBounds);
+
+        // Move the index into the scratch register to calculate the table
+        // element address.
+        // Moving the value of the index register to the scratch register
+        // also avoids overwriting the context of the index register.
+        self.asm.mov_rr(index, scratch, bound_size);
+        self.asm
+            .load_constant(table_data.element_size.bytes() as u64, tmp);
+        self.asm
+            .mul_rrr(tmp, scratch, scratch, table_data.element_size);
+        self.load_ptr(self.address_at_reg(ptr_base, table_data.offset), ptr_base);
+        // Copy the value of the table base into a temporary register
+        // so that we can use it later in case of a misspeculation.
+        self.asm.mov_rr(ptr_base, tmp, self.ptr_size);
+        // Calculate the address of the table element.
+        self.asm.add_rrr(scratch, ptr_base, ptr_base, self.ptr_size);
+        if self.shared_flags.enable_table_access_spectre_mitigation() {
+            // Perform a bounds check and override the value of the
+            // table element address in case the index is out of bounds.
+            self.asm.cmp_rr(bound, index, OperandSize::S32);
+            self.asm.csel(tmp, ptr_base, ptr_base, IntCmpKind::GeU);
+        }
+        context.free_reg(bound);
+        context.free_reg(tmp);
+        self.address_at_reg(ptr_base, 0)
     }
 
-    fn table_size(&mut self, _table_data: &TableData, _context: &mut CodeGenContext) {
-        todo!()
+    fn table_size(&mut self, table_data: &TableData, context: &mut CodeGenContext) {
+        let vmctx = <Self::ABI as ABI>::vmctx_reg();
+        let scratch = regs::scratch();
+        let size = context.any_gpr(self);
+
+        if let Some(offset) = table_data.import_from {
+            self.load_ptr(self.address_at_vmctx(offset), scratch);
+        } else {
+            self.asm.mov_rr(vmctx, scratch, self.ptr_size);
+        };
+
+        let size_addr = Address::offset(scratch, table_data.current_elems_offset as i64);
+        self.asm.ldr(
+            size_addr,
+            size,
+            table_data.current_elements_size,
+            MemFlags::trusted(),
+        );
+
+        context.stack.push(TypedReg::i32(size).into());
     }
 
-    fn memory_size(&mut self, _heap_data: &HeapData, _context: &mut CodeGenContext) {
-        todo!()
+    fn memory_size(&mut self, heap_data: &HeapData, context: &mut CodeGenContext) {
+        let size_reg = context.any_gpr(self);
+        let scratch = regs::scratch();
+        let vmctx = <Self::ABI as ABI>::vmctx_reg();
+
+        let base = if let Some(offset) = heap_data.import_from {
+            self.load_ptr(self.address_at_vmctx(offset), scratch);
+            scratch
+        } else {
+            vmctx
+        };
+
+        let size_addr = Address::offset(base, heap_data.current_length_offset as i64);
+        self.load_ptr(size_addr, size_reg);
+        // Prepare the stack to emit a shift to get the size in pages rather
+        // than in bytes.
+        context
+            .stack
+            .push(TypedReg::new(heap_data.ty, size_reg).into());
+
+        // Since the page size is a power-of-two, verify that 2^16, equals the
+        // defined constant. This is mostly a safeguard in case the constant
+        // value ever changes.
+        let pow = 16;
+        debug_assert_eq!(2u32.pow(pow), WASM_PAGE_SIZE);
+
+        // Ensure that the constant is correctly typed according to the heap
+        // type to reduce register pressure when emitting the shift operation.
+        match heap_data.ty {
+            WasmType::I32 => context.stack.push(Val::i32(pow as i32)),
+            WasmType::I64 => context.stack.push(Val::i64(pow as i64)),
+            _ => unreachable!(),
+        }
+
+        self.shift(context, ShiftKind::ShrU, heap_data.ty.into());
     }
 
-    fn address_from_sp(&self, _offset: SPOffset) -> Self::Address {
-        todo!()
+    fn address_from_sp(&self, offset: SPOffset) -> Self::Address {
+        Address::from_shadow_sp((self.sp_offset - offset.as_u32()) as i64)
     }
 
-    fn address_at_sp(&self, _offset: SPOffset) -> Self::Address {
-        todo!()
+    fn address_at_sp(&self, offset: SPOffset) -> Self::Address {
+        Address::from_shadow_sp(offset.as_u32() as i64)
     }
 
-    fn address_at_vmctx(&self, _offset: u32) -> Self::Address {
-        todo!()
+    fn address_at_vmctx(&self, offset: u32) -> Self::Address {
+        Address::offset(<Self::ABI as ABI>::vmctx_reg(), offset as i64)
     }
 
     fn store_ptr(&mut self, src: Reg, dst: Self::Address) {
@@ -141,45 +319,277 @@ impl Masm for MacroAssembler {
     }
 
     fn store(&mut self, src: RegImm, dst: Address, size: OperandSize) {
-        let src = match src {
-            RegImm::Imm(v) => {
-                let imm = match v {
-                    I::I32(v) => v as u64,
-                    I::I64(v) => v,
-                    _ => unreachable!(),
-                };
-                let scratch = regs::scratch();
-                self.asm.load_constant(imm, scratch);
-                scratch
+        self.store_impl(src, dst, size, MemFlags::trusted());
+    }
+
+    fn wasm_store(&mut self, src: Reg, dst: Self::Address, size: OperandSize) {
+        self.store_impl(src.into(), dst, size, MemFlags::new());
+    }
+
+    fn wasm_atomic_load(&mut self, src: Self::Address, dst: Reg, size: OperandSize) {
+        // Load-acquire and store-release are sequentially consistent with
+        // respect to each other.
+        let src = self.address_to_reg(src);
+        self.asm.ldar(src, dst, size, MemFlags::new());
+    }
+
+    fn wasm_atomic_store(&mut self, src: Reg, dst: Self::Address, size: OperandSize) {
+        let dst = self.address_to_reg(dst);
+        self.asm.stlr(src, dst, size, MemFlags::new());
+    }
+
+    fn atomic_rmw(&mut self, context: &mut CodeGenContext, size: OperandSize, op: RmwOp) {
+        let operand = context.pop_to_reg(self, None);
+        let addr = context.pop_to_reg(self, None);
+        let dst = context.any_gpr(self);
+        let tmp = regs::scratch();
+        let status = regs::ip1();
+        let alu_size = if size == OperandSize::S64 {
+            OperandSize::S64
+        } else {
+            OperandSize::S32
+        };
+
+        // Emit a load-exclusive/store-exclusive loop:
+        //
+        // again:
+        //   ldaxr   dst, [addr]
+        //   <op>    tmp, dst, operand
+        //   stlxr   status, tmp, [addr]
+        //   cbnz    status, again
+        //
+        // The exclusive load zero extends the old value and the exclusive
+        // store only writes the low `size` bits of the new one.
+        let again = self.get_label();
+        self.bind(again);
+        self.asm.ldaxr(addr.reg, dst, size, MemFlags::new());
+        let new = match op {
+            RmwOp::Xchg => operand.reg,
+            RmwOp::Add => {
+                self.asm.add_rrr(operand.reg, dst, tmp, alu_size);
+                tmp
+            }
+            RmwOp::Sub => {
+                self.asm.sub_rrr(operand.reg, dst, tmp, alu_size);
+                tmp
+            }
+            RmwOp::And => {
+                self.asm.and_rrr(operand.reg, dst, tmp, alu_size);
+                tmp
+            }
+            RmwOp::Or => {
+                self.asm.or_rrr(operand.reg, dst, tmp, alu_size);
+                tmp
+            }
+            RmwOp::Xor => {
+                self.asm.xor_rrr(operand.reg, dst, tmp, alu_size);
+                tmp
             }
-            RegImm::Reg(reg) => reg,
+        };
+        self.asm.stlxr(new, addr.reg, status, size, MemFlags::new());
+        self.asm.jmp_if_not_zero(status, again);
+
+        context.free_reg(addr);
+        context.free_reg(operand);
+        context.stack.push(TypedReg::new(operand.ty, dst).into());
+    }
+
+    fn atomic_cas(&mut self, context: &mut CodeGenContext, size: OperandSize) {
+        let replacement = context.pop_to_reg(self, None);
+        let expected = context.pop_to_reg(self, None);
+        let addr = context.pop_to_reg(self, None);
+        let dst = context.any_gpr(self);
+        let status = regs::ip1();
+
+        // The old value is zero extended by the exclusive load, so only
+        // compare it against the low `size` bits of the expected value.
+        let cmp_size = match size {
+            OperandSize::S8 => {
+                self.asm
+                    .and_ir(0xff, expected.reg, expected.reg, OperandSize::S32);
+                OperandSize::S32
+            }
+            OperandSize::S16 => {
+                self.asm
+                    .and_ir(0xffff, expected.reg, expected.reg, OperandSize::S32);
+                OperandSize::S32
+            }
+            OperandSize::S32 => OperandSize::S32,
+            OperandSize::S64 => OperandSize::S64,
+            OperandSize::S128 => unreachable!(),
         };
 
-        self.asm.str(src, dst, size);
+        // Emit a load-exclusive/store-exclusive loop:
+        //
+        // again:
+        //   ldaxr   dst, [addr]
+        //   cmp     dst, expected
+        //   b.ne    done
+        //   stlxr   status, replacement, [addr]
+        //   cbnz    status, again
+        // done:
+        let again = self.get_label();
+        let done = self.get_label();
+        self.bind(again);
+        self.asm.ldaxr(addr.reg, dst, size, MemFlags::new());
+        self.asm.cmp_rr(expected.reg, dst, cmp_size);
+        self.asm.jmp_if(IntCmpKind::Ne, done);
+        self.asm
+            .stlxr(replacement.reg, addr.reg, status, size, MemFlags::new());
+        self.asm.jmp_if_not_zero(status, again);
+        self.bind(done);
+
+        context.free_reg(addr);
+        context.free_reg(expected);
+        context.free_reg(replacement);
+        context.stack.push(TypedReg::new(expected.ty, dst).into());
+    }
+
+    fn fence(&mut self) {
+        self.asm.dmb_ish();
     }
 
     fn call(
         &mut self,
-        _stack_args_size: u32,
-        _load_callee: impl FnMut(&mut Self) -> CalleeKind,
+        stack_args_size: u32,
+        ref_slots: &[SPOffset],
+        mut load_callee: impl FnMut(&mut Self) -> CalleeKind,
     ) -> u32 {
-        todo!()
+        let alignment: u32 = <Self::ABI as abi::ABI>::call_stack_align().into();
+        let addend: u32 = <Self::ABI as abi::ABI>::arg_base_offset().into();
+        let delta = calculate_frame_adjustment(self.sp_offset().as_u32(), addend, alignment);
+        let aligned_args_size = align_to(stack_args_size, alignment);
+        let total_stack = delta + aligned_args_size;
+        self.reserve_stack(total_stack);
+        // The saved shadow stack pointer sits between the frame pointer and
+        // the space tracked by the stack pointer offset.
+        let stack_map = abi::stack_map(
+            self.sp_offset(),
+            ref_slots,
+            <Self::ABI as abi::ABI>::word_bytes(),
+            16,
+        );
+        let callee = load_callee(self);
+        match callee {
+            CalleeKind::Indirect(reg) => self.asm.call_with_reg(reg, stack_map),
+            CalleeKind::Direct(idx) => self.asm.call_with_index(idx, stack_map),
+            CalleeKind::Known(lib) => self.asm.call_with_lib(lib, stack_map),
+        };
+        total_stack
+    }
+
+    fn return_call(
+        &mut self,
+        stack_args_size: u32,
+        caller_stack_args_size: u32,
+        mut load_callee: impl FnMut(&mut Self) -> CalleeKind,
+    ) {
+        self.reserve_stack(stack_args_size);
+        let callee = load_callee(self);
+
+        let fp = regs::fp();
+        let shadow_sp = regs::shadow_sp();
+        // The scratch register might be needed to materialize large offsets,
+        // so use the second scratch register as a temporary.
+        let tmp = regs::ip1();
+        let load = |masm: &mut Self, addr, dst| {
+            masm.asm
+                .ldr(addr, dst, OperandSize::S64, MemFlags::trusted())
+        };
+        let store = |masm: &mut Self, src, addr| {
+            masm.asm
+                .str(src, addr, OperandSize::S64, MemFlags::trusted())
+        };
+
+        // The new stack arguments might overlap with the saved frame pointer,
+        // link register and shadow stack pointer, so save them first; the
+        // frame pointer and shadow stack pointer are saved right below the
+        // new stack arguments.
+        self.reserve_stack(16);
+        load(self, Address::offset(fp, 0), tmp);
+        store(self, tmp, Address::offset(shadow_sp, 0));
+        load(self, Address::offset(fp, -16), tmp);
+        store(self, tmp, Address::offset(shadow_sp, 8));
+        load(self, Address::offset(fp, 8), regs::lr());
+
+        // Move the new stack arguments so that they end where the caller's
+        // stack arguments end. The destination is always at a higher address
+        // than the source, so the copy starts from the highest word.
+        let base = 16 + caller_stack_args_size as i64 - stack_args_size as i64;
+        for offset in (0..stack_args_size).step_by(8).rev() {
+            load(self, Address::offset(shadow_sp, 16 + offset as i64), tmp);
+            store(self, tmp, Address::offset(fp, base + offset as i64));
+        }
+
+        // Compute the callee's stack pointer and restore the caller's frame
+        // pointer and shadow stack pointer. The shadow stack pointer is
+        // adjusted so that it's in sync with the stack pointer once the
+        // callee pops its stack arguments.
+        if base >= 0 {
+            self.asm.add_ir(base as u64, fp, tmp, OperandSize::S64);
+        } else {
+            self.asm.sub_ir(-base as u64, fp, tmp, OperandSize::S64);
+        }
+        load(self, Address::offset(shadow_sp, 0), fp);
+        load(self, Address::offset(shadow_sp, 8), shadow_sp);
+        let diff = base - 16;
+        if diff > 0 {
+            self.asm
+                .add_ir(diff as u64, shadow_sp, shadow_sp, OperandSize::S64);
+        } else if diff < 0 {
+            self.asm
+                .sub_ir(-diff as u64, shadow_sp, shadow_sp, OperandSize::S64);
+        }
+        // A move to the stack pointer is encoded as an addition, since `mov`
+        // interprets the stack pointer as the zero register.
+        self.asm.add_ir(0, tmp, regs::sp(), OperandSize::S64);
+
+        match callee {
+            CalleeKind::Indirect(reg) => self.asm.jmp_reg(reg),
+            CalleeKind::Direct(idx) => self.asm.jmp_with_index(idx),
+            CalleeKind::Known(_) => unreachable!("tail calls to libcalls are not supported"),
+        }
     }
 
     fn load(&mut self, src: Address, dst: Reg, size: OperandSize) {
-        self.asm.ldr(src, dst, size);
+        self.asm.ldr(src, dst, size, MemFlags::trusted());
     }
 
-    fn load_ptr(&mut self, _src: Self::Address, _dst: Reg) {
-        todo!()
+    fn wasm_load(
+        &mut self,
+        src: Self::Address,
+        dst: Reg,
+        size: OperandSize,
+        kind: Option<ExtendKind>,
+    ) {
+        if let Some(ext) = kind {
+            assert!(dst.is_int());
+            self.asm.sldr(src, dst, ext, MemFlags::new());
+        } else {
+            self.asm.ldr(src, dst, size, MemFlags::new());
+        }
     }
 
-    fn load_addr(&mut self, _src: Self::Address, _dst: Reg, _size: OperandSize) {
-        todo!()
+    fn load_ptr(&mut self, src: Self::Address, dst: Reg) {
+        self.load(src, dst, self.ptr_size);
     }
 
-    fn pop(&mut self, _dst: Reg, _size: OperandSize) {
-        todo!()
+    fn load_addr(&mut self, src: Self::Address, dst: Reg, size: OperandSize) {
+        match src {
+            Address::Offset { base, offset } if offset >= 0 => {
+                self.asm.add_ir(offset as u64, base, dst, size)
+            }
+            Address::Offset { base, offset } => {
+                self.asm.sub_ir(offset.unsigned_abs(), base, dst, size)
+            }
+            Address::IndexedSPOffset { .. } => unreachable!(),
+        }
+    }
+
+    fn pop(&mut self, dst: Reg, size: OperandSize) {
+        let addr = self.address_from_sp(SPOffset::from_u32(self.sp_offset));
+        self.asm.ldr(addr, dst, size, MemFlags::trusted());
+        self.free_stack(Self::stack_slot_size(size));
     }
 
     fn sp_offset(&self) -> SPOffset {
@@ -192,25 +602,49 @@ impl Masm for MacroAssembler {
 
     fn mov(&mut self, src: RegImm, dst: Reg, size: OperandSize) {
         match (src, dst) {
+            rr @ (RegImm::Reg(rs), rd) => match (rs.class(), rd.class()) {
+                (RegClass::Int, RegClass::Int) => self.asm.mov_rr(rs, rd, size),
+                (RegClass::Float, RegClass::Float) => self.asm.fmov_rr(rs, rd, size),
+                _ => Self::handle_invalid_operand_combination(rr.0, rr.1),
+            },
             (RegImm::Imm(v), rd) => {
-                let imm = match v {
-                    I::I32(v) => v as u64,
-                    I::I64(v) => v,
-                    _ => panic!(),
-                };
-
                 let scratch = regs::scratch();
-                self.asm.load_constant(imm, scratch);
-                self.asm.mov_rr(scratch, rd, size);
-            }
-            (RegImm::Reg(rs), rd) => {
-                self.asm.mov_rr(rs, rd, size);
+                match v {
+                    I::I32(v) => {
+                        self.asm.load_constant(v as u64, scratch);
+                        self.asm.mov_rr(scratch, rd, size);
+                    }
+                    I::I64(v) => {
+                        self.asm.load_constant(v, scratch);
+                        self.asm.mov_rr(scratch, rd, size);
+                    }
+                    I::F32(v) => {
+                        self.asm.load_constant(v as u64, scratch);
+                        self.asm.mov_to_fpu(scratch, rd, size);
+                    }
+                    I::F64(v) => {
+                        self.asm.load_constant(v, scratch);
+                        self.asm.mov_to_fpu(scratch, rd, size);
+                    }
+                    I::V128(v) => {
+                        // Fill the low half with `fmov`, which zeroes the
+                        // high half, and then insert the high half.
+                        self.asm.load_constant(v as u64, scratch);
+                        self.asm.mov_to_fpu(scratch, rd, OperandSize::S64);
+                        self.asm.load_constant((v >> 64) as u64, scratch);
+                        self.asm.mov_to_vec(scratch, rd, 1, OperandSize::S64);
+                    }
+                }
             }
         }
     }
 
-    fn cmov(&mut self, _src: Reg, _dst: Reg, _cc: IntCmpKind, _size: OperandSize) {
-        todo!()
+    fn cmov(&mut self, src: Reg, dst: Reg, cc: IntCmpKind, size: OperandSize) {
+        match (src.class(), dst.class()) {
+            (RegClass::Int, RegClass::Int) => self.asm.csel(src, dst, dst, cc),
+            (RegClass::Float, RegClass::Float) => self.asm.fpu_csel(src, dst, dst, cc, size),
+            _ => Self::handle_invalid_operand_combination(src, dst),
+        }
     }
 
     fn add(&mut self, dst: Reg, lhs: Reg, rhs: RegImm, size: OperandSize) {
@@ -231,6 +665,26 @@ impl Masm for MacroAssembler {
         }
     }
 
+    fn checked_uadd(&mut self, dst: Reg, lhs: Reg, rhs: RegImm, size: OperandSize, trap: TrapCode) {
+        match (rhs, lhs, dst) {
+            (RegImm::Imm(v), rn, rd) => {
+                let imm = match v {
+                    I::I32(v) => v as u64,
+                    I::I64(v) => v,
+                    _ => unreachable!(),
+                };
+
+                self.asm.adds_ir(imm, rn, rd, size);
+            }
+
+            (RegImm::Reg(rm), rn, rd) => {
+                self.asm.adds_rrr(rm, rn, rd, size);
+            }
+        }
+        // The carry flag is set on unsigned overflow.
+        self.asm.trapif(IntCmpKind::GeU, trap);
+    }
+
     fn sub(&mut self, dst: Reg, lhs: Reg, rhs: RegImm, size: OperandSize) {
         match (rhs, lhs, dst) {
             (RegImm::Imm(v), rn, rd) => {
@@ -267,154 +721,458 @@ impl Masm for MacroAssembler {
         }
     }
 
-    fn float_add(&mut self, _dst: Reg, _lhs: Reg, _rhs: Reg, _size: OperandSize) {
-        todo!()
+    fn float_add(&mut self, dst: Reg, lhs: Reg, rhs: Reg, size: OperandSize) {
+        self.asm.fadd_rrr(rhs, lhs, dst, size);
     }
 
-    fn float_sub(&mut self, _dst: Reg, _lhs: Reg, _rhs: Reg, _size: OperandSize) {
-        todo!()
+    fn float_sub(&mut self, dst: Reg, lhs: Reg, rhs: Reg, size: OperandSize) {
+        self.asm.fsub_rrr(rhs, lhs, dst, size);
     }
 
-    fn float_mul(&mut self, _dst: Reg, _lhs: Reg, _rhs: Reg, _size: OperandSize) {
-        todo!()
+    fn float_mul(&mut self, dst: Reg, lhs: Reg, rhs: Reg, size: OperandSize) {
+        self.asm.fmul_rrr(rhs, lhs, dst, size);
     }
 
-    fn float_div(&mut self, _dst: Reg, _lhs: Reg, _rhs: Reg, _size: OperandSize) {
-        todo!()
+    fn float_div(&mut self, dst: Reg, lhs: Reg, rhs: Reg, size: OperandSize) {
+        self.asm.fdiv_rrr(rhs, lhs, dst, size);
     }
 
-    fn float_min(&mut self, _dst: Reg, _lhs: Reg, _rhs: Reg, _size: OperandSize) {
-        todo!()
+    fn float_min(&mut self, dst: Reg, lhs: Reg, rhs: Reg, size: OperandSize) {
+        self.asm.fmin_rrr(rhs, lhs, dst, size);
     }
 
-    fn float_max(&mut self, _dst: Reg, _lhs: Reg, _rhs: Reg, _size: OperandSize) {
-        todo!()
+    fn float_max(&mut self, dst: Reg, lhs: Reg, rhs: Reg, size: OperandSize) {
+        self.asm.fmax_rrr(rhs, lhs, dst, size);
     }
 
-    fn float_copysign(&mut self, _dst: Reg, _lhs: Reg, _rhs: Reg, _size: OperandSize) {
-        todo!()
+    fn float_copysign(&mut self, dst: Reg, lhs: Reg, rhs: Reg, size: OperandSize) {
+        let max_shift = match size {
+            OperandSize::S32 => 0x1f,
+            OperandSize::S64 => 0x3f,
+            _ => unreachable!(),
+        };
+        let float_scratch = regs::float_scratch();
+
+        // Move the sign bit of the right hand side into the least
+        // significant bit of the scratch register.
+        self.asm.fushr_ri(max_shift, rhs, float_scratch, size);
+        if dst != lhs {
+            self.asm.fmov_rr(lhs, dst, size);
+        }
+        // Insert the sign bit into the destination, leaving every other bit
+        // untouched.
+        self.asm.fsli_ri(max_shift, float_scratch, dst, size);
     }
 
-    fn float_neg(&mut self, _dst: Reg, _size: OperandSize) {
-        todo!()
+    fn float_neg(&mut self, dst: Reg, size: OperandSize) {
+        self.asm.fneg_rr(dst, dst, size);
     }
 
-    fn float_abs(&mut self, _dst: Reg, _size: OperandSize) {
-        todo!()
+    fn float_abs(&mut self, dst: Reg, size: OperandSize) {
+        self.asm.fabs_rr(dst, dst, size);
     }
 
-    fn float_round(
-        &mut self,
-        _mode: RoundingMode,
-        _context: &mut CodeGenContext,
-        _size: OperandSize,
-    ) {
-        todo!();
+    fn float_round(&mut self, mode: RoundingMode, context: &mut CodeGenContext, size: OperandSize) {
+        let src = context.pop_to_reg(self, None);
+        self.asm.fround_rr(src.into(), src.into(), mode, size);
+        context.stack.push(src.into());
     }
 
-    fn float_sqrt(&mut self, _dst: Reg, _src: Reg, _size: OperandSize) {
-        todo!()
+    fn float_sqrt(&mut self, dst: Reg, src: Reg, size: OperandSize) {
+        self.asm.fsqrt_rr(src, dst, size);
     }
 
-    fn and(&mut self, _dst: Reg, _lhs: Reg, _rhs: RegImm, _size: OperandSize) {
-        todo!()
+    fn and(&mut self, dst: Reg, lhs: Reg, rhs: RegImm, size: OperandSize) {
+        match (rhs, lhs, dst) {
+            (RegImm::Imm(v), rn, rd) => {
+                let imm = match v {
+                    I::I32(v) => v as u64,
+                    I::I64(v) => v,
+                    _ => unreachable!(),
+                };
+
+                self.asm.and_ir(imm, rn, rd, size);
+            }
+
+            (RegImm::Reg(rm), rn, rd) => {
+                self.asm.and_rrr(rm, rn, rd, size);
+            }
+        }
     }
 
-    fn or(&mut self, _dst: Reg, _lhs: Reg, _rhs: RegImm, _size: OperandSize) {
-        todo!()
+    fn or(&mut self, dst: Reg, lhs: Reg, rhs: RegImm, size: OperandSize) {
+        match (rhs, lhs, dst) {
+            (RegImm::Imm(v), rn, rd) => {
+                let imm = match v {
+                    I::I32(v) => v as u64,
+                    I::I64(v) => v,
+                    _ => unreachable!(),
+                };
+
+                self.asm.or_ir(imm, rn, rd, size);
+            }
+
+            (RegImm::Reg(rm), rn, rd) => {
+                self.asm.or_rrr(rm, rn, rd, size);
+            }
+        }
     }
 
-    fn xor(&mut self, _dst: Reg, _lhs: Reg, _rhs: RegImm, _size: OperandSize) {
-        todo!()
+    fn xor(&mut self, dst: Reg, lhs: Reg, rhs: RegImm, size: OperandSize) {
+        match (rhs, lhs, dst) {
+            (RegImm::Imm(v), rn, rd) => {
+                let imm = match v {
+                    I::I32(v) => v as u64,
+                    I::I64(v) => v,
+                    _ => unreachable!(),
+                };
+
+                self.asm.xor_ir(imm, rn, rd, size);
+            }
+
+            (RegImm::Reg(rm), rn, rd) => {
+                self.asm.xor_rrr(rm, rn, rd, size);
+            }
+        }
     }
 
-    fn shift(&mut self, _context: &mut CodeGenContext, _kind: ShiftKind, _size: OperandSize) {
-        todo!()
+    fn shift(&mut self, context: &mut CodeGenContext, kind: ShiftKind, size: OperandSize) {
+        let top = context.stack.peek().expect("value at stack top");
+
+        if size == OperandSize::S32 && top.is_i32_const() {
+            let val = context
+                .stack
+                .pop_i32_const()
+                .expect("i32 const value at stack top");
+            let typed_reg = context.pop_to_reg(self, None);
+
+            self.asm
+                .shift_ir(val as u64, typed_reg.into(), typed_reg.into(), kind, size);
+
+            context.stack.push(typed_reg.into());
+        } else if size == OperandSize::S64 && top.is_i64_const() {
+            let val = context
+                .stack
+                .pop_i64_const()
+                .expect("i64 const value at stack top");
+            let typed_reg = context.pop_to_reg(self, None);
+
+            self.asm
+                .shift_ir(val as u64, typed_reg.into(), typed_reg.into(), kind, size);
+
+            context.stack.push(typed_reg.into());
+        } else {
+            // Unlike x64, the shift amount can be in any register; the
+            // variable shift instructions take the amount modulo the operand
+            // size.
+            let src = context.pop_to_reg(self, None);
+            let dst = context.pop_to_reg(self, None);
+
+            self.asm
+                .shift_rrr(src.into(), dst.into(), dst.into(), kind, size);
+
+            context.free_reg(src);
+            context.stack.push(dst.into());
+        }
     }
 
-    fn div(&mut self, _context: &mut CodeGenContext, _kind: DivKind, _size: OperandSize) {
-        todo!()
+    fn div(&mut self, context: &mut CodeGenContext, kind: DivKind, size: OperandSize) {
+        // Allocate the divisor, which can be any gpr.
+        let divisor = context.pop_to_reg(self, None);
+        // Allocate the dividend, which will also hold the quotient.
+        let dividend = context.pop_to_reg(self, None);
+
+        self.asm
+            .div_rrr(divisor.into(), dividend.into(), dividend.into(), kind, size);
+
+        context.free_reg(divisor);
+        context.stack.push(dividend.into());
     }
 
-    fn rem(&mut self, _context: &mut CodeGenContext, _kind: RemKind, _size: OperandSize) {
-        todo!()
+    fn rem(&mut self, context: &mut CodeGenContext, kind: RemKind, size: OperandSize) {
+        // Allocate the divisor, which can be any gpr.
+        let divisor = context.pop_to_reg(self, None);
+        // Allocate the dividend, which will also hold the remainder.
+        let dividend = context.pop_to_reg(self, None);
+
+        self.asm
+            .rem_rrr(divisor.into(), dividend.into(), dividend.into(), kind, size);
+
+        context.free_reg(divisor);
+        context.stack.push(dividend.into());
     }
 
     fn zero(&mut self, reg: Reg) {
         self.asm.load_constant(0, reg);
     }
 
-    fn popcnt(&mut self, _context: &mut CodeGenContext, _size: OperandSize) {
-        todo!()
+    fn popcnt(&mut self, context: &mut CodeGenContext, size: OperandSize) {
+        let src = context.pop_to_reg(self, None);
+        let tmp = regs::float_scratch();
+        self.asm.mov_to_fpu(src.into(), tmp, size);
+        self.asm.cnt(tmp, tmp);
+        self.asm.addv(tmp, tmp);
+        self.asm.mov_from_vec(tmp, src.into(), 0, OperandSize::S8);
+        context.stack.push(src.into());
     }
 
     fn signed_truncate(
         &mut self,
-        _src: Reg,
-        _dst: Reg,
-        _src_size: OperandSize,
-        _dst_size: OperandSize,
+        src: Reg,
+        dst: Reg,
+        src_size: OperandSize,
+        dst_size: OperandSize,
     ) {
-        todo!()
+        self.asm
+            .cvt_float_to_int_seq(src, dst, true, src_size, dst_size);
     }
 
     fn unsigned_truncate(
         &mut self,
-        _src: Reg,
-        _dst: Reg,
+        src: Reg,
+        dst: Reg,
         _tmp_fpr: Reg,
-        _src_size: OperandSize,
-        _dst_size: OperandSize,
+        src_size: OperandSize,
+        dst_size: OperandSize,
+    ) {
+        self.asm
+            .cvt_float_to_int_seq(src, dst, false, src_size, dst_size);
+    }
+
+    fn signed_convert(&mut self, src: Reg, dst: Reg, src_size: OperandSize, dst_size: OperandSize) {
+        self.asm
+            .cvt_int_to_float(src, dst, true, src_size, dst_size);
+    }
+
+    fn unsigned_convert(
+        &mut self,
+        src: Reg,
+        dst: Reg,
+        _tmp_gpr: Reg,
+        src_size: OperandSize,
+        dst_size: OperandSize,
     ) {
-        todo!()
+        self.asm
+            .cvt_int_to_float(src, dst, false, src_size, dst_size);
+    }
+
+    fn reinterpret_float_as_int(&mut self, src: Reg, dst: Reg, size: OperandSize) {
+        self.asm.mov_from_vec(src, dst, 0, size);
+    }
+
+    fn reinterpret_int_as_float(&mut self, src: Reg, dst: Reg, size: OperandSize) {
+        self.asm.mov_to_fpu(src, dst, size);
     }
 
-    fn signed_convert(
+    fn demote(&mut self, src: Reg, dst: Reg) {
+        self.asm
+            .cvt_float_to_float(src, dst, OperandSize::S64, OperandSize::S32);
+    }
+
+    fn promote(&mut self, src: Reg, dst: Reg) {
+        self.asm
+            .cvt_float_to_float(src, dst, OperandSize::S32, OperandSize::S64);
+    }
+
+    fn splat(&mut self, _src: Reg, _dst: Reg, _shape: VectorShape) {
+        unreachable!("SIMD is rejected by ensure_no_simd")
+    }
+
+    fn extract_lane(
         &mut self,
         _src: Reg,
         _dst: Reg,
-        _src_size: OperandSize,
-        _dst_size: OperandSize,
+        _lane: u8,
+        _shape: VectorShape,
+        _kind: Option<ExtendKind>,
     ) {
-        todo!()
+        unreachable!("SIMD is rejected by ensure_no_simd")
     }
 
-    fn unsigned_convert(
+    fn replace_lane(&mut self, _src: Reg, _dst: Reg, _lane: u8, _shape: VectorShape) {
+        unreachable!("SIMD is rejected by ensure_no_simd")
+    }
+
+    fn shuffle(&mut self, _dst: Reg, _lhs: Reg, _rhs: Reg, _lanes: [u8; 16]) {
+        unreachable!("SIMD is rejected by ensure_no_simd")
+    }
+
+    fn swizzle(&mut self, _dst: Reg, _lhs: Reg, _rhs: Reg) {
+        unreachable!("SIMD is rejected by ensure_no_simd")
+    }
+
+    fn v128_add(&mut self, _dst: Reg, _lhs: Reg, _rhs: Reg, _kind: V128AddKind) {
+        unreachable!("SIMD is rejected by ensure_no_simd")
+    }
+
+    fn v128_sub(&mut self, _dst: Reg, _lhs: Reg, _rhs: Reg, _kind: V128SubKind) {
+        unreachable!("SIMD is rejected by ensure_no_simd")
+    }
+
+    fn v128_mul(&mut self, _context: &mut CodeGenContext, _kind: V128MulKind) {
+        unreachable!("SIMD is rejected by ensure_no_simd")
+    }
+
+    fn v128_div(&mut self, _dst: Reg, _lhs: Reg, _rhs: Reg, _size: OperandSize) {
+        unreachable!("SIMD is rejected by ensure_no_simd")
+    }
+
+    fn v128_min(&mut self, _dst: Reg, _lhs: Reg, _rhs: Reg, _kind: V128MinMaxKind) {
+        unreachable!("SIMD is rejected by ensure_no_simd")
+    }
+
+    fn v128_max(&mut self, _dst: Reg, _lhs: Reg, _rhs: Reg, _kind: V128MinMaxKind) {
+        unreachable!("SIMD is rejected by ensure_no_simd")
+    }
+
+    fn v128_pmin(&mut self, _dst: Reg, _lhs: Reg, _rhs: Reg, _size: OperandSize) {
+        unreachable!("SIMD is rejected by ensure_no_simd")
+    }
+
+    fn v128_pmax(&mut self, _dst: Reg, _lhs: Reg, _rhs: Reg, _size: OperandSize) {
+        unreachable!("SIMD is rejected by ensure_no_simd")
+    }
+
+    fn v128_avgr_u(&mut self, _dst: Reg, _lhs: Reg, _rhs: Reg, _lane_size: OperandSize) {
+        unreachable!("SIMD is rejected by ensure_no_simd")
+    }
+
+    fn v128_q15mulr_sat_s(&mut self, _dst: Reg, _lhs: Reg, _rhs: Reg) {
+        unreachable!("SIMD is rejected by ensure_no_simd")
+    }
+
+    fn v128_dot(&mut self, _dst: Reg, _lhs: Reg, _rhs: Reg) {
+        unreachable!("SIMD is rejected by ensure_no_simd")
+    }
+
+    fn v128_extadd_pairwise(&mut self, _src: Reg, _dst: Reg, _kind: V128ExtAddKind) {
+        unreachable!("SIMD is rejected by ensure_no_simd")
+    }
+
+    fn v128_abs(&mut self, _dst: Reg, _shape: VectorShape) {
+        unreachable!("SIMD is rejected by ensure_no_simd")
+    }
+
+    fn v128_neg(&mut self, _dst: Reg, _shape: VectorShape) {
+        unreachable!("SIMD is rejected by ensure_no_simd")
+    }
+
+    fn v128_popcnt(&mut self, _src: Reg, _dst: Reg) {
+        unreachable!("SIMD is rejected by ensure_no_simd")
+    }
+
+    fn v128_sqrt(&mut self, _dst: Reg, _src: Reg, _size: OperandSize) {
+        unreachable!("SIMD is rejected by ensure_no_simd")
+    }
+
+    fn v128_round(&mut self, _dst: Reg, _src: Reg, _mode: RoundingMode, _size: OperandSize) {
+        unreachable!("SIMD is rejected by ensure_no_simd")
+    }
+
+    fn v128_shift(
+        &mut self,
+        _context: &mut CodeGenContext,
+        _lane_size: OperandSize,
+        _kind: ShiftKind,
+    ) {
+        unreachable!("SIMD is rejected by ensure_no_simd")
+    }
+
+    fn v128_int_cmp(
         &mut self,
-        _src: Reg,
         _dst: Reg,
-        _tmp_gpr: Reg,
-        _src_size: OperandSize,
-        _dst_size: OperandSize,
+        _lhs: Reg,
+        _rhs: Reg,
+        _kind: IntCmpKind,
+        _lane_size: OperandSize,
     ) {
-        todo!()
+        unreachable!("SIMD is rejected by ensure_no_simd")
     }
 
-    fn reinterpret_float_as_int(&mut self, _src: Reg, _dst: Reg, _size: OperandSize) {
-        todo!()
+    fn v128_float_cmp(
+        &mut self,
+        _dst: Reg,
+        _lhs: Reg,
+        _rhs: Reg,
+        _kind: FloatCmpKind,
+        _size: OperandSize,
+    ) {
+        unreachable!("SIMD is rejected by ensure_no_simd")
     }
 
-    fn reinterpret_int_as_float(&mut self, _src: Reg, _dst: Reg, _size: OperandSize) {
-        todo!()
+    fn v128_not(&mut self, _dst: Reg) {
+        unreachable!("SIMD is rejected by ensure_no_simd")
     }
 
-    fn demote(&mut self, _src: Reg, _dst: Reg) {
-        todo!()
+    fn v128_and(&mut self, _dst: Reg, _lhs: Reg, _rhs: Reg) {
+        unreachable!("SIMD is rejected by ensure_no_simd")
     }
 
-    fn promote(&mut self, _src: Reg, _dst: Reg) {
-        todo!()
+    fn v128_and_not(&mut self, _dst: Reg, _lhs: Reg, _rhs: Reg) {
+        unreachable!("SIMD is rejected by ensure_no_simd")
     }
 
-    fn push(&mut self, reg: Reg, _size: OperandSize) -> StackSlot {
-        let size = <Self::ABI as abi::ABI>::word_bytes();
-        self.reserve_stack(size);
-        let address = Address::from_shadow_sp(size as i64);
-        self.asm.str(reg, address, OperandSize::S64);
+    fn v128_or(&mut self, _dst: Reg, _lhs: Reg, _rhs: Reg) {
+        unreachable!("SIMD is rejected by ensure_no_simd")
+    }
+
+    fn v128_xor(&mut self, _dst: Reg, _lhs: Reg, _rhs: Reg) {
+        unreachable!("SIMD is rejected by ensure_no_simd")
+    }
+
+    fn v128_bitselect(&mut self, _dst: Reg, _lhs: Reg, _rhs: Reg, _mask: Reg) {
+        unreachable!("SIMD is rejected by ensure_no_simd")
+    }
+
+    fn v128_any_true(&mut self, _src: Reg, _dst: Reg) {
+        unreachable!("SIMD is rejected by ensure_no_simd")
+    }
+
+    fn v128_all_true(&mut self, _src: Reg, _dst: Reg, _lane_size: OperandSize) {
+        unreachable!("SIMD is rejected by ensure_no_simd")
+    }
+
+    fn v128_bitmask(&mut self, _src: Reg, _dst: Reg, _lane_size: OperandSize) {
+        unreachable!("SIMD is rejected by ensure_no_simd")
+    }
+
+    fn v128_extend(&mut self, _src: Reg, _dst: Reg, _kind: V128ExtendKind) {
+        unreachable!("SIMD is rejected by ensure_no_simd")
+    }
+
+    fn v128_narrow(&mut self, _dst: Reg, _lhs: Reg, _rhs: Reg, _kind: V128NarrowKind) {
+        unreachable!("SIMD is rejected by ensure_no_simd")
+    }
+
+    fn v128_convert(&mut self, _src: Reg, _dst: Reg, _kind: V128ConvertKind) {
+        unreachable!("SIMD is rejected by ensure_no_simd")
+    }
+
+    fn v128_trunc_sat(&mut self, _src: Reg, _dst: Reg, _kind: V128TruncSatKind) {
+        unreachable!("SIMD is rejected by ensure_no_simd")
+    }
+
+    fn v128_demote(&mut self, _src: Reg, _dst: Reg) {
+        unreachable!("SIMD is rejected by ensure_no_simd")
+    }
+
+    fn v128_promote(&mut self, _src: Reg, _dst: Reg) {
+        unreachable!("SIMD is rejected by ensure_no_simd")
+    }
+
+    fn push(&mut self, reg: Reg, size: OperandSize) -> StackSlot {
+        let bytes = match (reg.class(), size) {
+            (RegClass::Int, OperandSize::S64) => <Self::ABI as ABI>::word_bytes(),
+            (RegClass::Int, OperandSize::S32) | (RegClass::Float, _) => Self::stack_slot_size(size),
+            _ => unreachable!(),
+        };
+        self.reserve_stack(bytes);
+        let address = self.address_from_sp(SPOffset::from_u32(self.sp_offset));
+        self.asm.str(reg, address, size, MemFlags::trusted());
 
         StackSlot {
             offset: SPOffset::from_u32(self.sp_offset),
-            size,
+            size: bytes,
         }
     }
 
@@ -422,39 +1180,60 @@ impl Masm for MacroAssembler {
         Address::offset(reg, offset as i64)
     }
 
-    fn cmp_with_set(&mut self, _src: RegImm, _dst: Reg, _kind: IntCmpKind, _size: OperandSize) {
-        todo!()
+    fn cmp_with_set(&mut self, src: RegImm, dst: Reg, kind: IntCmpKind, size: OperandSize) {
+        self.cmp(src, dst, size);
+        self.asm.cset(dst, kind);
     }
 
-    fn cmp(&mut self, _src: RegImm, _dest: Reg, _size: OperandSize) {
-        todo!()
+    fn cmp(&mut self, src: RegImm, dst: Reg, size: OperandSize) {
+        match src {
+            RegImm::Imm(v) => {
+                let imm = match v {
+                    I::I32(v) => v as u64,
+                    I::I64(v) => v,
+                    _ => unreachable!(),
+                };
+
+                self.asm.cmp_ir(imm, dst, size);
+            }
+            RegImm::Reg(src) => {
+                self.asm.cmp_rr(src, dst, size);
+            }
+        }
     }
 
     fn float_cmp_with_set(
         &mut self,
-        _src1: Reg,
-        _src2: Reg,
-        _dst: Reg,
-        _kind: FloatCmpKind,
-        _size: OperandSize,
+        src1: Reg,
+        src2: Reg,
+        dst: Reg,
+        kind: FloatCmpKind,
+        size: OperandSize,
     ) {
-        todo!()
+        // The left hand side of the comparison is in `src2`; the
+        // conditions derived from the comparison kind evaluate to false for
+        // unordered operands, except for `Ne`, which evaluates to true.
+        self.asm.fcmp(src2, src1, size);
+        self.asm.cset(dst, kind);
     }
 
-    fn clz(&mut self, _src: Reg, _dst: Reg, _size: OperandSize) {
-        todo!()
+    fn clz(&mut self, src: Reg, dst: Reg, size: OperandSize) {
+        self.asm.clz(src, dst, size);
     }
 
-    fn ctz(&mut self, _src: Reg, _dst: Reg, _size: OperandSize) {
-        todo!()
+    fn ctz(&mut self, src: Reg, dst: Reg, size: OperandSize) {
+        // Aarch64 doesn't have a count trailing zeros instruction; reversing
+        // the bits and counting the leading zeros is equivalent.
+        self.asm.rbit(src, dst, size);
+        self.asm.clz(dst, dst, size);
     }
 
-    fn wrap(&mut self, _src: Reg, _dst: Reg) {
-        todo!()
+    fn wrap(&mut self, src: Reg, dst: Reg) {
+        self.asm.mov_rr(src, dst, OperandSize::S32);
     }
 
-    fn extend(&mut self, _src: Reg, _dst: Reg, _kind: ExtendKind) {
-        todo!()
+    fn extend(&mut self, src: Reg, dst: Reg, kind: ExtendKind) {
+        self.asm.extend(src, dst, kind);
     }
 
     fn get_label(&mut self) -> MachLabel {
@@ -468,33 +1247,64 @@ impl Masm for MacroAssembler {
 
     fn branch(
         &mut self,
-        _kind: IntCmpKind,
-        _lhs: RegImm,
-        _rhs: Reg,
-        _taken: MachLabel,
-        _size: OperandSize,
+        kind: IntCmpKind,
+        lhs: RegImm,
+        rhs: Reg,
+        taken: MachLabel,
+        size: OperandSize,
     ) {
-        todo!()
+        use IntCmpKind::*;
+
+        match &(lhs, rhs) {
+            (RegImm::Reg(rlhs), rrhs) => {
+                // If the comparision kind is zero or not zero and both operands
+                // are the same register, emit a test instruction. Else we emit
+                // a normal comparison.
+                if (kind == Eq || kind == Ne) && (rlhs == rrhs) {
+                    self.asm.tst_rr(*rrhs, *rlhs, size);
+                } else {
+                    self.cmp(lhs, rhs, size);
+                }
+            }
+            _ => self.cmp(lhs, rhs, size),
+        }
+        self.asm.jmp_if(kind, taken);
     }
 
-    fn jmp(&mut self, _target: MachLabel) {
-        todo!()
+    fn jmp(&mut self, target: MachLabel) {
+        self.asm.jmp(target);
     }
 
     fn unreachable(&mut self) {
-        todo!()
+        self.asm.udf(TrapCode::UnreachableCodeReached);
     }
 
-    fn jmp_table(&mut self, _targets: &[MachLabel], _index: Reg, _tmp: Reg) {
-        todo!()
+    fn jmp_table(&mut self, targets: &[MachLabel], index: Reg, tmp: Reg) {
+        // At least one default target.
+        assert!(targets.len() >= 1);
+        let default_index = targets.len() - 1;
+        // Emit the bounds check; the jump table sequence branches to the
+        // default target if the index is greater than or equal to the number
+        // of non-default targets.
+        self.asm
+            .cmp_ir(default_index as u64, index, OperandSize::S32);
+
+        let default = targets[default_index];
+        let rest = &targets[0..default_index];
+        let tmp1 = regs::scratch();
+        self.asm.jmp_table(rest, default, index, tmp1, tmp);
     }
 
-    fn trapz(&mut self, _src: Reg, _code: TrapCode) {
-        todo!()
+    fn trap(&mut self, code: TrapCode) {
+        self.asm.udf(code);
     }
 
-    fn trapif(&mut self, _cc: IntCmpKind, _code: TrapCode) {
-        todo!()
+    fn trapz(&mut self, src: Reg, code: TrapCode) {
+        self.asm.trapz(src, code);
+    }
+
+    fn trapif(&mut self, cc: IntCmpKind, code: TrapCode) {
+        self.asm.trapif(cc, code);
     }
 }
 
@@ -503,6 +1313,16 @@ impl MacroAssembler {
         self.sp_offset += bytes;
     }
 
+    fn decrement_sp(&mut self, bytes: u32) {
+        assert!(
+            self.sp_offset >= bytes,
+            "sp offset = {}; bytes = {}",
+            self.sp_offset,
+            bytes
+        );
+        self.sp_offset -= bytes;
+    }
+
     // Copies the value of the stack pointer to the shadow stack
     // pointer: mov x28, sp
 
@@ -511,9 +1331,70 @@ impl MacroAssembler {
     // space, or after performing a push or pop.
     // For more details around the stack pointer and shadow stack
     // pointer see the docs at regs::shadow_sp().
+    /// Returns the size of the stack slot used to spill a value of the
+    /// given size. Spilled values use at least a full word, which keeps all
+    /// the slots in the value stack word-aligned, as required by stack maps.
+    fn stack_slot_size(size: OperandSize) -> u32 {
+        size.bytes().max(<Aarch64ABI as ABI>::word_bytes())
+    }
+
+    /// Returns a register holding the given address, for instructions which
+    /// only support addressing through a base register.
+    fn address_to_reg(&mut self, addr: Address) -> Reg {
+        match addr {
+            Address::Offset { base, offset: 0 } => base,
+            addr => {
+                let scratch = regs::scratch();
+                self.load_addr(addr, scratch, OperandSize::S64);
+                scratch
+            }
+        }
+    }
+
     fn move_sp_to_shadow_sp(&mut self) {
         let sp = regs::sp();
         let shadow_sp = regs::shadow_sp();
         self.asm.mov_rr(sp, shadow_sp, OperandSize::S64);
     }
+
+    fn store_impl(&mut self, src: RegImm, dst: Address, size: OperandSize, flags: MemFlags) {
+        let src = match src {
+            RegImm::Imm(v @ I::V128(_)) => {
+                let scratch = regs::float_scratch();
+                self.mov(RegImm::Imm(v), scratch, OperandSize::S128);
+                scratch
+            }
+            RegImm::Imm(v) => {
+                let imm = match v {
+                    I::I32(v) => v as u64,
+                    I::I64(v) => v,
+                    I::F32(v) => v as u64,
+                    I::F64(v) => v,
+                    I::V128(_) => unreachable!(),
+                };
+                let scratch = regs::scratch();
+                self.asm.load_constant(imm, scratch);
+                scratch
+            }
+            // The stack pointer shares its encoding with the zero register
+            // when used as the source of a store, so copy it to a scratch
+            // register first.
+            RegImm::Reg(reg) if reg == regs::sp() => {
+                let scratch = regs::ip1();
+                self.asm.mov_rr(reg, scratch, OperandSize::S64);
+                scratch
+            }
+            RegImm::Reg(reg) => reg,
+        };
+
+        self.asm.str(src, dst, size, flags);
+    }
+
+    fn handle_invalid_operand_combination<T>(src: impl Into<RegImm>, dst: impl Into<RegImm>) -> T {
+        panic!(
+            "Invalid operand combination; src={:?}, dst={:?}",
+            src.into(),
+            dst.into()
+        );
+    }
 }
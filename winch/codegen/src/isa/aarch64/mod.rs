@@ -1,23 +1,26 @@
-use self::regs::{ALL_GPR, MAX_FPR, MAX_GPR, NON_ALLOCATABLE_GPR};
+use self::regs::{ALL_FPR, ALL_GPR, MAX_FPR, MAX_GPR, NON_ALLOCATABLE_FPR, NON_ALLOCATABLE_GPR};
 use crate::{
-    abi::ABI,
+    abi::wasm_sig,
     codegen::{CodeGen, CodeGenContext, FuncEnv},
     frame::{DefinedLocals, Frame},
-    isa::{Builder, CallingConvention, TargetIsa},
+    isa::{Builder, TargetIsa},
     masm::MacroAssembler,
     regalloc::RegAlloc,
     regset::RegBitSet,
     stack::Stack,
-    BuiltinFunctions, TrampolineKind,
+    trampoline::{Trampoline, TrampolineKind},
+    BuiltinFunctions,
 };
-use anyhow::Result;
+use anyhow::{bail, Result};
 use cranelift_codegen::settings::{self, Flags};
 use cranelift_codegen::{isa::aarch64::settings as aarch64_settings, Final, MachBufferFinalized};
 use cranelift_codegen::{MachTextSectionBuilder, TextSectionBuilder};
 use masm::MacroAssembler as Aarch64Masm;
 use target_lexicon::Triple;
-use wasmparser::{FuncValidator, FunctionBody, ValidatorResources};
-use wasmtime_environ::{ModuleTranslation, ModuleTypesBuilder, VMOffsets, WasmFuncType};
+use wasmparser::{BlockType, FuncValidator, FunctionBody, Operator, ValType, ValidatorResources};
+use wasmtime_environ::{
+    ModuleTranslation, ModuleType, ModuleTypesBuilder, Tunables, VMOffsets, WasmFuncType, WasmType,
+};
 
 mod abi;
 mod address;
@@ -39,8 +42,6 @@ pub(crate) fn isa_builder(triple: Triple) -> Builder {
 }
 
 /// Aarch64 ISA.
-// Until Aarch64 emission is supported.
-#[allow(dead_code)]
 pub(crate) struct Aarch64 {
     /// The target triple.
     triple: Triple,
@@ -88,17 +89,19 @@ impl TargetIsa for Aarch64 {
         body: &FunctionBody,
         translation: &ModuleTranslation,
         types: &ModuleTypesBuilder,
+        tunables: &Tunables,
         builtins: &mut BuiltinFunctions,
         validator: &mut FuncValidator<ValidatorResources>,
     ) -> Result<MachBufferFinalized<Final>> {
+        ensure_no_simd(sig, Some((body, translation, types)))?;
         let pointer_bytes = self.pointer_bytes();
         let vmoffsets = VMOffsets::new(pointer_bytes, &translation.module);
         let mut body = body.get_binary_reader();
         let mut masm = Aarch64Masm::new(pointer_bytes, self.shared_flags.clone());
         let stack = Stack::new();
-        let abi_sig = abi::Aarch64ABI::sig(sig, &CallingConvention::Default);
+        let abi_sig = wasm_sig::<abi::Aarch64ABI>(sig);
 
-        let env = FuncEnv::new(&vmoffsets, translation, types);
+        let env = FuncEnv::new(&vmoffsets, translation, types, tunables, self);
         let defined_locals = DefinedLocals::new::<abi::Aarch64ABI>(&env, &mut body, validator)?;
         let frame = Frame::new::<abi::Aarch64ABI>(&abi_sig, &defined_locals)?;
         let gpr = RegBitSet::int(
@@ -106,8 +109,11 @@ impl TargetIsa for Aarch64 {
             NON_ALLOCATABLE_GPR.into(),
             usize::try_from(MAX_GPR).unwrap(),
         );
-        // TODO: Add floating point bitmask
-        let fpr = RegBitSet::float(0, 0, usize::try_from(MAX_FPR).unwrap());
+        let fpr = RegBitSet::float(
+            ALL_FPR.into(),
+            NON_ALLOCATABLE_FPR.into(),
+            usize::try_from(MAX_FPR).unwrap(),
+        );
         let regalloc = RegAlloc::from(gpr, fpr);
         let codegen_context = CodeGenContext::new(regalloc, stack, frame, builtins, &vmoffsets);
         let mut codegen = CodeGen::new(&mut masm, codegen_context, env, abi_sig);
@@ -129,17 +135,116 @@ impl TargetIsa for Aarch64 {
 
     fn compile_trampoline(
         &self,
-        _ty: &WasmFuncType,
-        _kind: TrampolineKind,
+        ty: &WasmFuncType,
+        kind: TrampolineKind,
     ) -> Result<MachBufferFinalized<Final>> {
-        todo!()
+        use TrampolineKind::*;
+
+        ensure_no_simd(ty, None)?;
+        let mut masm = Aarch64Masm::new(self.pointer_bytes(), self.shared_flags.clone());
+        let call_conv = self.wasmtime_call_conv();
+
+        let mut trampoline = Trampoline::new(
+            &mut masm,
+            regs::scratch(),
+            regs::argv(),
+            &call_conv,
+            self.pointer_bytes(),
+        );
+
+        match kind {
+            ArrayToWasm(idx) => trampoline.emit_array_to_wasm(ty, idx)?,
+            NativeToWasm(idx) => trampoline.emit_native_to_wasm(ty, idx)?,
+            WasmToNative => trampoline.emit_wasm_to_native(ty)?,
+        }
+
+        Ok(masm.finalize())
     }
 
     fn emit_unwind_info(
         &self,
-        _result: &MachBufferFinalized<Final>,
-        _kind: cranelift_codegen::isa::unwind::UnwindInfoKind,
+        buffer: &MachBufferFinalized<Final>,
+        kind: cranelift_codegen::isa::unwind::UnwindInfoKind,
     ) -> Result<Option<cranelift_codegen::isa::unwind::UnwindInfo>> {
-        todo!()
+        Ok(cranelift_codegen::isa::aarch64::emit_unwind_info(
+            buffer, kind,
+        )?)
+    }
+
+    fn create_systemv_cie(&self) -> Option<gimli::write::CommonInformationEntry> {
+        Some(cranelift_codegen::isa::aarch64::create_cie())
+    }
+}
+
+/// Returns an error if the given signature or function uses the SIMD
+/// proposals, which aren't supported by Winch on aarch64 yet.
+///
+/// Functions without SIMD instructions may still see `v128` values through
+/// globals, calls, blocks and `select`, so those are rejected too. Modules
+/// declaring `v128` globals or function types are rejected entirely.
+fn ensure_no_simd(
+    sig: &WasmFuncType,
+    func: Option<(&FunctionBody, &ModuleTranslation, &ModuleTypesBuilder)>,
+) -> Result<()> {
+    let unsupported = || bail!("SIMD is not supported by Winch on aarch64 yet");
+    let uses_v128 = |sig: &WasmFuncType| {
+        sig.params()
+            .iter()
+            .chain(sig.returns())
+            .any(|ty| *ty == WasmType::V128)
+    };
+
+    if uses_v128(sig) {
+        return unsupported();
+    }
+    let Some((body, translation, types)) = func else {
+        return Ok(());
+    };
+    let module = &translation.module;
+    if module.globals.values().any(|g| g.wasm_ty == WasmType::V128)
+        || module.types.values().any(|ty| match ty {
+            ModuleType::Function(sig) => uses_v128(&types[*sig]),
+            // Winch doesn't support the instructions of the GC proposal, so
+            // the fields of `struct` and `array` types are never accessed.
+            ModuleType::Struct(_) | ModuleType::Array(_) => false,
+        })
+    {
+        return unsupported();
+    }
+    for local in body.get_locals_reader()? {
+        if local?.1 == ValType::V128 {
+            return unsupported();
+        }
+    }
+    let mut ops = body.get_operators_reader()?;
+    while !ops.eof() {
+        let uses_v128 = match ops.read()? {
+            Operator::Block { blockty } | Operator::Loop { blockty } | Operator::If { blockty } => {
+                blockty == BlockType::Type(ValType::V128)
+            }
+            Operator::TypedSelect { ty } => ty == ValType::V128,
+            op => is_simd(&op),
+        };
+        if uses_v128 {
+            return unsupported();
+        }
     }
+    Ok(())
 }
+
+macro_rules! define_is_simd {
+    ($( @$proposal:ident $op:ident $({ $($arg:ident: $argty:ty),* })? => $visit:ident)*) => {
+        /// Returns whether `op` belongs to one of the SIMD proposals.
+        fn is_simd(op: &Operator) -> bool {
+            match op {
+                $(Operator::$op { .. } => define_is_simd!(proposal $proposal),)*
+            }
+        }
+    };
+
+    (proposal simd) => { true };
+    (proposal relaxed_simd) => { true };
+    (proposal $other:ident) => { false };
+}
+
+wasmparser::for_each_operator!(define_is_simd);
@@ -38,6 +38,23 @@ pub(crate) const fn ip1() -> Reg {
     xreg(17)
 }
 
+/// Floating point scratch register.
+pub(crate) const fn float_scratch() -> Reg {
+    vreg(31)
+}
+
+/// This register is used as a scratch register, in the context of trampolines only,
+/// where we assume that callee-saved registers are given the correct handling
+/// according to the system ABI. x19 is chosen given that it's a callee-saved,
+/// non-argument register.
+///
+/// In the context of all other internal functions, this register is not excluded
+/// from register allocation, so no extra assumptions should be made regarding
+/// its availability.
+pub(crate) const fn argv() -> Reg {
+    xreg(19)
+}
+
 /// Register used to carry platform state.
 const fn platform() -> Reg {
     xreg(18)
@@ -98,8 +115,10 @@ pub(crate) const fn sp() -> Reg {
 /// the real stack pointer and x28 will look like during a function:
 ///
 /// +-----------+
+/// |           |      Save FP and LR
+/// +-----------+----- SP at function entry (after prologue, slots for FP and LR)
 /// |           |      Save x28 (callee-saved)
-/// +-----------+----- SP at function entry (after epilogue, slots for FP and LR)
+/// +-----------+----- SP after saving x28
 /// |           |      Copy the value of SP to x28
 /// |           |
 /// +-----------+----- SP after reserving stack space for locals and arguments
@@ -149,11 +168,18 @@ pub(crate) const NON_ALLOCATABLE_GPR: u32 = (1 << ip0().hw_enc())
 /// Bitmask to represent the available general purpose registers.
 pub(crate) const ALL_GPR: u32 = u32::MAX & !NON_ALLOCATABLE_GPR;
 
+/// Bitmask for non-allocatable FPR.
+pub(crate) const NON_ALLOCATABLE_FPR: u32 = 1 << float_scratch().hw_enc();
+
+/// Bitmask to represent the available floating point registers.
+pub(crate) const ALL_FPR: u32 = u32::MAX & !NON_ALLOCATABLE_FPR;
+
 /// Returns the callee-saved registers.
 ///
 /// This function will return the set of registers that need to be saved
 /// according to the system ABI and that are known not to be saved during the
-/// prologue emission.
+/// prologue emission; the shadow stack pointer (x28) is saved as part of the
+/// prologue.
 pub(crate) fn callee_saved() -> SmallVec<[(Reg, OperandSize); 18]> {
     use OperandSize::*;
     let regs: SmallVec<[_; 18]> = smallvec![
@@ -166,7 +192,6 @@ pub(crate) fn callee_saved() -> SmallVec<[(Reg, OperandSize); 18]> {
         xreg(25),
         xreg(26),
         xreg(27),
-        xreg(28),
         vreg(8),
         vreg(9),
         vreg(10),
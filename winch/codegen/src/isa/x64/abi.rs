@@ -5,7 +5,7 @@ use crate::{
     masm::OperandSize,
 };
 use smallvec::SmallVec;
-use wasmtime_environ::{WasmFuncType, WasmHeapType, WasmType};
+use wasmtime_environ::WasmType;
 
 /// Helper environment to track argument-register
 /// assignment in x64.
@@ -59,7 +59,8 @@ impl RegIndexEnv {
 pub(crate) struct X64ABI;
 
 impl ABI for X64ABI {
-    // TODO: change to 16 once SIMD is supported
+    // Vector values are always accessed through unaligned moves, so there's
+    // no need to bump the stack alignment to 16 to support v128.
     fn stack_align() -> u8 {
         8
     }
@@ -119,6 +120,7 @@ impl ABI for X64ABI {
             params,
             params_stack_offset,
             results.on_stack(),
+            call_conv,
             |ty, stack_offset| {
                 Self::to_abi_operand(
                     ty,
@@ -130,11 +132,7 @@ impl ABI for X64ABI {
             },
         );
 
-        ABISig::new(params, results)
-    }
-
-    fn sig(wasm_sig: &WasmFuncType, call_conv: &CallingConvention) -> ABISig {
-        Self::sig_from(wasm_sig.params(), wasm_sig.returns(), call_conv)
+        ABISig::new(*call_conv, params, results)
     }
 
     fn abi_results(returns: &[WasmType], call_conv: &CallingConvention) -> ABIResults {
@@ -184,13 +182,9 @@ impl ABI for X64ABI {
 
     fn sizeof(ty: &WasmType) -> u32 {
         match ty {
-            WasmType::Ref(rt) => match rt.heap_type {
-                WasmHeapType::Func => Self::word_bytes(),
-                ht => unimplemented!("Support for WasmHeapType: {ht}"),
-            },
-            WasmType::F64 | WasmType::I64 => Self::word_bytes(),
+            WasmType::Ref(_) | WasmType::F64 | WasmType::I64 => Self::word_bytes(),
             WasmType::F32 | WasmType::I32 => Self::word_bytes() / 2,
-            ty => unimplemented!("Support for WasmType: {ty}"),
+            WasmType::V128 => Self::word_bytes() * 2,
         }
     }
 }
@@ -204,47 +198,42 @@ impl X64ABI {
         params_or_returns: ParamsOrReturns,
     ) -> (ABIOperand, u32) {
         let (reg, ty) = match wasm_arg {
-            ty @ WasmType::Ref(rt) => match rt.heap_type {
-                WasmHeapType::Func => (
-                    Self::int_reg_for(index_env.next_gpr(), call_conv, params_or_returns),
-                    ty,
-                ),
-                ht => unimplemented!("Support for WasmHeapType: {ht}"),
-            },
-
-            ty @ (WasmType::I32 | WasmType::I64) => (
+            ty @ (WasmType::I32 | WasmType::I64 | WasmType::Ref(_)) => (
                 Self::int_reg_for(index_env.next_gpr(), call_conv, params_or_returns),
                 ty,
             ),
 
-            ty @ (WasmType::F32 | WasmType::F64) => (
+            ty @ (WasmType::F32 | WasmType::F64 | WasmType::V128) => (
                 Self::float_reg_for(index_env.next_fpr(), call_conv, params_or_returns),
                 ty,
             ),
-
-            ty => unimplemented!("Support for argument of WasmType: {ty}"),
         };
 
         let ty_size = <Self as ABI>::sizeof(wasm_arg);
         let default = || {
-            let arg = ABIOperand::stack_offset(stack_offset, *ty, ty_size);
-            let slot_size = Self::stack_slot_size();
             // Stack slots for parameters are aligned to a fixed slot size,
-            // in the case of x64, 8 bytes.
+            // in the case of x64, 8 bytes, or to the type size for types
+            // wider than the slot size (e.g. v128).
             // Stack slots for returns are type-size aligned.
-            let next_stack = if params_or_returns == ParamsOrReturns::Params {
-                align_to(stack_offset, slot_size) + slot_size
-            } else {
+            let (offset, size, next_stack) = if params_or_returns == ParamsOrReturns::Params {
+                let slot_size = Self::stack_slot_size().max(ty_size);
+                let offset = align_to(stack_offset, slot_size);
+                (offset, ty_size, offset + slot_size)
+            } else if call_conv.is_default() {
                 // For the default calling convention, we don't type-size align,
                 // given that results on the stack must match spills generated
-                // from within the compiler, which are not type-size aligned.
-                if call_conv.is_default() {
-                    stack_offset + ty_size
-                } else {
-                    align_to(stack_offset, ty_size) + ty_size
-                }
+                // from within the compiler, which are not type-size aligned,
+                // but use, at least, word-sized slots.
+                let slot_size = Self::stack_slot_size().max(ty_size);
+                (stack_offset, slot_size, stack_offset + slot_size)
+            } else {
+                (
+                    stack_offset,
+                    ty_size,
+                    align_to(stack_offset, ty_size) + ty_size,
+                )
             };
-            (arg, next_stack)
+            (ABIOperand::stack_offset(offset, *ty, size), next_stack)
         };
 
         reg.map_or_else(default, |reg| {
@@ -361,7 +350,11 @@ mod tests {
         let wasm_sig =
             WasmFuncType::new([I32, I64, I32, I64, I32, I32, I64, I32].into(), [].into());
 
-        let sig = X64ABI::sig(&wasm_sig, &CallingConvention::Default);
+        let sig = X64ABI::sig_from(
+            wasm_sig.params(),
+            wasm_sig.returns(),
+            &CallingConvention::Default,
+        );
         let params = sig.params;
 
         match_reg_arg(params.get(0).unwrap(), I32, regs::rdi());
@@ -381,7 +374,11 @@ mod tests {
             [I32, I32, I32].into(),
         );
 
-        let sig = X64ABI::sig(&wasm_sig, &CallingConvention::Default);
+        let sig = X64ABI::sig_from(
+            wasm_sig.params(),
+            wasm_sig.returns(),
+            &CallingConvention::Default,
+        );
         let params = sig.params;
         let results = sig.results;
 
@@ -394,7 +391,7 @@ mod tests {
         match_stack_arg(params.get(6).unwrap(), I64, 0);
         match_stack_arg(params.get(7).unwrap(), I32, 8);
 
-        match_stack_arg(results.get(0).unwrap(), I32, 4);
+        match_stack_arg(results.get(0).unwrap(), I32, 8);
         match_stack_arg(results.get(1).unwrap(), I32, 0);
         match_reg_arg(results.get(2).unwrap(), I32, regs::rax());
     }
@@ -406,7 +403,11 @@ mod tests {
             [].into(),
         );
 
-        let sig = X64ABI::sig(&wasm_sig, &CallingConvention::Default);
+        let sig = X64ABI::sig_from(
+            wasm_sig.params(),
+            wasm_sig.returns(),
+            &CallingConvention::Default,
+        );
         let params = sig.params;
 
         match_reg_arg(params.get(0).unwrap(), F32, regs::xmm0());
@@ -420,6 +421,35 @@ mod tests {
         match_stack_arg(params.get(8).unwrap(), F64, 0);
     }
 
+    #[test]
+    fn vector_abi_sig() {
+        let wasm_sig = WasmFuncType::new(
+            [
+                V128, I32, V128, V128, V128, V128, V128, V128, V128, F64, V128,
+            ]
+            .into(),
+            [V128].into(),
+        );
+
+        let sig = X64ABI::sig_from(
+            wasm_sig.params(),
+            wasm_sig.returns(),
+            &CallingConvention::Default,
+        );
+        let params = sig.params;
+        let results = sig.results;
+
+        match_reg_arg(params.get(0).unwrap(), V128, regs::xmm0());
+        match_reg_arg(params.get(1).unwrap(), I32, regs::rdi());
+        match_reg_arg(params.get(2).unwrap(), V128, regs::xmm1());
+        match_reg_arg(params.get(8).unwrap(), V128, regs::xmm7());
+        // Vector stack arguments are 16-byte aligned and take 16 bytes.
+        match_stack_arg(params.get(9).unwrap(), F64, 0);
+        match_stack_arg(params.get(10).unwrap(), V128, 16);
+
+        match_reg_arg(results.get(0).unwrap(), V128, regs::xmm0());
+    }
+
     #[test]
     fn mixed_abi_sig() {
         let wasm_sig = WasmFuncType::new(
@@ -427,7 +457,11 @@ mod tests {
             [].into(),
         );
 
-        let sig = X64ABI::sig(&wasm_sig, &CallingConvention::Default);
+        let sig = X64ABI::sig_from(
+            wasm_sig.params(),
+            wasm_sig.returns(),
+            &CallingConvention::Default,
+        );
         let params = sig.params;
 
         match_reg_arg(params.get(0).unwrap(), F32, regs::xmm0());
@@ -448,7 +482,11 @@ mod tests {
             [].into(),
         );
 
-        let sig = X64ABI::sig(&wasm_sig, &CallingConvention::SystemV);
+        let sig = X64ABI::sig_from(
+            wasm_sig.params(),
+            wasm_sig.returns(),
+            &CallingConvention::SystemV,
+        );
         let params = sig.params;
 
         match_reg_arg(params.get(0).unwrap(), F32, regs::xmm0());
@@ -469,7 +507,11 @@ mod tests {
             [].into(),
         );
 
-        let sig = X64ABI::sig(&wasm_sig, &CallingConvention::WindowsFastcall);
+        let sig = X64ABI::sig_from(
+            wasm_sig.params(),
+            wasm_sig.returns(),
+            &CallingConvention::WindowsFastcall,
+        );
         let params = sig.params;
 
         match_reg_arg(params.get(0).unwrap(), F32, regs::xmm0());
@@ -487,7 +529,11 @@ mod tests {
             [I32, F32, I32, F32, I64].into(),
         );
 
-        let sig = X64ABI::sig(&wasm_sig, &CallingConvention::WindowsFastcall);
+        let sig = X64ABI::sig_from(
+            wasm_sig.params(),
+            wasm_sig.returns(),
+            &CallingConvention::WindowsFastcall,
+        );
         let params = sig.params;
         let results = sig.results;
 
@@ -5,16 +5,19 @@ use crate::{
     masm::{DivKind, ExtendKind, IntCmpKind, OperandSize, RemKind, RoundingMode, ShiftKind},
 };
 use cranelift_codegen::{
+    binemit::{Reloc, StackMap},
     entity::EntityRef,
-    ir::{types, ConstantPool, ExternalName, LibCall, Opcode, TrapCode, UserExternalNameRef},
+    ir::{
+        types, ConstantPool, ExternalName, LibCall, MemFlags, Opcode, TrapCode, UserExternalNameRef,
+    },
     isa::{
         unwind::UnwindInst,
         x64::{
             args::{
-                self, AluRmiROpcode, Amode, CmpOpcode, DivSignedness, ExtMode, FromWritableReg,
-                Gpr, GprMem, GprMemImm, Imm8Gpr, Imm8Reg, RegMem, RegMemImm,
+                self, AluRmiROpcode, Amode, CmpOpcode, DivSignedness, ExtMode, FenceKind,
+                FromWritableReg, Gpr, GprMem, GprMemImm, Imm8Gpr, Imm8Reg, RegMem, RegMemImm,
                 ShiftKind as CraneliftShiftKind, SseOpcode, SyntheticAmode, WritableGpr,
-                WritableXmm, Xmm, XmmMem, XmmMemAligned, CC,
+                WritableXmm, Xmm, XmmMem, XmmMemAligned, XmmMemAlignedImm, CC,
             },
             settings as x64_settings, CallInfo, EmitInfo, EmitState, Inst,
         },
@@ -88,6 +91,8 @@ impl From<Reg> for Xmm {
 impl From<OperandSize> for args::OperandSize {
     fn from(size: OperandSize) -> Self {
         match size {
+            OperandSize::S8 => Self::Size8,
+            OperandSize::S16 => Self::Size16,
             OperandSize::S32 => Self::Size32,
             OperandSize::S64 => Self::Size64,
             s => panic!("Invalid operand size {:?}", s),
@@ -200,16 +205,28 @@ impl Assembler {
         inst.emit(&[], &mut self.buffer, &self.emit_info, &mut self.emit_state);
     }
 
+    /// Emit the given call instruction, associating the given stack map, if
+    /// any, to the call's return address.
+    fn emit_call(&mut self, inst: Inst, stack_map: Option<StackMap>) {
+        if let Some(stack_map) = stack_map {
+            self.emit_state.pre_safepoint(stack_map);
+        }
+        self.emit(inst);
+    }
+
     fn to_synthetic_amode(
         addr: &Address,
         pool: &mut ConstantPool,
         constants: &mut VCodeConstants,
         buffer: &mut MachBuffer<Inst>,
+        memflags: MemFlags,
     ) -> SyntheticAmode {
         match addr {
-            Address::Offset { base, offset } => {
-                SyntheticAmode::real(Amode::imm_reg(*offset as i32, (*base).into()))
-            }
+            Address::Offset { base, offset } => SyntheticAmode::real(Amode::ImmReg {
+                simm32: *offset as i32,
+                base: (*base).into(),
+                flags: memflags,
+            }),
             Address::Const(c) => {
                 // Defer the creation of the
                 // `SyntheticAmode::ConstantOffset` addressing mode
@@ -248,10 +265,8 @@ impl Assembler {
     }
 
     /// Return instruction.
-    pub fn ret(&mut self) {
-        self.emit(Inst::Ret {
-            stack_bytes_to_pop: 0,
-        });
+    pub fn ret(&mut self, stack_bytes_to_pop: u32) {
+        self.emit(Inst::Ret { stack_bytes_to_pop });
     }
 
     /// Register-to-register move.
@@ -264,10 +279,15 @@ impl Assembler {
     }
 
     /// Register-to-memory move.
-    pub fn mov_rm(&mut self, src: Reg, addr: &Address, size: OperandSize) {
+    pub fn mov_rm(&mut self, src: Reg, addr: &Address, size: OperandSize, flags: MemFlags) {
         assert!(addr.is_offset());
-        let dst =
-            Self::to_synthetic_amode(addr, &mut self.pool, &mut self.constants, &mut self.buffer);
+        let dst = Self::to_synthetic_amode(
+            addr,
+            &mut self.pool,
+            &mut self.constants,
+            &mut self.buffer,
+            flags,
+        );
         self.emit(Inst::MovRM {
             size: size.into(),
             src: src.into(),
@@ -276,10 +296,15 @@ impl Assembler {
     }
 
     /// Immediate-to-memory move.
-    pub fn mov_im(&mut self, src: i32, addr: &Address, size: OperandSize) {
+    pub fn mov_im(&mut self, src: i32, addr: &Address, size: OperandSize, flags: MemFlags) {
         assert!(addr.is_offset());
-        let dst =
-            Self::to_synthetic_amode(addr, &mut self.pool, &mut self.constants, &mut self.buffer);
+        let dst = Self::to_synthetic_amode(
+            addr,
+            &mut self.pool,
+            &mut self.constants,
+            &mut self.buffer,
+            flags,
+        );
         self.emit(Inst::MovImmM {
             size: size.into(),
             simm32: src,
@@ -299,12 +324,17 @@ impl Assembler {
         });
     }
 
-    /// Memory-to-register load.
-    pub fn mov_mr(&mut self, addr: &Address, dst: Reg, size: OperandSize) {
-        use OperandSize::S64;
+    /// Memory-to-register load, zero extending values narrower than 64 bits.
+    pub fn mov_mr(&mut self, addr: &Address, dst: Reg, size: OperandSize, flags: MemFlags) {
+        use OperandSize::*;
 
-        let src =
-            Self::to_synthetic_amode(addr, &mut self.pool, &mut self.constants, &mut self.buffer);
+        let src = Self::to_synthetic_amode(
+            addr,
+            &mut self.pool,
+            &mut self.constants,
+            &mut self.buffer,
+            flags,
+        );
 
         if size == S64 {
             self.emit(Inst::Mov64MR {
@@ -312,15 +342,39 @@ impl Assembler {
                 dst: dst.into(),
             });
         } else {
+            let ext_mode = match size {
+                S8 => ExtMode::BQ,
+                S16 => ExtMode::WQ,
+                S32 => ExtMode::LQ,
+                _ => unreachable!(),
+            };
             let reg_mem = RegMem::mem(src);
             self.emit(Inst::MovzxRmR {
-                ext_mode: ExtMode::LQ,
+                ext_mode,
                 src: GprMem::new(reg_mem).expect("valid memory address"),
                 dst: dst.into(),
             });
         }
     }
 
+    /// Memory-to-register load with sign extension.
+    pub fn movsx_mr(&mut self, addr: &Address, dst: Reg, kind: ExtendKind, flags: MemFlags) {
+        let src = Self::to_synthetic_amode(
+            addr,
+            &mut self.pool,
+            &mut self.constants,
+            &mut self.buffer,
+            flags,
+        );
+
+        let reg_mem = RegMem::mem(src);
+        self.emit(Inst::MovsxRmR {
+            ext_mode: kind.into(),
+            src: GprMem::new(reg_mem).expect("valid memory address"),
+            dst: dst.into(),
+        })
+    }
+
     /// Register-to-register move with zero extension.
     pub fn movzx_rr(&mut self, src: Reg, dst: Reg, kind: ExtendKind) {
         self.emit(Inst::MovzxRmR {
@@ -339,6 +393,50 @@ impl Assembler {
         });
     }
 
+    /// Atomic compare-and-exchange, `lock cmpxchg`. The value in
+    /// `expected` is compared to the value at `addr`; if equal, `replacement`
+    /// is stored at `addr`. In all cases, the value previously found at
+    /// `addr` is written to `dst`. Both `expected` and `dst` must be `rax`.
+    pub fn lock_cmpxchg(
+        &mut self,
+        addr: &Address,
+        replacement: Reg,
+        expected: Reg,
+        dst: Reg,
+        size: OperandSize,
+        flags: MemFlags,
+    ) {
+        let mem = Self::to_synthetic_amode(
+            addr,
+            &mut self.pool,
+            &mut self.constants,
+            &mut self.buffer,
+            flags,
+        );
+        let ty = match size {
+            OperandSize::S8 => types::I8,
+            OperandSize::S16 => types::I16,
+            OperandSize::S32 => types::I32,
+            OperandSize::S64 => types::I64,
+            OperandSize::S128 => unreachable!(),
+        };
+
+        self.emit(Inst::LockCmpxchg {
+            ty,
+            replacement: replacement.into(),
+            expected: expected.into(),
+            mem,
+            dst_old: Writable::from_reg(dst.into()),
+        });
+    }
+
+    /// Memory fence, `mfence`.
+    pub fn mfence(&mut self) {
+        self.emit(Inst::Fence {
+            kind: FenceKind::MFence,
+        });
+    }
+
     /// Integer register conditional move.
     pub fn cmov(&mut self, src: Reg, dst: Reg, cc: IntCmpKind, size: OperandSize) {
         self.emit(Inst::Cmove {
@@ -359,6 +457,7 @@ impl Assembler {
             S32 => SseOpcode::Movaps,
             S64 => SseOpcode::Movapd,
             S128 => SseOpcode::Movdqa,
+            S8 | S16 => unreachable!(),
         };
 
         self.emit(Inst::XmmUnaryRmRUnaligned {
@@ -369,7 +468,7 @@ impl Assembler {
     }
 
     /// Single and double precision floating point load.
-    pub fn xmm_mov_mr(&mut self, src: &Address, dst: Reg, size: OperandSize) {
+    pub fn xmm_mov_mr(&mut self, src: &Address, dst: Reg, size: OperandSize, flags: MemFlags) {
         use OperandSize::*;
 
         assert!(dst.is_float());
@@ -377,10 +476,16 @@ impl Assembler {
             S32 => SseOpcode::Movss,
             S64 => SseOpcode::Movsd,
             S128 => SseOpcode::Movdqu,
+            S8 | S16 => unreachable!(),
         };
 
-        let src =
-            Self::to_synthetic_amode(src, &mut self.pool, &mut self.constants, &mut self.buffer);
+        let src = Self::to_synthetic_amode(
+            src,
+            &mut self.pool,
+            &mut self.constants,
+            &mut self.buffer,
+            flags,
+        );
         self.emit(Inst::XmmUnaryRmRUnaligned {
             op,
             src: XmmMem::new(RegMem::mem(src)).expect("valid xmm unaligned"),
@@ -389,7 +494,7 @@ impl Assembler {
     }
 
     /// Single and double precision floating point store.
-    pub fn xmm_mov_rm(&mut self, src: Reg, dst: &Address, size: OperandSize) {
+    pub fn xmm_mov_rm(&mut self, src: Reg, dst: &Address, size: OperandSize, flags: MemFlags) {
         use OperandSize::*;
 
         assert!(src.is_float());
@@ -398,10 +503,16 @@ impl Assembler {
             S32 => SseOpcode::Movss,
             S64 => SseOpcode::Movsd,
             S128 => SseOpcode::Movdqu,
+            S8 | S16 => unreachable!(),
         };
 
-        let dst =
-            Self::to_synthetic_amode(dst, &mut self.pool, &mut self.constants, &mut self.buffer);
+        let dst = Self::to_synthetic_amode(
+            dst,
+            &mut self.pool,
+            &mut self.constants,
+            &mut self.buffer,
+            flags,
+        );
         self.emit(Inst::XmmMovRM {
             op,
             src: src.into(),
@@ -415,7 +526,8 @@ impl Assembler {
             OperandSize::S32 => types::F32,
             OperandSize::S64 => types::F64,
             // Move the entire 128 bits via movdqa.
-            OperandSize::S128 => types::I128,
+            OperandSize::S128 => types::I8X16,
+            OperandSize::S8 | OperandSize::S16 => unreachable!(),
         };
 
         self.emit(Inst::XmmCmove {
@@ -478,7 +590,7 @@ impl Assembler {
         let op = match size {
             OperandSize::S32 => SseOpcode::Andps,
             OperandSize::S64 => SseOpcode::Andpd,
-            OperandSize::S128 => unreachable!(),
+            OperandSize::S8 | OperandSize::S16 | OperandSize::S128 => unreachable!(),
         };
 
         self.emit(Inst::XmmRmR {
@@ -494,7 +606,7 @@ impl Assembler {
         let op = match size {
             OperandSize::S32 => SseOpcode::Andnps,
             OperandSize::S64 => SseOpcode::Andnpd,
-            OperandSize::S128 => unreachable!(),
+            OperandSize::S8 | OperandSize::S16 | OperandSize::S128 => unreachable!(),
         };
 
         self.emit(Inst::XmmRmR {
@@ -509,7 +621,7 @@ impl Assembler {
         let op = match size {
             OperandSize::S32 => SseOpcode::Movd,
             OperandSize::S64 => SseOpcode::Movq,
-            OperandSize::S128 => unreachable!(),
+            OperandSize::S8 | OperandSize::S16 | OperandSize::S128 => unreachable!(),
         };
 
         self.emit(Inst::GprToXmm {
@@ -524,7 +636,7 @@ impl Assembler {
         let op = match size {
             OperandSize::S32 => SseOpcode::Movd,
             OperandSize::S64 => SseOpcode::Movq,
-            OperandSize::S128 => unreachable!(),
+            OperandSize::S8 | OperandSize::S16 | OperandSize::S128 => unreachable!(),
         };
 
         self.emit(Inst::XmmToGpr {
@@ -590,7 +702,7 @@ impl Assembler {
         let op = match dst_size {
             OperandSize::S32 => SseOpcode::Cvtsi2ss,
             OperandSize::S64 => SseOpcode::Cvtsi2sd,
-            OperandSize::S128 => unreachable!(),
+            OperandSize::S8 | OperandSize::S16 | OperandSize::S128 => unreachable!(),
         };
         self.emit(Inst::CvtIntToFloat {
             op,
@@ -667,7 +779,7 @@ impl Assembler {
         let op = match size {
             OperandSize::S32 => SseOpcode::Orps,
             OperandSize::S64 => SseOpcode::Orpd,
-            OperandSize::S128 => unreachable!(),
+            OperandSize::S8 | OperandSize::S16 | OperandSize::S128 => unreachable!(),
         };
 
         self.emit(Inst::XmmRmR {
@@ -706,7 +818,7 @@ impl Assembler {
         let op = match size {
             OperandSize::S32 => SseOpcode::Xorps,
             OperandSize::S64 => SseOpcode::Xorpd,
-            OperandSize::S128 => unreachable!(),
+            OperandSize::S8 | OperandSize::S16 | OperandSize::S128 => unreachable!(),
         };
 
         self.emit(Inst::XmmRmR {
@@ -927,7 +1039,7 @@ impl Assembler {
         let op = match size {
             OperandSize::S32 => SseOpcode::Ucomiss,
             OperandSize::S64 => SseOpcode::Ucomisd,
-            OperandSize::S128 => unreachable!(),
+            OperandSize::S8 | OperandSize::S16 | OperandSize::S128 => unreachable!(),
         };
 
         self.emit(Inst::XmmCmpRmR {
@@ -1053,7 +1165,7 @@ impl Assembler {
         let op = match size {
             OperandSize::S32 => SseOpcode::Addss,
             OperandSize::S64 => SseOpcode::Addsd,
-            OperandSize::S128 => unreachable!(),
+            OperandSize::S8 | OperandSize::S16 | OperandSize::S128 => unreachable!(),
         };
 
         self.emit(Inst::XmmRmRUnaligned {
@@ -1069,7 +1181,7 @@ impl Assembler {
         let op = match size {
             OperandSize::S32 => SseOpcode::Subss,
             OperandSize::S64 => SseOpcode::Subsd,
-            OperandSize::S128 => unreachable!(),
+            OperandSize::S8 | OperandSize::S16 | OperandSize::S128 => unreachable!(),
         };
 
         self.emit(Inst::XmmRmRUnaligned {
@@ -1085,7 +1197,7 @@ impl Assembler {
         let op = match size {
             OperandSize::S32 => SseOpcode::Mulss,
             OperandSize::S64 => SseOpcode::Mulsd,
-            OperandSize::S128 => unreachable!(),
+            OperandSize::S8 | OperandSize::S16 | OperandSize::S128 => unreachable!(),
         };
 
         self.emit(Inst::XmmRmRUnaligned {
@@ -1101,7 +1213,7 @@ impl Assembler {
         let op = match size {
             OperandSize::S32 => SseOpcode::Divss,
             OperandSize::S64 => SseOpcode::Divsd,
-            OperandSize::S128 => unreachable!(),
+            OperandSize::S8 | OperandSize::S16 | OperandSize::S128 => unreachable!(),
         };
 
         self.emit(Inst::XmmRmRUnaligned {
@@ -1140,7 +1252,7 @@ impl Assembler {
         let op = match size {
             OperandSize::S32 => SseOpcode::Roundss,
             OperandSize::S64 => SseOpcode::Roundsd,
-            OperandSize::S128 => unreachable!(),
+            OperandSize::S8 | OperandSize::S16 | OperandSize::S128 => unreachable!(),
         };
 
         let imm: u8 = match mode {
@@ -1162,7 +1274,7 @@ impl Assembler {
         let op = match size {
             OperandSize::S32 => SseOpcode::Sqrtss,
             OperandSize::S64 => SseOpcode::Sqrtsd,
-            OperandSize::S128 => unreachable!(),
+            OperandSize::S8 | OperandSize::S16 | OperandSize::S128 => unreachable!(),
         };
 
         self.emit(Inst::XmmRmR {
@@ -1174,49 +1286,78 @@ impl Assembler {
     }
 
     /// Emit a call to an unknown location through a register.
-    pub fn call_with_reg(&mut self, callee: Reg) {
-        self.emit(Inst::CallUnknown {
-            dest: RegMem::reg(callee.into()),
-            info: Box::new(CallInfo {
-                uses: smallvec![],
-                defs: smallvec![],
-                clobbers: Default::default(),
-                opcode: Opcode::Call,
-                callee_pop_size: 0,
-                callee_conv: CallConv::SystemV,
-            }),
-        });
+    pub fn call_with_reg(&mut self, callee: Reg, stack_map: Option<StackMap>) {
+        self.emit_call(
+            Inst::CallUnknown {
+                dest: RegMem::reg(callee.into()),
+                info: Box::new(CallInfo {
+                    uses: smallvec![],
+                    defs: smallvec![],
+                    clobbers: Default::default(),
+                    opcode: Opcode::Call,
+                    callee_pop_size: 0,
+                    callee_conv: CallConv::SystemV,
+                }),
+            },
+            stack_map,
+        );
     }
 
     /// Emit a call to a locally defined function through an index.
-    pub fn call_with_index(&mut self, index: u32) {
+    pub fn call_with_index(&mut self, index: u32, stack_map: Option<StackMap>) {
         let dest = ExternalName::user(UserExternalNameRef::new(index as usize));
-        self.emit(Inst::CallKnown {
-            dest,
-            info: Box::new(CallInfo {
-                uses: smallvec![],
-                defs: smallvec![],
-                clobbers: Default::default(),
-                opcode: Opcode::Call,
-                callee_pop_size: 0,
-                callee_conv: CallConv::SystemV,
-            }),
-        });
+        self.emit_call(
+            Inst::CallKnown {
+                dest,
+                info: Box::new(CallInfo {
+                    uses: smallvec![],
+                    defs: smallvec![],
+                    clobbers: Default::default(),
+                    opcode: Opcode::Call,
+                    callee_pop_size: 0,
+                    callee_conv: CallConv::SystemV,
+                }),
+            },
+            stack_map,
+        );
     }
 
     /// Emit a call to a well-known libcall.
-    pub fn call_with_lib(&mut self, lib: LibCall) {
+    pub fn call_with_lib(&mut self, lib: LibCall, stack_map: Option<StackMap>) {
         let dest = ExternalName::LibCall(lib);
-        self.emit(Inst::CallKnown {
-            dest,
-            info: Box::new(CallInfo {
-                uses: smallvec![],
-                defs: smallvec![],
-                clobbers: Default::default(),
-                opcode: Opcode::Call,
-                callee_pop_size: 0,
-                callee_conv: CallConv::SystemV,
-            }),
+        self.emit_call(
+            Inst::CallKnown {
+                dest,
+                info: Box::new(CallInfo {
+                    uses: smallvec![],
+                    defs: smallvec![],
+                    clobbers: Default::default(),
+                    opcode: Opcode::Call,
+                    callee_pop_size: 0,
+                    callee_conv: CallConv::SystemV,
+                }),
+            },
+            stack_map,
+        );
+    }
+
+    /// Emits a jump to a locally defined function through an index, used
+    /// for tail calls.
+    pub fn jmp_with_index(&mut self, index: u32) {
+        let dest = ExternalName::user(UserExternalNameRef::new(index as usize));
+        // There's no Cranelift instruction for a jump to an external name,
+        // so encode `jmp rel32` directly; the addend accounts for the
+        // relocation being relative to the end of the instruction.
+        self.buffer.put1(0xE9);
+        self.buffer.add_reloc(Reloc::X86CallPCRel4, &dest, -4);
+        self.buffer.put4(0);
+    }
+
+    /// Emits an indirect jump through the given register, used for tail
+    /// calls.
+    pub fn jmp_reg(&mut self, target: Reg) {
+        self.emit(Inst::JmpUnknown {
+            target: RegMem::reg(target.into()),
         });
     }
 
@@ -1257,17 +1398,141 @@ impl Assembler {
     }
 
     /// Conditional trap.
-    pub fn trapif(&mut self, cc: IntCmpKind, trap_code: TrapCode) {
+    pub fn trapif(&mut self, cc: impl Into<CC>, trap_code: TrapCode) {
         self.emit(Inst::TrapIf {
             cc: cc.into(),
             trap_code,
         });
     }
 
+    /// Binary vector operation, with the result put in dst.
+    pub fn xmm_rm_r(&mut self, src: Reg, dst: Reg, op: SseOpcode) {
+        self.ensure_sse_flags(op);
+        self.emit(Inst::XmmRmR {
+            op,
+            src1: dst.into(),
+            src2: XmmMemAligned::from(Xmm::from(src)),
+            dst: dst.into(),
+        });
+    }
+
+    /// Binary vector operation with an immediate operand, with the result
+    /// put in dst. The source register might be a general purpose register
+    /// (e.g. `pinsr{b,w,d,q}`).
+    pub fn xmm_rm_r_imm(&mut self, src: Reg, dst: Reg, imm: u8, op: SseOpcode, size: OperandSize) {
+        self.ensure_sse_flags(op);
+        self.emit(Inst::XmmRmRImm {
+            op,
+            src1: dst.into(),
+            src2: src.into(),
+            dst: Writable::from_reg(dst.into()),
+            imm,
+            size: size.into(),
+        });
+    }
+
+    /// Unary vector operation from src to dst.
+    pub fn xmm_unary_rm_r(&mut self, src: Reg, dst: Reg, op: SseOpcode) {
+        self.ensure_sse_flags(op);
+        self.emit(Inst::XmmUnaryRmR {
+            op,
+            src: XmmMemAligned::from(Xmm::from(src)),
+            dst: dst.into(),
+        });
+    }
+
+    /// Unary vector operation with an immediate operand from src to dst.
+    pub fn xmm_unary_rm_r_imm(&mut self, src: Reg, dst: Reg, imm: u8, op: SseOpcode) {
+        self.ensure_sse_flags(op);
+        self.emit(Inst::XmmUnaryRmRImm {
+            op,
+            src: XmmMemAligned::from(Xmm::from(src)),
+            imm,
+            dst: dst.into(),
+        });
+    }
+
+    /// Lane-wise vector shift of dst by the amount held in the low 64 bits of
+    /// the src vector register.
+    pub fn xmm_shift_rr(&mut self, src: Reg, dst: Reg, op: SseOpcode) {
+        self.emit(Inst::XmmRmiReg {
+            opcode: op,
+            src1: dst.into(),
+            src2: XmmMemAlignedImm::new(src.into()).expect("valid xmm register"),
+            dst: dst.into(),
+        });
+    }
+
+    /// Lane-wise vector shift of dst by an immediate amount.
+    pub fn xmm_shift_ir(&mut self, imm: u8, dst: Reg, op: SseOpcode) {
+        self.emit(Inst::XmmRmiReg {
+            opcode: op,
+            src1: dst.into(),
+            src2: XmmMemAlignedImm::new(RegMemImm::imm(imm.into())).expect("valid immediate"),
+            dst: dst.into(),
+        });
+    }
+
+    /// Extract the vector lane at the given index of src into the general
+    /// purpose register dst.
+    pub fn xmm_to_gpr_imm(&mut self, src: Reg, dst: Reg, lane: u8, op: SseOpcode) {
+        self.ensure_sse_flags(op);
+        self.emit(Inst::XmmToGprImm {
+            op,
+            src: src.into(),
+            dst: dst.into(),
+            imm: lane,
+        });
+    }
+
+    /// Gather the most significant bit of each lane of src into dst.
+    pub fn xmm_movmsk(&mut self, src: Reg, dst: Reg, op: SseOpcode) {
+        self.emit(Inst::XmmToGpr {
+            op,
+            src: src.into(),
+            dst: dst.into(),
+            dst_size: args::OperandSize::Size32,
+        });
+    }
+
+    /// Set the zero flag if the bitwise and of src and dst is zero.
+    pub fn ptest(&mut self, src: Reg, dst: Reg) {
+        self.ensure_sse_flags(SseOpcode::Ptest);
+        self.emit(Inst::XmmCmpRmR {
+            op: SseOpcode::Ptest,
+            src: XmmMemAligned::from(Xmm::from(src)),
+            dst: dst.into(),
+        });
+    }
+
+    /// Ensures that the ISA flags required by the given vector instruction
+    /// are enabled.
+    fn ensure_sse_flags(&self, op: SseOpcode) {
+        use SseOpcode::*;
+        match op {
+            Pabsb | Pabsw | Pabsd | Palignr | Pmaddubsw | Pmulhrsw | Pshufb => {
+                assert!(self.isa_flags.has_ssse3(), "Requires has_ssse3 flag")
+            }
+            Insertps | Packusdw | Pblendw | Pcmpeqq | Pextrb | Pextrd | Pextrq | Pinsrb
+            | Pinsrd | Pmaxsb | Pmaxsd | Pmaxud | Pmaxuw | Pminsb | Pminsd | Pminud | Pminuw
+            | Pmovsxbw | Pmovsxwd | Pmovsxdq | Pmovzxbw | Pmovzxwd | Pmovzxdq | Pmuldq | Pmulld
+            | Ptest | Roundps | Roundpd => {
+                assert!(self.isa_flags.has_sse41(), "Requires has_sse41 flag")
+            }
+            Pcmpgtq => assert!(self.isa_flags.has_sse42(), "Requires has_sse42 flag"),
+            _ => {}
+        }
+    }
+
     /// Load effective address.
     pub fn lea(&mut self, addr: &Address, dst: Reg, size: OperandSize) {
-        let addr =
-            Self::to_synthetic_amode(addr, &mut self.pool, &mut self.constants, &mut self.buffer);
+        let addr = Self::to_synthetic_amode(
+            addr,
+            &mut self.pool,
+            &mut self.constants,
+            &mut self.buffer,
+            MemFlags::trusted(),
+        );
         self.emit(Inst::LoadEffectiveAddress {
             addr,
             dst: dst.into(),
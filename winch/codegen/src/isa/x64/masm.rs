@@ -7,7 +7,9 @@ use super::{
 
 use crate::masm::{
     DivKind, ExtendKind, FloatCmpKind, Imm as I, IntCmpKind, MacroAssembler as Masm, OperandSize,
-    RegImm, RemKind, RoundingMode, ShiftKind, TrapCode,
+    RegImm, RemKind, RmwOp, RoundingMode, ShiftKind, TrapCode, V128AddKind, V128ConvertKind,
+    V128ExtAddKind, V128ExtendKind, V128MinMaxKind, V128MulKind, V128NarrowKind, V128SubKind,
+    V128TruncSatKind, VectorShape,
 };
 use crate::{
     abi::ABI,
@@ -24,8 +26,11 @@ use crate::{
     masm::CalleeKind,
 };
 use cranelift_codegen::{
-    isa::unwind::UnwindInst, isa::x64::settings as x64_settings, settings, Final,
-    MachBufferFinalized, MachLabel,
+    ir::MemFlags,
+    isa::unwind::UnwindInst,
+    isa::x64::args::{SseOpcode, CC},
+    isa::x64::settings as x64_settings,
+    settings, Final, MachBufferFinalized, MachLabel,
 };
 
 use wasmtime_environ::{PtrSize, WasmType, WASM_PAGE_SIZE};
@@ -102,18 +107,27 @@ impl Masm for MacroAssembler {
                 word_bytes
             }
             (RegClass::Int, OperandSize::S32) => {
-                let bytes = size.bytes();
+                let bytes = Self::stack_slot_size(size);
                 self.reserve_stack(bytes);
                 let sp_offset = SPOffset::from_u32(self.sp_offset);
-                self.asm.mov_rm(reg, &self.address_from_sp(sp_offset), size);
+                self.asm.mov_rm(
+                    reg,
+                    &self.address_from_sp(sp_offset),
+                    size,
+                    MemFlags::trusted(),
+                );
                 bytes
             }
             (RegClass::Float, _) => {
-                let bytes = size.bytes();
+                let bytes = Self::stack_slot_size(size);
                 self.reserve_stack(bytes);
                 let sp_offset = SPOffset::from_u32(self.sp_offset);
-                self.asm
-                    .xmm_mov_rm(reg, &self.address_from_sp(sp_offset), size);
+                self.asm.xmm_mov_rm(
+                    reg,
+                    &self.address_from_sp(sp_offset),
+                    size,
+                    MemFlags::trusted(),
+                );
                 bytes
             }
             _ => unreachable!(),
@@ -190,8 +204,12 @@ impl Masm for MacroAssembler {
             // If the table data declares a particular offset base,
             // load the address into a register to further use it as
             // the table address.
-            self.asm
-                .mov_mr(&self.address_at_vmctx(offset), ptr_base, self.ptr_size);
+            self.asm.mov_mr(
+                &self.address_at_vmctx(offset),
+                ptr_base,
+                self.ptr_size,
+                MemFlags::trusted(),
+            );
         } else {
             // Else, simply move the vmctx register into the addr register as
             // the base to calculate the table address.
@@ -201,7 +219,8 @@ impl Masm for MacroAssembler {
         // OOB check.
         let bound_addr = self.address_at_reg(ptr_base, table_data.current_elems_offset);
         let bound_size = table_data.current_elements_size;
-        self.asm.mov_mr(&bound_addr, bound, bound_size);
+        self.asm
+            .mov_mr(&bound_addr, bound, bound_size, MemFlags::trusted());
         self.asm.cmp_rr(bound, index, bound_size);
         self.asm.trapif(IntCmpKind::GeU, TrapCode::TableOutOfBounds);
 
@@ -219,6 +238,7 @@ impl Masm for MacroAssembler {
             &self.address_at_reg(ptr_base, table_data.offset),
             ptr_base,
             self.ptr_size,
+            MemFlags::trusted(),
         );
         // Copy the value of the table base into a temporary register
         // so that we can use it later in case of a misspeculation.
@@ -242,15 +262,23 @@ impl Masm for MacroAssembler {
         let size = context.any_gpr(self);
 
         if let Some(offset) = table_data.import_from {
-            self.asm
-                .mov_mr(&self.address_at_vmctx(offset), scratch, self.ptr_size);
+            self.asm.mov_mr(
+                &self.address_at_vmctx(offset),
+                scratch,
+                self.ptr_size,
+                MemFlags::trusted(),
+            );
         } else {
             self.asm.mov_rr(vmctx, scratch, self.ptr_size);
         };
 
         let size_addr = Address::offset(scratch, table_data.current_elems_offset);
-        self.asm
-            .mov_mr(&size_addr, size, table_data.current_elements_size);
+        self.asm.mov_mr(
+            &size_addr,
+            size,
+            table_data.current_elements_size,
+            MemFlags::trusted(),
+        );
 
         context.stack.push(TypedReg::i32(size).into());
     }
@@ -261,15 +289,20 @@ impl Masm for MacroAssembler {
         let vmctx = <Self::ABI as ABI>::vmctx_reg();
 
         let base = if let Some(offset) = heap_data.import_from {
-            self.asm
-                .mov_mr(&self.address_at_vmctx(offset), scratch, self.ptr_size);
+            self.asm.mov_mr(
+                &self.address_at_vmctx(offset),
+                scratch,
+                self.ptr_size,
+                MemFlags::trusted(),
+            );
             scratch
         } else {
             vmctx
         };
 
         let size_addr = Address::offset(base, heap_data.current_length_offset);
-        self.asm.mov_mr(&size_addr, size_reg, self.ptr_size);
+        self.asm
+            .mov_mr(&size_addr, size_reg, self.ptr_size, MemFlags::trusted());
         // Prepare the stack to emit a shift to get the size in pages rather
         // than in bytes.
         context
@@ -310,39 +343,100 @@ impl Masm for MacroAssembler {
     }
 
     fn store(&mut self, src: RegImm, dst: Address, size: OperandSize) {
-        let scratch = <Self::ABI as ABI>::scratch_reg();
-        let float_scratch = <Self::ABI as ABI>::float_scratch_reg();
-        match src {
-            RegImm::Imm(imm) => match imm {
-                I::I32(v) => self.asm.mov_im(v as i32, &dst, size),
-                I::I64(v) => match v.try_into() {
-                    Ok(v) => self.asm.mov_im(v, &dst, size),
-                    Err(_) => {
-                        // If the immediate doesn't sign extend, use a scratch
-                        // register.
-                        self.asm.mov_ir(v, scratch, size);
-                        self.asm.mov_rm(scratch, &dst, size);
-                    }
-                },
-                I::F32(v) => {
-                    let addr = self.asm.add_constant(v.to_le_bytes().as_slice());
-                    self.asm.xmm_mov_mr(&addr, float_scratch, size);
-                    self.asm.xmm_mov_rm(float_scratch, &dst, size);
-                }
-                I::F64(v) => {
-                    let addr = self.asm.add_constant(v.to_le_bytes().as_slice());
-                    self.asm.xmm_mov_mr(&addr, float_scratch, size);
-                    self.asm.xmm_mov_rm(float_scratch, &dst, size);
-                }
-            },
-            RegImm::Reg(reg) => {
-                if reg.is_int() {
-                    self.asm.mov_rm(reg, &dst, size);
-                } else {
-                    self.asm.xmm_mov_rm(reg, &dst, size);
+        self.store_impl(src, dst, size, MemFlags::trusted());
+    }
+
+    fn wasm_store(&mut self, src: Reg, dst: Self::Address, size: OperandSize) {
+        self.store_impl(src.into(), dst, size, MemFlags::new());
+    }
+
+    fn wasm_atomic_load(&mut self, src: Self::Address, dst: Reg, size: OperandSize) {
+        // Under x64's memory model, plain loads are sequentially consistent.
+        self.asm.mov_mr(&src, dst, size, MemFlags::new());
+    }
+
+    fn wasm_atomic_store(&mut self, src: Reg, dst: Self::Address, size: OperandSize) {
+        self.asm.mov_rm(src, &dst, size, MemFlags::new());
+        self.asm.mfence();
+    }
+
+    fn atomic_rmw(&mut self, context: &mut CodeGenContext, size: OperandSize, op: RmwOp) {
+        // `lock cmpxchg` requires the old value in rax.
+        let operand = context.pop_to_reg(self, None);
+        let operand = self.evict_rax(context, operand);
+        let rax = context.reg(regs::rax(), self);
+        let addr = context.pop_to_reg(self, None);
+        let tmp = context.any_gpr(self);
+        let mem = self.address_at_reg(addr.reg, 0);
+
+        // Emit a compare-and-exchange loop:
+        //
+        //   mov{zx}         rax, [addr]
+        // again:
+        //   mov             tmp, rax
+        //   <op>            tmp, operand
+        //   lock cmpxchg    [addr], tmp
+        //   jnz             again
+        //
+        // The value in rax is zero extended by the initial load and
+        // `lock cmpxchg` only ever updates its low `size` bits, so it always
+        // holds the zero extended old value once the loop completes.
+        self.asm.mov_mr(&mem, rax, size, MemFlags::new());
+        let again = self.get_label();
+        self.bind(again);
+        match op {
+            RmwOp::Xchg => self.asm.mov_rr(operand.reg, tmp, OperandSize::S64),
+            _ => {
+                self.asm.mov_rr(rax, tmp, OperandSize::S64);
+                match op {
+                    RmwOp::Add => self.asm.add_rr(operand.reg, tmp, OperandSize::S64),
+                    RmwOp::Sub => self.asm.sub_rr(operand.reg, tmp, OperandSize::S64),
+                    RmwOp::And => self.asm.and_rr(operand.reg, tmp, OperandSize::S64),
+                    RmwOp::Or => self.asm.or_rr(operand.reg, tmp, OperandSize::S64),
+                    RmwOp::Xor => self.asm.xor_rr(operand.reg, tmp, OperandSize::S64),
+                    RmwOp::Xchg => unreachable!(),
                 }
             }
         }
+        self.asm
+            .lock_cmpxchg(&mem, tmp, rax, rax, size, MemFlags::new());
+        self.asm.jmp_if(CC::NZ, again);
+
+        context.free_reg(tmp);
+        context.free_reg(addr);
+        context.free_reg(operand);
+        context.stack.push(TypedReg::new(operand.ty, rax).into());
+    }
+
+    fn atomic_cas(&mut self, context: &mut CodeGenContext, size: OperandSize) {
+        // `lock cmpxchg` requires the expected value in rax.
+        let rax = regs::rax();
+        let replacement = context.pop_to_reg(self, None);
+        let replacement = self.evict_rax(context, replacement);
+        let expected = context.pop_to_reg(self, Some(rax));
+        let addr = context.pop_to_reg(self, None);
+        let mem = self.address_at_reg(addr.reg, 0);
+
+        self.asm
+            .lock_cmpxchg(&mem, replacement.reg, rax, rax, size, MemFlags::new());
+
+        // On success rax still holds the expected value and on failure only
+        // its low `size` bits are updated; zero extend it in both cases.
+        match size {
+            OperandSize::S8 => self.asm.and_ir(0xff, rax, OperandSize::S32),
+            OperandSize::S16 => self.asm.and_ir(0xffff, rax, OperandSize::S32),
+            OperandSize::S32 => self.asm.mov_rr(rax, rax, OperandSize::S32),
+            OperandSize::S64 => {}
+            OperandSize::S128 => unreachable!(),
+        }
+
+        context.free_reg(addr);
+        context.free_reg(replacement);
+        context.stack.push(expected.into());
+    }
+
+    fn fence(&mut self) {
+        self.asm.mfence();
     }
 
     fn pop(&mut self, dst: Reg, size: OperandSize) {
@@ -350,8 +444,8 @@ impl Masm for MacroAssembler {
         match (dst.class(), size) {
             (RegClass::Int, OperandSize::S32) => {
                 let addr = self.address_from_sp(current_sp);
-                self.asm.mov_mr(&addr, dst, size);
-                self.free_stack(size.bytes());
+                self.asm.mov_mr(&addr, dst, size, MemFlags::trusted());
+                self.free_stack(Self::stack_slot_size(size));
             }
             (RegClass::Int, OperandSize::S64) => {
                 self.asm.pop_r(dst);
@@ -359,8 +453,8 @@ impl Masm for MacroAssembler {
             }
             (RegClass::Float, _) => {
                 let addr = self.address_from_sp(current_sp);
-                self.asm.xmm_mov_mr(&addr, dst, size);
-                self.free_stack(size.bytes());
+                self.asm.xmm_mov_mr(&addr, dst, size, MemFlags::trusted());
+                self.free_stack(Self::stack_slot_size(size));
             }
             _ => unreachable!(),
         }
@@ -369,6 +463,7 @@ impl Masm for MacroAssembler {
     fn call(
         &mut self,
         stack_args_size: u32,
+        ref_slots: &[SPOffset],
         mut load_callee: impl FnMut(&mut Self) -> CalleeKind,
     ) -> u32 {
         let alignment: u32 = <Self::ABI as abi::ABI>::call_stack_align().into();
@@ -377,15 +472,61 @@ impl Masm for MacroAssembler {
         let aligned_args_size = align_to(stack_args_size, alignment);
         let total_stack = delta + aligned_args_size;
         self.reserve_stack(total_stack);
+        let stack_map = abi::stack_map(
+            self.sp_offset(),
+            ref_slots,
+            <Self::ABI as abi::ABI>::word_bytes(),
+            0,
+        );
         let callee = load_callee(self);
         match callee {
-            CalleeKind::Indirect(reg) => self.asm.call_with_reg(reg),
-            CalleeKind::Direct(idx) => self.asm.call_with_index(idx),
-            CalleeKind::Known(lib) => self.asm.call_with_lib(lib),
+            CalleeKind::Indirect(reg) => self.asm.call_with_reg(reg, stack_map),
+            CalleeKind::Direct(idx) => self.asm.call_with_index(idx, stack_map),
+            CalleeKind::Known(lib) => self.asm.call_with_lib(lib, stack_map),
         };
         total_stack
     }
 
+    fn return_call(
+        &mut self,
+        stack_args_size: u32,
+        caller_stack_args_size: u32,
+        mut load_callee: impl FnMut(&mut Self) -> CalleeKind,
+    ) {
+        let word_bytes = <Self::ABI as abi::ABI>::word_bytes();
+        self.reserve_stack(stack_args_size);
+        let callee = load_callee(self);
+
+        // Place the return address right below the new stack arguments and
+        // restore the caller's frame pointer.
+        let scratch = regs::scratch();
+        let ret_addr_offset: u32 = <Self::ABI as abi::ABI>::ret_addr_offset().into();
+        self.reserve_stack(word_bytes);
+        self.load_ptr(Address::offset(rbp(), ret_addr_offset), scratch);
+        self.store_ptr(scratch, Address::offset(rsp(), 0));
+        self.load_ptr(Address::offset(rbp(), 0), rbp());
+
+        // Move the return address and the new stack arguments on top of the
+        // current frame, so that they end where the caller's stack arguments
+        // end. The destination is always at a higher address than the
+        // source, so the copy starts from the highest word.
+        let delta = self.sp_offset + word_bytes + caller_stack_args_size - stack_args_size;
+        for offset in (0..stack_args_size + word_bytes)
+            .step_by(word_bytes as usize)
+            .rev()
+        {
+            self.load_ptr(Address::offset(rsp(), offset), scratch);
+            self.store_ptr(scratch, Address::offset(rsp(), offset + delta));
+        }
+        self.asm.add_ir(delta as i32, rsp(), OperandSize::S64);
+
+        match callee {
+            CalleeKind::Indirect(reg) => self.asm.jmp_reg(reg),
+            CalleeKind::Direct(idx) => self.asm.jmp_with_index(idx),
+            CalleeKind::Known(_) => unreachable!("tail calls to libcalls are not supported"),
+        }
+    }
+
     fn load_ptr(&mut self, src: Self::Address, dst: Reg) {
         self.load(src, dst, self.ptr_size);
     }
@@ -395,10 +536,21 @@ impl Masm for MacroAssembler {
     }
 
     fn load(&mut self, src: Address, dst: Reg, size: OperandSize) {
-        if dst.is_int() {
-            self.asm.mov_mr(&src, dst, size);
+        self.load_impl(src, dst, size, MemFlags::trusted());
+    }
+
+    fn wasm_load(
+        &mut self,
+        src: Self::Address,
+        dst: Reg,
+        size: OperandSize,
+        kind: Option<ExtendKind>,
+    ) {
+        if let Some(ext) = kind {
+            assert!(dst.is_int());
+            self.asm.movsx_mr(&src, dst, ext, MemFlags::new());
         } else {
-            self.asm.xmm_mov_mr(&src, dst, size);
+            self.load_impl(src, dst, size, MemFlags::new());
         }
     }
 
@@ -422,12 +574,13 @@ impl Masm for MacroAssembler {
                 I::I64(v) => self.asm.mov_ir(v, dst, size),
                 I::F32(v) => {
                     let addr = self.asm.add_constant(v.to_le_bytes().as_slice());
-                    self.asm.xmm_mov_mr(&addr, dst, size);
+                    self.asm.xmm_mov_mr(&addr, dst, size, MemFlags::trusted());
                 }
                 I::F64(v) => {
                     let addr = self.asm.add_constant(v.to_le_bytes().as_slice());
-                    self.asm.xmm_mov_mr(&addr, dst, size);
+                    self.asm.xmm_mov_mr(&addr, dst, size, MemFlags::trusted());
                 }
+                I::V128(v) => self.load_v128_constant(v, dst),
             },
         }
     }
@@ -459,6 +612,11 @@ impl Masm for MacroAssembler {
         }
     }
 
+    fn checked_uadd(&mut self, dst: Reg, lhs: Reg, rhs: RegImm, size: OperandSize, trap: TrapCode) {
+        self.add(dst, lhs, rhs, size);
+        self.asm.trapif(CC::B, trap);
+    }
+
     fn sub(&mut self, dst: Reg, lhs: Reg, rhs: RegImm, size: OperandSize) {
         Self::ensure_two_argument_form(&dst, &lhs);
         match (rhs, dst) {
@@ -534,7 +692,7 @@ impl Masm for MacroAssembler {
         let sign_mask = match size {
             OperandSize::S32 => I::I32(0x80000000),
             OperandSize::S64 => I::I64(0x8000000000000000),
-            OperandSize::S128 => unreachable!(),
+            OperandSize::S8 | OperandSize::S16 | OperandSize::S128 => unreachable!(),
         };
         self.load_constant(&sign_mask, scratch_gpr, size);
         self.asm.gpr_to_xmm(scratch_gpr, scratch_xmm, size);
@@ -556,7 +714,7 @@ impl Masm for MacroAssembler {
         let mask = match size {
             OperandSize::S32 => I::I32(0x80000000),
             OperandSize::S64 => I::I64(0x8000000000000000),
-            OperandSize::S128 => unreachable!(),
+            OperandSize::S8 | OperandSize::S16 | OperandSize::S128 => unreachable!(),
         };
         let scratch_gpr = regs::scratch();
         self.load_constant(&mask, scratch_gpr, size);
@@ -570,7 +728,7 @@ impl Masm for MacroAssembler {
         let mask = match size {
             OperandSize::S32 => I::I32(0x7fffffff),
             OperandSize::S64 => I::I64(0x7fffffffffffffff),
-            OperandSize::S128 => unreachable!(),
+            OperandSize::S8 | OperandSize::S16 | OperandSize::S128 => unreachable!(),
         };
         let scratch_gpr = regs::scratch();
         self.load_constant(&mask, scratch_gpr, size);
@@ -758,7 +916,7 @@ impl Masm for MacroAssembler {
         context.stack.push(Val::reg(rdx, divisor.ty));
     }
 
-    fn epilogue(&mut self, locals_size: u32) {
+    fn epilogue(&mut self, locals_size: u32, stack_args_size: u32) {
         assert_eq!(self.sp_offset, locals_size);
 
         let rsp = rsp();
@@ -766,7 +924,7 @@ impl Masm for MacroAssembler {
             self.asm.add_ir(locals_size as i32, rsp, OperandSize::S64);
         }
         self.asm.pop_r(rbp());
-        self.asm.ret();
+        self.asm.ret(stack_args_size);
     }
 
     fn finalize(self) -> MachBufferFinalized<Final> {
@@ -1078,10 +1236,883 @@ impl Masm for MacroAssembler {
             .cvt_float_to_float(src.into(), dst.into(), OperandSize::S32, OperandSize::S64);
     }
 
+    fn splat(&mut self, src: Reg, dst: Reg, shape: VectorShape) {
+        match shape {
+            VectorShape::I8x16 => {
+                // Broadcast the low byte by shuffling with an all-zeros mask.
+                let scratch = regs::scratch_xmm();
+                self.asm.gpr_to_xmm(src, dst, OperandSize::S32);
+                self.asm.xmm_rm_r(scratch, scratch, SseOpcode::Pxor);
+                self.asm.xmm_rm_r(scratch, dst, SseOpcode::Pshufb);
+            }
+            VectorShape::I16x8 => {
+                self.asm.gpr_to_xmm(src, dst, OperandSize::S32);
+                self.asm.xmm_unary_rm_r_imm(dst, dst, 0, SseOpcode::Pshuflw);
+                self.asm.xmm_unary_rm_r_imm(dst, dst, 0, SseOpcode::Pshufd);
+            }
+            VectorShape::I32x4 => {
+                self.asm.gpr_to_xmm(src, dst, OperandSize::S32);
+                self.asm.xmm_unary_rm_r_imm(dst, dst, 0, SseOpcode::Pshufd);
+            }
+            VectorShape::I64x2 => {
+                self.asm.gpr_to_xmm(src, dst, OperandSize::S64);
+                self.asm
+                    .xmm_unary_rm_r_imm(dst, dst, 0x44, SseOpcode::Pshufd);
+            }
+            VectorShape::F32x4 => {
+                self.asm.xmm_unary_rm_r_imm(src, dst, 0, SseOpcode::Pshufd);
+            }
+            VectorShape::F64x2 => {
+                self.asm
+                    .xmm_unary_rm_r_imm(src, dst, 0x44, SseOpcode::Pshufd);
+            }
+        }
+    }
+
+    fn extract_lane(
+        &mut self,
+        src: Reg,
+        dst: Reg,
+        lane: u8,
+        shape: VectorShape,
+        kind: Option<ExtendKind>,
+    ) {
+        match shape {
+            VectorShape::I8x16 | VectorShape::I16x8 => {
+                let op = if shape == VectorShape::I8x16 {
+                    SseOpcode::Pextrb
+                } else {
+                    SseOpcode::Pextrw
+                };
+                self.asm.xmm_to_gpr_imm(src, dst, lane, op);
+                if let Some(kind) = kind {
+                    self.asm.movsx_rr(dst, dst, kind);
+                }
+            }
+            VectorShape::I32x4 | VectorShape::I64x2 => {
+                let size = shape.lane_size();
+                if lane == 0 {
+                    self.asm.xmm_to_gpr(src, dst, size);
+                } else {
+                    let op = if size == OperandSize::S32 {
+                        SseOpcode::Pextrd
+                    } else {
+                        SseOpcode::Pextrq
+                    };
+                    self.asm.xmm_to_gpr_imm(src, dst, lane, op);
+                }
+            }
+            VectorShape::F32x4 => {
+                if lane == 0 {
+                    self.asm.xmm_mov_rr(src, dst, OperandSize::S128);
+                } else {
+                    self.asm
+                        .xmm_unary_rm_r_imm(src, dst, lane, SseOpcode::Pshufd);
+                }
+            }
+            VectorShape::F64x2 => {
+                if lane == 0 {
+                    self.asm.xmm_mov_rr(src, dst, OperandSize::S128);
+                } else {
+                    self.asm
+                        .xmm_unary_rm_r_imm(src, dst, 0xee, SseOpcode::Pshufd);
+                }
+            }
+        }
+    }
+
+    fn replace_lane(&mut self, src: Reg, dst: Reg, lane: u8, shape: VectorShape) {
+        match shape {
+            VectorShape::I8x16 => {
+                self.asm
+                    .xmm_rm_r_imm(src, dst, lane, SseOpcode::Pinsrb, OperandSize::S32)
+            }
+            VectorShape::I16x8 => {
+                self.asm
+                    .xmm_rm_r_imm(src, dst, lane, SseOpcode::Pinsrw, OperandSize::S32)
+            }
+            VectorShape::I32x4 => {
+                self.asm
+                    .xmm_rm_r_imm(src, dst, lane, SseOpcode::Pinsrd, OperandSize::S32)
+            }
+            // `pinsrd` with a 64-bit operand size emits `pinsrq`.
+            VectorShape::I64x2 => {
+                self.asm
+                    .xmm_rm_r_imm(src, dst, lane, SseOpcode::Pinsrd, OperandSize::S64)
+            }
+            VectorShape::F32x4 => {
+                self.asm
+                    .xmm_rm_r_imm(src, dst, lane << 4, SseOpcode::Insertps, OperandSize::S32)
+            }
+            VectorShape::F64x2 => {
+                let op = if lane == 0 {
+                    SseOpcode::Movsd
+                } else {
+                    SseOpcode::Movlhps
+                };
+                self.asm.xmm_rm_r(src, dst, op);
+            }
+        }
+    }
+
+    fn shuffle(&mut self, dst: Reg, lhs: Reg, rhs: Reg, lanes: [u8; 16]) {
+        Self::ensure_two_argument_form(&dst, &lhs);
+        let scratch = regs::scratch_xmm();
+        // `pshufb` zeroes the lanes whose mask byte has the high bit set, so
+        // select the lanes coming from each operand separately and then
+        // combine them.
+        let lhs_mask = lanes.map(|l| if l < 16 { l } else { 0x80 });
+        let rhs_mask = lanes.map(|l| if l < 16 { 0x80 } else { l - 16 });
+
+        self.load_v128_constant(i128::from_le_bytes(lhs_mask), scratch);
+        self.asm.xmm_rm_r(scratch, dst, SseOpcode::Pshufb);
+        self.load_v128_constant(i128::from_le_bytes(rhs_mask), scratch);
+        self.asm.xmm_rm_r(scratch, rhs, SseOpcode::Pshufb);
+        self.asm.xmm_rm_r(rhs, dst, SseOpcode::Por);
+    }
+
+    fn swizzle(&mut self, dst: Reg, lhs: Reg, rhs: Reg) {
+        Self::ensure_two_argument_form(&dst, &lhs);
+        let scratch = regs::scratch_xmm();
+        // Saturate the indices so that any index greater than 15 has the high
+        // bit set, which makes `pshufb` produce zero for the lane.
+        self.load_v128_constant(Self::splat_bits(0x70, OperandSize::S8), scratch);
+        self.asm.xmm_rm_r(scratch, rhs, SseOpcode::Paddusb);
+        self.asm.xmm_rm_r(rhs, dst, SseOpcode::Pshufb);
+    }
+
+    fn v128_add(&mut self, dst: Reg, lhs: Reg, rhs: Reg, kind: V128AddKind) {
+        Self::ensure_two_argument_form(&dst, &lhs);
+        let op = match kind {
+            V128AddKind::F32x4 => SseOpcode::Addps,
+            V128AddKind::F64x2 => SseOpcode::Addpd,
+            V128AddKind::I8x16 => SseOpcode::Paddb,
+            V128AddKind::I8x16SatS => SseOpcode::Paddsb,
+            V128AddKind::I8x16SatU => SseOpcode::Paddusb,
+            V128AddKind::I16x8 => SseOpcode::Paddw,
+            V128AddKind::I16x8SatS => SseOpcode::Paddsw,
+            V128AddKind::I16x8SatU => SseOpcode::Paddusw,
+            V128AddKind::I32x4 => SseOpcode::Paddd,
+            V128AddKind::I64x2 => SseOpcode::Paddq,
+        };
+        self.asm.xmm_rm_r(rhs, dst, op);
+    }
+
+    fn v128_sub(&mut self, dst: Reg, lhs: Reg, rhs: Reg, kind: V128SubKind) {
+        Self::ensure_two_argument_form(&dst, &lhs);
+        let op = match kind {
+            V128SubKind::F32x4 => SseOpcode::Subps,
+            V128SubKind::F64x2 => SseOpcode::Subpd,
+            V128SubKind::I8x16 => SseOpcode::Psubb,
+            V128SubKind::I8x16SatS => SseOpcode::Psubsb,
+            V128SubKind::I8x16SatU => SseOpcode::Psubusb,
+            V128SubKind::I16x8 => SseOpcode::Psubw,
+            V128SubKind::I16x8SatS => SseOpcode::Psubsw,
+            V128SubKind::I16x8SatU => SseOpcode::Psubusw,
+            V128SubKind::I32x4 => SseOpcode::Psubd,
+            V128SubKind::I64x2 => SseOpcode::Psubq,
+        };
+        self.asm.xmm_rm_r(rhs, dst, op);
+    }
+
+    fn v128_mul(&mut self, context: &mut CodeGenContext, kind: V128MulKind) {
+        let rhs = context.pop_to_reg(self, None);
+        let lhs = context.pop_to_reg(self, None);
+
+        let op = match kind {
+            V128MulKind::F32x4 => Some(SseOpcode::Mulps),
+            V128MulKind::F64x2 => Some(SseOpcode::Mulpd),
+            V128MulKind::I16x8 => Some(SseOpcode::Pmullw),
+            V128MulKind::I32x4 => Some(SseOpcode::Pmulld),
+            V128MulKind::I64x2 => None,
+        };
+
+        if let Some(op) = op {
+            self.asm.xmm_rm_r(rhs.into(), lhs.into(), op);
+        } else {
+            // There's no 64-bit lane multiplication before AVX-512, so
+            // compose it out of 32-bit multiplications:
+            // lo(a) * lo(b) + ((hi(a) * lo(b) + hi(b) * lo(a)) << 32).
+            let tmp = context.reg_for_class(RegClass::Float, self);
+            let scratch = regs::scratch_xmm();
+
+            self.asm.xmm_mov_rr(lhs.into(), scratch, OperandSize::S128);
+            self.asm.xmm_shift_ir(32, scratch, SseOpcode::Psrlq);
+            self.asm.xmm_rm_r(rhs.into(), scratch, SseOpcode::Pmuludq);
+
+            self.asm.xmm_mov_rr(rhs.into(), tmp, OperandSize::S128);
+            self.asm.xmm_shift_ir(32, tmp, SseOpcode::Psrlq);
+            self.asm.xmm_rm_r(lhs.into(), tmp, SseOpcode::Pmuludq);
+
+            self.asm.xmm_rm_r(tmp, scratch, SseOpcode::Paddq);
+            self.asm.xmm_shift_ir(32, scratch, SseOpcode::Psllq);
+
+            self.asm
+                .xmm_rm_r(rhs.into(), lhs.into(), SseOpcode::Pmuludq);
+            self.asm.xmm_rm_r(scratch, lhs.into(), SseOpcode::Paddq);
+            context.free_reg(tmp);
+        }
+
+        context.free_reg(rhs);
+        context.stack.push(lhs.into());
+    }
+
+    fn v128_div(&mut self, dst: Reg, lhs: Reg, rhs: Reg, size: OperandSize) {
+        Self::ensure_two_argument_form(&dst, &lhs);
+        let op = match size {
+            OperandSize::S32 => SseOpcode::Divps,
+            OperandSize::S64 => SseOpcode::Divpd,
+            _ => unreachable!(),
+        };
+        self.asm.xmm_rm_r(rhs, dst, op);
+    }
+
+    fn v128_min(&mut self, dst: Reg, lhs: Reg, rhs: Reg, kind: V128MinMaxKind) {
+        Self::ensure_two_argument_form(&dst, &lhs);
+        let op = match kind {
+            V128MinMaxKind::I8x16S => SseOpcode::Pminsb,
+            V128MinMaxKind::I8x16U => SseOpcode::Pminub,
+            V128MinMaxKind::I16x8S => SseOpcode::Pminsw,
+            V128MinMaxKind::I16x8U => SseOpcode::Pminuw,
+            V128MinMaxKind::I32x4S => SseOpcode::Pminsd,
+            V128MinMaxKind::I32x4U => SseOpcode::Pminud,
+            V128MinMaxKind::F32x4 | V128MinMaxKind::F64x2 => {
+                return self.v128_float_min(dst, rhs, kind == V128MinMaxKind::F32x4);
+            }
+        };
+        self.asm.xmm_rm_r(rhs, dst, op);
+    }
+
+    fn v128_max(&mut self, dst: Reg, lhs: Reg, rhs: Reg, kind: V128MinMaxKind) {
+        Self::ensure_two_argument_form(&dst, &lhs);
+        let op = match kind {
+            V128MinMaxKind::I8x16S => SseOpcode::Pmaxsb,
+            V128MinMaxKind::I8x16U => SseOpcode::Pmaxub,
+            V128MinMaxKind::I16x8S => SseOpcode::Pmaxsw,
+            V128MinMaxKind::I16x8U => SseOpcode::Pmaxuw,
+            V128MinMaxKind::I32x4S => SseOpcode::Pmaxsd,
+            V128MinMaxKind::I32x4U => SseOpcode::Pmaxud,
+            V128MinMaxKind::F32x4 | V128MinMaxKind::F64x2 => {
+                return self.v128_float_max(dst, rhs, kind == V128MinMaxKind::F32x4);
+            }
+        };
+        self.asm.xmm_rm_r(rhs, dst, op);
+    }
+
+    fn v128_pmin(&mut self, dst: Reg, lhs: Reg, rhs: Reg, size: OperandSize) {
+        Self::ensure_two_argument_form(&dst, &lhs);
+        let op = match size {
+            OperandSize::S32 => SseOpcode::Minps,
+            OperandSize::S64 => SseOpcode::Minpd,
+            _ => unreachable!(),
+        };
+        // `minps` returns its second operand when the operands are unordered
+        // or equal, which matches `rhs < lhs ? rhs : lhs` when rhs is the
+        // first operand.
+        let scratch = regs::scratch_xmm();
+        self.asm.xmm_mov_rr(rhs, scratch, OperandSize::S128);
+        self.asm.xmm_rm_r(dst, scratch, op);
+        self.asm.xmm_mov_rr(scratch, dst, OperandSize::S128);
+    }
+
+    fn v128_pmax(&mut self, dst: Reg, lhs: Reg, rhs: Reg, size: OperandSize) {
+        Self::ensure_two_argument_form(&dst, &lhs);
+        let op = match size {
+            OperandSize::S32 => SseOpcode::Maxps,
+            OperandSize::S64 => SseOpcode::Maxpd,
+            _ => unreachable!(),
+        };
+        let scratch = regs::scratch_xmm();
+        self.asm.xmm_mov_rr(rhs, scratch, OperandSize::S128);
+        self.asm.xmm_rm_r(dst, scratch, op);
+        self.asm.xmm_mov_rr(scratch, dst, OperandSize::S128);
+    }
+
+    fn v128_avgr_u(&mut self, dst: Reg, lhs: Reg, rhs: Reg, lane_size: OperandSize) {
+        Self::ensure_two_argument_form(&dst, &lhs);
+        let op = match lane_size {
+            OperandSize::S8 => SseOpcode::Pavgb,
+            OperandSize::S16 => SseOpcode::Pavgw,
+            _ => unreachable!(),
+        };
+        self.asm.xmm_rm_r(rhs, dst, op);
+    }
+
+    fn v128_q15mulr_sat_s(&mut self, dst: Reg, lhs: Reg, rhs: Reg) {
+        Self::ensure_two_argument_form(&dst, &lhs);
+        // `pmulhrsw` only overflows for `0x8000 * 0x8000`, producing `0x8000`
+        // instead of `0x7fff`; fix those lanes up.
+        let scratch = regs::scratch_xmm();
+        self.asm.xmm_rm_r(rhs, dst, SseOpcode::Pmulhrsw);
+        self.load_v128_constant(Self::splat_bits(0x8000, OperandSize::S16), scratch);
+        self.asm.xmm_rm_r(dst, scratch, SseOpcode::Pcmpeqw);
+        self.asm.xmm_rm_r(scratch, dst, SseOpcode::Pxor);
+    }
+
+    fn v128_dot(&mut self, dst: Reg, lhs: Reg, rhs: Reg) {
+        Self::ensure_two_argument_form(&dst, &lhs);
+        self.asm.xmm_rm_r(rhs, dst, SseOpcode::Pmaddwd);
+    }
+
+    fn v128_extadd_pairwise(&mut self, src: Reg, dst: Reg, kind: V128ExtAddKind) {
+        let scratch = regs::scratch_xmm();
+        match kind {
+            V128ExtAddKind::I8x16S => {
+                // `pmaddubsw` treats its first operand as unsigned and its
+                // second operand as signed.
+                self.load_v128_constant(Self::splat_bits(1, OperandSize::S8), dst);
+                self.asm.xmm_rm_r(src, dst, SseOpcode::Pmaddubsw);
+            }
+            V128ExtAddKind::I8x16U => {
+                self.asm.xmm_mov_rr(src, dst, OperandSize::S128);
+                self.load_v128_constant(Self::splat_bits(1, OperandSize::S8), scratch);
+                self.asm.xmm_rm_r(scratch, dst, SseOpcode::Pmaddubsw);
+            }
+            V128ExtAddKind::I16x8S => {
+                self.asm.xmm_mov_rr(src, dst, OperandSize::S128);
+                self.load_v128_constant(Self::splat_bits(1, OperandSize::S16), scratch);
+                self.asm.xmm_rm_r(scratch, dst, SseOpcode::Pmaddwd);
+            }
+            V128ExtAddKind::I16x8U => {
+                // Bias the lanes into the signed range, add them pairwise
+                // and undo the bias.
+                self.asm.xmm_mov_rr(src, dst, OperandSize::S128);
+                self.load_v128_constant(Self::splat_bits(0x8000, OperandSize::S16), scratch);
+                self.asm.xmm_rm_r(scratch, dst, SseOpcode::Pxor);
+                self.load_v128_constant(Self::splat_bits(1, OperandSize::S16), scratch);
+                self.asm.xmm_rm_r(scratch, dst, SseOpcode::Pmaddwd);
+                self.load_v128_constant(Self::splat_bits(0x10000, OperandSize::S32), scratch);
+                self.asm.xmm_rm_r(scratch, dst, SseOpcode::Paddd);
+            }
+        }
+    }
+
+    fn v128_abs(&mut self, dst: Reg, shape: VectorShape) {
+        let scratch = regs::scratch_xmm();
+        match shape {
+            VectorShape::I8x16 => self.asm.xmm_unary_rm_r(dst, dst, SseOpcode::Pabsb),
+            VectorShape::I16x8 => self.asm.xmm_unary_rm_r(dst, dst, SseOpcode::Pabsw),
+            VectorShape::I32x4 => self.asm.xmm_unary_rm_r(dst, dst, SseOpcode::Pabsd),
+            VectorShape::I64x2 => {
+                // Broadcast the sign of each lane and compute
+                // `(x ^ sign) - sign`.
+                self.asm
+                    .xmm_unary_rm_r_imm(dst, scratch, 0xf5, SseOpcode::Pshufd);
+                self.asm.xmm_shift_ir(31, scratch, SseOpcode::Psrad);
+                self.asm.xmm_rm_r(scratch, dst, SseOpcode::Pxor);
+                self.asm.xmm_rm_r(scratch, dst, SseOpcode::Psubq);
+            }
+            VectorShape::F32x4 => {
+                self.xmm_all_ones(scratch);
+                self.asm.xmm_shift_ir(1, scratch, SseOpcode::Psrld);
+                self.asm.xmm_rm_r(scratch, dst, SseOpcode::Andps);
+            }
+            VectorShape::F64x2 => {
+                self.xmm_all_ones(scratch);
+                self.asm.xmm_shift_ir(1, scratch, SseOpcode::Psrlq);
+                self.asm.xmm_rm_r(scratch, dst, SseOpcode::Andpd);
+            }
+        }
+    }
+
+    fn v128_neg(&mut self, dst: Reg, shape: VectorShape) {
+        let scratch = regs::scratch_xmm();
+        let sub = match shape {
+            VectorShape::I8x16 => SseOpcode::Psubb,
+            VectorShape::I16x8 => SseOpcode::Psubw,
+            VectorShape::I32x4 => SseOpcode::Psubd,
+            VectorShape::I64x2 => SseOpcode::Psubq,
+            VectorShape::F32x4 => {
+                self.xmm_all_ones(scratch);
+                self.asm.xmm_shift_ir(31, scratch, SseOpcode::Pslld);
+                self.asm.xmm_rm_r(scratch, dst, SseOpcode::Xorps);
+                return;
+            }
+            VectorShape::F64x2 => {
+                self.xmm_all_ones(scratch);
+                self.asm.xmm_shift_ir(63, scratch, SseOpcode::Psllq);
+                self.asm.xmm_rm_r(scratch, dst, SseOpcode::Xorpd);
+                return;
+            }
+        };
+        self.asm.xmm_rm_r(scratch, scratch, SseOpcode::Pxor);
+        self.asm.xmm_rm_r(dst, scratch, sub);
+        self.asm.xmm_mov_rr(scratch, dst, OperandSize::S128);
+    }
+
+    fn v128_popcnt(&mut self, src: Reg, dst: Reg) {
+        let scratch = regs::scratch_xmm();
+        // Look up the bit count of the low and high nibbles of each byte and
+        // add them up.
+        let lookup = i128::from_le_bytes([0, 1, 1, 2, 1, 2, 2, 3, 1, 2, 2, 3, 2, 3, 3, 4]);
+
+        self.load_v128_constant(Self::splat_bits(0x0f, OperandSize::S8), scratch);
+        self.asm.xmm_mov_rr(src, dst, OperandSize::S128);
+        self.asm.xmm_rm_r(scratch, dst, SseOpcode::Pand);
+        self.asm.xmm_shift_ir(4, src, SseOpcode::Psrlw);
+        self.asm.xmm_rm_r(scratch, src, SseOpcode::Pand);
+
+        self.load_v128_constant(lookup, scratch);
+        self.asm.xmm_rm_r(dst, scratch, SseOpcode::Pshufb);
+        self.load_v128_constant(lookup, dst);
+        self.asm.xmm_rm_r(src, dst, SseOpcode::Pshufb);
+        self.asm.xmm_rm_r(scratch, dst, SseOpcode::Paddb);
+    }
+
+    fn v128_sqrt(&mut self, dst: Reg, src: Reg, size: OperandSize) {
+        let op = match size {
+            OperandSize::S32 => SseOpcode::Sqrtps,
+            OperandSize::S64 => SseOpcode::Sqrtpd,
+            _ => unreachable!(),
+        };
+        self.asm.xmm_unary_rm_r(src, dst, op);
+    }
+
+    fn v128_round(&mut self, dst: Reg, src: Reg, mode: RoundingMode, size: OperandSize) {
+        let op = match size {
+            OperandSize::S32 => SseOpcode::Roundps,
+            OperandSize::S64 => SseOpcode::Roundpd,
+            _ => unreachable!(),
+        };
+        let imm = match mode {
+            RoundingMode::Nearest => 0x00,
+            RoundingMode::Down => 0x01,
+            RoundingMode::Up => 0x02,
+            RoundingMode::Zero => 0x03,
+        };
+        self.asm.xmm_unary_rm_r_imm(src, dst, imm, op);
+    }
+
+    fn v128_shift(
+        &mut self,
+        context: &mut CodeGenContext,
+        lane_size: OperandSize,
+        kind: ShiftKind,
+    ) {
+        let mask = lane_size.num_bits() - 1;
+        let top = context.stack.peek().expect("value at stack top");
+        let amount = if top.is_i32_const() {
+            let val = context
+                .stack
+                .pop_i32_const()
+                .expect("i32 const value at stack top");
+            RegImm::i32(val & mask)
+        } else {
+            let amount = context.pop_to_reg(self, None);
+            self.asm.and_ir(mask, amount.into(), OperandSize::S32);
+            RegImm::reg(amount.into())
+        };
+        let dst: Reg = context.pop_to_reg(self, None).into();
+
+        use OperandSize::*;
+        use ShiftKind::*;
+        match (lane_size, kind) {
+            (S8, kind @ (Shl | ShrU)) => {
+                // There are no 8-bit lane shifts; shift 16-bit lanes and clear
+                // the bits shifted in from the neighbouring byte.
+                let (op, index) = if matches!(kind, Shl) {
+                    (SseOpcode::Psllw, 0)
+                } else {
+                    (SseOpcode::Psrlw, 1)
+                };
+                let tmp = context.reg_for_class(RegClass::Float, self);
+                self.xmm_shift(amount, dst, op);
+                self.xmm_all_ones(tmp);
+                self.xmm_shift(amount, tmp, op);
+                let scratch = regs::scratch_xmm();
+                self.load_v128_constant(Self::splat_bits(index, S8), scratch);
+                self.asm.xmm_rm_r(scratch, tmp, SseOpcode::Pshufb);
+                self.asm.xmm_rm_r(tmp, dst, SseOpcode::Pand);
+                context.free_reg(tmp);
+            }
+            (S8, ShrS) => {
+                // Widen each byte to the high half of a 16-bit lane, shift
+                // arithmetically and narrow back.
+                let amount = match amount {
+                    RegImm::Imm(imm) => RegImm::i32(imm.to_i32().unwrap() + 8),
+                    RegImm::Reg(reg) => {
+                        self.asm.add_ir(8, reg, S32);
+                        amount
+                    }
+                };
+                let tmp = context.reg_for_class(RegClass::Float, self);
+                self.asm.xmm_mov_rr(dst, tmp, S128);
+                self.asm.xmm_rm_r(dst, dst, SseOpcode::Punpcklbw);
+                self.asm.xmm_rm_r(tmp, tmp, SseOpcode::Punpckhbw);
+                self.xmm_shift(amount, dst, SseOpcode::Psraw);
+                self.xmm_shift(amount, tmp, SseOpcode::Psraw);
+                self.asm.xmm_rm_r(tmp, dst, SseOpcode::Packsswb);
+                context.free_reg(tmp);
+            }
+            (S64, ShrS) => {
+                // There's no 64-bit arithmetic shift before AVX-512; shift
+                // logically and sign extend with `(x ^ m) - m`, where `m` is
+                // the shifted sign bit.
+                let tmp = context.reg_for_class(RegClass::Float, self);
+                self.xmm_shift(amount, dst, SseOpcode::Psrlq);
+                self.load_v128_constant(Self::splat_bits(0x8000000000000000, S64), tmp);
+                self.xmm_shift(amount, tmp, SseOpcode::Psrlq);
+                self.asm.xmm_rm_r(tmp, dst, SseOpcode::Pxor);
+                self.asm.xmm_rm_r(tmp, dst, SseOpcode::Psubq);
+                context.free_reg(tmp);
+            }
+            (S16, Shl) => self.xmm_shift(amount, dst, SseOpcode::Psllw),
+            (S32, Shl) => self.xmm_shift(amount, dst, SseOpcode::Pslld),
+            (S64, Shl) => self.xmm_shift(amount, dst, SseOpcode::Psllq),
+            (S16, ShrU) => self.xmm_shift(amount, dst, SseOpcode::Psrlw),
+            (S32, ShrU) => self.xmm_shift(amount, dst, SseOpcode::Psrld),
+            (S64, ShrU) => self.xmm_shift(amount, dst, SseOpcode::Psrlq),
+            (S16, ShrS) => self.xmm_shift(amount, dst, SseOpcode::Psraw),
+            (S32, ShrS) => self.xmm_shift(amount, dst, SseOpcode::Psrad),
+            _ => unreachable!(),
+        }
+
+        if let RegImm::Reg(reg) = amount {
+            context.free_reg(reg);
+        }
+        context.stack.push(TypedReg::v128(dst).into());
+    }
+
+    fn v128_int_cmp(
+        &mut self,
+        dst: Reg,
+        lhs: Reg,
+        rhs: Reg,
+        kind: IntCmpKind,
+        lane_size: OperandSize,
+    ) {
+        Self::ensure_two_argument_form(&dst, &lhs);
+        use OperandSize::*;
+        let scratch = regs::scratch_xmm();
+        let eq = Self::pcmpeq_op(lane_size);
+        let gt = match lane_size {
+            S8 => SseOpcode::Pcmpgtb,
+            S16 => SseOpcode::Pcmpgtw,
+            S32 => SseOpcode::Pcmpgtd,
+            S64 => SseOpcode::Pcmpgtq,
+            S128 => unreachable!(),
+        };
+        // Unsigned comparisons and non-strict signed comparisons are
+        // expressed as `max(lhs, rhs) == lhs` or `min(lhs, rhs) == lhs`.
+        let minmax = |signed: bool, max: bool| match (lane_size, signed, max) {
+            (S8, true, true) => SseOpcode::Pmaxsb,
+            (S8, false, true) => SseOpcode::Pmaxub,
+            (S16, true, true) => SseOpcode::Pmaxsw,
+            (S16, false, true) => SseOpcode::Pmaxuw,
+            (S32, true, true) => SseOpcode::Pmaxsd,
+            (S32, false, true) => SseOpcode::Pmaxud,
+            (S8, true, false) => SseOpcode::Pminsb,
+            (S8, false, false) => SseOpcode::Pminub,
+            (S16, true, false) => SseOpcode::Pminsw,
+            (S16, false, false) => SseOpcode::Pminuw,
+            (S32, true, false) => SseOpcode::Pminsd,
+            (S32, false, false) => SseOpcode::Pminud,
+            _ => unimplemented!("No {lane_size:?} lane min/max"),
+        };
+
+        match kind {
+            IntCmpKind::Eq => self.asm.xmm_rm_r(rhs, dst, eq),
+            IntCmpKind::Ne => {
+                self.asm.xmm_rm_r(rhs, dst, eq);
+                self.v128_not(dst);
+            }
+            IntCmpKind::GtS => self.asm.xmm_rm_r(rhs, dst, gt),
+            IntCmpKind::LtS => {
+                self.asm.xmm_rm_r(lhs, rhs, gt);
+                self.asm.xmm_mov_rr(rhs, dst, S128);
+            }
+            IntCmpKind::GeS if lane_size == S64 => {
+                self.asm.xmm_rm_r(lhs, rhs, gt);
+                self.asm.xmm_mov_rr(rhs, dst, S128);
+                self.v128_not(dst);
+            }
+            IntCmpKind::LeS if lane_size == S64 => {
+                self.asm.xmm_rm_r(rhs, dst, gt);
+                self.v128_not(dst);
+            }
+            IntCmpKind::GeS | IntCmpKind::GeU | IntCmpKind::LtU => {
+                let signed = kind == IntCmpKind::GeS;
+                self.asm.xmm_mov_rr(dst, scratch, S128);
+                self.asm.xmm_rm_r(rhs, scratch, minmax(signed, true));
+                self.asm.xmm_rm_r(scratch, dst, eq);
+                if kind == IntCmpKind::LtU {
+                    self.v128_not(dst);
+                }
+            }
+            IntCmpKind::LeS | IntCmpKind::LeU | IntCmpKind::GtU => {
+                let signed = kind == IntCmpKind::LeS;
+                self.asm.xmm_mov_rr(dst, scratch, S128);
+                self.asm.xmm_rm_r(rhs, scratch, minmax(signed, false));
+                self.asm.xmm_rm_r(scratch, dst, eq);
+                if kind == IntCmpKind::GtU {
+                    self.v128_not(dst);
+                }
+            }
+        }
+    }
+
+    fn v128_float_cmp(
+        &mut self,
+        dst: Reg,
+        lhs: Reg,
+        rhs: Reg,
+        kind: FloatCmpKind,
+        size: OperandSize,
+    ) {
+        Self::ensure_two_argument_form(&dst, &lhs);
+        let op = match size {
+            OperandSize::S32 => SseOpcode::Cmpps,
+            OperandSize::S64 => SseOpcode::Cmppd,
+            _ => unreachable!(),
+        };
+        // Comparison predicates, as defined for `cmpps`.
+        let (imm, swap) = match kind {
+            FloatCmpKind::Eq => (0, false),
+            FloatCmpKind::Lt => (1, false),
+            FloatCmpKind::Le => (2, false),
+            FloatCmpKind::Ne => (4, false),
+            FloatCmpKind::Gt => (1, true),
+            FloatCmpKind::Ge => (2, true),
+        };
+        if swap {
+            let scratch = regs::scratch_xmm();
+            self.asm.xmm_mov_rr(rhs, scratch, OperandSize::S128);
+            self.asm
+                .xmm_rm_r_imm(dst, scratch, imm, op, OperandSize::S32);
+            self.asm.xmm_mov_rr(scratch, dst, OperandSize::S128);
+        } else {
+            self.asm.xmm_rm_r_imm(rhs, dst, imm, op, OperandSize::S32);
+        }
+    }
+
+    fn v128_not(&mut self, dst: Reg) {
+        let scratch = regs::scratch_xmm();
+        self.xmm_all_ones(scratch);
+        self.asm.xmm_rm_r(scratch, dst, SseOpcode::Pxor);
+    }
+
+    fn v128_and(&mut self, dst: Reg, lhs: Reg, rhs: Reg) {
+        Self::ensure_two_argument_form(&dst, &lhs);
+        self.asm.xmm_rm_r(rhs, dst, SseOpcode::Pand);
+    }
+
+    fn v128_and_not(&mut self, dst: Reg, lhs: Reg, rhs: Reg) {
+        Self::ensure_two_argument_form(&dst, &lhs);
+        // `pandn` negates its destination operand.
+        self.asm.xmm_rm_r(lhs, rhs, SseOpcode::Pandn);
+        self.asm.xmm_mov_rr(rhs, dst, OperandSize::S128);
+    }
+
+    fn v128_or(&mut self, dst: Reg, lhs: Reg, rhs: Reg) {
+        Self::ensure_two_argument_form(&dst, &lhs);
+        self.asm.xmm_rm_r(rhs, dst, SseOpcode::Por);
+    }
+
+    fn v128_xor(&mut self, dst: Reg, lhs: Reg, rhs: Reg) {
+        Self::ensure_two_argument_form(&dst, &lhs);
+        self.asm.xmm_rm_r(rhs, dst, SseOpcode::Pxor);
+    }
+
+    fn v128_bitselect(&mut self, dst: Reg, lhs: Reg, rhs: Reg, mask: Reg) {
+        Self::ensure_two_argument_form(&dst, &lhs);
+        self.asm.xmm_rm_r(mask, dst, SseOpcode::Pand);
+        self.asm.xmm_rm_r(rhs, mask, SseOpcode::Pandn);
+        self.asm.xmm_rm_r(mask, dst, SseOpcode::Por);
+    }
+
+    fn v128_any_true(&mut self, src: Reg, dst: Reg) {
+        self.asm.ptest(src, src);
+        self.asm.setcc(IntCmpKind::Ne, dst);
+    }
+
+    fn v128_all_true(&mut self, src: Reg, dst: Reg, lane_size: OperandSize) {
+        // Set the lanes that are zero and check that none is set.
+        let scratch = regs::scratch_xmm();
+        self.asm.xmm_rm_r(scratch, scratch, SseOpcode::Pxor);
+        self.asm.xmm_rm_r(src, scratch, Self::pcmpeq_op(lane_size));
+        self.asm.ptest(scratch, scratch);
+        self.asm.setcc(IntCmpKind::Eq, dst);
+    }
+
+    fn v128_bitmask(&mut self, src: Reg, dst: Reg, lane_size: OperandSize) {
+        match lane_size {
+            OperandSize::S8 => self.asm.xmm_movmsk(src, dst, SseOpcode::Pmovmskb),
+            OperandSize::S16 => {
+                // Narrow the lanes to bytes, preserving their sign, and take
+                // the high half of the byte mask.
+                let scratch = regs::scratch_xmm();
+                self.asm.xmm_mov_rr(src, scratch, OperandSize::S128);
+                self.asm.xmm_rm_r(src, scratch, SseOpcode::Packsswb);
+                self.asm.xmm_movmsk(scratch, dst, SseOpcode::Pmovmskb);
+                self.asm.shift_ir(8, dst, ShiftKind::ShrU, OperandSize::S32);
+            }
+            OperandSize::S32 => self.asm.xmm_movmsk(src, dst, SseOpcode::Movmskps),
+            OperandSize::S64 => self.asm.xmm_movmsk(src, dst, SseOpcode::Movmskpd),
+            OperandSize::S128 => unreachable!(),
+        }
+    }
+
+    fn v128_extend(&mut self, src: Reg, dst: Reg, kind: V128ExtendKind) {
+        use V128ExtendKind::*;
+        let op = match kind {
+            LowI8x16S | HighI8x16S => SseOpcode::Pmovsxbw,
+            LowI8x16U | HighI8x16U => SseOpcode::Pmovzxbw,
+            LowI16x8S | HighI16x8S => SseOpcode::Pmovsxwd,
+            LowI16x8U | HighI16x8U => SseOpcode::Pmovzxwd,
+            LowI32x4S | HighI32x4S => SseOpcode::Pmovsxdq,
+            LowI32x4U | HighI32x4U => SseOpcode::Pmovzxdq,
+        };
+        if kind.is_high() {
+            // Move the high half to the low half first.
+            self.asm
+                .xmm_unary_rm_r_imm(src, dst, 0xee, SseOpcode::Pshufd);
+            self.asm.xmm_unary_rm_r(dst, dst, op);
+        } else {
+            self.asm.xmm_unary_rm_r(src, dst, op);
+        }
+    }
+
+    fn v128_narrow(&mut self, dst: Reg, lhs: Reg, rhs: Reg, kind: V128NarrowKind) {
+        Self::ensure_two_argument_form(&dst, &lhs);
+        let op = match kind {
+            V128NarrowKind::I16x8S => SseOpcode::Packsswb,
+            V128NarrowKind::I16x8U => SseOpcode::Packuswb,
+            V128NarrowKind::I32x4S => SseOpcode::Packssdw,
+            V128NarrowKind::I32x4U => SseOpcode::Packusdw,
+        };
+        self.asm.xmm_rm_r(rhs, dst, op);
+    }
+
+    fn v128_convert(&mut self, src: Reg, dst: Reg, kind: V128ConvertKind) {
+        let scratch = regs::scratch_xmm();
+        match kind {
+            V128ConvertKind::I32x4S => self.asm.xmm_unary_rm_r(src, dst, SseOpcode::Cvtdq2ps),
+            V128ConvertKind::I32x4U => {
+                // Convert the low 16 bits exactly, and the high 16 bits
+                // halved so that they fit in the signed range, then add both
+                // halves back together.
+                self.asm.xmm_mov_rr(src, dst, OperandSize::S128);
+                self.asm.xmm_shift_ir(16, dst, SseOpcode::Pslld);
+                self.asm.xmm_shift_ir(16, dst, SseOpcode::Psrld);
+                self.asm.xmm_rm_r(dst, src, SseOpcode::Psubd);
+                self.asm.xmm_unary_rm_r(dst, dst, SseOpcode::Cvtdq2ps);
+                self.asm.xmm_shift_ir(1, src, SseOpcode::Psrld);
+                self.asm.xmm_unary_rm_r(src, src, SseOpcode::Cvtdq2ps);
+                self.asm.xmm_rm_r(src, src, SseOpcode::Addps);
+                self.asm.xmm_rm_r(src, dst, SseOpcode::Addps);
+            }
+            V128ConvertKind::I32x4LowS => self.asm.xmm_unary_rm_r(src, dst, SseOpcode::Cvtdq2pd),
+            V128ConvertKind::I32x4LowU => {
+                // Build the doubles `2^52 + x` by using each integer as the
+                // low half of the mantissa, and subtract `2^52`.
+                self.asm.xmm_mov_rr(src, dst, OperandSize::S128);
+                self.load_v128_constant(Self::splat_bits(0x43300000, OperandSize::S32), scratch);
+                self.asm.xmm_rm_r(scratch, dst, SseOpcode::Unpcklps);
+                self.load_v128_constant(
+                    Self::splat_bits(0x4330000000000000, OperandSize::S64),
+                    scratch,
+                );
+                self.asm.xmm_rm_r(scratch, dst, SseOpcode::Subpd);
+            }
+        }
+    }
+
+    fn v128_trunc_sat(&mut self, src: Reg, dst: Reg, kind: V128TruncSatKind) {
+        let scratch = regs::scratch_xmm();
+        match kind {
+            V128TruncSatKind::F32x4S => {
+                // Zero the NaN lanes.
+                self.asm.xmm_mov_rr(src, scratch, OperandSize::S128);
+                self.asm
+                    .xmm_rm_r_imm(scratch, scratch, 0, SseOpcode::Cmpps, OperandSize::S32);
+                self.asm.xmm_rm_r(scratch, src, SseOpcode::Andps);
+                // Keep track of the positive lanes.
+                self.asm.xmm_rm_r(src, scratch, SseOpcode::Pxor);
+                // Out of range lanes are converted to `0x80000000`; turn the
+                // positive ones into `0x7fffffff`.
+                self.asm.xmm_unary_rm_r(src, dst, SseOpcode::Cvttps2dq);
+                self.asm.xmm_rm_r(dst, scratch, SseOpcode::Pand);
+                self.asm.xmm_shift_ir(31, scratch, SseOpcode::Psrad);
+                self.asm.xmm_rm_r(scratch, dst, SseOpcode::Pxor);
+            }
+            V128TruncSatKind::F32x4U => {
+                // Clamp NaN and negative lanes to zero.
+                self.asm.xmm_rm_r(scratch, scratch, SseOpcode::Xorps);
+                self.asm.xmm_rm_r(scratch, src, SseOpcode::Maxps);
+                // Build `2^31` in each lane.
+                self.xmm_all_ones(scratch);
+                self.asm.xmm_shift_ir(1, scratch, SseOpcode::Psrld);
+                self.asm
+                    .xmm_unary_rm_r(scratch, scratch, SseOpcode::Cvtdq2ps);
+                // Convert the lanes in the signed range.
+                self.asm.xmm_unary_rm_r(src, dst, SseOpcode::Cvttps2dq);
+                // Convert the lanes above `2^31`, after subtracting it.
+                self.asm.xmm_rm_r(scratch, src, SseOpcode::Subps);
+                self.asm
+                    .xmm_rm_r_imm(src, scratch, 2, SseOpcode::Cmpps, OperandSize::S32);
+                self.asm.xmm_unary_rm_r(src, src, SseOpcode::Cvttps2dq);
+                // Saturate the lanes above `2^32`, and add both conversions.
+                self.asm.xmm_rm_r(scratch, src, SseOpcode::Pxor);
+                self.asm.xmm_rm_r(scratch, scratch, SseOpcode::Pxor);
+                self.asm.xmm_rm_r(scratch, src, SseOpcode::Pmaxsd);
+                self.asm.xmm_rm_r(src, dst, SseOpcode::Paddd);
+            }
+            V128TruncSatKind::F64x2SZero => {
+                // Clamp the lanes to the maximum signed value, turning NaNs
+                // into zero.
+                self.asm.xmm_mov_rr(src, scratch, OperandSize::S128);
+                self.asm
+                    .xmm_rm_r_imm(scratch, scratch, 0, SseOpcode::Cmppd, OperandSize::S32);
+                self.load_v128_constant(
+                    Self::splat_bits(2147483647.0f64.to_bits(), OperandSize::S64),
+                    dst,
+                );
+                self.asm.xmm_rm_r(dst, scratch, SseOpcode::Andpd);
+                self.asm.xmm_rm_r(scratch, src, SseOpcode::Minpd);
+                self.asm.xmm_unary_rm_r(src, dst, SseOpcode::Cvttpd2dq);
+            }
+            V128TruncSatKind::F64x2UZero => {
+                // Clamp the lanes to the unsigned range, turning NaNs into
+                // zero, and truncate them.
+                self.asm.xmm_rm_r(scratch, scratch, SseOpcode::Xorpd);
+                self.asm.xmm_rm_r(scratch, src, SseOpcode::Maxpd);
+                self.load_v128_constant(
+                    Self::splat_bits(4294967295.0f64.to_bits(), OperandSize::S64),
+                    dst,
+                );
+                self.asm.xmm_rm_r(dst, src, SseOpcode::Minpd);
+                self.asm
+                    .xmm_unary_rm_r_imm(src, src, 0x03, SseOpcode::Roundpd);
+                // Adding `2^52` leaves the integer in the low half of the
+                // mantissa; gather the low halves, zeroing the high lanes.
+                self.load_v128_constant(
+                    Self::splat_bits(0x4330000000000000, OperandSize::S64),
+                    dst,
+                );
+                self.asm.xmm_rm_r(dst, src, SseOpcode::Addpd);
+                self.asm
+                    .xmm_rm_r_imm(scratch, src, 0x88, SseOpcode::Shufps, OperandSize::S32);
+                self.asm.xmm_mov_rr(src, dst, OperandSize::S128);
+            }
+        }
+    }
+
+    fn v128_demote(&mut self, src: Reg, dst: Reg) {
+        self.asm.xmm_unary_rm_r(src, dst, SseOpcode::Cvtpd2ps);
+    }
+
+    fn v128_promote(&mut self, src: Reg, dst: Reg) {
+        self.asm.xmm_unary_rm_r(src, dst, SseOpcode::Cvtps2pd);
+    }
+
     fn unreachable(&mut self) {
         self.asm.trap(TrapCode::UnreachableCodeReached)
     }
 
+    fn trap(&mut self, code: TrapCode) {
+        self.asm.trap(code);
+    }
+
     fn trapif(&mut self, cc: IntCmpKind, code: TrapCode) {
         self.asm.trapif(cc, code);
     }
@@ -1127,6 +2158,13 @@ impl MacroAssembler {
         }
     }
 
+    /// Returns the size of the stack slot used to spill a value of the
+    /// given size. Spilled values use at least a full word, which keeps all
+    /// the slots in the value stack word-aligned, as required by stack maps.
+    fn stack_slot_size(size: OperandSize) -> u32 {
+        size.bytes().max(<X64ABI as ABI>::word_bytes())
+    }
+
     fn increment_sp(&mut self, bytes: u32) {
         self.sp_offset += bytes;
     }
@@ -1149,6 +2187,178 @@ impl MacroAssembler {
         }
     }
 
+    fn store_impl(&mut self, src: RegImm, dst: Address, size: OperandSize, flags: MemFlags) {
+        let scratch = <X64ABI as ABI>::scratch_reg();
+        let float_scratch = <X64ABI as ABI>::float_scratch_reg();
+        match src {
+            RegImm::Imm(imm) => match imm {
+                I::I32(v) => self.asm.mov_im(v as i32, &dst, size, flags),
+                I::I64(v) => match v.try_into() {
+                    Ok(v) => self.asm.mov_im(v, &dst, size, flags),
+                    Err(_) => {
+                        // If the immediate doesn't sign extend, use a scratch
+                        // register.
+                        self.asm.mov_ir(v, scratch, size);
+                        self.asm.mov_rm(scratch, &dst, size, flags);
+                    }
+                },
+                I::F32(v) => {
+                    let addr = self.asm.add_constant(v.to_le_bytes().as_slice());
+                    self.asm
+                        .xmm_mov_mr(&addr, float_scratch, size, MemFlags::trusted());
+                    self.asm.xmm_mov_rm(float_scratch, &dst, size, flags);
+                }
+                I::F64(v) => {
+                    let addr = self.asm.add_constant(v.to_le_bytes().as_slice());
+                    self.asm
+                        .xmm_mov_mr(&addr, float_scratch, size, MemFlags::trusted());
+                    self.asm.xmm_mov_rm(float_scratch, &dst, size, flags);
+                }
+                I::V128(v) => {
+                    self.load_v128_constant(v, float_scratch);
+                    self.asm.xmm_mov_rm(float_scratch, &dst, size, flags);
+                }
+            },
+            RegImm::Reg(reg) => {
+                if reg.is_int() {
+                    self.asm.mov_rm(reg, &dst, size, flags);
+                } else {
+                    self.asm.xmm_mov_rm(reg, &dst, size, flags);
+                }
+            }
+        }
+    }
+
+    /// Lane-wise floating point minimum, following the Wasm semantics for
+    /// signed zeros and NaNs, which differ from `minps`: `minps` returns its
+    /// second operand when the operands are unordered or both zero.
+    fn v128_float_min(&mut self, dst: Reg, rhs: Reg, is_f32: bool) {
+        let (min, or, cmp, andn, shift, bits) = if is_f32 {
+            use SseOpcode::*;
+            (Minps, Orps, Cmpps, Andnps, Psrld, 10)
+        } else {
+            use SseOpcode::*;
+            (Minpd, Orpd, Cmppd, Andnpd, Psrlq, 13)
+        };
+        let scratch = regs::scratch_xmm();
+        // Compute the minimum in both directions and combine them, which
+        // propagates NaNs and selects -0.0 over 0.0.
+        self.asm.xmm_mov_rr(rhs, scratch, OperandSize::S128);
+        self.asm.xmm_rm_r(dst, scratch, min);
+        self.asm.xmm_rm_r(rhs, dst, min);
+        self.asm.xmm_rm_r(scratch, dst, or);
+        // Canonicalize the NaN lanes.
+        self.asm
+            .xmm_rm_r_imm(dst, scratch, 3, cmp, OperandSize::S32);
+        self.asm.xmm_rm_r(scratch, dst, or);
+        self.asm.xmm_shift_ir(bits, scratch, shift);
+        self.asm.xmm_rm_r(dst, scratch, andn);
+        self.asm.xmm_mov_rr(scratch, dst, OperandSize::S128);
+    }
+
+    /// Lane-wise floating point maximum, following the Wasm semantics for
+    /// signed zeros and NaNs.
+    /// The contents of rhs are clobbered.
+    fn v128_float_max(&mut self, dst: Reg, rhs: Reg, is_f32: bool) {
+        let (max, xor, or, sub, cmp, andn, shift, bits) = if is_f32 {
+            use SseOpcode::*;
+            (Maxps, Xorps, Orps, Subps, Cmpps, Andnps, Psrld, 10)
+        } else {
+            use SseOpcode::*;
+            (Maxpd, Xorpd, Orpd, Subpd, Cmppd, Andnpd, Psrlq, 13)
+        };
+        let scratch = regs::scratch_xmm();
+        // Compute the maximum in both directions; the lanes in which they
+        // differ are either NaNs or zeros of different sign.
+        self.asm.xmm_mov_rr(rhs, scratch, OperandSize::S128);
+        self.asm.xmm_rm_r(dst, scratch, max);
+        self.asm.xmm_rm_r(rhs, dst, max);
+        self.asm.xmm_mov_rr(dst, rhs, OperandSize::S128);
+        self.asm.xmm_rm_r(scratch, rhs, xor);
+        // Propagate NaNs and turn -0.0 into 0.0.
+        self.asm.xmm_rm_r(rhs, dst, or);
+        self.asm.xmm_mov_rr(dst, scratch, OperandSize::S128);
+        self.asm.xmm_rm_r(rhs, dst, sub);
+        // Canonicalize the NaN lanes.
+        self.asm
+            .xmm_rm_r_imm(scratch, scratch, 3, cmp, OperandSize::S32);
+        self.asm.xmm_shift_ir(bits, scratch, shift);
+        self.asm.xmm_rm_r(dst, scratch, andn);
+        self.asm.xmm_mov_rr(scratch, dst, OperandSize::S128);
+    }
+
+    /// Loads a 128-bit constant from the constant pool into the given vector
+    /// register.
+    fn load_v128_constant(&mut self, bits: i128, dst: Reg) {
+        let addr = self.asm.add_constant(bits.to_le_bytes().as_slice());
+        self.asm
+            .xmm_mov_mr(&addr, dst, OperandSize::S128, MemFlags::trusted());
+    }
+
+    /// Replicates the given bits to all the lanes of a 128-bit constant.
+    fn splat_bits(bits: u64, lane_size: OperandSize) -> i128 {
+        let lane_bits = lane_size.num_bits() as u32;
+        let mask = u128::MAX >> (128 - lane_bits);
+        let lane = bits as u128 & mask;
+        let splat = (0..128 / lane_bits).fold(0u128, |acc, i| acc | (lane << (i * lane_bits)));
+        splat as i128
+    }
+
+    /// Shifts the lanes of the given vector register by either an immediate
+    /// or the amount held in a general purpose register.
+    /// Clobbers the vector scratch register when the amount is a register.
+    fn xmm_shift(&mut self, amount: RegImm, dst: Reg, op: SseOpcode) {
+        match amount {
+            RegImm::Imm(imm) => {
+                let imm = imm.to_i32().expect("valid shift amount");
+                self.asm.xmm_shift_ir(imm as u8, dst, op);
+            }
+            RegImm::Reg(reg) => {
+                let scratch = regs::scratch_xmm();
+                self.asm.gpr_to_xmm(reg, scratch, OperandSize::S32);
+                self.asm.xmm_shift_rr(scratch, dst, op);
+            }
+        }
+    }
+
+    /// Sets all the bits of the given vector register.
+    fn xmm_all_ones(&mut self, dst: Reg) {
+        self.asm.xmm_rm_r(dst, dst, SseOpcode::Pcmpeqd);
+    }
+
+    /// Integer vector equality comparison for the given lane size.
+    fn pcmpeq_op(lane_size: OperandSize) -> SseOpcode {
+        match lane_size {
+            OperandSize::S8 => SseOpcode::Pcmpeqb,
+            OperandSize::S16 => SseOpcode::Pcmpeqw,
+            OperandSize::S32 => SseOpcode::Pcmpeqd,
+            OperandSize::S64 => SseOpcode::Pcmpeqq,
+            OperandSize::S128 => unreachable!(),
+        }
+    }
+
+    /// Moves the given value to a different register if it's held in rax,
+    /// so that rax can be allocated for instructions with fixed register
+    /// requirements.
+    fn evict_rax(&mut self, context: &mut CodeGenContext, reg: TypedReg) -> TypedReg {
+        if reg.reg != regs::rax() {
+            return reg;
+        }
+
+        let dst = context.any_gpr(self);
+        self.asm.mov_rr(reg.reg, dst, OperandSize::S64);
+        context.free_reg(reg.reg);
+        TypedReg::new(reg.ty, dst)
+    }
+
+    fn load_impl(&mut self, src: Address, dst: Reg, size: OperandSize, flags: MemFlags) {
+        if dst.is_int() {
+            self.asm.mov_mr(&src, dst, size, flags);
+        } else {
+            self.asm.xmm_mov_mr(&src, dst, size, flags);
+        }
+    }
+
     fn handle_invalid_operand_combination<T>(src: impl Into<RegImm>, dst: impl Into<RegImm>) -> T {
         panic!(
             "Invalid operand combination; src={:?}, dst={:?}",
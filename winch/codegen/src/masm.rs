@@ -3,7 +3,7 @@ use crate::codegen::{ptr_type_from_ptr_size, CodeGenContext, HeapData, TableData
 use crate::isa::reg::Reg;
 use cranelift_codegen::{ir::LibCall, Final, MachBufferFinalized, MachLabel};
 use std::{fmt::Debug, ops::Range};
-use wasmtime_environ::PtrSize;
+use wasmtime_environ::{PtrSize, WasmType};
 
 pub(crate) use cranelift_codegen::ir::TrapCode;
 
@@ -95,7 +95,7 @@ pub(crate) enum IntCmpKind {
 /// Kinds of float binary comparison in WebAssembly. The [`MacroAssembler`]
 /// implementation for each ISA is responsible for emitting the correct
 /// sequence of instructions when lowering code.
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy)]
 pub(crate) enum FloatCmpKind {
     /// Equal.
     Eq,
@@ -130,6 +130,7 @@ pub(crate) enum ShiftKind {
 /// Kinds of extends in WebAssembly. The [`masm`] implementation for each ISA
 /// is responsible for emitting the correct sequence of instructions when
 /// lowering to machine code.
+#[derive(Clone, Copy)]
 pub(crate) enum ExtendKind {
     /// Sign extends i32 to i64.
     I64ExtendI32S,
@@ -147,9 +148,253 @@ pub(crate) enum ExtendKind {
     I64Extend32S,
 }
 
+/// Kinds of atomic read-modify-write operations in WebAssembly. The
+/// [`masm`] implementation for each ISA is responsible for emitting the
+/// correct sequence of instructions when lowering to machine code.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub(crate) enum RmwOp {
+    /// Wrapping addition.
+    Add,
+    /// Wrapping subtraction.
+    Sub,
+    /// Bitwise and.
+    And,
+    /// Bitwise or.
+    Or,
+    /// Bitwise exclusive or.
+    Xor,
+    /// Exchange.
+    Xchg,
+}
+
+/// Shapes of 128-bit vectors in WebAssembly, describing the type and the
+/// number of the vector lanes.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub(crate) enum VectorShape {
+    /// 16 lanes of 8 bits.
+    I8x16,
+    /// 8 lanes of 16 bits.
+    I16x8,
+    /// 4 lanes of 32 bits.
+    I32x4,
+    /// 2 lanes of 64 bits.
+    I64x2,
+    /// 4 lanes of single precision floats.
+    F32x4,
+    /// 2 lanes of double precision floats.
+    F64x2,
+}
+
+impl VectorShape {
+    /// The size of each lane.
+    pub fn lane_size(&self) -> OperandSize {
+        match self {
+            Self::I8x16 => OperandSize::S8,
+            Self::I16x8 => OperandSize::S16,
+            Self::I32x4 | Self::F32x4 => OperandSize::S32,
+            Self::I64x2 | Self::F64x2 => OperandSize::S64,
+        }
+    }
+
+    /// The WebAssembly type used to represent a single lane as a scalar
+    /// value, e.g. when extracting or replacing lanes.
+    pub fn lane_type(&self) -> WasmType {
+        match self {
+            Self::I8x16 | Self::I16x8 | Self::I32x4 => WasmType::I32,
+            Self::I64x2 => WasmType::I64,
+            Self::F32x4 => WasmType::F32,
+            Self::F64x2 => WasmType::F64,
+        }
+    }
+}
+
+/// Kinds of vector addition in WebAssembly.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub(crate) enum V128AddKind {
+    /// f32x4 addition.
+    F32x4,
+    /// f64x2 addition.
+    F64x2,
+    /// i8x16 wrapping addition.
+    I8x16,
+    /// i8x16 signed saturating addition.
+    I8x16SatS,
+    /// i8x16 unsigned saturating addition.
+    I8x16SatU,
+    /// i16x8 wrapping addition.
+    I16x8,
+    /// i16x8 signed saturating addition.
+    I16x8SatS,
+    /// i16x8 unsigned saturating addition.
+    I16x8SatU,
+    /// i32x4 wrapping addition.
+    I32x4,
+    /// i64x2 wrapping addition.
+    I64x2,
+}
+
+/// Kinds of vector subtraction in WebAssembly.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub(crate) enum V128SubKind {
+    /// f32x4 subtraction.
+    F32x4,
+    /// f64x2 subtraction.
+    F64x2,
+    /// i8x16 wrapping subtraction.
+    I8x16,
+    /// i8x16 signed saturating subtraction.
+    I8x16SatS,
+    /// i8x16 unsigned saturating subtraction.
+    I8x16SatU,
+    /// i16x8 wrapping subtraction.
+    I16x8,
+    /// i16x8 signed saturating subtraction.
+    I16x8SatS,
+    /// i16x8 unsigned saturating subtraction.
+    I16x8SatU,
+    /// i32x4 wrapping subtraction.
+    I32x4,
+    /// i64x2 wrapping subtraction.
+    I64x2,
+}
+
+/// Kinds of vector multiplication in WebAssembly.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub(crate) enum V128MulKind {
+    /// f32x4 multiplication.
+    F32x4,
+    /// f64x2 multiplication.
+    F64x2,
+    /// i16x8 wrapping multiplication.
+    I16x8,
+    /// i32x4 wrapping multiplication.
+    I32x4,
+    /// i64x2 wrapping multiplication.
+    I64x2,
+}
+
+/// Kinds of vector minimum and maximum in WebAssembly.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub(crate) enum V128MinMaxKind {
+    /// f32x4, propagating NaNs.
+    F32x4,
+    /// f64x2, propagating NaNs.
+    F64x2,
+    /// Signed i8x16.
+    I8x16S,
+    /// Unsigned i8x16.
+    I8x16U,
+    /// Signed i16x8.
+    I16x8S,
+    /// Unsigned i16x8.
+    I16x8U,
+    /// Signed i32x4.
+    I32x4S,
+    /// Unsigned i32x4.
+    I32x4U,
+}
+
+/// Kinds of vector lane extensions in WebAssembly, extending either the
+/// low or the high half of the lanes of the source vector.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub(crate) enum V128ExtendKind {
+    /// Sign extends the low 8 lanes of an i8x16.
+    LowI8x16S,
+    /// Zero extends the low 8 lanes of an i8x16.
+    LowI8x16U,
+    /// Sign extends the low 4 lanes of an i16x8.
+    LowI16x8S,
+    /// Zero extends the low 4 lanes of an i16x8.
+    LowI16x8U,
+    /// Sign extends the low 2 lanes of an i32x4.
+    LowI32x4S,
+    /// Zero extends the low 2 lanes of an i32x4.
+    LowI32x4U,
+    /// Sign extends the high 8 lanes of an i8x16.
+    HighI8x16S,
+    /// Zero extends the high 8 lanes of an i8x16.
+    HighI8x16U,
+    /// Sign extends the high 4 lanes of an i16x8.
+    HighI16x8S,
+    /// Zero extends the high 4 lanes of an i16x8.
+    HighI16x8U,
+    /// Sign extends the high 2 lanes of an i32x4.
+    HighI32x4S,
+    /// Zero extends the high 2 lanes of an i32x4.
+    HighI32x4U,
+}
+
+impl V128ExtendKind {
+    /// Whether the extension applies to the high half of the lanes.
+    pub fn is_high(&self) -> bool {
+        use V128ExtendKind::*;
+        match self {
+            HighI8x16S | HighI8x16U | HighI16x8S | HighI16x8U | HighI32x4S | HighI32x4U => true,
+            LowI8x16S | LowI8x16U | LowI16x8S | LowI16x8U | LowI32x4S | LowI32x4U => false,
+        }
+    }
+}
+
+/// Kinds of vector narrowing in WebAssembly, named after the source shape.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub(crate) enum V128NarrowKind {
+    /// Narrows two i16x8 into an i8x16, saturating signed.
+    I16x8S,
+    /// Narrows two i16x8 into an i8x16, saturating unsigned.
+    I16x8U,
+    /// Narrows two i32x4 into an i16x8, saturating signed.
+    I32x4S,
+    /// Narrows two i32x4 into an i16x8, saturating unsigned.
+    I32x4U,
+}
+
+/// Kinds of integer to float vector conversions in WebAssembly.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub(crate) enum V128ConvertKind {
+    /// Signed i32x4 to f32x4.
+    I32x4S,
+    /// Unsigned i32x4 to f32x4.
+    I32x4U,
+    /// The low two lanes of a signed i32x4 to f64x2.
+    I32x4LowS,
+    /// The low two lanes of an unsigned i32x4 to f64x2.
+    I32x4LowU,
+}
+
+/// Kinds of saturating float to integer vector truncations in WebAssembly.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub(crate) enum V128TruncSatKind {
+    /// f32x4 to signed i32x4.
+    F32x4S,
+    /// f32x4 to unsigned i32x4.
+    F32x4U,
+    /// f64x2 to signed i32x4, zeroing the two high lanes.
+    F64x2SZero,
+    /// f64x2 to unsigned i32x4, zeroing the two high lanes.
+    F64x2UZero,
+}
+
+/// Kinds of pairwise extended additions in WebAssembly, named after the
+/// source shape.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub(crate) enum V128ExtAddKind {
+    /// Signed i8x16 lanes into i16x8.
+    I8x16S,
+    /// Unsigned i8x16 lanes into i16x8.
+    I8x16U,
+    /// Signed i16x8 lanes into i32x4.
+    I16x8S,
+    /// Unsigned i16x8 lanes into i32x4.
+    I16x8U,
+}
+
 /// Operand size, in bits.
 #[derive(Copy, Debug, Clone, Eq, PartialEq)]
 pub(crate) enum OperandSize {
+    /// 8 bits.
+    S8,
+    /// 16 bits.
+    S16,
     /// 32 bits.
     S32,
     /// 64 bits.
@@ -162,6 +407,8 @@ impl OperandSize {
     /// The number of bits in the operand.
     pub fn num_bits(&self) -> i32 {
         match self {
+            OperandSize::S8 => 8,
+            OperandSize::S16 => 16,
             OperandSize::S32 => 32,
             OperandSize::S64 => 64,
             OperandSize::S128 => 128,
@@ -171,6 +418,8 @@ impl OperandSize {
     /// The number of bytes in the operand.
     pub fn bytes(&self) -> u32 {
         match self {
+            Self::S8 => 1,
+            Self::S16 => 2,
             Self::S32 => 4,
             Self::S64 => 8,
             Self::S128 => 16,
@@ -180,6 +429,8 @@ impl OperandSize {
     /// The binary logarithm of the number of bits in the operand.
     pub fn log2(&self) -> u8 {
         match self {
+            OperandSize::S8 => 3,
+            OperandSize::S16 => 4,
             OperandSize::S32 => 5,
             OperandSize::S64 => 6,
             OperandSize::S128 => 7,
@@ -190,6 +441,8 @@ impl OperandSize {
     pub fn from_bytes(bytes: u8) -> Self {
         use OperandSize::*;
         match bytes {
+            1 => S8,
+            2 => S16,
             4 => S32,
             8 => S64,
             16 => S128,
@@ -218,6 +471,8 @@ pub(crate) enum Imm {
     F32(u32),
     /// F64 immediate.
     F64(u64),
+    /// V128 immediate.
+    V128(i128),
 }
 
 impl Imm {
@@ -245,6 +500,11 @@ impl Imm {
         Self::F64(bits)
     }
 
+    /// Create a new V128 immediate.
+    pub fn v128(bits: i128) -> Self {
+        Self::V128(bits)
+    }
+
     /// Convert the immediate to i32, if possible.
     pub fn to_i32(&self) -> Option<i32> {
         match self {
@@ -311,6 +571,11 @@ impl RegImm {
     pub fn f64(bits: u64) -> Self {
         RegImm::Imm(Imm::f64(bits))
     }
+
+    /// V128 immediate.
+    pub fn v128(bits: i128) -> Self {
+        RegImm::Imm(Imm::v128(bits))
+    }
 }
 
 impl From<Reg> for RegImm {
@@ -361,7 +626,10 @@ pub(crate) trait MacroAssembler {
     fn check_stack(&mut self);
 
     /// Emit the function epilogue.
-    fn epilogue(&mut self, locals_size: u32);
+    ///
+    /// `stack_args_size` is the size, in bytes, of the stack arguments that
+    /// are popped by the callee upon return.
+    fn epilogue(&mut self, locals_size: u32, stack_args_size: u32);
 
     /// Reserve stack space.
     fn reserve_stack(&mut self, bytes: u32);
@@ -413,7 +681,31 @@ pub(crate) trait MacroAssembler {
     fn address_at_reg(&self, reg: Reg, offset: u32) -> Self::Address;
 
     /// Emit a function call to either a local or external function.
-    fn call(&mut self, stack_args_size: u32, f: impl FnMut(&mut Self) -> CalleeKind) -> u32;
+    ///
+    /// `ref_slots` are the offsets of the stack slots holding `externref`s
+    /// at the call site, which are recorded in the call's stack map.
+    fn call(
+        &mut self,
+        stack_args_size: u32,
+        ref_slots: &[SPOffset],
+        f: impl FnMut(&mut Self) -> CalleeKind,
+    ) -> u32;
+
+    /// Emit a tail call sequence.
+    ///
+    /// The stack space for the callee's arguments, `stack_args_size` bytes,
+    /// is reserved before invoking the closure, which is responsible for
+    /// placing the arguments and loading the callee. The current frame is
+    /// then torn down: the callee's stack arguments replace the
+    /// `caller_stack_args_size` bytes of stack arguments of the current
+    /// function and control is transferred to the callee, which returns
+    /// directly to the current function's caller.
+    fn return_call(
+        &mut self,
+        stack_args_size: u32,
+        caller_stack_args_size: u32,
+        f: impl FnMut(&mut Self) -> CalleeKind,
+    );
 
     /// Get stack pointer offset.
     fn sp_offset(&self) -> SPOffset;
@@ -424,6 +716,52 @@ pub(crate) trait MacroAssembler {
     /// Perform a stack load.
     fn load(&mut self, src: Self::Address, dst: Reg, size: OperandSize);
 
+    /// Perform a WebAssembly load.
+    /// A WebAssembly load introduces several additional considerations over
+    /// a normal load: the load might be sign extended and the access might
+    /// fault, in which case the faulting instruction must be registered as a
+    /// potential trap site for out-of-bounds accesses. If no extension kind is
+    /// given, values narrower than the destination are zero extended.
+    fn wasm_load(
+        &mut self,
+        src: Self::Address,
+        dst: Reg,
+        size: OperandSize,
+        kind: Option<ExtendKind>,
+    );
+
+    /// Perform a WebAssembly store.
+    /// Like [`Self::wasm_load`], a WebAssembly store might fault, in which case
+    /// the faulting instruction is registered as a trap site for
+    /// out-of-bounds accesses.
+    fn wasm_store(&mut self, src: Reg, dst: Self::Address, size: OperandSize);
+
+    /// Perform a sequentially consistent WebAssembly atomic load.
+    /// Values narrower than the destination are zero extended.
+    fn wasm_atomic_load(&mut self, src: Self::Address, dst: Reg, size: OperandSize);
+
+    /// Perform a sequentially consistent WebAssembly atomic store.
+    fn wasm_atomic_store(&mut self, src: Reg, dst: Self::Address, size: OperandSize);
+
+    /// Perform a sequentially consistent atomic read-modify-write operation.
+    ///
+    /// The value stack is expected to contain, from the top: the operand and
+    /// the pointer-sized, already bounds-checked address of the access. Both
+    /// are popped and the value previously found in memory, zero extended, is
+    /// pushed with the operand's type.
+    fn atomic_rmw(&mut self, context: &mut CodeGenContext, size: OperandSize, op: RmwOp);
+
+    /// Perform a sequentially consistent atomic compare-and-exchange.
+    ///
+    /// The value stack is expected to contain, from the top: the replacement,
+    /// the expected value and the pointer-sized, already bounds-checked address
+    /// of the access. All of them are popped and the value previously found in
+    /// memory, zero extended, is pushed with the expected value's type.
+    fn atomic_cas(&mut self, context: &mut CodeGenContext, size: OperandSize);
+
+    /// Emit a sequentially consistent memory fence.
+    fn fence(&mut self);
+
     /// Alias for `MacroAssembler::load` with the operand size corresponding
     /// to the pointer size of the target.
     fn load_ptr(&mut self, src: Self::Address, dst: Reg);
@@ -501,6 +839,10 @@ pub(crate) trait MacroAssembler {
     /// Perform add operation.
     fn add(&mut self, dst: Reg, lhs: Reg, rhs: RegImm, size: OperandSize);
 
+    /// Perform a checked unsigned integer addition, emitting the provided trap
+    /// if the addition overflows.
+    fn checked_uadd(&mut self, dst: Reg, lhs: Reg, rhs: RegImm, size: OperandSize, trap: TrapCode);
+
     /// Perform subtraction operation.
     fn sub(&mut self, dst: Reg, lhs: Reg, rhs: RegImm, size: OperandSize);
 
@@ -670,6 +1012,174 @@ pub(crate) trait MacroAssembler {
     /// Promote an f32 to an f64.
     fn promote(&mut self, src: Reg, dst: Reg);
 
+    /// Replicate the scalar in src to all the lanes of the vector in dst.
+    fn splat(&mut self, src: Reg, dst: Reg, shape: VectorShape);
+
+    /// Extract the lane at the given index of the vector in src into dst.
+    /// Integer lanes narrower than 32 bits are zero extended, unless an
+    /// extension kind is given.
+    fn extract_lane(
+        &mut self,
+        src: Reg,
+        dst: Reg,
+        lane: u8,
+        shape: VectorShape,
+        kind: Option<ExtendKind>,
+    );
+
+    /// Replace the lane at the given index of the vector in dst with the
+    /// scalar in src.
+    fn replace_lane(&mut self, src: Reg, dst: Reg, lane: u8, shape: VectorShape);
+
+    /// Select bytes from the concatenation of lhs and rhs, using the
+    /// immediate lane indices, and put the result in dst.
+    /// The contents of rhs might be clobbered.
+    fn shuffle(&mut self, dst: Reg, lhs: Reg, rhs: Reg, lanes: [u8; 16]);
+
+    /// Select bytes from lhs using the lane indices in rhs, producing zero
+    /// for out of range indices, and put the result in dst.
+    /// The contents of rhs might be clobbered.
+    fn swizzle(&mut self, dst: Reg, lhs: Reg, rhs: Reg);
+
+    /// Perform a vector addition.
+    fn v128_add(&mut self, dst: Reg, lhs: Reg, rhs: Reg, kind: V128AddKind);
+
+    /// Perform a vector subtraction.
+    fn v128_sub(&mut self, dst: Reg, lhs: Reg, rhs: Reg, kind: V128SubKind);
+
+    /// Perform a vector multiplication.
+    /// Like [`Self::shift`], this function has access to the code generation
+    /// context, given that some lowerings require temporary registers.
+    fn v128_mul(&mut self, context: &mut CodeGenContext, kind: V128MulKind);
+
+    /// Perform a floating point vector division.
+    fn v128_div(&mut self, dst: Reg, lhs: Reg, rhs: Reg, size: OperandSize);
+
+    /// Perform a vector minimum operation.
+    /// The contents of rhs might be clobbered.
+    fn v128_min(&mut self, dst: Reg, lhs: Reg, rhs: Reg, kind: V128MinMaxKind);
+
+    /// Perform a vector maximum operation.
+    /// The contents of rhs might be clobbered.
+    fn v128_max(&mut self, dst: Reg, lhs: Reg, rhs: Reg, kind: V128MinMaxKind);
+
+    /// Perform a floating point vector pseudo-minimum operation
+    /// (`rhs < lhs ? rhs : lhs`).
+    fn v128_pmin(&mut self, dst: Reg, lhs: Reg, rhs: Reg, size: OperandSize);
+
+    /// Perform a floating point vector pseudo-maximum operation
+    /// (`lhs < rhs ? rhs : lhs`).
+    fn v128_pmax(&mut self, dst: Reg, lhs: Reg, rhs: Reg, size: OperandSize);
+
+    /// Perform an unsigned rounding average of the lanes of lhs and rhs.
+    fn v128_avgr_u(&mut self, dst: Reg, lhs: Reg, rhs: Reg, lane_size: OperandSize);
+
+    /// Perform a saturating, rounding Q15 multiplication of i16x8 vectors.
+    fn v128_q15mulr_sat_s(&mut self, dst: Reg, lhs: Reg, rhs: Reg);
+
+    /// Perform the dot product of two i16x8 vectors into an i32x4.
+    fn v128_dot(&mut self, dst: Reg, lhs: Reg, rhs: Reg);
+
+    /// Perform a pairwise extended addition of the lanes of src.
+    fn v128_extadd_pairwise(&mut self, src: Reg, dst: Reg, kind: V128ExtAddKind);
+
+    /// Perform a vector absolute value operation.
+    fn v128_abs(&mut self, dst: Reg, shape: VectorShape);
+
+    /// Perform a vector negation.
+    fn v128_neg(&mut self, dst: Reg, shape: VectorShape);
+
+    /// Count the number of 1 bits in each i8x16 lane of src.
+    /// The contents of src might be clobbered.
+    fn v128_popcnt(&mut self, src: Reg, dst: Reg);
+
+    /// Perform a floating point vector square root operation.
+    fn v128_sqrt(&mut self, dst: Reg, src: Reg, size: OperandSize);
+
+    /// Perform a floating point vector rounding operation.
+    fn v128_round(&mut self, dst: Reg, src: Reg, mode: RoundingMode, size: OperandSize);
+
+    /// Perform a lane-wise vector shift, by a scalar shift amount taken
+    /// modulo the lane width.
+    /// Like [`Self::shift`], this function has access to the code generation
+    /// context, allowing each implementation to decide the lowering path.
+    fn v128_shift(&mut self, context: &mut CodeGenContext, lane_size: OperandSize, kind: ShiftKind);
+
+    /// Perform a lane-wise integer vector comparison, setting all the bits
+    /// of each lane of dst if the comparison is true.
+    /// The contents of rhs might be clobbered.
+    fn v128_int_cmp(
+        &mut self,
+        dst: Reg,
+        lhs: Reg,
+        rhs: Reg,
+        kind: IntCmpKind,
+        lane_size: OperandSize,
+    );
+
+    /// Perform a lane-wise floating point vector comparison, setting all the
+    /// bits of each lane of dst if the comparison is true.
+    fn v128_float_cmp(
+        &mut self,
+        dst: Reg,
+        lhs: Reg,
+        rhs: Reg,
+        kind: FloatCmpKind,
+        size: OperandSize,
+    );
+
+    /// Perform a bitwise not operation.
+    fn v128_not(&mut self, dst: Reg);
+
+    /// Perform a bitwise and operation.
+    fn v128_and(&mut self, dst: Reg, lhs: Reg, rhs: Reg);
+
+    /// Perform a bitwise and not operation (`lhs & !rhs`).
+    /// The contents of rhs might be clobbered.
+    fn v128_and_not(&mut self, dst: Reg, lhs: Reg, rhs: Reg);
+
+    /// Perform a bitwise or operation.
+    fn v128_or(&mut self, dst: Reg, lhs: Reg, rhs: Reg);
+
+    /// Perform a bitwise exclusive or operation.
+    fn v128_xor(&mut self, dst: Reg, lhs: Reg, rhs: Reg);
+
+    /// Select the bits of lhs where the bits of mask are set and the bits of
+    /// rhs otherwise.
+    /// The contents of mask might be clobbered.
+    fn v128_bitselect(&mut self, dst: Reg, lhs: Reg, rhs: Reg, mask: Reg);
+
+    /// Set dst to 1 if any bit of src is set, 0 otherwise.
+    fn v128_any_true(&mut self, src: Reg, dst: Reg);
+
+    /// Set dst to 1 if all the lanes of src are non-zero, 0 otherwise.
+    fn v128_all_true(&mut self, src: Reg, dst: Reg, lane_size: OperandSize);
+
+    /// Gather the most significant bit of each lane of src into dst.
+    fn v128_bitmask(&mut self, src: Reg, dst: Reg, lane_size: OperandSize);
+
+    /// Extend half of the lanes of src into lanes twice as wide.
+    fn v128_extend(&mut self, src: Reg, dst: Reg, kind: V128ExtendKind);
+
+    /// Narrow the lanes of lhs and rhs into lanes half as wide, saturating
+    /// out of range values.
+    fn v128_narrow(&mut self, dst: Reg, lhs: Reg, rhs: Reg, kind: V128NarrowKind);
+
+    /// Convert the integer lanes of src to floating point.
+    /// The contents of src might be clobbered.
+    fn v128_convert(&mut self, src: Reg, dst: Reg, kind: V128ConvertKind);
+
+    /// Truncate the floating point lanes of src to integers, saturating out of
+    /// range values and converting NaNs to zero.
+    /// The contents of src might be clobbered.
+    fn v128_trunc_sat(&mut self, src: Reg, dst: Reg, kind: V128TruncSatKind);
+
+    /// Demote the two f64 lanes of src to f32, zeroing the two high lanes.
+    fn v128_demote(&mut self, src: Reg, dst: Reg);
+
+    /// Promote the two low f32 lanes of src to f64.
+    fn v128_promote(&mut self, src: Reg, dst: Reg);
+
     /// Zero a given memory range.
     ///
     /// The default implementation divides the given memory range
@@ -748,6 +1258,9 @@ pub(crate) trait MacroAssembler {
     /// Emit an unreachable code trap.
     fn unreachable(&mut self);
 
+    /// Emit an unconditional trap.
+    fn trap(&mut self, code: TrapCode);
+
     /// Traps if the condition code is met.
     fn trapif(&mut self, cc: IntCmpKind, code: TrapCode);
 
@@ -10,7 +10,7 @@
 // and VM context type should be derived from the ABI's pointer size. This is
 // going to be relevant once 32-bit architectures are supported.
 use crate::{
-    abi::{ABIOperand, ABIParams, ABISig, RetArea, ABI},
+    abi::{wasm_sig, ABIOperand, ABIParams, ABISig, RetArea, ABI, VMCTX_PARAMS},
     codegen::ptr_type_from_ptr_size,
     isa::CallingConvention,
     masm::{CalleeKind, MacroAssembler, OperandSize, RegImm, SPOffset},
@@ -100,22 +100,14 @@ where
 
         self.prologue_with_callee_saved();
 
-        // Get the VM context pointer and move it to the designated pinned
-        // register.
-        let (vmctx, caller_vmctx) = Self::callee_and_caller_vmctx(&array_sig.params)?;
-
-        self.masm.mov(
-            vmctx.into(),
-            <M::ABI as ABI>::vmctx_reg().into(),
-            OperandSize::S64,
-        );
+        let (_, caller_vmctx) = Self::callee_and_caller_vmctx(&array_sig.params)?;
 
         let ret_area = self.make_ret_area(&wasm_sig);
         let vmctx_runtime_limits_addr = self.vmctx_runtime_limits_addr(caller_vmctx);
         let (offsets, spill_size) = self.spill(array_sig.params());
 
         // Call the function that was passed into the trampoline.
-        let allocated_stack = self.masm.call(wasm_sig.params_stack_size(), |masm| {
+        let allocated_stack = self.masm.call(wasm_sig.params_stack_size(), &[], |masm| {
             // Save the SP when entering Wasm.
             // TODO: Once Winch supports comparison operators,
             // check that the caller VM context is what we expect.
@@ -127,6 +119,15 @@ where
                 &self.pointer_size,
             );
 
+            // Forward the callee and caller VM context pointers.
+            Self::assign_args(
+                masm,
+                &wasm_sig.params()[..VMCTX_PARAMS],
+                &array_sig.params()[..VMCTX_PARAMS],
+                &offsets[..VMCTX_PARAMS],
+                self.scratch_reg,
+            );
+
             // Move the values register to the scratch
             // register for argument assignment.
             masm.mov(val_ptr, self.scratch_reg.into(), OperandSize::S64);
@@ -140,7 +141,7 @@ where
             CalleeKind::Direct(callee_index.as_u32())
         });
 
-        self.masm.free_stack(allocated_stack);
+        self.free_call_stack(allocated_stack, &wasm_sig);
 
         // Move the val ptr back into the scratch register so we can
         // load the return values.
@@ -180,9 +181,10 @@ where
                         }
                         _ => unreachable!(),
                     };
-                    self.masm.load(addr, self.alloc_scratch_reg, (*ty).into());
+                    let scratch = Self::scratch_for(ty, self.alloc_scratch_reg);
+                    self.masm.load(addr, scratch, (*ty).into());
                     self.masm.store(
-                        self.alloc_scratch_reg.into(),
+                        scratch.into(),
                         self.masm.address_at_reg(self.scratch_reg, value_offset),
                         (*ty).into(),
                     );
@@ -199,21 +201,15 @@ where
     ) -> Result<()> {
         let native_sig = self.native_sig(&ty);
         let wasm_sig = self.wasm_sig(&ty);
-        let (vmctx, caller_vmctx) = Self::callee_and_caller_vmctx(&native_sig.params)?;
+        let (_, caller_vmctx) = Self::callee_and_caller_vmctx(&native_sig.params)?;
 
         self.prologue_with_callee_saved();
-        // Move the VM context pointer to the designated pinned register.
-        self.masm.mov(
-            vmctx.into(),
-            <M::ABI as ABI>::vmctx_reg().into(),
-            OperandSize::S64,
-        );
 
         let vmctx_runtime_limits_addr = self.vmctx_runtime_limits_addr(caller_vmctx);
         let ret_area = self.make_ret_area(&wasm_sig);
         let (offsets, spill_size) = self.spill(native_sig.params());
 
-        let reserved_stack = self.masm.call(wasm_sig.params_stack_size(), |masm| {
+        let reserved_stack = self.masm.call(wasm_sig.params_stack_size(), &[], |masm| {
             // Save the SP when entering Wasm.
             // TODO: Once Winch supports comparison operators,
             // check that the caller VM context is what we expect.
@@ -227,15 +223,15 @@ where
             Self::assign_args(
                 masm,
                 &wasm_sig.params_without_retptr(),
-                &native_sig.params_without_retptr()[2..],
-                &offsets[2..],
+                &native_sig.params_without_retptr(),
+                &offsets,
                 self.scratch_reg,
             );
             Self::load_retptr(masm, ret_area.as_ref(), &wasm_sig);
             CalleeKind::Direct(callee_index.as_u32())
         });
 
-        self.masm.free_stack(reserved_stack);
+        self.free_call_stack(reserved_stack, &wasm_sig);
         self.forward_results(&wasm_sig, &native_sig, ret_area.as_ref(), offsets.last());
         if wasm_sig.has_stack_results() {
             self.masm.free_stack(wasm_sig.results.size());
@@ -245,6 +241,16 @@ where
         Ok(())
     }
 
+    /// Frees the stack space reserved for a call to a Wasm function, taking
+    /// into account that the callee pops its stack arguments.
+    fn free_call_stack(&mut self, reserved_stack: u32, callee_sig: &ABISig) {
+        let callee_popped = callee_sig.callee_popped_stack_size();
+        let sp_offset = self.masm.sp_offset().as_u32();
+        self.masm
+            .reset_stack_pointer(SPOffset::from_u32(sp_offset - callee_popped));
+        self.masm.free_stack(reserved_stack - callee_popped);
+    }
+
     /// Creates the return area in the caller's frame.
     fn make_ret_area(&mut self, sig: &ABISig) -> Option<RetArea> {
         sig.has_stack_results().then(|| {
@@ -311,13 +317,14 @@ where
             match (callee_operand, caller_operand) {
                 (ABIOperand::Reg { ty, .. }, ABIOperand::Stack { offset, .. }) => {
                     let reg_offset = spill_offsets_iter.next().unwrap();
+                    let scratch = Self::scratch_for(ty, self.alloc_scratch_reg);
                     self.masm.load(
                         self.masm.address_from_sp(*reg_offset),
-                        self.alloc_scratch_reg,
+                        scratch,
                         (*ty).into(),
                     );
                     self.masm.store(
-                        self.alloc_scratch_reg.into(),
+                        scratch.into(),
                         self.masm.address_at_reg(caller_retptr.unwrap(), *offset),
                         (*ty).into(),
                     );
@@ -335,9 +342,10 @@ where
                         self.masm.address_from_sp(SPOffset::from_u32(slot_offset))
                     };
 
-                    self.masm.load(addr, self.alloc_scratch_reg, (*ty).into());
+                    let scratch = Self::scratch_for(ty, self.alloc_scratch_reg);
+                    self.masm.load(addr, scratch, (*ty).into());
                     self.masm.store(
-                        self.alloc_scratch_reg.into(),
+                        scratch.into(),
                         self.masm
                             .address_at_reg(caller_retptr.unwrap(), *caller_offset),
                         (*ty).into(),
@@ -367,11 +375,7 @@ where
 
     /// Emit a wasm-to-native trampoline.
     pub fn emit_wasm_to_native(&mut self, ty: &WasmFuncType) -> Result<()> {
-        let mut params = self.callee_and_caller_vmctx_types();
-        params.extend_from_slice(ty.params());
-
-        let wasm_ty = WasmFuncType::new(params.into_boxed_slice(), ty.returns().into());
-        let wasm_sig = self.wasm_sig(&wasm_ty);
+        let wasm_sig = self.wasm_sig(ty);
         let native_sig = self.native_sig(ty);
 
         let (vmctx, caller_vmctx) = Self::callee_and_caller_vmctx(&wasm_sig.params).unwrap();
@@ -394,7 +398,7 @@ where
         let ret_area = self.make_ret_area(&native_sig);
         let (offsets, spill_size) = self.spill(wasm_sig.params());
 
-        let reserved_stack = self.masm.call(native_sig.params_stack_size(), |masm| {
+        let reserved_stack = self.masm.call(native_sig.params_stack_size(), &[], |masm| {
             // Move the VM context into one of the scratch registers.
             masm.mov(
                 vmctx.into(),
@@ -427,7 +431,7 @@ where
             self.masm.free_stack(native_sig.results.size());
         }
 
-        self.epilogue(spill_size);
+        self.epilogue(spill_size, wasm_sig.callee_popped_stack_size());
 
         Ok(())
     }
@@ -461,6 +465,7 @@ where
                     (ABIOperand::Stack { ty, offset, .. }, ABIOperand::Reg { .. }) => {
                         let spill_offset = caller_stack_offsets[offset_index];
                         let addr = masm.address_from_sp(spill_offset);
+                        let scratch = Self::scratch_for(ty, scratch);
                         masm.load(addr, scratch, (*ty).into());
 
                         let arg_addr = masm.address_at_sp(SPOffset::from_u32(*offset));
@@ -485,6 +490,7 @@ where
                         },
                     ) => {
                         let addr = masm.address_at_reg(fp, arg_base_offset + caller_offset);
+                        let scratch = Self::scratch_for(ty, scratch);
                         masm.load(addr, scratch, (*ty).into());
 
                         let arg_addr = masm.address_at_sp(SPOffset::from_u32(*callee_offset));
@@ -553,7 +559,7 @@ where
 
     /// Returns an [ABISig] using the Winch's default calling convention.
     fn wasm_sig(&self, ty: &WasmFuncType) -> ABISig {
-        <M::ABI as ABI>::sig(ty, &CallingConvention::Default)
+        wasm_sig::<M::ABI>(ty)
     }
 
     /// Returns the register pair containing the callee and caller VM context pointers.
@@ -579,6 +585,16 @@ where
     }
 
     /// Performs a spill of the given operands.
+    /// Returns the register to use when moving a value of the given type
+    /// between memory locations; vector values don't fit in the general
+    /// purpose scratch registers.
+    fn scratch_for(ty: &WasmType, scratch: Reg) -> Reg {
+        match ty {
+            WasmType::V128 => <M::ABI as ABI>::float_scratch_reg(),
+            _ => scratch,
+        }
+    }
+
     fn spill(&mut self, operands: &[ABIOperand]) -> (SmallVec<[SPOffset; 6]>, u32) {
         let mut offsets = SmallVec::new();
         let mut spill_size = 0;
@@ -602,8 +618,9 @@ where
         values_reg: Reg,
         scratch: Reg,
     ) {
-        callee_sig
-            .params_without_retptr()
+        // Skip the callee and caller VM context params, which are not part
+        // of the values array.
+        callee_sig.params_without_retptr()[VMCTX_PARAMS..]
             .iter()
             .enumerate()
             .for_each(|(i, param)| {
@@ -616,6 +633,7 @@ where
                         (*ty).into(),
                     ),
                     ABIOperand::Stack { offset, ty, .. } => {
+                        let scratch = Self::scratch_for(ty, scratch);
                         masm.load(
                             masm.address_at_reg(values_reg, value_offset),
                             scratch,
@@ -721,13 +739,13 @@ where
         for (r, s) in self.callee_saved_regs.iter().rev() {
             self.masm.pop(*r, *s);
         }
-        self.masm.epilogue(0);
+        self.masm.epilogue(0, 0);
     }
 
     /// The trampoline's epilogue.
-    fn epilogue(&mut self, arg_size: u32) {
+    fn epilogue(&mut self, arg_size: u32, stack_args_size: u32) {
         // Free the stack space allocated by pushing the trampoline arguments.
         self.masm.free_stack(arg_size);
-        self.masm.epilogue(0);
+        self.masm.epilogue(0, stack_args_size);
     }
 }
@@ -4,21 +4,26 @@
 //! which validates and dispatches to the corresponding
 //! machine code emitter.
 
-use crate::abi::{RetArea, ABI};
-use crate::codegen::{control_index, Callee, CodeGen, ControlStackFrame, FnCall};
+use crate::abi::{is_externref, RetArea, ABI};
+use crate::codegen::{
+    control_index, BuiltinFunction, Callee, CodeGen, CodeGenContext, ControlStackFrame, FnCall,
+};
+use crate::isa::reg::Reg;
 use crate::masm::{
     DivKind, ExtendKind, FloatCmpKind, IntCmpKind, MacroAssembler, MemMoveDirection, OperandSize,
-    RegImm, RemKind, RoundingMode, SPOffset, ShiftKind,
+    RegImm, RemKind, RmwOp, RoundingMode, SPOffset, ShiftKind, V128AddKind, V128ConvertKind,
+    V128ExtAddKind, V128ExtendKind, V128MinMaxKind, V128MulKind, V128NarrowKind, V128SubKind,
+    V128TruncSatKind, VectorShape,
 };
 use crate::stack::{TypedReg, Val};
 use cranelift_codegen::ir::TrapCode;
 use regalloc2::RegClass;
 use smallvec::SmallVec;
 use wasmparser::BrTable;
-use wasmparser::{BlockType, Ieee32, Ieee64, VisitOperator};
+use wasmparser::{BlockType, Ieee32, Ieee64, MemArg, VisitOperator, V128};
 use wasmtime_environ::{
-    FuncIndex, GlobalIndex, MemoryIndex, TableIndex, TableStyle, TypeIndex, WasmHeapType, WasmType,
-    FUNCREF_INIT_BIT,
+    FuncIndex, GlobalIndex, MemoryIndex, TableIndex, TableStyle, TypeConvert, TypeIndex,
+    WasmHeapType, WasmRefType, WasmType, FUNCREF_INIT_BIT,
 };
 
 /// A macro to define unsupported WebAssembly operators.
@@ -201,6 +206,17 @@ macro_rules! def_unsupported {
     (emit TableGrow $($rest:tt)*) => {};
     (emit TableSize $($rest:tt)*) => {};
     (emit TableFill $($rest:tt)*) => {};
+    (emit CallRef $($rest:tt)*) => {};
+    (emit RefNull $($rest:tt)*) => {};
+    (emit RefIsNull $($rest:tt)*) => {};
+    (emit RefFunc $($rest:tt)*) => {};
+    (emit RefAsNonNull $($rest:tt)*) => {};
+    (emit BrOnNull $($rest:tt)*) => {};
+    (emit BrOnNonNull $($rest:tt)*) => {};
+    (emit TypedSelect $($rest:tt)*) => {};
+    (emit ReturnCall $($rest:tt)*) => {};
+    (emit ReturnCallIndirect $($rest:tt)*) => {};
+    (emit ReturnCallRef $($rest:tt)*) => {};
     (emit ElemDrop $($rest:tt)*) => {};
     (emit MemoryInit $($rest:tt)*) => {};
     (emit MemoryCopy $($rest:tt)*) => {};
@@ -208,6 +224,333 @@ macro_rules! def_unsupported {
     (emit MemoryFill $($rest:tt)*) => {};
     (emit MemorySize $($rest:tt)*) => {};
     (emit MemoryGrow $($rest:tt)*) => {};
+    (emit I32Load $($rest:tt)*) => {};
+    (emit I32Load8S $($rest:tt)*) => {};
+    (emit I32Load8U $($rest:tt)*) => {};
+    (emit I32Load16S $($rest:tt)*) => {};
+    (emit I32Load16U $($rest:tt)*) => {};
+    (emit I64Load $($rest:tt)*) => {};
+    (emit I64Load8S $($rest:tt)*) => {};
+    (emit I64Load8U $($rest:tt)*) => {};
+    (emit I64Load16S $($rest:tt)*) => {};
+    (emit I64Load16U $($rest:tt)*) => {};
+    (emit I64Load32S $($rest:tt)*) => {};
+    (emit I64Load32U $($rest:tt)*) => {};
+    (emit F32Load $($rest:tt)*) => {};
+    (emit F64Load $($rest:tt)*) => {};
+    (emit I32Store $($rest:tt)*) => {};
+    (emit I32Store8 $($rest:tt)*) => {};
+    (emit I32Store16 $($rest:tt)*) => {};
+    (emit I64Store $($rest:tt)*) => {};
+    (emit I64Store8 $($rest:tt)*) => {};
+    (emit I64Store16 $($rest:tt)*) => {};
+    (emit I64Store32 $($rest:tt)*) => {};
+    (emit F32Store $($rest:tt)*) => {};
+    (emit F64Store $($rest:tt)*) => {};
+    (emit V128Load $($rest:tt)*) => {};
+    (emit V128Load8x8S $($rest:tt)*) => {};
+    (emit V128Load8x8U $($rest:tt)*) => {};
+    (emit V128Load16x4S $($rest:tt)*) => {};
+    (emit V128Load16x4U $($rest:tt)*) => {};
+    (emit V128Load32x2S $($rest:tt)*) => {};
+    (emit V128Load32x2U $($rest:tt)*) => {};
+    (emit V128Load8Splat $($rest:tt)*) => {};
+    (emit V128Load16Splat $($rest:tt)*) => {};
+    (emit V128Load32Splat $($rest:tt)*) => {};
+    (emit V128Load64Splat $($rest:tt)*) => {};
+    (emit V128Load32Zero $($rest:tt)*) => {};
+    (emit V128Load64Zero $($rest:tt)*) => {};
+    (emit V128Store $($rest:tt)*) => {};
+    (emit V128Load8Lane $($rest:tt)*) => {};
+    (emit V128Load16Lane $($rest:tt)*) => {};
+    (emit V128Load32Lane $($rest:tt)*) => {};
+    (emit V128Load64Lane $($rest:tt)*) => {};
+    (emit V128Store8Lane $($rest:tt)*) => {};
+    (emit V128Store16Lane $($rest:tt)*) => {};
+    (emit V128Store32Lane $($rest:tt)*) => {};
+    (emit V128Store64Lane $($rest:tt)*) => {};
+    (emit V128Const $($rest:tt)*) => {};
+    (emit I8x16Shuffle $($rest:tt)*) => {};
+    (emit I8x16ExtractLaneS $($rest:tt)*) => {};
+    (emit I8x16ExtractLaneU $($rest:tt)*) => {};
+    (emit I8x16ReplaceLane $($rest:tt)*) => {};
+    (emit I16x8ExtractLaneS $($rest:tt)*) => {};
+    (emit I16x8ExtractLaneU $($rest:tt)*) => {};
+    (emit I16x8ReplaceLane $($rest:tt)*) => {};
+    (emit I32x4ExtractLane $($rest:tt)*) => {};
+    (emit I32x4ReplaceLane $($rest:tt)*) => {};
+    (emit I64x2ExtractLane $($rest:tt)*) => {};
+    (emit I64x2ReplaceLane $($rest:tt)*) => {};
+    (emit F32x4ExtractLane $($rest:tt)*) => {};
+    (emit F32x4ReplaceLane $($rest:tt)*) => {};
+    (emit F64x2ExtractLane $($rest:tt)*) => {};
+    (emit F64x2ReplaceLane $($rest:tt)*) => {};
+    (emit I8x16Swizzle $($rest:tt)*) => {};
+    (emit I8x16Splat $($rest:tt)*) => {};
+    (emit I16x8Splat $($rest:tt)*) => {};
+    (emit I32x4Splat $($rest:tt)*) => {};
+    (emit I64x2Splat $($rest:tt)*) => {};
+    (emit F32x4Splat $($rest:tt)*) => {};
+    (emit F64x2Splat $($rest:tt)*) => {};
+    (emit I8x16Eq $($rest:tt)*) => {};
+    (emit I8x16Ne $($rest:tt)*) => {};
+    (emit I8x16LtS $($rest:tt)*) => {};
+    (emit I8x16LtU $($rest:tt)*) => {};
+    (emit I8x16GtS $($rest:tt)*) => {};
+    (emit I8x16GtU $($rest:tt)*) => {};
+    (emit I8x16LeS $($rest:tt)*) => {};
+    (emit I8x16LeU $($rest:tt)*) => {};
+    (emit I8x16GeS $($rest:tt)*) => {};
+    (emit I8x16GeU $($rest:tt)*) => {};
+    (emit I16x8Eq $($rest:tt)*) => {};
+    (emit I16x8Ne $($rest:tt)*) => {};
+    (emit I16x8LtS $($rest:tt)*) => {};
+    (emit I16x8LtU $($rest:tt)*) => {};
+    (emit I16x8GtS $($rest:tt)*) => {};
+    (emit I16x8GtU $($rest:tt)*) => {};
+    (emit I16x8LeS $($rest:tt)*) => {};
+    (emit I16x8LeU $($rest:tt)*) => {};
+    (emit I16x8GeS $($rest:tt)*) => {};
+    (emit I16x8GeU $($rest:tt)*) => {};
+    (emit I32x4Eq $($rest:tt)*) => {};
+    (emit I32x4Ne $($rest:tt)*) => {};
+    (emit I32x4LtS $($rest:tt)*) => {};
+    (emit I32x4LtU $($rest:tt)*) => {};
+    (emit I32x4GtS $($rest:tt)*) => {};
+    (emit I32x4GtU $($rest:tt)*) => {};
+    (emit I32x4LeS $($rest:tt)*) => {};
+    (emit I32x4LeU $($rest:tt)*) => {};
+    (emit I32x4GeS $($rest:tt)*) => {};
+    (emit I32x4GeU $($rest:tt)*) => {};
+    (emit I64x2Eq $($rest:tt)*) => {};
+    (emit I64x2Ne $($rest:tt)*) => {};
+    (emit I64x2LtS $($rest:tt)*) => {};
+    (emit I64x2GtS $($rest:tt)*) => {};
+    (emit I64x2LeS $($rest:tt)*) => {};
+    (emit I64x2GeS $($rest:tt)*) => {};
+    (emit F32x4Eq $($rest:tt)*) => {};
+    (emit F32x4Ne $($rest:tt)*) => {};
+    (emit F32x4Lt $($rest:tt)*) => {};
+    (emit F32x4Gt $($rest:tt)*) => {};
+    (emit F32x4Le $($rest:tt)*) => {};
+    (emit F32x4Ge $($rest:tt)*) => {};
+    (emit F64x2Eq $($rest:tt)*) => {};
+    (emit F64x2Ne $($rest:tt)*) => {};
+    (emit F64x2Lt $($rest:tt)*) => {};
+    (emit F64x2Gt $($rest:tt)*) => {};
+    (emit F64x2Le $($rest:tt)*) => {};
+    (emit F64x2Ge $($rest:tt)*) => {};
+    (emit V128Not $($rest:tt)*) => {};
+    (emit V128And $($rest:tt)*) => {};
+    (emit V128AndNot $($rest:tt)*) => {};
+    (emit V128Or $($rest:tt)*) => {};
+    (emit V128Xor $($rest:tt)*) => {};
+    (emit V128Bitselect $($rest:tt)*) => {};
+    (emit V128AnyTrue $($rest:tt)*) => {};
+    (emit I8x16Abs $($rest:tt)*) => {};
+    (emit I8x16Neg $($rest:tt)*) => {};
+    (emit I8x16Popcnt $($rest:tt)*) => {};
+    (emit I8x16AllTrue $($rest:tt)*) => {};
+    (emit I8x16Bitmask $($rest:tt)*) => {};
+    (emit I8x16NarrowI16x8S $($rest:tt)*) => {};
+    (emit I8x16NarrowI16x8U $($rest:tt)*) => {};
+    (emit I8x16Shl $($rest:tt)*) => {};
+    (emit I8x16ShrS $($rest:tt)*) => {};
+    (emit I8x16ShrU $($rest:tt)*) => {};
+    (emit I8x16Add $($rest:tt)*) => {};
+    (emit I8x16AddSatS $($rest:tt)*) => {};
+    (emit I8x16AddSatU $($rest:tt)*) => {};
+    (emit I8x16Sub $($rest:tt)*) => {};
+    (emit I8x16SubSatS $($rest:tt)*) => {};
+    (emit I8x16SubSatU $($rest:tt)*) => {};
+    (emit I8x16MinS $($rest:tt)*) => {};
+    (emit I8x16MinU $($rest:tt)*) => {};
+    (emit I8x16MaxS $($rest:tt)*) => {};
+    (emit I8x16MaxU $($rest:tt)*) => {};
+    (emit I8x16AvgrU $($rest:tt)*) => {};
+    (emit I16x8ExtAddPairwiseI8x16S $($rest:tt)*) => {};
+    (emit I16x8ExtAddPairwiseI8x16U $($rest:tt)*) => {};
+    (emit I16x8Abs $($rest:tt)*) => {};
+    (emit I16x8Neg $($rest:tt)*) => {};
+    (emit I16x8Q15MulrSatS $($rest:tt)*) => {};
+    (emit I16x8AllTrue $($rest:tt)*) => {};
+    (emit I16x8Bitmask $($rest:tt)*) => {};
+    (emit I16x8NarrowI32x4S $($rest:tt)*) => {};
+    (emit I16x8NarrowI32x4U $($rest:tt)*) => {};
+    (emit I16x8ExtendLowI8x16S $($rest:tt)*) => {};
+    (emit I16x8ExtendHighI8x16S $($rest:tt)*) => {};
+    (emit I16x8ExtendLowI8x16U $($rest:tt)*) => {};
+    (emit I16x8ExtendHighI8x16U $($rest:tt)*) => {};
+    (emit I16x8Shl $($rest:tt)*) => {};
+    (emit I16x8ShrS $($rest:tt)*) => {};
+    (emit I16x8ShrU $($rest:tt)*) => {};
+    (emit I16x8Add $($rest:tt)*) => {};
+    (emit I16x8AddSatS $($rest:tt)*) => {};
+    (emit I16x8AddSatU $($rest:tt)*) => {};
+    (emit I16x8Sub $($rest:tt)*) => {};
+    (emit I16x8SubSatS $($rest:tt)*) => {};
+    (emit I16x8SubSatU $($rest:tt)*) => {};
+    (emit I16x8Mul $($rest:tt)*) => {};
+    (emit I16x8MinS $($rest:tt)*) => {};
+    (emit I16x8MinU $($rest:tt)*) => {};
+    (emit I16x8MaxS $($rest:tt)*) => {};
+    (emit I16x8MaxU $($rest:tt)*) => {};
+    (emit I16x8AvgrU $($rest:tt)*) => {};
+    (emit I16x8ExtMulLowI8x16S $($rest:tt)*) => {};
+    (emit I16x8ExtMulHighI8x16S $($rest:tt)*) => {};
+    (emit I16x8ExtMulLowI8x16U $($rest:tt)*) => {};
+    (emit I16x8ExtMulHighI8x16U $($rest:tt)*) => {};
+    (emit I32x4ExtAddPairwiseI16x8S $($rest:tt)*) => {};
+    (emit I32x4ExtAddPairwiseI16x8U $($rest:tt)*) => {};
+    (emit I32x4Abs $($rest:tt)*) => {};
+    (emit I32x4Neg $($rest:tt)*) => {};
+    (emit I32x4AllTrue $($rest:tt)*) => {};
+    (emit I32x4Bitmask $($rest:tt)*) => {};
+    (emit I32x4ExtendLowI16x8S $($rest:tt)*) => {};
+    (emit I32x4ExtendHighI16x8S $($rest:tt)*) => {};
+    (emit I32x4ExtendLowI16x8U $($rest:tt)*) => {};
+    (emit I32x4ExtendHighI16x8U $($rest:tt)*) => {};
+    (emit I32x4Shl $($rest:tt)*) => {};
+    (emit I32x4ShrS $($rest:tt)*) => {};
+    (emit I32x4ShrU $($rest:tt)*) => {};
+    (emit I32x4Add $($rest:tt)*) => {};
+    (emit I32x4Sub $($rest:tt)*) => {};
+    (emit I32x4Mul $($rest:tt)*) => {};
+    (emit I32x4MinS $($rest:tt)*) => {};
+    (emit I32x4MinU $($rest:tt)*) => {};
+    (emit I32x4MaxS $($rest:tt)*) => {};
+    (emit I32x4MaxU $($rest:tt)*) => {};
+    (emit I32x4DotI16x8S $($rest:tt)*) => {};
+    (emit I32x4ExtMulLowI16x8S $($rest:tt)*) => {};
+    (emit I32x4ExtMulHighI16x8S $($rest:tt)*) => {};
+    (emit I32x4ExtMulLowI16x8U $($rest:tt)*) => {};
+    (emit I32x4ExtMulHighI16x8U $($rest:tt)*) => {};
+    (emit I64x2Abs $($rest:tt)*) => {};
+    (emit I64x2Neg $($rest:tt)*) => {};
+    (emit I64x2AllTrue $($rest:tt)*) => {};
+    (emit I64x2Bitmask $($rest:tt)*) => {};
+    (emit I64x2ExtendLowI32x4S $($rest:tt)*) => {};
+    (emit I64x2ExtendHighI32x4S $($rest:tt)*) => {};
+    (emit I64x2ExtendLowI32x4U $($rest:tt)*) => {};
+    (emit I64x2ExtendHighI32x4U $($rest:tt)*) => {};
+    (emit I64x2Shl $($rest:tt)*) => {};
+    (emit I64x2ShrS $($rest:tt)*) => {};
+    (emit I64x2ShrU $($rest:tt)*) => {};
+    (emit I64x2Add $($rest:tt)*) => {};
+    (emit I64x2Sub $($rest:tt)*) => {};
+    (emit I64x2Mul $($rest:tt)*) => {};
+    (emit I64x2ExtMulLowI32x4S $($rest:tt)*) => {};
+    (emit I64x2ExtMulHighI32x4S $($rest:tt)*) => {};
+    (emit I64x2ExtMulLowI32x4U $($rest:tt)*) => {};
+    (emit I64x2ExtMulHighI32x4U $($rest:tt)*) => {};
+    (emit F32x4Ceil $($rest:tt)*) => {};
+    (emit F32x4Floor $($rest:tt)*) => {};
+    (emit F32x4Trunc $($rest:tt)*) => {};
+    (emit F32x4Nearest $($rest:tt)*) => {};
+    (emit F32x4Abs $($rest:tt)*) => {};
+    (emit F32x4Neg $($rest:tt)*) => {};
+    (emit F32x4Sqrt $($rest:tt)*) => {};
+    (emit F32x4Add $($rest:tt)*) => {};
+    (emit F32x4Sub $($rest:tt)*) => {};
+    (emit F32x4Mul $($rest:tt)*) => {};
+    (emit F32x4Div $($rest:tt)*) => {};
+    (emit F32x4Min $($rest:tt)*) => {};
+    (emit F32x4Max $($rest:tt)*) => {};
+    (emit F32x4PMin $($rest:tt)*) => {};
+    (emit F32x4PMax $($rest:tt)*) => {};
+    (emit F64x2Ceil $($rest:tt)*) => {};
+    (emit F64x2Floor $($rest:tt)*) => {};
+    (emit F64x2Trunc $($rest:tt)*) => {};
+    (emit F64x2Nearest $($rest:tt)*) => {};
+    (emit F64x2Abs $($rest:tt)*) => {};
+    (emit F64x2Neg $($rest:tt)*) => {};
+    (emit F64x2Sqrt $($rest:tt)*) => {};
+    (emit F64x2Add $($rest:tt)*) => {};
+    (emit F64x2Sub $($rest:tt)*) => {};
+    (emit F64x2Mul $($rest:tt)*) => {};
+    (emit F64x2Div $($rest:tt)*) => {};
+    (emit F64x2Min $($rest:tt)*) => {};
+    (emit F64x2Max $($rest:tt)*) => {};
+    (emit F64x2PMin $($rest:tt)*) => {};
+    (emit F64x2PMax $($rest:tt)*) => {};
+    (emit I32x4TruncSatF32x4S $($rest:tt)*) => {};
+    (emit I32x4TruncSatF32x4U $($rest:tt)*) => {};
+    (emit F32x4ConvertI32x4S $($rest:tt)*) => {};
+    (emit F32x4ConvertI32x4U $($rest:tt)*) => {};
+    (emit I32x4TruncSatF64x2SZero $($rest:tt)*) => {};
+    (emit I32x4TruncSatF64x2UZero $($rest:tt)*) => {};
+    (emit F64x2ConvertLowI32x4S $($rest:tt)*) => {};
+    (emit F64x2ConvertLowI32x4U $($rest:tt)*) => {};
+    (emit F32x4DemoteF64x2Zero $($rest:tt)*) => {};
+    (emit F64x2PromoteLowF32x4 $($rest:tt)*) => {};
+
+    (emit MemoryAtomicNotify $($rest:tt)*) => {};
+    (emit MemoryAtomicWait32 $($rest:tt)*) => {};
+    (emit MemoryAtomicWait64 $($rest:tt)*) => {};
+    (emit AtomicFence $($rest:tt)*) => {};
+    (emit I32AtomicLoad $($rest:tt)*) => {};
+    (emit I64AtomicLoad $($rest:tt)*) => {};
+    (emit I32AtomicLoad8U $($rest:tt)*) => {};
+    (emit I32AtomicLoad16U $($rest:tt)*) => {};
+    (emit I64AtomicLoad8U $($rest:tt)*) => {};
+    (emit I64AtomicLoad16U $($rest:tt)*) => {};
+    (emit I64AtomicLoad32U $($rest:tt)*) => {};
+    (emit I32AtomicStore $($rest:tt)*) => {};
+    (emit I64AtomicStore $($rest:tt)*) => {};
+    (emit I32AtomicStore8 $($rest:tt)*) => {};
+    (emit I32AtomicStore16 $($rest:tt)*) => {};
+    (emit I64AtomicStore8 $($rest:tt)*) => {};
+    (emit I64AtomicStore16 $($rest:tt)*) => {};
+    (emit I64AtomicStore32 $($rest:tt)*) => {};
+    (emit I32AtomicRmwAdd $($rest:tt)*) => {};
+    (emit I64AtomicRmwAdd $($rest:tt)*) => {};
+    (emit I32AtomicRmw8AddU $($rest:tt)*) => {};
+    (emit I32AtomicRmw16AddU $($rest:tt)*) => {};
+    (emit I64AtomicRmw8AddU $($rest:tt)*) => {};
+    (emit I64AtomicRmw16AddU $($rest:tt)*) => {};
+    (emit I64AtomicRmw32AddU $($rest:tt)*) => {};
+    (emit I32AtomicRmwSub $($rest:tt)*) => {};
+    (emit I64AtomicRmwSub $($rest:tt)*) => {};
+    (emit I32AtomicRmw8SubU $($rest:tt)*) => {};
+    (emit I32AtomicRmw16SubU $($rest:tt)*) => {};
+    (emit I64AtomicRmw8SubU $($rest:tt)*) => {};
+    (emit I64AtomicRmw16SubU $($rest:tt)*) => {};
+    (emit I64AtomicRmw32SubU $($rest:tt)*) => {};
+    (emit I32AtomicRmwAnd $($rest:tt)*) => {};
+    (emit I64AtomicRmwAnd $($rest:tt)*) => {};
+    (emit I32AtomicRmw8AndU $($rest:tt)*) => {};
+    (emit I32AtomicRmw16AndU $($rest:tt)*) => {};
+    (emit I64AtomicRmw8AndU $($rest:tt)*) => {};
+    (emit I64AtomicRmw16AndU $($rest:tt)*) => {};
+    (emit I64AtomicRmw32AndU $($rest:tt)*) => {};
+    (emit I32AtomicRmwOr $($rest:tt)*) => {};
+    (emit I64AtomicRmwOr $($rest:tt)*) => {};
+    (emit I32AtomicRmw8OrU $($rest:tt)*) => {};
+    (emit I32AtomicRmw16OrU $($rest:tt)*) => {};
+    (emit I64AtomicRmw8OrU $($rest:tt)*) => {};
+    (emit I64AtomicRmw16OrU $($rest:tt)*) => {};
+    (emit I64AtomicRmw32OrU $($rest:tt)*) => {};
+    (emit I32AtomicRmwXor $($rest:tt)*) => {};
+    (emit I64AtomicRmwXor $($rest:tt)*) => {};
+    (emit I32AtomicRmw8XorU $($rest:tt)*) => {};
+    (emit I32AtomicRmw16XorU $($rest:tt)*) => {};
+    (emit I64AtomicRmw8XorU $($rest:tt)*) => {};
+    (emit I64AtomicRmw16XorU $($rest:tt)*) => {};
+    (emit I64AtomicRmw32XorU $($rest:tt)*) => {};
+    (emit I32AtomicRmwXchg $($rest:tt)*) => {};
+    (emit I64AtomicRmwXchg $($rest:tt)*) => {};
+    (emit I32AtomicRmw8XchgU $($rest:tt)*) => {};
+    (emit I32AtomicRmw16XchgU $($rest:tt)*) => {};
+    (emit I64AtomicRmw8XchgU $($rest:tt)*) => {};
+    (emit I64AtomicRmw16XchgU $($rest:tt)*) => {};
+    (emit I64AtomicRmw32XchgU $($rest:tt)*) => {};
+    (emit I32AtomicRmwCmpxchg $($rest:tt)*) => {};
+    (emit I64AtomicRmwCmpxchg $($rest:tt)*) => {};
+    (emit I32AtomicRmw8CmpxchgU $($rest:tt)*) => {};
+    (emit I32AtomicRmw16CmpxchgU $($rest:tt)*) => {};
+    (emit I64AtomicRmw8CmpxchgU $($rest:tt)*) => {};
+    (emit I64AtomicRmw16CmpxchgU $($rest:tt)*) => {};
+    (emit I64AtomicRmw32CmpxchgU $($rest:tt)*) => {};
 
     (emit $unsupported:tt $($rest:tt)*) => {$($rest)*};
 }
@@ -1244,12 +1587,7 @@ where
             .get_local(index)
             .unwrap_or_else(|| panic!("valid local at slot = {}", index));
         match slot.ty {
-            I32 | I64 | F32 | F64 => context.stack.push(Val::local(index, slot.ty)),
-            Ref(rt) => match rt.heap_type {
-                WasmHeapType::Func => context.stack.push(Val::local(index, slot.ty)),
-                ht => unimplemented!("Support for WasmHeapType: {ht}"),
-            },
-            t => unimplemented!("Support local type: {t}"),
+            I32 | I64 | F32 | F64 | V128 | Ref(_) => context.stack.push(Val::local(index, slot.ty)),
         }
     }
 
@@ -1294,6 +1632,166 @@ where
         })
     }
 
+    fn visit_call_ref(&mut self, type_index: u32) {
+        let funcref = self.context.pop_to_reg(self.masm, None);
+        self.masm.trapz(funcref.reg, TrapCode::NullReference);
+        self.context.stack.push(funcref.into());
+
+        let type_index = TypeIndex::from_u32(type_index);
+        FnCall::emit::<M, M::Ptr, _>(self.masm, &mut self.context, |_| {
+            self.env.funcref(type_index)
+        })
+    }
+
+    fn visit_return_call(&mut self, index: u32) {
+        let callee = self.env.callee_from_index(FuncIndex::from_u32(index));
+        let caller_stack_args_size = self.sig.callee_popped_stack_size();
+        FnCall::emit_return_call::<M, M::Ptr, _>(
+            self.masm,
+            &mut self.context,
+            caller_stack_args_size,
+            |_| callee.clone(),
+        );
+        self.emit_tail_call_end();
+    }
+
+    fn visit_return_call_indirect(&mut self, type_index: u32, table_index: u32) {
+        // See the comment in `visit_call_indirect` on why the value stack is
+        // spilled upfront.
+        self.context.spill(self.masm);
+
+        let type_index = TypeIndex::from_u32(type_index);
+        let table_index = TableIndex::from_u32(table_index);
+
+        self.emit_lazy_init_funcref(table_index);
+
+        match self.env.translation.module.table_plans[table_index].style {
+            TableStyle::CallerChecksSignature => {
+                let funcref_ptr = self.context.stack.peek().map(|v| v.unwrap_reg()).unwrap();
+                self.masm
+                    .trapz(funcref_ptr.into(), TrapCode::IndirectCallToNull);
+                self.emit_typecheck_funcref(funcref_ptr.into(), type_index);
+            }
+        }
+
+        let caller_stack_args_size = self.sig.callee_popped_stack_size();
+        FnCall::emit_return_call::<M, M::Ptr, _>(
+            self.masm,
+            &mut self.context,
+            caller_stack_args_size,
+            |_| self.env.funcref(type_index),
+        );
+        self.emit_tail_call_end();
+    }
+
+    fn visit_return_call_ref(&mut self, type_index: u32) {
+        let funcref = self.context.pop_to_reg(self.masm, None);
+        self.masm.trapz(funcref.reg, TrapCode::NullReference);
+        self.context.stack.push(funcref.into());
+
+        let type_index = TypeIndex::from_u32(type_index);
+        let caller_stack_args_size = self.sig.callee_popped_stack_size();
+        FnCall::emit_return_call::<M, M::Ptr, _>(
+            self.masm,
+            &mut self.context,
+            caller_stack_args_size,
+            |_| self.env.funcref(type_index),
+        );
+        self.emit_tail_call_end();
+    }
+
+    fn visit_ref_null(&mut self, hty: wasmparser::HeapType) {
+        let ty = WasmType::Ref(WasmRefType {
+            nullable: true,
+            heap_type: self.env.convert_heap_type(hty),
+        });
+        let dst = self.context.any_gpr(self.masm);
+        self.masm.mov(RegImm::i64(0), dst, ty.into());
+        self.context.stack.push(Val::reg(dst, ty));
+    }
+
+    fn visit_ref_is_null(&mut self) {
+        self.context
+            .unop(self.masm, OperandSize::S64, &mut |masm, reg, size| {
+                masm.cmp_with_set(RegImm::i64(0), reg, IntCmpKind::Eq, size);
+                TypedReg::i32(reg)
+            });
+    }
+
+    fn visit_ref_func(&mut self, function_index: u32) {
+        let ptr_type = self.env.ptr_type();
+        let vmctx = TypedReg::new(ptr_type, <M::ABI as ABI>::vmctx_reg());
+        self.context
+            .stack
+            .extend([vmctx.into(), function_index.try_into().unwrap()]);
+        FnCall::emit::<M, M::Ptr, _>(self.masm, &mut self.context, |cx| {
+            Callee::Builtin(cx.builtins.ref_func::<M::ABI, M::Ptr>())
+        });
+
+        // The built-in function returns a pointer; retype it as a non-null
+        // reference to the function's type.
+        let sig =
+            self.env.translation.module.functions[FuncIndex::from_u32(function_index)].signature;
+        let ty = WasmType::Ref(WasmRefType {
+            nullable: false,
+            heap_type: WasmHeapType::TypedFunc(sig),
+        });
+        let funcref = self.context.pop_to_reg(self.masm, None);
+        self.context.stack.push(Val::reg(funcref.reg, ty));
+    }
+
+    fn visit_ref_as_non_null(&mut self) {
+        let reference = self.context.pop_to_reg(self.masm, None);
+        self.masm.trapz(reference.reg, TrapCode::NullReference);
+        self.context.stack.push(reference.into());
+    }
+
+    fn visit_br_on_null(&mut self, depth: u32) {
+        let index = control_index(depth, self.control_frames.len());
+        let frame = &mut self.control_frames[index];
+        frame.set_as_target();
+
+        // The reference is not part of the branch results; it remains in
+        // the value stack if the branch is not taken.
+        let reference = self.context.without::<TypedReg, M, _>(
+            frame.results::<M>().regs(),
+            self.masm,
+            |ctx, masm| ctx.pop_to_reg(masm, None),
+        );
+        self.emit_br_cond(index, reference.reg, IntCmpKind::Eq, OperandSize::S64);
+        self.context.stack.push(reference.into());
+    }
+
+    fn visit_br_on_non_null(&mut self, depth: u32) {
+        let index = control_index(depth, self.control_frames.len());
+        let frame = &mut self.control_frames[index];
+        frame.set_as_target();
+
+        // The reference is the last of the branch results, which might
+        // be moved to its result location before branching; keep a copy
+        // to check against null.
+        let cond = self.context.without::<Reg, M, _>(
+            frame.results::<M>().regs(),
+            self.masm,
+            |ctx, masm| {
+                let reference = ctx.pop_to_reg(masm, None);
+                let cond = ctx.any_gpr(masm);
+                masm.mov(reference.reg.into(), cond, OperandSize::S64);
+                ctx.stack.push(reference.into());
+                cond
+            },
+        );
+        self.emit_br_cond(index, cond, IntCmpKind::Ne, OperandSize::S64);
+        self.context.free_reg(cond);
+
+        // Drop the null reference when falling through.
+        self.visit_drop();
+    }
+
+    fn visit_typed_select(&mut self, _: wasmparser::ValType) {
+        self.visit_select();
+    }
+
     fn visit_table_init(&mut self, elem: u32, table: u32) {
         let ptr_type = self.env.ptr_type();
         let vmctx = TypedReg::new(ptr_type, <M::ABI as ABI>::vmctx_reg());
@@ -1339,11 +1837,12 @@ where
         let heap_type = plan.table.wasm_ty.heap_type;
         let style = &plan.style;
 
-        match heap_type {
+        match heap_type.top() {
             WasmHeapType::Func => match style {
                 TableStyle::CallerChecksSignature => self.emit_lazy_init_funcref(table_index),
             },
-            t => unimplemented!("Support for WasmHeapType: {t}"),
+            WasmHeapType::Extern => self.emit_externref_table_get(table_index),
+            _ => unreachable!("GC tables are rejected during translation"),
         }
     }
 
@@ -1352,12 +1851,16 @@ where
         let vmctx = TypedReg::new(ptr_type, <M::ABI as ABI>::vmctx_reg());
         let table_index = TableIndex::from_u32(table);
         let table_plan = self.env.table_plan(table_index);
-        let builtin = match table_plan.table.wasm_ty.heap_type {
+        let builtin = match table_plan.table.wasm_ty.heap_type.top() {
             WasmHeapType::Func => self
                 .context
                 .builtins
                 .table_grow_func_ref::<M::ABI, M::Ptr>(),
-            ty => unimplemented!("Support for HeapType: {ty}"),
+            WasmHeapType::Extern => self
+                .context
+                .builtins
+                .table_grow_externref::<M::ABI, M::Ptr>(),
+            _ => unreachable!("GC tables are rejected during translation"),
         };
 
         let len = self.context.stack.len();
@@ -1392,12 +1895,16 @@ where
         let vmctx = TypedReg::new(ptr_type, <M::ABI as ABI>::vmctx_reg());
         let table_index = TableIndex::from_u32(table);
         let table_plan = self.env.table_plan(table_index);
-        let builtin = match table_plan.table.wasm_ty.heap_type {
+        let builtin = match table_plan.table.wasm_ty.heap_type.top() {
             WasmHeapType::Func => self
                 .context
                 .builtins
                 .table_fill_func_ref::<M::ABI, M::Ptr>(),
-            ty => unimplemented!("Support for heap type: {ty}"),
+            WasmHeapType::Extern => self
+                .context
+                .builtins
+                .table_fill_externref::<M::ABI, M::Ptr>(),
+            _ => unreachable!("GC tables are rejected during translation"),
         };
 
         let len = self.context.stack.len();
@@ -1416,7 +1923,7 @@ where
         let table_index = TableIndex::from_u32(table);
         let table_data = self.env.resolve_table_data(table_index);
         let plan = self.env.table_plan(table_index);
-        match plan.table.wasm_ty.heap_type {
+        match plan.table.wasm_ty.heap_type.top() {
             WasmHeapType::Func => match plan.style {
                 TableStyle::CallerChecksSignature => {
                     let value = self.context.pop_to_reg(self.masm, None);
@@ -1444,7 +1951,22 @@ where
                     self.context.free_reg(base);
                 }
             },
-            ty => unimplemented!("Support for WasmHeapType: {ty}"),
+            WasmHeapType::Extern => {
+                // Setting an element is equivalent to filling a single
+                // element; the built-in function takes care of updating the
+                // reference counts of the new and previous elements.
+                let vmctx = TypedReg::new(ptr_type, <M::ABI as ABI>::vmctx_reg());
+                debug_assert!(self.context.stack.len() >= 2);
+                let at = self.context.stack.len() - 2;
+                self.context
+                    .stack
+                    .insert_many(at, [vmctx.into(), table.try_into().unwrap()]);
+                self.context.stack.push(Val::i32(1));
+                FnCall::emit::<M, M::Ptr, _>(self.masm, &mut self.context, |cx| {
+                    Callee::Builtin(cx.builtins.table_fill_externref::<M::ABI, M::Ptr>())
+                });
+            }
+            _ => unreachable!("GC tables are rejected during translation"),
         };
     }
 
@@ -1587,6 +2109,17 @@ where
             self.masm,
             &mut self.context,
         ));
+
+        // Loop headers are the only points at which a function can run for an
+        // unbounded amount of time without making a call, so fuel and epoch
+        // checks are emitted right after binding the loop header.
+        if self.env.tunables.consume_fuel {
+            self.emit_fuel_check();
+        }
+
+        if self.env.tunables.epoch_interruption {
+            self.emit_epoch_check();
+        }
     }
 
     fn visit_br(&mut self, depth: u32) {
@@ -1604,65 +2137,13 @@ where
         let frame = &mut self.control_frames[index];
         frame.set_as_target();
 
-        let top = {
-            let top = self.context.without::<TypedReg, M, _>(
-                frame.results::<M>().regs(),
-                self.masm,
-                |ctx, masm| ctx.pop_to_reg(masm, None),
-            );
-            frame.top_abi_results::<M, _>(
-                &mut self.context,
-                self.masm,
-                |results, context, masm| {
-                    // In the case of `br_if` theres a possibility that we'll
-                    // exit early from the block or falltrough, for
-                    // a falltrough, we cannot rely on the pre-computed return area;
-                    // it must be recalculated so that any values that are
-                    // generated are correctly placed near the current stack
-                    // pointer.
-                    results.on_stack().then(|| {
-                        let stack_consumed = context.stack.sizeof(results.stack_operands_len());
-                        let base = masm.sp_offset().as_u32() - stack_consumed;
-                        let offs = base + results.size();
-                        RetArea::sp(SPOffset::from_u32(offs))
-                    })
-                },
-            );
-            top
-        };
-
-        // Emit instructions to balance the machine stack if the frame has
-        // a different offset.
-        let current_sp_offset = self.masm.sp_offset();
-        let results_size = frame.results::<M>().size();
-        let state = frame.stack_state();
-        let (label, cmp, needs_cleanup) = if current_sp_offset > state.target_offset {
-            (self.masm.get_label(), IntCmpKind::Eq, true)
-        } else {
-            (*frame.label(), IntCmpKind::Ne, false)
-        };
-
-        self.masm
-            .branch(cmp, top.reg.into(), top.reg.into(), label, OperandSize::S32);
+        let top = self.context.without::<TypedReg, M, _>(
+            frame.results::<M>().regs(),
+            self.masm,
+            |ctx, masm| ctx.pop_to_reg(masm, None),
+        );
+        self.emit_br_cond(index, top.reg, IntCmpKind::Ne, OperandSize::S32);
         self.context.free_reg(top);
-
-        if needs_cleanup {
-            // Emit instructions to balance the stack and jump if not falling
-            // through.
-            self.masm.memmove(
-                current_sp_offset,
-                state.target_offset,
-                results_size,
-                MemMoveDirection::LowToHigh,
-            );
-            self.masm.ensure_sp_for_jump(state.target_offset);
-            self.masm.jmp(*frame.label());
-
-            // Restore sp_offset to what it was for falling through and emit
-            // fallthrough label.
-            self.masm.reset_stack_pointer(current_sp_offset);
-            self.masm.bind(label);
-        }
     }
 
     fn visit_br_table(&mut self, targets: BrTable<'a>) {
@@ -1762,6 +2243,18 @@ where
     fn visit_global_get(&mut self, global_index: u32) {
         let index = GlobalIndex::from_u32(global_index);
         let (ty, offset) = self.env.resolve_global_type_and_offset(index);
+        if is_externref(&ty) {
+            let ptr_type = self.env.ptr_type();
+            let vmctx = TypedReg::new(ptr_type, <M::ABI as ABI>::vmctx_reg());
+            self.context
+                .stack
+                .extend([vmctx.into(), global_index.try_into().unwrap()]);
+            FnCall::emit::<M, M::Ptr, _>(self.masm, &mut self.context, |cx| {
+                Callee::Builtin(cx.builtins.externref_global_get::<M::ABI, M::Ptr>())
+            });
+            return;
+        }
+
         let addr = self
             .masm
             .address_at_reg(<M::ABI as ABI>::vmctx_reg(), offset);
@@ -1773,6 +2266,20 @@ where
     fn visit_global_set(&mut self, global_index: u32) {
         let index = GlobalIndex::from_u32(global_index);
         let (ty, offset) = self.env.resolve_global_type_and_offset(index);
+        if is_externref(&ty) {
+            let ptr_type = self.env.ptr_type();
+            let vmctx = TypedReg::new(ptr_type, <M::ABI as ABI>::vmctx_reg());
+            debug_assert!(self.context.stack.len() >= 1);
+            let at = self.context.stack.len() - 1;
+            self.context
+                .stack
+                .insert_many(at, [vmctx.into(), global_index.try_into().unwrap()]);
+            FnCall::emit::<M, M::Ptr, _>(self.masm, &mut self.context, |cx| {
+                Callee::Builtin(cx.builtins.externref_global_set::<M::ABI, M::Ptr>())
+            });
+            return;
+        }
+
         let addr = self
             .masm
             .address_at_reg(<M::ABI as ABI>::vmctx_reg(), offset);
@@ -1804,45 +2311,1637 @@ where
         self.context.free_reg(cond);
     }
 
-    wasmparser::for_each_operator!(def_unsupported);
-}
+    fn visit_i32_load(&mut self, memarg: MemArg) {
+        self.emit_wasm_load(&memarg, WasmType::I32, OperandSize::S32, None);
+    }
 
-impl<'a, 'translation, 'data, M> CodeGen<'a, 'translation, 'data, M>
-where
-    M: MacroAssembler,
-{
-    fn cmp_i32s(&mut self, kind: IntCmpKind) {
-        self.context.i32_binop(self.masm, |masm, dst, src, size| {
-            masm.cmp_with_set(src, dst, kind, size);
-            TypedReg::i32(dst)
-        });
+    fn visit_i32_load8_s(&mut self, memarg: MemArg) {
+        self.emit_wasm_load(
+            &memarg,
+            WasmType::I32,
+            OperandSize::S8,
+            Some(ExtendKind::I32Extend8S),
+        );
     }
 
-    fn cmp_i64s(&mut self, kind: IntCmpKind) {
-        self.context
-            .i64_binop(self.masm, move |masm, dst, src, size| {
-                masm.cmp_with_set(src, dst, kind, size);
-                TypedReg::i32(dst) // Return value for comparisons is an `i32`.
-            });
+    fn visit_i32_load8_u(&mut self, memarg: MemArg) {
+        self.emit_wasm_load(&memarg, WasmType::I32, OperandSize::S8, None);
     }
-}
 
-impl From<WasmType> for OperandSize {
-    fn from(ty: WasmType) -> OperandSize {
-        match ty {
-            WasmType::I32 | WasmType::F32 => OperandSize::S32,
-            WasmType::I64 | WasmType::F64 => OperandSize::S64,
-            WasmType::Ref(rt) => {
-                match rt.heap_type {
-                    // TODO: Harcoded size, assuming 64-bit support only. Once
-                    // Wasmtime supports 32-bit architectures, this will need
-                    // to be updated in such a way that the calculation of the
-                    // OperandSize will depend on the target's  pointer size.
-                    WasmHeapType::Func => OperandSize::S64,
-                    t => unimplemented!("Support for WasmHeapType: {t}"),
-                }
-            }
-            ty => unimplemented!("Support for WasmType {ty}"),
+    fn visit_i32_load16_s(&mut self, memarg: MemArg) {
+        self.emit_wasm_load(
+            &memarg,
+            WasmType::I32,
+            OperandSize::S16,
+            Some(ExtendKind::I32Extend16S),
+        );
+    }
+
+    fn visit_i32_load16_u(&mut self, memarg: MemArg) {
+        self.emit_wasm_load(&memarg, WasmType::I32, OperandSize::S16, None);
+    }
+
+    fn visit_i64_load(&mut self, memarg: MemArg) {
+        self.emit_wasm_load(&memarg, WasmType::I64, OperandSize::S64, None);
+    }
+
+    fn visit_i64_load8_s(&mut self, memarg: MemArg) {
+        self.emit_wasm_load(
+            &memarg,
+            WasmType::I64,
+            OperandSize::S8,
+            Some(ExtendKind::I64Extend8S),
+        );
+    }
+
+    fn visit_i64_load8_u(&mut self, memarg: MemArg) {
+        self.emit_wasm_load(&memarg, WasmType::I64, OperandSize::S8, None);
+    }
+
+    fn visit_i64_load16_s(&mut self, memarg: MemArg) {
+        self.emit_wasm_load(
+            &memarg,
+            WasmType::I64,
+            OperandSize::S16,
+            Some(ExtendKind::I64Extend16S),
+        );
+    }
+
+    fn visit_i64_load16_u(&mut self, memarg: MemArg) {
+        self.emit_wasm_load(&memarg, WasmType::I64, OperandSize::S16, None);
+    }
+
+    fn visit_i64_load32_s(&mut self, memarg: MemArg) {
+        self.emit_wasm_load(
+            &memarg,
+            WasmType::I64,
+            OperandSize::S32,
+            Some(ExtendKind::I64Extend32S),
+        );
+    }
+
+    fn visit_i64_load32_u(&mut self, memarg: MemArg) {
+        self.emit_wasm_load(&memarg, WasmType::I64, OperandSize::S32, None);
+    }
+
+    fn visit_f32_load(&mut self, memarg: MemArg) {
+        self.emit_wasm_load(&memarg, WasmType::F32, OperandSize::S32, None);
+    }
+
+    fn visit_f64_load(&mut self, memarg: MemArg) {
+        self.emit_wasm_load(&memarg, WasmType::F64, OperandSize::S64, None);
+    }
+
+    fn visit_i32_store(&mut self, memarg: MemArg) {
+        self.emit_wasm_store(&memarg, OperandSize::S32);
+    }
+
+    fn visit_i32_store8(&mut self, memarg: MemArg) {
+        self.emit_wasm_store(&memarg, OperandSize::S8);
+    }
+
+    fn visit_i32_store16(&mut self, memarg: MemArg) {
+        self.emit_wasm_store(&memarg, OperandSize::S16);
+    }
+
+    fn visit_i64_store(&mut self, memarg: MemArg) {
+        self.emit_wasm_store(&memarg, OperandSize::S64);
+    }
+
+    fn visit_i64_store8(&mut self, memarg: MemArg) {
+        self.emit_wasm_store(&memarg, OperandSize::S8);
+    }
+
+    fn visit_i64_store16(&mut self, memarg: MemArg) {
+        self.emit_wasm_store(&memarg, OperandSize::S16);
+    }
+
+    fn visit_i64_store32(&mut self, memarg: MemArg) {
+        self.emit_wasm_store(&memarg, OperandSize::S32);
+    }
+
+    fn visit_f32_store(&mut self, memarg: MemArg) {
+        self.emit_wasm_store(&memarg, OperandSize::S32);
+    }
+
+    fn visit_f64_store(&mut self, memarg: MemArg) {
+        self.emit_wasm_store(&memarg, OperandSize::S64);
+    }
+
+    fn visit_v128_load(&mut self, memarg: MemArg) {
+        self.emit_wasm_load(&memarg, WasmType::V128, OperandSize::S128, None);
+    }
+
+    fn visit_v128_load8x8_s(&mut self, memarg: MemArg) {
+        self.emit_wasm_load_extend(&memarg, V128ExtendKind::LowI8x16S);
+    }
+
+    fn visit_v128_load8x8_u(&mut self, memarg: MemArg) {
+        self.emit_wasm_load_extend(&memarg, V128ExtendKind::LowI8x16U);
+    }
+
+    fn visit_v128_load16x4_s(&mut self, memarg: MemArg) {
+        self.emit_wasm_load_extend(&memarg, V128ExtendKind::LowI16x8S);
+    }
+
+    fn visit_v128_load16x4_u(&mut self, memarg: MemArg) {
+        self.emit_wasm_load_extend(&memarg, V128ExtendKind::LowI16x8U);
+    }
+
+    fn visit_v128_load32x2_s(&mut self, memarg: MemArg) {
+        self.emit_wasm_load_extend(&memarg, V128ExtendKind::LowI32x4S);
+    }
+
+    fn visit_v128_load32x2_u(&mut self, memarg: MemArg) {
+        self.emit_wasm_load_extend(&memarg, V128ExtendKind::LowI32x4U);
+    }
+
+    fn visit_v128_load8_splat(&mut self, memarg: MemArg) {
+        self.emit_wasm_load_splat(&memarg, VectorShape::I8x16);
+    }
+
+    fn visit_v128_load16_splat(&mut self, memarg: MemArg) {
+        self.emit_wasm_load_splat(&memarg, VectorShape::I16x8);
+    }
+
+    fn visit_v128_load32_splat(&mut self, memarg: MemArg) {
+        self.emit_wasm_load_splat(&memarg, VectorShape::I32x4);
+    }
+
+    fn visit_v128_load64_splat(&mut self, memarg: MemArg) {
+        self.emit_wasm_load_splat(&memarg, VectorShape::I64x2);
+    }
+
+    fn visit_v128_load32_zero(&mut self, memarg: MemArg) {
+        self.emit_wasm_load(&memarg, WasmType::V128, OperandSize::S32, None);
+    }
+
+    fn visit_v128_load64_zero(&mut self, memarg: MemArg) {
+        self.emit_wasm_load(&memarg, WasmType::V128, OperandSize::S64, None);
+    }
+
+    fn visit_v128_store(&mut self, memarg: MemArg) {
+        self.emit_wasm_store(&memarg, OperandSize::S128);
+    }
+
+    fn visit_v128_load8_lane(&mut self, memarg: MemArg, lane: u8) {
+        self.emit_wasm_load_lane(&memarg, lane, VectorShape::I8x16);
+    }
+
+    fn visit_v128_load16_lane(&mut self, memarg: MemArg, lane: u8) {
+        self.emit_wasm_load_lane(&memarg, lane, VectorShape::I16x8);
+    }
+
+    fn visit_v128_load32_lane(&mut self, memarg: MemArg, lane: u8) {
+        self.emit_wasm_load_lane(&memarg, lane, VectorShape::I32x4);
+    }
+
+    fn visit_v128_load64_lane(&mut self, memarg: MemArg, lane: u8) {
+        self.emit_wasm_load_lane(&memarg, lane, VectorShape::I64x2);
+    }
+
+    fn visit_v128_store8_lane(&mut self, memarg: MemArg, lane: u8) {
+        self.emit_wasm_store_lane(&memarg, lane, VectorShape::I8x16);
+    }
+
+    fn visit_v128_store16_lane(&mut self, memarg: MemArg, lane: u8) {
+        self.emit_wasm_store_lane(&memarg, lane, VectorShape::I16x8);
+    }
+
+    fn visit_v128_store32_lane(&mut self, memarg: MemArg, lane: u8) {
+        self.emit_wasm_store_lane(&memarg, lane, VectorShape::I32x4);
+    }
+
+    fn visit_v128_store64_lane(&mut self, memarg: MemArg, lane: u8) {
+        self.emit_wasm_store_lane(&memarg, lane, VectorShape::I64x2);
+    }
+
+    fn visit_v128_const(&mut self, val: V128) {
+        self.context.stack.push(Val::v128(val.i128()));
+    }
+
+    fn visit_i8x16_shuffle(&mut self, lanes: [u8; 16]) {
+        self.v128_binop(|masm, dst, src| masm.shuffle(dst, dst, src, lanes));
+    }
+
+    fn visit_i8x16_extract_lane_s(&mut self, lane: u8) {
+        self.extract_lane(lane, VectorShape::I8x16, Some(ExtendKind::I32Extend8S));
+    }
+
+    fn visit_i8x16_extract_lane_u(&mut self, lane: u8) {
+        self.extract_lane(lane, VectorShape::I8x16, None);
+    }
+
+    fn visit_i8x16_replace_lane(&mut self, lane: u8) {
+        self.replace_lane(lane, VectorShape::I8x16);
+    }
+
+    fn visit_i16x8_extract_lane_s(&mut self, lane: u8) {
+        self.extract_lane(lane, VectorShape::I16x8, Some(ExtendKind::I32Extend16S));
+    }
+
+    fn visit_i16x8_extract_lane_u(&mut self, lane: u8) {
+        self.extract_lane(lane, VectorShape::I16x8, None);
+    }
+
+    fn visit_i16x8_replace_lane(&mut self, lane: u8) {
+        self.replace_lane(lane, VectorShape::I16x8);
+    }
+
+    fn visit_i32x4_extract_lane(&mut self, lane: u8) {
+        self.extract_lane(lane, VectorShape::I32x4, None);
+    }
+
+    fn visit_i32x4_replace_lane(&mut self, lane: u8) {
+        self.replace_lane(lane, VectorShape::I32x4);
+    }
+
+    fn visit_i64x2_extract_lane(&mut self, lane: u8) {
+        self.extract_lane(lane, VectorShape::I64x2, None);
+    }
+
+    fn visit_i64x2_replace_lane(&mut self, lane: u8) {
+        self.replace_lane(lane, VectorShape::I64x2);
+    }
+
+    fn visit_f32x4_extract_lane(&mut self, lane: u8) {
+        self.extract_lane(lane, VectorShape::F32x4, None);
+    }
+
+    fn visit_f32x4_replace_lane(&mut self, lane: u8) {
+        self.replace_lane(lane, VectorShape::F32x4);
+    }
+
+    fn visit_f64x2_extract_lane(&mut self, lane: u8) {
+        self.extract_lane(lane, VectorShape::F64x2, None);
+    }
+
+    fn visit_f64x2_replace_lane(&mut self, lane: u8) {
+        self.replace_lane(lane, VectorShape::F64x2);
+    }
+
+    fn visit_i8x16_swizzle(&mut self) {
+        self.v128_binop(|masm, dst, src| masm.swizzle(dst, dst, src));
+    }
+
+    fn visit_i8x16_splat(&mut self) {
+        self.splat(VectorShape::I8x16);
+    }
+
+    fn visit_i16x8_splat(&mut self) {
+        self.splat(VectorShape::I16x8);
+    }
+
+    fn visit_i32x4_splat(&mut self) {
+        self.splat(VectorShape::I32x4);
+    }
+
+    fn visit_i64x2_splat(&mut self) {
+        self.splat(VectorShape::I64x2);
+    }
+
+    fn visit_f32x4_splat(&mut self) {
+        self.splat(VectorShape::F32x4);
+    }
+
+    fn visit_f64x2_splat(&mut self) {
+        self.splat(VectorShape::F64x2);
+    }
+
+    fn visit_i8x16_eq(&mut self) {
+        self.v128_int_cmp(IntCmpKind::Eq, OperandSize::S8);
+    }
+
+    fn visit_i8x16_ne(&mut self) {
+        self.v128_int_cmp(IntCmpKind::Ne, OperandSize::S8);
+    }
+
+    fn visit_i8x16_lt_s(&mut self) {
+        self.v128_int_cmp(IntCmpKind::LtS, OperandSize::S8);
+    }
+
+    fn visit_i8x16_lt_u(&mut self) {
+        self.v128_int_cmp(IntCmpKind::LtU, OperandSize::S8);
+    }
+
+    fn visit_i8x16_gt_s(&mut self) {
+        self.v128_int_cmp(IntCmpKind::GtS, OperandSize::S8);
+    }
+
+    fn visit_i8x16_gt_u(&mut self) {
+        self.v128_int_cmp(IntCmpKind::GtU, OperandSize::S8);
+    }
+
+    fn visit_i8x16_le_s(&mut self) {
+        self.v128_int_cmp(IntCmpKind::LeS, OperandSize::S8);
+    }
+
+    fn visit_i8x16_le_u(&mut self) {
+        self.v128_int_cmp(IntCmpKind::LeU, OperandSize::S8);
+    }
+
+    fn visit_i8x16_ge_s(&mut self) {
+        self.v128_int_cmp(IntCmpKind::GeS, OperandSize::S8);
+    }
+
+    fn visit_i8x16_ge_u(&mut self) {
+        self.v128_int_cmp(IntCmpKind::GeU, OperandSize::S8);
+    }
+
+    fn visit_i16x8_eq(&mut self) {
+        self.v128_int_cmp(IntCmpKind::Eq, OperandSize::S16);
+    }
+
+    fn visit_i16x8_ne(&mut self) {
+        self.v128_int_cmp(IntCmpKind::Ne, OperandSize::S16);
+    }
+
+    fn visit_i16x8_lt_s(&mut self) {
+        self.v128_int_cmp(IntCmpKind::LtS, OperandSize::S16);
+    }
+
+    fn visit_i16x8_lt_u(&mut self) {
+        self.v128_int_cmp(IntCmpKind::LtU, OperandSize::S16);
+    }
+
+    fn visit_i16x8_gt_s(&mut self) {
+        self.v128_int_cmp(IntCmpKind::GtS, OperandSize::S16);
+    }
+
+    fn visit_i16x8_gt_u(&mut self) {
+        self.v128_int_cmp(IntCmpKind::GtU, OperandSize::S16);
+    }
+
+    fn visit_i16x8_le_s(&mut self) {
+        self.v128_int_cmp(IntCmpKind::LeS, OperandSize::S16);
+    }
+
+    fn visit_i16x8_le_u(&mut self) {
+        self.v128_int_cmp(IntCmpKind::LeU, OperandSize::S16);
+    }
+
+    fn visit_i16x8_ge_s(&mut self) {
+        self.v128_int_cmp(IntCmpKind::GeS, OperandSize::S16);
+    }
+
+    fn visit_i16x8_ge_u(&mut self) {
+        self.v128_int_cmp(IntCmpKind::GeU, OperandSize::S16);
+    }
+
+    fn visit_i32x4_eq(&mut self) {
+        self.v128_int_cmp(IntCmpKind::Eq, OperandSize::S32);
+    }
+
+    fn visit_i32x4_ne(&mut self) {
+        self.v128_int_cmp(IntCmpKind::Ne, OperandSize::S32);
+    }
+
+    fn visit_i32x4_lt_s(&mut self) {
+        self.v128_int_cmp(IntCmpKind::LtS, OperandSize::S32);
+    }
+
+    fn visit_i32x4_lt_u(&mut self) {
+        self.v128_int_cmp(IntCmpKind::LtU, OperandSize::S32);
+    }
+
+    fn visit_i32x4_gt_s(&mut self) {
+        self.v128_int_cmp(IntCmpKind::GtS, OperandSize::S32);
+    }
+
+    fn visit_i32x4_gt_u(&mut self) {
+        self.v128_int_cmp(IntCmpKind::GtU, OperandSize::S32);
+    }
+
+    fn visit_i32x4_le_s(&mut self) {
+        self.v128_int_cmp(IntCmpKind::LeS, OperandSize::S32);
+    }
+
+    fn visit_i32x4_le_u(&mut self) {
+        self.v128_int_cmp(IntCmpKind::LeU, OperandSize::S32);
+    }
+
+    fn visit_i32x4_ge_s(&mut self) {
+        self.v128_int_cmp(IntCmpKind::GeS, OperandSize::S32);
+    }
+
+    fn visit_i32x4_ge_u(&mut self) {
+        self.v128_int_cmp(IntCmpKind::GeU, OperandSize::S32);
+    }
+
+    fn visit_i64x2_eq(&mut self) {
+        self.v128_int_cmp(IntCmpKind::Eq, OperandSize::S64);
+    }
+
+    fn visit_i64x2_ne(&mut self) {
+        self.v128_int_cmp(IntCmpKind::Ne, OperandSize::S64);
+    }
+
+    fn visit_i64x2_lt_s(&mut self) {
+        self.v128_int_cmp(IntCmpKind::LtS, OperandSize::S64);
+    }
+
+    fn visit_i64x2_gt_s(&mut self) {
+        self.v128_int_cmp(IntCmpKind::GtS, OperandSize::S64);
+    }
+
+    fn visit_i64x2_le_s(&mut self) {
+        self.v128_int_cmp(IntCmpKind::LeS, OperandSize::S64);
+    }
+
+    fn visit_i64x2_ge_s(&mut self) {
+        self.v128_int_cmp(IntCmpKind::GeS, OperandSize::S64);
+    }
+
+    fn visit_f32x4_eq(&mut self) {
+        self.v128_float_cmp(FloatCmpKind::Eq, OperandSize::S32);
+    }
+
+    fn visit_f32x4_ne(&mut self) {
+        self.v128_float_cmp(FloatCmpKind::Ne, OperandSize::S32);
+    }
+
+    fn visit_f32x4_lt(&mut self) {
+        self.v128_float_cmp(FloatCmpKind::Lt, OperandSize::S32);
+    }
+
+    fn visit_f32x4_gt(&mut self) {
+        self.v128_float_cmp(FloatCmpKind::Gt, OperandSize::S32);
+    }
+
+    fn visit_f32x4_le(&mut self) {
+        self.v128_float_cmp(FloatCmpKind::Le, OperandSize::S32);
+    }
+
+    fn visit_f32x4_ge(&mut self) {
+        self.v128_float_cmp(FloatCmpKind::Ge, OperandSize::S32);
+    }
+
+    fn visit_f64x2_eq(&mut self) {
+        self.v128_float_cmp(FloatCmpKind::Eq, OperandSize::S64);
+    }
+
+    fn visit_f64x2_ne(&mut self) {
+        self.v128_float_cmp(FloatCmpKind::Ne, OperandSize::S64);
+    }
+
+    fn visit_f64x2_lt(&mut self) {
+        self.v128_float_cmp(FloatCmpKind::Lt, OperandSize::S64);
+    }
+
+    fn visit_f64x2_gt(&mut self) {
+        self.v128_float_cmp(FloatCmpKind::Gt, OperandSize::S64);
+    }
+
+    fn visit_f64x2_le(&mut self) {
+        self.v128_float_cmp(FloatCmpKind::Le, OperandSize::S64);
+    }
+
+    fn visit_f64x2_ge(&mut self) {
+        self.v128_float_cmp(FloatCmpKind::Ge, OperandSize::S64);
+    }
+
+    fn visit_v128_not(&mut self) {
+        self.v128_unop(|masm, reg| masm.v128_not(reg));
+    }
+
+    fn visit_v128_and(&mut self) {
+        self.v128_binop(|masm, dst, src| masm.v128_and(dst, dst, src));
+    }
+
+    fn visit_v128_andnot(&mut self) {
+        self.v128_binop(|masm, dst, src| masm.v128_and_not(dst, dst, src));
+    }
+
+    fn visit_v128_or(&mut self) {
+        self.v128_binop(|masm, dst, src| masm.v128_or(dst, dst, src));
+    }
+
+    fn visit_v128_xor(&mut self) {
+        self.v128_binop(|masm, dst, src| masm.v128_xor(dst, dst, src));
+    }
+
+    fn visit_v128_bitselect(&mut self) {
+        let mask = self.context.pop_to_reg(self.masm, None);
+        let rhs = self.context.pop_to_reg(self.masm, None);
+        let lhs = self.context.pop_to_reg(self.masm, None);
+        self.masm
+            .v128_bitselect(lhs.into(), lhs.into(), rhs.into(), mask.into());
+        self.context.free_reg(mask);
+        self.context.free_reg(rhs);
+        self.context.stack.push(lhs.into());
+    }
+
+    fn visit_v128_any_true(&mut self) {
+        self.context
+            .convert_op(self.masm, WasmType::I32, |masm, dst, src, _size| {
+                masm.v128_any_true(src, dst)
+            });
+    }
+
+    fn visit_i8x16_abs(&mut self) {
+        self.v128_unop(|masm, reg| masm.v128_abs(reg, VectorShape::I8x16));
+    }
+
+    fn visit_i8x16_neg(&mut self) {
+        self.v128_unop(|masm, reg| masm.v128_neg(reg, VectorShape::I8x16));
+    }
+
+    fn visit_i8x16_popcnt(&mut self) {
+        self.v128_convert_op(|masm, dst, src| masm.v128_popcnt(src, dst));
+    }
+
+    fn visit_i8x16_all_true(&mut self) {
+        self.context
+            .convert_op(self.masm, WasmType::I32, |masm, dst, src, _size| {
+                masm.v128_all_true(src, dst, OperandSize::S8)
+            });
+    }
+
+    fn visit_i8x16_bitmask(&mut self) {
+        self.context
+            .convert_op(self.masm, WasmType::I32, |masm, dst, src, _size| {
+                masm.v128_bitmask(src, dst, OperandSize::S8)
+            });
+    }
+
+    fn visit_i8x16_narrow_i16x8_s(&mut self) {
+        self.v128_binop(|masm, dst, src| masm.v128_narrow(dst, dst, src, V128NarrowKind::I16x8S));
+    }
+
+    fn visit_i8x16_narrow_i16x8_u(&mut self) {
+        self.v128_binop(|masm, dst, src| masm.v128_narrow(dst, dst, src, V128NarrowKind::I16x8U));
+    }
+
+    fn visit_i8x16_shl(&mut self) {
+        self.masm
+            .v128_shift(&mut self.context, OperandSize::S8, ShiftKind::Shl);
+    }
+
+    fn visit_i8x16_shr_s(&mut self) {
+        self.masm
+            .v128_shift(&mut self.context, OperandSize::S8, ShiftKind::ShrS);
+    }
+
+    fn visit_i8x16_shr_u(&mut self) {
+        self.masm
+            .v128_shift(&mut self.context, OperandSize::S8, ShiftKind::ShrU);
+    }
+
+    fn visit_i8x16_add(&mut self) {
+        self.v128_binop(|masm, dst, src| masm.v128_add(dst, dst, src, V128AddKind::I8x16));
+    }
+
+    fn visit_i8x16_add_sat_s(&mut self) {
+        self.v128_binop(|masm, dst, src| masm.v128_add(dst, dst, src, V128AddKind::I8x16SatS));
+    }
+
+    fn visit_i8x16_add_sat_u(&mut self) {
+        self.v128_binop(|masm, dst, src| masm.v128_add(dst, dst, src, V128AddKind::I8x16SatU));
+    }
+
+    fn visit_i8x16_sub(&mut self) {
+        self.v128_binop(|masm, dst, src| masm.v128_sub(dst, dst, src, V128SubKind::I8x16));
+    }
+
+    fn visit_i8x16_sub_sat_s(&mut self) {
+        self.v128_binop(|masm, dst, src| masm.v128_sub(dst, dst, src, V128SubKind::I8x16SatS));
+    }
+
+    fn visit_i8x16_sub_sat_u(&mut self) {
+        self.v128_binop(|masm, dst, src| masm.v128_sub(dst, dst, src, V128SubKind::I8x16SatU));
+    }
+
+    fn visit_i8x16_min_s(&mut self) {
+        self.v128_binop(|masm, dst, src| masm.v128_min(dst, dst, src, V128MinMaxKind::I8x16S));
+    }
+
+    fn visit_i8x16_min_u(&mut self) {
+        self.v128_binop(|masm, dst, src| masm.v128_min(dst, dst, src, V128MinMaxKind::I8x16U));
+    }
+
+    fn visit_i8x16_max_s(&mut self) {
+        self.v128_binop(|masm, dst, src| masm.v128_max(dst, dst, src, V128MinMaxKind::I8x16S));
+    }
+
+    fn visit_i8x16_max_u(&mut self) {
+        self.v128_binop(|masm, dst, src| masm.v128_max(dst, dst, src, V128MinMaxKind::I8x16U));
+    }
+
+    fn visit_i8x16_avgr_u(&mut self) {
+        self.v128_binop(|masm, dst, src| masm.v128_avgr_u(dst, dst, src, OperandSize::S8));
+    }
+
+    fn visit_i16x8_extadd_pairwise_i8x16_s(&mut self) {
+        self.v128_convert_op(|masm, dst, src| {
+            masm.v128_extadd_pairwise(src, dst, V128ExtAddKind::I8x16S)
+        });
+    }
+
+    fn visit_i16x8_extadd_pairwise_i8x16_u(&mut self) {
+        self.v128_convert_op(|masm, dst, src| {
+            masm.v128_extadd_pairwise(src, dst, V128ExtAddKind::I8x16U)
+        });
+    }
+
+    fn visit_i16x8_abs(&mut self) {
+        self.v128_unop(|masm, reg| masm.v128_abs(reg, VectorShape::I16x8));
+    }
+
+    fn visit_i16x8_neg(&mut self) {
+        self.v128_unop(|masm, reg| masm.v128_neg(reg, VectorShape::I16x8));
+    }
+
+    fn visit_i16x8_q15mulr_sat_s(&mut self) {
+        self.v128_binop(|masm, dst, src| masm.v128_q15mulr_sat_s(dst, dst, src));
+    }
+
+    fn visit_i16x8_all_true(&mut self) {
+        self.context
+            .convert_op(self.masm, WasmType::I32, |masm, dst, src, _size| {
+                masm.v128_all_true(src, dst, OperandSize::S16)
+            });
+    }
+
+    fn visit_i16x8_bitmask(&mut self) {
+        self.context
+            .convert_op(self.masm, WasmType::I32, |masm, dst, src, _size| {
+                masm.v128_bitmask(src, dst, OperandSize::S16)
+            });
+    }
+
+    fn visit_i16x8_narrow_i32x4_s(&mut self) {
+        self.v128_binop(|masm, dst, src| masm.v128_narrow(dst, dst, src, V128NarrowKind::I32x4S));
+    }
+
+    fn visit_i16x8_narrow_i32x4_u(&mut self) {
+        self.v128_binop(|masm, dst, src| masm.v128_narrow(dst, dst, src, V128NarrowKind::I32x4U));
+    }
+
+    fn visit_i16x8_extend_low_i8x16_s(&mut self) {
+        self.v128_unop(|masm, reg| masm.v128_extend(reg, reg, V128ExtendKind::LowI8x16S));
+    }
+
+    fn visit_i16x8_extend_high_i8x16_s(&mut self) {
+        self.v128_unop(|masm, reg| masm.v128_extend(reg, reg, V128ExtendKind::HighI8x16S));
+    }
+
+    fn visit_i16x8_extend_low_i8x16_u(&mut self) {
+        self.v128_unop(|masm, reg| masm.v128_extend(reg, reg, V128ExtendKind::LowI8x16U));
+    }
+
+    fn visit_i16x8_extend_high_i8x16_u(&mut self) {
+        self.v128_unop(|masm, reg| masm.v128_extend(reg, reg, V128ExtendKind::HighI8x16U));
+    }
+
+    fn visit_i16x8_shl(&mut self) {
+        self.masm
+            .v128_shift(&mut self.context, OperandSize::S16, ShiftKind::Shl);
+    }
+
+    fn visit_i16x8_shr_s(&mut self) {
+        self.masm
+            .v128_shift(&mut self.context, OperandSize::S16, ShiftKind::ShrS);
+    }
+
+    fn visit_i16x8_shr_u(&mut self) {
+        self.masm
+            .v128_shift(&mut self.context, OperandSize::S16, ShiftKind::ShrU);
+    }
+
+    fn visit_i16x8_add(&mut self) {
+        self.v128_binop(|masm, dst, src| masm.v128_add(dst, dst, src, V128AddKind::I16x8));
+    }
+
+    fn visit_i16x8_add_sat_s(&mut self) {
+        self.v128_binop(|masm, dst, src| masm.v128_add(dst, dst, src, V128AddKind::I16x8SatS));
+    }
+
+    fn visit_i16x8_add_sat_u(&mut self) {
+        self.v128_binop(|masm, dst, src| masm.v128_add(dst, dst, src, V128AddKind::I16x8SatU));
+    }
+
+    fn visit_i16x8_sub(&mut self) {
+        self.v128_binop(|masm, dst, src| masm.v128_sub(dst, dst, src, V128SubKind::I16x8));
+    }
+
+    fn visit_i16x8_sub_sat_s(&mut self) {
+        self.v128_binop(|masm, dst, src| masm.v128_sub(dst, dst, src, V128SubKind::I16x8SatS));
+    }
+
+    fn visit_i16x8_sub_sat_u(&mut self) {
+        self.v128_binop(|masm, dst, src| masm.v128_sub(dst, dst, src, V128SubKind::I16x8SatU));
+    }
+
+    fn visit_i16x8_mul(&mut self) {
+        self.masm.v128_mul(&mut self.context, V128MulKind::I16x8);
+    }
+
+    fn visit_i16x8_min_s(&mut self) {
+        self.v128_binop(|masm, dst, src| masm.v128_min(dst, dst, src, V128MinMaxKind::I16x8S));
+    }
+
+    fn visit_i16x8_min_u(&mut self) {
+        self.v128_binop(|masm, dst, src| masm.v128_min(dst, dst, src, V128MinMaxKind::I16x8U));
+    }
+
+    fn visit_i16x8_max_s(&mut self) {
+        self.v128_binop(|masm, dst, src| masm.v128_max(dst, dst, src, V128MinMaxKind::I16x8S));
+    }
+
+    fn visit_i16x8_max_u(&mut self) {
+        self.v128_binop(|masm, dst, src| masm.v128_max(dst, dst, src, V128MinMaxKind::I16x8U));
+    }
+
+    fn visit_i16x8_avgr_u(&mut self) {
+        self.v128_binop(|masm, dst, src| masm.v128_avgr_u(dst, dst, src, OperandSize::S16));
+    }
+
+    fn visit_i16x8_extmul_low_i8x16_s(&mut self) {
+        self.v128_extmul(V128ExtendKind::LowI8x16S, V128MulKind::I16x8);
+    }
+
+    fn visit_i16x8_extmul_high_i8x16_s(&mut self) {
+        self.v128_extmul(V128ExtendKind::HighI8x16S, V128MulKind::I16x8);
+    }
+
+    fn visit_i16x8_extmul_low_i8x16_u(&mut self) {
+        self.v128_extmul(V128ExtendKind::LowI8x16U, V128MulKind::I16x8);
+    }
+
+    fn visit_i16x8_extmul_high_i8x16_u(&mut self) {
+        self.v128_extmul(V128ExtendKind::HighI8x16U, V128MulKind::I16x8);
+    }
+
+    fn visit_i32x4_extadd_pairwise_i16x8_s(&mut self) {
+        self.v128_convert_op(|masm, dst, src| {
+            masm.v128_extadd_pairwise(src, dst, V128ExtAddKind::I16x8S)
+        });
+    }
+
+    fn visit_i32x4_extadd_pairwise_i16x8_u(&mut self) {
+        self.v128_convert_op(|masm, dst, src| {
+            masm.v128_extadd_pairwise(src, dst, V128ExtAddKind::I16x8U)
+        });
+    }
+
+    fn visit_i32x4_abs(&mut self) {
+        self.v128_unop(|masm, reg| masm.v128_abs(reg, VectorShape::I32x4));
+    }
+
+    fn visit_i32x4_neg(&mut self) {
+        self.v128_unop(|masm, reg| masm.v128_neg(reg, VectorShape::I32x4));
+    }
+
+    fn visit_i32x4_all_true(&mut self) {
+        self.context
+            .convert_op(self.masm, WasmType::I32, |masm, dst, src, _size| {
+                masm.v128_all_true(src, dst, OperandSize::S32)
+            });
+    }
+
+    fn visit_i32x4_bitmask(&mut self) {
+        self.context
+            .convert_op(self.masm, WasmType::I32, |masm, dst, src, _size| {
+                masm.v128_bitmask(src, dst, OperandSize::S32)
+            });
+    }
+
+    fn visit_i32x4_extend_low_i16x8_s(&mut self) {
+        self.v128_unop(|masm, reg| masm.v128_extend(reg, reg, V128ExtendKind::LowI16x8S));
+    }
+
+    fn visit_i32x4_extend_high_i16x8_s(&mut self) {
+        self.v128_unop(|masm, reg| masm.v128_extend(reg, reg, V128ExtendKind::HighI16x8S));
+    }
+
+    fn visit_i32x4_extend_low_i16x8_u(&mut self) {
+        self.v128_unop(|masm, reg| masm.v128_extend(reg, reg, V128ExtendKind::LowI16x8U));
+    }
+
+    fn visit_i32x4_extend_high_i16x8_u(&mut self) {
+        self.v128_unop(|masm, reg| masm.v128_extend(reg, reg, V128ExtendKind::HighI16x8U));
+    }
+
+    fn visit_i32x4_shl(&mut self) {
+        self.masm
+            .v128_shift(&mut self.context, OperandSize::S32, ShiftKind::Shl);
+    }
+
+    fn visit_i32x4_shr_s(&mut self) {
+        self.masm
+            .v128_shift(&mut self.context, OperandSize::S32, ShiftKind::ShrS);
+    }
+
+    fn visit_i32x4_shr_u(&mut self) {
+        self.masm
+            .v128_shift(&mut self.context, OperandSize::S32, ShiftKind::ShrU);
+    }
+
+    fn visit_i32x4_add(&mut self) {
+        self.v128_binop(|masm, dst, src| masm.v128_add(dst, dst, src, V128AddKind::I32x4));
+    }
+
+    fn visit_i32x4_sub(&mut self) {
+        self.v128_binop(|masm, dst, src| masm.v128_sub(dst, dst, src, V128SubKind::I32x4));
+    }
+
+    fn visit_i32x4_mul(&mut self) {
+        self.masm.v128_mul(&mut self.context, V128MulKind::I32x4);
+    }
+
+    fn visit_i32x4_min_s(&mut self) {
+        self.v128_binop(|masm, dst, src| masm.v128_min(dst, dst, src, V128MinMaxKind::I32x4S));
+    }
+
+    fn visit_i32x4_min_u(&mut self) {
+        self.v128_binop(|masm, dst, src| masm.v128_min(dst, dst, src, V128MinMaxKind::I32x4U));
+    }
+
+    fn visit_i32x4_max_s(&mut self) {
+        self.v128_binop(|masm, dst, src| masm.v128_max(dst, dst, src, V128MinMaxKind::I32x4S));
+    }
+
+    fn visit_i32x4_max_u(&mut self) {
+        self.v128_binop(|masm, dst, src| masm.v128_max(dst, dst, src, V128MinMaxKind::I32x4U));
+    }
+
+    fn visit_i32x4_dot_i16x8_s(&mut self) {
+        self.v128_binop(|masm, dst, src| masm.v128_dot(dst, dst, src));
+    }
+
+    fn visit_i32x4_extmul_low_i16x8_s(&mut self) {
+        self.v128_extmul(V128ExtendKind::LowI16x8S, V128MulKind::I32x4);
+    }
+
+    fn visit_i32x4_extmul_high_i16x8_s(&mut self) {
+        self.v128_extmul(V128ExtendKind::HighI16x8S, V128MulKind::I32x4);
+    }
+
+    fn visit_i32x4_extmul_low_i16x8_u(&mut self) {
+        self.v128_extmul(V128ExtendKind::LowI16x8U, V128MulKind::I32x4);
+    }
+
+    fn visit_i32x4_extmul_high_i16x8_u(&mut self) {
+        self.v128_extmul(V128ExtendKind::HighI16x8U, V128MulKind::I32x4);
+    }
+
+    fn visit_i64x2_abs(&mut self) {
+        self.v128_unop(|masm, reg| masm.v128_abs(reg, VectorShape::I64x2));
+    }
+
+    fn visit_i64x2_neg(&mut self) {
+        self.v128_unop(|masm, reg| masm.v128_neg(reg, VectorShape::I64x2));
+    }
+
+    fn visit_i64x2_all_true(&mut self) {
+        self.context
+            .convert_op(self.masm, WasmType::I32, |masm, dst, src, _size| {
+                masm.v128_all_true(src, dst, OperandSize::S64)
+            });
+    }
+
+    fn visit_i64x2_bitmask(&mut self) {
+        self.context
+            .convert_op(self.masm, WasmType::I32, |masm, dst, src, _size| {
+                masm.v128_bitmask(src, dst, OperandSize::S64)
+            });
+    }
+
+    fn visit_i64x2_extend_low_i32x4_s(&mut self) {
+        self.v128_unop(|masm, reg| masm.v128_extend(reg, reg, V128ExtendKind::LowI32x4S));
+    }
+
+    fn visit_i64x2_extend_high_i32x4_s(&mut self) {
+        self.v128_unop(|masm, reg| masm.v128_extend(reg, reg, V128ExtendKind::HighI32x4S));
+    }
+
+    fn visit_i64x2_extend_low_i32x4_u(&mut self) {
+        self.v128_unop(|masm, reg| masm.v128_extend(reg, reg, V128ExtendKind::LowI32x4U));
+    }
+
+    fn visit_i64x2_extend_high_i32x4_u(&mut self) {
+        self.v128_unop(|masm, reg| masm.v128_extend(reg, reg, V128ExtendKind::HighI32x4U));
+    }
+
+    fn visit_i64x2_shl(&mut self) {
+        self.masm
+            .v128_shift(&mut self.context, OperandSize::S64, ShiftKind::Shl);
+    }
+
+    fn visit_i64x2_shr_s(&mut self) {
+        self.masm
+            .v128_shift(&mut self.context, OperandSize::S64, ShiftKind::ShrS);
+    }
+
+    fn visit_i64x2_shr_u(&mut self) {
+        self.masm
+            .v128_shift(&mut self.context, OperandSize::S64, ShiftKind::ShrU);
+    }
+
+    fn visit_i64x2_add(&mut self) {
+        self.v128_binop(|masm, dst, src| masm.v128_add(dst, dst, src, V128AddKind::I64x2));
+    }
+
+    fn visit_i64x2_sub(&mut self) {
+        self.v128_binop(|masm, dst, src| masm.v128_sub(dst, dst, src, V128SubKind::I64x2));
+    }
+
+    fn visit_i64x2_mul(&mut self) {
+        self.masm.v128_mul(&mut self.context, V128MulKind::I64x2);
+    }
+
+    fn visit_i64x2_extmul_low_i32x4_s(&mut self) {
+        self.v128_extmul(V128ExtendKind::LowI32x4S, V128MulKind::I64x2);
+    }
+
+    fn visit_i64x2_extmul_high_i32x4_s(&mut self) {
+        self.v128_extmul(V128ExtendKind::HighI32x4S, V128MulKind::I64x2);
+    }
+
+    fn visit_i64x2_extmul_low_i32x4_u(&mut self) {
+        self.v128_extmul(V128ExtendKind::LowI32x4U, V128MulKind::I64x2);
+    }
+
+    fn visit_i64x2_extmul_high_i32x4_u(&mut self) {
+        self.v128_extmul(V128ExtendKind::HighI32x4U, V128MulKind::I64x2);
+    }
+
+    fn visit_f32x4_ceil(&mut self) {
+        self.v128_unop(|masm, reg| masm.v128_round(reg, reg, RoundingMode::Up, OperandSize::S32));
+    }
+
+    fn visit_f32x4_floor(&mut self) {
+        self.v128_unop(|masm, reg| masm.v128_round(reg, reg, RoundingMode::Down, OperandSize::S32));
+    }
+
+    fn visit_f32x4_trunc(&mut self) {
+        self.v128_unop(|masm, reg| masm.v128_round(reg, reg, RoundingMode::Zero, OperandSize::S32));
+    }
+
+    fn visit_f32x4_nearest(&mut self) {
+        self.v128_unop(|masm, reg| {
+            masm.v128_round(reg, reg, RoundingMode::Nearest, OperandSize::S32)
+        });
+    }
+
+    fn visit_f32x4_abs(&mut self) {
+        self.v128_unop(|masm, reg| masm.v128_abs(reg, VectorShape::F32x4));
+    }
+
+    fn visit_f32x4_neg(&mut self) {
+        self.v128_unop(|masm, reg| masm.v128_neg(reg, VectorShape::F32x4));
+    }
+
+    fn visit_f32x4_sqrt(&mut self) {
+        self.v128_unop(|masm, reg| masm.v128_sqrt(reg, reg, OperandSize::S32));
+    }
+
+    fn visit_f32x4_add(&mut self) {
+        self.v128_binop(|masm, dst, src| masm.v128_add(dst, dst, src, V128AddKind::F32x4));
+    }
+
+    fn visit_f32x4_sub(&mut self) {
+        self.v128_binop(|masm, dst, src| masm.v128_sub(dst, dst, src, V128SubKind::F32x4));
+    }
+
+    fn visit_f32x4_mul(&mut self) {
+        self.masm.v128_mul(&mut self.context, V128MulKind::F32x4);
+    }
+
+    fn visit_f32x4_div(&mut self) {
+        self.v128_binop(|masm, dst, src| masm.v128_div(dst, dst, src, OperandSize::S32));
+    }
+
+    fn visit_f32x4_min(&mut self) {
+        self.v128_binop(|masm, dst, src| masm.v128_min(dst, dst, src, V128MinMaxKind::F32x4));
+    }
+
+    fn visit_f32x4_max(&mut self) {
+        self.v128_binop(|masm, dst, src| masm.v128_max(dst, dst, src, V128MinMaxKind::F32x4));
+    }
+
+    fn visit_f32x4_pmin(&mut self) {
+        self.v128_binop(|masm, dst, src| masm.v128_pmin(dst, dst, src, OperandSize::S32));
+    }
+
+    fn visit_f32x4_pmax(&mut self) {
+        self.v128_binop(|masm, dst, src| masm.v128_pmax(dst, dst, src, OperandSize::S32));
+    }
+
+    fn visit_f64x2_ceil(&mut self) {
+        self.v128_unop(|masm, reg| masm.v128_round(reg, reg, RoundingMode::Up, OperandSize::S64));
+    }
+
+    fn visit_f64x2_floor(&mut self) {
+        self.v128_unop(|masm, reg| masm.v128_round(reg, reg, RoundingMode::Down, OperandSize::S64));
+    }
+
+    fn visit_f64x2_trunc(&mut self) {
+        self.v128_unop(|masm, reg| masm.v128_round(reg, reg, RoundingMode::Zero, OperandSize::S64));
+    }
+
+    fn visit_f64x2_nearest(&mut self) {
+        self.v128_unop(|masm, reg| {
+            masm.v128_round(reg, reg, RoundingMode::Nearest, OperandSize::S64)
+        });
+    }
+
+    fn visit_f64x2_abs(&mut self) {
+        self.v128_unop(|masm, reg| masm.v128_abs(reg, VectorShape::F64x2));
+    }
+
+    fn visit_f64x2_neg(&mut self) {
+        self.v128_unop(|masm, reg| masm.v128_neg(reg, VectorShape::F64x2));
+    }
+
+    fn visit_f64x2_sqrt(&mut self) {
+        self.v128_unop(|masm, reg| masm.v128_sqrt(reg, reg, OperandSize::S64));
+    }
+
+    fn visit_f64x2_add(&mut self) {
+        self.v128_binop(|masm, dst, src| masm.v128_add(dst, dst, src, V128AddKind::F64x2));
+    }
+
+    fn visit_f64x2_sub(&mut self) {
+        self.v128_binop(|masm, dst, src| masm.v128_sub(dst, dst, src, V128SubKind::F64x2));
+    }
+
+    fn visit_f64x2_mul(&mut self) {
+        self.masm.v128_mul(&mut self.context, V128MulKind::F64x2);
+    }
+
+    fn visit_f64x2_div(&mut self) {
+        self.v128_binop(|masm, dst, src| masm.v128_div(dst, dst, src, OperandSize::S64));
+    }
+
+    fn visit_f64x2_min(&mut self) {
+        self.v128_binop(|masm, dst, src| masm.v128_min(dst, dst, src, V128MinMaxKind::F64x2));
+    }
+
+    fn visit_f64x2_max(&mut self) {
+        self.v128_binop(|masm, dst, src| masm.v128_max(dst, dst, src, V128MinMaxKind::F64x2));
+    }
+
+    fn visit_f64x2_pmin(&mut self) {
+        self.v128_binop(|masm, dst, src| masm.v128_pmin(dst, dst, src, OperandSize::S64));
+    }
+
+    fn visit_f64x2_pmax(&mut self) {
+        self.v128_binop(|masm, dst, src| masm.v128_pmax(dst, dst, src, OperandSize::S64));
+    }
+
+    fn visit_i32x4_trunc_sat_f32x4_s(&mut self) {
+        self.v128_convert_op(|masm, dst, src| {
+            masm.v128_trunc_sat(src, dst, V128TruncSatKind::F32x4S)
+        });
+    }
+
+    fn visit_i32x4_trunc_sat_f32x4_u(&mut self) {
+        self.v128_convert_op(|masm, dst, src| {
+            masm.v128_trunc_sat(src, dst, V128TruncSatKind::F32x4U)
+        });
+    }
+
+    fn visit_f32x4_convert_i32x4_s(&mut self) {
+        self.v128_convert_op(|masm, dst, src| masm.v128_convert(src, dst, V128ConvertKind::I32x4S));
+    }
+
+    fn visit_f32x4_convert_i32x4_u(&mut self) {
+        self.v128_convert_op(|masm, dst, src| masm.v128_convert(src, dst, V128ConvertKind::I32x4U));
+    }
+
+    fn visit_i32x4_trunc_sat_f64x2_s_zero(&mut self) {
+        self.v128_convert_op(|masm, dst, src| {
+            masm.v128_trunc_sat(src, dst, V128TruncSatKind::F64x2SZero)
+        });
+    }
+
+    fn visit_i32x4_trunc_sat_f64x2_u_zero(&mut self) {
+        self.v128_convert_op(|masm, dst, src| {
+            masm.v128_trunc_sat(src, dst, V128TruncSatKind::F64x2UZero)
+        });
+    }
+
+    fn visit_f64x2_convert_low_i32x4_s(&mut self) {
+        self.v128_convert_op(|masm, dst, src| {
+            masm.v128_convert(src, dst, V128ConvertKind::I32x4LowS)
+        });
+    }
+
+    fn visit_f64x2_convert_low_i32x4_u(&mut self) {
+        self.v128_convert_op(|masm, dst, src| {
+            masm.v128_convert(src, dst, V128ConvertKind::I32x4LowU)
+        });
+    }
+
+    fn visit_f32x4_demote_f64x2_zero(&mut self) {
+        self.v128_unop(|masm, reg| masm.v128_demote(reg, reg));
+    }
+
+    fn visit_f64x2_promote_low_f32x4(&mut self) {
+        self.v128_unop(|masm, reg| masm.v128_promote(reg, reg));
+    }
+
+    fn visit_memory_atomic_notify(&mut self, memarg: MemArg) {
+        self.emit_atomic_wait_or_notify(&memarg, 2, |cx| {
+            cx.builtins.memory_atomic_notify::<M::ABI, M::Ptr>()
+        });
+    }
+
+    fn visit_memory_atomic_wait32(&mut self, memarg: MemArg) {
+        self.emit_atomic_wait_or_notify(&memarg, 3, |cx| {
+            cx.builtins.memory_atomic_wait32::<M::ABI, M::Ptr>()
+        });
+    }
+
+    fn visit_memory_atomic_wait64(&mut self, memarg: MemArg) {
+        self.emit_atomic_wait_or_notify(&memarg, 3, |cx| {
+            cx.builtins.memory_atomic_wait64::<M::ABI, M::Ptr>()
+        });
+    }
+
+    fn visit_atomic_fence(&mut self) {
+        self.masm.fence();
+    }
+
+    fn visit_i32_atomic_load(&mut self, memarg: MemArg) {
+        self.emit_wasm_atomic_load(&memarg, WasmType::I32, OperandSize::S32);
+    }
+
+    fn visit_i64_atomic_load(&mut self, memarg: MemArg) {
+        self.emit_wasm_atomic_load(&memarg, WasmType::I64, OperandSize::S64);
+    }
+
+    fn visit_i32_atomic_load8_u(&mut self, memarg: MemArg) {
+        self.emit_wasm_atomic_load(&memarg, WasmType::I32, OperandSize::S8);
+    }
+
+    fn visit_i32_atomic_load16_u(&mut self, memarg: MemArg) {
+        self.emit_wasm_atomic_load(&memarg, WasmType::I32, OperandSize::S16);
+    }
+
+    fn visit_i64_atomic_load8_u(&mut self, memarg: MemArg) {
+        self.emit_wasm_atomic_load(&memarg, WasmType::I64, OperandSize::S8);
+    }
+
+    fn visit_i64_atomic_load16_u(&mut self, memarg: MemArg) {
+        self.emit_wasm_atomic_load(&memarg, WasmType::I64, OperandSize::S16);
+    }
+
+    fn visit_i64_atomic_load32_u(&mut self, memarg: MemArg) {
+        self.emit_wasm_atomic_load(&memarg, WasmType::I64, OperandSize::S32);
+    }
+
+    fn visit_i32_atomic_store(&mut self, memarg: MemArg) {
+        self.emit_wasm_atomic_store(&memarg, OperandSize::S32);
+    }
+
+    fn visit_i64_atomic_store(&mut self, memarg: MemArg) {
+        self.emit_wasm_atomic_store(&memarg, OperandSize::S64);
+    }
+
+    fn visit_i32_atomic_store8(&mut self, memarg: MemArg) {
+        self.emit_wasm_atomic_store(&memarg, OperandSize::S8);
+    }
+
+    fn visit_i32_atomic_store16(&mut self, memarg: MemArg) {
+        self.emit_wasm_atomic_store(&memarg, OperandSize::S16);
+    }
+
+    fn visit_i64_atomic_store8(&mut self, memarg: MemArg) {
+        self.emit_wasm_atomic_store(&memarg, OperandSize::S8);
+    }
+
+    fn visit_i64_atomic_store16(&mut self, memarg: MemArg) {
+        self.emit_wasm_atomic_store(&memarg, OperandSize::S16);
+    }
+
+    fn visit_i64_atomic_store32(&mut self, memarg: MemArg) {
+        self.emit_wasm_atomic_store(&memarg, OperandSize::S32);
+    }
+
+    fn visit_i32_atomic_rmw_add(&mut self, memarg: MemArg) {
+        self.emit_atomic_rmw(&memarg, OperandSize::S32, RmwOp::Add);
+    }
+
+    fn visit_i64_atomic_rmw_add(&mut self, memarg: MemArg) {
+        self.emit_atomic_rmw(&memarg, OperandSize::S64, RmwOp::Add);
+    }
+
+    fn visit_i32_atomic_rmw8_add_u(&mut self, memarg: MemArg) {
+        self.emit_atomic_rmw(&memarg, OperandSize::S8, RmwOp::Add);
+    }
+
+    fn visit_i32_atomic_rmw16_add_u(&mut self, memarg: MemArg) {
+        self.emit_atomic_rmw(&memarg, OperandSize::S16, RmwOp::Add);
+    }
+
+    fn visit_i64_atomic_rmw8_add_u(&mut self, memarg: MemArg) {
+        self.emit_atomic_rmw(&memarg, OperandSize::S8, RmwOp::Add);
+    }
+
+    fn visit_i64_atomic_rmw16_add_u(&mut self, memarg: MemArg) {
+        self.emit_atomic_rmw(&memarg, OperandSize::S16, RmwOp::Add);
+    }
+
+    fn visit_i64_atomic_rmw32_add_u(&mut self, memarg: MemArg) {
+        self.emit_atomic_rmw(&memarg, OperandSize::S32, RmwOp::Add);
+    }
+
+    fn visit_i32_atomic_rmw_sub(&mut self, memarg: MemArg) {
+        self.emit_atomic_rmw(&memarg, OperandSize::S32, RmwOp::Sub);
+    }
+
+    fn visit_i64_atomic_rmw_sub(&mut self, memarg: MemArg) {
+        self.emit_atomic_rmw(&memarg, OperandSize::S64, RmwOp::Sub);
+    }
+
+    fn visit_i32_atomic_rmw8_sub_u(&mut self, memarg: MemArg) {
+        self.emit_atomic_rmw(&memarg, OperandSize::S8, RmwOp::Sub);
+    }
+
+    fn visit_i32_atomic_rmw16_sub_u(&mut self, memarg: MemArg) {
+        self.emit_atomic_rmw(&memarg, OperandSize::S16, RmwOp::Sub);
+    }
+
+    fn visit_i64_atomic_rmw8_sub_u(&mut self, memarg: MemArg) {
+        self.emit_atomic_rmw(&memarg, OperandSize::S8, RmwOp::Sub);
+    }
+
+    fn visit_i64_atomic_rmw16_sub_u(&mut self, memarg: MemArg) {
+        self.emit_atomic_rmw(&memarg, OperandSize::S16, RmwOp::Sub);
+    }
+
+    fn visit_i64_atomic_rmw32_sub_u(&mut self, memarg: MemArg) {
+        self.emit_atomic_rmw(&memarg, OperandSize::S32, RmwOp::Sub);
+    }
+
+    fn visit_i32_atomic_rmw_and(&mut self, memarg: MemArg) {
+        self.emit_atomic_rmw(&memarg, OperandSize::S32, RmwOp::And);
+    }
+
+    fn visit_i64_atomic_rmw_and(&mut self, memarg: MemArg) {
+        self.emit_atomic_rmw(&memarg, OperandSize::S64, RmwOp::And);
+    }
+
+    fn visit_i32_atomic_rmw8_and_u(&mut self, memarg: MemArg) {
+        self.emit_atomic_rmw(&memarg, OperandSize::S8, RmwOp::And);
+    }
+
+    fn visit_i32_atomic_rmw16_and_u(&mut self, memarg: MemArg) {
+        self.emit_atomic_rmw(&memarg, OperandSize::S16, RmwOp::And);
+    }
+
+    fn visit_i64_atomic_rmw8_and_u(&mut self, memarg: MemArg) {
+        self.emit_atomic_rmw(&memarg, OperandSize::S8, RmwOp::And);
+    }
+
+    fn visit_i64_atomic_rmw16_and_u(&mut self, memarg: MemArg) {
+        self.emit_atomic_rmw(&memarg, OperandSize::S16, RmwOp::And);
+    }
+
+    fn visit_i64_atomic_rmw32_and_u(&mut self, memarg: MemArg) {
+        self.emit_atomic_rmw(&memarg, OperandSize::S32, RmwOp::And);
+    }
+
+    fn visit_i32_atomic_rmw_or(&mut self, memarg: MemArg) {
+        self.emit_atomic_rmw(&memarg, OperandSize::S32, RmwOp::Or);
+    }
+
+    fn visit_i64_atomic_rmw_or(&mut self, memarg: MemArg) {
+        self.emit_atomic_rmw(&memarg, OperandSize::S64, RmwOp::Or);
+    }
+
+    fn visit_i32_atomic_rmw8_or_u(&mut self, memarg: MemArg) {
+        self.emit_atomic_rmw(&memarg, OperandSize::S8, RmwOp::Or);
+    }
+
+    fn visit_i32_atomic_rmw16_or_u(&mut self, memarg: MemArg) {
+        self.emit_atomic_rmw(&memarg, OperandSize::S16, RmwOp::Or);
+    }
+
+    fn visit_i64_atomic_rmw8_or_u(&mut self, memarg: MemArg) {
+        self.emit_atomic_rmw(&memarg, OperandSize::S8, RmwOp::Or);
+    }
+
+    fn visit_i64_atomic_rmw16_or_u(&mut self, memarg: MemArg) {
+        self.emit_atomic_rmw(&memarg, OperandSize::S16, RmwOp::Or);
+    }
+
+    fn visit_i64_atomic_rmw32_or_u(&mut self, memarg: MemArg) {
+        self.emit_atomic_rmw(&memarg, OperandSize::S32, RmwOp::Or);
+    }
+
+    fn visit_i32_atomic_rmw_xor(&mut self, memarg: MemArg) {
+        self.emit_atomic_rmw(&memarg, OperandSize::S32, RmwOp::Xor);
+    }
+
+    fn visit_i64_atomic_rmw_xor(&mut self, memarg: MemArg) {
+        self.emit_atomic_rmw(&memarg, OperandSize::S64, RmwOp::Xor);
+    }
+
+    fn visit_i32_atomic_rmw8_xor_u(&mut self, memarg: MemArg) {
+        self.emit_atomic_rmw(&memarg, OperandSize::S8, RmwOp::Xor);
+    }
+
+    fn visit_i32_atomic_rmw16_xor_u(&mut self, memarg: MemArg) {
+        self.emit_atomic_rmw(&memarg, OperandSize::S16, RmwOp::Xor);
+    }
+
+    fn visit_i64_atomic_rmw8_xor_u(&mut self, memarg: MemArg) {
+        self.emit_atomic_rmw(&memarg, OperandSize::S8, RmwOp::Xor);
+    }
+
+    fn visit_i64_atomic_rmw16_xor_u(&mut self, memarg: MemArg) {
+        self.emit_atomic_rmw(&memarg, OperandSize::S16, RmwOp::Xor);
+    }
+
+    fn visit_i64_atomic_rmw32_xor_u(&mut self, memarg: MemArg) {
+        self.emit_atomic_rmw(&memarg, OperandSize::S32, RmwOp::Xor);
+    }
+
+    fn visit_i32_atomic_rmw_xchg(&mut self, memarg: MemArg) {
+        self.emit_atomic_rmw(&memarg, OperandSize::S32, RmwOp::Xchg);
+    }
+
+    fn visit_i64_atomic_rmw_xchg(&mut self, memarg: MemArg) {
+        self.emit_atomic_rmw(&memarg, OperandSize::S64, RmwOp::Xchg);
+    }
+
+    fn visit_i32_atomic_rmw8_xchg_u(&mut self, memarg: MemArg) {
+        self.emit_atomic_rmw(&memarg, OperandSize::S8, RmwOp::Xchg);
+    }
+
+    fn visit_i32_atomic_rmw16_xchg_u(&mut self, memarg: MemArg) {
+        self.emit_atomic_rmw(&memarg, OperandSize::S16, RmwOp::Xchg);
+    }
+
+    fn visit_i64_atomic_rmw8_xchg_u(&mut self, memarg: MemArg) {
+        self.emit_atomic_rmw(&memarg, OperandSize::S8, RmwOp::Xchg);
+    }
+
+    fn visit_i64_atomic_rmw16_xchg_u(&mut self, memarg: MemArg) {
+        self.emit_atomic_rmw(&memarg, OperandSize::S16, RmwOp::Xchg);
+    }
+
+    fn visit_i64_atomic_rmw32_xchg_u(&mut self, memarg: MemArg) {
+        self.emit_atomic_rmw(&memarg, OperandSize::S32, RmwOp::Xchg);
+    }
+
+    fn visit_i32_atomic_rmw_cmpxchg(&mut self, memarg: MemArg) {
+        self.emit_atomic_cmpxchg(&memarg, OperandSize::S32);
+    }
+
+    fn visit_i64_atomic_rmw_cmpxchg(&mut self, memarg: MemArg) {
+        self.emit_atomic_cmpxchg(&memarg, OperandSize::S64);
+    }
+
+    fn visit_i32_atomic_rmw8_cmpxchg_u(&mut self, memarg: MemArg) {
+        self.emit_atomic_cmpxchg(&memarg, OperandSize::S8);
+    }
+
+    fn visit_i32_atomic_rmw16_cmpxchg_u(&mut self, memarg: MemArg) {
+        self.emit_atomic_cmpxchg(&memarg, OperandSize::S16);
+    }
+
+    fn visit_i64_atomic_rmw8_cmpxchg_u(&mut self, memarg: MemArg) {
+        self.emit_atomic_cmpxchg(&memarg, OperandSize::S8);
+    }
+
+    fn visit_i64_atomic_rmw16_cmpxchg_u(&mut self, memarg: MemArg) {
+        self.emit_atomic_cmpxchg(&memarg, OperandSize::S16);
+    }
+
+    fn visit_i64_atomic_rmw32_cmpxchg_u(&mut self, memarg: MemArg) {
+        self.emit_atomic_cmpxchg(&memarg, OperandSize::S32);
+    }
+
+    wasmparser::for_each_operator!(def_unsupported);
+}
+
+impl<'a, 'translation, 'data, M> CodeGen<'a, 'translation, 'data, M>
+where
+    M: MacroAssembler,
+{
+    /// Emits a call to one of the `memory.atomic.wait*` or
+    /// `memory.atomic.notify` built-in functions. The value stack is
+    /// expected to contain the address of the access followed by the
+    /// remaining operands; `arity` includes the address.
+    fn emit_atomic_wait_or_notify<F>(&mut self, memarg: &MemArg, arity: usize, mut builtin: F)
+    where
+        F: FnMut(&mut CodeGenContext) -> BuiltinFunction,
+    {
+        debug_assert!(self.context.stack.len() >= arity);
+        let heap = self.env.resolve_heap(MemoryIndex::from_u32(memarg.memory));
+        let mut operands: SmallVec<[TypedReg; 2]> = SmallVec::new();
+        for _ in 1..arity {
+            operands.push(self.context.pop_to_reg(self.masm, None));
+        }
+
+        // The built-in functions expect the effective address as a 64-bit
+        // integer; alignment and bounds are checked by the runtime.
+        let addr = self.context.pop_to_reg(self.masm, None);
+        if memarg.offset != 0 {
+            self.masm.checked_uadd(
+                addr.reg,
+                addr.reg,
+                RegImm::i64(memarg.offset as i64),
+                heap.ty.into(),
+                TrapCode::HeapOutOfBounds,
+            );
+        }
+        if heap.ty == WasmType::I32 {
+            self.masm
+                .extend(addr.reg, addr.reg, ExtendKind::I64ExtendI32U);
+        }
+
+        // The desired state is
+        //   [ vmctx, memory, address, operands.. ]
+        let ptr_type = self.env.ptr_type();
+        let vmctx = TypedReg::new(ptr_type, <M::ABI as ABI>::vmctx_reg());
+        self.context.stack.extend([
+            vmctx.into(),
+            memarg.memory.try_into().unwrap(),
+            TypedReg::i64(addr.reg).into(),
+        ]);
+        self.context
+            .stack
+            .extend(operands.into_iter().rev().map(Into::into));
+
+        FnCall::emit::<M, M::Ptr, _>(self.masm, &mut self.context, |cx| {
+            Callee::Builtin(builtin(cx))
+        });
+    }
+
+    /// Marks the code following a tail call as unreachable. Similar to
+    /// `unreachable`, the implicit outermost frame is set as target to
+    /// perform the necessary stack clean up.
+    fn emit_tail_call_end(&mut self) {
+        self.context.reachable = false;
+        let outermost = &mut self.control_frames[0];
+        outermost.set_as_target();
+    }
+
+    /// Emits a conditional branch to the control frame at `index`, taken
+    /// when comparing `cond` against zero satisfies `kind`, which must be
+    /// either [`IntCmpKind::Eq`] or [`IntCmpKind::Ne`]. The branch
+    /// results of the target frame are expected to be at the top of the
+    /// value stack.
+    fn emit_br_cond(&mut self, index: usize, cond: Reg, kind: IntCmpKind, size: OperandSize) {
+        let frame = &mut self.control_frames[index];
+        frame.top_abi_results::<M, _>(&mut self.context, self.masm, |results, context, masm| {
+            // In the case of conditional branches theres a possibility that
+            // we'll exit early from the block or falltrough, for
+            // a falltrough, we cannot rely on the pre-computed return area;
+            // it must be recalculated so that any values that are
+            // generated are correctly placed near the current stack
+            // pointer.
+            results.on_stack().then(|| {
+                let stack_consumed = context.stack.sizeof(results.stack_operands_len());
+                let base = masm.sp_offset().as_u32() - stack_consumed;
+                let offs = base + results.size();
+                RetArea::sp(SPOffset::from_u32(offs))
+            })
+        });
+
+        // Emit instructions to balance the machine stack if the frame has
+        // a different offset.
+        let current_sp_offset = self.masm.sp_offset();
+        let results_size = frame.results::<M>().size();
+        let state = frame.stack_state();
+        let (label, cmp, needs_cleanup) = if current_sp_offset > state.target_offset {
+            let inverse = match kind {
+                IntCmpKind::Eq => IntCmpKind::Ne,
+                IntCmpKind::Ne => IntCmpKind::Eq,
+                k => unreachable!("Unexpected branch condition: {k:?}"),
+            };
+            (self.masm.get_label(), inverse, true)
+        } else {
+            (*frame.label(), kind, false)
+        };
+
+        self.masm.branch(cmp, cond.into(), cond, label, size);
+
+        if needs_cleanup {
+            // Emit instructions to balance the stack and jump if not falling
+            // through.
+            self.masm.memmove(
+                current_sp_offset,
+                state.target_offset,
+                results_size,
+                MemMoveDirection::LowToHigh,
+            );
+            self.masm.ensure_sp_for_jump(state.target_offset);
+            self.masm.jmp(*frame.label());
+
+            // Restore sp_offset to what it was for falling through and emit
+            // fallthrough label.
+            self.masm.reset_stack_pointer(current_sp_offset);
+            self.masm.bind(label);
+        }
+    }
+
+    fn cmp_i32s(&mut self, kind: IntCmpKind) {
+        self.context.i32_binop(self.masm, |masm, dst, src, size| {
+            masm.cmp_with_set(src, dst, kind, size);
+            TypedReg::i32(dst)
+        });
+    }
+
+    fn cmp_i64s(&mut self, kind: IntCmpKind) {
+        self.context
+            .i64_binop(self.masm, move |masm, dst, src, size| {
+                masm.cmp_with_set(src, dst, kind, size);
+                TypedReg::i32(dst) // Return value for comparisons is an `i32`.
+            });
+    }
+
+    /// Emit a binary operation on two v128 operands, producing a v128.
+    fn v128_binop<F>(&mut self, mut emit: F)
+    where
+        F: FnMut(&mut M, Reg, Reg),
+    {
+        self.context
+            .binop(self.masm, OperandSize::S128, |masm, dst, src, _size| {
+                emit(masm, dst, src);
+                TypedReg::v128(dst)
+            });
+    }
+
+    /// Emit an in-place unary operation on a v128 operand.
+    fn v128_unop<F>(&mut self, mut emit: F)
+    where
+        F: FnMut(&mut M, Reg),
+    {
+        self.context
+            .unop(self.masm, OperandSize::S128, &mut |masm, reg, _size| {
+                emit(masm, reg);
+                TypedReg::v128(reg)
+            });
+    }
+
+    /// Emit a unary operation on a v128 operand whose result must be put in
+    /// a different register.
+    fn v128_convert_op<F>(&mut self, mut emit: F)
+    where
+        F: FnMut(&mut M, Reg, Reg),
+    {
+        self.context
+            .convert_op(self.masm, WasmType::V128, |masm, dst, src, _size| {
+                emit(masm, dst, src)
+            });
+    }
+
+    fn v128_int_cmp(&mut self, kind: IntCmpKind, lane_size: OperandSize) {
+        self.v128_binop(|masm, dst, src| masm.v128_int_cmp(dst, dst, src, kind, lane_size));
+    }
+
+    fn v128_float_cmp(&mut self, kind: FloatCmpKind, size: OperandSize) {
+        self.v128_binop(|masm, dst, src| masm.v128_float_cmp(dst, dst, src, kind, size));
+    }
+
+    fn splat(&mut self, shape: VectorShape) {
+        self.context
+            .convert_op(self.masm, WasmType::V128, |masm, dst, src, _size| {
+                masm.splat(src, dst, shape)
+            });
+    }
+
+    fn extract_lane(&mut self, lane: u8, shape: VectorShape, kind: Option<ExtendKind>) {
+        self.context
+            .convert_op(self.masm, shape.lane_type(), |masm, dst, src, _size| {
+                masm.extract_lane(src, dst, lane, shape, kind)
+            });
+    }
+
+    fn replace_lane(&mut self, lane: u8, shape: VectorShape) {
+        self.v128_binop(|masm, dst, src| masm.replace_lane(src, dst, lane, shape));
+    }
+
+    /// Emit an extended multiplication, by extending both operands and
+    /// multiplying the resulting lanes.
+    fn v128_extmul(&mut self, extend: V128ExtendKind, mul: V128MulKind) {
+        let rhs = self.context.pop_to_reg(self.masm, None);
+        let lhs = self.context.pop_to_reg(self.masm, None);
+        self.masm.v128_extend(lhs.into(), lhs.into(), extend);
+        self.masm.v128_extend(rhs.into(), rhs.into(), extend);
+        self.context.stack.push(lhs.into());
+        self.context.stack.push(rhs.into());
+        self.masm.v128_mul(&mut self.context, mul);
+    }
+}
+
+impl From<WasmType> for OperandSize {
+    fn from(ty: WasmType) -> OperandSize {
+        match ty {
+            WasmType::I32 | WasmType::F32 => OperandSize::S32,
+            WasmType::I64 | WasmType::F64 => OperandSize::S64,
+            WasmType::V128 => OperandSize::S128,
+            // TODO: Harcoded size, assuming 64-bit support only. Once
+            // Wasmtime supports 32-bit architectures, this will need
+            // to be updated in such a way that the calculation of the
+            // OperandSize will depend on the target's  pointer size.
+            WasmType::Ref(_) => OperandSize::S64,
         }
     }
 }
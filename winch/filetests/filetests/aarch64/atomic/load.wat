@@ -0,0 +1,69 @@
+;;! target = "aarch64"
+
+(module
+  (memory 1 1 shared)
+  (func (param i32) (result i32)
+    (i32.atomic.load offset=8 (local.get 0))
+  )
+
+  (func (param i32) (result i64)
+    (i64.atomic.load8_u (local.get 0))
+  )
+)
+;;      	 fd7bbfa9             	stp	x29, x30, [sp, #-0x10]!
+;;      	 fd030091             	mov	x29, sp
+;;      	 fc0f1ff8             	str	x28, [sp, #-0x10]!
+;;      	 fc030091             	mov	x28, sp
+;;      	 e90300aa             	mov	x9, x0
+;;      	 ff4300d1             	sub	sp, sp, #0x10
+;;      	 fc030091             	mov	x28, sp
+;;      	 308140f8             	ldur	x16, [x9, #8]
+;;      	 100240f8             	ldur	x16, [x16]
+;;      	 ff6330eb             	cmp	sp, x16
+;;      	 63020054             	b.lo	#0x74
+;;   2c:	 82c300b8             	stur	w2, [x28, #0xc]
+;;      	 890300f8             	stur	x9, [x28]
+;;      	 80c340b8             	ldur	w0, [x28, #0xc]
+;;      	 e103002a             	mov	w1, w0
+;;      	 21040012             	and	w1, w1, #3
+;;      	 3f000071             	cmp	w1, #0
+;;      	 a1010054             	b.ne	#0x78
+;;   48:	 e003002a             	mov	w0, w0
+;;      	 308144f8             	ldur	x16, [x9, #0x48]
+;;      	 010240f8             	ldur	x1, [x16]
+;;      	 2160208b             	add	x1, x1, x0, uxtx
+;;      	 21200091             	add	x1, x1, #8
+;;      	 20fcdf88             	ldar	w0, [x1]
+;;      	 ff430091             	add	sp, sp, #0x10
+;;      	 fc030091             	mov	x28, sp
+;;      	 fc0741f8             	ldr	x28, [sp], #0x10
+;;      	 fd7bc1a8             	ldp	x29, x30, [sp], #0x10
+;;      	 c0035fd6             	ret	
+;;   74:	 1fc10000             	.byte	0x1f, 0xc1, 0x00, 0x00
+;;   78:	 1fc10000             	.byte	0x1f, 0xc1, 0x00, 0x00
+;;
+;;      	 fd7bbfa9             	stp	x29, x30, [sp, #-0x10]!
+;;      	 fd030091             	mov	x29, sp
+;;      	 fc0f1ff8             	str	x28, [sp, #-0x10]!
+;;      	 fc030091             	mov	x28, sp
+;;      	 e90300aa             	mov	x9, x0
+;;      	 ff4300d1             	sub	sp, sp, #0x10
+;;      	 fc030091             	mov	x28, sp
+;;      	 308140f8             	ldur	x16, [x9, #8]
+;;      	 100240f8             	ldur	x16, [x16]
+;;      	 ff6330eb             	cmp	sp, x16
+;;      	 c3010054             	b.lo	#0x60
+;;   2c:	 82c300b8             	stur	w2, [x28, #0xc]
+;;      	 890300f8             	stur	x9, [x28]
+;;      	 80c340b8             	ldur	w0, [x28, #0xc]
+;;      	 e003002a             	mov	w0, w0
+;;      	 308144f8             	ldur	x16, [x9, #0x48]
+;;      	 010240f8             	ldur	x1, [x16]
+;;      	 2160208b             	add	x1, x1, x0, uxtx
+;;      	 20fcdf08             	ldarb	w0, [x1]
+;;      	 ff430091             	add	sp, sp, #0x10
+;;      	 fc030091             	mov	x28, sp
+;;      	 fc0741f8             	ldr	x28, [sp], #0x10
+;;      	 fd7bc1a8             	ldp	x29, x30, [sp], #0x10
+;;      	 c0035fd6             	ret	
+;;   60:	 1fc10000             	.byte	0x1f, 0xc1, 0x00, 0x00
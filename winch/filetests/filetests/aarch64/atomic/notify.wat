@@ -0,0 +1,54 @@
+;;! target = "aarch64"
+
+(module
+  (memory 1 1 shared)
+  (func (param i32 i32) (result i32)
+    (memory.atomic.notify (local.get 0) (local.get 1))
+  )
+)
+;;      	 fd7bbfa9             	stp	x29, x30, [sp, #-0x10]!
+;;      	 fd030091             	mov	x29, sp
+;;      	 fc0f1ff8             	str	x28, [sp, #-0x10]!
+;;      	 fc030091             	mov	x28, sp
+;;      	 e90300aa             	mov	x9, x0
+;;      	 ff4300d1             	sub	sp, sp, #0x10
+;;      	 fc030091             	mov	x28, sp
+;;      	 308140f8             	ldur	x16, [x9, #8]
+;;      	 100240f8             	ldur	x16, [x16]
+;;      	 ff6330eb             	cmp	sp, x16
+;;      	 63040054             	b.lo	#0xb4
+;;   2c:	 82c300b8             	stur	w2, [x28, #0xc]
+;;      	 838300b8             	stur	w3, [x28, #8]
+;;      	 890300f8             	stur	x9, [x28]
+;;      	 808340b8             	ldur	w0, [x28, #8]
+;;      	 81c340b8             	ldur	w1, [x28, #0xc]
+;;      	 e103012a             	mov	w1, w1
+;;      	 ff2300d1             	sub	sp, sp, #8
+;;      	 fc030091             	mov	x28, sp
+;;      	 890300f8             	stur	x9, [x28]
+;;      	 ff2300d1             	sub	sp, sp, #8
+;;      	 fc030091             	mov	x28, sp
+;;      	 810300f8             	stur	x1, [x28]
+;;      	 ff2300d1             	sub	sp, sp, #8
+;;      	 fc030091             	mov	x28, sp
+;;      	 800300b8             	stur	w0, [x28]
+;;      	 308143f8             	ldur	x16, [x9, #0x38]
+;;      	 040249f8             	ldur	x4, [x16, #0x90]
+;;      	 ff2300d1             	sub	sp, sp, #8
+;;      	 fc030091             	mov	x28, sp
+;;      	 808341f8             	ldur	x0, [x28, #0x18]
+;;      	 100080d2             	mov	x16, #0
+;;      	 e103102a             	mov	w1, w16
+;;      	 820341f8             	ldur	x2, [x28, #0x10]
+;;      	 838340b8             	ldur	w3, [x28, #8]
+;;      	 80003fd6             	blr	x4
+;;   90:	 ff230091             	add	sp, sp, #8
+;;      	 fc030091             	mov	x28, sp
+;;      	 ff630091             	add	sp, sp, #0x18
+;;      	 fc030091             	mov	x28, sp
+;;      	 ff430091             	add	sp, sp, #0x10
+;;      	 fc030091             	mov	x28, sp
+;;      	 fc0741f8             	ldr	x28, [sp], #0x10
+;;      	 fd7bc1a8             	ldp	x29, x30, [sp], #0x10
+;;      	 c0035fd6             	ret	
+;;   b4:	 1fc10000             	.byte	0x1f, 0xc1, 0x00, 0x00
@@ -0,0 +1,43 @@
+;;! target = "aarch64"
+
+(module
+  (memory 1)
+  (func (param i32 i64) (result i64)
+    (i64.atomic.rmw16.xchg_u offset=2 (local.get 0) (local.get 1))
+  )
+)
+;;      	 fd7bbfa9             	stp	x29, x30, [sp, #-0x10]!
+;;      	 fd030091             	mov	x29, sp
+;;      	 fc0f1ff8             	str	x28, [sp, #-0x10]!
+;;      	 fc030091             	mov	x28, sp
+;;      	 e90300aa             	mov	x9, x0
+;;      	 ff6300d1             	sub	sp, sp, #0x18
+;;      	 fc030091             	mov	x28, sp
+;;      	 308140f8             	ldur	x16, [x9, #8]
+;;      	 100240f8             	ldur	x16, [x16]
+;;      	 ff6330eb             	cmp	sp, x16
+;;      	 e3020054             	b.lo	#0x84
+;;   2c:	 824301b8             	stur	w2, [x28, #0x14]
+;;      	 838300f8             	stur	x3, [x28, #8]
+;;      	 890300f8             	stur	x9, [x28]
+;;      	 808340f8             	ldur	x0, [x28, #8]
+;;      	 814341b8             	ldur	w1, [x28, #0x14]
+;;      	 e203012a             	mov	w2, w1
+;;      	 42000012             	and	w2, w2, #1
+;;      	 5f000071             	cmp	w2, #0
+;;      	 e1010054             	b.ne	#0x88
+;;   50:	 e103012a             	mov	w1, w1
+;;      	 220145f8             	ldur	x2, [x9, #0x50]
+;;      	 4260218b             	add	x2, x2, x1, uxtx
+;;      	 42080091             	add	x2, x2, #2
+;;      	 41fc5f48             	ldaxrh	w1, [x2]
+;;      	 40fc1148             	stlxrh	w17, w0, [x2]
+;;      	 d1ffffb5             	cbnz	x17, #0x60
+;;   6c:	 e00301aa             	mov	x0, x1
+;;      	 ff630091             	add	sp, sp, #0x18
+;;      	 fc030091             	mov	x28, sp
+;;      	 fc0741f8             	ldr	x28, [sp], #0x10
+;;      	 fd7bc1a8             	ldp	x29, x30, [sp], #0x10
+;;      	 c0035fd6             	ret	
+;;   84:	 1fc10000             	.byte	0x1f, 0xc1, 0x00, 0x00
+;;   88:	 1fc10000             	.byte	0x1f, 0xc1, 0x00, 0x00
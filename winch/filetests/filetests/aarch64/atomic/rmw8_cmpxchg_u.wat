@@ -0,0 +1,42 @@
+;;! target = "aarch64"
+
+(module
+  (memory 1)
+  (func (param i32 i32 i32) (result i32)
+    (i32.atomic.rmw8.cmpxchg_u (local.get 0) (local.get 1) (local.get 2))
+  )
+)
+;;      	 fd7bbfa9             	stp	x29, x30, [sp, #-0x10]!
+;;      	 fd030091             	mov	x29, sp
+;;      	 fc0f1ff8             	str	x28, [sp, #-0x10]!
+;;      	 fc030091             	mov	x28, sp
+;;      	 e90300aa             	mov	x9, x0
+;;      	 ff6300d1             	sub	sp, sp, #0x18
+;;      	 fc030091             	mov	x28, sp
+;;      	 308140f8             	ldur	x16, [x9, #8]
+;;      	 100240f8             	ldur	x16, [x16]
+;;      	 ff6330eb             	cmp	sp, x16
+;;      	 e3020054             	b.lo	#0x84
+;;   2c:	 824301b8             	stur	w2, [x28, #0x14]
+;;      	 830301b8             	stur	w3, [x28, #0x10]
+;;      	 84c300b8             	stur	w4, [x28, #0xc]
+;;      	 890300f8             	stur	x9, [x28]
+;;      	 80c340b8             	ldur	w0, [x28, #0xc]
+;;      	 810341b8             	ldur	w1, [x28, #0x10]
+;;      	 824341b8             	ldur	w2, [x28, #0x14]
+;;      	 e203022a             	mov	w2, w2
+;;      	 230145f8             	ldur	x3, [x9, #0x50]
+;;      	 6360228b             	add	x3, x3, x2, uxtx
+;;      	 211c0012             	and	w1, w1, #0xff
+;;      	 62fc5f08             	ldaxrb	w2, [x3]
+;;      	 5f60216b             	cmp	w2, w1, uxtx
+;;      	 61000054             	b.ne	#0x6c
+;;   64:	 60fc1108             	stlxrb	w17, w0, [x3]
+;;      	 91ffffb5             	cbnz	x17, #0x58
+;;   6c:	 e003022a             	mov	w0, w2
+;;      	 ff630091             	add	sp, sp, #0x18
+;;      	 fc030091             	mov	x28, sp
+;;      	 fc0741f8             	ldr	x28, [sp], #0x10
+;;      	 fd7bc1a8             	ldp	x29, x30, [sp], #0x10
+;;      	 c0035fd6             	ret	
+;;   84:	 1fc10000             	.byte	0x1f, 0xc1, 0x00, 0x00
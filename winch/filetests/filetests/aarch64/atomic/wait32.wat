@@ -0,0 +1,63 @@
+;;! target = "aarch64"
+
+(module
+  (memory 1 1 shared)
+  (func (param i32 i32 i64) (result i32)
+    (memory.atomic.wait32 offset=4 (local.get 0) (local.get 1) (local.get 2))
+  )
+)
+;;      	 fd7bbfa9             	stp	x29, x30, [sp, #-0x10]!
+;;      	 fd030091             	mov	x29, sp
+;;      	 fc0f1ff8             	str	x28, [sp, #-0x10]!
+;;      	 fc030091             	mov	x28, sp
+;;      	 e90300aa             	mov	x9, x0
+;;      	 ff6300d1             	sub	sp, sp, #0x18
+;;      	 fc030091             	mov	x28, sp
+;;      	 308140f8             	ldur	x16, [x9, #8]
+;;      	 100240f8             	ldur	x16, [x16]
+;;      	 ff6330eb             	cmp	sp, x16
+;;      	 63050054             	b.lo	#0xd4
+;;   2c:	 824301b8             	stur	w2, [x28, #0x14]
+;;      	 830301b8             	stur	w3, [x28, #0x10]
+;;      	 848300f8             	stur	x4, [x28, #8]
+;;      	 890300f8             	stur	x9, [x28]
+;;      	 808340f8             	ldur	x0, [x28, #8]
+;;      	 810341b8             	ldur	w1, [x28, #0x10]
+;;      	 824341b8             	ldur	w2, [x28, #0x14]
+;;      	 42100031             	adds	w2, w2, #4
+;;      	 62040054             	b.hs	#0xd8
+;;   50:	 e203022a             	mov	w2, w2
+;;      	 ff2300d1             	sub	sp, sp, #8
+;;      	 fc030091             	mov	x28, sp
+;;      	 890300f8             	stur	x9, [x28]
+;;      	 ff2300d1             	sub	sp, sp, #8
+;;      	 fc030091             	mov	x28, sp
+;;      	 820300f8             	stur	x2, [x28]
+;;      	 ff2300d1             	sub	sp, sp, #8
+;;      	 fc030091             	mov	x28, sp
+;;      	 810300b8             	stur	w1, [x28]
+;;      	 ff2300d1             	sub	sp, sp, #8
+;;      	 fc030091             	mov	x28, sp
+;;      	 800300f8             	stur	x0, [x28]
+;;      	 308143f8             	ldur	x16, [x9, #0x38]
+;;      	 058249f8             	ldur	x5, [x16, #0x98]
+;;      	 ff2300d1             	sub	sp, sp, #8
+;;      	 fc030091             	mov	x28, sp
+;;      	 800342f8             	ldur	x0, [x28, #0x20]
+;;      	 100080d2             	mov	x16, #0
+;;      	 e103102a             	mov	w1, w16
+;;      	 828341f8             	ldur	x2, [x28, #0x18]
+;;      	 830341b8             	ldur	w3, [x28, #0x10]
+;;      	 848340f8             	ldur	x4, [x28, #8]
+;;      	 a0003fd6             	blr	x5
+;;   b0:	 ff230091             	add	sp, sp, #8
+;;      	 fc030091             	mov	x28, sp
+;;      	 ff830091             	add	sp, sp, #0x20
+;;      	 fc030091             	mov	x28, sp
+;;      	 ff630091             	add	sp, sp, #0x18
+;;      	 fc030091             	mov	x28, sp
+;;      	 fc0741f8             	ldr	x28, [sp], #0x10
+;;      	 fd7bc1a8             	ldp	x29, x30, [sp], #0x10
+;;      	 c0035fd6             	ret	
+;;   d4:	 1fc10000             	.byte	0x1f, 0xc1, 0x00, 0x00
+;;   d8:	 1fc10000             	.byte	0x1f, 0xc1, 0x00, 0x00
@@ -0,0 +1,56 @@
+;;! target = "aarch64"
+
+(module
+  (func $dummy)
+  (func (export "as-if-condition")
+   (block (result i32) (i32.const 1)) (if (then (call $dummy)))
+  )
+)
+;;      	 fd7bbfa9             	stp	x29, x30, [sp, #-0x10]!
+;;      	 fd030091             	mov	x29, sp
+;;      	 fc0f1ff8             	str	x28, [sp, #-0x10]!
+;;      	 fc030091             	mov	x28, sp
+;;      	 e90300aa             	mov	x9, x0
+;;      	 ff2300d1             	sub	sp, sp, #8
+;;      	 fc030091             	mov	x28, sp
+;;      	 308140f8             	ldur	x16, [x9, #8]
+;;      	 100240f8             	ldur	x16, [x16]
+;;      	 ff6330eb             	cmp	sp, x16
+;;      	 e3000054             	b.lo	#0x44
+;;   2c:	 890300f8             	stur	x9, [x28]
+;;      	 ff230091             	add	sp, sp, #8
+;;      	 fc030091             	mov	x28, sp
+;;      	 fc0741f8             	ldr	x28, [sp], #0x10
+;;      	 fd7bc1a8             	ldp	x29, x30, [sp], #0x10
+;;      	 c0035fd6             	ret	
+;;   44:	 1fc10000             	.byte	0x1f, 0xc1, 0x00, 0x00
+;;
+;;      	 fd7bbfa9             	stp	x29, x30, [sp, #-0x10]!
+;;      	 fd030091             	mov	x29, sp
+;;      	 fc0f1ff8             	str	x28, [sp, #-0x10]!
+;;      	 fc030091             	mov	x28, sp
+;;      	 e90300aa             	mov	x9, x0
+;;      	 ff2300d1             	sub	sp, sp, #8
+;;      	 fc030091             	mov	x28, sp
+;;      	 308140f8             	ldur	x16, [x9, #8]
+;;      	 100240f8             	ldur	x16, [x16]
+;;      	 ff6330eb             	cmp	sp, x16
+;;      	 43020054             	b.lo	#0x70
+;;   2c:	 890300f8             	stur	x9, [x28]
+;;      	 300080d2             	mov	x16, #1
+;;      	 e003102a             	mov	w0, w16
+;;      	 1f00006a             	tst	w0, w0
+;;      	 00010054             	b.eq	#0x5c
+;;   40:	 ff2300d1             	sub	sp, sp, #8
+;;      	 fc030091             	mov	x28, sp
+;;      	 e00309aa             	mov	x0, x9
+;;      	 e10309aa             	mov	x1, x9
+;;      	 00000094             	bl	#0x50
+;;   54:	 ff230091             	add	sp, sp, #8
+;;      	 fc030091             	mov	x28, sp
+;;      	 ff230091             	add	sp, sp, #8
+;;      	 fc030091             	mov	x28, sp
+;;      	 fc0741f8             	ldr	x28, [sp], #0x10
+;;      	 fd7bc1a8             	ldp	x29, x30, [sp], #0x10
+;;      	 c0035fd6             	ret	
+;;   70:	 1fc10000             	.byte	0x1f, 0xc1, 0x00, 0x00
@@ -0,0 +1,33 @@
+;;! target = "aarch64"
+(module
+  (func (export "as-if-else") (result i32)
+      (if (result i32) (i32.const 1) (then (i32.const 2)) (else (block (result i32) (i32.const 1))))
+  )
+)
+;;      	 fd7bbfa9             	stp	x29, x30, [sp, #-0x10]!
+;;      	 fd030091             	mov	x29, sp
+;;      	 fc0f1ff8             	str	x28, [sp, #-0x10]!
+;;      	 fc030091             	mov	x28, sp
+;;      	 e90300aa             	mov	x9, x0
+;;      	 ff2300d1             	sub	sp, sp, #8
+;;      	 fc030091             	mov	x28, sp
+;;      	 308140f8             	ldur	x16, [x9, #8]
+;;      	 100240f8             	ldur	x16, [x16]
+;;      	 ff6330eb             	cmp	sp, x16
+;;      	 03020054             	b.lo	#0x68
+;;   2c:	 890300f8             	stur	x9, [x28]
+;;      	 300080d2             	mov	x16, #1
+;;      	 e003102a             	mov	w0, w16
+;;      	 1f00006a             	tst	w0, w0
+;;      	 80000054             	b.eq	#0x4c
+;;   40:	 500080d2             	mov	x16, #2
+;;      	 e003102a             	mov	w0, w16
+;;      	 03000014             	b	#0x54
+;;   4c:	 300080d2             	mov	x16, #1
+;;      	 e003102a             	mov	w0, w16
+;;      	 ff230091             	add	sp, sp, #8
+;;      	 fc030091             	mov	x28, sp
+;;      	 fc0741f8             	ldr	x28, [sp], #0x10
+;;      	 fd7bc1a8             	ldp	x29, x30, [sp], #0x10
+;;      	 c0035fd6             	ret	
+;;   68:	 1fc10000             	.byte	0x1f, 0xc1, 0x00, 0x00
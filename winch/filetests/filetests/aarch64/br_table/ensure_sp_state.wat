@@ -0,0 +1,49 @@
+;;! target = "aarch64"
+
+(module
+  (func (export "") (result i32)
+    block (result i32)
+       i32.const 0
+    end
+    i32.const 0
+    i32.const 0
+    br_table 0
+  )
+)
+;;      	 fd7bbfa9             	stp	x29, x30, [sp, #-0x10]!
+;;      	 fd030091             	mov	x29, sp
+;;      	 fc0f1ff8             	str	x28, [sp, #-0x10]!
+;;      	 fc030091             	mov	x28, sp
+;;      	 e90300aa             	mov	x9, x0
+;;      	 ff2300d1             	sub	sp, sp, #8
+;;      	 fc030091             	mov	x28, sp
+;;      	 308140f8             	ldur	x16, [x9, #8]
+;;      	 100240f8             	ldur	x16, [x16]
+;;      	 ff6330eb             	cmp	sp, x16
+;;      	 43030054             	b.lo	#0x90
+;;   2c:	 890300f8             	stur	x9, [x28]
+;;      	 100080d2             	mov	x16, #0
+;;      	 e003102a             	mov	w0, w16
+;;      	 ff2300d1             	sub	sp, sp, #8
+;;      	 fc030091             	mov	x28, sp
+;;      	 800300b8             	stur	w0, [x28]
+;;      	 100080d2             	mov	x16, #0
+;;      	 e103102a             	mov	w1, w16
+;;      	 100080d2             	mov	x16, #0
+;;      	 e003102a             	mov	w0, w16
+;;      	 3f000071             	cmp	w1, #0
+;;      	 e2000054             	b.hs	#0x74
+;;   5c:	 e223819a             	csel	x2, xzr, x1, hs
+;;      	 9f2203d5             	csdb	
+;;      	 90000010             	adr	x16, #0x74
+;;      	 025aa2b8             	ldrsw	x2, [x16, w2, uxtw #2]
+;;      	 1002028b             	add	x16, x16, x2
+;;      	 00021fd6             	br	x16
+;;   74:	 ff230091             	add	sp, sp, #8
+;;      	 fc030091             	mov	x28, sp
+;;      	 ff230091             	add	sp, sp, #8
+;;      	 fc030091             	mov	x28, sp
+;;      	 fc0741f8             	ldr	x28, [sp], #0x10
+;;      	 fd7bc1a8             	ldp	x29, x30, [sp], #0x10
+;;      	 c0035fd6             	ret	
+;;   90:	 1fc10000             	.byte	0x1f, 0xc1, 0x00, 0x00
@@ -0,0 +1,180 @@
+;;! target = "aarch64"
+
+(module
+  (func (export "main") (param i32) (param i32) (result i32)
+    (local.get 1)
+    (local.get 0)
+    (i32.div_u)
+
+    (call $add (i32.const 1) (i32.const 2) (i32.const 3) (i32.const 4) (i32.const 5) (i32.const 6) (i32.const 7) (i32.const 8))
+
+    (local.get 1)
+    (local.get 0)
+    (i32.div_u)
+
+    (call $add (i32.const 2) (i32.const 3) (i32.const 4) (i32.const 5) (i32.const 6) (i32.const 7) (i32.const 8))
+  )
+
+  (func $add (param i32 i32 i32 i32 i32 i32 i32 i32 i32) (result i32)
+    (local.get 0)
+    (local.get 1)
+    (i32.add)
+    (local.get 2)
+    (i32.add)
+    (local.get 3)
+    (i32.add)
+    (local.get 4)
+    (i32.add)
+    (local.get 5)
+    (i32.add)
+    (local.get 6)
+    (i32.add)
+    (local.get 7)
+    (i32.add)
+    (local.get 8)
+    (i32.add)
+  )
+)
+;;      	 fd7bbfa9             	stp	x29, x30, [sp, #-0x10]!
+;;      	 fd030091             	mov	x29, sp
+;;      	 fc0f1ff8             	str	x28, [sp, #-0x10]!
+;;      	 fc030091             	mov	x28, sp
+;;      	 e90300aa             	mov	x9, x0
+;;      	 ff4300d1             	sub	sp, sp, #0x10
+;;      	 fc030091             	mov	x28, sp
+;;      	 308140f8             	ldur	x16, [x9, #8]
+;;      	 100240f8             	ldur	x16, [x16]
+;;      	 ff6330eb             	cmp	sp, x16
+;;      	 a30a0054             	b.lo	#0x17c
+;;   2c:	 82c300b8             	stur	w2, [x28, #0xc]
+;;      	 838300b8             	stur	w3, [x28, #8]
+;;      	 890300f8             	stur	x9, [x28]
+;;      	 80c340b8             	ldur	w0, [x28, #0xc]
+;;      	 818340b8             	ldur	w1, [x28, #8]
+;;      	 e003002a             	mov	w0, w0
+;;      	 e103012a             	mov	w1, w1
+;;      	 c00900b4             	cbz	x0, #0x180
+;;   4c:	 2108c09a             	udiv	x1, x1, x0
+;;      	 ff2300d1             	sub	sp, sp, #8
+;;      	 fc030091             	mov	x28, sp
+;;      	 810300b8             	stur	w1, [x28]
+;;      	 ffa300d1             	sub	sp, sp, #0x28
+;;      	 fc030091             	mov	x28, sp
+;;      	 e00309aa             	mov	x0, x9
+;;      	 e10309aa             	mov	x1, x9
+;;      	 828342b8             	ldur	w2, [x28, #0x28]
+;;      	 300080d2             	mov	x16, #1
+;;      	 e303102a             	mov	w3, w16
+;;      	 500080d2             	mov	x16, #2
+;;      	 e403102a             	mov	w4, w16
+;;      	 700080d2             	mov	x16, #3
+;;      	 e503102a             	mov	w5, w16
+;;      	 900080d2             	mov	x16, #4
+;;      	 e603102a             	mov	w6, w16
+;;      	 b00080d2             	mov	x16, #5
+;;      	 e703102a             	mov	w7, w16
+;;      	 d00080d2             	mov	x16, #6
+;;      	 f003102a             	mov	w16, w16
+;;      	 900300b8             	stur	w16, [x28]
+;;      	 f00080d2             	mov	x16, #7
+;;      	 f003102a             	mov	w16, w16
+;;      	 908300b8             	stur	w16, [x28, #8]
+;;      	 100180d2             	mov	x16, #8
+;;      	 f003102a             	mov	w16, w16
+;;      	 900301b8             	stur	w16, [x28, #0x10]
+;;      	 00000094             	bl	#0xbc
+;;   c0:	 ff230091             	add	sp, sp, #8
+;;      	 fc030091             	mov	x28, sp
+;;      	 ff230091             	add	sp, sp, #8
+;;      	 fc030091             	mov	x28, sp
+;;      	 81c340b8             	ldur	w1, [x28, #0xc]
+;;      	 828340b8             	ldur	w2, [x28, #8]
+;;      	 e103012a             	mov	w1, w1
+;;      	 e203022a             	mov	w2, w2
+;;      	 210500b4             	cbz	x1, #0x184
+;;   e4:	 4208c19a             	udiv	x2, x2, x1
+;;      	 ff2300d1             	sub	sp, sp, #8
+;;      	 fc030091             	mov	x28, sp
+;;      	 800300b8             	stur	w0, [x28]
+;;      	 ff2300d1             	sub	sp, sp, #8
+;;      	 fc030091             	mov	x28, sp
+;;      	 820300b8             	stur	w2, [x28]
+;;      	 ff8300d1             	sub	sp, sp, #0x20
+;;      	 fc030091             	mov	x28, sp
+;;      	 e00309aa             	mov	x0, x9
+;;      	 e10309aa             	mov	x1, x9
+;;      	 828342b8             	ldur	w2, [x28, #0x28]
+;;      	 830342b8             	ldur	w3, [x28, #0x20]
+;;      	 500080d2             	mov	x16, #2
+;;      	 e403102a             	mov	w4, w16
+;;      	 700080d2             	mov	x16, #3
+;;      	 e503102a             	mov	w5, w16
+;;      	 900080d2             	mov	x16, #4
+;;      	 e603102a             	mov	w6, w16
+;;      	 b00080d2             	mov	x16, #5
+;;      	 e703102a             	mov	w7, w16
+;;      	 d00080d2             	mov	x16, #6
+;;      	 f003102a             	mov	w16, w16
+;;      	 900300b8             	stur	w16, [x28]
+;;      	 f00080d2             	mov	x16, #7
+;;      	 f003102a             	mov	w16, w16
+;;      	 908300b8             	stur	w16, [x28, #8]
+;;      	 100180d2             	mov	x16, #8
+;;      	 f003102a             	mov	w16, w16
+;;      	 900301b8             	stur	w16, [x28, #0x10]
+;;      	 00000094             	bl	#0x15c
+;;  160:	 ff430091             	add	sp, sp, #0x10
+;;      	 fc030091             	mov	x28, sp
+;;      	 ff430091             	add	sp, sp, #0x10
+;;      	 fc030091             	mov	x28, sp
+;;      	 fc0741f8             	ldr	x28, [sp], #0x10
+;;      	 fd7bc1a8             	ldp	x29, x30, [sp], #0x10
+;;      	 c0035fd6             	ret	
+;;  17c:	 1fc10000             	.byte	0x1f, 0xc1, 0x00, 0x00
+;;  180:	 1fc10000             	.byte	0x1f, 0xc1, 0x00, 0x00
+;;  184:	 1fc10000             	.byte	0x1f, 0xc1, 0x00, 0x00
+;;
+;;      	 fd7bbfa9             	stp	x29, x30, [sp, #-0x10]!
+;;      	 fd030091             	mov	x29, sp
+;;      	 fc0f1ff8             	str	x28, [sp, #-0x10]!
+;;      	 fc030091             	mov	x28, sp
+;;      	 e90300aa             	mov	x9, x0
+;;      	 ff8300d1             	sub	sp, sp, #0x20
+;;      	 fc030091             	mov	x28, sp
+;;      	 308140f8             	ldur	x16, [x9, #8]
+;;      	 100240f8             	ldur	x16, [x16]
+;;      	 ff6330eb             	cmp	sp, x16
+;;      	 23040054             	b.lo	#0xac
+;;   2c:	 82c301b8             	stur	w2, [x28, #0x1c]
+;;      	 838301b8             	stur	w3, [x28, #0x18]
+;;      	 844301b8             	stur	w4, [x28, #0x14]
+;;      	 850301b8             	stur	w5, [x28, #0x10]
+;;      	 86c300b8             	stur	w6, [x28, #0xc]
+;;      	 878300b8             	stur	w7, [x28, #8]
+;;      	 890300f8             	stur	x9, [x28]
+;;      	 808341b8             	ldur	w0, [x28, #0x18]
+;;      	 81c341b8             	ldur	w1, [x28, #0x1c]
+;;      	 2160200b             	add	w1, w1, w0, uxtx
+;;      	 804341b8             	ldur	w0, [x28, #0x14]
+;;      	 2160200b             	add	w1, w1, w0, uxtx
+;;      	 800341b8             	ldur	w0, [x28, #0x10]
+;;      	 2160200b             	add	w1, w1, w0, uxtx
+;;      	 80c340b8             	ldur	w0, [x28, #0xc]
+;;      	 2160200b             	add	w1, w1, w0, uxtx
+;;      	 808340b8             	ldur	w0, [x28, #8]
+;;      	 2160200b             	add	w1, w1, w0, uxtx
+;;      	 a00341b8             	ldur	w0, [x29, #0x10]
+;;      	 2160200b             	add	w1, w1, w0, uxtx
+;;      	 a08341b8             	ldur	w0, [x29, #0x18]
+;;      	 2160200b             	add	w1, w1, w0, uxtx
+;;      	 a00342b8             	ldur	w0, [x29, #0x20]
+;;      	 2160200b             	add	w1, w1, w0, uxtx
+;;      	 e003012a             	mov	w0, w1
+;;      	 ff830091             	add	sp, sp, #0x20
+;;      	 fc030091             	mov	x28, sp
+;;      	 fc0741f8             	ldr	x28, [sp], #0x10
+;;      	 fd7bc1a8             	ldp	x29, x30, [sp], #0x10
+;;      	 ff830091             	add	sp, sp, #0x20
+;;      	 9c830091             	add	x28, x28, #0x20
+;;      	 c0035fd6             	ret	
+;;   ac:	 1fc10000             	.byte	0x1f, 0xc1, 0x00, 0x00
@@ -0,0 +1,198 @@
+;;! target = "aarch64"
+
+(module
+  (type $over-i32 (func (param i32) (result i32)))
+
+  (table funcref
+    (elem
+      $fib-i32
+    )
+  )
+  
+  (func $fib-i32 (export "fib-i32") (type $over-i32)
+    (if (result i32) (i32.le_u (local.get 0) (i32.const 1))
+      (then (i32.const 1))
+      (else
+        (i32.add
+          (call_indirect (type $over-i32)
+            (i32.sub (local.get 0) (i32.const 2))
+            (i32.const 0)
+          )
+          (call_indirect (type $over-i32)
+            (i32.sub (local.get 0) (i32.const 1))
+            (i32.const 0)
+          )
+        )
+      )
+    )
+  )
+)
+;;      	 fd7bbfa9             	stp	x29, x30, [sp, #-0x10]!
+;;      	 fd030091             	mov	x29, sp
+;;      	 fc0f1ff8             	str	x28, [sp, #-0x10]!
+;;      	 fc030091             	mov	x28, sp
+;;      	 e90300aa             	mov	x9, x0
+;;      	 ff4300d1             	sub	sp, sp, #0x10
+;;      	 fc030091             	mov	x28, sp
+;;      	 308140f8             	ldur	x16, [x9, #8]
+;;      	 100240f8             	ldur	x16, [x16]
+;;      	 ff6330eb             	cmp	sp, x16
+;;      	 03130054             	b.lo	#0x288
+;;   2c:	 82c300b8             	stur	w2, [x28, #0xc]
+;;      	 890300f8             	stur	x9, [x28]
+;;      	 80c340b8             	ldur	w0, [x28, #0xc]
+;;      	 1f040071             	cmp	w0, #1
+;;      	 e0879f9a             	cset	x0, ls
+;;      	 1f00006a             	tst	w0, w0
+;;      	 80000054             	b.eq	#0x54
+;;   48:	 300080d2             	mov	x16, #1
+;;      	 e003102a             	mov	w0, w16
+;;      	 89000014             	b	#0x274
+;;   54:	 80c340b8             	ldur	w0, [x28, #0xc]
+;;      	 00080051             	sub	w0, w0, #2
+;;      	 ff2300d1             	sub	sp, sp, #8
+;;      	 fc030091             	mov	x28, sp
+;;      	 800300b8             	stur	w0, [x28]
+;;      	 100080d2             	mov	x16, #0
+;;      	 e103102a             	mov	w1, w16
+;;      	 e20309aa             	mov	x2, x9
+;;      	 430045b8             	ldur	w3, [x2, #0x50]
+;;      	 3f60236b             	cmp	w1, w3, uxtx
+;;      	 82100054             	b.hs	#0x28c
+;;   80:	 f003012a             	mov	w16, w1
+;;      	 040180d2             	mov	x4, #8
+;;      	 107e049b             	mul	x16, x16, x4
+;;      	 428044f8             	ldur	x2, [x2, #0x48]
+;;      	 e40302aa             	mov	x4, x2
+;;      	 4260308b             	add	x2, x2, x16, uxtx
+;;      	 3f60236b             	cmp	w1, w3, uxtx
+;;      	 8220829a             	csel	x2, x4, x2, hs
+;;      	 400040f8             	ldur	x0, [x2]
+;;      	 1f0000ea             	tst	x0, x0
+;;      	 a1020054             	b.ne	#0xfc
+;;   ac:	 ff2300d1             	sub	sp, sp, #8
+;;      	 fc030091             	mov	x28, sp
+;;      	 890300f8             	stur	x9, [x28]
+;;      	 ff2300d1             	sub	sp, sp, #8
+;;      	 fc030091             	mov	x28, sp
+;;      	 810300b8             	stur	w1, [x28]
+;;      	 308143f8             	ldur	x16, [x9, #0x38]
+;;      	 038244f8             	ldur	x3, [x16, #0x48]
+;;      	 ff2300d1             	sub	sp, sp, #8
+;;      	 fc030091             	mov	x28, sp
+;;      	 800341f8             	ldur	x0, [x28, #0x10]
+;;      	 100080d2             	mov	x16, #0
+;;      	 e103102a             	mov	w1, w16
+;;      	 828340b8             	ldur	w2, [x28, #8]
+;;      	 60003fd6             	blr	x3
+;;   e8:	 ff230091             	add	sp, sp, #8
+;;      	 fc030091             	mov	x28, sp
+;;      	 ff430091             	add	sp, sp, #0x10
+;;      	 fc030091             	mov	x28, sp
+;;      	 02000014             	b	#0x100
+;;   fc:	 00f87f92             	and	x0, x0, #0xfffffffffffffffe
+;;      	 800c00b4             	cbz	x0, #0x290
+;;  104:	 300144f8             	ldur	x16, [x9, #0x40]
+;;      	 010240b8             	ldur	w1, [x16]
+;;      	 028041b8             	ldur	w2, [x0, #0x18]
+;;      	 3f60226b             	cmp	w1, w2, uxtx
+;;      	 010c0054             	b.ne	#0x294
+;;  118:	 ff2300d1             	sub	sp, sp, #8
+;;      	 fc030091             	mov	x28, sp
+;;      	 800300f8             	stur	x0, [x28]
+;;      	 830340f8             	ldur	x3, [x28]
+;;      	 ff230091             	add	sp, sp, #8
+;;      	 fc030091             	mov	x28, sp
+;;      	 650042f8             	ldur	x5, [x3, #0x20]
+;;      	 640041f8             	ldur	x4, [x3, #0x10]
+;;      	 ff2300d1             	sub	sp, sp, #8
+;;      	 fc030091             	mov	x28, sp
+;;      	 e00305aa             	mov	x0, x5
+;;      	 e10309aa             	mov	x1, x9
+;;      	 828340b8             	ldur	w2, [x28, #8]
+;;      	 80003fd6             	blr	x4
+;;  150:	 890341f8             	ldur	x9, [x28, #0x10]
+;;      	 ff230091             	add	sp, sp, #8
+;;      	 fc030091             	mov	x28, sp
+;;      	 ff230091             	add	sp, sp, #8
+;;      	 fc030091             	mov	x28, sp
+;;      	 81c340b8             	ldur	w1, [x28, #0xc]
+;;      	 21040051             	sub	w1, w1, #1
+;;      	 ff2300d1             	sub	sp, sp, #8
+;;      	 fc030091             	mov	x28, sp
+;;      	 800300b8             	stur	w0, [x28]
+;;      	 ff2300d1             	sub	sp, sp, #8
+;;      	 fc030091             	mov	x28, sp
+;;      	 810300b8             	stur	w1, [x28]
+;;      	 100080d2             	mov	x16, #0
+;;      	 e103102a             	mov	w1, w16
+;;      	 e20309aa             	mov	x2, x9
+;;      	 430045b8             	ldur	w3, [x2, #0x50]
+;;      	 3f60236b             	cmp	w1, w3, uxtx
+;;      	 02080054             	b.hs	#0x298
+;;  19c:	 f003012a             	mov	w16, w1
+;;      	 040180d2             	mov	x4, #8
+;;      	 107e049b             	mul	x16, x16, x4
+;;      	 428044f8             	ldur	x2, [x2, #0x48]
+;;      	 e40302aa             	mov	x4, x2
+;;      	 4260308b             	add	x2, x2, x16, uxtx
+;;      	 3f60236b             	cmp	w1, w3, uxtx
+;;      	 8220829a             	csel	x2, x4, x2, hs
+;;      	 400040f8             	ldur	x0, [x2]
+;;      	 1f0000ea             	tst	x0, x0
+;;      	 21020054             	b.ne	#0x208
+;;  1c8:	 ff2300d1             	sub	sp, sp, #8
+;;      	 fc030091             	mov	x28, sp
+;;      	 890300f8             	stur	x9, [x28]
+;;      	 ff2300d1             	sub	sp, sp, #8
+;;      	 fc030091             	mov	x28, sp
+;;      	 810300b8             	stur	w1, [x28]
+;;      	 308143f8             	ldur	x16, [x9, #0x38]
+;;      	 038244f8             	ldur	x3, [x16, #0x48]
+;;      	 808340f8             	ldur	x0, [x28, #8]
+;;      	 100080d2             	mov	x16, #0
+;;      	 e103102a             	mov	w1, w16
+;;      	 820340b8             	ldur	w2, [x28]
+;;      	 60003fd6             	blr	x3
+;;  1fc:	 ff430091             	add	sp, sp, #0x10
+;;      	 fc030091             	mov	x28, sp
+;;      	 02000014             	b	#0x20c
+;;  208:	 00f87f92             	and	x0, x0, #0xfffffffffffffffe
+;;      	 800400b4             	cbz	x0, #0x29c
+;;  210:	 300144f8             	ldur	x16, [x9, #0x40]
+;;      	 010240b8             	ldur	w1, [x16]
+;;      	 028041b8             	ldur	w2, [x0, #0x18]
+;;      	 3f60226b             	cmp	w1, w2, uxtx
+;;      	 01040054             	b.ne	#0x2a0
+;;  224:	 ff2300d1             	sub	sp, sp, #8
+;;      	 fc030091             	mov	x28, sp
+;;      	 800300f8             	stur	x0, [x28]
+;;      	 830340f8             	ldur	x3, [x28]
+;;      	 ff230091             	add	sp, sp, #8
+;;      	 fc030091             	mov	x28, sp
+;;      	 650042f8             	ldur	x5, [x3, #0x20]
+;;      	 640041f8             	ldur	x4, [x3, #0x10]
+;;      	 e00305aa             	mov	x0, x5
+;;      	 e10309aa             	mov	x1, x9
+;;      	 820340b8             	ldur	w2, [x28]
+;;      	 80003fd6             	blr	x4
+;;  254:	 890341f8             	ldur	x9, [x28, #0x10]
+;;      	 ff230091             	add	sp, sp, #8
+;;      	 fc030091             	mov	x28, sp
+;;      	 810340b8             	ldur	w1, [x28]
+;;      	 ff230091             	add	sp, sp, #8
+;;      	 fc030091             	mov	x28, sp
+;;      	 2160200b             	add	w1, w1, w0, uxtx
+;;      	 e003012a             	mov	w0, w1
+;;      	 ff430091             	add	sp, sp, #0x10
+;;      	 fc030091             	mov	x28, sp
+;;      	 fc0741f8             	ldr	x28, [sp], #0x10
+;;      	 fd7bc1a8             	ldp	x29, x30, [sp], #0x10
+;;      	 c0035fd6             	ret	
+;;  288:	 1fc10000             	.byte	0x1f, 0xc1, 0x00, 0x00
+;;  28c:	 1fc10000             	.byte	0x1f, 0xc1, 0x00, 0x00
+;;  290:	 1fc10000             	.byte	0x1f, 0xc1, 0x00, 0x00
+;;  294:	 1fc10000             	.byte	0x1f, 0xc1, 0x00, 0x00
+;;  298:	 1fc10000             	.byte	0x1f, 0xc1, 0x00, 0x00
+;;  29c:	 1fc10000             	.byte	0x1f, 0xc1, 0x00, 0x00
+;;  2a0:	 1fc10000             	.byte	0x1f, 0xc1, 0x00, 0x00
@@ -0,0 +1,127 @@
+;;! target = "aarch64"
+
+(module
+    (type $param-i32 (func (param i32)))
+
+    (func $param-i32 (type $param-i32))
+    (func (export "")
+        (local i32)
+        local.get 0
+        (call_indirect (type $param-i32) (i32.const 0))
+    )
+
+    (table funcref
+      (elem
+        $param-i32)
+    )
+)
+;;      	 fd7bbfa9             	stp	x29, x30, [sp, #-0x10]!
+;;      	 fd030091             	mov	x29, sp
+;;      	 fc0f1ff8             	str	x28, [sp, #-0x10]!
+;;      	 fc030091             	mov	x28, sp
+;;      	 e90300aa             	mov	x9, x0
+;;      	 ff4300d1             	sub	sp, sp, #0x10
+;;      	 fc030091             	mov	x28, sp
+;;      	 308140f8             	ldur	x16, [x9, #8]
+;;      	 100240f8             	ldur	x16, [x16]
+;;      	 ff6330eb             	cmp	sp, x16
+;;      	 03010054             	b.lo	#0x48
+;;   2c:	 82c300b8             	stur	w2, [x28, #0xc]
+;;      	 890300f8             	stur	x9, [x28]
+;;      	 ff430091             	add	sp, sp, #0x10
+;;      	 fc030091             	mov	x28, sp
+;;      	 fc0741f8             	ldr	x28, [sp], #0x10
+;;      	 fd7bc1a8             	ldp	x29, x30, [sp], #0x10
+;;      	 c0035fd6             	ret	
+;;   48:	 1fc10000             	.byte	0x1f, 0xc1, 0x00, 0x00
+;;
+;;      	 fd7bbfa9             	stp	x29, x30, [sp, #-0x10]!
+;;      	 fd030091             	mov	x29, sp
+;;      	 fc0f1ff8             	str	x28, [sp, #-0x10]!
+;;      	 fc030091             	mov	x28, sp
+;;      	 e90300aa             	mov	x9, x0
+;;      	 ff4300d1             	sub	sp, sp, #0x10
+;;      	 fc030091             	mov	x28, sp
+;;      	 308140f8             	ldur	x16, [x9, #8]
+;;      	 100240f8             	ldur	x16, [x16]
+;;      	 ff6330eb             	cmp	sp, x16
+;;      	 83090054             	b.lo	#0x158
+;;   2c:	 100080d2             	mov	x16, #0
+;;      	 908300f8             	stur	x16, [x28, #8]
+;;      	 890300f8             	stur	x9, [x28]
+;;      	 90c340b8             	ldur	w16, [x28, #0xc]
+;;      	 ff2300d1             	sub	sp, sp, #8
+;;      	 fc030091             	mov	x28, sp
+;;      	 900300b8             	stur	w16, [x28]
+;;      	 100080d2             	mov	x16, #0
+;;      	 e103102a             	mov	w1, w16
+;;      	 e20309aa             	mov	x2, x9
+;;      	 430045b8             	ldur	w3, [x2, #0x50]
+;;      	 3f60236b             	cmp	w1, w3, uxtx
+;;      	 02080054             	b.hs	#0x15c
+;;   60:	 f003012a             	mov	w16, w1
+;;      	 040180d2             	mov	x4, #8
+;;      	 107e049b             	mul	x16, x16, x4
+;;      	 428044f8             	ldur	x2, [x2, #0x48]
+;;      	 e40302aa             	mov	x4, x2
+;;      	 4260308b             	add	x2, x2, x16, uxtx
+;;      	 3f60236b             	cmp	w1, w3, uxtx
+;;      	 8220829a             	csel	x2, x4, x2, hs
+;;      	 400040f8             	ldur	x0, [x2]
+;;      	 1f0000ea             	tst	x0, x0
+;;      	 a1020054             	b.ne	#0xdc
+;;   8c:	 ff2300d1             	sub	sp, sp, #8
+;;      	 fc030091             	mov	x28, sp
+;;      	 890300f8             	stur	x9, [x28]
+;;      	 ff2300d1             	sub	sp, sp, #8
+;;      	 fc030091             	mov	x28, sp
+;;      	 810300b8             	stur	w1, [x28]
+;;      	 308143f8             	ldur	x16, [x9, #0x38]
+;;      	 038244f8             	ldur	x3, [x16, #0x48]
+;;      	 ff2300d1             	sub	sp, sp, #8
+;;      	 fc030091             	mov	x28, sp
+;;      	 800341f8             	ldur	x0, [x28, #0x10]
+;;      	 100080d2             	mov	x16, #0
+;;      	 e103102a             	mov	w1, w16
+;;      	 828340b8             	ldur	w2, [x28, #8]
+;;      	 60003fd6             	blr	x3
+;;   c8:	 ff230091             	add	sp, sp, #8
+;;      	 fc030091             	mov	x28, sp
+;;      	 ff430091             	add	sp, sp, #0x10
+;;      	 fc030091             	mov	x28, sp
+;;      	 02000014             	b	#0xe0
+;;   dc:	 00f87f92             	and	x0, x0, #0xfffffffffffffffe
+;;      	 000400b4             	cbz	x0, #0x160
+;;   e4:	 300144f8             	ldur	x16, [x9, #0x40]
+;;      	 010240b8             	ldur	w1, [x16]
+;;      	 028041b8             	ldur	w2, [x0, #0x18]
+;;      	 3f60226b             	cmp	w1, w2, uxtx
+;;      	 81030054             	b.ne	#0x164
+;;   f8:	 ff2300d1             	sub	sp, sp, #8
+;;      	 fc030091             	mov	x28, sp
+;;      	 800300f8             	stur	x0, [x28]
+;;      	 830340f8             	ldur	x3, [x28]
+;;      	 ff230091             	add	sp, sp, #8
+;;      	 fc030091             	mov	x28, sp
+;;      	 650042f8             	ldur	x5, [x3, #0x20]
+;;      	 640041f8             	ldur	x4, [x3, #0x10]
+;;      	 ff2300d1             	sub	sp, sp, #8
+;;      	 fc030091             	mov	x28, sp
+;;      	 e00305aa             	mov	x0, x5
+;;      	 e10309aa             	mov	x1, x9
+;;      	 828340b8             	ldur	w2, [x28, #8]
+;;      	 80003fd6             	blr	x4
+;;  130:	 890341f8             	ldur	x9, [x28, #0x10]
+;;      	 ff230091             	add	sp, sp, #8
+;;      	 fc030091             	mov	x28, sp
+;;      	 ff230091             	add	sp, sp, #8
+;;      	 fc030091             	mov	x28, sp
+;;      	 ff430091             	add	sp, sp, #0x10
+;;      	 fc030091             	mov	x28, sp
+;;      	 fc0741f8             	ldr	x28, [sp], #0x10
+;;      	 fd7bc1a8             	ldp	x29, x30, [sp], #0x10
+;;      	 c0035fd6             	ret	
+;;  158:	 1fc10000             	.byte	0x1f, 0xc1, 0x00, 0x00
+;;  15c:	 1fc10000             	.byte	0x1f, 0xc1, 0x00, 0x00
+;;  160:	 1fc10000             	.byte	0x1f, 0xc1, 0x00, 0x00
+;;  164:	 1fc10000             	.byte	0x1f, 0xc1, 0x00, 0x00
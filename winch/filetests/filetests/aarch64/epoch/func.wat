@@ -0,0 +1,44 @@
+;;! target = "aarch64"
+;;! [tunables]
+;;! epoch_interruption = true
+
+(module
+  (func (result i32)
+    (i32.const 42)
+  )
+)
+;;      	 fd7bbfa9             	stp	x29, x30, [sp, #-0x10]!
+;;      	 fd030091             	mov	x29, sp
+;;      	 fc0f1ff8             	str	x28, [sp, #-0x10]!
+;;      	 fc030091             	mov	x28, sp
+;;      	 e90300aa             	mov	x9, x0
+;;      	 ff2300d1             	sub	sp, sp, #8
+;;      	 fc030091             	mov	x28, sp
+;;      	 308140f8             	ldur	x16, [x9, #8]
+;;      	 100240f8             	ldur	x16, [x16]
+;;      	 ff6330eb             	cmp	sp, x16
+;;      	 03030054             	b.lo	#0x88
+;;   2c:	 890300f8             	stur	x9, [x28]
+;;      	 208141f8             	ldur	x0, [x9, #0x18]
+;;      	 000040f8             	ldur	x0, [x0]
+;;      	 218140f8             	ldur	x1, [x9, #8]
+;;      	 210041f8             	ldur	x1, [x1, #0x10]
+;;      	 1f6021eb             	cmp	x0, x1, uxtx
+;;      	 43010054             	b.lo	#0x6c
+;;   48:	 308143f8             	ldur	x16, [x9, #0x38]
+;;      	 01024bf8             	ldur	x1, [x16, #0xb0]
+;;      	 ff2300d1             	sub	sp, sp, #8
+;;      	 fc030091             	mov	x28, sp
+;;      	 890300f8             	stur	x9, [x28]
+;;      	 800340f8             	ldur	x0, [x28]
+;;      	 20003fd6             	blr	x1
+;;   64:	 ff230091             	add	sp, sp, #8
+;;      	 fc030091             	mov	x28, sp
+;;      	 500580d2             	mov	x16, #0x2a
+;;      	 e003102a             	mov	w0, w16
+;;      	 ff230091             	add	sp, sp, #8
+;;      	 fc030091             	mov	x28, sp
+;;      	 fc0741f8             	ldr	x28, [sp], #0x10
+;;      	 fd7bc1a8             	ldp	x29, x30, [sp], #0x10
+;;      	 c0035fd6             	ret	
+;;   88:	 1fc10000             	.byte	0x1f, 0xc1, 0x00, 0x00
@@ -0,0 +1,50 @@
+;;! target = "aarch64"
+
+(module
+    (func (result f32)
+        (local $foo f32)  
+        (local $bar f32)
+
+        (f32.const 1.1)
+        (local.set $foo)
+
+        (f32.const 2.2)
+        (local.set $bar)
+
+        (local.get $foo)
+        (local.get $bar)
+        f32.add
+    )
+)
+;;      	 fd7bbfa9             	stp	x29, x30, [sp, #-0x10]!
+;;      	 fd030091             	mov	x29, sp
+;;      	 fc0f1ff8             	str	x28, [sp, #-0x10]!
+;;      	 fc030091             	mov	x28, sp
+;;      	 e90300aa             	mov	x9, x0
+;;      	 ff4300d1             	sub	sp, sp, #0x10
+;;      	 fc030091             	mov	x28, sp
+;;      	 308140f8             	ldur	x16, [x9, #8]
+;;      	 100240f8             	ldur	x16, [x16]
+;;      	 ff6330eb             	cmp	sp, x16
+;;      	 a3020054             	b.lo	#0x7c
+;;   2c:	 100080d2             	mov	x16, #0
+;;      	 908300f8             	stur	x16, [x28, #8]
+;;      	 890300f8             	stur	x9, [x28]
+;;      	 b0999952             	mov	w16, #0xcccd
+;;      	 90f1a772             	movk	w16, #0x3f8c, lsl #16
+;;      	 0002271e             	fmov	s0, w16
+;;      	 80c300bc             	stur	s0, [x28, #0xc]
+;;      	 b0999952             	mov	w16, #0xcccd
+;;      	 9001a872             	movk	w16, #0x400c, lsl #16
+;;      	 0002271e             	fmov	s0, w16
+;;      	 808300bc             	stur	s0, [x28, #8]
+;;      	 808340bc             	ldur	s0, [x28, #8]
+;;      	 81c340bc             	ldur	s1, [x28, #0xc]
+;;      	 2128201e             	fadd	s1, s1, s0
+;;      	 2040601e             	fmov	d0, d1
+;;      	 ff430091             	add	sp, sp, #0x10
+;;      	 fc030091             	mov	x28, sp
+;;      	 fc0741f8             	ldr	x28, [sp], #0x10
+;;      	 fd7bc1a8             	ldp	x29, x30, [sp], #0x10
+;;      	 c0035fd6             	ret	
+;;   7c:	 1fc10000             	.byte	0x1f, 0xc1, 0x00, 0x00
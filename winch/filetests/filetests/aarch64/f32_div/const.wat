@@ -0,0 +1,35 @@
+;;! target = "aarch64"
+
+(module
+    (func (result f32)
+        (f32.const 1.1)
+        (f32.const 2.2)
+        (f32.div)
+    )
+)
+;;      	 fd7bbfa9             	stp	x29, x30, [sp, #-0x10]!
+;;      	 fd030091             	mov	x29, sp
+;;      	 fc0f1ff8             	str	x28, [sp, #-0x10]!
+;;      	 fc030091             	mov	x28, sp
+;;      	 e90300aa             	mov	x9, x0
+;;      	 ff2300d1             	sub	sp, sp, #8
+;;      	 fc030091             	mov	x28, sp
+;;      	 308140f8             	ldur	x16, [x9, #8]
+;;      	 100240f8             	ldur	x16, [x16]
+;;      	 ff6330eb             	cmp	sp, x16
+;;      	 e3010054             	b.lo	#0x64
+;;   2c:	 890300f8             	stur	x9, [x28]
+;;      	 b0999952             	mov	w16, #0xcccd
+;;      	 9001a872             	movk	w16, #0x400c, lsl #16
+;;      	 0002271e             	fmov	s0, w16
+;;      	 b0999952             	mov	w16, #0xcccd
+;;      	 90f1a772             	movk	w16, #0x3f8c, lsl #16
+;;      	 0102271e             	fmov	s1, w16
+;;      	 2118201e             	fdiv	s1, s1, s0
+;;      	 2040601e             	fmov	d0, d1
+;;      	 ff230091             	add	sp, sp, #8
+;;      	 fc030091             	mov	x28, sp
+;;      	 fc0741f8             	ldr	x28, [sp], #0x10
+;;      	 fd7bc1a8             	ldp	x29, x30, [sp], #0x10
+;;      	 c0035fd6             	ret	
+;;   64:	 1fc10000             	.byte	0x1f, 0xc1, 0x00, 0x00
@@ -0,0 +1,32 @@
+;;! target = "aarch64"
+
+(module
+    (func (result f64)
+        (f64.const -1.32)
+        (f64.abs)
+    )
+)
+;;      	 fd7bbfa9             	stp	x29, x30, [sp, #-0x10]!
+;;      	 fd030091             	mov	x29, sp
+;;      	 fc0f1ff8             	str	x28, [sp, #-0x10]!
+;;      	 fc030091             	mov	x28, sp
+;;      	 e90300aa             	mov	x9, x0
+;;      	 ff2300d1             	sub	sp, sp, #8
+;;      	 fc030091             	mov	x28, sp
+;;      	 308140f8             	ldur	x16, [x9, #8]
+;;      	 100240f8             	ldur	x16, [x16]
+;;      	 ff6330eb             	cmp	sp, x16
+;;      	 a3010054             	b.lo	#0x5c
+;;   2c:	 890300f8             	stur	x9, [x28]
+;;      	 f0a390d2             	mov	x16, #0x851f
+;;      	 703daaf2             	movk	x16, #0x51eb, lsl #16
+;;      	 10d7c3f2             	movk	x16, #0x1eb8, lsl #32
+;;      	 b0fef7f2             	movk	x16, #0xbff5, lsl #48
+;;      	 0002679e             	fmov	d0, x16
+;;      	 00c0601e             	fabs	d0, d0
+;;      	 ff230091             	add	sp, sp, #8
+;;      	 fc030091             	mov	x28, sp
+;;      	 fc0741f8             	ldr	x28, [sp], #0x10
+;;      	 fd7bc1a8             	ldp	x29, x30, [sp], #0x10
+;;      	 c0035fd6             	ret	
+;;   5c:	 1fc10000             	.byte	0x1f, 0xc1, 0x00, 0x00
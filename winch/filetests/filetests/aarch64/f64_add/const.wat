@@ -0,0 +1,39 @@
+;;! target = "aarch64"
+
+(module
+    (func (result f64)
+        (f64.const 1.1)
+        (f64.const 2.2)
+        (f64.add)
+    )
+)
+;;      	 fd7bbfa9             	stp	x29, x30, [sp, #-0x10]!
+;;      	 fd030091             	mov	x29, sp
+;;      	 fc0f1ff8             	str	x28, [sp, #-0x10]!
+;;      	 fc030091             	mov	x28, sp
+;;      	 e90300aa             	mov	x9, x0
+;;      	 ff2300d1             	sub	sp, sp, #8
+;;      	 fc030091             	mov	x28, sp
+;;      	 308140f8             	ldur	x16, [x9, #8]
+;;      	 100240f8             	ldur	x16, [x16]
+;;      	 ff6330eb             	cmp	sp, x16
+;;      	 63020054             	b.lo	#0x74
+;;   2c:	 890300f8             	stur	x9, [x28]
+;;      	 503393d2             	mov	x16, #0x999a
+;;      	 3033b3f2             	movk	x16, #0x9999, lsl #16
+;;      	 3033d3f2             	movk	x16, #0x9999, lsl #32
+;;      	 3000e8f2             	movk	x16, #0x4001, lsl #48
+;;      	 0002679e             	fmov	d0, x16
+;;      	 503393d2             	mov	x16, #0x999a
+;;      	 3033b3f2             	movk	x16, #0x9999, lsl #16
+;;      	 3033d3f2             	movk	x16, #0x9999, lsl #32
+;;      	 30fee7f2             	movk	x16, #0x3ff1, lsl #48
+;;      	 0102679e             	fmov	d1, x16
+;;      	 2128601e             	fadd	d1, d1, d0
+;;      	 2040601e             	fmov	d0, d1
+;;      	 ff230091             	add	sp, sp, #8
+;;      	 fc030091             	mov	x28, sp
+;;      	 fc0741f8             	ldr	x28, [sp], #0x10
+;;      	 fd7bc1a8             	ldp	x29, x30, [sp], #0x10
+;;      	 c0035fd6             	ret	
+;;   74:	 1fc10000             	.byte	0x1f, 0xc1, 0x00, 0x00
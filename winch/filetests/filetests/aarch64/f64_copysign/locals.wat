@@ -0,0 +1,56 @@
+;;! target = "aarch64"
+
+(module
+    (func (result f64)
+        (local $foo f64)  
+        (local $bar f64)
+
+        (f64.const -1.1)
+        (local.set $foo)
+
+        (f64.const 2.2)
+        (local.set $bar)
+
+        (local.get $foo)
+        (local.get $bar)
+        f64.copysign
+    )
+)
+;;      	 fd7bbfa9             	stp	x29, x30, [sp, #-0x10]!
+;;      	 fd030091             	mov	x29, sp
+;;      	 fc0f1ff8             	str	x28, [sp, #-0x10]!
+;;      	 fc030091             	mov	x28, sp
+;;      	 e90300aa             	mov	x9, x0
+;;      	 ff6300d1             	sub	sp, sp, #0x18
+;;      	 fc030091             	mov	x28, sp
+;;      	 308140f8             	ldur	x16, [x9, #8]
+;;      	 100240f8             	ldur	x16, [x16]
+;;      	 ff6330eb             	cmp	sp, x16
+;;      	 63030054             	b.lo	#0x94
+;;   2c:	 100080d2             	mov	x16, #0
+;;      	 900301f8             	stur	x16, [x28, #0x10]
+;;      	 908300f8             	stur	x16, [x28, #8]
+;;      	 890300f8             	stur	x9, [x28]
+;;      	 503393d2             	mov	x16, #0x999a
+;;      	 3033b3f2             	movk	x16, #0x9999, lsl #16
+;;      	 3033d3f2             	movk	x16, #0x9999, lsl #32
+;;      	 30fef7f2             	movk	x16, #0xbff1, lsl #48
+;;      	 0002679e             	fmov	d0, x16
+;;      	 800301fc             	stur	d0, [x28, #0x10]
+;;      	 503393d2             	mov	x16, #0x999a
+;;      	 3033b3f2             	movk	x16, #0x9999, lsl #16
+;;      	 3033d3f2             	movk	x16, #0x9999, lsl #32
+;;      	 3000e8f2             	movk	x16, #0x4001, lsl #48
+;;      	 0002679e             	fmov	d0, x16
+;;      	 808300fc             	stur	d0, [x28, #8]
+;;      	 808340fc             	ldur	d0, [x28, #8]
+;;      	 810341fc             	ldur	d1, [x28, #0x10]
+;;      	 1f04417f             	ushr	d31, d0, #0x3f
+;;      	 e1577f7f             	sli	d1, d31, #0x3f
+;;      	 2040601e             	fmov	d0, d1
+;;      	 ff630091             	add	sp, sp, #0x18
+;;      	 fc030091             	mov	x28, sp
+;;      	 fc0741f8             	ldr	x28, [sp], #0x10
+;;      	 fd7bc1a8             	ldp	x29, x30, [sp], #0x10
+;;      	 c0035fd6             	ret	
+;;   94:	 1fc10000             	.byte	0x1f, 0xc1, 0x00, 0x00
@@ -0,0 +1,59 @@
+;;! target = "aarch64"
+;;! [tunables]
+;;! consume_fuel = true
+
+(module
+  (import "" "" (func $foo))
+  (func (export "")
+    (call $foo)
+  )
+)
+;;      	 fd7bbfa9             	stp	x29, x30, [sp, #-0x10]!
+;;      	 fd030091             	mov	x29, sp
+;;      	 fc0f1ff8             	str	x28, [sp, #-0x10]!
+;;      	 fc030091             	mov	x28, sp
+;;      	 e90300aa             	mov	x9, x0
+;;      	 ff2300d1             	sub	sp, sp, #8
+;;      	 fc030091             	mov	x28, sp
+;;      	 308140f8             	ldur	x16, [x9, #8]
+;;      	 100240f8             	ldur	x16, [x16]
+;;      	 ff6330eb             	cmp	sp, x16
+;;      	 c3040054             	b.lo	#0xc0
+;;   2c:	 890300f8             	stur	x9, [x28]
+;;      	 208140f8             	ldur	x0, [x9, #8]
+;;      	 018040f8             	ldur	x1, [x0, #8]
+;;      	 21040091             	add	x1, x1, #1
+;;      	 018000f8             	stur	x1, [x0, #8]
+;;      	 208140f8             	ldur	x0, [x9, #8]
+;;      	 008040f8             	ldur	x0, [x0, #8]
+;;      	 1f0000f1             	cmp	x0, #0
+;;      	 4b010054             	b.lt	#0x74
+;;   50:	 308143f8             	ldur	x16, [x9, #0x38]
+;;      	 01824af8             	ldur	x1, [x16, #0xa8]
+;;      	 ff2300d1             	sub	sp, sp, #8
+;;      	 fc030091             	mov	x28, sp
+;;      	 890300f8             	stur	x9, [x28]
+;;      	 800340f8             	ldur	x0, [x28]
+;;      	 20003fd6             	blr	x1
+;;   6c:	 ff230091             	add	sp, sp, #8
+;;      	 fc030091             	mov	x28, sp
+;;      	 208140f8             	ldur	x0, [x9, #8]
+;;      	 018040f8             	ldur	x1, [x0, #8]
+;;      	 21040091             	add	x1, x1, #1
+;;      	 018000f8             	stur	x1, [x0, #8]
+;;      	 230146f8             	ldur	x3, [x9, #0x60]
+;;      	 228144f8             	ldur	x2, [x9, #0x48]
+;;      	 ff2300d1             	sub	sp, sp, #8
+;;      	 fc030091             	mov	x28, sp
+;;      	 e00303aa             	mov	x0, x3
+;;      	 e10309aa             	mov	x1, x9
+;;      	 40003fd6             	blr	x2
+;;   a0:	 898340f8             	ldur	x9, [x28, #8]
+;;      	 ff230091             	add	sp, sp, #8
+;;      	 fc030091             	mov	x28, sp
+;;      	 ff230091             	add	sp, sp, #8
+;;      	 fc030091             	mov	x28, sp
+;;      	 fc0741f8             	ldr	x28, [sp], #0x10
+;;      	 fd7bc1a8             	ldp	x29, x30, [sp], #0x10
+;;      	 c0035fd6             	ret	
+;;   c0:	 1fc10000             	.byte	0x1f, 0xc1, 0x00, 0x00
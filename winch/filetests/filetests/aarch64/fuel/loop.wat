@@ -0,0 +1,92 @@
+;;! target = "aarch64"
+;;! [tunables]
+;;! consume_fuel = true
+
+(module
+  (func (param i32) (result i32)
+    (local i32)
+    (loop $l
+      (local.set 1 (i32.add (local.get 1) (i32.const 1)))
+      (br_if $l (i32.lt_u (local.get 1) (local.get 0)))
+    )
+    (local.get 1)
+  )
+)
+;;      	 fd7bbfa9             	stp	x29, x30, [sp, #-0x10]!
+;;      	 fd030091             	mov	x29, sp
+;;      	 fc0f1ff8             	str	x28, [sp, #-0x10]!
+;;      	 fc030091             	mov	x28, sp
+;;      	 e90300aa             	mov	x9, x0
+;;      	 ff4300d1             	sub	sp, sp, #0x10
+;;      	 fc030091             	mov	x28, sp
+;;      	 308140f8             	ldur	x16, [x9, #8]
+;;      	 100240f8             	ldur	x16, [x16]
+;;      	 ff6330eb             	cmp	sp, x16
+;;      	 63080054             	b.lo	#0x134
+;;   2c:	 82c300b8             	stur	w2, [x28, #0xc]
+;;      	 100080d2             	mov	x16, #0
+;;      	 908300b8             	stur	w16, [x28, #8]
+;;      	 100080d2             	mov	x16, #0
+;;      	 890300f8             	stur	x9, [x28]
+;;      	 208140f8             	ldur	x0, [x9, #8]
+;;      	 018040f8             	ldur	x1, [x0, #8]
+;;      	 21040091             	add	x1, x1, #1
+;;      	 018000f8             	stur	x1, [x0, #8]
+;;      	 208140f8             	ldur	x0, [x9, #8]
+;;      	 008040f8             	ldur	x0, [x0, #8]
+;;      	 1f0000f1             	cmp	x0, #0
+;;      	 cb010054             	b.lt	#0x94
+;;   60:	 308143f8             	ldur	x16, [x9, #0x38]
+;;      	 01824af8             	ldur	x1, [x16, #0xa8]
+;;      	 ff2300d1             	sub	sp, sp, #8
+;;      	 fc030091             	mov	x28, sp
+;;      	 890300f8             	stur	x9, [x28]
+;;      	 ff2300d1             	sub	sp, sp, #8
+;;      	 fc030091             	mov	x28, sp
+;;      	 808340f8             	ldur	x0, [x28, #8]
+;;      	 20003fd6             	blr	x1
+;;   84:	 ff230091             	add	sp, sp, #8
+;;      	 fc030091             	mov	x28, sp
+;;      	 ff230091             	add	sp, sp, #8
+;;      	 fc030091             	mov	x28, sp
+;;      	 208140f8             	ldur	x0, [x9, #8]
+;;      	 008040f8             	ldur	x0, [x0, #8]
+;;      	 1f0000f1             	cmp	x0, #0
+;;      	 cb010054             	b.lt	#0xd8
+;;   a4:	 308143f8             	ldur	x16, [x9, #0x38]
+;;      	 01824af8             	ldur	x1, [x16, #0xa8]
+;;      	 ff2300d1             	sub	sp, sp, #8
+;;      	 fc030091             	mov	x28, sp
+;;      	 890300f8             	stur	x9, [x28]
+;;      	 ff2300d1             	sub	sp, sp, #8
+;;      	 fc030091             	mov	x28, sp
+;;      	 808340f8             	ldur	x0, [x28, #8]
+;;      	 20003fd6             	blr	x1
+;;   c8:	 ff230091             	add	sp, sp, #8
+;;      	 fc030091             	mov	x28, sp
+;;      	 ff230091             	add	sp, sp, #8
+;;      	 fc030091             	mov	x28, sp
+;;      	 808340b8             	ldur	w0, [x28, #8]
+;;      	 00040011             	add	w0, w0, #1
+;;      	 808300b8             	stur	w0, [x28, #8]
+;;      	 80c340b8             	ldur	w0, [x28, #0xc]
+;;      	 818340b8             	ldur	w1, [x28, #8]
+;;      	 3f60206b             	cmp	w1, w0, uxtx
+;;      	 e1279f9a             	cset	x1, lo
+;;      	 208140f8             	ldur	x0, [x9, #8]
+;;      	 028040f8             	ldur	x2, [x0, #8]
+;;      	 42200091             	add	x2, x2, #8
+;;      	 028000f8             	stur	x2, [x0, #8]
+;;      	 3f00016a             	tst	w1, w1
+;;      	 61fcff54             	b.ne	#0x94
+;;  10c:	 208140f8             	ldur	x0, [x9, #8]
+;;      	 018040f8             	ldur	x1, [x0, #8]
+;;      	 21040091             	add	x1, x1, #1
+;;      	 018000f8             	stur	x1, [x0, #8]
+;;      	 808340b8             	ldur	w0, [x28, #8]
+;;      	 ff430091             	add	sp, sp, #0x10
+;;      	 fc030091             	mov	x28, sp
+;;      	 fc0741f8             	ldr	x28, [sp], #0x10
+;;      	 fd7bc1a8             	ldp	x29, x30, [sp], #0x10
+;;      	 c0035fd6             	ret	
+;;  134:	 1fc10000             	.byte	0x1f, 0xc1, 0x00, 0x00
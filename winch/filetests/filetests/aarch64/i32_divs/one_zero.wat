@@ -0,0 +1,41 @@
+;;! target = "aarch64"
+
+(module
+    (func (result i32)
+	(i32.const 1)
+	(i32.const 0)
+	(i32.div_s)
+    )
+)
+;;      	 fd7bbfa9             	stp	x29, x30, [sp, #-0x10]!
+;;      	 fd030091             	mov	x29, sp
+;;      	 fc0f1ff8             	str	x28, [sp, #-0x10]!
+;;      	 fc030091             	mov	x28, sp
+;;      	 e90300aa             	mov	x9, x0
+;;      	 ff2300d1             	sub	sp, sp, #8
+;;      	 fc030091             	mov	x28, sp
+;;      	 308140f8             	ldur	x16, [x9, #8]
+;;      	 100240f8             	ldur	x16, [x16]
+;;      	 ff6330eb             	cmp	sp, x16
+;;      	 63020054             	b.lo	#0x74
+;;   2c:	 890300f8             	stur	x9, [x28]
+;;      	 100080d2             	mov	x16, #0
+;;      	 e003102a             	mov	w0, w16
+;;      	 300080d2             	mov	x16, #1
+;;      	 e103102a             	mov	w1, w16
+;;      	 007c4093             	sxtw	x0, w0
+;;      	 217c4093             	sxtw	x1, w1
+;;      	 800100b4             	cbz	x0, #0x78
+;;   4c:	 1f040031             	cmn	w0, #1
+;;      	 2008417a             	ccmp	w1, #1, #0, eq
+;;      	 46010054             	b.vs	#0x7c
+;;   58:	 210cc09a             	sdiv	x1, x1, x0
+;;      	 e003012a             	mov	w0, w1
+;;      	 ff230091             	add	sp, sp, #8
+;;      	 fc030091             	mov	x28, sp
+;;      	 fc0741f8             	ldr	x28, [sp], #0x10
+;;      	 fd7bc1a8             	ldp	x29, x30, [sp], #0x10
+;;      	 c0035fd6             	ret	
+;;   74:	 1fc10000             	.byte	0x1f, 0xc1, 0x00, 0x00
+;;   78:	 1fc10000             	.byte	0x1f, 0xc1, 0x00, 0x00
+;;   7c:	 1fc10000             	.byte	0x1f, 0xc1, 0x00, 0x00
@@ -0,0 +1,38 @@
+;;! target = "aarch64"
+
+(module
+    (func (result i32)
+	(i32.const 7)
+	(i32.const 5)
+	(i32.rem_u)
+    )
+)
+;;      	 fd7bbfa9             	stp	x29, x30, [sp, #-0x10]!
+;;      	 fd030091             	mov	x29, sp
+;;      	 fc0f1ff8             	str	x28, [sp, #-0x10]!
+;;      	 fc030091             	mov	x28, sp
+;;      	 e90300aa             	mov	x9, x0
+;;      	 ff2300d1             	sub	sp, sp, #8
+;;      	 fc030091             	mov	x28, sp
+;;      	 308140f8             	ldur	x16, [x9, #8]
+;;      	 100240f8             	ldur	x16, [x16]
+;;      	 ff6330eb             	cmp	sp, x16
+;;      	 23020054             	b.lo	#0x6c
+;;   2c:	 890300f8             	stur	x9, [x28]
+;;      	 b00080d2             	mov	x16, #5
+;;      	 e003102a             	mov	w0, w16
+;;      	 f00080d2             	mov	x16, #7
+;;      	 e103102a             	mov	w1, w16
+;;      	 e003002a             	mov	w0, w0
+;;      	 e103012a             	mov	w1, w1
+;;      	 400100b4             	cbz	x0, #0x70
+;;   4c:	 3008c09a             	udiv	x16, x1, x0
+;;      	 0186009b             	msub	x1, x16, x0, x1
+;;      	 e003012a             	mov	w0, w1
+;;      	 ff230091             	add	sp, sp, #8
+;;      	 fc030091             	mov	x28, sp
+;;      	 fc0741f8             	ldr	x28, [sp], #0x10
+;;      	 fd7bc1a8             	ldp	x29, x30, [sp], #0x10
+;;      	 c0035fd6             	ret	
+;;   6c:	 1fc10000             	.byte	0x1f, 0xc1, 0x00, 0x00
+;;   70:	 1fc10000             	.byte	0x1f, 0xc1, 0x00, 0x00
@@ -0,0 +1,46 @@
+;;! target = "aarch64"
+
+(module
+    (func (result i32)
+        (local f64)  
+
+        (local.get 0)
+        (i32.trunc_f64_s)
+    )
+)
+;;      	 fd7bbfa9             	stp	x29, x30, [sp, #-0x10]!
+;;      	 fd030091             	mov	x29, sp
+;;      	 fc0f1ff8             	str	x28, [sp, #-0x10]!
+;;      	 fc030091             	mov	x28, sp
+;;      	 e90300aa             	mov	x9, x0
+;;      	 ff4300d1             	sub	sp, sp, #0x10
+;;      	 fc030091             	mov	x28, sp
+;;      	 308140f8             	ldur	x16, [x9, #8]
+;;      	 100240f8             	ldur	x16, [x16]
+;;      	 ff6330eb             	cmp	sp, x16
+;;      	 c3020054             	b.lo	#0x80
+;;   2c:	 100080d2             	mov	x16, #0
+;;      	 908300f8             	stur	x16, [x28, #8]
+;;      	 890300f8             	stur	x9, [x28]
+;;      	 808340fc             	ldur	d0, [x28, #8]
+;;      	 0020601e             	fcmp	d0, d0
+;;      	 26020054             	b.vs	#0x84
+;;   44:	 1004a0d2             	mov	x16, #0x200000
+;;      	 103cf8f2             	movk	x16, #0xc1e0, lsl #48
+;;      	 1f02679e             	fmov	d31, x16
+;;      	 00207f1e             	fcmp	d0, d31
+;;      	 a9010054             	b.ls	#0x88
+;;   58:	 103ce8d2             	mov	x16, #0x41e0000000000000
+;;      	 1f02679e             	fmov	d31, x16
+;;      	 00207f1e             	fcmp	d0, d31
+;;      	 4a010054             	b.ge	#0x8c
+;;   68:	 0000781e             	fcvtzs	w0, d0
+;;      	 ff430091             	add	sp, sp, #0x10
+;;      	 fc030091             	mov	x28, sp
+;;      	 fc0741f8             	ldr	x28, [sp], #0x10
+;;      	 fd7bc1a8             	ldp	x29, x30, [sp], #0x10
+;;      	 c0035fd6             	ret	
+;;   80:	 1fc10000             	.byte	0x1f, 0xc1, 0x00, 0x00
+;;   84:	 1fc10000             	.byte	0x1f, 0xc1, 0x00, 0x00
+;;   88:	 1fc10000             	.byte	0x1f, 0xc1, 0x00, 0x00
+;;   8c:	 1fc10000             	.byte	0x1f, 0xc1, 0x00, 0x00
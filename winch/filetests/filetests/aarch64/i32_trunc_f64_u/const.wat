@@ -0,0 +1,42 @@
+;;! target = "aarch64"
+
+(module
+    (func (result i32)
+        (f64.const 1.0)
+        (i32.trunc_f64_u)
+    )
+)
+;;      	 fd7bbfa9             	stp	x29, x30, [sp, #-0x10]!
+;;      	 fd030091             	mov	x29, sp
+;;      	 fc0f1ff8             	str	x28, [sp, #-0x10]!
+;;      	 fc030091             	mov	x28, sp
+;;      	 e90300aa             	mov	x9, x0
+;;      	 ff2300d1             	sub	sp, sp, #8
+;;      	 fc030091             	mov	x28, sp
+;;      	 308140f8             	ldur	x16, [x9, #8]
+;;      	 100240f8             	ldur	x16, [x16]
+;;      	 ff6330eb             	cmp	sp, x16
+;;      	 83020054             	b.lo	#0x78
+;;   2c:	 890300f8             	stur	x9, [x28]
+;;      	 10fee7d2             	mov	x16, #0x3ff0000000000000
+;;      	 0102679e             	fmov	d1, x16
+;;      	 2020611e             	fcmp	d1, d1
+;;      	 06020054             	b.vs	#0x7c
+;;   40:	 10fef7d2             	mov	x16, #-0x4010000000000000
+;;      	 1f02679e             	fmov	d31, x16
+;;      	 20207f1e             	fcmp	d1, d31
+;;      	 a9010054             	b.ls	#0x80
+;;   50:	 103ee8d2             	mov	x16, #0x41f0000000000000
+;;      	 1f02679e             	fmov	d31, x16
+;;      	 20207f1e             	fcmp	d1, d31
+;;      	 4a010054             	b.ge	#0x84
+;;   60:	 2000791e             	fcvtzu	w0, d1
+;;      	 ff230091             	add	sp, sp, #8
+;;      	 fc030091             	mov	x28, sp
+;;      	 fc0741f8             	ldr	x28, [sp], #0x10
+;;      	 fd7bc1a8             	ldp	x29, x30, [sp], #0x10
+;;      	 c0035fd6             	ret	
+;;   78:	 1fc10000             	.byte	0x1f, 0xc1, 0x00, 0x00
+;;   7c:	 1fc10000             	.byte	0x1f, 0xc1, 0x00, 0x00
+;;   80:	 1fc10000             	.byte	0x1f, 0xc1, 0x00, 0x00
+;;   84:	 1fc10000             	.byte	0x1f, 0xc1, 0x00, 0x00
@@ -0,0 +1,39 @@
+;;! target = "aarch64"
+
+(module
+    (func (result i64)
+	(i64.const 1)
+	(i64.const 0)
+	(i64.div_s)
+    )
+)
+;;      	 fd7bbfa9             	stp	x29, x30, [sp, #-0x10]!
+;;      	 fd030091             	mov	x29, sp
+;;      	 fc0f1ff8             	str	x28, [sp, #-0x10]!
+;;      	 fc030091             	mov	x28, sp
+;;      	 e90300aa             	mov	x9, x0
+;;      	 ff2300d1             	sub	sp, sp, #8
+;;      	 fc030091             	mov	x28, sp
+;;      	 308140f8             	ldur	x16, [x9, #8]
+;;      	 100240f8             	ldur	x16, [x16]
+;;      	 ff6330eb             	cmp	sp, x16
+;;      	 23020054             	b.lo	#0x6c
+;;   2c:	 890300f8             	stur	x9, [x28]
+;;      	 100080d2             	mov	x16, #0
+;;      	 e00310aa             	mov	x0, x16
+;;      	 300080d2             	mov	x16, #1
+;;      	 e10310aa             	mov	x1, x16
+;;      	 800100b4             	cbz	x0, #0x70
+;;   44:	 1f0400b1             	cmn	x0, #1
+;;      	 200841fa             	ccmp	x1, #1, #0, eq
+;;      	 46010054             	b.vs	#0x74
+;;   50:	 210cc09a             	sdiv	x1, x1, x0
+;;      	 e00301aa             	mov	x0, x1
+;;      	 ff230091             	add	sp, sp, #8
+;;      	 fc030091             	mov	x28, sp
+;;      	 fc0741f8             	ldr	x28, [sp], #0x10
+;;      	 fd7bc1a8             	ldp	x29, x30, [sp], #0x10
+;;      	 c0035fd6             	ret	
+;;   6c:	 1fc10000             	.byte	0x1f, 0xc1, 0x00, 0x00
+;;   70:	 1fc10000             	.byte	0x1f, 0xc1, 0x00, 0x00
+;;   74:	 1fc10000             	.byte	0x1f, 0xc1, 0x00, 0x00
@@ -0,0 +1,42 @@
+;;! target = "aarch64"
+
+(module
+    (func (result i64)
+        (f32.const 1.0)
+        (i64.trunc_f32_s)
+    )
+)
+;;      	 fd7bbfa9             	stp	x29, x30, [sp, #-0x10]!
+;;      	 fd030091             	mov	x29, sp
+;;      	 fc0f1ff8             	str	x28, [sp, #-0x10]!
+;;      	 fc030091             	mov	x28, sp
+;;      	 e90300aa             	mov	x9, x0
+;;      	 ff2300d1             	sub	sp, sp, #8
+;;      	 fc030091             	mov	x28, sp
+;;      	 308140f8             	ldur	x16, [x9, #8]
+;;      	 100240f8             	ldur	x16, [x16]
+;;      	 ff6330eb             	cmp	sp, x16
+;;      	 83020054             	b.lo	#0x78
+;;   2c:	 890300f8             	stur	x9, [x28]
+;;      	 10f0a7d2             	mov	x16, #0x3f800000
+;;      	 0002271e             	fmov	s0, w16
+;;      	 0020201e             	fcmp	s0, s0
+;;      	 06020054             	b.vs	#0x7c
+;;   40:	 10e0bbd2             	mov	x16, #0xdf000000
+;;      	 1f02271e             	fmov	s31, w16
+;;      	 00203f1e             	fcmp	s0, s31
+;;      	 a4010054             	b.mi	#0x80
+;;   50:	 10e0abd2             	mov	x16, #0x5f000000
+;;      	 1f02271e             	fmov	s31, w16
+;;      	 00203f1e             	fcmp	s0, s31
+;;      	 4a010054             	b.ge	#0x84
+;;   60:	 0000389e             	fcvtzs	x0, s0
+;;      	 ff230091             	add	sp, sp, #8
+;;      	 fc030091             	mov	x28, sp
+;;      	 fc0741f8             	ldr	x28, [sp], #0x10
+;;      	 fd7bc1a8             	ldp	x29, x30, [sp], #0x10
+;;      	 c0035fd6             	ret	
+;;   78:	 1fc10000             	.byte	0x1f, 0xc1, 0x00, 0x00
+;;   7c:	 1fc10000             	.byte	0x1f, 0xc1, 0x00, 0x00
+;;   80:	 1fc10000             	.byte	0x1f, 0xc1, 0x00, 0x00
+;;   84:	 1fc10000             	.byte	0x1f, 0xc1, 0x00, 0x00
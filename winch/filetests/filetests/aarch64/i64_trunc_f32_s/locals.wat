@@ -0,0 +1,45 @@
+;;! target = "aarch64"
+
+(module
+    (func (result i64)
+        (local f32)  
+
+        (local.get 0)
+        (i64.trunc_f32_s)
+    )
+)
+;;      	 fd7bbfa9             	stp	x29, x30, [sp, #-0x10]!
+;;      	 fd030091             	mov	x29, sp
+;;      	 fc0f1ff8             	str	x28, [sp, #-0x10]!
+;;      	 fc030091             	mov	x28, sp
+;;      	 e90300aa             	mov	x9, x0
+;;      	 ff4300d1             	sub	sp, sp, #0x10
+;;      	 fc030091             	mov	x28, sp
+;;      	 308140f8             	ldur	x16, [x9, #8]
+;;      	 100240f8             	ldur	x16, [x16]
+;;      	 ff6330eb             	cmp	sp, x16
+;;      	 a3020054             	b.lo	#0x7c
+;;   2c:	 100080d2             	mov	x16, #0
+;;      	 908300f8             	stur	x16, [x28, #8]
+;;      	 890300f8             	stur	x9, [x28]
+;;      	 80c340bc             	ldur	s0, [x28, #0xc]
+;;      	 0020201e             	fcmp	s0, s0
+;;      	 06020054             	b.vs	#0x80
+;;   44:	 10e0bbd2             	mov	x16, #0xdf000000
+;;      	 1f02271e             	fmov	s31, w16
+;;      	 00203f1e             	fcmp	s0, s31
+;;      	 a4010054             	b.mi	#0x84
+;;   54:	 10e0abd2             	mov	x16, #0x5f000000
+;;      	 1f02271e             	fmov	s31, w16
+;;      	 00203f1e             	fcmp	s0, s31
+;;      	 4a010054             	b.ge	#0x88
+;;   64:	 0000389e             	fcvtzs	x0, s0
+;;      	 ff430091             	add	sp, sp, #0x10
+;;      	 fc030091             	mov	x28, sp
+;;      	 fc0741f8             	ldr	x28, [sp], #0x10
+;;      	 fd7bc1a8             	ldp	x29, x30, [sp], #0x10
+;;      	 c0035fd6             	ret	
+;;   7c:	 1fc10000             	.byte	0x1f, 0xc1, 0x00, 0x00
+;;   80:	 1fc10000             	.byte	0x1f, 0xc1, 0x00, 0x00
+;;   84:	 1fc10000             	.byte	0x1f, 0xc1, 0x00, 0x00
+;;   88:	 1fc10000             	.byte	0x1f, 0xc1, 0x00, 0x00
@@ -0,0 +1,45 @@
+;;! target = "aarch64"
+
+(module
+    (func (result i64)
+        (local f32)  
+
+        (local.get 0)
+        (i64.trunc_f32_u)
+    )
+)
+;;      	 fd7bbfa9             	stp	x29, x30, [sp, #-0x10]!
+;;      	 fd030091             	mov	x29, sp
+;;      	 fc0f1ff8             	str	x28, [sp, #-0x10]!
+;;      	 fc030091             	mov	x28, sp
+;;      	 e90300aa             	mov	x9, x0
+;;      	 ff4300d1             	sub	sp, sp, #0x10
+;;      	 fc030091             	mov	x28, sp
+;;      	 308140f8             	ldur	x16, [x9, #8]
+;;      	 100240f8             	ldur	x16, [x16]
+;;      	 ff6330eb             	cmp	sp, x16
+;;      	 a3020054             	b.lo	#0x7c
+;;   2c:	 100080d2             	mov	x16, #0
+;;      	 908300f8             	stur	x16, [x28, #8]
+;;      	 890300f8             	stur	x9, [x28]
+;;      	 81c340bc             	ldur	s1, [x28, #0xc]
+;;      	 2020211e             	fcmp	s1, s1
+;;      	 06020054             	b.vs	#0x80
+;;   44:	 10f0b7d2             	mov	x16, #0xbf800000
+;;      	 1f02271e             	fmov	s31, w16
+;;      	 20203f1e             	fcmp	s1, s31
+;;      	 a9010054             	b.ls	#0x84
+;;   54:	 10f0abd2             	mov	x16, #0x5f800000
+;;      	 1f02271e             	fmov	s31, w16
+;;      	 20203f1e             	fcmp	s1, s31
+;;      	 4a010054             	b.ge	#0x88
+;;   64:	 2000399e             	fcvtzu	x0, s1
+;;      	 ff430091             	add	sp, sp, #0x10
+;;      	 fc030091             	mov	x28, sp
+;;      	 fc0741f8             	ldr	x28, [sp], #0x10
+;;      	 fd7bc1a8             	ldp	x29, x30, [sp], #0x10
+;;      	 c0035fd6             	ret	
+;;   7c:	 1fc10000             	.byte	0x1f, 0xc1, 0x00, 0x00
+;;   80:	 1fc10000             	.byte	0x1f, 0xc1, 0x00, 0x00
+;;   84:	 1fc10000             	.byte	0x1f, 0xc1, 0x00, 0x00
+;;   88:	 1fc10000             	.byte	0x1f, 0xc1, 0x00, 0x00
@@ -0,0 +1,88 @@
+;;! target = "aarch64"
+
+(module
+  (memory i64 1)
+  (func (param i64) (result i32)
+    (i32.load offset=16 (local.get 0))
+  )
+
+  (func (param i64) (result i64)
+    (i64.load8_u offset=4294967296 (local.get 0))
+  )
+)
+;;      	 fd7bbfa9             	stp	x29, x30, [sp, #-0x10]!
+;;      	 fd030091             	mov	x29, sp
+;;      	 fc0f1ff8             	str	x28, [sp, #-0x10]!
+;;      	 fc030091             	mov	x28, sp
+;;      	 e90300aa             	mov	x9, x0
+;;      	 ff4300d1             	sub	sp, sp, #0x10
+;;      	 fc030091             	mov	x28, sp
+;;      	 308140f8             	ldur	x16, [x9, #8]
+;;      	 100240f8             	ldur	x16, [x16]
+;;      	 ff6330eb             	cmp	sp, x16
+;;      	 e3020054             	b.lo	#0x84
+;;   2c:	 828300f8             	stur	x2, [x28, #8]
+;;      	 890300f8             	stur	x9, [x28]
+;;      	 808340f8             	ldur	x0, [x28, #8]
+;;      	 218145f8             	ldur	x1, [x9, #0x58]
+;;      	 e20300aa             	mov	x2, x0
+;;      	 425000b1             	adds	x2, x2, #0x14
+;;      	 22020054             	b.hs	#0x88
+;;   48:	 5f6021eb             	cmp	x2, x1, uxtx
+;;      	 08020054             	b.hi	#0x8c
+;;   50:	 230145f8             	ldur	x3, [x9, #0x50]
+;;      	 6360208b             	add	x3, x3, x0, uxtx
+;;      	 63400091             	add	x3, x3, #0x10
+;;      	 100080d2             	mov	x16, #0
+;;      	 e40310aa             	mov	x4, x16
+;;      	 5f6021eb             	cmp	x2, x1, uxtx
+;;      	 8380839a             	csel	x3, x4, x3, hi
+;;      	 600040b8             	ldur	w0, [x3]
+;;      	 ff430091             	add	sp, sp, #0x10
+;;      	 fc030091             	mov	x28, sp
+;;      	 fc0741f8             	ldr	x28, [sp], #0x10
+;;      	 fd7bc1a8             	ldp	x29, x30, [sp], #0x10
+;;      	 c0035fd6             	ret	
+;;   84:	 1fc10000             	.byte	0x1f, 0xc1, 0x00, 0x00
+;;   88:	 1fc10000             	.byte	0x1f, 0xc1, 0x00, 0x00
+;;   8c:	 1fc10000             	.byte	0x1f, 0xc1, 0x00, 0x00
+;;
+;;      	 fd7bbfa9             	stp	x29, x30, [sp, #-0x10]!
+;;      	 fd030091             	mov	x29, sp
+;;      	 fc0f1ff8             	str	x28, [sp, #-0x10]!
+;;      	 fc030091             	mov	x28, sp
+;;      	 e90300aa             	mov	x9, x0
+;;      	 ff4300d1             	sub	sp, sp, #0x10
+;;      	 fc030091             	mov	x28, sp
+;;      	 308140f8             	ldur	x16, [x9, #8]
+;;      	 100240f8             	ldur	x16, [x16]
+;;      	 ff6330eb             	cmp	sp, x16
+;;      	 23030054             	b.lo	#0x8c
+;;   2c:	 828300f8             	stur	x2, [x28, #8]
+;;      	 890300f8             	stur	x9, [x28]
+;;      	 808340f8             	ldur	x0, [x28, #8]
+;;      	 3000c0d2             	mov	x16, #0x100000000
+;;      	 006030ab             	adds	x0, x0, x16, uxtx
+;;      	 82020054             	b.hs	#0x90
+;;   44:	 218145f8             	ldur	x1, [x9, #0x58]
+;;      	 e20300aa             	mov	x2, x0
+;;      	 420400b1             	adds	x2, x2, #1
+;;      	 22020054             	b.hs	#0x94
+;;   54:	 5f6021eb             	cmp	x2, x1, uxtx
+;;      	 08020054             	b.hi	#0x98
+;;   5c:	 230145f8             	ldur	x3, [x9, #0x50]
+;;      	 6360208b             	add	x3, x3, x0, uxtx
+;;      	 100080d2             	mov	x16, #0
+;;      	 e40310aa             	mov	x4, x16
+;;      	 5f6021eb             	cmp	x2, x1, uxtx
+;;      	 8380839a             	csel	x3, x4, x3, hi
+;;      	 60004038             	ldurb	w0, [x3]
+;;      	 ff430091             	add	sp, sp, #0x10
+;;      	 fc030091             	mov	x28, sp
+;;      	 fc0741f8             	ldr	x28, [sp], #0x10
+;;      	 fd7bc1a8             	ldp	x29, x30, [sp], #0x10
+;;      	 c0035fd6             	ret	
+;;   8c:	 1fc10000             	.byte	0x1f, 0xc1, 0x00, 0x00
+;;   90:	 1fc10000             	.byte	0x1f, 0xc1, 0x00, 0x00
+;;   94:	 1fc10000             	.byte	0x1f, 0xc1, 0x00, 0x00
+;;   98:	 1fc10000             	.byte	0x1f, 0xc1, 0x00, 0x00
@@ -0,0 +1,47 @@
+;;! target = "aarch64"
+;;! [tunables]
+;;! static_memory_bound = 0
+
+(module
+  (memory 1)
+  (func (param i32) (result i32)
+    (i32.load16_s offset=4 (local.get 0))
+  )
+)
+;;      	 fd7bbfa9             	stp	x29, x30, [sp, #-0x10]!
+;;      	 fd030091             	mov	x29, sp
+;;      	 fc0f1ff8             	str	x28, [sp, #-0x10]!
+;;      	 fc030091             	mov	x28, sp
+;;      	 e90300aa             	mov	x9, x0
+;;      	 ff4300d1             	sub	sp, sp, #0x10
+;;      	 fc030091             	mov	x28, sp
+;;      	 308140f8             	ldur	x16, [x9, #8]
+;;      	 100240f8             	ldur	x16, [x16]
+;;      	 ff6330eb             	cmp	sp, x16
+;;      	 03030054             	b.lo	#0x88
+;;   2c:	 82c300b8             	stur	w2, [x28, #0xc]
+;;      	 890300f8             	stur	x9, [x28]
+;;      	 80c340b8             	ldur	w0, [x28, #0xc]
+;;      	 e003002a             	mov	w0, w0
+;;      	 218145f8             	ldur	x1, [x9, #0x58]
+;;      	 e20300aa             	mov	x2, x0
+;;      	 421800b1             	adds	x2, x2, #6
+;;      	 22020054             	b.hs	#0x8c
+;;   4c:	 5f6021eb             	cmp	x2, x1, uxtx
+;;      	 08020054             	b.hi	#0x90
+;;   54:	 230145f8             	ldur	x3, [x9, #0x50]
+;;      	 6360208b             	add	x3, x3, x0, uxtx
+;;      	 63100091             	add	x3, x3, #4
+;;      	 100080d2             	mov	x16, #0
+;;      	 e40310aa             	mov	x4, x16
+;;      	 5f6021eb             	cmp	x2, x1, uxtx
+;;      	 8380839a             	csel	x3, x4, x3, hi
+;;      	 60008078             	ldursh	x0, [x3]
+;;      	 ff430091             	add	sp, sp, #0x10
+;;      	 fc030091             	mov	x28, sp
+;;      	 fc0741f8             	ldr	x28, [sp], #0x10
+;;      	 fd7bc1a8             	ldp	x29, x30, [sp], #0x10
+;;      	 c0035fd6             	ret	
+;;   88:	 1fc10000             	.byte	0x1f, 0xc1, 0x00, 0x00
+;;   8c:	 1fc10000             	.byte	0x1f, 0xc1, 0x00, 0x00
+;;   90:	 1fc10000             	.byte	0x1f, 0xc1, 0x00, 0x00
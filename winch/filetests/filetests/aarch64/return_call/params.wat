@@ -0,0 +1,119 @@
+;;! target = "aarch64"
+(module
+  (func $callee (param i64 i64 i64 i64 i64 i64 i64 i64 i64 i64) (result i64)
+    (i64.add (local.get 0) (local.get 9)))
+  (func (param i64) (result i64)
+    (return_call $callee
+      (local.get 0)
+      (i64.const 1)
+      (i64.const 2)
+      (i64.const 3)
+      (i64.const 4)
+      (i64.const 5)
+      (i64.const 6)
+      (i64.const 7)
+      (i64.const 8)
+      (i64.const 9)))
+)
+;;      	 fd7bbfa9             	stp	x29, x30, [sp, #-0x10]!
+;;      	 fd030091             	mov	x29, sp
+;;      	 fc0f1ff8             	str	x28, [sp, #-0x10]!
+;;      	 fc030091             	mov	x28, sp
+;;      	 e90300aa             	mov	x9, x0
+;;      	 ffe300d1             	sub	sp, sp, #0x38
+;;      	 fc030091             	mov	x28, sp
+;;      	 308140f8             	ldur	x16, [x9, #8]
+;;      	 100240f8             	ldur	x16, [x16]
+;;      	 ff6330eb             	cmp	sp, x16
+;;      	 63020054             	b.lo	#0x74
+;;   2c:	 820303f8             	stur	x2, [x28, #0x30]
+;;      	 838302f8             	stur	x3, [x28, #0x28]
+;;      	 840302f8             	stur	x4, [x28, #0x20]
+;;      	 858301f8             	stur	x5, [x28, #0x18]
+;;      	 860301f8             	stur	x6, [x28, #0x10]
+;;      	 878300f8             	stur	x7, [x28, #8]
+;;      	 890300f8             	stur	x9, [x28]
+;;      	 a08342f8             	ldur	x0, [x29, #0x28]
+;;      	 810343f8             	ldur	x1, [x28, #0x30]
+;;      	 2160208b             	add	x1, x1, x0, uxtx
+;;      	 e00301aa             	mov	x0, x1
+;;      	 ffe30091             	add	sp, sp, #0x38
+;;      	 fc030091             	mov	x28, sp
+;;      	 fc0741f8             	ldr	x28, [sp], #0x10
+;;      	 fd7bc1a8             	ldp	x29, x30, [sp], #0x10
+;;      	 ff830091             	add	sp, sp, #0x20
+;;      	 9c830091             	add	x28, x28, #0x20
+;;      	 c0035fd6             	ret	
+;;   74:	 1fc10000             	.byte	0x1f, 0xc1, 0x00, 0x00
+;;
+;;      	 fd7bbfa9             	stp	x29, x30, [sp, #-0x10]!
+;;      	 fd030091             	mov	x29, sp
+;;      	 fc0f1ff8             	str	x28, [sp, #-0x10]!
+;;      	 fc030091             	mov	x28, sp
+;;      	 e90300aa             	mov	x9, x0
+;;      	 ff4300d1             	sub	sp, sp, #0x10
+;;      	 fc030091             	mov	x28, sp
+;;      	 308140f8             	ldur	x16, [x9, #8]
+;;      	 100240f8             	ldur	x16, [x16]
+;;      	 ff6330eb             	cmp	sp, x16
+;;      	 83070054             	b.lo	#0x118
+;;   2c:	 828300f8             	stur	x2, [x28, #8]
+;;      	 890300f8             	stur	x9, [x28]
+;;      	 908340f8             	ldur	x16, [x28, #8]
+;;      	 ff2300d1             	sub	sp, sp, #8
+;;      	 fc030091             	mov	x28, sp
+;;      	 900300f8             	stur	x16, [x28]
+;;      	 ff8300d1             	sub	sp, sp, #0x20
+;;      	 fc030091             	mov	x28, sp
+;;      	 e00309aa             	mov	x0, x9
+;;      	 e10309aa             	mov	x1, x9
+;;      	 820342f8             	ldur	x2, [x28, #0x20]
+;;      	 300080d2             	mov	x16, #1
+;;      	 e30310aa             	mov	x3, x16
+;;      	 500080d2             	mov	x16, #2
+;;      	 e40310aa             	mov	x4, x16
+;;      	 700080d2             	mov	x16, #3
+;;      	 e50310aa             	mov	x5, x16
+;;      	 900080d2             	mov	x16, #4
+;;      	 e60310aa             	mov	x6, x16
+;;      	 b00080d2             	mov	x16, #5
+;;      	 e70310aa             	mov	x7, x16
+;;      	 d00080d2             	mov	x16, #6
+;;      	 f00310aa             	mov	x16, x16
+;;      	 900300f8             	stur	x16, [x28]
+;;      	 f00080d2             	mov	x16, #7
+;;      	 f00310aa             	mov	x16, x16
+;;      	 908300f8             	stur	x16, [x28, #8]
+;;      	 100180d2             	mov	x16, #8
+;;      	 f00310aa             	mov	x16, x16
+;;      	 900301f8             	stur	x16, [x28, #0x10]
+;;      	 300180d2             	mov	x16, #9
+;;      	 f00310aa             	mov	x16, x16
+;;      	 908301f8             	stur	x16, [x28, #0x18]
+;;      	 ff4300d1             	sub	sp, sp, #0x10
+;;      	 fc030091             	mov	x28, sp
+;;      	 b10340f8             	ldur	x17, [x29]
+;;      	 910300f8             	stur	x17, [x28]
+;;      	 b1035ff8             	ldur	x17, [x29, #-0x10]
+;;      	 918300f8             	stur	x17, [x28, #8]
+;;      	 be8340f8             	ldur	x30, [x29, #8]
+;;      	 918342f8             	ldur	x17, [x28, #0x28]
+;;      	 b18300f8             	stur	x17, [x29, #8]
+;;      	 910342f8             	ldur	x17, [x28, #0x20]
+;;      	 b10300f8             	stur	x17, [x29]
+;;      	 918341f8             	ldur	x17, [x28, #0x18]
+;;      	 b1831ff8             	stur	x17, [x29, #-8]
+;;      	 910341f8             	ldur	x17, [x28, #0x10]
+;;      	 b1031ff8             	stur	x17, [x29, #-0x10]
+;;      	 b14300d1             	sub	x17, x29, #0x10
+;;      	 9d0340f8             	ldur	x29, [x28]
+;;      	 9c8340f8             	ldur	x28, [x28, #8]
+;;      	 9c8300d1             	sub	x28, x28, #0x20
+;;      	 3f020091             	mov	sp, x17
+;;      	 00000014             	b	#0x100
+;;  104:	 ff430091             	add	sp, sp, #0x10
+;;      	 fc030091             	mov	x28, sp
+;;      	 fc0741f8             	ldr	x28, [sp], #0x10
+;;      	 fd7bc1a8             	ldp	x29, x30, [sp], #0x10
+;;      	 c0035fd6             	ret	
+;;  118:	 1fc10000             	.byte	0x1f, 0xc1, 0x00, 0x00
@@ -0,0 +1,46 @@
+;;! target = "aarch64"
+
+(module
+  (memory i64 1)
+  (func (param i64 f64)
+    (f64.store offset=8 (local.get 0) (local.get 1))
+  )
+)
+;;      	 fd7bbfa9             	stp	x29, x30, [sp, #-0x10]!
+;;      	 fd030091             	mov	x29, sp
+;;      	 fc0f1ff8             	str	x28, [sp, #-0x10]!
+;;      	 fc030091             	mov	x28, sp
+;;      	 e90300aa             	mov	x9, x0
+;;      	 ff6300d1             	sub	sp, sp, #0x18
+;;      	 fc030091             	mov	x28, sp
+;;      	 308140f8             	ldur	x16, [x9, #8]
+;;      	 100240f8             	ldur	x16, [x16]
+;;      	 ff6330eb             	cmp	sp, x16
+;;      	 23030054             	b.lo	#0x8c
+;;   2c:	 820301f8             	stur	x2, [x28, #0x10]
+;;      	 808300fc             	stur	d0, [x28, #8]
+;;      	 890300f8             	stur	x9, [x28]
+;;      	 808340fc             	ldur	d0, [x28, #8]
+;;      	 800341f8             	ldur	x0, [x28, #0x10]
+;;      	 218145f8             	ldur	x1, [x9, #0x58]
+;;      	 e20300aa             	mov	x2, x0
+;;      	 424000b1             	adds	x2, x2, #0x10
+;;      	 22020054             	b.hs	#0x90
+;;   50:	 5f6021eb             	cmp	x2, x1, uxtx
+;;      	 08020054             	b.hi	#0x94
+;;   58:	 230145f8             	ldur	x3, [x9, #0x50]
+;;      	 6360208b             	add	x3, x3, x0, uxtx
+;;      	 63200091             	add	x3, x3, #8
+;;      	 100080d2             	mov	x16, #0
+;;      	 e40310aa             	mov	x4, x16
+;;      	 5f6021eb             	cmp	x2, x1, uxtx
+;;      	 8380839a             	csel	x3, x4, x3, hi
+;;      	 600000fc             	stur	d0, [x3]
+;;      	 ff630091             	add	sp, sp, #0x18
+;;      	 fc030091             	mov	x28, sp
+;;      	 fc0741f8             	ldr	x28, [sp], #0x10
+;;      	 fd7bc1a8             	ldp	x29, x30, [sp], #0x10
+;;      	 c0035fd6             	ret	
+;;   8c:	 1fc10000             	.byte	0x1f, 0xc1, 0x00, 0x00
+;;   90:	 1fc10000             	.byte	0x1f, 0xc1, 0x00, 0x00
+;;   94:	 1fc10000             	.byte	0x1f, 0xc1, 0x00, 0x00
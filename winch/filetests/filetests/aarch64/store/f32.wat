@@ -0,0 +1,34 @@
+;;! target = "aarch64"
+
+(module
+  (memory 1)
+  (func (param i32)
+    (f32.store (local.get 0) (f32.const 1.5))
+  )
+)
+;;      	 fd7bbfa9             	stp	x29, x30, [sp, #-0x10]!
+;;      	 fd030091             	mov	x29, sp
+;;      	 fc0f1ff8             	str	x28, [sp, #-0x10]!
+;;      	 fc030091             	mov	x28, sp
+;;      	 e90300aa             	mov	x9, x0
+;;      	 ff4300d1             	sub	sp, sp, #0x10
+;;      	 fc030091             	mov	x28, sp
+;;      	 308140f8             	ldur	x16, [x9, #8]
+;;      	 100240f8             	ldur	x16, [x16]
+;;      	 ff6330eb             	cmp	sp, x16
+;;      	 e3010054             	b.lo	#0x64
+;;   2c:	 82c300b8             	stur	w2, [x28, #0xc]
+;;      	 890300f8             	stur	x9, [x28]
+;;      	 10f8a7d2             	mov	x16, #0x3fc00000
+;;      	 0002271e             	fmov	s0, w16
+;;      	 80c340b8             	ldur	w0, [x28, #0xc]
+;;      	 e003002a             	mov	w0, w0
+;;      	 210145f8             	ldur	x1, [x9, #0x50]
+;;      	 2160208b             	add	x1, x1, x0, uxtx
+;;      	 200000bc             	stur	s0, [x1]
+;;      	 ff430091             	add	sp, sp, #0x10
+;;      	 fc030091             	mov	x28, sp
+;;      	 fc0741f8             	ldr	x28, [sp], #0x10
+;;      	 fd7bc1a8             	ldp	x29, x30, [sp], #0x10
+;;      	 c0035fd6             	ret	
+;;   64:	 1fc10000             	.byte	0x1f, 0xc1, 0x00, 0x00
@@ -0,0 +1,164 @@
+;;! target = "aarch64"
+(module
+  (type $t0 (func))
+  (func $f1 (type $t0))
+  (func $f2 (type $t0))
+  (func $f3 (type $t0))
+
+  ;; Define two tables of funcref
+  (table $t1 3 funcref)
+  (table $t2 10 funcref)
+
+  ;; Initialize table $t1 with functions $f1, $f2, $f3
+  (elem (i32.const 0) $f1 $f2 $f3)
+
+  ;; Function to fill table $t1 using a function reference from table $t2
+  (func (export "fill") (param $i i32) (param $r i32) (param $n i32)
+    (local $ref funcref)
+    (local.set $ref (table.get $t1 (local.get $r)))
+    (table.fill $t2 (local.get $i) (local.get $ref) (local.get $n))
+  )
+)
+;;      	 fd7bbfa9             	stp	x29, x30, [sp, #-0x10]!
+;;      	 fd030091             	mov	x29, sp
+;;      	 fc0f1ff8             	str	x28, [sp, #-0x10]!
+;;      	 fc030091             	mov	x28, sp
+;;      	 e90300aa             	mov	x9, x0
+;;      	 ff2300d1             	sub	sp, sp, #8
+;;      	 fc030091             	mov	x28, sp
+;;      	 308140f8             	ldur	x16, [x9, #8]
+;;      	 100240f8             	ldur	x16, [x16]
+;;      	 ff6330eb             	cmp	sp, x16
+;;      	 e3000054             	b.lo	#0x44
+;;   2c:	 890300f8             	stur	x9, [x28]
+;;      	 ff230091             	add	sp, sp, #8
+;;      	 fc030091             	mov	x28, sp
+;;      	 fc0741f8             	ldr	x28, [sp], #0x10
+;;      	 fd7bc1a8             	ldp	x29, x30, [sp], #0x10
+;;      	 c0035fd6             	ret	
+;;   44:	 1fc10000             	.byte	0x1f, 0xc1, 0x00, 0x00
+;;
+;;      	 fd7bbfa9             	stp	x29, x30, [sp, #-0x10]!
+;;      	 fd030091             	mov	x29, sp
+;;      	 fc0f1ff8             	str	x28, [sp, #-0x10]!
+;;      	 fc030091             	mov	x28, sp
+;;      	 e90300aa             	mov	x9, x0
+;;      	 ff2300d1             	sub	sp, sp, #8
+;;      	 fc030091             	mov	x28, sp
+;;      	 308140f8             	ldur	x16, [x9, #8]
+;;      	 100240f8             	ldur	x16, [x16]
+;;      	 ff6330eb             	cmp	sp, x16
+;;      	 e3000054             	b.lo	#0x44
+;;   2c:	 890300f8             	stur	x9, [x28]
+;;      	 ff230091             	add	sp, sp, #8
+;;      	 fc030091             	mov	x28, sp
+;;      	 fc0741f8             	ldr	x28, [sp], #0x10
+;;      	 fd7bc1a8             	ldp	x29, x30, [sp], #0x10
+;;      	 c0035fd6             	ret	
+;;   44:	 1fc10000             	.byte	0x1f, 0xc1, 0x00, 0x00
+;;
+;;      	 fd7bbfa9             	stp	x29, x30, [sp, #-0x10]!
+;;      	 fd030091             	mov	x29, sp
+;;      	 fc0f1ff8             	str	x28, [sp, #-0x10]!
+;;      	 fc030091             	mov	x28, sp
+;;      	 e90300aa             	mov	x9, x0
+;;      	 ff2300d1             	sub	sp, sp, #8
+;;      	 fc030091             	mov	x28, sp
+;;      	 308140f8             	ldur	x16, [x9, #8]
+;;      	 100240f8             	ldur	x16, [x16]
+;;      	 ff6330eb             	cmp	sp, x16
+;;      	 e3000054             	b.lo	#0x44
+;;   2c:	 890300f8             	stur	x9, [x28]
+;;      	 ff230091             	add	sp, sp, #8
+;;      	 fc030091             	mov	x28, sp
+;;      	 fc0741f8             	ldr	x28, [sp], #0x10
+;;      	 fd7bc1a8             	ldp	x29, x30, [sp], #0x10
+;;      	 c0035fd6             	ret	
+;;   44:	 1fc10000             	.byte	0x1f, 0xc1, 0x00, 0x00
+;;
+;;      	 fd7bbfa9             	stp	x29, x30, [sp, #-0x10]!
+;;      	 fd030091             	mov	x29, sp
+;;      	 fc0f1ff8             	str	x28, [sp, #-0x10]!
+;;      	 fc030091             	mov	x28, sp
+;;      	 e90300aa             	mov	x9, x0
+;;      	 ff8300d1             	sub	sp, sp, #0x20
+;;      	 fc030091             	mov	x28, sp
+;;      	 308140f8             	ldur	x16, [x9, #8]
+;;      	 100240f8             	ldur	x16, [x16]
+;;      	 ff6330eb             	cmp	sp, x16
+;;      	 43090054             	b.lo	#0x150
+;;   2c:	 82c301b8             	stur	w2, [x28, #0x1c]
+;;      	 838301b8             	stur	w3, [x28, #0x18]
+;;      	 844301b8             	stur	w4, [x28, #0x14]
+;;      	 100080d2             	mov	x16, #0
+;;      	 900301b8             	stur	w16, [x28, #0x10]
+;;      	 100080d2             	mov	x16, #0
+;;      	 908300f8             	stur	x16, [x28, #8]
+;;      	 890300f8             	stur	x9, [x28]
+;;      	 818341b8             	ldur	w1, [x28, #0x18]
+;;      	 e20309aa             	mov	x2, x9
+;;      	 430045b8             	ldur	w3, [x2, #0x50]
+;;      	 3f60236b             	cmp	w1, w3, uxtx
+;;      	 c2070054             	b.hs	#0x154
+;;   60:	 f003012a             	mov	w16, w1
+;;      	 040180d2             	mov	x4, #8
+;;      	 107e049b             	mul	x16, x16, x4
+;;      	 428044f8             	ldur	x2, [x2, #0x48]
+;;      	 e40302aa             	mov	x4, x2
+;;      	 4260308b             	add	x2, x2, x16, uxtx
+;;      	 3f60236b             	cmp	w1, w3, uxtx
+;;      	 8220829a             	csel	x2, x4, x2, hs
+;;      	 400040f8             	ldur	x0, [x2]
+;;      	 1f0000ea             	tst	x0, x0
+;;      	 21020054             	b.ne	#0xcc
+;;   8c:	 ff2300d1             	sub	sp, sp, #8
+;;      	 fc030091             	mov	x28, sp
+;;      	 890300f8             	stur	x9, [x28]
+;;      	 ff2300d1             	sub	sp, sp, #8
+;;      	 fc030091             	mov	x28, sp
+;;      	 810300b8             	stur	w1, [x28]
+;;      	 308143f8             	ldur	x16, [x9, #0x38]
+;;      	 038244f8             	ldur	x3, [x16, #0x48]
+;;      	 808340f8             	ldur	x0, [x28, #8]
+;;      	 100080d2             	mov	x16, #0
+;;      	 e103102a             	mov	w1, w16
+;;      	 820340b8             	ldur	w2, [x28]
+;;      	 60003fd6             	blr	x3
+;;   c0:	 ff430091             	add	sp, sp, #0x10
+;;      	 fc030091             	mov	x28, sp
+;;      	 02000014             	b	#0xd0
+;;   cc:	 00f87f92             	and	x0, x0, #0xfffffffffffffffe
+;;      	 80c300f8             	stur	x0, [x28, #0xc]
+;;      	 308143f8             	ldur	x16, [x9, #0x38]
+;;      	 058246f8             	ldur	x5, [x16, #0x68]
+;;      	 ff2300d1             	sub	sp, sp, #8
+;;      	 fc030091             	mov	x28, sp
+;;      	 890300f8             	stur	x9, [x28]
+;;      	 904342b8             	ldur	w16, [x28, #0x24]
+;;      	 ff2300d1             	sub	sp, sp, #8
+;;      	 fc030091             	mov	x28, sp
+;;      	 900300b8             	stur	w16, [x28]
+;;      	 90c341f8             	ldur	x16, [x28, #0x1c]
+;;      	 ff2300d1             	sub	sp, sp, #8
+;;      	 fc030091             	mov	x28, sp
+;;      	 900300f8             	stur	x16, [x28]
+;;      	 90c342b8             	ldur	w16, [x28, #0x2c]
+;;      	 ff2300d1             	sub	sp, sp, #8
+;;      	 fc030091             	mov	x28, sp
+;;      	 900300b8             	stur	w16, [x28]
+;;      	 808341f8             	ldur	x0, [x28, #0x18]
+;;      	 300080d2             	mov	x16, #1
+;;      	 e103102a             	mov	w1, w16
+;;      	 820341b8             	ldur	w2, [x28, #0x10]
+;;      	 838340f8             	ldur	x3, [x28, #8]
+;;      	 840340b8             	ldur	w4, [x28]
+;;      	 a0003fd6             	blr	x5
+;;  134:	 ff830091             	add	sp, sp, #0x20
+;;      	 fc030091             	mov	x28, sp
+;;      	 ff830091             	add	sp, sp, #0x20
+;;      	 fc030091             	mov	x28, sp
+;;      	 fc0741f8             	ldr	x28, [sp], #0x10
+;;      	 fd7bc1a8             	ldp	x29, x30, [sp], #0x10
+;;      	 c0035fd6             	ret	
+;;  150:	 1fc10000             	.byte	0x1f, 0xc1, 0x00, 0x00
+;;  154:	 1fc10000             	.byte	0x1f, 0xc1, 0x00, 0x00
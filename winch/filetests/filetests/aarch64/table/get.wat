@@ -0,0 +1,81 @@
+;;! target = "aarch64"
+(module
+  (table $t3 3 funcref)
+  (elem (table $t3) (i32.const 1) func $dummy)
+  (func $dummy)
+  (func $f3 (export "get-funcref") (param $i i32) (result funcref)
+    (table.get $t3 (local.get $i))
+  )
+)
+;;      	 fd7bbfa9             	stp	x29, x30, [sp, #-0x10]!
+;;      	 fd030091             	mov	x29, sp
+;;      	 fc0f1ff8             	str	x28, [sp, #-0x10]!
+;;      	 fc030091             	mov	x28, sp
+;;      	 e90300aa             	mov	x9, x0
+;;      	 ff2300d1             	sub	sp, sp, #8
+;;      	 fc030091             	mov	x28, sp
+;;      	 308140f8             	ldur	x16, [x9, #8]
+;;      	 100240f8             	ldur	x16, [x16]
+;;      	 ff6330eb             	cmp	sp, x16
+;;      	 e3000054             	b.lo	#0x44
+;;   2c:	 890300f8             	stur	x9, [x28]
+;;      	 ff230091             	add	sp, sp, #8
+;;      	 fc030091             	mov	x28, sp
+;;      	 fc0741f8             	ldr	x28, [sp], #0x10
+;;      	 fd7bc1a8             	ldp	x29, x30, [sp], #0x10
+;;      	 c0035fd6             	ret	
+;;   44:	 1fc10000             	.byte	0x1f, 0xc1, 0x00, 0x00
+;;
+;;      	 fd7bbfa9             	stp	x29, x30, [sp, #-0x10]!
+;;      	 fd030091             	mov	x29, sp
+;;      	 fc0f1ff8             	str	x28, [sp, #-0x10]!
+;;      	 fc030091             	mov	x28, sp
+;;      	 e90300aa             	mov	x9, x0
+;;      	 ff4300d1             	sub	sp, sp, #0x10
+;;      	 fc030091             	mov	x28, sp
+;;      	 308140f8             	ldur	x16, [x9, #8]
+;;      	 100240f8             	ldur	x16, [x16]
+;;      	 ff6330eb             	cmp	sp, x16
+;;      	 23050054             	b.lo	#0xcc
+;;   2c:	 82c300b8             	stur	w2, [x28, #0xc]
+;;      	 890300f8             	stur	x9, [x28]
+;;      	 81c340b8             	ldur	w1, [x28, #0xc]
+;;      	 e20309aa             	mov	x2, x9
+;;      	 430045b8             	ldur	w3, [x2, #0x50]
+;;      	 3f60236b             	cmp	w1, w3, uxtx
+;;      	 62040054             	b.hs	#0xd0
+;;   48:	 f003012a             	mov	w16, w1
+;;      	 040180d2             	mov	x4, #8
+;;      	 107e049b             	mul	x16, x16, x4
+;;      	 428044f8             	ldur	x2, [x2, #0x48]
+;;      	 e40302aa             	mov	x4, x2
+;;      	 4260308b             	add	x2, x2, x16, uxtx
+;;      	 3f60236b             	cmp	w1, w3, uxtx
+;;      	 8220829a             	csel	x2, x4, x2, hs
+;;      	 400040f8             	ldur	x0, [x2]
+;;      	 1f0000ea             	tst	x0, x0
+;;      	 21020054             	b.ne	#0xb4
+;;   74:	 ff2300d1             	sub	sp, sp, #8
+;;      	 fc030091             	mov	x28, sp
+;;      	 890300f8             	stur	x9, [x28]
+;;      	 ff2300d1             	sub	sp, sp, #8
+;;      	 fc030091             	mov	x28, sp
+;;      	 810300b8             	stur	w1, [x28]
+;;      	 308143f8             	ldur	x16, [x9, #0x38]
+;;      	 038244f8             	ldur	x3, [x16, #0x48]
+;;      	 808340f8             	ldur	x0, [x28, #8]
+;;      	 100080d2             	mov	x16, #0
+;;      	 e103102a             	mov	w1, w16
+;;      	 820340b8             	ldur	w2, [x28]
+;;      	 60003fd6             	blr	x3
+;;   a8:	 ff430091             	add	sp, sp, #0x10
+;;      	 fc030091             	mov	x28, sp
+;;      	 02000014             	b	#0xb8
+;;   b4:	 00f87f92             	and	x0, x0, #0xfffffffffffffffe
+;;      	 ff430091             	add	sp, sp, #0x10
+;;      	 fc030091             	mov	x28, sp
+;;      	 fc0741f8             	ldr	x28, [sp], #0x10
+;;      	 fd7bc1a8             	ldp	x29, x30, [sp], #0x10
+;;      	 c0035fd6             	ret	
+;;   cc:	 1fc10000             	.byte	0x1f, 0xc1, 0x00, 0x00
+;;   d0:	 1fc10000             	.byte	0x1f, 0xc1, 0x00, 0x00
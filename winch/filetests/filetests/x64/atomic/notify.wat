@@ -0,0 +1,40 @@
+;;! target = "x86_64"
+
+(module
+  (memory 1 1 shared)
+  (func (param i32 i32) (result i32)
+    (memory.atomic.notify (local.get 0) (local.get 1))
+  )
+)
+;;      	 55                   	push	rbp
+;;      	 4889e5               	mov	rbp, rsp
+;;      	 4989fe               	mov	r14, rdi
+;;      	 4883ec10             	sub	rsp, 0x10
+;;      	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;      	 4d8b1b               	mov	r11, qword ptr [r11]
+;;      	 4939e3               	cmp	r11, rsp
+;;      	 0f8752000000         	ja	0x6d
+;;   1b:	 8954240c             	mov	dword ptr [rsp + 0xc], edx
+;;      	 894c2408             	mov	dword ptr [rsp + 8], ecx
+;;      	 4c893424             	mov	qword ptr [rsp], r14
+;;      	 8b442408             	mov	eax, dword ptr [rsp + 8]
+;;      	 8b4c240c             	mov	ecx, dword ptr [rsp + 0xc]
+;;      	 8bc9                 	mov	ecx, ecx
+;;      	 4156                 	push	r14
+;;      	 51                   	push	rcx
+;;      	 4883ec08             	sub	rsp, 8
+;;      	 890424               	mov	dword ptr [rsp], eax
+;;      	 4d8b5e38             	mov	r11, qword ptr [r14 + 0x38]
+;;      	 498b9b90000000       	mov	rbx, qword ptr [r11 + 0x90]
+;;      	 4883ec08             	sub	rsp, 8
+;;      	 488b7c2418           	mov	rdi, qword ptr [rsp + 0x18]
+;;      	 be00000000           	mov	esi, 0
+;;      	 488b542410           	mov	rdx, qword ptr [rsp + 0x10]
+;;      	 8b4c2408             	mov	ecx, dword ptr [rsp + 8]
+;;      	 ffd3                 	call	rbx
+;;      	 4883c408             	add	rsp, 8
+;;      	 4883c418             	add	rsp, 0x18
+;;      	 4883c410             	add	rsp, 0x10
+;;      	 5d                   	pop	rbp
+;;      	 c3                   	ret	
+;;   6d:	 0f0b                 	ud2	
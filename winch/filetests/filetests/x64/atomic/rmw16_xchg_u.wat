@@ -0,0 +1,39 @@
+;;! target = "x86_64"
+
+(module
+  (memory 1)
+  (func (param i32 i64) (result i64)
+    (i64.atomic.rmw16.xchg_u offset=2 (local.get 0) (local.get 1))
+  )
+)
+;;      	 55                   	push	rbp
+;;      	 4889e5               	mov	rbp, rsp
+;;      	 4989fe               	mov	r14, rdi
+;;      	 4883ec18             	sub	rsp, 0x18
+;;      	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;      	 4d8b1b               	mov	r11, qword ptr [r11]
+;;      	 4939e3               	cmp	r11, rsp
+;;      	 0f874c000000         	ja	0x67
+;;   1b:	 89542414             	mov	dword ptr [rsp + 0x14], edx
+;;      	 48894c2408           	mov	qword ptr [rsp + 8], rcx
+;;      	 4c893424             	mov	qword ptr [rsp], r14
+;;      	 488b442408           	mov	rax, qword ptr [rsp + 8]
+;;      	 8b4c2414             	mov	ecx, dword ptr [rsp + 0x14]
+;;      	 89ca                 	mov	edx, ecx
+;;      	 83e201               	and	edx, 1
+;;      	 83fa00               	cmp	edx, 0
+;;      	 0f852a000000         	jne	0x69
+;;   3f:	 8bc9                 	mov	ecx, ecx
+;;      	 498b5650             	mov	rdx, qword ptr [r14 + 0x50]
+;;      	 4801ca               	add	rdx, rcx
+;;      	 4883c202             	add	rdx, 2
+;;      	 4889c1               	mov	rcx, rax
+;;      	 480fb702             	movzx	rax, word ptr [rdx]
+;;      	 4889cb               	mov	rbx, rcx
+;;      	 66f00fb11a           	lock cmpxchg	word ptr [rdx], bx
+;;      	 0f85f2ffffff         	jne	0x53
+;;   61:	 4883c418             	add	rsp, 0x18
+;;      	 5d                   	pop	rbp
+;;      	 c3                   	ret	
+;;   67:	 0f0b                 	ud2	
+;;   69:	 0f0b                 	ud2	
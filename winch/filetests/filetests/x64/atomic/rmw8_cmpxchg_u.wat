@@ -0,0 +1,34 @@
+;;! target = "x86_64"
+
+(module
+  (memory 1)
+  (func (param i32 i32 i32) (result i32)
+    (i32.atomic.rmw8.cmpxchg_u (local.get 0) (local.get 1) (local.get 2))
+  )
+)
+;;      	 55                   	push	rbp
+;;      	 4889e5               	mov	rbp, rsp
+;;      	 4989fe               	mov	r14, rdi
+;;      	 4883ec18             	sub	rsp, 0x18
+;;      	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;      	 4d8b1b               	mov	r11, qword ptr [r11]
+;;      	 4939e3               	cmp	r11, rsp
+;;      	 0f873b000000         	ja	0x56
+;;   1b:	 89542414             	mov	dword ptr [rsp + 0x14], edx
+;;      	 894c2410             	mov	dword ptr [rsp + 0x10], ecx
+;;      	 448944240c           	mov	dword ptr [rsp + 0xc], r8d
+;;      	 4c893424             	mov	qword ptr [rsp], r14
+;;      	 8b44240c             	mov	eax, dword ptr [rsp + 0xc]
+;;      	 8b4c2410             	mov	ecx, dword ptr [rsp + 0x10]
+;;      	 8b542414             	mov	edx, dword ptr [rsp + 0x14]
+;;      	 8bd2                 	mov	edx, edx
+;;      	 498b5e50             	mov	rbx, qword ptr [r14 + 0x50]
+;;      	 4801d3               	add	rbx, rdx
+;;      	 4889c2               	mov	rdx, rax
+;;      	 89c8                 	mov	eax, ecx
+;;      	 f00fb013             	lock cmpxchg	byte ptr [rbx], dl
+;;      	 81e0ff000000         	and	eax, 0xff
+;;      	 4883c418             	add	rsp, 0x18
+;;      	 5d                   	pop	rbp
+;;      	 c3                   	ret	
+;;   56:	 0f0b                 	ud2	
@@ -0,0 +1,39 @@
+;;! target = "x86_64"
+
+(module
+  (memory 1)
+  (func (param i32 i32) (result i32)
+    (i32.atomic.rmw.add (local.get 0) (local.get 1))
+  )
+)
+;;      	 55                   	push	rbp
+;;      	 4889e5               	mov	rbp, rsp
+;;      	 4989fe               	mov	r14, rdi
+;;      	 4883ec10             	sub	rsp, 0x10
+;;      	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;      	 4d8b1b               	mov	r11, qword ptr [r11]
+;;      	 4939e3               	cmp	r11, rsp
+;;      	 0f8746000000         	ja	0x61
+;;   1b:	 8954240c             	mov	dword ptr [rsp + 0xc], edx
+;;      	 894c2408             	mov	dword ptr [rsp + 8], ecx
+;;      	 4c893424             	mov	qword ptr [rsp], r14
+;;      	 8b442408             	mov	eax, dword ptr [rsp + 8]
+;;      	 8b4c240c             	mov	ecx, dword ptr [rsp + 0xc]
+;;      	 89ca                 	mov	edx, ecx
+;;      	 83e203               	and	edx, 3
+;;      	 83fa00               	cmp	edx, 0
+;;      	 0f8526000000         	jne	0x63
+;;   3d:	 8bc9                 	mov	ecx, ecx
+;;      	 498b5650             	mov	rdx, qword ptr [r14 + 0x50]
+;;      	 4801ca               	add	rdx, rcx
+;;      	 4889c1               	mov	rcx, rax
+;;      	 8b02                 	mov	eax, dword ptr [rdx]
+;;      	 4889c3               	mov	rbx, rax
+;;      	 4801cb               	add	rbx, rcx
+;;      	 f00fb11a             	lock cmpxchg	dword ptr [rdx], ebx
+;;      	 0f85f0ffffff         	jne	0x4b
+;;   5b:	 4883c410             	add	rsp, 0x10
+;;      	 5d                   	pop	rbp
+;;      	 c3                   	ret	
+;;   61:	 0f0b                 	ud2	
+;;   63:	 0f0b                 	ud2	
@@ -0,0 +1,36 @@
+;;! target = "x86_64"
+
+(module
+  (memory 1)
+  (func (param i32 i64)
+    (i64.atomic.store32 offset=4 (local.get 0) (local.get 1))
+  )
+)
+;;      	 55                   	push	rbp
+;;      	 4889e5               	mov	rbp, rsp
+;;      	 4989fe               	mov	r14, rdi
+;;      	 4883ec18             	sub	rsp, 0x18
+;;      	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;      	 4d8b1b               	mov	r11, qword ptr [r11]
+;;      	 4939e3               	cmp	r11, rsp
+;;      	 0f873c000000         	ja	0x57
+;;   1b:	 89542414             	mov	dword ptr [rsp + 0x14], edx
+;;      	 48894c2408           	mov	qword ptr [rsp + 8], rcx
+;;      	 4c893424             	mov	qword ptr [rsp], r14
+;;      	 488b442408           	mov	rax, qword ptr [rsp + 8]
+;;      	 8b4c2414             	mov	ecx, dword ptr [rsp + 0x14]
+;;      	 89ca                 	mov	edx, ecx
+;;      	 83e203               	and	edx, 3
+;;      	 83fa00               	cmp	edx, 0
+;;      	 0f851a000000         	jne	0x59
+;;   3f:	 8bc9                 	mov	ecx, ecx
+;;      	 498b5650             	mov	rdx, qword ptr [r14 + 0x50]
+;;      	 4801ca               	add	rdx, rcx
+;;      	 4883c204             	add	rdx, 4
+;;      	 8902                 	mov	dword ptr [rdx], eax
+;;      	 0faef0               	mfence	
+;;      	 4883c418             	add	rsp, 0x18
+;;      	 5d                   	pop	rbp
+;;      	 c3                   	ret	
+;;   57:	 0f0b                 	ud2	
+;;   59:	 0f0b                 	ud2	
@@ -0,0 +1,47 @@
+;;! target = "x86_64"
+
+(module
+  (memory 1 1 shared)
+  (func (param i32 i32 i64) (result i32)
+    (memory.atomic.wait32 offset=4 (local.get 0) (local.get 1) (local.get 2))
+  )
+)
+;;      	 55                   	push	rbp
+;;      	 4889e5               	mov	rbp, rsp
+;;      	 4989fe               	mov	r14, rdi
+;;      	 4883ec18             	sub	rsp, 0x18
+;;      	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;      	 4d8b1b               	mov	r11, qword ptr [r11]
+;;      	 4939e3               	cmp	r11, rsp
+;;      	 0f876b000000         	ja	0x86
+;;   1b:	 89542414             	mov	dword ptr [rsp + 0x14], edx
+;;      	 894c2410             	mov	dword ptr [rsp + 0x10], ecx
+;;      	 4c89442408           	mov	qword ptr [rsp + 8], r8
+;;      	 4c893424             	mov	qword ptr [rsp], r14
+;;      	 488b442408           	mov	rax, qword ptr [rsp + 8]
+;;      	 8b4c2410             	mov	ecx, dword ptr [rsp + 0x10]
+;;      	 8b542414             	mov	edx, dword ptr [rsp + 0x14]
+;;      	 83c204               	add	edx, 4
+;;      	 0f8246000000         	jb	0x88
+;;   42:	 8bd2                 	mov	edx, edx
+;;      	 4156                 	push	r14
+;;      	 52                   	push	rdx
+;;      	 4883ec08             	sub	rsp, 8
+;;      	 890c24               	mov	dword ptr [rsp], ecx
+;;      	 50                   	push	rax
+;;      	 4d8b5e38             	mov	r11, qword ptr [r14 + 0x38]
+;;      	 498b9b98000000       	mov	rbx, qword ptr [r11 + 0x98]
+;;      	 4883ec08             	sub	rsp, 8
+;;      	 488b7c2420           	mov	rdi, qword ptr [rsp + 0x20]
+;;      	 be00000000           	mov	esi, 0
+;;      	 488b542418           	mov	rdx, qword ptr [rsp + 0x18]
+;;      	 8b4c2410             	mov	ecx, dword ptr [rsp + 0x10]
+;;      	 4c8b442408           	mov	r8, qword ptr [rsp + 8]
+;;      	 ffd3                 	call	rbx
+;;      	 4883c408             	add	rsp, 8
+;;      	 4883c420             	add	rsp, 0x20
+;;      	 4883c418             	add	rsp, 0x18
+;;      	 5d                   	pop	rbp
+;;      	 c3                   	ret	
+;;   86:	 0f0b                 	ud2	
+;;   88:	 0f0b                 	ud2	
@@ -9,32 +9,36 @@
   
 ;;      	 55                   	push	rbp
 ;;      	 4889e5               	mov	rbp, rsp
+;;      	 4989fe               	mov	r14, rdi
 ;;      	 4883ec08             	sub	rsp, 8
 ;;      	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
 ;;      	 4d8b1b               	mov	r11, qword ptr [r11]
 ;;      	 4939e3               	cmp	r11, rsp
-;;      	 0f870a000000         	ja	0x22
-;;   18:	 4c893424             	mov	qword ptr [rsp], r14
+;;      	 0f870a000000         	ja	0x25
+;;   1b:	 4c893424             	mov	qword ptr [rsp], r14
 ;;      	 4883c408             	add	rsp, 8
 ;;      	 5d                   	pop	rbp
 ;;      	 c3                   	ret	
-;;   22:	 0f0b                 	ud2	
+;;   25:	 0f0b                 	ud2	
 ;;
 ;;      	 55                   	push	rbp
 ;;      	 4889e5               	mov	rbp, rsp
+;;      	 4989fe               	mov	r14, rdi
 ;;      	 4883ec08             	sub	rsp, 8
 ;;      	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
 ;;      	 4d8b1b               	mov	r11, qword ptr [r11]
 ;;      	 4939e3               	cmp	r11, rsp
-;;      	 0f8724000000         	ja	0x3c
-;;   18:	 4c893424             	mov	qword ptr [rsp], r14
+;;      	 0f872a000000         	ja	0x45
+;;   1b:	 4c893424             	mov	qword ptr [rsp], r14
 ;;      	 b801000000           	mov	eax, 1
 ;;      	 85c0                 	test	eax, eax
-;;      	 0f840d000000         	je	0x36
-;;   29:	 4883ec08             	sub	rsp, 8
-;;      	 e800000000           	call	0x32
+;;      	 0f8413000000         	je	0x3f
+;;   2c:	 4883ec08             	sub	rsp, 8
+;;      	 4c89f7               	mov	rdi, r14
+;;      	 4c89f6               	mov	rsi, r14
+;;      	 e800000000           	call	0x3b
 ;;      	 4883c408             	add	rsp, 8
 ;;      	 4883c408             	add	rsp, 8
 ;;      	 5d                   	pop	rbp
 ;;      	 c3                   	ret	
-;;   3c:	 0f0b                 	ud2	
+;;   45:	 0f0b                 	ud2	
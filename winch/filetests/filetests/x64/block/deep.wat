@@ -46,30 +46,34 @@
 )
 ;;      	 55                   	push	rbp
 ;;      	 4889e5               	mov	rbp, rsp
+;;      	 4989fe               	mov	r14, rdi
 ;;      	 4883ec08             	sub	rsp, 8
 ;;      	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
 ;;      	 4d8b1b               	mov	r11, qword ptr [r11]
 ;;      	 4939e3               	cmp	r11, rsp
-;;      	 0f870a000000         	ja	0x22
-;;   18:	 4c893424             	mov	qword ptr [rsp], r14
+;;      	 0f870a000000         	ja	0x25
+;;   1b:	 4c893424             	mov	qword ptr [rsp], r14
 ;;      	 4883c408             	add	rsp, 8
 ;;      	 5d                   	pop	rbp
 ;;      	 c3                   	ret	
-;;   22:	 0f0b                 	ud2	
+;;   25:	 0f0b                 	ud2	
 ;;
 ;;      	 55                   	push	rbp
 ;;      	 4889e5               	mov	rbp, rsp
+;;      	 4989fe               	mov	r14, rdi
 ;;      	 4883ec08             	sub	rsp, 8
 ;;      	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
 ;;      	 4d8b1b               	mov	r11, qword ptr [r11]
 ;;      	 4939e3               	cmp	r11, rsp
-;;      	 0f871c000000         	ja	0x34
-;;   18:	 4c893424             	mov	qword ptr [rsp], r14
+;;      	 0f8722000000         	ja	0x3d
+;;   1b:	 4c893424             	mov	qword ptr [rsp], r14
 ;;      	 4883ec08             	sub	rsp, 8
-;;      	 e800000000           	call	0x25
+;;      	 4c89f7               	mov	rdi, r14
+;;      	 4c89f6               	mov	rsi, r14
+;;      	 e800000000           	call	0x2e
 ;;      	 4883c408             	add	rsp, 8
 ;;      	 b896000000           	mov	eax, 0x96
 ;;      	 4883c408             	add	rsp, 8
 ;;      	 5d                   	pop	rbp
 ;;      	 c3                   	ret	
-;;   34:	 0f0b                 	ud2	
+;;   3d:	 0f0b                 	ud2	
@@ -10,20 +10,21 @@
 )
 ;;      	 55                   	push	rbp
 ;;      	 4889e5               	mov	rbp, rsp
+;;      	 4989fe               	mov	r14, rdi
 ;;      	 4883ec10             	sub	rsp, 0x10
 ;;      	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
 ;;      	 4d8b1b               	mov	r11, qword ptr [r11]
 ;;      	 4939e3               	cmp	r11, rsp
-;;      	 0f8726000000         	ja	0x3e
-;;   18:	 897c240c             	mov	dword ptr [rsp + 0xc], edi
+;;      	 0f8726000000         	ja	0x41
+;;   1b:	 8954240c             	mov	dword ptr [rsp + 0xc], edx
 ;;      	 4c893424             	mov	qword ptr [rsp], r14
 ;;      	 448b5c240c           	mov	r11d, dword ptr [rsp + 0xc]
-;;      	 4883ec04             	sub	rsp, 4
+;;      	 4883ec08             	sub	rsp, 8
 ;;      	 44891c24             	mov	dword ptr [rsp], r11d
 ;;      	 8b0424               	mov	eax, dword ptr [rsp]
-;;      	 4883c404             	add	rsp, 4
+;;      	 4883c408             	add	rsp, 8
 ;;      	 8944240c             	mov	dword ptr [rsp + 0xc], eax
 ;;      	 4883c410             	add	rsp, 0x10
 ;;      	 5d                   	pop	rbp
 ;;      	 c3                   	ret	
-;;   3e:	 0f0b                 	ud2	
+;;   41:	 0f0b                 	ud2	
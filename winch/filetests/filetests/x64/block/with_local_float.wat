@@ -9,19 +9,20 @@
 )
 ;;      	 55                   	push	rbp
 ;;      	 4889e5               	mov	rbp, rsp
+;;      	 4989fe               	mov	r14, rdi
 ;;      	 4883ec10             	sub	rsp, 0x10
 ;;      	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
 ;;      	 4d8b1b               	mov	r11, qword ptr [r11]
 ;;      	 4939e3               	cmp	r11, rsp
-;;      	 0f872a000000         	ja	0x42
-;;   18:	 f30f1144240c         	movss	dword ptr [rsp + 0xc], xmm0
+;;      	 0f872a000000         	ja	0x45
+;;   1b:	 f30f1144240c         	movss	dword ptr [rsp + 0xc], xmm0
 ;;      	 4c893424             	mov	qword ptr [rsp], r14
 ;;      	 f3440f107c240c       	movss	xmm15, dword ptr [rsp + 0xc]
-;;      	 4883ec04             	sub	rsp, 4
+;;      	 4883ec08             	sub	rsp, 8
 ;;      	 f3440f113c24         	movss	dword ptr [rsp], xmm15
 ;;      	 f30f100424           	movss	xmm0, dword ptr [rsp]
-;;      	 4883c404             	add	rsp, 4
+;;      	 4883c408             	add	rsp, 8
 ;;      	 4883c410             	add	rsp, 0x10
 ;;      	 5d                   	pop	rbp
 ;;      	 c3                   	ret	
-;;   42:	 0f0b                 	ud2	
+;;   45:	 0f0b                 	ud2	
@@ -9,31 +9,33 @@
 )
 ;;      	 55                   	push	rbp
 ;;      	 4889e5               	mov	rbp, rsp
+;;      	 4989fe               	mov	r14, rdi
 ;;      	 4883ec18             	sub	rsp, 0x18
 ;;      	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
 ;;      	 4d8b1b               	mov	r11, qword ptr [r11]
 ;;      	 4939e3               	cmp	r11, rsp
-;;      	 0f871b000000         	ja	0x33
-;;   18:	 897c2414             	mov	dword ptr [rsp + 0x14], edi
-;;      	 89742410             	mov	dword ptr [rsp + 0x10], esi
-;;      	 8954240c             	mov	dword ptr [rsp + 0xc], edx
+;;      	 0f871c000000         	ja	0x37
+;;   1b:	 89542414             	mov	dword ptr [rsp + 0x14], edx
+;;      	 894c2410             	mov	dword ptr [rsp + 0x10], ecx
+;;      	 448944240c           	mov	dword ptr [rsp + 0xc], r8d
 ;;      	 4c893424             	mov	qword ptr [rsp], r14
 ;;      	 b8ffffffff           	mov	eax, 0xffffffff
 ;;      	 4883c418             	add	rsp, 0x18
 ;;      	 5d                   	pop	rbp
 ;;      	 c3                   	ret	
-;;   33:	 0f0b                 	ud2	
+;;   37:	 0f0b                 	ud2	
 ;;
 ;;      	 55                   	push	rbp
 ;;      	 4889e5               	mov	rbp, rsp
+;;      	 4989fe               	mov	r14, rdi
 ;;      	 4883ec08             	sub	rsp, 8
 ;;      	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
 ;;      	 4d8b1b               	mov	r11, qword ptr [r11]
 ;;      	 4939e3               	cmp	r11, rsp
-;;      	 0f870f000000         	ja	0x27
-;;   18:	 4c893424             	mov	qword ptr [rsp], r14
+;;      	 0f870f000000         	ja	0x2a
+;;   1b:	 4c893424             	mov	qword ptr [rsp], r14
 ;;      	 b80e000000           	mov	eax, 0xe
 ;;      	 4883c408             	add	rsp, 8
 ;;      	 5d                   	pop	rbp
 ;;      	 c3                   	ret	
-;;   27:	 0f0b                 	ud2	
+;;   2a:	 0f0b                 	ud2	
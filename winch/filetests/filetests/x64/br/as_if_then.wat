@@ -11,21 +11,22 @@
 )
 ;;      	 55                   	push	rbp
 ;;      	 4889e5               	mov	rbp, rsp
+;;      	 4989fe               	mov	r14, rdi
 ;;      	 4883ec10             	sub	rsp, 0x10
 ;;      	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
 ;;      	 4d8b1b               	mov	r11, qword ptr [r11]
 ;;      	 4939e3               	cmp	r11, rsp
-;;      	 0f872c000000         	ja	0x44
-;;   18:	 897c240c             	mov	dword ptr [rsp + 0xc], edi
-;;      	 89742408             	mov	dword ptr [rsp + 8], esi
+;;      	 0f872c000000         	ja	0x47
+;;   1b:	 8954240c             	mov	dword ptr [rsp + 0xc], edx
+;;      	 894c2408             	mov	dword ptr [rsp + 8], ecx
 ;;      	 4c893424             	mov	qword ptr [rsp], r14
 ;;      	 8b44240c             	mov	eax, dword ptr [rsp + 0xc]
 ;;      	 85c0                 	test	eax, eax
-;;      	 0f840a000000         	je	0x3a
-;;   30:	 b803000000           	mov	eax, 3
-;;      	 e904000000           	jmp	0x3e
-;;   3a:	 8b442408             	mov	eax, dword ptr [rsp + 8]
+;;      	 0f840a000000         	je	0x3d
+;;   33:	 b803000000           	mov	eax, 3
+;;      	 e904000000           	jmp	0x41
+;;   3d:	 8b442408             	mov	eax, dword ptr [rsp + 8]
 ;;      	 4883c410             	add	rsp, 0x10
 ;;      	 5d                   	pop	rbp
 ;;      	 c3                   	ret	
-;;   44:	 0f0b                 	ud2	
+;;   47:	 0f0b                 	ud2	
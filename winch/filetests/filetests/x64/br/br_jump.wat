@@ -14,23 +14,24 @@
 )
 ;;      	 55                   	push	rbp
 ;;      	 4889e5               	mov	rbp, rsp
+;;      	 4989fe               	mov	r14, rdi
 ;;      	 4883ec10             	sub	rsp, 0x10
 ;;      	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
 ;;      	 4d8b1b               	mov	r11, qword ptr [r11]
 ;;      	 4939e3               	cmp	r11, rsp
-;;      	 0f8736000000         	ja	0x4e
-;;   18:	 48c744240800000000   	
+;;      	 0f8736000000         	ja	0x51
+;;   1b:	 48c744240800000000   	
 ;; 				mov	qword ptr [rsp + 8], 0
 ;;      	 4c893424             	mov	qword ptr [rsp], r14
 ;;      	 448b5c240c           	mov	r11d, dword ptr [rsp + 0xc]
-;;      	 4883ec04             	sub	rsp, 4
+;;      	 4883ec08             	sub	rsp, 8
 ;;      	 44891c24             	mov	dword ptr [rsp], r11d
-;;      	 448b5c2410           	mov	r11d, dword ptr [rsp + 0x10]
-;;      	 4883ec04             	sub	rsp, 4
+;;      	 448b5c2414           	mov	r11d, dword ptr [rsp + 0x14]
+;;      	 4883ec08             	sub	rsp, 8
 ;;      	 44891c24             	mov	dword ptr [rsp], r11d
-;;      	 4883c404             	add	rsp, 4
-;;      	 e9eaffffff           	jmp	0x32
-;;   48:	 4883c410             	add	rsp, 0x10
+;;      	 4883c408             	add	rsp, 8
+;;      	 e9eaffffff           	jmp	0x35
+;;   4b:	 4883c410             	add	rsp, 0x10
 ;;      	 5d                   	pop	rbp
 ;;      	 c3                   	ret	
-;;   4e:	 0f0b                 	ud2	
+;;   51:	 0f0b                 	ud2	
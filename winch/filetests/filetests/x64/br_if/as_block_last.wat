@@ -7,32 +7,38 @@
 )
 ;;      	 55                   	push	rbp
 ;;      	 4889e5               	mov	rbp, rsp
+;;      	 4989fe               	mov	r14, rdi
 ;;      	 4883ec08             	sub	rsp, 8
 ;;      	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
 ;;      	 4d8b1b               	mov	r11, qword ptr [r11]
 ;;      	 4939e3               	cmp	r11, rsp
-;;      	 0f870a000000         	ja	0x22
-;;   18:	 4c893424             	mov	qword ptr [rsp], r14
+;;      	 0f870a000000         	ja	0x25
+;;   1b:	 4c893424             	mov	qword ptr [rsp], r14
 ;;      	 4883c408             	add	rsp, 8
 ;;      	 5d                   	pop	rbp
 ;;      	 c3                   	ret	
-;;   22:	 0f0b                 	ud2	
+;;   25:	 0f0b                 	ud2	
 ;;
 ;;      	 55                   	push	rbp
 ;;      	 4889e5               	mov	rbp, rsp
+;;      	 4989fe               	mov	r14, rdi
 ;;      	 4883ec10             	sub	rsp, 0x10
 ;;      	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
 ;;      	 4d8b1b               	mov	r11, qword ptr [r11]
 ;;      	 4939e3               	cmp	r11, rsp
-;;      	 0f8724000000         	ja	0x3c
-;;   18:	 897c240c             	mov	dword ptr [rsp + 0xc], edi
+;;      	 0f8730000000         	ja	0x4b
+;;   1b:	 8954240c             	mov	dword ptr [rsp + 0xc], edx
 ;;      	 4c893424             	mov	qword ptr [rsp], r14
-;;      	 e800000000           	call	0x25
-;;      	 e800000000           	call	0x2a
+;;      	 4c89f7               	mov	rdi, r14
+;;      	 4c89f6               	mov	rsi, r14
+;;      	 e800000000           	call	0x2e
+;;      	 4c89f7               	mov	rdi, r14
+;;      	 4c89f6               	mov	rsi, r14
+;;      	 e800000000           	call	0x39
 ;;      	 8b44240c             	mov	eax, dword ptr [rsp + 0xc]
 ;;      	 85c0                 	test	eax, eax
-;;      	 0f8500000000         	jne	0x36
-;;   36:	 4883c410             	add	rsp, 0x10
+;;      	 0f8500000000         	jne	0x45
+;;   45:	 4883c410             	add	rsp, 0x10
 ;;      	 5d                   	pop	rbp
 ;;      	 c3                   	ret	
-;;   3c:	 0f0b                 	ud2	
+;;   4b:	 0f0b                 	ud2	
@@ -11,43 +11,45 @@
 )
 ;;      	 55                   	push	rbp
 ;;      	 4889e5               	mov	rbp, rsp
+;;      	 4989fe               	mov	r14, rdi
 ;;      	 4883ec18             	sub	rsp, 0x18
 ;;      	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
 ;;      	 4d8b1b               	mov	r11, qword ptr [r11]
 ;;      	 4939e3               	cmp	r11, rsp
-;;      	 0f871b000000         	ja	0x33
-;;   18:	 897c2414             	mov	dword ptr [rsp + 0x14], edi
-;;      	 89742410             	mov	dword ptr [rsp + 0x10], esi
-;;      	 8954240c             	mov	dword ptr [rsp + 0xc], edx
+;;      	 0f871c000000         	ja	0x37
+;;   1b:	 89542414             	mov	dword ptr [rsp + 0x14], edx
+;;      	 894c2410             	mov	dword ptr [rsp + 0x10], ecx
+;;      	 448944240c           	mov	dword ptr [rsp + 0xc], r8d
 ;;      	 4c893424             	mov	qword ptr [rsp], r14
 ;;      	 b8ffffffff           	mov	eax, 0xffffffff
 ;;      	 4883c418             	add	rsp, 0x18
 ;;      	 5d                   	pop	rbp
 ;;      	 c3                   	ret	
-;;   33:	 0f0b                 	ud2	
+;;   37:	 0f0b                 	ud2	
 ;;
 ;;      	 55                   	push	rbp
 ;;      	 4889e5               	mov	rbp, rsp
+;;      	 4989fe               	mov	r14, rdi
 ;;      	 4883ec08             	sub	rsp, 8
 ;;      	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
 ;;      	 4d8b1b               	mov	r11, qword ptr [r11]
 ;;      	 4939e3               	cmp	r11, rsp
-;;      	 0f8742000000         	ja	0x5a
-;;   18:	 4c893424             	mov	qword ptr [rsp], r14
+;;      	 0f8740000000         	ja	0x5b
+;;   1b:	 4c893424             	mov	qword ptr [rsp], r14
 ;;      	 b901000000           	mov	ecx, 1
 ;;      	 b80d000000           	mov	eax, 0xd
 ;;      	 85c9                 	test	ecx, ecx
-;;      	 0f8526000000         	jne	0x54
-;;   2e:	 4883ec04             	sub	rsp, 4
+;;      	 0f8524000000         	jne	0x55
+;;   31:	 4883ec08             	sub	rsp, 8
 ;;      	 890424               	mov	dword ptr [rsp], eax
-;;      	 4883ec04             	sub	rsp, 4
-;;      	 bf01000000           	mov	edi, 1
-;;      	 8b742404             	mov	esi, dword ptr [rsp + 4]
-;;      	 ba03000000           	mov	edx, 3
-;;      	 e800000000           	call	0x4c
-;;      	 4883c404             	add	rsp, 4
-;;      	 4883c404             	add	rsp, 4
+;;      	 4c89f7               	mov	rdi, r14
+;;      	 4c89f6               	mov	rsi, r14
+;;      	 ba01000000           	mov	edx, 1
+;;      	 8b0c24               	mov	ecx, dword ptr [rsp]
+;;      	 41b803000000         	mov	r8d, 3
+;;      	 e800000000           	call	0x51
+;;      	 4883c408             	add	rsp, 8
 ;;      	 4883c408             	add	rsp, 8
 ;;      	 5d                   	pop	rbp
 ;;      	 c3                   	ret	
-;;   5a:	 0f0b                 	ud2	
+;;   5b:	 0f0b                 	ud2	
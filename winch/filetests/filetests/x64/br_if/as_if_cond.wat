@@ -12,23 +12,24 @@
 )
 ;;      	 55                   	push	rbp
 ;;      	 4889e5               	mov	rbp, rsp
+;;      	 4989fe               	mov	r14, rdi
 ;;      	 4883ec10             	sub	rsp, 0x10
 ;;      	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
 ;;      	 4d8b1b               	mov	r11, qword ptr [r11]
 ;;      	 4939e3               	cmp	r11, rsp
-;;      	 0f8736000000         	ja	0x4e
-;;   18:	 897c240c             	mov	dword ptr [rsp + 0xc], edi
+;;      	 0f8736000000         	ja	0x51
+;;   1b:	 8954240c             	mov	dword ptr [rsp + 0xc], edx
 ;;      	 4c893424             	mov	qword ptr [rsp], r14
 ;;      	 8b4c240c             	mov	ecx, dword ptr [rsp + 0xc]
 ;;      	 b801000000           	mov	eax, 1
 ;;      	 85c9                 	test	ecx, ecx
-;;      	 0f8517000000         	jne	0x48
-;;   31:	 85c0                 	test	eax, eax
-;;      	 0f840a000000         	je	0x43
-;;   39:	 b802000000           	mov	eax, 2
-;;      	 e905000000           	jmp	0x48
-;;   43:	 b803000000           	mov	eax, 3
+;;      	 0f8517000000         	jne	0x4b
+;;   34:	 85c0                 	test	eax, eax
+;;      	 0f840a000000         	je	0x46
+;;   3c:	 b802000000           	mov	eax, 2
+;;      	 e905000000           	jmp	0x4b
+;;   46:	 b803000000           	mov	eax, 3
 ;;      	 4883c410             	add	rsp, 0x10
 ;;      	 5d                   	pop	rbp
 ;;      	 c3                   	ret	
-;;   4e:	 0f0b                 	ud2	
+;;   51:	 0f0b                 	ud2	
@@ -9,36 +9,40 @@
 )
 ;;      	 55                   	push	rbp
 ;;      	 4889e5               	mov	rbp, rsp
+;;      	 4989fe               	mov	r14, rdi
 ;;      	 4883ec08             	sub	rsp, 8
 ;;      	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
 ;;      	 4d8b1b               	mov	r11, qword ptr [r11]
 ;;      	 4939e3               	cmp	r11, rsp
-;;      	 0f870a000000         	ja	0x22
-;;   18:	 4c893424             	mov	qword ptr [rsp], r14
+;;      	 0f870a000000         	ja	0x25
+;;   1b:	 4c893424             	mov	qword ptr [rsp], r14
 ;;      	 4883c408             	add	rsp, 8
 ;;      	 5d                   	pop	rbp
 ;;      	 c3                   	ret	
-;;   22:	 0f0b                 	ud2	
+;;   25:	 0f0b                 	ud2	
 ;;
 ;;      	 55                   	push	rbp
 ;;      	 4889e5               	mov	rbp, rsp
+;;      	 4989fe               	mov	r14, rdi
 ;;      	 4883ec10             	sub	rsp, 0x10
 ;;      	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
 ;;      	 4d8b1b               	mov	r11, qword ptr [r11]
 ;;      	 4939e3               	cmp	r11, rsp
-;;      	 0f8734000000         	ja	0x4c
-;;   18:	 897c240c             	mov	dword ptr [rsp + 0xc], edi
-;;      	 89742408             	mov	dword ptr [rsp + 8], esi
+;;      	 0f873a000000         	ja	0x55
+;;   1b:	 8954240c             	mov	dword ptr [rsp + 0xc], edx
+;;      	 894c2408             	mov	dword ptr [rsp + 8], ecx
 ;;      	 4c893424             	mov	qword ptr [rsp], r14
 ;;      	 8b44240c             	mov	eax, dword ptr [rsp + 0xc]
 ;;      	 85c0                 	test	eax, eax
-;;      	 0f8411000000         	je	0x41
-;;   30:	 8b442408             	mov	eax, dword ptr [rsp + 8]
+;;      	 0f8411000000         	je	0x44
+;;   33:	 8b442408             	mov	eax, dword ptr [rsp + 8]
 ;;      	 85c0                 	test	eax, eax
-;;      	 0f850a000000         	jne	0x46
-;;      	 e905000000           	jmp	0x46
-;;   41:	 e800000000           	call	0x46
+;;      	 0f8510000000         	jne	0x4f
+;;      	 e90b000000           	jmp	0x4f
+;;   44:	 4c89f7               	mov	rdi, r14
+;;      	 4c89f6               	mov	rsi, r14
+;;      	 e800000000           	call	0x4f
 ;;      	 4883c410             	add	rsp, 0x10
 ;;      	 5d                   	pop	rbp
 ;;      	 c3                   	ret	
-;;   4c:	 0f0b                 	ud2	
+;;   55:	 0f0b                 	ud2	
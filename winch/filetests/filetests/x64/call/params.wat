@@ -37,93 +37,96 @@
 )
 ;;      	 55                   	push	rbp
 ;;      	 4889e5               	mov	rbp, rsp
+;;      	 4989fe               	mov	r14, rdi
 ;;      	 4883ec10             	sub	rsp, 0x10
 ;;      	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
 ;;      	 4d8b1b               	mov	r11, qword ptr [r11]
 ;;      	 4939e3               	cmp	r11, rsp
-;;      	 0f87fe000000         	ja	0x116
-;;   18:	 897c240c             	mov	dword ptr [rsp + 0xc], edi
-;;      	 89742408             	mov	dword ptr [rsp + 8], esi
+;;      	 0f871e010000         	ja	0x139
+;;   1b:	 8954240c             	mov	dword ptr [rsp + 0xc], edx
+;;      	 894c2408             	mov	dword ptr [rsp + 8], ecx
 ;;      	 4c893424             	mov	qword ptr [rsp], r14
 ;;      	 8b4c240c             	mov	ecx, dword ptr [rsp + 0xc]
 ;;      	 8b442408             	mov	eax, dword ptr [rsp + 8]
 ;;      	 31d2                 	xor	edx, edx
 ;;      	 f7f1                 	div	ecx
-;;      	 4883ec04             	sub	rsp, 4
+;;      	 4883ec08             	sub	rsp, 8
 ;;      	 890424               	mov	dword ptr [rsp], eax
-;;      	 4883ec2c             	sub	rsp, 0x2c
-;;      	 8b7c242c             	mov	edi, dword ptr [rsp + 0x2c]
-;;      	 be01000000           	mov	esi, 1
-;;      	 ba02000000           	mov	edx, 2
-;;      	 b903000000           	mov	ecx, 3
-;;      	 41b804000000         	mov	r8d, 4
-;;      	 41b905000000         	mov	r9d, 5
-;;      	 41bb06000000         	mov	r11d, 6
+;;      	 4883ec38             	sub	rsp, 0x38
+;;      	 4c89f7               	mov	rdi, r14
+;;      	 4c89f6               	mov	rsi, r14
+;;      	 8b542438             	mov	edx, dword ptr [rsp + 0x38]
+;;      	 b901000000           	mov	ecx, 1
+;;      	 41b802000000         	mov	r8d, 2
+;;      	 41b903000000         	mov	r9d, 3
+;;      	 41bb04000000         	mov	r11d, 4
 ;;      	 44891c24             	mov	dword ptr [rsp], r11d
-;;      	 41bb07000000         	mov	r11d, 7
+;;      	 41bb05000000         	mov	r11d, 5
 ;;      	 44895c2408           	mov	dword ptr [rsp + 8], r11d
-;;      	 41bb08000000         	mov	r11d, 8
+;;      	 41bb06000000         	mov	r11d, 6
 ;;      	 44895c2410           	mov	dword ptr [rsp + 0x10], r11d
-;;      	 e800000000           	call	0x7f
-;;      	 4883c42c             	add	rsp, 0x2c
-;;      	 4883c404             	add	rsp, 4
-;;      	 4883ec04             	sub	rsp, 4
+;;      	 41bb07000000         	mov	r11d, 7
+;;      	 44895c2418           	mov	dword ptr [rsp + 0x18], r11d
+;;      	 41bb08000000         	mov	r11d, 8
+;;      	 44895c2420           	mov	dword ptr [rsp + 0x20], r11d
+;;      	 e800000000           	call	0x94
+;;      	 4883c408             	add	rsp, 8
+;;      	 4883c408             	add	rsp, 8
+;;      	 4883ec08             	sub	rsp, 8
 ;;      	 890424               	mov	dword ptr [rsp], eax
-;;      	 448b5c240c           	mov	r11d, dword ptr [rsp + 0xc]
-;;      	 4883ec04             	sub	rsp, 4
+;;      	 448b5c2410           	mov	r11d, dword ptr [rsp + 0x10]
+;;      	 4883ec08             	sub	rsp, 8
 ;;      	 44891c24             	mov	dword ptr [rsp], r11d
-;;      	 448b5c2414           	mov	r11d, dword ptr [rsp + 0x14]
-;;      	 4883ec04             	sub	rsp, 4
+;;      	 448b5c241c           	mov	r11d, dword ptr [rsp + 0x1c]
+;;      	 4883ec08             	sub	rsp, 8
 ;;      	 44891c24             	mov	dword ptr [rsp], r11d
 ;;      	 8b0c24               	mov	ecx, dword ptr [rsp]
-;;      	 4883c404             	add	rsp, 4
+;;      	 4883c408             	add	rsp, 8
 ;;      	 8b0424               	mov	eax, dword ptr [rsp]
-;;      	 4883c404             	add	rsp, 4
+;;      	 4883c408             	add	rsp, 8
 ;;      	 31d2                 	xor	edx, edx
 ;;      	 f7f1                 	div	ecx
-;;      	 4883ec04             	sub	rsp, 4
+;;      	 4883ec08             	sub	rsp, 8
 ;;      	 890424               	mov	dword ptr [rsp], eax
-;;      	 4883ec28             	sub	rsp, 0x28
-;;      	 8b7c242c             	mov	edi, dword ptr [rsp + 0x2c]
-;;      	 8b742428             	mov	esi, dword ptr [rsp + 0x28]
-;;      	 ba02000000           	mov	edx, 2
-;;      	 b903000000           	mov	ecx, 3
-;;      	 41b804000000         	mov	r8d, 4
-;;      	 41b905000000         	mov	r9d, 5
-;;      	 41bb06000000         	mov	r11d, 6
+;;      	 4883ec30             	sub	rsp, 0x30
+;;      	 4c89f7               	mov	rdi, r14
+;;      	 4c89f6               	mov	rsi, r14
+;;      	 8b542438             	mov	edx, dword ptr [rsp + 0x38]
+;;      	 8b4c2430             	mov	ecx, dword ptr [rsp + 0x30]
+;;      	 41b802000000         	mov	r8d, 2
+;;      	 41b903000000         	mov	r9d, 3
+;;      	 41bb04000000         	mov	r11d, 4
 ;;      	 44891c24             	mov	dword ptr [rsp], r11d
-;;      	 41bb07000000         	mov	r11d, 7
+;;      	 41bb05000000         	mov	r11d, 5
 ;;      	 44895c2408           	mov	dword ptr [rsp + 8], r11d
-;;      	 41bb08000000         	mov	r11d, 8
+;;      	 41bb06000000         	mov	r11d, 6
 ;;      	 44895c2410           	mov	dword ptr [rsp + 0x10], r11d
-;;      	 e800000000           	call	0x108
-;;      	 4883c428             	add	rsp, 0x28
-;;      	 4883c408             	add	rsp, 8
+;;      	 41bb07000000         	mov	r11d, 7
+;;      	 44895c2418           	mov	dword ptr [rsp + 0x18], r11d
+;;      	 41bb08000000         	mov	r11d, 8
+;;      	 44895c2420           	mov	dword ptr [rsp + 0x20], r11d
+;;      	 e800000000           	call	0x12f
+;;      	 4883c410             	add	rsp, 0x10
 ;;      	 4883c410             	add	rsp, 0x10
 ;;      	 5d                   	pop	rbp
 ;;      	 c3                   	ret	
-;;  116:	 0f0b                 	ud2	
+;;  139:	 0f0b                 	ud2	
 ;;
 ;;      	 55                   	push	rbp
 ;;      	 4889e5               	mov	rbp, rsp
-;;      	 4883ec20             	sub	rsp, 0x20
+;;      	 4989fe               	mov	r14, rdi
+;;      	 4883ec18             	sub	rsp, 0x18
 ;;      	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
 ;;      	 4d8b1b               	mov	r11, qword ptr [r11]
 ;;      	 4939e3               	cmp	r11, rsp
-;;      	 0f8757000000         	ja	0x6f
-;;   18:	 897c241c             	mov	dword ptr [rsp + 0x1c], edi
-;;      	 89742418             	mov	dword ptr [rsp + 0x18], esi
-;;      	 89542414             	mov	dword ptr [rsp + 0x14], edx
+;;      	 0f874f000000         	ja	0x6a
+;;   1b:	 89542414             	mov	dword ptr [rsp + 0x14], edx
 ;;      	 894c2410             	mov	dword ptr [rsp + 0x10], ecx
 ;;      	 448944240c           	mov	dword ptr [rsp + 0xc], r8d
 ;;      	 44894c2408           	mov	dword ptr [rsp + 8], r9d
 ;;      	 4c893424             	mov	qword ptr [rsp], r14
-;;      	 8b442418             	mov	eax, dword ptr [rsp + 0x18]
-;;      	 8b4c241c             	mov	ecx, dword ptr [rsp + 0x1c]
-;;      	 01c1                 	add	ecx, eax
-;;      	 8b442414             	mov	eax, dword ptr [rsp + 0x14]
-;;      	 01c1                 	add	ecx, eax
 ;;      	 8b442410             	mov	eax, dword ptr [rsp + 0x10]
+;;      	 8b4c2414             	mov	ecx, dword ptr [rsp + 0x14]
 ;;      	 01c1                 	add	ecx, eax
 ;;      	 8b44240c             	mov	eax, dword ptr [rsp + 0xc]
 ;;      	 01c1                 	add	ecx, eax
@@ -135,8 +138,12 @@
 ;;      	 01c1                 	add	ecx, eax
 ;;      	 8b4520               	mov	eax, dword ptr [rbp + 0x20]
 ;;      	 01c1                 	add	ecx, eax
+;;      	 8b4528               	mov	eax, dword ptr [rbp + 0x28]
+;;      	 01c1                 	add	ecx, eax
+;;      	 8b4530               	mov	eax, dword ptr [rbp + 0x30]
+;;      	 01c1                 	add	ecx, eax
 ;;      	 89c8                 	mov	eax, ecx
-;;      	 4883c420             	add	rsp, 0x20
+;;      	 4883c418             	add	rsp, 0x18
 ;;      	 5d                   	pop	rbp
-;;      	 c3                   	ret	
-;;   6f:	 0f0b                 	ud2	
+;;      	 c23000               	ret	0x30
+;;   6a:	 0f0b                 	ud2	
@@ -24,46 +24,49 @@
 )
 ;;      	 55                   	push	rbp
 ;;      	 4889e5               	mov	rbp, rsp
+;;      	 4989fe               	mov	r14, rdi
 ;;      	 4883ec10             	sub	rsp, 0x10
 ;;      	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
 ;;      	 4d8b1b               	mov	r11, qword ptr [r11]
 ;;      	 4939e3               	cmp	r11, rsp
-;;      	 0f8787000000         	ja	0x9f
-;;   18:	 897c240c             	mov	dword ptr [rsp + 0xc], edi
+;;      	 0f878a000000         	ja	0xa5
+;;   1b:	 8954240c             	mov	dword ptr [rsp + 0xc], edx
 ;;      	 4c893424             	mov	qword ptr [rsp], r14
 ;;      	 8b44240c             	mov	eax, dword ptr [rsp + 0xc]
 ;;      	 83f801               	cmp	eax, 1
 ;;      	 b800000000           	mov	eax, 0
 ;;      	 400f9ec0             	setle	al
 ;;      	 85c0                 	test	eax, eax
-;;      	 0f8409000000         	je	0x41
-;;   38:	 8b44240c             	mov	eax, dword ptr [rsp + 0xc]
-;;      	 e958000000           	jmp	0x99
-;;   41:	 8b44240c             	mov	eax, dword ptr [rsp + 0xc]
+;;      	 0f8409000000         	je	0x44
+;;   3b:	 8b44240c             	mov	eax, dword ptr [rsp + 0xc]
+;;      	 e95b000000           	jmp	0x9f
+;;   44:	 8b44240c             	mov	eax, dword ptr [rsp + 0xc]
 ;;      	 83e801               	sub	eax, 1
-;;      	 4883ec04             	sub	rsp, 4
+;;      	 4883ec08             	sub	rsp, 8
 ;;      	 890424               	mov	dword ptr [rsp], eax
-;;      	 4883ec0c             	sub	rsp, 0xc
-;;      	 8b7c240c             	mov	edi, dword ptr [rsp + 0xc]
-;;      	 e800000000           	call	0x5c
-;;      	 4883c40c             	add	rsp, 0xc
-;;      	 4883c404             	add	rsp, 4
+;;      	 4883ec08             	sub	rsp, 8
+;;      	 4c89f7               	mov	rdi, r14
+;;      	 4c89f6               	mov	rsi, r14
+;;      	 8b542408             	mov	edx, dword ptr [rsp + 8]
+;;      	 e800000000           	call	0x65
+;;      	 4883c408             	add	rsp, 8
+;;      	 4883c408             	add	rsp, 8
 ;;      	 8b4c240c             	mov	ecx, dword ptr [rsp + 0xc]
 ;;      	 83e902               	sub	ecx, 2
-;;      	 4883ec04             	sub	rsp, 4
+;;      	 4883ec08             	sub	rsp, 8
 ;;      	 890424               	mov	dword ptr [rsp], eax
-;;      	 4883ec04             	sub	rsp, 4
-;;      	 890c24               	mov	dword ptr [rsp], ecx
 ;;      	 4883ec08             	sub	rsp, 8
-;;      	 8b7c2408             	mov	edi, dword ptr [rsp + 8]
-;;      	 e800000000           	call	0x86
+;;      	 890c24               	mov	dword ptr [rsp], ecx
+;;      	 4c89f7               	mov	rdi, r14
+;;      	 4c89f6               	mov	rsi, r14
+;;      	 8b1424               	mov	edx, dword ptr [rsp]
+;;      	 e800000000           	call	0x90
 ;;      	 4883c408             	add	rsp, 8
-;;      	 4883c404             	add	rsp, 4
 ;;      	 8b0c24               	mov	ecx, dword ptr [rsp]
-;;      	 4883c404             	add	rsp, 4
+;;      	 4883c408             	add	rsp, 8
 ;;      	 01c1                 	add	ecx, eax
 ;;      	 89c8                 	mov	eax, ecx
 ;;      	 4883c410             	add	rsp, 0x10
 ;;      	 5d                   	pop	rbp
 ;;      	 c3                   	ret	
-;;   9f:	 0f0b                 	ud2	
+;;   a5:	 0f0b                 	ud2	
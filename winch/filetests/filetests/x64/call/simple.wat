@@ -15,44 +15,48 @@
 )
 ;;      	 55                   	push	rbp
 ;;      	 4889e5               	mov	rbp, rsp
+;;      	 4989fe               	mov	r14, rdi
 ;;      	 4883ec10             	sub	rsp, 0x10
 ;;      	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
 ;;      	 4d8b1b               	mov	r11, qword ptr [r11]
 ;;      	 4939e3               	cmp	r11, rsp
-;;      	 0f8751000000         	ja	0x69
-;;   18:	 48c744240800000000   	
+;;      	 0f8757000000         	ja	0x72
+;;   1b:	 48c744240800000000   	
 ;; 				mov	qword ptr [rsp + 8], 0
 ;;      	 4c893424             	mov	qword ptr [rsp], r14
-;;      	 bf14000000           	mov	edi, 0x14
-;;      	 be50000000           	mov	esi, 0x50
-;;      	 e800000000           	call	0x34
+;;      	 4c89f7               	mov	rdi, r14
+;;      	 4c89f6               	mov	rsi, r14
+;;      	 ba14000000           	mov	edx, 0x14
+;;      	 b950000000           	mov	ecx, 0x50
+;;      	 e800000000           	call	0x3d
 ;;      	 b902000000           	mov	ecx, 2
 ;;      	 894c240c             	mov	dword ptr [rsp + 0xc], ecx
-;;      	 4883ec04             	sub	rsp, 4
+;;      	 4883ec08             	sub	rsp, 8
 ;;      	 890424               	mov	dword ptr [rsp], eax
-;;      	 448b5c2410           	mov	r11d, dword ptr [rsp + 0x10]
-;;      	 4883ec04             	sub	rsp, 4
+;;      	 448b5c2414           	mov	r11d, dword ptr [rsp + 0x14]
+;;      	 4883ec08             	sub	rsp, 8
 ;;      	 44891c24             	mov	dword ptr [rsp], r11d
 ;;      	 8b0c24               	mov	ecx, dword ptr [rsp]
-;;      	 4883c404             	add	rsp, 4
+;;      	 4883c408             	add	rsp, 8
 ;;      	 8b0424               	mov	eax, dword ptr [rsp]
-;;      	 4883c404             	add	rsp, 4
+;;      	 4883c408             	add	rsp, 8
 ;;      	 31d2                 	xor	edx, edx
 ;;      	 f7f1                 	div	ecx
 ;;      	 4883c410             	add	rsp, 0x10
 ;;      	 5d                   	pop	rbp
 ;;      	 c3                   	ret	
-;;   69:	 0f0b                 	ud2	
+;;   72:	 0f0b                 	ud2	
 ;;
 ;;      	 55                   	push	rbp
 ;;      	 4889e5               	mov	rbp, rsp
+;;      	 4989fe               	mov	r14, rdi
 ;;      	 4883ec10             	sub	rsp, 0x10
 ;;      	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
 ;;      	 4d8b1b               	mov	r11, qword ptr [r11]
 ;;      	 4939e3               	cmp	r11, rsp
-;;      	 0f871f000000         	ja	0x37
-;;   18:	 897c240c             	mov	dword ptr [rsp + 0xc], edi
-;;      	 89742408             	mov	dword ptr [rsp + 8], esi
+;;      	 0f871f000000         	ja	0x3a
+;;   1b:	 8954240c             	mov	dword ptr [rsp + 0xc], edx
+;;      	 894c2408             	mov	dword ptr [rsp + 8], ecx
 ;;      	 4c893424             	mov	qword ptr [rsp], r14
 ;;      	 8b442408             	mov	eax, dword ptr [rsp + 8]
 ;;      	 8b4c240c             	mov	ecx, dword ptr [rsp + 0xc]
@@ -61,4 +65,4 @@
 ;;      	 4883c410             	add	rsp, 0x10
 ;;      	 5d                   	pop	rbp
 ;;      	 c3                   	ret	
-;;   37:	 0f0b                 	ud2	
+;;   3a:	 0f0b                 	ud2	
@@ -0,0 +1,93 @@
+;;! target = "x86_64"
+;;! flags = ["has_sse3", "has_ssse3", "has_sse41", "has_sse42"]
+
+(module
+    (func $id (param v128 i32 v128 v128 v128 v128 v128 v128 v128 f64 v128) (result v128)
+        (local.get 10)
+    )
+    (func (result v128)
+        (call $id
+            (v128.const i64x2 1 2)
+            (i32.const 0)
+            (v128.const i64x2 1 2)
+            (v128.const i64x2 1 2)
+            (v128.const i64x2 1 2)
+            (v128.const i64x2 1 2)
+            (v128.const i64x2 1 2)
+            (v128.const i64x2 1 2)
+            (v128.const i64x2 1 2)
+            (f64.const 1)
+            (v128.const i64x2 3 4)
+        )
+    )
+)
+;;      	 55                   	push	rbp
+;;      	 4889e5               	mov	rbp, rsp
+;;      	 4989fe               	mov	r14, rdi
+;;      	 4881ec98000000       	sub	rsp, 0x98
+;;      	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;      	 4d8b1b               	mov	r11, qword ptr [r11]
+;;      	 4939e3               	cmp	r11, rsp
+;;      	 0f874e000000         	ja	0x6c
+;;   1e:	 f30f7f842488000000   	
+;; 				movdqu	xmmword ptr [rsp + 0x88], xmm0
+;;      	 89942484000000       	mov	dword ptr [rsp + 0x84], edx
+;;      	 f30f7f4c2468         	movdqu	xmmword ptr [rsp + 0x68], xmm1
+;;      	 f30f7f542458         	movdqu	xmmword ptr [rsp + 0x58], xmm2
+;;      	 f30f7f5c2448         	movdqu	xmmword ptr [rsp + 0x48], xmm3
+;;      	 f30f7f642438         	movdqu	xmmword ptr [rsp + 0x38], xmm4
+;;      	 f30f7f6c2428         	movdqu	xmmword ptr [rsp + 0x28], xmm5
+;;      	 f30f7f742418         	movdqu	xmmword ptr [rsp + 0x18], xmm6
+;;      	 f30f7f7c2408         	movdqu	xmmword ptr [rsp + 8], xmm7
+;;      	 4c893424             	mov	qword ptr [rsp], r14
+;;      	 f30f6f4520           	movdqu	xmm0, xmmword ptr [rbp + 0x20]
+;;      	 4881c498000000       	add	rsp, 0x98
+;;      	 5d                   	pop	rbp
+;;      	 c22000               	ret	0x20
+;;   6c:	 0f0b                 	ud2	
+;;
+;;      	 55                   	push	rbp
+;;      	 4889e5               	mov	rbp, rsp
+;;      	 4989fe               	mov	r14, rdi
+;;      	 4883ec08             	sub	rsp, 8
+;;      	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;      	 4d8b1b               	mov	r11, qword ptr [r11]
+;;      	 4939e3               	cmp	r11, rsp
+;;      	 0f8781000000         	ja	0x9c
+;;   1b:	 4c893424             	mov	qword ptr [rsp], r14
+;;      	 4883ec28             	sub	rsp, 0x28
+;;      	 4c89f7               	mov	rdi, r14
+;;      	 4c89f6               	mov	rsi, r14
+;;      	 f30f6f056f000000     	movdqu	xmm0, xmmword ptr [rip + 0x6f]
+;;      	 ba00000000           	mov	edx, 0
+;;      	 f30f6f0d62000000     	movdqu	xmm1, xmmword ptr [rip + 0x62]
+;;      	 f30f6f155a000000     	movdqu	xmm2, xmmword ptr [rip + 0x5a]
+;;      	 f30f6f1d52000000     	movdqu	xmm3, xmmword ptr [rip + 0x52]
+;;      	 f30f6f254a000000     	movdqu	xmm4, xmmword ptr [rip + 0x4a]
+;;      	 f30f6f2d42000000     	movdqu	xmm5, xmmword ptr [rip + 0x42]
+;;      	 f30f6f353a000000     	movdqu	xmm6, xmmword ptr [rip + 0x3a]
+;;      	 f30f6f3d32000000     	movdqu	xmm7, xmmword ptr [rip + 0x32]
+;;      	 f2440f103d39000000   	
+;; 				movsd	xmm15, qword ptr [rip + 0x39]
+;;      	 f2440f113c24         	movsd	qword ptr [rsp], xmm15
+;;      	 f3440f6f3d3a000000   	
+;; 				movdqu	xmm15, xmmword ptr [rip + 0x3a]
+;;      	 f3440f7f7c2410       	movdqu	xmmword ptr [rsp + 0x10], xmm15
+;;      	 e800000000           	call	0x92
+;;      	 4883c408             	add	rsp, 8
+;;      	 4883c408             	add	rsp, 8
+;;      	 5d                   	pop	rbp
+;;      	 c3                   	ret	
+;;   9c:	 0f0b                 	ud2	
+;;   9e:	 0000                 	add	byte ptr [rax], al
+;;   a0:	 0100                 	add	dword ptr [rax], eax
+;;   a2:	 0000                 	add	byte ptr [rax], al
+;;   a4:	 0000                 	add	byte ptr [rax], al
+;;   a6:	 0000                 	add	byte ptr [rax], al
+;;   a8:	 0200                 	add	al, byte ptr [rax]
+;;   aa:	 0000                 	add	byte ptr [rax], al
+;;   ac:	 0000                 	add	byte ptr [rax], al
+;;   ae:	 0000                 	add	byte ptr [rax], al
+;;   b0:	 0000                 	add	byte ptr [rax], al
+;;   b2:	 0000                 	add	byte ptr [rax], al
+;;   b4:	 0000                 	add	byte ptr [rax], al
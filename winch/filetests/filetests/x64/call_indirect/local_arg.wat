@@ -76,37 +76,39 @@
 
 ;;      	 55                   	push	rbp
 ;;      	 4889e5               	mov	rbp, rsp
+;;      	 4989fe               	mov	r14, rdi
 ;;      	 4883ec10             	sub	rsp, 0x10
 ;;      	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
 ;;      	 4d8b1b               	mov	r11, qword ptr [r11]
 ;;      	 4939e3               	cmp	r11, rsp
-;;      	 0f870e000000         	ja	0x26
-;;   18:	 897c240c             	mov	dword ptr [rsp + 0xc], edi
+;;      	 0f870e000000         	ja	0x29
+;;   1b:	 8954240c             	mov	dword ptr [rsp + 0xc], edx
 ;;      	 4c893424             	mov	qword ptr [rsp], r14
 ;;      	 4883c410             	add	rsp, 0x10
 ;;      	 5d                   	pop	rbp
 ;;      	 c3                   	ret	
-;;   26:	 0f0b                 	ud2	
+;;   29:	 0f0b                 	ud2	
 ;;
 ;;      	 55                   	push	rbp
 ;;      	 4889e5               	mov	rbp, rsp
+;;      	 4989fe               	mov	r14, rdi
 ;;      	 4883ec10             	sub	rsp, 0x10
 ;;      	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
 ;;      	 4d8b1b               	mov	r11, qword ptr [r11]
 ;;      	 4939e3               	cmp	r11, rsp
-;;      	 0f87b4000000         	ja	0xcc
-;;   18:	 48c744240800000000   	
+;;      	 0f87cc000000         	ja	0xe7
+;;   1b:	 48c744240800000000   	
 ;; 				mov	qword ptr [rsp + 8], 0
 ;;      	 4c893424             	mov	qword ptr [rsp], r14
 ;;      	 448b5c240c           	mov	r11d, dword ptr [rsp + 0xc]
-;;      	 4883ec04             	sub	rsp, 4
+;;      	 4883ec08             	sub	rsp, 8
 ;;      	 44891c24             	mov	dword ptr [rsp], r11d
 ;;      	 b900000000           	mov	ecx, 0
 ;;      	 4c89f2               	mov	rdx, r14
 ;;      	 8b5a50               	mov	ebx, dword ptr [rdx + 0x50]
 ;;      	 39d9                 	cmp	ecx, ebx
-;;      	 0f8389000000         	jae	0xce
-;;   45:	 4189cb               	mov	r11d, ecx
+;;      	 0f83a1000000         	jae	0xe9
+;;   48:	 4189cb               	mov	r11d, ecx
 ;;      	 4d6bdb08             	imul	r11, r11, 8
 ;;      	 488b5248             	mov	rdx, qword ptr [rdx + 0x48]
 ;;      	 4889d6               	mov	rsi, rdx
@@ -115,36 +117,42 @@
 ;;      	 480f43d6             	cmovae	rdx, rsi
 ;;      	 488b02               	mov	rax, qword ptr [rdx]
 ;;      	 4885c0               	test	rax, rax
-;;      	 0f8529000000         	jne	0x91
-;;   68:	 4d8b5e38             	mov	r11, qword ptr [r14 + 0x38]
+;;      	 0f8532000000         	jne	0x9d
+;;   6b:	 4d8b5e38             	mov	r11, qword ptr [r14 + 0x38]
 ;;      	 498b5b48             	mov	rbx, qword ptr [r11 + 0x48]
 ;;      	 4156                 	push	r14
-;;      	 4883ec04             	sub	rsp, 4
+;;      	 4883ec08             	sub	rsp, 8
 ;;      	 890c24               	mov	dword ptr [rsp], ecx
-;;      	 488b7c2404           	mov	rdi, qword ptr [rsp + 4]
+;;      	 4883ec08             	sub	rsp, 8
+;;      	 488b7c2410           	mov	rdi, qword ptr [rsp + 0x10]
 ;;      	 be00000000           	mov	esi, 0
-;;      	 8b1424               	mov	edx, dword ptr [rsp]
+;;      	 8b542408             	mov	edx, dword ptr [rsp + 8]
 ;;      	 ffd3                 	call	rbx
-;;      	 4883c40c             	add	rsp, 0xc
-;;      	 e904000000           	jmp	0x95
-;;   91:	 4883e0fe             	and	rax, 0xfffffffffffffffe
+;;      	 4883c408             	add	rsp, 8
+;;      	 4883c410             	add	rsp, 0x10
+;;      	 e904000000           	jmp	0xa1
+;;   9d:	 4883e0fe             	and	rax, 0xfffffffffffffffe
 ;;      	 4885c0               	test	rax, rax
-;;      	 0f8432000000         	je	0xd0
-;;   9e:	 4d8b5e40             	mov	r11, qword ptr [r14 + 0x40]
+;;      	 0f8441000000         	je	0xeb
+;;   aa:	 4d8b5e40             	mov	r11, qword ptr [r14 + 0x40]
 ;;      	 418b0b               	mov	ecx, dword ptr [r11]
 ;;      	 8b5018               	mov	edx, dword ptr [rax + 0x18]
 ;;      	 39d1                 	cmp	ecx, edx
-;;      	 0f8522000000         	jne	0xd2
-;;   b0:	 488b4810             	mov	rcx, qword ptr [rax + 0x10]
-;;      	 4883ec0c             	sub	rsp, 0xc
-;;      	 8b7c240c             	mov	edi, dword ptr [rsp + 0xc]
+;;      	 0f8531000000         	jne	0xed
+;;   bc:	 488b5820             	mov	rbx, qword ptr [rax + 0x20]
+;;      	 488b4810             	mov	rcx, qword ptr [rax + 0x10]
+;;      	 4883ec08             	sub	rsp, 8
+;;      	 4889df               	mov	rdi, rbx
+;;      	 4c89f6               	mov	rsi, r14
+;;      	 8b542408             	mov	edx, dword ptr [rsp + 8]
 ;;      	 ffd1                 	call	rcx
-;;      	 4883c40c             	add	rsp, 0xc
-;;      	 4883c404             	add	rsp, 4
+;;      	 4c8b742410           	mov	r14, qword ptr [rsp + 0x10]
+;;      	 4883c408             	add	rsp, 8
+;;      	 4883c408             	add	rsp, 8
 ;;      	 4883c410             	add	rsp, 0x10
 ;;      	 5d                   	pop	rbp
 ;;      	 c3                   	ret	
-;;   cc:	 0f0b                 	ud2	
-;;   ce:	 0f0b                 	ud2	
-;;   d0:	 0f0b                 	ud2	
-;;   d2:	 0f0b                 	ud2	
+;;   e7:	 0f0b                 	ud2	
+;;   e9:	 0f0b                 	ud2	
+;;   eb:	 0f0b                 	ud2	
+;;   ed:	 0f0b                 	ud2	
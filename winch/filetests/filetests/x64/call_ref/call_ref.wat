@@ -0,0 +1,64 @@
+;;! target = "x86_64"
+(module
+  (type $t (func (param i32) (result i32)))
+  (func $id (type $t) (local.get 0))
+  (elem declare func $id)
+  (func (export "call-ref") (param i32) (result i32)
+    (call_ref $t (local.get 0) (ref.func $id))
+  )
+)
+;;      	 55                   	push	rbp
+;;      	 4889e5               	mov	rbp, rsp
+;;      	 4989fe               	mov	r14, rdi
+;;      	 4883ec10             	sub	rsp, 0x10
+;;      	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;      	 4d8b1b               	mov	r11, qword ptr [r11]
+;;      	 4939e3               	cmp	r11, rsp
+;;      	 0f8712000000         	ja	0x2d
+;;   1b:	 8954240c             	mov	dword ptr [rsp + 0xc], edx
+;;      	 4c893424             	mov	qword ptr [rsp], r14
+;;      	 8b44240c             	mov	eax, dword ptr [rsp + 0xc]
+;;      	 4883c410             	add	rsp, 0x10
+;;      	 5d                   	pop	rbp
+;;      	 c3                   	ret	
+;;   2d:	 0f0b                 	ud2	
+;;
+;;      	 55                   	push	rbp
+;;      	 4889e5               	mov	rbp, rsp
+;;      	 4989fe               	mov	r14, rdi
+;;      	 4883ec10             	sub	rsp, 0x10
+;;      	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;      	 4d8b1b               	mov	r11, qword ptr [r11]
+;;      	 4939e3               	cmp	r11, rsp
+;;      	 0f8764000000         	ja	0x7f
+;;   1b:	 8954240c             	mov	dword ptr [rsp + 0xc], edx
+;;      	 4c893424             	mov	qword ptr [rsp], r14
+;;      	 4d8b5e38             	mov	r11, qword ptr [r14 + 0x38]
+;;      	 498b4b38             	mov	rcx, qword ptr [r11 + 0x38]
+;;      	 448b5c240c           	mov	r11d, dword ptr [rsp + 0xc]
+;;      	 4883ec08             	sub	rsp, 8
+;;      	 44891c24             	mov	dword ptr [rsp], r11d
+;;      	 4156                 	push	r14
+;;      	 488b3c24             	mov	rdi, qword ptr [rsp]
+;;      	 be00000000           	mov	esi, 0
+;;      	 ffd1                 	call	rcx
+;;      	 4883c408             	add	rsp, 8
+;;      	 4885c0               	test	rax, rax
+;;      	 0f842f000000         	je	0x81
+;;   52:	 50                   	push	rax
+;;      	 59                   	pop	rcx
+;;      	 4c8b4120             	mov	r8, qword ptr [rcx + 0x20]
+;;      	 488b5910             	mov	rbx, qword ptr [rcx + 0x10]
+;;      	 4883ec08             	sub	rsp, 8
+;;      	 4c89c7               	mov	rdi, r8
+;;      	 4c89f6               	mov	rsi, r14
+;;      	 8b542408             	mov	edx, dword ptr [rsp + 8]
+;;      	 ffd3                 	call	rbx
+;;      	 4c8b742410           	mov	r14, qword ptr [rsp + 0x10]
+;;      	 4883c408             	add	rsp, 8
+;;      	 4883c408             	add	rsp, 8
+;;      	 4883c410             	add	rsp, 0x10
+;;      	 5d                   	pop	rbp
+;;      	 c3                   	ret	
+;;   7f:	 0f0b                 	ud2	
+;;   81:	 0f0b                 	ud2	
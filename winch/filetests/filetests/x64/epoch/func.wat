@@ -0,0 +1,35 @@
+;;! target = "x86_64"
+;;! [tunables]
+;;! epoch_interruption = true
+
+(module
+  (func (result i32)
+    (i32.const 42)
+  )
+)
+;;      	 55                   	push	rbp
+;;      	 4889e5               	mov	rbp, rsp
+;;      	 4989fe               	mov	r14, rdi
+;;      	 4883ec08             	sub	rsp, 8
+;;      	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;      	 4d8b1b               	mov	r11, qword ptr [r11]
+;;      	 4939e3               	cmp	r11, rsp
+;;      	 0f873e000000         	ja	0x59
+;;   1b:	 4c893424             	mov	qword ptr [rsp], r14
+;;      	 498b4618             	mov	rax, qword ptr [r14 + 0x18]
+;;      	 488b00               	mov	rax, qword ptr [rax]
+;;      	 498b4e08             	mov	rcx, qword ptr [r14 + 8]
+;;      	 488b4910             	mov	rcx, qword ptr [rcx + 0x10]
+;;      	 4839c8               	cmp	rax, rcx
+;;      	 0f8217000000         	jb	0x4e
+;;   37:	 4d8b5e38             	mov	r11, qword ptr [r14 + 0x38]
+;;      	 498b8bb0000000       	mov	rcx, qword ptr [r11 + 0xb0]
+;;      	 4156                 	push	r14
+;;      	 488b3c24             	mov	rdi, qword ptr [rsp]
+;;      	 ffd1                 	call	rcx
+;;      	 4883c408             	add	rsp, 8
+;;      	 b82a000000           	mov	eax, 0x2a
+;;      	 4883c408             	add	rsp, 8
+;;      	 5d                   	pop	rbp
+;;      	 c3                   	ret	
+;;   59:	 0f0b                 	ud2	
@@ -10,21 +10,22 @@
 )
 ;;      	 55                   	push	rbp
 ;;      	 4889e5               	mov	rbp, rsp
+;;      	 4989fe               	mov	r14, rdi
 ;;      	 4883ec10             	sub	rsp, 0x10
 ;;      	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
 ;;      	 4d8b1b               	mov	r11, qword ptr [r11]
 ;;      	 4939e3               	cmp	r11, rsp
-;;      	 0f8747000000         	ja	0x5f
-;;   18:	 48c744240800000000   	
+;;      	 0f8747000000         	ja	0x62
+;;   1b:	 48c744240800000000   	
 ;; 				mov	qword ptr [rsp + 8], 0
 ;;      	 4c893424             	mov	qword ptr [rsp], r14
 ;;      	 8b4c240c             	mov	ecx, dword ptr [rsp + 0xc]
 ;;      	 8bc9                 	mov	ecx, ecx
 ;;      	 4883f900             	cmp	rcx, 0
-;;      	 0f8c0a000000         	jl	0x3f
-;;   35:	 f3480f2ac1           	cvtsi2ss	xmm0, rcx
-;;      	 e91a000000           	jmp	0x59
-;;   3f:	 4989cb               	mov	r11, rcx
+;;      	 0f8c0a000000         	jl	0x42
+;;   38:	 f3480f2ac1           	cvtsi2ss	xmm0, rcx
+;;      	 e91a000000           	jmp	0x5c
+;;   42:	 4989cb               	mov	r11, rcx
 ;;      	 49c1eb01             	shr	r11, 1
 ;;      	 4889c8               	mov	rax, rcx
 ;;      	 4883e001             	and	rax, 1
@@ -34,4 +35,4 @@
 ;;      	 4883c410             	add	rsp, 0x10
 ;;      	 5d                   	pop	rbp
 ;;      	 c3                   	ret	
-;;   5f:	 0f0b                 	ud2	
+;;   62:	 0f0b                 	ud2	
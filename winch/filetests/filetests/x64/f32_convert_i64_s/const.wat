@@ -8,15 +8,16 @@
 )
 ;;      	 55                   	push	rbp
 ;;      	 4889e5               	mov	rbp, rsp
+;;      	 4989fe               	mov	r14, rdi
 ;;      	 4883ec08             	sub	rsp, 8
 ;;      	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
 ;;      	 4d8b1b               	mov	r11, qword ptr [r11]
 ;;      	 4939e3               	cmp	r11, rsp
-;;      	 0f8716000000         	ja	0x2e
-;;   18:	 4c893424             	mov	qword ptr [rsp], r14
+;;      	 0f8716000000         	ja	0x31
+;;   1b:	 4c893424             	mov	qword ptr [rsp], r14
 ;;      	 48c7c001000000       	mov	rax, 1
 ;;      	 f3480f2ac0           	cvtsi2ss	xmm0, rax
 ;;      	 4883c408             	add	rsp, 8
 ;;      	 5d                   	pop	rbp
 ;;      	 c3                   	ret	
-;;   2e:	 0f0b                 	ud2	
+;;   31:	 0f0b                 	ud2	
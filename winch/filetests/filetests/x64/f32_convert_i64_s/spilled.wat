@@ -10,19 +10,20 @@
 )
 ;;      	 55                   	push	rbp
 ;;      	 4889e5               	mov	rbp, rsp
+;;      	 4989fe               	mov	r14, rdi
 ;;      	 4883ec08             	sub	rsp, 8
 ;;      	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
 ;;      	 4d8b1b               	mov	r11, qword ptr [r11]
 ;;      	 4939e3               	cmp	r11, rsp
-;;      	 0f8728000000         	ja	0x40
-;;   18:	 4c893424             	mov	qword ptr [rsp], r14
+;;      	 0f8728000000         	ja	0x43
+;;   1b:	 4c893424             	mov	qword ptr [rsp], r14
 ;;      	 48c7c001000000       	mov	rax, 1
 ;;      	 f3480f2ac0           	cvtsi2ss	xmm0, rax
-;;      	 4883ec04             	sub	rsp, 4
+;;      	 4883ec08             	sub	rsp, 8
 ;;      	 f30f110424           	movss	dword ptr [rsp], xmm0
 ;;      	 f30f100424           	movss	xmm0, dword ptr [rsp]
-;;      	 4883c404             	add	rsp, 4
+;;      	 4883c408             	add	rsp, 8
 ;;      	 4883c408             	add	rsp, 8
 ;;      	 5d                   	pop	rbp
 ;;      	 c3                   	ret	
-;;   40:	 0f0b                 	ud2	
+;;   43:	 0f0b                 	ud2	
@@ -8,19 +8,20 @@
 )
 ;;      	 55                   	push	rbp
 ;;      	 4889e5               	mov	rbp, rsp
+;;      	 4989fe               	mov	r14, rdi
 ;;      	 4883ec10             	sub	rsp, 0x10
 ;;      	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
 ;;      	 4d8b1b               	mov	r11, qword ptr [r11]
 ;;      	 4939e3               	cmp	r11, rsp
-;;      	 0f8742000000         	ja	0x5a
-;;   18:	 48897c2408           	mov	qword ptr [rsp + 8], rdi
+;;      	 0f8742000000         	ja	0x5d
+;;   1b:	 4889542408           	mov	qword ptr [rsp + 8], rdx
 ;;      	 4c893424             	mov	qword ptr [rsp], r14
 ;;      	 488b4c2408           	mov	rcx, qword ptr [rsp + 8]
 ;;      	 4883f900             	cmp	rcx, 0
-;;      	 0f8c0a000000         	jl	0x3a
-;;   30:	 f3480f2ac1           	cvtsi2ss	xmm0, rcx
-;;      	 e91a000000           	jmp	0x54
-;;   3a:	 4989cb               	mov	r11, rcx
+;;      	 0f8c0a000000         	jl	0x3d
+;;   33:	 f3480f2ac1           	cvtsi2ss	xmm0, rcx
+;;      	 e91a000000           	jmp	0x57
+;;   3d:	 4989cb               	mov	r11, rcx
 ;;      	 49c1eb01             	shr	r11, 1
 ;;      	 4889c8               	mov	rax, rcx
 ;;      	 4883e001             	and	rax, 1
@@ -30,4 +31,4 @@
 ;;      	 4883c410             	add	rsp, 0x10
 ;;      	 5d                   	pop	rbp
 ;;      	 c3                   	ret	
-;;   5a:	 0f0b                 	ud2	
+;;   5d:	 0f0b                 	ud2	
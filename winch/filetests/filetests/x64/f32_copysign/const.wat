@@ -9,14 +9,15 @@
 )
 ;;      	 55                   	push	rbp
 ;;      	 4889e5               	mov	rbp, rsp
+;;      	 4989fe               	mov	r14, rdi
 ;;      	 4883ec08             	sub	rsp, 8
 ;;      	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
 ;;      	 4d8b1b               	mov	r11, qword ptr [r11]
 ;;      	 4939e3               	cmp	r11, rsp
-;;      	 0f8737000000         	ja	0x4f
-;;   18:	 4c893424             	mov	qword ptr [rsp], r14
-;;      	 f30f100534000000     	movss	xmm0, dword ptr [rip + 0x34]
-;;      	 f30f100d34000000     	movss	xmm1, dword ptr [rip + 0x34]
+;;      	 0f8737000000         	ja	0x52
+;;   1b:	 4c893424             	mov	qword ptr [rsp], r14
+;;      	 f30f100531000000     	movss	xmm0, dword ptr [rip + 0x31]
+;;      	 f30f100d31000000     	movss	xmm1, dword ptr [rip + 0x31]
 ;;      	 41bb00000080         	mov	r11d, 0x80000000
 ;;      	 66450f6efb           	movd	xmm15, r11d
 ;;      	 410f54c7             	andps	xmm0, xmm15
@@ -27,12 +28,10 @@
 ;;      	 4883c408             	add	rsp, 8
 ;;      	 5d                   	pop	rbp
 ;;      	 c3                   	ret	
-;;   4f:	 0f0b                 	ud2	
-;;   51:	 0000                 	add	byte ptr [rax], al
-;;   53:	 0000                 	add	byte ptr [rax], al
-;;   55:	 0000                 	add	byte ptr [rax], al
-;;   57:	 00cd                 	add	ch, cl
-;;   59:	 cc                   	int3	
+;;   52:	 0f0b                 	ud2	
+;;   54:	 0000                 	add	byte ptr [rax], al
+;;   56:	 0000                 	add	byte ptr [rax], al
+;;   58:	 cdcc                 	int	0xcc
 ;;   5a:	 0c40                 	or	al, 0x40
 ;;   5c:	 0000                 	add	byte ptr [rax], al
 ;;   5e:	 0000                 	add	byte ptr [rax], al
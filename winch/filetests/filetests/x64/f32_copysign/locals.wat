@@ -18,17 +18,18 @@
 )
 ;;      	 55                   	push	rbp
 ;;      	 4889e5               	mov	rbp, rsp
+;;      	 4989fe               	mov	r14, rdi
 ;;      	 4883ec10             	sub	rsp, 0x10
 ;;      	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
 ;;      	 4d8b1b               	mov	r11, qword ptr [r11]
 ;;      	 4939e3               	cmp	r11, rsp
-;;      	 0f8758000000         	ja	0x70
-;;   18:	 48c744240800000000   	
+;;      	 0f8758000000         	ja	0x73
+;;   1b:	 48c744240800000000   	
 ;; 				mov	qword ptr [rsp + 8], 0
 ;;      	 4c893424             	mov	qword ptr [rsp], r14
-;;      	 f30f10054b000000     	movss	xmm0, dword ptr [rip + 0x4b]
+;;      	 f30f100548000000     	movss	xmm0, dword ptr [rip + 0x48]
 ;;      	 f30f1144240c         	movss	dword ptr [rsp + 0xc], xmm0
-;;      	 f30f100545000000     	movss	xmm0, dword ptr [rip + 0x45]
+;;      	 f30f100542000000     	movss	xmm0, dword ptr [rip + 0x42]
 ;;      	 f30f11442408         	movss	dword ptr [rsp + 8], xmm0
 ;;      	 f30f10442408         	movss	xmm0, dword ptr [rsp + 8]
 ;;      	 f30f104c240c         	movss	xmm1, dword ptr [rsp + 0xc]
@@ -42,8 +43,7 @@
 ;;      	 4883c410             	add	rsp, 0x10
 ;;      	 5d                   	pop	rbp
 ;;      	 c3                   	ret	
-;;   70:	 0f0b                 	ud2	
-;;   72:	 0000                 	add	byte ptr [rax], al
-;;   74:	 0000                 	add	byte ptr [rax], al
-;;   76:	 0000                 	add	byte ptr [rax], al
-;;   78:	 cdcc                 	int	0xcc
+;;   73:	 0f0b                 	ud2	
+;;   75:	 0000                 	add	byte ptr [rax], al
+;;   77:	 00cd                 	add	ch, cl
+;;   79:	 cc                   	int3	
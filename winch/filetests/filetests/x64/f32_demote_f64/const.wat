@@ -8,18 +8,22 @@
 )
 ;;      	 55                   	push	rbp
 ;;      	 4889e5               	mov	rbp, rsp
+;;      	 4989fe               	mov	r14, rdi
 ;;      	 4883ec08             	sub	rsp, 8
 ;;      	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
 ;;      	 4d8b1b               	mov	r11, qword ptr [r11]
 ;;      	 4939e3               	cmp	r11, rsp
-;;      	 0f8716000000         	ja	0x2e
-;;   18:	 4c893424             	mov	qword ptr [rsp], r14
-;;      	 f20f10050c000000     	movsd	xmm0, qword ptr [rip + 0xc]
+;;      	 0f8716000000         	ja	0x31
+;;   1b:	 4c893424             	mov	qword ptr [rsp], r14
+;;      	 f20f100511000000     	movsd	xmm0, qword ptr [rip + 0x11]
 ;;      	 f20f5ac0             	cvtsd2ss	xmm0, xmm0
 ;;      	 4883c408             	add	rsp, 8
 ;;      	 5d                   	pop	rbp
 ;;      	 c3                   	ret	
-;;   2e:	 0f0b                 	ud2	
-;;   30:	 0000                 	add	byte ptr [rax], al
-;;   32:	 0000                 	add	byte ptr [rax], al
-;;   34:	 0000                 	add	byte ptr [rax], al
+;;   31:	 0f0b                 	ud2	
+;;   33:	 0000                 	add	byte ptr [rax], al
+;;   35:	 0000                 	add	byte ptr [rax], al
+;;   37:	 0000                 	add	byte ptr [rax], al
+;;   39:	 0000                 	add	byte ptr [rax], al
+;;   3b:	 0000                 	add	byte ptr [rax], al
+;;   3d:	 00f0                 	add	al, dh
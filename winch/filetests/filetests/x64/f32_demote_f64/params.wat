@@ -8,16 +8,17 @@
 )
 ;;      	 55                   	push	rbp
 ;;      	 4889e5               	mov	rbp, rsp
+;;      	 4989fe               	mov	r14, rdi
 ;;      	 4883ec10             	sub	rsp, 0x10
 ;;      	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
 ;;      	 4d8b1b               	mov	r11, qword ptr [r11]
 ;;      	 4939e3               	cmp	r11, rsp
-;;      	 0f871a000000         	ja	0x32
-;;   18:	 f20f11442408         	movsd	qword ptr [rsp + 8], xmm0
+;;      	 0f871a000000         	ja	0x35
+;;   1b:	 f20f11442408         	movsd	qword ptr [rsp + 8], xmm0
 ;;      	 4c893424             	mov	qword ptr [rsp], r14
 ;;      	 f20f10442408         	movsd	xmm0, qword ptr [rsp + 8]
 ;;      	 f20f5ac0             	cvtsd2ss	xmm0, xmm0
 ;;      	 4883c410             	add	rsp, 0x10
 ;;      	 5d                   	pop	rbp
 ;;      	 c3                   	ret	
-;;   32:	 0f0b                 	ud2	
+;;   35:	 0f0b                 	ud2	
@@ -18,17 +18,18 @@
 )
 ;;      	 55                   	push	rbp
 ;;      	 4889e5               	mov	rbp, rsp
+;;      	 4989fe               	mov	r14, rdi
 ;;      	 4883ec10             	sub	rsp, 0x10
 ;;      	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
 ;;      	 4d8b1b               	mov	r11, qword ptr [r11]
 ;;      	 4939e3               	cmp	r11, rsp
-;;      	 0f8742000000         	ja	0x5a
-;;   18:	 48c744240800000000   	
+;;      	 0f8742000000         	ja	0x5d
+;;   1b:	 48c744240800000000   	
 ;; 				mov	qword ptr [rsp + 8], 0
 ;;      	 4c893424             	mov	qword ptr [rsp], r14
-;;      	 f30f100533000000     	movss	xmm0, dword ptr [rip + 0x33]
+;;      	 f30f100530000000     	movss	xmm0, dword ptr [rip + 0x30]
 ;;      	 f30f1144240c         	movss	dword ptr [rsp + 0xc], xmm0
-;;      	 f30f10052d000000     	movss	xmm0, dword ptr [rip + 0x2d]
+;;      	 f30f10052a000000     	movss	xmm0, dword ptr [rip + 0x2a]
 ;;      	 f30f11442408         	movss	dword ptr [rsp + 8], xmm0
 ;;      	 f30f10442408         	movss	xmm0, dword ptr [rsp + 8]
 ;;      	 f30f104c240c         	movss	xmm1, dword ptr [rsp + 0xc]
@@ -37,7 +38,6 @@
 ;;      	 4883c410             	add	rsp, 0x10
 ;;      	 5d                   	pop	rbp
 ;;      	 c3                   	ret	
-;;   5a:	 0f0b                 	ud2	
-;;   5c:	 0000                 	add	byte ptr [rax], al
-;;   5e:	 0000                 	add	byte ptr [rax], al
-;;   60:	 cdcc                 	int	0xcc
+;;   5d:	 0f0b                 	ud2	
+;;   5f:	 00cd                 	add	ch, cl
+;;   61:	 cc                   	int3	
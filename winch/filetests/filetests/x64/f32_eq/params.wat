@@ -9,12 +9,13 @@
 )
 ;;      	 55                   	push	rbp
 ;;      	 4889e5               	mov	rbp, rsp
+;;      	 4989fe               	mov	r14, rdi
 ;;      	 4883ec10             	sub	rsp, 0x10
 ;;      	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
 ;;      	 4d8b1b               	mov	r11, qword ptr [r11]
 ;;      	 4939e3               	cmp	r11, rsp
-;;      	 0f873b000000         	ja	0x53
-;;   18:	 f30f1144240c         	movss	dword ptr [rsp + 0xc], xmm0
+;;      	 0f873b000000         	ja	0x56
+;;   1b:	 f30f1144240c         	movss	dword ptr [rsp + 0xc], xmm0
 ;;      	 f30f114c2408         	movss	dword ptr [rsp + 8], xmm1
 ;;      	 4c893424             	mov	qword ptr [rsp], r14
 ;;      	 f30f10442408         	movss	xmm0, dword ptr [rsp + 8]
@@ -28,4 +29,4 @@
 ;;      	 4883c410             	add	rsp, 0x10
 ;;      	 5d                   	pop	rbp
 ;;      	 c3                   	ret	
-;;   53:	 0f0b                 	ud2	
+;;   56:	 0f0b                 	ud2	
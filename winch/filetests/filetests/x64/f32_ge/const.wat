@@ -9,14 +9,15 @@
 )
 ;;      	 55                   	push	rbp
 ;;      	 4889e5               	mov	rbp, rsp
+;;      	 4989fe               	mov	r14, rdi
 ;;      	 4883ec08             	sub	rsp, 8
 ;;      	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
 ;;      	 4d8b1b               	mov	r11, qword ptr [r11]
 ;;      	 4939e3               	cmp	r11, rsp
-;;      	 0f8733000000         	ja	0x4b
-;;   18:	 4c893424             	mov	qword ptr [rsp], r14
-;;      	 f30f10052c000000     	movss	xmm0, dword ptr [rip + 0x2c]
-;;      	 f30f100d2c000000     	movss	xmm1, dword ptr [rip + 0x2c]
+;;      	 0f8733000000         	ja	0x4e
+;;   1b:	 4c893424             	mov	qword ptr [rsp], r14
+;;      	 f30f100529000000     	movss	xmm0, dword ptr [rip + 0x29]
+;;      	 f30f100d29000000     	movss	xmm1, dword ptr [rip + 0x29]
 ;;      	 0f2ec8               	ucomiss	xmm1, xmm0
 ;;      	 b800000000           	mov	eax, 0
 ;;      	 400f93c0             	setae	al
@@ -26,10 +27,8 @@
 ;;      	 4883c408             	add	rsp, 8
 ;;      	 5d                   	pop	rbp
 ;;      	 c3                   	ret	
-;;   4b:	 0f0b                 	ud2	
-;;   4d:	 0000                 	add	byte ptr [rax], al
-;;   4f:	 00cd                 	add	ch, cl
-;;   51:	 cc                   	int3	
+;;   4e:	 0f0b                 	ud2	
+;;   50:	 cdcc                 	int	0xcc
 ;;   52:	 0c40                 	or	al, 0x40
 ;;   54:	 0000                 	add	byte ptr [rax], al
 ;;   56:	 0000                 	add	byte ptr [rax], al
@@ -18,17 +18,18 @@
 )
 ;;      	 55                   	push	rbp
 ;;      	 4889e5               	mov	rbp, rsp
+;;      	 4989fe               	mov	r14, rdi
 ;;      	 4883ec10             	sub	rsp, 0x10
 ;;      	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
 ;;      	 4d8b1b               	mov	r11, qword ptr [r11]
 ;;      	 4939e3               	cmp	r11, rsp
-;;      	 0f8754000000         	ja	0x6c
-;;   18:	 48c744240800000000   	
+;;      	 0f8754000000         	ja	0x6f
+;;   1b:	 48c744240800000000   	
 ;; 				mov	qword ptr [rsp + 8], 0
 ;;      	 4c893424             	mov	qword ptr [rsp], r14
-;;      	 f30f100543000000     	movss	xmm0, dword ptr [rip + 0x43]
+;;      	 f30f100548000000     	movss	xmm0, dword ptr [rip + 0x48]
 ;;      	 f30f1144240c         	movss	dword ptr [rsp + 0xc], xmm0
-;;      	 f30f10053d000000     	movss	xmm0, dword ptr [rip + 0x3d]
+;;      	 f30f100542000000     	movss	xmm0, dword ptr [rip + 0x42]
 ;;      	 f30f11442408         	movss	dword ptr [rsp + 8], xmm0
 ;;      	 f30f10442408         	movss	xmm0, dword ptr [rsp + 8]
 ;;      	 f30f104c240c         	movss	xmm1, dword ptr [rsp + 0xc]
@@ -41,6 +42,9 @@
 ;;      	 4883c410             	add	rsp, 0x10
 ;;      	 5d                   	pop	rbp
 ;;      	 c3                   	ret	
-;;   6c:	 0f0b                 	ud2	
-;;   6e:	 0000                 	add	byte ptr [rax], al
-;;   70:	 cdcc                 	int	0xcc
+;;   6f:	 0f0b                 	ud2	
+;;   71:	 0000                 	add	byte ptr [rax], al
+;;   73:	 0000                 	add	byte ptr [rax], al
+;;   75:	 0000                 	add	byte ptr [rax], al
+;;   77:	 00cd                 	add	ch, cl
+;;   79:	 cc                   	int3	
@@ -9,30 +9,30 @@
 )
 ;;      	 55                   	push	rbp
 ;;      	 4889e5               	mov	rbp, rsp
+;;      	 4989fe               	mov	r14, rdi
 ;;      	 4883ec08             	sub	rsp, 8
 ;;      	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
 ;;      	 4d8b1b               	mov	r11, qword ptr [r11]
 ;;      	 4939e3               	cmp	r11, rsp
-;;      	 0f8742000000         	ja	0x5a
-;;   18:	 4c893424             	mov	qword ptr [rsp], r14
-;;      	 f30f10053c000000     	movss	xmm0, dword ptr [rip + 0x3c]
-;;      	 f30f100d3c000000     	movss	xmm1, dword ptr [rip + 0x3c]
+;;      	 0f8742000000         	ja	0x5d
+;;   1b:	 4c893424             	mov	qword ptr [rsp], r14
+;;      	 f30f100539000000     	movss	xmm0, dword ptr [rip + 0x39]
+;;      	 f30f100d39000000     	movss	xmm1, dword ptr [rip + 0x39]
 ;;      	 0f2ec8               	ucomiss	xmm1, xmm0
-;;      	 0f8518000000         	jne	0x4d
-;;      	 0f8a08000000         	jp	0x43
-;;   3b:	 0f54c8               	andps	xmm1, xmm0
-;;      	 e90e000000           	jmp	0x51
-;;   43:	 f30f58c8             	addss	xmm1, xmm0
-;;      	 0f8a04000000         	jp	0x51
-;;   4d:	 f30f5fc8             	maxss	xmm1, xmm0
+;;      	 0f8518000000         	jne	0x50
+;;      	 0f8a08000000         	jp	0x46
+;;   3e:	 0f54c8               	andps	xmm1, xmm0
+;;      	 e90e000000           	jmp	0x54
+;;   46:	 f30f58c8             	addss	xmm1, xmm0
+;;      	 0f8a04000000         	jp	0x54
+;;   50:	 f30f5fc8             	maxss	xmm1, xmm0
 ;;      	 0f28c1               	movaps	xmm0, xmm1
 ;;      	 4883c408             	add	rsp, 8
 ;;      	 5d                   	pop	rbp
 ;;      	 c3                   	ret	
-;;   5a:	 0f0b                 	ud2	
-;;   5c:	 0000                 	add	byte ptr [rax], al
-;;   5e:	 0000                 	add	byte ptr [rax], al
-;;   60:	 cdcc                 	int	0xcc
+;;   5d:	 0f0b                 	ud2	
+;;   5f:	 00cd                 	add	ch, cl
+;;   61:	 cc                   	int3	
 ;;   62:	 0c40                 	or	al, 0x40
 ;;   64:	 0000                 	add	byte ptr [rax], al
 ;;   66:	 0000                 	add	byte ptr [rax], al
@@ -8,22 +8,22 @@
 )
 ;;      	 55                   	push	rbp
 ;;      	 4889e5               	mov	rbp, rsp
+;;      	 4989fe               	mov	r14, rdi
 ;;      	 4883ec08             	sub	rsp, 8
 ;;      	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
 ;;      	 4d8b1b               	mov	r11, qword ptr [r11]
 ;;      	 4939e3               	cmp	r11, rsp
-;;      	 0f8721000000         	ja	0x39
-;;   18:	 4c893424             	mov	qword ptr [rsp], r14
-;;      	 f30f10051c000000     	movss	xmm0, dword ptr [rip + 0x1c]
+;;      	 0f8721000000         	ja	0x3c
+;;   1b:	 4c893424             	mov	qword ptr [rsp], r14
+;;      	 f30f100519000000     	movss	xmm0, dword ptr [rip + 0x19]
 ;;      	 41bb00000080         	mov	r11d, 0x80000000
 ;;      	 66450f6efb           	movd	xmm15, r11d
 ;;      	 410f57c7             	xorps	xmm0, xmm15
 ;;      	 4883c408             	add	rsp, 8
 ;;      	 5d                   	pop	rbp
 ;;      	 c3                   	ret	
-;;   39:	 0f0b                 	ud2	
-;;   3b:	 0000                 	add	byte ptr [rax], al
-;;   3d:	 0000                 	add	byte ptr [rax], al
-;;   3f:	 00c3                 	add	bl, al
+;;   3c:	 0f0b                 	ud2	
+;;   3e:	 0000                 	add	byte ptr [rax], al
+;;   40:	 c3                   	ret	
 ;;   41:	 f5                   	cmc	
 ;;   42:	 a8bf                 	test	al, 0xbf
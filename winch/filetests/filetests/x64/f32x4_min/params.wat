@@ -0,0 +1,37 @@
+;;! target = "x86_64"
+;;! flags = ["has_sse3", "has_ssse3", "has_sse41", "has_sse42"]
+
+(module
+    (func (param v128 v128) (result v128)
+        (local.get 0)
+        (local.get 1)
+        (f32x4.min)
+    )
+)
+;;      	 55                   	push	rbp
+;;      	 4889e5               	mov	rbp, rsp
+;;      	 4989fe               	mov	r14, rdi
+;;      	 4883ec28             	sub	rsp, 0x28
+;;      	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;      	 4d8b1b               	mov	r11, qword ptr [r11]
+;;      	 4939e3               	cmp	r11, rsp
+;;      	 0f874e000000         	ja	0x69
+;;   1b:	 f30f7f442418         	movdqu	xmmword ptr [rsp + 0x18], xmm0
+;;      	 f30f7f4c2408         	movdqu	xmmword ptr [rsp + 8], xmm1
+;;      	 4c893424             	mov	qword ptr [rsp], r14
+;;      	 f30f6f442408         	movdqu	xmm0, xmmword ptr [rsp + 8]
+;;      	 f30f6f4c2418         	movdqu	xmm1, xmmword ptr [rsp + 0x18]
+;;      	 66440f6ff8           	movdqa	xmm15, xmm0
+;;      	 440f5df9             	minps	xmm15, xmm1
+;;      	 0f5dc8               	minps	xmm1, xmm0
+;;      	 410f56cf             	orps	xmm1, xmm15
+;;      	 440fc2f903           	cmpunordps	xmm15, xmm1
+;;      	 410f56cf             	orps	xmm1, xmm15
+;;      	 66410f72d70a         	psrld	xmm15, 0xa
+;;      	 440f55f9             	andnps	xmm15, xmm1
+;;      	 66410f6fcf           	movdqa	xmm1, xmm15
+;;      	 660f6fc1             	movdqa	xmm0, xmm1
+;;      	 4883c428             	add	rsp, 0x28
+;;      	 5d                   	pop	rbp
+;;      	 c3                   	ret	
+;;   69:	 0f0b                 	ud2	
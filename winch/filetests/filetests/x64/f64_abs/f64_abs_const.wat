@@ -8,13 +8,14 @@
 )
 ;;      	 55                   	push	rbp
 ;;      	 4889e5               	mov	rbp, rsp
+;;      	 4989fe               	mov	r14, rdi
 ;;      	 4883ec08             	sub	rsp, 8
 ;;      	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
 ;;      	 4d8b1b               	mov	r11, qword ptr [r11]
 ;;      	 4939e3               	cmp	r11, rsp
-;;      	 0f8726000000         	ja	0x3e
-;;   18:	 4c893424             	mov	qword ptr [rsp], r14
-;;      	 f20f10051c000000     	movsd	xmm0, qword ptr [rip + 0x1c]
+;;      	 0f8726000000         	ja	0x41
+;;   1b:	 4c893424             	mov	qword ptr [rsp], r14
+;;      	 f20f100521000000     	movsd	xmm0, qword ptr [rip + 0x21]
 ;;      	 49bbffffffffffffff7f 	
 ;; 				movabs	r11, 0x7fffffffffffffff
 ;;      	 664d0f6efb           	movq	xmm15, r11
@@ -22,4 +23,9 @@
 ;;      	 4883c408             	add	rsp, 8
 ;;      	 5d                   	pop	rbp
 ;;      	 c3                   	ret	
-;;   3e:	 0f0b                 	ud2	
+;;   41:	 0f0b                 	ud2	
+;;   43:	 0000                 	add	byte ptr [rax], al
+;;   45:	 0000                 	add	byte ptr [rax], al
+;;   47:	 001f                 	add	byte ptr [rdi], bl
+;;   49:	 85eb                 	test	ebx, ebp
+;;   4b:	 51                   	push	rcx
@@ -9,18 +9,20 @@
 )
 ;;      	 55                   	push	rbp
 ;;      	 4889e5               	mov	rbp, rsp
+;;      	 4989fe               	mov	r14, rdi
 ;;      	 4883ec08             	sub	rsp, 8
 ;;      	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
 ;;      	 4d8b1b               	mov	r11, qword ptr [r11]
 ;;      	 4939e3               	cmp	r11, rsp
-;;      	 0f8718000000         	ja	0x30
-;;   18:	 4c893424             	mov	qword ptr [rsp], r14
-;;      	 f20f100514000000     	movsd	xmm0, qword ptr [rip + 0x14]
+;;      	 0f8718000000         	ja	0x33
+;;   1b:	 4c893424             	mov	qword ptr [rsp], r14
+;;      	 f20f100511000000     	movsd	xmm0, qword ptr [rip + 0x11]
 ;;      	 660f3a0bc002         	roundsd	xmm0, xmm0, 2
 ;;      	 4883c408             	add	rsp, 8
 ;;      	 5d                   	pop	rbp
 ;;      	 c3                   	ret	
-;;   30:	 0f0b                 	ud2	
-;;   32:	 0000                 	add	byte ptr [rax], al
-;;   34:	 0000                 	add	byte ptr [rax], al
-;;   36:	 0000                 	add	byte ptr [rax], al
+;;   33:	 0f0b                 	ud2	
+;;   35:	 0000                 	add	byte ptr [rax], al
+;;   37:	 001f                 	add	byte ptr [rdi], bl
+;;   39:	 85eb                 	test	ebx, ebp
+;;   3b:	 51                   	push	rcx
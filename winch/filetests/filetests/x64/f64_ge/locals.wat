@@ -18,18 +18,19 @@
 )
 ;;      	 55                   	push	rbp
 ;;      	 4889e5               	mov	rbp, rsp
+;;      	 4989fe               	mov	r14, rdi
 ;;      	 4883ec18             	sub	rsp, 0x18
 ;;      	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
 ;;      	 4d8b1b               	mov	r11, qword ptr [r11]
 ;;      	 4939e3               	cmp	r11, rsp
-;;      	 0f8759000000         	ja	0x71
-;;   18:	 4531db               	xor	r11d, r11d
+;;      	 0f8759000000         	ja	0x74
+;;   1b:	 4531db               	xor	r11d, r11d
 ;;      	 4c895c2410           	mov	qword ptr [rsp + 0x10], r11
 ;;      	 4c895c2408           	mov	qword ptr [rsp + 8], r11
 ;;      	 4c893424             	mov	qword ptr [rsp], r14
-;;      	 f20f100547000000     	movsd	xmm0, qword ptr [rip + 0x47]
+;;      	 f20f100544000000     	movsd	xmm0, qword ptr [rip + 0x44]
 ;;      	 f20f11442410         	movsd	qword ptr [rsp + 0x10], xmm0
-;;      	 f20f100541000000     	movsd	xmm0, qword ptr [rip + 0x41]
+;;      	 f20f10053e000000     	movsd	xmm0, qword ptr [rip + 0x3e]
 ;;      	 f20f11442408         	movsd	qword ptr [rsp + 8], xmm0
 ;;      	 f20f10442408         	movsd	xmm0, qword ptr [rsp + 8]
 ;;      	 f20f104c2410         	movsd	xmm1, qword ptr [rsp + 0x10]
@@ -42,9 +43,5 @@
 ;;      	 4883c418             	add	rsp, 0x18
 ;;      	 5d                   	pop	rbp
 ;;      	 c3                   	ret	
-;;   71:	 0f0b                 	ud2	
-;;   73:	 0000                 	add	byte ptr [rax], al
-;;   75:	 0000                 	add	byte ptr [rax], al
-;;   77:	 009a99999999         	add	byte ptr [rdx - 0x66666667], bl
-;;   7d:	 99                   	cdq	
-;;   7e:	 f1                   	int1	
+;;   74:	 0f0b                 	ud2	
+;;   76:	 0000                 	add	byte ptr [rax], al
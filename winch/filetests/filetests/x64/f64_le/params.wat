@@ -18,18 +18,19 @@
 )
 ;;      	 55                   	push	rbp
 ;;      	 4889e5               	mov	rbp, rsp
+;;      	 4989fe               	mov	r14, rdi
 ;;      	 4883ec18             	sub	rsp, 0x18
 ;;      	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
 ;;      	 4d8b1b               	mov	r11, qword ptr [r11]
 ;;      	 4939e3               	cmp	r11, rsp
-;;      	 0f874c000000         	ja	0x64
-;;   18:	 4531db               	xor	r11d, r11d
+;;      	 0f874c000000         	ja	0x67
+;;   1b:	 4531db               	xor	r11d, r11d
 ;;      	 4c895c2410           	mov	qword ptr [rsp + 0x10], r11
 ;;      	 4c895c2408           	mov	qword ptr [rsp + 8], r11
 ;;      	 4c893424             	mov	qword ptr [rsp], r14
-;;      	 f20f100537000000     	movsd	xmm0, qword ptr [rip + 0x37]
+;;      	 f20f10053c000000     	movsd	xmm0, qword ptr [rip + 0x3c]
 ;;      	 f20f11442410         	movsd	qword ptr [rsp + 0x10], xmm0
-;;      	 f20f100531000000     	movsd	xmm0, qword ptr [rip + 0x31]
+;;      	 f20f100536000000     	movsd	xmm0, qword ptr [rip + 0x36]
 ;;      	 f20f11442408         	movsd	qword ptr [rsp + 8], xmm0
 ;;      	 f20f10442408         	movsd	xmm0, qword ptr [rsp + 8]
 ;;      	 f20f104c2410         	movsd	xmm1, qword ptr [rsp + 0x10]
@@ -39,5 +40,10 @@
 ;;      	 4883c418             	add	rsp, 0x18
 ;;      	 5d                   	pop	rbp
 ;;      	 c3                   	ret	
-;;   64:	 0f0b                 	ud2	
-;;   66:	 0000                 	add	byte ptr [rax], al
+;;   67:	 0f0b                 	ud2	
+;;   69:	 0000                 	add	byte ptr [rax], al
+;;   6b:	 0000                 	add	byte ptr [rax], al
+;;   6d:	 0000                 	add	byte ptr [rax], al
+;;   6f:	 009a99999999         	add	byte ptr [rdx - 0x66666667], bl
+;;   75:	 99                   	cdq	
+;;   76:	 f1                   	int1	
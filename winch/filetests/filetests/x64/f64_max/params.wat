@@ -9,26 +9,27 @@
 )
 ;;      	 55                   	push	rbp
 ;;      	 4889e5               	mov	rbp, rsp
+;;      	 4989fe               	mov	r14, rdi
 ;;      	 4883ec18             	sub	rsp, 0x18
 ;;      	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
 ;;      	 4d8b1b               	mov	r11, qword ptr [r11]
 ;;      	 4939e3               	cmp	r11, rsp
-;;      	 0f874d000000         	ja	0x65
-;;   18:	 f20f11442410         	movsd	qword ptr [rsp + 0x10], xmm0
+;;      	 0f874d000000         	ja	0x68
+;;   1b:	 f20f11442410         	movsd	qword ptr [rsp + 0x10], xmm0
 ;;      	 f20f114c2408         	movsd	qword ptr [rsp + 8], xmm1
 ;;      	 4c893424             	mov	qword ptr [rsp], r14
 ;;      	 f20f10442408         	movsd	xmm0, qword ptr [rsp + 8]
 ;;      	 f20f104c2410         	movsd	xmm1, qword ptr [rsp + 0x10]
 ;;      	 660f2ec8             	ucomisd	xmm1, xmm0
-;;      	 0f8519000000         	jne	0x57
-;;      	 0f8a09000000         	jp	0x4d
-;;   44:	 660f54c8             	andpd	xmm1, xmm0
-;;      	 e90e000000           	jmp	0x5b
-;;   4d:	 f20f58c8             	addsd	xmm1, xmm0
-;;      	 0f8a04000000         	jp	0x5b
-;;   57:	 f20f5fc8             	maxsd	xmm1, xmm0
+;;      	 0f8519000000         	jne	0x5a
+;;      	 0f8a09000000         	jp	0x50
+;;   47:	 660f54c8             	andpd	xmm1, xmm0
+;;      	 e90e000000           	jmp	0x5e
+;;   50:	 f20f58c8             	addsd	xmm1, xmm0
+;;      	 0f8a04000000         	jp	0x5e
+;;   5a:	 f20f5fc8             	maxsd	xmm1, xmm0
 ;;      	 660f28c1             	movapd	xmm0, xmm1
 ;;      	 4883c418             	add	rsp, 0x18
 ;;      	 5d                   	pop	rbp
 ;;      	 c3                   	ret	
-;;   65:	 0f0b                 	ud2	
+;;   68:	 0f0b                 	ud2	
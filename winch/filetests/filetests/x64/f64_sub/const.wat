@@ -9,19 +9,27 @@
 )
 ;;      	 55                   	push	rbp
 ;;      	 4889e5               	mov	rbp, rsp
+;;      	 4989fe               	mov	r14, rdi
 ;;      	 4883ec08             	sub	rsp, 8
 ;;      	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
 ;;      	 4d8b1b               	mov	r11, qword ptr [r11]
 ;;      	 4939e3               	cmp	r11, rsp
-;;      	 0f8722000000         	ja	0x3a
-;;   18:	 4c893424             	mov	qword ptr [rsp], r14
-;;      	 f20f10051c000000     	movsd	xmm0, qword ptr [rip + 0x1c]
-;;      	 f20f100d1c000000     	movsd	xmm1, qword ptr [rip + 0x1c]
+;;      	 0f8722000000         	ja	0x3d
+;;   1b:	 4c893424             	mov	qword ptr [rsp], r14
+;;      	 f20f100519000000     	movsd	xmm0, qword ptr [rip + 0x19]
+;;      	 f20f100d19000000     	movsd	xmm1, qword ptr [rip + 0x19]
 ;;      	 f20f5cc8             	subsd	xmm1, xmm0
 ;;      	 660f28c1             	movapd	xmm0, xmm1
 ;;      	 4883c408             	add	rsp, 8
 ;;      	 5d                   	pop	rbp
 ;;      	 c3                   	ret	
-;;   3a:	 0f0b                 	ud2	
-;;   3c:	 0000                 	add	byte ptr [rax], al
-;;   3e:	 0000                 	add	byte ptr [rax], al
+;;   3d:	 0f0b                 	ud2	
+;;   3f:	 009a99999999         	add	byte ptr [rdx - 0x66666667], bl
+;;   45:	 99                   	cdq	
+;;   46:	 01409a               	add	dword ptr [rax - 0x66], eax
+;;   49:	 99                   	cdq	
+;;   4a:	 99                   	cdq	
+;;   4b:	 99                   	cdq	
+;;   4c:	 99                   	cdq	
+;;   4d:	 99                   	cdq	
+;;   4e:	 f1                   	int1	
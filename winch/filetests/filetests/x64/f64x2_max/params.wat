@@ -0,0 +1,40 @@
+;;! target = "x86_64"
+;;! flags = ["has_sse3", "has_ssse3", "has_sse41", "has_sse42"]
+
+(module
+    (func (param v128 v128) (result v128)
+        (local.get 0)
+        (local.get 1)
+        (f64x2.max)
+    )
+)
+;;      	 55                   	push	rbp
+;;      	 4889e5               	mov	rbp, rsp
+;;      	 4989fe               	mov	r14, rdi
+;;      	 4883ec28             	sub	rsp, 0x28
+;;      	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;      	 4d8b1b               	mov	r11, qword ptr [r11]
+;;      	 4939e3               	cmp	r11, rsp
+;;      	 0f8760000000         	ja	0x7b
+;;   1b:	 f30f7f442418         	movdqu	xmmword ptr [rsp + 0x18], xmm0
+;;      	 f30f7f4c2408         	movdqu	xmmword ptr [rsp + 8], xmm1
+;;      	 4c893424             	mov	qword ptr [rsp], r14
+;;      	 f30f6f442408         	movdqu	xmm0, xmmword ptr [rsp + 8]
+;;      	 f30f6f4c2418         	movdqu	xmm1, xmmword ptr [rsp + 0x18]
+;;      	 66440f6ff8           	movdqa	xmm15, xmm0
+;;      	 66440f5ff9           	maxpd	xmm15, xmm1
+;;      	 660f5fc8             	maxpd	xmm1, xmm0
+;;      	 660f6fc1             	movdqa	xmm0, xmm1
+;;      	 66410f57c7           	xorpd	xmm0, xmm15
+;;      	 660f56c8             	orpd	xmm1, xmm0
+;;      	 66440f6ff9           	movdqa	xmm15, xmm1
+;;      	 660f5cc8             	subpd	xmm1, xmm0
+;;      	 66450fc2ff03         	cmpunordpd	xmm15, xmm15
+;;      	 66410f73d70d         	psrlq	xmm15, 0xd
+;;      	 66440f55f9           	andnpd	xmm15, xmm1
+;;      	 66410f6fcf           	movdqa	xmm1, xmm15
+;;      	 660f6fc1             	movdqa	xmm0, xmm1
+;;      	 4883c428             	add	rsp, 0x28
+;;      	 5d                   	pop	rbp
+;;      	 c3                   	ret	
+;;   7b:	 0f0b                 	ud2	
@@ -0,0 +1,29 @@
+;;! target = "x86_64"
+;;! flags = ["has_sse3", "has_ssse3", "has_sse41", "has_sse42"]
+
+(module
+    (func (param v128 f64) (result v128)
+        (local.get 0)
+        (local.get 1)
+        (f64x2.replace_lane 1)
+    )
+)
+;;      	 55                   	push	rbp
+;;      	 4889e5               	mov	rbp, rsp
+;;      	 4989fe               	mov	r14, rdi
+;;      	 4883ec20             	sub	rsp, 0x20
+;;      	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;      	 4d8b1b               	mov	r11, qword ptr [r11]
+;;      	 4939e3               	cmp	r11, rsp
+;;      	 0f8729000000         	ja	0x44
+;;   1b:	 f30f7f442410         	movdqu	xmmword ptr [rsp + 0x10], xmm0
+;;      	 f20f114c2408         	movsd	qword ptr [rsp + 8], xmm1
+;;      	 4c893424             	mov	qword ptr [rsp], r14
+;;      	 f20f10442408         	movsd	xmm0, qword ptr [rsp + 8]
+;;      	 f30f6f4c2410         	movdqu	xmm1, xmmword ptr [rsp + 0x10]
+;;      	 0f16c8               	movlhps	xmm1, xmm0
+;;      	 660f6fc1             	movdqa	xmm0, xmm1
+;;      	 4883c420             	add	rsp, 0x20
+;;      	 5d                   	pop	rbp
+;;      	 c3                   	ret	
+;;   44:	 0f0b                 	ud2	
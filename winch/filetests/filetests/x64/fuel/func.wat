@@ -0,0 +1,49 @@
+;;! target = "x86_64"
+;;! [tunables]
+;;! consume_fuel = true
+
+(module
+  (import "" "" (func $foo))
+  (func (export "")
+    (call $foo)
+  )
+)
+;;      	 55                   	push	rbp
+;;      	 4889e5               	mov	rbp, rsp
+;;      	 4989fe               	mov	r14, rdi
+;;      	 4883ec08             	sub	rsp, 8
+;;      	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;      	 4d8b1b               	mov	r11, qword ptr [r11]
+;;      	 4939e3               	cmp	r11, rsp
+;;      	 0f8770000000         	ja	0x8b
+;;   1b:	 4c893424             	mov	qword ptr [rsp], r14
+;;      	 498b4608             	mov	rax, qword ptr [r14 + 8]
+;;      	 488b4808             	mov	rcx, qword ptr [rax + 8]
+;;      	 4883c101             	add	rcx, 1
+;;      	 48894808             	mov	qword ptr [rax + 8], rcx
+;;      	 498b4608             	mov	rax, qword ptr [r14 + 8]
+;;      	 488b4008             	mov	rax, qword ptr [rax + 8]
+;;      	 4883f800             	cmp	rax, 0
+;;      	 0f8c17000000         	jl	0x58
+;;   41:	 4d8b5e38             	mov	r11, qword ptr [r14 + 0x38]
+;;      	 498b83a8000000       	mov	rax, qword ptr [r11 + 0xa8]
+;;      	 4156                 	push	r14
+;;      	 488b3c24             	mov	rdi, qword ptr [rsp]
+;;      	 ffd0                 	call	rax
+;;      	 4883c408             	add	rsp, 8
+;;      	 498b4608             	mov	rax, qword ptr [r14 + 8]
+;;      	 488b4808             	mov	rcx, qword ptr [rax + 8]
+;;      	 4883c101             	add	rcx, 1
+;;      	 48894808             	mov	qword ptr [rax + 8], rcx
+;;      	 498b4e60             	mov	rcx, qword ptr [r14 + 0x60]
+;;      	 498b4648             	mov	rax, qword ptr [r14 + 0x48]
+;;      	 4883ec08             	sub	rsp, 8
+;;      	 4889cf               	mov	rdi, rcx
+;;      	 4c89f6               	mov	rsi, r14
+;;      	 ffd0                 	call	rax
+;;      	 4c8b742408           	mov	r14, qword ptr [rsp + 8]
+;;      	 4883c408             	add	rsp, 8
+;;      	 4883c408             	add	rsp, 8
+;;      	 5d                   	pop	rbp
+;;      	 c3                   	ret	
+;;   8b:	 0f0b                 	ud2	
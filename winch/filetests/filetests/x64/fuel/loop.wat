@@ -0,0 +1,77 @@
+;;! target = "x86_64"
+;;! [tunables]
+;;! consume_fuel = true
+
+(module
+  (func (param i32) (result i32)
+    (local i32)
+    (loop $l
+      (local.set 1 (i32.add (local.get 1) (i32.const 1)))
+      (br_if $l (i32.lt_u (local.get 1) (local.get 0)))
+    )
+    (local.get 1)
+  )
+)
+;;      	 55                   	push	rbp
+;;      	 4889e5               	mov	rbp, rsp
+;;      	 4989fe               	mov	r14, rdi
+;;      	 4883ec10             	sub	rsp, 0x10
+;;      	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;      	 4d8b1b               	mov	r11, qword ptr [r11]
+;;      	 4939e3               	cmp	r11, rsp
+;;      	 0f87d7000000         	ja	0xf2
+;;   1b:	 8954240c             	mov	dword ptr [rsp + 0xc], edx
+;;      	 c744240800000000     	mov	dword ptr [rsp + 8], 0
+;;      	 4531db               	xor	r11d, r11d
+;;      	 4c893424             	mov	qword ptr [rsp], r14
+;;      	 498b4608             	mov	rax, qword ptr [r14 + 8]
+;;      	 488b4808             	mov	rcx, qword ptr [rax + 8]
+;;      	 4883c101             	add	rcx, 1
+;;      	 48894808             	mov	qword ptr [rax + 8], rcx
+;;      	 498b4608             	mov	rax, qword ptr [r14 + 8]
+;;      	 488b4008             	mov	rax, qword ptr [rax + 8]
+;;      	 4883f800             	cmp	rax, 0
+;;      	 0f8c20000000         	jl	0x70
+;;   50:	 4d8b5e38             	mov	r11, qword ptr [r14 + 0x38]
+;;      	 498b83a8000000       	mov	rax, qword ptr [r11 + 0xa8]
+;;      	 4156                 	push	r14
+;;      	 4883ec08             	sub	rsp, 8
+;;      	 488b7c2408           	mov	rdi, qword ptr [rsp + 8]
+;;      	 ffd0                 	call	rax
+;;      	 4883c408             	add	rsp, 8
+;;      	 4883c408             	add	rsp, 8
+;;      	 498b4608             	mov	rax, qword ptr [r14 + 8]
+;;      	 488b4008             	mov	rax, qword ptr [rax + 8]
+;;      	 4883f800             	cmp	rax, 0
+;;      	 0f8c20000000         	jl	0xa2
+;;   82:	 4d8b5e38             	mov	r11, qword ptr [r14 + 0x38]
+;;      	 498b83a8000000       	mov	rax, qword ptr [r11 + 0xa8]
+;;      	 4156                 	push	r14
+;;      	 4883ec08             	sub	rsp, 8
+;;      	 488b7c2408           	mov	rdi, qword ptr [rsp + 8]
+;;      	 ffd0                 	call	rax
+;;      	 4883c408             	add	rsp, 8
+;;      	 4883c408             	add	rsp, 8
+;;      	 8b442408             	mov	eax, dword ptr [rsp + 8]
+;;      	 83c001               	add	eax, 1
+;;      	 89442408             	mov	dword ptr [rsp + 8], eax
+;;      	 8b44240c             	mov	eax, dword ptr [rsp + 0xc]
+;;      	 8b4c2408             	mov	ecx, dword ptr [rsp + 8]
+;;      	 39c1                 	cmp	ecx, eax
+;;      	 b900000000           	mov	ecx, 0
+;;      	 400f92c1             	setb	cl
+;;      	 498b4608             	mov	rax, qword ptr [r14 + 8]
+;;      	 488b5008             	mov	rdx, qword ptr [rax + 8]
+;;      	 4883c208             	add	rdx, 8
+;;      	 48895008             	mov	qword ptr [rax + 8], rdx
+;;      	 85c9                 	test	ecx, ecx
+;;      	 0f8598ffffff         	jne	0x70
+;;   d8:	 498b4608             	mov	rax, qword ptr [r14 + 8]
+;;      	 488b4808             	mov	rcx, qword ptr [rax + 8]
+;;      	 4883c101             	add	rcx, 1
+;;      	 48894808             	mov	qword ptr [rax + 8], rcx
+;;      	 8b442408             	mov	eax, dword ptr [rsp + 8]
+;;      	 4883c410             	add	rsp, 0x10
+;;      	 5d                   	pop	rbp
+;;      	 c3                   	ret	
+;;   f2:	 0f0b                 	ud2	
@@ -0,0 +1,26 @@
+;;! target = "x86_64"
+;;! flags = ["has_sse3", "has_ssse3", "has_sse41", "has_sse42"]
+
+(module
+    (func (param v128) (result i32)
+        (local.get 0)
+        (i16x8.extract_lane_s 3)
+    )
+)
+;;      	 55                   	push	rbp
+;;      	 4889e5               	mov	rbp, rsp
+;;      	 4989fe               	mov	r14, rdi
+;;      	 4883ec18             	sub	rsp, 0x18
+;;      	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;      	 4d8b1b               	mov	r11, qword ptr [r11]
+;;      	 4939e3               	cmp	r11, rsp
+;;      	 0f871e000000         	ja	0x39
+;;   1b:	 f30f7f442408         	movdqu	xmmword ptr [rsp + 8], xmm0
+;;      	 4c893424             	mov	qword ptr [rsp], r14
+;;      	 f30f6f442408         	movdqu	xmm0, xmmword ptr [rsp + 8]
+;;      	 660fc5c003           	pextrw	eax, xmm0, 3
+;;      	 0fbfc0               	movsx	eax, ax
+;;      	 4883c418             	add	rsp, 0x18
+;;      	 5d                   	pop	rbp
+;;      	 c3                   	ret	
+;;   39:	 0f0b                 	ud2	
@@ -8,12 +8,13 @@
 )
 ;;      	 55                   	push	rbp
 ;;      	 4889e5               	mov	rbp, rsp
+;;      	 4989fe               	mov	r14, rdi
 ;;      	 4883ec08             	sub	rsp, 8
 ;;      	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
 ;;      	 4d8b1b               	mov	r11, qword ptr [r11]
 ;;      	 4939e3               	cmp	r11, rsp
-;;      	 0f8723000000         	ja	0x3b
-;;   18:	 4c893424             	mov	qword ptr [rsp], r14
+;;      	 0f8723000000         	ja	0x3e
+;;   1b:	 4c893424             	mov	qword ptr [rsp], r14
 ;;      	 b801000000           	mov	eax, 1
 ;;      	 0fbcc0               	bsf	eax, eax
 ;;      	 41bb00000000         	mov	r11d, 0
@@ -23,4 +24,4 @@
 ;;      	 4883c408             	add	rsp, 8
 ;;      	 5d                   	pop	rbp
 ;;      	 c3                   	ret	
-;;   3b:	 0f0b                 	ud2	
+;;   3e:	 0f0b                 	ud2	
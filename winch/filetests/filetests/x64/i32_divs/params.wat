@@ -9,22 +9,23 @@
 )
 ;;      	 55                   	push	rbp
 ;;      	 4889e5               	mov	rbp, rsp
+;;      	 4989fe               	mov	r14, rdi
 ;;      	 4883ec10             	sub	rsp, 0x10
 ;;      	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
 ;;      	 4d8b1b               	mov	r11, qword ptr [r11]
 ;;      	 4939e3               	cmp	r11, rsp
-;;      	 0f8726000000         	ja	0x3e
-;;   18:	 897c240c             	mov	dword ptr [rsp + 0xc], edi
-;;      	 89742408             	mov	dword ptr [rsp + 8], esi
+;;      	 0f8726000000         	ja	0x41
+;;   1b:	 8954240c             	mov	dword ptr [rsp + 0xc], edx
+;;      	 894c2408             	mov	dword ptr [rsp + 8], ecx
 ;;      	 4c893424             	mov	qword ptr [rsp], r14
 ;;      	 8b4c2408             	mov	ecx, dword ptr [rsp + 8]
 ;;      	 8b44240c             	mov	eax, dword ptr [rsp + 0xc]
 ;;      	 83f900               	cmp	ecx, 0
-;;      	 0f840b000000         	je	0x40
-;;   35:	 99                   	cdq	
+;;      	 0f840b000000         	je	0x43
+;;   38:	 99                   	cdq	
 ;;      	 f7f9                 	idiv	ecx
 ;;      	 4883c410             	add	rsp, 0x10
 ;;      	 5d                   	pop	rbp
 ;;      	 c3                   	ret	
-;;   3e:	 0f0b                 	ud2	
-;;   40:	 0f0b                 	ud2	
+;;   41:	 0f0b                 	ud2	
+;;   43:	 0f0b                 	ud2	
@@ -9,12 +9,13 @@
 )
 ;;      	 55                   	push	rbp
 ;;      	 4889e5               	mov	rbp, rsp
+;;      	 4989fe               	mov	r14, rdi
 ;;      	 4883ec08             	sub	rsp, 8
 ;;      	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
 ;;      	 4d8b1b               	mov	r11, qword ptr [r11]
 ;;      	 4939e3               	cmp	r11, rsp
-;;      	 0f8745000000         	ja	0x5d
-;;   18:	 4c893424             	mov	qword ptr [rsp], r14
+;;      	 0f8745000000         	ja	0x60
+;;   1b:	 4c893424             	mov	qword ptr [rsp], r14
 ;;      	 b803000000           	mov	eax, 3
 ;;      	 89c1                 	mov	ecx, eax
 ;;      	 c1e801               	shr	eax, 1
@@ -35,4 +36,4 @@
 ;;      	 4883c408             	add	rsp, 8
 ;;      	 5d                   	pop	rbp
 ;;      	 c3                   	ret	
-;;   5d:	 0f0b                 	ud2	
+;;   60:	 0f0b                 	ud2	
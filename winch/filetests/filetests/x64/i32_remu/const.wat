@@ -9,12 +9,13 @@
 )
 ;;      	 55                   	push	rbp
 ;;      	 4889e5               	mov	rbp, rsp
+;;      	 4989fe               	mov	r14, rdi
 ;;      	 4883ec08             	sub	rsp, 8
 ;;      	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
 ;;      	 4d8b1b               	mov	r11, qword ptr [r11]
 ;;      	 4939e3               	cmp	r11, rsp
-;;      	 0f871a000000         	ja	0x32
-;;   18:	 4c893424             	mov	qword ptr [rsp], r14
+;;      	 0f871a000000         	ja	0x35
+;;   1b:	 4c893424             	mov	qword ptr [rsp], r14
 ;;      	 b905000000           	mov	ecx, 5
 ;;      	 b807000000           	mov	eax, 7
 ;;      	 31d2                 	xor	edx, edx
@@ -23,4 +24,4 @@
 ;;      	 4883c408             	add	rsp, 8
 ;;      	 5d                   	pop	rbp
 ;;      	 c3                   	ret	
-;;   32:	 0f0b                 	ud2	
+;;   35:	 0f0b                 	ud2	
@@ -8,30 +8,31 @@
 )
 ;;      	 55                   	push	rbp
 ;;      	 4889e5               	mov	rbp, rsp
+;;      	 4989fe               	mov	r14, rdi
 ;;      	 4883ec10             	sub	rsp, 0x10
 ;;      	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
 ;;      	 4d8b1b               	mov	r11, qword ptr [r11]
 ;;      	 4939e3               	cmp	r11, rsp
-;;      	 0f8750000000         	ja	0x68
-;;   18:	 f30f1144240c         	movss	dword ptr [rsp + 0xc], xmm0
+;;      	 0f8750000000         	ja	0x6b
+;;   1b:	 f30f1144240c         	movss	dword ptr [rsp + 0xc], xmm0
 ;;      	 4c893424             	mov	qword ptr [rsp], r14
 ;;      	 f30f1044240c         	movss	xmm0, dword ptr [rsp + 0xc]
 ;;      	 f30f2cc0             	cvttss2si	eax, xmm0
 ;;      	 83f801               	cmp	eax, 1
-;;      	 0f812d000000         	jno	0x62
-;;   35:	 0f2ec0               	ucomiss	xmm0, xmm0
-;;      	 0f8a2c000000         	jp	0x6a
-;;   3e:	 41bb000000cf         	mov	r11d, 0xcf000000
+;;      	 0f812d000000         	jno	0x65
+;;   38:	 0f2ec0               	ucomiss	xmm0, xmm0
+;;      	 0f8a2c000000         	jp	0x6d
+;;   41:	 41bb000000cf         	mov	r11d, 0xcf000000
 ;;      	 66450f6efb           	movd	xmm15, r11d
 ;;      	 410f2ec7             	ucomiss	xmm0, xmm15
-;;      	 0f8219000000         	jb	0x6c
-;;   53:	 66450f57ff           	xorpd	xmm15, xmm15
+;;      	 0f8219000000         	jb	0x6f
+;;   56:	 66450f57ff           	xorpd	xmm15, xmm15
 ;;      	 440f2ef8             	ucomiss	xmm15, xmm0
-;;      	 0f820c000000         	jb	0x6e
-;;   62:	 4883c410             	add	rsp, 0x10
+;;      	 0f820c000000         	jb	0x71
+;;   65:	 4883c410             	add	rsp, 0x10
 ;;      	 5d                   	pop	rbp
 ;;      	 c3                   	ret	
-;;   68:	 0f0b                 	ud2	
-;;   6a:	 0f0b                 	ud2	
-;;   6c:	 0f0b                 	ud2	
-;;   6e:	 0f0b                 	ud2	
+;;   6b:	 0f0b                 	ud2	
+;;   6d:	 0f0b                 	ud2	
+;;   6f:	 0f0b                 	ud2	
+;;   71:	 0f0b                 	ud2	
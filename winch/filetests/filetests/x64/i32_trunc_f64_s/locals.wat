@@ -10,32 +10,33 @@
 )
 ;;      	 55                   	push	rbp
 ;;      	 4889e5               	mov	rbp, rsp
+;;      	 4989fe               	mov	r14, rdi
 ;;      	 4883ec10             	sub	rsp, 0x10
 ;;      	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
 ;;      	 4d8b1b               	mov	r11, qword ptr [r11]
 ;;      	 4939e3               	cmp	r11, rsp
-;;      	 0f875a000000         	ja	0x72
-;;   18:	 48c744240800000000   	
+;;      	 0f875a000000         	ja	0x75
+;;   1b:	 48c744240800000000   	
 ;; 				mov	qword ptr [rsp + 8], 0
 ;;      	 4c893424             	mov	qword ptr [rsp], r14
 ;;      	 f20f10442408         	movsd	xmm0, qword ptr [rsp + 8]
 ;;      	 f20f2cc0             	cvttsd2si	eax, xmm0
 ;;      	 83f801               	cmp	eax, 1
-;;      	 0f8134000000         	jno	0x6c
-;;   38:	 660f2ec0             	ucomisd	xmm0, xmm0
-;;      	 0f8a32000000         	jp	0x74
-;;   42:	 49bb000020000000e0c1 	
+;;      	 0f8134000000         	jno	0x6f
+;;   3b:	 660f2ec0             	ucomisd	xmm0, xmm0
+;;      	 0f8a32000000         	jp	0x77
+;;   45:	 49bb000020000000e0c1 	
 ;; 				movabs	r11, 0xc1e0000000200000
 ;;      	 664d0f6efb           	movq	xmm15, r11
 ;;      	 66410f2ec7           	ucomisd	xmm0, xmm15
-;;      	 0f861a000000         	jbe	0x76
-;;   5c:	 66450f57ff           	xorpd	xmm15, xmm15
+;;      	 0f861a000000         	jbe	0x79
+;;   5f:	 66450f57ff           	xorpd	xmm15, xmm15
 ;;      	 66440f2ef8           	ucomisd	xmm15, xmm0
-;;      	 0f820c000000         	jb	0x78
-;;   6c:	 4883c410             	add	rsp, 0x10
+;;      	 0f820c000000         	jb	0x7b
+;;   6f:	 4883c410             	add	rsp, 0x10
 ;;      	 5d                   	pop	rbp
 ;;      	 c3                   	ret	
-;;   72:	 0f0b                 	ud2	
-;;   74:	 0f0b                 	ud2	
-;;   76:	 0f0b                 	ud2	
-;;   78:	 0f0b                 	ud2	
+;;   75:	 0f0b                 	ud2	
+;;   77:	 0f0b                 	ud2	
+;;   79:	 0f0b                 	ud2	
+;;   7b:	 0f0b                 	ud2	
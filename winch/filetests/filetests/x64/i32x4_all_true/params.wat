@@ -0,0 +1,29 @@
+;;! target = "x86_64"
+;;! flags = ["has_sse3", "has_ssse3", "has_sse41", "has_sse42"]
+
+(module
+    (func (param v128) (result i32)
+        (local.get 0)
+        (i32x4.all_true)
+    )
+)
+;;      	 55                   	push	rbp
+;;      	 4889e5               	mov	rbp, rsp
+;;      	 4989fe               	mov	r14, rdi
+;;      	 4883ec18             	sub	rsp, 0x18
+;;      	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;      	 4d8b1b               	mov	r11, qword ptr [r11]
+;;      	 4939e3               	cmp	r11, rsp
+;;      	 0f872f000000         	ja	0x4a
+;;   1b:	 f30f7f442408         	movdqu	xmmword ptr [rsp + 8], xmm0
+;;      	 4c893424             	mov	qword ptr [rsp], r14
+;;      	 f30f6f442408         	movdqu	xmm0, xmmword ptr [rsp + 8]
+;;      	 66450fefff           	pxor	xmm15, xmm15
+;;      	 66440f76f8           	pcmpeqd	xmm15, xmm0
+;;      	 66450f3817ff         	ptest	xmm15, xmm15
+;;      	 b800000000           	mov	eax, 0
+;;      	 400f94c0             	sete	al
+;;      	 4883c418             	add	rsp, 0x18
+;;      	 5d                   	pop	rbp
+;;      	 c3                   	ret	
+;;   4a:	 0f0b                 	ud2	
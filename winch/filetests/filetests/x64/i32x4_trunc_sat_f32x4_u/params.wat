@@ -0,0 +1,38 @@
+;;! target = "x86_64"
+;;! flags = ["has_sse3", "has_ssse3", "has_sse41", "has_sse42"]
+
+(module
+    (func (param v128) (result v128)
+        (local.get 0)
+        (i32x4.trunc_sat_f32x4_u)
+    )
+)
+;;      	 55                   	push	rbp
+;;      	 4889e5               	mov	rbp, rsp
+;;      	 4989fe               	mov	r14, rdi
+;;      	 4883ec18             	sub	rsp, 0x18
+;;      	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;      	 4d8b1b               	mov	r11, qword ptr [r11]
+;;      	 4939e3               	cmp	r11, rsp
+;;      	 0f8756000000         	ja	0x71
+;;   1b:	 f30f7f442408         	movdqu	xmmword ptr [rsp + 8], xmm0
+;;      	 4c893424             	mov	qword ptr [rsp], r14
+;;      	 f30f6f442408         	movdqu	xmm0, xmmword ptr [rsp + 8]
+;;      	 450f57ff             	xorps	xmm15, xmm15
+;;      	 410f5fc7             	maxps	xmm0, xmm15
+;;      	 66450f76ff           	pcmpeqd	xmm15, xmm15
+;;      	 66410f72d701         	psrld	xmm15, 1
+;;      	 450f5bff             	cvtdq2ps	xmm15, xmm15
+;;      	 f30f5bc8             	cvttps2dq	xmm1, xmm0
+;;      	 410f5cc7             	subps	xmm0, xmm15
+;;      	 440fc2f802           	cmpleps	xmm15, xmm0
+;;      	 f30f5bc0             	cvttps2dq	xmm0, xmm0
+;;      	 66410fefc7           	pxor	xmm0, xmm15
+;;      	 66450fefff           	pxor	xmm15, xmm15
+;;      	 66410f383dc7         	pmaxsd	xmm0, xmm15
+;;      	 660ffec8             	paddd	xmm1, xmm0
+;;      	 660f6fc1             	movdqa	xmm0, xmm1
+;;      	 4883c418             	add	rsp, 0x18
+;;      	 5d                   	pop	rbp
+;;      	 c3                   	ret	
+;;   71:	 0f0b                 	ud2	
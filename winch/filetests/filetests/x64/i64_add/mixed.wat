@@ -9,15 +9,16 @@
 )
 ;;      	 55                   	push	rbp
 ;;      	 4889e5               	mov	rbp, rsp
+;;      	 4989fe               	mov	r14, rdi
 ;;      	 4883ec08             	sub	rsp, 8
 ;;      	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
 ;;      	 4d8b1b               	mov	r11, qword ptr [r11]
 ;;      	 4939e3               	cmp	r11, rsp
-;;      	 0f8715000000         	ja	0x2d
-;;   18:	 4c893424             	mov	qword ptr [rsp], r14
+;;      	 0f8715000000         	ja	0x30
+;;   1b:	 4c893424             	mov	qword ptr [rsp], r14
 ;;      	 48c7c0ffffffff       	mov	rax, 0xffffffffffffffff
 ;;      	 4883c001             	add	rax, 1
 ;;      	 4883c408             	add	rsp, 8
 ;;      	 5d                   	pop	rbp
 ;;      	 c3                   	ret	
-;;   2d:	 0f0b                 	ud2	
+;;   30:	 0f0b                 	ud2	
@@ -9,12 +9,13 @@
 )
 ;;      	 55                   	push	rbp
 ;;      	 4889e5               	mov	rbp, rsp
+;;      	 4989fe               	mov	r14, rdi
 ;;      	 4883ec08             	sub	rsp, 8
 ;;      	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
 ;;      	 4d8b1b               	mov	r11, qword ptr [r11]
 ;;      	 4939e3               	cmp	r11, rsp
-;;      	 0f871e000000         	ja	0x36
-;;   18:	 4c893424             	mov	qword ptr [rsp], r14
+;;      	 0f871e000000         	ja	0x39
+;;   1b:	 4c893424             	mov	qword ptr [rsp], r14
 ;;      	 48c7c10a000000       	mov	rcx, 0xa
 ;;      	 48c7c014000000       	mov	rax, 0x14
 ;;      	 4831d2               	xor	rdx, rdx
@@ -22,4 +23,4 @@
 ;;      	 4883c408             	add	rsp, 8
 ;;      	 5d                   	pop	rbp
 ;;      	 c3                   	ret	
-;;   36:	 0f0b                 	ud2	
+;;   39:	 0f0b                 	ud2	
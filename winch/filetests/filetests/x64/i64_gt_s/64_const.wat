@@ -9,12 +9,13 @@
 )
 ;;      	 55                   	push	rbp
 ;;      	 4889e5               	mov	rbp, rsp
+;;      	 4989fe               	mov	r14, rdi
 ;;      	 4883ec08             	sub	rsp, 8
 ;;      	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
 ;;      	 4d8b1b               	mov	r11, qword ptr [r11]
 ;;      	 4939e3               	cmp	r11, rsp
-;;      	 0f872a000000         	ja	0x42
-;;   18:	 4c893424             	mov	qword ptr [rsp], r14
+;;      	 0f872a000000         	ja	0x45
+;;   1b:	 4c893424             	mov	qword ptr [rsp], r14
 ;;      	 48b8feffffffffffff7f 	
 ;; 				movabs	rax, 0x7ffffffffffffffe
 ;;      	 49bbffffffffffffff7f 	
@@ -25,4 +26,4 @@
 ;;      	 4883c408             	add	rsp, 8
 ;;      	 5d                   	pop	rbp
 ;;      	 c3                   	ret	
-;;   42:	 0f0b                 	ud2	
+;;   45:	 0f0b                 	ud2	
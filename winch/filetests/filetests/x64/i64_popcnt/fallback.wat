@@ -8,12 +8,13 @@
 )
 ;;      	 55                   	push	rbp
 ;;      	 4889e5               	mov	rbp, rsp
+;;      	 4989fe               	mov	r14, rdi
 ;;      	 4883ec08             	sub	rsp, 8
 ;;      	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
 ;;      	 4d8b1b               	mov	r11, qword ptr [r11]
 ;;      	 4939e3               	cmp	r11, rsp
-;;      	 0f876b000000         	ja	0x83
-;;   18:	 4c893424             	mov	qword ptr [rsp], r14
+;;      	 0f876b000000         	ja	0x86
+;;   1b:	 4c893424             	mov	qword ptr [rsp], r14
 ;;      	 48c7c00f000000       	mov	rax, 0xf
 ;;      	 4889c1               	mov	rcx, rax
 ;;      	 48c1e801             	shr	rax, 1
@@ -41,4 +42,4 @@
 ;;      	 4883c408             	add	rsp, 8
 ;;      	 5d                   	pop	rbp
 ;;      	 c3                   	ret	
-;;   83:	 0f0b                 	ud2	
+;;   86:	 0f0b                 	ud2	
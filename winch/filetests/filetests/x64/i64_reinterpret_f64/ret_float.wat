@@ -10,23 +10,22 @@
 )
 ;;      	 55                   	push	rbp
 ;;      	 4889e5               	mov	rbp, rsp
+;;      	 4989fe               	mov	r14, rdi
 ;;      	 4883ec08             	sub	rsp, 8
 ;;      	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
 ;;      	 4d8b1b               	mov	r11, qword ptr [r11]
 ;;      	 4939e3               	cmp	r11, rsp
-;;      	 0f871f000000         	ja	0x37
-;;   18:	 4c893424             	mov	qword ptr [rsp], r14
-;;      	 f20f10051c000000     	movsd	xmm0, qword ptr [rip + 0x1c]
+;;      	 0f871f000000         	ja	0x3a
+;;   1b:	 4c893424             	mov	qword ptr [rsp], r14
+;;      	 f20f100519000000     	movsd	xmm0, qword ptr [rip + 0x19]
 ;;      	 66480f7ec0           	movq	rax, xmm0
-;;      	 f20f10050f000000     	movsd	xmm0, qword ptr [rip + 0xf]
+;;      	 f20f10050c000000     	movsd	xmm0, qword ptr [rip + 0xc]
 ;;      	 4883c408             	add	rsp, 8
 ;;      	 5d                   	pop	rbp
 ;;      	 c3                   	ret	
-;;   37:	 0f0b                 	ud2	
-;;   39:	 0000                 	add	byte ptr [rax], al
-;;   3b:	 0000                 	add	byte ptr [rax], al
-;;   3d:	 0000                 	add	byte ptr [rax], al
-;;   3f:	 0000                 	add	byte ptr [rax], al
-;;   41:	 0000                 	add	byte ptr [rax], al
-;;   43:	 0000                 	add	byte ptr [rax], al
-;;   45:	 00f0                 	add	al, dh
+;;   3a:	 0f0b                 	ud2	
+;;   3c:	 0000                 	add	byte ptr [rax], al
+;;   3e:	 0000                 	add	byte ptr [rax], al
+;;   40:	 0000                 	add	byte ptr [rax], al
+;;   42:	 0000                 	add	byte ptr [rax], al
+;;   44:	 0000                 	add	byte ptr [rax], al
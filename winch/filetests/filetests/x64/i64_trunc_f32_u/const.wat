@@ -8,34 +8,37 @@
 )
 ;;      	 55                   	push	rbp
 ;;      	 4889e5               	mov	rbp, rsp
+;;      	 4989fe               	mov	r14, rdi
 ;;      	 4883ec08             	sub	rsp, 8
 ;;      	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
 ;;      	 4d8b1b               	mov	r11, qword ptr [r11]
 ;;      	 4939e3               	cmp	r11, rsp
-;;      	 0f8762000000         	ja	0x7a
-;;   18:	 4c893424             	mov	qword ptr [rsp], r14
-;;      	 f30f100d5c000000     	movss	xmm1, dword ptr [rip + 0x5c]
+;;      	 0f8762000000         	ja	0x7d
+;;   1b:	 4c893424             	mov	qword ptr [rsp], r14
+;;      	 f30f100d61000000     	movss	xmm1, dword ptr [rip + 0x61]
 ;;      	 41bb0000005f         	mov	r11d, 0x5f000000
 ;;      	 66450f6efb           	movd	xmm15, r11d
 ;;      	 410f2ecf             	ucomiss	xmm1, xmm15
-;;      	 0f8317000000         	jae	0x50
-;;      	 0f8a3d000000         	jp	0x7c
-;;   3f:	 f3480f2cc1           	cvttss2si	rax, xmm1
+;;      	 0f8317000000         	jae	0x53
+;;      	 0f8a3d000000         	jp	0x7f
+;;   42:	 f3480f2cc1           	cvttss2si	rax, xmm1
 ;;      	 4883f800             	cmp	rax, 0
-;;      	 0f8d26000000         	jge	0x74
-;;   4e:	 0f0b                 	ud2	
+;;      	 0f8d26000000         	jge	0x77
+;;   51:	 0f0b                 	ud2	
 ;;      	 0f28c1               	movaps	xmm0, xmm1
 ;;      	 f3410f5cc7           	subss	xmm0, xmm15
 ;;      	 f3480f2cc0           	cvttss2si	rax, xmm0
 ;;      	 4883f800             	cmp	rax, 0
-;;      	 0f8c17000000         	jl	0x7e
-;;   67:	 49bb0000000000000080 	
+;;      	 0f8c17000000         	jl	0x81
+;;   6a:	 49bb0000000000000080 	
 ;; 				movabs	r11, 0x8000000000000000
 ;;      	 4c01d8               	add	rax, r11
 ;;      	 4883c408             	add	rsp, 8
 ;;      	 5d                   	pop	rbp
 ;;      	 c3                   	ret	
-;;   7a:	 0f0b                 	ud2	
-;;   7c:	 0f0b                 	ud2	
-;;   7e:	 0f0b                 	ud2	
-;;   80:	 0000                 	add	byte ptr [rax], al
+;;   7d:	 0f0b                 	ud2	
+;;   7f:	 0f0b                 	ud2	
+;;   81:	 0f0b                 	ud2	
+;;   83:	 0000                 	add	byte ptr [rax], al
+;;   85:	 0000                 	add	byte ptr [rax], al
+;;   87:	 0000                 	add	byte ptr [rax], al
@@ -8,34 +8,35 @@
 )
 ;;      	 55                   	push	rbp
 ;;      	 4889e5               	mov	rbp, rsp
+;;      	 4989fe               	mov	r14, rdi
 ;;      	 4883ec10             	sub	rsp, 0x10
 ;;      	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
 ;;      	 4d8b1b               	mov	r11, qword ptr [r11]
 ;;      	 4939e3               	cmp	r11, rsp
-;;      	 0f8766000000         	ja	0x7e
-;;   18:	 f30f1144240c         	movss	dword ptr [rsp + 0xc], xmm0
+;;      	 0f8766000000         	ja	0x81
+;;   1b:	 f30f1144240c         	movss	dword ptr [rsp + 0xc], xmm0
 ;;      	 4c893424             	mov	qword ptr [rsp], r14
 ;;      	 f30f104c240c         	movss	xmm1, dword ptr [rsp + 0xc]
 ;;      	 41bb0000005f         	mov	r11d, 0x5f000000
 ;;      	 66450f6efb           	movd	xmm15, r11d
 ;;      	 410f2ecf             	ucomiss	xmm1, xmm15
-;;      	 0f8317000000         	jae	0x54
-;;      	 0f8a3d000000         	jp	0x80
-;;   43:	 f3480f2cc1           	cvttss2si	rax, xmm1
+;;      	 0f8317000000         	jae	0x57
+;;      	 0f8a3d000000         	jp	0x83
+;;   46:	 f3480f2cc1           	cvttss2si	rax, xmm1
 ;;      	 4883f800             	cmp	rax, 0
-;;      	 0f8d26000000         	jge	0x78
-;;   52:	 0f0b                 	ud2	
+;;      	 0f8d26000000         	jge	0x7b
+;;   55:	 0f0b                 	ud2	
 ;;      	 0f28c1               	movaps	xmm0, xmm1
 ;;      	 f3410f5cc7           	subss	xmm0, xmm15
 ;;      	 f3480f2cc0           	cvttss2si	rax, xmm0
 ;;      	 4883f800             	cmp	rax, 0
-;;      	 0f8c17000000         	jl	0x82
-;;   6b:	 49bb0000000000000080 	
+;;      	 0f8c17000000         	jl	0x85
+;;   6e:	 49bb0000000000000080 	
 ;; 				movabs	r11, 0x8000000000000000
 ;;      	 4c01d8               	add	rax, r11
 ;;      	 4883c410             	add	rsp, 0x10
 ;;      	 5d                   	pop	rbp
 ;;      	 c3                   	ret	
-;;   7e:	 0f0b                 	ud2	
-;;   80:	 0f0b                 	ud2	
-;;   82:	 0f0b                 	ud2	
+;;   81:	 0f0b                 	ud2	
+;;   83:	 0f0b                 	ud2	
+;;   85:	 0f0b                 	ud2	
@@ -10,32 +10,33 @@
 )
 ;;      	 55                   	push	rbp
 ;;      	 4889e5               	mov	rbp, rsp
+;;      	 4989fe               	mov	r14, rdi
 ;;      	 4883ec10             	sub	rsp, 0x10
 ;;      	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
 ;;      	 4d8b1b               	mov	r11, qword ptr [r11]
 ;;      	 4939e3               	cmp	r11, rsp
-;;      	 0f875c000000         	ja	0x74
-;;   18:	 48c744240800000000   	
+;;      	 0f875c000000         	ja	0x77
+;;   1b:	 48c744240800000000   	
 ;; 				mov	qword ptr [rsp + 8], 0
 ;;      	 4c893424             	mov	qword ptr [rsp], r14
 ;;      	 f20f10442408         	movsd	xmm0, qword ptr [rsp + 8]
 ;;      	 f2480f2cc0           	cvttsd2si	rax, xmm0
 ;;      	 4883f801             	cmp	rax, 1
-;;      	 0f8134000000         	jno	0x6e
-;;   3a:	 660f2ec0             	ucomisd	xmm0, xmm0
-;;      	 0f8a32000000         	jp	0x76
-;;   44:	 49bb000000000000e0c3 	
+;;      	 0f8134000000         	jno	0x71
+;;   3d:	 660f2ec0             	ucomisd	xmm0, xmm0
+;;      	 0f8a32000000         	jp	0x79
+;;   47:	 49bb000000000000e0c3 	
 ;; 				movabs	r11, 0xc3e0000000000000
 ;;      	 664d0f6efb           	movq	xmm15, r11
 ;;      	 66410f2ec7           	ucomisd	xmm0, xmm15
-;;      	 0f821a000000         	jb	0x78
-;;   5e:	 66450f57ff           	xorpd	xmm15, xmm15
+;;      	 0f821a000000         	jb	0x7b
+;;   61:	 66450f57ff           	xorpd	xmm15, xmm15
 ;;      	 66440f2ef8           	ucomisd	xmm15, xmm0
-;;      	 0f820c000000         	jb	0x7a
-;;   6e:	 4883c410             	add	rsp, 0x10
+;;      	 0f820c000000         	jb	0x7d
+;;   71:	 4883c410             	add	rsp, 0x10
 ;;      	 5d                   	pop	rbp
 ;;      	 c3                   	ret	
-;;   74:	 0f0b                 	ud2	
-;;   76:	 0f0b                 	ud2	
-;;   78:	 0f0b                 	ud2	
-;;   7a:	 0f0b                 	ud2	
+;;   77:	 0f0b                 	ud2	
+;;   79:	 0f0b                 	ud2	
+;;   7b:	 0f0b                 	ud2	
+;;   7d:	 0f0b                 	ud2	
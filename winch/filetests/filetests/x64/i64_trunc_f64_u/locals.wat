@@ -10,12 +10,13 @@
 )
 ;;      	 55                   	push	rbp
 ;;      	 4889e5               	mov	rbp, rsp
+;;      	 4989fe               	mov	r14, rdi
 ;;      	 4883ec10             	sub	rsp, 0x10
 ;;      	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
 ;;      	 4d8b1b               	mov	r11, qword ptr [r11]
 ;;      	 4939e3               	cmp	r11, rsp
-;;      	 0f876e000000         	ja	0x86
-;;   18:	 48c744240800000000   	
+;;      	 0f876e000000         	ja	0x89
+;;   1b:	 48c744240800000000   	
 ;; 				mov	qword ptr [rsp + 8], 0
 ;;      	 4c893424             	mov	qword ptr [rsp], r14
 ;;      	 f20f104c2408         	movsd	xmm1, qword ptr [rsp + 8]
@@ -23,23 +24,23 @@
 ;; 				movabs	r11, 0x43e0000000000000
 ;;      	 664d0f6efb           	movq	xmm15, r11
 ;;      	 66410f2ecf           	ucomisd	xmm1, xmm15
-;;      	 0f8317000000         	jae	0x5c
-;;      	 0f8a3d000000         	jp	0x88
-;;   4b:	 f2480f2cc1           	cvttsd2si	rax, xmm1
+;;      	 0f8317000000         	jae	0x5f
+;;      	 0f8a3d000000         	jp	0x8b
+;;   4e:	 f2480f2cc1           	cvttsd2si	rax, xmm1
 ;;      	 4883f800             	cmp	rax, 0
-;;      	 0f8d26000000         	jge	0x80
-;;   5a:	 0f0b                 	ud2	
+;;      	 0f8d26000000         	jge	0x83
+;;   5d:	 0f0b                 	ud2	
 ;;      	 0f28c1               	movaps	xmm0, xmm1
 ;;      	 f2410f5cc7           	subsd	xmm0, xmm15
 ;;      	 f2480f2cc0           	cvttsd2si	rax, xmm0
 ;;      	 4883f800             	cmp	rax, 0
-;;      	 0f8c17000000         	jl	0x8a
-;;   73:	 49bb0000000000000080 	
+;;      	 0f8c17000000         	jl	0x8d
+;;   76:	 49bb0000000000000080 	
 ;; 				movabs	r11, 0x8000000000000000
 ;;      	 4c01d8               	add	rax, r11
 ;;      	 4883c410             	add	rsp, 0x10
 ;;      	 5d                   	pop	rbp
 ;;      	 c3                   	ret	
-;;   86:	 0f0b                 	ud2	
-;;   88:	 0f0b                 	ud2	
-;;   8a:	 0f0b                 	ud2	
+;;   89:	 0f0b                 	ud2	
+;;   8b:	 0f0b                 	ud2	
+;;   8d:	 0f0b                 	ud2	
@@ -0,0 +1,38 @@
+;;! target = "x86_64"
+;;! flags = ["has_sse3", "has_ssse3", "has_sse41", "has_sse42"]
+
+(module
+    (func (param v128 v128) (result v128)
+        (local.get 0)
+        (local.get 1)
+        (i64x2.mul)
+    )
+)
+;;      	 55                   	push	rbp
+;;      	 4889e5               	mov	rbp, rsp
+;;      	 4989fe               	mov	r14, rdi
+;;      	 4883ec28             	sub	rsp, 0x28
+;;      	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;      	 4d8b1b               	mov	r11, qword ptr [r11]
+;;      	 4939e3               	cmp	r11, rsp
+;;      	 0f8757000000         	ja	0x72
+;;   1b:	 f30f7f442418         	movdqu	xmmword ptr [rsp + 0x18], xmm0
+;;      	 f30f7f4c2408         	movdqu	xmmword ptr [rsp + 8], xmm1
+;;      	 4c893424             	mov	qword ptr [rsp], r14
+;;      	 f30f6f442408         	movdqu	xmm0, xmmword ptr [rsp + 8]
+;;      	 f30f6f4c2418         	movdqu	xmm1, xmmword ptr [rsp + 0x18]
+;;      	 66440f6ff9           	movdqa	xmm15, xmm1
+;;      	 66410f73d720         	psrlq	xmm15, 0x20
+;;      	 66440ff4f8           	pmuludq	xmm15, xmm0
+;;      	 660f6fd0             	movdqa	xmm2, xmm0
+;;      	 660f73d220           	psrlq	xmm2, 0x20
+;;      	 660ff4d1             	pmuludq	xmm2, xmm1
+;;      	 66440fd4fa           	paddq	xmm15, xmm2
+;;      	 66410f73f720         	psllq	xmm15, 0x20
+;;      	 660ff4c8             	pmuludq	xmm1, xmm0
+;;      	 66410fd4cf           	paddq	xmm1, xmm15
+;;      	 660f6fc1             	movdqa	xmm0, xmm1
+;;      	 4883c428             	add	rsp, 0x28
+;;      	 5d                   	pop	rbp
+;;      	 c3                   	ret	
+;;   72:	 0f0b                 	ud2	
@@ -0,0 +1,47 @@
+;;! target = "x86_64"
+;;! flags = ["has_sse3", "has_ssse3", "has_sse41", "has_sse42"]
+
+(module
+    (func (param v128 i32) (result v128)
+        (local.get 0)
+        (local.get 1)
+        (i64x2.shr_s)
+    )
+)
+;;      	 55                   	push	rbp
+;;      	 4889e5               	mov	rbp, rsp
+;;      	 4989fe               	mov	r14, rdi
+;;      	 4883ec20             	sub	rsp, 0x20
+;;      	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;      	 4d8b1b               	mov	r11, qword ptr [r11]
+;;      	 4939e3               	cmp	r11, rsp
+;;      	 0f8745000000         	ja	0x60
+;;   1b:	 f30f7f442410         	movdqu	xmmword ptr [rsp + 0x10], xmm0
+;;      	 8954240c             	mov	dword ptr [rsp + 0xc], edx
+;;      	 4c893424             	mov	qword ptr [rsp], r14
+;;      	 8b44240c             	mov	eax, dword ptr [rsp + 0xc]
+;;      	 83e03f               	and	eax, 0x3f
+;;      	 f30f6f442410         	movdqu	xmm0, xmmword ptr [rsp + 0x10]
+;;      	 66440f6ef8           	movd	xmm15, eax
+;;      	 66410fd3c7           	psrlq	xmm0, xmm15
+;;      	 f30f6f0d28000000     	movdqu	xmm1, xmmword ptr [rip + 0x28]
+;;      	 66440f6ef8           	movd	xmm15, eax
+;;      	 66410fd3cf           	psrlq	xmm1, xmm15
+;;      	 660fefc1             	pxor	xmm0, xmm1
+;;      	 660ffbc1             	psubq	xmm0, xmm1
+;;      	 4883c420             	add	rsp, 0x20
+;;      	 5d                   	pop	rbp
+;;      	 c3                   	ret	
+;;   60:	 0f0b                 	ud2	
+;;   62:	 0000                 	add	byte ptr [rax], al
+;;   64:	 0000                 	add	byte ptr [rax], al
+;;   66:	 0000                 	add	byte ptr [rax], al
+;;   68:	 0000                 	add	byte ptr [rax], al
+;;   6a:	 0000                 	add	byte ptr [rax], al
+;;   6c:	 0000                 	add	byte ptr [rax], al
+;;   6e:	 0000                 	add	byte ptr [rax], al
+;;   70:	 0000                 	add	byte ptr [rax], al
+;;   72:	 0000                 	add	byte ptr [rax], al
+;;   74:	 0000                 	add	byte ptr [rax], al
+;;   76:	 008000000000         	add	byte ptr [rax], al
+;;   7c:	 0000                 	add	byte ptr [rax], al
@@ -0,0 +1,46 @@
+;;! target = "x86_64"
+;;! flags = ["has_sse3", "has_ssse3", "has_sse41", "has_sse42"]
+
+(module
+    (func (param v128) (result v128)
+        (local.get 0)
+        (i32.const 3)
+        (i8x16.shl)
+    )
+)
+;;      	 55                   	push	rbp
+;;      	 4889e5               	mov	rbp, rsp
+;;      	 4989fe               	mov	r14, rdi
+;;      	 4883ec18             	sub	rsp, 0x18
+;;      	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;      	 4d8b1b               	mov	r11, qword ptr [r11]
+;;      	 4939e3               	cmp	r11, rsp
+;;      	 0f8737000000         	ja	0x52
+;;   1b:	 f30f7f442408         	movdqu	xmmword ptr [rsp + 8], xmm0
+;;      	 4c893424             	mov	qword ptr [rsp], r14
+;;      	 f30f6f442408         	movdqu	xmm0, xmmword ptr [rsp + 8]
+;;      	 660f71f003           	psllw	xmm0, 3
+;;      	 660f76c9             	pcmpeqd	xmm1, xmm1
+;;      	 660f71f103           	psllw	xmm1, 3
+;;      	 f3440f6f3d1e000000   	
+;; 				movdqu	xmm15, xmmword ptr [rip + 0x1e]
+;;      	 66410f3800cf         	pshufb	xmm1, xmm15
+;;      	 660fdbc1             	pand	xmm0, xmm1
+;;      	 4883c418             	add	rsp, 0x18
+;;      	 5d                   	pop	rbp
+;;      	 c3                   	ret	
+;;   52:	 0f0b                 	ud2	
+;;   54:	 0000                 	add	byte ptr [rax], al
+;;   56:	 0000                 	add	byte ptr [rax], al
+;;   58:	 0000                 	add	byte ptr [rax], al
+;;   5a:	 0000                 	add	byte ptr [rax], al
+;;   5c:	 0000                 	add	byte ptr [rax], al
+;;   5e:	 0000                 	add	byte ptr [rax], al
+;;   60:	 0000                 	add	byte ptr [rax], al
+;;   62:	 0000                 	add	byte ptr [rax], al
+;;   64:	 0000                 	add	byte ptr [rax], al
+;;   66:	 0000                 	add	byte ptr [rax], al
+;;   68:	 0000                 	add	byte ptr [rax], al
+;;   6a:	 0000                 	add	byte ptr [rax], al
+;;   6c:	 0000                 	add	byte ptr [rax], al
+;;   6e:	 0000                 	add	byte ptr [rax], al
@@ -0,0 +1,37 @@
+;;! target = "x86_64"
+;;! flags = ["has_sse3", "has_ssse3", "has_sse41", "has_sse42"]
+
+(module
+    (func (param v128 i32) (result v128)
+        (local.get 0)
+        (local.get 1)
+        (i8x16.shr_s)
+    )
+)
+;;      	 55                   	push	rbp
+;;      	 4889e5               	mov	rbp, rsp
+;;      	 4989fe               	mov	r14, rdi
+;;      	 4883ec20             	sub	rsp, 0x20
+;;      	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;      	 4d8b1b               	mov	r11, qword ptr [r11]
+;;      	 4939e3               	cmp	r11, rsp
+;;      	 0f8748000000         	ja	0x63
+;;   1b:	 f30f7f442410         	movdqu	xmmword ptr [rsp + 0x10], xmm0
+;;      	 8954240c             	mov	dword ptr [rsp + 0xc], edx
+;;      	 4c893424             	mov	qword ptr [rsp], r14
+;;      	 8b44240c             	mov	eax, dword ptr [rsp + 0xc]
+;;      	 83e007               	and	eax, 7
+;;      	 f30f6f442410         	movdqu	xmm0, xmmword ptr [rsp + 0x10]
+;;      	 83c008               	add	eax, 8
+;;      	 660f6fc8             	movdqa	xmm1, xmm0
+;;      	 660f60c0             	punpcklbw	xmm0, xmm0
+;;      	 660f68c9             	punpckhbw	xmm1, xmm1
+;;      	 66440f6ef8           	movd	xmm15, eax
+;;      	 66410fe1c7           	psraw	xmm0, xmm15
+;;      	 66440f6ef8           	movd	xmm15, eax
+;;      	 66410fe1cf           	psraw	xmm1, xmm15
+;;      	 660f63c1             	packsswb	xmm0, xmm1
+;;      	 4883c420             	add	rsp, 0x20
+;;      	 5d                   	pop	rbp
+;;      	 c3                   	ret	
+;;   63:	 0f0b                 	ud2	
@@ -0,0 +1,43 @@
+;;! target = "x86_64"
+;;! flags = ["has_sse3", "has_ssse3", "has_sse41", "has_sse42"]
+
+(module
+    (func (param v128 v128) (result v128)
+        (local.get 0)
+        (local.get 1)
+        (i8x16.shuffle 0 17 2 19 4 21 6 23 8 25 10 27 12 29 14 31)
+    )
+)
+;;      	 55                   	push	rbp
+;;      	 4889e5               	mov	rbp, rsp
+;;      	 4989fe               	mov	r14, rdi
+;;      	 4883ec28             	sub	rsp, 0x28
+;;      	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;      	 4d8b1b               	mov	r11, qword ptr [r11]
+;;      	 4939e3               	cmp	r11, rsp
+;;      	 0f8748000000         	ja	0x63
+;;   1b:	 f30f7f442418         	movdqu	xmmword ptr [rsp + 0x18], xmm0
+;;      	 f30f7f4c2408         	movdqu	xmmword ptr [rsp + 8], xmm1
+;;      	 4c893424             	mov	qword ptr [rsp], r14
+;;      	 f30f6f442408         	movdqu	xmm0, xmmword ptr [rsp + 8]
+;;      	 f30f6f4c2418         	movdqu	xmm1, xmmword ptr [rsp + 0x18]
+;;      	 f3440f6f3d30000000   	
+;; 				movdqu	xmm15, xmmword ptr [rip + 0x30]
+;;      	 66410f3800cf         	pshufb	xmm1, xmm15
+;;      	 f3440f6f3d31000000   	
+;; 				movdqu	xmm15, xmmword ptr [rip + 0x31]
+;;      	 66410f3800c7         	pshufb	xmm0, xmm15
+;;      	 660febc8             	por	xmm1, xmm0
+;;      	 660f6fc1             	movdqa	xmm0, xmm1
+;;      	 4883c428             	add	rsp, 0x28
+;;      	 5d                   	pop	rbp
+;;      	 c3                   	ret	
+;;   63:	 0f0b                 	ud2	
+;;   65:	 0000                 	add	byte ptr [rax], al
+;;   67:	 0000                 	add	byte ptr [rax], al
+;;   69:	 0000                 	add	byte ptr [rax], al
+;;   6b:	 0000                 	add	byte ptr [rax], al
+;;   6d:	 0000                 	add	byte ptr [rax], al
+;;   6f:	 0000                 	add	byte ptr [rax], al
+;;   71:	 800280               	add	byte ptr [rdx], 0x80
+;;   74:	 0480                 	add	al, 0x80
@@ -14,40 +14,46 @@
 )
 ;;      	 55                   	push	rbp
 ;;      	 4889e5               	mov	rbp, rsp
+;;      	 4989fe               	mov	r14, rdi
 ;;      	 4883ec08             	sub	rsp, 8
 ;;      	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
 ;;      	 4d8b1b               	mov	r11, qword ptr [r11]
 ;;      	 4939e3               	cmp	r11, rsp
-;;      	 0f870a000000         	ja	0x22
-;;   18:	 4c893424             	mov	qword ptr [rsp], r14
+;;      	 0f870a000000         	ja	0x25
+;;   1b:	 4c893424             	mov	qword ptr [rsp], r14
 ;;      	 4883c408             	add	rsp, 8
 ;;      	 5d                   	pop	rbp
 ;;      	 c3                   	ret	
-;;   22:	 0f0b                 	ud2	
+;;   25:	 0f0b                 	ud2	
 ;;
 ;;      	 55                   	push	rbp
 ;;      	 4889e5               	mov	rbp, rsp
+;;      	 4989fe               	mov	r14, rdi
 ;;      	 4883ec10             	sub	rsp, 0x10
 ;;      	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
 ;;      	 4d8b1b               	mov	r11, qword ptr [r11]
 ;;      	 4939e3               	cmp	r11, rsp
-;;      	 0f874a000000         	ja	0x62
-;;   18:	 897c240c             	mov	dword ptr [rsp + 0xc], edi
+;;      	 0f8756000000         	ja	0x71
+;;   1b:	 8954240c             	mov	dword ptr [rsp + 0xc], edx
 ;;      	 4c893424             	mov	qword ptr [rsp], r14
 ;;      	 8b44240c             	mov	eax, dword ptr [rsp + 0xc]
 ;;      	 85c0                 	test	eax, eax
-;;      	 0f840a000000         	je	0x36
-;;   2c:	 b801000000           	mov	eax, 1
-;;      	 e905000000           	jmp	0x3b
-;;   36:	 b800000000           	mov	eax, 0
+;;      	 0f840a000000         	je	0x39
+;;   2f:	 b801000000           	mov	eax, 1
+;;      	 e905000000           	jmp	0x3e
+;;   39:	 b800000000           	mov	eax, 0
 ;;      	 85c0                 	test	eax, eax
-;;      	 0f840f000000         	je	0x52
-;;   43:	 e800000000           	call	0x48
+;;      	 0f8415000000         	je	0x5b
+;;   46:	 4c89f7               	mov	rdi, r14
+;;      	 4c89f6               	mov	rsi, r14
+;;      	 e800000000           	call	0x51
 ;;      	 b802000000           	mov	eax, 2
-;;      	 e90a000000           	jmp	0x5c
-;;   52:	 e800000000           	call	0x57
+;;      	 e910000000           	jmp	0x6b
+;;   5b:	 4c89f7               	mov	rdi, r14
+;;      	 4c89f6               	mov	rsi, r14
+;;      	 e800000000           	call	0x66
 ;;      	 b803000000           	mov	eax, 3
 ;;      	 4883c410             	add	rsp, 0x10
 ;;      	 5d                   	pop	rbp
 ;;      	 c3                   	ret	
-;;   62:	 0f0b                 	ud2	
+;;   71:	 0f0b                 	ud2	
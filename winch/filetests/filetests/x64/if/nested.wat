@@ -24,66 +24,84 @@
 )
 ;;      	 55                   	push	rbp
 ;;      	 4889e5               	mov	rbp, rsp
+;;      	 4989fe               	mov	r14, rdi
 ;;      	 4883ec08             	sub	rsp, 8
 ;;      	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
 ;;      	 4d8b1b               	mov	r11, qword ptr [r11]
 ;;      	 4939e3               	cmp	r11, rsp
-;;      	 0f870a000000         	ja	0x22
-;;   18:	 4c893424             	mov	qword ptr [rsp], r14
+;;      	 0f870a000000         	ja	0x25
+;;   1b:	 4c893424             	mov	qword ptr [rsp], r14
 ;;      	 4883c408             	add	rsp, 8
 ;;      	 5d                   	pop	rbp
 ;;      	 c3                   	ret	
-;;   22:	 0f0b                 	ud2	
+;;   25:	 0f0b                 	ud2	
 ;;
 ;;      	 55                   	push	rbp
 ;;      	 4889e5               	mov	rbp, rsp
+;;      	 4989fe               	mov	r14, rdi
 ;;      	 4883ec10             	sub	rsp, 0x10
 ;;      	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
 ;;      	 4d8b1b               	mov	r11, qword ptr [r11]
 ;;      	 4939e3               	cmp	r11, rsp
-;;      	 0f87bb000000         	ja	0xd3
-;;   18:	 897c240c             	mov	dword ptr [rsp + 0xc], edi
-;;      	 89742408             	mov	dword ptr [rsp + 8], esi
+;;      	 0f87eb000000         	ja	0x106
+;;   1b:	 8954240c             	mov	dword ptr [rsp + 0xc], edx
+;;      	 894c2408             	mov	dword ptr [rsp + 8], ecx
 ;;      	 4c893424             	mov	qword ptr [rsp], r14
 ;;      	 8b44240c             	mov	eax, dword ptr [rsp + 0xc]
 ;;      	 85c0                 	test	eax, eax
-;;      	 0f8451000000         	je	0x81
-;;   30:	 8b442408             	mov	eax, dword ptr [rsp + 8]
+;;      	 0f8469000000         	je	0x9c
+;;   33:	 8b442408             	mov	eax, dword ptr [rsp + 8]
 ;;      	 85c0                 	test	eax, eax
-;;      	 0f8405000000         	je	0x41
-;;   3c:	 e800000000           	call	0x41
+;;      	 0f840b000000         	je	0x4a
+;;   3f:	 4c89f7               	mov	rdi, r14
+;;      	 4c89f6               	mov	rsi, r14
+;;      	 e800000000           	call	0x4a
 ;;      	 8b442408             	mov	eax, dword ptr [rsp + 8]
 ;;      	 85c0                 	test	eax, eax
-;;      	 0f8405000000         	je	0x52
-;;      	 e905000000           	jmp	0x57
-;;   52:	 e800000000           	call	0x57
+;;      	 0f8405000000         	je	0x5b
+;;      	 e90b000000           	jmp	0x66
+;;   5b:	 4c89f7               	mov	rdi, r14
+;;      	 4c89f6               	mov	rsi, r14
+;;      	 e800000000           	call	0x66
 ;;      	 8b442408             	mov	eax, dword ptr [rsp + 8]
 ;;      	 85c0                 	test	eax, eax
-;;      	 0f840f000000         	je	0x72
-;;   63:	 e800000000           	call	0x68
+;;      	 0f8415000000         	je	0x87
+;;   72:	 4c89f7               	mov	rdi, r14
+;;      	 4c89f6               	mov	rsi, r14
+;;      	 e800000000           	call	0x7d
 ;;      	 b809000000           	mov	eax, 9
-;;      	 e95b000000           	jmp	0xcd
-;;   72:	 e800000000           	call	0x77
+;;      	 e979000000           	jmp	0x100
+;;   87:	 4c89f7               	mov	rdi, r14
+;;      	 4c89f6               	mov	rsi, r14
+;;      	 e800000000           	call	0x92
 ;;      	 b80a000000           	mov	eax, 0xa
-;;      	 e94c000000           	jmp	0xcd
-;;   81:	 8b442408             	mov	eax, dword ptr [rsp + 8]
+;;      	 e964000000           	jmp	0x100
+;;   9c:	 8b442408             	mov	eax, dword ptr [rsp + 8]
 ;;      	 85c0                 	test	eax, eax
-;;      	 0f8405000000         	je	0x92
-;;   8d:	 e800000000           	call	0x92
+;;      	 0f840b000000         	je	0xb3
+;;   a8:	 4c89f7               	mov	rdi, r14
+;;      	 4c89f6               	mov	rsi, r14
+;;      	 e800000000           	call	0xb3
 ;;      	 8b442408             	mov	eax, dword ptr [rsp + 8]
 ;;      	 85c0                 	test	eax, eax
-;;      	 0f8405000000         	je	0xa3
-;;      	 e905000000           	jmp	0xa8
-;;   a3:	 e800000000           	call	0xa8
+;;      	 0f8405000000         	je	0xc4
+;;      	 e90b000000           	jmp	0xcf
+;;   c4:	 4c89f7               	mov	rdi, r14
+;;      	 4c89f6               	mov	rsi, r14
+;;      	 e800000000           	call	0xcf
 ;;      	 8b442408             	mov	eax, dword ptr [rsp + 8]
 ;;      	 85c0                 	test	eax, eax
-;;      	 0f840f000000         	je	0xc3
-;;   b4:	 e800000000           	call	0xb9
+;;      	 0f8415000000         	je	0xf0
+;;   db:	 4c89f7               	mov	rdi, r14
+;;      	 4c89f6               	mov	rsi, r14
+;;      	 e800000000           	call	0xe6
 ;;      	 b80a000000           	mov	eax, 0xa
-;;      	 e90a000000           	jmp	0xcd
-;;   c3:	 e800000000           	call	0xc8
+;;      	 e910000000           	jmp	0x100
+;;   f0:	 4c89f7               	mov	rdi, r14
+;;      	 4c89f6               	mov	rsi, r14
+;;      	 e800000000           	call	0xfb
 ;;      	 b80b000000           	mov	eax, 0xb
 ;;      	 4883c410             	add	rsp, 0x10
 ;;      	 5d                   	pop	rbp
 ;;      	 c3                   	ret	
-;;   d3:	 0f0b                 	ud2	
+;;  106:	 0f0b                 	ud2	
@@ -0,0 +1,77 @@
+;;! target = "x86_64"
+
+(module
+  (memory i64 1)
+  (func (param i64) (result i32)
+    (i32.load offset=16 (local.get 0))
+  )
+
+  (func (param i64) (result i64)
+    (i64.load8_u offset=4294967296 (local.get 0))
+  )
+)
+;;      	 55                   	push	rbp
+;;      	 4889e5               	mov	rbp, rsp
+;;      	 4989fe               	mov	r14, rdi
+;;      	 4883ec10             	sub	rsp, 0x10
+;;      	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;      	 4d8b1b               	mov	r11, qword ptr [r11]
+;;      	 4939e3               	cmp	r11, rsp
+;;      	 0f8749000000         	ja	0x64
+;;   1b:	 4889542408           	mov	qword ptr [rsp + 8], rdx
+;;      	 4c893424             	mov	qword ptr [rsp], r14
+;;      	 488b442408           	mov	rax, qword ptr [rsp + 8]
+;;      	 498b4e58             	mov	rcx, qword ptr [r14 + 0x58]
+;;      	 4889c2               	mov	rdx, rax
+;;      	 4883c214             	add	rdx, 0x14
+;;      	 0f822c000000         	jb	0x66
+;;   3a:	 4839ca               	cmp	rdx, rcx
+;;      	 0f8725000000         	ja	0x68
+;;   43:	 498b5e50             	mov	rbx, qword ptr [r14 + 0x50]
+;;      	 4801c3               	add	rbx, rax
+;;      	 4883c310             	add	rbx, 0x10
+;;      	 48c7c600000000       	mov	rsi, 0
+;;      	 4839ca               	cmp	rdx, rcx
+;;      	 480f47de             	cmova	rbx, rsi
+;;      	 8b03                 	mov	eax, dword ptr [rbx]
+;;      	 4883c410             	add	rsp, 0x10
+;;      	 5d                   	pop	rbp
+;;      	 c3                   	ret	
+;;   64:	 0f0b                 	ud2	
+;;   66:	 0f0b                 	ud2	
+;;   68:	 0f0b                 	ud2	
+;;
+;;      	 55                   	push	rbp
+;;      	 4889e5               	mov	rbp, rsp
+;;      	 4989fe               	mov	r14, rdi
+;;      	 4883ec10             	sub	rsp, 0x10
+;;      	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;      	 4d8b1b               	mov	r11, qword ptr [r11]
+;;      	 4939e3               	cmp	r11, rsp
+;;      	 0f875a000000         	ja	0x75
+;;   1b:	 4889542408           	mov	qword ptr [rsp + 8], rdx
+;;      	 4c893424             	mov	qword ptr [rsp], r14
+;;      	 488b442408           	mov	rax, qword ptr [rsp + 8]
+;;      	 49bb0000000001000000 	
+;; 				movabs	r11, 0x100000000
+;;      	 4c01d8               	add	rax, r11
+;;      	 0f823b000000         	jb	0x77
+;;   3c:	 498b4e58             	mov	rcx, qword ptr [r14 + 0x58]
+;;      	 4889c2               	mov	rdx, rax
+;;      	 4883c201             	add	rdx, 1
+;;      	 0f822c000000         	jb	0x79
+;;   4d:	 4839ca               	cmp	rdx, rcx
+;;      	 0f8725000000         	ja	0x7b
+;;   56:	 498b5e50             	mov	rbx, qword ptr [r14 + 0x50]
+;;      	 4801c3               	add	rbx, rax
+;;      	 48c7c600000000       	mov	rsi, 0
+;;      	 4839ca               	cmp	rdx, rcx
+;;      	 480f47de             	cmova	rbx, rsi
+;;      	 480fb603             	movzx	rax, byte ptr [rbx]
+;;      	 4883c410             	add	rsp, 0x10
+;;      	 5d                   	pop	rbp
+;;      	 c3                   	ret	
+;;   75:	 0f0b                 	ud2	
+;;   77:	 0f0b                 	ud2	
+;;   79:	 0f0b                 	ud2	
+;;   7b:	 0f0b                 	ud2	
@@ -0,0 +1,41 @@
+;;! target = "x86_64"
+;;! [tunables]
+;;! static_memory_bound = 0
+
+(module
+  (memory 1)
+  (func (param i32) (result i32)
+    (i32.load16_s offset=4 (local.get 0))
+  )
+)
+;;      	 55                   	push	rbp
+;;      	 4889e5               	mov	rbp, rsp
+;;      	 4989fe               	mov	r14, rdi
+;;      	 4883ec10             	sub	rsp, 0x10
+;;      	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;      	 4d8b1b               	mov	r11, qword ptr [r11]
+;;      	 4939e3               	cmp	r11, rsp
+;;      	 0f874a000000         	ja	0x65
+;;   1b:	 8954240c             	mov	dword ptr [rsp + 0xc], edx
+;;      	 4c893424             	mov	qword ptr [rsp], r14
+;;      	 8b44240c             	mov	eax, dword ptr [rsp + 0xc]
+;;      	 8bc0                 	mov	eax, eax
+;;      	 498b4e58             	mov	rcx, qword ptr [r14 + 0x58]
+;;      	 4889c2               	mov	rdx, rax
+;;      	 4883c206             	add	rdx, 6
+;;      	 0f822d000000         	jb	0x67
+;;   3a:	 4839ca               	cmp	rdx, rcx
+;;      	 0f8726000000         	ja	0x69
+;;   43:	 498b5e50             	mov	rbx, qword ptr [r14 + 0x50]
+;;      	 4801c3               	add	rbx, rax
+;;      	 4883c304             	add	rbx, 4
+;;      	 48c7c600000000       	mov	rsi, 0
+;;      	 4839ca               	cmp	rdx, rcx
+;;      	 480f47de             	cmova	rbx, rsi
+;;      	 0fbf03               	movsx	eax, word ptr [rbx]
+;;      	 4883c410             	add	rsp, 0x10
+;;      	 5d                   	pop	rbp
+;;      	 c3                   	ret	
+;;   65:	 0f0b                 	ud2	
+;;   67:	 0f0b                 	ud2	
+;;   69:	 0f0b                 	ud2	
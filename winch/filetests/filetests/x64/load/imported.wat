@@ -0,0 +1,39 @@
+;;! target = "x86_64"
+
+(module
+  (import "env" "memory" (memory i64 1))
+  (func (param i64) (result f64)
+    (f64.load (local.get 0))
+  )
+)
+;;      	 55                   	push	rbp
+;;      	 4889e5               	mov	rbp, rsp
+;;      	 4989fe               	mov	r14, rdi
+;;      	 4883ec10             	sub	rsp, 0x10
+;;      	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;      	 4d8b1b               	mov	r11, qword ptr [r11]
+;;      	 4939e3               	cmp	r11, rsp
+;;      	 0f874e000000         	ja	0x69
+;;   1b:	 4889542408           	mov	qword ptr [rsp + 8], rdx
+;;      	 4c893424             	mov	qword ptr [rsp], r14
+;;      	 488b442408           	mov	rax, qword ptr [rsp + 8]
+;;      	 4d8b5e48             	mov	r11, qword ptr [r14 + 0x48]
+;;      	 498b4b08             	mov	rcx, qword ptr [r11 + 8]
+;;      	 4889c2               	mov	rdx, rax
+;;      	 4883c208             	add	rdx, 8
+;;      	 0f822d000000         	jb	0x6b
+;;   3e:	 4839ca               	cmp	rdx, rcx
+;;      	 0f8726000000         	ja	0x6d
+;;   47:	 4d8b5e48             	mov	r11, qword ptr [r14 + 0x48]
+;;      	 498b1b               	mov	rbx, qword ptr [r11]
+;;      	 4801c3               	add	rbx, rax
+;;      	 48c7c600000000       	mov	rsi, 0
+;;      	 4839ca               	cmp	rdx, rcx
+;;      	 480f47de             	cmova	rbx, rsi
+;;      	 f20f1003             	movsd	xmm0, qword ptr [rbx]
+;;      	 4883c410             	add	rsp, 0x10
+;;      	 5d                   	pop	rbp
+;;      	 c3                   	ret	
+;;   69:	 0f0b                 	ud2	
+;;   6b:	 0f0b                 	ud2	
+;;   6d:	 0f0b                 	ud2	
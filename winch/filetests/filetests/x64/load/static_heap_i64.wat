@@ -0,0 +1,75 @@
+;;! target = "x86_64"
+
+(module
+  (memory i64 1 1)
+  (func (param i64) (result i32)
+    (i32.load offset=4 (local.get 0))
+  )
+
+  (func (param i64) (result i32)
+    (i32.load offset=4294967296 (local.get 0))
+  )
+)
+;;      	 55                   	push	rbp
+;;      	 4889e5               	mov	rbp, rsp
+;;      	 4989fe               	mov	r14, rdi
+;;      	 4883ec10             	sub	rsp, 0x10
+;;      	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;      	 4d8b1b               	mov	r11, qword ptr [r11]
+;;      	 4939e3               	cmp	r11, rsp
+;;      	 0f874c000000         	ja	0x67
+;;   1b:	 4889542408           	mov	qword ptr [rsp + 8], rdx
+;;      	 4c893424             	mov	qword ptr [rsp], r14
+;;      	 488b442408           	mov	rax, qword ptr [rsp + 8]
+;;      	 49bbf8ffffff00000000 	
+;; 				movabs	r11, 0xfffffff8
+;;      	 4c39d8               	cmp	rax, r11
+;;      	 0f872d000000         	ja	0x69
+;;   3c:	 498b4e50             	mov	rcx, qword ptr [r14 + 0x50]
+;;      	 4801c1               	add	rcx, rax
+;;      	 4883c104             	add	rcx, 4
+;;      	 48c7c200000000       	mov	rdx, 0
+;;      	 49bbf8ffffff00000000 	
+;; 				movabs	r11, 0xfffffff8
+;;      	 4c39d8               	cmp	rax, r11
+;;      	 480f47ca             	cmova	rcx, rdx
+;;      	 8b01                 	mov	eax, dword ptr [rcx]
+;;      	 4883c410             	add	rsp, 0x10
+;;      	 5d                   	pop	rbp
+;;      	 c3                   	ret	
+;;   67:	 0f0b                 	ud2	
+;;   69:	 0f0b                 	ud2	
+;;
+;;      	 55                   	push	rbp
+;;      	 4889e5               	mov	rbp, rsp
+;;      	 4989fe               	mov	r14, rdi
+;;      	 4883ec10             	sub	rsp, 0x10
+;;      	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;      	 4d8b1b               	mov	r11, qword ptr [r11]
+;;      	 4939e3               	cmp	r11, rsp
+;;      	 0f875b000000         	ja	0x76
+;;   1b:	 4889542408           	mov	qword ptr [rsp + 8], rdx
+;;      	 4c893424             	mov	qword ptr [rsp], r14
+;;      	 488b442408           	mov	rax, qword ptr [rsp + 8]
+;;      	 49bb0000000001000000 	
+;; 				movabs	r11, 0x100000000
+;;      	 4c01d8               	add	rax, r11
+;;      	 0f823c000000         	jb	0x78
+;;   3c:	 49bbfcffffff00000000 	
+;; 				movabs	r11, 0xfffffffc
+;;      	 4c39d8               	cmp	rax, r11
+;;      	 0f872b000000         	ja	0x7a
+;;   4f:	 498b4e50             	mov	rcx, qword ptr [r14 + 0x50]
+;;      	 4801c1               	add	rcx, rax
+;;      	 48c7c200000000       	mov	rdx, 0
+;;      	 49bbfcffffff00000000 	
+;; 				movabs	r11, 0xfffffffc
+;;      	 4c39d8               	cmp	rax, r11
+;;      	 480f47ca             	cmova	rcx, rdx
+;;      	 8b01                 	mov	eax, dword ptr [rcx]
+;;      	 4883c410             	add	rsp, 0x10
+;;      	 5d                   	pop	rbp
+;;      	 c3                   	ret	
+;;   76:	 0f0b                 	ud2	
+;;   78:	 0f0b                 	ud2	
+;;   7a:	 0f0b                 	ud2	
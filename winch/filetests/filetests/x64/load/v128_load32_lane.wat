@@ -0,0 +1,33 @@
+;;! target = "x86_64"
+;;! flags = ["has_sse3", "has_ssse3", "has_sse41", "has_sse42"]
+
+(module
+    (memory 1)
+    (func (param i32 v128) (result v128)
+        (local.get 0)
+        (local.get 1)
+        (v128.load32_lane 2)
+    )
+)
+;;      	 55                   	push	rbp
+;;      	 4889e5               	mov	rbp, rsp
+;;      	 4989fe               	mov	r14, rdi
+;;      	 4883ec28             	sub	rsp, 0x28
+;;      	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;      	 4d8b1b               	mov	r11, qword ptr [r11]
+;;      	 4939e3               	cmp	r11, rsp
+;;      	 0f872f000000         	ja	0x4a
+;;   1b:	 89542424             	mov	dword ptr [rsp + 0x24], edx
+;;      	 f30f7f442408         	movdqu	xmmword ptr [rsp + 8], xmm0
+;;      	 4c893424             	mov	qword ptr [rsp], r14
+;;      	 f30f6f442408         	movdqu	xmm0, xmmword ptr [rsp + 8]
+;;      	 8b442424             	mov	eax, dword ptr [rsp + 0x24]
+;;      	 8bc0                 	mov	eax, eax
+;;      	 498b4e50             	mov	rcx, qword ptr [r14 + 0x50]
+;;      	 4801c1               	add	rcx, rax
+;;      	 8b01                 	mov	eax, dword ptr [rcx]
+;;      	 660f3a22c002         	pinsrd	xmm0, eax, 2
+;;      	 4883c428             	add	rsp, 0x28
+;;      	 5d                   	pop	rbp
+;;      	 c3                   	ret	
+;;   4a:	 0f0b                 	ud2	
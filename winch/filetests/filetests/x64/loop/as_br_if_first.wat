@@ -6,17 +6,18 @@
 )
 ;;      	 55                   	push	rbp
 ;;      	 4889e5               	mov	rbp, rsp
+;;      	 4989fe               	mov	r14, rdi
 ;;      	 4883ec08             	sub	rsp, 8
 ;;      	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
 ;;      	 4d8b1b               	mov	r11, qword ptr [r11]
 ;;      	 4939e3               	cmp	r11, rsp
-;;      	 0f871c000000         	ja	0x34
-;;   18:	 4c893424             	mov	qword ptr [rsp], r14
+;;      	 0f871c000000         	ja	0x37
+;;   1b:	 4c893424             	mov	qword ptr [rsp], r14
 ;;      	 b902000000           	mov	ecx, 2
 ;;      	 b801000000           	mov	eax, 1
 ;;      	 85c9                 	test	ecx, ecx
-;;      	 0f8500000000         	jne	0x2e
-;;   2e:	 4883c408             	add	rsp, 8
+;;      	 0f8500000000         	jne	0x31
+;;   31:	 4883c408             	add	rsp, 8
 ;;      	 5d                   	pop	rbp
 ;;      	 c3                   	ret	
-;;   34:	 0f0b                 	ud2	
+;;   37:	 0f0b                 	ud2	
@@ -11,21 +11,22 @@
 )
 ;;      	 55                   	push	rbp
 ;;      	 4889e5               	mov	rbp, rsp
+;;      	 4989fe               	mov	r14, rdi
 ;;      	 4883ec10             	sub	rsp, 0x10
 ;;      	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
 ;;      	 4d8b1b               	mov	r11, qword ptr [r11]
 ;;      	 4939e3               	cmp	r11, rsp
-;;      	 0f872e000000         	ja	0x46
-;;   18:	 48c744240800000000   	
+;;      	 0f872e000000         	ja	0x49
+;;   1b:	 48c744240800000000   	
 ;; 				mov	qword ptr [rsp + 8], 0
 ;;      	 4c893424             	mov	qword ptr [rsp], r14
 ;;      	 b800000000           	mov	eax, 0
 ;;      	 8944240c             	mov	dword ptr [rsp + 0xc], eax
 ;;      	 448b5c240c           	mov	r11d, dword ptr [rsp + 0xc]
-;;      	 4883ec04             	sub	rsp, 4
+;;      	 4883ec08             	sub	rsp, 8
 ;;      	 44891c24             	mov	dword ptr [rsp], r11d
-;;      	 e9fbffffff           	jmp	0x3b
-;;   40:	 4883c410             	add	rsp, 0x10
+;;      	 e9fbffffff           	jmp	0x3e
+;;   43:	 4883c410             	add	rsp, 0x10
 ;;      	 5d                   	pop	rbp
 ;;      	 c3                   	ret	
-;;   46:	 0f0b                 	ud2	
+;;   49:	 0f0b                 	ud2	
@@ -17,12 +17,13 @@
 )
 ;;      	 55                   	push	rbp
 ;;      	 4889e5               	mov	rbp, rsp
+;;      	 4989fe               	mov	r14, rdi
 ;;      	 4883ec20             	sub	rsp, 0x20
 ;;      	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
 ;;      	 4d8b1b               	mov	r11, qword ptr [r11]
 ;;      	 4939e3               	cmp	r11, rsp
-;;      	 0f877d000000         	ja	0x95
-;;   18:	 48897c2418           	mov	qword ptr [rsp + 0x18], rdi
+;;      	 0f877d000000         	ja	0x98
+;;   1b:	 4889542418           	mov	qword ptr [rsp + 0x18], rdx
 ;;      	 4531db               	xor	r11d, r11d
 ;;      	 4c895c2410           	mov	qword ptr [rsp + 0x10], r11
 ;;      	 4c895c2408           	mov	qword ptr [rsp + 8], r11
@@ -37,17 +38,17 @@
 ;;      	 b900000000           	mov	ecx, 0
 ;;      	 400f97c1             	seta	cl
 ;;      	 85c9                 	test	ecx, ecx
-;;      	 0f8526000000         	jne	0x8a
-;;   64:	 488b442408           	mov	rax, qword ptr [rsp + 8]
+;;      	 0f8526000000         	jne	0x8d
+;;   67:	 488b442408           	mov	rax, qword ptr [rsp + 8]
 ;;      	 488b4c2410           	mov	rcx, qword ptr [rsp + 0x10]
 ;;      	 480fafc8             	imul	rcx, rax
 ;;      	 48894c2410           	mov	qword ptr [rsp + 0x10], rcx
 ;;      	 488b442408           	mov	rax, qword ptr [rsp + 8]
 ;;      	 4883c001             	add	rax, 1
 ;;      	 4889442408           	mov	qword ptr [rsp + 8], rax
-;;      	 e9bcffffff           	jmp	0x46
-;;   8a:	 488b442410           	mov	rax, qword ptr [rsp + 0x10]
+;;      	 e9bcffffff           	jmp	0x49
+;;   8d:	 488b442410           	mov	rax, qword ptr [rsp + 0x10]
 ;;      	 4883c420             	add	rsp, 0x20
 ;;      	 5d                   	pop	rbp
 ;;      	 c3                   	ret	
-;;   95:	 0f0b                 	ud2	
+;;   98:	 0f0b                 	ud2	
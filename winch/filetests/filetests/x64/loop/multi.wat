@@ -9,48 +9,64 @@
 )
 ;;      	 55                   	push	rbp
 ;;      	 4889e5               	mov	rbp, rsp
+;;      	 4989fe               	mov	r14, rdi
 ;;      	 4883ec08             	sub	rsp, 8
 ;;      	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
 ;;      	 4d8b1b               	mov	r11, qword ptr [r11]
 ;;      	 4939e3               	cmp	r11, rsp
-;;      	 0f870a000000         	ja	0x22
-;;   18:	 4c893424             	mov	qword ptr [rsp], r14
+;;      	 0f870a000000         	ja	0x25
+;;   1b:	 4c893424             	mov	qword ptr [rsp], r14
 ;;      	 4883c408             	add	rsp, 8
 ;;      	 5d                   	pop	rbp
 ;;      	 c3                   	ret	
-;;   22:	 0f0b                 	ud2	
+;;   25:	 0f0b                 	ud2	
 ;;
 ;;      	 55                   	push	rbp
 ;;      	 4889e5               	mov	rbp, rsp
+;;      	 4989fe               	mov	r14, rdi
 ;;      	 4883ec08             	sub	rsp, 8
 ;;      	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
 ;;      	 4d8b1b               	mov	r11, qword ptr [r11]
 ;;      	 4939e3               	cmp	r11, rsp
-;;      	 0f876a000000         	ja	0x82
-;;   18:	 4c893424             	mov	qword ptr [rsp], r14
+;;      	 0f8794000000         	ja	0xaf
+;;   1b:	 4c893424             	mov	qword ptr [rsp], r14
 ;;      	 4883ec08             	sub	rsp, 8
-;;      	 e800000000           	call	0x25
+;;      	 4c89f7               	mov	rdi, r14
+;;      	 4c89f6               	mov	rsi, r14
+;;      	 e800000000           	call	0x2e
 ;;      	 4883c408             	add	rsp, 8
 ;;      	 4883ec08             	sub	rsp, 8
-;;      	 e800000000           	call	0x32
+;;      	 4c89f7               	mov	rdi, r14
+;;      	 4c89f6               	mov	rsi, r14
+;;      	 e800000000           	call	0x41
 ;;      	 4883c408             	add	rsp, 8
 ;;      	 4883ec08             	sub	rsp, 8
-;;      	 e800000000           	call	0x3f
+;;      	 4c89f7               	mov	rdi, r14
+;;      	 4c89f6               	mov	rsi, r14
+;;      	 e800000000           	call	0x54
 ;;      	 4883c408             	add	rsp, 8
 ;;      	 4883ec08             	sub	rsp, 8
-;;      	 e800000000           	call	0x4c
+;;      	 4c89f7               	mov	rdi, r14
+;;      	 4c89f6               	mov	rsi, r14
+;;      	 e800000000           	call	0x67
 ;;      	 4883c408             	add	rsp, 8
 ;;      	 4883ec08             	sub	rsp, 8
-;;      	 e800000000           	call	0x59
+;;      	 4c89f7               	mov	rdi, r14
+;;      	 4c89f6               	mov	rsi, r14
+;;      	 e800000000           	call	0x7a
 ;;      	 4883c408             	add	rsp, 8
 ;;      	 4883ec08             	sub	rsp, 8
-;;      	 e800000000           	call	0x66
+;;      	 4c89f7               	mov	rdi, r14
+;;      	 4c89f6               	mov	rsi, r14
+;;      	 e800000000           	call	0x8d
 ;;      	 4883c408             	add	rsp, 8
 ;;      	 4883ec08             	sub	rsp, 8
-;;      	 e800000000           	call	0x73
+;;      	 4c89f7               	mov	rdi, r14
+;;      	 4c89f6               	mov	rsi, r14
+;;      	 e800000000           	call	0xa0
 ;;      	 4883c408             	add	rsp, 8
 ;;      	 b808000000           	mov	eax, 8
 ;;      	 4883c408             	add	rsp, 8
 ;;      	 5d                   	pop	rbp
 ;;      	 c3                   	ret	
-;;   82:	 0f0b                 	ud2	
+;;   af:	 0f0b                 	ud2	
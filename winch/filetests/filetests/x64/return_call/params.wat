@@ -0,0 +1,94 @@
+;;! target = "x86_64"
+(module
+  (func $callee (param i64 i64 i64 i64 i64 i64 i64 i64 i64 i64) (result i64)
+    (i64.add (local.get 0) (local.get 9)))
+  (func (param i64) (result i64)
+    (return_call $callee
+      (local.get 0)
+      (i64.const 1)
+      (i64.const 2)
+      (i64.const 3)
+      (i64.const 4)
+      (i64.const 5)
+      (i64.const 6)
+      (i64.const 7)
+      (i64.const 8)
+      (i64.const 9)))
+)
+;;      	 55                   	push	rbp
+;;      	 4889e5               	mov	rbp, rsp
+;;      	 4989fe               	mov	r14, rdi
+;;      	 4883ec28             	sub	rsp, 0x28
+;;      	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;      	 4d8b1b               	mov	r11, qword ptr [r11]
+;;      	 4939e3               	cmp	r11, rsp
+;;      	 0f872f000000         	ja	0x4a
+;;   1b:	 4889542420           	mov	qword ptr [rsp + 0x20], rdx
+;;      	 48894c2418           	mov	qword ptr [rsp + 0x18], rcx
+;;      	 4c89442410           	mov	qword ptr [rsp + 0x10], r8
+;;      	 4c894c2408           	mov	qword ptr [rsp + 8], r9
+;;      	 4c893424             	mov	qword ptr [rsp], r14
+;;      	 488b4538             	mov	rax, qword ptr [rbp + 0x38]
+;;      	 488b4c2420           	mov	rcx, qword ptr [rsp + 0x20]
+;;      	 4801c1               	add	rcx, rax
+;;      	 4889c8               	mov	rax, rcx
+;;      	 4883c428             	add	rsp, 0x28
+;;      	 5d                   	pop	rbp
+;;      	 c23000               	ret	0x30
+;;   4a:	 0f0b                 	ud2	
+;;
+;;      	 55                   	push	rbp
+;;      	 4889e5               	mov	rbp, rsp
+;;      	 4989fe               	mov	r14, rdi
+;;      	 4883ec10             	sub	rsp, 0x10
+;;      	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;      	 4d8b1b               	mov	r11, qword ptr [r11]
+;;      	 4939e3               	cmp	r11, rsp
+;;      	 0f87df000000         	ja	0xfa
+;;   1b:	 4889542408           	mov	qword ptr [rsp + 8], rdx
+;;      	 4c893424             	mov	qword ptr [rsp], r14
+;;      	 4c8b5c2408           	mov	r11, qword ptr [rsp + 8]
+;;      	 4153                 	push	r11
+;;      	 4883ec30             	sub	rsp, 0x30
+;;      	 4c89f7               	mov	rdi, r14
+;;      	 4c89f6               	mov	rsi, r14
+;;      	 488b542430           	mov	rdx, qword ptr [rsp + 0x30]
+;;      	 48c7c101000000       	mov	rcx, 1
+;;      	 49c7c002000000       	mov	r8, 2
+;;      	 49c7c103000000       	mov	r9, 3
+;;      	 49c7c304000000       	mov	r11, 4
+;;      	 4c891c24             	mov	qword ptr [rsp], r11
+;;      	 49c7c305000000       	mov	r11, 5
+;;      	 4c895c2408           	mov	qword ptr [rsp + 8], r11
+;;      	 49c7c306000000       	mov	r11, 6
+;;      	 4c895c2410           	mov	qword ptr [rsp + 0x10], r11
+;;      	 49c7c307000000       	mov	r11, 7
+;;      	 4c895c2418           	mov	qword ptr [rsp + 0x18], r11
+;;      	 49c7c308000000       	mov	r11, 8
+;;      	 4c895c2420           	mov	qword ptr [rsp + 0x20], r11
+;;      	 49c7c309000000       	mov	r11, 9
+;;      	 4c895c2428           	mov	qword ptr [rsp + 0x28], r11
+;;      	 4883ec08             	sub	rsp, 8
+;;      	 4c8b5d08             	mov	r11, qword ptr [rbp + 8]
+;;      	 4c891c24             	mov	qword ptr [rsp], r11
+;;      	 488b6d00             	mov	rbp, qword ptr [rbp]
+;;      	 4c8b5c2430           	mov	r11, qword ptr [rsp + 0x30]
+;;      	 4c895c2458           	mov	qword ptr [rsp + 0x58], r11
+;;      	 4c8b5c2428           	mov	r11, qword ptr [rsp + 0x28]
+;;      	 4c895c2450           	mov	qword ptr [rsp + 0x50], r11
+;;      	 4c8b5c2420           	mov	r11, qword ptr [rsp + 0x20]
+;;      	 4c895c2448           	mov	qword ptr [rsp + 0x48], r11
+;;      	 4c8b5c2418           	mov	r11, qword ptr [rsp + 0x18]
+;;      	 4c895c2440           	mov	qword ptr [rsp + 0x40], r11
+;;      	 4c8b5c2410           	mov	r11, qword ptr [rsp + 0x10]
+;;      	 4c895c2438           	mov	qword ptr [rsp + 0x38], r11
+;;      	 4c8b5c2408           	mov	r11, qword ptr [rsp + 8]
+;;      	 4c895c2430           	mov	qword ptr [rsp + 0x30], r11
+;;      	 4c8b1c24             	mov	r11, qword ptr [rsp]
+;;      	 4c895c2428           	mov	qword ptr [rsp + 0x28], r11
+;;      	 4883c428             	add	rsp, 0x28
+;;      	 e900000000           	jmp	0xf4
+;;   f4:	 4883c410             	add	rsp, 0x10
+;;      	 5d                   	pop	rbp
+;;      	 c3                   	ret	
+;;   fa:	 0f0b                 	ud2	
@@ -0,0 +1,52 @@
+;;! target = "x86_64"
+(module
+  (func $fact (param i64 i64) (result i64)
+    (if (result i64) (i64.eqz (local.get 0))
+      (then (local.get 1))
+      (else
+        (return_call $fact
+          (i64.sub (local.get 0) (i64.const 1))
+          (i64.mul (local.get 0) (local.get 1))))))
+)
+;;      	 55                   	push	rbp
+;;      	 4889e5               	mov	rbp, rsp
+;;      	 4989fe               	mov	r14, rdi
+;;      	 4883ec18             	sub	rsp, 0x18
+;;      	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;      	 4d8b1b               	mov	r11, qword ptr [r11]
+;;      	 4939e3               	cmp	r11, rsp
+;;      	 0f8782000000         	ja	0x9d
+;;   1b:	 4889542410           	mov	qword ptr [rsp + 0x10], rdx
+;;      	 48894c2408           	mov	qword ptr [rsp + 8], rcx
+;;      	 4c893424             	mov	qword ptr [rsp], r14
+;;      	 488b442410           	mov	rax, qword ptr [rsp + 0x10]
+;;      	 4883f800             	cmp	rax, 0
+;;      	 b800000000           	mov	eax, 0
+;;      	 400f94c0             	sete	al
+;;      	 85c0                 	test	eax, eax
+;;      	 0f840a000000         	je	0x4d
+;;   43:	 488b442408           	mov	rax, qword ptr [rsp + 8]
+;;      	 e94a000000           	jmp	0x97
+;;   4d:	 488b442410           	mov	rax, qword ptr [rsp + 0x10]
+;;      	 4883e801             	sub	rax, 1
+;;      	 488b4c2408           	mov	rcx, qword ptr [rsp + 8]
+;;      	 488b542410           	mov	rdx, qword ptr [rsp + 0x10]
+;;      	 480fafd1             	imul	rdx, rcx
+;;      	 50                   	push	rax
+;;      	 52                   	push	rdx
+;;      	 4c89f7               	mov	rdi, r14
+;;      	 4c89f6               	mov	rsi, r14
+;;      	 488b542408           	mov	rdx, qword ptr [rsp + 8]
+;;      	 488b0c24             	mov	rcx, qword ptr [rsp]
+;;      	 4883ec08             	sub	rsp, 8
+;;      	 4c8b5d08             	mov	r11, qword ptr [rbp + 8]
+;;      	 4c891c24             	mov	qword ptr [rsp], r11
+;;      	 488b6d00             	mov	rbp, qword ptr [rbp]
+;;      	 4c8b1c24             	mov	r11, qword ptr [rsp]
+;;      	 4c895c2438           	mov	qword ptr [rsp + 0x38], r11
+;;      	 4883c438             	add	rsp, 0x38
+;;      	 e900000000           	jmp	0x97
+;;   97:	 4883c418             	add	rsp, 0x18
+;;      	 5d                   	pop	rbp
+;;      	 c3                   	ret	
+;;   9d:	 0f0b                 	ud2	
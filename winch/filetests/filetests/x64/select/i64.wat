@@ -8,14 +8,15 @@
  
 ;;      	 55                   	push	rbp
 ;;      	 4889e5               	mov	rbp, rsp
+;;      	 4989fe               	mov	r14, rdi
 ;;      	 4883ec20             	sub	rsp, 0x20
 ;;      	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
 ;;      	 4d8b1b               	mov	r11, qword ptr [r11]
 ;;      	 4939e3               	cmp	r11, rsp
-;;      	 0f8730000000         	ja	0x48
-;;   18:	 48897c2418           	mov	qword ptr [rsp + 0x18], rdi
-;;      	 4889742410           	mov	qword ptr [rsp + 0x10], rsi
-;;      	 8954240c             	mov	dword ptr [rsp + 0xc], edx
+;;      	 0f8731000000         	ja	0x4c
+;;   1b:	 4889542418           	mov	qword ptr [rsp + 0x18], rdx
+;;      	 48894c2410           	mov	qword ptr [rsp + 0x10], rcx
+;;      	 448944240c           	mov	dword ptr [rsp + 0xc], r8d
 ;;      	 4c893424             	mov	qword ptr [rsp], r14
 ;;      	 8b44240c             	mov	eax, dword ptr [rsp + 0xc]
 ;;      	 488b4c2410           	mov	rcx, qword ptr [rsp + 0x10]
@@ -26,4 +27,4 @@
 ;;      	 4883c420             	add	rsp, 0x20
 ;;      	 5d                   	pop	rbp
 ;;      	 c3                   	ret	
-;;   48:	 0f0b                 	ud2	
+;;   4c:	 0f0b                 	ud2	
@@ -0,0 +1,33 @@
+;;! target = "x86_64"
+;;! flags = ["has_sse3", "has_ssse3", "has_sse41", "has_sse42"]
+
+(module
+    (func (param v128 v128 i32) (result v128)
+        (local.get 0)
+        (local.get 1)
+        (local.get 2)
+        (select)
+    )
+)
+;;      	 55                   	push	rbp
+;;      	 4889e5               	mov	rbp, rsp
+;;      	 4989fe               	mov	r14, rdi
+;;      	 4883ec30             	sub	rsp, 0x30
+;;      	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;      	 4d8b1b               	mov	r11, qword ptr [r11]
+;;      	 4939e3               	cmp	r11, rsp
+;;      	 0f8737000000         	ja	0x52
+;;   1b:	 f30f7f442420         	movdqu	xmmword ptr [rsp + 0x20], xmm0
+;;      	 f30f7f4c2410         	movdqu	xmmword ptr [rsp + 0x10], xmm1
+;;      	 8954240c             	mov	dword ptr [rsp + 0xc], edx
+;;      	 4c893424             	mov	qword ptr [rsp], r14
+;;      	 8b44240c             	mov	eax, dword ptr [rsp + 0xc]
+;;      	 f30f6f442410         	movdqu	xmm0, xmmword ptr [rsp + 0x10]
+;;      	 f30f6f4c2420         	movdqu	xmm1, xmmword ptr [rsp + 0x20]
+;;      	 83f800               	cmp	eax, 0
+;;      	 0f8404000000         	je	0x4c
+;;   48:	 660f6fc1             	movdqa	xmm0, xmm1
+;;      	 4883c430             	add	rsp, 0x30
+;;      	 5d                   	pop	rbp
+;;      	 c3                   	ret	
+;;   52:	 0f0b                 	ud2	
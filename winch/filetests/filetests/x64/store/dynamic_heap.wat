@@ -0,0 +1,40 @@
+;;! target = "x86_64"
+
+(module
+  (memory i64 1)
+  (func (param i64 f64)
+    (f64.store offset=8 (local.get 0) (local.get 1))
+  )
+)
+;;      	 55                   	push	rbp
+;;      	 4889e5               	mov	rbp, rsp
+;;      	 4989fe               	mov	r14, rdi
+;;      	 4883ec18             	sub	rsp, 0x18
+;;      	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;      	 4d8b1b               	mov	r11, qword ptr [r11]
+;;      	 4939e3               	cmp	r11, rsp
+;;      	 0f8757000000         	ja	0x72
+;;   1b:	 4889542410           	mov	qword ptr [rsp + 0x10], rdx
+;;      	 f20f11442408         	movsd	qword ptr [rsp + 8], xmm0
+;;      	 4c893424             	mov	qword ptr [rsp], r14
+;;      	 f20f10442408         	movsd	xmm0, qword ptr [rsp + 8]
+;;      	 488b442410           	mov	rax, qword ptr [rsp + 0x10]
+;;      	 498b4e58             	mov	rcx, qword ptr [r14 + 0x58]
+;;      	 4889c2               	mov	rdx, rax
+;;      	 4883c210             	add	rdx, 0x10
+;;      	 0f822e000000         	jb	0x74
+;;   46:	 4839ca               	cmp	rdx, rcx
+;;      	 0f8727000000         	ja	0x76
+;;   4f:	 498b5e50             	mov	rbx, qword ptr [r14 + 0x50]
+;;      	 4801c3               	add	rbx, rax
+;;      	 4883c308             	add	rbx, 8
+;;      	 48c7c600000000       	mov	rsi, 0
+;;      	 4839ca               	cmp	rdx, rcx
+;;      	 480f47de             	cmova	rbx, rsi
+;;      	 f20f1103             	movsd	qword ptr [rbx], xmm0
+;;      	 4883c418             	add	rsp, 0x18
+;;      	 5d                   	pop	rbp
+;;      	 c3                   	ret	
+;;   72:	 0f0b                 	ud2	
+;;   74:	 0f0b                 	ud2	
+;;   76:	 0f0b                 	ud2	
@@ -0,0 +1,56 @@
+;;! target = "x86_64"
+
+(module
+  (memory 1)
+  (func (param i32 i64)
+    (i64.store16 (local.get 0) (local.get 1))
+  )
+
+  (func (param i32)
+    (i64.store32 offset=4 (local.get 0) (i64.const 0x1_0000_0001))
+  )
+)
+;;      	 55                   	push	rbp
+;;      	 4889e5               	mov	rbp, rsp
+;;      	 4989fe               	mov	r14, rdi
+;;      	 4883ec18             	sub	rsp, 0x18
+;;      	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;      	 4d8b1b               	mov	r11, qword ptr [r11]
+;;      	 4939e3               	cmp	r11, rsp
+;;      	 0f8728000000         	ja	0x43
+;;   1b:	 89542414             	mov	dword ptr [rsp + 0x14], edx
+;;      	 48894c2408           	mov	qword ptr [rsp + 8], rcx
+;;      	 4c893424             	mov	qword ptr [rsp], r14
+;;      	 488b442408           	mov	rax, qword ptr [rsp + 8]
+;;      	 8b4c2414             	mov	ecx, dword ptr [rsp + 0x14]
+;;      	 8bc9                 	mov	ecx, ecx
+;;      	 498b5650             	mov	rdx, qword ptr [r14 + 0x50]
+;;      	 4801ca               	add	rdx, rcx
+;;      	 668902               	mov	word ptr [rdx], ax
+;;      	 4883c418             	add	rsp, 0x18
+;;      	 5d                   	pop	rbp
+;;      	 c3                   	ret	
+;;   43:	 0f0b                 	ud2	
+;;
+;;      	 55                   	push	rbp
+;;      	 4889e5               	mov	rbp, rsp
+;;      	 4989fe               	mov	r14, rdi
+;;      	 4883ec10             	sub	rsp, 0x10
+;;      	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;      	 4d8b1b               	mov	r11, qword ptr [r11]
+;;      	 4939e3               	cmp	r11, rsp
+;;      	 0f872b000000         	ja	0x46
+;;   1b:	 8954240c             	mov	dword ptr [rsp + 0xc], edx
+;;      	 4c893424             	mov	qword ptr [rsp], r14
+;;      	 48b80100000001000000 	
+;; 				movabs	rax, 0x100000001
+;;      	 8b4c240c             	mov	ecx, dword ptr [rsp + 0xc]
+;;      	 8bc9                 	mov	ecx, ecx
+;;      	 498b5650             	mov	rdx, qword ptr [r14 + 0x50]
+;;      	 4801ca               	add	rdx, rcx
+;;      	 4883c204             	add	rdx, 4
+;;      	 8902                 	mov	dword ptr [rdx], eax
+;;      	 4883c410             	add	rsp, 0x10
+;;      	 5d                   	pop	rbp
+;;      	 c3                   	ret	
+;;   46:	 0f0b                 	ud2	
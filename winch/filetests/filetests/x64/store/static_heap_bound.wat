@@ -0,0 +1,39 @@
+;;! target = "x86_64"
+;;! [tunables]
+;;! static_memory_bound = 1
+;;! static_memory_offset_guard_size = 0
+
+(module
+  (memory 1 1)
+  (func (param i32 i64)
+    (i64.store offset=32 (local.get 0) (local.get 1))
+  )
+)
+;;      	 55                   	push	rbp
+;;      	 4889e5               	mov	rbp, rsp
+;;      	 4989fe               	mov	r14, rdi
+;;      	 4883ec18             	sub	rsp, 0x18
+;;      	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;      	 4d8b1b               	mov	r11, qword ptr [r11]
+;;      	 4939e3               	cmp	r11, rsp
+;;      	 0f874b000000         	ja	0x66
+;;   1b:	 89542414             	mov	dword ptr [rsp + 0x14], edx
+;;      	 48894c2408           	mov	qword ptr [rsp + 8], rcx
+;;      	 4c893424             	mov	qword ptr [rsp], r14
+;;      	 488b442408           	mov	rax, qword ptr [rsp + 8]
+;;      	 8b4c2414             	mov	ecx, dword ptr [rsp + 0x14]
+;;      	 8bc9                 	mov	ecx, ecx
+;;      	 4881f9d8ff0000       	cmp	rcx, 0xffd8
+;;      	 0f8728000000         	ja	0x68
+;;   40:	 498b5650             	mov	rdx, qword ptr [r14 + 0x50]
+;;      	 4801ca               	add	rdx, rcx
+;;      	 4883c220             	add	rdx, 0x20
+;;      	 48c7c300000000       	mov	rbx, 0
+;;      	 4881f9d8ff0000       	cmp	rcx, 0xffd8
+;;      	 480f47d3             	cmova	rdx, rbx
+;;      	 488902               	mov	qword ptr [rdx], rax
+;;      	 4883c418             	add	rsp, 0x18
+;;      	 5d                   	pop	rbp
+;;      	 c3                   	ret	
+;;   66:	 0f0b                 	ud2	
+;;   68:	 0f0b                 	ud2	
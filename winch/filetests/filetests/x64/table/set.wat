@@ -16,34 +16,36 @@
 
 ;;      	 55                   	push	rbp
 ;;      	 4889e5               	mov	rbp, rsp
+;;      	 4989fe               	mov	r14, rdi
 ;;      	 4883ec08             	sub	rsp, 8
 ;;      	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
 ;;      	 4d8b1b               	mov	r11, qword ptr [r11]
 ;;      	 4939e3               	cmp	r11, rsp
-;;      	 0f870a000000         	ja	0x22
-;;   18:	 4c893424             	mov	qword ptr [rsp], r14
+;;      	 0f870a000000         	ja	0x25
+;;   1b:	 4c893424             	mov	qword ptr [rsp], r14
 ;;      	 4883c408             	add	rsp, 8
 ;;      	 5d                   	pop	rbp
 ;;      	 c3                   	ret	
-;;   22:	 0f0b                 	ud2	
+;;   25:	 0f0b                 	ud2	
 ;;
 ;;      	 55                   	push	rbp
 ;;      	 4889e5               	mov	rbp, rsp
+;;      	 4989fe               	mov	r14, rdi
 ;;      	 4883ec18             	sub	rsp, 0x18
 ;;      	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
 ;;      	 4d8b1b               	mov	r11, qword ptr [r11]
 ;;      	 4939e3               	cmp	r11, rsp
-;;      	 0f8748000000         	ja	0x60
-;;   18:	 897c2414             	mov	dword ptr [rsp + 0x14], edi
-;;      	 4889742408           	mov	qword ptr [rsp + 8], rsi
+;;      	 0f8748000000         	ja	0x63
+;;   1b:	 89542414             	mov	dword ptr [rsp + 0x14], edx
+;;      	 48894c2408           	mov	qword ptr [rsp + 8], rcx
 ;;      	 4c893424             	mov	qword ptr [rsp], r14
 ;;      	 488b442408           	mov	rax, qword ptr [rsp + 8]
 ;;      	 8b4c2414             	mov	ecx, dword ptr [rsp + 0x14]
 ;;      	 4c89f2               	mov	rdx, r14
 ;;      	 8b5a50               	mov	ebx, dword ptr [rdx + 0x50]
 ;;      	 39d9                 	cmp	ecx, ebx
-;;      	 0f8326000000         	jae	0x62
-;;   3c:	 4189cb               	mov	r11d, ecx
+;;      	 0f8326000000         	jae	0x65
+;;   3f:	 4189cb               	mov	r11d, ecx
 ;;      	 4d6bdb08             	imul	r11, r11, 8
 ;;      	 488b5248             	mov	rdx, qword ptr [rdx + 0x48]
 ;;      	 4889d6               	mov	rsi, rdx
@@ -55,25 +57,26 @@
 ;;      	 4883c418             	add	rsp, 0x18
 ;;      	 5d                   	pop	rbp
 ;;      	 c3                   	ret	
-;;   60:	 0f0b                 	ud2	
-;;   62:	 0f0b                 	ud2	
+;;   63:	 0f0b                 	ud2	
+;;   65:	 0f0b                 	ud2	
 ;;
 ;;      	 55                   	push	rbp
 ;;      	 4889e5               	mov	rbp, rsp
+;;      	 4989fe               	mov	r14, rdi
 ;;      	 4883ec10             	sub	rsp, 0x10
 ;;      	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
 ;;      	 4d8b1b               	mov	r11, qword ptr [r11]
 ;;      	 4939e3               	cmp	r11, rsp
-;;      	 0f87b4000000         	ja	0xcc
-;;   18:	 897c240c             	mov	dword ptr [rsp + 0xc], edi
-;;      	 89742408             	mov	dword ptr [rsp + 8], esi
+;;      	 0f87bd000000         	ja	0xd8
+;;   1b:	 8954240c             	mov	dword ptr [rsp + 0xc], edx
+;;      	 894c2408             	mov	dword ptr [rsp + 8], ecx
 ;;      	 4c893424             	mov	qword ptr [rsp], r14
 ;;      	 8b4c2408             	mov	ecx, dword ptr [rsp + 8]
 ;;      	 4c89f2               	mov	rdx, r14
 ;;      	 8b5a50               	mov	ebx, dword ptr [rdx + 0x50]
 ;;      	 39d9                 	cmp	ecx, ebx
-;;      	 0f8398000000         	jae	0xce
-;;   36:	 4189cb               	mov	r11d, ecx
+;;      	 0f83a1000000         	jae	0xda
+;;   39:	 4189cb               	mov	r11d, ecx
 ;;      	 4d6bdb08             	imul	r11, r11, 8
 ;;      	 488b5248             	mov	rdx, qword ptr [rdx + 0x48]
 ;;      	 4889d6               	mov	rsi, rdx
@@ -82,29 +85,31 @@
 ;;      	 480f43d6             	cmovae	rdx, rsi
 ;;      	 488b02               	mov	rax, qword ptr [rdx]
 ;;      	 4885c0               	test	rax, rax
-;;      	 0f8536000000         	jne	0x8f
-;;   59:	 4d8b5e38             	mov	r11, qword ptr [r14 + 0x38]
+;;      	 0f853f000000         	jne	0x9b
+;;   5c:	 4d8b5e38             	mov	r11, qword ptr [r14 + 0x38]
 ;;      	 498b5b48             	mov	rbx, qword ptr [r11 + 0x48]
 ;;      	 448b5c240c           	mov	r11d, dword ptr [rsp + 0xc]
-;;      	 4883ec04             	sub	rsp, 4
+;;      	 4883ec08             	sub	rsp, 8
 ;;      	 44891c24             	mov	dword ptr [rsp], r11d
 ;;      	 4156                 	push	r14
-;;      	 4883ec04             	sub	rsp, 4
+;;      	 4883ec08             	sub	rsp, 8
 ;;      	 890c24               	mov	dword ptr [rsp], ecx
-;;      	 488b7c2404           	mov	rdi, qword ptr [rsp + 4]
+;;      	 4883ec08             	sub	rsp, 8
+;;      	 488b7c2410           	mov	rdi, qword ptr [rsp + 0x10]
 ;;      	 be00000000           	mov	esi, 0
-;;      	 8b1424               	mov	edx, dword ptr [rsp]
+;;      	 8b542408             	mov	edx, dword ptr [rsp + 8]
 ;;      	 ffd3                 	call	rbx
-;;      	 4883c40c             	add	rsp, 0xc
-;;      	 e904000000           	jmp	0x93
-;;   8f:	 4883e0fe             	and	rax, 0xfffffffffffffffe
+;;      	 4883c408             	add	rsp, 8
+;;      	 4883c410             	add	rsp, 0x10
+;;      	 e904000000           	jmp	0x9f
+;;   9b:	 4883e0fe             	and	rax, 0xfffffffffffffffe
 ;;      	 8b0c24               	mov	ecx, dword ptr [rsp]
-;;      	 4883c404             	add	rsp, 4
+;;      	 4883c408             	add	rsp, 8
 ;;      	 4c89f2               	mov	rdx, r14
 ;;      	 8b5a50               	mov	ebx, dword ptr [rdx + 0x50]
 ;;      	 39d9                 	cmp	ecx, ebx
-;;      	 0f8328000000         	jae	0xd0
-;;   a8:	 4189cb               	mov	r11d, ecx
+;;      	 0f8328000000         	jae	0xdc
+;;   b4:	 4189cb               	mov	r11d, ecx
 ;;      	 4d6bdb08             	imul	r11, r11, 8
 ;;      	 488b5248             	mov	rdx, qword ptr [rdx + 0x48]
 ;;      	 4889d6               	mov	rsi, rdx
@@ -116,6 +121,6 @@
 ;;      	 4883c410             	add	rsp, 0x10
 ;;      	 5d                   	pop	rbp
 ;;      	 c3                   	ret	
-;;   cc:	 0f0b                 	ud2	
-;;   ce:	 0f0b                 	ud2	
-;;   d0:	 0f0b                 	ud2	
+;;   d8:	 0f0b                 	ud2	
+;;   da:	 0f0b                 	ud2	
+;;   dc:	 0f0b                 	ud2	
@@ -0,0 +1,34 @@
+;;! target = "x86_64"
+;;! flags = ["has_sse3", "has_ssse3", "has_sse41", "has_sse42"]
+
+(module
+    (func (param v128 v128 v128) (result v128)
+        (local.get 0)
+        (local.get 1)
+        (local.get 2)
+        (v128.bitselect)
+    )
+)
+;;      	 55                   	push	rbp
+;;      	 4889e5               	mov	rbp, rsp
+;;      	 4989fe               	mov	r14, rdi
+;;      	 4883ec38             	sub	rsp, 0x38
+;;      	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;      	 4d8b1b               	mov	r11, qword ptr [r11]
+;;      	 4939e3               	cmp	r11, rsp
+;;      	 0f873e000000         	ja	0x59
+;;   1b:	 f30f7f442428         	movdqu	xmmword ptr [rsp + 0x28], xmm0
+;;      	 f30f7f4c2418         	movdqu	xmmword ptr [rsp + 0x18], xmm1
+;;      	 f30f7f542408         	movdqu	xmmword ptr [rsp + 8], xmm2
+;;      	 4c893424             	mov	qword ptr [rsp], r14
+;;      	 f30f6f442408         	movdqu	xmm0, xmmword ptr [rsp + 8]
+;;      	 f30f6f4c2418         	movdqu	xmm1, xmmword ptr [rsp + 0x18]
+;;      	 f30f6f542428         	movdqu	xmm2, xmmword ptr [rsp + 0x28]
+;;      	 660fdbd0             	pand	xmm2, xmm0
+;;      	 660fdfc1             	pandn	xmm0, xmm1
+;;      	 660febd0             	por	xmm2, xmm0
+;;      	 660f6fc2             	movdqa	xmm0, xmm2
+;;      	 4883c438             	add	rsp, 0x38
+;;      	 5d                   	pop	rbp
+;;      	 c3                   	ret	
+;;   59:	 0f0b                 	ud2	
@@ -0,0 +1,30 @@
+;;! target = "x86_64"
+;;! flags = ["has_sse3", "has_ssse3", "has_sse41", "has_sse42"]
+
+(module
+    (func (result v128)
+        (v128.const i32x4 1 2 3 4)
+    )
+)
+;;      	 55                   	push	rbp
+;;      	 4889e5               	mov	rbp, rsp
+;;      	 4989fe               	mov	r14, rdi
+;;      	 4883ec08             	sub	rsp, 8
+;;      	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;      	 4d8b1b               	mov	r11, qword ptr [r11]
+;;      	 4939e3               	cmp	r11, rsp
+;;      	 0f8712000000         	ja	0x2d
+;;   1b:	 4c893424             	mov	qword ptr [rsp], r14
+;;      	 f30f6f0509000000     	movdqu	xmm0, xmmword ptr [rip + 9]
+;;      	 4883c408             	add	rsp, 8
+;;      	 5d                   	pop	rbp
+;;      	 c3                   	ret	
+;;   2d:	 0f0b                 	ud2	
+;;   2f:	 0001                 	add	byte ptr [rcx], al
+;;   31:	 0000                 	add	byte ptr [rax], al
+;;   33:	 0002                 	add	byte ptr [rdx], al
+;;   35:	 0000                 	add	byte ptr [rax], al
+;;   37:	 0003                 	add	byte ptr [rbx], al
+;;   39:	 0000                 	add	byte ptr [rax], al
+;;   3b:	 000400               	add	byte ptr [rax + rax], al
+;;   3e:	 0000                 	add	byte ptr [rax], al
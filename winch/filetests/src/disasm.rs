@@ -29,12 +29,14 @@ pub fn disasm(
         .map(|i| {
             use capstone::InsnGroupType::{CS_GRP_JUMP, CS_GRP_RET};
 
-            let detail = dis.insn_detail(&i).unwrap();
+            // Data skipped by the disassembler (e.g. jump tables or trap
+            // instructions in Aarch64) doesn't carry any details.
+            let groups = dis
+                .insn_detail(&i)
+                .map(|detail| detail.groups().map(|g| g.0 as u32).collect::<Vec<_>>())
+                .unwrap_or_default();
 
-            let is_jump = detail
-                .groups()
-                .find(|g| g.0 as u32 == CS_GRP_JUMP)
-                .is_some();
+            let is_jump = groups.contains(&CS_GRP_JUMP);
 
             let mut line = String::new();
 
@@ -67,8 +69,7 @@ pub fn disasm(
 
             // Flip write_offsets to true once we've seen a `ret`, as instructions that follow the
             // return are often related to trap tables.
-            write_offsets =
-                write_offsets || detail.groups().find(|g| g.0 as u32 == CS_GRP_RET).is_some();
+            write_offsets = write_offsets || groups.contains(&CS_GRP_RET);
 
             line
         })
@@ -11,7 +11,7 @@ mod test {
     use target_lexicon::Triple;
     use wasmtime_environ::ModuleTranslation;
     use wasmtime_environ::{
-        wasmparser::{Parser as WasmParser, Validator},
+        wasmparser::{Parser as WasmParser, Validator, WasmFeatures},
         DefinedFuncIndex, FunctionBodyData, ModuleEnvironment, ModuleTypesBuilder, Tunables,
         TypeConvert, VMOffsets,
     };
@@ -22,6 +22,39 @@ mod test {
     struct TestConfig {
         target: String,
         flags: Option<Vec<String>>,
+        #[serde(default)]
+        tunables: TestTunables,
+    }
+
+    /// Overrides for the default [`Tunables`] used to compile a test.
+    #[derive(Clone, Debug, Default, Serialize, Deserialize)]
+    struct TestTunables {
+        static_memory_bound: Option<u64>,
+        static_memory_offset_guard_size: Option<u64>,
+        dynamic_memory_offset_guard_size: Option<u64>,
+        consume_fuel: Option<bool>,
+        epoch_interruption: Option<bool>,
+    }
+
+    impl TestTunables {
+        /// Apply the overrides to the given [`Tunables`].
+        fn apply(&self, tunables: &mut Tunables) {
+            if let Some(bound) = self.static_memory_bound {
+                tunables.static_memory_bound = bound;
+            }
+            if let Some(size) = self.static_memory_offset_guard_size {
+                tunables.static_memory_offset_guard_size = size;
+            }
+            if let Some(size) = self.dynamic_memory_offset_guard_size {
+                tunables.dynamic_memory_offset_guard_size = size;
+            }
+            if let Some(enable) = self.consume_fuel {
+                tunables.consume_fuel = enable;
+            }
+            if let Some(enable) = self.epoch_interruption {
+                tunables.epoch_interruption = enable;
+            }
+        }
     }
 
     /// A helper function to parse the test configuration from the top of the file.
@@ -101,10 +134,18 @@ mod test {
         }
         let isa = isa_builder.finish(shared_flags).unwrap();
 
-        let mut validator = Validator::new();
+        let mut validator = Validator::new_with_features(WasmFeatures {
+            memory64: true,
+            simd: true,
+            threads: true,
+            function_references: true,
+            tail_call: true,
+            ..Default::default()
+        });
         let parser = WasmParser::new(0);
         let mut types = Default::default();
-        let tunables = Tunables::default();
+        let mut tunables = Tunables::default();
+        config.tunables.apply(&mut tunables);
         let mut translation = ModuleEnvironment::new(&tunables, &mut validator, &mut types)
             .translate(parser, &wasm)
             .context("Failed to translate WebAssembly module")
@@ -114,7 +155,7 @@ mod test {
 
         let binding = body_inputs
             .into_iter()
-            .map(|func| compile(&isa, &types, &translation, func).join("\n"))
+            .map(|func| compile(&isa, &types, &translation, &tunables, func).join("\n"))
             .collect::<Vec<String>>()
             .join("\n\n");
         let actual = binding.as_str();
@@ -149,6 +190,7 @@ mod test {
         isa: &Box<dyn TargetIsa>,
         module_types: &ModuleTypesBuilder,
         translation: &ModuleTranslation,
+        tunables: &Tunables,
         f: (DefinedFuncIndex, FunctionBodyData<'_>),
     ) -> Vec<String> {
         let module = &translation.module;
@@ -168,6 +210,7 @@ mod test {
                 &body,
                 translation,
                 module_types,
+                tunables,
                 &mut builtins,
                 &mut validator,
             )
@@ -40,7 +40,7 @@ pub fn run(opt: &Options) -> Result<()> {
 
     body_inputs
         .into_iter()
-        .try_for_each(|func| compile(&isa, &translation, &types, func))?;
+        .try_for_each(|func| compile(&isa, &translation, &types, &tunables, func))?;
 
     Ok(())
 }
@@ -49,6 +49,7 @@ fn compile(
     isa: &Box<dyn TargetIsa>,
     translation: &ModuleTranslation,
     module_types: &ModuleTypesBuilder,
+    tunables: &Tunables,
     f: (DefinedFuncIndex, FunctionBodyData<'_>),
 ) -> Result<()> {
     let index = translation.module.func_index(f.0);
@@ -65,6 +66,7 @@ fn compile(
             &body,
             translation,
             module_types,
+            tunables,
             &mut builtins,
             &mut validator,
         )